@@ -0,0 +1,128 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nautilus_adapters::pool::DataPool;
+use nautilus_model::{
+    data::{delta::OrderBookDelta, order::BookOrder, trade::TradeTick},
+    enums::{AggressorSide, BookAction, OrderSide},
+    identifiers::{InstrumentId, TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+
+const BATCHES: usize = 10;
+const BATCH_SIZE: usize = 100_000;
+
+fn decode_delta(instrument_id: InstrumentId, i: usize) -> OrderBookDelta {
+    let order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from_raw(i as i64, 2),
+        Quantity::from_raw(i as u64, 0),
+        i as u64,
+    );
+    OrderBookDelta::new(
+        instrument_id,
+        BookAction::Add,
+        order,
+        0,
+        i as u64,
+        (i as u64).into(),
+        (i as u64).into(),
+    )
+}
+
+fn decode_trade(instrument_id: InstrumentId, trade_id: TradeId, i: usize) -> TradeTick {
+    TradeTick::new(
+        instrument_id,
+        Price::from_raw(i as i64, 2),
+        Quantity::from_raw(i as u64, 0),
+        AggressorSide::Buyer,
+        trade_id,
+        (i as u64).into(),
+        (i as u64).into(),
+    )
+}
+
+fn bench_deltas(c: &mut Criterion) {
+    let instrument_id = InstrumentId::from("ESM4.GLBX");
+    let mut group = c.benchmark_group("pool_deltas");
+    group.sample_size(10);
+
+    group.bench_function("fresh_vec", |b| {
+        b.iter(|| {
+            for _ in 0..BATCHES {
+                let mut deltas = Vec::new();
+                for i in 0..BATCH_SIZE {
+                    deltas.push(decode_delta(instrument_id, i));
+                }
+                black_box(&deltas);
+            }
+        });
+    });
+
+    group.bench_function("pooled_vec", |b| {
+        let mut pool = DataPool::new(BATCH_SIZE, 1);
+        b.iter(|| {
+            for _ in 0..BATCHES {
+                let mut deltas = pool.acquire();
+                for i in 0..BATCH_SIZE {
+                    deltas.push(decode_delta(instrument_id, i));
+                }
+                black_box(&deltas);
+                pool.release(deltas);
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_trades(c: &mut Criterion) {
+    let instrument_id = InstrumentId::from("ESM4.GLBX");
+    let trade_id = TradeId::new("1");
+    let mut group = c.benchmark_group("pool_trades");
+    group.sample_size(10);
+
+    group.bench_function("fresh_vec", |b| {
+        b.iter(|| {
+            for _ in 0..BATCHES {
+                let mut trades = Vec::new();
+                for i in 0..BATCH_SIZE {
+                    trades.push(decode_trade(instrument_id, trade_id, i));
+                }
+                black_box(&trades);
+            }
+        });
+    });
+
+    group.bench_function("pooled_vec", |b| {
+        let mut pool = DataPool::new(BATCH_SIZE, 1);
+        b.iter(|| {
+            for _ in 0..BATCHES {
+                let mut trades = pool.acquire();
+                for i in 0..BATCH_SIZE {
+                    trades.push(decode_trade(instrument_id, trade_id, i));
+                }
+                black_box(&trades);
+                pool.release(trades);
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_deltas, bench_trades);
+criterion_main!(benches);
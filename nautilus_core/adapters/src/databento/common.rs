@@ -16,12 +16,33 @@
 //! Common functions to support Databento adapter operations.
 
 use databento::historical::DateTimeRange;
+use nautilus_common::capabilities::ClientCapabilities;
 use nautilus_core::nanos::UnixNanos;
+use nautilus_model::enums::BookType;
 use time::OffsetDateTime;
 
 pub const DATABENTO: &str = "DATABENTO";
 pub const ALL_SYMBOLS: &str = "ALL_SYMBOLS";
 
+/// Returns the capabilities of a Databento data client.
+///
+/// Order books are provided as MBO deltas, or as MBP-1 and MBP-10 snapshots.
+#[must_use]
+pub fn databento_capabilities() -> ClientCapabilities {
+    ClientCapabilities::unrestricted()
+        .with_data_types([
+            "InstrumentAny",
+            "OrderBookDelta",
+            "OrderBookDepth10",
+            "QuoteTick",
+            "TradeTick",
+            "Bar",
+            "InstrumentStatus",
+        ])
+        .with_book_types([BookType::L1_MBP, BookType::L2_MBP, BookType::L3_MBO])
+        .with_book_depths([1, 10])
+}
+
 pub fn get_date_time_range(start: UnixNanos, end: UnixNanos) -> anyhow::Result<DateTimeRange> {
     Ok(DateTimeRange::from((
         OffsetDateTime::from_unix_timestamp_nanos(i128::from(start.as_u64()))?,
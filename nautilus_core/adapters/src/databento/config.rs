@@ -0,0 +1,47 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the configuration for a Databento live data client.
+
+use std::path::PathBuf;
+
+use nautilus_common::{
+    config::{check_setting, ValidateConfig},
+    credentials::CredentialSource,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::pipeline::PipelineConfig;
+
+/// Configuration for a Databento live data client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabentoDataClientConfig {
+    /// The source of the Databento API secret key.
+    pub api_key: CredentialSource,
+    /// The Databento dataset to subscribe to, e.g. `GLBX.MDP3`.
+    pub dataset: String,
+    /// The path to the publishers JSON file mapping publisher IDs to venues.
+    pub publishers_filepath: PathBuf,
+    /// The capacities and backpressure policies of the live message pipeline.
+    #[serde(default)]
+    pub pipeline: PipelineConfig,
+}
+
+impl ValidateConfig for DatabentoDataClientConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(!self.dataset.is_empty(), "databento.dataset", "was empty")?;
+        self.pipeline.validate()
+    }
+}
@@ -264,6 +264,7 @@ pub fn decode_equity_v1(
         instrument_id,
         instrument_id.symbol,
         None, // No ISIN available yet
+        None, // No FIGI available yet
         currency,
         currency.precision,
         decode_price(msg.min_price_increment, currency.precision)?,
@@ -306,6 +307,8 @@ pub fn decode_futures_contract_v1(
         instrument_id.symbol,
         asset_class.unwrap_or(AssetClass::Commodity),
         Some(exchange),
+        None, // No ISIN available yet
+        None, // No FIGI available yet
         underlying,
         msg.activation.into(),
         msg.expiration.into(),
@@ -408,6 +411,8 @@ pub fn decode_options_contract_v1(
         instrument_id.symbol,
         asset_class_opt.unwrap_or(AssetClass::Commodity),
         Some(exchange),
+        None, // No ISIN available yet
+        None, // No FIGI available yet
         underlying,
         parse_option_kind(msg.instrument_class)?,
         Price::from_raw(msg.strike_price, currency.precision),
@@ -942,6 +947,7 @@ pub fn decode_equity(
         instrument_id,
         instrument_id.symbol,
         None, // No ISIN available yet
+        None, // No FIGI available yet
         currency,
         currency.precision,
         decode_price(msg.min_price_increment, currency.precision)?,
@@ -984,6 +990,8 @@ pub fn decode_futures_contract(
         instrument_id.symbol,
         asset_class.unwrap_or(AssetClass::Commodity),
         Some(exchange),
+        None, // No ISIN available yet
+        None, // No FIGI available yet
         underlying,
         msg.activation.into(),
         msg.expiration.into(),
@@ -1086,6 +1094,8 @@ pub fn decode_options_contract(
         instrument_id.symbol,
         asset_class_opt.unwrap_or(AssetClass::Commodity),
         Some(exchange),
+        None, // No ISIN available yet
+        None, // No FIGI available yet
         underlying,
         parse_option_kind(msg.instrument_class)?,
         Price::from_raw(msg.strike_price, currency.precision),
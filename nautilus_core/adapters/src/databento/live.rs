@@ -33,6 +33,7 @@ use nautilus_model::{
         Data,
     },
     enums::RecordFlag,
+    events::instrument::InstrumentUpdate,
     identifiers::{InstrumentId, Symbol, Venue},
     instruments::any::InstrumentAny,
 };
@@ -45,9 +46,12 @@ use super::{
     decode::{decode_imbalance_msg, decode_statistics_msg, decode_status_msg},
     types::{DatabentoImbalance, DatabentoStatistics},
 };
-use crate::databento::{
-    decode::{decode_instrument_def_msg, decode_record},
-    types::PublisherId,
+use crate::{
+    databento::{
+        decode::{decode_instrument_def_msg, decode_record},
+        types::PublisherId,
+    },
+    pipeline::{ConflationKey, PipelineItem, PipelineSender, StreamType},
 };
 
 #[derive(Debug)]
@@ -62,6 +66,8 @@ pub enum LiveCommand {
 pub enum LiveMessage {
     Data(Data),
     Instrument(InstrumentAny),
+    /// An intraday change to a previously defined instrument, with its new definition.
+    InstrumentUpdate(InstrumentUpdate, InstrumentAny),
     Status(InstrumentStatus),
     Imbalance(DatabentoImbalance),
     Statistics(DatabentoStatistics),
@@ -69,16 +75,40 @@ pub enum LiveMessage {
     Close,
 }
 
+impl PipelineItem for LiveMessage {
+    fn stream_type(&self) -> Option<StreamType> {
+        match self {
+            Self::Data(data) => data.stream_type(),
+            Self::Instrument(_) | Self::InstrumentUpdate(..) => Some(StreamType::Instruments),
+            Self::Status(_) => Some(StreamType::Status),
+            Self::Imbalance(_) | Self::Statistics(_) => Some(StreamType::Other),
+            Self::Error(_) | Self::Close => None,
+        }
+    }
+
+    fn conflation_key(&self) -> Option<ConflationKey> {
+        let instrument_id = match self {
+            Self::Data(data) => return data.conflation_key(),
+            Self::Instrument(instrument) | Self::InstrumentUpdate(_, instrument) => instrument.id(),
+            Self::Status(status) => status.instrument_id,
+            Self::Imbalance(imbalance) => imbalance.instrument_id,
+            Self::Statistics(statistics) => statistics.instrument_id,
+            Self::Error(_) | Self::Close => return None,
+        };
+        Some(ConflationKey::Instrument(instrument_id))
+    }
+}
+
 /// Handles a raw TCP data feed from the Databento LSG for a single dataset.
 ///
 /// [`LiveCommand`] messages are recieved synchronously across a channel,
-/// decoded records are sent asynchronously on a tokio channel as [`LiveMessage`]s
+/// decoded records are sent asynchronously on a bounded pipeline as [`LiveMessage`]s
 /// back to a message processing task.
 pub struct DatabentoFeedHandler {
     key: String,
     dataset: String,
     cmd_rx: tokio::sync::mpsc::UnboundedReceiver<LiveCommand>,
-    msg_tx: tokio::sync::mpsc::Sender<LiveMessage>,
+    msg_tx: PipelineSender<LiveMessage>,
     publisher_venue_map: IndexMap<PublisherId, Venue>,
     replay: bool,
 }
@@ -90,7 +120,7 @@ impl DatabentoFeedHandler {
         key: String,
         dataset: String,
         rx: tokio::sync::mpsc::UnboundedReceiver<LiveCommand>,
-        tx: tokio::sync::mpsc::Sender<LiveMessage>,
+        tx: PipelineSender<LiveMessage>,
         publisher_venue_map: IndexMap<PublisherId, Venue>,
     ) -> Self {
         Self {
@@ -109,6 +139,7 @@ impl DatabentoFeedHandler {
         let clock = get_atomic_clock_realtime();
         let mut symbol_map = PitSymbolMap::new();
         let mut instrument_id_map: HashMap<u32, InstrumentId> = HashMap::new();
+        let mut definitions: HashMap<InstrumentId, InstrumentAny> = HashMap::new();
 
         let mut buffering_start = None;
         let mut buffered_deltas: HashMap<InstrumentId, Vec<OrderBookDelta>> = HashMap::new();
@@ -214,7 +245,15 @@ impl DatabentoFeedHandler {
                 handle_symbol_mapping_msg(msg, &mut symbol_map, &mut instrument_id_map);
             } else if let Some(msg) = record.get::<dbn::InstrumentDefMsg>() {
                 let data = handle_instrument_def_msg(msg, &self.publisher_venue_map, clock)?;
-                self.send_msg(LiveMessage::Instrument(data)).await;
+                // Redefinitions during the session are sent as updates to the instrument
+                let msg = match definitions.insert(data.id(), data.clone()) {
+                    Some(previous) => match InstrumentUpdate::between(&previous, &data) {
+                        Some(update) => LiveMessage::InstrumentUpdate(update, data),
+                        None => LiveMessage::Instrument(data),
+                    },
+                    None => LiveMessage::Instrument(data),
+                };
+                self.send_msg(msg).await;
             } else if let Some(msg) = record.get::<dbn::StatusMsg>() {
                 let data = handle_status_msg(
                     msg,
@@ -260,19 +260,26 @@ impl DatabentoDataLoader {
         filepath: &Path,
         instrument_id: Option<InstrumentId>,
     ) -> anyhow::Result<Vec<OrderBookDelta>> {
-        self.read_records::<dbn::MboMsg>(filepath, instrument_id, false)?
-            .filter_map(|result| match result {
-                Ok((Some(item1), _)) => {
-                    if let Data::Delta(delta) = item1 {
-                        Some(Ok(delta))
-                    } else {
-                        None
-                    }
-                }
-                Ok((None, _)) => None,
-                Err(e) => Some(Err(e)),
-            })
-            .collect()
+        let mut deltas = Vec::new();
+        self.load_order_book_deltas_into(filepath, instrument_id, &mut deltas)?;
+        Ok(deltas)
+    }
+
+    /// Loads order book deltas into the given `buffer`, which is cleared first while retaining
+    /// its allocation (e.g. a buffer acquired from a [`DataPool`](crate::pool::DataPool)).
+    pub fn load_order_book_deltas_into(
+        &self,
+        filepath: &Path,
+        instrument_id: Option<InstrumentId>,
+        buffer: &mut Vec<OrderBookDelta>,
+    ) -> anyhow::Result<()> {
+        buffer.clear();
+        for result in self.read_records::<dbn::MboMsg>(filepath, instrument_id, false)? {
+            if let (Some(Data::Delta(delta)), _) = result? {
+                buffer.push(delta);
+            }
+        }
+        Ok(())
     }
 
     pub fn load_order_book_depth10(
@@ -359,19 +366,26 @@ impl DatabentoDataLoader {
         filepath: &Path,
         instrument_id: Option<InstrumentId>,
     ) -> anyhow::Result<Vec<TradeTick>> {
-        self.read_records::<dbn::TradeMsg>(filepath, instrument_id, false)?
-            .filter_map(|result| match result {
-                Ok((Some(item1), _)) => {
-                    if let Data::Trade(trade) = item1 {
-                        Some(Ok(trade))
-                    } else {
-                        None
-                    }
-                }
-                Ok((None, _)) => None,
-                Err(e) => Some(Err(e)),
-            })
-            .collect()
+        let mut trades = Vec::new();
+        self.load_trades_into(filepath, instrument_id, &mut trades)?;
+        Ok(trades)
+    }
+
+    /// Loads trades into the given `buffer`, which is cleared first while retaining its
+    /// allocation (e.g. a buffer acquired from a [`DataPool`](crate::pool::DataPool)).
+    pub fn load_trades_into(
+        &self,
+        filepath: &Path,
+        instrument_id: Option<InstrumentId>,
+        buffer: &mut Vec<TradeTick>,
+    ) -> anyhow::Result<()> {
+        buffer.clear();
+        for result in self.read_records::<dbn::TradeMsg>(filepath, instrument_id, false)? {
+            if let (Some(Data::Trade(trade)), _) = result? {
+                buffer.push(trade);
+            }
+        }
+        Ok(())
     }
 
     pub fn load_bars(
@@ -541,6 +555,7 @@ mod tests {
     use rstest::*;
 
     use super::*;
+    use crate::pool::DataPool;
 
     fn test_data_path() -> PathBuf {
         Path::new(env!("CARGO_MANIFEST_DIR")).join("src/databento/test_data")
@@ -638,6 +653,41 @@ mod tests {
         assert_eq!(trades.len(), 2);
     }
 
+    #[rstest]
+    fn test_load_into_pooled_buffers() {
+        let loader = data_loader();
+        let instrument_id = InstrumentId::from("ESM4.GLBX");
+        let mut delta_pool = DataPool::new(16, 1);
+        let mut trade_pool = DataPool::new(16, 1);
+
+        let mut deltas = delta_pool.acquire();
+        let mut trades = trade_pool.acquire();
+        for _ in 0..2 {
+            loader
+                .load_order_book_deltas_into(
+                    &test_data_path().join("test_data.mbo.dbn.zst"),
+                    Some(instrument_id),
+                    &mut deltas,
+                )
+                .unwrap();
+            loader
+                .load_trades_into(
+                    &test_data_path().join("test_data.trades.dbn.zst"),
+                    Some(instrument_id),
+                    &mut trades,
+                )
+                .unwrap();
+        }
+
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(trades.len(), 2);
+        assert_eq!(deltas.capacity(), 16);
+        delta_pool.release(deltas);
+        trade_pool.release(trades);
+        assert_eq!(delta_pool.available(), 1);
+        assert_eq!(trade_pool.available(), 1);
+    }
+
     #[rstest]
     // #[case(test_data_path().join("test_data.ohlcv-1d.dbn.zst"))]  // TODO: Needs new data
     #[case(test_data_path().join("test_data.ohlcv-1h.dbn.zst"))]
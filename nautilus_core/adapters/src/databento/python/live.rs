@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 #![allow(clippy::legacy_numeric_constants)]
-use std::{fs, i128, path::PathBuf, str::FromStr};
+use std::{collections::HashMap, fs, i128, path::PathBuf, str::FromStr};
 
 use databento::{dbn, live::Subscription};
 use indexmap::IndexMap;
@@ -25,10 +25,15 @@ use nautilus_model::{
 use pyo3::prelude::*;
 use time::OffsetDateTime;
 
-use crate::databento::{
-    live::{DatabentoFeedHandler, LiveCommand, LiveMessage},
-    symbology::{check_consistent_symbology, infer_symbology_type},
-    types::DatabentoPublisher,
+use crate::{
+    databento::{
+        live::{DatabentoFeedHandler, LiveCommand, LiveMessage},
+        symbology::{check_consistent_symbology, infer_symbology_type},
+        types::DatabentoPublisher,
+    },
+    pipeline::{
+        pipeline, PipelineConfig, PipelineMonitor, PipelineReceiver, StreamMetrics, StreamType,
+    },
 };
 
 #[cfg_attr(
@@ -44,7 +49,8 @@ pub struct DatabentoLiveClient {
     is_closed: bool,
     cmd_tx: tokio::sync::mpsc::UnboundedSender<LiveCommand>,
     cmd_rx: Option<tokio::sync::mpsc::UnboundedReceiver<LiveCommand>>,
-    buffer_size: usize,
+    pipeline_config: PipelineConfig,
+    pipeline_monitor: Option<PipelineMonitor<LiveMessage>>,
     publisher_venue_map: IndexMap<u16, Venue>,
 }
 
@@ -54,8 +60,19 @@ impl DatabentoLiveClient {
         self.cmd_tx.is_closed()
     }
 
+    /// Sets the configuration of the pipeline carrying messages from the feed handler.
+    pub fn set_pipeline_config(&mut self, config: PipelineConfig) {
+        self.pipeline_config = config;
+    }
+
+    /// Returns the saturation metrics of the message pipeline, once the client is running.
+    #[must_use]
+    pub fn pipeline_metrics(&self) -> Option<HashMap<StreamType, StreamMetrics>> {
+        self.pipeline_monitor.as_ref().map(PipelineMonitor::metrics)
+    }
+
     async fn process_messages(
-        mut msg_rx: tokio::sync::mpsc::Receiver<LiveMessage>,
+        mut msg_rx: PipelineReceiver<LiveMessage>,
         callback: PyObject,
         callback_pyo3: PyObject,
     ) -> PyResult<()> {
@@ -68,11 +85,14 @@ impl DatabentoLiveClient {
                     let py_obj = data_to_pycapsule(py, data);
                     call_python(py, &callback, py_obj)
                 }),
-                LiveMessage::Instrument(data) => Python::with_gil(|py| {
-                    let py_obj =
-                        instrument_any_to_pyobject(py, data).expect("Failed creating instrument");
-                    call_python(py, &callback, py_obj)
-                }),
+                // Python receives the new definition of an updated instrument
+                LiveMessage::InstrumentUpdate(_, data) | LiveMessage::Instrument(data) => {
+                    Python::with_gil(|py| {
+                        let py_obj = instrument_any_to_pyobject(py, data)
+                            .expect("Failed creating instrument");
+                        call_python(py, &callback, py_obj)
+                    })
+                }
                 LiveMessage::Status(data) => Python::with_gil(|py| {
                     let py_obj = data.into_py(py);
                     call_python(py, &callback_pyo3, py_obj)
@@ -131,15 +151,13 @@ impl DatabentoLiveClient {
 
         let (cmd_tx, cmd_rx) = tokio::sync::mpsc::unbounded_channel::<LiveCommand>();
 
-        // Hard-coded to a reasonable size for now
-        let buffer_size = 100_000;
-
         Ok(Self {
             key,
             dataset,
             cmd_tx,
             cmd_rx: Some(cmd_rx),
-            buffer_size,
+            pipeline_config: PipelineConfig::default(),
+            pipeline_monitor: None,
             is_running: false,
             is_closed: false,
             publisher_venue_map,
@@ -202,7 +220,8 @@ impl DatabentoLiveClient {
 
         self.is_running = true;
 
-        let (msg_tx, msg_rx) = tokio::sync::mpsc::channel::<LiveMessage>(self.buffer_size);
+        let (msg_tx, msg_rx) = pipeline::<LiveMessage>(self.pipeline_config.clone());
+        self.pipeline_monitor = Some(msg_rx.monitor());
 
         // Consume the receiver
         // SAFETY: We guard the client from being started more than once with the
@@ -33,6 +33,9 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 //! - `tardis`: Includes the Tardis integration adapter.
 
+pub mod pipeline;
+pub mod pool;
+
 #[cfg(feature = "databento")]
 pub mod databento;
 
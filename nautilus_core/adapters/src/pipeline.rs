@@ -0,0 +1,672 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A bounded channel carrying decoded messages from an adapter's feed handler to its consumer.
+//!
+//! Each stream type has its own capacity and [`BackpressurePolicy`], applied when the messages
+//! of that type queued in the channel reach its capacity, so a slow consumer cannot cause
+//! unbounded memory growth. Messages are received in the order they were sent, and saturation
+//! metrics are kept per stream type.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use nautilus_common::config::{check_setting, ValidateConfig};
+use nautilus_model::{
+    data::{bar::BarType, Data},
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+use tokio::sync::Notify;
+
+/// The default capacity of each stream type in a pipeline.
+pub const DEFAULT_PIPELINE_CAPACITY: usize = 100_000;
+
+/// The policy applied when a stream's messages queued in a pipeline reach its capacity.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BackpressurePolicy {
+    /// The sender waits until the consumer receives a message of the stream.
+    #[default]
+    Block,
+    /// The oldest queued message of the stream is dropped.
+    DropOldest,
+    /// A queued message with the same conflation key is replaced by the newest message,
+    /// otherwise the oldest queued message of the stream is dropped.
+    Conflate,
+}
+
+/// The type of a stream of messages in a pipeline.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum StreamType {
+    OrderBookDeltas,
+    OrderBookDepth,
+    Quotes,
+    Trades,
+    Bars,
+    Instruments,
+    Status,
+    Other,
+}
+
+impl StreamType {
+    /// Returns whether each message of the stream is a full state which supersedes the
+    /// previous message for the same key, and so can be conflated.
+    #[must_use]
+    pub const fn is_conflatable(self) -> bool {
+        !matches!(self, Self::OrderBookDeltas | Self::Trades | Self::Other)
+    }
+
+    /// Returns whether messages of the stream can be dropped without corrupting the
+    /// state built from them downstream.
+    #[must_use]
+    pub const fn is_droppable(self) -> bool {
+        !matches!(self, Self::OrderBookDeltas)
+    }
+}
+
+/// The key identifying the messages of a stream which supersede each other when conflated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConflationKey {
+    Instrument(InstrumentId),
+    Bar(BarType),
+}
+
+/// A message which can be sent through a pipeline.
+pub trait PipelineItem {
+    /// Returns the stream type of the message, or `None` for a control message which is
+    /// never dropped and does not count towards any capacity.
+    fn stream_type(&self) -> Option<StreamType>;
+
+    /// Returns the conflation key of the message, or `None` for a control message.
+    fn conflation_key(&self) -> Option<ConflationKey>;
+}
+
+impl PipelineItem for Data {
+    fn stream_type(&self) -> Option<StreamType> {
+        Some(match self {
+            Self::Delta(_) | Self::Deltas(_) => StreamType::OrderBookDeltas,
+            Self::Depth10(_) => StreamType::OrderBookDepth,
+            Self::Quote(_) => StreamType::Quotes,
+            Self::Trade(_) => StreamType::Trades,
+            Self::Bar(_) => StreamType::Bars,
+        })
+    }
+
+    fn conflation_key(&self) -> Option<ConflationKey> {
+        Some(match self {
+            Self::Bar(bar) => ConflationKey::Bar(bar.bar_type),
+            _ => ConflationKey::Instrument(self.instrument_id()),
+        })
+    }
+}
+
+/// Configuration for the capacity and backpressure policy of a stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StreamConfig {
+    /// The maximum number of the stream's messages queued before the policy is applied.
+    pub capacity: usize,
+    /// The policy applied when the stream is at capacity.
+    pub policy: BackpressurePolicy,
+}
+
+impl Default for StreamConfig {
+    /// Creates a new default [`StreamConfig`] instance.
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_PIPELINE_CAPACITY,
+            policy: BackpressurePolicy::Block,
+        }
+    }
+}
+
+/// Configuration for a pipeline.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PipelineConfig {
+    /// The configuration for stream types without their own configuration.
+    pub default: StreamConfig,
+    /// The configurations for individual stream types.
+    pub streams: HashMap<StreamType, StreamConfig>,
+}
+
+impl PipelineConfig {
+    /// Returns the configuration for the given `stream_type`.
+    #[must_use]
+    pub fn stream(&self, stream_type: StreamType) -> StreamConfig {
+        self.streams
+            .get(&stream_type)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl ValidateConfig for PipelineConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        for stream_type in StreamType::iter() {
+            let config = self.stream(stream_type);
+            check_setting(
+                config.capacity > 0,
+                &format!("pipeline.{stream_type}.capacity"),
+                "must be positive",
+            )?;
+            check_setting(
+                config.policy != BackpressurePolicy::Conflate || stream_type.is_conflatable(),
+                &format!("pipeline.{stream_type}.policy"),
+                "CONFLATE not supported for incremental messages",
+            )?;
+            check_setting(
+                config.policy != BackpressurePolicy::DropOldest || stream_type.is_droppable(),
+                &format!("pipeline.{stream_type}.policy"),
+                "DROP_OLDEST not supported for order book deltas",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The saturation metrics for a stream in a pipeline.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StreamMetrics {
+    /// The capacity of the stream.
+    pub capacity: usize,
+    /// The number of the stream's messages currently queued.
+    pub len: usize,
+    /// The highest number of the stream's messages queued at once.
+    pub high_water_mark: usize,
+    /// The count of messages sent.
+    pub sent: u64,
+    /// The count of messages dropped at capacity.
+    pub dropped: u64,
+    /// The count of messages replaced by a newer message at capacity.
+    pub conflated: u64,
+    /// The count of sends which waited for capacity.
+    pub blocked: u64,
+    /// If the stream has reached capacity since its queue was last at most half full.
+    pub saturated: bool,
+}
+
+impl StreamMetrics {
+    /// Returns the fraction of the stream's capacity currently queued.
+    #[must_use]
+    pub fn saturation(&self) -> f64 {
+        self.len as f64 / self.capacity as f64
+    }
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    metrics: HashMap<StreamType, StreamMetrics>,
+    rx_closed: bool,
+}
+
+impl<T: PipelineItem> State<T> {
+    /// Pushes the `item`, returning it if its stream is at capacity and the sender must wait.
+    fn push(&mut self, item: T, config: &PipelineConfig) -> Result<(), T> {
+        let Some(stream_type) = item.stream_type() else {
+            self.queue.push_back(item);
+            return Ok(());
+        };
+        let stream_config = config.stream(stream_type);
+        let metrics = self.metrics.entry(stream_type).or_default();
+        metrics.capacity = stream_config.capacity;
+
+        if metrics.len >= stream_config.capacity {
+            let policy = stream_config.policy;
+            if !metrics.saturated {
+                metrics.saturated = true;
+                tracing::warn!(
+                    "Pipeline stream {stream_type} saturated at capacity {}, applying {policy}",
+                    stream_config.capacity,
+                );
+            }
+            let key = item.conflation_key();
+            let position = match policy {
+                BackpressurePolicy::Block => {
+                    metrics.blocked += 1;
+                    return Err(item);
+                }
+                BackpressurePolicy::Conflate => self
+                    .queue
+                    .iter()
+                    .rposition(|queued| {
+                        queued.stream_type() == Some(stream_type) && queued.conflation_key() == key
+                    })
+                    .inspect(|_| metrics.conflated += 1),
+                BackpressurePolicy::DropOldest => None,
+            };
+            let position = position.or_else(|| {
+                metrics.dropped += 1;
+                self.queue
+                    .iter()
+                    .position(|queued| queued.stream_type() == Some(stream_type))
+            });
+            if let Some(position) = position {
+                self.queue.remove(position);
+                metrics.len -= 1;
+            }
+        }
+
+        metrics.sent += 1;
+        metrics.len += 1;
+        metrics.high_water_mark = metrics.high_water_mark.max(metrics.len);
+        self.queue.push_back(item);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let item = self.queue.pop_front()?;
+        if let Some(stream_type) = item.stream_type() {
+            if let Some(metrics) = self.metrics.get_mut(&stream_type) {
+                metrics.len -= 1;
+                if metrics.saturated && metrics.len <= metrics.capacity / 2 {
+                    metrics.saturated = false;
+                    tracing::info!("Pipeline stream {stream_type} no longer saturated");
+                }
+            }
+        }
+        Some(item)
+    }
+}
+
+struct Shared<T> {
+    config: PipelineConfig,
+    state: Mutex<State<T>>,
+    senders: AtomicUsize,
+    not_empty: Notify,
+    not_full: Notify,
+}
+
+impl<T> Shared<T> {
+    fn state(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().expect("Pipeline state lock poisoned")
+    }
+
+    fn metrics(&self) -> HashMap<StreamType, StreamMetrics> {
+        self.state().metrics.clone()
+    }
+}
+
+/// Creates a new pipeline with the given `config`, returning its sender and receiver.
+#[must_use]
+pub fn pipeline<T: PipelineItem>(
+    config: PipelineConfig,
+) -> (PipelineSender<T>, PipelineReceiver<T>) {
+    let shared = Arc::new(Shared {
+        config,
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            metrics: HashMap::new(),
+            rx_closed: false,
+        }),
+        senders: AtomicUsize::new(1),
+        not_empty: Notify::new(),
+        not_full: Notify::new(),
+    });
+    (
+        PipelineSender {
+            shared: shared.clone(),
+        },
+        PipelineReceiver { shared },
+    )
+}
+
+/// Sends messages into a pipeline, applying the backpressure policy of each stream.
+pub struct PipelineSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: PipelineItem> PipelineSender<T> {
+    /// Sends the `item`, waiting for capacity if its stream is at capacity with a
+    /// [`BackpressurePolicy::Block`] policy.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the receiver has been closed.
+    pub async fn send(&self, item: T) -> anyhow::Result<()> {
+        let mut item = item;
+        loop {
+            let notified = self.shared.not_full.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            {
+                let mut state = self.shared.state();
+                if state.rx_closed {
+                    anyhow::bail!("Pipeline receiver closed");
+                }
+                match state.push(item, &self.shared.config) {
+                    Ok(()) => {
+                        drop(state);
+                        self.shared.not_empty.notify_one();
+                        return Ok(());
+                    }
+                    Err(returned) => item = returned,
+                }
+            }
+            notified.await;
+        }
+    }
+
+    /// Returns whether the receiver has been closed.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.shared.state().rx_closed
+    }
+
+    /// Returns the saturation metrics for each stream which has sent a message.
+    #[must_use]
+    pub fn metrics(&self) -> HashMap<StreamType, StreamMetrics> {
+        self.shared.metrics()
+    }
+}
+
+impl<T> Clone for PipelineSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::AcqRel);
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for PipelineSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.not_empty.notify_one();
+        }
+    }
+}
+
+/// Receives messages from a pipeline in the order they were sent.
+pub struct PipelineReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: PipelineItem> PipelineReceiver<T> {
+    /// Receives the next message, waiting until one is sent.
+    ///
+    /// Returns `None` once all senders are dropped and no messages remain.
+    pub async fn recv(&mut self) -> Option<T> {
+        let shared = self.shared.clone();
+        loop {
+            let notified = shared.not_empty.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if let Some(item) = self.try_recv() {
+                return Some(item);
+            }
+            if shared.senders.load(Ordering::Acquire) == 0 {
+                return self.try_recv();
+            }
+            notified.await;
+        }
+    }
+
+    /// Receives the next message if one is queued.
+    pub fn try_recv(&mut self) -> Option<T> {
+        let item = self.shared.state().pop();
+        if item.is_some() {
+            self.shared.not_full.notify_waiters();
+        }
+        item
+    }
+
+    /// Closes the receiver, failing subsequent sends while still allowing queued messages to
+    /// be received.
+    pub fn close(&mut self) {
+        self.shared.state().rx_closed = true;
+        self.shared.not_full.notify_waiters();
+    }
+
+    /// Returns the saturation metrics for each stream which has sent a message.
+    #[must_use]
+    pub fn metrics(&self) -> HashMap<StreamType, StreamMetrics> {
+        self.shared.metrics()
+    }
+
+    /// Returns a monitor for the pipeline's metrics which outlives the receiver.
+    #[must_use]
+    pub fn monitor(&self) -> PipelineMonitor<T> {
+        PipelineMonitor {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for PipelineReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.state().rx_closed = true;
+        self.shared.not_full.notify_waiters();
+    }
+}
+
+/// Provides the metrics of a pipeline without sending or receiving messages.
+pub struct PipelineMonitor<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> PipelineMonitor<T> {
+    /// Returns the saturation metrics for each stream which has sent a message.
+    #[must_use]
+    pub fn metrics(&self) -> HashMap<StreamType, StreamMetrics> {
+        self.shared.metrics()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use nautilus_common::config::{parse_config, ConfigFormat};
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::data::{
+        quote::QuoteTick,
+        stubs::{quote_ethusdt_binance, stub_trade_ethusdt_buyer},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn config(
+        stream_type: StreamType,
+        capacity: usize,
+        policy: BackpressurePolicy,
+    ) -> PipelineConfig {
+        PipelineConfig {
+            default: StreamConfig::default(),
+            streams: HashMap::from([(stream_type, StreamConfig { capacity, policy })]),
+        }
+    }
+
+    fn quote(instrument_id: &str, ts_event: u64) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            ts_event: UnixNanos::from(ts_event),
+            ..quote_ethusdt_binance()
+        })
+    }
+
+    fn drain(rx: &mut PipelineReceiver<Data>) -> Vec<(InstrumentId, u64)> {
+        std::iter::from_fn(|| rx.try_recv())
+            .map(|data| (data.instrument_id(), data.ts_event().as_u64()))
+            .collect()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_drop_oldest_at_capacity() {
+        let (tx, mut rx) = pipeline(config(
+            StreamType::Quotes,
+            2,
+            BackpressurePolicy::DropOldest,
+        ));
+        let trade = Data::Trade(stub_trade_ethusdt_buyer());
+
+        tx.send(quote("ETHUSDT.BINANCE", 1)).await.unwrap();
+        tx.send(trade.clone()).await.unwrap();
+        tx.send(quote("ETHUSDT.BINANCE", 2)).await.unwrap();
+        tx.send(quote("ETHUSDT.BINANCE", 3)).await.unwrap();
+
+        let metrics = rx.metrics()[&StreamType::Quotes];
+        assert_eq!(
+            (
+                metrics.sent,
+                metrics.dropped,
+                metrics.len,
+                metrics.high_water_mark
+            ),
+            (3, 1, 2, 2)
+        );
+        assert!((metrics.saturation() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(rx.recv().await, Some(trade));
+        assert_eq!(
+            drain(&mut rx),
+            [
+                (InstrumentId::from("ETHUSDT.BINANCE"), 2),
+                (InstrumentId::from("ETHUSDT.BINANCE"), 3)
+            ]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_conflate_at_capacity() {
+        let (tx, mut rx) = pipeline(config(StreamType::Quotes, 2, BackpressurePolicy::Conflate));
+
+        tx.send(quote("ETHUSDT.BINANCE", 1)).await.unwrap();
+        tx.send(quote("BTCUSDT.BINANCE", 2)).await.unwrap();
+        tx.send(quote("ETHUSDT.BINANCE", 3)).await.unwrap();
+        tx.send(quote("SOLUSDT.BINANCE", 4)).await.unwrap();
+
+        let metrics = rx.metrics()[&StreamType::Quotes];
+        assert_eq!((metrics.conflated, metrics.dropped), (1, 1));
+        assert_eq!(
+            drain(&mut rx),
+            [
+                (InstrumentId::from("ETHUSDT.BINANCE"), 3),
+                (InstrumentId::from("SOLUSDT.BINANCE"), 4)
+            ]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_block_waits_for_consumer() {
+        let (tx, mut rx) = pipeline(config(StreamType::Quotes, 1, BackpressurePolicy::Block));
+
+        tx.send(quote("ETHUSDT.BINANCE", 1)).await.unwrap();
+        let blocked = tokio::time::timeout(
+            Duration::from_millis(10),
+            tx.send(quote("ETHUSDT.BINANCE", 2)),
+        )
+        .await;
+        assert!(blocked.is_err());
+
+        let sender = tokio::spawn(async move {
+            tx.send(quote("ETHUSDT.BINANCE", 3)).await.unwrap();
+        });
+        assert_eq!(rx.recv().await.unwrap().ts_event().as_u64(), 1);
+        sender.await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().ts_event().as_u64(), 3);
+        assert_eq!(rx.recv().await, None);
+        assert!(rx.metrics()[&StreamType::Quotes].blocked >= 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_send_fails_when_receiver_closed() {
+        let (tx, mut rx) = pipeline::<Data>(PipelineConfig::default());
+
+        rx.close();
+
+        assert!(tx.is_closed());
+        assert!(tx.send(quote("ETHUSDT.BINANCE", 1)).await.is_err());
+    }
+
+    #[rstest]
+    fn test_parse_config() {
+        let config: PipelineConfig = parse_config(
+            r#"
+default = { capacity = 1000 }
+
+[streams.quotes]
+capacity = 10
+policy = "CONFLATE"
+"#,
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.stream(StreamType::Quotes),
+            StreamConfig {
+                capacity: 10,
+                policy: BackpressurePolicy::Conflate
+            }
+        );
+        assert_eq!(config.stream(StreamType::Trades).capacity, 1000);
+    }
+
+    #[rstest]
+    #[case(
+        StreamType::Quotes,
+        0,
+        BackpressurePolicy::Block,
+        "Invalid setting 'pipeline.quotes.capacity': must be positive"
+    )]
+    #[case(StreamType::OrderBookDeltas, 10, BackpressurePolicy::Conflate, "Invalid setting 'pipeline.order_book_deltas.policy': CONFLATE not supported for incremental messages")]
+    #[case(StreamType::OrderBookDeltas, 10, BackpressurePolicy::DropOldest, "Invalid setting 'pipeline.order_book_deltas.policy': DROP_OLDEST not supported for order book deltas")]
+    fn test_validation_errors(
+        #[case] stream_type: StreamType,
+        #[case] capacity: usize,
+        #[case] policy: BackpressurePolicy,
+        #[case] expected: &str,
+    ) {
+        assert!(PipelineConfig::default().validate().is_ok());
+        assert_eq!(
+            config(stream_type, capacity, policy)
+                .validate()
+                .unwrap_err()
+                .to_string(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,137 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A small pool of reusable buffers for decoded data objects.
+
+/// The default initial capacity for buffers created by a [`DataPool`].
+pub const DEFAULT_POOL_BUFFER_CAPACITY: usize = 65_536;
+
+/// The default maximum number of idle buffers retained by a [`DataPool`].
+pub const DEFAULT_POOL_MAX_BUFFERS: usize = 4;
+
+/// Provides a pool of reusable buffers for decoded data objects such as
+/// `OrderBookDelta` and `TradeTick`.
+///
+/// These data types are `Copy` and stored inline, so the heap allocations on decode paths
+/// are the buffers which batches are collected into. Acquiring a buffer from the pool reuses
+/// a previously released allocation where one is available, which avoids repeated
+/// allocation and regrowth when replaying many batches.
+#[derive(Debug)]
+pub struct DataPool<T> {
+    buffers: Vec<Vec<T>>,
+    capacity: usize,
+    max_buffers: usize,
+}
+
+impl<T> DataPool<T> {
+    /// Creates a new [`DataPool`] instance.
+    ///
+    /// New buffers are created with the given initial `capacity`, and at most `max_buffers`
+    /// idle buffers are retained for reuse.
+    #[must_use]
+    pub fn new(capacity: usize, max_buffers: usize) -> Self {
+        Self {
+            buffers: Vec::with_capacity(max_buffers),
+            capacity,
+            max_buffers,
+        }
+    }
+
+    /// Returns an empty buffer, reusing a released allocation if one is available.
+    pub fn acquire(&mut self) -> Vec<T> {
+        self.buffers
+            .pop()
+            .unwrap_or_else(|| Vec::with_capacity(self.capacity))
+    }
+
+    /// Returns the `buffer` to the pool for reuse.
+    ///
+    /// The buffer is cleared, retaining its allocation. If the pool already holds the
+    /// maximum number of idle buffers then the buffer is dropped.
+    pub fn release(&mut self, mut buffer: Vec<T>) {
+        if self.buffers.len() < self.max_buffers {
+            buffer.clear();
+            self.buffers.push(buffer);
+        }
+    }
+
+    /// Returns the number of idle buffers available for reuse.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Returns the initial capacity of newly created buffers.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<T> Default for DataPool<T> {
+    /// Creates a new default [`DataPool`] instance.
+    fn default() -> Self {
+        Self::new(DEFAULT_POOL_BUFFER_CAPACITY, DEFAULT_POOL_MAX_BUFFERS)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_acquire_from_empty_pool() {
+        let mut pool: DataPool<u64> = DataPool::new(16, 2);
+
+        let buffer = pool.acquire();
+
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.capacity(), 16);
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[rstest]
+    fn test_release_and_reacquire_reuses_allocation() {
+        let mut pool: DataPool<u64> = DataPool::new(16, 2);
+        let mut buffer = pool.acquire();
+        buffer.extend(0..100);
+        let ptr = buffer.as_ptr();
+        let capacity = buffer.capacity();
+
+        pool.release(buffer);
+        let buffer = pool.acquire();
+
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer.capacity(), capacity);
+    }
+
+    #[rstest]
+    fn test_release_beyond_max_buffers_drops_buffer() {
+        let mut pool: DataPool<u64> = DataPool::new(16, 1);
+        let buffer1 = pool.acquire();
+        let buffer2 = pool.acquire();
+
+        pool.release(buffer1);
+        pool.release(buffer2);
+
+        assert_eq!(pool.available(), 1);
+    }
+}
@@ -13,9 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_common::config::{check_setting, ValidateConfig};
 use serde::{Deserialize, Serialize};
 
-use super::machine::ReplayNormalizedRequestOptions;
+use super::machine::{ReplayNormalizedRequestOptions, StreamNormalizedRequestOptions};
 
 /// Provides a configuration for a Tarid Machine -> Nautilus data -> Parquet replay run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,3 +28,31 @@ pub struct TardisReplayConfig {
     /// The Tardis Machine replay options.
     pub options: Vec<ReplayNormalizedRequestOptions>,
 }
+
+/// Provides a configuration for a Tardis Machine live data client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisDataClientConfig {
+    /// The Tardis Machine websocket url (from the `TARDIS_MACHINE_WS_URL` env var if None).
+    #[serde(default)]
+    pub tardis_ws_url: Option<String>,
+    /// The Tardis Machine stream options.
+    pub options: Vec<StreamNormalizedRequestOptions>,
+}
+
+impl ValidateConfig for TardisDataClientConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            !self.options.is_empty(),
+            "tardis.options",
+            "at least one stream is required",
+        )?;
+        for options in &self.options {
+            check_setting(
+                !options.data_types.is_empty(),
+                "tardis.options.data_types",
+                format!("no data types for {}", options.exchange),
+            )?;
+        }
+        Ok(())
+    }
+}
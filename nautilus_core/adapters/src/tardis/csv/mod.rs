@@ -32,6 +32,7 @@ use nautilus_model::{
 };
 
 mod record;
+pub mod stream;
 
 use super::{
     csv::record::{
@@ -47,12 +48,12 @@ use super::{
 /// Creates a new CSV reader which can handle gzip compression.
 pub fn create_csv_reader<P: AsRef<Path>>(
     filepath: P,
-) -> anyhow::Result<Reader<Box<dyn std::io::Read>>> {
+) -> anyhow::Result<Reader<Box<dyn std::io::Read + Send>>> {
     let file = File::open(filepath.as_ref())?;
     let buf_reader = BufReader::new(file);
 
     // Determine if the file is gzipped by its extension
-    let reader: Box<dyn std::io::Read> =
+    let reader: Box<dyn std::io::Read + Send> =
         if filepath.as_ref().extension().unwrap_or_default() == "gz" {
             Box::new(GzDecoder::new(buf_reader)) // Decompress the gzipped file
         } else {
@@ -70,49 +71,47 @@ pub fn load_deltas<P: AsRef<Path>>(
     instrument_id: Option<InstrumentId>,
     limit: Option<usize>,
 ) -> Result<Vec<OrderBookDelta>, Box<dyn Error>> {
-    let mut csv_reader = create_csv_reader(filepath)?;
     let mut deltas: Vec<OrderBookDelta> = Vec::new();
+    load_deltas_into(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        limit,
+        &mut deltas,
+    )?;
+    Ok(deltas)
+}
+
+/// Load [`OrderBookDelta`]s from a Tardis format CSV at the given `filepath` into the given
+/// `deltas` buffer, which is cleared first while retaining its allocation (e.g. a buffer acquired
+/// from a [`DataPool`](crate::pool::DataPool)).
+pub fn load_deltas_into<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+    deltas: &mut Vec<OrderBookDelta>,
+) -> Result<(), Box<dyn Error>> {
+    let mut csv_reader = create_csv_reader(filepath)?;
+    deltas.clear();
     let mut last_ts_event = UnixNanos::default();
 
     let mut raw_record = StringRecord::new();
     while csv_reader.read_record(&mut raw_record)? {
         let record: TardisBookUpdateRecord = raw_record.deserialize(None)?;
-
-        let instrument_id = match &instrument_id {
-            Some(id) => *id,
-            None => parse_instrument_id(&record.exchange, &record.symbol),
-        };
-        let side = parse_order_side(&record.side);
-        let price = Price::new(record.price, price_precision);
-        let size = Quantity::new(record.amount, size_precision);
-        let order_id = 0; // Not applicable for L2 data
-        let order = BookOrder::new(side, price, size, order_id);
-
-        let action = parse_book_action(record.is_snapshot, record.amount);
-        let flags = 0; // Flags always zero until timestamp changes
-        let sequence = 0; // Sequence not available
-        let ts_event = parse_timestamp(record.timestamp);
-        let ts_init = parse_timestamp(record.local_timestamp);
+        let delta = parse_delta_record(&record, price_precision, size_precision, instrument_id);
 
         // Check if timestamp is different from last timestamp
-        if last_ts_event != ts_event {
+        if last_ts_event != delta.ts_event {
             if let Some(last_delta) = deltas.last_mut() {
                 // Set previous delta flags as F_LAST
                 last_delta.flags = RecordFlag::F_LAST.value();
             }
         }
 
-        last_ts_event = ts_event;
-
-        let delta = OrderBookDelta::new(
-            instrument_id,
-            action,
-            order,
-            flags,
-            sequence,
-            ts_event,
-            ts_init,
-        );
+        last_ts_event = delta.ts_event;
 
         deltas.push(delta);
 
@@ -128,7 +127,40 @@ pub fn load_deltas<P: AsRef<Path>>(
         last_delta.flags = RecordFlag::F_LAST.value();
     }
 
-    Ok(deltas)
+    Ok(())
+}
+
+fn parse_delta_record(
+    record: &TardisBookUpdateRecord,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+) -> OrderBookDelta {
+    let instrument_id = match instrument_id {
+        Some(id) => id,
+        None => parse_instrument_id(&record.exchange, &record.symbol),
+    };
+    let side = parse_order_side(&record.side);
+    let price = Price::new(record.price, price_precision);
+    let size = Quantity::new(record.amount, size_precision);
+    let order_id = 0; // Not applicable for L2 data
+    let order = BookOrder::new(side, price, size, order_id);
+
+    let action = parse_book_action(record.is_snapshot, record.amount);
+    let flags = 0; // Flags always zero until timestamp changes
+    let sequence = 0; // Sequence not available
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    OrderBookDelta::new(
+        instrument_id,
+        action,
+        order,
+        flags,
+        sequence,
+        ts_event,
+        ts_init,
+    )
 }
 
 fn create_book_order(
@@ -397,27 +429,7 @@ pub fn load_quote_ticks<P: AsRef<Path>>(
     let mut raw_record = StringRecord::new();
     while csv_reader.read_record(&mut raw_record)? {
         let record: TardisQuoteRecord = raw_record.deserialize(None)?;
-
-        let instrument_id = match &instrument_id {
-            Some(id) => *id,
-            None => parse_instrument_id(&record.exchange, &record.symbol),
-        };
-        let bid_price = Price::new(record.bid_price.unwrap_or(0.0), price_precision);
-        let bid_size = Quantity::new(record.bid_amount.unwrap_or(0.0), size_precision);
-        let ask_price = Price::new(record.ask_price.unwrap_or(0.0), price_precision);
-        let ask_size = Quantity::new(record.ask_amount.unwrap_or(0.0), size_precision);
-        let ts_event = parse_timestamp(record.timestamp);
-        let ts_init = parse_timestamp(record.local_timestamp);
-
-        let quote = QuoteTick::new(
-            instrument_id,
-            bid_price,
-            ask_price,
-            bid_size,
-            ask_size,
-            ts_event,
-            ts_init,
-        );
+        let quote = parse_quote_record(&record, price_precision, size_precision, instrument_id);
 
         quotes.push(quote);
 
@@ -431,6 +443,34 @@ pub fn load_quote_ticks<P: AsRef<Path>>(
     Ok(quotes)
 }
 
+fn parse_quote_record(
+    record: &TardisQuoteRecord,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+) -> QuoteTick {
+    let instrument_id = match instrument_id {
+        Some(id) => id,
+        None => parse_instrument_id(&record.exchange, &record.symbol),
+    };
+    let bid_price = Price::new(record.bid_price.unwrap_or(0.0), price_precision);
+    let bid_size = Quantity::new(record.bid_amount.unwrap_or(0.0), size_precision);
+    let ask_price = Price::new(record.ask_price.unwrap_or(0.0), price_precision);
+    let ask_size = Quantity::new(record.ask_amount.unwrap_or(0.0), size_precision);
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    QuoteTick::new(
+        instrument_id,
+        bid_price,
+        ask_price,
+        bid_size,
+        ask_size,
+        ts_event,
+        ts_init,
+    )
+}
+
 /// Load [`TradeTick`]s from a Tardis format CSV at the given `filepath`.
 pub fn load_trade_ticks<P: AsRef<Path>>(
     filepath: P,
@@ -439,33 +479,36 @@ pub fn load_trade_ticks<P: AsRef<Path>>(
     instrument_id: Option<InstrumentId>,
     limit: Option<usize>,
 ) -> Result<Vec<TradeTick>, Box<dyn Error>> {
-    let mut csv_reader = create_csv_reader(filepath)?;
     let mut trades = Vec::new();
+    load_trade_ticks_into(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        limit,
+        &mut trades,
+    )?;
+    Ok(trades)
+}
+
+/// Load [`TradeTick`]s from a Tardis format CSV at the given `filepath` into the given
+/// `trades` buffer, which is cleared first while retaining its allocation (e.g. a buffer acquired
+/// from a [`DataPool`](crate::pool::DataPool)).
+pub fn load_trade_ticks_into<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+    trades: &mut Vec<TradeTick>,
+) -> Result<(), Box<dyn Error>> {
+    let mut csv_reader = create_csv_reader(filepath)?;
+    trades.clear();
 
     let mut raw_record = StringRecord::new();
     while csv_reader.read_record(&mut raw_record)? {
         let record: TardisTradeRecord = raw_record.deserialize(None)?;
-
-        let instrument_id = match &instrument_id {
-            Some(id) => *id,
-            None => parse_instrument_id(&record.exchange, &record.symbol),
-        };
-        let price = Price::new(record.price, price_precision);
-        let size = Quantity::new(record.amount, size_precision);
-        let aggressor_side = parse_aggressor_side(&record.side);
-        let trade_id = TradeId::new(&record.id);
-        let ts_event = parse_timestamp(record.timestamp);
-        let ts_init = parse_timestamp(record.local_timestamp);
-
-        let trade = TradeTick::new(
-            instrument_id,
-            price,
-            size,
-            aggressor_side,
-            trade_id,
-            ts_event,
-            ts_init,
-        );
+        let trade = parse_trade_record(&record, price_precision, size_precision, instrument_id);
 
         trades.push(trade);
 
@@ -476,7 +519,35 @@ pub fn load_trade_ticks<P: AsRef<Path>>(
         }
     }
 
-    Ok(trades)
+    Ok(())
+}
+
+fn parse_trade_record(
+    record: &TardisTradeRecord,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+) -> TradeTick {
+    let instrument_id = match instrument_id {
+        Some(id) => id,
+        None => parse_instrument_id(&record.exchange, &record.symbol),
+    };
+    let price = Price::new(record.price, price_precision);
+    let size = Quantity::new(record.amount, size_precision);
+    let aggressor_side = parse_aggressor_side(&record.side);
+    let trade_id = TradeId::new(&record.id);
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    TradeTick::new(
+        instrument_id,
+        price,
+        size,
+        aggressor_side,
+        trade_id,
+        ts_event,
+        ts_init,
+    )
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Lazy streaming of Tardis format CSV files in chunks, for files too large to load at once.
+
+use std::{io::Read, path::Path};
+
+use csv::{Reader, StringRecord};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{delta::OrderBookDelta, quote::QuoteTick, trade::TradeTick},
+    enums::RecordFlag,
+    identifiers::InstrumentId,
+};
+
+use super::{
+    create_csv_reader, parse_delta_record, parse_quote_record, parse_trade_record,
+    record::{TardisBookUpdateRecord, TardisQuoteRecord, TardisTradeRecord},
+};
+
+type ParseFn<T> = Box<dyn Fn(&StringRecord) -> anyhow::Result<T> + Send>;
+
+/// A data type which can be streamed from a Tardis format CSV.
+pub trait TardisStreamItem: Sized {
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    fn ts_event(&self) -> UnixNanos;
+
+    /// Finalizes a chunk before it is returned, which always ends on a change of `ts_event`.
+    fn finish_chunk(_chunk: &mut [Self]) {}
+}
+
+impl TardisStreamItem for OrderBookDelta {
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn finish_chunk(chunk: &mut [Self]) {
+        // Set F_LAST flag for the final delta of each event
+        let mut next_ts_event = None;
+        for delta in chunk.iter_mut().rev() {
+            if next_ts_event != Some(delta.ts_event) {
+                delta.flags = RecordFlag::F_LAST.value();
+            }
+            next_ts_event = Some(delta.ts_event);
+        }
+    }
+}
+
+impl TardisStreamItem for QuoteTick {
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+}
+
+impl TardisStreamItem for TradeTick {
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+}
+
+/// Provides lazy iteration over a Tardis format CSV in chunks of parsed data.
+///
+/// Each chunk holds at least `chunk_size` items (other than the last), extended as needed so
+/// that items sharing a `ts_event` are never split across chunks.
+pub struct TardisCsvStream<T> {
+    reader: Reader<Box<dyn Read + Send>>,
+    raw_record: StringRecord,
+    chunk_size: usize,
+    pending: Option<T>,
+    parse: ParseFn<T>,
+}
+
+impl<T> TardisCsvStream<T> {
+    fn new<P: AsRef<Path>>(
+        filepath: P,
+        chunk_size: usize,
+        parse: ParseFn<T>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            reader: create_csv_reader(filepath)?,
+            raw_record: StringRecord::new(),
+            chunk_size,
+            pending: None,
+            parse,
+        })
+    }
+}
+
+impl<T: TardisStreamItem> Iterator for TardisCsvStream<T> {
+    type Item = anyhow::Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        chunk.extend(self.pending.take());
+
+        loop {
+            match self.reader.read_record(&mut self.raw_record) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => return Some(Err(e.into())),
+            }
+            let item = match (self.parse)(&self.raw_record) {
+                Ok(item) => item,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if chunk.len() >= self.chunk_size
+                && chunk
+                    .last()
+                    .is_some_and(|last: &T| last.ts_event() != item.ts_event())
+            {
+                self.pending = Some(item);
+                break;
+            }
+            chunk.push(item);
+        }
+
+        if chunk.is_empty() {
+            return None;
+        }
+
+        T::finish_chunk(&mut chunk);
+        Some(Ok(chunk))
+    }
+}
+
+/// Streams [`OrderBookDelta`]s from a Tardis format CSV at the given `filepath`.
+///
+/// # Errors
+///
+/// This function returns an error if the file cannot be opened.
+pub fn stream_deltas<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    chunk_size: usize,
+) -> anyhow::Result<TardisCsvStream<OrderBookDelta>> {
+    let parse = move |raw_record: &StringRecord| {
+        let record: TardisBookUpdateRecord = raw_record.deserialize(None)?;
+        Ok(parse_delta_record(
+            &record,
+            price_precision,
+            size_precision,
+            instrument_id,
+        ))
+    };
+    TardisCsvStream::new(filepath, chunk_size, Box::new(parse))
+}
+
+/// Streams [`QuoteTick`]s from a Tardis format CSV at the given `filepath`.
+///
+/// # Errors
+///
+/// This function returns an error if the file cannot be opened.
+pub fn stream_quote_ticks<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    chunk_size: usize,
+) -> anyhow::Result<TardisCsvStream<QuoteTick>> {
+    let parse = move |raw_record: &StringRecord| {
+        let record: TardisQuoteRecord = raw_record.deserialize(None)?;
+        Ok(parse_quote_record(
+            &record,
+            price_precision,
+            size_precision,
+            instrument_id,
+        ))
+    };
+    TardisCsvStream::new(filepath, chunk_size, Box::new(parse))
+}
+
+/// Streams [`TradeTick`]s from a Tardis format CSV at the given `filepath`.
+///
+/// # Errors
+///
+/// This function returns an error if the file cannot be opened.
+pub fn stream_trade_ticks<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    chunk_size: usize,
+) -> anyhow::Result<TardisCsvStream<TradeTick>> {
+    let parse = move |raw_record: &StringRecord| {
+        let record: TardisTradeRecord = raw_record.deserialize(None)?;
+        Ok(parse_trade_record(
+            &record,
+            price_precision,
+            size_precision,
+            instrument_id,
+        ))
+    };
+    TardisCsvStream::new(filepath, chunk_size, Box::new(parse))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const DELTAS_CSV: &str = "\
+exchange,symbol,timestamp,local_timestamp,is_snapshot,side,price,amount
+deribit,BTC-PERPETUAL,1000,1100,true,ask,6421.5,18640
+deribit,BTC-PERPETUAL,1000,1100,true,bid,6421.0,1000
+deribit,BTC-PERPETUAL,2000,2100,false,ask,6422.0,500
+deribit,BTC-PERPETUAL,3000,3100,false,bid,6420.5,200
+deribit,BTC-PERPETUAL,3000,3100,false,bid,6420.0,0
+";
+
+    fn write_csv(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}-{}.csv", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[rstest]
+    fn test_stream_deltas_matches_load_deltas(#[values(0, 1, 2, 10)] chunk_size: usize) {
+        let path = write_csv(&format!("stream_deltas_{chunk_size}"), DELTAS_CSV);
+        let expected = super::super::load_deltas(&path, 1, 0, None, None).unwrap();
+
+        let chunks: Vec<Vec<OrderBookDelta>> = stream_deltas(&path, 1, 0, None, chunk_size)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        std::fs::remove_file(&path).unwrap();
+
+        for chunk in &chunks {
+            assert_eq!(chunk.last().unwrap().flags, RecordFlag::F_LAST.value());
+        }
+        assert_eq!(chunks.concat(), expected);
+    }
+
+    #[rstest]
+    fn test_stream_deltas_does_not_split_events() {
+        let path = write_csv("stream_deltas_events", DELTAS_CSV);
+
+        let chunk_lens: Vec<usize> = stream_deltas(&path, 1, 0, None, 1)
+            .unwrap()
+            .map(|chunk| chunk.unwrap().len())
+            .collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(chunk_lens, vec![2, 1, 2]);
+    }
+
+    #[rstest]
+    fn test_stream_deltas_with_invalid_record_errors() {
+        let path = write_csv(
+            "stream_deltas_invalid",
+            "exchange,symbol,timestamp,local_timestamp,is_snapshot,side,price,amount\n\
+             deribit,BTC-PERPETUAL,not_a_timestamp,1100,true,ask,6421.5,18640\n",
+        );
+
+        let result = stream_deltas(&path, 1, 0, None, 10)
+            .unwrap()
+            .next()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
@@ -13,16 +13,17 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{env, time::Duration};
+use std::{env, path::Path, time::Duration};
 
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
+use nautilus_common::credentials::Secret;
 use nautilus_core::{nanos::UnixNanos, version::USER_AGENT};
 use nautilus_model::instruments::any::InstrumentAny;
 
 use super::{
     parse::parse_instrument_any,
     types::{InstrumentInfo, Response},
-    TARDIS_BASE_URL,
+    TARDIS_BASE_URL, TARDIS_DATASETS_URL,
 };
 use crate::tardis::enums::Exchange;
 
@@ -40,6 +41,9 @@ pub enum Error {
     /// An error when deserializing the response from the server.
     #[error("Error deserializing message: {0}")]
     Deserialization(#[from] serde_json::Error),
+    /// An error when writing a downloaded file.
+    #[error("Error writing file: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// A Tardis HTTP API client.
@@ -51,7 +55,7 @@ pub enum Error {
 #[derive(Debug, Clone)]
 pub struct TardisHttpClient {
     base_url: String,
-    api_key: String,
+    api_key: Secret,
     client: reqwest::Client,
 }
 
@@ -63,8 +67,8 @@ impl TardisHttpClient {
         timeout_secs: Option<u64>,
     ) -> anyhow::Result<Self> {
         let api_key = match api_key {
-            Some(key) => key.to_string(),
-            None => env::var("TARDIS_API_KEY").map_err(|_| {
+            Some(key) => Secret::new(key),
+            None => env::var("TARDIS_API_KEY").map(Secret::from).map_err(|_| {
                 anyhow::anyhow!(
                     "API key must be provided or set in the 'TARDIS_API_KEY' environment variable"
                 )
@@ -97,7 +101,7 @@ impl TardisHttpClient {
         Ok(self
             .client
             .get(format!("{}/instruments/{exchange}", &self.base_url))
-            .bearer_auth(&self.api_key)
+            .bearer_auth(self.api_key.expose())
             .send()
             .await?
             .json::<Response<Vec<InstrumentInfo>>>()
@@ -119,7 +123,7 @@ impl TardisHttpClient {
                 "{}/instruments/{exchange}/{symbol}",
                 &self.base_url
             ))
-            .bearer_auth(&self.api_key)
+            .bearer_auth(self.api_key.expose())
             .send()
             .await?
             .json::<Response<InstrumentInfo>>()
@@ -164,4 +168,38 @@ impl TardisHttpClient {
 
         Ok(parse_instrument_any(info, ts_init))
     }
+
+    /// Downloads the gzipped CSV dataset of `data_type` for an `exchange`, `symbol` and `date`
+    /// to the given `filepath`, returning the number of bytes written.
+    /// See <https://docs.tardis.dev/downloadable-csv-files>
+    pub async fn download_dataset(
+        &self,
+        exchange: Exchange,
+        data_type: &str,
+        date: NaiveDate,
+        symbol: &str,
+        filepath: &Path,
+    ) -> Result<u64> {
+        let url = format!(
+            "{TARDIS_DATASETS_URL}/{exchange}/{data_type}/{}/{symbol}.csv.gz",
+            date.format("%Y/%m/%d")
+        );
+        tracing::debug!("Requesting dataset {url}");
+
+        let bytes = self
+            .client
+            .get(url)
+            .bearer_auth(self.api_key.expose())
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        if let Some(parent) = filepath.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(filepath, &bytes).await?;
+        Ok(bytes.len() as u64)
+    }
 }
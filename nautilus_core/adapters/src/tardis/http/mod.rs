@@ -20,3 +20,4 @@ pub mod types;
 pub use crate::tardis::http::client::TardisHttpClient;
 
 pub const TARDIS_BASE_URL: &str = "https://api.tardis.dev/v1";
+pub const TARDIS_DATASETS_URL: &str = "https://datasets.tardis.dev/v1";
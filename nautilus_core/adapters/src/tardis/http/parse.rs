@@ -163,6 +163,8 @@ fn parse_option_instrument(info: InstrumentInfo, ts_init: UnixNanos) -> Instrume
         instrument_id.symbol,
         AssetClass::Cryptocurrency,
         Some(Ustr::from(instrument_id.venue.as_str())),
+        None,
+        None,
         Ustr::from(info.base_currency.to_string().to_uppercase().as_str()),
         parse_option_kind(
             info.option_type
@@ -23,7 +23,8 @@ use std::{
 };
 
 use futures_util::{pin_mut, Stream, StreamExt};
-use nautilus_model::{data::Data, identifiers::InstrumentId};
+use nautilus_common::capabilities::ClientCapabilities;
+use nautilus_model::{data::Data, enums::BookType, identifiers::InstrumentId};
 
 use super::{
     message::WsMessage, replay_normalized, stream_normalized, Error, InstrumentMiniInfo,
@@ -64,6 +65,22 @@ impl TardisMachineClient {
         })
     }
 
+    /// Returns the capabilities of the client.
+    ///
+    /// Order books are streamed as L2 deltas or snapshots of any depth.
+    #[must_use]
+    pub fn capabilities(&self) -> ClientCapabilities {
+        ClientCapabilities::unrestricted()
+            .with_data_types([
+                "OrderBookDelta",
+                "OrderBookDeltas",
+                "QuoteTick",
+                "TradeTick",
+                "Bar",
+            ])
+            .with_book_types([BookType::L2_MBP])
+    }
+
     pub fn add_instrument_info(&mut self, info: InstrumentMiniInfo) {
         self.instruments.insert(info.instrument_id, Arc::new(info));
     }
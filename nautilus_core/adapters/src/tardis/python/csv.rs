@@ -21,14 +21,28 @@ use nautilus_model::{
         delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick, Data,
     },
     identifiers::InstrumentId,
+    python::data::stream::DataStream,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
 use crate::tardis::csv::{
     load_deltas, load_depth10_from_snapshot25, load_depth10_from_snapshot5, load_quote_ticks,
     load_trade_ticks,
+    stream::{stream_deltas, stream_quote_ticks, stream_trade_ticks, TardisCsvStream},
 };
 
+/// The default number of records decoded per chunk when streaming.
+const DEFAULT_STREAM_CHUNK_SIZE: usize = 100_000;
+
+fn to_data_stream<T>(stream: TardisCsvStream<T>) -> DataStream
+where
+    T: Into<Data> + Send + 'static,
+    TardisCsvStream<T>: Iterator<Item = anyhow::Result<Vec<T>>>,
+{
+    let chunks = stream.map(|chunk| chunk.map(|chunk| chunk.into_iter().map(Into::into).collect()));
+    DataStream::new(Box::new(chunks))
+}
+
 #[pyfunction(name = "load_tardis_deltas")]
 #[pyo3(signature = (filepath, price_precision, size_precision, instrument_id=None, limit=None))]
 pub fn py_load_tardis_deltas(
@@ -248,3 +262,72 @@ pub fn py_load_tardis_trades_as_pycapsule(
     let capsule = PyCapsule::new_bound::<CVec>(py, cvec, None)?;
     Ok(capsule.into_py(py))
 }
+
+/// Streams [`OrderBookDelta`] objects lazily from a Tardis format CSV at the given `filepath`.
+///
+/// Records are parsed `chunk_size` at a time with the GIL released.
+#[pyfunction(name = "stream_tardis_deltas")]
+#[pyo3(signature = (filepath, price_precision, size_precision, instrument_id=None, chunk_size=DEFAULT_STREAM_CHUNK_SIZE))]
+pub fn py_stream_tardis_deltas(
+    filepath: PathBuf,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    chunk_size: usize,
+) -> PyResult<DataStream> {
+    let stream = stream_deltas(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        chunk_size,
+    )
+    .map_err(to_pyvalue_err)?;
+    Ok(to_data_stream(stream))
+}
+
+/// Streams [`QuoteTick`] objects lazily from a Tardis format CSV at the given `filepath`.
+///
+/// Records are parsed `chunk_size` at a time with the GIL released.
+#[pyfunction(name = "stream_tardis_quotes")]
+#[pyo3(signature = (filepath, price_precision, size_precision, instrument_id=None, chunk_size=DEFAULT_STREAM_CHUNK_SIZE))]
+pub fn py_stream_tardis_quotes(
+    filepath: PathBuf,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    chunk_size: usize,
+) -> PyResult<DataStream> {
+    let stream = stream_quote_ticks(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        chunk_size,
+    )
+    .map_err(to_pyvalue_err)?;
+    Ok(to_data_stream(stream))
+}
+
+/// Streams [`TradeTick`] objects lazily from a Tardis format CSV at the given `filepath`.
+///
+/// Records are parsed `chunk_size` at a time with the GIL released.
+#[pyfunction(name = "stream_tardis_trades")]
+#[pyo3(signature = (filepath, price_precision, size_precision, instrument_id=None, chunk_size=DEFAULT_STREAM_CHUNK_SIZE))]
+pub fn py_stream_tardis_trades(
+    filepath: PathBuf,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    chunk_size: usize,
+) -> PyResult<DataStream> {
+    let stream = stream_trade_ticks(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        chunk_size,
+    )
+    .map_err(to_pyvalue_err)?;
+    Ok(to_data_stream(stream))
+}
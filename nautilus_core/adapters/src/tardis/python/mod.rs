@@ -65,5 +65,8 @@ pub fn tardis(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         csv::py_load_tardis_trades_as_pycapsule,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(csv::py_stream_tardis_deltas, m)?)?;
+    m.add_function(wrap_pyfunction!(csv::py_stream_tardis_quotes, m)?)?;
+    m.add_function(wrap_pyfunction!(csv::py_stream_tardis_trades, m)?)?;
     Ok(())
 }
@@ -416,7 +416,9 @@ fn batch_and_write_bars(bars: Vec<Bar>, bar_type: &BarType, date: NaiveDate, pat
     }
 }
 
-fn parquet_filepath(typename: &str, instrument_id: &InstrumentId, date: NaiveDate) -> PathBuf {
+/// Returns the catalog relative path for a day of `typename` data for an `instrument_id`.
+#[must_use]
+pub fn parquet_filepath(typename: &str, instrument_id: &InstrumentId, date: NaiveDate) -> PathBuf {
     let typename = typename.to_snake_case();
     let instrument_id_str = instrument_id.to_string().replace('/', "");
     let date_str = date.to_string().replace('-', "");
@@ -426,7 +428,9 @@ fn parquet_filepath(typename: &str, instrument_id: &InstrumentId, date: NaiveDat
         .join(format!("{date_str}.parquet"))
 }
 
-fn parquet_filepath_bars(bar_type: &BarType, date: NaiveDate) -> PathBuf {
+/// Returns the catalog relative path for a day of bars of `bar_type`.
+#[must_use]
+pub fn parquet_filepath_bars(bar_type: &BarType, date: NaiveDate) -> PathBuf {
     let bar_type_str = bar_type.to_string().replace('/', "");
     let date_str = date.to_string().replace('-', "");
     PathBuf::new()
@@ -358,14 +358,16 @@ mod tests {
     use std::sync::Arc;
 
     use nautilus_model::{
-        enums::{AccountType, LiquiditySide, OrderSide},
+        enums::{AccountType, LiquiditySide, NettingPolicy, OrderSide},
         events::{account::state::AccountState, order::OrderFilled},
         identifiers::{
             stubs::{instrument_id_aud_usd_sim, strategy_id_ema_cross, trader_id},
             AccountId, ClientOrderId,
         },
         instruments::any::InstrumentAny,
-        types::{balance::AccountBalance, money::Money, quantity::Quantity},
+        types::{
+            balance::AccountBalance, metadata::OrderMetadata, money::Money, quantity::Quantity,
+        },
     };
 
     use super::*;
@@ -444,6 +446,10 @@ mod tests {
             buy_qty: Quantity::default(),
             sell_qty: Quantity::default(),
             commissions: HashMap::new(),
+            netting_policy: NettingPolicy::default(),
+            open_lots: Vec::new(),
+            closed_lots: Vec::new(),
+            metadata: OrderMetadata::default(),
         }
     }
 
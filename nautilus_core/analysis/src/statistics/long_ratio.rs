@@ -65,12 +65,12 @@ mod tests {
 
     use nautilus_core::nanos::UnixNanos;
     use nautilus_model::{
-        enums::OrderSide,
+        enums::{NettingPolicy, OrderSide},
         identifiers::{
             stubs::{instrument_id_aud_usd_sim, strategy_id_ema_cross, trader_id},
             AccountId, ClientOrderId, PositionId,
         },
-        types::{currency::Currency, quantity::Quantity},
+        types::{currency::Currency, metadata::OrderMetadata, quantity::Quantity},
     };
 
     use super::*;
@@ -110,6 +110,10 @@ mod tests {
             buy_qty: Quantity::default(),
             sell_qty: Quantity::default(),
             commissions: HashMap::new(),
+            netting_policy: NettingPolicy::default(),
+            open_lots: Vec::new(),
+            closed_lots: Vec::new(),
+            metadata: OrderMetadata::default(),
         }
     }
 
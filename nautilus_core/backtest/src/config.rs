@@ -0,0 +1,385 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the configuration for a backtest run, loadable from a file with
+//! [`nautilus_common::config::load_config`].
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_common::{
+    cache::CacheConfig,
+    config::{check_setting, ValidateConfig},
+};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_data::engine::config::DataEngineConfig;
+use nautilus_execution::{
+    engine::config::ExecutionEngineConfig, self_trade::SelfTradePreventionMode,
+};
+use nautilus_model::{
+    enums::{AccountType, BookType, OmsType},
+    identifiers::{InstrumentId, TraderId, Venue},
+    types::{currency::Currency, money::Money},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{matching_engine::config::HaltBehavior, models::bar_execution::BarExecutionModel};
+
+/// The data types which can be loaded for a backtest.
+pub const BACKTEST_DATA_TYPES: [&str; 6] = [
+    "OrderBookDelta",
+    "OrderBookDepth10",
+    "QuoteTick",
+    "TradeTick",
+    "Bar",
+    "InstrumentStatus",
+];
+
+/// Configuration for a simulated exchange venue.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BacktestVenueConfig {
+    /// The venue name.
+    pub name: Venue,
+    /// The order management system type for the venue.
+    pub oms_type: OmsType,
+    /// The account type for the venue.
+    pub account_type: AccountType,
+    /// The starting account balances, e.g. `["1_000_000 USD"]`.
+    pub starting_balances: Vec<Money>,
+    /// The base currency for a single-currency account (multi-currency if None).
+    #[serde(default)]
+    pub base_currency: Option<Currency>,
+    /// The default leverage for margin accounts.
+    #[serde(default = "default_leverage")]
+    pub default_leverage: Decimal,
+    /// The leverages for specific instruments, overriding the default.
+    #[serde(default)]
+    pub leverages: HashMap<InstrumentId, Decimal>,
+    /// The order book type for the matching engines.
+    #[serde(default = "default_book_type")]
+    pub book_type: BookType,
+    /// If bars should be used to move the market.
+    #[serde(default = "default_true")]
+    pub bar_execution: bool,
+    /// The fill model for executing orders against bars.
+    #[serde(default)]
+    pub bar_execution_model: BarExecutionModel,
+    /// The behavior for orders received while an instrument is halted or in an auction.
+    #[serde(default)]
+    pub halt_behavior: HaltBehavior,
+    /// The self-trade prevention mode for the venue (disabled if None).
+    #[serde(default)]
+    pub self_trade_prevention: Option<SelfTradePreventionMode>,
+}
+
+/// Configuration for a stream of data loaded from a Parquet catalog.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BacktestDataConfig {
+    /// The path to the data catalog.
+    pub catalog_path: String,
+    /// The data type name, one of [`BACKTEST_DATA_TYPES`].
+    pub data_type: String,
+    /// The instrument to load data for (all instruments if None).
+    #[serde(default)]
+    pub instrument_id: Option<InstrumentId>,
+    /// UNIX timestamp (nanoseconds) to load data from (inclusive).
+    #[serde(default)]
+    pub start_time: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) to load data until (inclusive).
+    #[serde(default)]
+    pub end_time: Option<UnixNanos>,
+}
+
+/// The data timestamp the simulation clock is advanced on.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ClockTimestamp {
+    /// Advance on processing time, when the data is received (`ts_init`), so that the feed
+    /// latency is simulated.
+    #[default]
+    TsInit,
+    /// Advance on event time, when the data occurred at the venue (`ts_event`), ignoring the
+    /// feed latency.
+    TsEvent,
+}
+
+/// Configuration for the backtest simulation clock.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BacktestClockConfig {
+    /// The data timestamp the simulation clock is advanced on.
+    #[serde(default)]
+    pub advance_on: ClockTimestamp,
+    /// The feed latency (nanoseconds) for data without a recorded latency, i.e. where `ts_init`
+    /// is not after `ts_event`.
+    #[serde(default)]
+    pub default_feed_latency_ns: u64,
+}
+
+/// Configuration for a backtest run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BacktestConfig {
+    /// The trader ID for the run.
+    #[serde(default = "default_trader_id")]
+    pub trader_id: TraderId,
+    /// The simulated venues.
+    pub venues: Vec<BacktestVenueConfig>,
+    /// The data to load.
+    #[serde(default)]
+    pub data: Vec<BacktestDataConfig>,
+    /// The configuration for the cache.
+    #[serde(default)]
+    pub cache: CacheConfig,
+    /// The configuration for the data engine.
+    #[serde(default)]
+    pub data_engine: DataEngineConfig,
+    /// The configuration for the execution engine.
+    #[serde(default)]
+    pub exec_engine: ExecutionEngineConfig,
+    /// The configuration for the simulation clock.
+    #[serde(default)]
+    pub clock: BacktestClockConfig,
+}
+
+fn default_trader_id() -> TraderId {
+    TraderId::from("BACKTESTER-001")
+}
+
+const fn default_leverage() -> Decimal {
+    Decimal::ONE
+}
+
+const fn default_book_type() -> BookType {
+    BookType::L1_MBP
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+impl ValidateConfig for BacktestVenueConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        let setting = |field: &str| format!("venues.{}.{field}", self.name);
+        check_setting(
+            !self.starting_balances.is_empty(),
+            &setting("starting_balances"),
+            "at least one balance is required",
+        )?;
+        if let Some(base_currency) = self.base_currency {
+            check_setting(
+                self.starting_balances
+                    .iter()
+                    .all(|balance| balance.currency == base_currency),
+                &setting("starting_balances"),
+                format!("all balances must be in the base currency {base_currency}"),
+            )?;
+        }
+        check_setting(
+            self.default_leverage >= Decimal::ONE,
+            &setting("default_leverage"),
+            "must be at least 1",
+        )?;
+        for (instrument_id, leverage) in &self.leverages {
+            check_setting(
+                *leverage >= Decimal::ONE,
+                &setting(&format!("leverages.{instrument_id}")),
+                "must be at least 1",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl ValidateConfig for BacktestDataConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            !self.catalog_path.is_empty(),
+            "data.catalog_path",
+            "was empty",
+        )?;
+        check_setting(
+            BACKTEST_DATA_TYPES.contains(&self.data_type.as_str()),
+            "data.data_type",
+            format!(
+                "'{}' is not one of {}",
+                self.data_type,
+                BACKTEST_DATA_TYPES.join(", ")
+            ),
+        )?;
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            check_setting(
+                start <= end,
+                "data.start_time",
+                "must not be after end_time",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl ValidateConfig for BacktestConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            !self.venues.is_empty(),
+            "venues",
+            "at least one venue is required",
+        )?;
+
+        let mut venues = HashSet::new();
+        for venue in &self.venues {
+            check_setting(
+                venues.insert(venue.name),
+                "venues",
+                format!("venue {} is configured more than once", venue.name),
+            )?;
+            venue.validate()?;
+        }
+
+        for data in &self.data {
+            data.validate()?;
+            if let Some(instrument_id) = data.instrument_id {
+                check_setting(
+                    venues.contains(&instrument_id.venue),
+                    "data.instrument_id",
+                    format!("no venue is configured for {instrument_id}"),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::config::{parse_config, ConfigFormat};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::models::bar_execution::IntraBarOrdering;
+
+    const CONFIG: &str = r#"
+trader_id = "TESTER-001"
+
+[[venues]]
+name = "SIM"
+oms_type = "NETTING"
+account_type = "MARGIN"
+starting_balances = ["1_000_000 USD"]
+base_currency = "USD"
+halt_behavior = "PARK"
+self_trade_prevention = "CANCEL_OLDEST"
+
+[venues.bar_execution_model]
+ordering = "ADAPTIVE"
+slippage_ticks = 1
+
+[[data]]
+catalog_path = "/tmp/catalog"
+data_type = "QuoteTick"
+instrument_id = "AUD/USD.SIM"
+
+[exec_engine]
+snapshot_orders = true
+
+[clock]
+advance_on = "TS_EVENT"
+default_feed_latency_ns = 1000
+"#;
+
+    #[rstest]
+    fn test_parse_backtest_config() {
+        let config: BacktestConfig = parse_config(CONFIG, ConfigFormat::Toml).unwrap();
+
+        assert_eq!(config.trader_id, TraderId::from("TESTER-001"));
+        assert_eq!(config.venues.len(), 1);
+        assert_eq!(config.venues[0].oms_type, OmsType::Netting);
+        assert_eq!(
+            config.venues[0].starting_balances[0],
+            Money::from("1000000 USD")
+        );
+        assert_eq!(config.venues[0].default_leverage, Decimal::ONE);
+        assert_eq!(config.venues[0].book_type, BookType::L1_MBP);
+        assert_eq!(config.venues[0].halt_behavior, HaltBehavior::Park);
+        assert_eq!(
+            config.venues[0].self_trade_prevention,
+            Some(SelfTradePreventionMode::CancelOldest)
+        );
+        assert_eq!(
+            config.venues[0].bar_execution_model,
+            BarExecutionModel {
+                ordering: IntraBarOrdering::Adaptive,
+                slippage_ticks: 1,
+                limit_requires_trade_through: false,
+                penetration_ticks: 0,
+            }
+        );
+        assert_eq!(
+            config.data[0].instrument_id,
+            Some(InstrumentId::from("AUD/USD.SIM"))
+        );
+        assert!(config.exec_engine.snapshot_orders);
+        assert!(config.exec_engine.load_cache);
+        assert_eq!(
+            config.clock,
+            BacktestClockConfig {
+                advance_on: ClockTimestamp::TsEvent,
+                default_feed_latency_ns: 1000,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "base_currency = \"USD\"",
+        "base_currency = \"EUR\"",
+        "Invalid setting 'venues.SIM.starting_balances': all balances must be in the base currency EUR"
+    )]
+    #[case(
+        "starting_balances = [\"1_000_000 USD\"]",
+        "starting_balances = []",
+        "Invalid setting 'venues.SIM.starting_balances': at least one balance is required"
+    )]
+    #[case(
+        "data_type = \"QuoteTick\"",
+        "data_type = \"Ticks\"",
+        "Invalid setting 'data.data_type': 'Ticks' is not one of"
+    )]
+    fn test_validation_errors(#[case] from: &str, #[case] to: &str, #[case] expected: &str) {
+        let text = CONFIG.replace(from, to);
+        let err = parse_config::<BacktestConfig>(&text, ConfigFormat::Toml).unwrap_err();
+        assert!(err.to_string().starts_with(expected), "{err}");
+    }
+
+    #[rstest]
+    fn test_validation_error_for_unknown_venue() {
+        let text = CONFIG.replace("AUD/USD.SIM", "AUD/USD.IDEALPRO");
+        let err = parse_config::<BacktestConfig>(&text, ConfigFormat::Toml).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid setting 'data.instrument_id': no venue is configured for AUD/USD.IDEALPRO"
+        );
+    }
+
+    #[rstest]
+    fn test_invalid_identifier_is_a_parse_error() {
+        let text = CONFIG.replace("TESTER-001", "TESTER");
+        let err = parse_config::<BacktestConfig>(&text, ConfigFormat::Toml).unwrap_err();
+        assert!(err.to_string().contains("trader_id"), "{err}");
+    }
+}
@@ -23,6 +23,7 @@ use std::{cell::RefCell, rc::Rc};
 
 use nautilus_common::{
     cache::Cache,
+    capabilities::ClientCapabilities,
     messages::data::{DataRequest, Payload},
     msgbus::MessageBus,
 };
@@ -65,6 +66,9 @@ impl DataClient for BacktestDataClient {
     fn is_disconnected(&self) -> bool {
         false
     }
+    fn capabilities(&self) -> ClientCapabilities {
+        ClientCapabilities::unrestricted()
+    }
 
     // -- COMMAND HANDLERS ---------------------------------------------------------------------------
 
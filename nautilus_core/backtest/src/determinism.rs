@@ -0,0 +1,482 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Determinism checks for backtest runs.
+//!
+//! [`check_determinism`] runs the same backtest twice and compares the hashes of the resulting
+//! event streams (order events, including fills, and position events), reporting the first
+//! divergent event. A divergence points to nondeterminism such as dependence on hash map
+//! iteration order or on uninitialized state.
+//!
+//! Order events are recorded by an [`EventRecorder`] in the order they are dispatched to the
+//! execution engine, in a canonical form which excludes the randomly generated event IDs.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    hash::{DefaultHasher, Hash, Hasher},
+    rc::Rc,
+};
+
+use nautilus_common::{
+    cache::Cache,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{data::Data, events::order::any::OrderEventAny, position::Position};
+use ustr::Ustr;
+
+use crate::engine::BacktestEngine;
+
+/// Represents an event of a backtest run in canonical form.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EventRecord {
+    pub ts_event: UnixNanos,
+    /// The canonical description of the event.
+    pub description: String,
+}
+
+impl EventRecord {
+    /// Creates a new [`EventRecord`] instance.
+    #[must_use]
+    pub const fn new(ts_event: UnixNanos, description: String) -> Self {
+        Self {
+            ts_event,
+            description,
+        }
+    }
+
+    /// Creates an [`EventRecord`] from the given order `event`.
+    #[must_use]
+    pub fn from_order_event(event: &OrderEventAny) -> Self {
+        let ts_event = event.ts_event();
+        let event = event.clone().into_boxed();
+        let description = format!(
+            "{}(client_order_id={}, venue_order_id={:?}, trade_id={:?}, side={:?}, quantity={:?}, \
+            price={:?}, trigger_price={:?}, last_qty={:?}, last_px={:?}, commission={:?}, \
+            reason={:?})",
+            event.kind(),
+            event.client_order_id(),
+            event.venue_order_id(),
+            event.trade_id(),
+            event.order_side(),
+            event.quantity(),
+            event.price(),
+            event.trigger_price(),
+            event.last_qty(),
+            event.last_px(),
+            event.commission(),
+            event.reason(),
+        );
+        Self::new(ts_event, description)
+    }
+}
+
+impl Display for EventRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} @ {}", self.description, self.ts_event)
+    }
+}
+
+/// Represents the ordered event stream of a backtest run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventStream {
+    records: Vec<EventRecord>,
+}
+
+impl EventStream {
+    /// Creates a new [`EventStream`] instance from the given ordered `records`.
+    #[must_use]
+    pub const fn new(records: Vec<EventRecord>) -> Self {
+        Self { records }
+    }
+
+    #[must_use]
+    pub fn records(&self) -> &[EventRecord] {
+        &self.records
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the hash of the event stream.
+    ///
+    /// The hash is stable across runs of the same build.
+    #[must_use]
+    pub fn hash_value(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.records.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the first event at which this stream diverges from the `other` stream, if any.
+    #[must_use]
+    pub fn first_divergence(&self, other: &Self) -> Option<Divergence> {
+        let len = self.records.len().max(other.records.len());
+        (0..len).find_map(|index| {
+            let first = self.records.get(index);
+            let second = other.records.get(index);
+            (first != second).then(|| Divergence {
+                index,
+                first: first.cloned(),
+                second: second.cloned(),
+            })
+        })
+    }
+}
+
+/// Records the order events dispatched to the execution engine, in dispatch order.
+///
+/// The recorder intercepts the `exec_engine_process` endpoint and forwards each message to the
+/// handler previously registered there, so it must be registered after the execution engine.
+#[derive(Clone, Debug, Default)]
+pub struct EventRecorder {
+    records: Rc<RefCell<Vec<EventRecord>>>,
+}
+
+impl EventRecorder {
+    /// Creates a new [`EventRecorder`] and registers it on the `exec_engine_process` endpoint
+    /// of the given `msgbus`.
+    #[must_use]
+    pub fn register(msgbus: &Rc<RefCell<MessageBus>>) -> Self {
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        let inner = msgbus.borrow().get_endpoint(endpoint).cloned();
+        let recorder = Self::default();
+        let handler = RecordingHandler {
+            id: Ustr::from("EventRecorder"),
+            records: Rc::clone(&recorder.records),
+            inner,
+        };
+        msgbus
+            .borrow_mut()
+            .register(endpoint, ShareableMessageHandler(Rc::new(handler)));
+        recorder
+    }
+
+    /// Returns the recorded order events in dispatch order, followed by the position events
+    /// derived from the fills of the positions in the `cache`.
+    ///
+    /// Positions are sequenced by their opened time and ID.
+    #[must_use]
+    pub fn event_stream(&self, cache: &Cache) -> EventStream {
+        let mut positions: Vec<&Position> = cache.positions(None, None, None, None);
+        positions.sort_by_key(|position| (position.ts_opened, position.id));
+
+        let records = self
+            .records
+            .borrow()
+            .iter()
+            .cloned()
+            .chain(positions.into_iter().flat_map(position_records))
+            .collect();
+        EventStream::new(records)
+    }
+}
+
+struct RecordingHandler {
+    id: Ustr,
+    records: Rc<RefCell<Vec<EventRecord>>>,
+    inner: Option<ShareableMessageHandler>,
+}
+
+impl MessageHandler for RecordingHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(event) = message.downcast_ref::<OrderEventAny>() {
+            self.records
+                .borrow_mut()
+                .push(EventRecord::from_order_event(event));
+        }
+        if let Some(inner) = &self.inner {
+            inner.0.handle(message);
+        }
+    }
+
+    fn handle_response(&self, resp: DataResponse) {
+        if let Some(inner) = &self.inner {
+            inner.0.handle_response(resp);
+        }
+    }
+
+    fn handle_data(&self, data: Data) {
+        if let Some(inner) = &self.inner {
+            inner.0.handle_data(data);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the first divergent event between the event streams of two runs.
+///
+/// An event is `None` where the stream of that run ended before the divergence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub index: usize,
+    pub first: Option<EventRecord>,
+    pub second: Option<EventRecord>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let describe = |record: &Option<EventRecord>| {
+            record
+                .as_ref()
+                .map_or_else(|| "<end of stream>".to_string(), ToString::to_string)
+        };
+        write!(
+            f,
+            "Event streams diverge at event {}: first run {}, second run {}",
+            self.index,
+            describe(&self.first),
+            describe(&self.second),
+        )
+    }
+}
+
+/// Represents the result of a determinism check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeterminismReport {
+    /// The event stream hashes of the first and second runs.
+    pub hashes: (u64, u64),
+    /// The event counts of the first and second runs.
+    pub event_counts: (usize, usize),
+    /// The first divergent event, if the runs diverged.
+    pub divergence: Option<Divergence>,
+}
+
+impl DeterminismReport {
+    #[must_use]
+    pub const fn is_deterministic(&self) -> bool {
+        self.divergence.is_none()
+    }
+}
+
+/// Runs the backtest built by `run` twice and compares the event streams of both runs.
+///
+/// The `run` function must build and run the backtest from scratch on each call, returning the
+/// engine after the run. Event recording must be enabled on the engine before the run.
+///
+/// # Errors
+///
+/// This function returns an error if either run fails, or if event recording was not enabled.
+pub fn check_determinism(
+    mut run: impl FnMut() -> anyhow::Result<BacktestEngine>,
+) -> anyhow::Result<DeterminismReport> {
+    check_event_streams(|| run().and_then(|engine| engine.event_stream()))
+}
+
+/// Runs the function `run` twice and compares the returned event streams.
+///
+/// # Errors
+///
+/// This function returns an error if either run fails.
+pub fn check_event_streams(
+    mut run: impl FnMut() -> anyhow::Result<EventStream>,
+) -> anyhow::Result<DeterminismReport> {
+    let first = run()?;
+    let second = run()?;
+
+    let hashes = (first.hash_value(), second.hash_value());
+    let divergence = if hashes.0 == hashes.1 {
+        None
+    } else {
+        first.first_divergence(&second)
+    };
+    if let Some(divergence) = &divergence {
+        log::warn!("{divergence}");
+    }
+
+    Ok(DeterminismReport {
+        hashes,
+        event_counts: (first.len(), second.len()),
+        divergence,
+    })
+}
+
+// Derives the opened, changed and closed events of the `position` from its fills
+fn position_records(position: &Position) -> Vec<EventRecord> {
+    let last = position.events.len().saturating_sub(1);
+    position
+        .events
+        .iter()
+        .enumerate()
+        .map(|(index, fill)| {
+            let kind = if index == 0 {
+                "PositionOpened"
+            } else if index == last && position.is_closed() {
+                "PositionClosed"
+            } else {
+                "PositionChanged"
+            };
+            let mut description = format!(
+                "{kind}(position_id={}, trade_id={}, side={}, last_qty={}, last_px={}",
+                position.id, fill.trade_id, fill.order_side, fill.last_qty, fill.last_px,
+            );
+            if index == last {
+                description.push_str(&format!(
+                    ", quantity={}, realized_pnl={:?}",
+                    position.quantity, position.realized_pnl,
+                ));
+            }
+            description.push(')');
+            EventRecord::new(fill.ts_event, description)
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::msgbus::stubs::{get_message_saving_handler, get_saved_messages};
+    use nautilus_model::{
+        enums::{OmsType, OrderSide, OrderType},
+        identifiers::{AccountId, PositionId},
+        instruments::{any::InstrumentAny, crypto_perpetual::CryptoPerpetual, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn stream(descriptions: &[&str]) -> EventStream {
+        EventStream::new(
+            descriptions
+                .iter()
+                .enumerate()
+                .map(|(ts, description)| {
+                    EventRecord::new(UnixNanos::from(ts as u64), (*description).to_string())
+                })
+                .collect(),
+        )
+    }
+
+    #[rstest]
+    fn test_identical_runs_are_deterministic() {
+        let report = check_event_streams(|| Ok(stream(&["A", "B", "C"]))).unwrap();
+
+        assert!(report.is_deterministic());
+        assert_eq!(report.hashes.0, report.hashes.1);
+        assert_eq!(report.event_counts, (3, 3));
+    }
+
+    #[rstest]
+    fn test_reports_first_divergent_event() {
+        let mut runs =
+            vec![stream(&["A", "B", "C", "D"]), stream(&["A", "B", "X", "Y"])].into_iter();
+
+        let report = check_event_streams(|| Ok(runs.next().unwrap())).unwrap();
+
+        assert!(!report.is_deterministic());
+        assert_ne!(report.hashes.0, report.hashes.1);
+        let divergence = report.divergence.unwrap();
+        assert_eq!(divergence.index, 2);
+        assert_eq!(divergence.first.unwrap().description, "C");
+        assert_eq!(divergence.second.unwrap().description, "X");
+    }
+
+    #[rstest]
+    fn test_reports_divergence_at_end_of_shorter_stream() {
+        let mut runs = vec![stream(&["A", "B"]), stream(&["A"])].into_iter();
+
+        let report = check_event_streams(|| Ok(runs.next().unwrap())).unwrap();
+
+        let divergence = report.divergence.unwrap();
+        assert_eq!(divergence.index, 1);
+        assert!(divergence.second.is_none());
+        assert_eq!(report.event_counts, (2, 1));
+    }
+
+    #[rstest]
+    fn test_run_error_is_returned() {
+        assert!(check_event_streams(|| anyhow::bail!("Run failed")).is_err());
+    }
+
+    #[rstest]
+    fn test_recorder_records_events_in_dispatch_order(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let submitted = TestOrderEventStubs::order_submitted(&order, AccountId::new("SIM-001"));
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from("2000.00")),
+            None,
+            None,
+            None,
+            Some(UnixNanos::from(1)),
+            None,
+        );
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, handler.clone());
+        let recorder = EventRecorder::register(&msgbus);
+
+        // Dispatched out of timestamp order, which the stream must preserve
+        msgbus.borrow().send(&endpoint, &filled as &dyn Any);
+        msgbus.borrow().send(&endpoint, &submitted as &dyn Any);
+
+        let OrderEventAny::Filled(fill) = filled else {
+            panic!("Expected a fill");
+        };
+        let mut cache = Cache::default();
+        cache
+            .add_position(Position::new(&instrument, fill), OmsType::Netting)
+            .unwrap();
+        let stream = recorder.event_stream(&cache);
+
+        assert_eq!(get_saved_messages::<OrderEventAny>(handler).len(), 2);
+        assert_eq!(stream.len(), 3);
+        assert!(stream.records()[0].description.starts_with("OrderFilled"));
+        assert!(stream.records()[1]
+            .description
+            .starts_with("OrderSubmitted"));
+        assert!(stream.records()[2]
+            .description
+            .starts_with("PositionOpened(position_id=P-1"));
+        // Event IDs are excluded so the same events hash the same
+        assert_eq!(
+            stream.hash_value(),
+            recorder.event_stream(&cache).hash_value()
+        );
+    }
+}
@@ -15,8 +15,363 @@
 
 //! The core `BacktestEngine` for backtesting on historical data.
 
-use nautilus_common::{clock::TestClock, timer::TimeEventHandlerV2};
-use nautilus_core::nanos::UnixNanos;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache, clock::TestClock, msgbus::MessageBus, timer::TimeEventHandlerV2,
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use nautilus_model::{
+    accounts::{any::AccountAny, funding::FundingPayment},
+    data::{bar::Bar, funding::FundingRateUpdate, Data, GetTsInit},
+    events::order::{any::OrderEventAny, filled::OrderFilled},
+    identifiers::{AccountId, Venue},
+    instruments::any::InstrumentAny,
+    orders::any::OrderAny,
+    position::Position,
+    types::{currency::Currency, money::Money},
+};
+
+use crate::{
+    config::{BacktestClockConfig, ClockTimestamp},
+    determinism::{EventRecorder, EventStream},
+    exchange::SimulatedExchange,
+    models::latency::{FeedLatencyModel, FeedLatencyStats},
+    result::{BacktestResult, BarStats, EquityPoint},
+};
+
+/// Hosts the simulated venues of a backtest run.
+///
+/// Each venue has its own account (cash or margin), base currency, fee model and latency
+/// model, while sharing a single cache so the portfolio can be aggregated across venues.
+///
+/// When a simulation clock is set, it is advanced on each processed data point on either its
+/// processing time (`ts_init`) or event time (`ts_event`), with the delta between the two
+/// modeled as the feed latency.
+pub struct BacktestEngine {
+    cache: Rc<RefCell<Cache>>,
+    venues: HashMap<Venue, SimulatedExchange>,
+    equity_curve: Vec<EquityPoint>,
+    bar_stats: Vec<BarStats>,
+    clock: Option<&'static AtomicTime>,
+    clock_config: BacktestClockConfig,
+    feed_latency_model: FeedLatencyModel,
+    feed_latency: FeedLatencyStats,
+    late_data_count: u64,
+    event_recorder: Option<EventRecorder>,
+}
+
+impl BacktestEngine {
+    /// Creates a new [`BacktestEngine`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            cache,
+            venues: HashMap::new(),
+            equity_curve: Vec::new(),
+            bar_stats: Vec::new(),
+            clock: None,
+            clock_config: BacktestClockConfig::default(),
+            feed_latency_model: FeedLatencyModel::default(),
+            feed_latency: FeedLatencyStats::default(),
+            late_data_count: 0,
+            event_recorder: None,
+        }
+    }
+
+    /// Sets the simulation `clock` to advance on each processed data point, per the `config`.
+    pub fn set_clock(&mut self, clock: &'static AtomicTime, config: BacktestClockConfig) {
+        self.clock = Some(clock);
+        self.clock_config = config;
+        self.feed_latency_model = FeedLatencyModel::new(config.default_feed_latency_ns);
+    }
+
+    /// Returns the feed latencies observed for the processed data.
+    #[must_use]
+    pub const fn feed_latency(&self) -> FeedLatencyStats {
+        self.feed_latency
+    }
+
+    /// Returns the count of processed data which was timestamped before the simulation clock,
+    /// and so did not advance it.
+    #[must_use]
+    pub const fn late_data_count(&self) -> u64 {
+        self.late_data_count
+    }
+
+    /// Adds the given simulated `exchange` and initializes its venue account.
+    ///
+    /// Returns the ID of the initialized account.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a venue with the same ID was already added, or
+    /// if the venue account could not be initialized.
+    pub fn add_venue(&mut self, mut exchange: SimulatedExchange) -> anyhow::Result<AccountId> {
+        let venue = exchange.id();
+        if self.venues.contains_key(&venue) {
+            anyhow::bail!("Venue {venue} already added to the engine");
+        }
+
+        let account_id = AccountId::new(format!("{venue}-001"));
+        exchange.initialize_account(account_id)?;
+        self.venues.insert(venue, exchange);
+
+        log::info!("Added venue {venue} with account {account_id}");
+        Ok(account_id)
+    }
+
+    /// Returns a reference to the simulated exchange for the given `venue` (if found).
+    #[must_use]
+    pub fn venue(&self, venue: &Venue) -> Option<&SimulatedExchange> {
+        self.venues.get(venue)
+    }
+
+    /// Returns a mutable reference to the simulated exchange for the given `venue` (if found).
+    pub fn venue_mut(&mut self, venue: &Venue) -> Option<&mut SimulatedExchange> {
+        self.venues.get_mut(venue)
+    }
+
+    /// Returns the IDs of all venues, sorted.
+    #[must_use]
+    pub fn venues(&self) -> Vec<Venue> {
+        let mut venues: Vec<Venue> = self.venues.keys().copied().collect();
+        venues.sort();
+        venues
+    }
+
+    /// Adds the given `instrument` to the cache and to the simulated exchange for its venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no venue was added for the instrument, or if the
+    /// exchange rejects the instrument.
+    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        let instrument_id = instrument.id();
+        let Some(exchange) = self.venues.get_mut(&instrument_id.venue) else {
+            anyhow::bail!(
+                "Cannot add instrument {instrument_id}: no venue {} added",
+                instrument_id.venue
+            );
+        };
+
+        exchange.add_instrument(instrument.clone())?;
+        self.cache.borrow_mut().add_instrument(instrument)
+    }
+
+    /// Routes the given `data` to the simulated exchange for its instrument venue.
+    ///
+    /// If a simulation clock is set, it is first advanced to the data timestamp configured to
+    /// advance on. The clock never moves backwards, so data timestamped before the current time
+    /// is processed at the current time.
+    ///
+    /// The time driven simulation modules of all venues are then processed at the current
+    /// time, in venue order. The total account balances and per-bar stats are sampled after
+    /// each bar for the backtest result.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no venue was added for the data, or if the data
+    /// type is not supported by the simulated exchange.
+    pub fn process_data(&mut self, data: Data) -> anyhow::Result<()> {
+        let instrument_id = data.instrument_id();
+        let Some(exchange) = self.venues.get_mut(&instrument_id.venue) else {
+            anyhow::bail!(
+                "Cannot process data for {instrument_id}: no venue {} added",
+                instrument_id.venue
+            );
+        };
+
+        let ts_event = data.ts_event();
+        let latency_ns = self.feed_latency_model.latency_ns(ts_event, data.ts_init());
+        self.feed_latency.record(latency_ns);
+        if let Some(clock) = self.clock {
+            let ts_now = match self.clock_config.advance_on {
+                ClockTimestamp::TsInit => ts_event + latency_ns,
+                ClockTimestamp::TsEvent => ts_event,
+            };
+            if ts_now >= clock.get_time_ns() {
+                clock.set_time(ts_now);
+            } else {
+                self.late_data_count += 1;
+            }
+        }
+
+        let bar = match data {
+            Data::Bar(bar) => Some(bar),
+            _ => None,
+        };
+
+        match data {
+            Data::Delta(delta) => exchange.process_order_book_delta(delta),
+            Data::Deltas(deltas) => exchange.process_order_book_deltas((*deltas).clone()),
+            Data::Quote(quote) => exchange.process_quote_tick(&quote),
+            Data::Trade(trade) => exchange.process_trade_tick(&trade),
+            Data::Bar(bar) => exchange.process_bar(bar),
+            Data::Depth10(_) => {
+                anyhow::bail!("Cannot process data for {instrument_id}: depth not supported")
+            }
+        }
+
+        let ts_now = self.clock.map_or(ts_event, AtomicTime::get_time_ns);
+        for venue in self.venues() {
+            if let Some(exchange) = self.venues.get_mut(&venue) {
+                exchange.process(ts_now);
+            }
+        }
+
+        if let Some(bar) = bar {
+            self.sample_bar(&bar);
+        }
+        Ok(())
+    }
+
+    /// Routes the funding rate `update` to the simulated exchange for its instrument venue,
+    /// booking the funding payments for the open positions there.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no venue was added for the instrument.
+    pub fn process_funding_rate(
+        &mut self,
+        update: &FundingRateUpdate,
+    ) -> anyhow::Result<Vec<FundingPayment>> {
+        let instrument_id = update.instrument_id;
+        let Some(exchange) = self.venues.get_mut(&instrument_id.venue) else {
+            anyhow::bail!(
+                "Cannot process funding rate for {instrument_id}: no venue {} added",
+                instrument_id.venue
+            );
+        };
+        Ok(exchange.process_funding_rate(update))
+    }
+
+    /// Returns the results of the backtest run so far.
+    #[must_use]
+    pub fn result(&self) -> BacktestResult {
+        let cache = self.cache.borrow();
+        let mut fills: Vec<OrderFilled> = cache
+            .orders(None, None, None, None)
+            .into_iter()
+            .flat_map(order_fills)
+            .collect();
+        fills.sort_by_key(|fill| fill.ts_event);
+        let mut positions: Vec<Position> = cache
+            .positions(None, None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        positions.sort_by_key(|position| position.ts_opened);
+
+        BacktestResult::new(
+            fills,
+            positions,
+            self.equity_curve.clone(),
+            self.bar_stats.clone(),
+        )
+    }
+
+    /// Records the order events dispatched to the execution engine on the `msgbus`, for
+    /// determinism checks.
+    ///
+    /// Must be called after the execution engine is registered on the `msgbus`.
+    pub fn record_events(&mut self, msgbus: &Rc<RefCell<MessageBus>>) {
+        self.event_recorder = Some(EventRecorder::register(msgbus));
+    }
+
+    /// Returns the canonical event stream of the backtest run so far, for determinism checks.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if event recording was not enabled.
+    pub fn event_stream(&self) -> anyhow::Result<EventStream> {
+        let Some(recorder) = &self.event_recorder else {
+            anyhow::bail!("Event recording not enabled, call `record_events` before the run");
+        };
+        Ok(recorder.event_stream(&self.cache.borrow()))
+    }
+
+    /// Returns the account for the given `venue` (if found).
+    #[must_use]
+    pub fn account(&self, venue: &Venue) -> Option<AccountAny> {
+        self.cache.borrow().account_for_venue(venue).cloned()
+    }
+
+    /// Returns the accounts of all venues, keyed by venue.
+    #[must_use]
+    pub fn accounts(&self) -> HashMap<Venue, AccountAny> {
+        let cache = self.cache.borrow();
+        self.venues
+            .keys()
+            .filter_map(|venue| {
+                cache
+                    .account_for_venue(venue)
+                    .map(|account| (*venue, account.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the total balances aggregated across all venue accounts, per currency.
+    #[must_use]
+    pub fn balances_total(&self) -> HashMap<Currency, Money> {
+        aggregate_balances(self.accounts().values().map(AccountAny::balances_total))
+    }
+
+    /// Returns the free balances aggregated across all venue accounts, per currency.
+    #[must_use]
+    pub fn balances_free(&self) -> HashMap<Currency, Money> {
+        aggregate_balances(self.accounts().values().map(AccountAny::balances_free))
+    }
+
+    fn sample_bar(&mut self, bar: &Bar) {
+        let mut balances: Vec<(Currency, Money)> = self.balances_total().into_iter().collect();
+        balances.sort_by_key(|(currency, _)| currency.code);
+        self.equity_curve
+            .extend(balances.into_iter().map(|(currency, money)| EquityPoint {
+                ts: bar.ts_init,
+                currency,
+                total: money.as_f64(),
+            }));
+
+        let instrument_id = bar.bar_type.instrument_id();
+        let cache = self.cache.borrow();
+        let open_positions = cache
+            .positions_open(None, Some(&instrument_id), None, None)
+            .len();
+        let fills = cache
+            .orders(None, Some(&instrument_id), None, None)
+            .into_iter()
+            .map(|order| order_fills(order).count())
+            .sum();
+        self.bar_stats.push(BarStats {
+            ts: bar.ts_init,
+            bar_type: bar.bar_type,
+            close: bar.close,
+            open_positions,
+            fills,
+        });
+    }
+}
+
+fn order_fills(order: &OrderAny) -> impl Iterator<Item = OrderFilled> + '_ {
+    order.events().into_iter().filter_map(|event| match event {
+        OrderEventAny::Filled(fill) | OrderEventAny::PartiallyFilled(fill) => Some(*fill),
+        _ => None,
+    })
+}
+
+fn aggregate_balances(
+    balances: impl Iterator<Item = HashMap<Currency, Money>>,
+) -> HashMap<Currency, Money> {
+    let mut totals: HashMap<Currency, Money> = HashMap::new();
+    for (currency, money) in balances.flatten() {
+        totals
+            .entry(currency)
+            .and_modify(|total| *total += money)
+            .or_insert(money);
+    }
+    totals
+}
 
 /// Provides a means of accumulating and draining time event handlers.
 pub struct TimeEventAccumulator {
@@ -61,13 +416,569 @@ impl Default for TimeEventAccumulator {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_common::timer::{TimeEvent, TimeEventCallback};
-    use nautilus_core::uuid::UUID4;
+    use std::sync::LazyLock;
+
+    use nautilus_common::{
+        msgbus::{
+            stubs::{get_message_saving_handler, get_saved_messages},
+            MessageBus,
+        },
+        timer::{TimeEvent, TimeEventCallback},
+    };
+    use nautilus_core::{time::AtomicTime, uuid::UUID4};
+    use nautilus_model::{
+        accounts::{funding::FundingLedger, stubs::margin_tier_schedule},
+        data::{bar::BarType, quote::QuoteTick},
+        enums::{AccountType, BookType, OmsType, OrderSide, OrderType},
+        events::dividend::Dividend,
+        identifiers::PositionId,
+        instruments::{
+            commodity::Commodity,
+            crypto_perpetual::CryptoPerpetual,
+            ndf::Ndf,
+            stubs::{commodity_wti_cushing, crypto_perpetual_ethusdt, ndf_usdinr_1m},
+        },
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{price::Price, quantity::Quantity},
+    };
     use pyo3::{prelude::*, types::PyList, Py, Python};
     use rstest::*;
+    use rust_decimal_macros::dec;
     use ustr::Ustr;
 
     use super::*;
+    use crate::{
+        models::{
+            fee::{FeeModelAny, FixedFeeModel, MakerTakerFeeModel},
+            fill::FillModel,
+            latency::LatencyModel,
+        },
+        modules::{
+            delivery::{DeliveryConfig, DeliveryModule},
+            dividend::DividendModule,
+            financing::{FinancingConfig, FinancingModule},
+            interest::{InterestConfig, InterestModule},
+            liquidation::LiquidationModule,
+            settlement::SettlementModule,
+        },
+    };
+
+    const DAY_NS: u64 = 86_400_000_000_000;
+    const CUTOFF_NS: u64 = 22 * 3_600_000_000_000;
+
+    static ATOMIC_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(true, UnixNanos::default()));
+
+    fn get_exchange(
+        venue: &str,
+        account_type: AccountType,
+        starting_balances: Vec<Money>,
+        base_currency: Option<Currency>,
+        fee_model: FeeModelAny,
+        cache: &Rc<RefCell<Cache>>,
+        msgbus: &Rc<RefCell<MessageBus>>,
+    ) -> SimulatedExchange {
+        SimulatedExchange::new(
+            Venue::new(venue),
+            OmsType::Netting,
+            account_type,
+            starting_balances,
+            base_currency,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            Rc::clone(msgbus),
+            Rc::clone(cache),
+            &ATOMIC_TIME,
+            FillModel::default(),
+            fee_model,
+            LatencyModel,
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn get_engine() -> BacktestEngine {
+        get_engine_with_msgbus(&Rc::new(RefCell::new(MessageBus::default())))
+    }
+
+    fn get_engine_with_msgbus(msgbus: &Rc<RefCell<MessageBus>>) -> BacktestEngine {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = BacktestEngine::new(Rc::clone(&cache));
+        engine
+            .add_venue(get_exchange(
+                "SIM",
+                AccountType::Cash,
+                vec![Money::from("1_000 USD")],
+                Some(Currency::USD()),
+                FeeModelAny::Fixed(FixedFeeModel::new(Money::from("1 USD"), None).unwrap()),
+                &cache,
+                msgbus,
+            ))
+            .unwrap();
+        engine
+            .add_venue(get_exchange(
+                "BINANCE",
+                AccountType::Margin,
+                vec![Money::from("500 USD"), Money::from("10_000 USDT")],
+                None,
+                FeeModelAny::MakerTaker(MakerTakerFeeModel),
+                &cache,
+                msgbus,
+            ))
+            .unwrap();
+        engine
+    }
+
+    fn add_position(
+        engine: &BacktestEngine,
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: &str,
+        price: &str,
+    ) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(instrument, filled.into());
+        engine
+            .cache
+            .borrow_mut()
+            .add_position(position.clone(), OmsType::Netting)
+            .unwrap();
+        position
+    }
+
+    fn process_quote(engine: &mut BacktestEngine, instrument: &InstrumentAny, mid: &str, ts: u64) {
+        let price = Price::from(mid);
+        let quote = QuoteTick::new(
+            instrument.id(),
+            price,
+            price,
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            ts.into(),
+            ts.into(),
+        );
+        engine.cache.borrow_mut().add_quote(quote).unwrap();
+        engine.process_data(Data::Quote(quote)).unwrap();
+    }
+
+    #[rstest]
+    fn test_add_venues_initializes_separate_accounts() {
+        let engine = get_engine();
+
+        assert_eq!(
+            engine.venues(),
+            vec![Venue::new("BINANCE"), Venue::new("SIM")]
+        );
+
+        let sim = engine.account(&Venue::new("SIM")).unwrap();
+        assert_eq!(sim.id(), AccountId::new("SIM-001"));
+        assert_eq!(sim.account_type(), AccountType::Cash);
+        assert_eq!(sim.base_currency(), Some(Currency::USD()));
+
+        let binance = engine.account(&Venue::new("BINANCE")).unwrap();
+        assert_eq!(binance.id(), AccountId::new("BINANCE-001"));
+        assert_eq!(binance.account_type(), AccountType::Margin);
+        assert_eq!(binance.base_currency(), None);
+
+        let exchange = engine.venue(&Venue::new("SIM")).unwrap();
+        assert!(matches!(exchange.fee_model(), FeeModelAny::Fixed(_)));
+    }
+
+    #[rstest]
+    fn test_balances_aggregated_across_venues() {
+        let engine = get_engine();
+
+        let totals = engine.balances_total();
+
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&Currency::USD()], Money::from("1_500 USD"));
+        assert_eq!(totals[&Currency::USDT()], Money::from("10_000 USDT"));
+        assert_eq!(engine.balances_free(), totals);
+    }
+
+    #[rstest]
+    fn test_add_duplicate_venue_fails() {
+        let mut engine = get_engine();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let exchange = get_exchange(
+            "SIM",
+            AccountType::Cash,
+            vec![Money::from("1_000 USD")],
+            None,
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            &cache,
+            &Rc::new(RefCell::new(MessageBus::default())),
+        );
+
+        let result = engine.add_venue(exchange);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Venue SIM already added to the engine"
+        );
+    }
+
+    #[rstest]
+    fn test_add_instrument_routes_to_venue(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_engine();
+
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let binance = engine.venue(&Venue::new("BINANCE")).unwrap();
+        assert!(binance.get_matching_engine(instrument_id).is_some());
+        assert!(engine.cache.borrow().instrument(&instrument_id).is_some());
+    }
+
+    #[rstest]
+    fn test_result_samples_bars(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_engine();
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        let bar = Bar::new(
+            BarType::from("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL"),
+            Price::from("10000.0"),
+            Price::from("10001.0"),
+            Price::from("9999.0"),
+            Price::from("10000.5"),
+            Quantity::from("10.000"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        engine.process_data(Data::Bar(bar)).unwrap();
+        let result = engine.result();
+
+        assert!(result.fills.is_empty());
+        assert!(result.positions.is_empty());
+        assert_eq!(result.bar_stats.len(), 1);
+        assert_eq!(result.bar_stats[0].ts, 2);
+        assert_eq!(result.bar_stats[0].close, bar.close);
+        assert!(!result.equity_curve.is_empty());
+        assert!(result.equity_curve.iter().all(|point| point.ts == 2));
+    }
+
+    #[rstest]
+    #[case(ClockTimestamp::TsInit, [150, 210])]
+    #[case(ClockTimestamp::TsEvent, [100, 200])]
+    fn test_process_data_advances_clock(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] advance_on: ClockTimestamp,
+        #[case] expected: [u64; 2],
+    ) {
+        let clock: &'static AtomicTime =
+            Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let mut engine = get_engine();
+        engine.set_clock(
+            clock,
+            BacktestClockConfig {
+                advance_on,
+                default_feed_latency_ns: 10,
+            },
+        );
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        let bar = |ts_event: u64, ts_init: u64| {
+            Data::Bar(Bar::new(
+                BarType::from("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL"),
+                Price::from("10000.0"),
+                Price::from("10001.0"),
+                Price::from("9999.0"),
+                Price::from("10000.5"),
+                Quantity::from("10.000"),
+                UnixNanos::from(ts_event),
+                UnixNanos::from(ts_init),
+            ))
+        };
+
+        engine.process_data(bar(100, 150)).unwrap();
+        assert_eq!(clock.get_time_ns(), expected[0]);
+        engine.process_data(bar(200, 200)).unwrap();
+        assert_eq!(clock.get_time_ns(), expected[1]);
+        // Late data does not move the clock backwards
+        engine.process_data(bar(120, 120)).unwrap();
+        assert_eq!(clock.get_time_ns(), expected[1]);
+
+        let latency = engine.feed_latency();
+        assert_eq!((latency.count, latency.min_ns, latency.max_ns), (3, 10, 50));
+        assert_eq!(engine.late_data_count(), 1);
+    }
+
+    #[rstest]
+    fn test_process_data_books_financing_at_cutoffs(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine();
+        let binance = Venue::new("BINANCE");
+        engine
+            .venue_mut(&binance)
+            .unwrap()
+            .set_financing_module(FinancingModule::new(FinancingConfig {
+                borrow_rates: HashMap::from([(instrument.id(), dec!(0.36))]),
+                ..Default::default()
+            }));
+        engine.add_instrument(instrument.clone()).unwrap();
+        add_position(&engine, &instrument, OrderSide::Sell, "1.000", "2000.00");
+
+        process_quote(&mut engine, &instrument, "2000.00", CUTOFF_NS + 1);
+        assert_eq!(
+            engine.balances_total()[&Currency::USDT()],
+            Money::from("10_000 USDT")
+        );
+
+        // Crossing the next daily cut-off books the borrow fee for the short position
+        process_quote(&mut engine, &instrument, "2000.00", DAY_NS + CUTOFF_NS + 1);
+        assert_eq!(
+            engine.balances_total()[&Currency::USDT()],
+            Money::from("9_998 USDT")
+        );
+    }
+
+    #[rstest]
+    fn test_process_funding_rate_books_payments(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine();
+        engine
+            .venue_mut(&Venue::new("BINANCE"))
+            .unwrap()
+            .set_funding_ledger(FundingLedger::new());
+        engine.add_instrument(instrument.clone()).unwrap();
+        let position = add_position(&engine, &instrument, OrderSide::Buy, "1.000", "2000.00");
+        process_quote(&mut engine, &instrument, "2000.00", 1);
+        let update =
+            FundingRateUpdate::new(instrument.id(), dec!(0.0001), None, 2.into(), 2.into());
+
+        let payments = engine.process_funding_rate(&update).unwrap();
+        // The same funding period is only booked once
+        let repeated = engine.process_funding_rate(&update).unwrap();
+
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].position_id, position.id);
+        assert_eq!(payments[0].amount, Money::from("-0.2 USDT"));
+        assert!(repeated.is_empty());
+        assert_eq!(
+            engine.balances_total()[&Currency::USDT()],
+            Money::from("9_999.8 USDT")
+        );
+    }
+
+    #[rstest]
+    fn test_process_data_credits_interest_at_cutoffs(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine();
+        engine
+            .venue_mut(&Venue::new("BINANCE"))
+            .unwrap()
+            .set_interest_module(InterestModule::new(InterestConfig {
+                rates: HashMap::from([(Currency::USDT(), dec!(0.365))]),
+                ..Default::default()
+            }));
+        engine.add_instrument(instrument.clone()).unwrap();
+
+        process_quote(&mut engine, &instrument, "2000.00", 1);
+        process_quote(&mut engine, &instrument, "2000.00", DAY_NS + 1);
+
+        let totals = engine.balances_total();
+        assert_eq!(totals[&Currency::USDT()], Money::from("10_010 USDT"));
+        assert_eq!(totals[&Currency::USD()], Money::from("1_500 USD"));
+    }
+
+    #[rstest]
+    fn test_process_data_pays_dividends_on_ex_date(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine();
+        let mut dividends = DividendModule::new();
+        dividends.add_dividends(&[Dividend::new(
+            instrument.id(),
+            Money::from("1.5 USDT"),
+            DAY_NS.into(),
+            None,
+            0.into(),
+            0.into(),
+        )]);
+        engine
+            .venue_mut(&Venue::new("BINANCE"))
+            .unwrap()
+            .set_dividend_module(dividends);
+        engine.add_instrument(instrument.clone()).unwrap();
+        add_position(&engine, &instrument, OrderSide::Buy, "2.000", "2000.00");
+
+        process_quote(&mut engine, &instrument, "2000.00", DAY_NS - 1);
+        assert_eq!(
+            engine.balances_total()[&Currency::USDT()],
+            Money::from("10_000 USDT")
+        );
+
+        process_quote(&mut engine, &instrument, "2000.00", DAY_NS);
+        process_quote(&mut engine, &instrument, "2000.00", DAY_NS + 1);
+        assert_eq!(
+            engine.balances_total()[&Currency::USDT()],
+            Money::from("10_003 USDT")
+        );
+    }
+
+    #[rstest]
+    fn test_process_data_liquidates_at_maintenance_breach(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, handler.clone());
+        let mut engine = get_engine_with_msgbus(&msgbus);
+        let exchange = engine.venue_mut(&Venue::new("BINANCE")).unwrap();
+        exchange.set_liquidation_module(LiquidationModule::default());
+        exchange.set_leverage(instrument.id(), dec!(10));
+        exchange.set_margin_tiers(instrument.id(), margin_tier_schedule());
+        engine.add_instrument(instrument.clone()).unwrap();
+        let position = add_position(&engine, &instrument, OrderSide::Buy, "1.000", "2000.00");
+
+        process_quote(&mut engine, &instrument, "1850.00", 1);
+        assert!(get_saved_messages::<OrderEventAny>(handler.clone()).is_empty());
+
+        process_quote(&mut engine, &instrument, "1800.00", 2);
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Filled(fill) = events[0] else {
+            panic!("Expected a liquidation fill");
+        };
+        assert_eq!(fill.position_id, Some(position.id));
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from("1.000"));
+        assert_eq!(fill.last_px, Price::from("1800.00"));
+    }
+
+    #[rstest]
+    fn test_event_stream_records_dispatched_events(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, handler.clone());
+        let mut engine = get_engine_with_msgbus(&msgbus);
+        assert!(engine.event_stream().is_err());
+
+        engine.record_events(&msgbus);
+        let exchange = engine.venue_mut(&Venue::new("BINANCE")).unwrap();
+        exchange.set_liquidation_module(LiquidationModule::default());
+        exchange.set_leverage(instrument.id(), dec!(10));
+        exchange.set_margin_tiers(instrument.id(), margin_tier_schedule());
+        engine.add_instrument(instrument.clone()).unwrap();
+        add_position(&engine, &instrument, OrderSide::Buy, "1.000", "2000.00");
+        process_quote(&mut engine, &instrument, "1800.00", 1);
+
+        let stream = engine.event_stream().unwrap();
+        assert_eq!(get_saved_messages::<OrderEventAny>(handler).len(), 1);
+        assert!(stream.records()[0].description.starts_with("OrderFilled"));
+    }
+
+    #[rstest]
+    fn test_process_data_settles_forwards_at_fixing(ndf_usdinr_1m: Ndf) {
+        let instrument = InstrumentAny::Ndf(ndf_usdinr_1m);
+        let fixing_ns = ndf_usdinr_1m.fixing_date.as_u64();
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, handler.clone());
+        let mut engine = get_engine_with_msgbus(&msgbus);
+        let mut settlement = SettlementModule::new();
+        settlement.set_fixing(instrument.id(), Price::from("83.5000"));
+        engine
+            .venue_mut(&Venue::new("SIM"))
+            .unwrap()
+            .set_settlement_module(settlement);
+        engine.add_instrument(instrument.clone()).unwrap();
+        let position = add_position(&engine, &instrument, OrderSide::Buy, "1000", "83.0000");
+
+        process_quote(&mut engine, &instrument, "83.2000", fixing_ns - 1);
+        assert!(get_saved_messages::<OrderEventAny>(handler.clone()).is_empty());
+
+        process_quote(&mut engine, &instrument, "83.2000", fixing_ns);
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Filled(fill) = events[0] else {
+            panic!("Expected a settlement fill");
+        };
+        assert_eq!(fill.position_id, Some(position.id));
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from("1000"));
+        assert_eq!(fill.last_px, Price::from("83.5000"));
+    }
+
+    #[rstest]
+    fn test_process_data_flattens_ahead_of_delivery(commodity_wti_cushing: Commodity) {
+        let instrument = InstrumentAny::Commodity(commodity_wti_cushing);
+        let delivery_ns = commodity_wti_cushing.delivery_ns.unwrap().as_u64();
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, handler.clone());
+        let mut engine = get_engine_with_msgbus(&msgbus);
+        engine
+            .venue_mut(&Venue::new("SIM"))
+            .unwrap()
+            .set_delivery_module(DeliveryModule::new(DeliveryConfig::default()));
+        engine.add_instrument(instrument.clone()).unwrap();
+        let position = add_position(&engine, &instrument, OrderSide::Buy, "10", "78.00");
+
+        // Warnings ahead of delivery send no fills
+        process_quote(&mut engine, &instrument, "79.00", delivery_ns - 2 * DAY_NS);
+        assert!(get_saved_messages::<OrderEventAny>(handler.clone()).is_empty());
+
+        process_quote(&mut engine, &instrument, "80.00", delivery_ns - DAY_NS);
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Filled(fill) = events[0] else {
+            panic!("Expected a flatten fill");
+        };
+        assert_eq!(fill.position_id, Some(position.id));
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from("10"));
+        assert_eq!(fill.last_px, Price::from("80.00"));
+    }
+
+    #[rstest]
+    fn test_add_instrument_without_venue_fails(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = BacktestEngine::new(cache);
+
+        let result =
+            engine.add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt));
+
+        assert!(result.is_err());
+        assert!(engine.balances_total().is_empty());
+    }
 
     #[rstest]
     fn test_accumulator_drain_sorted() {
@@ -19,39 +19,71 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_common::{
+    cache::Cache, msgbus::MessageBus, price_limits::PriceBand, short_sale::ShortSaleConstraints,
+};
 use nautilus_core::{
     correctness::{check_equal, FAILED},
     nanos::UnixNanos,
     time::AtomicTime,
+    uuid::UUID4,
+};
+use nautilus_execution::{
+    client::ExecutionClient,
+    messages::{CancelOrder, TradingCommand},
+    self_trade::SelfTradePreventionMode,
 };
-use nautilus_execution::{client::ExecutionClient, messages::TradingCommand};
 use nautilus_model::{
     accounts::any::AccountAny,
+    accounts::funding::{FundingLedger, FundingPayment},
+    accounts::margin::MarginAccount,
+    accounts::tiers::MarginTierSchedule,
     data::{
         bar::Bar,
         delta::OrderBookDelta,
         deltas::{OrderBookDeltas, OrderBookDeltas_API},
+        funding::FundingRateUpdate,
         quote::QuoteTick,
         status::InstrumentStatus,
         trade::TradeTick,
         Data,
     },
-    enums::{AccountType, BookType, OmsType},
-    identifiers::{InstrumentId, Venue},
+    enums::{AccountType, BookType, OmsType, PriceType},
+    events::{account::state::AccountState, order::OrderEventAny},
+    identifiers::{AccountId, InstrumentId, Venue},
     instruments::any::InstrumentAny,
     orderbook::book::OrderBook,
-    orders::any::PassiveOrderAny,
-    types::{currency::Currency, money::Money, price::Price},
+    orders::any::{OrderAny, PassiveOrderAny},
+    position::Position,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 use crate::{
-    matching_engine::{config::OrderMatchingEngineConfig, OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel},
-    modules::SimulationModule,
+    config::BacktestVenueConfig,
+    matching_engine::{
+        config::{HaltBehavior, OrderMatchingEngineConfig},
+        OrderMatchingEngine,
+    },
+    models::{
+        bar_execution::BarExecutionModel,
+        fee::{FeeModelAny, MakerTakerFeeModel},
+        fill::FillModel,
+        hidden_liquidity::HiddenLiquidityModel, latency::LatencyModel,
+    },
+    modules::{
+        delivery::{DeliveryModule, DeliveryNotice, DeliveryNoticeType},
+        dividend::{DividendModule, DividendPayment},
+        financing::FinancingModule,
+        interest::InterestModule,
+        liquidation::{Liquidation, LiquidationModule},
+        settlement::{Settlement, SettlementModule},
+        SimulationModule,
+    },
 };
 
 pub struct SimulatedExchange {
@@ -59,15 +91,29 @@ pub struct SimulatedExchange {
     oms_type: OmsType,
     account_type: AccountType,
     book_type: BookType,
+    starting_balances: Vec<Money>,
+    base_currency: Option<Currency>,
     default_leverage: Decimal,
     exec_client: Option<ExecutionClient>,
     fee_model: FeeModelAny,
     fill_model: FillModel,
+    bar_execution_model: BarExecutionModel,
+    hidden_liquidity_model: Option<HiddenLiquidityModel>,
+    halt_behavior: HaltBehavior,
+    self_trade_prevention: Option<SelfTradePreventionMode>,
     latency_model: LatencyModel,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
+    margin_tiers: HashMap<InstrumentId, MarginTierSchedule>,
     modules: Vec<Box<dyn SimulationModule>>,
+    financing: Option<FinancingModule>,
+    funding: Option<FundingLedger>,
+    interest: Option<InterestModule>,
+    liquidation: Option<LiquidationModule>,
+    settlement: Option<SettlementModule>,
+    delivery: Option<DeliveryModule>,
+    dividend: Option<DividendModule>,
     clock: &'static AtomicTime,
     msgbus: Rc<RefCell<MessageBus>>,
     cache: Rc<RefCell<Cache>>,
@@ -80,6 +126,7 @@ pub struct SimulatedExchange {
     use_random_ids: bool,
     use_reduce_only: bool,
     use_message_queue: bool,
+    use_auctions: bool,
 }
 
 impl SimulatedExchange {
@@ -110,6 +157,7 @@ impl SimulatedExchange {
         use_random_ids: Option<bool>,
         use_reduce_only: Option<bool>,
         use_message_queue: Option<bool>,
+        use_auctions: Option<bool>,
     ) -> anyhow::Result<Self> {
         if starting_balances.is_empty() {
             anyhow::bail!("Starting balances must be provided")
@@ -123,15 +171,29 @@ impl SimulatedExchange {
             oms_type,
             account_type,
             book_type,
+            starting_balances,
+            base_currency,
             default_leverage,
             exec_client: None,
             fee_model,
             fill_model,
+            bar_execution_model: BarExecutionModel::default(),
+            hidden_liquidity_model: None,
+            halt_behavior: HaltBehavior::default(),
+            self_trade_prevention: None,
             latency_model,
             instruments: HashMap::new(),
             matching_engines: HashMap::new(),
             leverages,
+            margin_tiers: HashMap::new(),
             modules,
+            financing: None,
+            funding: None,
+            interest: None,
+            liquidation: None,
+            settlement: None,
+            delivery: None,
+            dividend: None,
             clock,
             msgbus,
             cache,
@@ -144,9 +206,85 @@ impl SimulatedExchange {
             use_random_ids: use_random_ids.unwrap_or(false),
             use_reduce_only: use_reduce_only.unwrap_or(true),
             use_message_queue: use_message_queue.unwrap_or(true),
+            use_auctions: use_auctions.unwrap_or(false),
         })
     }
 
+    /// Creates a new [`SimulatedExchange`] instance from the given venue `config`, with the
+    /// default fill model, maker/taker fee model and latency model.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the starting balances are invalid for the account.
+    pub fn from_config(
+        config: BacktestVenueConfig,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        clock: &'static AtomicTime,
+    ) -> anyhow::Result<Self> {
+        let mut exchange = Self::new(
+            config.name,
+            config.oms_type,
+            config.account_type,
+            config.starting_balances,
+            config.base_currency,
+            config.default_leverage,
+            config.leverages,
+            vec![],
+            msgbus,
+            cache,
+            clock,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel,
+            config.book_type,
+            None,
+            Some(config.bar_execution),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        exchange.set_bar_execution_model(config.bar_execution_model);
+        exchange.set_halt_behavior(config.halt_behavior);
+        exchange.set_self_trade_prevention(config.self_trade_prevention);
+        Ok(exchange)
+    }
+
+    #[must_use]
+    pub const fn id(&self) -> Venue {
+        self.id
+    }
+
+    #[must_use]
+    pub const fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    #[must_use]
+    pub const fn base_currency(&self) -> Option<Currency> {
+        self.base_currency
+    }
+
+    #[must_use]
+    pub fn starting_balances(&self) -> &[Money] {
+        &self.starting_balances
+    }
+
+    #[must_use]
+    pub const fn fee_model(&self) -> &FeeModelAny {
+        &self.fee_model
+    }
+
+    #[must_use]
+    pub const fn latency_model(&self) -> &LatencyModel {
+        &self.latency_model
+    }
+
     pub fn register_client(&mut self, client: ExecutionClient) {
         let client_id = client.client_id;
         self.exec_client = Some(client);
@@ -165,13 +303,168 @@ impl SimulatedExchange {
         self.fill_model = fill_model;
     }
 
+    pub fn set_bar_execution_model(&mut self, bar_execution_model: BarExecutionModel) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_bar_execution_model(bar_execution_model.clone());
+        }
+        log::info!(
+            "Setting bar execution model for {} to {:?}",
+            self.id,
+            bar_execution_model
+        );
+        self.bar_execution_model = bar_execution_model;
+    }
+
+    pub fn set_hidden_liquidity_model(&mut self, model: Option<HiddenLiquidityModel>) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_hidden_liquidity_model(model.clone());
+        }
+        match &model {
+            Some(model) => log::info!("Setting hidden liquidity model for {} to {model}", self.id),
+            None => log::info!("Removing hidden liquidity model for {}", self.id),
+        }
+        self.hidden_liquidity_model = model;
+    }
+
+    pub fn set_halt_behavior(&mut self, halt_behavior: HaltBehavior) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_halt_behavior(halt_behavior);
+        }
+        log::info!(
+            "Setting halt behavior for {} to {:?}",
+            self.id,
+            halt_behavior
+        );
+        self.halt_behavior = halt_behavior;
+    }
+
+    pub fn set_self_trade_prevention(&mut self, mode: Option<SelfTradePreventionMode>) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_self_trade_prevention(mode);
+        }
+        log::info!(
+            "Setting self-trade prevention for {} to {:?}",
+            self.id,
+            mode
+        );
+        self.self_trade_prevention = mode;
+    }
+
     pub fn set_latency_model(&mut self, latency_model: LatencyModel) {
         self.latency_model = latency_model;
         log::info!("Setting latency model to {}", self.latency_model);
     }
 
-    pub fn initialize_account(&mut self, _account_id: u64) {
-        todo!("initialize account")
+    pub fn set_financing_module(&mut self, financing: FinancingModule) {
+        self.financing = Some(financing);
+        log::info!("Setting financing module for {}", self.id);
+    }
+
+    pub fn set_funding_ledger(&mut self, funding: FundingLedger) {
+        self.funding = Some(funding);
+        log::info!("Setting funding ledger for {}", self.id);
+    }
+
+    pub fn set_interest_module(&mut self, interest: InterestModule) {
+        self.interest = Some(interest);
+        log::info!("Setting interest module for {}", self.id);
+    }
+
+    /// Sets the margin tier schedule for the given `instrument_id`, used for margin account
+    /// calculations and liquidations.
+    pub fn set_margin_tiers(&mut self, instrument_id: InstrumentId, schedule: MarginTierSchedule) {
+        self.margin_tiers.insert(instrument_id, schedule.clone());
+        self.update_margin_account(|account| account.set_margin_tiers(instrument_id, schedule));
+        log::info!("Setting margin tiers for {instrument_id}");
+    }
+
+    /// Sets the account `leverage` for the given `instrument_id`, which is capped by the max
+    /// leverage of its margin tiers (if any).
+    pub fn set_leverage(&mut self, instrument_id: InstrumentId, leverage: Decimal) {
+        self.leverages.insert(instrument_id, leverage);
+        let leverage = leverage.to_f64().unwrap_or(1.0);
+        self.update_margin_account(|account| account.set_leverage(instrument_id, leverage));
+        log::info!("Setting leverage for {instrument_id} to {leverage}");
+    }
+
+    fn update_margin_account(&self, update: impl FnOnce(&mut MarginAccount)) {
+        let mut cache = self.cache.as_ref().borrow_mut();
+        let Some(AccountAny::Margin(mut account)) = cache.account_for_venue(&self.id).cloned()
+        else {
+            return; // Applied when the account is initialized
+        };
+        update(&mut account);
+        if let Err(e) = cache.update_account(AccountAny::Margin(account)) {
+            log::error!("Error updating account: {e}");
+        }
+    }
+
+    pub fn set_liquidation_module(&mut self, liquidation: LiquidationModule) {
+        self.liquidation = Some(liquidation);
+        log::info!("Setting liquidation module for {}", self.id);
+    }
+
+    pub fn set_settlement_module(&mut self, settlement: SettlementModule) {
+        self.settlement = Some(settlement);
+        log::info!("Setting settlement module for {}", self.id);
+    }
+
+    pub fn set_delivery_module(&mut self, delivery: DeliveryModule) {
+        self.delivery = Some(delivery);
+        log::info!("Setting delivery module for {}", self.id);
+    }
+
+    pub fn set_dividend_module(&mut self, dividend: DividendModule) {
+        self.dividend = Some(dividend);
+        log::info!("Setting dividend module for {}", self.id);
+    }
+
+    /// Initializes the venue account with the starting balances and adds it to the cache.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `account_id` issuer is not this venue, or
+    /// if the account could not be added to the cache.
+    pub fn initialize_account(&mut self, account_id: AccountId) -> anyhow::Result<()> {
+        check_equal(
+            account_id.get_issuer(),
+            self.id,
+            "Issuer of account id",
+            "Venue of simulated exchange",
+        )?;
+
+        let balances = self
+            .starting_balances
+            .iter()
+            .map(|money| AccountBalance::new(*money, Money::new(0.0, money.currency), *money))
+            .collect();
+        let ts_now = self.clock.get_time_ns();
+        let state = AccountState::new(
+            account_id,
+            self.account_type,
+            balances,
+            vec![],
+            true,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            self.base_currency,
+        );
+
+        let mut account = AccountAny::from(state);
+        if let AccountAny::Margin(margin) = &mut account {
+            margin.set_default_leverage(self.default_leverage.to_f64().unwrap_or(1.0));
+            for (instrument_id, leverage) in &self.leverages {
+                margin.set_leverage(*instrument_id, leverage.to_f64().unwrap_or(1.0));
+            }
+            for (instrument_id, schedule) in &self.margin_tiers {
+                margin.set_margin_tiers(*instrument_id, schedule.clone());
+            }
+        }
+
+        self.cache.borrow_mut().add_account(account)?;
+        log::info!("Initialized {} account {account_id}", self.account_type);
+        Ok(())
     }
 
     pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
@@ -200,9 +493,10 @@ impl SimulatedExchange {
             self.use_position_ids,
             self.use_random_ids,
             self.use_reduce_only,
+            self.use_auctions,
         );
         let instrument_id = instrument.id();
-        let matching_engine = OrderMatchingEngine::new(
+        let mut matching_engine = OrderMatchingEngine::new(
             instrument,
             self.instruments.len() as u32,
             self.fill_model.clone(),
@@ -214,6 +508,10 @@ impl SimulatedExchange {
             Rc::clone(&self.cache),
             matching_engine_config,
         );
+        matching_engine.set_bar_execution_model(self.bar_execution_model.clone());
+        matching_engine.set_halt_behavior(self.halt_behavior);
+        matching_engine.set_self_trade_prevention(self.self_trade_prevention);
+        matching_engine.set_hidden_liquidity_model(self.hidden_liquidity_model.clone());
         self.matching_engines.insert(instrument_id, matching_engine);
 
         log::info!("Added instrument {instrument_id} and created matching engine");
@@ -314,8 +612,71 @@ impl SimulatedExchange {
             .map(nautilus_execution::client::ExecutionClient::get_account)
     }
 
-    pub fn adjust_account(&mut self, _adjustment: Money) {
-        todo!("adjust account")
+    /// Adjusts the total and free balances of the venue account for the currency of the given
+    /// `adjustment`, updating the cached account and publishing the new account state.
+    ///
+    /// Adjustments which would result in a negative total balance are not applied.
+    pub fn adjust_account(&mut self, adjustment: Money) {
+        if self.frozen_account {
+            return; // Nothing to adjust
+        }
+
+        let state = {
+            let mut cache = self.cache.as_ref().borrow_mut();
+            let Some(mut account) = cache.account_for_venue(&self.id).cloned() else {
+                log::error!("Cannot adjust account: no account found for {}", self.id);
+                return;
+            };
+            let Some(last_state) = account.last_event() else {
+                log::error!(
+                    "Cannot adjust account: no account state for {}",
+                    account.id()
+                );
+                return;
+            };
+            let mut balances = last_state.balances;
+            let Some(balance) = balances
+                .iter_mut()
+                .find(|balance| balance.currency == adjustment.currency)
+            else {
+                log::error!(
+                    "Cannot adjust account: no balance found for {}",
+                    adjustment.currency
+                );
+                return;
+            };
+            if (balance.total + adjustment).raw < 0 {
+                log::error!(
+                    "Cannot adjust account: {adjustment} exceeds total balance {}",
+                    balance.total
+                );
+                return;
+            }
+            balance.total += adjustment;
+            balance.free += adjustment;
+
+            let ts_now = self.clock.get_time_ns();
+            let state = AccountState::new(
+                account.id(),
+                self.account_type,
+                balances,
+                last_state.margins,
+                true,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                self.base_currency,
+            );
+            account.apply(state.clone());
+            if let Err(e) = cache.update_account(account) {
+                log::error!("Error updating account: {e}");
+                return;
+            }
+            state
+        };
+
+        let msgbus = self.msgbus.as_ref().borrow();
+        msgbus.publish(&msgbus.switchboard.account_state_topic, &state as &dyn Any);
     }
 
     pub fn send(&self, _command: TradingCommand) {
@@ -326,6 +687,56 @@ impl SimulatedExchange {
         todo!("generate inflight command")
     }
 
+    /// Processes the given `order` for the venue `account_id` in the matching engine for its
+    /// instrument, creating the matching engine from the cached instrument if required.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument of the order is not found in the cache.
+    pub fn process_order(&mut self, order: &OrderAny, account_id: AccountId) -> anyhow::Result<()> {
+        self.matching_engine_mut(order.instrument_id())?
+            .process_order(order, account_id);
+        Ok(())
+    }
+
+    /// Processes the given cancel `command` for the venue `account_id` in the matching engine
+    /// for its instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument of the order is not found in the cache.
+    pub fn process_cancel(
+        &mut self,
+        command: &CancelOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        self.matching_engine_mut(command.instrument_id)?
+            .process_cancel(command, account_id);
+        Ok(())
+    }
+
+    fn matching_engine_mut(
+        &mut self,
+        instrument_id: InstrumentId,
+    ) -> anyhow::Result<&mut OrderMatchingEngine> {
+        if !self.matching_engines.contains_key(&instrument_id) {
+            let instrument = self
+                .cache
+                .as_ref()
+                .borrow()
+                .instrument(&instrument_id)
+                .cloned();
+            let Some(instrument) = instrument else {
+                anyhow::bail!("No instrument found for {instrument_id}");
+            };
+            self.add_instrument(instrument)?;
+        }
+        Ok(self
+            .matching_engines
+            .get_mut(&instrument_id)
+            .expect("Matching engine should be initialized"))
+    }
+
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
         for module in &self.modules {
             module.pre_process(Data::Delta(delta));
@@ -492,8 +903,303 @@ impl SimulatedExchange {
         }
     }
 
-    pub fn process(&mut self, _ts_now: UnixNanos) {
-        todo!("process")
+    pub fn process_price_band(&mut self, band: PriceBand) {
+        if !self.matching_engines.contains_key(&band.instrument_id) {
+            let instrument = {
+                let cache = self.cache.as_ref().borrow();
+                cache.instrument(&band.instrument_id).cloned()
+            };
+
+            if let Some(instrument) = instrument {
+                self.add_instrument(instrument).unwrap();
+            } else {
+                panic!(
+                    "No matching engine found for instrument {}",
+                    band.instrument_id
+                );
+            }
+        }
+
+        if let Some(matching_engine) = self.matching_engines.get_mut(&band.instrument_id) {
+            matching_engine.update_price_band(band);
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
+    /// Sets the short-sale constraints for the constraints' instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument has not been added to the exchange.
+    pub fn set_short_sale_constraints(
+        &mut self,
+        constraints: ShortSaleConstraints,
+    ) -> anyhow::Result<()> {
+        let Some(matching_engine) = self.matching_engines.get_mut(&constraints.instrument_id)
+        else {
+            anyhow::bail!(
+                "No matching engine found for instrument {}",
+                constraints.instrument_id
+            );
+        };
+        matching_engine.set_short_sale_constraints(constraints);
+        Ok(())
+    }
+
+    /// Sets the quantity located for selling the given `instrument_id` short.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument has not been added to the exchange.
+    pub fn set_located(
+        &mut self,
+        instrument_id: InstrumentId,
+        quantity: Quantity,
+    ) -> anyhow::Result<()> {
+        let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) else {
+            anyhow::bail!("No matching engine found for instrument {instrument_id}");
+        };
+        matching_engine.set_located(quantity);
+        Ok(())
+    }
+
+    /// Books the overnight financing charges for the open positions at this venue, for the
+    /// daily cut-offs crossed up to `ts_now`.
+    pub fn process_financing(&mut self, ts_now: UnixNanos) {
+        let Some(financing) = self.financing.as_mut() else {
+            return;
+        };
+
+        let charges = {
+            let cache = self.cache.as_ref().borrow();
+            let positions = cache.positions_open(Some(&self.id), None, None, None);
+            let prices = mark_prices(&cache, &positions);
+            financing.process(ts_now, &positions, &prices)
+        };
+
+        for charge in charges {
+            log::debug!(
+                "Applying {:?} financing {} for {}",
+                charge.charge_type,
+                charge.amount,
+                charge.position_id
+            );
+            self.adjust_account(charge.amount);
+        }
+    }
+
+    /// Books the funding payments for the open positions at this venue in the instrument of the
+    /// funding rate `update`, valued at the latest mark price.
+    pub fn process_funding_rate(&mut self, update: &FundingRateUpdate) -> Vec<FundingPayment> {
+        let Some(funding) = self.funding.as_mut() else {
+            return Vec::new();
+        };
+
+        let payments = {
+            let cache = self.cache.as_ref().borrow();
+            let positions =
+                cache.positions_open(Some(&self.id), Some(&update.instrument_id), None, None);
+            if positions.is_empty() {
+                return Vec::new();
+            }
+            let prices = mark_prices(&cache, &positions);
+            let Some(mark_price) = prices.get(&update.instrument_id) else {
+                log::warn!(
+                    "Cannot apply funding for {}: no mark price",
+                    update.instrument_id
+                );
+                return Vec::new();
+            };
+            funding.process(update, *mark_price, &positions)
+        };
+
+        for payment in &payments {
+            log::debug!(
+                "Applying funding {} at rate {} for {}",
+                payment.amount,
+                payment.rate,
+                payment.position_id
+            );
+            self.adjust_account(payment.amount);
+        }
+        payments
+    }
+
+    /// Credits (or debits) the dividends for the open positions at this venue whose ex-dates
+    /// have been reached at `ts_now`.
+    pub fn process_dividends(&mut self, ts_now: UnixNanos) -> Vec<DividendPayment> {
+        let Some(dividend) = self.dividend.as_mut() else {
+            return Vec::new();
+        };
+
+        let payments = {
+            let cache = self.cache.as_ref().borrow();
+            let positions = cache.positions_open(Some(&self.id), None, None, None);
+            dividend.process(ts_now, &positions)
+        };
+
+        for payment in &payments {
+            log::info!(
+                "Applying dividend {} for {} {}",
+                payment.amount,
+                payment.instrument_id,
+                payment.position_id,
+            );
+            self.adjust_account(payment.amount);
+        }
+        payments
+    }
+
+    /// Accrues interest on the idle cash balances of the account at this venue, for the daily
+    /// cut-offs crossed up to `ts_now`.
+    pub fn process_interest(&mut self, ts_now: UnixNanos) {
+        let Some(interest) = self.interest.as_mut() else {
+            return;
+        };
+
+        let credits = {
+            let cache = self.cache.as_ref().borrow();
+            let balances = cache
+                .account_for_venue(&self.id)
+                .and_then(AccountAny::last_event)
+                .map(|state| state.balances)
+                .unwrap_or_default();
+            interest.process(ts_now, &balances)
+        };
+
+        for credit in credits {
+            log::debug!(
+                "Applying interest {} accrued over {} days",
+                credit.amount,
+                credit.accrual_days
+            );
+            self.adjust_account(credit.amount);
+        }
+    }
+
+    /// Checks the open positions at this venue against their maintenance margins at `ts_now`,
+    /// sending the fills for the positions liquidated to the execution engine.
+    pub fn process_liquidations(&mut self, ts_now: UnixNanos) -> Vec<Liquidation> {
+        let Some(liquidation) = self.liquidation.as_mut() else {
+            return Vec::new();
+        };
+
+        let liquidations = {
+            let cache = self.cache.as_ref().borrow();
+            let Some(AccountAny::Margin(account)) = cache.account_for_venue(&self.id) else {
+                return Vec::new();
+            };
+            let positions = cache.positions_open(Some(&self.id), None, None, None);
+            let prices = mark_prices(&cache, &positions);
+            let leverages: HashMap<InstrumentId, f64> = positions
+                .iter()
+                .map(|position| {
+                    let instrument_id = position.instrument_id;
+                    (instrument_id, account.get_leverage(&instrument_id))
+                })
+                .collect();
+            liquidation.process(
+                ts_now,
+                &positions,
+                &prices,
+                &account.margin_tiers,
+                &leverages,
+            )
+        };
+
+        for liquidation in &liquidations {
+            log::warn!(
+                "Liquidating {} {} at bankruptcy price {} (mark {}), margin balance {} below maintenance margin {}",
+                liquidation.quantity,
+                liquidation.position_id,
+                liquidation.bankruptcy_price,
+                liquidation.mark_price,
+                liquidation.margin_balance,
+                liquidation.maintenance_margin,
+            );
+            let event = OrderEventAny::Filled(liquidation.fill);
+            let msgbus = self.msgbus.as_ref().borrow();
+            msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        }
+        liquidations
+    }
+
+    /// Cash settles the open forward positions at this venue which have reached their
+    /// settlement date at `ts_now`, sending the fills at the fixing rate to the execution engine.
+    pub fn process_settlements(&mut self, ts_now: UnixNanos) -> Vec<Settlement> {
+        let Some(settlement) = self.settlement.as_mut() else {
+            return Vec::new();
+        };
+
+        let settlements = {
+            let cache = self.cache.as_ref().borrow();
+            let positions = cache.positions_open(Some(&self.id), None, None, None);
+            let prices = mark_prices(&cache, &positions);
+            settlement.process(ts_now, &positions, &self.instruments, &prices)
+        };
+
+        for settlement in &settlements {
+            log::info!(
+                "Settling {} {} at fixing {} for {}",
+                settlement.quantity,
+                settlement.position_id,
+                settlement.fixing_price,
+                settlement.amount,
+            );
+            let event = OrderEventAny::Filled(settlement.fill);
+            let msgbus = self.msgbus.as_ref().borrow();
+            msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        }
+        settlements
+    }
+
+    /// Warns of the open positions at this venue approaching physical delivery at `ts_now`,
+    /// sending the fills for the positions flattened ahead of delivery to the execution engine.
+    pub fn process_deliveries(&mut self, ts_now: UnixNanos) -> Vec<DeliveryNotice> {
+        let Some(delivery) = self.delivery.as_mut() else {
+            return Vec::new();
+        };
+
+        let notices = {
+            let cache = self.cache.as_ref().borrow();
+            let positions = cache.positions_open(Some(&self.id), None, None, None);
+            let prices = mark_prices(&cache, &positions);
+            delivery.process(ts_now, &positions, &self.instruments, &prices)
+        };
+
+        for notice in &notices {
+            match (notice.notice_type, notice.fill) {
+                (DeliveryNoticeType::Flatten, Some(fill)) => {
+                    log::warn!(
+                        "Flattening {} ahead of physical delivery of {} at {}",
+                        notice.position_id,
+                        notice.instrument_id,
+                        notice.delivery_ns,
+                    );
+                    let event = OrderEventAny::Filled(fill);
+                    let msgbus = self.msgbus.as_ref().borrow();
+                    msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+                }
+                _ => log::warn!(
+                    "Position {} is approaching physical delivery of {} at {}",
+                    notice.position_id,
+                    notice.instrument_id,
+                    notice.delivery_ns,
+                ),
+            }
+        }
+        notices
+    }
+
+    /// Processes the time driven simulation modules of this venue at `ts_now`.
+    pub fn process(&mut self, ts_now: UnixNanos) {
+        self.process_financing(ts_now);
+        self.process_interest(ts_now);
+        self.process_dividends(ts_now);
+        self.process_liquidations(ts_now);
+        self.process_settlements(ts_now);
+        self.process_deliveries(ts_now);
     }
 
     pub fn reset(&mut self) {
@@ -509,6 +1215,20 @@ impl SimulatedExchange {
     }
 }
 
+/// Returns the prices to value the given open `positions` at, being the latest mid price (or
+/// last trade price if no quotes) for each position instrument found in the `cache`.
+fn mark_prices(cache: &Cache, positions: &[&Position]) -> HashMap<InstrumentId, Price> {
+    positions
+        .iter()
+        .filter_map(|position| {
+            cache
+                .price(&position.instrument_id, PriceType::Mid)
+                .or_else(|| cache.price(&position.instrument_id, PriceType::Last))
+                .map(|price| (position.instrument_id, price))
+        })
+        .collect()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -519,6 +1239,7 @@ mod tests {
     use nautilus_common::{cache::Cache, msgbus::MessageBus};
     use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
     use nautilus_model::{
+        accounts::{any::AccountAny, stubs::margin_tier_schedule},
         data::{
             bar::{Bar, BarType},
             delta::OrderBookDelta,
@@ -532,13 +1253,14 @@ mod tests {
             AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
             OmsType, OrderSide,
         },
-        identifiers::{TradeId, Venue},
+        identifiers::{AccountId, TradeId, Venue},
         instruments::{
             any::InstrumentAny, crypto_perpetual::CryptoPerpetual, stubs::crypto_perpetual_ethusdt,
         },
         types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
     };
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
     use crate::{
         exchange::SimulatedExchange,
@@ -582,6 +1304,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -608,6 +1331,31 @@ mod tests {
         exchange.add_instrument(instrument).unwrap();
     }
 
+    #[rstest]
+    fn test_margin_tiers_and_leverage_applied_to_account(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut exchange =
+            get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L1_MBP);
+        exchange
+            .initialize_account(AccountId::new("BINANCE-001"))
+            .unwrap();
+        let instrument_id = crypto_perpetual_ethusdt.id;
+
+        exchange.set_leverage(instrument_id, dec!(125));
+        exchange.set_margin_tiers(instrument_id, margin_tier_schedule());
+
+        let cache = exchange.cache.borrow();
+        let Some(AccountAny::Margin(account)) = cache.account_for_venue(&exchange.id) else {
+            panic!("Expected a margin account");
+        };
+        assert_eq!(account.get_leverage(&instrument_id), 125.0);
+        assert_eq!(
+            account.effective_leverage(&instrument_id, 1_000_000.0),
+            50.0
+        );
+    }
+
     #[rstest]
     fn test_exchange_process_quote_tick(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let mut exchange: SimulatedExchange =
@@ -27,12 +27,16 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod config;
 pub mod data_client;
+pub mod determinism;
 pub mod engine;
 pub mod exchange;
 pub mod matching_engine;
 pub mod models;
 pub mod modules;
+pub mod result;
+pub mod sandbox;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
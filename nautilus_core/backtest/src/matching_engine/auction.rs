@@ -0,0 +1,391 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Call auction (opening and closing cross) matching for the `OrderMatchingEngine`.
+//!
+//! Orders are accumulated in an [`AuctionBook`] while the auction is open, then uncrossed at a
+//! single equilibrium price which maximizes the matched volume. Ties are broken by the smallest
+//! volume imbalance, then the closest price to the reference price, then the lowest price.
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{ClientOrderId, VenueOrderId},
+    orders::any::OrderAny,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// The type of call auction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuctionType {
+    /// The opening cross, filling `AT_THE_OPEN` orders.
+    Opening,
+    /// The closing cross, filling `AT_THE_CLOSE` orders.
+    Closing,
+}
+
+impl Display for AuctionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Opening => write!(f, "opening"),
+            Self::Closing => write!(f, "closing"),
+        }
+    }
+}
+
+/// Represents an order accepted into a call auction.
+#[derive(Clone, Debug)]
+pub struct AuctionOrder {
+    pub order: OrderAny,
+    pub venue_order_id: VenueOrderId,
+}
+
+impl AuctionOrder {
+    fn is_eligible(&self, price: Price) -> bool {
+        match (self.order.order_side(), self.order.price()) {
+            (_, None) => true, // Market orders always participate
+            (OrderSide::Buy, Some(limit)) => limit >= price,
+            (_, Some(limit)) => limit <= price,
+        }
+    }
+}
+
+/// Represents the fill of an auction order at the cross price.
+#[derive(Clone, Debug)]
+pub struct AuctionFill {
+    pub order: AuctionOrder,
+    pub quantity: Quantity,
+}
+
+/// Represents the result of uncrossing a call auction.
+#[derive(Clone, Debug, Default)]
+pub struct AuctionResult {
+    /// The single cross price (if the auction crossed).
+    pub price: Option<Price>,
+    /// The fills at the cross price.
+    pub fills: Vec<AuctionFill>,
+    /// The orders which were not completely filled.
+    pub unfilled: Vec<AuctionOrder>,
+}
+
+/// Accumulates the orders of a call auction.
+#[derive(Clone, Debug, Default)]
+pub struct AuctionBook {
+    orders: Vec<AuctionOrder>,
+}
+
+impl AuctionBook {
+    /// Creates a new empty [`AuctionBook`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given `order` to the auction.
+    pub fn add(&mut self, order: OrderAny, venue_order_id: VenueOrderId) {
+        self.orders.push(AuctionOrder {
+            order,
+            venue_order_id,
+        });
+    }
+
+    /// Removes the order with the given `client_order_id` from the auction (if found).
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<AuctionOrder> {
+        let index = self
+            .orders
+            .iter()
+            .position(|entry| entry.order.client_order_id() == *client_order_id)?;
+        Some(self.orders.remove(index))
+    }
+
+    #[must_use]
+    pub fn orders(&self) -> &[AuctionOrder] {
+        &self.orders
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Clears all orders from the auction.
+    pub fn clear(&mut self) {
+        self.orders.clear();
+    }
+
+    /// Returns the equilibrium cross price and matched quantity for the auction, or `None`
+    /// if no volume would match.
+    ///
+    /// The `reference_price` breaks ties between prices, and is the cross price when only
+    /// market orders participate.
+    #[must_use]
+    pub fn cross_price(&self, reference_price: Option<Price>) -> Option<(Price, Quantity)> {
+        let mut candidates: Vec<Price> = self
+            .orders
+            .iter()
+            .filter_map(|entry| entry.order.price())
+            .collect();
+        if candidates.is_empty() {
+            candidates.extend(reference_price);
+        }
+
+        let mut best: Option<(Price, u64, u64)> = None;
+        for price in candidates {
+            let buy_raw = self.eligible_raw(OrderSide::Buy, price);
+            let sell_raw = self.eligible_raw(OrderSide::Sell, price);
+            let matched = buy_raw.min(sell_raw);
+            let imbalance = buy_raw.abs_diff(sell_raw);
+            if matched == 0 {
+                continue;
+            }
+
+            let is_better = best.is_none_or(|(best_price, best_matched, best_imbalance)| {
+                if matched != best_matched {
+                    return matched > best_matched;
+                }
+                if imbalance != best_imbalance {
+                    return imbalance < best_imbalance;
+                }
+                if let Some(reference) = reference_price {
+                    let distance = price.raw.abs_diff(reference.raw);
+                    let best_distance = best_price.raw.abs_diff(reference.raw);
+                    if distance != best_distance {
+                        return distance < best_distance;
+                    }
+                }
+                price < best_price
+            });
+            if is_better {
+                best = Some((price, matched, imbalance));
+            }
+        }
+
+        let precision = self.orders.first()?.order.quantity().precision;
+        best.map(|(price, matched, _)| (price, Quantity::from_raw(matched, precision)))
+    }
+
+    /// Uncrosses the auction at the equilibrium price, allocating the matched quantity by
+    /// price then time priority (market orders first), and clears the book.
+    pub fn uncross(&mut self, reference_price: Option<Price>) -> AuctionResult {
+        let Some((price, matched)) = self.cross_price(reference_price) else {
+            return AuctionResult {
+                price: None,
+                fills: Vec::new(),
+                unfilled: std::mem::take(&mut self.orders),
+            };
+        };
+
+        let mut result = AuctionResult {
+            price: Some(price),
+            ..Default::default()
+        };
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            let mut entries: Vec<&AuctionOrder> = self
+                .orders
+                .iter()
+                .filter(|entry| entry.order.order_side() == side && entry.is_eligible(price))
+                .collect();
+            // Stable sort keeps time priority within each price level
+            entries.sort_by(|a, b| match (a.order.price(), b.order.price()) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, Some(_)) => std::cmp::Ordering::Less,
+                (Some(_), None) => std::cmp::Ordering::Greater,
+                (Some(a), Some(b)) if side == OrderSide::Buy => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+            });
+
+            let mut remaining_raw = matched.raw;
+            for entry in entries {
+                if remaining_raw == 0 {
+                    break;
+                }
+                let fill_raw = entry.order.leaves_qty().raw.min(remaining_raw);
+                remaining_raw -= fill_raw;
+                result.fills.push(AuctionFill {
+                    order: entry.clone(),
+                    quantity: Quantity::from_raw(fill_raw, matched.precision),
+                });
+            }
+        }
+
+        for entry in self.orders.drain(..) {
+            let filled_raw: u64 = result
+                .fills
+                .iter()
+                .filter(|fill| fill.order.order.client_order_id() == entry.order.client_order_id())
+                .map(|fill| fill.quantity.raw)
+                .sum();
+            if filled_raw < entry.order.leaves_qty().raw {
+                result.unfilled.push(entry);
+            }
+        }
+        result
+    }
+
+    fn eligible_raw(&self, side: OrderSide, price: Price) -> u64 {
+        self.orders
+            .iter()
+            .filter(|entry| entry.order.order_side() == side && entry.is_eligible(price))
+            .map(|entry| entry.order.leaves_qty().raw)
+            .sum()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{enums::OrderType, orders::builder::OrderTestBuilder};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order(id: &str, side: OrderSide, quantity: &str, price: Option<&str>) -> OrderAny {
+        let order_type = if price.is_some() {
+            OrderType::Limit
+        } else {
+            OrderType::Market
+        };
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id("AAPL.XNAS".into())
+            .client_order_id(ClientOrderId::from(id))
+            .side(side)
+            .quantity(Quantity::from(quantity));
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        builder.build()
+    }
+
+    fn book(orders: Vec<OrderAny>) -> AuctionBook {
+        let mut book = AuctionBook::new();
+        for (i, order) in orders.into_iter().enumerate() {
+            book.add(order, VenueOrderId::new(format!("V-{i}")));
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_cross_price_maximizes_matched_volume() {
+        let book = book(vec![
+            order("B1", OrderSide::Buy, "100", Some("10.20")),
+            order("B2", OrderSide::Buy, "200", Some("10.10")),
+            order("S1", OrderSide::Sell, "150", Some("10.00")),
+            order("S2", OrderSide::Sell, "150", Some("10.10")),
+        ]);
+
+        let (price, matched) = book.cross_price(None).unwrap();
+
+        assert_eq!(price, Price::from("10.10"));
+        assert_eq!(matched, Quantity::from("300"));
+    }
+
+    #[rstest]
+    fn test_cross_price_breaks_ties_by_imbalance_then_reference() {
+        let book = book(vec![
+            order("B1", OrderSide::Buy, "100", Some("10.20")),
+            order("S1", OrderSide::Sell, "100", Some("10.00")),
+        ]);
+
+        // Both limit prices match 100 with no imbalance
+        assert_eq!(book.cross_price(None).unwrap().0, Price::from("10.00"));
+        assert_eq!(
+            book.cross_price(Some(Price::from("10.18"))).unwrap().0,
+            Price::from("10.20")
+        );
+    }
+
+    #[rstest]
+    fn test_cross_price_with_market_orders_only() {
+        let book = book(vec![
+            order("B1", OrderSide::Buy, "100", None),
+            order("S1", OrderSide::Sell, "50", None),
+        ]);
+
+        assert_eq!(book.cross_price(None), None);
+        assert_eq!(
+            book.cross_price(Some(Price::from("10.05"))),
+            Some((Price::from("10.05"), Quantity::from("50")))
+        );
+    }
+
+    #[rstest]
+    fn test_cross_price_when_not_crossed() {
+        let book = book(vec![
+            order("B1", OrderSide::Buy, "100", Some("9.90")),
+            order("S1", OrderSide::Sell, "100", Some("10.00")),
+        ]);
+
+        assert_eq!(book.cross_price(Some(Price::from("9.95"))), None);
+    }
+
+    #[rstest]
+    fn test_uncross_allocates_by_price_then_time_priority() {
+        let mut book = book(vec![
+            order("B1", OrderSide::Buy, "100", Some("10.10")),
+            order("B2", OrderSide::Buy, "100", None),
+            order("B3", OrderSide::Buy, "100", Some("10.20")),
+            order("S1", OrderSide::Sell, "250", Some("10.00")),
+        ]);
+
+        let result = book.uncross(None);
+
+        // 10.00 and 10.10 both match 250, lowest price wins without a reference price
+        assert_eq!(result.price, Some(Price::from("10.00")));
+        let fills: Vec<(String, Quantity)> = result
+            .fills
+            .iter()
+            .map(|fill| {
+                (
+                    fill.order.order.client_order_id().to_string(),
+                    fill.quantity,
+                )
+            })
+            .collect();
+        assert_eq!(
+            fills,
+            vec![
+                ("B2".to_string(), Quantity::from("100")),
+                ("B3".to_string(), Quantity::from("100")),
+                ("B1".to_string(), Quantity::from("50")),
+                ("S1".to_string(), Quantity::from("250")),
+            ]
+        );
+        assert_eq!(result.unfilled.len(), 1);
+        assert_eq!(
+            result.unfilled[0].order.client_order_id(),
+            ClientOrderId::from("B1")
+        );
+        assert!(book.is_empty());
+    }
+
+    #[rstest]
+    fn test_uncross_when_not_crossed_returns_all_unfilled() {
+        let mut book = book(vec![
+            order("B1", OrderSide::Buy, "100", Some("9.90")),
+            order("S1", OrderSide::Sell, "100", Some("10.00")),
+        ]);
+
+        let result = book.uncross(None);
+
+        assert_eq!(result.price, None);
+        assert!(result.fills.is_empty());
+        assert_eq!(result.unfilled.len(), 2);
+        assert!(book.is_empty());
+    }
+}
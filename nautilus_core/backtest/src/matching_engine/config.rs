@@ -13,6 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use serde::{Deserialize, Serialize};
+
+/// The behavior for orders received while an instrument is halted or in an auction.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HaltBehavior {
+    /// Reject the order.
+    #[default]
+    Reject,
+    /// Park the order until continuous trading resumes, then process it.
+    Park,
+}
+
+/// The behavior for post-only orders which would cross the spread and take liquidity.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PostOnlyBehavior {
+    /// Reject the order.
+    #[default]
+    Reject,
+    /// Reprice the order one tick inside the opposite side of the book, so it rests as a maker.
+    Reprice,
+}
+
 /// Configuration for `OrderMatchingEngine` instances.
 #[derive(Debug, Clone)]
 pub struct OrderMatchingEngineConfig {
@@ -23,11 +47,13 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    pub use_auctions: bool,
 }
 
 impl OrderMatchingEngineConfig {
     /// Creates a new default [`OrderMatchingEngineConfig`] instance.
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         bar_execution: bool,
         reject_stop_orders: bool,
@@ -36,6 +62,7 @@ impl OrderMatchingEngineConfig {
         use_position_ids: bool,
         use_random_ids: bool,
         use_reduce_only: bool,
+        use_auctions: bool,
     ) -> Self {
         Self {
             bar_execution,
@@ -45,6 +72,7 @@ impl OrderMatchingEngineConfig {
             use_position_ids,
             use_random_ids,
             use_reduce_only,
+            use_auctions,
         }
     }
 }
@@ -61,6 +89,7 @@ impl Default for OrderMatchingEngineConfig {
             use_position_ids: false,
             use_random_ids: false,
             use_reduce_only: false,
+            use_auctions: false,
         }
     }
 }
@@ -17,6 +17,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
+pub mod auction;
 pub mod config;
 
 #[cfg(test)]
@@ -25,21 +26,37 @@ mod tests;
 use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 use chrono::TimeDelta;
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
-use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
-use nautilus_execution::matching_core::OrderMatchingCore;
+use nautilus_common::{
+    cache::Cache,
+    msgbus::MessageBus,
+    price_limits::{PriceBand, PriceLimits},
+    session::{order_expire_time, SessionCalendar},
+    short_sale::{ShortSaleConstraints, ShortSaleRules},
+};
+use nautilus_core::{
+    correctness::{check_equal, FAILED},
+    nanos::UnixNanos,
+    time::AtomicTime,
+    uuid::UUID4,
+};
+use nautilus_execution::{
+    matching_core::OrderMatchingCore,
+    messages::CancelOrder,
+    self_trade::{resolve_self_trade, SelfTradePreventionMode},
+};
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
         delta::OrderBookDelta,
         deltas::OrderBookDeltas,
+        order::BookOrder,
         quote::QuoteTick,
         trade::TradeTick,
     },
     enums::{
         AccountType, AggregationSource, AggressorSide, BarAggregation, BookType, ContingencyType,
         LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderStatus,
-        OrderType, PriceType,
+        OrderType, PriceType, TimeInForce, VenueRejectReason,
     },
     events::order::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired,
@@ -62,7 +79,15 @@ use nautilus_model::{
 use ustr::Ustr;
 use uuid::Uuid;
 
-use crate::{matching_engine::config::OrderMatchingEngineConfig, models::fill::FillModel};
+use crate::{
+    matching_engine::{
+        auction::{AuctionBook, AuctionOrder, AuctionType},
+        config::{HaltBehavior, OrderMatchingEngineConfig, PostOnlyBehavior},
+    },
+    models::{
+        bar_execution::BarExecutionModel, fill::FillModel, hidden_liquidity::HiddenLiquidityModel,
+    },
+};
 
 /// An order matching engine for a single market.
 pub struct OrderMatchingEngine {
@@ -88,6 +113,18 @@ pub struct OrderMatchingEngine {
     book: OrderBook,
     core: OrderMatchingCore,
     fill_model: FillModel,
+    bar_execution_model: BarExecutionModel,
+    hidden_liquidity_model: Option<HiddenLiquidityModel>,
+    price_limits: PriceLimits,
+    short_sale: ShortSaleRules,
+    session_calendar: Option<SessionCalendar>,
+    halt_behavior: HaltBehavior,
+    post_only_behavior: PostOnlyBehavior,
+    self_trade_prevention: Option<SelfTradePreventionMode>,
+    auction: Option<AuctionType>,
+    opening_auction: AuctionBook,
+    closing_auction: AuctionBook,
+    parked_orders: Vec<(OrderAny, AccountId)>,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
     target_last: Option<Price>,
@@ -129,6 +166,11 @@ impl OrderMatchingEngine {
             instrument,
             raw_id,
             fill_model,
+            bar_execution_model: BarExecutionModel::default(),
+            hidden_liquidity_model: None,
+            price_limits: PriceLimits::new(),
+            short_sale: ShortSaleRules::new(),
+            session_calendar: None,
             book_type,
             oms_type,
             account_type,
@@ -138,6 +180,13 @@ impl OrderMatchingEngine {
             book,
             core,
             market_status: MarketStatus::Open,
+            halt_behavior: HaltBehavior::default(),
+            post_only_behavior: PostOnlyBehavior::default(),
+            self_trade_prevention: None,
+            auction: None,
+            opening_auction: AuctionBook::new(),
+            closing_auction: AuctionBook::new(),
+            parked_orders: Vec::new(),
             config,
             target_bid: None,
             target_ask: None,
@@ -159,6 +208,12 @@ impl OrderMatchingEngine {
         self.execution_bar_deltas.clear();
         self.account_ids.clear();
         self.core.reset();
+        self.price_limits.clear();
+        self.short_sale.clear();
+        self.auction = None;
+        self.opening_auction.clear();
+        self.closing_auction.clear();
+        self.parked_orders.clear();
         self.target_bid = None;
         self.target_ask = None;
         self.target_last = None;
@@ -173,6 +228,111 @@ impl OrderMatchingEngine {
         self.fill_model = fill_model;
     }
 
+    /// Sets the model of hidden liquidity available to market orders beyond the displayed depth.
+    pub fn set_hidden_liquidity_model(&mut self, model: Option<HiddenLiquidityModel>) {
+        self.hidden_liquidity_model = model;
+    }
+
+    #[must_use]
+    pub const fn hidden_liquidity_model(&self) -> Option<&HiddenLiquidityModel> {
+        self.hidden_liquidity_model.as_ref()
+    }
+
+    /// Sets the behavior for orders received while the market is halted or in an auction.
+    pub fn set_halt_behavior(&mut self, halt_behavior: HaltBehavior) {
+        self.halt_behavior = halt_behavior;
+    }
+
+    #[must_use]
+    pub const fn halt_behavior(&self) -> HaltBehavior {
+        self.halt_behavior
+    }
+
+    /// Sets the behavior for post-only orders which would cross the spread.
+    pub fn set_post_only_behavior(&mut self, post_only_behavior: PostOnlyBehavior) {
+        self.post_only_behavior = post_only_behavior;
+    }
+
+    #[must_use]
+    pub const fn post_only_behavior(&self) -> PostOnlyBehavior {
+        self.post_only_behavior
+    }
+
+    /// Sets the self-trade prevention mode for orders which would trade against resting orders
+    /// of the same strategy (disabled if `None`).
+    pub fn set_self_trade_prevention(&mut self, mode: Option<SelfTradePreventionMode>) {
+        self.self_trade_prevention = mode;
+    }
+
+    #[must_use]
+    pub const fn self_trade_prevention(&self) -> Option<SelfTradePreventionMode> {
+        self.self_trade_prevention
+    }
+
+    /// Sets the fill model for executing orders against bars.
+    pub fn set_bar_execution_model(&mut self, bar_execution_model: BarExecutionModel) {
+        self.bar_execution_model = bar_execution_model;
+    }
+
+    #[must_use]
+    pub const fn bar_execution_model(&self) -> &BarExecutionModel {
+        &self.bar_execution_model
+    }
+
+    /// Updates the venue price band, orders priced outside the band will be rejected.
+    pub fn update_price_band(&mut self, band: PriceBand) {
+        check_equal(
+            band.instrument_id,
+            self.instrument.id(),
+            "Instrument id of price band",
+            "Instrument id of matching engine",
+        )
+        .expect(FAILED);
+
+        log::debug!("Updating {band:?}");
+        self.price_limits.update(band);
+    }
+
+    #[must_use]
+    pub fn price_band(&self) -> Option<&PriceBand> {
+        self.price_limits.band(&self.instrument.id())
+    }
+
+    /// Sets the short-sale constraints, sell orders which would result in a disallowed short
+    /// position will be rejected.
+    pub fn set_short_sale_constraints(&mut self, constraints: ShortSaleConstraints) {
+        check_equal(
+            constraints.instrument_id,
+            self.instrument.id(),
+            "Instrument id of short-sale constraints",
+            "Instrument id of matching engine",
+        )
+        .expect(FAILED);
+
+        log::debug!("Setting {constraints:?}");
+        self.short_sale.set_constraints(constraints);
+    }
+
+    #[must_use]
+    pub fn short_sale_constraints(&self) -> Option<&ShortSaleConstraints> {
+        self.short_sale.constraints(&self.instrument.id())
+    }
+
+    /// Sets the quantity located for selling the instrument short.
+    pub fn set_located(&mut self, quantity: Quantity) {
+        self.short_sale.set_located(self.instrument.id(), quantity);
+    }
+
+    /// Sets the session calendar used to expire `AT_THE_OPEN` and `AT_THE_CLOSE` orders.
+    pub fn set_session_calendar(&mut self, calendar: Option<SessionCalendar>) {
+        self.session_calendar = calendar;
+    }
+
+    #[must_use]
+    pub const fn session_calendar(&self) -> Option<&SessionCalendar> {
+        self.session_calendar.as_ref()
+    }
+
     #[must_use]
     pub fn best_bid_price(&self) -> Option<Price> {
         self.book.best_bid_price()
@@ -334,31 +494,31 @@ impl OrderMatchingEngine {
             self.core.set_last_raw(trade_tick.price);
         }
 
-        // High
-        // Check if higher than last
-        if self.core.last.is_some_and(|last| bar.high > last) {
-            trade_tick.price = bar.high;
-            trade_tick.aggressor_side = AggressorSide::Buyer;
-            trade_tick.trade_id = self.generate_trade_id();
-
-            self.book.update_trade_tick(&trade_tick).unwrap();
-            self.iterate(trade_tick.ts_init);
-
-            self.core.set_last_raw(trade_tick.price);
-        }
-
-        // Low
-        // Check if lower than last
-        // Assumption: market traded down, aggressor hitting the bid(setting aggressor to seller)
-        if self.core.last.is_some_and(|last| bar.low < last) {
-            trade_tick.price = bar.low;
-            trade_tick.aggressor_side = AggressorSide::Seller;
-            trade_tick.trade_id = self.generate_trade_id();
-
-            self.book.update_trade_tick(&trade_tick).unwrap();
-            self.iterate(trade_tick.ts_init);
-
-            self.core.set_last_raw(trade_tick.price);
+        // High and low, in the assumed intra-bar ordering
+        // Assumption: market traded up, aggressor lifting the ask (setting aggressor to buyer)
+        // Assumption: market traded down, aggressor hitting the bid (setting aggressor to seller)
+        let high = (bar.high, AggressorSide::Buyer);
+        let low = (bar.low, AggressorSide::Seller);
+        let extremes = if self.bar_execution_model.ordering.is_high_first(bar) {
+            [high, low]
+        } else {
+            [low, high]
+        };
+        for (price, aggressor_side) in extremes {
+            let is_new_extreme = self.core.last.is_some_and(|last| match aggressor_side {
+                AggressorSide::Buyer => price > last,
+                _ => price < last,
+            });
+            if is_new_extreme {
+                trade_tick.price = price;
+                trade_tick.aggressor_side = aggressor_side;
+                trade_tick.trade_id = self.generate_trade_id();
+
+                self.book.update_trade_tick(&trade_tick).unwrap();
+                self.iterate(trade_tick.ts_init);
+
+                self.core.set_last_raw(trade_tick.price);
+            }
         }
 
         // Close
@@ -409,17 +569,20 @@ impl OrderMatchingEngine {
         self.book.update_quote_tick(&quote_tick).unwrap();
         self.iterate(quote_tick.ts_init);
 
-        // High
-        quote_tick.bid_price = bid_bar.high;
-        quote_tick.ask_price = ask_bar.high;
-        self.book.update_quote_tick(&quote_tick).unwrap();
-        self.iterate(quote_tick.ts_init);
-
-        // Low
-        quote_tick.bid_price = bid_bar.low;
-        quote_tick.ask_price = ask_bar.low;
-        self.book.update_quote_tick(&quote_tick).unwrap();
-        self.iterate(quote_tick.ts_init);
+        // High and low, in the assumed intra-bar ordering (decided by the bid bar)
+        let high = (bid_bar.high, ask_bar.high);
+        let low = (bid_bar.low, ask_bar.low);
+        let extremes = if self.bar_execution_model.ordering.is_high_first(&bid_bar) {
+            [high, low]
+        } else {
+            [low, high]
+        };
+        for (bid_price, ask_price) in extremes {
+            quote_tick.bid_price = bid_price;
+            quote_tick.ask_price = ask_price;
+            self.book.update_quote_tick(&quote_tick).unwrap();
+            self.iterate(quote_tick.ts_init);
+        }
 
         // Close
         quote_tick.bid_price = bid_bar.close;
@@ -445,26 +608,112 @@ impl OrderMatchingEngine {
 
     pub fn process_status(&mut self, action: MarketStatusAction) {
         log::debug!("Processing {action}");
-
-        // Check if market is closed and market opens with trading or pre-open status
-        if self.market_status == MarketStatus::Closed
-            && (action == MarketStatusAction::Trading || action == MarketStatusAction::PreOpen)
-        {
-            self.market_status = MarketStatus::Open;
+        let was_trading = self.is_trading();
+
+        match action {
+            // Check if market opens into or moves through an auction
+            MarketStatusAction::PreOpen
+            | MarketStatusAction::PreCross
+            | MarketStatusAction::Cross
+            | MarketStatusAction::Rotation => {
+                if matches!(
+                    self.market_status,
+                    MarketStatus::Open | MarketStatus::Closed
+                ) {
+                    self.market_status = MarketStatus::Open;
+                    self.auction = Some(AuctionType::Opening);
+                }
+            }
+            // Check if market is open and the closing auction starts
+            MarketStatusAction::PreClose
+                if self.config.use_auctions && self.market_status == MarketStatus::Open =>
+            {
+                self.auction = Some(AuctionType::Closing);
+            }
+            // Check if continuous trading starts or resumes, uncrossing the opening auction
+            MarketStatusAction::Trading => {
+                if !was_trading {
+                    self.uncross_auction(AuctionType::Opening);
+                }
+                self.market_status = MarketStatus::Open;
+                self.auction = None;
+            }
+            // Check if market is open and market pauses
+            MarketStatusAction::Pause if self.market_status == MarketStatus::Open => {
+                self.market_status = MarketStatus::Paused;
+            }
+            // Check if market is open and market suspends
+            MarketStatusAction::Suspend if self.market_status == MarketStatus::Open => {
+                self.market_status = MarketStatus::Suspended;
+            }
+            // Check if market is open and we halt or close, uncrossing the closing auction
+            MarketStatusAction::Halt | MarketStatusAction::Close
+                if self.market_status == MarketStatus::Open =>
+            {
+                if action == MarketStatusAction::Close {
+                    self.uncross_auction(AuctionType::Closing);
+                }
+                self.market_status = MarketStatus::Closed;
+                self.auction = None;
+            }
+            MarketStatusAction::NotAvailableForTrading => {
+                self.market_status = MarketStatus::NotAvailable;
+            }
+            _ => {}
         }
-        // Check if market is open and market pauses
-        if self.market_status == MarketStatus::Open && action == MarketStatusAction::Pause {
-            self.market_status = MarketStatus::Paused;
+
+        if !was_trading && self.is_trading() {
+            self.release_parked_orders();
         }
-        // Check if market is open and market suspends
-        if self.market_status == MarketStatus::Open && action == MarketStatusAction::Suspend {
-            self.market_status = MarketStatus::Suspended;
+    }
+
+    /// Returns whether the market is open for continuous trading, i.e. not halted,
+    /// closed or in an auction.
+    #[must_use]
+    pub fn is_trading(&self) -> bool {
+        self.market_status == MarketStatus::Open && self.auction.is_none()
+    }
+
+    #[must_use]
+    pub const fn in_auction(&self) -> bool {
+        self.auction.is_some()
+    }
+
+    /// Returns the current auction (if any).
+    #[must_use]
+    pub const fn auction(&self) -> Option<AuctionType> {
+        self.auction
+    }
+
+    /// Returns the orders accepted into the given auction.
+    #[must_use]
+    pub fn auction_orders(&self, auction_type: AuctionType) -> &[AuctionOrder] {
+        match auction_type {
+            AuctionType::Opening => self.opening_auction.orders(),
+            AuctionType::Closing => self.closing_auction.orders(),
         }
-        // Check if market is open and we halt or close
-        if self.market_status == MarketStatus::Open
-            && (action == MarketStatusAction::Halt || action == MarketStatusAction::Close)
-        {
-            self.market_status = MarketStatus::Closed;
+    }
+
+    /// Returns the orders parked while the market is not trading.
+    #[must_use]
+    pub fn parked_orders(&self) -> Vec<&OrderAny> {
+        self.parked_orders.iter().map(|(order, _)| order).collect()
+    }
+
+    fn release_parked_orders(&mut self) {
+        let parked_orders = std::mem::take(&mut self.parked_orders);
+        for (order, account_id) in parked_orders {
+            let is_closed = self
+                .cache
+                .borrow()
+                .order(&order.client_order_id())
+                .is_some_and(OrderAny::is_closed);
+            if is_closed {
+                continue; // Closed while parked
+            }
+
+            log::info!("Releasing parked order {}", order.client_order_id());
+            self.process_order(&order, account_id);
         }
     }
 
@@ -472,18 +721,57 @@ impl OrderMatchingEngine {
 
     #[allow(clippy::needless_return)]
     pub fn process_order(&mut self, order: &OrderAny, account_id: AccountId) {
+        let mut reduce_only_qty: Option<Quantity> = None;
+
         // Enter the scope where you will borrow a cache
         {
             let cache_borrow = self.cache.as_ref().borrow();
 
             if self.core.order_exists(order.client_order_id()) {
-                self.generate_order_rejected(order, "Order already exists".into());
+                self.generate_order_rejected(
+                    order,
+                    "Order already exists".into(),
+                    VenueRejectReason::DuplicateOrder,
+                );
                 return;
             }
 
             // Index identifiers
             self.account_ids.insert(order.trader_id(), account_id);
 
+            // Check market is open for continuous trading, auction orders are accepted anytime
+            let auction_type = self.get_auction_type(order);
+            if !self.is_trading() && auction_type.is_none() {
+                match self.halt_behavior {
+                    HaltBehavior::Reject => {
+                        let state = if self.in_auction() {
+                            "in auction".to_string()
+                        } else {
+                            self.market_status.to_string().to_lowercase()
+                        };
+                        self.generate_order_rejected(
+                            order,
+                            format!(
+                                "Market for {} is {state}, order {} not accepted",
+                                self.instrument.id(),
+                                order.client_order_id(),
+                            )
+                            .into(),
+                            VenueRejectReason::MarketClosed,
+                        );
+                    }
+                    HaltBehavior::Park => {
+                        log::info!(
+                            "Parking order {} until {} market trading resumes",
+                            order.client_order_id(),
+                            self.instrument.id(),
+                        );
+                        self.parked_orders.push((order.clone(), account_id));
+                    }
+                }
+                return;
+            }
+
             // Check for instrument expiration or activation
             if EXPIRING_INSTRUMENT_TYPES.contains(&self.instrument.instrument_class()) {
                 if let Some(activation_ns) = self.instrument.activation_ns() {
@@ -496,6 +784,7 @@ impl OrderMatchingEngine {
                                 self.instrument.activation_ns().unwrap()
                             )
                             .into(),
+                            VenueRejectReason::MarketClosed,
                         );
                         return;
                     }
@@ -510,6 +799,7 @@ impl OrderMatchingEngine {
                                 self.instrument.expiration_ns().unwrap()
                             )
                             .into(),
+                            VenueRejectReason::MarketClosed,
                         );
                         return;
                     }
@@ -533,6 +823,7 @@ impl OrderMatchingEngine {
                             self.generate_order_rejected(
                                 order,
                                 format!("Rejected OTO order from {parent_order_id}").into(),
+                                VenueRejectReason::Unknown,
                             );
                             return;
                         } else if parent_order.status() == OrderStatus::Accepted
@@ -561,6 +852,7 @@ impl OrderMatchingEngine {
                                     order,
                                     format!("Contingent order {client_order_id} already closed")
                                         .into(),
+                                    VenueRejectReason::Unknown,
                                 );
                                 return;
                             }
@@ -571,6 +863,27 @@ impl OrderMatchingEngine {
                 }
             }
 
+            // Check session time in force can be expired
+            if matches!(
+                order.time_in_force(),
+                TimeInForce::AtTheOpen | TimeInForce::AtTheClose
+            ) && self.session_calendar.is_none()
+                && auction_type.is_none()
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "{} time in force not supported for order {}, no session calendar for {}",
+                        order.time_in_force(),
+                        order.client_order_id(),
+                        self.instrument.id(),
+                    )
+                    .into(),
+                    VenueRejectReason::Unknown,
+                );
+                return;
+            }
+
             // Check fo valid order quantity precision
             if order.quantity().precision != self.instrument.size_precision() {
                 self.generate_order_rejected(
@@ -583,10 +896,27 @@ impl OrderMatchingEngine {
                         self.instrument.size_precision()
                     )
                         .into(),
+                    VenueRejectReason::InvalidQuantity,
                 );
                 return;
             }
 
+            // Check order price is within venue price limits
+            if let Some(price) = order.price() {
+                if let Some(breach) = self.price_limits.check_price(&self.instrument.id(), price) {
+                    self.generate_order_rejected(
+                        order,
+                        format!(
+                            "Order price {price} for order {} was {breach}",
+                            order.client_order_id(),
+                        )
+                        .into(),
+                        VenueRejectReason::InvalidPrice,
+                    );
+                    return;
+                }
+            }
+
             // Check for valid order price precision
             if let Some(price) = order.price() {
                 if price.precision != self.instrument.price_precision() {
@@ -600,9 +930,10 @@ impl OrderMatchingEngine {
                             self.instrument.price_precision()
                         )
                             .into(),
+                        VenueRejectReason::InvalidPrice,
                     );
+                    return;
                 }
-                return;
             }
 
             // Check for valid order trigger price precision
@@ -618,6 +949,7 @@ impl OrderMatchingEngine {
                             self.instrument.price_precision()
                         )
                             .into(),
+                        VenueRejectReason::InvalidPrice,
                     );
                     return;
                 }
@@ -651,6 +983,31 @@ impl OrderMatchingEngine {
                         "Short selling not permitted on a CASH account with position {position_string} and order {order}",
                     )
                         .into(),
+                    VenueRejectReason::Unknown,
+                );
+                return;
+            }
+
+            // Check short-sale constraints
+            let net_position: f64 = cache_borrow
+                .positions_open(None, Some(&self.instrument.id()), None, None)
+                .iter()
+                .map(|position| position.signed_qty)
+                .sum();
+            if let Some(violation) = self.short_sale.check_order(
+                &self.instrument.id(),
+                order.order_side(),
+                order.quantity(),
+                net_position,
+            ) {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Short sale not permitted for order {}, {violation}",
+                        order.client_order_id(),
+                    )
+                    .into(),
+                    VenueRejectReason::Unknown,
                 );
                 return;
             }
@@ -674,25 +1031,324 @@ impl OrderMatchingEngine {
                         order.order_side().to_string().to_uppercase()
                     )
                     .into(),
+                    VenueRejectReason::ReduceOnlyWouldIncrease,
                 );
                 return;
             }
+
+            // Clamp reduce-only order quantity to the position size
+            if self.config.use_reduce_only && order.is_reduce_only() {
+                if let Some(pos) = position {
+                    if order.leaves_qty() > pos.quantity {
+                        reduce_only_qty = Some(pos.quantity);
+                    }
+                }
+            }
+        }
+
+        let mut order = order.clone();
+        let mut venue_order_id = None; // Assigned if accepted when amended on submission
+        if let Some(quantity) = reduce_only_qty {
+            log::info!(
+                "Reducing reduce-only order {} quantity from {} to position size {quantity}",
+                order.client_order_id(),
+                order.quantity(),
+            );
+            let (price, trigger_price) = (order.price(), order.trigger_price());
+            venue_order_id =
+                Some(self.amend_order(&mut order, venue_order_id, quantity, price, trigger_price));
+        }
+
+        if let Some(auction_type) = self.get_auction_type(&order) {
+            self.add_auction_order(&order, venue_order_id, auction_type);
+            return;
+        }
+
+        // Check for self-trades against resting orders of the same strategy
+        if let Some(mode) = self.self_trade_prevention {
+            if !self.prevent_self_trade(mode, &order) {
+                return;
+            }
+        }
+
+        // Check post-only instruction
+        if order.order_type() == OrderType::Limit
+            && order.is_post_only()
+            && self.would_take_liquidity(&order)
+        {
+            match self.post_only_behavior {
+                PostOnlyBehavior::Reject => {
+                    self.generate_order_rejected(
+                        &order,
+                        format!(
+                            "POST_ONLY {} {} order limit px of {} would have been a TAKER: bid={}, ask={}",
+                            order.order_type().to_string().to_uppercase(),
+                            order.order_side().to_string().to_uppercase(),
+                            order.price().unwrap(),
+                            self.core.bid.map_or("None".to_string(), |bid| bid.to_string()),
+                            self.core.ask.map_or("None".to_string(), |ask| ask.to_string()),
+                        )
+                        .into(),
+                        VenueRejectReason::PostOnlyWouldCross,
+                    );
+                    return;
+                }
+                PostOnlyBehavior::Reprice => {
+                    let price = post_only_reprice(
+                        order.order_side(),
+                        self.core.bid,
+                        self.core.ask,
+                        self.core.price_increment,
+                    );
+                    log::info!(
+                        "Repricing post-only order {} from {} to {price}",
+                        order.client_order_id(),
+                        order.price().unwrap(),
+                    );
+                    let quantity = order.quantity();
+                    venue_order_id = Some(self.amend_order(
+                        &mut order,
+                        venue_order_id,
+                        quantity,
+                        Some(price),
+                        None,
+                    ));
+                }
+            }
         }
 
         match order.order_type() {
-            OrderType::Market => self.process_market_order(order),
-            OrderType::Limit => self.process_limit_order(order),
-            OrderType::MarketToLimit => self.process_market_to_limit_order(order),
-            OrderType::StopMarket => self.process_stop_market_order(order),
-            OrderType::StopLimit => self.process_stop_limit_order(order),
-            OrderType::MarketIfTouched => self.process_market_if_touched_order(order),
-            OrderType::LimitIfTouched => self.process_limit_if_touched_order(order),
-            OrderType::TrailingStopMarket => self.process_trailing_stop_market_order(order),
-            OrderType::TrailingStopLimit => self.process_trailing_stop_limit_order(order),
+            OrderType::Market => self.process_market_order(&order, venue_order_id),
+            OrderType::Limit => self.process_limit_order(&order),
+            OrderType::MarketToLimit => self.process_market_to_limit_order(&order),
+            OrderType::StopMarket => self.process_stop_market_order(&order),
+            OrderType::StopLimit => self.process_stop_limit_order(&order),
+            OrderType::MarketIfTouched => self.process_market_if_touched_order(&order),
+            OrderType::LimitIfTouched => self.process_limit_if_touched_order(&order),
+            OrderType::TrailingStopMarket => self.process_trailing_stop_market_order(&order),
+            OrderType::TrailingStopLimit => self.process_trailing_stop_limit_order(&order),
         }
     }
 
-    fn process_market_order(&mut self, order: &OrderAny) {
+    /// Processes the given cancel `command` for the venue `account_id`, canceling the order if
+    /// it is open in the matching engine, otherwise generating an `OrderCancelRejected` event.
+    pub fn process_cancel(&mut self, command: &CancelOrder, account_id: AccountId) {
+        let order = self.cache.borrow().order(&command.client_order_id).cloned();
+        match order {
+            Some(order) if self.core.order_exists(command.client_order_id) => {
+                self.cancel_order(&order);
+            }
+            _ => self.generate_order_cancel_rejected(
+                command.trader_id,
+                command.strategy_id,
+                account_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+                format!("Order {} not found", command.client_order_id).into(),
+                VenueRejectReason::OrderNotFound,
+            ),
+        }
+    }
+
+    fn would_take_liquidity(&self, order: &OrderAny) -> bool {
+        let Some(price) = order.price() else {
+            return false;
+        };
+        match order.order_side() {
+            OrderSide::Buy => self.core.ask.is_some_and(|ask| price >= ask),
+            OrderSide::Sell => self.core.bid.is_some_and(|bid| price <= bid),
+            OrderSide::NoOrderSide => false,
+        }
+    }
+
+    /// Amends the order to the given values, generating an `OrderUpdated` event.
+    /// Amends the incoming `order`, first accepting it (unless already accepted with the
+    /// `venue_order_id`) as only accepted orders can be updated, returning the venue order ID.
+    fn amend_order(
+        &mut self,
+        order: &mut OrderAny,
+        venue_order_id: Option<VenueOrderId>,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> VenueOrderId {
+        let venue_order_id = match venue_order_id {
+            Some(venue_order_id) => venue_order_id,
+            None => {
+                let venue_order_id = self.generate_venue_order_id();
+                self.generate_order_accepted(order, venue_order_id);
+                venue_order_id
+            }
+        };
+        let event = self.generate_order_updated(
+            order,
+            Some(venue_order_id),
+            quantity,
+            price,
+            trigger_price,
+        );
+        order.update(&event);
+        venue_order_id
+    }
+
+    fn get_auction_type(&self, order: &OrderAny) -> Option<AuctionType> {
+        if !self.config.use_auctions
+            || !matches!(order.order_type(), OrderType::Market | OrderType::Limit)
+        {
+            return None;
+        }
+
+        match order.time_in_force() {
+            TimeInForce::AtTheOpen => Some(AuctionType::Opening),
+            TimeInForce::AtTheClose => Some(AuctionType::Closing),
+            _ => None,
+        }
+    }
+
+    fn add_auction_order(
+        &mut self,
+        order: &OrderAny,
+        venue_order_id: Option<VenueOrderId>,
+        auction_type: AuctionType,
+    ) {
+        // Already accepted if amended on submission
+        let venue_order_id = match venue_order_id {
+            Some(venue_order_id) => venue_order_id,
+            None => {
+                let venue_order_id = self.generate_venue_order_id();
+                self.generate_order_accepted(order, venue_order_id);
+                venue_order_id
+            }
+        };
+
+        log::info!(
+            "Accepted order {} into {auction_type} auction for {}",
+            order.client_order_id(),
+            self.instrument.id(),
+        );
+        match auction_type {
+            AuctionType::Opening => self.opening_auction.add(order.clone(), venue_order_id),
+            AuctionType::Closing => self.closing_auction.add(order.clone(), venue_order_id),
+        }
+    }
+
+    fn uncross_auction(&mut self, auction_type: AuctionType) {
+        let reference_price = self.core.last.or(self.core.bid);
+        let result = match auction_type {
+            AuctionType::Opening => self.opening_auction.uncross(reference_price),
+            AuctionType::Closing => self.closing_auction.uncross(reference_price),
+        };
+
+        if let Some(price) = result.price {
+            log::info!(
+                "Uncrossed {auction_type} auction for {} at {price}",
+                self.instrument.id()
+            );
+            self.core.set_last_raw(price);
+
+            let quote_currency = self.instrument.quote_currency();
+            for fill in result.fills {
+                let order = &fill.order.order;
+                let liquidity_side = match order.order_type() {
+                    OrderType::Limit => LiquiditySide::Maker,
+                    _ => LiquiditySide::Taker,
+                };
+                let venue_position_id = self.get_position_id(order, None);
+                self.generate_order_filled(
+                    order,
+                    fill.order.venue_order_id,
+                    venue_position_id,
+                    fill.quantity,
+                    price,
+                    quote_currency,
+                    Money::new(0.0, quote_currency), // TBD (commission from the fee model)
+                    liquidity_side,
+                );
+            }
+        }
+
+        // Session orders expire with the auction
+        for entry in result.unfilled {
+            self.generate_order_expired(&entry.order, Some(entry.venue_order_id));
+        }
+    }
+
+    /// Applies self-trade prevention for the incoming `order` against the resting orders,
+    /// returning whether the incoming order should continue to be processed.
+    ///
+    /// As an order cannot be amended before it is accepted, an incoming order which is only
+    /// partly offset under [`SelfTradePreventionMode::Decrement`] continues with its original
+    /// quantity, the overlapping resting quantity having been removed.
+    fn prevent_self_trade(&mut self, mode: SelfTradePreventionMode, order: &OrderAny) -> bool {
+        let resting: Vec<OrderAny> = {
+            let cache = self.cache.borrow();
+            self.core
+                .get_orders_bid()
+                .iter()
+                .chain(self.core.get_orders_ask())
+                .filter_map(|resting| cache.order(&resting.client_order_id()).cloned())
+                .collect()
+        };
+        let Some(resolution) = resolve_self_trade(mode, order, &resting.iter().collect::<Vec<_>>())
+        else {
+            return true;
+        };
+
+        log::info!(
+            "Self-trade prevented for order {} against {:?} ({mode:?})",
+            order.client_order_id(),
+            resolution.resting_order_ids(),
+        );
+
+        for resting_order in &resting {
+            let client_order_id = resting_order.client_order_id();
+            if resolution.canceled.contains(&client_order_id) {
+                self.remove_resting_order(&client_order_id);
+                let venue_order_id = resting_order
+                    .venue_order_id()
+                    .unwrap_or_else(|| self.generate_venue_order_id());
+                self.generate_order_canceled(resting_order, venue_order_id);
+            } else if let Some((_, quantity)) = resolution
+                .decremented
+                .iter()
+                .find(|(id, _)| *id == client_order_id)
+            {
+                self.generate_order_updated(
+                    resting_order,
+                    resting_order.venue_order_id(),
+                    *quantity,
+                    resting_order.price(),
+                    resting_order.trigger_price(),
+                );
+            }
+        }
+
+        if resolution.cancel_incoming {
+            let venue_order_id = self.generate_venue_order_id();
+            self.generate_order_canceled(order, venue_order_id);
+            return false;
+        }
+
+        true
+    }
+
+    fn remove_resting_order(&mut self, client_order_id: &ClientOrderId) {
+        let resting = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .find(|order| order.client_order_id() == *client_order_id)
+            .cloned();
+        if let Some(resting) = resting {
+            if let Err(e) = self.core.delete_order(&resting) {
+                log::error!("Cannot remove resting order {client_order_id}: {e}");
+            }
+        }
+    }
+
+    fn process_market_order(&mut self, order: &OrderAny, venue_order_id: Option<VenueOrderId>) {
         // Check if market exists
         let order_side = order.order_side();
         let is_ask_initialized = self.core.is_ask_initialized;
@@ -700,9 +1356,16 @@ impl OrderMatchingEngine {
         if (order.order_side() == OrderSide::Buy && !self.core.is_ask_initialized)
             || (order.order_side() == OrderSide::Sell && !self.core.is_bid_initialized)
         {
+            // An order accepted when amended on submission can no longer be rejected
+            if let Some(venue_order_id) = venue_order_id {
+                log::warn!("No market for {}", order.instrument_id());
+                self.generate_order_canceled(order, venue_order_id);
+                return;
+            }
             self.generate_order_rejected(
                 order,
                 format!("No market for {}", order.instrument_id()).into(),
+                VenueRejectReason::Unknown,
             );
             return;
         }
@@ -775,13 +1438,12 @@ impl OrderMatchingEngine {
             };
 
             // Check expiration
-            if self.config.support_gtd_orders {
-                if let Some(expire_time) = order.expire_time() {
-                    if timestamp_ns >= expire_time {
-                        // SAFTEY: We know this order is in the core
-                        self.core.delete_order(order).unwrap();
-                        self.expire_order(order);
-                    }
+            if let Some(expire_time) = self.get_expire_time(order) {
+                if timestamp_ns >= expire_time {
+                    // SAFTEY: We know this order is in the core
+                    self.core.delete_order(order).unwrap();
+                    self.expire_order(order);
+                    continue;
                 }
             }
 
@@ -810,8 +1472,23 @@ impl OrderMatchingEngine {
         todo!("determine_limit_price_and_volume")
     }
 
-    fn determine_market_price_and_volume(&self, order: &OrderAny) {
-        todo!("determine_market_price_and_volume")
+    fn determine_market_price_and_volume(&mut self, order: &OrderAny) -> Vec<(Price, Quantity)> {
+        let quantity = order.leaves_qty();
+        if let Some(model) = self.hidden_liquidity_model.as_mut() {
+            return model.simulate_market_fills(
+                &self.book,
+                order.order_side(),
+                quantity,
+                self.instrument.price_increment(),
+            );
+        }
+
+        let price = match order.order_side() {
+            OrderSide::Buy => Price::max(self.instrument.price_precision()),
+            _ => Price::min(self.instrument.price_precision()),
+        };
+        let book_order = BookOrder::new(order.order_side(), price, quantity, 0);
+        self.book.simulate_fills(&book_order)
     }
 
     fn fill_market_order(&mut self, order: &OrderAny) {
@@ -865,6 +1542,16 @@ impl OrderMatchingEngine {
         TradeId::from(trade_id.as_str())
     }
 
+    fn generate_venue_order_id(&mut self) -> VenueOrderId {
+        self.order_count += 1;
+        let venue_order_id = if self.config.use_random_ids {
+            Uuid::new_v4().to_string()
+        } else {
+            format!("{}-{}-{}", self.venue, self.raw_id, self.order_count)
+        };
+        VenueOrderId::from(venue_order_id.as_str())
+    }
+
     fn get_position_id(&mut self, order: &OrderAny, generate: Option<bool>) -> Option<PositionId> {
         let generate = generate.unwrap_or(true);
         if self.oms_type == OmsType::Hedging {
@@ -914,12 +1601,34 @@ impl OrderMatchingEngine {
         todo!("accept_order")
     }
 
+    fn get_expire_time(&self, order: &PassiveOrderAny) -> Option<UnixNanos> {
+        let time_in_force = order.time_in_force();
+        if time_in_force == TimeInForce::Gtd && !self.config.support_gtd_orders {
+            return None;
+        }
+        order_expire_time(
+            time_in_force,
+            order.expire_time(),
+            order.ts_init(),
+            self.session_calendar.as_ref(),
+        )
+    }
+
     fn expire_order(&mut self, order: &PassiveOrderAny) {
-        todo!("expire_order")
+        let client_order_id = order.client_order_id();
+        let order = self.cache.borrow().order(&client_order_id).cloned();
+        match order {
+            Some(order) => self.generate_order_expired(&order, None),
+            None => log::error!("Cannot expire order {client_order_id}: not found in cache"),
+        }
     }
 
     fn cancel_order(&mut self, order: &OrderAny) {
-        todo!("cancel_order")
+        self.remove_resting_order(&order.client_order_id());
+        let venue_order_id = order
+            .venue_order_id()
+            .unwrap_or_else(|| self.generate_venue_order_id());
+        self.generate_order_canceled(order, venue_order_id);
     }
 
     fn update_order(&mut self, order: &OrderAny) {
@@ -940,7 +1649,12 @@ impl OrderMatchingEngine {
 
     // -- EVENT GENERATORS -----------------------------------------------------
 
-    fn generate_order_rejected(&self, order: &OrderAny, reason: Ustr) {
+    fn generate_order_rejected(
+        &self,
+        order: &OrderAny,
+        reason: Ustr,
+        reject_reason: VenueRejectReason,
+    ) {
         let ts_now = self.clock.get_time_ns();
         let account_id = order
             .account_id()
@@ -957,6 +1671,7 @@ impl OrderMatchingEngine {
             ts_now,
             ts_now,
             false,
+            reject_reason,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
@@ -993,6 +1708,7 @@ impl OrderMatchingEngine {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
         reason: Ustr,
+        reject_reason: VenueRejectReason,
     ) {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::ModifyRejected(OrderModifyRejected::new(
@@ -1007,6 +1723,7 @@ impl OrderMatchingEngine {
             false,
             Some(venue_order_id),
             Some(account_id),
+            reject_reason,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
@@ -1022,6 +1739,7 @@ impl OrderMatchingEngine {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
         reason: Ustr,
+        reject_reason: VenueRejectReason,
     ) {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::CancelRejected(OrderCancelRejected::new(
@@ -1036,6 +1754,7 @@ impl OrderMatchingEngine {
             false,
             Some(venue_order_id),
             Some(account_id),
+            reject_reason,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
@@ -1044,12 +1763,13 @@ impl OrderMatchingEngine {
     fn generate_order_updated(
         &self,
         order: &OrderAny,
+        venue_order_id: Option<VenueOrderId>,
         quantity: Quantity,
-        price: Price,
-        trigger_price: Price,
-    ) {
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> OrderUpdated {
         let ts_now = self.clock.get_time_ns();
-        let event = OrderEventAny::Updated(OrderUpdated::new(
+        let updated = OrderUpdated::new(
             order.trader_id(),
             order.strategy_id(),
             order.instrument_id(),
@@ -1059,13 +1779,15 @@ impl OrderMatchingEngine {
             ts_now,
             ts_now,
             false,
-            order.venue_order_id(),
+            venue_order_id,
             order.account_id(),
-            Some(price),
-            Some(trigger_price),
-        ));
+            price,
+            trigger_price,
+        );
+        let event = OrderEventAny::Updated(updated);
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        updated
     }
 
     fn generate_order_canceled(&self, order: &OrderAny, venue_order_id: VenueOrderId) {
@@ -1104,8 +1826,11 @@ impl OrderMatchingEngine {
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 
-    fn generate_order_expired(&self, order: &OrderAny) {
+    fn generate_order_expired(&self, order: &OrderAny, venue_order_id: Option<VenueOrderId>) {
         let ts_now = self.clock.get_time_ns();
+        let account_id = order
+            .account_id()
+            .or_else(|| self.account_ids.get(&order.trader_id()).copied());
         let event = OrderEventAny::Expired(OrderExpired::new(
             order.trader_id(),
             order.strategy_id(),
@@ -1115,8 +1840,8 @@ impl OrderMatchingEngine {
             ts_now,
             ts_now,
             false,
-            order.venue_order_id(),
-            order.account_id(),
+            venue_order_id.or(order.venue_order_id()),
+            account_id,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
@@ -1127,7 +1852,7 @@ impl OrderMatchingEngine {
         &mut self,
         order: &OrderAny,
         venue_order_id: VenueOrderId,
-        venue_position_id: PositionId,
+        venue_position_id: Option<PositionId>,
         last_qty: Quantity,
         last_px: Price,
         quote_currency: Currency,
@@ -1156,10 +1881,25 @@ impl OrderMatchingEngine {
             ts_now,
             ts_now,
             false,
-            Some(venue_position_id),
+            venue_position_id,
             Some(commission),
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 }
+
+/// Returns the price for a post-only order on the given `side` repriced one tick inside the
+/// opposite side of the book, so the order would rest as a maker.
+fn post_only_reprice(
+    side: OrderSide,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    price_increment: Price,
+) -> Price {
+    match side {
+        OrderSide::Buy => ask.expect("Ask must be initialized") - price_increment,
+        OrderSide::Sell => bid.expect("Bid must be initialized") + price_increment,
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide`, was {side}"),
+    }
+}
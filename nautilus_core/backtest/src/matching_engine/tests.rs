@@ -25,13 +25,17 @@ use nautilus_common::{
         stubs::{get_message_saving_handler, get_saved_messages},
         MessageBus,
     },
+    price_limits::PriceBand,
+    session::{SessionCalendar, TradingSession},
+    short_sale::ShortSaleConstraints,
 };
 use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::{messages::CancelOrder, self_trade::SelfTradePreventionMode};
 use nautilus_model::{
     data::{delta::OrderBookDelta, order::BookOrder},
     enums::{
-        AccountType, BookAction, BookType, ContingencyType, LiquiditySide, OmsType, OrderSide,
-        OrderType,
+        AccountType, BookAction, BookType, ContingencyType, LiquiditySide, MarketStatus,
+        MarketStatusAction, OmsType, OrderSide, OrderType, TimeInForce, VenueRejectReason,
     },
     events::order::{
         rejected::OrderRejectedBuilder, OrderEventAny, OrderEventType, OrderFilled, OrderRejected,
@@ -43,7 +47,11 @@ use nautilus_model::{
         equity::Equity,
         stubs::{crypto_perpetual_ethusdt, equity_aapl, futures_contract_es},
     },
-    orders::{any::OrderAny, builder::OrderTestBuilder, stubs::TestOrderStubs},
+    orders::{
+        any::{OrderAny, PassiveOrderAny},
+        builder::OrderTestBuilder,
+        stubs::TestOrderStubs,
+    },
     position::Position,
     types::{price::Price, quantity::Quantity},
 };
@@ -51,8 +59,15 @@ use rstest::{fixture, rstest};
 use ustr::Ustr;
 
 use crate::{
-    matching_engine::{config::OrderMatchingEngineConfig, OrderMatchingEngine},
-    models::fill::FillModel,
+    matching_engine::{
+        auction::AuctionType,
+        config::{HaltBehavior, OrderMatchingEngineConfig, PostOnlyBehavior},
+        post_only_reprice, OrderMatchingEngine,
+    },
+    models::{
+        fill::FillModel,
+        hidden_liquidity::{HiddenLiquidityModel, HiddenSizeDistribution},
+    },
 };
 
 static ATOMIC_TIME: LazyLock<AtomicTime> =
@@ -160,6 +175,7 @@ fn engine_config() -> OrderMatchingEngineConfig {
         use_position_ids: false,
         use_random_ids: false,
         use_reduce_only: true,
+        use_auctions: false,
     }
 }
 // -- HELPERS ---------------------------------------------------------------------------
@@ -214,6 +230,19 @@ fn get_order_event_handler_messages(event_handler: ShareableMessageHandler) -> V
     get_saved_messages::<OrderEventAny>(event_handler)
 }
 
+fn add_passive_order(engine: &mut OrderMatchingEngine, order: &OrderAny) {
+    engine
+        .cache
+        .as_ref()
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    engine
+        .core
+        .add_order(PassiveOrderAny::from(order.clone()))
+        .unwrap();
+}
+
 // -- TESTS -----------------------------------------------------------------------------------
 
 #[rstest]
@@ -330,6 +359,10 @@ fn test_process_order_when_invalid_quantity_precision(
         first_message.message().unwrap(),
         Ustr::from("Invalid order quantity precision for order O-19700101-000000-001-001-1, was 0 when ETHUSDT-PERP.BINANCE size precision is 3")
     );
+    assert_eq!(
+        first_message.reject_reason(),
+        Some(VenueRejectReason::InvalidQuantity)
+    );
 }
 
 #[rstest]
@@ -373,6 +406,62 @@ fn test_process_order_when_invalid_price_precision(
         first_message.message().unwrap(),
         Ustr::from("Invalid order price precision for order O-19700101-000000-001-001-1, was 5 when ESZ21.GLBX price precision is 2")
     );
+    assert_eq!(
+        first_message.reject_reason(),
+        Some(VenueRejectReason::InvalidPrice)
+    );
+}
+
+#[rstest]
+fn test_process_order_when_price_outside_price_band(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    // Create engine with a venue published price band
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.update_price_band(PriceBand::new(
+        instrument_es.id(),
+        Some(Price::from("4490.00")),
+        Some(Price::from("4510.00")),
+        UnixNanos::default(),
+    ));
+
+    let limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("4520.00")) // <- above the upper price limit
+        .quantity(Quantity::from("1"))
+        .build();
+
+    engine.process_order(&limit_order, account_id);
+
+    // Get messages and test
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        first_message.message().unwrap(),
+        Ustr::from("Order price 4520.00 for order O-19700101-000000-001-001-1 was above upper price limit 4510.00")
+    );
+    assert_eq!(
+        first_message.reject_reason(),
+        Some(VenueRejectReason::InvalidPrice)
+    );
 }
 
 #[rstest]
@@ -455,6 +544,65 @@ fn test_process_order_when_shorting_equity_without_margin_account(
     );
 }
 
+#[rstest]
+#[case(
+    true,
+    None,
+    None,
+    "Short sale not permitted for order O-19700101-000000-001-001-1, hard to borrow"
+)]
+#[case(false, Some("50"), None, "Short sale not permitted for order O-19700101-000000-001-001-1, short quantity 100 exceeds max shortable 50")]
+#[case(false, None, Some("60"), "Short sale not permitted for order O-19700101-000000-001-001-1, short quantity 100 exceeds located 60")]
+fn test_process_order_when_violating_short_sale_constraints(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    equity_aapl: Equity,
+    #[case] hard_to_borrow: bool,
+    #[case] max_shortable: Option<&str>,
+    #[case] located: Option<&str>,
+    #[case] expected: &str,
+) {
+    let instrument = InstrumentAny::Equity(equity_aapl);
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    // Create engine with short-sale constraints on a margin account
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        Some(AccountType::Margin),
+        None,
+    );
+    engine.set_short_sale_constraints(ShortSaleConstraints::new(
+        instrument.id(),
+        hard_to_borrow,
+        max_shortable.map(Quantity::from),
+        located.is_some(),
+    ));
+    if let Some(located) = located {
+        engine.set_located(Quantity::from(located));
+    }
+
+    let sell_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from("100"))
+        .build();
+
+    engine.process_order(&sell_order, AccountId::from("SIM-001"));
+
+    // Get messages and test
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert_eq!(first_message.message().unwrap(), Ustr::from(expected));
+}
+
 #[rstest]
 fn test_process_order_when_invalid_reduce_only(
     mut msgbus: MessageBus,
@@ -495,6 +643,166 @@ fn test_process_order_when_invalid_reduce_only(
         first_message.message().unwrap(),
         Ustr::from("Reduce-only order O-19700101-000000-001-001-1 (MARKET-BUY) would have increased position")
     );
+    assert_eq!(
+        first_message.reject_reason(),
+        Some(VenueRejectReason::ReduceOnlyWouldIncrease)
+    );
+}
+
+#[rstest]
+fn test_process_order_when_reduce_only_exceeds_position_clamps_quantity(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    market_order_fill: OrderFilled,
+    engine_config: OrderMatchingEngineConfig,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let fill = OrderFilled {
+        last_qty: Quantity::from("1.000"),
+        position_id: Some(PositionId::new("ETHUSDT-PERP.BINANCE-S-001")),
+        ..market_order_fill
+    };
+    let position = Position::new(&instrument_eth_usdt, fill);
+    cache
+        .borrow_mut()
+        .add_position(position, OmsType::Netting)
+        .unwrap();
+
+    // Rest the order in the opening auction, so the clamped order is held by the engine
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache),
+        None,
+        Some(OrderMatchingEngineConfig {
+            use_auctions: true,
+            ..engine_config
+        }),
+    );
+    let market_order_reduce = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from("2.000"))
+        .time_in_force(TimeInForce::AtTheOpen)
+        .reduce_only(true)
+        .build();
+
+    engine.process_order(&market_order_reduce, account_id);
+
+    // The order is accepted before it is updated, and only accepted once
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let OrderEventAny::Accepted(accepted) = saved_messages[0] else {
+        panic!("Expected `OrderAccepted`, was {:?}", saved_messages[0]);
+    };
+    let OrderEventAny::Updated(updated) = saved_messages[1] else {
+        panic!("Expected `OrderUpdated`, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(updated.quantity, Quantity::from("1.000"));
+    assert_eq!(updated.venue_order_id, Some(accepted.venue_order_id));
+
+    let auction_orders = engine.auction_orders(AuctionType::Opening);
+    assert_eq!(auction_orders.len(), 1);
+    assert_eq!(auction_orders[0].order.quantity(), Quantity::from("1.000"));
+    assert_eq!(auction_orders[0].venue_order_id, accepted.venue_order_id);
+}
+
+#[rstest]
+fn test_process_order_when_post_only_would_take_liquidity(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    assert_eq!(engine_l2.post_only_behavior(), PostOnlyBehavior::Reject);
+
+    let orderbook_delta_buy = OrderBookDelta::new(
+        instrument_es.id(),
+        BookAction::Add,
+        BookOrder::new(
+            OrderSide::Buy,
+            Price::from("4500.00"),
+            Quantity::from("1"),
+            0,
+        ),
+        0,
+        0,
+        UnixNanos::from(0),
+        UnixNanos::from(0),
+    );
+    let orderbook_delta_sell = OrderBookDelta::new(
+        instrument_es.id(),
+        BookAction::Add,
+        BookOrder::new(
+            OrderSide::Sell,
+            Price::from("4500.25"),
+            Quantity::from("1"),
+            1,
+        ),
+        0,
+        1,
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+    engine_l2.process_order_book_delta(&orderbook_delta_buy);
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("4500.25"))
+        .quantity(Quantity::from("1"))
+        .post_only(true)
+        .build();
+
+    engine_l2.process_order(&limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        first_message.message().unwrap(),
+        Ustr::from(
+            "POST_ONLY LIMIT BUY order limit px of 4500.25 would have been a TAKER: bid=4500.00, ask=4500.25"
+        )
+    );
+    assert_eq!(
+        first_message.reject_reason(),
+        Some(VenueRejectReason::PostOnlyWouldCross)
+    );
+}
+
+#[rstest]
+#[case(OrderSide::Buy, "4500.00")]
+#[case(OrderSide::Sell, "4500.25")]
+fn test_post_only_reprice(#[case] side: OrderSide, #[case] expected: &str) {
+    let price = post_only_reprice(
+        side,
+        Some(Price::from("4500.00")),
+        Some(Price::from("4500.25")),
+        Price::from("0.25"),
+    );
+    assert_eq!(price, Price::from(expected));
 }
 
 #[rstest]
@@ -695,6 +1003,99 @@ fn test_process_market_order_no_market_rejected(
     );
 }
 
+#[rstest]
+#[case(MarketStatusAction::Halt, "closed")]
+#[case(MarketStatusAction::Pause, "paused")]
+#[case(MarketStatusAction::PreOpen, "in auction")]
+fn test_process_order_when_market_not_trading_rejected(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    market_order_buy: OrderAny,
+    #[case] action: MarketStatusAction,
+    #[case] state: &str,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    // Create engine and halt trading
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.process_status(action);
+    assert!(!engine.is_trading());
+
+    engine.process_order(&market_order_buy, account_id);
+
+    // Get messages and test
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        first_message.message().unwrap(),
+        Ustr::from(&format!(
+            "Market for ETHUSDT-PERP.BINANCE is {state}, order O-19700101-000000-001-001-1 not accepted"
+        ))
+    );
+}
+
+#[rstest]
+fn test_process_order_when_market_halted_parks_until_trading(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    market_order_buy: OrderAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    // Create engine which parks orders and halt trading
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.set_halt_behavior(HaltBehavior::Park);
+    engine.process_status(MarketStatusAction::Halt);
+
+    engine.process_order(&market_order_buy, account_id);
+
+    assert_eq!(engine.parked_orders().len(), 1);
+    assert!(get_order_event_handler_messages(order_event_handler.clone()).is_empty());
+
+    // Order is only processed once continuous trading resumes
+    engine.process_status(MarketStatusAction::PreOpen);
+    assert_eq!(engine.parked_orders().len(), 1);
+
+    engine.process_status(MarketStatusAction::Trading);
+
+    assert!(engine.is_trading());
+    assert!(engine.parked_orders().is_empty());
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert!(first_message
+        .message()
+        .unwrap()
+        .starts_with("Invalid order quantity precision")); // <- processed normally
+}
+
 #[rstest]
 fn test_matching_core_bid_ask_initialized(
     msgbus: MessageBus,
@@ -744,6 +1145,128 @@ fn test_matching_core_bid_ask_initialized(
     assert!(engine_l2.core.is_ask_initialized);
 }
 
+#[rstest]
+#[case(None, vec![("4500.50", "5")])]
+#[case(Some(HiddenSizeDistribution::Fixed(1.0)), vec![("4500.50", "8")])]
+fn test_market_fills_with_hidden_liquidity(
+    instrument_es: InstrumentAny,
+    #[case] size_distribution: Option<HiddenSizeDistribution>,
+    #[case] expected: Vec<(&str, &str)>,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(MessageBus::default())),
+        None,
+        None,
+        None,
+    );
+    for (side, price, order_id) in [
+        (OrderSide::Buy, "4500.00", 0),
+        (OrderSide::Sell, "4500.50", 1),
+    ] {
+        engine_l2.process_order_book_delta(&OrderBookDelta::new(
+            instrument_es.id(),
+            BookAction::Add,
+            BookOrder::new(side, Price::from(price), Quantity::from("5"), order_id),
+            0,
+            order_id,
+            UnixNanos::from(order_id),
+            UnixNanos::from(order_id),
+        ));
+    }
+    engine_l2.set_hidden_liquidity_model(
+        size_distribution.map(|distribution| {
+            HiddenLiquidityModel::new(1.0, 0.0, distribution, Some(42)).unwrap()
+        }),
+    );
+
+    let market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("8"))
+        .build();
+    let fills = engine_l2.determine_market_price_and_volume(&market_order);
+
+    let expected: Vec<(Price, Quantity)> = expected
+        .into_iter()
+        .map(|(price, quantity)| (Price::from(price), Quantity::from(quantity)))
+        .collect();
+    assert_eq!(fills, expected);
+}
+
+#[rstest]
+#[case(SelfTradePreventionMode::CancelNewest, vec![(OrderEventType::Canceled, "O-2")])]
+#[case(
+    SelfTradePreventionMode::CancelOldest,
+    vec![(OrderEventType::Canceled, "O-1"), (OrderEventType::Rejected, "O-2")],
+)]
+#[case(
+    SelfTradePreventionMode::CancelBoth,
+    vec![(OrderEventType::Canceled, "O-1"), (OrderEventType::Canceled, "O-2")],
+)]
+#[case(
+    SelfTradePreventionMode::Decrement,
+    vec![(OrderEventType::Updated, "O-1"), (OrderEventType::Canceled, "O-2")],
+)]
+fn test_process_order_with_self_trade_prevention(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+    #[case] mode: SelfTradePreventionMode,
+    #[case] expected: Vec<(OrderEventType, &str)>,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.set_self_trade_prevention(Some(mode));
+
+    let resting_order = TestOrderStubs::make_accepted_order(
+        &OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_es.id())
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Sell)
+            .price(Price::from("4500.00"))
+            .quantity(Quantity::from("5"))
+            .build(),
+    );
+    add_passive_order(&mut engine, &resting_order);
+    let incoming_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_es.id())
+        .client_order_id(ClientOrderId::from("O-2"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("3"))
+        .build();
+
+    engine.process_order(&incoming_order, account_id);
+
+    let events: Vec<(OrderEventType, ClientOrderId)> =
+        get_order_event_handler_messages(order_event_handler)
+            .iter()
+            .map(|event| (event.event_type(), event.client_order_id()))
+            .collect();
+    let expected: Vec<(OrderEventType, ClientOrderId)> = expected
+        .into_iter()
+        .map(|(event_type, client_order_id)| (event_type, ClientOrderId::from(client_order_id)))
+        .collect();
+    assert_eq!(events, expected);
+    assert_eq!(
+        engine.order_exists(resting_order.client_order_id()),
+        matches!(
+            mode,
+            SelfTradePreventionMode::CancelNewest | SelfTradePreventionMode::Decrement
+        )
+    );
+}
+
 #[rstest]
 fn test_generate_venue_position_id(
     order_event_handler: ShareableMessageHandler,
@@ -895,3 +1418,366 @@ fn test_get_position_id_netting(
     let position_id = engine.get_position_id(&market_order_buy, None);
     assert_eq!(position_id, Some(position.id));
 }
+
+#[rstest]
+fn test_process_order_when_session_time_in_force_without_calendar(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("4500.00"))
+        .quantity(Quantity::from("1"))
+        .time_in_force(TimeInForce::AtTheOpen)
+        .build();
+
+    engine.process_order(&limit_order, account_id);
+
+    // Get messages and test
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        first_message.message().unwrap(),
+        Ustr::from("AT_THE_OPEN time in force not supported for order O-19700101-000000-001-001-1, no session calendar for ESZ21.GLBX")
+    );
+}
+
+#[rstest]
+fn test_iterate_expires_gtd_order(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    instrument_es: InstrumentAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(OrderMatchingEngineConfig {
+            support_gtd_orders: true,
+            ..OrderMatchingEngineConfig::default()
+        }),
+    );
+
+    let limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("4500.00"))
+        .quantity(Quantity::from("1"))
+        .time_in_force(TimeInForce::Gtd)
+        .expire_time(UnixNanos::from(1_000))
+        .build();
+    add_passive_order(&mut engine, &limit_order);
+
+    engine.iterate(UnixNanos::from(999));
+    assert!(get_order_event_handler_messages(order_event_handler.clone()).is_empty());
+
+    engine.iterate(UnixNanos::from(1_000));
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Expired);
+    assert_eq!(
+        first_message.client_order_id(),
+        limit_order.client_order_id()
+    );
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_process_cancel(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("4500.00"))
+        .quantity(Quantity::from("1"))
+        .build();
+    add_passive_order(&mut engine, &limit_order);
+
+    let cancel = |client_order_id: ClientOrderId| CancelOrder {
+        instrument_id: instrument_es.id(),
+        client_order_id,
+        ..CancelOrder::default()
+    };
+    engine.process_cancel(&cancel(limit_order.client_order_id()), account_id);
+    engine.process_cancel(&cancel(ClientOrderId::from("O-UNKNOWN")), account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Canceled);
+    assert_eq!(
+        saved_messages[0].client_order_id(),
+        limit_order.client_order_id()
+    );
+    assert_eq!(
+        saved_messages[1].event_type(),
+        OrderEventType::CancelRejected
+    );
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_iterate_expires_at_the_close_order_at_session_close(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    instrument_es: InstrumentAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    // Regular session 14:30 to 21:00 UTC, the first close is 1970-01-01T21:00:00Z
+    engine.set_session_calendar(Some(SessionCalendar::weekdays(
+        TradingSession::new(14 * 3600 + 30 * 60, 21 * 3600),
+        0,
+    )));
+    let session_close = UnixNanos::from(21 * 3600 * 1_000_000_000);
+
+    let limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("4500.00"))
+        .quantity(Quantity::from("1"))
+        .time_in_force(TimeInForce::AtTheClose)
+        .build();
+    add_passive_order(&mut engine, &limit_order);
+
+    engine.iterate(UnixNanos::from(session_close.as_u64() - 1));
+    assert!(get_order_event_handler_messages(order_event_handler.clone()).is_empty());
+
+    engine.iterate(session_close);
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(
+        saved_messages.first().unwrap().event_type(),
+        OrderEventType::Expired
+    );
+}
+
+fn auction_order(
+    client_order_id: &str,
+    side: OrderSide,
+    quantity: &str,
+    price: Option<&str>,
+    time_in_force: TimeInForce,
+) -> OrderAny {
+    let order_type = if price.is_some() {
+        OrderType::Limit
+    } else {
+        OrderType::Market
+    };
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id("AAPL.XNAS".into())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(side)
+        .quantity(Quantity::from(quantity))
+        .time_in_force(time_in_force);
+    if let Some(price) = price {
+        builder.price(Price::from(price));
+    }
+    builder.build()
+}
+
+fn get_auction_matching_engine(
+    msgbus: MessageBus,
+    order_event_handler: &ShareableMessageHandler,
+    equity_aapl: Equity,
+) -> OrderMatchingEngine {
+    let mut msgbus = msgbus;
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    get_order_matching_engine(
+        InstrumentAny::Equity(equity_aapl),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        Some(AccountType::Margin),
+        Some(OrderMatchingEngineConfig {
+            use_auctions: true,
+            ..OrderMatchingEngineConfig::default()
+        }),
+    )
+}
+
+fn fill_summary(events: &[OrderEventAny]) -> Vec<(String, Quantity, Price)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(fill) => Some((
+                fill.client_order_id.to_string(),
+                fill.last_qty,
+                fill.last_px,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[rstest]
+fn test_opening_auction_uncrosses_at_single_price(
+    msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    let mut engine = get_auction_matching_engine(msgbus, &order_event_handler, equity_aapl);
+    engine.process_status(MarketStatusAction::PreOpen);
+    assert_eq!(engine.auction(), Some(AuctionType::Opening));
+
+    for order in [
+        auction_order(
+            "B1",
+            OrderSide::Buy,
+            "100",
+            Some("150.10"),
+            TimeInForce::AtTheOpen,
+        ),
+        auction_order("B2", OrderSide::Buy, "50", None, TimeInForce::AtTheOpen),
+        auction_order(
+            "S1",
+            OrderSide::Sell,
+            "120",
+            Some("150.00"),
+            TimeInForce::AtTheOpen,
+        ),
+    ] {
+        engine.process_order(&order, account_id);
+    }
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler.clone());
+    assert_eq!(saved_messages.len(), 3);
+    assert!(saved_messages
+        .iter()
+        .all(|event| event.event_type() == OrderEventType::Accepted));
+    assert_eq!(engine.auction_orders(AuctionType::Opening).len(), 3);
+
+    engine.process_status(MarketStatusAction::Trading);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(
+        fill_summary(&saved_messages),
+        vec![
+            (
+                "B2".to_string(),
+                Quantity::from("50"),
+                Price::from("150.00")
+            ),
+            (
+                "B1".to_string(),
+                Quantity::from("70"),
+                Price::from("150.00")
+            ),
+            (
+                "S1".to_string(),
+                Quantity::from("120"),
+                Price::from("150.00")
+            ),
+        ]
+    );
+    // Remainder of the partially filled order expires with the auction
+    let last = saved_messages.last().unwrap();
+    assert_eq!(last.event_type(), OrderEventType::Expired);
+    assert_eq!(last.client_order_id(), ClientOrderId::from("B1"));
+    assert!(engine.auction_orders(AuctionType::Opening).is_empty());
+    assert!(engine.is_trading());
+}
+
+#[rstest]
+fn test_closing_auction_accepts_orders_during_continuous_trading(
+    msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    let mut engine = get_auction_matching_engine(msgbus, &order_event_handler, equity_aapl);
+
+    // Market-on-close accepted while continuously trading
+    let moc = auction_order("B1", OrderSide::Buy, "100", None, TimeInForce::AtTheClose);
+    engine.process_order(&moc, account_id);
+    engine.process_status(MarketStatusAction::PreClose);
+    assert_eq!(engine.auction(), Some(AuctionType::Closing));
+
+    let loc = auction_order(
+        "S1",
+        OrderSide::Sell,
+        "100",
+        Some("149.50"),
+        TimeInForce::AtTheClose,
+    );
+    engine.process_order(&loc, account_id);
+    engine.process_status(MarketStatusAction::Close);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    assert_eq!(
+        fill_summary(&saved_messages),
+        vec![
+            (
+                "B1".to_string(),
+                Quantity::from("100"),
+                Price::from("149.50")
+            ),
+            (
+                "S1".to_string(),
+                Quantity::from("100"),
+                Price::from("149.50")
+            ),
+        ]
+    );
+    assert_eq!(engine.market_status, MarketStatus::Closed);
+}
@@ -0,0 +1,549 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fill semantics for executing orders against bar data.
+//!
+//! A bar only records its open, high, low and close, so execution against bars must assume a
+//! path the market took within the bar. The path always starts at the open and ends at the
+//! close, visiting the high and the low in between in the order given by the
+//! [`IntraBarOrdering`], with the price moving continuously between each of these points.
+//!
+//! Orders are then filled along the path as follows:
+//! - Market orders fill at the open, slipped against the order by `slippage_ticks`.
+//! - Limit orders fill at the open when it is already through the limit price (taker), and
+//!   otherwise at the limit price when the path first touches it (maker). When
+//!   `limit_requires_trade_through` is set the path must trade strictly through the limit.
+//! - Stop and if-touched orders trigger at the open when it has gapped through the trigger
+//!   price, and otherwise at the trigger price when the path first touches it. Market orders
+//!   released by the trigger fill at the trigger point slipped by `slippage_ticks`, while limit
+//!   orders continue along the remainder of the path from the trigger point.
+//!
+//! To reduce optimistic fills, the path can be required to move through trigger and limit
+//! prices by `penetration_ticks` before they are considered touched. Triggered stops then
+//! fill from the penetration level, while limits still fill at their limit price.
+//!
+//! When bid and ask bars are both available, buy orders are evaluated against the ask path and
+//! sell orders against the bid path, otherwise a single `LAST` or `MID` bar serves both sides.
+//!
+//! Slippage is never applied to limit fills, and the full order quantity is assumed to fill.
+
+use anyhow::bail;
+use nautilus_model::{
+    data::bar::Bar,
+    enums::{LiquiditySide, OrderSide, OrderType, PriceType},
+    orders::any::OrderAny,
+    types::price::Price,
+};
+use serde::{Deserialize, Serialize};
+
+/// The assumed order in which the high and low of a bar were traded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IntraBarOrdering {
+    /// The high is always traded before the low.
+    #[default]
+    OpenHighLowClose,
+    /// The low is always traded before the high.
+    OpenLowHighClose,
+    /// The extreme nearest the open is traded first (the high on a tie).
+    Adaptive,
+}
+
+impl IntraBarOrdering {
+    /// Returns whether the high of the `bar` is assumed to be traded before the low.
+    #[must_use]
+    pub fn is_high_first(&self, bar: &Bar) -> bool {
+        match self {
+            Self::OpenHighLowClose => true,
+            Self::OpenLowHighClose => false,
+            Self::Adaptive => bar.high.raw - bar.open.raw <= bar.open.raw - bar.low.raw,
+        }
+    }
+
+    /// Returns the assumed price path of the `bar`.
+    #[must_use]
+    pub fn path(&self, bar: &Bar) -> [Price; 4] {
+        if self.is_high_first(bar) {
+            [bar.open, bar.high, bar.low, bar.close]
+        } else {
+            [bar.open, bar.low, bar.high, bar.close]
+        }
+    }
+}
+
+/// Represents the simulated fill of an order against a bar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BarFill {
+    /// The fill price.
+    pub price: Price,
+    /// The liquidity side of the fill.
+    pub liquidity_side: LiquiditySide,
+}
+
+/// The fill model for executing orders against bars.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BarExecutionModel {
+    /// The assumed order in which the high and low of a bar were traded.
+    pub ordering: IntraBarOrdering,
+    /// The number of price increments which market and stop fills slip against the order.
+    pub slippage_ticks: u32,
+    /// If limit orders only fill when the path trades strictly through the limit price.
+    pub limit_requires_trade_through: bool,
+    /// The number of price increments the path must move through a trigger or limit price before
+    /// it is considered touched.
+    pub penetration_ticks: u32,
+}
+
+impl BarExecutionModel {
+    /// Returns the assumed price path of the `bar`.
+    #[must_use]
+    pub fn path(&self, bar: &Bar) -> [Price; 4] {
+        self.ordering.path(bar)
+    }
+
+    /// Simulates the execution of the `order` against the `bar`, returning the fill (if any).
+    ///
+    /// The bar is used for both sides of the market, so should be a `LAST` or `MID` bar. The
+    /// `price_increment` is the tick size of the instrument, used for slippage and penetration.
+    /// Trailing stop orders are treated as stops at their current trigger price.
+    #[must_use]
+    pub fn simulate(&self, order: &OrderAny, bar: &Bar, price_increment: Price) -> Option<BarFill> {
+        self.simulate_path(order, &self.path(bar), price_increment)
+    }
+
+    /// Simulates the execution of the `order` against the bid and ask bars for the same interval,
+    /// returning the fill (if any).
+    ///
+    /// Buy orders trigger and fill on the path of the `ask_bar`, and sell orders on the path of
+    /// the `bid_bar`. The intra-bar ordering is decided by the `bid_bar` for both sides, so the
+    /// two paths move together.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the bars are not `BID` and `ASK` bars respectively for
+    /// the same instrument and interval.
+    pub fn simulate_bid_ask(
+        &self,
+        order: &OrderAny,
+        bid_bar: &Bar,
+        ask_bar: &Bar,
+        price_increment: Price,
+    ) -> anyhow::Result<Option<BarFill>> {
+        if bid_bar.bar_type.spec().price_type != PriceType::Bid {
+            bail!("Expected a BID bar, was {}", bid_bar.bar_type);
+        }
+        if ask_bar.bar_type.spec().price_type != PriceType::Ask {
+            bail!("Expected an ASK bar, was {}", ask_bar.bar_type);
+        }
+        if bid_bar.instrument_id() != ask_bar.instrument_id()
+            || bid_bar.ts_event != ask_bar.ts_event
+        {
+            bail!("Bid bar {bid_bar} and ask bar {ask_bar} were not for the same interval");
+        }
+
+        let high_first = self.ordering.is_high_first(bid_bar);
+        let bar = match order.order_side() {
+            OrderSide::Buy => ask_bar,
+            _ => bid_bar,
+        };
+        let path = if high_first {
+            [bar.open, bar.high, bar.low, bar.close]
+        } else {
+            [bar.open, bar.low, bar.high, bar.close]
+        };
+        Ok(self.simulate_path(order, &path, price_increment))
+    }
+
+    fn simulate_path(
+        &self,
+        order: &OrderAny,
+        path: &[Price; 4],
+        price_increment: Price,
+    ) -> Option<BarFill> {
+        let side = order.order_side();
+
+        match order.order_type() {
+            OrderType::Market | OrderType::MarketToLimit => Some(BarFill {
+                price: self.slip(path[0], side, price_increment),
+                liquidity_side: LiquiditySide::Taker,
+            }),
+            OrderType::Limit => self.simulate_limit(path, side, order.price()?, price_increment),
+            OrderType::StopMarket | OrderType::TrailingStopMarket | OrderType::MarketIfTouched => {
+                let (trigger_px, _) = self.trigger(path, order, price_increment)?;
+                Some(BarFill {
+                    price: self.slip(trigger_px, side, price_increment),
+                    liquidity_side: LiquiditySide::Taker,
+                })
+            }
+            OrderType::StopLimit | OrderType::TrailingStopLimit | OrderType::LimitIfTouched => {
+                let (trigger_px, next) = self.trigger(path, order, price_increment)?;
+                let mut remaining = vec![trigger_px];
+                remaining.extend_from_slice(&path[next..]);
+                self.simulate_limit(&remaining, side, order.price()?, price_increment)
+            }
+        }
+    }
+
+    // Returns the price at which the order triggers, after any penetration, along with the
+    // index of the next point on the path
+    fn trigger(
+        &self,
+        path: &[Price],
+        order: &OrderAny,
+        price_increment: Price,
+    ) -> Option<(Price, usize)> {
+        // Stops trigger in the direction of the order side, if-touched orders against it
+        let is_if_touched = matches!(
+            order.order_type(),
+            OrderType::MarketIfTouched | OrderType::LimitIfTouched
+        );
+        let upwards = (order.order_side() == OrderSide::Buy) != is_if_touched;
+        let level = self.penetrate(order.trigger_price()?, upwards, price_increment);
+        let (price, next, _) = Self::first_touch(path, upwards, level, false)?;
+        Some((price, next))
+    }
+
+    fn simulate_limit(
+        &self,
+        path: &[Price],
+        side: OrderSide,
+        limit_px: Price,
+        price_increment: Price,
+    ) -> Option<BarFill> {
+        // A buy limit is touched from above, a sell limit from below
+        let upwards = side == OrderSide::Sell;
+        let level = self.penetrate(limit_px, upwards, price_increment);
+        let (price, _, gapped) =
+            Self::first_touch(path, upwards, level, self.limit_requires_trade_through)?;
+
+        // A limit touched along the path fills at the limit price, never the penetration level
+        Some(if gapped {
+            BarFill {
+                price,
+                liquidity_side: LiquiditySide::Taker,
+            }
+        } else {
+            BarFill {
+                price: limit_px,
+                liquidity_side: LiquiditySide::Maker,
+            }
+        })
+    }
+
+    // Returns the price at which the `path` first reaches `level` moving upwards (or downwards),
+    // the index of the next point on the path, and whether the start of the path had already
+    // gapped through the level (in which case the touch is at the starting price).
+    fn first_touch(
+        path: &[Price],
+        upwards: bool,
+        level: Price,
+        strict: bool,
+    ) -> Option<(Price, usize, bool)> {
+        let reached = |price: Price| match (upwards, strict) {
+            (true, false) => price >= level,
+            (true, true) => price > level,
+            (false, false) => price <= level,
+            (false, true) => price < level,
+        };
+
+        let start = *path.first()?;
+        if reached(start) {
+            return Some((start, 1, true));
+        }
+        // Each segment of the path is monotonic, so it crosses the level if its end reaches it
+        path.iter()
+            .position(|price| reached(*price))
+            .map(|index| (level, index, false))
+    }
+
+    fn penetrate(&self, level: Price, upwards: bool, price_increment: Price) -> Price {
+        let penetration = i64::from(self.penetration_ticks) * price_increment.raw;
+        let raw = if upwards {
+            level.raw + penetration
+        } else {
+            level.raw - penetration
+        };
+        Price::from_raw(raw, level.precision)
+    }
+
+    fn slip(&self, price: Price, side: OrderSide, price_increment: Price) -> Price {
+        let slippage = i64::from(self.slippage_ticks) * price_increment.raw;
+        let raw = match side {
+            OrderSide::Buy => price.raw + slippage,
+            _ => price.raw - slippage,
+        };
+        Price::from_raw(raw, price.precision)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::bar::BarType, orders::builder::OrderTestBuilder, types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn bar(open: &str, high: &str, low: &str, close: &str) -> Bar {
+        bar_of("LAST", open, high, low, close)
+    }
+
+    fn bar_of(price_type: &str, open: &str, high: &str, low: &str, close: &str) -> Bar {
+        Bar::new(
+            BarType::from(format!("AUD/USD.SIM-1-MINUTE-{price_type}-EXTERNAL").as_str()),
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from(100_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn order(
+        order_type: OrderType,
+        side: OrderSide,
+        price: Option<&str>,
+        trigger_price: Option<&str>,
+    ) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id("AUD/USD.SIM".into())
+            .side(side)
+            .quantity(Quantity::from(100_000));
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        if let Some(trigger_price) = trigger_price {
+            builder.trigger_price(Price::from(trigger_price));
+        }
+        builder.build()
+    }
+
+    fn tick() -> Price {
+        Price::from("0.00001")
+    }
+
+    #[rstest]
+    #[case(IntraBarOrdering::OpenHighLowClose, "1.00000", true)]
+    #[case(IntraBarOrdering::OpenLowHighClose, "1.00000", false)]
+    #[case(IntraBarOrdering::Adaptive, "1.00016", true)]
+    #[case(IntraBarOrdering::Adaptive, "1.00004", false)]
+    fn test_is_high_first(
+        #[case] ordering: IntraBarOrdering,
+        #[case] open: &str,
+        #[case] expected: bool,
+    ) {
+        let bar = bar(open, "1.00020", "1.00000", "1.00010");
+        assert_eq!(ordering.is_high_first(&bar), expected);
+    }
+
+    #[rstest]
+    fn test_market_order_fills_at_open_with_slippage() {
+        let model = BarExecutionModel {
+            slippage_ticks: 2,
+            ..Default::default()
+        };
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+
+        let buy = order(OrderType::Market, OrderSide::Buy, None, None);
+        let sell = order(OrderType::Market, OrderSide::Sell, None, None);
+
+        assert_eq!(
+            model.simulate(&buy, &bar, tick()).unwrap().price,
+            Price::from("1.00012")
+        );
+        assert_eq!(
+            model.simulate(&sell, &bar, tick()).unwrap().price,
+            Price::from("1.00008")
+        );
+    }
+
+    #[rstest]
+    #[case("1.00005", false, Some(("1.00005", LiquiditySide::Maker)))]
+    #[case("1.00015", false, Some(("1.00010", LiquiditySide::Taker)))]
+    #[case("1.00000", false, Some(("1.00000", LiquiditySide::Maker)))]
+    #[case("1.00000", true, None)]
+    #[case("0.99999", false, None)]
+    fn test_limit_buy(
+        #[case] limit_px: &str,
+        #[case] limit_requires_trade_through: bool,
+        #[case] expected: Option<(&str, LiquiditySide)>,
+    ) {
+        let model = BarExecutionModel {
+            slippage_ticks: 1,
+            limit_requires_trade_through,
+            ..Default::default()
+        };
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(OrderType::Limit, OrderSide::Buy, Some(limit_px), None);
+
+        let expected = expected.map(|(price, liquidity_side)| BarFill {
+            price: Price::from(price),
+            liquidity_side,
+        });
+        assert_eq!(model.simulate(&order, &bar, tick()), expected);
+    }
+
+    #[rstest]
+    fn test_stop_market_triggers_at_stop_with_slippage() {
+        let model = BarExecutionModel {
+            slippage_ticks: 1,
+            ..Default::default()
+        };
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(OrderType::StopMarket, OrderSide::Buy, None, Some("1.00018"));
+
+        let fill = model.simulate(&order, &bar, tick()).unwrap();
+        assert_eq!(fill.price, Price::from("1.00019"));
+        assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+    }
+
+    #[rstest]
+    fn test_stop_market_gapped_through_fills_at_open() {
+        let model = BarExecutionModel::default();
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(
+            OrderType::StopMarket,
+            OrderSide::Sell,
+            None,
+            Some("1.00015"),
+        );
+
+        let fill = model.simulate(&order, &bar, tick()).unwrap();
+        assert_eq!(fill.price, Price::from("1.00010"));
+    }
+
+    #[rstest]
+    fn test_market_if_touched_triggers_against_order_side() {
+        let model = BarExecutionModel::default();
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(
+            OrderType::MarketIfTouched,
+            OrderSide::Buy,
+            None,
+            Some("1.00003"),
+        );
+
+        let fill = model.simulate(&order, &bar, tick()).unwrap();
+        assert_eq!(fill.price, Price::from("1.00003"));
+    }
+
+    #[rstest]
+    #[case(IntraBarOrdering::OpenHighLowClose, Some("1.00004"))]
+    #[case(IntraBarOrdering::OpenLowHighClose, None)]
+    fn test_stop_limit_continues_along_path_after_trigger(
+        #[case] ordering: IntraBarOrdering,
+        #[case] expected: Option<&str>,
+    ) {
+        let model = BarExecutionModel {
+            ordering,
+            ..Default::default()
+        };
+        // Buy stop triggers on the way up to the high, the limit then needs the market to
+        // come back down, which only happens when the low is traded after the high
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(
+            OrderType::StopLimit,
+            OrderSide::Buy,
+            Some("1.00004"),
+            Some("1.00018"),
+        );
+
+        let fill = model.simulate(&order, &bar, tick());
+        assert_eq!(fill.map(|fill| fill.price), expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[case(2, Some("1.00020"))]
+    #[case(3, None)]
+    fn test_stop_requires_penetration(
+        #[case] penetration_ticks: u32,
+        #[case] expected: Option<&str>,
+    ) {
+        let model = BarExecutionModel {
+            penetration_ticks,
+            ..Default::default()
+        };
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(OrderType::StopMarket, OrderSide::Buy, None, Some("1.00018"));
+
+        let fill = model.simulate(&order, &bar, tick());
+        assert_eq!(fill.map(|fill| fill.price), expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[case(1, Some("1.00001"))]
+    #[case(2, None)]
+    fn test_limit_requires_penetration_and_fills_at_limit(
+        #[case] penetration_ticks: u32,
+        #[case] expected: Option<&str>,
+    ) {
+        let model = BarExecutionModel {
+            penetration_ticks,
+            ..Default::default()
+        };
+        let bar = bar("1.00010", "1.00020", "1.00000", "1.00012");
+        let order = order(OrderType::Limit, OrderSide::Buy, Some("1.00001"), None);
+
+        let fill = model.simulate(&order, &bar, tick());
+        assert_eq!(fill.map(|fill| fill.price), expected.map(Price::from));
+    }
+
+    #[rstest]
+    fn test_bid_ask_bars_evaluate_each_side_on_its_own_path() {
+        let model = BarExecutionModel::default();
+        let bid_bar = bar_of("BID", "1.00010", "1.00017", "1.00000", "1.00012");
+        let ask_bar = bar_of("ASK", "1.00012", "1.00019", "1.00002", "1.00014");
+
+        // Buy stop is only reached by the ask
+        let buy_stop = order(OrderType::StopMarket, OrderSide::Buy, None, Some("1.00018"));
+        let fill = model
+            .simulate_bid_ask(&buy_stop, &bid_bar, &ask_bar, tick())
+            .unwrap();
+        assert_eq!(fill.unwrap().price, Price::from("1.00018"));
+
+        // Sell limit is not reached by the bid
+        let sell_limit = order(OrderType::Limit, OrderSide::Sell, Some("1.00018"), None);
+        let fill = model
+            .simulate_bid_ask(&sell_limit, &bid_bar, &ask_bar, tick())
+            .unwrap();
+        assert_eq!(fill, None);
+
+        // While a single last price bar would have filled it
+        assert!(model.simulate(&sell_limit, &ask_bar, tick()).is_some());
+    }
+
+    #[rstest]
+    fn test_bid_ask_bars_with_wrong_price_types() {
+        let model = BarExecutionModel::default();
+        let last_bar = bar("1.00010", "1.00017", "1.00000", "1.00012");
+        let ask_bar = bar_of("ASK", "1.00012", "1.00019", "1.00002", "1.00014");
+        let order = order(OrderType::Market, OrderSide::Buy, None, None);
+
+        let result = model.simulate_bid_ask(&order, &last_bar, &ask_bar, tick());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Expected a BID bar, was AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL"
+        );
+    }
+}
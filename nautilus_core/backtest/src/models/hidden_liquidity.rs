@@ -0,0 +1,366 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Hidden (iceberg) liquidity modeling for the simulated order book.
+//!
+//! Venues may hold undisplayed size at or inside the spread, so a market order can fill more
+//! than the displayed depth at the best prices. The [`HiddenLiquidityModel`] samples hidden
+//! orders each time a market order is simulated, sized as a multiple of the displayed size at
+//! the touch.
+
+use std::fmt::Display;
+
+use nautilus_core::correctness::{
+    check_in_range_inclusive_f64, check_non_negative_f64, check_predicate_true,
+};
+use nautilus_model::{
+    enums::OrderSide,
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The size distribution of hidden orders, as a multiple of the displayed size at the touch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HiddenSizeDistribution {
+    /// A fixed multiple.
+    Fixed(f64),
+    /// A multiple uniformly distributed between `min` and `max`.
+    Uniform { min: f64, max: f64 },
+    /// A multiple exponentially distributed with the given `mean`.
+    Exponential { mean: f64 },
+}
+
+impl HiddenSizeDistribution {
+    fn check(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Fixed(multiple) => check_non_negative_f64(*multiple, "multiple"),
+            Self::Uniform { min, max } => {
+                check_non_negative_f64(*min, "min")?;
+                check_predicate_true(min <= max, "`min` was greater than `max`")
+            }
+            Self::Exponential { mean } => check_non_negative_f64(*mean, "mean"),
+        }
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        match self {
+            Self::Fixed(multiple) => *multiple,
+            Self::Uniform { min, max } => rng.gen_range(*min..=*max),
+            Self::Exponential { mean } => -mean * (1.0 - rng.gen::<f64>()).ln(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HiddenLiquidityModel {
+    /// The probability of a hidden order resting at the best displayed price.
+    prob_hidden_at_touch: f64,
+    /// The probability of a hidden order resting one tick inside the spread.
+    prob_hidden_inside_spread: f64,
+    /// The size distribution of hidden orders.
+    size_distribution: HiddenSizeDistribution,
+    /// Random number generator
+    rng: StdRng,
+}
+
+impl HiddenLiquidityModel {
+    /// Creates a new [`HiddenLiquidityModel`] instance.
+    pub fn new(
+        prob_hidden_at_touch: f64,
+        prob_hidden_inside_spread: f64,
+        size_distribution: HiddenSizeDistribution,
+        random_seed: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(prob_hidden_at_touch, 0.0, 1.0, "prob_hidden_at_touch")?;
+        check_in_range_inclusive_f64(
+            prob_hidden_inside_spread,
+            0.0,
+            1.0,
+            "prob_hidden_inside_spread",
+        )?;
+        size_distribution.check()?;
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Self {
+            prob_hidden_at_touch,
+            prob_hidden_inside_spread,
+            size_distribution,
+            rng,
+        })
+    }
+
+    /// Returns the simulated fills for a market order of `side` and `quantity` against the
+    /// displayed depth of the `book` plus any sampled hidden liquidity, best price first.
+    ///
+    /// Hidden liquidity is only sampled relative to displayed liquidity, so no fills are
+    /// returned when the opposite side of the book is empty.
+    pub fn simulate_market_fills(
+        &mut self,
+        book: &OrderBook,
+        side: OrderSide,
+        quantity: Quantity,
+        price_increment: Price,
+    ) -> Vec<(Price, Quantity)> {
+        let (mut levels, opposite_best): (Vec<(Price, f64)>, Option<Price>) = match side {
+            OrderSide::Buy => (
+                book.asks().map(|l| (l.price.value, l.size())).collect(),
+                book.best_bid_price(),
+            ),
+            _ => (
+                book.bids().map(|l| (l.price.value, l.size())).collect(),
+                book.best_ask_price(),
+            ),
+        };
+        let Some(&(touch_price, touch_size)) = levels.first() else {
+            return Vec::new();
+        };
+
+        if self.event_success(self.prob_hidden_at_touch) {
+            levels[0].1 += touch_size * self.size_distribution.sample(&mut self.rng);
+        }
+
+        let tick = price_increment.as_f64();
+        let spread = opposite_best.map(|best| (touch_price.as_f64() - best.as_f64()).abs());
+        if spread.is_some_and(|spread| spread >= 2.0 * tick - f64::EPSILON)
+            && self.event_success(self.prob_hidden_inside_spread)
+        {
+            let inside = match side {
+                OrderSide::Buy => touch_price.as_f64() - tick,
+                _ => touch_price.as_f64() + tick,
+            };
+            let size = touch_size * self.size_distribution.sample(&mut self.rng);
+            levels.insert(0, (Price::new(inside, touch_price.precision), size));
+        }
+
+        let mut fills = Vec::new();
+        let mut remaining = quantity.as_f64();
+        for (price, size) in levels {
+            let fill_qty = Quantity::new(size.min(remaining), quantity.precision);
+            if !fill_qty.is_positive() {
+                continue;
+            }
+            remaining -= fill_qty.as_f64();
+            fills.push((price, fill_qty));
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        fills
+    }
+
+    fn event_success(&mut self, probability: f64) -> bool {
+        match probability {
+            0.0 => false,
+            1.0 => true,
+            _ => self.rng.gen_bool(probability),
+        }
+    }
+}
+
+impl Display for HiddenLiquidityModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HiddenLiquidityModel(prob_hidden_at_touch: {}, prob_hidden_inside_spread: {}, size_distribution: {:?})",
+            self.prob_hidden_at_touch, self.prob_hidden_inside_spread, self.size_distribution
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::order::BookOrder, enums::BookType, identifiers::InstrumentId};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        for (side, price, size) in [
+            (OrderSide::Buy, "99.97", "100"),
+            (OrderSide::Sell, "100.00", "100"),
+            (OrderSide::Sell, "100.01", "200"),
+        ] {
+            book.add(
+                BookOrder::new(side, Price::from(price), Quantity::from(size), 0),
+                0,
+                0,
+                0.into(),
+            );
+        }
+        book
+    }
+
+    fn model(at_touch: f64, inside_spread: f64, multiple: f64) -> HiddenLiquidityModel {
+        HiddenLiquidityModel::new(
+            at_touch,
+            inside_spread,
+            HiddenSizeDistribution::Fixed(multiple),
+            Some(42),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_invalid_probability() {
+        let result = HiddenLiquidityModel::new(1.1, 0.0, HiddenSizeDistribution::Fixed(1.0), None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_invalid_uniform_distribution() {
+        let result = HiddenLiquidityModel::new(
+            0.5,
+            0.5,
+            HiddenSizeDistribution::Uniform { min: 2.0, max: 1.0 },
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_no_hidden_liquidity_fills_displayed_depth(book: OrderBook) {
+        let mut model = model(0.0, 0.0, 1.0);
+
+        let fills = model.simulate_market_fills(
+            &book,
+            OrderSide::Buy,
+            Quantity::from("250"),
+            Price::from("0.01"),
+        );
+
+        assert_eq!(
+            fills,
+            vec![
+                (Price::from("100.00"), Quantity::from("100")),
+                (Price::from("100.01"), Quantity::from("150")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_hidden_liquidity_at_touch(book: OrderBook) {
+        let mut model = model(1.0, 0.0, 1.5);
+
+        let fills = model.simulate_market_fills(
+            &book,
+            OrderSide::Buy,
+            Quantity::from("300"),
+            Price::from("0.01"),
+        );
+
+        assert_eq!(
+            fills,
+            vec![
+                (Price::from("100.00"), Quantity::from("250")),
+                (Price::from("100.01"), Quantity::from("50")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_hidden_liquidity_inside_spread(book: OrderBook) {
+        let mut model = model(0.0, 1.0, 0.5);
+
+        let buy_fills = model.simulate_market_fills(
+            &book,
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("0.01"),
+        );
+        let sell_fills = model.simulate_market_fills(
+            &book,
+            OrderSide::Sell,
+            Quantity::from("100"),
+            Price::from("0.01"),
+        );
+
+        assert_eq!(
+            buy_fills,
+            vec![
+                (Price::from("99.99"), Quantity::from("50")),
+                (Price::from("100.00"), Quantity::from("50")),
+            ]
+        );
+        assert_eq!(
+            sell_fills,
+            vec![
+                (Price::from("99.98"), Quantity::from("50")),
+                (Price::from("99.97"), Quantity::from("50")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_no_hidden_liquidity_inside_one_tick_spread() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("99.99"),
+                Quantity::from("100"),
+                0,
+            ),
+            0,
+            0,
+            0.into(),
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.00"),
+                Quantity::from("100"),
+                0,
+            ),
+            0,
+            0,
+            0.into(),
+        );
+        let mut model = model(0.0, 1.0, 1.0);
+
+        let fills = model.simulate_market_fills(
+            &book,
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("0.01"),
+        );
+
+        assert_eq!(fills, vec![(Price::from("100.00"), Quantity::from("100"))]);
+    }
+
+    #[rstest]
+    fn test_sampled_size_distributions_are_deterministic_with_seed(book: OrderBook) {
+        let distribution = HiddenSizeDistribution::Exponential { mean: 1.0 };
+        let mut model1 = HiddenLiquidityModel::new(1.0, 0.0, distribution, Some(7)).unwrap();
+        let mut model2 = HiddenLiquidityModel::new(1.0, 0.0, distribution, Some(7)).unwrap();
+        let quantity = Quantity::from("10000");
+        let tick = Price::from("0.01");
+
+        let fills1 = model1.simulate_market_fills(&book, OrderSide::Buy, quantity, tick);
+        let fills2 = model2.simulate_market_fills(&book, OrderSide::Buy, quantity, tick);
+
+        assert_eq!(fills1, fills2);
+        assert!(fills1[0].1 > Quantity::from("100"));
+    }
+}
@@ -15,6 +15,8 @@
 
 use std::fmt::Display;
 
+use nautilus_core::nanos::UnixNanos;
+
 pub struct LatencyModel;
 
 impl Display for LatencyModel {
@@ -22,3 +24,113 @@ impl Display for LatencyModel {
         write!(f, "LatencyModel()")
     }
 }
+
+/// Models the latency of a market data feed as the delta between the `ts_event` of the data
+/// (when it occurred at the venue) and its `ts_init` (when it was received).
+///
+/// The default latency is applied for data without a recorded latency, i.e. where `ts_init`
+/// is not after `ts_event`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeedLatencyModel {
+    pub default_latency_ns: u64,
+}
+
+impl FeedLatencyModel {
+    /// Creates a new [`FeedLatencyModel`] instance.
+    #[must_use]
+    pub const fn new(default_latency_ns: u64) -> Self {
+        Self { default_latency_ns }
+    }
+
+    /// Returns the feed latency (nanoseconds) for data with the given timestamps.
+    #[must_use]
+    pub fn latency_ns(&self, ts_event: UnixNanos, ts_init: UnixNanos) -> u64 {
+        if ts_init > ts_event {
+            ts_init.as_u64() - ts_event.as_u64()
+        } else {
+            self.default_latency_ns
+        }
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) when data with the given timestamps is received.
+    #[must_use]
+    pub fn ts_received(&self, ts_event: UnixNanos, ts_init: UnixNanos) -> UnixNanos {
+        ts_event + self.latency_ns(ts_event, ts_init)
+    }
+}
+
+impl Display for FeedLatencyModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FeedLatencyModel(default_latency_ns={})",
+            self.default_latency_ns
+        )
+    }
+}
+
+/// Represents the feed latencies observed over a backtest run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeedLatencyStats {
+    pub count: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+    pub total_ns: u64,
+}
+
+impl FeedLatencyStats {
+    /// Records the given feed `latency_ns`.
+    pub fn record(&mut self, latency_ns: u64) {
+        self.min_ns = if self.count == 0 {
+            latency_ns
+        } else {
+            self.min_ns.min(latency_ns)
+        };
+        self.max_ns = self.max_ns.max(latency_ns);
+        self.total_ns = self.total_ns.saturating_add(latency_ns);
+        self.count += 1;
+    }
+
+    /// Returns the mean feed latency (nanoseconds), or `None` if no latency was recorded.
+    #[must_use]
+    pub fn mean_ns(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.total_ns as f64 / self.count as f64)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(100, 150, 50)]
+    #[case(100, 100, 10)]
+    #[case(100, 90, 10)]
+    fn test_feed_latency(#[case] ts_event: u64, #[case] ts_init: u64, #[case] expected: u64) {
+        let model = FeedLatencyModel::new(10);
+
+        assert_eq!(model.latency_ns(ts_event.into(), ts_init.into()), expected);
+        assert_eq!(
+            model.ts_received(ts_event.into(), ts_init.into()),
+            UnixNanos::from(ts_event + expected)
+        );
+    }
+
+    #[rstest]
+    fn test_feed_latency_stats() {
+        let mut stats = FeedLatencyStats::default();
+        assert_eq!(stats.mean_ns(), None);
+
+        for latency_ns in [30, 10, 20] {
+            stats.record(latency_ns);
+        }
+
+        assert_eq!((stats.count, stats.min_ns, stats.max_ns), (3, 10, 30));
+        assert_eq!(stats.mean_ns(), Some(20.0));
+    }
+}
@@ -13,6 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod bar_execution;
 pub mod fee;
 pub mod fill;
+pub mod hidden_liquidity;
 pub mod latency;
@@ -0,0 +1,335 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Handling of simulated positions approaching physical delivery.
+//!
+//! A backtest cannot take or make delivery of a physically settled instrument, so open positions
+//! are warned about ahead of the delivery date, and optionally flattened at the market price
+//! before it is reached.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+    events::order::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, PositionId, TradeId, VenueOrderId},
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::price::Price,
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// Configuration for the [`DeliveryModule`].
+#[derive(Clone, Debug)]
+pub struct DeliveryConfig {
+    /// The time (nanoseconds) before the delivery date from which open positions are warned.
+    pub warn_before_ns: u64,
+    /// The time (nanoseconds) before the delivery date at which open positions are flattened
+    /// (if any).
+    pub flatten_before_ns: Option<u64>,
+    /// The further instruments settled by physical delivery, such as deliverable futures
+    /// contracts, with their expiration as the delivery date.
+    pub physical_instruments: HashSet<InstrumentId>,
+}
+
+impl Default for DeliveryConfig {
+    /// Creates a new default [`DeliveryConfig`] instance, warning two days and flattening one
+    /// day before delivery.
+    fn default() -> Self {
+        Self {
+            warn_before_ns: 2 * NANOSECONDS_IN_DAY,
+            flatten_before_ns: Some(NANOSECONDS_IN_DAY),
+            physical_instruments: HashSet::new(),
+        }
+    }
+}
+
+/// The type of a delivery notice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeliveryNoticeType {
+    /// The position is approaching physical delivery.
+    Warning,
+    /// The position was flattened ahead of physical delivery.
+    Flatten,
+}
+
+/// Represents a notice for an open position approaching physical delivery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeliveryNotice {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub notice_type: DeliveryNoticeType,
+    pub delivery_ns: UnixNanos,
+    /// The fill closing the position at the market price (flatten notices only).
+    pub fill: Option<OrderFilled>,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides warnings and flattening for simulated positions approaching physical delivery.
+#[derive(Clone, Debug, Default)]
+pub struct DeliveryModule {
+    config: DeliveryConfig,
+    warned: HashSet<PositionId>,
+    flattened: HashSet<PositionId>,
+    notices: Vec<DeliveryNotice>,
+}
+
+impl DeliveryModule {
+    /// Creates a new [`DeliveryModule`] instance.
+    #[must_use]
+    pub fn new(config: DeliveryConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns all delivery notices so far.
+    #[must_use]
+    pub fn notices(&self) -> &[DeliveryNotice] {
+        &self.notices
+    }
+
+    /// Returns the delivery date for the given `instrument`, if settled by physical delivery.
+    #[must_use]
+    pub fn delivery_ns(&self, instrument: &InstrumentAny) -> Option<UnixNanos> {
+        match instrument {
+            InstrumentAny::Commodity(inst) if inst.is_physically_settled => inst.delivery_ns,
+            _ if self.config.physical_instruments.contains(&instrument.id()) => {
+                instrument.expiration_ns()
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks the open `positions` against the delivery dates of their `instruments` at
+    /// `ts_now`, returning notices for the positions approaching delivery.
+    ///
+    /// Each position is warned once, and flattened once at its price in `prices` (positions
+    /// without a price are flattened once one is available).
+    pub fn process(
+        &mut self,
+        ts_now: UnixNanos,
+        positions: &[&Position],
+        instruments: &HashMap<InstrumentId, InstrumentAny>,
+        prices: &HashMap<InstrumentId, Price>,
+    ) -> Vec<DeliveryNotice> {
+        let mut notices = Vec::new();
+        for position in positions.iter().filter(|p| p.is_open()) {
+            if self.flattened.contains(&position.id) {
+                continue;
+            }
+            let Some(delivery_ns) = instruments
+                .get(&position.instrument_id)
+                .and_then(|instrument| self.delivery_ns(instrument))
+            else {
+                continue;
+            };
+
+            if let Some(flatten_before_ns) = self.config.flatten_before_ns {
+                if ts_now.as_u64() + flatten_before_ns >= delivery_ns.as_u64() {
+                    if let Some(price) = prices.get(&position.instrument_id) {
+                        let fill = self.flatten_fill(position, *price, ts_now);
+                        self.flattened.insert(position.id);
+                        notices.push(DeliveryNotice {
+                            position_id: position.id,
+                            instrument_id: position.instrument_id,
+                            notice_type: DeliveryNoticeType::Flatten,
+                            delivery_ns,
+                            fill: Some(fill),
+                            ts_event: ts_now,
+                        });
+                        continue;
+                    }
+                }
+            }
+
+            if ts_now.as_u64() + self.config.warn_before_ns >= delivery_ns.as_u64()
+                && self.warned.insert(position.id)
+            {
+                notices.push(DeliveryNotice {
+                    position_id: position.id,
+                    instrument_id: position.instrument_id,
+                    notice_type: DeliveryNoticeType::Warning,
+                    delivery_ns,
+                    fill: None,
+                    ts_event: ts_now,
+                });
+            }
+        }
+        self.notices.extend(notices.iter().copied());
+        notices
+    }
+
+    /// Resets the module to its initial state.
+    pub fn reset(&mut self) {
+        self.warned.clear();
+        self.flattened.clear();
+        self.notices.clear();
+    }
+
+    fn flatten_fill(&self, position: &Position, price: Price, ts_now: UnixNanos) -> OrderFilled {
+        let count = self.flattened.len() + 1;
+        let order_side = match position.side {
+            PositionSide::Long => OrderSide::Sell,
+            _ => OrderSide::Buy,
+        };
+        OrderFilled::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            ClientOrderId::new(format!("DLV-{}-{count}", position.id).as_str()),
+            VenueOrderId::new(format!("DLV-{count}").as_str()),
+            position.account_id,
+            TradeId::new(format!("DLV-{count}").as_str()),
+            order_side,
+            OrderType::Market,
+            position.quantity,
+            price,
+            position.quote_currency,
+            LiquiditySide::Taker,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(position.id),
+            None,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        instruments::{commodity::Commodity, currency_pair::CurrencyPair, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, qty: &str, price: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(qty))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    #[rstest]
+    fn test_warns_once_then_flattens_before_delivery(commodity_wti_cushing: Commodity) {
+        let delivery_ns = commodity_wti_cushing.delivery_ns.unwrap().as_u64();
+        let instrument = InstrumentAny::Commodity(commodity_wti_cushing);
+        let position = position(&instrument, OrderSide::Buy, "10", "78.50");
+        let instruments = HashMap::from([(instrument.id(), instrument.clone())]);
+        let prices = HashMap::from([(instrument.id(), Price::from("79.00"))]);
+        let mut module = DeliveryModule::new(DeliveryConfig::default());
+
+        let ts = UnixNanos::from(delivery_ns - 3 * NANOSECONDS_IN_DAY);
+        assert!(module
+            .process(ts, &[&position], &instruments, &prices)
+            .is_empty());
+
+        let ts = UnixNanos::from(delivery_ns - 2 * NANOSECONDS_IN_DAY);
+        let notices = module.process(ts, &[&position], &instruments, &prices);
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].notice_type, DeliveryNoticeType::Warning);
+        assert!(notices[0].fill.is_none());
+        assert!(module
+            .process(ts, &[&position], &instruments, &prices)
+            .is_empty());
+
+        let ts = UnixNanos::from(delivery_ns - NANOSECONDS_IN_DAY);
+        let notices = module.process(ts, &[&position], &instruments, &prices);
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].notice_type, DeliveryNoticeType::Flatten);
+        let fill = notices[0].fill.unwrap();
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from("10"));
+        assert_eq!(fill.last_px, Price::from("79.00"));
+        assert_eq!(fill.position_id, Some(position.id));
+        assert_eq!(module.notices().len(), 2);
+    }
+
+    #[rstest]
+    fn test_ignores_cash_settled_and_spot_instruments(
+        commodity_wti_cushing: Commodity,
+        audusd_sim: CurrencyPair,
+    ) {
+        let delivery_ns = commodity_wti_cushing.delivery_ns;
+        let physical = InstrumentAny::Commodity(commodity_wti_cushing);
+        let cash_settled = InstrumentAny::Commodity(Commodity {
+            is_physically_settled: false,
+            ..commodity_wti_cushing
+        });
+        let spot = InstrumentAny::CurrencyPair(audusd_sim);
+        let module = DeliveryModule::new(DeliveryConfig::default());
+
+        assert_eq!(module.delivery_ns(&physical), delivery_ns);
+        assert_eq!(module.delivery_ns(&cash_settled), None);
+        assert_eq!(module.delivery_ns(&spot), None);
+    }
+
+    #[rstest]
+    fn test_physical_instruments_use_expiration() {
+        let instrument = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let module = DeliveryModule::new(DeliveryConfig {
+            physical_instruments: HashSet::from([instrument.id()]),
+            ..Default::default()
+        });
+
+        assert_eq!(module.delivery_ns(&instrument), instrument.expiration_ns());
+    }
+
+    #[rstest]
+    fn test_warn_only_without_flatten(commodity_wti_cushing: Commodity) {
+        let delivery_ns = commodity_wti_cushing.delivery_ns.unwrap();
+        let instrument = InstrumentAny::Commodity(commodity_wti_cushing);
+        let position = position(&instrument, OrderSide::Sell, "10", "78.50");
+        let instruments = HashMap::from([(instrument.id(), instrument.clone())]);
+        let prices = HashMap::from([(instrument.id(), Price::from("79.00"))]);
+        let mut module = DeliveryModule::new(DeliveryConfig {
+            flatten_before_ns: None,
+            ..Default::default()
+        });
+
+        let notices = module.process(delivery_ns, &[&position], &instruments, &prices);
+
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].notice_type, DeliveryNoticeType::Warning);
+        module.reset();
+        assert!(module.notices().is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Cash dividends on simulated positions.
+//!
+//! Positions held into the ex-date of a dividend are credited the dividend (or debited for
+//! short positions) on the ex-date.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    events::dividend::Dividend,
+    identifiers::{InstrumentId, PositionId},
+    position::Position,
+    types::money::Money,
+};
+
+/// Represents a dividend credited to (or if negative, debited from) an account for a position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DividendPayment {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub amount: Money,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides cash dividends on simulated positions from per-instrument dividend schedules.
+#[derive(Clone, Debug, Default)]
+pub struct DividendModule {
+    schedules: HashMap<InstrumentId, Vec<Dividend>>,
+    paid: HashSet<(PositionId, UnixNanos)>,
+    payments: Vec<DividendPayment>,
+}
+
+impl DividendModule {
+    /// Creates a new [`DividendModule`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `dividends` to the schedules of their instruments.
+    ///
+    /// A dividend replaces any scheduled dividend for the same instrument and ex-date.
+    pub fn add_dividends(&mut self, dividends: &[Dividend]) {
+        for dividend in dividends {
+            let schedule = self.schedules.entry(dividend.instrument_id).or_default();
+            schedule.retain(|d| d.ex_date != dividend.ex_date);
+            schedule.push(*dividend);
+            schedule.sort_by_key(|d| d.ex_date);
+        }
+    }
+
+    /// Returns the dividend schedule for the given `instrument_id`, sorted by ex-date.
+    #[must_use]
+    pub fn dividends(&self, instrument_id: &InstrumentId) -> &[Dividend] {
+        self.schedules
+            .get(instrument_id)
+            .map_or(&[], |schedule| schedule.as_slice())
+    }
+
+    /// Returns all dividend payments so far.
+    #[must_use]
+    pub fn payments(&self) -> &[DividendPayment] {
+        &self.payments
+    }
+
+    /// Checks the open `positions` against the dividend schedules of their instruments at
+    /// `ts_now`, returning the payments for the ex-dates reached.
+    ///
+    /// Only positions opened before an ex-date are entitled to the dividend, and each position
+    /// is paid each dividend once.
+    pub fn process(&mut self, ts_now: UnixNanos, positions: &[&Position]) -> Vec<DividendPayment> {
+        let mut payments = Vec::new();
+        for position in positions.iter().filter(|p| p.is_open()) {
+            let Some(schedule) = self.schedules.get(&position.instrument_id) else {
+                continue;
+            };
+            for dividend in schedule
+                .iter()
+                .take_while(|d| d.ex_date <= ts_now)
+                .filter(|d| position.ts_opened < d.ex_date)
+            {
+                if !self.paid.insert((position.id, dividend.ex_date)) {
+                    continue;
+                }
+                let amount = dividend.entitlement(position);
+                if amount.is_zero() {
+                    continue;
+                }
+                payments.push(DividendPayment {
+                    position_id: position.id,
+                    instrument_id: position.instrument_id,
+                    amount,
+                    ts_event: dividend.ex_date,
+                });
+            }
+        }
+        payments.sort_by_key(|payment| payment.ts_event);
+        self.payments.extend(payments.iter().copied());
+        payments
+    }
+
+    /// Resets the module to its initial state, retaining the dividend schedules.
+    pub fn reset(&mut self) {
+        self.paid.clear();
+        self.payments.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        instruments::{any::InstrumentAny, equity::Equity, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, qty: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(qty))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from("180.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    fn dividend(instrument_id: InstrumentId, amount: &str, ex_date: u64) -> Dividend {
+        Dividend::new(
+            instrument_id,
+            Money::from(amount),
+            ex_date.into(),
+            None,
+            ex_date.into(),
+            ex_date.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_long_position_credited_once_on_ex_date(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let position = position(&instrument, OrderSide::Buy, "100");
+        let mut module = DividendModule::new();
+        module.add_dividends(&[dividend(instrument.id(), "0.25 USD", 1_000)]);
+
+        assert!(module.process(999.into(), &[&position]).is_empty());
+
+        let payments = module.process(1_000.into(), &[&position]);
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].amount, Money::from("25.00 USD"));
+        assert_eq!(payments[0].ts_event, UnixNanos::from(1_000));
+
+        assert!(module.process(2_000.into(), &[&position]).is_empty());
+        assert_eq!(module.payments().len(), 1);
+    }
+
+    #[rstest]
+    fn test_short_position_debited(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let position = position(&instrument, OrderSide::Sell, "100");
+        let mut module = DividendModule::new();
+        module.add_dividends(&[
+            dividend(instrument.id(), "0.25 USD", 1_000),
+            dividend(instrument.id(), "0.26 USD", 2_000),
+        ]);
+
+        // Both ex-dates crossed in one call
+        let payments = module.process(2_000.into(), &[&position]);
+
+        let amounts: Vec<Money> = payments.iter().map(|p| p.amount).collect();
+        assert_eq!(
+            amounts,
+            vec![Money::from("-25.00 USD"), Money::from("-26.00 USD")]
+        );
+    }
+
+    #[rstest]
+    fn test_position_opened_on_ex_date_not_entitled(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let mut position = position(&instrument, OrderSide::Buy, "100");
+        position.ts_opened = 1_000.into();
+        let mut module = DividendModule::new();
+        module.add_dividends(&[dividend(instrument.id(), "0.25 USD", 1_000)]);
+
+        assert!(module.process(1_000.into(), &[&position]).is_empty());
+    }
+
+    #[rstest]
+    fn test_add_dividends_replaces_same_ex_date_and_reset(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let position = position(&instrument, OrderSide::Buy, "100");
+        let mut module = DividendModule::new();
+        module.add_dividends(&[
+            dividend(instrument.id(), "0.26 USD", 2_000),
+            dividend(instrument.id(), "0.25 USD", 1_000),
+        ]);
+        module.add_dividends(&[dividend(instrument.id(), "0.30 USD", 2_000)]);
+
+        let schedule = module.dividends(&instrument.id());
+        assert_eq!(schedule.len(), 2);
+        assert_eq!(schedule[0].ex_date, UnixNanos::from(1_000));
+        assert_eq!(schedule[1].amount, Money::from("0.30 USD"));
+
+        module.process(2_000.into(), &[&position]);
+        module.reset();
+        assert!(module.payments().is_empty());
+        assert_eq!(module.process(2_000.into(), &[&position]).len(), 2);
+    }
+}
@@ -0,0 +1,408 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Overnight financing for simulated positions, accruing borrow fees for short equity/CFD
+//! positions and swap (rollover) charges for FX positions.
+
+use std::collections::HashMap;
+
+use chrono::Weekday;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::rate::RateSchedule,
+    enums::PositionSide,
+    identifiers::{InstrumentId, PositionId},
+    position::Position,
+    types::{money::Money, price::Price},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// Represents the annual swap rates for holding an FX position over the rollover cut-off.
+///
+/// Positive rates are credited to the position holder, negative rates are debited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapRates {
+    /// The annual swap rate applied to long positions.
+    pub long: Decimal,
+    /// The annual swap rate applied to short positions.
+    pub short: Decimal,
+}
+
+/// Configuration for the [`FinancingModule`].
+#[derive(Clone, Debug)]
+pub struct FinancingConfig {
+    /// The annual borrow fee rates for short positions, per instrument.
+    pub borrow_rates: HashMap<InstrumentId, Decimal>,
+    /// The historical annual borrow fee rates for short positions, per instrument, which take
+    /// precedence over `borrow_rates` from their first record.
+    pub borrow_rate_schedules: HashMap<InstrumentId, RateSchedule>,
+    /// The annual swap rates for FX positions, per instrument.
+    pub swap_rates: HashMap<InstrumentId, SwapRates>,
+    /// The daily cut-off time as nanoseconds after midnight UTC.
+    pub cutoff_ns: u64,
+    /// The number of days in a year used to convert annual rates to daily rates.
+    pub day_count_basis: u32,
+    /// The weekday on which swaps are charged three days to cover the weekend (if any).
+    pub triple_rollover_day: Option<Weekday>,
+}
+
+impl Default for FinancingConfig {
+    /// Creates a new default [`FinancingConfig`] instance, with the cut-off at 22:00 UTC
+    /// (17:00 New York) and triple swaps charged on Wednesdays.
+    fn default() -> Self {
+        Self {
+            borrow_rates: HashMap::new(),
+            borrow_rate_schedules: HashMap::new(),
+            swap_rates: HashMap::new(),
+            cutoff_ns: 22 * 3_600_000_000_000,
+            day_count_basis: 360,
+            triple_rollover_day: Some(Weekday::Wed),
+        }
+    }
+}
+
+/// The type of a financing charge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FinancingChargeType {
+    /// A borrow fee for holding a short position.
+    Borrow,
+    /// A swap (rollover) charge or credit for holding an FX position.
+    Swap,
+}
+
+/// Represents a financing charge booked for a position at a daily cut-off.
+///
+/// A positive `amount` was credited to the account, a negative `amount` was debited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FinancingCharge {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub charge_type: FinancingChargeType,
+    pub amount: Money,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides daily borrow fee and swap accrual for simulated positions.
+#[derive(Clone, Debug)]
+pub struct FinancingModule {
+    config: FinancingConfig,
+    last_cutoff_day: Option<u64>,
+    charges: Vec<FinancingCharge>,
+}
+
+impl FinancingModule {
+    /// Creates a new [`FinancingModule`] instance.
+    #[must_use]
+    pub fn new(config: FinancingConfig) -> Self {
+        Self {
+            config,
+            last_cutoff_day: None,
+            charges: Vec::new(),
+        }
+    }
+
+    /// Returns all financing charges booked so far.
+    #[must_use]
+    pub fn charges(&self) -> &[FinancingCharge] {
+        &self.charges
+    }
+
+    /// Processes the daily cut-offs crossed since the last call at `ts_now`, returning the
+    /// financing charges for the open `positions` valued at the given `prices`.
+    ///
+    /// The first call only records the most recent cut-off, so accrual starts from there.
+    /// Positions without a price are not charged.
+    pub fn process(
+        &mut self,
+        ts_now: UnixNanos,
+        positions: &[&Position],
+        prices: &HashMap<InstrumentId, Price>,
+    ) -> Vec<FinancingCharge> {
+        let Some(cutoff_day) = self.cutoff_day(ts_now) else {
+            return Vec::new();
+        };
+        let Some(last_cutoff_day) = self.last_cutoff_day.replace(cutoff_day) else {
+            return Vec::new();
+        };
+
+        let mut charges = Vec::new();
+        for day in (last_cutoff_day + 1)..=cutoff_day {
+            let ts_event = UnixNanos::from(day * NANOSECONDS_IN_DAY + self.config.cutoff_ns);
+            for position in positions.iter().filter(|p| p.is_open()) {
+                let Some(price) = prices.get(&position.instrument_id) else {
+                    continue;
+                };
+                if let Some(amount) = self.borrow_fee(position, *price, ts_event) {
+                    charges.push(FinancingCharge {
+                        position_id: position.id,
+                        instrument_id: position.instrument_id,
+                        charge_type: FinancingChargeType::Borrow,
+                        amount,
+                        ts_event,
+                    });
+                }
+                if let Some(amount) = self.swap(position, *price, weekday(day)) {
+                    charges.push(FinancingCharge {
+                        position_id: position.id,
+                        instrument_id: position.instrument_id,
+                        charge_type: FinancingChargeType::Swap,
+                        amount,
+                        ts_event,
+                    });
+                }
+            }
+        }
+        self.charges.extend(charges.iter().copied());
+        charges
+    }
+
+    /// Resets the module to its initial state.
+    pub fn reset(&mut self) {
+        self.last_cutoff_day = None;
+        self.charges.clear();
+    }
+
+    fn cutoff_day(&self, ts_now: UnixNanos) -> Option<u64> {
+        ts_now
+            .checked_sub(self.config.cutoff_ns)
+            .map(|ns| ns / NANOSECONDS_IN_DAY)
+    }
+
+    fn borrow_fee(&self, position: &Position, price: Price, ts_event: UnixNanos) -> Option<Money> {
+        if position.side != PositionSide::Short {
+            return None;
+        }
+        let rate = self.borrow_rate(&position.instrument_id, ts_event)?;
+        Some(self.accrue(position, price, -rate, 1))
+    }
+
+    fn borrow_rate(&self, instrument_id: &InstrumentId, ts_event: UnixNanos) -> Option<Decimal> {
+        self.config
+            .borrow_rate_schedules
+            .get(instrument_id)
+            .and_then(|schedule| schedule.rate_at(ts_event))
+            .or_else(|| self.config.borrow_rates.get(instrument_id).copied())
+    }
+
+    fn swap(&self, position: &Position, price: Price, weekday: Weekday) -> Option<Money> {
+        let rates = self.config.swap_rates.get(&position.instrument_id)?;
+        let days = match weekday {
+            Weekday::Sat | Weekday::Sun => return None,
+            _ if Some(weekday) == self.config.triple_rollover_day => 3,
+            _ => 1,
+        };
+        let rate = match position.side {
+            PositionSide::Long => rates.long,
+            PositionSide::Short => rates.short,
+            PositionSide::Flat | PositionSide::NoPositionSide => return None,
+        };
+        Some(self.accrue(position, price, rate, days))
+    }
+
+    fn accrue(&self, position: &Position, price: Price, annual_rate: Decimal, days: u32) -> Money {
+        let notional = position.notional_value(price);
+        let amount = notional.as_decimal() * annual_rate * Decimal::from(days)
+            / Decimal::from(self.config.day_count_basis);
+        Money::new(amount.to_f64().unwrap(), notional.currency)
+    }
+}
+
+fn weekday(day: u64) -> Weekday {
+    // The UNIX epoch (day zero) was a Thursday
+    Weekday::try_from(((day + 3) % 7) as u8).expect("weekday was in range")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::rate::RateRecord,
+        enums::{OrderSide, OrderType},
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, equity::Equity, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    // 2024-01-01 (Monday) 00:00 UTC
+    const MONDAY: u64 = 19_723 * NANOSECONDS_IN_DAY;
+    const CUTOFF_NS: u64 = 22 * 3_600_000_000_000;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, qty: &str, price: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(qty))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    #[rstest]
+    fn test_weekday() {
+        assert_eq!(weekday(0), Weekday::Thu);
+        assert_eq!(weekday(MONDAY / NANOSECONDS_IN_DAY), Weekday::Mon);
+    }
+
+    #[rstest]
+    fn test_first_process_records_cutoff_without_charging(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let position = position(&instrument, OrderSide::Sell, "100", "180.00");
+        let mut config = FinancingConfig::default();
+        config.borrow_rates.insert(instrument.id(), dec!(0.036));
+        let mut module = FinancingModule::new(config);
+        let prices = HashMap::from([(instrument.id(), Price::from("180.00"))]);
+
+        let charges = module.process((MONDAY + CUTOFF_NS).into(), &[&position], &prices);
+
+        assert!(charges.is_empty());
+    }
+
+    #[rstest]
+    fn test_borrow_fee_accrues_per_calendar_day_for_short_positions(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let short = position(&instrument, OrderSide::Sell, "100", "180.00");
+        let long = position(&instrument, OrderSide::Buy, "100", "180.00");
+        let mut config = FinancingConfig::default();
+        config.borrow_rates.insert(instrument.id(), dec!(0.036));
+        let mut module = FinancingModule::new(config);
+        let prices = HashMap::from([(instrument.id(), Price::from("180.00"))]);
+
+        module.process(MONDAY.into(), &[&short, &long], &prices);
+        // Crosses the Monday, Tuesday and Wednesday cut-offs
+        let charges = module.process(
+            (MONDAY + 2 * NANOSECONDS_IN_DAY + CUTOFF_NS).into(),
+            &[&short, &long],
+            &prices,
+        );
+
+        // 18,000 USD notional at 3.6% p.a. over 360 days is 1.80 USD per day
+        assert_eq!(charges.len(), 3);
+        assert!(charges
+            .iter()
+            .all(|c| c.charge_type == FinancingChargeType::Borrow
+                && c.amount == Money::from("-1.80 USD")));
+        assert_eq!(charges[0].ts_event, UnixNanos::from(MONDAY + CUTOFF_NS));
+        assert_eq!(module.charges().len(), 3);
+    }
+
+    #[rstest]
+    fn test_borrow_fee_uses_rate_schedule(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let short = position(&instrument, OrderSide::Sell, "100", "180.00");
+        let mut config = FinancingConfig::default();
+        config.borrow_rates.insert(instrument.id(), dec!(0.036));
+        config.borrow_rate_schedules.insert(
+            instrument.id(),
+            RateSchedule::new(vec![RateRecord::new(
+                dec!(0.072),
+                (MONDAY + NANOSECONDS_IN_DAY).into(),
+            )]),
+        );
+        let mut module = FinancingModule::new(config);
+        let prices = HashMap::from([(instrument.id(), Price::from("180.00"))]);
+
+        module.process(MONDAY.into(), &[&short], &prices);
+        let charges = module.process(
+            (MONDAY + NANOSECONDS_IN_DAY + CUTOFF_NS).into(),
+            &[&short],
+            &prices,
+        );
+
+        // The static rate applies until the schedule's first record, then the historical rate
+        let amounts: Vec<Money> = charges.iter().map(|c| c.amount).collect();
+        assert_eq!(
+            amounts,
+            vec![Money::from("-1.80 USD"), Money::from("-3.60 USD")]
+        );
+    }
+
+    #[rstest]
+    fn test_swap_skips_weekends_and_triples_on_rollover_day(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy, "100000", "0.80000");
+        let mut config = FinancingConfig::default();
+        config.swap_rates.insert(
+            instrument.id(),
+            SwapRates {
+                long: dec!(-0.018),
+                short: dec!(0.009),
+            },
+        );
+        let mut module = FinancingModule::new(config);
+        let prices = HashMap::from([(instrument.id(), Price::from("0.80000"))]);
+
+        module.process(MONDAY.into(), &[&long], &prices);
+        // Crosses the cut-offs from Monday through Sunday
+        let charges = module.process((MONDAY + 7 * NANOSECONDS_IN_DAY).into(), &[&long], &prices);
+
+        // 80,000 USD notional at -1.8% p.a. over 360 days is -4.00 USD per day
+        let amounts: Vec<Money> = charges.iter().map(|c| c.amount).collect();
+        assert_eq!(
+            amounts,
+            vec![
+                Money::from("-4.00 USD"),
+                Money::from("-4.00 USD"),
+                Money::from("-12.00 USD"),
+                Money::from("-4.00 USD"),
+                Money::from("-4.00 USD"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_reset(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let short = position(&instrument, OrderSide::Sell, "100000", "0.80000");
+        let mut config = FinancingConfig::default();
+        config.swap_rates.insert(
+            instrument.id(),
+            SwapRates {
+                long: dec!(-0.018),
+                short: dec!(0.009),
+            },
+        );
+        let mut module = FinancingModule::new(config);
+        let prices = HashMap::from([(instrument.id(), Price::from("0.80000"))]);
+
+        module.process(MONDAY.into(), &[&short], &prices);
+        let charges = module.process((MONDAY + CUTOFF_NS).into(), &[&short], &prices);
+        assert_eq!(charges[0].amount, Money::from("2.00 USD"));
+
+        module.reset();
+        assert!(module.charges().is_empty());
+        assert!(module
+            .process((MONDAY + 2 * NANOSECONDS_IN_DAY).into(), &[&short], &prices)
+            .is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Interest accrual on the idle cash balances of simulated accounts.
+//!
+//! Interest accrues daily on the free balance of each currency with a configured rate, and is
+//! credited (or debited for negative rates) at the end of each compounding period.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::rate::RateSchedule,
+    types::{balance::AccountBalance, currency::Currency, money::Money},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// The schedule on which accrued interest is credited to the account, and so compounds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InterestCompounding {
+    /// Credit the accrued interest at every daily cut-off.
+    #[default]
+    Daily,
+    /// Credit the accrued interest at the first cut-off of each calendar month.
+    Monthly,
+    /// Credit the accrued interest at the first cut-off of each calendar year.
+    Annually,
+}
+
+/// Configuration for the [`InterestModule`].
+#[derive(Clone, Debug)]
+pub struct InterestConfig {
+    /// The annual interest rates on idle cash, per currency (negative rates are charged).
+    pub rates: HashMap<Currency, Decimal>,
+    /// The historical annual interest rates on idle cash, per currency, which take precedence
+    /// over `rates` from their first record.
+    pub rate_schedules: HashMap<Currency, RateSchedule>,
+    /// The schedule on which accrued interest is credited.
+    pub compounding: InterestCompounding,
+    /// The daily cut-off time as nanoseconds after midnight UTC.
+    pub cutoff_ns: u64,
+    /// The number of days in a year used to convert annual rates to daily rates.
+    pub day_count_basis: u32,
+}
+
+impl Default for InterestConfig {
+    /// Creates a new default [`InterestConfig`] instance, with daily compounding at a
+    /// midnight UTC cut-off on an actual/365 basis.
+    fn default() -> Self {
+        Self {
+            rates: HashMap::new(),
+            rate_schedules: HashMap::new(),
+            compounding: InterestCompounding::Daily,
+            cutoff_ns: 0,
+            day_count_basis: 365,
+        }
+    }
+}
+
+/// Represents accrued interest credited to (or if negative, debited from) an account balance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterestCredit {
+    pub amount: Money,
+    /// The number of days the interest accrued over.
+    pub accrual_days: u32,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides interest accrual on the idle cash balances of a simulated account.
+#[derive(Clone, Debug)]
+pub struct InterestModule {
+    config: InterestConfig,
+    last_cutoff_day: Option<u64>,
+    accrued: HashMap<Currency, (f64, u32)>,
+    credits: Vec<InterestCredit>,
+}
+
+impl InterestModule {
+    /// Creates a new [`InterestModule`] instance.
+    #[must_use]
+    pub fn new(config: InterestConfig) -> Self {
+        Self {
+            config,
+            last_cutoff_day: None,
+            accrued: HashMap::new(),
+            credits: Vec::new(),
+        }
+    }
+
+    /// Returns all interest credits so far.
+    #[must_use]
+    pub fn credits(&self) -> &[InterestCredit] {
+        &self.credits
+    }
+
+    /// Returns the interest accrued but not yet credited for the given `currency` (if any).
+    #[must_use]
+    pub fn accrued(&self, currency: &Currency) -> Option<Money> {
+        self.accrued
+            .get(currency)
+            .map(|(amount, _)| Money::new(*amount, *currency))
+    }
+
+    /// Processes the daily cut-offs crossed since the last call at `ts_now`, accruing interest
+    /// on the free `balances` and returning the credits for the compounding periods ended.
+    ///
+    /// The first call only records the most recent cut-off, so accrual starts from there.
+    /// Interest credited within the cut-offs crossed compounds on the following days. Only
+    /// positive free balances accrue interest.
+    pub fn process(
+        &mut self,
+        ts_now: UnixNanos,
+        balances: &[AccountBalance],
+    ) -> Vec<InterestCredit> {
+        let Some(cutoff_day) = self.cutoff_day(ts_now) else {
+            return Vec::new();
+        };
+        let Some(last_cutoff_day) = self.last_cutoff_day.replace(cutoff_day) else {
+            return Vec::new();
+        };
+
+        let mut free: HashMap<Currency, f64> = balances
+            .iter()
+            .map(|balance| (balance.currency, balance.free.as_f64()))
+            .collect();
+        let mut credits = Vec::new();
+        for day in (last_cutoff_day + 1)..=cutoff_day {
+            let ts_event = UnixNanos::from(day * NANOSECONDS_IN_DAY + self.config.cutoff_ns);
+            let is_period_end = self.is_period_end(day);
+            for (currency, balance) in &mut free {
+                let Some(rate) = self.rate(currency, ts_event) else {
+                    continue;
+                };
+                let (amount, days) = self.accrued.entry(*currency).or_insert((0.0, 0));
+                if *balance > 0.0 {
+                    *amount += *balance * rate.to_f64().unwrap_or(0.0)
+                        / f64::from(self.config.day_count_basis);
+                }
+                *days += 1;
+
+                if is_period_end {
+                    let credit = InterestCredit {
+                        amount: Money::new(*amount, *currency),
+                        accrual_days: *days,
+                        ts_event,
+                    };
+                    *balance += credit.amount.as_f64();
+                    *amount = 0.0;
+                    *days = 0;
+                    if !credit.amount.is_zero() {
+                        credits.push(credit);
+                    }
+                }
+            }
+        }
+        credits.sort_by_key(|credit| credit.ts_event);
+        self.credits.extend(credits.iter().copied());
+        credits
+    }
+
+    /// Resets the module to its initial state.
+    pub fn reset(&mut self) {
+        self.last_cutoff_day = None;
+        self.accrued.clear();
+        self.credits.clear();
+    }
+
+    fn rate(&self, currency: &Currency, ts_event: UnixNanos) -> Option<Decimal> {
+        self.config
+            .rate_schedules
+            .get(currency)
+            .and_then(|schedule| schedule.rate_at(ts_event))
+            .or_else(|| self.config.rates.get(currency).copied())
+    }
+
+    fn cutoff_day(&self, ts_now: UnixNanos) -> Option<u64> {
+        ts_now
+            .checked_sub(self.config.cutoff_ns)
+            .map(|ns| ns / NANOSECONDS_IN_DAY)
+    }
+
+    fn is_period_end(&self, day: u64) -> bool {
+        let date = DateTime::from_timestamp((day * 86_400) as i64, 0)
+            .expect("day was in range")
+            .date_naive();
+        match self.config.compounding {
+            InterestCompounding::Daily => true,
+            InterestCompounding::Monthly => date.day() == 1,
+            InterestCompounding::Annually => date.ordinal() == 1,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::rate::RateRecord;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    // 2024-01-01 00:00 UTC
+    const JAN_1: u64 = 19_723 * NANOSECONDS_IN_DAY;
+
+    fn balance(amount: &str) -> AccountBalance {
+        let total = Money::from(amount);
+        AccountBalance::new(total, Money::new(0.0, total.currency), total)
+    }
+
+    fn module(rate: Decimal, compounding: InterestCompounding) -> InterestModule {
+        let mut config = InterestConfig {
+            compounding,
+            ..Default::default()
+        };
+        config.rates.insert(Currency::USD(), rate);
+        InterestModule::new(config)
+    }
+
+    #[rstest]
+    fn test_first_process_records_cutoff_without_accruing() {
+        let mut module = module(dec!(0.0365), InterestCompounding::Daily);
+
+        let credits = module.process(JAN_1.into(), &[balance("1000000.00 USD")]);
+
+        assert!(credits.is_empty());
+        assert!(module.accrued(&Currency::USD()).is_none());
+    }
+
+    #[rstest]
+    fn test_daily_compounding_credits_each_day() {
+        let mut module = module(dec!(0.0365), InterestCompounding::Daily);
+        let balances = [balance("1000000.00 USD"), balance("10.00000000 BTC")];
+
+        module.process(JAN_1.into(), &balances);
+        let credits = module.process((JAN_1 + 2 * NANOSECONDS_IN_DAY).into(), &balances);
+
+        // 1,000,000 USD at 3.65% p.a. over 365 days is 100.00 USD per day, compounding
+        let amounts: Vec<Money> = credits.iter().map(|c| c.amount).collect();
+        assert_eq!(
+            amounts,
+            vec![Money::from("100.00 USD"), Money::from("100.01 USD")]
+        );
+        assert!(credits.iter().all(|c| c.accrual_days == 1));
+        assert_eq!(
+            credits[0].ts_event,
+            UnixNanos::from(JAN_1 + NANOSECONDS_IN_DAY)
+        );
+        assert_eq!(module.credits().len(), 2);
+    }
+
+    #[rstest]
+    fn test_rate_schedule_takes_precedence_from_first_record() {
+        let mut module = module(dec!(0.0365), InterestCompounding::Daily);
+        module.config.rate_schedules.insert(
+            Currency::USD(),
+            RateSchedule::new(vec![RateRecord::new(
+                dec!(0.073),
+                (JAN_1 + 2 * NANOSECONDS_IN_DAY).into(),
+            )]),
+        );
+        let balances = [balance("1000000.00 USD")];
+
+        module.process(JAN_1.into(), &balances);
+        let credits = module.process((JAN_1 + 2 * NANOSECONDS_IN_DAY).into(), &balances);
+
+        let amounts: Vec<Money> = credits.iter().map(|c| c.amount).collect();
+        assert_eq!(
+            amounts,
+            vec![Money::from("100.00 USD"), Money::from("200.02 USD")]
+        );
+    }
+
+    #[rstest]
+    fn test_monthly_compounding_credits_at_month_end() {
+        let mut module = module(dec!(0.0365), InterestCompounding::Monthly);
+        let balances = [balance("1000000.00 USD")];
+
+        module.process(JAN_1.into(), &balances);
+        let credits = module.process((JAN_1 + 30 * NANOSECONDS_IN_DAY).into(), &balances);
+        assert!(credits.is_empty());
+        assert_eq!(
+            module.accrued(&Currency::USD()),
+            Some(Money::from("3000.00 USD"))
+        );
+
+        let credits = module.process((JAN_1 + 31 * NANOSECONDS_IN_DAY).into(), &balances);
+        assert_eq!(credits.len(), 1);
+        assert_eq!(credits[0].amount, Money::from("3100.00 USD"));
+        assert_eq!(credits[0].accrual_days, 31);
+        assert_eq!(module.accrued(&Currency::USD()), Some(Money::from("0 USD")));
+    }
+
+    #[rstest]
+    fn test_negative_rate_debits_and_reset() {
+        let mut module = module(dec!(-0.0073), InterestCompounding::Daily);
+        let balances = [balance("1000000.00 USD")];
+
+        module.process(JAN_1.into(), &balances);
+        let credits = module.process((JAN_1 + NANOSECONDS_IN_DAY).into(), &balances);
+        assert_eq!(credits[0].amount, Money::from("-20.00 USD"));
+
+        module.reset();
+        assert!(module.credits().is_empty());
+        assert!(module
+            .process((JAN_1 + 2 * NANOSECONDS_IN_DAY).into(), &balances)
+            .is_empty());
+    }
+}
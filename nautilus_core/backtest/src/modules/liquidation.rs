@@ -0,0 +1,492 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liquidation of simulated derivatives positions which breach their maintenance margin under
+//! the venue margin tier schedules.
+//!
+//! Each position is treated as isolated, with the margin posted at entry being the entry notional
+//! value divided by the leverage, capped by the max leverage of the position's margin tier.
+//! Liquidated positions are closed at their bankruptcy price, where the margin is exhausted, and
+//! the difference to the mark price is taken by (or charged to) the insurance fund.
+
+use std::collections::HashMap;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    accounts::tiers::MarginTierSchedule,
+    enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+    events::order::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, PositionId, TradeId, VenueOrderId},
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+/// The type of liquidation for positions which breach their maintenance margin.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LiquidationMode {
+    /// Close the whole position.
+    #[default]
+    Full,
+    /// Close a fraction of the position, repeating while the maintenance margin is breached.
+    Partial,
+}
+
+/// Configuration for the [`LiquidationModule`].
+#[derive(Clone, Debug)]
+pub struct LiquidationConfig {
+    /// The type of liquidation.
+    pub mode: LiquidationMode,
+    /// The fraction of the position quantity closed by each partial liquidation.
+    pub partial_fraction: Decimal,
+}
+
+impl Default for LiquidationConfig {
+    /// Creates a new default [`LiquidationConfig`] instance, with full liquidations.
+    fn default() -> Self {
+        Self {
+            mode: LiquidationMode::Full,
+            partial_fraction: Decimal::new(5, 1),
+        }
+    }
+}
+
+/// Represents a liquidation of a position which breached its maintenance margin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Liquidation {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub side: PositionSide,
+    pub mark_price: Price,
+    /// The isolated margin plus the unrealized PnL of the position at the mark price.
+    pub margin_balance: Money,
+    pub maintenance_margin: Money,
+    /// The price at which the margin for the position is exhausted.
+    pub bankruptcy_price: Price,
+    /// The quantity of the position liquidated.
+    pub quantity: Quantity,
+    /// The amount credited to (or if negative, charged to) the insurance fund.
+    pub insurance_fund_delta: Money,
+    /// The fill closing the liquidated quantity at the bankruptcy price.
+    pub fill: OrderFilled,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides liquidation of simulated positions using margin tier schedules.
+#[derive(Clone, Debug, Default)]
+pub struct LiquidationModule {
+    config: LiquidationConfig,
+    liquidated_qtys: HashMap<PositionId, Quantity>,
+    liquidations: Vec<Liquidation>,
+    insurance_fund: HashMap<Currency, Money>,
+}
+
+impl LiquidationModule {
+    /// Creates a new [`LiquidationModule`] instance.
+    #[must_use]
+    pub fn new(config: LiquidationConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns all liquidations so far.
+    #[must_use]
+    pub fn liquidations(&self) -> &[Liquidation] {
+        &self.liquidations
+    }
+
+    /// Returns the insurance fund balance for the given `currency` (if any liquidations).
+    #[must_use]
+    pub fn insurance_fund(&self, currency: &Currency) -> Option<Money> {
+        self.insurance_fund.get(currency).copied()
+    }
+
+    /// Checks the open `positions` at the given mark `prices`, returning liquidations for the
+    /// positions which have breached their maintenance margin.
+    ///
+    /// Only positions for instruments with a schedule in `margin_tiers` are checked. Positions
+    /// without a leverage in `leverages` are unleveraged. A position is not liquidated again
+    /// until the fill for its previous liquidation has been applied.
+    pub fn process(
+        &mut self,
+        ts_now: UnixNanos,
+        positions: &[&Position],
+        prices: &HashMap<InstrumentId, Price>,
+        margin_tiers: &HashMap<InstrumentId, MarginTierSchedule>,
+        leverages: &HashMap<InstrumentId, f64>,
+    ) -> Vec<Liquidation> {
+        let mut liquidations = Vec::new();
+        for position in positions.iter().filter(|p| p.is_open()) {
+            if self.liquidated_qtys.get(&position.id) == Some(&position.quantity) {
+                continue;
+            }
+            let (Some(schedule), Some(mark_price)) = (
+                margin_tiers.get(&position.instrument_id),
+                prices.get(&position.instrument_id),
+            ) else {
+                continue;
+            };
+            let leverage = leverages
+                .get(&position.instrument_id)
+                .copied()
+                .unwrap_or(1.0);
+
+            let margin = isolated_margin(position, schedule, leverage);
+            let notional = position.notional_value(*mark_price);
+            let margin_balance = margin + position.unrealized_pnl(*mark_price).as_f64();
+            let maintenance_margin = schedule.maintenance_margin(notional.as_f64());
+            if margin_balance > maintenance_margin {
+                continue;
+            }
+
+            let quantity = self.liquidation_quantity(position);
+            let bankruptcy_price = bankruptcy_price(position, margin);
+            let insurance_fund_delta =
+                position.calculate_pnl(bankruptcy_price.as_f64(), mark_price.as_f64(), quantity);
+            let fill = self.liquidation_fill(position, quantity, bankruptcy_price, ts_now);
+
+            let currency = position.settlement_currency;
+            let liquidation = Liquidation {
+                position_id: position.id,
+                instrument_id: position.instrument_id,
+                side: position.side,
+                mark_price: *mark_price,
+                margin_balance: Money::new(margin_balance, currency),
+                maintenance_margin: Money::new(maintenance_margin, currency),
+                bankruptcy_price,
+                quantity,
+                insurance_fund_delta,
+                fill,
+                ts_event: ts_now,
+            };
+            self.liquidated_qtys.insert(position.id, position.quantity);
+            *self
+                .insurance_fund
+                .entry(currency)
+                .or_insert_with(|| Money::new(0.0, currency)) += insurance_fund_delta;
+            liquidations.push(liquidation);
+        }
+        self.liquidations.extend(liquidations.iter().copied());
+        liquidations
+    }
+
+    /// Resets the module to its initial state.
+    pub fn reset(&mut self) {
+        self.liquidated_qtys.clear();
+        self.liquidations.clear();
+        self.insurance_fund.clear();
+    }
+
+    fn liquidation_quantity(&self, position: &Position) -> Quantity {
+        if self.config.mode == LiquidationMode::Full {
+            return position.quantity;
+        }
+        let fraction = self.config.partial_fraction.to_f64().unwrap_or(1.0);
+        let quantity = Quantity::new(
+            position.quantity.as_f64() * fraction,
+            position.size_precision,
+        );
+        if quantity.is_zero() || quantity >= position.quantity {
+            position.quantity
+        } else {
+            quantity
+        }
+    }
+
+    fn liquidation_fill(
+        &self,
+        position: &Position,
+        quantity: Quantity,
+        price: Price,
+        ts_now: UnixNanos,
+    ) -> OrderFilled {
+        let count = self.liquidations.len() + 1;
+        let order_side = match position.side {
+            PositionSide::Long => OrderSide::Sell,
+            _ => OrderSide::Buy,
+        };
+        OrderFilled::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            ClientOrderId::new(format!("LIQ-{}-{count}", position.id).as_str()),
+            VenueOrderId::new(format!("LIQ-{count}").as_str()),
+            position.account_id,
+            TradeId::new(format!("LIQ-{count}").as_str()),
+            order_side,
+            OrderType::Market,
+            quantity,
+            price,
+            position.quote_currency,
+            LiquiditySide::Taker,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(position.id),
+            None,
+        )
+    }
+}
+
+/// Returns the mark price at which the given linear `position` would be liquidated, with the
+/// isolated margin for the given `leverage` (none for flat or inverse positions).
+///
+/// The margin tier is taken at the entry notional value of the position.
+#[must_use]
+pub fn liquidation_price(
+    position: &Position,
+    schedule: &MarginTierSchedule,
+    leverage: f64,
+) -> Option<Price> {
+    if position.is_inverse || !position.is_open() {
+        return None;
+    }
+    let entry_notional = entry_notional(position);
+    let tier = schedule.tier(entry_notional);
+    let rate = tier.maintenance_rate.to_f64()?;
+    let amount = tier.maintenance_amount.to_f64()?;
+    let margin = isolated_margin(position, schedule, leverage);
+    let size = position.quantity.as_f64() * position.multiplier.as_f64();
+
+    let price = match position.side {
+        PositionSide::Long => (entry_notional - margin + amount) / (size * (1.0 - rate)),
+        PositionSide::Short => (entry_notional + margin + amount) / (size * (1.0 + rate)),
+        PositionSide::Flat | PositionSide::NoPositionSide => return None,
+    };
+    Some(Price::new(price.max(0.0), position.price_precision))
+}
+
+/// Returns the price at which the given isolated `margin` of the `position` is exhausted.
+#[must_use]
+pub fn bankruptcy_price(position: &Position, margin: f64) -> Price {
+    let size = position.quantity.as_f64() * position.multiplier.as_f64();
+    let entry_price = position.avg_px_open;
+    let sign = match position.side {
+        PositionSide::Long => -1.0,
+        _ => 1.0,
+    };
+    let price = if position.is_inverse {
+        // Inverse PnL is settled in the base currency: size * (1 / entry - 1 / price)
+        1.0 / (1.0 / entry_price - sign * margin / size)
+    } else {
+        entry_price + sign * margin / size
+    };
+    Price::new(price.max(0.0), position.price_precision)
+}
+
+fn entry_notional(position: &Position) -> f64 {
+    let entry_price = Price::new(position.avg_px_open, position.price_precision);
+    position.notional_value(entry_price).as_f64()
+}
+
+fn isolated_margin(position: &Position, schedule: &MarginTierSchedule, leverage: f64) -> f64 {
+    let entry_notional = entry_notional(position);
+    entry_notional / leverage.min(schedule.max_leverage(entry_notional))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        accounts::stubs::margin_tier_schedule,
+        enums::{OrderSide, OrderType},
+        instruments::{any::InstrumentAny, crypto_perpetual::CryptoPerpetual, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, qty: &str, price: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(qty))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1967.87")]
+    #[case(OrderSide::Sell, "2031.87")]
+    fn test_liquidation_price(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        margin_tier_schedule: MarginTierSchedule,
+        #[case] side: OrderSide,
+        #[case] expected: &str,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let position = position(&instrument, side, "10.000", "2000.00");
+
+        // 20,000 USDT notional at 50x is 400 USDT margin in the 0.4% tier
+        let price = liquidation_price(&position, &margin_tier_schedule, 50.0);
+
+        assert_eq!(price, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    fn test_process_liquidates_position_once_below_maintenance(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        margin_tier_schedule: MarginTierSchedule,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let position = position(&instrument, OrderSide::Buy, "10.000", "2000.00");
+        let margin_tiers = HashMap::from([(instrument.id(), margin_tier_schedule)]);
+        let leverages = HashMap::from([(instrument.id(), 50.0)]);
+        let mut module = LiquidationModule::new(LiquidationConfig::default());
+
+        let prices = HashMap::from([(instrument.id(), Price::from("1970.00"))]);
+        let liquidations = module.process(
+            UnixNanos::from(1),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+        assert!(liquidations.is_empty());
+
+        let prices = HashMap::from([(instrument.id(), Price::from("1959.00"))]);
+        let liquidations = module.process(
+            UnixNanos::from(2),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+        assert_eq!(liquidations.len(), 1);
+        assert_eq!(liquidations[0].position_id, position.id);
+        assert_eq!(
+            liquidations[0].margin_balance,
+            Money::from("-10.00000000 USDT")
+        );
+        assert_eq!(
+            liquidations[0].maintenance_margin,
+            Money::from("78.36000000 USDT")
+        );
+
+        // Already liquidated
+        let liquidations = module.process(
+            UnixNanos::from(3),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+        assert!(liquidations.is_empty());
+        assert_eq!(module.liquidations().len(), 1);
+    }
+
+    #[rstest]
+    fn test_full_liquidation_at_bankruptcy_price(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        margin_tier_schedule: MarginTierSchedule,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let position = position(&instrument, OrderSide::Buy, "10.000", "2000.00");
+        let margin_tiers = HashMap::from([(instrument.id(), margin_tier_schedule)]);
+        let leverages = HashMap::from([(instrument.id(), 50.0)]);
+        let prices = HashMap::from([(instrument.id(), Price::from("1965.00"))]);
+        let mut module = LiquidationModule::new(LiquidationConfig::default());
+
+        let liquidations = module.process(
+            UnixNanos::from(1),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+
+        // The 400 USDT margin is exhausted 40 USDT below the entry price
+        let liquidation = liquidations[0];
+        assert_eq!(liquidation.bankruptcy_price, Price::from("1960.00"));
+        assert_eq!(liquidation.quantity, Quantity::from("10.000"));
+        assert_eq!(liquidation.fill.order_side, OrderSide::Sell);
+        assert_eq!(liquidation.fill.last_px, Price::from("1960.00"));
+        assert_eq!(liquidation.fill.position_id, Some(position.id));
+        // The remaining margin balance goes to the insurance fund
+        assert_eq!(
+            liquidation.insurance_fund_delta,
+            Money::from("50.00000000 USDT")
+        );
+        assert_eq!(
+            module.insurance_fund(&Currency::USDT()),
+            Some(Money::from("50.00000000 USDT"))
+        );
+    }
+
+    #[rstest]
+    fn test_partial_liquidation_repeats_after_fill_applied(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        margin_tier_schedule: MarginTierSchedule,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut position = position(&instrument, OrderSide::Buy, "10.000", "2000.00");
+        let margin_tiers = HashMap::from([(instrument.id(), margin_tier_schedule)]);
+        let leverages = HashMap::from([(instrument.id(), 50.0)]);
+        let prices = HashMap::from([(instrument.id(), Price::from("1965.00"))]);
+        let mut module = LiquidationModule::new(LiquidationConfig {
+            mode: LiquidationMode::Partial,
+            ..Default::default()
+        });
+
+        let liquidations = module.process(
+            UnixNanos::from(1),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+        assert_eq!(liquidations[0].quantity, Quantity::from("5.000"));
+
+        // Not liquidated again until the fill has been applied
+        let liquidations_pending = module.process(
+            UnixNanos::from(2),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+        assert!(liquidations_pending.is_empty());
+
+        position.apply(&liquidations[0].fill);
+        let liquidations = module.process(
+            UnixNanos::from(3),
+            &[&position],
+            &prices,
+            &margin_tiers,
+            &leverages,
+        );
+        assert_eq!(liquidations[0].quantity, Quantity::from("2.500"));
+        assert_eq!(module.liquidations().len(), 2);
+    }
+}
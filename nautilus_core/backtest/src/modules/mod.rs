@@ -13,6 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod delivery;
+pub mod dividend;
+pub mod financing;
+pub mod interest;
+pub mod liquidation;
+pub mod settlement;
+
 use nautilus_common::logging::logger::Logger;
 use nautilus_core::nanos::UnixNanos;
 use nautilus_model::data::Data;
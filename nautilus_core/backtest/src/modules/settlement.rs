@@ -0,0 +1,309 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Settlement of simulated FX forward and non-deliverable forward (NDF) positions.
+//!
+//! Open positions are cash settled at the fixing rate once their settlement date is reached,
+//! being the value date for FX forwards and the fixing date for NDFs. The fixing rate is the
+//! fixing set for the instrument, otherwise the prevailing market price.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+    events::order::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, PositionId, TradeId, VenueOrderId},
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents the cash settlement of a forward position at its settlement date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Settlement {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub side: PositionSide,
+    /// The fixing rate the position was settled at.
+    pub fixing_price: Price,
+    pub quantity: Quantity,
+    /// The cash settlement amount, in the settlement currency for NDFs.
+    pub amount: Money,
+    /// The fill closing the position at the fixing rate.
+    pub fill: OrderFilled,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides cash settlement of simulated forward positions.
+#[derive(Clone, Debug, Default)]
+pub struct SettlementModule {
+    fixings: HashMap<InstrumentId, Price>,
+    settled: HashSet<PositionId>,
+    settlements: Vec<Settlement>,
+}
+
+impl SettlementModule {
+    /// Creates a new [`SettlementModule`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fixing rate published for the given `instrument_id`.
+    pub fn set_fixing(&mut self, instrument_id: InstrumentId, price: Price) {
+        self.fixings.insert(instrument_id, price);
+    }
+
+    /// Returns the fixing rate set for the given `instrument_id` (if any).
+    #[must_use]
+    pub fn fixing(&self, instrument_id: &InstrumentId) -> Option<Price> {
+        self.fixings.get(instrument_id).copied()
+    }
+
+    /// Returns all settlements so far.
+    #[must_use]
+    pub fn settlements(&self) -> &[Settlement] {
+        &self.settlements
+    }
+
+    /// Checks the open `positions` against the settlement dates of their `instruments` at
+    /// `ts_now`, returning settlements for the positions which have reached them.
+    ///
+    /// Positions without a fixing or market price in `prices` are settled once one is
+    /// available. A position is only settled once.
+    pub fn process(
+        &mut self,
+        ts_now: UnixNanos,
+        positions: &[&Position],
+        instruments: &HashMap<InstrumentId, InstrumentAny>,
+        prices: &HashMap<InstrumentId, Price>,
+    ) -> Vec<Settlement> {
+        let mut settlements = Vec::new();
+        for position in positions.iter().filter(|p| p.is_open()) {
+            if self.settled.contains(&position.id) {
+                continue;
+            }
+            let Some(instrument) = instruments.get(&position.instrument_id) else {
+                continue;
+            };
+            let Some(settlement_ns) = settlement_ns(instrument) else {
+                continue;
+            };
+            if ts_now < settlement_ns {
+                continue;
+            }
+            let Some(fixing_price) = self
+                .fixing(&position.instrument_id)
+                .or_else(|| prices.get(&position.instrument_id).copied())
+            else {
+                log::warn!(
+                    "Cannot settle {} for {}: no fixing or market price",
+                    position.id,
+                    position.instrument_id
+                );
+                continue;
+            };
+
+            let amount = settlement_amount(instrument, position, fixing_price);
+            let fill = self.settlement_fill(position, fixing_price, ts_now);
+            self.settled.insert(position.id);
+            settlements.push(Settlement {
+                position_id: position.id,
+                instrument_id: position.instrument_id,
+                side: position.side,
+                fixing_price,
+                quantity: position.quantity,
+                amount,
+                fill,
+                ts_event: ts_now,
+            });
+        }
+        self.settlements.extend(settlements.iter().copied());
+        settlements
+    }
+
+    /// Resets the module to its initial state.
+    pub fn reset(&mut self) {
+        self.fixings.clear();
+        self.settled.clear();
+        self.settlements.clear();
+    }
+
+    fn settlement_fill(&self, position: &Position, price: Price, ts_now: UnixNanos) -> OrderFilled {
+        let count = self.settlements.len() + 1;
+        let order_side = match position.side {
+            PositionSide::Long => OrderSide::Sell,
+            _ => OrderSide::Buy,
+        };
+        OrderFilled::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            ClientOrderId::new(format!("STL-{}-{count}", position.id).as_str()),
+            VenueOrderId::new(format!("STL-{count}").as_str()),
+            position.account_id,
+            TradeId::new(format!("STL-{count}").as_str()),
+            order_side,
+            OrderType::Market,
+            position.quantity,
+            price,
+            position.quote_currency,
+            LiquiditySide::Taker,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(position.id),
+            None,
+        )
+    }
+}
+
+/// Returns the settlement date for the given forward `instrument` (none for other instruments).
+#[must_use]
+pub fn settlement_ns(instrument: &InstrumentAny) -> Option<UnixNanos> {
+    match instrument {
+        InstrumentAny::FxForward(inst) => Some(inst.value_date),
+        InstrumentAny::Ndf(inst) => Some(inst.fixing_date),
+        _ => None,
+    }
+}
+
+fn settlement_amount(
+    instrument: &InstrumentAny,
+    position: &Position,
+    fixing_price: Price,
+) -> Money {
+    match instrument {
+        InstrumentAny::Ndf(inst) => inst.settlement_amount(
+            position.signed_qty,
+            position.avg_px_open,
+            fixing_price.as_f64(),
+        ),
+        _ => position.calculate_pnl(
+            position.avg_px_open,
+            fixing_price.as_f64(),
+            position.quantity,
+        ),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        instruments::{fx_forward::FxForward, ndf::Ndf, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, qty: &str, price: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(qty))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    #[rstest]
+    fn test_ndf_settles_at_fixing_on_fixing_date(ndf_usdinr_1m: Ndf) {
+        let fixing_date = ndf_usdinr_1m.fixing_date;
+        let instrument = InstrumentAny::Ndf(ndf_usdinr_1m);
+        let position = position(&instrument, OrderSide::Buy, "1000000", "83.3700");
+        let instruments = HashMap::from([(instrument.id(), instrument.clone())]);
+        let prices = HashMap::from([(instrument.id(), Price::from("83.9000"))]);
+        let mut module = SettlementModule::new();
+        module.set_fixing(instrument.id(), Price::from("84.0000"));
+
+        let before = UnixNanos::from(fixing_date.as_u64() - 1);
+        assert!(module
+            .process(before, &[&position], &instruments, &prices)
+            .is_empty());
+
+        let settlements = module.process(fixing_date, &[&position], &instruments, &prices);
+
+        assert_eq!(settlements.len(), 1);
+        let settlement = settlements[0];
+        assert_eq!(settlement.fixing_price, Price::from("84.0000"));
+        assert_eq!(settlement.amount, Money::from("7500.00 USD"));
+        assert_eq!(settlement.fill.order_side, OrderSide::Sell);
+        assert_eq!(settlement.fill.last_px, Price::from("84.0000"));
+        assert_eq!(settlement.fill.last_qty, Quantity::from("1000000"));
+        assert_eq!(settlement.fill.position_id, Some(position.id));
+
+        // Already settled
+        assert!(module
+            .process(fixing_date, &[&position], &instruments, &prices)
+            .is_empty());
+        assert_eq!(module.settlements().len(), 1);
+    }
+
+    #[rstest]
+    fn test_fx_forward_settles_at_market_price_on_value_date(fx_forward_eurusd_3m: FxForward) {
+        let value_date = fx_forward_eurusd_3m.value_date;
+        let instrument = InstrumentAny::FxForward(fx_forward_eurusd_3m);
+        let position = position(&instrument, OrderSide::Sell, "100000", "1.08965");
+        let instruments = HashMap::from([(instrument.id(), instrument.clone())]);
+        let prices = HashMap::from([(instrument.id(), Price::from("1.08465"))]);
+        let mut module = SettlementModule::new();
+
+        let settlements = module.process(value_date, &[&position], &instruments, &prices);
+
+        assert_eq!(settlements.len(), 1);
+        assert_eq!(settlements[0].fill.order_side, OrderSide::Buy);
+        assert_eq!(settlements[0].amount, Money::from("500.00 USD"));
+    }
+
+    #[rstest]
+    fn test_waits_for_fixing_or_price(ndf_usdinr_1m: Ndf) {
+        let fixing_date = ndf_usdinr_1m.fixing_date;
+        let instrument = InstrumentAny::Ndf(ndf_usdinr_1m);
+        let position = position(&instrument, OrderSide::Buy, "1000000", "83.3700");
+        let instruments = HashMap::from([(instrument.id(), instrument.clone())]);
+        let mut module = SettlementModule::new();
+
+        assert!(module
+            .process(fixing_date, &[&position], &instruments, &HashMap::new())
+            .is_empty());
+
+        module.set_fixing(instrument.id(), Price::from("83.3700"));
+        let settlements = module.process(fixing_date, &[&position], &instruments, &HashMap::new());
+        assert_eq!(settlements[0].amount, Money::from("0.00 USD"));
+
+        module.reset();
+        assert!(module.settlements().is_empty());
+        assert_eq!(module.fixing(&instrument.id()), None);
+    }
+}
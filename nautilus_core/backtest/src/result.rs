@@ -0,0 +1,757 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The results of a backtest run and their export for analysis.
+//!
+//! A [`BacktestResult`] is exported as a bundle of Parquet files (fills, positions, equity curve
+//! and per-bar stats) together with a self-contained HTML tear sheet with the equity curve
+//! rendered as inline SVG.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs::{self, File},
+    path::Path,
+    sync::Arc,
+};
+
+use arrow::{
+    array::{ArrayRef, Float64Array, StringArray, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use nautilus_common::equity::EquityUpdate;
+use nautilus_core::{datetime::unix_nanos_to_iso8601, nanos::UnixNanos};
+use nautilus_model::{
+    data::bar::BarType,
+    events::order::filled::OrderFilled,
+    position::Position,
+    types::{
+        currency::Currency,
+        fixed::{fixed_i64_slice_to_f64, fixed_u64_slice_to_f64},
+        price::Price,
+    },
+};
+use parquet::arrow::ArrowWriter;
+use plotters::prelude::*;
+use ustr::Ustr;
+
+/// The file names of an exported backtest result bundle.
+pub const FILLS_FILE: &str = "fills.parquet";
+pub const POSITIONS_FILE: &str = "positions.parquet";
+pub const EQUITY_FILE: &str = "equity.parquet";
+pub const BAR_STATS_FILE: &str = "bar_stats.parquet";
+pub const TEAR_SHEET_FILE: &str = "tearsheet.html";
+
+/// Represents the total balance of an account currency at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquityPoint {
+    pub ts: UnixNanos,
+    pub currency: Currency,
+    pub total: f64,
+}
+
+impl From<&EquityUpdate> for EquityPoint {
+    /// Creates an [`EquityPoint`] from a live [`EquityUpdate`], so that recorded live equity can
+    /// be analyzed as for a backtest.
+    fn from(update: &EquityUpdate) -> Self {
+        Self {
+            ts: update.ts_event,
+            currency: update.equity.currency,
+            total: update.equity.as_f64(),
+        }
+    }
+}
+
+/// Represents the state of the backtest sampled on each processed bar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BarStats {
+    pub ts: UnixNanos,
+    pub bar_type: BarType,
+    pub close: Price,
+    /// The open positions for the bar instrument.
+    pub open_positions: usize,
+    /// The cumulative fills for the bar instrument.
+    pub fills: usize,
+}
+
+/// Summary statistics of the equity curve for a currency.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquitySummary {
+    pub start: f64,
+    pub end: f64,
+    /// The total return as a fraction of the starting equity.
+    pub total_return: f64,
+    /// The maximum drawdown as a fraction of the running peak equity.
+    pub max_drawdown: f64,
+}
+
+/// The results of a backtest run.
+#[derive(Clone, Debug, Default)]
+pub struct BacktestResult {
+    pub fills: Vec<OrderFilled>,
+    pub positions: Vec<Position>,
+    pub equity_curve: Vec<EquityPoint>,
+    pub bar_stats: Vec<BarStats>,
+}
+
+impl BacktestResult {
+    /// Creates a new [`BacktestResult`] instance.
+    #[must_use]
+    pub const fn new(
+        fills: Vec<OrderFilled>,
+        positions: Vec<Position>,
+        equity_curve: Vec<EquityPoint>,
+        bar_stats: Vec<BarStats>,
+    ) -> Self {
+        Self {
+            fills,
+            positions,
+            equity_curve,
+            bar_stats,
+        }
+    }
+
+    /// Returns the equity curve summary for each currency code.
+    #[must_use]
+    pub fn equity_summaries(&self) -> BTreeMap<Ustr, EquitySummary> {
+        let mut summaries = BTreeMap::new();
+        for (currency, points) in self.equity_by_currency() {
+            let start = points[0].1;
+            let end = points[points.len() - 1].1;
+            let mut peak = f64::MIN;
+            let mut max_drawdown = 0.0_f64;
+            for (_, total) in &points {
+                peak = peak.max(*total);
+                if peak > 0.0 {
+                    max_drawdown = max_drawdown.max((peak - total) / peak);
+                }
+            }
+            let total_return = if start == 0.0 { 0.0 } else { end / start - 1.0 };
+
+            summaries.insert(
+                currency,
+                EquitySummary {
+                    start,
+                    end,
+                    total_return,
+                    max_drawdown,
+                },
+            );
+        }
+        summaries
+    }
+
+    /// Returns the realized PnL summed over all positions, per settlement currency code.
+    #[must_use]
+    pub fn realized_pnls(&self) -> BTreeMap<Ustr, f64> {
+        let mut pnls = BTreeMap::new();
+        for pnl in self.positions.iter().filter_map(|p| p.realized_pnl) {
+            *pnls.entry(pnl.currency.code).or_insert(0.0) += pnl.as_f64();
+        }
+        pnls
+    }
+
+    /// Returns the commissions summed over all fills, per currency code.
+    #[must_use]
+    pub fn commissions(&self) -> BTreeMap<Ustr, f64> {
+        let mut commissions = BTreeMap::new();
+        for commission in self.fills.iter().filter_map(|f| f.commission) {
+            *commissions.entry(commission.currency.code).or_insert(0.0) += commission.as_f64();
+        }
+        commissions
+    }
+
+    /// Exports the result to the directory at the given `path`, creating it if needed.
+    ///
+    /// Writes the fills, positions, equity curve and per-bar stats as Parquet files, and an
+    /// HTML tear sheet.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the directory or any file cannot be written.
+    pub fn export(&self, path: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(path)?;
+        write_parquet(&path.join(FILLS_FILE), self.fills_batch()?)?;
+        write_parquet(&path.join(POSITIONS_FILE), self.positions_batch()?)?;
+        write_parquet(&path.join(EQUITY_FILE), self.equity_batch()?)?;
+        write_parquet(&path.join(BAR_STATS_FILE), self.bar_stats_batch()?)?;
+        fs::write(path.join(TEAR_SHEET_FILE), self.tear_sheet()?)?;
+
+        log::info!("Exported backtest result to {}", path.display());
+        Ok(())
+    }
+
+    /// Returns the fills as an Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the record batch cannot be created.
+    pub fn fills_batch(&self) -> anyhow::Result<RecordBatch> {
+        let fills = &self.fills;
+        let schema = Schema::new(vec![
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("venue_order_id", DataType::Utf8, false),
+            Field::new("trade_id", DataType::Utf8, false),
+            Field::new("position_id", DataType::Utf8, true),
+            Field::new("order_side", DataType::Utf8, false),
+            Field::new("last_qty", DataType::Float64, false),
+            Field::new("last_px", DataType::Float64, false),
+            Field::new("liquidity_side", DataType::Utf8, false),
+            Field::new("commission", DataType::Float64, true),
+            Field::new("commission_currency", DataType::Utf8, true),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt64Array::from_iter_values(
+                fills.iter().map(|f| f.ts_event.as_u64()),
+            )),
+            strings(fills.iter().map(|f| Some(f.instrument_id.to_string()))),
+            strings(fills.iter().map(|f| Some(f.strategy_id.to_string()))),
+            strings(fills.iter().map(|f| Some(f.client_order_id.to_string()))),
+            strings(fills.iter().map(|f| Some(f.venue_order_id.to_string()))),
+            strings(fills.iter().map(|f| Some(f.trade_id.to_string()))),
+            strings(fills.iter().map(|f| f.position_id.map(|id| id.to_string()))),
+            strings(fills.iter().map(|f| Some(f.order_side.to_string()))),
+            quantities(fills.iter().map(|f| f.last_qty.raw)),
+            prices(fills.iter().map(|f| f.last_px.raw)),
+            strings(fills.iter().map(|f| Some(f.liquidity_side.to_string()))),
+            Arc::new(Float64Array::from_iter(
+                fills.iter().map(|f| f.commission.map(|c| c.as_f64())),
+            )),
+            strings(
+                fills
+                    .iter()
+                    .map(|f| f.commission.map(|c| c.currency.code.to_string())),
+            ),
+        ];
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+
+    /// Returns the positions as an Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the record batch cannot be created.
+    pub fn positions_batch(&self) -> anyhow::Result<RecordBatch> {
+        let positions = &self.positions;
+        let schema = Schema::new(vec![
+            Field::new("position_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("entry", DataType::Utf8, false),
+            Field::new("side", DataType::Utf8, false),
+            Field::new("quantity", DataType::Float64, false),
+            Field::new("peak_qty", DataType::Float64, false),
+            Field::new("avg_px_open", DataType::Float64, false),
+            Field::new("avg_px_close", DataType::Float64, true),
+            Field::new("realized_return", DataType::Float64, false),
+            Field::new("realized_pnl", DataType::Float64, true),
+            Field::new("settlement_currency", DataType::Utf8, false),
+            Field::new("ts_opened", DataType::UInt64, false),
+            Field::new("ts_closed", DataType::UInt64, true),
+            Field::new("duration_ns", DataType::UInt64, false),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            strings(positions.iter().map(|p| Some(p.id.to_string()))),
+            strings(positions.iter().map(|p| Some(p.instrument_id.to_string()))),
+            strings(positions.iter().map(|p| Some(p.strategy_id.to_string()))),
+            strings(positions.iter().map(|p| Some(p.entry.to_string()))),
+            strings(positions.iter().map(|p| Some(p.side.to_string()))),
+            quantities(positions.iter().map(|p| p.quantity.raw)),
+            quantities(positions.iter().map(|p| p.peak_qty.raw)),
+            Arc::new(Float64Array::from_iter_values(
+                positions.iter().map(|p| p.avg_px_open),
+            )),
+            Arc::new(Float64Array::from_iter(
+                positions.iter().map(|p| p.avg_px_close),
+            )),
+            Arc::new(Float64Array::from_iter_values(
+                positions.iter().map(|p| p.realized_return),
+            )),
+            Arc::new(Float64Array::from_iter(
+                positions.iter().map(|p| p.realized_pnl.map(|m| m.as_f64())),
+            )),
+            strings(
+                positions
+                    .iter()
+                    .map(|p| Some(p.settlement_currency.code.to_string())),
+            ),
+            Arc::new(UInt64Array::from_iter_values(
+                positions.iter().map(|p| p.ts_opened.as_u64()),
+            )),
+            Arc::new(UInt64Array::from_iter(
+                positions.iter().map(|p| p.ts_closed.map(|ts| ts.as_u64())),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                positions.iter().map(|p| p.duration_ns),
+            )),
+        ];
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+
+    /// Returns the equity curve as an Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the record batch cannot be created.
+    pub fn equity_batch(&self) -> anyhow::Result<RecordBatch> {
+        let points = &self.equity_curve;
+        let schema = Schema::new(vec![
+            Field::new("ts", DataType::UInt64, false),
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("total", DataType::Float64, false),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt64Array::from_iter_values(
+                points.iter().map(|p| p.ts.as_u64()),
+            )),
+            strings(points.iter().map(|p| Some(p.currency.code.to_string()))),
+            Arc::new(Float64Array::from_iter_values(
+                points.iter().map(|p| p.total),
+            )),
+        ];
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+
+    /// Returns the per-bar stats as an Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the record batch cannot be created.
+    pub fn bar_stats_batch(&self) -> anyhow::Result<RecordBatch> {
+        let stats = &self.bar_stats;
+        let schema = Schema::new(vec![
+            Field::new("ts", DataType::UInt64, false),
+            Field::new("bar_type", DataType::Utf8, false),
+            Field::new("close", DataType::Float64, false),
+            Field::new("open_positions", DataType::UInt64, false),
+            Field::new("fills", DataType::UInt64, false),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt64Array::from_iter_values(
+                stats.iter().map(|s| s.ts.as_u64()),
+            )),
+            strings(stats.iter().map(|s| Some(s.bar_type.to_string()))),
+            prices(stats.iter().map(|s| s.close.raw)),
+            Arc::new(UInt64Array::from_iter_values(
+                stats.iter().map(|s| s.open_positions as u64),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                stats.iter().map(|s| s.fills as u64),
+            )),
+        ];
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+
+    /// Returns a self-contained HTML tear sheet of the result.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the equity curve chart cannot be rendered.
+    pub fn tear_sheet(&self) -> anyhow::Result<String> {
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Backtest Tear Sheet</title>\n<style>\n\
+             body { font-family: sans-serif; margin: 2em; }\n\
+             table { border-collapse: collapse; margin-bottom: 2em; }\n\
+             th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: right; }\n\
+             </style>\n</head>\n<body>\n<h1>Backtest Tear Sheet</h1>\n",
+        );
+
+        html.push_str("<h2>Summary</h2>\n");
+        let closed = self.positions.iter().filter(|p| p.is_closed()).count();
+        html_table(
+            &mut html,
+            &["Statistic", "Value"],
+            vec![
+                vec!["Fills".to_string(), self.fills.len().to_string()],
+                vec!["Positions".to_string(), self.positions.len().to_string()],
+                vec!["Positions closed".to_string(), closed.to_string()],
+            ],
+        );
+
+        let realized_pnls = self.realized_pnls();
+        let commissions = self.commissions();
+        let summaries = self.equity_summaries();
+        let mut currencies: Vec<&Ustr> = realized_pnls
+            .keys()
+            .chain(commissions.keys())
+            .chain(summaries.keys())
+            .collect();
+        currencies.sort();
+        currencies.dedup();
+        let rows = currencies
+            .into_iter()
+            .map(|currency| {
+                let summary = summaries.get(currency);
+                vec![
+                    currency.to_string(),
+                    format_value(summary.map(|s| s.start)),
+                    format_value(summary.map(|s| s.end)),
+                    format_percent(summary.map(|s| s.total_return)),
+                    format_percent(summary.map(|s| s.max_drawdown)),
+                    format_value(realized_pnls.get(currency).copied()),
+                    format_value(commissions.get(currency).copied()),
+                ]
+            })
+            .collect();
+        html_table(
+            &mut html,
+            &[
+                "Currency",
+                "Start",
+                "End",
+                "Return",
+                "Max drawdown",
+                "Realized PnL",
+                "Commissions",
+            ],
+            rows,
+        );
+
+        html.push_str("<h2>Equity Curve</h2>\n");
+        for (currency, points) in self.equity_by_currency() {
+            html.push_str(&render_equity_svg(&currency, &points)?);
+            html.push('\n');
+        }
+
+        html.push_str("<h2>Positions</h2>\n");
+        let rows = self
+            .positions
+            .iter()
+            .map(|p| {
+                vec![
+                    p.id.to_string(),
+                    p.instrument_id.to_string(),
+                    p.entry.to_string(),
+                    p.peak_qty.to_string(),
+                    format!("{:.5}", p.avg_px_open),
+                    format_value(p.avg_px_close),
+                    p.realized_pnl.map_or_else(String::new, |m| m.to_string()),
+                    unix_nanos_to_iso8601(p.ts_opened),
+                    p.ts_closed.map_or_else(String::new, unix_nanos_to_iso8601),
+                ]
+            })
+            .collect();
+        html_table(
+            &mut html,
+            &[
+                "Position",
+                "Instrument",
+                "Entry",
+                "Peak qty",
+                "Avg px open",
+                "Avg px close",
+                "Realized PnL",
+                "Opened",
+                "Closed",
+            ],
+            rows,
+        );
+
+        html.push_str("<h2>Fills</h2>\n");
+        let rows = self
+            .fills
+            .iter()
+            .map(|f| {
+                vec![
+                    unix_nanos_to_iso8601(f.ts_event),
+                    f.instrument_id.to_string(),
+                    f.client_order_id.to_string(),
+                    f.order_side.to_string(),
+                    f.last_qty.to_string(),
+                    f.last_px.to_string(),
+                    f.liquidity_side.to_string(),
+                    f.commission.map_or_else(String::new, |m| m.to_string()),
+                ]
+            })
+            .collect();
+        html_table(
+            &mut html,
+            &[
+                "Time",
+                "Instrument",
+                "Order",
+                "Side",
+                "Qty",
+                "Price",
+                "Liquidity",
+                "Commission",
+            ],
+            rows,
+        );
+
+        html.push_str("</body>\n</html>\n");
+        Ok(html)
+    }
+
+    // Returns the (timestamp, total) points of the equity curve grouped by currency
+    fn equity_by_currency(&self) -> BTreeMap<Ustr, Vec<(u64, f64)>> {
+        let mut curves: BTreeMap<Ustr, Vec<(u64, f64)>> = BTreeMap::new();
+        for point in &self.equity_curve {
+            curves
+                .entry(point.currency.code)
+                .or_default()
+                .push((point.ts.as_u64(), point.total));
+        }
+        curves
+    }
+}
+
+fn strings(values: impl Iterator<Item = Option<String>>) -> ArrayRef {
+    Arc::new(values.collect::<StringArray>())
+}
+
+// Converts the raw fixed-point values in a single batch
+fn prices(raws: impl Iterator<Item = i64>) -> ArrayRef {
+    let raws: Vec<i64> = raws.collect();
+    Arc::new(Float64Array::from(fixed_i64_slice_to_f64(&raws)))
+}
+
+// Converts the raw fixed-point values in a single batch
+fn quantities(raws: impl Iterator<Item = u64>) -> ArrayRef {
+    let raws: Vec<u64> = raws.collect();
+    Arc::new(Float64Array::from(fixed_u64_slice_to_f64(&raws)))
+}
+
+fn write_parquet(path: &Path, batch: RecordBatch) -> anyhow::Result<()> {
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+fn format_value(value: Option<f64>) -> String {
+    value.map_or_else(String::new, |v| format!("{v:.2}"))
+}
+
+fn format_percent(value: Option<f64>) -> String {
+    value.map_or_else(String::new, |v| format!("{:.2}%", v * 100.0))
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn html_table(html: &mut String, headers: &[&str], rows: Vec<Vec<String>>) {
+    html.push_str("<table>\n<tr>");
+    for header in headers {
+        let _ = write!(html, "<th>{}</th>", escape_html(header));
+    }
+    html.push_str("</tr>\n");
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            let _ = write!(html, "<td>{}</td>", escape_html(&cell));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+fn render_equity_svg(currency: &Ustr, points: &[(u64, f64)]) -> anyhow::Result<String> {
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, (960, 400)).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        let (ts_min, ts_max) = (points[0].0, points[points.len() - 1].0);
+        let (min, max) = points
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), (_, v)| {
+                (min.min(*v), max.max(*v))
+            });
+        // Pad the ranges so that flat curves and single points are still drawn
+        let pad = ((max - min) * 0.05).max(max.abs() * 0.001).max(1e-9);
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(format!("Equity ({currency})"), ("sans-serif", 20))
+            .margin(10)
+            .x_label_area_size(40)
+            .y_label_area_size(80)
+            .build_cartesian_2d(ts_min..ts_max.max(ts_min + 1), (min - pad)..(max + pad))?;
+        chart
+            .configure_mesh()
+            .x_labels(6)
+            .x_label_formatter(&|ts| unix_nanos_to_iso8601(UnixNanos::from(*ts))[..19].to_string())
+            .draw()?;
+        chart.draw_series(LineSeries::new(points.iter().copied(), &BLUE))?;
+        root.present()?;
+    }
+    Ok(svg)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::stub_bar,
+        enums::{LiquiditySide, OrderSide},
+        identifiers::{PositionId, TradeId},
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::{money::Money, quantity::Quantity},
+    };
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn equity(ts: u64, total: f64) -> EquityPoint {
+        EquityPoint {
+            ts: UnixNanos::from(ts),
+            currency: Currency::USD(),
+            total,
+        }
+    }
+
+    fn result(audusd_sim: &CurrencyPair) -> BacktestResult {
+        let instrument = InstrumentAny::CurrencyPair(*audusd_sim);
+        let mut buy = OrderFilled {
+            instrument_id: audusd_sim.id,
+            order_side: OrderSide::Buy,
+            last_qty: Quantity::from(100_000),
+            last_px: Price::from("1.00000"),
+            liquidity_side: LiquiditySide::Taker,
+            position_id: Some(PositionId::new("P-1")),
+            commission: Some(Money::from("2 USD")),
+            ..Default::default()
+        };
+        buy.trade_id = TradeId::new("1");
+        let mut sell = buy;
+        sell.order_side = OrderSide::Sell;
+        sell.last_px = Price::from("1.00100");
+        sell.trade_id = TradeId::new("2");
+        let mut position = Position::new(&instrument, buy);
+        position.apply(&sell);
+        let bar = stub_bar();
+
+        BacktestResult::new(
+            vec![buy, sell],
+            vec![position],
+            vec![
+                equity(1, 1_000.0),
+                equity(2, 1_100.0),
+                equity(3, 990.0),
+                equity(4, 1_050.0),
+            ],
+            vec![BarStats {
+                ts: UnixNanos::from(1),
+                bar_type: bar.bar_type,
+                close: bar.close,
+                open_positions: 0,
+                fills: 2,
+            }],
+        )
+    }
+
+    #[rstest]
+    fn test_equity_point_from_update() {
+        let update = EquityUpdate {
+            equity: Money::from("1010 USD"),
+            balance: Money::from("1000 USD"),
+            unrealized_pnl: Money::from("10 USD"),
+            period_return: 0.0,
+            ts_event: UnixNanos::from(1),
+            ts_init: UnixNanos::from(2),
+        };
+
+        assert_eq!(EquityPoint::from(&update), equity(1, 1_010.0));
+    }
+
+    #[rstest]
+    fn test_equity_summaries(audusd_sim: CurrencyPair) {
+        let summaries = result(&audusd_sim).equity_summaries();
+        let summary = summaries[&Ustr::from("USD")];
+
+        assert_eq!(summary.start, 1_000.0);
+        assert_eq!(summary.end, 1_050.0);
+        assert!((summary.total_return - 0.05).abs() < 1e-12);
+        assert!((summary.max_drawdown - 0.1).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_pnls_and_commissions(audusd_sim: CurrencyPair) {
+        let result = result(&audusd_sim);
+
+        assert_eq!(result.commissions()[&Ustr::from("USD")], 4.0);
+        assert_eq!(result.realized_pnls()[&Ustr::from("USD")], 96.0);
+    }
+
+    #[rstest]
+    fn test_fills_batch_converts_prices_and_quantities(audusd_sim: CurrencyPair) {
+        let batch = result(&audusd_sim).fills_batch().unwrap();
+        let column = |name: &str| {
+            batch
+                .column_by_name(name)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+
+        assert_eq!(column("last_qty"), vec![100_000.0, 100_000.0]);
+        assert_eq!(column("last_px"), vec![1.0, 1.001]);
+    }
+
+    #[rstest]
+    fn test_tear_sheet(audusd_sim: CurrencyPair) {
+        let html = result(&audusd_sim).tear_sheet().unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<svg"));
+        assert!(html.contains("Equity (USD)"));
+        assert!(html.contains("<td>5.00%</td>"));
+        assert!(html.contains("<td>10.00%</td>"));
+        assert!(html.contains("P-1"));
+    }
+
+    #[rstest]
+    fn test_export(audusd_sim: CurrencyPair) {
+        let dir = tempdir().unwrap();
+        result(&audusd_sim).export(dir.path()).unwrap();
+
+        for (file, rows) in [
+            (FILLS_FILE, 2),
+            (POSITIONS_FILE, 1),
+            (EQUITY_FILE, 4),
+            (BAR_STATS_FILE, 1),
+        ] {
+            let reader =
+                SerializedFileReader::new(File::open(dir.path().join(file)).unwrap()).unwrap();
+            assert_eq!(reader.metadata().file_metadata().num_rows(), rows, "{file}");
+        }
+        assert!(dir.path().join(TEAR_SHEET_FILE).exists());
+    }
+
+    #[rstest]
+    fn test_export_empty_result() {
+        let dir = tempdir().unwrap();
+        BacktestResult::default().export(dir.path()).unwrap();
+
+        let html = fs::read_to_string(dir.path().join(TEAR_SHEET_FILE)).unwrap();
+        assert!(!html.contains("<svg"));
+    }
+}
@@ -0,0 +1,759 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `SandboxExecutionClient` for paper trading in live nodes.
+//!
+//! The sandbox fills orders locally against an embedded [`SimulatedExchange`], which is fed the
+//! real market data published by the live data clients for the sandbox venue, so strategies can
+//! be paper traded without a venue test environment.
+//!
+//! Market data is buffered as it is received from the message bus (which is borrowed by the
+//! publisher), and applied to the exchange by a timer at the configured interval, before each
+//! trading command, or on [`SandboxExecutionClient::process_pending`].
+//!
+//! The sandbox is registered with the `ExecutionEngine` of a live node through the
+//! [`ExecutionClient`] returned by [`SandboxExecutionClient::execution_client`].
+
+use std::{
+    any::Any,
+    cell::{Ref, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    config::{check_setting, ValidateConfig},
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::{
+    client::{ExecutionClient, ExecutionHandler, NativeBatchSupport},
+    messages::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, SubmitOrder, SubmitOrderList,
+        TradingCommand,
+    },
+};
+use nautilus_model::{
+    data::{
+        deltas::{OrderBookDeltas, OrderBookDeltas_API},
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data,
+    },
+    enums::{AccountType, OmsType, OrderSide},
+    events::order::{OrderEventAny, OrderSubmitted},
+    identifiers::{AccountId, ClientId, TraderId, Venue},
+    orders::any::OrderAny,
+    types::currency::Currency,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{config::BacktestVenueConfig, exchange::SimulatedExchange};
+
+/// Configuration for `SandboxExecutionClient` instances.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SandboxExecutionClientConfig {
+    /// The client ID (the venue name if None).
+    #[serde(default)]
+    pub client_id: Option<ClientId>,
+    /// The simulated venue, which must be the venue of the live market data to fill against.
+    pub venue: BacktestVenueConfig,
+    /// The interval at which received market data is applied to the exchange (100 ms if None).
+    #[serde(default)]
+    pub process_interval_ms: Option<u64>,
+}
+
+impl ValidateConfig for SandboxExecutionClientConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.process_interval_ms != Some(0),
+            "sandbox.process_interval_ms",
+            "must be positive",
+        )?;
+        self.venue.validate()
+    }
+}
+
+/// Provides an execution client which fills orders against an embedded simulated exchange,
+/// driven by live market data.
+pub struct SandboxExecutionClient {
+    client_id: ClientId,
+    account_id: AccountId,
+    oms_type: OmsType,
+    account_type: AccountType,
+    base_currency: Option<Currency>,
+    process_interval_ns: u64,
+    exchange: Rc<RefCell<SimulatedExchange>>,
+    pending: Rc<RefCell<VecDeque<Data>>>,
+    handler: Option<ShareableMessageHandler>,
+    clock: &'static AtomicTime,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl SandboxExecutionClient {
+    /// Creates a new [`SandboxExecutionClient`] instance, initializing the venue account with
+    /// the starting balances.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `config` is invalid, or if the account could not
+    /// be initialized.
+    pub fn new(
+        config: SandboxExecutionClientConfig,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> anyhow::Result<Self> {
+        config.validate()?;
+
+        let venue = config.venue.name;
+        let oms_type = config.venue.oms_type;
+        let account_type = config.venue.account_type;
+        let base_currency = config.venue.base_currency;
+        let mut exchange =
+            SimulatedExchange::from_config(config.venue, msgbus.clone(), cache.clone(), clock)?;
+
+        let account_id = AccountId::new(format!("{venue}-001"));
+        exchange.initialize_account(account_id)?;
+
+        Ok(Self {
+            client_id: config
+                .client_id
+                .unwrap_or_else(|| ClientId::new(venue.as_str())),
+            account_id,
+            oms_type,
+            account_type,
+            base_currency,
+            process_interval_ns: config.process_interval_ms.unwrap_or(100)
+                * NANOSECONDS_IN_MILLISECOND,
+            exchange: Rc::new(RefCell::new(exchange)),
+            pending: Rc::new(RefCell::new(VecDeque::new())),
+            handler: None,
+            clock,
+            cache,
+            msgbus,
+        })
+    }
+
+    #[must_use]
+    pub const fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    #[must_use]
+    pub const fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        self.exchange.borrow().id()
+    }
+
+    #[must_use]
+    pub fn exchange(&self) -> Ref<'_, SimulatedExchange> {
+        self.exchange.borrow()
+    }
+
+    #[must_use]
+    pub const fn is_connected(&self) -> bool {
+        self.handler.is_some()
+    }
+
+    /// Returns an execution client for the sandbox `client` to register with the
+    /// `ExecutionEngine` of a live node, which executes the commands routed to it against the
+    /// sandbox.
+    #[must_use]
+    pub fn execution_client(client: &Rc<RefCell<Self>>, trader_id: TraderId) -> ExecutionClient {
+        let sandbox = client.borrow();
+        let mut exec_client = ExecutionClient::new(
+            trader_id,
+            sandbox.client_id,
+            sandbox.venue(),
+            sandbox.oms_type,
+            sandbox.account_id,
+            sandbox.account_type,
+            sandbox.base_currency,
+            sandbox.clock,
+            sandbox.cache.clone(),
+            sandbox.msgbus.clone(),
+        );
+        exec_client.native_batch = NativeBatchSupport {
+            submit_order_list: true,
+            cancel_all_orders: true,
+            batch_cancel_orders: true,
+            batch_modify_orders: false,
+        };
+        exec_client.is_connected = sandbox.is_connected();
+        exec_client.set_handler(client.clone());
+        exec_client
+    }
+
+    /// Subscribes to the quotes, trades and order book deltas of the venue on the message bus,
+    /// and starts the timer on the `clock` which applies the received data to the exchange.
+    pub fn connect(&mut self, clock: &mut dyn Clock) {
+        if self.is_connected() {
+            return;
+        }
+
+        let handler = ShareableMessageHandler(Rc::new(SandboxDataHandler {
+            id: Ustr::from(&format!("SandboxExecutionClient-{}", self.client_id)),
+            pending: self.pending.clone(),
+        }));
+        let mut msgbus = self.msgbus.borrow_mut();
+        for topic in self.topics() {
+            msgbus.subscribe(topic, handler.clone(), None);
+        }
+        drop(msgbus);
+
+        let exchange = self.exchange.clone();
+        let pending = self.pending.clone();
+        let cache = self.cache.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+            // The exchange is busy while executing a command, which applies the data itself
+            if let Ok(mut exchange) = exchange.try_borrow_mut() {
+                apply_pending(&mut exchange, &cache, &pending);
+            }
+        }));
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            &self.timer_name(),
+            self.process_interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        );
+
+        self.handler = Some(handler);
+        log::info!("Connected sandbox execution client {}", self.client_id);
+    }
+
+    /// Unsubscribes from the market data of the venue and cancels the timer on the `clock`,
+    /// discarding any pending data.
+    pub fn disconnect(&mut self, clock: &mut dyn Clock) {
+        let Some(handler) = self.handler.take() else {
+            return;
+        };
+
+        clock.cancel_timer(&self.timer_name());
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        for topic in self.topics() {
+            msgbus.unsubscribe(topic, handler.clone());
+        }
+        self.pending.borrow_mut().clear();
+        log::info!("Disconnected sandbox execution client {}", self.client_id);
+    }
+
+    /// Applies the market data received since the last call to the exchange, returning the
+    /// number of updates applied.
+    ///
+    /// Data for instruments not found in the cache is discarded.
+    pub fn process_pending(&mut self) -> usize {
+        apply_pending(&mut self.exchange.borrow_mut(), &self.cache, &self.pending)
+    }
+
+    /// Executes the given trading `command` against the exchange.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the command is not supported by the sandbox, or if
+    /// no instrument is found for the command.
+    pub fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        match command {
+            TradingCommand::SubmitOrder(command) => self.submit_order(&command),
+            TradingCommand::SubmitOrderList(command) => self.submit_order_list(&command),
+            TradingCommand::CancelOrder(command) => self.cancel_order(&command),
+            TradingCommand::CancelAllOrders(command) => self.cancel_all_orders(&command),
+            TradingCommand::BatchCancelOrders(command) => self.batch_cancel_orders(&command),
+            command => anyhow::bail!("{command} not supported by the sandbox"),
+        }
+    }
+
+    /// Submits the order of the given `command` to the exchange.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no instrument is found for the order.
+    pub fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()> {
+        self.process_pending();
+        self.submit(&command.order)
+    }
+
+    /// Submits the orders of the given `command` to the exchange, in order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no instrument is found for an order.
+    pub fn submit_order_list(&mut self, command: &SubmitOrderList) -> anyhow::Result<()> {
+        self.process_pending();
+        for order in &command.order_list.orders {
+            self.submit(order)?;
+        }
+        Ok(())
+    }
+
+    /// Cancels the order of the given `command` at the exchange.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no instrument is found for the order.
+    pub fn cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<()> {
+        self.process_pending();
+        self.exchange
+            .borrow_mut()
+            .process_cancel(command, self.account_id)
+    }
+
+    /// Cancels the open orders of the strategy for the instrument (and side) of the given
+    /// `command` at the exchange.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no instrument is found for the command.
+    pub fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> anyhow::Result<()> {
+        self.process_pending();
+
+        let side = (command.order_side != OrderSide::NoOrderSide).then_some(command.order_side);
+        let cancels: Vec<CancelOrder> = self
+            .cache
+            .borrow()
+            .orders_open(
+                None,
+                Some(&command.instrument_id),
+                Some(&command.strategy_id),
+                side,
+            )
+            .into_iter()
+            .map(|order| CancelOrder {
+                trader_id: command.trader_id,
+                client_id: command.client_id,
+                strategy_id: command.strategy_id,
+                instrument_id: command.instrument_id,
+                client_order_id: order.client_order_id(),
+                venue_order_id: order.venue_order_id().unwrap_or_default(),
+                command_id: UUID4::new(),
+                ts_init: command.ts_init,
+            })
+            .collect();
+
+        for cancel in &cancels {
+            self.exchange
+                .borrow_mut()
+                .process_cancel(cancel, self.account_id)?;
+        }
+        Ok(())
+    }
+
+    /// Cancels the orders of the given batch `command` at the exchange, in order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no instrument is found for an order.
+    pub fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> anyhow::Result<()> {
+        self.process_pending();
+        for cancel in &command.cancels {
+            self.exchange
+                .borrow_mut()
+                .process_cancel(cancel, self.account_id)?;
+        }
+        Ok(())
+    }
+
+    fn submit(&mut self, order: &OrderAny) -> anyhow::Result<()> {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Submitted(OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            self.account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        ));
+        {
+            let msgbus = self.msgbus.borrow();
+            msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        }
+
+        self.exchange
+            .borrow_mut()
+            .process_order(order, self.account_id)
+    }
+
+    fn timer_name(&self) -> String {
+        format!("SandboxExecutionClient-{}|process_pending", self.client_id)
+    }
+
+    fn topics(&self) -> [String; 3] {
+        let venue = self.venue();
+        [
+            format!("data.quotes.{venue}.*"),
+            format!("data.trades.{venue}.*"),
+            format!("data.book.deltas.{venue}.*"),
+        ]
+    }
+}
+
+impl ExecutionHandler for SandboxExecutionClient {
+    fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        Self::execute(self, command)
+    }
+}
+
+// Applies the `pending` market data to the `exchange`, returning the number of updates applied
+fn apply_pending(
+    exchange: &mut SimulatedExchange,
+    cache: &Rc<RefCell<Cache>>,
+    pending: &Rc<RefCell<VecDeque<Data>>>,
+) -> usize {
+    let pending: Vec<Data> = pending.borrow_mut().drain(..).collect();
+    let mut count = 0;
+    for data in pending {
+        let instrument_id = data.instrument_id();
+        if cache.borrow().instrument(&instrument_id).is_none() {
+            log::warn!("Discarding sandbox data: no instrument found for {instrument_id}");
+            continue;
+        }
+
+        match data {
+            Data::Quote(quote) => exchange.process_quote_tick(&quote),
+            Data::Trade(trade) => exchange.process_trade_tick(&trade),
+            Data::Deltas(deltas) => exchange.process_order_book_deltas((*deltas).clone()),
+            _ => continue,
+        }
+        count += 1;
+    }
+    count
+}
+
+struct SandboxDataHandler {
+    id: Ustr,
+    pending: Rc<RefCell<VecDeque<Data>>>,
+}
+
+impl MessageHandler for SandboxDataHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let data = if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            Data::Quote(*quote)
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            Data::Trade(*trade)
+        } else if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            Data::Deltas(OrderBookDeltas_API::new(deltas.clone()))
+        } else {
+            return;
+        };
+        self.pending.borrow_mut().push_back(data);
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        self.pending.borrow_mut().push_back(data);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_execution::engine::{config::ExecutionEngineConfig, ExecutionEngine};
+    use nautilus_model::{
+        enums::{AccountType, BookType, OmsType, OrderType},
+        events::order::OrderEventType,
+        identifiers::{ClientOrderId, StrategyId, TraderId, VenueOrderId},
+        instruments::{
+            any::InstrumentAny, crypto_perpetual::CryptoPerpetual, stubs::crypto_perpetual_ethusdt,
+        },
+        orders::builder::OrderTestBuilder,
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    static ATOMIC_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(true, UnixNanos::default()));
+
+    struct TestContext {
+        client: SandboxExecutionClient,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        events: ShareableMessageHandler,
+    }
+
+    fn context(instrument: CryptoPerpetual) -> TestContext {
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CryptoPerpetual(instrument))
+            .unwrap();
+        let cache = Rc::new(RefCell::new(cache));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let events = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, events.clone());
+
+        let config = SandboxExecutionClientConfig {
+            client_id: None,
+            venue: BacktestVenueConfig {
+                name: Venue::new("BINANCE"),
+                oms_type: OmsType::Netting,
+                account_type: AccountType::Margin,
+                starting_balances: vec![Money::from("10_000 USDT")],
+                base_currency: None,
+                default_leverage: 1.into(),
+                leverages: Default::default(),
+                book_type: BookType::L1_MBP,
+                bar_execution: false,
+                bar_execution_model: Default::default(),
+                halt_behavior: Default::default(),
+                self_trade_prevention: None,
+            },
+            process_interval_ms: None,
+        };
+        let client =
+            SandboxExecutionClient::new(config, &ATOMIC_TIME, cache.clone(), msgbus.clone())
+                .unwrap();
+        TestContext {
+            client,
+            cache,
+            msgbus,
+            events,
+        }
+    }
+
+    fn publish_quote(msgbus: &Rc<RefCell<MessageBus>>, quote: &QuoteTick) {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
+        msgbus.publish(&topic, quote as &dyn Any);
+    }
+
+    #[rstest]
+    fn test_new_initializes_account(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let ctx = context(crypto_perpetual_ethusdt);
+
+        assert_eq!(ctx.client.client_id(), ClientId::from("BINANCE"));
+        assert_eq!(ctx.client.account_id(), AccountId::from("BINANCE-001"));
+        let cache = ctx.cache.borrow();
+        let account = cache.account(&ctx.client.account_id()).unwrap();
+        assert_eq!(account.account_type(), AccountType::Margin);
+    }
+
+    #[rstest]
+    fn test_market_data_from_msgbus_applied_when_connected(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut ctx = context(crypto_perpetual_ethusdt);
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        // Not subscribed until connected
+        publish_quote(&ctx.msgbus, &quote);
+        assert_eq!(ctx.client.process_pending(), 0);
+
+        let mut clock = TestClock::new();
+        ctx.client.connect(&mut clock);
+        assert!(ctx.client.is_connected());
+        publish_quote(&ctx.msgbus, &quote);
+        assert_eq!(ctx.client.process_pending(), 1);
+        assert_eq!(
+            ctx.client.exchange().best_bid_price(instrument_id),
+            Some(Price::from("1000.00"))
+        );
+        assert_eq!(
+            ctx.client.exchange().best_ask_price(instrument_id),
+            Some(Price::from("1001.00"))
+        );
+
+        ctx.client.disconnect(&mut clock);
+        assert!(!ctx.client.is_connected());
+        publish_quote(&ctx.msgbus, &quote);
+        assert_eq!(ctx.client.process_pending(), 0);
+    }
+
+    #[rstest]
+    fn test_submit_order_without_market_is_rejected(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut ctx = context(crypto_perpetual_ethusdt);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(crypto_perpetual_ethusdt.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ctx.client.client_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        ctx.client
+            .execute(TradingCommand::SubmitOrder(command))
+            .unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(ctx.events);
+        assert_eq!(events.len(), 2);
+        let OrderEventAny::Submitted(submitted) = &events[0] else {
+            panic!("Expected OrderSubmitted");
+        };
+        assert_eq!(submitted.account_id, ctx.client.account_id());
+        assert_eq!(events[1].event_type(), OrderEventType::Rejected);
+    }
+
+    #[rstest]
+    fn test_cancel_unknown_order_is_rejected(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut ctx = context(crypto_perpetual_ethusdt);
+        let command = CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            ctx.client.client_id(),
+            StrategyId::from("S-001"),
+            crypto_perpetual_ethusdt.id,
+            ClientOrderId::from("O-1"),
+            VenueOrderId::from("V-1"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        ctx.client.cancel_order(&command).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(ctx.events);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), OrderEventType::CancelRejected);
+        assert_eq!(events[0].client_order_id(), ClientOrderId::from("O-1"));
+    }
+
+    #[rstest]
+    fn test_market_data_applied_on_timer(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut ctx = context(crypto_perpetual_ethusdt);
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        let mut clock = TestClock::new();
+        ctx.client.connect(&mut clock);
+
+        publish_quote(&ctx.msgbus, &quote);
+        assert_eq!(ctx.client.exchange().best_bid_price(instrument_id), None);
+
+        let events = clock.advance_time((100 * NANOSECONDS_IN_MILLISECOND).into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        assert_eq!(
+            ctx.client.exchange().best_bid_price(instrument_id),
+            Some(Price::from("1000.00"))
+        );
+
+        ctx.client.disconnect(&mut clock);
+        assert_eq!(clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_execution_client_routes_commands_from_engine(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let ctx = context(crypto_perpetual_ethusdt);
+        let sandbox = Rc::new(RefCell::new(ctx.client));
+        let trader_id = TraderId::from("TRADER-001");
+        let mut engine = ExecutionEngine::new(
+            Rc::new(RefCell::new(TestClock::new())),
+            ctx.cache.clone(),
+            ctx.msgbus.clone(),
+            ExecutionEngineConfig::default(),
+        )
+        .unwrap();
+        engine
+            .register_client(SandboxExecutionClient::execution_client(
+                &sandbox, trader_id,
+            ))
+            .unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(crypto_perpetual_ethusdt.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let command = SubmitOrder::new(
+            trader_id,
+            ClientId::from("BINANCE"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        engine.execute(TradingCommand::SubmitOrder(command));
+
+        let events = get_saved_messages::<OrderEventAny>(ctx.events);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type(), OrderEventType::Submitted);
+        assert_eq!(events[1].event_type(), OrderEventType::Rejected);
+    }
+}
@@ -0,0 +1,421 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Loading of backtest configs, resolution of their data from Parquet catalogs, and running
+//! backtests through the `BacktestEngine`.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    path::Path,
+    rc::Rc,
+};
+
+use anyhow::{anyhow, bail};
+use arrow::record_batch::RecordBatch;
+use heck::ToSnakeCase;
+use nautilus_backtest::{
+    config::{BacktestConfig, BacktestDataConfig},
+    engine::BacktestEngine,
+    exchange::SimulatedExchange,
+    result::BacktestResult,
+};
+use nautilus_common::{cache::Cache, config::load_config, msgbus::MessageBus};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
+        trade::TradeTick, Data, GetTsInit,
+    },
+    identifiers::InstrumentId,
+    instruments::any::InstrumentAny,
+};
+use nautilus_serialization::arrow::DecodeDataFromRecordBatch;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+use crate::{
+    catalog::{scan_catalog, CatalogFile},
+    opt::{BacktestCommand, BacktestOpt},
+};
+
+pub fn run_backtest_command(opt: BacktestOpt) -> anyhow::Result<()> {
+    match opt.command {
+        BacktestCommand::Run {
+            config,
+            instruments,
+            output,
+            dry_run,
+        } => {
+            let config: BacktestConfig = load_config(&config)?;
+            log::info!(
+                "Loaded backtest config for {} with {} venues",
+                config.trader_id,
+                config.venues.len()
+            );
+
+            for data in &config.data {
+                let files = resolve_data_files(data)?;
+                log::info!(
+                    "{} data for {}: {} files in {}",
+                    data.data_type,
+                    data.instrument_id
+                        .map_or_else(|| "all instruments".to_string(), |id| id.to_string()),
+                    files.len(),
+                    data.catalog_path,
+                );
+            }
+            if dry_run {
+                return Ok(());
+            }
+
+            let instruments =
+                load_instruments(&instruments.ok_or_else(|| anyhow!("No instruments file"))?)?;
+            let result = run_backtest(config, instruments)?;
+            log::info!(
+                "Backtest completed with {} fills and {} positions",
+                result.fills.len(),
+                result.positions.len(),
+            );
+            for (currency, pnl) in result.realized_pnls() {
+                log::info!("Realized PnL: {pnl:.2} {currency}");
+            }
+
+            if let Some(output) = output {
+                result.export(&output)?;
+                log::info!("Exported backtest result to {}", output.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Loads the instruments from the JSON array in the file at `path`.
+///
+/// # Errors
+///
+/// This function returns an error if the file cannot be read or parsed.
+pub fn load_instruments(path: &Path) -> anyhow::Result<Vec<InstrumentAny>> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read instruments file '{}': {e}", path.display()))?;
+    serde_json::from_str(&text)
+        .map_err(|e| anyhow!("Invalid instruments file '{}': {e}", path.display()))
+}
+
+/// Runs a backtest for the `config`, adding the `instruments` to the simulated venues and
+/// replaying the configured catalog data through them in `ts_init` order.
+///
+/// The `BacktestEngine` does not host strategies yet, so only the venue simulation (account
+/// balances, funding and other time driven modules) is run on the data.
+///
+/// # Errors
+///
+/// This function returns an error if a venue or instrument cannot be added, the data cannot be
+/// loaded, or the data cannot be processed by its venue.
+pub fn run_backtest(
+    config: BacktestConfig,
+    instruments: Vec<InstrumentAny>,
+) -> anyhow::Result<BacktestResult> {
+    let data = load_data(&config.data)?;
+    log::info!("Loaded {} data points", data.len());
+
+    let cache = Rc::new(RefCell::new(Cache::new(Some(config.cache), None)));
+    let msgbus = Rc::new(RefCell::new(MessageBus::new(
+        config.trader_id,
+        UUID4::new(),
+        None,
+        None,
+    )));
+    // The simulation clock must outlive the exchanges referencing it
+    let clock: &'static AtomicTime =
+        Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+
+    let mut engine = BacktestEngine::new(cache.clone());
+    engine.set_clock(clock, config.clock);
+    for venue in config.venues {
+        let exchange = SimulatedExchange::from_config(venue, msgbus.clone(), cache.clone(), clock)?;
+        engine.add_venue(exchange)?;
+    }
+    for instrument in instruments {
+        engine.add_instrument(instrument)?;
+    }
+    let instrument_ids: HashSet<InstrumentId> = data.iter().map(Data::instrument_id).collect();
+    for instrument_id in instrument_ids {
+        if cache.borrow().instrument(&instrument_id).is_none() {
+            bail!("No instrument {instrument_id} for the backtest data");
+        }
+    }
+    for data in data {
+        engine.process_data(data)?;
+    }
+
+    Ok(engine.result())
+}
+
+/// Loads the catalog data of the backtest data `configs`, within their time ranges and sorted
+/// by `ts_init`.
+///
+/// # Errors
+///
+/// This function returns an error if the data cannot be resolved, read or decoded.
+pub fn load_data(configs: &[BacktestDataConfig]) -> anyhow::Result<Vec<Data>> {
+    let mut data = Vec::new();
+    for config in configs {
+        let start = config.start_time.unwrap_or_default();
+        let end = config.end_time.unwrap_or(UnixNanos::from(u64::MAX));
+        for file in resolve_data_files(config)? {
+            // Record batches read from Parquet don't carry the schema metadata
+            let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&file.path)?)?;
+            let metadata = builder.schema().metadata().clone();
+            for batch in builder.build()? {
+                let decoded = decode_data(&config.data_type, &metadata, batch?)
+                    .map_err(|e| anyhow!("Failed to decode {}: {e}", file.path.display()))?;
+                data.extend(
+                    decoded
+                        .into_iter()
+                        .filter(|d| (start..=end).contains(&d.ts_init())),
+                );
+            }
+        }
+    }
+    data.sort_by_key(GetTsInit::ts_init);
+    Ok(data)
+}
+
+fn decode_data(
+    data_type: &str,
+    metadata: &HashMap<String, String>,
+    batch: RecordBatch,
+) -> anyhow::Result<Vec<Data>> {
+    let data = match data_type {
+        "OrderBookDelta" => OrderBookDelta::decode_data_batch(metadata, batch)?,
+        "OrderBookDepth10" => OrderBookDepth10::decode_data_batch(metadata, batch)?,
+        "QuoteTick" => QuoteTick::decode_data_batch(metadata, batch)?,
+        "TradeTick" => TradeTick::decode_data_batch(metadata, batch)?,
+        "Bar" => Bar::decode_data_batch(metadata, batch)?,
+        other => bail!("Running on {other} data is not supported"),
+    };
+    Ok(data)
+}
+
+/// Returns the catalog files for the data type and instrument of a backtest data config.
+///
+/// # Errors
+///
+/// This function returns an error if the catalog cannot be read, or has no matching files.
+pub fn resolve_data_files(data: &BacktestDataConfig) -> anyhow::Result<Vec<CatalogFile>> {
+    let data_type = match data.data_type.as_str() {
+        "OrderBookDelta" => "order_book_deltas".to_string(),
+        other => other.to_snake_case(),
+    };
+    let instrument_id = data.instrument_id.map(|id| id.to_string().replace('/', ""));
+
+    let files: Vec<CatalogFile> = scan_catalog(Path::new(&data.catalog_path))?
+        .into_iter()
+        .filter(|file| file.data_type == data_type)
+        .filter(|file| {
+            instrument_id.as_ref().is_none_or(|id| {
+                // Bar directories are named by bar type, which starts with the instrument ID
+                file.identifier == *id || file.identifier.starts_with(&format!("{id}-"))
+            })
+        })
+        .collect();
+
+    if files.is_empty() {
+        bail!(
+            "No {} data found in catalog '{}'",
+            data.data_type,
+            data.catalog_path
+        );
+    }
+    Ok(files)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use nautilus_common::config::{parse_config, ConfigFormat};
+    use nautilus_model::{
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::{price::Price, quantity::Quantity},
+    };
+    use nautilus_serialization::arrow::EncodeToRecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn data_config(catalog: &Path, data_type: &str, instrument_id: &str) -> BacktestDataConfig {
+        BacktestDataConfig {
+            catalog_path: catalog.display().to_string(),
+            data_type: data_type.to_string(),
+            instrument_id: Some(InstrumentId::from(instrument_id)),
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    #[rstest]
+    #[case("OrderBookDelta", "AUD/USD.SIM", 1)]
+    #[case("Bar", "AUD/USD.SIM", 2)]
+    #[case("QuoteTick", "AUD/USD.SIM", 1)]
+    fn test_resolve_data_files(
+        #[case] data_type: &str,
+        #[case] instrument_id: &str,
+        #[case] expected: usize,
+    ) {
+        let catalog = TempDir::new().unwrap();
+        for path in [
+            "order_book_deltas/AUDUSD.SIM/20240101.parquet",
+            "bar/AUDUSD.SIM-1-MINUTE-BID-EXTERNAL/20240101.parquet",
+            "bar/AUDUSD.SIM-1-MINUTE-ASK-EXTERNAL/20240101.parquet",
+            "quote_tick/AUDUSD.SIM/20240101.parquet",
+            "quote_tick/EURUSD.SIM/20240101.parquet",
+        ] {
+            let path = catalog.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, b"").unwrap();
+        }
+
+        let config = data_config(catalog.path(), data_type, instrument_id);
+        assert_eq!(resolve_data_files(&config).unwrap().len(), expected);
+    }
+
+    #[rstest]
+    fn test_resolve_data_files_none_found() {
+        let catalog = TempDir::new().unwrap();
+        let config = data_config(catalog.path(), "TradeTick", "AUD/USD.SIM");
+        let err = resolve_data_files(&config).unwrap_err();
+        assert!(err.to_string().starts_with("No TradeTick data found"));
+    }
+
+    fn write_quotes(path: &Path, quotes: &[QuoteTick]) {
+        let metadata = QuoteTick::get_metadata(&quotes[0].instrument_id, 5, 0);
+        let batch = QuoteTick::encode_batch(&metadata, quotes).unwrap();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[rstest]
+    fn test_run_backtest(audusd_sim: CurrencyPair) {
+        let catalog = TempDir::new().unwrap();
+        let quotes: Vec<QuoteTick> = (1..=3)
+            .map(|i| {
+                QuoteTick::new(
+                    audusd_sim.id,
+                    Price::from("1.00000"),
+                    Price::from("1.00010"),
+                    Quantity::from(100_000),
+                    Quantity::from(100_000),
+                    UnixNanos::from(i),
+                    UnixNanos::from(i),
+                )
+            })
+            .collect();
+        write_quotes(
+            &catalog
+                .path()
+                .join("quote_tick/AUDUSD.SIM/20240101.parquet"),
+            &quotes,
+        );
+        let instruments_path = catalog.path().join("instruments.json");
+        fs::write(
+            &instruments_path,
+            serde_json::to_string(&[InstrumentAny::CurrencyPair(audusd_sim)]).unwrap(),
+        )
+        .unwrap();
+        let mut config: BacktestConfig = parse_config(
+            &format!(
+                r#"
+[[venues]]
+name = "SIM"
+oms_type = "NETTING"
+account_type = "MARGIN"
+starting_balances = ["1_000_000 USD"]
+base_currency = "USD"
+
+[[data]]
+catalog_path = "{}"
+data_type = "QuoteTick"
+instrument_id = "AUD/USD.SIM"
+"#,
+                catalog.path().display()
+            ),
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+        config.data[0].start_time = Some(UnixNanos::from(2));
+
+        let data = load_data(&config.data).unwrap();
+        let instruments = load_instruments(&instruments_path).unwrap();
+        let result = run_backtest(config, instruments).unwrap();
+
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].ts_init(), UnixNanos::from(2));
+        assert!(result.fills.is_empty());
+    }
+
+    #[rstest]
+    fn test_run_backtest_without_instrument_errors(audusd_sim: CurrencyPair) {
+        let catalog = TempDir::new().unwrap();
+        let quote = QuoteTick::new(
+            audusd_sim.id,
+            Price::from("1.00000"),
+            Price::from("1.00010"),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        write_quotes(
+            &catalog
+                .path()
+                .join("quote_tick/AUDUSD.SIM/20240101.parquet"),
+            &[quote],
+        );
+        let config: BacktestConfig = parse_config(
+            &format!(
+                r#"
+[[venues]]
+name = "SIM"
+oms_type = "NETTING"
+account_type = "MARGIN"
+starting_balances = ["1_000_000 USD"]
+
+[[data]]
+catalog_path = "{}"
+data_type = "QuoteTick"
+"#,
+                catalog.path().display()
+            ),
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        let err = run_backtest(config, vec![]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No instrument AUD/USD.SIM for the backtest data"
+        );
+    }
+}
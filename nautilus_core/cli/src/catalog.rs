@@ -0,0 +1,391 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Inspection, validation and conversion of a Parquet data catalog.
+//!
+//! A catalog stores each day of data in a file at
+//! `<catalog>/<data_type>/<instrument_id or bar_type>/<YYYYMMDD>.parquet`, with the data type
+//! in snake case and any `/` removed from the identifier.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail};
+use arrow::{array::UInt64Array, ipc::writer::FileWriter};
+use parquet::{
+    arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+    file::reader::{FileReader, SerializedFileReader},
+};
+
+use crate::opt::{CatalogCommand, CatalogOpt};
+
+/// Represents a Parquet file in a data catalog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogFile {
+    /// The data type directory name, e.g. `quote_tick`.
+    pub data_type: String,
+    /// The instrument ID or bar type directory name.
+    pub identifier: String,
+    /// The path to the file.
+    pub path: PathBuf,
+}
+
+/// Represents a summary of the files for one data type and identifier in a catalog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogSummary {
+    pub data_type: String,
+    pub identifier: String,
+    pub files: usize,
+    pub rows: u64,
+    pub bytes: u64,
+    /// The name of the first file, without extension.
+    pub first: String,
+    /// The name of the last file, without extension.
+    pub last: String,
+}
+
+pub fn run_catalog_command(opt: CatalogOpt) -> anyhow::Result<()> {
+    match opt.command {
+        CatalogCommand::Info { path } => {
+            let summaries = summarize_catalog(&path)?;
+            if summaries.is_empty() {
+                log::info!("No data found in catalog {}", path.display());
+            }
+            for summary in summaries {
+                log::info!(
+                    "{}/{}: {} files, {} rows, {} bytes, {} to {}",
+                    summary.data_type,
+                    summary.identifier,
+                    summary.files,
+                    summary.rows,
+                    summary.bytes,
+                    summary.first,
+                    summary.last,
+                );
+            }
+        }
+        CatalogCommand::Validate { path } => {
+            let issues = validate_catalog(&path)?;
+            for issue in &issues {
+                log::warn!("{issue}");
+            }
+            if !issues.is_empty() {
+                bail!("Found {} invalid files in catalog", issues.len());
+            }
+            log::info!("Catalog {} is valid", path.display());
+        }
+        CatalogCommand::Convert { path, output } => {
+            let count = convert_catalog(&path, &output)?;
+            log::info!("Converted {count} files to {}", output.display());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the Parquet files in the catalog at `path`, sorted by data type, identifier and name.
+///
+/// # Errors
+///
+/// This function returns an error if `path` is not a directory or cannot be read.
+pub fn scan_catalog(path: &Path) -> anyhow::Result<Vec<CatalogFile>> {
+    if !path.is_dir() {
+        bail!("Catalog path '{}' is not a directory", path.display());
+    }
+
+    let mut files = Vec::new();
+    for data_type_dir in sorted_entries(path)?.into_iter().filter(|p| p.is_dir()) {
+        for identifier_dir in sorted_entries(&data_type_dir)?
+            .into_iter()
+            .filter(|p| p.is_dir())
+        {
+            for file in sorted_entries(&identifier_dir)? {
+                if file.extension().is_some_and(|ext| ext == "parquet") {
+                    files.push(CatalogFile {
+                        data_type: file_name(&data_type_dir),
+                        identifier: file_name(&identifier_dir),
+                        path: file,
+                    });
+                }
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Returns a summary of each data type and identifier in the catalog at `path`.
+///
+/// # Errors
+///
+/// This function returns an error if the catalog cannot be read, or a file has invalid
+/// Parquet metadata.
+pub fn summarize_catalog(path: &Path) -> anyhow::Result<Vec<CatalogSummary>> {
+    let mut summaries: BTreeMap<(String, String), CatalogSummary> = BTreeMap::new();
+
+    for file in scan_catalog(path)? {
+        let reader = SerializedFileReader::new(File::open(&file.path)?)
+            .map_err(|e| anyhow!("Invalid Parquet file '{}': {e}", file.path.display()))?;
+        let rows = reader.metadata().file_metadata().num_rows() as u64;
+        let bytes = fs::metadata(&file.path)?.len();
+        let stem = file_stem(&file.path);
+
+        let summary = summaries
+            .entry((file.data_type.clone(), file.identifier.clone()))
+            .or_insert_with(|| CatalogSummary {
+                data_type: file.data_type,
+                identifier: file.identifier,
+                files: 0,
+                rows: 0,
+                bytes: 0,
+                first: stem.clone(),
+                last: String::new(),
+            });
+        summary.files += 1;
+        summary.rows += rows;
+        summary.bytes += bytes;
+        summary.last = stem;
+    }
+
+    Ok(summaries.into_values().collect())
+}
+
+/// Checks every file in the catalog at `path`, returning a description of each invalid file.
+///
+/// A file is valid if it can be read, has the instrument ID (or bar type) metadata of its
+/// directory, and has a `ts_init` column which does not decrease within the file, or from
+/// the preceding file for the same identifier.
+///
+/// # Errors
+///
+/// This function returns an error if the catalog cannot be read.
+pub fn validate_catalog(path: &Path) -> anyhow::Result<Vec<String>> {
+    let mut issues = Vec::new();
+    let mut last_ts_init: Option<u64> = None;
+    let mut previous: Option<(String, String)> = None;
+
+    for file in scan_catalog(path)? {
+        let key = (file.data_type.clone(), file.identifier.clone());
+        if previous.as_ref() != Some(&key) {
+            last_ts_init = None;
+            previous = Some(key);
+        }
+        if let Err(e) = validate_file(&file, &mut last_ts_init) {
+            issues.push(format!("{}: {e}", file.path.display()));
+        }
+    }
+    Ok(issues)
+}
+
+fn validate_file(file: &CatalogFile, last_ts_init: &mut Option<u64>) -> anyhow::Result<()> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&file.path)?)?;
+
+    let key = if file.data_type == "bar" {
+        "bar_type"
+    } else {
+        "instrument_id"
+    };
+    let Some(identifier) = builder.schema().metadata().get(key) else {
+        bail!("Missing `{key}` metadata");
+    };
+    if identifier.replace('/', "") != file.identifier {
+        bail!("`{key}` metadata {identifier} does not match the directory");
+    }
+
+    for batch in builder.build()? {
+        let batch = batch?;
+        let ts_init = batch
+            .column_by_name("ts_init")
+            .and_then(|column| column.as_any().downcast_ref::<UInt64Array>())
+            .ok_or_else(|| anyhow!("Missing `ts_init` UInt64 column"))?;
+        for &ts in ts_init.values() {
+            if let Some(last) = *last_ts_init {
+                if ts < last {
+                    bail!("`ts_init` {ts} is before the preceding {last}");
+                }
+            }
+            *last_ts_init = Some(ts);
+        }
+    }
+    Ok(())
+}
+
+/// Converts every Parquet file in the catalog at `path` to an Arrow IPC file under `output`,
+/// with the same layout and schema metadata, returning the number of files converted.
+///
+/// # Errors
+///
+/// This function returns an error if a file cannot be read or written.
+pub fn convert_catalog(path: &Path, output: &Path) -> anyhow::Result<usize> {
+    let files = scan_catalog(path)?;
+
+    for file in &files {
+        let target = output
+            .join(&file.data_type)
+            .join(&file.identifier)
+            .join(format!("{}.arrow", file_stem(&file.path)));
+        fs::create_dir_all(target.parent().expect("Target has a parent directory"))?;
+
+        // The schema of the builder keeps the metadata needed to decode the batches
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&file.path)?)?;
+        let mut writer = FileWriter::try_new(File::create(&target)?, &builder.schema().clone())?;
+        for batch in builder.build()? {
+            writer.write(&batch?)?;
+        }
+        writer.finish()?;
+        log::debug!("File written: {}", target.display());
+    }
+    Ok(files.len())
+}
+
+fn sorted_entries(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use nautilus_adapters::tardis::replay::parquet_filepath;
+    use nautilus_model::{
+        data::{quote::QuoteTick, stubs::quote_ethusdt_binance},
+        identifiers::InstrumentId,
+    };
+    use nautilus_persistence::mmap::{MmapReplayConfig, MmapReplayReader};
+    use nautilus_serialization::{
+        arrow::quote_ticks_to_arrow_record_batch_bytes, parquet::write_batch_to_parquet,
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn write_quotes(catalog: &Path, day: u32, ts_inits: &[u64]) -> PathBuf {
+        let quotes = ts_inits
+            .iter()
+            .map(|&ts| QuoteTick {
+                ts_event: ts.into(),
+                ts_init: ts.into(),
+                ..quote_ethusdt_binance()
+            })
+            .collect();
+        let batch = quote_ticks_to_arrow_record_batch_bytes(quotes).unwrap();
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let filepath = catalog.join(parquet_filepath("QuoteTick", &instrument_id, date));
+        write_batch_to_parquet(&batch, &filepath, None).unwrap();
+        filepath
+    }
+
+    #[rstest]
+    fn test_summarize_catalog() {
+        let catalog = TempDir::new().unwrap();
+        write_quotes(catalog.path(), 1, &[1, 2, 3]);
+        write_quotes(catalog.path(), 2, &[4, 5]);
+
+        let summaries = summarize_catalog(catalog.path()).unwrap();
+
+        assert_eq!(summaries.len(), 1);
+        let summary = &summaries[0];
+        assert_eq!(summary.data_type, "quote_tick");
+        assert_eq!(summary.identifier, "ETHUSDT-PERP.BINANCE");
+        assert_eq!(summary.files, 2);
+        assert_eq!(summary.rows, 5);
+        assert_eq!(summary.first, "20240101");
+        assert_eq!(summary.last, "20240102");
+    }
+
+    #[rstest]
+    fn test_scan_catalog_missing_path() {
+        let catalog = TempDir::new().unwrap();
+        assert!(scan_catalog(&catalog.path().join("missing")).is_err());
+    }
+
+    #[rstest]
+    fn test_validate_catalog() {
+        let catalog = TempDir::new().unwrap();
+        write_quotes(catalog.path(), 1, &[1, 2, 3]);
+        assert!(validate_catalog(catalog.path()).unwrap().is_empty());
+
+        let unsorted = write_quotes(catalog.path(), 2, &[2, 5]);
+        fs::write(catalog.path().join("quote_tick/README.parquet"), b"").unwrap();
+
+        let issues = validate_catalog(catalog.path()).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0],
+            format!(
+                "{}: `ts_init` 2 is before the preceding 3",
+                unsorted.display()
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_validate_catalog_mismatched_directory() {
+        let catalog = TempDir::new().unwrap();
+        let filepath = write_quotes(catalog.path(), 1, &[1]);
+        let moved = catalog
+            .path()
+            .join("quote_tick/BTCUSDT-PERP.BINANCE/20240101.parquet");
+        fs::create_dir_all(moved.parent().unwrap()).unwrap();
+        fs::rename(filepath, &moved).unwrap();
+
+        let issues = validate_catalog(catalog.path()).unwrap();
+        assert_eq!(
+            issues,
+            vec![format!(
+                "{}: `instrument_id` metadata ETHUSDT-PERP.BINANCE does not match the directory",
+                moved.display()
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_convert_catalog_to_ipc() {
+        let catalog = TempDir::new().unwrap();
+        let output = TempDir::new().unwrap();
+        write_quotes(catalog.path(), 1, &[1, 2, 3]);
+
+        assert_eq!(convert_catalog(catalog.path(), output.path()).unwrap(), 1);
+
+        let ipc_path = output
+            .path()
+            .join("quote_tick/ETHUSDT-PERP.BINANCE/20240101.arrow");
+        let reader =
+            MmapReplayReader::open::<QuoteTick>(ipc_path, MmapReplayConfig::default()).unwrap();
+        let data: Vec<_> = reader.flat_map(Result::unwrap).collect();
+        assert_eq!(data.len(), 3);
+    }
+}
@@ -14,16 +14,25 @@
 // -------------------------------------------------------------------------------------------------
 
 use crate::{
+    backtest::run_backtest_command,
+    catalog::run_catalog_command,
     database::postgres::run_database_command,
     opt::{Commands, NautilusCli},
+    tardis::run_tardis_command,
 };
 
+pub mod backtest;
+pub mod catalog;
 mod database;
 pub mod opt;
+pub mod tardis;
 
 pub async fn run(opt: NautilusCli) -> anyhow::Result<()> {
     match opt.command {
         Commands::Database(database_opt) => run_database_command(database_opt).await?,
+        Commands::Catalog(catalog_opt) => run_catalog_command(catalog_opt)?,
+        Commands::Tardis(tardis_opt) => run_tardis_command(tardis_opt).await?,
+        Commands::Backtest(backtest_opt) => run_backtest_command(backtest_opt)?,
     }
     Ok(())
 }
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use clap::Parser;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use clap::{Parser, ValueEnum};
 
 #[derive(Parser)]
 #[clap(version, about, author)]
@@ -25,6 +28,9 @@ pub struct NautilusCli {
 #[derive(Parser, Debug)]
 pub enum Commands {
     Database(DatabaseOpt),
+    Catalog(CatalogOpt),
+    Tardis(TardisOpt),
+    Backtest(BacktestOpt),
 }
 
 #[derive(Parser, Debug)]
@@ -64,3 +70,135 @@ pub enum DatabaseCommand {
     /// Drops roles, privileges and deletes all data from the database
     Drop(DatabaseConfig),
 }
+
+#[derive(Parser, Debug)]
+#[command(about = "Parquet data catalog operations", long_about = None)]
+pub struct CatalogOpt {
+    #[clap(subcommand)]
+    pub command: CatalogCommand,
+}
+
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Parquet data catalog operations", long_about = None)]
+pub enum CatalogCommand {
+    /// Summarizes the data types, instruments, files and rows in a catalog
+    Info {
+        /// Directory path to the catalog
+        path: PathBuf,
+    },
+    /// Checks every file in a catalog is readable, has metadata and is sorted by `ts_init`
+    Validate {
+        /// Directory path to the catalog
+        path: PathBuf,
+    },
+    /// Converts the Parquet files in a catalog to Arrow IPC files for memory-mapped replay
+    Convert {
+        /// Directory path to the catalog
+        path: PathBuf,
+        /// Directory path to write the Arrow IPC files to, with the same layout
+        output: PathBuf,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Tardis data operations", long_about = None)]
+pub struct TardisOpt {
+    #[clap(subcommand)]
+    pub command: TardisCommand,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct TardisDownloadConfig {
+    /// Tardis exchange name, e.g. `bitmex`
+    #[arg(long)]
+    pub exchange: String,
+    /// Tardis dataset type, e.g. `trades` (may be repeated)
+    #[arg(long = "data-type", required = true)]
+    pub data_types: Vec<String>,
+    /// Exchange symbol, e.g. `XBTUSD` (may be repeated)
+    #[arg(long = "symbol", required = true)]
+    pub symbols: Vec<String>,
+    /// First date to download (inclusive), e.g. `2024-01-01`
+    #[arg(long)]
+    pub from: NaiveDate,
+    /// Last date to download (inclusive)
+    #[arg(long)]
+    pub to: NaiveDate,
+    /// Directory path to write the CSV files to
+    #[arg(long)]
+    pub output: PathBuf,
+    /// Tardis API key
+    #[arg(long, env = "TARDIS_API_KEY", hide_env_values = true)]
+    pub api_key: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TardisCsvType {
+    /// `incremental_book_L2` to order book deltas
+    Deltas,
+    /// `book_snapshot_5` to order book depth 10
+    BookSnapshot5,
+    /// `book_snapshot_25` to order book depth 10
+    BookSnapshot25,
+    /// `quotes` to quote ticks
+    Quotes,
+    /// `trades` to trade ticks
+    Trades,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct TardisConvertConfig {
+    /// File path to the Tardis CSV (optionally gzipped)
+    pub input: PathBuf,
+    /// Directory path to the catalog to write to
+    #[arg(long)]
+    pub catalog: PathBuf,
+    /// The type of data in the CSV
+    #[arg(long, value_enum)]
+    pub data_type: TardisCsvType,
+    /// Price precision for the instrument
+    #[arg(long)]
+    pub price_precision: u8,
+    /// Size precision for the instrument
+    #[arg(long)]
+    pub size_precision: u8,
+    /// Instrument ID to use instead of the one parsed from the CSV
+    #[arg(long)]
+    pub instrument_id: Option<String>,
+}
+
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Tardis data operations", long_about = None)]
+pub enum TardisCommand {
+    /// Downloads daily CSV datasets from Tardis
+    Download(TardisDownloadConfig),
+    /// Converts a Tardis CSV to Parquet files in a catalog
+    Convert(TardisConvertConfig),
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Backtest operations", long_about = None)]
+pub struct BacktestOpt {
+    #[clap(subcommand)]
+    pub command: BacktestCommand,
+}
+
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Backtest operations", long_about = None)]
+pub enum BacktestCommand {
+    /// Runs a backtest from a TOML or JSON config file, replaying its catalog data through the
+    /// simulated venues
+    Run {
+        /// File path to the backtest config
+        config: PathBuf,
+        /// File path to a JSON array of the instruments to add to the venues
+        #[arg(long, required_unless_present = "dry_run")]
+        instruments: Option<PathBuf>,
+        /// Directory path to export the backtest result to
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Only validate the config and resolve its data, without running
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
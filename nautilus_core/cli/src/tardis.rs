@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Downloading of Tardis CSV datasets, and their conversion to Parquet files in a data catalog.
+
+use std::{collections::HashMap, error::Error, path::Path, str::FromStr};
+
+use anyhow::{anyhow, bail};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, NaiveDate};
+use nautilus_adapters::tardis::{
+    csv::{
+        load_deltas, load_depth10_from_snapshot25, load_depth10_from_snapshot5, load_quote_ticks,
+        load_trade_ticks,
+    },
+    enums::Exchange,
+    http::TardisHttpClient,
+    replay::parquet_filepath,
+};
+use nautilus_model::{data::GetTsInit, identifiers::InstrumentId};
+use nautilus_serialization::{
+    arrow::{
+        order_book_deltas_to_arrow_record_batch_bytes,
+        order_book_depth10_to_arrow_record_batch_bytes, quote_ticks_to_arrow_record_batch_bytes,
+        trade_ticks_to_arrow_record_batch_bytes, EncodingError,
+    },
+    parquet::write_batch_to_parquet,
+};
+
+use crate::opt::{
+    TardisCommand, TardisConvertConfig, TardisCsvType, TardisDownloadConfig, TardisOpt,
+};
+
+pub async fn run_tardis_command(opt: TardisOpt) -> anyhow::Result<()> {
+    match opt.command {
+        TardisCommand::Download(config) => {
+            let count = download_datasets(&config).await?;
+            log::info!("Downloaded {count} files to {}", config.output.display());
+        }
+        TardisCommand::Convert(config) => {
+            let count = convert_csv(&config)?;
+            log::info!("Wrote {count} files to {}", config.catalog.display());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the file name for a downloaded dataset, in the format used by the Tardis clients.
+#[must_use]
+pub fn dataset_filename(
+    exchange: &Exchange,
+    data_type: &str,
+    date: NaiveDate,
+    symbol: &str,
+) -> String {
+    format!("{exchange}_{data_type}_{date}_{symbol}.csv.gz")
+}
+
+/// Downloads each configured dataset for every date in the range, returning the number of
+/// files downloaded.
+///
+/// # Errors
+///
+/// This function returns an error if the config is invalid, or a download fails.
+pub async fn download_datasets(config: &TardisDownloadConfig) -> anyhow::Result<usize> {
+    let exchange = Exchange::from_str(&config.exchange)
+        .map_err(|_| anyhow!("Unknown Tardis exchange '{}'", config.exchange))?;
+    if config.from > config.to {
+        bail!("From date {} is after to date {}", config.from, config.to);
+    }
+
+    let client = TardisHttpClient::new(config.api_key.as_deref(), None, None)?;
+    let mut count = 0;
+    for date in config
+        .from
+        .iter_days()
+        .take_while(|date| *date <= config.to)
+    {
+        for data_type in &config.data_types {
+            for symbol in &config.symbols {
+                let filepath = config
+                    .output
+                    .join(dataset_filename(&exchange, data_type, date, symbol));
+                let bytes = client
+                    .download_dataset(exchange.clone(), data_type, date, symbol, &filepath)
+                    .await?;
+                log::info!("File written: {} ({bytes} bytes)", filepath.display());
+                count += 1;
+            }
+        }
+    }
+    Ok(count)
+}
+
+/// Converts a Tardis CSV to Parquet files in the catalog, one per instrument and UTC day,
+/// returning the number of files written.
+///
+/// # Errors
+///
+/// This function returns an error if the CSV cannot be loaded, or a file cannot be written.
+pub fn convert_csv(config: &TardisConvertConfig) -> anyhow::Result<usize> {
+    let instrument_id = config
+        .instrument_id
+        .as_deref()
+        .map(InstrumentId::from_str)
+        .transpose()?;
+    let input = &config.input;
+    let (price_precision, size_precision) = (config.price_precision, config.size_precision);
+    let load_error = |e: Box<dyn Error>| anyhow!("Failed to load '{}': {e}", input.display());
+
+    match config.data_type {
+        TardisCsvType::Deltas => write_days(
+            load_deltas(input, price_precision, size_precision, instrument_id, None)
+                .map_err(load_error)?,
+            stringify!(OrderBookDeltas),
+            |delta| delta.instrument_id,
+            order_book_deltas_to_arrow_record_batch_bytes,
+            &config.catalog,
+        ),
+        TardisCsvType::BookSnapshot5 => write_days(
+            load_depth10_from_snapshot5(
+                input,
+                price_precision,
+                size_precision,
+                instrument_id,
+                None,
+            )
+            .map_err(load_error)?,
+            stringify!(OrderBookDepth10),
+            |depth| depth.instrument_id,
+            order_book_depth10_to_arrow_record_batch_bytes,
+            &config.catalog,
+        ),
+        TardisCsvType::BookSnapshot25 => write_days(
+            load_depth10_from_snapshot25(
+                input,
+                price_precision,
+                size_precision,
+                instrument_id,
+                None,
+            )
+            .map_err(load_error)?,
+            stringify!(OrderBookDepth10),
+            |depth| depth.instrument_id,
+            order_book_depth10_to_arrow_record_batch_bytes,
+            &config.catalog,
+        ),
+        TardisCsvType::Quotes => write_days(
+            load_quote_ticks(input, price_precision, size_precision, instrument_id, None)
+                .map_err(load_error)?,
+            stringify!(QuoteTick),
+            |quote| quote.instrument_id,
+            quote_ticks_to_arrow_record_batch_bytes,
+            &config.catalog,
+        ),
+        TardisCsvType::Trades => write_days(
+            load_trade_ticks(input, price_precision, size_precision, instrument_id, None)
+                .map_err(load_error)?,
+            stringify!(TradeTick),
+            |trade| trade.instrument_id,
+            trade_ticks_to_arrow_record_batch_bytes,
+            &config.catalog,
+        ),
+    }
+}
+
+fn write_days<T: GetTsInit>(
+    data: Vec<T>,
+    typename: &str,
+    get_instrument_id: fn(&T) -> InstrumentId,
+    encode: fn(Vec<T>) -> Result<RecordBatch, EncodingError>,
+    catalog: &Path,
+) -> anyhow::Result<usize> {
+    let mut days: HashMap<(InstrumentId, NaiveDate), Vec<T>> = HashMap::new();
+    for item in data {
+        let date = DateTime::from_timestamp_nanos(item.ts_init().as_i64()).date_naive();
+        days.entry((get_instrument_id(&item), date))
+            .or_default()
+            .push(item);
+    }
+
+    let count = days.len();
+    for ((instrument_id, date), items) in days {
+        let batch =
+            encode(items).map_err(|e| anyhow!("Error converting `{typename}` to Arrow: {e}"))?;
+        let filepath = catalog.join(parquet_filepath(typename, &instrument_id, date));
+        write_batch_to_parquet(&batch, &filepath, None)
+            .map_err(|e| anyhow!("Error writing {}: {e}", filepath.display()))?;
+        log::info!("File written: {}", filepath.display());
+    }
+    Ok(count)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::catalog::summarize_catalog;
+
+    const TRADES_CSV: &str = "\
+exchange,symbol,timestamp,local_timestamp,id,side,price,amount
+bitmex,XBTUSD,1704153599000000,1704153599100000,a,buy,42000.5,100
+bitmex,XBTUSD,1704153600000000,1704153600100000,b,sell,42001.0,200
+bitmex,XBTUSD,1704153601000000,1704153601100000,c,buy,42001.5,300
+";
+
+    #[rstest]
+    fn test_dataset_filename() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        assert_eq!(
+            dataset_filename(&Exchange::Bitmex, "trades", date, "XBTUSD"),
+            "bitmex_trades_2024-01-02_XBTUSD.csv.gz"
+        );
+    }
+
+    #[rstest]
+    fn test_convert_csv_splits_days() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("trades.csv");
+        fs::write(&input, TRADES_CSV).unwrap();
+        let config = TardisConvertConfig {
+            input,
+            catalog: dir.path().join("catalog"),
+            data_type: TardisCsvType::Trades,
+            price_precision: 1,
+            size_precision: 0,
+            instrument_id: None,
+        };
+
+        assert_eq!(convert_csv(&config).unwrap(), 2);
+
+        let summaries = summarize_catalog(&config.catalog).unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].data_type, "trade_tick");
+        assert_eq!(summaries[0].identifier, "XBTUSD.BITMEX");
+        assert_eq!(summaries[0].rows, 3);
+        assert_eq!(
+            (summaries[0].first.as_str(), summaries[0].last.as_str()),
+            ("20240101", "20240102")
+        );
+    }
+}
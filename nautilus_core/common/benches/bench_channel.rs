@@ -0,0 +1,107 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Compares the SPSC channel in `runtime::channel` with `tokio::sync::mpsc`.
+//!
+//! - `channel_throughput`: a producer thread pushes `MESSAGES` values which are drained on the
+//!   benchmark thread, so the result is the time to transfer the whole batch across threads.
+//! - `channel_latency`: a single push followed by a pop on the same thread, isolating the
+//!   per-message cost of the queue operations.
+
+use std::thread;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use nautilus_common::runtime::channel::channel;
+use nautilus_model::data::{stubs::stub_trade_ethusdt_buyer, Data};
+
+const CAPACITY: usize = 4096;
+const MESSAGES: u64 = 100_000;
+
+fn bench_throughput(c: &mut Criterion) {
+    let data = Data::Trade(stub_trade_ethusdt_buyer());
+    let mut group = c.benchmark_group("channel_throughput");
+    group.throughput(Throughput::Elements(MESSAGES));
+    group.sample_size(10);
+
+    group.bench_function("spsc", |b| {
+        b.iter(|| {
+            let (mut producer, mut consumer) = channel(CAPACITY);
+            let data = data.clone();
+            let handle = thread::spawn(move || {
+                for _ in 0..MESSAGES {
+                    let mut value = data.clone();
+                    while let Err(v) = producer.push(value) {
+                        value = v;
+                        std::hint::spin_loop();
+                    }
+                }
+            });
+
+            let mut received = 0;
+            while received < MESSAGES {
+                if let Some(value) = consumer.pop() {
+                    black_box(value);
+                    received += 1;
+                }
+            }
+            handle.join().unwrap();
+        });
+    });
+
+    group.bench_function("tokio_mpsc", |b| {
+        b.iter(|| {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(CAPACITY);
+            let data = data.clone();
+            let handle = thread::spawn(move || {
+                for _ in 0..MESSAGES {
+                    tx.blocking_send(data.clone()).unwrap();
+                }
+            });
+
+            for _ in 0..MESSAGES {
+                black_box(rx.blocking_recv().unwrap());
+            }
+            handle.join().unwrap();
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_latency(c: &mut Criterion) {
+    let data = Data::Trade(stub_trade_ethusdt_buyer());
+    let mut group = c.benchmark_group("channel_latency");
+
+    group.bench_function("spsc", |b| {
+        let (mut producer, mut consumer) = channel(CAPACITY);
+        b.iter(|| {
+            producer.push(black_box(data.clone())).unwrap();
+            black_box(consumer.pop().unwrap());
+        });
+    });
+
+    group.bench_function("tokio_mpsc", |b| {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(CAPACITY);
+        b.iter(|| {
+            tx.try_send(black_box(data.clone())).unwrap();
+            black_box(rx.try_recv().unwrap());
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_throughput, bench_latency);
+criterion_main!(benches);
@@ -20,12 +20,13 @@
 #![allow(unused_variables)]
 
 pub mod database;
+pub mod rolling;
 
 #[cfg(test)]
 mod tests;
 
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{HashMap, HashSet},
     time::{SystemTime, UNIX_EPOCH},
 };
 
@@ -42,16 +43,19 @@ use nautilus_model::{
         trade::TradeTick,
     },
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
+    events::instrument::InstrumentUpdate,
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
         OrderListId, PositionId, StrategyId, Venue, VenueOrderId,
     },
     instruments::{any::InstrumentAny, synthetic::SyntheticInstrument},
+    mic::MIC_REGISTRY,
     orderbook::book::OrderBook,
     orders::{any::OrderAny, list::OrderList},
     position::Position,
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
+use rolling::DataCache;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -167,6 +171,8 @@ pub struct CacheIndex {
     actors: HashSet<ComponentId>,
     strategies: HashSet<StrategyId>,
     exec_algorithms: HashSet<ExecAlgorithmId>,
+    isin_instrument: HashMap<Ustr, InstrumentId>,
+    figi_instrument: HashMap<Ustr, InstrumentId>,
 }
 
 impl CacheIndex {
@@ -200,6 +206,31 @@ impl CacheIndex {
         self.actors.clear();
         self.strategies.clear();
         self.exec_algorithms.clear();
+        self.isin_instrument.clear();
+        self.figi_instrument.clear();
+    }
+
+    fn index_instrument(&mut self, instrument: &InstrumentAny) {
+        if let Some(isin) = instrument.isin() {
+            self.isin_instrument.insert(isin, instrument.id());
+        }
+        if let Some(figi) = instrument.figi() {
+            self.figi_instrument.insert(figi, instrument.id());
+        }
+    }
+
+    fn unindex_instrument(&mut self, instrument: &InstrumentAny) {
+        let instrument_id = instrument.id();
+        if let Some(isin) = instrument.isin() {
+            if self.isin_instrument.get(&isin) == Some(&instrument_id) {
+                self.isin_instrument.remove(&isin);
+            }
+        }
+        if let Some(figi) = instrument.figi() {
+            if self.figi_instrument.get(&figi) == Some(&instrument_id) {
+                self.figi_instrument.remove(&figi);
+            }
+        }
     }
 }
 
@@ -209,12 +240,11 @@ pub struct Cache {
     index: CacheIndex,
     database: Option<Box<dyn CacheDatabaseAdapter>>,
     general: HashMap<String, Bytes>,
-    quotes: HashMap<InstrumentId, VecDeque<QuoteTick>>,
-    trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
+    data: DataCache,
     books: HashMap<InstrumentId, OrderBook>,
-    bars: HashMap<BarType, VecDeque<Bar>>,
     currencies: HashMap<Ustr, Currency>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
+    instrument_versions: HashMap<InstrumentId, u64>,
     synthetics: HashMap<InstrumentId, SyntheticInstrument>,
     accounts: HashMap<AccountId, AccountAny>,
     orders: HashMap<ClientOrderId, OrderAny>,
@@ -270,19 +300,23 @@ impl Cache {
             actors: HashSet::new(),
             strategies: HashSet::new(),
             exec_algorithms: HashSet::new(),
+            isin_instrument: HashMap::new(),
+            figi_instrument: HashMap::new(),
         };
 
+        let config = config.unwrap_or_default();
+        let data = DataCache::new(config.tick_capacity, config.bar_capacity);
+
         Self {
-            config: config.unwrap_or_default(),
+            config,
             index,
             database,
             general: HashMap::new(),
-            quotes: HashMap::new(),
-            trades: HashMap::new(),
+            data,
             books: HashMap::new(),
-            bars: HashMap::new(),
             currencies: HashMap::new(),
             instruments: HashMap::new(),
+            instrument_versions: HashMap::new(),
             synthetics: HashMap::new(),
             accounts: HashMap::new(),
             orders: HashMap::new(),
@@ -552,6 +586,11 @@ impl Cache {
             // 9: Build index.strategies -> {StrategyId}
             self.index.strategies.insert(strategy_id);
         }
+
+        // Index instruments by ISIN and FIGI
+        for instrument in self.instruments.values() {
+            self.index.index_instrument(instrument);
+        }
     }
 
     /// Checks integrity of data within the cache.
@@ -927,12 +966,11 @@ impl Cache {
         log::debug!("Resetting cache");
 
         self.general.clear();
-        self.quotes.clear();
-        self.trades.clear();
+        self.data.clear();
         self.books.clear();
-        self.bars.clear();
         self.currencies.clear();
         self.instruments.clear();
+        self.instrument_versions.clear();
         self.synthetics.clear();
         self.accounts.clear();
         self.orders.clear();
@@ -1000,11 +1038,7 @@ impl Cache {
             }
         }
 
-        let quotes_deque = self
-            .quotes
-            .entry(quote.instrument_id)
-            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
-        quotes_deque.push_front(quote);
+        self.data.add_quote(quote);
         Ok(())
     }
 
@@ -1023,13 +1057,8 @@ impl Cache {
             }
         }
 
-        let quotes_deque = self
-            .quotes
-            .entry(instrument_id)
-            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
-
         for quote in quotes {
-            quotes_deque.push_front(*quote);
+            self.data.add_quote(*quote);
         }
         Ok(())
     }
@@ -1044,11 +1073,7 @@ impl Cache {
             }
         }
 
-        let trades_deque = self
-            .trades
-            .entry(trade.instrument_id)
-            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
-        trades_deque.push_front(trade);
+        self.data.add_trade(trade);
         Ok(())
     }
 
@@ -1067,13 +1092,8 @@ impl Cache {
             }
         }
 
-        let trades_deque = self
-            .trades
-            .entry(instrument_id)
-            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
-
         for trade in trades {
-            trades_deque.push_front(*trade);
+            self.data.add_trade(*trade);
         }
         Ok(())
     }
@@ -1088,11 +1108,7 @@ impl Cache {
             }
         }
 
-        let bars = self
-            .bars
-            .entry(bar.bar_type)
-            .or_insert_with(|| VecDeque::with_capacity(self.config.bar_capacity));
-        bars.push_front(bar);
+        self.data.add_bar(bar);
         Ok(())
     }
 
@@ -1111,13 +1127,8 @@ impl Cache {
             }
         }
 
-        let bars_deque = self
-            .bars
-            .entry(bar_type)
-            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
-
         for bar in bars {
-            bars_deque.push_front(*bar);
+            self.data.add_bar(*bar);
         }
         Ok(())
     }
@@ -1135,6 +1146,8 @@ impl Cache {
     }
 
     /// Adds the given `instrument` to the cache.
+    ///
+    /// Replacing the definition of a cached instrument increments its version.
     pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
         log::debug!("Adding `Instrument` {}", instrument.id());
 
@@ -1142,10 +1155,46 @@ impl Cache {
             database.add_instrument(&instrument)?;
         }
 
-        self.instruments.insert(instrument.id(), instrument);
+        let instrument_id = instrument.id();
+        if let Some(previous) = self.instruments.get(&instrument_id) {
+            self.index.unindex_instrument(previous);
+            *self.instrument_versions.entry(instrument_id).or_default() += 1;
+        }
+        self.index.index_instrument(&instrument);
+        self.instruments.insert(instrument_id, instrument);
         Ok(())
     }
 
+    /// Applies the given instrument `update` to the cached instrument in place, returning the
+    /// new version of the instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument is not in the cache.
+    /// - If the `update` cannot be applied to the instrument (which is then unchanged).
+    pub fn update_instrument(&mut self, update: &InstrumentUpdate) -> anyhow::Result<u64> {
+        log::debug!("Updating `Instrument` {}", update.instrument_id);
+
+        let Some(instrument) = self.instruments.get(&update.instrument_id) else {
+            anyhow::bail!("Instrument {} not found in cache", update.instrument_id);
+        };
+        let mut instrument = instrument.clone();
+        instrument.apply_update(update)?;
+
+        if let Some(database) = &mut self.database {
+            database.add_instrument(&instrument)?;
+        }
+
+        self.instruments.insert(update.instrument_id, instrument);
+        let version = self
+            .instrument_versions
+            .entry(update.instrument_id)
+            .or_default();
+        *version += 1;
+        Ok(*version)
+    }
+
     /// Adds the given `synthetic` instrument to the cache.
     pub fn add_synthetic(&mut self, synthetic: SyntheticInstrument) -> anyhow::Result<()> {
         log::debug!("Adding `SyntheticInstrument` {}", synthetic.id);
@@ -1433,6 +1482,7 @@ impl Cache {
         if let Some(database) = &mut self.database {
             database.update_account(&account)?;
         }
+        self.accounts.insert(account.id(), account);
         Ok(())
     }
 
@@ -1483,6 +1533,8 @@ impl Cache {
             // }
         }
 
+        self.orders.insert(client_order_id, order.clone());
+
         Ok(())
     }
 
@@ -1504,6 +1556,8 @@ impl Cache {
             self.index.positions_open.remove(&position.id);
         }
 
+        self.positions.insert(position.id, position.clone());
+
         if let Some(database) = &mut self.database {
             database.update_position(position)?;
             // TODO: Implement order snapshots
@@ -2339,51 +2393,81 @@ impl Cache {
     #[must_use]
     pub fn price(&self, instrument_id: &InstrumentId, price_type: PriceType) -> Option<Price> {
         match price_type {
-            PriceType::Bid => self
-                .quotes
-                .get(instrument_id)
-                .and_then(|quotes| quotes.front().map(|quote| quote.bid_price)),
-            PriceType::Ask => self
-                .quotes
-                .get(instrument_id)
-                .and_then(|quotes| quotes.front().map(|quote| quote.ask_price)),
-            PriceType::Mid => self.quotes.get(instrument_id).and_then(|quotes| {
-                quotes.front().map(|quote| {
-                    Price::new(
-                        (quote.ask_price.as_f64() + quote.bid_price.as_f64()) / 2.0,
-                        quote.bid_price.precision + 1,
-                    )
-                })
+            PriceType::Bid => self.quote(instrument_id).map(|quote| quote.bid_price),
+            PriceType::Ask => self.quote(instrument_id).map(|quote| quote.ask_price),
+            PriceType::Mid => self.quote(instrument_id).map(|quote| {
+                Price::new(
+                    (quote.ask_price.as_f64() + quote.bid_price.as_f64()) / 2.0,
+                    quote.bid_price.precision + 1,
+                )
             }),
-            PriceType::Last => self
-                .trades
-                .get(instrument_id)
-                .and_then(|trades| trades.front().map(|trade| trade.price)),
+            PriceType::Last => self.trade(instrument_id).map(|trade| trade.price),
         }
     }
 
+    /// Returns the exchange rate from `from_currency` to `to_currency` for the given
+    /// `price_type`, from the price of a currency pair on the given `venue` quoted in either
+    /// direction (if found).
+    #[must_use]
+    pub fn get_xrate(
+        &self,
+        venue: &Venue,
+        from_currency: Currency,
+        to_currency: Currency,
+        price_type: PriceType,
+    ) -> Option<f64> {
+        if from_currency == to_currency {
+            return Some(1.0);
+        }
+
+        for instrument in self.instruments(venue, None) {
+            let Some(base_currency) = instrument.base_currency() else {
+                continue;
+            };
+            let quote_currency = instrument.quote_currency();
+            let Some(price) = self
+                .price(&instrument.id(), price_type)
+                .filter(|price| price.raw > 0)
+            else {
+                continue;
+            };
+
+            if base_currency == from_currency && quote_currency == to_currency {
+                return Some(price.as_f64());
+            }
+            if base_currency == to_currency && quote_currency == from_currency {
+                return Some(1.0 / price.as_f64());
+            }
+        }
+        None
+    }
+
+    /// Returns a reference to the rolling windows of recent quotes, trades and bars.
+    #[must_use]
+    pub const fn data(&self) -> &DataCache {
+        &self.data
+    }
+
     /// Gets all quote ticks for the given `instrument_id`.
     #[must_use]
     pub fn quotes(&self, instrument_id: &InstrumentId) -> Option<Vec<QuoteTick>> {
-        self.quotes
-            .get(instrument_id)
-            .map(|quotes| quotes.iter().copied().collect())
+        self.data
+            .quote_window(instrument_id)
+            .map(|quotes| quotes.to_vec())
     }
 
     /// Gets all trade ticks for the given `instrument_id`.
     #[must_use]
     pub fn trades(&self, instrument_id: &InstrumentId) -> Option<Vec<TradeTick>> {
-        self.trades
-            .get(instrument_id)
-            .map(|trades| trades.iter().copied().collect())
+        self.data
+            .trade_window(instrument_id)
+            .map(|trades| trades.to_vec())
     }
 
     /// Gets all bars for the given `bar_type`.
     #[must_use]
     pub fn bars(&self, bar_type: &BarType) -> Option<Vec<Bar>> {
-        self.bars
-            .get(bar_type)
-            .map(|bars| bars.iter().copied().collect())
+        self.data.bar_window(bar_type).map(|bars| bars.to_vec())
     }
 
     /// Gets a reference to the order book for the given `instrument_id`.
@@ -2401,23 +2485,19 @@ impl Cache {
     /// Gets a reference to the latest quote tick for the given `instrument_id`.
     #[must_use]
     pub fn quote(&self, instrument_id: &InstrumentId) -> Option<&QuoteTick> {
-        self.quotes
-            .get(instrument_id)
-            .and_then(|quotes| quotes.front())
+        self.data.quote(instrument_id)
     }
 
     /// Gets a refernece to the latest trade tick for the given `instrument_id`.
     #[must_use]
     pub fn trade(&self, instrument_id: &InstrumentId) -> Option<&TradeTick> {
-        self.trades
-            .get(instrument_id)
-            .and_then(|trades| trades.front())
+        self.data.trade(instrument_id)
     }
 
     /// Gets a reference to the latest bar for the given `bar_type`.
     #[must_use]
     pub fn bar(&self, bar_type: &BarType) -> Option<&Bar> {
-        self.bars.get(bar_type).and_then(|bars| bars.front())
+        self.data.bar(bar_type)
     }
 
     /// Gets the order book update count for the given `instrument_id`.
@@ -2429,25 +2509,19 @@ impl Cache {
     /// Gets the quote tick count for the given `instrument_id`.
     #[must_use]
     pub fn quote_count(&self, instrument_id: &InstrumentId) -> usize {
-        self.quotes
-            .get(instrument_id)
-            .map_or(0, std::collections::VecDeque::len)
+        self.data.quotes(instrument_id).len()
     }
 
     /// Gets the trade tick count for the given `instrument_id`.
     #[must_use]
     pub fn trade_count(&self, instrument_id: &InstrumentId) -> usize {
-        self.trades
-            .get(instrument_id)
-            .map_or(0, std::collections::VecDeque::len)
+        self.data.trades(instrument_id).len()
     }
 
     /// Gets the bar count for the given `instrument_id`.
     #[must_use]
     pub fn bar_count(&self, bar_type: &BarType) -> usize {
-        self.bars
-            .get(bar_type)
-            .map_or(0, std::collections::VecDeque::len)
+        self.data.bars(bar_type).len()
     }
 
     /// Returns whether the cache contains an order book for the given `instrument_id`.
@@ -2482,6 +2556,51 @@ impl Cache {
         self.instruments.get(instrument_id)
     }
 
+    /// Returns the version of the instrument for the given `instrument_id` (if found), which
+    /// starts at 0 and increments with each update to its definition.
+    #[must_use]
+    pub fn instrument_version(&self, instrument_id: &InstrumentId) -> Option<u64> {
+        self.instruments.contains_key(instrument_id).then(|| {
+            self.instrument_versions
+                .get(instrument_id)
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns a reference to the instrument with the given `isin` (if found).
+    #[must_use]
+    pub fn instrument_by_isin(&self, isin: &Ustr) -> Option<&InstrumentAny> {
+        self.index
+            .isin_instrument
+            .get(isin)
+            .and_then(|instrument_id| self.instruments.get(instrument_id))
+    }
+
+    /// Returns a reference to the instrument with the given `figi` (if found).
+    #[must_use]
+    pub fn instrument_by_figi(&self, figi: &Ustr) -> Option<&InstrumentAny> {
+        self.index
+            .figi_instrument
+            .get(figi)
+            .and_then(|instrument_id| self.instruments.get(instrument_id))
+    }
+
+    /// Returns references to all instruments trading on the given ISO 10383 `operating_mic`,
+    /// including all of its market segments (e.g. GLBX and XNYM for XCME).
+    ///
+    /// The operating MIC of each instrument is resolved from the global [`MIC_REGISTRY`], using
+    /// its `exchange` (if any) otherwise its venue.
+    #[must_use]
+    pub fn instruments_by_operating_mic(&self, operating_mic: &str) -> Vec<&InstrumentAny> {
+        let operating_mic = Ustr::from(operating_mic);
+        let registry = MIC_REGISTRY.lock().expect("`MIC_REGISTRY` lock poisoned");
+        self.instruments
+            .values()
+            .filter(|i| registry.instrument_operating_mic(i) == Some(operating_mic))
+            .collect()
+    }
+
     /// Returns references to all instrument IDs for the given `venue`.
     #[must_use]
     pub fn instrument_ids(&self, venue: Option<&Venue>) -> Vec<&InstrumentId> {
@@ -2510,8 +2629,8 @@ impl Cache {
         aggregation_source: AggregationSource,
     ) -> Vec<&BarType> {
         let mut bar_types = self
-            .bars
-            .keys()
+            .data
+            .bar_types()
             .filter(|bar_type| bar_type.aggregation_source() == aggregation_source)
             .collect::<Vec<&BarType>>();
 
@@ -2563,12 +2682,32 @@ impl Cache {
             .and_then(|account_id| self.accounts.get(account_id))
     }
 
+    /// Returns references to all accounts for the given `venue`, sorted by account ID, for venues
+    /// with multiple accounts (sub-accounts).
+    #[must_use]
+    pub fn accounts_for_venue(&self, venue: &Venue) -> Vec<&AccountAny> {
+        let mut accounts: Vec<&AccountAny> = self
+            .accounts
+            .iter()
+            .filter(|(account_id, _)| &account_id.get_issuer() == venue)
+            .map(|(_, account)| account)
+            .collect();
+        accounts.sort_by_key(|account| account.id());
+        accounts
+    }
+
     /// Returns a reference to the account ID for the given `venue` (if found).
     #[must_use]
     pub fn account_id(&self, venue: &Venue) -> Option<&AccountId> {
         self.index.venue_account.get(venue)
     }
 
+    /// Returns references to the IDs of all accounts.
+    #[must_use]
+    pub fn account_ids(&self) -> Vec<&AccountId> {
+        self.accounts.keys().collect()
+    }
+
     /// Returns references to all accounts for the given `account_id`.
     #[must_use]
     pub fn accounts(&self, account_id: &AccountId) -> Vec<&AccountAny> {
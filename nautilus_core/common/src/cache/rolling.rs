@@ -0,0 +1,365 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bounded rolling windows of recent market data.
+//!
+//! A [`RollingWindow`] holds the most recent items with the latest item first, so that
+//! `window[..n]` is a slice of the `n` most recent items. A [`DataCache`] maintains these windows
+//! of quotes, trades and bars so that recent data can be queried without requesting history.
+
+use std::{collections::HashMap, ops::Deref};
+
+use nautilus_core::correctness::{check_predicate_true, FAILED};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    identifiers::InstrumentId,
+};
+
+/// A bounded window of the most recent items, indexed from the latest item.
+///
+/// The items are stored in a buffer of twice the capacity, which is written backwards so that
+/// the window is always contiguous. When the start of the buffer is reached the retained items
+/// are copied to the end, giving amortized O(1) appends.
+#[derive(Clone, Debug)]
+pub struct RollingWindow<T: Copy> {
+    capacity: usize,
+    buffer: Vec<T>,
+    start: usize,
+    len: usize,
+}
+
+impl<T: Copy> RollingWindow<T> {
+    /// Creates a new [`RollingWindow`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `capacity` is zero.
+    pub fn new_checked(capacity: usize) -> anyhow::Result<Self> {
+        check_predicate_true(capacity > 0, "`capacity` must be positive")?;
+
+        Ok(Self {
+            capacity,
+            buffer: Vec::new(),
+            start: 0,
+            len: 0,
+        })
+    }
+
+    /// Creates a new [`RollingWindow`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::new_checked(capacity).expect(FAILED)
+    }
+
+    /// Returns the maximum number of items held by the window.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns whether the window holds as many items as its capacity.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Returns the latest item (if any).
+    #[must_use]
+    pub fn latest(&self) -> Option<&T> {
+        self.first()
+    }
+
+    /// Returns the items, latest first.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.buffer[self.start..self.start + self.len]
+    }
+
+    /// Appends the given `item` as the latest, dropping the oldest item if the window is full.
+    pub fn push(&mut self, item: T) {
+        if self.buffer.is_empty() {
+            self.buffer = vec![item; 2 * self.capacity];
+            self.start = self.buffer.len();
+        }
+
+        if self.start == 0 {
+            let retained = self.len.min(self.capacity - 1);
+            let offset = self.buffer.len() - retained;
+            self.buffer.copy_within(..retained, offset);
+            self.start = offset;
+            self.len = retained;
+        }
+
+        self.start -= 1;
+        self.buffer[self.start] = item;
+        self.len = (self.len + 1).min(self.capacity);
+    }
+
+    /// Removes all items from the window.
+    pub fn clear(&mut self) {
+        self.buffer = Vec::new();
+        self.start = 0;
+        self.len = 0;
+    }
+}
+
+impl<T: Copy> Deref for RollingWindow<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+/// Maintains bounded rolling windows of recent quotes, trades and bars.
+#[derive(Clone, Debug)]
+pub struct DataCache {
+    tick_capacity: usize,
+    bar_capacity: usize,
+    quotes: HashMap<InstrumentId, RollingWindow<QuoteTick>>,
+    trades: HashMap<InstrumentId, RollingWindow<TradeTick>>,
+    bars: HashMap<BarType, RollingWindow<Bar>>,
+}
+
+impl DataCache {
+    /// Creates a new [`DataCache`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `tick_capacity` or `bar_capacity` is zero.
+    pub fn new_checked(tick_capacity: usize, bar_capacity: usize) -> anyhow::Result<Self> {
+        check_predicate_true(tick_capacity > 0, "`tick_capacity` must be positive")?;
+        check_predicate_true(bar_capacity > 0, "`bar_capacity` must be positive")?;
+
+        Ok(Self {
+            tick_capacity,
+            bar_capacity,
+            quotes: HashMap::new(),
+            trades: HashMap::new(),
+            bars: HashMap::new(),
+        })
+    }
+
+    /// Creates a new [`DataCache`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `tick_capacity` or `bar_capacity` is zero.
+    #[must_use]
+    pub fn new(tick_capacity: usize, bar_capacity: usize) -> Self {
+        Self::new_checked(tick_capacity, bar_capacity).expect(FAILED)
+    }
+
+    /// Returns the maximum number of quotes and trades held per instrument.
+    #[must_use]
+    pub const fn tick_capacity(&self) -> usize {
+        self.tick_capacity
+    }
+
+    /// Returns the maximum number of bars held per bar type.
+    #[must_use]
+    pub const fn bar_capacity(&self) -> usize {
+        self.bar_capacity
+    }
+
+    /// Adds the given `quote` as the latest for its instrument.
+    pub fn add_quote(&mut self, quote: QuoteTick) {
+        self.quotes
+            .entry(quote.instrument_id)
+            .or_insert_with(|| RollingWindow::new(self.tick_capacity))
+            .push(quote);
+    }
+
+    /// Adds the given `trade` as the latest for its instrument.
+    pub fn add_trade(&mut self, trade: TradeTick) {
+        self.trades
+            .entry(trade.instrument_id)
+            .or_insert_with(|| RollingWindow::new(self.tick_capacity))
+            .push(trade);
+    }
+
+    /// Adds the given `bar` as the latest for its bar type.
+    pub fn add_bar(&mut self, bar: Bar) {
+        self.bars
+            .entry(bar.bar_type)
+            .or_insert_with(|| RollingWindow::new(self.bar_capacity))
+            .push(bar);
+    }
+
+    /// Returns the quote window for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn quote_window(&self, instrument_id: &InstrumentId) -> Option<&RollingWindow<QuoteTick>> {
+        self.quotes.get(instrument_id)
+    }
+
+    /// Returns the trade window for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn trade_window(&self, instrument_id: &InstrumentId) -> Option<&RollingWindow<TradeTick>> {
+        self.trades.get(instrument_id)
+    }
+
+    /// Returns the bar window for the given `bar_type` (if found).
+    #[must_use]
+    pub fn bar_window(&self, bar_type: &BarType) -> Option<&RollingWindow<Bar>> {
+        self.bars.get(bar_type)
+    }
+
+    /// Returns the bar types with a bar window.
+    pub fn bar_types(&self) -> impl Iterator<Item = &BarType> {
+        self.bars.keys()
+    }
+
+    /// Returns the recent quotes for the given `instrument_id`, latest first.
+    #[must_use]
+    pub fn quotes(&self, instrument_id: &InstrumentId) -> &[QuoteTick] {
+        self.quote_window(instrument_id)
+            .map_or(&[], RollingWindow::as_slice)
+    }
+
+    /// Returns the recent trades for the given `instrument_id`, latest first.
+    #[must_use]
+    pub fn trades(&self, instrument_id: &InstrumentId) -> &[TradeTick] {
+        self.trade_window(instrument_id)
+            .map_or(&[], RollingWindow::as_slice)
+    }
+
+    /// Returns the recent bars for the given `bar_type`, latest first.
+    #[must_use]
+    pub fn bars(&self, bar_type: &BarType) -> &[Bar] {
+        self.bar_window(bar_type)
+            .map_or(&[], RollingWindow::as_slice)
+    }
+
+    /// Returns the latest quote for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn quote(&self, instrument_id: &InstrumentId) -> Option<&QuoteTick> {
+        self.quotes(instrument_id).first()
+    }
+
+    /// Returns the latest trade for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn trade(&self, instrument_id: &InstrumentId) -> Option<&TradeTick> {
+        self.trades(instrument_id).first()
+    }
+
+    /// Returns the latest bar for the given `bar_type` (if found).
+    #[must_use]
+    pub fn bar(&self, bar_type: &BarType) -> Option<&Bar> {
+        self.bars(bar_type).first()
+    }
+
+    /// Removes all windows from the cache.
+    pub fn clear(&mut self) {
+        self.quotes.clear();
+        self.trades.clear();
+        self.bars.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::{quote_ethusdt_binance, stub_bar};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_rolling_window_zero_capacity() {
+        assert!(RollingWindow::<u64>::new_checked(0).is_err());
+    }
+
+    #[rstest]
+    fn test_rolling_window_empty() {
+        let window = RollingWindow::<u64>::new(3);
+
+        assert!(window.is_empty());
+        assert!(!window.is_full());
+        assert_eq!(window.latest(), None);
+        assert_eq!(window.as_slice(), &[] as &[u64]);
+    }
+
+    #[rstest]
+    #[case(1, vec![1, 2, 3, 4, 5], vec![5])]
+    #[case(3, vec![1, 2], vec![2, 1])]
+    #[case(3, vec![1, 2, 3], vec![3, 2, 1])]
+    #[case(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10], vec![10, 9, 8])]
+    fn test_rolling_window_push(
+        #[case] capacity: usize,
+        #[case] items: Vec<u64>,
+        #[case] expected: Vec<u64>,
+    ) {
+        let mut window = RollingWindow::new(capacity);
+        for item in items {
+            window.push(item);
+        }
+
+        assert_eq!(window.as_slice(), expected.as_slice());
+        assert_eq!(window.latest(), expected.first());
+    }
+
+    #[rstest]
+    fn test_rolling_window_index_and_slice() {
+        let mut window = RollingWindow::new(4);
+        for item in 1..=6_u64 {
+            window.push(item);
+        }
+
+        assert!(window.is_full());
+        assert_eq!(window[0], 6);
+        assert_eq!(window[3], 3);
+        assert_eq!(&window[..2], &[6, 5]);
+        window.clear();
+        assert!(window.is_empty());
+    }
+
+    #[rstest]
+    fn test_data_cache_windows() {
+        let mut cache = DataCache::new(2, 1);
+        let quote = quote_ethusdt_binance();
+        let bar = stub_bar();
+        let mut bar2 = bar;
+        bar2.ts_event = bar.ts_event + 60_000_000_000;
+
+        for ts in [1, 2, 3] {
+            let mut quote = quote;
+            quote.ts_init = ts.into();
+            cache.add_quote(quote);
+        }
+        cache.add_bar(bar);
+        cache.add_bar(bar2);
+
+        let quotes = cache.quotes(&quote.instrument_id);
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].ts_init, 3);
+        assert_eq!(quotes[1].ts_init, 2);
+        assert_eq!(cache.bars(&bar.bar_type), &[bar2]);
+        assert!(cache.trades(&quote.instrument_id).is_empty());
+        assert_eq!(cache.trade(&quote.instrument_id), None);
+
+        cache.clear();
+        assert!(cache.quote_window(&quote.instrument_id).is_none());
+    }
+}
@@ -16,23 +16,29 @@
 //! Tests module for `Cache`.
 
 use bytes::Bytes;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
-    accounts::any::AccountAny,
+    accounts::{any::AccountAny, cash::CashAccount},
     data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
-    enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType},
-    events::order::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
-    identifiers::{AccountId, ClientOrderId, PositionId, Venue},
+    enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType, PriceType},
+    events::{
+        instrument::InstrumentUpdate,
+        order::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
+    },
+    identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId, Venue},
     instruments::{
-        any::InstrumentAny, currency_pair::CurrencyPair, stubs::*, synthetic::SyntheticInstrument,
+        any::InstrumentAny, currency_pair::CurrencyPair, equity::Equity, stubs::*,
+        synthetic::SyntheticInstrument,
     },
     orderbook::book::OrderBook,
     orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
     position::Position,
-    types::{price::Price, quantity::Quantity},
+    types::{currency::Currency, price::Price, quantity::Quantity},
 };
 use rstest::{fixture, rstest};
+use ustr::Ustr;
 
-use super::Cache;
+use super::{Cache, CacheConfig};
 
 #[fixture]
 fn cache() -> Cache {
@@ -435,6 +441,92 @@ fn test_instrument_when_some(mut cache: Cache, audusd_sim: CurrencyPair) {
     assert_eq!(result, Some(&InstrumentAny::CurrencyPair(audusd_sim)));
 }
 
+#[rstest]
+fn test_update_instrument(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let instrument_id = audusd_sim.id;
+    cache
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+    let update = InstrumentUpdate::new(
+        instrument_id,
+        Some(Price::from("0.00005")),
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+
+    assert_eq!(cache.instrument_version(&instrument_id), Some(0));
+    assert_eq!(cache.update_instrument(&update).unwrap(), 1);
+    assert_eq!(cache.instrument_version(&instrument_id), Some(1));
+    assert_eq!(
+        cache.instrument(&instrument_id).unwrap().price_increment(),
+        Price::from("0.00005")
+    );
+
+    cache
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+    assert_eq!(cache.instrument_version(&instrument_id), Some(2));
+}
+
+#[rstest]
+fn test_update_instrument_when_invalid(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let update = InstrumentUpdate::new(
+        audusd_sim.id,
+        Some(Price::from("0.01")),
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+    assert!(cache.update_instrument(&update).is_err());
+
+    cache
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+    assert!(cache.update_instrument(&update).is_err());
+    assert_eq!(cache.instrument_version(&audusd_sim.id), Some(0));
+    assert_eq!(
+        cache.instrument(&audusd_sim.id).unwrap().price_increment(),
+        audusd_sim.price_increment
+    );
+}
+
+#[rstest]
+fn test_get_xrate(mut cache: Cache, audusd_sim: CurrencyPair) {
+    cache
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+    let quote = QuoteTick {
+        instrument_id: audusd_sim.id,
+        bid_price: Price::from("0.80000"),
+        ask_price: Price::from("0.80000"),
+        ..Default::default()
+    };
+    let venue = audusd_sim.id.venue;
+    let aud = Currency::AUD();
+    let usd = Currency::USD();
+
+    assert_eq!(cache.get_xrate(&venue, aud, usd, PriceType::Mid), None);
+    assert_eq!(cache.get_xrate(&venue, usd, usd, PriceType::Mid), Some(1.0));
+
+    cache.add_quote(quote).unwrap();
+    assert_eq!(cache.get_xrate(&venue, aud, usd, PriceType::Mid), Some(0.8));
+    assert_eq!(
+        cache.get_xrate(&venue, usd, aud, PriceType::Bid),
+        Some(1.25)
+    );
+    assert_eq!(
+        cache.get_xrate(&venue, aud, Currency::EUR(), PriceType::Mid),
+        None
+    );
+}
+
 #[rstest]
 fn test_instruments_when_empty(cache: Cache) {
     let esz1 = futures_contract_es(None, None);
@@ -455,6 +547,87 @@ fn test_instruments_when_some(mut cache: Cache) {
     assert_eq!(result2, vec![&InstrumentAny::FuturesContract(esz1)]);
 }
 
+#[rstest]
+fn test_instrument_by_isin_and_figi(mut cache: Cache, equity_aapl: Equity) {
+    let isin = Ustr::from("US0378331005");
+    let figi = Ustr::from("BBG000B9XRY4");
+    assert!(cache.instrument_by_isin(&isin).is_none());
+
+    cache
+        .add_instrument(InstrumentAny::Equity(equity_aapl))
+        .unwrap();
+
+    assert_eq!(
+        cache.instrument_by_isin(&isin),
+        Some(&InstrumentAny::Equity(equity_aapl))
+    );
+    assert_eq!(
+        cache.instrument_by_figi(&figi),
+        Some(&InstrumentAny::Equity(equity_aapl))
+    );
+}
+
+#[rstest]
+fn test_instrument_by_isin_when_identifiers_replaced(mut cache: Cache, equity_aapl: Equity) {
+    cache
+        .add_instrument(InstrumentAny::Equity(equity_aapl))
+        .unwrap();
+    let mut replacement = equity_aapl;
+    replacement.isin = Some(Ustr::from("US5949181045"));
+    replacement.figi = None;
+    cache
+        .add_instrument(InstrumentAny::Equity(replacement))
+        .unwrap();
+
+    assert!(cache
+        .instrument_by_isin(&Ustr::from("US0378331005"))
+        .is_none());
+    assert!(cache
+        .instrument_by_figi(&Ustr::from("BBG000B9XRY4"))
+        .is_none());
+    assert_eq!(
+        cache
+            .instrument_by_isin(&Ustr::from("US5949181045"))
+            .map(InstrumentAny::id),
+        Some(equity_aapl.id)
+    );
+
+    // Rebuilding the index preserves the lookup
+    cache.build_index();
+    assert!(cache
+        .instrument_by_isin(&Ustr::from("US5949181045"))
+        .is_some());
+}
+
+#[rstest]
+fn test_instruments_by_operating_mic(mut cache: Cache, equity_aapl: Equity) {
+    let esz1 = futures_contract_es(None, None);
+    let mut clz1 = esz1;
+    clz1.id = InstrumentId::from("CLZ1.GLBX");
+    clz1.exchange = Some(Ustr::from("XNYM"));
+    for instrument in [
+        InstrumentAny::FuturesContract(esz1),
+        InstrumentAny::FuturesContract(clz1),
+        InstrumentAny::Equity(equity_aapl),
+    ] {
+        cache.add_instrument(instrument).unwrap();
+    }
+
+    let mut result: Vec<InstrumentId> = cache
+        .instruments_by_operating_mic("XCME")
+        .into_iter()
+        .map(InstrumentAny::id)
+        .collect();
+    result.sort();
+
+    assert_eq!(result, vec![clz1.id, esz1.id]);
+    assert_eq!(
+        cache.instruments_by_operating_mic("XNAS"),
+        vec![&InstrumentAny::Equity(equity_aapl)]
+    );
+    assert!(cache.instruments_by_operating_mic("XNYS").is_empty());
+}
+
 #[rstest]
 fn test_cache_synthetics_when_no_database(mut cache: Cache) {
     assert!(cache.cache_synthetics().is_ok());
@@ -598,6 +771,28 @@ fn test_bars_when_some(mut cache: Cache) {
     assert_eq!(result, Some(bars));
 }
 
+#[rstest]
+fn test_quote_ticks_bounded_by_tick_capacity() {
+    let config = CacheConfig {
+        tick_capacity: 2,
+        ..Default::default()
+    };
+    let mut cache = Cache::new(Some(config), None);
+    let quotes: Vec<QuoteTick> = (1..=3_u64)
+        .map(|ts| QuoteTick {
+            ts_init: ts.into(),
+            ..Default::default()
+        })
+        .collect();
+    cache.add_quotes(&quotes).unwrap();
+
+    let instrument_id = quotes[0].instrument_id;
+    let recent = cache.data().quotes(&instrument_id);
+    assert_eq!(cache.quote_count(&instrument_id), 2);
+    assert_eq!(recent[0], quotes[2]);
+    assert_eq!(recent[..2], [quotes[2], quotes[1]]);
+}
+
 // -- ACCOUNT ---------------------------------------------------------------------------------
 
 #[rstest]
@@ -614,6 +809,20 @@ fn test_cache_add_account(mut cache: Cache) {
     assert_eq!(*result.unwrap(), account);
 }
 
+#[rstest]
+fn test_cache_update_account(mut cache: Cache) {
+    let mut account = AccountAny::default();
+    cache.add_account(account.clone()).unwrap();
+    let mut state = account.last_event().unwrap();
+    state.event_id = UUID4::new();
+    account.apply(state);
+
+    cache.update_account(account.clone()).unwrap();
+
+    let result = cache.account(&account.id()).unwrap();
+    assert_eq!(result.events().len(), 2);
+}
+
 #[rstest]
 fn test_cache_accounts_when_no_accounts_returns_empty(cache: Cache) {
     let result = cache.accounts(&AccountId::default());
@@ -627,6 +836,20 @@ fn test_cache_account_for_venue_returns_empty(cache: Cache) {
     assert!(result.is_none());
 }
 
+#[rstest]
+fn test_cache_accounts_for_venue_returns_sub_accounts(mut cache: Cache) {
+    let account = AccountAny::default();
+    let mut state = account.last_event().unwrap();
+    state.account_id = AccountId::from("SIM-002");
+    let sub_account = AccountAny::Cash(CashAccount::new(state, true));
+    cache.add_account(sub_account.clone()).unwrap();
+    cache.add_account(account.clone()).unwrap();
+
+    let result = cache.accounts_for_venue(&Venue::from("SIM"));
+    assert_eq!(result, vec![&account, &sub_account]);
+    assert!(cache.accounts_for_venue(&Venue::from("BINANCE")).is_empty());
+}
+
 #[rstest]
 fn test_cache_account_for_venue_return_correct(mut cache: Cache) {
     let account = AccountAny::default();
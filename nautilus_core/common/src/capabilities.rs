@@ -0,0 +1,258 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The capabilities declared by data and execution clients.
+//!
+//! The `DataEngine` and `ExecutionEngine` check requests against the [`ClientCapabilities`] of
+//! the client they are routed to before forwarding them, so that unsupported requests fail
+//! immediately with a clear error rather than being rejected by the venue.
+
+use std::{collections::HashSet, fmt::Display, hash::Hash, str::FromStr};
+
+use nautilus_model::{
+    data::DataType,
+    enums::{BookType, OrderType, TimeInForce},
+    orders::any::OrderAny,
+};
+
+/// Represents the capabilities of a data or execution client.
+///
+/// Each capability is unrestricted if `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientCapabilities {
+    /// The supported order types.
+    pub order_types: Option<HashSet<OrderType>>,
+    /// The supported time in force options.
+    pub time_in_force: Option<HashSet<TimeInForce>>,
+    /// The supported data type names, e.g. `QuoteTick`.
+    pub data_types: Option<HashSet<String>>,
+    /// The supported order book types.
+    pub book_types: Option<HashSet<BookType>>,
+    /// The supported order book depths.
+    pub book_depths: Option<HashSet<usize>>,
+}
+
+impl ClientCapabilities {
+    /// Creates a new [`ClientCapabilities`] instance with all capabilities unrestricted.
+    #[must_use]
+    pub const fn unrestricted() -> Self {
+        Self {
+            order_types: None,
+            time_in_force: None,
+            data_types: None,
+            book_types: None,
+            book_depths: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_order_types(mut self, order_types: impl IntoIterator<Item = OrderType>) -> Self {
+        self.order_types = Some(order_types.into_iter().collect());
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_in_force(
+        mut self,
+        time_in_force: impl IntoIterator<Item = TimeInForce>,
+    ) -> Self {
+        self.time_in_force = Some(time_in_force.into_iter().collect());
+        self
+    }
+
+    #[must_use]
+    pub fn with_data_types<'a>(mut self, data_types: impl IntoIterator<Item = &'a str>) -> Self {
+        self.data_types = Some(data_types.into_iter().map(str::to_string).collect());
+        self
+    }
+
+    #[must_use]
+    pub fn with_book_types(mut self, book_types: impl IntoIterator<Item = BookType>) -> Self {
+        self.book_types = Some(book_types.into_iter().collect());
+        self
+    }
+
+    #[must_use]
+    pub fn with_book_depths(mut self, book_depths: impl IntoIterator<Item = usize>) -> Self {
+        self.book_depths = Some(book_depths.into_iter().collect());
+        self
+    }
+
+    /// Checks the given `order` is supported.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order type or time in force is not supported.
+    pub fn check_order(&self, order: &OrderAny) -> anyhow::Result<()> {
+        check_supported("order type", &order.order_type(), &self.order_types)?;
+        check_supported("time in force", &order.time_in_force(), &self.time_in_force)
+    }
+
+    /// Checks a subscription or request for the given `data_type` is supported.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the data type, or the order book type or depth from
+    /// its metadata, is not supported.
+    pub fn check_data_type(&self, data_type: &DataType) -> anyhow::Result<()> {
+        check_supported(
+            "data type",
+            &data_type.type_name().to_string(),
+            &self.data_types,
+        )?;
+
+        let metadata = data_type.metadata();
+        let value = |key: &str| metadata.and_then(|metadata| metadata.get(key));
+        if let Some(book_type) = value("book_type") {
+            let book_type = BookType::from_str(book_type)?;
+            check_supported("book type", &book_type, &self.book_types)?;
+        }
+        if let Some(depth) = value("depth") {
+            let depth: usize = depth.parse()?;
+            check_supported("book depth", &depth, &self.book_depths)?;
+        }
+        Ok(())
+    }
+}
+
+fn check_supported<T: Display + Eq + Hash>(
+    capability: &str,
+    value: &T,
+    supported: &Option<HashSet<T>>,
+) -> anyhow::Result<()> {
+    let Some(supported) = supported else {
+        return Ok(());
+    };
+    if !supported.contains(value) {
+        let mut names: Vec<String> = supported.iter().map(ToString::to_string).collect();
+        names.sort();
+        anyhow::bail!(
+            "{capability} {value} not supported, supported: [{}]",
+            names.join(", ")
+        );
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use nautilus_model::{
+        identifiers::InstrumentId,
+        orders::builder::OrderTestBuilder,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order(order_type: OrderType, time_in_force: TimeInForce) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(InstrumentId::from("BTCUSDT-PERP.BINANCE"))
+            .quantity(Quantity::from(1))
+            .time_in_force(time_in_force);
+        if order_type != OrderType::Market {
+            builder.price(Price::from("100.00"));
+        }
+        builder.build()
+    }
+
+    fn book_data_type(book_type: &str, depth: &str) -> DataType {
+        let metadata = IndexMap::from([
+            (
+                "instrument_id".to_string(),
+                "BTCUSDT-PERP.BINANCE".to_string(),
+            ),
+            ("book_type".to_string(), book_type.to_string()),
+            ("depth".to_string(), depth.to_string()),
+        ]);
+        DataType::new("OrderBookDelta", Some(metadata))
+    }
+
+    #[rstest]
+    fn test_unrestricted_supports_everything() {
+        let capabilities = ClientCapabilities::unrestricted();
+
+        assert_eq!(capabilities, ClientCapabilities::default());
+        assert!(capabilities
+            .check_order(&order(OrderType::Limit, TimeInForce::Fok))
+            .is_ok());
+        assert!(capabilities
+            .check_data_type(&book_data_type("L3_MBO", "100"))
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_check_order() {
+        let capabilities = ClientCapabilities::unrestricted()
+            .with_order_types([OrderType::Market, OrderType::Limit])
+            .with_time_in_force([TimeInForce::Gtc, TimeInForce::Ioc]);
+
+        assert!(capabilities
+            .check_order(&order(OrderType::Limit, TimeInForce::Ioc))
+            .is_ok());
+        assert_eq!(
+            capabilities
+                .check_order(&order(OrderType::MarketToLimit, TimeInForce::Gtc))
+                .unwrap_err()
+                .to_string(),
+            "order type MARKET_TO_LIMIT not supported, supported: [LIMIT, MARKET]"
+        );
+        assert_eq!(
+            capabilities
+                .check_order(&order(OrderType::Limit, TimeInForce::Fok))
+                .unwrap_err()
+                .to_string(),
+            "time in force FOK not supported, supported: [GTC, IOC]"
+        );
+    }
+
+    #[rstest]
+    fn test_check_data_type() {
+        let capabilities = ClientCapabilities::unrestricted()
+            .with_data_types(["OrderBookDelta", "QuoteTick"])
+            .with_book_types([BookType::L2_MBP])
+            .with_book_depths([10, 20]);
+
+        assert!(capabilities
+            .check_data_type(&book_data_type("L2_MBP", "10"))
+            .is_ok());
+        assert_eq!(
+            capabilities
+                .check_data_type(&DataType::new("TradeTick", None))
+                .unwrap_err()
+                .to_string(),
+            "data type TradeTick not supported, supported: [OrderBookDelta, QuoteTick]"
+        );
+        assert_eq!(
+            capabilities
+                .check_data_type(&book_data_type("L3_MBO", "10"))
+                .unwrap_err()
+                .to_string(),
+            "book type L3_MBO not supported, supported: [L2_MBP]"
+        );
+        assert_eq!(
+            capabilities
+                .check_data_type(&book_data_type("L2_MBP", "50"))
+                .unwrap_err()
+                .to_string(),
+            "book depth 50 not supported, supported: [10, 20]"
+        );
+    }
+}
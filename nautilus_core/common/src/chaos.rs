@@ -0,0 +1,463 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Seeded fault injection for testing resilience to unreliable client connections.
+//!
+//! A [`FaultInjector`] draws a [`Fault`] for each message passing through it from a random
+//! schedule seeded by the configuration, so a run with the same seed and messages injects exactly
+//! the same faults. Messages can be dropped, delayed, duplicated or corrupted, and a disconnect
+//! fault drops all messages until the connection is restored.
+
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos};
+use nautilus_model::{data::Data, enums::OrderSide, events::order::OrderEventAny};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::config::{check_setting, ValidateConfig};
+
+/// Configuration for `FaultInjector` instances.
+///
+/// Each probability applies per message, with the total probability of a fault at most one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FaultConfig {
+    /// The seed for the fault schedule.
+    pub seed: u64,
+    /// The probability a message is dropped.
+    pub drop_probability: f64,
+    /// The probability a message is delayed.
+    pub delay_probability: f64,
+    /// The maximum delay for a delayed message, in milliseconds.
+    pub max_delay_ms: u64,
+    /// The probability a message is delivered twice.
+    pub duplicate_probability: f64,
+    /// The probability a message is corrupted.
+    pub corrupt_probability: f64,
+    /// The probability the connection is forced to disconnect (dropping the message).
+    pub disconnect_probability: f64,
+}
+
+impl Default for FaultConfig {
+    /// Creates a new default [`FaultConfig`] instance, which injects no faults.
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            drop_probability: 0.0,
+            delay_probability: 0.0,
+            max_delay_ms: 1_000,
+            duplicate_probability: 0.0,
+            corrupt_probability: 0.0,
+            disconnect_probability: 0.0,
+        }
+    }
+}
+
+impl FaultConfig {
+    fn probabilities(&self) -> [(Fault, f64); 5] {
+        [
+            (Fault::Drop, self.drop_probability),
+            (Fault::Delay, self.delay_probability),
+            (Fault::Duplicate, self.duplicate_probability),
+            (Fault::Corrupt, self.corrupt_probability),
+            (Fault::Disconnect, self.disconnect_probability),
+        ]
+    }
+}
+
+impl ValidateConfig for FaultConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        let mut total = 0.0;
+        for (fault, probability) in self.probabilities() {
+            check_setting(
+                (0.0..=1.0).contains(&probability),
+                &format!("faults.{}_probability", fault.to_string().to_lowercase()),
+                "must be between 0 and 1",
+            )?;
+            total += probability;
+        }
+        check_setting(
+            total <= 1.0,
+            "faults",
+            "probabilities must not sum to more than 1",
+        )?;
+        check_setting(
+            self.delay_probability == 0.0 || self.max_delay_ms > 0,
+            "faults.max_delay_ms",
+            "must be positive when messages are delayed",
+        )
+    }
+}
+
+/// Represents a fault injected for a message.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum Fault {
+    /// The message is delivered unchanged.
+    None,
+    /// The message is not delivered.
+    Drop,
+    /// The message is delivered later.
+    Delay,
+    /// The message is delivered twice.
+    Duplicate,
+    /// The message is delivered corrupted.
+    Corrupt,
+    /// The connection is disconnected, and the message is not delivered.
+    Disconnect,
+}
+
+/// Represents the count of messages for each fault injected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FaultStats {
+    pub messages: u64,
+    pub dropped: u64,
+    pub delayed: u64,
+    pub duplicated: u64,
+    pub corrupted: u64,
+    pub disconnects: u64,
+}
+
+/// A message which can be corrupted by a [`FaultInjector`], such that the corruption is
+/// detectable by downstream validation.
+pub trait Corrupt {
+    /// Corrupts the message in place.
+    fn corrupt(&mut self);
+}
+
+impl Corrupt for Data {
+    /// Crosses the bid and ask of quotes, and rewinds the event timestamp of other data to zero
+    /// (out of order).
+    fn corrupt(&mut self) {
+        match self {
+            Self::Quote(quote) => std::mem::swap(&mut quote.bid_price, &mut quote.ask_price),
+            Self::Delta(delta) => delta.ts_event = UnixNanos::default(),
+            Self::Deltas(deltas) => deltas.ts_event = UnixNanos::default(),
+            Self::Depth10(depth) => depth.ts_event = UnixNanos::default(),
+            Self::Trade(trade) => trade.ts_event = UnixNanos::default(),
+            Self::Bar(bar) => bar.ts_event = UnixNanos::default(),
+        }
+    }
+}
+
+impl Corrupt for OrderEventAny {
+    /// Flips the side of fills, and rewinds the event timestamp of other events to zero.
+    fn corrupt(&mut self) {
+        let ts_event = match self {
+            Self::PartiallyFilled(event) | Self::Filled(event) => {
+                event.order_side = match event.order_side {
+                    OrderSide::Buy => OrderSide::Sell,
+                    OrderSide::Sell => OrderSide::Buy,
+                    side => side,
+                };
+                return;
+            }
+            Self::Initialized(event) => &mut event.ts_event,
+            Self::Denied(event) => &mut event.ts_event,
+            Self::Emulated(event) => &mut event.ts_event,
+            Self::Released(event) => &mut event.ts_event,
+            Self::Submitted(event) => &mut event.ts_event,
+            Self::Accepted(event) => &mut event.ts_event,
+            Self::Rejected(event) => &mut event.ts_event,
+            Self::Canceled(event) => &mut event.ts_event,
+            Self::Expired(event) => &mut event.ts_event,
+            Self::Triggered(event) => &mut event.ts_event,
+            Self::PendingUpdate(event) => &mut event.ts_event,
+            Self::PendingCancel(event) => &mut event.ts_event,
+            Self::ModifyRejected(event) => &mut event.ts_event,
+            Self::CancelRejected(event) => &mut event.ts_event,
+            Self::Updated(event) => &mut event.ts_event,
+        };
+        *ts_event = UnixNanos::default();
+    }
+}
+
+/// Injects faults into a stream of messages according to a seeded schedule.
+#[derive(Debug)]
+pub struct FaultInjector<T> {
+    config: FaultConfig,
+    rng: StdRng,
+    delayed: Vec<(UnixNanos, T)>,
+    stats: FaultStats,
+    is_disconnected: bool,
+}
+
+impl<T: Clone + Corrupt> FaultInjector<T> {
+    /// Creates a new [`FaultInjector`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `config` is invalid.
+    pub fn new(config: FaultConfig) -> anyhow::Result<Self> {
+        config.validate()?;
+        Ok(Self {
+            rng: StdRng::seed_from_u64(config.seed),
+            config,
+            delayed: Vec::new(),
+            stats: FaultStats::default(),
+            is_disconnected: false,
+        })
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &FaultConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub const fn stats(&self) -> FaultStats {
+        self.stats
+    }
+
+    #[must_use]
+    pub const fn is_disconnected(&self) -> bool {
+        self.is_disconnected
+    }
+
+    /// Restores the connection after a disconnect fault.
+    pub fn reconnect(&mut self) {
+        self.is_disconnected = false;
+    }
+
+    /// Returns the number of delayed messages not yet delivered.
+    #[must_use]
+    pub fn delayed_count(&self) -> usize {
+        self.delayed.len()
+    }
+
+    /// Processes the `message` received at `ts_now`, returning the fault injected and the
+    /// messages to deliver, which are the delayed messages now due followed by the message.
+    ///
+    /// While disconnected all messages are dropped, without drawing from the schedule.
+    pub fn process(&mut self, mut message: T, ts_now: UnixNanos) -> (Fault, Vec<T>) {
+        let mut messages = self.release(ts_now);
+        if self.is_disconnected {
+            self.stats.dropped += 1;
+            return (Fault::Drop, messages);
+        }
+
+        self.stats.messages += 1;
+        let fault = self.next_fault();
+        match fault {
+            Fault::None => messages.push(message),
+            Fault::Drop => self.stats.dropped += 1,
+            Fault::Delay => {
+                let delay_ms = self.rng.gen_range(1..=self.config.max_delay_ms);
+                let release_ts = ts_now + delay_ms * NANOSECONDS_IN_MILLISECOND;
+                let i = self.delayed.partition_point(|(ts, _)| *ts <= release_ts);
+                self.delayed.insert(i, (release_ts, message));
+                self.stats.delayed += 1;
+            }
+            Fault::Duplicate => {
+                messages.push(message.clone());
+                messages.push(message);
+                self.stats.duplicated += 1;
+            }
+            Fault::Corrupt => {
+                message.corrupt();
+                messages.push(message);
+                self.stats.corrupted += 1;
+            }
+            Fault::Disconnect => {
+                self.is_disconnected = true;
+                self.stats.disconnects += 1;
+            }
+        }
+        (fault, messages)
+    }
+
+    /// Returns the delayed messages due for delivery at `ts_now`, in order of delivery time.
+    pub fn release(&mut self, ts_now: UnixNanos) -> Vec<T> {
+        let due = self.delayed.partition_point(|(ts, _)| *ts <= ts_now);
+        self.delayed
+            .drain(..due)
+            .map(|(_, message)| message)
+            .collect()
+    }
+
+    /// Returns all delayed messages, in order of delivery time.
+    pub fn flush(&mut self) -> Vec<T> {
+        self.delayed.drain(..).map(|(_, message)| message).collect()
+    }
+
+    fn next_fault(&mut self) -> Fault {
+        let draw: f64 = self.rng.gen();
+        let mut cumulative = 0.0;
+        for (fault, probability) in self.config.probabilities() {
+            cumulative += probability;
+            if draw < cumulative {
+                return fault;
+            }
+        }
+        Fault::None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{quote::QuoteTick, stubs::quote_ethusdt_binance},
+        events::order::OrderFilled,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(ts: u64) -> Data {
+        Data::Quote(QuoteTick {
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+            ..quote_ethusdt_binance()
+        })
+    }
+
+    fn run(config: FaultConfig) -> (Vec<Fault>, Vec<Data>) {
+        let mut injector = FaultInjector::new(config).unwrap();
+        let mut faults = Vec::new();
+        let mut delivered = Vec::new();
+        for i in 0..100 {
+            let ts = UnixNanos::from(i * NANOSECONDS_IN_MILLISECOND);
+            let (fault, messages) = injector.process(quote_at(ts.as_u64()), ts);
+            faults.push(fault);
+            delivered.extend(messages);
+        }
+        delivered.extend(injector.flush());
+        (faults, delivered)
+    }
+
+    #[rstest]
+    #[case(FaultConfig { drop_probability: 1.1, ..Default::default() })]
+    #[case(FaultConfig { drop_probability: 0.6, delay_probability: 0.6, ..Default::default() })]
+    #[case(FaultConfig { delay_probability: 0.1, max_delay_ms: 0, ..Default::default() })]
+    fn test_config_validate_errors(#[case] config: FaultConfig) {
+        assert!(config.validate().is_err());
+        assert!(FaultInjector::<Data>::new(config).is_err());
+    }
+
+    #[rstest]
+    fn test_no_faults_by_default() {
+        let (faults, delivered) = run(FaultConfig::default());
+
+        assert!(faults.iter().all(|fault| *fault == Fault::None));
+        assert_eq!(delivered.len(), 100);
+    }
+
+    #[rstest]
+    fn test_schedule_is_deterministic_for_seed() {
+        let config = FaultConfig {
+            seed: 42,
+            drop_probability: 0.1,
+            delay_probability: 0.1,
+            max_delay_ms: 5,
+            duplicate_probability: 0.1,
+            corrupt_probability: 0.1,
+            ..Default::default()
+        };
+
+        let (faults, delivered) = run(config.clone());
+        assert_eq!(run(config.clone()), (faults.clone(), delivered));
+
+        let other = run(FaultConfig { seed: 7, ..config }).0;
+        assert_ne!(faults, other);
+        assert!(faults.contains(&Fault::Delay));
+    }
+
+    #[rstest]
+    fn test_delayed_messages_released_when_due() {
+        let config = FaultConfig {
+            delay_probability: 1.0,
+            max_delay_ms: 1,
+            ..Default::default()
+        };
+        let mut injector = FaultInjector::new(config).unwrap();
+
+        let (fault, messages) = injector.process(quote_at(0), UnixNanos::default());
+        assert_eq!(fault, Fault::Delay);
+        assert!(messages.is_empty());
+        assert_eq!(injector.delayed_count(), 1);
+
+        assert!(injector.release(UnixNanos::from(1)).is_empty());
+        let released = injector.release(UnixNanos::from(NANOSECONDS_IN_MILLISECOND));
+        assert_eq!(released, vec![quote_at(0)]);
+        assert_eq!(injector.stats().delayed, 1);
+    }
+
+    #[rstest]
+    fn test_duplicate_and_corrupt() {
+        let mut duplicator = FaultInjector::new(FaultConfig {
+            duplicate_probability: 1.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let (_, messages) = duplicator.process(quote_at(1), UnixNanos::default());
+        assert_eq!(messages, vec![quote_at(1), quote_at(1)]);
+
+        let mut corruptor = FaultInjector::new(FaultConfig {
+            corrupt_probability: 1.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let (_, messages) = corruptor.process(quote_at(1), UnixNanos::default());
+        let Data::Quote(quote) = messages[0] else {
+            panic!("Expected quote");
+        };
+        assert!(quote.bid_price > quote.ask_price);
+        assert_eq!(corruptor.stats().corrupted, 1);
+    }
+
+    #[rstest]
+    fn test_disconnect_drops_until_reconnected() {
+        let mut injector = FaultInjector::new(FaultConfig {
+            disconnect_probability: 1.0,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let (fault, messages) = injector.process(quote_at(1), UnixNanos::default());
+        assert_eq!(fault, Fault::Disconnect);
+        assert!(messages.is_empty());
+        assert!(injector.is_disconnected());
+
+        let (fault, messages) = injector.process(quote_at(2), UnixNanos::default());
+        assert_eq!(fault, Fault::Drop);
+        assert!(messages.is_empty());
+
+        injector.reconnect();
+        assert!(!injector.is_disconnected());
+        let stats = injector.stats();
+        assert_eq!(
+            (stats.messages, stats.dropped, stats.disconnects),
+            (1, 1, 1)
+        );
+    }
+
+    #[rstest]
+    fn test_corrupt_fill_flips_side() {
+        let mut event = OrderEventAny::Filled(OrderFilled {
+            order_side: OrderSide::Buy,
+            ..Default::default()
+        });
+
+        event.corrupt();
+
+        let OrderEventAny::Filled(corrupted) = event else {
+            panic!("Expected fill");
+        };
+        assert_eq!(corrupted.order_side, OrderSide::Sell);
+    }
+}
@@ -16,9 +16,11 @@
 //! Real-time and static test `Clock` implementations.
 
 use std::{
+    cell::RefCell,
     collections::{BTreeMap, BinaryHeap, HashMap},
     ops::Deref,
     pin::Pin,
+    rc::Rc,
     sync::Arc,
     task::{Context, Poll},
 };
@@ -212,6 +214,20 @@ impl TestClock {
             })
             .collect()
     }
+
+    /// Advances the shared `clock` to `to_time_ns` and runs the handlers of the triggered timers.
+    ///
+    /// The clock is not borrowed while the handlers run, so they can set or cancel timers.
+    pub fn advance_time_and_run(clock: &Rc<RefCell<Self>>, to_time_ns: UnixNanos) {
+        let handlers = {
+            let mut clock = clock.borrow_mut();
+            let events = clock.advance_time(to_time_ns, true);
+            clock.match_handlers(events)
+        };
+        for handler in handlers {
+            handler.run();
+        }
+    }
 }
 
 impl Iterator for TestClock {
@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Loading of strongly-typed configurations from TOML or JSON files.
+//!
+//! Before parsing, `${VAR}` references in the file are replaced with the value of the environment
+//! variable `VAR`, or with `default` for `${VAR:-default}` if the variable is not set. After
+//! parsing, the configuration is checked with [`ValidateConfig::validate`].
+
+use std::{env, fmt::Display, fs, path::Path};
+
+use anyhow::{anyhow, bail};
+use serde::de::DeserializeOwned;
+
+/// Represents the file formats configurations can be loaded from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Returns the format for the given `path` from its file extension.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the extension is not `toml` or `json`.
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            Some(ext) => bail!("Unsupported config file extension '.{ext}', use .toml or .json"),
+            None => bail!("Config file has no extension, use .toml or .json"),
+        }
+    }
+}
+
+/// Provides validation of a configuration beyond what is checked during deserialization.
+pub trait ValidateConfig {
+    /// Checks the configuration is consistent.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error describing the first invalid setting found.
+    fn validate(&self) -> anyhow::Result<()>;
+}
+
+/// Loads and validates a configuration from the file at `path`.
+///
+/// # Errors
+///
+/// This function returns an error if the file cannot be read, references an unset environment
+/// variable, cannot be parsed, or fails validation.
+pub fn load_config<T, P>(path: P) -> anyhow::Result<T>
+where
+    T: DeserializeOwned + ValidateConfig,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let format = ConfigFormat::from_path(path)?;
+    let text = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read config file '{}': {e}", path.display()))?;
+    parse_config(&text, format)
+        .map_err(|e| anyhow!("Invalid config file '{}': {e}", path.display()))
+}
+
+/// Parses and validates a configuration from `text` in the given `format`.
+///
+/// # Errors
+///
+/// This function returns an error if `text` references an unset environment variable, cannot be
+/// parsed, or fails validation.
+pub fn parse_config<T>(text: &str, format: ConfigFormat) -> anyhow::Result<T>
+where
+    T: DeserializeOwned + ValidateConfig,
+{
+    let text = interpolate_env(text)?;
+    let config: T = match format {
+        ConfigFormat::Toml => toml::from_str(&text)?,
+        ConfigFormat::Json => serde_json::from_str(&text)?,
+    };
+    config.validate()?;
+    Ok(config)
+}
+
+/// Returns `text` with `${VAR}` and `${VAR:-default}` references replaced from the environment.
+///
+/// # Errors
+///
+/// This function returns an error if a reference is unterminated, or names a variable which is
+/// not set and has no default.
+pub fn interpolate_env(text: &str) -> anyhow::Result<String> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let line = text[..text.len() - rest.len() + start]
+            .matches('\n')
+            .count()
+            + 1;
+        let Some(end) = rest[start..].find('}') else {
+            bail!("Unterminated '${{' on line {line}");
+        };
+
+        let reference = &rest[start + 2..start + end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        match (env::var(name), default) {
+            (Ok(value), _) => result.push_str(&value),
+            (Err(_), Some(default)) => result.push_str(default),
+            (Err(_), None) => bail!(
+                "Environment variable '{name}' referenced on line {line} is not set \
+                 (use '${{{name}:-default}}' to provide a default)"
+            ),
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Checks the `predicate` holds for a configuration setting.
+///
+/// # Errors
+///
+/// This function returns an error naming the `setting` with the `message` if `predicate` is false.
+pub fn check_setting(predicate: bool, setting: &str, message: impl Display) -> anyhow::Result<()> {
+    if !predicate {
+        bail!("Invalid setting '{setting}': {message}");
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct TestConfig {
+        name: String,
+        #[serde(default)]
+        size: u32,
+    }
+
+    impl ValidateConfig for TestConfig {
+        fn validate(&self) -> anyhow::Result<()> {
+            check_setting(self.size > 0, "size", "must be positive")
+        }
+    }
+
+    #[rstest]
+    #[case("config.toml", ConfigFormat::Toml)]
+    #[case("dir/config.json", ConfigFormat::Json)]
+    fn test_format_from_path(#[case] path: &str, #[case] expected: ConfigFormat) {
+        assert_eq!(ConfigFormat::from_path(Path::new(path)).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_format_from_path_unsupported() {
+        let err = ConfigFormat::from_path(Path::new("config.yaml")).unwrap_err();
+        assert!(err.to_string().contains(".yaml"));
+    }
+
+    #[rstest]
+    fn test_interpolate_env() {
+        env::set_var("NAUTILUS_TEST_CONFIG_NAME", "alpha");
+        let text =
+            "name = \"${NAUTILUS_TEST_CONFIG_NAME}\"\nsize = ${NAUTILUS_TEST_CONFIG_UNSET:-3}";
+        assert_eq!(interpolate_env(text).unwrap(), "name = \"alpha\"\nsize = 3");
+    }
+
+    #[rstest]
+    fn test_interpolate_env_unset_reports_line() {
+        let err = interpolate_env("a = 1\n${NAUTILUS_TEST_CONFIG_UNSET}").unwrap_err();
+        assert!(err.to_string().contains("'NAUTILUS_TEST_CONFIG_UNSET'"));
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[rstest]
+    fn test_interpolate_env_unterminated() {
+        assert!(interpolate_env("a = \"${NAME\"").is_err());
+    }
+
+    #[rstest]
+    fn test_parse_config_toml_and_json() {
+        let toml: TestConfig = parse_config("name = \"a\"\nsize = 2", ConfigFormat::Toml).unwrap();
+        let json: TestConfig =
+            parse_config(r#"{"name": "a", "size": 2}"#, ConfigFormat::Json).unwrap();
+        assert_eq!((toml.name.as_str(), toml.size), ("a", 2));
+        assert_eq!((json.name.as_str(), json.size), ("a", 2));
+    }
+
+    #[rstest]
+    fn test_parse_config_validation_error() {
+        let err = parse_config::<TestConfig>("name = \"a\"", ConfigFormat::Toml).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid setting 'size': must be positive");
+    }
+
+    #[rstest]
+    fn test_load_config_reports_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.toml");
+        fs::write(&path, "name = 1").unwrap();
+
+        let err = load_config::<TestConfig, _>(&path).unwrap_err();
+        assert!(err.to_string().contains("node.toml"));
+        assert!(err.to_string().contains("name"));
+    }
+}
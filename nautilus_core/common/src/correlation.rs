@@ -0,0 +1,367 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Rolling covariance and correlation matrices of instrument returns.
+//!
+//! A [`CorrelationService`] computes the returns of the bar closes for each subscribed
+//! instrument, and aligns them by bar timestamp into rows of returns. The sums and cross products
+//! of the rows in the rolling window are updated incrementally as rows are added and removed,
+//! so the matrices can be queried cheaply by strategies and the risk module on every bar.
+
+use std::collections::{HashMap, VecDeque};
+
+use indexmap::IndexMap;
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    nanos::UnixNanos,
+};
+use nautilus_model::{data::bar::Bar, identifiers::InstrumentId};
+
+/// Maintains rolling covariance and correlation matrices of returns across instruments.
+#[derive(Clone, Debug)]
+pub struct CorrelationService {
+    window: usize,
+    instruments: IndexMap<InstrumentId, usize>,
+    last_closes: HashMap<InstrumentId, f64>,
+    pending_ts: Option<UnixNanos>,
+    pending: HashMap<usize, f64>,
+    rows: VecDeque<Vec<f64>>,
+    sums: Vec<f64>,
+    cross_sums: Vec<Vec<f64>>,
+}
+
+impl CorrelationService {
+    /// Creates a new [`CorrelationService`] instance with a rolling `window` of return rows.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `window` is less than 2.
+    #[must_use]
+    pub fn new(window: usize) -> Self {
+        check_predicate_true(window >= 2, "`window` was less than 2").expect(FAILED);
+        Self {
+            window,
+            instruments: IndexMap::new(),
+            last_closes: HashMap::new(),
+            pending_ts: None,
+            pending: HashMap::new(),
+            rows: VecDeque::with_capacity(window),
+            sums: Vec::new(),
+            cross_sums: Vec::new(),
+        }
+    }
+
+    /// Returns the rolling window size.
+    #[must_use]
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the subscribed instruments, in matrix order.
+    #[must_use]
+    pub fn instruments(&self) -> Vec<InstrumentId> {
+        self.instruments.keys().copied().collect()
+    }
+
+    /// Returns the number of return rows in the window.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns whether the rolling window is full.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.rows.len() == self.window
+    }
+
+    /// Subscribes the given `instrument_id`, adding it to the matrices.
+    ///
+    /// As the dimensions of the matrices change, the rolling window is restarted.
+    pub fn subscribe(&mut self, instrument_id: InstrumentId) {
+        if self.instruments.contains_key(&instrument_id) {
+            return;
+        }
+        self.instruments
+            .insert(instrument_id, self.instruments.len());
+        self.reset_window();
+    }
+
+    /// Unsubscribes the given `instrument_id`, removing it from the matrices.
+    ///
+    /// As the dimensions of the matrices change, the rolling window is restarted.
+    pub fn unsubscribe(&mut self, instrument_id: &InstrumentId) {
+        if self.instruments.shift_remove(instrument_id).is_none() {
+            return;
+        }
+        for (index, value) in self.instruments.values_mut().enumerate() {
+            *value = index;
+        }
+        self.last_closes.remove(instrument_id);
+        self.reset_window();
+    }
+
+    /// Handles the given `bar`, updating the matrices once every subscribed instrument has a
+    /// return for the bar timestamp.
+    ///
+    /// Rows with missing returns are discarded when a bar for a later timestamp is received.
+    pub fn handle_bar(&mut self, bar: &Bar) {
+        let instrument_id = bar.instrument_id();
+        let Some(index) = self.instruments.get(&instrument_id).copied() else {
+            return;
+        };
+
+        let close = bar.close.as_f64();
+        let Some(last_close) = self.last_closes.insert(instrument_id, close) else {
+            return;
+        };
+        if last_close == 0.0 {
+            return;
+        }
+
+        match self.pending_ts {
+            Some(ts) if bar.ts_event < ts => return, // Stale bar
+            Some(ts) if bar.ts_event > ts => self.pending.clear(),
+            _ => {}
+        }
+        self.pending_ts = Some(bar.ts_event);
+        self.pending.insert(index, close / last_close - 1.0);
+
+        if self.pending.len() == self.instruments.len() {
+            let row = (0..self.instruments.len())
+                .map(|i| self.pending[&i])
+                .collect();
+            self.pending.clear();
+            self.add_row(row);
+        }
+    }
+
+    /// Returns the sample covariance of the returns of the two instruments (if available).
+    #[must_use]
+    pub fn covariance(&self, a: &InstrumentId, b: &InstrumentId) -> Option<f64> {
+        let i = *self.instruments.get(a)?;
+        let j = *self.instruments.get(b)?;
+        self.covariance_at(i, j)
+    }
+
+    /// Returns the correlation of the returns of the two instruments (if available).
+    ///
+    /// Returns `None` if either instrument's returns have no variance.
+    #[must_use]
+    pub fn correlation(&self, a: &InstrumentId, b: &InstrumentId) -> Option<f64> {
+        let i = *self.instruments.get(a)?;
+        let j = *self.instruments.get(b)?;
+        self.correlation_at(i, j)
+    }
+
+    /// Returns the sample covariance matrix of returns in instrument order (if available).
+    #[must_use]
+    pub fn covariance_matrix(&self) -> Option<Vec<Vec<f64>>> {
+        let n = self.instruments.len();
+        (0..n)
+            .map(|i| (0..n).map(|j| self.covariance_at(i, j)).collect())
+            .collect()
+    }
+
+    /// Returns the correlation matrix of returns in instrument order (if available).
+    #[must_use]
+    pub fn correlation_matrix(&self) -> Option<Vec<Vec<f64>>> {
+        let n = self.instruments.len();
+        (0..n)
+            .map(|i| (0..n).map(|j| self.correlation_at(i, j)).collect())
+            .collect()
+    }
+
+    /// Returns the volatility of the returns of a portfolio with the given `weights` per
+    /// instrument, where instruments without a weight are not held (if available).
+    #[must_use]
+    pub fn portfolio_volatility(&self, weights: &HashMap<InstrumentId, f64>) -> Option<f64> {
+        let mut variance = 0.0;
+        for (a, weight_a) in weights {
+            for (b, weight_b) in weights {
+                variance += weight_a * weight_b * self.covariance(a, b)?;
+            }
+        }
+        Some(variance.max(0.0).sqrt())
+    }
+
+    /// Resets the service to its initial state, keeping the subscriptions.
+    pub fn reset(&mut self) {
+        self.last_closes.clear();
+        self.reset_window();
+    }
+
+    fn reset_window(&mut self) {
+        let n = self.instruments.len();
+        self.pending_ts = None;
+        self.pending.clear();
+        self.rows.clear();
+        self.sums = vec![0.0; n];
+        self.cross_sums = vec![vec![0.0; n]; n];
+    }
+
+    fn add_row(&mut self, row: Vec<f64>) {
+        if self.rows.len() == self.window {
+            if let Some(removed) = self.rows.pop_front() {
+                self.update_sums(&removed, -1.0);
+            }
+        }
+        self.update_sums(&row, 1.0);
+        self.rows.push_back(row);
+    }
+
+    fn update_sums(&mut self, row: &[f64], sign: f64) {
+        for (i, x) in row.iter().enumerate() {
+            self.sums[i] += sign * x;
+            for (j, y) in row.iter().enumerate() {
+                self.cross_sums[i][j] += sign * x * y;
+            }
+        }
+    }
+
+    fn covariance_at(&self, i: usize, j: usize) -> Option<f64> {
+        let n = self.rows.len();
+        if n < 2 {
+            return None;
+        }
+        let n = n as f64;
+        Some((self.cross_sums[i][j] - self.sums[i] * self.sums[j] / n) / (n - 1.0))
+    }
+
+    fn correlation_at(&self, i: usize, j: usize) -> Option<f64> {
+        let denominator = (self.covariance_at(i, i)? * self.covariance_at(j, j)?).sqrt();
+        if denominator <= f64::EPSILON {
+            return None;
+        }
+        Some((self.covariance_at(i, j)? / denominator).clamp(-1.0, 1.0))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::bar::BarType,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn bar(instrument: &str, close: &str, ts: u64) -> Bar {
+        let bar_type = BarType::from(format!("{instrument}-1-MINUTE-LAST-EXTERNAL").as_str());
+        let close = Price::from(close);
+        Bar::new(
+            bar_type,
+            close,
+            close,
+            close,
+            close,
+            Quantity::from(1),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn service(window: usize) -> CorrelationService {
+        let mut service = CorrelationService::new(window);
+        service.subscribe(InstrumentId::from("AAA.XNAS"));
+        service.subscribe(InstrumentId::from("BBB.XNAS"));
+        let closes = [
+            ("100.00", "50.000"),
+            ("110.00", "45.000"),
+            ("99.00", "49.500"),
+            ("108.90", "44.550"),
+        ];
+        for (ts, (a, b)) in closes.into_iter().enumerate() {
+            service.handle_bar(&bar("AAA.XNAS", a, ts as u64));
+            service.handle_bar(&bar("BBB.XNAS", b, ts as u64));
+        }
+        service
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`window` was less than 2")]
+    fn test_new_with_window_too_small() {
+        let _ = CorrelationService::new(1);
+    }
+
+    #[rstest]
+    fn test_covariance_and_correlation() {
+        let service = service(10);
+        let a = InstrumentId::from("AAA.XNAS");
+        let b = InstrumentId::from("BBB.XNAS");
+
+        // Returns of +10%, -10%, +10% against -10%, +10%, -10%
+        assert_eq!(service.count(), 3);
+        assert!(!service.is_initialized());
+        assert!((service.covariance(&a, &a).unwrap() - 0.04 / 3.0).abs() < 1e-12);
+        assert!((service.covariance(&a, &b).unwrap() + 0.04 / 3.0).abs() < 1e-12);
+        assert!((service.correlation(&a, &b).unwrap() + 1.0).abs() < 1e-12);
+
+        let matrix = service.correlation_matrix().unwrap();
+        assert!((matrix[0][0] - 1.0).abs() < 1e-12);
+        assert!((matrix[1][0] + 1.0).abs() < 1e-12);
+        assert_eq!(service.instruments(), vec![a, b]);
+    }
+
+    #[rstest]
+    fn test_rolling_window_drops_oldest_row() {
+        let service = service(2);
+        let a = InstrumentId::from("AAA.XNAS");
+
+        // Only the last two returns of -10% and +10% remain
+        assert!(service.is_initialized());
+        assert!((service.covariance(&a, &a).unwrap() - 0.02).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_incomplete_rows_are_discarded() {
+        let mut service = CorrelationService::new(10);
+        service.subscribe(InstrumentId::from("AAA.XNAS"));
+        service.subscribe(InstrumentId::from("BBB.XNAS"));
+
+        service.handle_bar(&bar("AAA.XNAS", "100.00", 0));
+        service.handle_bar(&bar("BBB.XNAS", "50.000", 0));
+        service.handle_bar(&bar("AAA.XNAS", "110.00", 1));
+        service.handle_bar(&bar("AAA.XNAS", "99.00", 2));
+        service.handle_bar(&bar("BBB.XNAS", "45.000", 2));
+
+        assert_eq!(service.count(), 1);
+        assert!(service.covariance_matrix().is_none());
+    }
+
+    #[rstest]
+    fn test_portfolio_volatility_of_hedged_portfolio() {
+        let service = service(10);
+        let weights = HashMap::from([
+            (InstrumentId::from("AAA.XNAS"), 1.0),
+            (InstrumentId::from("BBB.XNAS"), 1.0),
+        ]);
+
+        assert!(service.portfolio_volatility(&weights).unwrap() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_subscribe_restarts_window() {
+        let mut service = service(10);
+        service.subscribe(InstrumentId::from("CCC.XNAS"));
+
+        assert_eq!(service.count(), 0);
+        assert!(service.correlation_matrix().is_none());
+    }
+}
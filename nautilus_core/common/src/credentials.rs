@@ -0,0 +1,295 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Secrets management for adapter API credentials.
+//!
+//! Configurations reference where a secret is stored with a [`CredentialSource`], rather than
+//! holding the secret inline, e.g. in TOML:
+//!
+//! ```toml
+//! [credentials]
+//! api_key = { env = "BINANCE_API_KEY" }
+//! api_secret = { file = "/run/secrets/binance_api_secret" }
+//! # or from the OS keychain
+//! # api_secret = { keychain = { service = "nautilus", account = "binance" } }
+//! ```
+//!
+//! Resolved values are held in a [`Secret`], which is redacted from `Debug` and `Display`
+//! output and zeroed from memory on drop.
+
+use std::{env, fmt, fs, path::PathBuf};
+
+use anyhow::{anyhow, bail};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// The text shown in place of a secret value.
+pub const REDACTED: &str = "<redacted>";
+
+/// Represents a secret value such as an API key, which is never displayed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(Zeroizing<String>);
+
+impl Secret {
+    /// Creates a new [`Secret`] instance.
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(Zeroizing::new(value.into()))
+    }
+
+    /// Returns the secret value, which must not be logged.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret({REDACTED})")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Represents where a secret is stored.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialSource {
+    /// The value of an environment variable.
+    Env(String),
+    /// The contents of a file, without trailing whitespace.
+    File(PathBuf),
+    /// An item in the OS keychain (macOS Keychain, or the Secret Service on Linux).
+    Keychain { service: String, account: String },
+}
+
+impl CredentialSource {
+    /// Reads the secret from its source.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the secret cannot be read or is empty.
+    pub fn resolve(&self) -> anyhow::Result<Secret> {
+        let value = match self {
+            Self::Env(name) => {
+                env::var(name).map_err(|_| anyhow!("Environment variable '{name}' is not set"))?
+            }
+            Self::File(path) => {
+                let mut value = fs::read_to_string(path)
+                    .map_err(|e| anyhow!("Failed to read secret file '{}': {e}", path.display()))?;
+                value.truncate(value.trim_end().len());
+                value
+            }
+            Self::Keychain { service, account } => read_keychain(service, account)?,
+        };
+
+        let secret = Secret::new(value);
+        if secret.expose().is_empty() {
+            bail!("Secret from {self} was empty");
+        }
+        Ok(secret)
+    }
+}
+
+impl fmt::Display for CredentialSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "env:{name}"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Keychain { service, account } => write!(f, "keychain:{service}/{account}"),
+        }
+    }
+}
+
+/// Provides the API credentials for an adapter.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Credentials {
+    /// The source of the API key.
+    pub api_key: CredentialSource,
+    /// The source of the API secret, for venues which sign requests.
+    #[serde(default)]
+    pub api_secret: Option<CredentialSource>,
+}
+
+impl Credentials {
+    /// Creates a new [`Credentials`] instance.
+    #[must_use]
+    pub const fn new(api_key: CredentialSource, api_secret: Option<CredentialSource>) -> Self {
+        Self {
+            api_key,
+            api_secret,
+        }
+    }
+
+    /// Reads the API key from its source.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the key cannot be read.
+    pub fn api_key(&self) -> anyhow::Result<Secret> {
+        self.api_key.resolve()
+    }
+
+    /// Reads the API secret from its source.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no API secret is configured, or it cannot be read.
+    pub fn api_secret(&self) -> anyhow::Result<Secret> {
+        self.api_secret
+            .as_ref()
+            .ok_or_else(|| anyhow!("No API secret configured"))?
+            .resolve()
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn read_keychain(service: &str, account: &str) -> anyhow::Result<String> {
+    run_keychain_command(
+        "security",
+        &["find-generic-password", "-s", service, "-a", account, "-w"],
+    )
+}
+
+#[cfg(target_os = "linux")]
+fn read_keychain(service: &str, account: &str) -> anyhow::Result<String> {
+    run_keychain_command(
+        "secret-tool",
+        &["lookup", "service", service, "account", account],
+    )
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn read_keychain(service: &str, account: &str) -> anyhow::Result<String> {
+    bail!("OS keychain is not supported on this platform for {service}/{account}")
+}
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn run_keychain_command(program: &str, args: &[&str]) -> anyhow::Result<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| anyhow!("Failed to run '{program}' to read the OS keychain: {e}"))?;
+    if !output.status.success() {
+        bail!(
+            "'{program}' could not find the keychain item: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let mut value = String::from_utf8(output.stdout)?;
+    value.truncate(value.trim_end().len());
+    Ok(value)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_secret_is_redacted() {
+        let secret = Secret::new("hunter2");
+        assert_eq!(format!("{secret}"), REDACTED);
+        assert_eq!(format!("{secret:?}"), "Secret(<redacted>)");
+        assert_eq!(secret.expose(), "hunter2");
+    }
+
+    #[rstest]
+    fn test_resolve_env() {
+        env::set_var("NAUTILUS_TEST_CREDENTIALS_KEY", "abc123");
+        let source = CredentialSource::Env("NAUTILUS_TEST_CREDENTIALS_KEY".to_string());
+        assert_eq!(source.resolve().unwrap().expose(), "abc123");
+    }
+
+    #[rstest]
+    fn test_resolve_env_not_set() {
+        let source = CredentialSource::Env("NAUTILUS_TEST_CREDENTIALS_UNSET".to_string());
+        assert_eq!(
+            source.resolve().unwrap_err().to_string(),
+            "Environment variable 'NAUTILUS_TEST_CREDENTIALS_UNSET' is not set"
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_file_trims_trailing_newline() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("api_secret");
+        fs::write(&path, "s3cr3t\n").unwrap();
+
+        let source = CredentialSource::File(path);
+        assert_eq!(source.resolve().unwrap().expose(), "s3cr3t");
+    }
+
+    #[rstest]
+    fn test_resolve_empty_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("api_key");
+        fs::write(&path, "\n").unwrap();
+
+        let err = CredentialSource::File(path.clone()).resolve().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("Secret from file:{} was empty", path.display())
+        );
+    }
+
+    #[rstest]
+    fn test_credentials_deserialize() {
+        let credentials: Credentials = serde_json::from_str(
+            r#"{
+                "api_key": {"env": "VENUE_API_KEY"},
+                "api_secret": {"keychain": {"service": "nautilus", "account": "venue"}}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            credentials,
+            Credentials::new(
+                CredentialSource::Env("VENUE_API_KEY".to_string()),
+                Some(CredentialSource::Keychain {
+                    service: "nautilus".to_string(),
+                    account: "venue".to_string(),
+                }),
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_credentials_without_api_secret() {
+        let credentials =
+            Credentials::new(CredentialSource::Env("VENUE_API_KEY".to_string()), None);
+        assert_eq!(
+            credentials.api_secret().unwrap_err().to_string(),
+            "No API secret configured"
+        );
+    }
+}
@@ -322,3 +322,39 @@ pub enum SerializationEncoding {
     #[serde(rename = "json")]
     Json = 1,
 }
+
+/// The period after which a new log file is started.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromRepr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.common.enums")
+)]
+pub enum LogRotation {
+    /// A new log file is started every minute.
+    Minutely = 0,
+    /// A new log file is started every hour.
+    Hourly = 1,
+    /// A new log file is started every day.
+    Daily = 2,
+    /// A single log file is written.
+    Never = 3,
+}
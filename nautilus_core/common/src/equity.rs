@@ -0,0 +1,400 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Periodic snapshots of portfolio equity for monitoring live performance.
+//!
+//! An [`EquitySnapshotter`] values the total account balances plus the unrealized PnL of open
+//! positions in a base currency on a timer, and publishes each snapshot as an [`EquityUpdate`]
+//! on the equity topic, where it can be monitored and recorded to a catalog. The equity can also
+//! be broken down by account, for venues with multiple accounts (sub-accounts).
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::GetTsInit,
+    enums::PriceType,
+    identifiers::{AccountId, Venue},
+    types::{currency::Currency, money::Money},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// The name of the timer which triggers equity snapshots.
+pub const EQUITY_SNAPSHOT_TIMER: &str = "EquitySnapshotter";
+
+/// Represents a snapshot of portfolio equity in a base currency.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EquityUpdate {
+    /// The total account balances plus the unrealized PnL of open positions.
+    pub equity: Money,
+    /// The total account balances.
+    pub balance: Money,
+    /// The unrealized PnL of open positions.
+    pub unrealized_pnl: Money,
+    /// The return of the equity since the previous snapshot (zero for the first snapshot).
+    pub period_return: f64,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl GetTsInit for EquityUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Represents the equity of a single account in a base currency.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccountEquity {
+    pub account_id: AccountId,
+    /// The total account balances plus the unrealized PnL of open positions for the account.
+    pub equity: Money,
+    /// The total account balances.
+    pub balance: Money,
+    /// The unrealized PnL of open positions for the account.
+    pub unrealized_pnl: Money,
+}
+
+/// Snapshots portfolio equity in a base currency and publishes [`EquityUpdate`]s.
+pub struct EquitySnapshotter {
+    base_currency: Currency,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    last_equity: Cell<Option<f64>>,
+}
+
+impl EquitySnapshotter {
+    /// Creates a new [`EquitySnapshotter`] instance.
+    #[must_use]
+    pub const fn new(
+        base_currency: Currency,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            base_currency,
+            cache,
+            msgbus,
+            last_equity: Cell::new(None),
+        }
+    }
+
+    /// Returns the base currency equity is valued in.
+    #[must_use]
+    pub const fn base_currency(&self) -> Currency {
+        self.base_currency
+    }
+
+    /// Starts snapshotting equity every `interval_ns` on the given `clock`.
+    pub fn start(self: &Rc<Self>, clock: &mut dyn Clock, interval_ns: u64) {
+        let snapshotter = self.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            if let Err(e) = snapshotter.snapshot(event.ts_event) {
+                log::error!("Error snapshotting equity: {e}");
+            }
+        }));
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            EQUITY_SNAPSHOT_TIMER,
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        );
+        log::info!(
+            "Snapshotting equity in {} every {interval_ns}ns",
+            self.base_currency
+        );
+    }
+
+    /// Stops snapshotting equity on the given `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(EQUITY_SNAPSHOT_TIMER);
+    }
+
+    /// Snapshots the equity at `ts_event` and publishes the update.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an amount cannot be converted to the base currency, or
+    /// there is no price to value an open position.
+    pub fn snapshot(&self, ts_event: UnixNanos) -> anyhow::Result<EquityUpdate> {
+        let (balance, unrealized_pnl) = self.calculate(&self.cache.borrow(), None)?;
+        let equity = balance + unrealized_pnl;
+        let period_return = match self.last_equity.get() {
+            Some(last) if last != 0.0 => equity / last - 1.0,
+            _ => 0.0,
+        };
+        self.last_equity.set(Some(equity));
+
+        let update = EquityUpdate {
+            equity: Money::new(equity, self.base_currency),
+            balance: Money::new(balance, self.base_currency),
+            unrealized_pnl: Money::new(unrealized_pnl, self.base_currency),
+            period_return,
+            ts_event,
+            ts_init: ts_event,
+        };
+        let msgbus = self.msgbus.borrow();
+        msgbus.publish(&msgbus.switchboard.equity_topic, &update as &dyn Any);
+        Ok(update)
+    }
+
+    /// Returns the equity of each account, sorted by account ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an amount cannot be converted to the base currency, or
+    /// there is no price to value an open position.
+    pub fn breakdown(&self) -> anyhow::Result<Vec<AccountEquity>> {
+        let cache = self.cache.borrow();
+        let mut account_ids: Vec<AccountId> = cache.account_ids().into_iter().copied().collect();
+        account_ids.sort();
+
+        account_ids
+            .into_iter()
+            .map(|account_id| {
+                let (balance, unrealized_pnl) = self.calculate(&cache, Some(&account_id))?;
+                Ok(AccountEquity {
+                    account_id,
+                    equity: Money::new(balance + unrealized_pnl, self.base_currency),
+                    balance: Money::new(balance, self.base_currency),
+                    unrealized_pnl: Money::new(unrealized_pnl, self.base_currency),
+                })
+            })
+            .collect()
+    }
+
+    // Returns the total balances and unrealized PnL in the base currency, of all accounts or the
+    // given account only
+    fn calculate(
+        &self,
+        cache: &Cache,
+        account_id: Option<&AccountId>,
+    ) -> anyhow::Result<(f64, f64)> {
+        let mut balance = 0.0;
+        for account_id in cache
+            .account_ids()
+            .into_iter()
+            .filter(|id| account_id.is_none_or(|account_id| *id == account_id))
+        {
+            let Some(account) = cache.account(account_id) else {
+                continue;
+            };
+            let venue = account_id.get_issuer();
+            for money in account.balances_total().values() {
+                balance += self.convert(cache, &venue, *money)?;
+            }
+        }
+
+        let mut unrealized_pnl = 0.0;
+        for position in cache
+            .positions_open(None, None, None, None)
+            .into_iter()
+            .filter(|position| account_id.is_none_or(|id| position.account_id == *id))
+        {
+            let instrument_id = position.instrument_id;
+            let Some(last) = cache
+                .price(&instrument_id, PriceType::Last)
+                .or_else(|| cache.price(&instrument_id, PriceType::Mid))
+            else {
+                anyhow::bail!(
+                    "No price to value position {} for {instrument_id}",
+                    position.id
+                );
+            };
+            let pnl = position.unrealized_pnl(last);
+            unrealized_pnl += self.convert(cache, &instrument_id.venue, pnl)?;
+        }
+
+        Ok((balance, unrealized_pnl))
+    }
+
+    fn convert(&self, cache: &Cache, venue: &Venue, money: Money) -> anyhow::Result<f64> {
+        let Some(xrate) =
+            cache.get_xrate(venue, money.currency, self.base_currency, PriceType::Mid)
+        else {
+            anyhow::bail!(
+                "No exchange rate from {} to {} on {venue}",
+                money.currency,
+                self.base_currency
+            );
+        };
+        Ok(money.as_f64() * xrate)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        accounts::{
+            any::AccountAny, base::Account, cash::CashAccount, stubs::cash_account_million_usd,
+        },
+        data::quote::QuoteTick,
+        enums::{OmsType, OrderSide},
+        events::order::filled::OrderFilled,
+        identifiers::PositionId,
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        position::Position,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+
+    fn cache_with_position(
+        account: CashAccount,
+        audusd_sim: CurrencyPair,
+        price: &str,
+    ) -> Rc<RefCell<Cache>> {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = Cache::default();
+        cache.add_account(AccountAny::Cash(account)).unwrap();
+        cache.add_instrument(instrument.clone()).unwrap();
+        let fill = OrderFilled {
+            instrument_id: audusd_sim.id,
+            order_side: OrderSide::Buy,
+            last_qty: Quantity::from(100_000),
+            last_px: Price::from("1.00000"),
+            position_id: Some(PositionId::new("P-1")),
+            ..Default::default()
+        };
+        cache
+            .add_position(Position::new(&instrument, fill), OmsType::Netting)
+            .unwrap();
+        cache
+            .add_quote(QuoteTick {
+                instrument_id: audusd_sim.id,
+                bid_price: Price::from(price),
+                ask_price: Price::from(price),
+                ..Default::default()
+            })
+            .unwrap();
+        Rc::new(RefCell::new(cache))
+    }
+
+    #[rstest]
+    fn test_snapshot_publishes_equity(
+        cash_account_million_usd: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let cache = cache_with_position(cash_account_million_usd, audusd_sim, "1.00100");
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<EquityUpdate>(None);
+        let topic = msgbus.borrow().switchboard.equity_topic;
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+        let snapshotter = EquitySnapshotter::new(Currency::USD(), cache.clone(), msgbus);
+
+        let first = snapshotter.snapshot(UnixNanos::from(1)).unwrap();
+        cache
+            .borrow_mut()
+            .add_quote(QuoteTick {
+                instrument_id: audusd_sim.id,
+                bid_price: Price::from("1.00200"),
+                ask_price: Price::from("1.00200"),
+                ..Default::default()
+            })
+            .unwrap();
+        let second = snapshotter.snapshot(UnixNanos::from(2)).unwrap();
+
+        assert_eq!(first.balance, Money::from("1000000 USD"));
+        assert_eq!(first.unrealized_pnl, Money::from("100 USD"));
+        assert_eq!(first.equity, Money::from("1000100 USD"));
+        assert_eq!(first.period_return, 0.0);
+        assert_eq!(second.equity, Money::from("1000200 USD"));
+        assert!((second.period_return - 100.0 / 1_000_100.0).abs() < 1e-12);
+        assert_eq!(
+            get_saved_messages::<EquityUpdate>(handler),
+            vec![first, second]
+        );
+    }
+
+    #[rstest]
+    fn test_breakdown_by_account(cash_account_million_usd: CashAccount, audusd_sim: CurrencyPair) {
+        let mut state = cash_account_million_usd.last_event().unwrap();
+        state.account_id = AccountId::from("SIM-002");
+        let sub_account = AccountAny::Cash(CashAccount::new(state, true));
+        let cache = cache_with_position(cash_account_million_usd, audusd_sim, "1.00100");
+        cache.borrow_mut().add_account(sub_account).unwrap();
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let snapshotter = EquitySnapshotter::new(Currency::USD(), cache, msgbus);
+
+        let breakdown = snapshotter.breakdown().unwrap();
+
+        // The position is held in the default account of the fill
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown[0].account_id, AccountId::from("SIM-001"));
+        assert_eq!(breakdown[0].equity, Money::from("1000100 USD"));
+        assert_eq!(breakdown[1].account_id, AccountId::from("SIM-002"));
+        assert_eq!(breakdown[1].unrealized_pnl, Money::from("0 USD"));
+        assert_eq!(breakdown[1].equity, Money::from("1000000 USD"));
+    }
+
+    #[rstest]
+    fn test_snapshot_without_exchange_rate(
+        cash_account_million_usd: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let cache = cache_with_position(cash_account_million_usd, audusd_sim, "1.00100");
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let snapshotter = EquitySnapshotter::new(Currency::EUR(), cache, msgbus);
+
+        assert!(snapshotter.snapshot(UnixNanos::from(1)).is_err());
+    }
+
+    #[rstest]
+    fn test_start_snapshots_on_timer(
+        cash_account_million_usd: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let cache = cache_with_position(cash_account_million_usd, audusd_sim, "1.00000");
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<EquityUpdate>(None);
+        let topic = msgbus.borrow().switchboard.equity_topic;
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+        let snapshotter = Rc::new(EquitySnapshotter::new(Currency::USD(), cache, msgbus));
+        let mut clock = TestClock::new();
+
+        snapshotter.start(&mut clock, 1_000);
+        let events = clock.advance_time(UnixNanos::from(3_000), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+        snapshotter.stop(&mut clock);
+
+        let updates = get_saved_messages::<EquityUpdate>(handler);
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[2].ts_event, 3_000);
+        assert!(clock.timer_names().is_empty());
+    }
+}
@@ -242,7 +242,7 @@ pub mod tests {
         assert_eq!(market_order.quantity(), 100.into());
         // assert_eq!(market_order.time_in_force(), TimeInForce::Gtc);
         // assert!(!market_order.is_reduce_only);
-        // assert!(!market_order.is_quote_quantity);
+        assert!(!market_order.is_quote_quantity());
         assert_eq!(market_order.exec_algorithm_id(), None);
         // assert_eq!(market_order.exec_algorithm_params(), None);
         // assert_eq!(market_order.exec_spawn_id, None);
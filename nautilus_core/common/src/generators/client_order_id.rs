@@ -13,10 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{cell::RefCell, rc::Rc};
+
+use bytes::Bytes;
 use nautilus_core::time::AtomicTime;
 use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId};
 
 use super::get_datetime_tag;
+use crate::cache::Cache;
 
 #[repr(C)]
 pub struct ClientOrderIdGenerator {
@@ -24,6 +28,7 @@ pub struct ClientOrderIdGenerator {
     trader_id: TraderId,
     strategy_id: StrategyId,
     count: usize,
+    cache: Option<Rc<RefCell<Cache>>>,
 }
 
 impl ClientOrderIdGenerator {
@@ -40,15 +45,53 @@ impl ClientOrderIdGenerator {
             strategy_id,
             count: initial_count,
             clock,
+            cache: None,
         }
     }
 
+    /// Creates a new [`ClientOrderIdGenerator`] instance which persists its sequence to the
+    /// given `cache`, so that IDs remain unique across process restarts.
+    ///
+    /// The sequence continues from the last count persisted for the trader and strategy, which
+    /// must already be loaded into the cache from its database (see `Cache::cache_general`).
+    #[must_use]
+    pub fn new_persistent(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        let key = sequence_key(&trader_id, &strategy_id);
+        let count = cache
+            .borrow()
+            .get(&key)
+            .ok()
+            .flatten()
+            .and_then(|value| std::str::from_utf8(value).ok()?.parse().ok())
+            .unwrap_or(0);
+        Self {
+            trader_id,
+            strategy_id,
+            count,
+            clock,
+            cache: Some(cache),
+        }
+    }
+
+    /// Returns whether the sequence is persisted to a cache.
+    #[must_use]
+    pub const fn is_persistent(&self) -> bool {
+        self.cache.is_some()
+    }
+
     pub fn set_count(&mut self, count: usize) {
         self.count = count;
+        self.persist_count();
     }
 
     pub fn reset(&mut self) {
         self.count = 0;
+        self.persist_count();
     }
 
     #[must_use]
@@ -61,12 +104,29 @@ impl ClientOrderIdGenerator {
         let trader_tag = self.trader_id.get_tag();
         let strategy_tag = self.strategy_id.get_tag();
         self.count += 1;
+        self.persist_count();
         let value = format!(
             "O-{}-{}-{}-{}",
             datetime_tag, trader_tag, strategy_tag, self.count
         );
         ClientOrderId::from(value)
     }
+
+    // The count is persisted before the ID is returned, so an ID is never reissued after a restart
+    fn persist_count(&self) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        let key = sequence_key(&self.trader_id, &self.strategy_id);
+        let value = Bytes::from(self.count.to_string());
+        if let Err(e) = cache.borrow_mut().add(&key, value) {
+            log::error!("Failed to persist client order ID sequence {key}: {e}");
+        }
+    }
+}
+
+fn sequence_key(trader_id: &TraderId, strategy_id: &StrategyId) -> String {
+    format!("client_order_id_sequence:{trader_id}:{strategy_id}")
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -74,11 +134,13 @@ impl ClientOrderIdGenerator {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
     use nautilus_core::time::get_atomic_clock_static;
     use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId};
     use rstest::rstest;
 
-    use crate::generators::client_order_id::ClientOrderIdGenerator;
+    use crate::{cache::Cache, generators::client_order_id::ClientOrderIdGenerator};
 
     fn get_client_order_id_generator(initial_count: Option<usize>) -> ClientOrderIdGenerator {
         ClientOrderIdGenerator::new(
@@ -135,4 +197,43 @@ mod tests {
 
         assert_eq!(result, ClientOrderId::new("O-19700101-000000-001-001-1"));
     }
+
+    #[rstest]
+    fn test_persistent_sequence_continues_after_restart() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut generator = ClientOrderIdGenerator::new_persistent(
+            TraderId::default(),
+            StrategyId::default(),
+            get_atomic_clock_static(),
+            cache.clone(),
+        );
+        generator.generate();
+        generator.generate();
+
+        // A new generator for another strategy has its own sequence
+        let mut other = ClientOrderIdGenerator::new_persistent(
+            TraderId::default(),
+            StrategyId::from("S-002"),
+            get_atomic_clock_static(),
+            cache.clone(),
+        );
+        assert_eq!(
+            other.generate(),
+            ClientOrderId::new("O-19700101-000000-001-002-1")
+        );
+
+        // Simulate a restart with the persisted sequence
+        let mut restarted = ClientOrderIdGenerator::new_persistent(
+            TraderId::default(),
+            StrategyId::default(),
+            get_atomic_clock_static(),
+            cache,
+        );
+        assert!(restarted.is_persistent());
+        assert_eq!(restarted.count(), 2);
+        assert_eq!(
+            restarted.generate(),
+            ClientOrderId::new("O-19700101-000000-001-001-3")
+        );
+    }
 }
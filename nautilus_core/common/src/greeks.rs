@@ -0,0 +1,456 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Aggregation of option position greeks per underlying for hedging.
+//!
+//! A [`GreeksAggregator`] implies the volatility of each open option position from its price,
+//! calculates the Black-Scholes greeks, and sums them per underlying along with the delta of any
+//! positions in the underlying instruments. Each aggregate is published as a [`GreeksUpdate`] on
+//! the greeks topic, where it can be consumed by hedging strategies and exec algorithms.
+
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+use nautilus_model::{
+    data::{greeks::imply_vol_and_greeks, GetTsInit},
+    enums::{OptionKind, PriceType},
+    identifiers::InstrumentId,
+    instruments::any::InstrumentAny,
+    position::Position,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// The name of the timer which triggers greeks updates.
+pub const GREEKS_UPDATE_TIMER: &str = "GreeksAggregator";
+
+const SECONDS_IN_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
+
+/// Represents the aggregate greeks of the open positions for an underlying.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GreeksUpdate {
+    pub underlying: Ustr,
+    /// The change in value per unit change in the underlying price, in underlying units.
+    pub delta: f64,
+    pub gamma: f64,
+    /// The change in value for a one vol point change in implied volatility.
+    pub vega: f64,
+    /// The change in value per calendar day.
+    pub theta: f64,
+    /// The delta in units of the beta reference instrument (if configured).
+    pub beta_weighted_delta: Option<f64>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl GetTsInit for GreeksUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Configuration for `GreeksAggregator` instances.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GreeksAggregatorConfig {
+    /// The continuously compounded risk-free interest rate, also used as the cost of carry.
+    pub interest_rate: f64,
+    /// The instrument providing the price of each underlying, where positions in these
+    /// instruments are aggregated as delta one.
+    pub underlyings: HashMap<Ustr, InstrumentId>,
+    /// The beta of each underlying to the beta reference instrument (1.0 if not specified).
+    pub betas: HashMap<Ustr, f64>,
+    /// The instrument the deltas are beta weighted to (no beta weighting if None).
+    pub beta_reference: Option<InstrumentId>,
+}
+
+/// Aggregates the greeks of open positions per underlying and publishes [`GreeksUpdate`]s.
+pub struct GreeksAggregator {
+    config: GreeksAggregatorConfig,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl GreeksAggregator {
+    /// Creates a new [`GreeksAggregator`] instance.
+    #[must_use]
+    pub const fn new(
+        config: GreeksAggregatorConfig,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            config,
+            cache,
+            msgbus,
+        }
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &GreeksAggregatorConfig {
+        &self.config
+    }
+
+    /// Starts publishing greeks updates every `interval_ns` on the given `clock`.
+    pub fn start(self: &Rc<Self>, clock: &mut dyn Clock, interval_ns: u64) {
+        let aggregator = self.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            if let Err(e) = aggregator.publish(event.ts_event) {
+                log::error!("Error aggregating greeks: {e}");
+            }
+        }));
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            GREEKS_UPDATE_TIMER,
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        );
+        log::info!("Aggregating greeks every {interval_ns}ns");
+    }
+
+    /// Stops publishing greeks updates on the given `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(GREEKS_UPDATE_TIMER);
+    }
+
+    /// Aggregates the greeks at `ts_event` and publishes an update for each underlying.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the greeks could not be aggregated.
+    pub fn publish(&self, ts_event: UnixNanos) -> anyhow::Result<Vec<GreeksUpdate>> {
+        let updates = self.aggregate(ts_event)?;
+        let msgbus = self.msgbus.borrow();
+        for update in &updates {
+            msgbus.publish(&msgbus.switchboard.greeks_topic, update as &dyn Any);
+        }
+        Ok(updates)
+    }
+
+    /// Returns the aggregate greeks of the open positions at `ts_event` per underlying, sorted by
+    /// underlying.
+    ///
+    /// Positions in instruments which are neither options nor configured underlyings are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there is no price for an option or its underlying.
+    pub fn aggregate(&self, ts_event: UnixNanos) -> anyhow::Result<Vec<GreeksUpdate>> {
+        let cache = self.cache.borrow();
+        let mut aggregates: HashMap<Ustr, GreeksUpdate> = HashMap::new();
+
+        for position in cache.positions_open(None, None, None, None) {
+            let Some((underlying, greeks)) = self.position_greeks(&cache, position, ts_event)?
+            else {
+                continue;
+            };
+            let aggregate = aggregates
+                .entry(underlying)
+                .or_insert_with(|| GreeksUpdate {
+                    underlying,
+                    delta: 0.0,
+                    gamma: 0.0,
+                    vega: 0.0,
+                    theta: 0.0,
+                    beta_weighted_delta: None,
+                    ts_event,
+                    ts_init: ts_event,
+                });
+            aggregate.delta += greeks.delta;
+            aggregate.gamma += greeks.gamma;
+            aggregate.vega += greeks.vega;
+            aggregate.theta += greeks.theta;
+        }
+
+        let mut updates: Vec<GreeksUpdate> = aggregates.into_values().collect();
+        for update in &mut updates {
+            update.beta_weighted_delta = self.beta_weighted_delta(&cache, update)?;
+        }
+        updates.sort_by_key(|update| update.underlying);
+        Ok(updates)
+    }
+
+    // Returns the underlying and greeks of the position, or None if not aggregated
+    fn position_greeks(
+        &self,
+        cache: &Cache,
+        position: &Position,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<Option<(Ustr, PositionGreeks)>> {
+        let option = match cache.instrument(&position.instrument_id) {
+            Some(InstrumentAny::OptionsContract(option)) => option,
+            _ => {
+                let underlying = self
+                    .config
+                    .underlyings
+                    .iter()
+                    .find(|(_, id)| **id == position.instrument_id)
+                    .map(|(underlying, _)| *underlying);
+                return Ok(underlying.map(|underlying| {
+                    let delta = position.signed_qty * position.multiplier.as_f64();
+                    (underlying, PositionGreeks::delta_one(delta))
+                }));
+            }
+        };
+
+        if option.expiration_ns <= ts_event {
+            log::warn!("Ignoring expired option position {}", position.id);
+            return Ok(None);
+        }
+        let Some(underlying_id) = self.config.underlyings.get(&option.underlying) else {
+            anyhow::bail!(
+                "No instrument configured for underlying {}",
+                option.underlying
+            );
+        };
+        let s = price(cache, underlying_id)?;
+        let option_price = price(cache, &option.id)?;
+        let t = (option.expiration_ns.as_u64() - ts_event.as_u64()) as f64
+            / NANOSECONDS_IN_SECOND as f64
+            / SECONDS_IN_YEAR;
+        let r = self.config.interest_rate;
+
+        let greeks = imply_vol_and_greeks(
+            s,
+            r,
+            r,
+            option.option_kind == OptionKind::Call,
+            option.strike_price.as_f64(),
+            t,
+            option_price,
+            option.multiplier.as_f64(),
+        );
+        let qty = position.signed_qty;
+        Ok(Some((
+            option.underlying,
+            PositionGreeks {
+                delta: greeks.delta * qty,
+                gamma: greeks.gamma * qty,
+                vega: greeks.vega * qty,
+                theta: greeks.theta * qty,
+            },
+        )))
+    }
+
+    fn beta_weighted_delta(
+        &self,
+        cache: &Cache,
+        update: &GreeksUpdate,
+    ) -> anyhow::Result<Option<f64>> {
+        let Some(reference_id) = self.config.beta_reference else {
+            return Ok(None);
+        };
+        let Some(underlying_id) = self.config.underlyings.get(&update.underlying) else {
+            return Ok(None);
+        };
+        let beta = self
+            .config
+            .betas
+            .get(&update.underlying)
+            .copied()
+            .unwrap_or(1.0);
+        let s = price(cache, underlying_id)?;
+        let reference = price(cache, &reference_id)?;
+        Ok(Some(update.delta * beta * s / reference))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PositionGreeks {
+    delta: f64,
+    gamma: f64,
+    vega: f64,
+    theta: f64,
+}
+
+impl PositionGreeks {
+    const fn delta_one(delta: f64) -> Self {
+        Self {
+            delta,
+            gamma: 0.0,
+            vega: 0.0,
+            theta: 0.0,
+        }
+    }
+}
+
+fn price(cache: &Cache, instrument_id: &InstrumentId) -> anyhow::Result<f64> {
+    cache
+        .price(instrument_id, PriceType::Mid)
+        .or_else(|| cache.price(instrument_id, PriceType::Last))
+        .map(|price| price.as_f64())
+        .ok_or_else(|| anyhow::anyhow!("No price for {instrument_id}"))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        enums::{OmsType, OrderSide},
+        events::order::filled::OrderFilled,
+        identifiers::PositionId,
+        instruments::{
+            equity::Equity,
+            options_contract::OptionsContract,
+            stubs::{equity_aapl, options_contract_appl},
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::stubs::{get_message_saving_handler, get_saved_messages};
+
+    fn add_position(
+        cache: &mut Cache,
+        instrument: &InstrumentAny,
+        position_id: &str,
+        side: OrderSide,
+        qty: i64,
+    ) {
+        let fill = OrderFilled {
+            instrument_id: instrument.id(),
+            order_side: side,
+            last_qty: Quantity::from(qty),
+            last_px: Price::from("1.00"),
+            position_id: Some(PositionId::new(position_id)),
+            ..Default::default()
+        };
+        cache
+            .add_position(Position::new(instrument, fill), OmsType::Hedging)
+            .unwrap();
+    }
+
+    fn add_quote(cache: &mut Cache, instrument_id: InstrumentId, price: &str) {
+        cache
+            .add_quote(QuoteTick {
+                instrument_id,
+                bid_price: Price::from(price),
+                ask_price: Price::from(price),
+                ..Default::default()
+            })
+            .unwrap();
+    }
+
+    fn aggregator(
+        option: OptionsContract,
+        equity: Equity,
+        beta_reference: Option<InstrumentId>,
+    ) -> (GreeksAggregator, Rc<RefCell<MessageBus>>) {
+        let option = InstrumentAny::OptionsContract(option);
+        let equity = InstrumentAny::Equity(equity);
+        let mut cache = Cache::default();
+        cache.add_instrument(option.clone()).unwrap();
+        cache.add_instrument(equity.clone()).unwrap();
+        add_position(&mut cache, &option, "P-1", OrderSide::Buy, 10);
+        add_position(&mut cache, &equity, "P-2", OrderSide::Sell, 500);
+        add_quote(&mut cache, option.id(), "5.00");
+        add_quote(&mut cache, equity.id(), "150.00");
+        add_quote(&mut cache, InstrumentId::from("SPY.XNAS"), "450.00");
+
+        let config = GreeksAggregatorConfig {
+            interest_rate: 0.01,
+            underlyings: HashMap::from([(Ustr::from("AAPL"), equity.id())]),
+            betas: HashMap::from([(Ustr::from("AAPL"), 1.2)]),
+            beta_reference,
+        };
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let aggregator =
+            GreeksAggregator::new(config, Rc::new(RefCell::new(cache)), msgbus.clone());
+        (aggregator, msgbus)
+    }
+
+    #[rstest]
+    fn test_publish_aggregates_greeks_per_underlying(
+        options_contract_appl: OptionsContract,
+        equity_aapl: Equity,
+    ) {
+        let ts_event = options_contract_appl.activation_ns;
+        let t = (options_contract_appl.expiration_ns.as_u64() - ts_event.as_u64()) as f64
+            / NANOSECONDS_IN_SECOND as f64
+            / SECONDS_IN_YEAR;
+        let expected = imply_vol_and_greeks(150.0, 0.01, 0.01, true, 149.0, t, 5.0, 1.0);
+        let (aggregator, msgbus) = aggregator(options_contract_appl, equity_aapl, None);
+        let handler = get_message_saving_handler::<GreeksUpdate>(None);
+        let topic = msgbus.borrow().switchboard.greeks_topic;
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+
+        let updates = aggregator.publish(ts_event).unwrap();
+
+        // 10 calls hedged with 500 shares short
+        assert_eq!(updates.len(), 1);
+        let update = updates[0];
+        assert_eq!(update.underlying, Ustr::from("AAPL"));
+        assert!((update.delta - (expected.delta * 10.0 - 500.0)).abs() < 1e-9);
+        assert!((update.gamma - expected.gamma * 10.0).abs() < 1e-9);
+        assert!((update.vega - expected.vega * 10.0).abs() < 1e-9);
+        assert!((update.theta - expected.theta * 10.0).abs() < 1e-9);
+        assert_eq!(update.beta_weighted_delta, None);
+        assert_eq!(get_saved_messages::<GreeksUpdate>(handler), updates);
+    }
+
+    #[rstest]
+    fn test_beta_weighted_delta(options_contract_appl: OptionsContract, equity_aapl: Equity) {
+        let ts_event = options_contract_appl.activation_ns;
+        let (aggregator, _) = aggregator(
+            options_contract_appl,
+            equity_aapl,
+            Some(InstrumentId::from("SPY.XNAS")),
+        );
+
+        let update = aggregator.aggregate(ts_event).unwrap()[0];
+
+        let expected = update.delta * 1.2 * 150.0 / 450.0;
+        assert!((update.beta_weighted_delta.unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_expired_options_are_ignored(
+        options_contract_appl: OptionsContract,
+        equity_aapl: Equity,
+    ) {
+        let ts_event = options_contract_appl.expiration_ns;
+        let (aggregator, _) = aggregator(options_contract_appl, equity_aapl, None);
+
+        let update = aggregator.aggregate(ts_event).unwrap()[0];
+
+        assert_eq!(update.delta, -500.0);
+        assert_eq!(update.gamma, 0.0);
+    }
+
+    #[rstest]
+    fn test_aggregate_without_underlying_configured(
+        options_contract_appl: OptionsContract,
+        equity_aapl: Equity,
+    ) {
+        let ts_event = options_contract_appl.activation_ns;
+        let (mut aggregator, _) = aggregator(options_contract_appl, equity_aapl, None);
+        aggregator.config.underlyings.clear();
+
+        assert!(aggregator.aggregate(ts_event).is_err());
+    }
+}
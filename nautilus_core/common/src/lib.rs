@@ -25,25 +25,42 @@
 //! for the main `nautilus_trader` Python package, or as part of a Rust only build.
 //!
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
+//! - `ml`: Enables ONNX model inference from `ort`.
 //! - `python`: Enables Python bindings from `pyo3`.
 //! - `stubs`: Enables type stubs for use in testing scenarios.
 
 pub mod actor;
 pub mod cache;
+pub mod capabilities;
+pub mod chaos;
 pub mod clock;
 pub mod component;
+pub mod config;
+pub mod correlation;
+pub mod credentials;
 pub mod custom;
 pub mod enums;
+pub mod equity;
 pub mod factories;
 pub mod generators;
+pub mod greeks;
 pub mod logging;
 pub mod messages;
+pub mod ml;
+pub mod monitor;
 pub mod msgbus;
+pub mod parameters;
+pub mod price_limits;
 pub mod runtime;
+pub mod schedule;
+pub mod session;
+pub mod short_sale;
 pub mod signal;
+pub mod telemetry;
 pub mod testing;
 pub mod throttler;
 pub mod timer;
+pub mod universe;
 pub mod xrate;
 
 #[cfg(feature = "ffi")]
@@ -36,6 +36,7 @@ use crate::enums::LogLevel;
 
 pub mod headers;
 pub mod logger;
+pub mod subscriber;
 pub mod writer;
 
 pub const RECV: &str = "<--";
@@ -101,6 +102,8 @@ pub extern "C" fn logging_clock_set_static_time(time_ns: u64) {
 /// configured to filter modules and write up to a specific level by passing
 /// a configuration using the `RUST_LOG` environment variable.
 ///
+/// To configure tracing from a config instead, see [`subscriber::init_tracing_with_config`].
+///
 /// # Safety
 ///
 /// Should only be called once during an applications run, ideally at the
@@ -0,0 +1,393 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A configurable `tracing` subscriber for Nautilus systems.
+//!
+//! Where [`init_tracing`](super::init_tracing) is configured only from the `RUST_LOG` environment
+//! variable, [`init_tracing_with_config`] builds the subscriber from a [`TracingConfig`], which
+//! can be deserialized as part of a node configuration.
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Write},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{
+    callsite::Identifier,
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_appender::{
+    non_blocking::WorkerGuard,
+    rolling::{RollingFileAppender, Rotation},
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
+};
+
+use crate::enums::LogRotation;
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+/// Configuration for a rotating log file output.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TracingFileConfig {
+    /// The directory to write log files to.
+    pub directory: String,
+    /// The prefix for log file names, which are suffixed with the rotation period.
+    pub file_name_prefix: String,
+    /// The period after which a new log file is started.
+    pub rotation: LogRotation,
+    /// The maximum number of log files to retain (unlimited if `None`).
+    pub max_files: Option<usize>,
+    /// If log lines are written in JSON format.
+    pub json: bool,
+}
+
+impl Default for TracingFileConfig {
+    /// Creates a new default [`TracingFileConfig`] instance.
+    fn default() -> Self {
+        Self {
+            directory: "logs".to_string(),
+            file_name_prefix: "nautilus".to_string(),
+            rotation: LogRotation::Daily,
+            max_files: None,
+            json: false,
+        }
+    }
+}
+
+/// Configuration for the `tracing` subscriber.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TracingConfig {
+    /// The maximum level for all components without their own level.
+    pub level: String,
+    /// Per-component maximum levels, keyed by target (e.g. `nautilus_network=debug`).
+    pub component_levels: HashMap<String, String>,
+    /// If log lines are written to stdout.
+    pub stdout: bool,
+    /// If stdout log lines use ANSI color codes.
+    pub is_colored: bool,
+    /// The rotating log file output (disabled if `None`).
+    pub file: Option<TracingFileConfig>,
+    /// The interval (milliseconds) within which repeats of the same warning are suppressed
+    /// (disabled if `None`).
+    pub warn_dedupe_interval_ms: Option<u64>,
+}
+
+impl Default for TracingConfig {
+    /// Creates a new default [`TracingConfig`] instance.
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            component_levels: HashMap::new(),
+            stdout: true,
+            is_colored: true,
+            file: None,
+            warn_dedupe_interval_ms: None,
+        }
+    }
+}
+
+impl TracingConfig {
+    /// Returns the filter directives for the configured levels.
+    #[must_use]
+    pub fn directives(&self) -> String {
+        let mut components: Vec<_> = self.component_levels.iter().collect();
+        components.sort();
+
+        let mut directives = self.level.clone();
+        for (component, level) in components {
+            write!(directives, ",{component}={level}").expect("Writing to string");
+        }
+        directives
+    }
+}
+
+/// Holds the background writer of the file output, which flushes on drop.
+#[derive(Debug)]
+pub struct TracingGuard {
+    _file_guard: Option<WorkerGuard>,
+}
+
+/// Initializes the global `tracing` subscriber from the given `config`.
+///
+/// The returned guard must be held for the life of the application, so that buffered file
+/// output is flushed at exit.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the configured levels are not valid filter directives.
+/// - If the log file cannot be created.
+/// - If a global subscriber has already been set.
+pub fn init_tracing_with_config(config: &TracingConfig) -> anyhow::Result<TracingGuard> {
+    let filter = EnvFilter::try_new(config.directives())?;
+    let mut layers: Vec<BoxedLayer> = Vec::new();
+
+    if let Some(interval_ms) = config.warn_dedupe_interval_ms {
+        layers.push(WarnDedupeLayer::new(Duration::from_millis(interval_ms)).boxed());
+    }
+
+    if config.stdout {
+        layers.push(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(config.is_colored)
+                .boxed(),
+        );
+    }
+
+    let mut file_guard = None;
+    if let Some(file_config) = &config.file {
+        let mut builder = RollingFileAppender::builder()
+            .rotation(map_rotation(file_config.rotation))
+            .filename_prefix(&file_config.file_name_prefix)
+            .filename_suffix(if file_config.json { "json" } else { "log" });
+        if let Some(max_files) = file_config.max_files {
+            builder = builder.max_log_files(max_files);
+        }
+        let appender = builder.build(&file_config.directory)?;
+        let (writer, guard) = tracing_appender::non_blocking(appender);
+        file_guard = Some(guard);
+
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(writer);
+        layers.push(if file_config.json {
+            layer.json().boxed()
+        } else {
+            layer.boxed()
+        });
+    }
+
+    tracing_subscriber::registry()
+        .with(layers)
+        .with(filter)
+        .try_init()?;
+
+    Ok(TracingGuard {
+        _file_guard: file_guard,
+    })
+}
+
+const fn map_rotation(rotation: LogRotation) -> Rotation {
+    match rotation {
+        LogRotation::Minutely => Rotation::MINUTELY,
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Daily => Rotation::DAILY,
+        LogRotation::Never => Rotation::NEVER,
+    }
+}
+
+/// A layer which drops repeats of the same warning within an interval.
+///
+/// Warnings are considered the same when they come from the same callsite with the same
+/// message. The first warning is always passed through, then repeats are suppressed until
+/// the interval has elapsed since it was last passed through.
+pub struct WarnDedupeLayer {
+    interval: Duration,
+    last_seen: Mutex<HashMap<(Identifier, String), Instant>>,
+}
+
+impl WarnDedupeLayer {
+    /// Creates a new [`WarnDedupeLayer`] instance.
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn should_pass(&self, key: (Identifier, String), now: Instant) -> bool {
+        let mut last_seen = self.last_seen.lock().expect("Lock poisoned");
+
+        // Drop expired entries so that the map holds only the warnings being suppressed
+        let interval = self.interval;
+        last_seen.retain(|_, seen| now.duration_since(*seen) < interval);
+
+        match last_seen.get(&key) {
+            Some(_) => false,
+            None => {
+                last_seen.insert(key, now);
+                true
+            }
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for WarnDedupeLayer {
+    fn event_enabled(&self, event: &Event<'_>, _ctx: Context<'_, S>) -> bool {
+        let metadata = event.metadata();
+        if *metadata.level() != Level::WARN {
+            return true;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.should_pass((metadata.callsite(), visitor.0), Instant::now())
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            write!(self.0, "{value:?}").expect("Writing to string");
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use rstest::rstest;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for SharedBuffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[rstest]
+    fn test_directives() {
+        let config = TracingConfig {
+            level: "warn".to_string(),
+            component_levels: HashMap::from([
+                ("nautilus_network".to_string(), "debug".to_string()),
+                ("nautilus_data".to_string(), "trace".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.directives(),
+            "warn,nautilus_data=trace,nautilus_network=debug"
+        );
+        assert!(EnvFilter::try_new(config.directives()).is_ok());
+    }
+
+    #[rstest]
+    fn test_config_deserializes_with_defaults() {
+        let config: TracingConfig = serde_json::from_str(
+            r#"{"level": "debug", "file": {"rotation": "HOURLY", "json": true}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.level, "debug");
+        assert!(config.stdout);
+        let file = config.file.unwrap();
+        assert_eq!(file.rotation, LogRotation::Hourly);
+        assert!(file.json);
+        assert_eq!(file.directory, "logs");
+    }
+
+    #[rstest]
+    fn test_warn_dedupe_suppresses_repeats() {
+        let buffer = SharedBuffer::default();
+        let subscriber = tracing_subscriber::registry()
+            .with(WarnDedupeLayer::new(Duration::from_secs(60)))
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(buffer.clone()),
+            );
+
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..3 {
+                tracing::warn!("Stale quote");
+                tracing::info!("Heartbeat");
+            }
+            tracing::warn!("Book crossed");
+        });
+
+        let contents = buffer.contents();
+        assert_eq!(contents.matches("Stale quote").count(), 1);
+        assert_eq!(contents.matches("Heartbeat").count(), 3);
+        assert_eq!(contents.matches("Book crossed").count(), 1);
+    }
+
+    #[rstest]
+    fn test_warn_dedupe_passes_after_interval() {
+        let layer = WarnDedupeLayer::new(Duration::from_millis(10));
+        let key = || (Identifier(&CALLSITE), "message".to_string());
+        let start = Instant::now();
+
+        assert!(layer.should_pass(key(), start));
+        assert!(!layer.should_pass(key(), start + Duration::from_millis(5)));
+        assert!(layer.should_pass(key(), start + Duration::from_millis(15)));
+        assert_eq!(*key().0 .0.metadata().level(), Level::WARN);
+    }
+
+    struct TestCallsite;
+
+    static CALLSITE: TestCallsite = TestCallsite;
+
+    static METADATA: tracing::Metadata<'static> = tracing::Metadata::new(
+        "test_event",
+        "nautilus_common",
+        Level::WARN,
+        None,
+        None,
+        None,
+        tracing::field::FieldSet::new(&["message"], Identifier(&CALLSITE)),
+        tracing::metadata::Kind::EVENT,
+    );
+
+    impl tracing::callsite::Callsite for TestCallsite {
+        fn set_interest(&self, _interest: tracing::subscriber::Interest) {}
+
+        fn metadata(&self) -> &tracing::Metadata<'_> {
+            &METADATA
+        }
+    }
+}
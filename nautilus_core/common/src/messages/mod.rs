@@ -14,3 +14,5 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod data;
+pub mod quality;
+pub mod risk;
@@ -0,0 +1,161 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Alerts raised when live market data fails quality checks.
+//!
+//! Alerts are published on the `events.data_quality.{venue}.{symbol}` topics from
+//! [`MessagingSwitchboard::get_data_quality_topic`](crate::msgbus::switchboard::MessagingSwitchboard::get_data_quality_topic),
+//! for risk engines and strategies to act on.
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{identifiers::InstrumentId, types::price::Price};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// Represents a market data quality issue.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+pub enum DataQualityIssue {
+    /// No updates were received within the staleness threshold.
+    Stale {
+        last_update_ns: UnixNanos,
+        threshold_ns: u64,
+    },
+    /// The best bid price was above the best ask price.
+    CrossedBook { bid: Price, ask: Price },
+    /// The best bid price was equal to the best ask price.
+    LockedBook { price: Price },
+    /// The event timestamp was before that of the previous update of the same type.
+    OutOfOrder {
+        ts_event: UnixNanos,
+        last_ts_event: UnixNanos,
+    },
+    /// The price moved by more than the threshold since the previous update, with the
+    /// relative `change`.
+    PriceJump { last: f64, price: f64, change: f64 },
+}
+
+impl Display for DataQualityIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stale {
+                last_update_ns,
+                threshold_ns,
+            } => write!(
+                f,
+                "Stale: no updates since {last_update_ns} (threshold {threshold_ns}ns)"
+            ),
+            Self::CrossedBook { bid, ask } => write!(f, "Crossed book: bid {bid} > ask {ask}"),
+            Self::LockedBook { price } => write!(f, "Locked book: bid = ask {price}"),
+            Self::OutOfOrder {
+                ts_event,
+                last_ts_event,
+            } => write!(
+                f,
+                "Out of order: ts_event {ts_event} before previous {last_ts_event}"
+            ),
+            Self::PriceJump {
+                last,
+                price,
+                change,
+            } => write!(f, "Price jump: {last} -> {price} ({:.2}%)", change * 100.0),
+        }
+    }
+}
+
+/// Represents an alert for a market data quality issue on an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataQualityAlert {
+    /// The instrument ID for the alert.
+    pub instrument_id: InstrumentId,
+    /// The name of the data type the issue was found in, e.g. `QuoteTick`.
+    pub data_type: Ustr,
+    /// The issue found.
+    pub issue: DataQualityIssue,
+    /// UNIX timestamp (nanoseconds) of the data the issue was found in, or of the check.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the alert was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl DataQualityAlert {
+    /// Creates a new [`DataQualityAlert`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        data_type: &str,
+        issue: DataQualityIssue,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            data_type: Ustr::from(data_type),
+            issue,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for DataQualityAlert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({}, {}, {})",
+            stringify!(DataQualityAlert),
+            self.instrument_id,
+            self.data_type,
+            self.issue,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_alert_display_and_serialization() {
+        let alert = DataQualityAlert::new(
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            "QuoteTick",
+            DataQualityIssue::CrossedBook {
+                bid: Price::from("10001.00"),
+                ask: Price::from("10000.00"),
+            },
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(
+            alert.to_string(),
+            "DataQualityAlert(ETHUSDT.BINANCE, QuoteTick, Crossed book: bid 10001.00 > ask 10000.00)"
+        );
+        let json = serde_json::to_string(&alert).unwrap();
+        assert!(json.contains(r#""issue":"crossed_book""#), "{json}");
+        assert_eq!(
+            serde_json::from_str::<DataQualityAlert>(&json).unwrap(),
+            alert
+        );
+    }
+}
@@ -0,0 +1,387 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Events raised by the risk engine.
+//!
+//! Events are published on the `events.risk` topic from
+//! [`MessagingSwitchboard::risk_events_topic`](crate::msgbus::switchboard::MessagingSwitchboard::risk_events_topic).
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::TradingState,
+    identifiers::{ClientOrderId, InstrumentId, StrategyId, TraderId},
+    types::money::Money,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// Represents an event where the trading state of the risk engine has changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingStateChanged {
+    /// The trader ID for the event.
+    pub trader_id: TraderId,
+    /// The new trading state.
+    pub state: TradingState,
+    /// The reason for the change, if raised by a risk check rather than a command.
+    pub reason: Option<Ustr>,
+    /// If open positions should be closed, with the state remaining reducing until reset.
+    pub flatten: bool,
+    /// The unique identifier for the event.
+    pub event_id: UUID4,
+    /// UNIX timestamp (nanoseconds) when the event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the event was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl TradingStateChanged {
+    /// Creates a new [`TradingStateChanged`] instance.
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        state: TradingState,
+        reason: Option<Ustr>,
+        flatten: bool,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            state,
+            reason,
+            flatten,
+            event_id,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for TradingStateChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(trader_id={}, state={}, reason={}, flatten={})",
+            stringify!(TradingStateChanged),
+            self.trader_id,
+            self.state,
+            self.reason.map_or("None", |reason| reason.as_str()),
+            self.flatten,
+        )
+    }
+}
+
+/// Represents a per-strategy risk limit breached by an order, with the limit and the value the
+/// order would have reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StrategyLimitBreach {
+    /// The sum of the absolute exposures over all instruments.
+    GrossExposure { limit: Money, value: Money },
+    /// The absolute value of the exposures netted over all instruments.
+    NetExposure { limit: Money, value: Money },
+    /// The number of instruments with exposure or orders.
+    Instruments { limit: usize, value: usize },
+    /// The number of orders submitted in the last minute.
+    OrdersPerMinute { limit: usize, value: usize },
+}
+
+impl Display for StrategyLimitBreach {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GrossExposure { limit, value } => write!(
+                f,
+                "gross exposure {value} exceeds limit {limit} by {}",
+                *value - *limit
+            ),
+            Self::NetExposure { limit, value } => write!(
+                f,
+                "net exposure {value} exceeds limit {limit} by {}",
+                *value - *limit
+            ),
+            Self::Instruments { limit, value } => write!(
+                f,
+                "instruments {value} exceeds limit {limit} by {}",
+                value - limit
+            ),
+            Self::OrdersPerMinute { limit, value } => write!(
+                f,
+                "orders per minute {value} exceeds limit {limit} by {}",
+                value - limit
+            ),
+        }
+    }
+}
+
+/// Represents an event where an order was denied for breaching a per-strategy risk limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategyLimitViolated {
+    /// The trader ID for the event.
+    pub trader_id: TraderId,
+    /// The strategy ID the limit applies to.
+    pub strategy_id: StrategyId,
+    /// The instrument ID of the order.
+    pub instrument_id: InstrumentId,
+    /// The client order ID of the order.
+    pub client_order_id: ClientOrderId,
+    /// The limit breached.
+    pub breach: StrategyLimitBreach,
+    /// The unique identifier for the event.
+    pub event_id: UUID4,
+    /// UNIX timestamp (nanoseconds) when the event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the event was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl StrategyLimitViolated {
+    /// Creates a new [`StrategyLimitViolated`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        breach: StrategyLimitBreach,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            breach,
+            event_id,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for StrategyLimitViolated {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, instrument_id={}, client_order_id={}, breach={})",
+            stringify!(StrategyLimitViolated),
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.breach,
+        )
+    }
+}
+
+/// Represents a trade compliance rule breached by an order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ComplianceBreach {
+    /// The instrument is on the restricted list.
+    RestrictedInstrument,
+    /// The ratio of orders to trades for the instrument would exceed the limit.
+    OrderToTradeRatio {
+        limit: usize,
+        orders: usize,
+        trades: usize,
+    },
+    /// The order is opposite to an order for the same instrument within the wash trade window.
+    WashTrade {
+        opposite_order_id: ClientOrderId,
+        window_secs: u64,
+    },
+}
+
+impl Display for ComplianceBreach {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RestrictedInstrument => write!(f, "instrument is restricted"),
+            Self::OrderToTradeRatio {
+                limit,
+                orders,
+                trades,
+            } => write!(
+                f,
+                "order to trade ratio {orders}:{trades} exceeds limit {limit}:1"
+            ),
+            Self::WashTrade {
+                opposite_order_id,
+                window_secs,
+            } => write!(
+                f,
+                "potential wash trade against {opposite_order_id} within {window_secs}s"
+            ),
+        }
+    }
+}
+
+/// Represents an event where an order was denied for breaching a trade compliance rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceViolated {
+    /// The trader ID for the event.
+    pub trader_id: TraderId,
+    /// The strategy ID of the order.
+    pub strategy_id: StrategyId,
+    /// The instrument ID of the order.
+    pub instrument_id: InstrumentId,
+    /// The client order ID of the order.
+    pub client_order_id: ClientOrderId,
+    /// The rule breached.
+    pub breach: ComplianceBreach,
+    /// The unique identifier for the event.
+    pub event_id: UUID4,
+    /// UNIX timestamp (nanoseconds) when the event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the event was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl ComplianceViolated {
+    /// Creates a new [`ComplianceViolated`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        breach: ComplianceBreach,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            breach,
+            event_id,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for ComplianceViolated {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, instrument_id={}, client_order_id={}, breach={})",
+            stringify!(ComplianceViolated),
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.breach,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_trading_state_changed_display_and_serialization() {
+        let event = TradingStateChanged::new(
+            TraderId::from("TRADER-001"),
+            TradingState::Halted,
+            Some(Ustr::from("Drawdown breached")),
+            false,
+            UUID4::new(),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(
+            event.to_string(),
+            "TradingStateChanged(trader_id=TRADER-001, state=HALTED, reason=Drawdown breached, flatten=false)"
+        );
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TradingStateChanged>(&json).unwrap(),
+            event
+        );
+    }
+
+    #[rstest]
+    fn test_strategy_limit_violated_display_and_serialization() {
+        let event = StrategyLimitViolated::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::from("O-001"),
+            StrategyLimitBreach::GrossExposure {
+                limit: Money::from("100000 USD"),
+                value: Money::from("120000 USD"),
+            },
+            UUID4::new(),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(
+            event.to_string(),
+            "StrategyLimitViolated(strategy_id=S-001, instrument_id=ETHUSDT.BINANCE, \
+             client_order_id=O-001, breach=gross exposure 120000.00 USD exceeds limit \
+             100000.00 USD by 20000.00 USD)"
+        );
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""kind":"gross_exposure""#), "{json}");
+        assert_eq!(
+            serde_json::from_str::<StrategyLimitViolated>(&json).unwrap(),
+            event
+        );
+    }
+
+    #[rstest]
+    fn test_compliance_violated_display_and_serialization() {
+        let event = ComplianceViolated::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::from("O-002"),
+            ComplianceBreach::WashTrade {
+                opposite_order_id: ClientOrderId::from("O-001"),
+                window_secs: 60,
+            },
+            UUID4::new(),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(
+            event.to_string(),
+            "ComplianceViolated(strategy_id=S-001, instrument_id=ETHUSDT.BINANCE, \
+             client_order_id=O-002, breach=potential wash trade against O-001 within 60s)"
+        );
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""kind":"wash_trade""#), "{json}");
+        assert_eq!(
+            serde_json::from_str::<ComplianceViolated>(&json).unwrap(),
+            event
+        );
+    }
+}
@@ -0,0 +1,221 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Machine learning model inference for generating trading signals.
+//!
+//! An [`InferenceModel`] maps an input tensor of features to output values, which a
+//! [`ModelSignalGenerator`] publishes as a typed [`ModelSignal`] on the message bus so that
+//! strategies can subscribe to model outputs like any other data. ONNX models are supported with
+//! the `ml` feature flag.
+
+#[cfg(feature = "ml")]
+pub mod onnx;
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_core::{
+    correctness::{check_equal_usize, check_slice_not_empty},
+    nanos::UnixNanos,
+};
+use nautilus_model::{data::GetTsInit, identifiers::InstrumentId};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::msgbus::MessageBus;
+
+/// Represents the output values of a model inference for an instrument.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModelSignal {
+    pub model_id: Ustr,
+    pub instrument_id: InstrumentId,
+    pub values: Vec<f32>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl ModelSignal {
+    /// Creates a new [`ModelSignal`] instance.
+    #[must_use]
+    pub const fn new(
+        model_id: Ustr,
+        instrument_id: InstrumentId,
+        values: Vec<f32>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            model_id,
+            instrument_id,
+            values,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the first output value (if any), for models with a single output.
+    #[must_use]
+    pub fn value(&self) -> Option<f32> {
+        self.values.first().copied()
+    }
+}
+
+impl GetTsInit for ModelSignal {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Provides inference for a model with a single input and output tensor.
+pub trait InferenceModel {
+    /// Returns the model ID, used in the signal topic.
+    fn id(&self) -> Ustr;
+
+    /// Runs the model on the row-major `input` with the given `shape`, returning the output
+    /// values.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if inference fails.
+    fn predict(&mut self, input: &[f32], shape: &[usize]) -> anyhow::Result<Vec<f32>>;
+}
+
+/// Runs an [`InferenceModel`] and publishes the outputs as [`ModelSignal`]s on the message bus.
+pub struct ModelSignalGenerator {
+    model: Box<dyn InferenceModel>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl ModelSignalGenerator {
+    /// Creates a new [`ModelSignalGenerator`] instance.
+    pub fn new(model: Box<dyn InferenceModel>, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self { model, msgbus }
+    }
+
+    /// Returns the ID of the model.
+    #[must_use]
+    pub fn model_id(&self) -> Ustr {
+        self.model.id()
+    }
+
+    /// Returns the topic on which signals for the given `instrument_id` are published.
+    #[must_use]
+    pub fn topic(&self, instrument_id: InstrumentId) -> Ustr {
+        self.msgbus
+            .borrow_mut()
+            .switchboard
+            .get_model_signal_topic(self.model.id(), instrument_id)
+    }
+
+    /// Runs the model on the given `input` and publishes the resulting signal.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The `shape` is empty or does not match the length of `input`.
+    /// - Inference fails.
+    pub fn generate(
+        &mut self,
+        instrument_id: InstrumentId,
+        input: &[f32],
+        shape: &[usize],
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<ModelSignal> {
+        check_slice_not_empty(shape, stringify!(shape))?;
+        check_equal_usize(
+            shape.iter().product(),
+            input.len(),
+            "shape size",
+            "input length",
+        )?;
+
+        let values = self.model.predict(input, shape)?;
+        let signal = ModelSignal::new(self.model.id(), instrument_id, values, ts_event, ts_init);
+
+        let topic = self.topic(instrument_id);
+        self.msgbus.borrow().publish(&topic, &signal as &dyn Any);
+        Ok(signal)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::identifiers::InstrumentId;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::stubs::{get_message_saving_handler, get_saved_messages};
+
+    // Outputs the sum of each input row
+    struct RowSumModel;
+
+    impl InferenceModel for RowSumModel {
+        fn id(&self) -> Ustr {
+            Ustr::from("ROW_SUM")
+        }
+
+        fn predict(&mut self, input: &[f32], shape: &[usize]) -> anyhow::Result<Vec<f32>> {
+            let cols = shape[shape.len() - 1];
+            Ok(input.chunks(cols).map(|row| row.iter().sum()).collect())
+        }
+    }
+
+    #[rstest]
+    fn test_generate_publishes_signal() {
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let mut generator = ModelSignalGenerator::new(Box::new(RowSumModel), msgbus.clone());
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let handler = get_message_saving_handler::<ModelSignal>(None);
+        let topic = generator.topic(instrument_id);
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+
+        let signal = generator
+            .generate(
+                instrument_id,
+                &[1.0, 2.0, 3.0, 4.0],
+                &[2, 2],
+                UnixNanos::from(1),
+                UnixNanos::from(2),
+            )
+            .unwrap();
+
+        assert_eq!(topic, "data.model_signals.ROW_SUM.BINANCE.ETHUSDT-PERP");
+        assert_eq!(signal.values, vec![3.0, 7.0]);
+        assert_eq!(signal.value(), Some(3.0));
+        assert_eq!(signal.ts_init(), 2);
+        assert_eq!(get_saved_messages::<ModelSignal>(handler), vec![signal]);
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[3])]
+    fn test_generate_with_invalid_shape(#[case] shape: &[usize]) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let mut generator = ModelSignalGenerator::new(Box::new(RowSumModel), msgbus);
+
+        let result = generator.generate(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            &[1.0, 2.0],
+            shape,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_err());
+    }
+}
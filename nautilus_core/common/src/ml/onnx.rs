@@ -0,0 +1,113 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! ONNX model inference using the ONNX Runtime via `ort`.
+//!
+//! The ONNX Runtime shared library is loaded dynamically, either from the path in the
+//! `ORT_DYLIB_PATH` environment variable or from the path given to [`init_runtime`].
+
+use std::path::Path;
+
+use ort::{session::Session, value::Tensor};
+use ustr::Ustr;
+
+use super::InferenceModel;
+
+/// Initializes the ONNX Runtime from the shared library at the given `dylib_path`.
+///
+/// Must be called before loading any models if `ORT_DYLIB_PATH` is not set.
+///
+/// # Errors
+///
+/// This function returns an error if the library cannot be loaded.
+pub fn init_runtime(dylib_path: &str) -> anyhow::Result<()> {
+    ort::init_from(dylib_path).commit()?;
+    Ok(())
+}
+
+/// An ONNX model with a single `f32` input and output tensor.
+pub struct OnnxModel {
+    id: Ustr,
+    session: Session,
+    input_name: String,
+    output_name: String,
+}
+
+impl OnnxModel {
+    /// Loads the ONNX model at the given `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the model cannot be loaded, or has no inputs or outputs.
+    pub fn from_file(id: Ustr, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let session = Session::builder()?.commit_from_file(path)?;
+        Self::from_session(id, session)
+    }
+
+    /// Loads the ONNX model from the given serialized `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the model cannot be loaded, or has no inputs or outputs.
+    pub fn from_memory(id: Ustr, bytes: &[u8]) -> anyhow::Result<Self> {
+        let session = Session::builder()?.commit_from_memory(bytes)?;
+        Self::from_session(id, session)
+    }
+
+    fn from_session(id: Ustr, session: Session) -> anyhow::Result<Self> {
+        let Some(input) = session.inputs.first() else {
+            anyhow::bail!("ONNX model {id} has no inputs");
+        };
+        let Some(output) = session.outputs.first() else {
+            anyhow::bail!("ONNX model {id} has no outputs");
+        };
+        let input_name = input.name.clone();
+        let output_name = output.name.clone();
+
+        Ok(Self {
+            id,
+            session,
+            input_name,
+            output_name,
+        })
+    }
+
+    /// Returns the name of the model input.
+    #[must_use]
+    pub fn input_name(&self) -> &str {
+        &self.input_name
+    }
+
+    /// Returns the name of the model output.
+    #[must_use]
+    pub fn output_name(&self) -> &str {
+        &self.output_name
+    }
+}
+
+impl InferenceModel for OnnxModel {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn predict(&mut self, input: &[f32], shape: &[usize]) -> anyhow::Result<Vec<f32>> {
+        let tensor = Tensor::from_array((shape.to_vec(), input.to_vec()))?;
+        let outputs = self
+            .session
+            .run(ort::inputs![self.input_name.as_str() => tensor])?;
+        let (_, values) = outputs[self.output_name.as_str()].try_extract_tensor::<f32>()?;
+        Ok(values.to_vec())
+    }
+}
@@ -0,0 +1,30 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration for the monitoring WebSocket server.
+
+use std::net::SocketAddr;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the monitoring WebSocket server.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorConfig {
+    /// The socket address to serve WebSocket connections on (disabled if `None`).
+    pub addr: Option<SocketAddr>,
+    /// The message bus topics (or topic patterns) to broadcast, e.g. `data.quotes.*`.
+    pub topics: Vec<String>,
+}
@@ -0,0 +1,176 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Real-time monitoring of message bus traffic over WebSocket.
+//!
+//! A [`MonitorPublisher`] is subscribed to each topic selected in the [`MonitorConfig`], encoding
+//! the published ticks, fills and position updates as JSON frames of the form
+//! `{"topic": ..., "type": ..., "data": ...}`. The frames are broadcast to the connections of an
+//! embedded WebSocket server, where each client only receives the topics it subscribed to.
+
+pub mod config;
+pub mod server;
+
+use std::{any::Any, net::SocketAddr, rc::Rc};
+
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick, Data},
+    events::{
+        order::{any::OrderEventAny, filled::OrderFilled},
+        position::{
+            changed::PositionChanged, closed::PositionClosed, opened::PositionOpened,
+            snapshot::PositionSnapshot,
+        },
+    },
+};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use ustr::Ustr;
+
+pub use crate::monitor::config::MonitorConfig;
+use crate::{
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+
+/// The number of frames buffered for each connection before the oldest are dropped.
+pub(crate) const MONITOR_BUFFER_SIZE: usize = 10_000;
+
+/// Represents a JSON encoded message broadcast to monitoring clients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonitorFrame {
+    /// The topic the message was subscribed on.
+    pub topic: Ustr,
+    /// The JSON text of the frame.
+    pub json: String,
+}
+
+#[derive(Serialize)]
+struct FrameRef<'a, T> {
+    topic: &'a str,
+    #[serde(rename = "type")]
+    data_type: &'a str,
+    data: &'a T,
+}
+
+/// Encodes the messages published on a topic as [`MonitorFrame`]s for broadcasting.
+///
+/// Messages of unsupported types are ignored.
+pub struct MonitorPublisher {
+    id: Ustr,
+    topic: Ustr,
+    sender: broadcast::Sender<MonitorFrame>,
+}
+
+impl MonitorPublisher {
+    /// Creates a new [`MonitorPublisher`] instance for the given `topic`.
+    #[must_use]
+    pub fn new(topic: Ustr, sender: broadcast::Sender<MonitorFrame>) -> Self {
+        Self {
+            id: Ustr::from(&format!("MonitorPublisher-{topic}")),
+            topic,
+            sender,
+        }
+    }
+
+    fn publish<T: Serialize>(&self, data_type: &str, data: &T) {
+        let frame = FrameRef {
+            topic: &self.topic,
+            data_type,
+            data,
+        };
+        match serde_json::to_string(&frame) {
+            Ok(json) => {
+                // An error only indicates there are no connected clients
+                let _ = self.sender.send(MonitorFrame {
+                    topic: self.topic,
+                    json,
+                });
+            }
+            Err(e) => log::error!("Error encoding {data_type} for monitor: {e}"),
+        }
+    }
+}
+
+impl MessageHandler for MonitorPublisher {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            self.publish("QuoteTick", quote);
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            self.publish("TradeTick", trade);
+        } else if let Some(bar) = message.downcast_ref::<Bar>() {
+            self.publish("Bar", bar);
+        } else if let Some(fill) = message.downcast_ref::<OrderFilled>() {
+            self.publish("OrderFilled", fill);
+        } else if let Some(OrderEventAny::Filled(fill)) = message.downcast_ref::<OrderEventAny>() {
+            self.publish("OrderFilled", fill);
+        } else if let Some(event) = message.downcast_ref::<PositionOpened>() {
+            self.publish("PositionOpened", event);
+        } else if let Some(event) = message.downcast_ref::<PositionChanged>() {
+            self.publish("PositionChanged", event);
+        } else if let Some(event) = message.downcast_ref::<PositionClosed>() {
+            self.publish("PositionClosed", event);
+        } else if let Some(snapshot) = message.downcast_ref::<PositionSnapshot>() {
+            self.publish("PositionSnapshot", snapshot);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        match &data {
+            Data::Quote(quote) => self.publish("QuoteTick", quote),
+            Data::Trade(trade) => self.publish("TradeTick", trade),
+            Data::Bar(bar) => self.publish("Bar", bar),
+            _ => {}
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Starts the monitoring WebSocket server if enabled by the given `config`, subscribing a
+/// [`MonitorPublisher`] to each of its topics on the `msgbus`, and returning the bound address.
+///
+/// # Errors
+///
+/// This function returns an error if the configured address cannot be bound.
+pub fn init_monitor(
+    config: &MonitorConfig,
+    msgbus: &mut MessageBus,
+) -> anyhow::Result<Option<SocketAddr>> {
+    let Some(addr) = config.addr else {
+        return Ok(None);
+    };
+
+    let (sender, _) = broadcast::channel(MONITOR_BUFFER_SIZE);
+    for topic in &config.topics {
+        let topic = Ustr::from(topic);
+        let handler =
+            ShareableMessageHandler(Rc::new(MonitorPublisher::new(topic, sender.clone())));
+        msgbus.subscribe(topic, handler, None);
+    }
+
+    server::start_monitor_server(addr, sender).map(Some)
+}
@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The WebSocket server broadcasting [`MonitorFrame`]s to monitoring clients.
+//!
+//! Clients select the frames they receive by sending JSON requests:
+//!
+//! - `{"op": "subscribe", "topic": "data.quotes.*"}`
+//! - `{"op": "unsubscribe", "topic": "data.quotes.*"}`
+//!
+//! Each request is acknowledged with `{"op": "subscribed", "topic": ...}` or
+//! `{"op": "unsubscribed", "topic": ...}`, or `{"op": "error", "message": ...}` if invalid.
+//! Topics are matched against the topic a frame was subscribed on, with `*` and `?` wildcards.
+
+use std::net::SocketAddr;
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+    routing::get,
+    serve, Router,
+};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    net::TcpListener,
+    sync::broadcast::{self, error::RecvError},
+};
+use ustr::Ustr;
+
+use super::MonitorFrame;
+use crate::{msgbus::is_matching, runtime::get_runtime};
+
+/// The path WebSocket connections are accepted on.
+pub const MONITOR_PATH: &str = "/ws";
+
+/// Represents a request from a monitoring client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum MonitorRequest {
+    /// Receive the frames for topics matching `topic`.
+    Subscribe { topic: String },
+    /// Stop receiving the frames for topics matching `topic`.
+    Unsubscribe { topic: String },
+}
+
+/// Represents a response to a monitoring client request.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum MonitorResponse {
+    Subscribed { topic: String },
+    Unsubscribed { topic: String },
+    Error { message: String },
+}
+
+/// Starts serving WebSocket connections at [`MONITOR_PATH`] on the given `addr`, broadcasting
+/// the frames sent on `sender`, and returning the bound address.
+///
+/// # Errors
+///
+/// This function returns an error if the address cannot be bound.
+pub fn start_monitor_server(
+    addr: SocketAddr,
+    sender: broadcast::Sender<MonitorFrame>,
+) -> anyhow::Result<SocketAddr> {
+    let runtime = get_runtime();
+    let listener = runtime.block_on(TcpListener::bind(addr))?;
+    let local_addr = listener.local_addr()?;
+    let app = Router::new()
+        .route(MONITOR_PATH, get(upgrade))
+        .with_state(sender);
+
+    runtime.spawn(async move {
+        if let Err(e) = serve(listener, app).await {
+            log::error!("Monitor server stopped: {e}");
+        }
+    });
+
+    log::info!("Serving monitor on ws://{local_addr}{MONITOR_PATH}");
+    Ok(local_addr)
+}
+
+async fn upgrade(
+    ws: WebSocketUpgrade,
+    State(sender): State<broadcast::Sender<MonitorFrame>>,
+) -> Response {
+    let rx = sender.subscribe();
+    ws.on_upgrade(move |socket| handle_connection(socket, rx))
+}
+
+async fn handle_connection(mut socket: WebSocket, mut rx: broadcast::Receiver<MonitorFrame>) {
+    let mut patterns: Vec<Ustr> = Vec::new();
+
+    loop {
+        tokio::select! {
+            frame = rx.recv() => match frame {
+                Ok(frame) => {
+                    if !patterns.iter().any(|pattern| is_matching(&frame.topic, pattern)) {
+                        continue;
+                    }
+                    if socket.send(Message::Text(frame.json)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(count)) => {
+                    log::warn!("Monitor connection lagging, dropped {count} frames");
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let response = apply_request(&text, &mut patterns);
+                    let json = serde_json::to_string(&response).expect("Failed to encode response");
+                    if socket.send(Message::Text(json)).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+fn apply_request(text: &str, patterns: &mut Vec<Ustr>) -> MonitorResponse {
+    match serde_json::from_str::<MonitorRequest>(text) {
+        Ok(MonitorRequest::Subscribe { topic }) => {
+            let pattern = Ustr::from(&topic);
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+            MonitorResponse::Subscribed { topic }
+        }
+        Ok(MonitorRequest::Unsubscribe { topic }) => {
+            let pattern = Ustr::from(&topic);
+            patterns.retain(|p| *p != pattern);
+            MonitorResponse::Unsubscribed { topic }
+        }
+        Err(e) => MonitorResponse::Error {
+            message: format!("Invalid request: {e}"),
+        },
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, time::Duration};
+
+    use futures::{SinkExt, StreamExt};
+    use nautilus_model::data::stubs::{quote_ethusdt_binance, stub_trade_ethusdt_buyer};
+    use rstest::rstest;
+    use tokio_tungstenite::{connect_async, tungstenite};
+
+    use super::*;
+    use crate::{
+        monitor::{init_monitor, MonitorConfig},
+        msgbus::MessageBus,
+    };
+
+    type Client = tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >;
+
+    fn connect(addr: SocketAddr) -> Client {
+        let url = format!("ws://{addr}{MONITOR_PATH}");
+        get_runtime().block_on(connect_async(url)).unwrap().0
+    }
+
+    fn request(client: &mut Client, request: &MonitorRequest) -> MonitorResponse {
+        let json = serde_json::to_string(request).unwrap();
+        get_runtime().block_on(async {
+            client.send(tungstenite::Message::text(json)).await.unwrap();
+            receive(client).await.unwrap()
+        })
+    }
+
+    async fn receive<T: for<'de> Deserialize<'de>>(client: &mut Client) -> Option<T> {
+        let message = tokio::time::timeout(Duration::from_millis(200), client.next())
+            .await
+            .ok()?;
+        let text = message.unwrap().unwrap().into_text().unwrap();
+        Some(serde_json::from_str(&text).unwrap())
+    }
+
+    #[rstest]
+    fn test_apply_request() {
+        let mut patterns = Vec::new();
+
+        let response = apply_request(r#"{"op":"subscribe","topic":"data.*"}"#, &mut patterns);
+        assert_eq!(
+            response,
+            MonitorResponse::Subscribed {
+                topic: "data.*".to_string()
+            }
+        );
+        assert_eq!(patterns, vec![Ustr::from("data.*")]);
+
+        apply_request(r#"{"op":"unsubscribe","topic":"data.*"}"#, &mut patterns);
+        assert!(patterns.is_empty());
+
+        let response = apply_request(r#"{"op":"publish"}"#, &mut patterns);
+        assert!(matches!(response, MonitorResponse::Error { .. }));
+    }
+
+    #[rstest]
+    fn test_init_monitor_disabled_by_default() {
+        let mut msgbus = MessageBus::default();
+        assert!(init_monitor(&MonitorConfig::default(), &mut msgbus)
+            .unwrap()
+            .is_none());
+        assert!(msgbus.subscriptions().is_empty());
+    }
+
+    #[rstest]
+    fn test_monitor_broadcasts_subscribed_topics() {
+        let quote_topic = Ustr::from("data.quotes.BINANCE.ETHUSDT-PERP");
+        let trade_topic = Ustr::from("data.trades.BINANCE.ETHUSDT-PERP");
+        let config = MonitorConfig {
+            addr: Some("127.0.0.1:0".parse().unwrap()),
+            topics: vec![quote_topic.to_string(), trade_topic.to_string()],
+        };
+        let mut msgbus = MessageBus::default();
+        let addr = init_monitor(&config, &mut msgbus).unwrap().unwrap();
+        assert_eq!(msgbus.subscriptions().len(), 2);
+
+        let mut client = connect(addr);
+        let subscribe = MonitorRequest::Subscribe {
+            topic: "data.quotes.*".to_string(),
+        };
+        assert_eq!(
+            request(&mut client, &subscribe),
+            MonitorResponse::Subscribed {
+                topic: "data.quotes.*".to_string()
+            }
+        );
+
+        let quote = quote_ethusdt_binance();
+        msgbus.publish(&trade_topic, &stub_trade_ethusdt_buyer() as &dyn Any);
+        msgbus.publish(&quote_topic, &quote as &dyn Any);
+
+        let frame: serde_json::Value = get_runtime()
+            .block_on(receive(&mut client))
+            .expect("No frame received");
+        assert_eq!(frame["topic"], quote_topic.as_str());
+        assert_eq!(frame["type"], "QuoteTick");
+        assert_eq!(frame["data"], serde_json::to_value(quote).unwrap());
+
+        let next: Option<serde_json::Value> = get_runtime().block_on(receive(&mut client));
+        assert!(next.is_none());
+    }
+}
@@ -380,6 +380,18 @@ impl MessageBus {
         })
     }
 
+    /// Returns the subscriptions in priority order whose topic (which may be a pattern)
+    /// matches the given published `topic`.
+    pub(crate) fn published_subscriptions<'a>(&'a self, topic: &'a Ustr) -> Vec<&'a Subscription> {
+        let mut matching_subs: Vec<&'a Subscription> = self
+            .subscriptions
+            .keys()
+            .filter(|sub| is_matching(topic, &sub.topic))
+            .collect();
+        matching_subs.sort();
+        matching_subs
+    }
+
     /// Sends a message to an endpoint.
     pub fn send(&self, endpoint: &Ustr, message: &dyn Any) {
         if let Some(handler) = self.get_endpoint(endpoint) {
@@ -393,7 +405,7 @@ impl MessageBus {
             "Publishing topic '{topic}' {message:?} {}",
             self.memory_address()
         );
-        let matching_subs = self.matching_subscriptions(topic);
+        let matching_subs = self.published_subscriptions(topic);
 
         log::trace!("Matched {} subscriptions", matching_subs.len());
 
@@ -430,7 +442,7 @@ impl MessageBus {
 
     /// Publish [`Data`] to a topic.
     pub fn publish_data(&self, topic: &Ustr, message: Data) {
-        let matching_subs = self.matching_subscriptions(topic);
+        let matching_subs = self.published_subscriptions(topic);
 
         for sub in matching_subs {
             sub.handler.0.handle_data(message.clone());
@@ -628,6 +640,34 @@ mod tests {
         assert_eq!(subs[3].handler_id, handler_id2);
     }
 
+    #[rstest]
+    fn test_publish_to_pattern_subscription() {
+        let mut msgbus = stub_msgbus();
+        let handler_id = Ustr::from("1");
+        let handler = get_stub_shareable_handler(Some(handler_id));
+
+        msgbus.subscribe("data.quotes.*", handler, None);
+
+        let quote_topic = Ustr::from("data.quotes.BINANCE.ETHUSDT");
+        let trade_topic = Ustr::from("data.trades.BINANCE.ETHUSDT");
+        let subs = msgbus.published_subscriptions(&quote_topic);
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].handler_id, handler_id);
+        assert!(msgbus.published_subscriptions(&trade_topic).is_empty());
+    }
+
+    #[rstest]
+    fn test_publish_to_pattern_topic_does_not_match_subscriptions() {
+        let mut msgbus = stub_msgbus();
+        let handler = get_stub_shareable_handler(None);
+
+        msgbus.subscribe("data.quotes.BINANCE.ETHUSDT", handler, None);
+
+        let pattern = Ustr::from("data.quotes.*");
+        assert!(msgbus.published_subscriptions(&pattern).is_empty());
+        assert_eq!(msgbus.matching_subscriptions(&pattern).len(), 1);
+    }
+
     #[rstest]
     #[case("*", "*", true)]
     #[case("a", "*", true)]
@@ -13,11 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroU64};
 
 use nautilus_model::{
     data::{bar::BarType, DataType},
-    identifiers::InstrumentId,
+    identifiers::{InstrumentId, StrategyId},
 };
 use ustr::Ustr;
 
@@ -28,14 +28,29 @@ pub struct MessagingSwitchboard {
     pub data_engine_process: Ustr,
     pub exec_engine_execute: Ustr,
     pub exec_engine_process: Ustr,
+    pub parameter_service_update: Ustr,
+    pub risk_events_topic: Ustr,
+    pub account_state_topic: Ustr,
+    pub equity_topic: Ustr,
+    pub greeks_topic: Ustr,
+    pub universe_topic: Ustr,
+    pub drop_copy_topic: Ustr,
+    pub order_status_topic: Ustr,
     custom_topics: HashMap<DataType, Ustr>,
     instrument_topics: HashMap<InstrumentId, Ustr>,
     deltas_topics: HashMap<InstrumentId, Ustr>,
     snapshots_topics: HashMap<InstrumentId, Ustr>,
+    depth_snapshot_topics: HashMap<(InstrumentId, NonZeroU64), Ustr>,
     depth_topics: HashMap<InstrumentId, Ustr>,
     quote_topics: HashMap<InstrumentId, Ustr>,
     trade_topics: HashMap<InstrumentId, Ustr>,
+    consolidated_quote_topics: HashMap<InstrumentId, Ustr>,
+    conflated_quote_topics: HashMap<(InstrumentId, NonZeroU64), Ustr>,
+    conflated_trade_topics: HashMap<(InstrumentId, NonZeroU64), Ustr>,
     bar_topics: HashMap<BarType, Ustr>,
+    data_quality_topics: HashMap<InstrumentId, Ustr>,
+    model_signal_topics: HashMap<(Ustr, InstrumentId), Ustr>,
+    parameters_topics: HashMap<StrategyId, Ustr>,
 }
 
 impl Default for MessagingSwitchboard {
@@ -46,14 +61,29 @@ impl Default for MessagingSwitchboard {
             data_engine_process: Ustr::from("DataEngine.process"),
             exec_engine_execute: Ustr::from("ExecEngine.execute"),
             exec_engine_process: Ustr::from("ExecEngine.process"),
+            parameter_service_update: Ustr::from("ParameterService.update"),
+            risk_events_topic: Ustr::from("events.risk"),
+            account_state_topic: Ustr::from("events.account"),
+            equity_topic: Ustr::from("events.equity"),
+            greeks_topic: Ustr::from("events.greeks"),
+            universe_topic: Ustr::from("events.universe"),
+            drop_copy_topic: Ustr::from("events.drop_copy"),
+            order_status_topic: Ustr::from("events.order_status"),
             custom_topics: HashMap::new(),
             instrument_topics: HashMap::new(),
             deltas_topics: HashMap::new(),
             snapshots_topics: HashMap::new(),
+            depth_snapshot_topics: HashMap::new(),
             depth_topics: HashMap::new(),
             quote_topics: HashMap::new(),
             trade_topics: HashMap::new(),
+            consolidated_quote_topics: HashMap::new(),
+            conflated_quote_topics: HashMap::new(),
+            conflated_trade_topics: HashMap::new(),
             bar_topics: HashMap::new(),
+            data_quality_topics: HashMap::new(),
+            model_signal_topics: HashMap::new(),
+            parameters_topics: HashMap::new(),
         }
     }
 }
@@ -133,6 +163,70 @@ impl MessagingSwitchboard {
         })
     }
 
+    #[must_use]
+    pub fn get_consolidated_quote_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .consolidated_quote_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.consolidated_quotes.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_depth_snapshot_topic(
+        &mut self,
+        instrument_id: InstrumentId,
+        interval_ms: NonZeroU64,
+    ) -> Ustr {
+        *self
+            .depth_snapshot_topics
+            .entry((instrument_id, interval_ms))
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.book.depth_snapshots.{}.{}.{interval_ms}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_conflated_quote_topic(
+        &mut self,
+        instrument_id: InstrumentId,
+        interval_ms: NonZeroU64,
+    ) -> Ustr {
+        *self
+            .conflated_quote_topics
+            .entry((instrument_id, interval_ms))
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.conflated_quotes.{}.{}.{interval_ms}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_conflated_trade_topic(
+        &mut self,
+        instrument_id: InstrumentId,
+        interval_ms: NonZeroU64,
+    ) -> Ustr {
+        *self
+            .conflated_trade_topics
+            .entry((instrument_id, interval_ms))
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.conflated_trades.{}.{}.{interval_ms}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
     #[must_use]
     pub fn get_bar_topic(&mut self, bar_type: BarType) -> Ustr {
         *self
@@ -140,6 +234,40 @@ impl MessagingSwitchboard {
             .entry(bar_type)
             .or_insert_with(|| Ustr::from(&format!("data.bars.{bar_type}")))
     }
+
+    #[must_use]
+    pub fn get_data_quality_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .data_quality_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "events.data_quality.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_model_signal_topic(&mut self, model_id: Ustr, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .model_signal_topics
+            .entry((model_id, instrument_id))
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.model_signals.{model_id}.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_parameters_topic(&mut self, strategy_id: StrategyId) -> Ustr {
+        *self
+            .parameters_topics
+            .entry(strategy_id)
+            .or_insert_with(|| Ustr::from(&format!("events.parameters.{strategy_id}")))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -228,6 +356,87 @@ mod tests {
         assert!(switchboard.trade_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_data_quality_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("events.data_quality.XCME.ESZ24");
+        let result = switchboard.get_data_quality_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.data_quality_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_model_signal_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let model_id = Ustr::from("MOMENTUM");
+        let expected_topic = Ustr::from("data.model_signals.MOMENTUM.XCME.ESZ24");
+        let result = switchboard.get_model_signal_topic(model_id, instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .model_signal_topics
+            .contains_key(&(model_id, instrument_id)));
+    }
+
+    #[rstest]
+    fn test_get_parameters_topic(mut switchboard: MessagingSwitchboard) {
+        let strategy_id = StrategyId::from("EMACross-001");
+        let expected_topic = Ustr::from("events.parameters.EMACross-001");
+        let result = switchboard.get_parameters_topic(strategy_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.parameters_topics.contains_key(&strategy_id));
+    }
+
+    #[rstest]
+    fn test_get_consolidated_quote_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.consolidated_quotes.XCME.ESZ24");
+        let result = switchboard.get_consolidated_quote_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .consolidated_quote_topics
+            .contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_depth_snapshot_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let interval_ms = NonZeroU64::new(1_000).unwrap();
+        assert_eq!(
+            switchboard.get_depth_snapshot_topic(instrument_id, interval_ms),
+            Ustr::from("data.book.depth_snapshots.XCME.ESZ24.1000")
+        );
+        assert!(switchboard
+            .depth_snapshot_topics
+            .contains_key(&(instrument_id, interval_ms)));
+    }
+
+    #[rstest]
+    fn test_get_conflated_topics(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let interval_ms = NonZeroU64::new(50).unwrap();
+        assert_eq!(
+            switchboard.get_conflated_quote_topic(instrument_id, interval_ms),
+            Ustr::from("data.conflated_quotes.XCME.ESZ24.50")
+        );
+        assert_eq!(
+            switchboard.get_conflated_trade_topic(instrument_id, interval_ms),
+            Ustr::from("data.conflated_trades.XCME.ESZ24.50")
+        );
+        assert!(switchboard
+            .conflated_quote_topics
+            .contains_key(&(instrument_id, interval_ms)));
+    }
+
     #[rstest]
     fn test_get_bar_topic(mut switchboard: MessagingSwitchboard) {
         let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-LAST-INTERNAL");
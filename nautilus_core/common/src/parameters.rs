@@ -0,0 +1,612 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Strategy parameters which can be tuned at runtime without restarting the node.
+//!
+//! Strategies register their tunable parameters with a [`ParameterService`], each with a type
+//! and optional bounds or choices. Updates are sent to the service as [`UpdateParameter`]
+//! commands on the parameter service endpoint (or applied directly, e.g. from the engine control
+//! gRPC service), validated against the parameter definition, and on success a
+//! [`ParameterChanged`] event is published on the strategy's parameters topic.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{data::Data, identifiers::StrategyId};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use ustr::Ustr;
+
+use crate::{
+    clock::Clock,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+
+/// The type of a strategy parameter.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ParameterKind {
+    Bool,
+    Int,
+    Float,
+    String,
+}
+
+/// Represents the value of a strategy parameter.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ParameterValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+impl ParameterValue {
+    #[must_use]
+    pub const fn kind(&self) -> ParameterKind {
+        match self {
+            Self::Bool(_) => ParameterKind::Bool,
+            Self::Int(_) => ParameterKind::Int,
+            Self::Float(_) => ParameterKind::Float,
+            Self::String(_) => ParameterKind::String,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float, for both `Int` and `Float` values.
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value converted to the given `kind`, widening an `Int` to a `Float`.
+    fn coerce(self, kind: ParameterKind) -> Option<Self> {
+        match (self, kind) {
+            (Self::Int(value), ParameterKind::Float) => Some(Self::Float(value as f64)),
+            (value, kind) if value.kind() == kind => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParameterValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Defines a strategy parameter, with the constraints applied to its updates.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParameterDefinition {
+    pub name: Ustr,
+    pub kind: ParameterKind,
+    /// The initial value of the parameter.
+    pub default: ParameterValue,
+    /// The minimum (inclusive) of a numeric parameter.
+    pub min: Option<f64>,
+    /// The maximum (inclusive) of a numeric parameter.
+    pub max: Option<f64>,
+    /// The allowed values of a string parameter (any value if empty).
+    pub choices: Vec<String>,
+}
+
+impl ParameterDefinition {
+    /// Creates a new [`ParameterDefinition`] instance, with the type of the `default` value.
+    #[must_use]
+    pub fn new(name: &str, default: ParameterValue) -> Self {
+        Self {
+            name: Ustr::from(name),
+            kind: default.kind(),
+            default,
+            min: None,
+            max: None,
+            choices: Vec::new(),
+        }
+    }
+
+    /// Sets the inclusive bounds of a numeric parameter.
+    #[must_use]
+    pub const fn with_bounds(mut self, min: Option<f64>, max: Option<f64>) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Sets the allowed values of a string parameter.
+    #[must_use]
+    pub fn with_choices(mut self, choices: &[&str]) -> Self {
+        self.choices = choices.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Returns the `value` converted to the parameter type, if valid for the parameter.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `value` is not of the parameter type.
+    /// - If a numeric `value` is outside the bounds.
+    /// - If a string `value` is not one of the choices.
+    pub fn validate(&self, value: ParameterValue) -> anyhow::Result<ParameterValue> {
+        let name = self.name;
+        let kind = value.kind();
+        let Some(value) = value.coerce(self.kind) else {
+            anyhow::bail!("Parameter '{name}' expected {}, was {kind}", self.kind);
+        };
+        if let Some(number) = value.as_f64() {
+            if let Some(min) = self.min.filter(|min| number < *min) {
+                anyhow::bail!("Parameter '{name}' value {value} below minimum {min}");
+            }
+            if let Some(max) = self.max.filter(|max| number > *max) {
+                anyhow::bail!("Parameter '{name}' value {value} above maximum {max}");
+            }
+            if number.is_nan() {
+                anyhow::bail!("Parameter '{name}' value was NaN");
+            }
+        }
+        if let Some(string) = value.as_str() {
+            if !self.choices.is_empty() && !self.choices.iter().any(|choice| choice == string) {
+                anyhow::bail!(
+                    "Parameter '{name}' value '{string}' not one of {:?}",
+                    self.choices
+                );
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Represents a command to update the value of a strategy parameter.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateParameter {
+    pub strategy_id: StrategyId,
+    pub name: Ustr,
+    pub value: ParameterValue,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl UpdateParameter {
+    /// Creates a new [`UpdateParameter`] instance.
+    #[must_use]
+    pub fn new(
+        strategy_id: StrategyId,
+        name: &str,
+        value: ParameterValue,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            strategy_id,
+            name: Ustr::from(name),
+            value,
+            command_id: UUID4::new(),
+            ts_init,
+        }
+    }
+}
+
+/// Represents a change in the value of a strategy parameter.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParameterChanged {
+    pub strategy_id: StrategyId,
+    pub name: Ustr,
+    pub old_value: ParameterValue,
+    pub new_value: ParameterValue,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for ParameterChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, name={}, old_value={}, new_value={})",
+            stringify!(ParameterChanged),
+            self.strategy_id,
+            self.name,
+            self.old_value,
+            self.new_value,
+        )
+    }
+}
+
+struct Parameter {
+    definition: ParameterDefinition,
+    value: ParameterValue,
+}
+
+struct StrategyParameters {
+    topic: Ustr,
+    parameters: IndexMap<Ustr, Parameter>,
+}
+
+/// Holds the registered strategy parameters, applying validated updates and publishing
+/// [`ParameterChanged`] events.
+pub struct ParameterService {
+    clock: Rc<RefCell<dyn Clock>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    strategies: RefCell<BTreeMap<StrategyId, StrategyParameters>>,
+}
+
+impl ParameterService {
+    /// Creates a new [`ParameterService`] instance.
+    #[must_use]
+    pub fn new(clock: Rc<RefCell<dyn Clock>>, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            clock,
+            msgbus,
+            strategies: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Registers the service on the parameter service endpoint, returning the handler.
+    pub fn start(self: &Rc<Self>) -> ShareableMessageHandler {
+        let handler = ShareableMessageHandler(Rc::new(ParameterServiceHandler {
+            id: Ustr::from("ParameterService"),
+            service: self.clone(),
+        }));
+        let mut msgbus = self.msgbus.borrow_mut();
+        let endpoint = msgbus.switchboard.parameter_service_update;
+        msgbus.register(endpoint, handler.clone());
+        handler
+    }
+
+    /// Deregisters the service from the parameter service endpoint.
+    pub fn stop(&self) {
+        let mut msgbus = self.msgbus.borrow_mut();
+        let endpoint = msgbus.switchboard.parameter_service_update;
+        msgbus.deregister(&endpoint);
+    }
+
+    /// Registers a parameter for the given `strategy_id`, with its default value.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the default value is invalid for the `definition`.
+    /// - If the parameter is already registered for the strategy.
+    pub fn register(
+        &self,
+        strategy_id: StrategyId,
+        definition: ParameterDefinition,
+    ) -> anyhow::Result<()> {
+        let value = definition.validate(definition.default.clone())?;
+        let mut strategies = self.strategies.borrow_mut();
+        let strategy = strategies.entry(strategy_id).or_insert_with(|| {
+            let topic = self
+                .msgbus
+                .borrow_mut()
+                .switchboard
+                .get_parameters_topic(strategy_id);
+            StrategyParameters {
+                topic,
+                parameters: IndexMap::new(),
+            }
+        });
+        if strategy.parameters.contains_key(&definition.name) {
+            anyhow::bail!(
+                "Parameter '{}' already registered for {strategy_id}",
+                definition.name
+            );
+        }
+        log::debug!(
+            "Registered parameter '{}' for {strategy_id} = {value}",
+            definition.name
+        );
+        strategy
+            .parameters
+            .insert(definition.name, Parameter { definition, value });
+        Ok(())
+    }
+
+    /// Deregisters all parameters for the given `strategy_id`.
+    pub fn deregister(&self, strategy_id: &StrategyId) {
+        self.strategies.borrow_mut().remove(strategy_id);
+    }
+
+    /// Returns the current value of the parameter `name` for the given `strategy_id`.
+    #[must_use]
+    pub fn value(&self, strategy_id: &StrategyId, name: &str) -> Option<ParameterValue> {
+        self.strategies
+            .borrow()
+            .get(strategy_id)?
+            .parameters
+            .get(&Ustr::from(name))
+            .map(|parameter| parameter.value.clone())
+    }
+
+    /// Returns the definitions and current values of the parameters for the given
+    /// `strategy_id`, in registration order.
+    #[must_use]
+    pub fn parameters(
+        &self,
+        strategy_id: &StrategyId,
+    ) -> Vec<(ParameterDefinition, ParameterValue)> {
+        self.strategies
+            .borrow()
+            .get(strategy_id)
+            .map(|strategy| {
+                strategy
+                    .parameters
+                    .values()
+                    .map(|parameter| (parameter.definition.clone(), parameter.value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Validates and applies the `command`, publishing a [`ParameterChanged`] event on the
+    /// strategy's parameters topic.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the parameter is not registered for the strategy.
+    /// - If the value is invalid for the parameter definition.
+    pub fn update(&self, command: UpdateParameter) -> anyhow::Result<ParameterChanged> {
+        let UpdateParameter {
+            strategy_id,
+            name,
+            value,
+            ..
+        } = command;
+        let (topic, event) = {
+            let mut strategies = self.strategies.borrow_mut();
+            let Some(strategy) = strategies.get_mut(&strategy_id) else {
+                anyhow::bail!("Parameter '{name}' not registered for {strategy_id}");
+            };
+            let Some(parameter) = strategy.parameters.get_mut(&name) else {
+                anyhow::bail!("Parameter '{name}' not registered for {strategy_id}");
+            };
+            let new_value = parameter.definition.validate(value)?;
+            let old_value = std::mem::replace(&mut parameter.value, new_value.clone());
+            let ts_now = self.clock.borrow().timestamp_ns();
+            let event = ParameterChanged {
+                strategy_id,
+                name,
+                old_value,
+                new_value,
+                event_id: UUID4::new(),
+                ts_event: ts_now,
+                ts_init: ts_now,
+            };
+            (strategy.topic, event)
+        };
+
+        log::info!("{event}");
+        self.msgbus.borrow().publish(&topic, &event as &dyn Any);
+        Ok(event)
+    }
+}
+
+/// Applies the [`UpdateParameter`] commands sent to the parameter service endpoint.
+struct ParameterServiceHandler {
+    id: Ustr,
+    service: Rc<ParameterService>,
+}
+
+impl MessageHandler for ParameterServiceHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let Some(command) = message.downcast_ref::<UpdateParameter>() else {
+            log::error!("Parameter service received unexpected message");
+            return;
+        };
+        if let Err(e) = self.service.update(command.clone()) {
+            log::error!("Rejected parameter update {}: {e}", command.command_id);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+
+    fn service() -> (Rc<ParameterService>, Rc<RefCell<MessageBus>>) {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        clock.borrow_mut().set_time(100.into());
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let service = Rc::new(ParameterService::new(clock, msgbus.clone()));
+        let strategy_id = StrategyId::from("EMACross-001");
+        service
+            .register(
+                strategy_id,
+                ParameterDefinition::new("threshold", ParameterValue::Float(0.5))
+                    .with_bounds(Some(0.0), Some(1.0)),
+            )
+            .unwrap();
+        service
+            .register(
+                strategy_id,
+                ParameterDefinition::new("mode", ParameterValue::String("passive".to_string()))
+                    .with_choices(&["passive", "aggressive"]),
+            )
+            .unwrap();
+        (service, msgbus)
+    }
+
+    fn update(name: &str, value: ParameterValue) -> UpdateParameter {
+        UpdateParameter::new(StrategyId::from("EMACross-001"), name, value, 0.into())
+    }
+
+    #[rstest]
+    #[case(ParameterValue::Float(0.5), Some(ParameterValue::Float(0.5)))]
+    #[case(ParameterValue::Int(1), Some(ParameterValue::Float(1.0)))]
+    #[case(ParameterValue::Float(1.5), None)]
+    #[case(ParameterValue::Float(f64::NAN), None)]
+    #[case(ParameterValue::Bool(true), None)]
+    fn test_validate_numeric(
+        #[case] value: ParameterValue,
+        #[case] expected: Option<ParameterValue>,
+    ) {
+        let definition = ParameterDefinition::new("threshold", ParameterValue::Float(0.0))
+            .with_bounds(Some(0.0), Some(1.0));
+
+        assert_eq!(definition.validate(value).ok(), expected);
+    }
+
+    #[rstest]
+    fn test_register_duplicate_and_invalid_default() {
+        let (service, _) = service();
+        let strategy_id = StrategyId::from("EMACross-001");
+
+        assert!(service
+            .register(
+                strategy_id,
+                ParameterDefinition::new("threshold", ParameterValue::Float(0.1))
+            )
+            .is_err());
+        assert!(service
+            .register(
+                strategy_id,
+                ParameterDefinition::new("size", ParameterValue::Int(-1))
+                    .with_bounds(Some(0.0), None)
+            )
+            .is_err());
+        assert_eq!(service.parameters(&strategy_id).len(), 2);
+    }
+
+    #[rstest]
+    fn test_update_publishes_change_event() {
+        let (service, msgbus) = service();
+        let strategy_id = StrategyId::from("EMACross-001");
+        let handler = get_message_saving_handler::<ParameterChanged>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.parameters.*", handler.clone(), None);
+
+        let event = service
+            .update(update("threshold", ParameterValue::Float(0.8)))
+            .unwrap();
+
+        assert_eq!(event.old_value, ParameterValue::Float(0.5));
+        assert_eq!(event.new_value, ParameterValue::Float(0.8));
+        assert_eq!(event.ts_event, UnixNanos::from(100));
+        assert_eq!(
+            service.value(&strategy_id, "threshold"),
+            Some(ParameterValue::Float(0.8))
+        );
+        assert_eq!(get_saved_messages::<ParameterChanged>(handler), vec![event]);
+    }
+
+    #[rstest]
+    #[case("threshold", ParameterValue::String("high".to_string()), "Parameter 'threshold' expected FLOAT, was STRING")]
+    #[case("mode", ParameterValue::String("random".to_string()), "Parameter 'mode' value 'random' not one of [\"passive\", \"aggressive\"]")]
+    #[case(
+        "size",
+        ParameterValue::Int(1),
+        "Parameter 'size' not registered for EMACross-001"
+    )]
+    fn test_update_rejected(
+        #[case] name: &str,
+        #[case] value: ParameterValue,
+        #[case] expected: &str,
+    ) {
+        let (service, _) = service();
+
+        let result = service.update(update(name, value));
+
+        assert_eq!(result.unwrap_err().to_string(), expected);
+        assert_eq!(
+            service.value(&StrategyId::from("EMACross-001"), "mode"),
+            Some(ParameterValue::String("passive".to_string()))
+        );
+    }
+
+    #[rstest]
+    fn test_update_sent_to_endpoint() {
+        let (service, msgbus) = service();
+        let strategy_id = StrategyId::from("EMACross-001");
+        service.start();
+        let endpoint = msgbus.borrow().switchboard.parameter_service_update;
+
+        let command = update("mode", ParameterValue::String("aggressive".to_string()));
+        msgbus.borrow().send(&endpoint, &command as &dyn Any);
+
+        assert_eq!(
+            service.value(&strategy_id, "mode"),
+            Some(ParameterValue::String("aggressive".to_string()))
+        );
+
+        service.stop();
+        assert!(!msgbus.borrow().is_registered(endpoint));
+    }
+}
@@ -0,0 +1,222 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tracking of venue-published price limits (price banding, limit-up/limit-down).
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    nanos::UnixNanos,
+};
+use nautilus_model::{identifiers::InstrumentId, types::price::Price};
+
+/// Represents a venue-published price band for an instrument.
+///
+/// Either limit may be absent for venues which only publish a one-sided band.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PriceBand {
+    pub instrument_id: InstrumentId,
+    pub lower: Option<Price>,
+    pub upper: Option<Price>,
+    pub ts_event: UnixNanos,
+}
+
+impl PriceBand {
+    /// Creates a new [`PriceBand`] instance with correctness checking.
+    pub fn new_checked(
+        instrument_id: InstrumentId,
+        lower: Option<Price>,
+        upper: Option<Price>,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        if let (Some(lower), Some(upper)) = (lower, upper) {
+            check_predicate_true(lower <= upper, "`lower` was greater than `upper`")?;
+        }
+
+        Ok(Self {
+            instrument_id,
+            lower,
+            upper,
+            ts_event,
+        })
+    }
+
+    /// Creates a new [`PriceBand`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        lower: Option<Price>,
+        upper: Option<Price>,
+        ts_event: UnixNanos,
+    ) -> Self {
+        Self::new_checked(instrument_id, lower, upper, ts_event).expect(FAILED)
+    }
+
+    /// Returns the breach of this band for the given `price` (if any).
+    #[must_use]
+    pub fn check_price(&self, price: Price) -> Option<PriceLimitBreach> {
+        if let Some(lower) = self.lower {
+            if price < lower {
+                return Some(PriceLimitBreach::BelowLower(lower));
+            }
+        }
+        if let Some(upper) = self.upper {
+            if price > upper {
+                return Some(PriceLimitBreach::AboveUpper(upper));
+            }
+        }
+        None
+    }
+}
+
+/// Represents a price which falls outside of a price band.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceLimitBreach {
+    /// The price was below the lower limit.
+    BelowLower(Price),
+    /// The price was above the upper limit.
+    AboveUpper(Price),
+}
+
+impl Display for PriceLimitBreach {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BelowLower(lower) => write!(f, "below lower price limit {lower}"),
+            Self::AboveUpper(upper) => write!(f, "above upper price limit {upper}"),
+        }
+    }
+}
+
+/// Tracks the current price bands published by venues, per instrument.
+#[derive(Clone, Debug, Default)]
+pub struct PriceLimits {
+    bands: HashMap<InstrumentId, PriceBand>,
+}
+
+impl PriceLimits {
+    /// Creates a new empty [`PriceLimits`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the price band for the band's instrument, replacing any previous band.
+    pub fn update(&mut self, band: PriceBand) {
+        self.bands.insert(band.instrument_id, band);
+    }
+
+    /// Removes the price band for the given `instrument_id` (if found).
+    pub fn remove(&mut self, instrument_id: &InstrumentId) -> Option<PriceBand> {
+        self.bands.remove(instrument_id)
+    }
+
+    /// Clears all price bands.
+    pub fn clear(&mut self) {
+        self.bands.clear();
+    }
+
+    /// Returns the current price band for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn band(&self, instrument_id: &InstrumentId) -> Option<&PriceBand> {
+        self.bands.get(instrument_id)
+    }
+
+    /// Returns the breach of the current price band for the given `instrument_id` and `price`,
+    /// or `None` if within the band or no band is being tracked.
+    #[must_use]
+    pub fn check_price(
+        &self,
+        instrument_id: &InstrumentId,
+        price: Price,
+    ) -> Option<PriceLimitBreach> {
+        self.band(instrument_id)
+            .and_then(|band| band.check_price(price))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn band(lower: Option<&str>, upper: Option<&str>) -> PriceBand {
+        PriceBand::new(
+            InstrumentId::from("ESZ21.GLBX"),
+            lower.map(Price::from),
+            upper.map(Price::from),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`lower` was greater than `upper`")]
+    fn test_band_with_inverted_limits() {
+        let _ = band(Some("4510.00"), Some("4490.00"));
+    }
+
+    #[rstest]
+    #[case("4489.75", Some(PriceLimitBreach::BelowLower(Price::from("4490.00"))))]
+    #[case("4490.00", None)]
+    #[case("4510.00", None)]
+    #[case("4510.25", Some(PriceLimitBreach::AboveUpper(Price::from("4510.00"))))]
+    fn test_check_price(#[case] price: &str, #[case] expected: Option<PriceLimitBreach>) {
+        let mut limits = PriceLimits::new();
+        limits.update(band(Some("4490.00"), Some("4510.00")));
+
+        let instrument_id = InstrumentId::from("ESZ21.GLBX");
+        assert_eq!(
+            limits.check_price(&instrument_id, Price::from(price)),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_check_price_with_one_sided_band() {
+        let mut limits = PriceLimits::new();
+        limits.update(band(None, Some("4510.00")));
+
+        let instrument_id = InstrumentId::from("ESZ21.GLBX");
+        assert_eq!(
+            limits.check_price(&instrument_id, Price::from("1.00")),
+            None
+        );
+        assert_eq!(
+            limits
+                .check_price(&instrument_id, Price::from("4520.00"))
+                .unwrap()
+                .to_string(),
+            "above upper price limit 4510.00"
+        );
+    }
+
+    #[rstest]
+    fn test_check_price_without_band() {
+        let mut limits = PriceLimits::new();
+        let instrument_id = InstrumentId::from("ESZ21.GLBX");
+        limits.update(band(Some("4490.00"), Some("4510.00")));
+        limits.remove(&instrument_id);
+
+        assert!(limits.band(&instrument_id).is_none());
+        assert_eq!(
+            limits.check_price(&instrument_id, Price::from("1.00")),
+            None
+        );
+    }
+}
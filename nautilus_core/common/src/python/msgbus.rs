@@ -50,7 +50,7 @@ impl MessageBus {
     #[pyo3(name = "publish")]
     pub fn publish_py(&self, topic: &str, message: PyObject) {
         let topic = Ustr::from(topic);
-        let matching_subs = self.matching_subscriptions(&topic);
+        let matching_subs = self.published_subscriptions(&topic);
 
         for sub in matching_subs {
             sub.handler.0.handle(&message);
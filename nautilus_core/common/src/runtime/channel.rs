@@ -0,0 +1,371 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A bounded lock-free single-producer single-consumer (SPSC) channel.
+//!
+//! The channel is a fixed-capacity ring buffer of slots for values of a single type, intended
+//! for fixed-size data enums passed between the data engine and its consumers. Unlike
+//! `tokio::sync::mpsc`, pushing never allocates or takes a lock: the producer and consumer each
+//! own one index and only synchronize through atomic loads and stores of the other's index.
+//!
+//! The consumer can be polled synchronously with [`Consumer::pop`], or awaited with
+//! [`Consumer::recv`] which parks on a [`Notify`] until a value is pushed.
+//!
+//! Throughput and latency against `tokio::sync::mpsc` are measured by the `bench_channel`
+//! criterion benchmarks of this crate:
+//!  - `channel_throughput` pushes a batch of values from a producer thread and drains them on
+//!    the benchmark thread, measuring sustained transfer rate.
+//!  - `channel_latency` measures a single push followed by a pop on the same thread, the
+//!    per-message overhead of the queue itself.
+
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use tokio::sync::Notify;
+
+/// Pads and aligns a value to the length of a cache line, preventing false sharing between
+/// the producer and consumer indexes.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+struct Shared<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    mask: usize,
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+    closed: AtomicBool,
+    notify: Notify,
+}
+
+// SAFETY: Each slot is accessed by only one side at a time, as ownership of a slot is handed
+// between the producer and consumer through release/acquire stores of the head and tail.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let head = *self.head.0.get_mut();
+        let tail = *self.tail.0.get_mut();
+        for index in head..tail {
+            // SAFETY: Slots between the head and tail were written and never read
+            unsafe { self.buffer[index & self.mask].get_mut().assume_init_drop() };
+        }
+    }
+}
+
+/// Provides the sending half of an SPSC channel.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+    tail: usize,
+    cached_head: usize,
+}
+
+/// Provides the receiving half of an SPSC channel.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+    head: usize,
+    cached_tail: usize,
+}
+
+/// Creates a new bounded SPSC channel, returning the producer and consumer halves.
+///
+/// The `capacity` is rounded up to the next power of two.
+///
+/// # Panics
+///
+/// This function panics if `capacity` is zero.
+#[must_use]
+pub fn channel<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(capacity > 0, "Channel capacity must be positive");
+    let capacity = capacity.next_power_of_two();
+    let buffer = (0..capacity)
+        .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+        .collect();
+    let shared = Arc::new(Shared {
+        buffer,
+        mask: capacity - 1,
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+        closed: AtomicBool::new(false),
+        notify: Notify::new(),
+    });
+
+    let producer = Producer {
+        shared: shared.clone(),
+        tail: 0,
+        cached_head: 0,
+    };
+    let consumer = Consumer {
+        shared,
+        head: 0,
+        cached_tail: 0,
+    };
+    (producer, consumer)
+}
+
+impl<T> Producer<T> {
+    /// Pushes the `value` onto the channel.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the `value` back as an error if the channel is full.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.tail - self.cached_head == self.capacity() {
+            self.cached_head = self.shared.head.load(Ordering::Acquire);
+            if self.tail - self.cached_head == self.capacity() {
+                return Err(value);
+            }
+        }
+
+        let slot = &self.shared.buffer[self.tail & self.shared.mask];
+        // SAFETY: The slot is not visible to the consumer until the tail is released below
+        unsafe { (*slot.get()).write(value) };
+        self.tail += 1;
+        self.shared.tail.store(self.tail, Ordering::Release);
+        self.shared.notify.notify_one();
+        Ok(())
+    }
+
+    /// Returns the capacity of the channel.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.shared.buffer.len()
+    }
+
+    /// Returns whether the consumer half has been dropped.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+}
+
+impl<T> Drop for Producer<T> {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::Release);
+        self.shared.notify.notify_one();
+    }
+}
+
+impl<T> Consumer<T> {
+    /// Pops the next value from the channel, or returns `None` if the channel is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.head == self.cached_tail {
+            self.cached_tail = self.shared.tail.load(Ordering::Acquire);
+            if self.head == self.cached_tail {
+                return None;
+            }
+        }
+
+        let slot = &self.shared.buffer[self.head & self.shared.mask];
+        // SAFETY: The slot was written by the producer before the tail was released
+        let value = unsafe { (*slot.get()).assume_init_read() };
+        self.head += 1;
+        self.shared.head.store(self.head, Ordering::Release);
+        Some(value)
+    }
+
+    /// Receives the next value from the channel, waiting until one is pushed.
+    ///
+    /// Returns `None` once the producer half has been dropped and the channel is empty.
+    pub async fn recv(&mut self) -> Option<T> {
+        loop {
+            if let Some(value) = self.pop() {
+                return Some(value);
+            }
+            if self.shared.closed.load(Ordering::Acquire) {
+                // Values may have been pushed before the producer was dropped
+                return self.pop();
+            }
+            self.shared.notify.notified().await;
+        }
+    }
+
+    /// Returns the number of values currently in the channel.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shared.tail.load(Ordering::Acquire) - self.head
+    }
+
+    /// Returns whether the channel is currently empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the channel.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.shared.buffer.len()
+    }
+}
+
+impl<T> Drop for Consumer<T> {
+    fn drop(&mut self) {
+        // Drain remaining values so the shared buffer only drops what was never received
+        while self.pop().is_some() {}
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(3, 4)]
+    #[case(1024, 1024)]
+    fn test_capacity_rounds_to_power_of_two(#[case] capacity: usize, #[case] expected: usize) {
+        let (producer, consumer) = channel::<u64>(capacity);
+
+        assert_eq!(producer.capacity(), expected);
+        assert_eq!(consumer.capacity(), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Channel capacity must be positive")]
+    fn test_zero_capacity_panics() {
+        let _ = channel::<u64>(0);
+    }
+
+    #[rstest]
+    fn test_push_pop_in_order() {
+        let (mut producer, mut consumer) = channel(4);
+
+        producer.push(1).unwrap();
+        producer.push(2).unwrap();
+        producer.push(3).unwrap();
+
+        assert_eq!(consumer.len(), 3);
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+        assert_eq!(consumer.pop(), None);
+        assert!(consumer.is_empty());
+    }
+
+    #[rstest]
+    fn test_push_when_full_returns_value() {
+        let (mut producer, mut consumer) = channel(2);
+        producer.push(1).unwrap();
+        producer.push(2).unwrap();
+
+        assert_eq!(producer.push(3), Err(3));
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(producer.push(3), Ok(()));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+    }
+
+    #[rstest]
+    fn test_wraps_around_buffer() {
+        let (mut producer, mut consumer) = channel(4);
+
+        for i in 0..100 {
+            producer.push(i).unwrap();
+            assert_eq!(consumer.pop(), Some(i));
+        }
+    }
+
+    #[rstest]
+    fn test_unreceived_values_are_dropped() {
+        let value = Arc::new(());
+        let (mut producer, consumer) = channel(4);
+        producer.push(value.clone()).unwrap();
+        producer.push(value.clone()).unwrap();
+
+        drop(producer);
+        drop(consumer);
+
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+
+    #[rstest]
+    fn test_producer_is_closed_when_consumer_dropped() {
+        let (producer, consumer) = channel::<u64>(4);
+        assert!(!producer.is_closed());
+
+        drop(consumer);
+
+        assert!(producer.is_closed());
+    }
+
+    #[rstest]
+    fn test_transfer_across_threads() {
+        let (mut producer, mut consumer) = channel(64);
+        let count = 10_000;
+
+        let handle = thread::spawn(move || {
+            for i in 0..count {
+                let mut value = i;
+                while let Err(v) = producer.push(value) {
+                    value = v;
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut expected = 0;
+        while expected < count {
+            match consumer.pop() {
+                Some(value) => {
+                    assert_eq!(value, expected);
+                    expected += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+        handle.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recv_until_producer_dropped() {
+        let (mut producer, mut consumer) = channel(4);
+
+        let handle = tokio::spawn(async move {
+            for i in 0..3 {
+                producer.push(i).unwrap();
+                tokio::task::yield_now().await;
+            }
+        });
+
+        assert_eq!(consumer.recv().await, Some(0));
+        assert_eq!(consumer.recv().await, Some(1));
+        assert_eq!(consumer.recv().await, Some(2));
+        assert_eq!(consumer.recv().await, None);
+        handle.await.unwrap();
+    }
+}
@@ -15,6 +15,8 @@
 
 //! The centralized Tokio runtime for a running Nautilus system.
 
+pub mod channel;
+
 use std::sync::OnceLock;
 
 use tokio::runtime::Runtime;
@@ -0,0 +1,515 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Cron-like schedules for recurring time events, such as open and close routines or periodic
+//! rebalancing.
+//!
+//! A [`CronSchedule`] is parsed from a standard five field cron expression
+//! (`minute hour day-of-month month day-of-week`), evaluated in UTC, in a time zone, or in the
+//! local time of a [`SessionCalendar`] restricted to the days with a trading session, e.g.
+//! `29 9 * * *` with the NYSE calendar fires every trading day at 09:29 New York time.
+//! A [`CronTimer`] sets a time alert on a clock at each scheduled time, delivering a
+//! `TimeEvent` to its callback.
+
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    rc::Rc,
+    str::FromStr,
+};
+
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+
+use crate::{
+    clock::Clock,
+    session::SessionCalendar,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+// The number of days searched for the next scheduled time, covering a leap day every 8 years
+const MAX_SEARCH_DAYS: i64 = 8 * 366;
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// The set of values matched by a single field of a cron expression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CronField {
+    bits: u64,
+    is_wildcard: bool,
+}
+
+impl CronField {
+    /// Parses the field `value` with values from `min` to `max` (inclusive), optionally
+    /// given by `names` starting at `min`.
+    fn parse(value: &str, field: &str, min: u32, max: u32, names: &[&str]) -> anyhow::Result<Self> {
+        let parse_value = |value: &str| -> anyhow::Result<u32> {
+            let upper = value.to_ascii_uppercase();
+            if let Some(index) = names.iter().position(|name| *name == upper) {
+                return Ok(min + index as u32);
+            }
+            let number: u32 = value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid {field} value '{value}'"))?;
+            if number < min || number > max {
+                anyhow::bail!("Invalid {field} value {number}, expected {min} to {max}");
+            }
+            Ok(number)
+        };
+
+        let mut bits = 0;
+        for part in value.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 = step
+                        .parse()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| anyhow::anyhow!("Invalid {field} step '{step}'"))?;
+                    (range, step)
+                }
+                None => (part, 1),
+            };
+            let (start, end) = match range {
+                "*" => (min, max),
+                _ => match range.split_once('-') {
+                    Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+                    // A single value with a step runs to the maximum, e.g. `5/15`
+                    None if step > 1 => (parse_value(range)?, max),
+                    None => {
+                        let value = parse_value(range)?;
+                        (value, value)
+                    }
+                },
+            };
+            if start > end {
+                anyhow::bail!("Invalid {field} range '{range}'");
+            }
+            for value in (start..=end).step_by(step as usize) {
+                bits |= 1 << value;
+            }
+        }
+
+        Ok(Self {
+            bits,
+            is_wildcard: value == "*",
+        })
+    }
+
+    const fn contains(&self, value: u32) -> bool {
+        self.bits & (1 << value) != 0
+    }
+
+    fn values(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..64).filter(|value| self.contains(*value))
+    }
+}
+
+/// Represents a cron-like schedule of recurring times.
+///
+/// The day of month and day of week fields follow standard cron semantics: when both are
+/// restricted, a day matching either field is scheduled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: CronField,
+    hours: CronField,
+    days_of_month: CronField,
+    months: CronField,
+    days_of_week: CronField,
+    calendar: SessionCalendar,
+    trading_days_only: bool,
+}
+
+impl CronSchedule {
+    /// Creates a new [`CronSchedule`] instance from a five field cron `expression`, in UTC.
+    ///
+    /// Months and days of week may be given by their three letter names (e.g. `MON-FRI`),
+    /// and a day of week of 0 or 7 is Sunday.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `expression` is invalid.
+    pub fn new(expression: &str) -> anyhow::Result<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields.as_slice() else {
+            anyhow::bail!(
+                "Invalid cron expression '{expression}', expected 5 fields, was {}",
+                fields.len()
+            );
+        };
+
+        let mut days_of_week = CronField::parse(days_of_week, "day of week", 0, 7, &WEEKDAY_NAMES)?;
+        if days_of_week.contains(7) {
+            days_of_week.bits = (days_of_week.bits | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes: CronField::parse(minutes, "minute", 0, 59, &[])?,
+            hours: CronField::parse(hours, "hour", 0, 23, &[])?,
+            days_of_month: CronField::parse(days_of_month, "day of month", 1, 31, &[])?,
+            months: CronField::parse(months, "month", 1, 12, &MONTH_NAMES)?,
+            days_of_week,
+            calendar: SessionCalendar::new(0),
+            trading_days_only: false,
+        })
+    }
+
+    /// Returns the schedule evaluated in the given `timezone`.
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.calendar = self.calendar.with_timezone(timezone);
+        self
+    }
+
+    /// Returns the schedule evaluated in the local time of the given `calendar`, scheduled
+    /// only on the local days with a trading session opening.
+    #[must_use]
+    pub fn with_calendar(mut self, calendar: SessionCalendar) -> Self {
+        self.calendar = calendar;
+        self.trading_days_only = true;
+        self
+    }
+
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns the first scheduled time after `ts` (if any within the next eight years).
+    #[must_use]
+    pub fn next_after(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let local_day = self.calendar.local_day(ts);
+        (local_day..=local_day + MAX_SEARCH_DAYS)
+            .filter(|day| self.is_scheduled_day(*day))
+            .find_map(|day| {
+                self.hours
+                    .values()
+                    .flat_map(|hour| self.minutes.values().map(move |min| hour * 3600 + min * 60))
+                    .filter_map(|secs| self.calendar.local_to_utc_secs(day, secs))
+                    .filter_map(|secs| u64::try_from(secs).ok())
+                    .map(|secs| UnixNanos::from(secs * NANOSECONDS_IN_SECOND))
+                    .find(|next| *next > ts)
+            })
+    }
+
+    /// Returns the scheduled times after `start` up to and including `end`, in ascending order.
+    #[must_use]
+    pub fn times(&self, start: UnixNanos, end: UnixNanos) -> Vec<UnixNanos> {
+        std::iter::successors(self.next_after(start), |ts| self.next_after(*ts))
+            .take_while(|ts| *ts <= end)
+            .collect()
+    }
+
+    // Returns whether the local `day` (days since the UNIX epoch) is scheduled
+    fn is_scheduled_day(&self, day: i64) -> bool {
+        let Some(date) = i32::try_from(day)
+            .ok()
+            .and_then(|day| NaiveDate::from_num_days_from_ce_opt(day + UNIX_EPOCH_DAYS_FROM_CE))
+        else {
+            return false;
+        };
+        let weekday = date.weekday();
+        if !self.months.contains(date.month()) {
+            return false;
+        }
+        if self.trading_days_only && self.calendar.session(weekday).is_none() {
+            return false;
+        }
+
+        let day_of_month = self.days_of_month.contains(date.day());
+        let day_of_week = self.days_of_week.contains(weekday.num_days_from_sunday());
+        match (
+            self.days_of_month.is_wildcard,
+            self.days_of_week.is_wildcard,
+        ) {
+            (true, _) => day_of_week,
+            (false, true) => day_of_month,
+            (false, false) => day_of_month || day_of_week,
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+/// Sets a time alert on a clock at each time of a [`CronSchedule`], delivering a
+/// [`TimeEvent`] named for the timer to its callback.
+///
+/// Only the next scheduled time is set on the clock, and the alert is set again for the
+/// following time as each event is handled. Scheduled times already passed by the clock
+/// when an event is handled are skipped.
+///
+/// The clock only holds a weak reference to the timer, so alerts stop once the last
+/// [`Rc`] of the timer is dropped.
+pub struct CronTimer {
+    name: String,
+    schedule: CronSchedule,
+    clock: Rc<RefCell<dyn Clock>>,
+    callback: TimeEventCallback,
+}
+
+impl CronTimer {
+    /// Creates a new [`CronTimer`] instance.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        schedule: CronSchedule,
+        clock: Rc<RefCell<dyn Clock>>,
+        callback: TimeEventCallback,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            schedule,
+            clock,
+            callback,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn schedule(&self) -> &CronSchedule {
+        &self.schedule
+    }
+
+    /// Starts the timer, setting a time alert at the next scheduled time after the current
+    /// time, returning that time (if any).
+    pub fn start(self: &Rc<Self>) -> Option<UnixNanos> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let next = self.set_next_alert(ts_now);
+        log::info!(
+            "Started cron timer {} '{}', next at {next:?}",
+            self.name,
+            self.schedule
+        );
+        next
+    }
+
+    /// Stops the timer, cancelling its pending time alert.
+    pub fn stop(&self) {
+        let mut clock = self.clock.borrow_mut();
+        if clock.timer_names().contains(&self.name.as_str()) {
+            clock.cancel_timer(&self.name);
+        }
+    }
+
+    fn set_next_alert(self: &Rc<Self>, ts: UnixNanos) -> Option<UnixNanos> {
+        let next = self.schedule.next_after(ts)?;
+        let timer = Rc::downgrade(self);
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let Some(timer) = timer.upgrade() else {
+                return;
+            };
+            let ts_now = timer.clock.borrow().timestamp_ns();
+            timer.set_next_alert(event.ts_event.max(ts_now));
+            timer.callback.call(event);
+        }));
+        self.clock
+            .borrow_mut()
+            .set_time_alert_ns(&self.name, next, Some(callback));
+        Some(next)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use chrono::{TimeZone, Utc};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, session::TradingSession};
+
+    fn utc(month: u32, day: u32, hour: u32, min: u32) -> UnixNanos {
+        // January 1st 2024 was a Monday
+        let dt = Utc
+            .with_ymd_and_hms(2024, month, day, hour, min, 0)
+            .unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn nyse() -> SessionCalendar {
+        SessionCalendar::weekdays(TradingSession::new(9 * 3600 + 30 * 60, 16 * 3600), 0)
+            .with_timezone(chrono_tz::America::New_York)
+    }
+
+    #[rstest]
+    #[case(
+        "* * * *",
+        "Invalid cron expression '* * * *', expected 5 fields, was 4"
+    )]
+    #[case("60 * * * *", "Invalid minute value 60, expected 0 to 59")]
+    #[case("0 9 * * XYZ", "Invalid day of week value 'XYZ'")]
+    #[case("*/0 * * * *", "Invalid minute step '0'")]
+    #[case("0 17-9 * * *", "Invalid hour range '17-9'")]
+    fn test_parse_errors(#[case] expression: &str, #[case] expected: &str) {
+        assert_eq!(
+            CronSchedule::new(expression).unwrap_err().to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("*/15 * * * *", utc(1, 1, 10, 7), utc(1, 1, 10, 15))]
+    #[case("0 9,17 * * *", utc(1, 1, 9, 0), utc(1, 1, 17, 0))]
+    #[case("30 9 * * MON-FRI", utc(1, 5, 10, 0), utc(1, 8, 9, 30))]
+    #[case("0 0 1 * *", utc(1, 15, 0, 0), utc(2, 1, 0, 0))]
+    #[case("0 0 29 FEB *", utc(1, 1, 0, 0), utc(2, 29, 0, 0))]
+    #[case("0 12 * * 7", utc(1, 1, 0, 0), utc(1, 7, 12, 0))]
+    // Day of month or day of week when both are restricted
+    #[case("0 0 15 * MON", utc(1, 2, 0, 0), utc(1, 8, 0, 0))]
+    fn test_next_after(
+        #[case] expression: &str,
+        #[case] ts: UnixNanos,
+        #[case] expected: UnixNanos,
+    ) {
+        let schedule = CronSchedule::new(expression).unwrap();
+
+        assert_eq!(schedule.next_after(ts), Some(expected));
+    }
+
+    #[rstest]
+    fn test_timezone_follows_dst() {
+        let schedule = CronSchedule::new("29 9 * * *")
+            .unwrap()
+            .with_timezone(chrono_tz::America::New_York);
+
+        // EST (UTC-5) before and EDT (UTC-4) after March 10th 2024
+        assert_eq!(
+            schedule.times(utc(3, 8, 0, 0), utc(3, 12, 0, 0)),
+            vec![
+                utc(3, 8, 14, 29),
+                utc(3, 9, 14, 29),
+                utc(3, 10, 13, 29),
+                utc(3, 11, 13, 29),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_calendar_schedules_trading_days_only() {
+        let schedule = CronSchedule::new("29 9 * * *")
+            .unwrap()
+            .with_calendar(nyse());
+
+        // Friday 5th to Tuesday 9th January
+        assert_eq!(
+            schedule.times(utc(1, 5, 0, 0), utc(1, 10, 0, 0)),
+            vec![utc(1, 5, 14, 29), utc(1, 8, 14, 29), utc(1, 9, 14, 29)]
+        );
+    }
+
+    #[rstest]
+    fn test_cron_timer_sets_alert_after_each_event() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        clock.borrow_mut().set_time(utc(1, 5, 0, 0));
+        let count = Rc::new(Cell::new(0));
+        let callback_count = count.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            assert_eq!(event.name.as_str(), "MarketOpen");
+            callback_count.set(callback_count.get() + 1);
+        }));
+        let schedule = CronSchedule::new("29 9 * * *")
+            .unwrap()
+            .with_calendar(nyse());
+        let timer = Rc::new(CronTimer::new(
+            "MarketOpen",
+            schedule,
+            clock.clone(),
+            callback,
+        ));
+
+        assert_eq!(timer.start(), Some(utc(1, 5, 14, 29)));
+
+        for to_time in [utc(1, 6, 0, 0), utc(1, 9, 0, 0)] {
+            let handlers = {
+                let mut clock = clock.borrow_mut();
+                let events = clock.advance_time(to_time, true);
+                clock.match_handlers(events)
+            };
+            for handler in handlers {
+                handler.run();
+            }
+        }
+
+        assert_eq!(count.get(), 2);
+        assert_eq!(clock.borrow().next_time_ns("MarketOpen"), utc(1, 9, 14, 29));
+
+        timer.stop();
+        assert_eq!(clock.borrow().timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_cron_timer_skips_times_passed_by_clock() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        clock.borrow_mut().set_time(utc(1, 5, 0, 0));
+        let count = Rc::new(Cell::new(0));
+        let callback_count = count.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_: TimeEvent| {
+            callback_count.set(callback_count.get() + 1);
+        }));
+        let schedule = CronSchedule::new("29 9 * * *")
+            .unwrap()
+            .with_calendar(nyse());
+        let timer = Rc::new(CronTimer::new(
+            "MarketOpen",
+            schedule,
+            clock.clone(),
+            callback,
+        ));
+        timer.start();
+
+        let handlers = {
+            let mut clock = clock.borrow_mut();
+            let events = clock.advance_time(utc(1, 10, 0, 0), true);
+            clock.match_handlers(events)
+        };
+        for handler in handlers {
+            handler.run();
+        }
+
+        assert_eq!(count.get(), 1);
+        assert_eq!(
+            clock.borrow().next_time_ns("MarketOpen"),
+            utc(1, 10, 14, 29)
+        );
+        assert_eq!(Rc::strong_count(&timer), 1);
+    }
+}
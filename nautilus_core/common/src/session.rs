@@ -0,0 +1,461 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Session calendars describing the regular trading hours of a venue.
+//!
+//! A calendar is either at a fixed UTC offset, or in the venue's time zone so that sessions
+//! follow daylight saving time. Daily and weekly bars can be cut at the venue's session close
+//! (e.g. 17:00 America/Chicago for CME) with [`SessionCalendar::session_bar_close`].
+
+use chrono::{DateTime, LocalResult, NaiveDate, Offset, TimeDelta, TimeZone, Weekday};
+use chrono_tz::Tz;
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    datetime::NANOSECONDS_IN_SECOND,
+    nanos::UnixNanos,
+};
+use nautilus_model::enums::{BarAggregation, TimeInForce};
+use serde::{Deserialize, Serialize};
+
+const SECONDS_IN_DAY: i64 = 86_400;
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// Represents the regular trading hours for a single trading day.
+///
+/// Times are seconds after local midnight. A `close` at or before `open` describes an
+/// overnight session which closes on the following day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TradingSession {
+    pub open: u32,
+    pub close: u32,
+}
+
+impl TradingSession {
+    /// Creates a new [`TradingSession`] instance with correctness checking.
+    pub fn new_checked(open: u32, close: u32) -> anyhow::Result<Self> {
+        check_predicate_true(
+            i64::from(open) < SECONDS_IN_DAY,
+            "`open` was not within the day",
+        )?;
+        check_predicate_true(
+            i64::from(close) < SECONDS_IN_DAY,
+            "`close` was not within the day",
+        )?;
+        check_predicate_true(open != close, "`open` was equal to `close`")?;
+
+        Ok(Self { open, close })
+    }
+
+    /// Creates a new [`TradingSession`] instance.
+    #[must_use]
+    pub fn new(open: u32, close: u32) -> Self {
+        Self::new_checked(open, close).expect(FAILED)
+    }
+
+    /// Returns whether the session closes on the day after it opens.
+    #[must_use]
+    pub const fn is_overnight(&self) -> bool {
+        self.close < self.open
+    }
+}
+
+/// A weekly calendar of trading sessions at a fixed UTC offset, or in a time zone.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionCalendar {
+    /// The offset of the venue's local time from UTC (seconds), unused with a `timezone`.
+    pub utc_offset_secs: i32,
+    /// The time zone of the venue's local time (if any).
+    #[serde(default)]
+    pub timezone: Option<Tz>,
+    sessions: [Option<TradingSession>; 7],
+}
+
+impl SessionCalendar {
+    /// Creates a new [`SessionCalendar`] instance with no trading sessions.
+    #[must_use]
+    pub fn new(utc_offset_secs: i32) -> Self {
+        Self {
+            utc_offset_secs,
+            timezone: None,
+            sessions: [None; 7],
+        }
+    }
+
+    /// Creates a new [`SessionCalendar`] instance trading the same session Monday to Friday.
+    #[must_use]
+    pub fn weekdays(session: TradingSession, utc_offset_secs: i32) -> Self {
+        let mut calendar = Self::new(utc_offset_secs);
+        for weekday in [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ] {
+            calendar.set_session(weekday, Some(session));
+        }
+        calendar
+    }
+
+    /// Returns the calendar with session times in the given `timezone`, which replaces the
+    /// fixed UTC offset.
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Sets the trading session opening on the given `weekday` (`None` for no session).
+    pub fn set_session(&mut self, weekday: Weekday, session: Option<TradingSession>) {
+        self.sessions[weekday.num_days_from_monday() as usize] = session;
+    }
+
+    /// Returns the trading session opening on the given `weekday` (if any).
+    #[must_use]
+    pub fn session(&self, weekday: Weekday) -> Option<TradingSession> {
+        self.sessions[weekday.num_days_from_monday() as usize]
+    }
+
+    /// Returns whether the venue is within a trading session at `ts`.
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.session_bounds(ts, 1, 7)
+            .any(|(open, close)| open <= ts && ts < close)
+    }
+
+    /// Returns the first session open at or after `ts` (if any sessions are defined).
+    #[must_use]
+    pub fn next_open(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.session_bounds(ts, 1, 7)
+            .map(|(open, _)| open)
+            .find(|open| *open >= ts)
+    }
+
+    /// Returns the first session close at or after `ts` (if any sessions are defined).
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.session_bounds(ts, 1, 7)
+            .map(|(_, close)| close)
+            .find(|close| *close >= ts)
+    }
+
+    /// Returns the last session close of the trading week at or after `ts` (if any sessions
+    /// are defined).
+    ///
+    /// The trading week closes with the last session close before a close in a later local
+    /// (Monday to Sunday) week.
+    #[must_use]
+    pub fn next_week_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let closes: Vec<UnixNanos> = self
+            .session_bounds(ts, 1, 14)
+            .map(|(_, close)| close)
+            .filter(|close| *close >= ts)
+            .collect();
+        closes
+            .windows(2)
+            .find(|pair| self.local_week(pair[0]) != self.local_week(pair[1]))
+            .map(|pair| pair[0])
+    }
+
+    /// Returns the close of the session aligned bar containing `ts`, for `Day` and `Week`
+    /// aggregations (otherwise `None`).
+    ///
+    /// Daily bars close at each session close and weekly bars at the close of the trading
+    /// week, in the venue's local time rather than at UTC midnight. A `ts` at a close is
+    /// within the bar closing at that time.
+    #[must_use]
+    pub fn session_bar_close(
+        &self,
+        ts: UnixNanos,
+        aggregation: BarAggregation,
+    ) -> Option<UnixNanos> {
+        match aggregation {
+            BarAggregation::Day => self.next_close(ts),
+            BarAggregation::Week => self.next_week_close(ts),
+            _ => None,
+        }
+    }
+
+    // Yields the (open, close) bounds of every session opening from `days_before` the local
+    // day of `ts` through `days_after` it, in ascending order.
+    fn session_bounds(
+        &self,
+        ts: UnixNanos,
+        days_before: i64,
+        days_after: i64,
+    ) -> impl Iterator<Item = (UnixNanos, UnixNanos)> + '_ {
+        let local_day = self.local_day(ts);
+
+        (local_day - days_before..=local_day + days_after).filter_map(move |day| {
+            // 1970-01-01 was a Thursday
+            let weekday = (day + 3).rem_euclid(7) as usize;
+            let session = self.sessions[weekday]?;
+            let close_day = if session.is_overnight() { day + 1 } else { day };
+            let open = self.local_to_utc_secs(day, session.open)?;
+            let close = self.local_to_utc_secs(close_day, session.close)?;
+            let to_nanos = |secs: i64| {
+                u64::try_from(secs)
+                    .ok()
+                    .map(|secs| UnixNanos::from(secs * NANOSECONDS_IN_SECOND))
+            };
+            Some((to_nanos(open)?, to_nanos(close)?))
+        })
+    }
+
+    // Returns the offset of local time from UTC at `utc_secs` (seconds)
+    fn offset_secs(&self, utc_secs: i64) -> i64 {
+        match (self.timezone, DateTime::from_timestamp(utc_secs, 0)) {
+            (Some(tz), Some(dt)) => i64::from(
+                tz.offset_from_utc_datetime(&dt.naive_utc())
+                    .fix()
+                    .local_minus_utc(),
+            ),
+            _ => i64::from(self.utc_offset_secs),
+        }
+    }
+
+    /// Returns the local day (days since the UNIX epoch) of `ts`.
+    pub(crate) fn local_day(&self, ts: UnixNanos) -> i64 {
+        let utc_secs = ts.as_i64().div_euclid(NANOSECONDS_IN_SECOND as i64);
+        (utc_secs + self.offset_secs(utc_secs)).div_euclid(SECONDS_IN_DAY)
+    }
+
+    // Returns the local week (weeks since the Monday before the UNIX epoch) of `ts`
+    fn local_week(&self, ts: UnixNanos) -> i64 {
+        (self.local_day(ts) + 3).div_euclid(7)
+    }
+
+    /// Converts `secs` after local midnight of the local `day` to UTC (seconds).
+    pub(crate) fn local_to_utc_secs(&self, day: i64, secs: u32) -> Option<i64> {
+        let local_secs = day * SECONDS_IN_DAY + i64::from(secs);
+        let Some(tz) = self.timezone else {
+            return Some(local_secs - i64::from(self.utc_offset_secs));
+        };
+
+        let local = NaiveDate::from_num_days_from_ce_opt(
+            i32::try_from(day).ok()? + UNIX_EPOCH_DAYS_FROM_CE,
+        )?
+        .and_hms_opt(0, 0, 0)?
+            + TimeDelta::seconds(i64::from(secs));
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Some(dt.timestamp()),
+            // A local time skipped by a daylight saving transition is taken at the offset
+            // before the transition
+            LocalResult::None => Some(local_secs - self.offset_secs(local_secs - SECONDS_IN_DAY)),
+        }
+    }
+}
+
+/// Returns the time at which an order with the given time in force expires (if any).
+///
+/// `GTD` orders expire at their `expire_time`, while `AT_THE_OPEN` and `AT_THE_CLOSE` orders
+/// expire at the first session open or close following `ts_init` respectively, which requires
+/// a session `calendar`.
+#[must_use]
+pub fn order_expire_time(
+    time_in_force: TimeInForce,
+    expire_time: Option<UnixNanos>,
+    ts_init: UnixNanos,
+    calendar: Option<&SessionCalendar>,
+) -> Option<UnixNanos> {
+    match time_in_force {
+        TimeInForce::Gtd => expire_time,
+        TimeInForce::AtTheOpen => calendar.and_then(|calendar| calendar.next_open(ts_init)),
+        TimeInForce::AtTheClose => calendar.and_then(|calendar| calendar.next_close(ts_init)),
+        _ => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const NINE_THIRTY: u32 = 9 * 3600 + 30 * 60;
+    const SIXTEEN: u32 = 16 * 3600;
+    const EST: i32 = -5 * 3600;
+
+    fn jan(day: u32, hour: u32, min: u32) -> UnixNanos {
+        // January 2024, the 1st was a Monday
+        let dt = Utc.with_ymd_and_hms(2024, 1, day, hour, min, 0).unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn utc(month: u32, day: u32, hour: u32) -> UnixNanos {
+        let dt = Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    #[fixture]
+    fn nyse() -> SessionCalendar {
+        SessionCalendar::weekdays(TradingSession::new(NINE_THIRTY, SIXTEEN), EST)
+    }
+
+    #[fixture]
+    fn cme() -> SessionCalendar {
+        // Globex sessions from 17:00 to 16:00 Chicago time, opening Sunday to Thursday
+        let session = TradingSession::new(17 * 3600, SIXTEEN);
+        let mut calendar = SessionCalendar::new(0).with_timezone(chrono_tz::America::Chicago);
+        for weekday in [
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+        ] {
+            calendar.set_session(weekday, Some(session));
+        }
+        calendar
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`open` was equal to `close`")]
+    fn test_session_with_equal_open_and_close() {
+        let _ = TradingSession::new(NINE_THIRTY, NINE_THIRTY);
+    }
+
+    #[rstest]
+    #[case(jan(2, 14, 29), false)]
+    #[case(jan(2, 14, 30), true)]
+    #[case(jan(2, 20, 59), true)]
+    #[case(jan(2, 21, 0), false)]
+    #[case(jan(6, 15, 0), false)] // Saturday
+    fn test_is_open(nyse: SessionCalendar, #[case] ts: UnixNanos, #[case] expected: bool) {
+        assert_eq!(nyse.is_open(ts), expected);
+    }
+
+    #[rstest]
+    #[case(jan(2, 3, 0), jan(2, 14, 30))]
+    #[case(jan(2, 14, 30), jan(2, 14, 30))]
+    #[case(jan(2, 15, 0), jan(3, 14, 30))]
+    #[case(jan(5, 22, 0), jan(8, 14, 30))] // Friday evening rolls over the weekend
+    fn test_next_open(nyse: SessionCalendar, #[case] ts: UnixNanos, #[case] expected: UnixNanos) {
+        assert_eq!(nyse.next_open(ts), Some(expected));
+    }
+
+    #[rstest]
+    #[case(jan(2, 3, 0), jan(2, 21, 0))]
+    #[case(jan(2, 15, 0), jan(2, 21, 0))]
+    #[case(jan(2, 22, 0), jan(3, 21, 0))]
+    fn test_next_close(nyse: SessionCalendar, #[case] ts: UnixNanos, #[case] expected: UnixNanos) {
+        assert_eq!(nyse.next_close(ts), Some(expected));
+    }
+
+    #[rstest]
+    fn test_overnight_session() {
+        // Sunday 18:00 to Monday 17:00 (UTC)
+        let mut calendar = SessionCalendar::new(0);
+        calendar.set_session(
+            Weekday::Sun,
+            Some(TradingSession::new(18 * 3600, 17 * 3600)),
+        );
+
+        assert!(calendar.is_open(jan(8, 2, 0)));
+        assert!(!calendar.is_open(jan(8, 17, 0)));
+        assert_eq!(calendar.next_close(jan(7, 19, 0)), Some(jan(8, 17, 0)));
+    }
+
+    #[rstest]
+    fn test_empty_calendar_has_no_sessions() {
+        let calendar = SessionCalendar::new(0);
+
+        assert!(!calendar.is_open(jan(2, 15, 0)));
+        assert_eq!(calendar.next_open(jan(2, 15, 0)), None);
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Gtd, Some(jan(3, 0, 0)))]
+    #[case(TimeInForce::AtTheOpen, Some(jan(3, 14, 30)))]
+    #[case(TimeInForce::AtTheClose, Some(jan(2, 21, 0)))]
+    #[case(TimeInForce::Gtc, None)]
+    fn test_order_expire_time(
+        nyse: SessionCalendar,
+        #[case] time_in_force: TimeInForce,
+        #[case] expected: Option<UnixNanos>,
+    ) {
+        let expire_time = Some(jan(3, 0, 0));
+        let ts_init = jan(2, 15, 0);
+
+        assert_eq!(
+            order_expire_time(time_in_force, expire_time, ts_init, Some(&nyse)),
+            expected
+        );
+        if time_in_force != TimeInForce::Gtd {
+            assert_eq!(
+                order_expire_time(time_in_force, expire_time, ts_init, None),
+                None
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(utc(3, 8, 15), Some(utc(3, 8, 22)))] // CST before the DST change on 10 March
+    #[case(utc(3, 8, 22), Some(utc(3, 8, 22)))]
+    #[case(utc(3, 9, 12), Some(utc(3, 11, 21)))] // Saturday rolls over to the CDT Monday close
+    #[case(utc(3, 11, 21), Some(utc(3, 11, 21)))]
+    #[case(utc(3, 11, 22), Some(utc(3, 12, 21)))]
+    fn test_session_bar_close_daily_follows_dst(
+        cme: SessionCalendar,
+        #[case] ts: UnixNanos,
+        #[case] expected: Option<UnixNanos>,
+    ) {
+        assert_eq!(cme.session_bar_close(ts, BarAggregation::Day), expected);
+    }
+
+    #[rstest]
+    #[case(utc(3, 4, 12), utc(3, 8, 22))]
+    #[case(utc(3, 8, 22), utc(3, 8, 22))]
+    #[case(utc(3, 8, 23), utc(3, 15, 21))]
+    fn test_session_bar_close_weekly(
+        cme: SessionCalendar,
+        #[case] ts: UnixNanos,
+        #[case] expected: UnixNanos,
+    ) {
+        assert_eq!(
+            cme.session_bar_close(ts, BarAggregation::Week),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_session_bar_close_not_session_aligned(cme: SessionCalendar) {
+        assert_eq!(
+            cme.session_bar_close(utc(3, 8, 15), BarAggregation::Hour),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_timezone_sessions_open_across_dst(cme: SessionCalendar) {
+        // Sunday open at 17:00 CDT is 22:00 UTC
+        assert!(!cme.is_open(utc(3, 10, 21)));
+        assert!(cme.is_open(utc(3, 10, 22)));
+        assert_eq!(cme.next_open(utc(3, 9, 0)), Some(utc(3, 10, 22)));
+    }
+
+    #[rstest]
+    fn test_calendar_with_timezone_serde_round_trip(cme: SessionCalendar) {
+        let json = serde_json::to_string(&cme).unwrap();
+        let calendar: SessionCalendar = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(calendar, cme);
+        assert!(json.contains("America/Chicago"));
+    }
+}
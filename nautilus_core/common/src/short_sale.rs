@@ -0,0 +1,271 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Short-sale constraints (hard-to-borrow, max shortable quantity, locates) for instruments.
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_model::{enums::OrderSide, identifiers::InstrumentId, types::quantity::Quantity};
+
+/// Represents the short-sale constraints for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShortSaleConstraints {
+    pub instrument_id: InstrumentId,
+    /// If the instrument is on the hard-to-borrow list, and so cannot be sold short.
+    pub hard_to_borrow: bool,
+    /// The maximum quantity which can be held short (if limited).
+    pub max_shortable: Option<Quantity>,
+    /// If a locate must be obtained for the short quantity before selling short.
+    pub locate_required: bool,
+}
+
+impl ShortSaleConstraints {
+    /// Creates a new [`ShortSaleConstraints`] instance.
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        hard_to_borrow: bool,
+        max_shortable: Option<Quantity>,
+        locate_required: bool,
+    ) -> Self {
+        Self {
+            instrument_id,
+            hard_to_borrow,
+            max_shortable,
+            locate_required,
+        }
+    }
+}
+
+/// Represents a short sale which violates the constraints for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortSaleViolation {
+    /// The instrument is hard to borrow.
+    HardToBorrow,
+    /// The resulting short quantity would exceed the maximum shortable quantity.
+    ExceedsMaxShortable { short: Quantity, max: Quantity },
+    /// The resulting short quantity would exceed the located quantity.
+    ExceedsLocated { short: Quantity, located: Quantity },
+}
+
+impl Display for ShortSaleViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HardToBorrow => write!(f, "hard to borrow"),
+            Self::ExceedsMaxShortable { short, max } => {
+                write!(f, "short quantity {short} exceeds max shortable {max}")
+            }
+            Self::ExceedsLocated { short, located } => {
+                write!(f, "short quantity {short} exceeds located {located}")
+            }
+        }
+    }
+}
+
+/// Tracks the short-sale constraints and located quantities, per instrument.
+///
+/// Instruments without constraints can be sold short without limit.
+#[derive(Clone, Debug, Default)]
+pub struct ShortSaleRules {
+    constraints: HashMap<InstrumentId, ShortSaleConstraints>,
+    located: HashMap<InstrumentId, Quantity>,
+}
+
+impl ShortSaleRules {
+    /// Creates a new empty [`ShortSaleRules`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the constraints for the constraints' instrument, replacing any previous constraints.
+    pub fn set_constraints(&mut self, constraints: ShortSaleConstraints) {
+        self.constraints
+            .insert(constraints.instrument_id, constraints);
+    }
+
+    /// Returns the constraints for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn constraints(&self, instrument_id: &InstrumentId) -> Option<&ShortSaleConstraints> {
+        self.constraints.get(instrument_id)
+    }
+
+    /// Sets the located quantity for the given `instrument_id`, replacing any previous locate.
+    pub fn set_located(&mut self, instrument_id: InstrumentId, quantity: Quantity) {
+        self.located.insert(instrument_id, quantity);
+    }
+
+    /// Returns the located quantity for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn located(&self, instrument_id: &InstrumentId) -> Option<Quantity> {
+        self.located.get(instrument_id).copied()
+    }
+
+    /// Clears all constraints and locates.
+    pub fn clear(&mut self) {
+        self.constraints.clear();
+        self.located.clear();
+    }
+
+    /// Returns the violation of the constraints for the given `instrument_id` if an order
+    /// of `side` and `quantity` were filled against the signed `net_position`, or `None` if
+    /// the order would not result in a disallowed short position.
+    #[must_use]
+    pub fn check_order(
+        &self,
+        instrument_id: &InstrumentId,
+        side: OrderSide,
+        quantity: Quantity,
+        net_position: f64,
+    ) -> Option<ShortSaleViolation> {
+        if side != OrderSide::Sell {
+            return None;
+        }
+
+        let constraints = self.constraints.get(instrument_id)?;
+        let resulting = net_position - quantity.as_f64();
+        if resulting >= 0.0 {
+            return None; // Reducing a long position
+        }
+
+        if constraints.hard_to_borrow {
+            return Some(ShortSaleViolation::HardToBorrow);
+        }
+
+        let short = Quantity::new(-resulting, quantity.precision);
+        if let Some(max) = constraints.max_shortable {
+            if short > max {
+                return Some(ShortSaleViolation::ExceedsMaxShortable { short, max });
+            }
+        }
+        if constraints.locate_required {
+            let located = self
+                .located(instrument_id)
+                .unwrap_or_else(|| Quantity::zero(quantity.precision));
+            if short > located {
+                return Some(ShortSaleViolation::ExceedsLocated { short, located });
+            }
+        }
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn rules(hard_to_borrow: bool, max_shortable: Option<&str>, locate: bool) -> ShortSaleRules {
+        let mut rules = ShortSaleRules::new();
+        rules.set_constraints(ShortSaleConstraints::new(
+            InstrumentId::from("AAPL.XNAS"),
+            hard_to_borrow,
+            max_shortable.map(Quantity::from),
+            locate,
+        ));
+        rules
+    }
+
+    #[rstest]
+    fn test_unconstrained_instrument_can_be_shorted() {
+        let rules = ShortSaleRules::new();
+
+        let violation = rules.check_order(
+            &InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Sell,
+            Quantity::from(1_000),
+            0.0,
+        );
+
+        assert_eq!(violation, None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 100, 0.0, None)]
+    #[case(OrderSide::Sell, 100, 100.0, None)]
+    #[case(OrderSide::Sell, 100, 50.0, Some(ShortSaleViolation::HardToBorrow))]
+    #[case(OrderSide::Sell, 100, -50.0, Some(ShortSaleViolation::HardToBorrow))]
+    fn test_hard_to_borrow(
+        #[case] side: OrderSide,
+        #[case] quantity: i64,
+        #[case] net_position: f64,
+        #[case] expected: Option<ShortSaleViolation>,
+    ) {
+        let rules = rules(true, None, false);
+
+        let violation = rules.check_order(
+            &InstrumentId::from("AAPL.XNAS"),
+            side,
+            Quantity::from(quantity),
+            net_position,
+        );
+
+        assert_eq!(violation, expected);
+    }
+
+    #[rstest]
+    #[case(100, -100.0, None)]
+    #[case(100, -150.0, Some(ShortSaleViolation::ExceedsMaxShortable { short: Quantity::from(250), max: Quantity::from(200) }))]
+    #[case(300, 100.0, None)]
+    #[case(400, 100.0, Some(ShortSaleViolation::ExceedsMaxShortable { short: Quantity::from(300), max: Quantity::from(200) }))]
+    fn test_max_shortable(
+        #[case] quantity: i64,
+        #[case] net_position: f64,
+        #[case] expected: Option<ShortSaleViolation>,
+    ) {
+        let rules = rules(false, Some("200"), false);
+
+        let violation = rules.check_order(
+            &InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Sell,
+            Quantity::from(quantity),
+            net_position,
+        );
+
+        assert_eq!(violation, expected);
+    }
+
+    #[rstest]
+    fn test_locate_required() {
+        let mut rules = rules(false, None, true);
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+
+        assert_eq!(
+            rules.check_order(&instrument_id, OrderSide::Sell, Quantity::from(100), 0.0),
+            Some(ShortSaleViolation::ExceedsLocated {
+                short: Quantity::from(100),
+                located: Quantity::from(0),
+            })
+        );
+
+        rules.set_located(instrument_id, Quantity::from(100));
+
+        assert_eq!(
+            rules.check_order(&instrument_id, OrderSide::Sell, Quantity::from(100), 0.0),
+            None
+        );
+        assert_eq!(
+            rules
+                .check_order(&instrument_id, OrderSide::Sell, Quantity::from(101), 0.0)
+                .unwrap()
+                .to_string(),
+            "short quantity 101 exceeds located 100"
+        );
+    }
+}
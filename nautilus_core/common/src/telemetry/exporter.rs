@@ -0,0 +1,115 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A Prometheus HTTP exporter for the platform metrics.
+
+use std::net::SocketAddr;
+
+use axum::{http::StatusCode, routing::get, serve, Router};
+use tokio::net::TcpListener;
+
+use super::{telemetry, TelemetryConfig};
+use crate::runtime::get_runtime;
+
+/// The path metrics are served on.
+pub const METRICS_PATH: &str = "/metrics";
+
+/// Starts the Prometheus exporter if enabled by the given `config`, returning the bound address.
+///
+/// The exporter runs on the Nautilus runtime, serving the [`telemetry`] metrics at
+/// [`METRICS_PATH`] until the process exits.
+///
+/// # Errors
+///
+/// This function returns an error if the configured address cannot be bound.
+pub fn init_telemetry(config: &TelemetryConfig) -> anyhow::Result<Option<SocketAddr>> {
+    match config.prometheus_addr {
+        Some(addr) => start_prometheus_exporter(addr).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Starts serving the [`telemetry`] metrics at [`METRICS_PATH`] on the given `addr`,
+/// returning the bound address.
+///
+/// # Errors
+///
+/// This function returns an error if the address cannot be bound.
+pub fn start_prometheus_exporter(addr: SocketAddr) -> anyhow::Result<SocketAddr> {
+    let runtime = get_runtime();
+    let listener = runtime.block_on(TcpListener::bind(addr))?;
+    let local_addr = listener.local_addr()?;
+    let app = Router::new().route(METRICS_PATH, get(metrics));
+
+    runtime.spawn(async move {
+        if let Err(e) = serve(listener, app).await {
+            log::error!("Prometheus exporter stopped: {e}");
+        }
+    });
+
+    log::info!("Serving Prometheus metrics on http://{local_addr}{METRICS_PATH}");
+    Ok(local_addr)
+}
+
+async fn metrics() -> (StatusCode, String) {
+    match telemetry().encode() {
+        Ok(text) => (StatusCode::OK, text),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    use super::*;
+
+    #[rstest]
+    fn test_init_telemetry_disabled_by_default() {
+        assert!(init_telemetry(&TelemetryConfig::default())
+            .unwrap()
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_exporter_serves_metrics() {
+        let config = TelemetryConfig {
+            prometheus_addr: Some("127.0.0.1:0".parse().unwrap()),
+        };
+        let addr = init_telemetry(&config).unwrap().unwrap();
+        telemetry().record_reconnect("test_exporter_serves_metrics");
+
+        let response = get_runtime().block_on(async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            let request =
+                format!("GET {METRICS_PATH} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        });
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response
+            .contains("nautilus_reconnects_total{client=\"test_exporter_serves_metrics\"} 1"));
+    }
+}
@@ -0,0 +1,296 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Metrics and telemetry collection for the platform.
+//!
+//! Metrics are recorded into a process wide [`Telemetry`] registry, accessed with [`telemetry`],
+//! which can be scraped by Prometheus through the optional HTTP exporter started from a
+//! [`TelemetryConfig`].
+
+pub mod config;
+pub mod exporter;
+
+use std::{sync::OnceLock, time::Duration};
+
+pub use prometheus::{Gauge, IntCounter, IntGauge};
+use prometheus::{
+    GaugeVec, Histogram, HistogramOpts, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+pub use crate::telemetry::config::TelemetryConfig;
+
+/// The histogram buckets (seconds) for latencies measured within the process.
+const LOCAL_LATENCY_BUCKETS: [f64; 10] = [
+    0.000_001, 0.000_002, 0.000_005, 0.000_01, 0.000_025, 0.000_05, 0.000_1, 0.000_25, 0.001, 0.01,
+];
+
+/// The histogram buckets (seconds) for latencies including a round trip to a venue.
+const ROUND_TRIP_LATENCY_BUCKETS: [f64; 10] = [
+    0.000_5, 0.001, 0.002, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 1.0,
+];
+
+static TELEMETRY: OnceLock<Telemetry> = OnceLock::new();
+
+/// Returns the process wide [`Telemetry`] registry.
+pub fn telemetry() -> &'static Telemetry {
+    TELEMETRY.get_or_init(|| Telemetry::new().expect("Failed to register telemetry metrics"))
+}
+
+/// Provides the metrics collected for the platform.
+///
+/// - `nautilus_messages_total`: messages processed, labeled by `component`.
+/// - `nautilus_order_round_trip_seconds`: latency from order submission to venue acknowledgement.
+/// - `nautilus_book_apply_seconds`: latency of applying an update to an order book.
+/// - `nautilus_reconnects_total`: client reconnections, labeled by `client`.
+/// - `nautilus_conflated_total`: data dropped by conflated subscriptions, labeled by `data_type`.
+/// - `nautilus_data_sequence_total`: out of order, duplicate and reordered data, labeled by
+///   `data_type` and `issue`.
+/// - `nautilus_data_update_rate`: market data updates per second, labeled by `instrument_id`.
+/// - `nautilus_data_peak_burst`: peak market data updates within the burst window, labeled by
+///   `instrument_id`.
+/// - `nautilus_data_delta_trade_ratio`: order book deltas per trade, labeled by `instrument_id`.
+#[derive(Clone, Debug)]
+pub struct Telemetry {
+    registry: Registry,
+    messages: IntCounterVec,
+    order_round_trip: Histogram,
+    book_apply: Histogram,
+    reconnects: IntCounterVec,
+    conflated: IntCounterVec,
+    data_sequence: IntCounterVec,
+    update_rate: GaugeVec,
+    peak_burst: IntGaugeVec,
+    delta_trade_ratio: GaugeVec,
+}
+
+impl Telemetry {
+    /// Creates a new [`Telemetry`] instance with its own registry.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any metric fails to register.
+    pub fn new() -> anyhow::Result<Self> {
+        let registry = Registry::new();
+        let messages = IntCounterVec::new(
+            Opts::new("nautilus_messages_total", "Messages processed"),
+            &["component"],
+        )?;
+        let order_round_trip = Histogram::with_opts(
+            HistogramOpts::new(
+                "nautilus_order_round_trip_seconds",
+                "Latency from order submission to venue acknowledgement",
+            )
+            .buckets(ROUND_TRIP_LATENCY_BUCKETS.to_vec()),
+        )?;
+        let book_apply = Histogram::with_opts(
+            HistogramOpts::new(
+                "nautilus_book_apply_seconds",
+                "Latency of applying an update to an order book",
+            )
+            .buckets(LOCAL_LATENCY_BUCKETS.to_vec()),
+        )?;
+        let reconnects = IntCounterVec::new(
+            Opts::new("nautilus_reconnects_total", "Client reconnections"),
+            &["client"],
+        )?;
+        let conflated = IntCounterVec::new(
+            Opts::new(
+                "nautilus_conflated_total",
+                "Data dropped by conflated subscriptions",
+            ),
+            &["data_type"],
+        )?;
+        let data_sequence = IntCounterVec::new(
+            Opts::new(
+                "nautilus_data_sequence_total",
+                "Out of order, duplicate and reordered data",
+            ),
+            &["data_type", "issue"],
+        )?;
+        let update_rate = GaugeVec::new(
+            Opts::new(
+                "nautilus_data_update_rate",
+                "Market data updates per second",
+            ),
+            &["instrument_id"],
+        )?;
+        let peak_burst = IntGaugeVec::new(
+            Opts::new(
+                "nautilus_data_peak_burst",
+                "Peak market data updates within the burst window",
+            ),
+            &["instrument_id"],
+        )?;
+        let delta_trade_ratio = GaugeVec::new(
+            Opts::new(
+                "nautilus_data_delta_trade_ratio",
+                "Order book deltas per trade",
+            ),
+            &["instrument_id"],
+        )?;
+
+        registry.register(Box::new(messages.clone()))?;
+        registry.register(Box::new(order_round_trip.clone()))?;
+        registry.register(Box::new(book_apply.clone()))?;
+        registry.register(Box::new(reconnects.clone()))?;
+        registry.register(Box::new(conflated.clone()))?;
+        registry.register(Box::new(data_sequence.clone()))?;
+        registry.register(Box::new(update_rate.clone()))?;
+        registry.register(Box::new(peak_burst.clone()))?;
+        registry.register(Box::new(delta_trade_ratio.clone()))?;
+
+        Ok(Self {
+            registry,
+            messages,
+            order_round_trip,
+            book_apply,
+            reconnects,
+            conflated,
+            data_sequence,
+            update_rate,
+            peak_burst,
+            delta_trade_ratio,
+        })
+    }
+
+    /// Returns the underlying metrics registry.
+    #[must_use]
+    pub const fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Returns the message counter for the given `component`.
+    ///
+    /// Hot paths should hold onto the returned counter rather than calling
+    /// [`Self::record_message`], which looks up the label on each call.
+    #[must_use]
+    pub fn message_counter(&self, component: &str) -> IntCounter {
+        self.messages.with_label_values(&[component])
+    }
+
+    /// Records a message processed by the given `component`.
+    pub fn record_message(&self, component: &str) {
+        self.messages.with_label_values(&[component]).inc();
+    }
+
+    /// Records the `latency` from an order submission to its acknowledgement by the venue.
+    pub fn observe_order_round_trip(&self, latency: Duration) {
+        self.order_round_trip.observe(latency.as_secs_f64());
+    }
+
+    /// Records the `latency` of applying an update to an order book.
+    pub fn observe_book_apply(&self, latency: Duration) {
+        self.book_apply.observe(latency.as_secs_f64());
+    }
+
+    /// Records a reconnection of the given `client`.
+    pub fn record_reconnect(&self, client: &str) {
+        self.reconnects.with_label_values(&[client]).inc();
+    }
+
+    /// Returns the counter of data dropped by conflated subscriptions for the given `data_type`.
+    #[must_use]
+    pub fn conflated_counter(&self, data_type: &str) -> IntCounter {
+        self.conflated.with_label_values(&[data_type])
+    }
+
+    /// Records a data sequence `issue` (e.g. late or duplicate) for the given `data_type`.
+    pub fn record_data_sequence_issue(&self, data_type: &str, issue: &str) {
+        self.data_sequence
+            .with_label_values(&[data_type, issue])
+            .inc();
+    }
+
+    /// Returns the gauge of market data updates per second for the given `instrument_id`.
+    #[must_use]
+    pub fn update_rate_gauge(&self, instrument_id: &str) -> Gauge {
+        self.update_rate.with_label_values(&[instrument_id])
+    }
+
+    /// Returns the gauge of peak market data updates within the burst window for the given
+    /// `instrument_id`.
+    #[must_use]
+    pub fn peak_burst_gauge(&self, instrument_id: &str) -> IntGauge {
+        self.peak_burst.with_label_values(&[instrument_id])
+    }
+
+    /// Returns the gauge of order book deltas per trade for the given `instrument_id`.
+    #[must_use]
+    pub fn delta_trade_ratio_gauge(&self, instrument_id: &str) -> Gauge {
+        self.delta_trade_ratio.with_label_values(&[instrument_id])
+    }
+
+    /// Encodes the current metrics in the Prometheus text exposition format.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the metrics fail to encode.
+    pub fn encode(&self) -> anyhow::Result<String> {
+        Ok(TextEncoder::new().encode_to_string(&self.registry.gather())?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_record_and_encode() {
+        let telemetry = Telemetry::new().unwrap();
+        let counter = telemetry.message_counter("DataEngine");
+
+        counter.inc();
+        telemetry.record_message("DataEngine");
+        telemetry.record_reconnect("BINANCE");
+        telemetry.conflated_counter("QuoteTick").inc_by(3);
+        telemetry.record_data_sequence_issue("TradeTick", "late");
+        telemetry.observe_book_apply(Duration::from_micros(3));
+        telemetry.observe_order_round_trip(Duration::from_millis(4));
+        telemetry.update_rate_gauge("ETHUSDT.BINANCE").set(12.5);
+        telemetry.peak_burst_gauge("ETHUSDT.BINANCE").set(7);
+        telemetry
+            .delta_trade_ratio_gauge("ETHUSDT.BINANCE")
+            .set(4.0);
+
+        let text = telemetry.encode().unwrap();
+        assert!(text.contains("nautilus_messages_total{component=\"DataEngine\"} 2"));
+        assert!(text.contains("nautilus_reconnects_total{client=\"BINANCE\"} 1"));
+        assert!(text.contains("nautilus_conflated_total{data_type=\"QuoteTick\"} 3"));
+        assert!(
+            text.contains("nautilus_data_sequence_total{data_type=\"TradeTick\",issue=\"late\"} 1")
+        );
+        assert!(text.contains("nautilus_book_apply_seconds_bucket{le=\"0.000005\"} 1"));
+        assert!(text.contains("nautilus_order_round_trip_seconds_count 1"));
+        assert!(text.contains("nautilus_data_update_rate{instrument_id=\"ETHUSDT.BINANCE\"} 12.5"));
+        assert!(text.contains("nautilus_data_peak_burst{instrument_id=\"ETHUSDT.BINANCE\"} 7"));
+        assert!(
+            text.contains("nautilus_data_delta_trade_ratio{instrument_id=\"ETHUSDT.BINANCE\"} 4")
+        );
+    }
+
+    #[rstest]
+    fn test_global_telemetry_is_shared() {
+        telemetry().record_reconnect("test_global_telemetry_is_shared");
+
+        let text = telemetry().encode().unwrap();
+        assert!(text
+            .contains("nautilus_reconnects_total{client=\"test_global_telemetry_is_shared\"} 1"));
+    }
+}
@@ -0,0 +1,403 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `VenueThrottler` which limits the messages sent to a venue session to a message budget.
+//!
+//! The budget is a token bucket refilled at a sustained rate up to a burst size, in the style of
+//! venue messaging policies such as the CME messaging efficiency program. The throttler is shared
+//! by cloning, so that the messages of all strategies trading on the venue count towards the same
+//! budget. Messages exceeding the budget are queued, with high priority messages (such as
+//! cancels) sent before normal priority messages (such as new orders) as the budget refills.
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug, rc::Rc};
+
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    nanos::UnixNanos,
+};
+use serde::{Deserialize, Serialize};
+
+use super::RateLimit;
+use crate::{
+    clock::Clock,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// Represents a message budget with a sustained rate and a burst size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageBudget {
+    /// The sustained rate at which the budget refills.
+    pub rate: RateLimit,
+    /// The maximum number of messages which can be sent at once.
+    pub burst: usize,
+}
+
+impl MessageBudget {
+    /// Creates a new [`MessageBudget`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the rate limit or `burst` is zero, or the rate interval
+    /// is shorter than the limit in nanoseconds.
+    pub fn new_checked(rate: RateLimit, burst: usize) -> anyhow::Result<Self> {
+        check_predicate_true(rate.limit > 0, "`rate.limit` must be positive")?;
+        check_predicate_true(
+            rate.interval_ns >= rate.limit as u64,
+            "`rate.interval_ns` must not be less than `rate.limit`",
+        )?;
+        check_predicate_true(burst > 0, "`burst` must be positive")?;
+        Ok(Self { rate, burst })
+    }
+
+    /// Creates a new [`MessageBudget`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a check in [`MessageBudget::new_checked`] fails.
+    #[must_use]
+    pub fn new(rate: RateLimit, burst: usize) -> Self {
+        Self::new_checked(rate, burst).expect(FAILED)
+    }
+
+    /// Returns the interval (nanoseconds) at which a message is added to the budget.
+    #[must_use]
+    pub const fn emission_interval_ns(&self) -> u64 {
+        self.rate.interval_ns / self.rate.limit as u64
+    }
+}
+
+/// The priority of a message sent through a [`VenueThrottler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MessagePriority {
+    /// Sent before any queued normal priority messages, e.g. cancels.
+    High,
+    /// Sent in order after any queued high priority messages, e.g. new orders.
+    Normal,
+}
+
+struct InnerVenueThrottler<T, F> {
+    budget: MessageBudget,
+    clock: Rc<RefCell<dyn Clock>>,
+    timer_name: String,
+    tokens: usize,
+    ts_refill: UnixNanos,
+    high: VecDeque<T>,
+    normal: VecDeque<T>,
+    is_limiting: bool,
+    recv_count: usize,
+    sent_count: usize,
+    output_send: F,
+}
+
+impl<T, F> InnerVenueThrottler<T, F> {
+    fn refill(&mut self) {
+        let now = self.clock.borrow().timestamp_ns();
+        let emission_ns = self.budget.emission_interval_ns();
+        let elapsed_ns = now.as_u64().saturating_sub(self.ts_refill.as_u64());
+        let added = (elapsed_ns / emission_ns) as usize;
+
+        if self.tokens + added >= self.budget.burst {
+            self.tokens = self.budget.burst;
+            self.ts_refill = now;
+        } else {
+            self.tokens += added;
+            self.ts_refill = UnixNanos::from(self.ts_refill.as_u64() + added as u64 * emission_ns);
+        }
+    }
+
+    fn delta_next(&self) -> u64 {
+        let now = self.clock.borrow().timestamp_ns();
+        let elapsed_ns = now.as_u64().saturating_sub(self.ts_refill.as_u64());
+        self.budget
+            .emission_interval_ns()
+            .saturating_sub(elapsed_ns)
+            .max(1)
+    }
+
+    fn qsize(&self) -> usize {
+        self.high.len() + self.normal.len()
+    }
+
+    fn pop_next(&mut self) -> Option<T> {
+        self.high.pop_front().or_else(|| self.normal.pop_front())
+    }
+}
+
+/// Limits the messages sent to a venue session to a [`MessageBudget`], queueing messages which
+/// exceed the budget by priority.
+///
+/// Clones share the same budget and queues.
+pub struct VenueThrottler<T, F> {
+    inner: Rc<RefCell<InnerVenueThrottler<T, F>>>,
+}
+
+impl<T, F> Clone for VenueThrottler<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, F> Debug for VenueThrottler<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct(stringify!(VenueThrottler))
+            .field("budget", &inner.budget)
+            .field("timer_name", &inner.timer_name)
+            .field("tokens", &inner.tokens)
+            .field("qsize", &inner.qsize())
+            .field("recv_count", &inner.recv_count)
+            .field("sent_count", &inner.sent_count)
+            .finish()
+    }
+}
+
+impl<T, F> VenueThrottler<T, F> {
+    /// Creates a new [`VenueThrottler`] instance, with the full burst initially available.
+    pub fn new(
+        budget: MessageBudget,
+        clock: Rc<RefCell<dyn Clock>>,
+        timer_name: String,
+        output_send: F,
+    ) -> Self {
+        let ts_refill = clock.borrow().timestamp_ns();
+        let inner = InnerVenueThrottler {
+            budget,
+            clock,
+            timer_name,
+            tokens: budget.burst,
+            ts_refill,
+            high: VecDeque::new(),
+            normal: VecDeque::new(),
+            is_limiting: false,
+            recv_count: 0,
+            sent_count: 0,
+            output_send,
+        };
+        Self {
+            inner: Rc::new(RefCell::new(inner)),
+        }
+    }
+
+    /// Returns the message budget.
+    #[must_use]
+    pub fn budget(&self) -> MessageBudget {
+        self.inner.borrow().budget
+    }
+
+    /// Returns the number of messages which can be sent immediately.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        inner.refill();
+        inner.tokens
+    }
+
+    /// Returns the number of queued messages.
+    #[must_use]
+    pub fn qsize(&self) -> usize {
+        self.inner.borrow().qsize()
+    }
+
+    /// Returns the number of messages received.
+    #[must_use]
+    pub fn recv_count(&self) -> usize {
+        self.inner.borrow().recv_count
+    }
+
+    /// Returns the number of messages sent.
+    #[must_use]
+    pub fn sent_count(&self) -> usize {
+        self.inner.borrow().sent_count
+    }
+
+    /// Resets the throttler, clearing the queues and restoring the full burst.
+    pub fn reset(&self) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.is_limiting {
+            let timer_name = inner.timer_name.clone();
+            inner.clock.borrow_mut().cancel_timer(&timer_name);
+        }
+        inner.high.clear();
+        inner.normal.clear();
+        inner.is_limiting = false;
+        inner.tokens = inner.budget.burst;
+        let ts_now = inner.clock.borrow().timestamp_ns();
+        inner.ts_refill = ts_now;
+        inner.recv_count = 0;
+        inner.sent_count = 0;
+    }
+}
+
+impl<T, F> VenueThrottler<T, F>
+where
+    F: Fn(T) + 'static,
+    T: 'static,
+{
+    /// Sends the given `msg` if the budget allows, otherwise queues it with the given `priority`.
+    pub fn send(&self, msg: T, priority: MessagePriority) {
+        let mut inner = self.inner.borrow_mut();
+        inner.recv_count += 1;
+        inner.refill();
+
+        if !inner.is_limiting && inner.tokens > 0 {
+            inner.tokens -= 1;
+            inner.sent_count += 1;
+            (inner.output_send)(msg);
+            return;
+        }
+
+        match priority {
+            MessagePriority::High => inner.high.push_back(msg),
+            MessagePriority::Normal => inner.normal.push_back(msg),
+        }
+        log::debug!(
+            "Queued {priority:?} priority message, qsize={}",
+            inner.qsize()
+        );
+
+        if !inner.is_limiting {
+            inner.is_limiting = true;
+            drop(inner);
+            self.set_timer();
+        }
+    }
+
+    fn set_timer(&self) {
+        let inner = self.inner.borrow();
+        let throttler = self.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+            throttler.process();
+        }));
+        let delta = inner.delta_next();
+        let mut clock = inner.clock.borrow_mut();
+        let alert_time_ns = clock.timestamp_ns() + delta;
+        clock.set_time_alert_ns(&inner.timer_name, alert_time_ns, Some(callback));
+    }
+
+    fn process(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.refill();
+        while inner.tokens > 0 {
+            let Some(msg) = inner.pop_next() else {
+                break;
+            };
+            inner.tokens -= 1;
+            inner.sent_count += 1;
+            (inner.output_send)(msg);
+        }
+
+        if inner.qsize() == 0 {
+            inner.is_limiting = false;
+        } else {
+            drop(inner);
+            self.set_timer();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    type SentMessages = Rc<RefCell<Vec<&'static str>>>;
+    type TestThrottler = VenueThrottler<&'static str, Box<dyn Fn(&'static str)>>;
+
+    fn throttler(burst: usize) -> (TestThrottler, Rc<RefCell<TestClock>>, SentMessages) {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let sent: SentMessages = Rc::new(RefCell::new(Vec::new()));
+        let sent_clone = sent.clone();
+        let output_send: Box<dyn Fn(&'static str)> = Box::new(move |msg| {
+            sent_clone.borrow_mut().push(msg);
+        });
+        // Sustained rate of 1 message per 10ns
+        let budget = MessageBudget::new(RateLimit::new(1, 10), burst);
+        let throttler = VenueThrottler::new(
+            budget,
+            clock.clone(),
+            "venue_timer".to_string(),
+            output_send,
+        );
+        (throttler, clock, sent)
+    }
+
+    #[rstest]
+    fn test_burst_sent_immediately_then_queued() {
+        let (throttler, _clock, sent) = throttler(3);
+
+        for msg in ["A", "B", "C", "D"] {
+            throttler.send(msg, MessagePriority::Normal);
+        }
+
+        assert_eq!(*sent.borrow(), vec!["A", "B", "C"]);
+        assert_eq!(throttler.qsize(), 1);
+        assert_eq!(throttler.available(), 0);
+        assert_eq!(throttler.recv_count(), 4);
+        assert_eq!(throttler.sent_count(), 3);
+    }
+
+    #[rstest]
+    fn test_queued_messages_sent_at_sustained_rate_with_cancels_first() {
+        let (throttler, clock, sent) = throttler(2);
+
+        throttler.send("NEW-1", MessagePriority::Normal);
+        throttler.send("NEW-2", MessagePriority::Normal);
+        throttler.send("NEW-3", MessagePriority::Normal);
+        throttler.send("NEW-4", MessagePriority::Normal);
+        throttler.send("CANCEL-1", MessagePriority::High);
+        assert_eq!(*sent.borrow(), vec!["NEW-1", "NEW-2"]);
+
+        TestClock::advance_time_and_run(&clock, UnixNanos::from(10));
+        assert_eq!(*sent.borrow(), vec!["NEW-1", "NEW-2", "CANCEL-1"]);
+
+        TestClock::advance_time_and_run(&clock, UnixNanos::from(30));
+        assert_eq!(
+            *sent.borrow(),
+            vec!["NEW-1", "NEW-2", "CANCEL-1", "NEW-3", "NEW-4"]
+        );
+        assert_eq!(throttler.qsize(), 0);
+    }
+
+    #[rstest]
+    fn test_budget_shared_between_clones() {
+        let (throttler, clock, sent) = throttler(2);
+        let other = throttler.clone();
+
+        throttler.send("S1-1", MessagePriority::Normal);
+        other.send("S2-1", MessagePriority::Normal);
+        other.send("S2-2", MessagePriority::Normal);
+        assert_eq!(throttler.qsize(), 1);
+
+        // The budget refills up to the burst only
+        TestClock::advance_time_and_run(&clock, UnixNanos::from(100));
+        assert_eq!(sent.borrow().len(), 3);
+        assert_eq!(throttler.available(), 1);
+    }
+
+    #[rstest]
+    #[case(RateLimit::new(0, 10), 1)]
+    #[case(RateLimit::new(1, 10), 0)]
+    #[case(RateLimit::new(10, 5), 1)]
+    fn test_invalid_budget(#[case] rate: RateLimit, #[case] burst: usize) {
+        assert!(MessageBudget::new_checked(rate, burst).is_err());
+    }
+}
@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod budget;
 pub mod callbacks;
 pub mod inner;
 
@@ -20,10 +21,12 @@ use std::{cell::RefCell, fmt::Debug, rc::Rc};
 
 use callbacks::{ThrottlerProcess, ThrottlerResume};
 use inner::InnerThrottler;
+use serde::{Deserialize, Serialize};
 
 use crate::clock::Clock;
 
 /// Represents a throttling limit per interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RateLimit {
     pub limit: usize,
     pub interval_ns: u64,
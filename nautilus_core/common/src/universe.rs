@@ -0,0 +1,407 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Point-in-time universe membership for survivorship-bias-free multi-asset backtests.
+//!
+//! A [`UniverseDefinition`] records the intervals over which each instrument was a member of a
+//! universe (such as an index), including instruments which have since been removed or delisted.
+//! A [`UniversePublisher`] publishes a [`UniverseChanged`] event on the universe topic at each
+//! membership change, such as an index rebalance.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos};
+use nautilus_model::{data::GetTsInit, identifiers::InstrumentId};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    clock::Clock,
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// Represents an interval of universe membership, from `start` (inclusive) until `end`
+/// (exclusive, open ended if None).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MembershipInterval {
+    pub start: UnixNanos,
+    pub end: Option<UnixNanos>,
+}
+
+impl MembershipInterval {
+    /// Returns whether the interval contains the given timestamp `ts`.
+    #[must_use]
+    pub fn contains(&self, ts: UnixNanos) -> bool {
+        self.start <= ts && self.end.is_none_or(|end| ts < end)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.end.is_none_or(|end| other.start < end) && other.end.is_none_or(|end| self.start < end)
+    }
+}
+
+/// Represents a change in the members of a universe.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UniverseChanged {
+    pub universe: Ustr,
+    /// The instruments added to the universe, sorted by ID.
+    pub added: Vec<InstrumentId>,
+    /// The instruments removed from the universe, sorted by ID.
+    pub removed: Vec<InstrumentId>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl GetTsInit for UniverseChanged {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl Display for UniverseChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(universe={}, added={}, removed={}, ts_event={})",
+            stringify!(UniverseChanged),
+            self.universe,
+            self.added.len(),
+            self.removed.len(),
+            self.ts_event,
+        )
+    }
+}
+
+/// Represents the membership of a universe of instruments over time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UniverseDefinition {
+    pub name: Ustr,
+    memberships: HashMap<InstrumentId, Vec<MembershipInterval>>,
+}
+
+impl UniverseDefinition {
+    /// Creates a new empty [`UniverseDefinition`] instance.
+    #[must_use]
+    pub fn new(name: Ustr) -> Self {
+        Self {
+            name,
+            memberships: HashMap::new(),
+        }
+    }
+
+    /// Adds a membership interval for the given `instrument_id` from `start` until `end`
+    /// (open ended if None).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `end` is not after `start`.
+    /// - If the interval overlaps an existing membership interval for the instrument.
+    pub fn add_membership(
+        &mut self,
+        instrument_id: InstrumentId,
+        start: UnixNanos,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        check_predicate_true(
+            end.is_none_or(|end| end > start),
+            "membership `end` must be after `start`",
+        )?;
+        let interval = MembershipInterval { start, end };
+        let intervals = self.memberships.entry(instrument_id).or_default();
+        if let Some(existing) = intervals.iter().find(|i| i.overlaps(&interval)) {
+            anyhow::bail!(
+                "Membership {interval:?} for {instrument_id} overlaps existing membership {existing:?}"
+            );
+        }
+        intervals.push(interval);
+        intervals.sort_by_key(|i| i.start);
+        Ok(())
+    }
+
+    /// Returns the membership intervals for the given `instrument_id`, sorted by start.
+    #[must_use]
+    pub fn memberships(&self, instrument_id: &InstrumentId) -> &[MembershipInterval] {
+        self.memberships
+            .get(instrument_id)
+            .map_or(&[], |intervals| intervals.as_slice())
+    }
+
+    /// Returns whether the given `instrument_id` is a member of the universe at `ts`.
+    #[must_use]
+    pub fn is_member(&self, instrument_id: &InstrumentId, ts: UnixNanos) -> bool {
+        self.memberships(instrument_id)
+            .iter()
+            .any(|interval| interval.contains(ts))
+    }
+
+    /// Returns the members of the universe at `ts`, sorted by ID.
+    #[must_use]
+    pub fn members_at(&self, ts: UnixNanos) -> Vec<InstrumentId> {
+        let mut members: Vec<InstrumentId> = self
+            .memberships
+            .iter()
+            .filter(|(_, intervals)| intervals.iter().any(|interval| interval.contains(ts)))
+            .map(|(instrument_id, _)| *instrument_id)
+            .collect();
+        members.sort();
+        members
+    }
+
+    /// Returns every instrument which has ever been a member of the universe, sorted by ID.
+    #[must_use]
+    pub fn instruments(&self) -> Vec<InstrumentId> {
+        let mut instruments: Vec<InstrumentId> = self.memberships.keys().copied().collect();
+        instruments.sort();
+        instruments
+    }
+
+    /// Returns the times at which the members of the universe change, in ascending order.
+    #[must_use]
+    pub fn change_times(&self) -> Vec<UnixNanos> {
+        let times: BTreeSet<UnixNanos> = self
+            .memberships
+            .values()
+            .flatten()
+            .flat_map(|interval| std::iter::once(interval.start).chain(interval.end))
+            .collect();
+        times.into_iter().collect()
+    }
+
+    /// Returns the change in the members of the universe at `ts` (if any).
+    #[must_use]
+    pub fn change_at(&self, ts: UnixNanos) -> Option<UniverseChanged> {
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        for (instrument_id, intervals) in &self.memberships {
+            let is_member = intervals.iter().any(|interval| interval.contains(ts));
+            let was_member = ts.as_u64() > 0
+                && intervals
+                    .iter()
+                    .any(|interval| interval.contains(UnixNanos::from(ts.as_u64() - 1)));
+            match (was_member, is_member) {
+                (false, true) => added.push(*instrument_id),
+                (true, false) => removed.push(*instrument_id),
+                _ => {}
+            }
+        }
+        if added.is_empty() && removed.is_empty() {
+            return None;
+        }
+        added.sort();
+        removed.sort();
+        Some(UniverseChanged {
+            universe: self.name,
+            added,
+            removed,
+            ts_event: ts,
+            ts_init: ts,
+        })
+    }
+
+    /// Returns the changes in the members of the universe after `start` up to and including
+    /// `end`, in time order.
+    #[must_use]
+    pub fn changes(&self, start: UnixNanos, end: UnixNanos) -> Vec<UniverseChanged> {
+        self.change_times()
+            .into_iter()
+            .filter(|ts| start < *ts && *ts <= end)
+            .filter_map(|ts| self.change_at(ts))
+            .collect()
+    }
+}
+
+/// Publishes [`UniverseChanged`] events for a universe on the universe topic.
+pub struct UniversePublisher {
+    definition: UniverseDefinition,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl UniversePublisher {
+    /// Creates a new [`UniversePublisher`] instance.
+    #[must_use]
+    pub const fn new(definition: UniverseDefinition, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self { definition, msgbus }
+    }
+
+    #[must_use]
+    pub const fn definition(&self) -> &UniverseDefinition {
+        &self.definition
+    }
+
+    /// Starts publishing the membership changes after the current time on the given `clock`,
+    /// with a time alert at each change.
+    pub fn start(self: &Rc<Self>, clock: &mut dyn Clock) {
+        let ts_now = clock.timestamp_ns();
+        let change_times: Vec<UnixNanos> = self
+            .definition
+            .change_times()
+            .into_iter()
+            .filter(|ts| *ts > ts_now)
+            .collect();
+        for ts in &change_times {
+            let publisher = self.clone();
+            let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+                publisher.publish(event.ts_event);
+            }));
+            clock.set_time_alert_ns(&self.alert_name(*ts), *ts, Some(callback));
+        }
+        log::info!(
+            "Publishing {} changes for universe {}",
+            change_times.len(),
+            self.definition.name
+        );
+    }
+
+    /// Stops publishing the membership changes on the given `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        for ts in self.definition.change_times() {
+            let name = self.alert_name(ts);
+            if clock.timer_names().contains(&name.as_str()) {
+                clock.cancel_timer(&name);
+            }
+        }
+    }
+
+    /// Publishes the change in the members of the universe at `ts` (if any).
+    pub fn publish(&self, ts: UnixNanos) -> Option<UniverseChanged> {
+        let change = self.definition.change_at(ts)?;
+        log::debug!("{change}");
+        let msgbus = self.msgbus.borrow();
+        msgbus.publish(&msgbus.switchboard.universe_topic, &change as &dyn Any);
+        Some(change)
+    }
+
+    fn alert_name(&self, ts: UnixNanos) -> String {
+        format!("Universe-{}-{ts}", self.definition.name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+
+    fn id(value: &str) -> InstrumentId {
+        InstrumentId::from(value)
+    }
+
+    // AAPL is a member throughout, XOM is removed at 200 and TSLA added at 200
+    fn definition() -> UniverseDefinition {
+        let mut definition = UniverseDefinition::new(Ustr::from("SPX"));
+        definition
+            .add_membership(id("AAPL.XNAS"), 100.into(), None)
+            .unwrap();
+        definition
+            .add_membership(id("XOM.XNYS"), 100.into(), Some(200.into()))
+            .unwrap();
+        definition
+            .add_membership(id("TSLA.XNAS"), 200.into(), None)
+            .unwrap();
+        definition
+    }
+
+    #[rstest]
+    fn test_members_at() {
+        let definition = definition();
+
+        assert!(definition.members_at(99.into()).is_empty());
+        assert_eq!(
+            definition.members_at(199.into()),
+            vec![id("AAPL.XNAS"), id("XOM.XNYS")]
+        );
+        assert_eq!(
+            definition.members_at(200.into()),
+            vec![id("AAPL.XNAS"), id("TSLA.XNAS")]
+        );
+        assert!(definition.is_member(&id("XOM.XNYS"), 150.into()));
+        assert!(!definition.is_member(&id("XOM.XNYS"), 200.into()));
+        assert_eq!(definition.instruments().len(), 3);
+    }
+
+    #[rstest]
+    fn test_add_membership_validation() {
+        let mut definition = definition();
+
+        assert!(definition
+            .add_membership(id("MSFT.XNAS"), 100.into(), Some(100.into()))
+            .is_err());
+        assert!(definition
+            .add_membership(id("XOM.XNYS"), 150.into(), Some(250.into()))
+            .is_err());
+
+        // Readmitted after removal
+        definition
+            .add_membership(id("XOM.XNYS"), 300.into(), None)
+            .unwrap();
+        assert_eq!(definition.memberships(&id("XOM.XNYS")).len(), 2);
+        assert!(definition.is_member(&id("XOM.XNYS"), 300.into()));
+    }
+
+    #[rstest]
+    fn test_changes() {
+        let definition = definition();
+
+        let changes = definition.changes(0.into(), 1_000.into());
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].ts_event, UnixNanos::from(100));
+        assert_eq!(changes[0].added, vec![id("AAPL.XNAS"), id("XOM.XNYS")]);
+        assert!(changes[0].removed.is_empty());
+        assert_eq!(changes[1].added, vec![id("TSLA.XNAS")]);
+        assert_eq!(changes[1].removed, vec![id("XOM.XNYS")]);
+        assert!(definition.change_at(150.into()).is_none());
+        assert_eq!(definition.changes(100.into(), 1_000.into()).len(), 1);
+    }
+
+    #[rstest]
+    fn test_publisher_publishes_changes_on_clock() {
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<UniverseChanged>(None);
+        let topic = msgbus.borrow().switchboard.universe_topic;
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+        let publisher = Rc::new(UniversePublisher::new(definition(), msgbus));
+        let mut clock = TestClock::new();
+        clock.set_time(150.into());
+
+        publisher.start(&mut clock);
+        assert_eq!(clock.timer_count(), 1);
+
+        let events = clock.advance_time(1_000.into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let messages = get_saved_messages::<UniverseChanged>(handler);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].added, vec![id("TSLA.XNAS")]);
+        assert_eq!(messages[0].ts_event, UnixNanos::from(200));
+    }
+}
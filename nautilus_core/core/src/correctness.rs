@@ -31,6 +31,7 @@ use std::{
 };
 
 use indexmap::IndexMap;
+use rust_decimal::Decimal;
 
 /// A message prefix that can be used with calls to `expect` or other assertion-related functions.
 ///
@@ -163,6 +164,14 @@ pub fn check_positive_i64(value: i64, param: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Checks the `Decimal` value is positive (> 0).
+pub fn check_positive_decimal(value: Decimal, param: &str) -> anyhow::Result<()> {
+    if value <= Decimal::ZERO {
+        anyhow::bail!("invalid Decimal for '{param}' not positive, was {value}")
+    }
+    Ok(())
+}
+
 /// Checks the `f64` value is non-negative (< 0).
 pub fn check_non_negative_f64(value: f64, param: &str) -> anyhow::Result<()> {
     if value.is_nan() || value.is_infinite() {
@@ -546,6 +555,20 @@ mod tests {
         assert!(check_positive_i64(value, param).is_err());
     }
 
+    #[rstest]
+    #[case(Decimal::new(1, 8), "value")]
+    #[case(Decimal::ONE, "value")]
+    fn test_check_positive_decimal_when_positive(#[case] value: Decimal, #[case] param: &str) {
+        assert!(check_positive_decimal(value, param).is_ok());
+    }
+
+    #[rstest]
+    #[case(Decimal::ZERO, "value")]
+    #[case(Decimal::NEGATIVE_ONE, "value")]
+    fn test_check_positive_decimal_when_not_positive(#[case] value: Decimal, #[case] param: &str) {
+        assert!(check_positive_decimal(value, param).is_err());
+    }
+
     #[rstest]
     #[case(0.0, "value")]
     #[case(1.0, "value")]
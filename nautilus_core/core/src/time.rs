@@ -15,13 +15,15 @@
 
 //! The core `AtomicTime` for real-time and static clocks.
 
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{
     ops::Deref,
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
         OnceLock,
     },
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::Duration,
 };
 
 use crate::{
@@ -46,12 +48,23 @@ pub fn get_atomic_clock_static() -> &'static AtomicTime {
 }
 
 #[must_use]
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 pub fn duration_since_unix_epoch() -> Duration {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("Error calling `SystemTime::now.duration_since`")
 }
 
+/// Returns the duration since the UNIX epoch from the JavaScript host clock.
+///
+/// `SystemTime::now` is unsupported on `wasm32-unknown-unknown`, and the host clock only has
+/// millisecond resolution.
+#[must_use]
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub fn duration_since_unix_epoch() -> Duration {
+    Duration::from_secs_f64(js_sys::Date::now() / 1_000.0)
+}
+
 /// Represents an atomic timekeeping structure.
 ///
 /// `AtomicTime` can act as a real-time clock or static clock based on its mode.
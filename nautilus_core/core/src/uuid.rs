@@ -135,7 +135,7 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        self.to_string().serialize(serializer)
     }
 }
 
@@ -214,4 +214,16 @@ mod tests {
         let uuid = UUID4::from(uuid_string);
         assert_eq!(format!("{uuid}"), uuid_string);
     }
+
+    #[rstest]
+    fn test_json_round_trip() {
+        let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+        let uuid = UUID4::from(uuid_string);
+
+        let json = serde_json::to_string(&uuid).unwrap();
+        let result: UUID4 = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, format!("\"{uuid_string}\""));
+        assert_eq!(result, uuid);
+    }
 }
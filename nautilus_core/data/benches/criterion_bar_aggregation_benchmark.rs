@@ -0,0 +1,117 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use nautilus_data::aggregation::{
+    BarAggregator, TickBarAggregator, ValueBarAggregator, VolumeBarAggregator,
+};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarSpecification, BarType},
+        trade::TradeTick,
+    },
+    enums::{AggregationSource, AggressorSide, BarAggregation, PriceType},
+    identifiers::TradeId,
+    instruments::{any::InstrumentAny, stubs::equity_aapl},
+    types::{price::Price, quantity::Quantity},
+};
+
+const TRADES: u64 = 10_000;
+
+fn trades(instrument: &InstrumentAny) -> Vec<TradeTick> {
+    (0..TRADES)
+        .map(|i| {
+            TradeTick::new(
+                instrument.id(),
+                Price::from_raw((100 + (i % 10) as i64) * 1_000_000_000, 2),
+                Quantity::from_raw(100_000_000_000, 0),
+                AggressorSide::Buyer,
+                TradeId::new("1"),
+                i.into(),
+                i.into(),
+            )
+        })
+        .collect()
+}
+
+fn bar_type(instrument: &InstrumentAny, step: usize, aggregation: BarAggregation) -> BarType {
+    BarType::new(
+        instrument.id(),
+        BarSpecification::new(step, aggregation, PriceType::Last),
+        AggregationSource::Internal,
+    )
+}
+
+fn bench_aggregator<A: BarAggregator>(
+    c: &mut Criterion,
+    name: &str,
+    mut aggregator: A,
+    trades: &[TradeTick],
+) {
+    c.benchmark_group("bar_aggregation")
+        .throughput(Throughput::Elements(TRADES))
+        .bench_function(name, |b| {
+            b.iter(|| {
+                for trade in trades {
+                    aggregator.handle_trade(black_box(*trade));
+                }
+            });
+        });
+}
+
+fn bench_bar_aggregation(c: &mut Criterion) {
+    let instrument = InstrumentAny::Equity(equity_aapl());
+    let trades = trades(&instrument);
+    let handler = |bar: Bar| {
+        black_box(bar);
+    };
+
+    bench_aggregator(
+        c,
+        "tick",
+        TickBarAggregator::new(
+            &instrument,
+            bar_type(&instrument, 100, BarAggregation::Tick),
+            handler,
+            false,
+        ),
+        &trades,
+    );
+    bench_aggregator(
+        c,
+        "volume",
+        VolumeBarAggregator::new(
+            &instrument,
+            bar_type(&instrument, 10_000, BarAggregation::Volume),
+            handler,
+            false,
+        ),
+        &trades,
+    );
+    bench_aggregator(
+        c,
+        "value",
+        ValueBarAggregator::new(
+            &instrument,
+            bar_type(&instrument, 1_000_000, BarAggregation::Value),
+            handler,
+            false,
+        ),
+        &trades,
+    );
+}
+
+criterion_group!(benches, bench_bar_aggregation);
+criterion_main!(benches);
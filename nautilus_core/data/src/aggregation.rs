@@ -24,6 +24,7 @@ use std::{cell::RefCell, ops::Add, rc::Rc};
 use chrono::TimeDelta;
 use nautilus_common::{
     clock::Clock,
+    session::SessionCalendar,
     timer::{TimeEvent, TimeEventCallback},
 };
 use nautilus_core::{
@@ -36,7 +37,7 @@ use nautilus_model::{
         quote::QuoteTick,
         trade::TradeTick,
     },
-    enums::AggregationSource,
+    enums::{AggregationSource, BarAggregation},
     instruments::any::InstrumentAny,
     types::{fixed::FIXED_SCALAR, price::Price, quantity::Quantity},
 };
@@ -497,6 +498,8 @@ where
     interval: TimeDelta,
     interval_ns: UnixNanos,
     next_close_ns: UnixNanos,
+    session_calendar: Option<SessionCalendar>,
+    session_callback: Option<TimeEventCallback>,
 }
 
 #[derive(Clone)]
@@ -513,7 +516,7 @@ impl<C: Clock, H: FnMut(Bar)> NewBarCallback<C, H> {
 impl<C: Clock + 'static, H: FnMut(Bar) + 'static> From<NewBarCallback<C, H>> for TimeEventCallback {
     fn from(value: NewBarCallback<C, H>) -> Self {
         Self::Rust(Rc::new(move |event: TimeEvent| {
-            value.aggregator.borrow_mut().build_bar(event);
+            value.aggregator.borrow_mut().on_time_event(event);
         }))
     }
 }
@@ -555,11 +558,45 @@ where
             interval: get_bar_interval(&bar_type),
             interval_ns: get_bar_interval_ns(&bar_type),
             next_close_ns: UnixNanos::default(),
+            session_calendar: None,
+            session_callback: None,
         }
     }
 
+    /// Sets the session `calendar` which `DAY` and `WEEK` bars are cut at the session close of,
+    /// in the venue's local time rather than at UTC midnight.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the bar aggregation is not `DAY` or `WEEK`.
+    /// - If the bar step is not 1.
+    pub fn set_session_calendar(&mut self, calendar: SessionCalendar) -> anyhow::Result<()> {
+        let spec = self.bar_type().spec();
+        correctness::check_predicate_true(
+            matches!(spec.aggregation, BarAggregation::Day | BarAggregation::Week),
+            "session aligned bars require a `DAY` or `WEEK` aggregation",
+        )?;
+        correctness::check_predicate_true(
+            spec.step == 1,
+            "session aligned bars require a step of 1",
+        )?;
+
+        self.session_calendar = Some(calendar);
+        Ok(())
+    }
+
     /// Starts the time bar aggregator.
     pub fn start(&mut self, callback: NewBarCallback<C, H>) -> anyhow::Result<()> {
+        if self.session_calendar.is_some() {
+            let callback: TimeEventCallback = callback.into();
+            self.session_callback = Some(callback);
+            let now_ns = self.clock.timestamp_ns();
+            self.set_session_close_alert(now_ns + 1)?;
+            log::debug!("Started session close alerts {}", self.timer_name);
+            return Ok(());
+        }
+
         let now = self.clock.utc_now();
         let start_time = get_time_bar_start(now, &self.bar_type());
         let start_time_ns = UnixNanos::from(start_time.timestamp_nanos_opt().unwrap() as u64);
@@ -579,6 +616,24 @@ where
     /// Stops the time bar aggregator.
     pub fn stop(&mut self) {
         self.clock.cancel_timer(&self.timer_name);
+        self.session_callback = None;
+    }
+
+    // Sets an alert for the close of the session aligned bar containing `ts`
+    fn set_session_close_alert(&mut self, ts: UnixNanos) -> anyhow::Result<()> {
+        let (Some(calendar), Some(callback)) = (&self.session_calendar, &self.session_callback)
+        else {
+            return Ok(());
+        };
+        let Some(close_ns) = calendar.session_bar_close(ts, self.core.bar_type.spec().aggregation)
+        else {
+            anyhow::bail!("No session close in calendar for {}", self.timer_name);
+        };
+
+        self.clock
+            .set_time_alert_ns(&self.timer_name, close_ns, Some(callback.clone()));
+        self.next_close_ns = close_ns;
+        Ok(())
     }
 
     fn build_bar(&mut self, event: TimeEvent) {
@@ -607,6 +662,14 @@ where
         self.stored_open_ns = event.ts_event;
         self.next_close_ns = self.clock.next_time_ns(&self.timer_name);
     }
+
+    fn on_time_event(&mut self, event: TimeEvent) {
+        let ts_event = event.ts_event;
+        self.build_bar(event);
+        if let Err(e) = self.set_session_close_alert(ts_event + 1) {
+            log::error!("{e}");
+        }
+    }
 }
 
 impl<C, H> BarAggregator for TimeBarAggregator<C, H>
@@ -647,6 +710,8 @@ where
 mod tests {
     use std::sync::{Arc, Mutex};
 
+    use chrono::{TimeZone, Utc};
+    use nautilus_common::{clock::TestClock, session::TradingSession};
     use nautilus_model::{
         data::bar::{BarSpecification, BarType},
         enums::{AggregationSource, BarAggregation, PriceType},
@@ -974,4 +1039,93 @@ mod tests {
         assert_eq!(bar.ts_event, trade.ts_event);
         assert_eq!(bar.ts_init, trade.ts_init);
     }
+
+    fn utc(month: u32, day: u32, hour: u32) -> UnixNanos {
+        let dt = Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn advance_clock<H: FnMut(Bar) + 'static>(
+        aggregator: &Rc<RefCell<TimeBarAggregator<TestClock, H>>>,
+        to_time_ns: UnixNanos,
+    ) {
+        let events = aggregator.borrow_mut().clock.advance_time(to_time_ns, true);
+        let handlers = aggregator.borrow().clock.match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_daily_bars_cut_at_session_close(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Day, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+        let mut clock = TestClock::new();
+        clock.advance_time(utc(3, 8, 12), true);
+
+        let mut aggregator = TimeBarAggregator::new(
+            &instrument,
+            bar_type,
+            move |bar: Bar| handler_clone.lock().unwrap().push(bar),
+            false,
+            clock,
+            false,
+            false,
+            "left-open",
+        );
+        let calendar =
+            SessionCalendar::weekdays(TradingSession::new(9 * 3600 + 1800, 16 * 3600), 0)
+                .with_timezone(chrono_tz::America::New_York);
+        aggregator.set_session_calendar(calendar).unwrap();
+        let aggregator = Rc::new(RefCell::new(aggregator));
+        aggregator
+            .borrow_mut()
+            .start(NewBarCallback::new(aggregator.clone()))
+            .unwrap();
+
+        // Friday 16:00 EST, then Monday 16:00 EDT after the DST change on 10 March
+        assert_eq!(aggregator.borrow().next_close_ns, utc(3, 8, 21));
+        aggregator
+            .borrow_mut()
+            .update(Price::from("100.00"), Quantity::from(1), utc(3, 8, 15));
+        advance_clock(&aggregator, utc(3, 8, 21));
+
+        assert_eq!(aggregator.borrow().next_close_ns, utc(3, 11, 20));
+        aggregator
+            .borrow_mut()
+            .update(Price::from("101.00"), Quantity::from(2), utc(3, 11, 14));
+        advance_clock(&aggregator, utc(3, 11, 20));
+
+        let bars = handler.lock().unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].ts_init, utc(3, 8, 21));
+        assert_eq!(bars[1].ts_init, utc(3, 11, 20));
+        assert_eq!(bars[1].close, Price::from("101.00"));
+        assert_eq!(aggregator.borrow().next_close_ns, utc(3, 12, 20));
+        aggregator.borrow_mut().stop();
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_session_calendar_requires_daily_or_weekly(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Hour, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let mut aggregator = TimeBarAggregator::new(
+            &instrument,
+            bar_type,
+            |_bar: Bar| {},
+            false,
+            TestClock::new(),
+            false,
+            false,
+            "left-open",
+        );
+
+        assert!(aggregator
+            .set_session_calendar(SessionCalendar::new(0))
+            .is_err());
+    }
 }
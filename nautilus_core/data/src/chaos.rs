@@ -0,0 +1,375 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fault injection for data clients.
+//!
+//! A [`FaultyDataClient`] wraps a data client, injecting faults from a seeded schedule into the
+//! data it receives before the data is processed by the `DataEngine`. A disconnect fault stops
+//! the wrapped client, after which subscriptions fail and data is dropped until it is restarted.
+
+use std::cell::RefCell;
+
+use nautilus_common::{
+    capabilities::ClientCapabilities,
+    chaos::{Fault, FaultConfig, FaultInjector, FaultStats},
+    messages::data::{DataRequest, Payload},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data, DataType,
+    },
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::any::InstrumentAny,
+};
+
+use crate::client::DataClient;
+
+/// Provides a data client which injects faults into the data of a wrapped client.
+pub struct FaultyDataClient {
+    client: Box<dyn DataClient>,
+    injector: RefCell<FaultInjector<Data>>,
+}
+
+impl FaultyDataClient {
+    /// Creates a new [`FaultyDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `config` is invalid.
+    pub fn new(client: Box<dyn DataClient>, config: FaultConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            client,
+            injector: RefCell::new(FaultInjector::new(config)?),
+        })
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> FaultStats {
+        self.injector.borrow().stats()
+    }
+
+    /// Processes the `data` received from the wrapped client at `ts_now`, returning the data to
+    /// deliver to the `DataEngine`.
+    pub fn process(&self, data: Data, ts_now: UnixNanos) -> Vec<Data> {
+        let (fault, data) = self.injector.borrow_mut().process(data, ts_now);
+        if fault == Fault::Disconnect {
+            log::warn!("Injected disconnect fault for {}", self.client.client_id());
+            self.client.stop();
+        }
+        data
+    }
+
+    /// Returns the delayed data due for delivery at `ts_now`.
+    pub fn release(&self, ts_now: UnixNanos) -> Vec<Data> {
+        self.injector.borrow_mut().release(ts_now)
+    }
+
+    fn check_connected(&self) -> anyhow::Result<()> {
+        if self.injector.borrow().is_disconnected() {
+            anyhow::bail!(
+                "Client {} disconnected by fault injection",
+                self.client.client_id()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl DataClient for FaultyDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client.client_id()
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        self.client.venue()
+    }
+
+    /// Starts the wrapped client, restoring the connection after a disconnect fault.
+    fn start(&self) {
+        self.injector.borrow_mut().reconnect();
+        self.client.start();
+    }
+
+    fn stop(&self) {
+        self.client.stop();
+    }
+
+    fn reset(&self) {
+        self.client.reset();
+    }
+
+    fn dispose(&self) {
+        self.client.dispose();
+    }
+
+    fn is_connected(&self) -> bool {
+        !self.injector.borrow().is_disconnected() && self.client.is_connected()
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.injector.borrow().is_disconnected() || self.client.is_disconnected()
+    }
+
+    fn capabilities(&self) -> ClientCapabilities {
+        self.client.capabilities()
+    }
+
+    // -- COMMAND HANDLERS ---------------------------------------------------------------------------
+
+    fn subscribe(&mut self, data_type: &DataType) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe(data_type)
+    }
+
+    fn subscribe_instruments(&mut self, venue: Option<&Venue>) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_instruments(venue)
+    }
+
+    fn subscribe_instrument(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_instrument(instrument_id)
+    }
+
+    fn subscribe_order_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+        book_type: BookType,
+        depth: Option<usize>,
+    ) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client
+            .subscribe_order_book_deltas(instrument_id, book_type, depth)
+    }
+
+    fn subscribe_order_book_snapshots(
+        &mut self,
+        instrument_id: &InstrumentId,
+        book_type: BookType,
+        depth: Option<usize>,
+    ) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client
+            .subscribe_order_book_snapshots(instrument_id, book_type, depth)
+    }
+
+    fn subscribe_quote_ticks(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_quote_ticks(instrument_id)
+    }
+
+    fn subscribe_trade_ticks(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_trade_ticks(instrument_id)
+    }
+
+    fn subscribe_bars(&mut self, bar_type: &BarType) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_bars(bar_type)
+    }
+
+    fn subscribe_instrument_status(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_instrument_status(instrument_id)
+    }
+
+    fn subscribe_instrument_close(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.client.subscribe_instrument_close(instrument_id)
+    }
+
+    fn unsubscribe(&mut self, data_type: &DataType) -> anyhow::Result<()> {
+        self.client.unsubscribe(data_type)
+    }
+
+    fn unsubscribe_instruments(&mut self, venue: Option<&Venue>) -> anyhow::Result<()> {
+        self.client.unsubscribe_instruments(venue)
+    }
+
+    fn unsubscribe_instrument(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.client.unsubscribe_instrument(instrument_id)
+    }
+
+    fn unsubscribe_order_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        self.client.unsubscribe_order_book_deltas(instrument_id)
+    }
+
+    fn unsubscribe_order_book_snapshots(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        self.client.unsubscribe_order_book_snapshots(instrument_id)
+    }
+
+    fn unsubscribe_quote_ticks(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.client.unsubscribe_quote_ticks(instrument_id)
+    }
+
+    fn unsubscribe_trade_ticks(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.client.unsubscribe_trade_ticks(instrument_id)
+    }
+
+    fn unsubscribe_bars(&mut self, bar_type: &BarType) -> anyhow::Result<()> {
+        self.client.unsubscribe_bars(bar_type)
+    }
+
+    fn unsubscribe_instrument_status(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        self.client.unsubscribe_instrument_status(instrument_id)
+    }
+
+    fn unsubscribe_instrument_close(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.client.unsubscribe_instrument_close(instrument_id)
+    }
+
+    // -- DATA REQUEST HANDLERS ---------------------------------------------------------------------------
+
+    fn request_data(&self, request: DataRequest) {
+        self.client.request_data(request);
+    }
+
+    fn request_instruments(
+        &self,
+        correlation_id: UUID4,
+        venue: Venue,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<InstrumentAny> {
+        self.client
+            .request_instruments(correlation_id, venue, start, end)
+    }
+
+    fn request_instrument(
+        &self,
+        correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> InstrumentAny {
+        self.client
+            .request_instrument(correlation_id, instrument_id, start, end)
+    }
+
+    fn request_order_book_snapshot(
+        &self,
+        correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        depth: Option<usize>,
+    ) -> Payload {
+        self.client
+            .request_order_book_snapshot(correlation_id, instrument_id, depth)
+    }
+
+    fn request_quote_ticks(
+        &self,
+        correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+    ) -> Vec<QuoteTick> {
+        self.client
+            .request_quote_ticks(correlation_id, instrument_id, start, end, limit)
+    }
+
+    fn request_trade_ticks(
+        &self,
+        correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+    ) -> Vec<TradeTick> {
+        self.client
+            .request_trade_ticks(correlation_id, instrument_id, start, end, limit)
+    }
+
+    fn request_bars(
+        &self,
+        correlation_id: UUID4,
+        bar_type: BarType,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+    ) -> Vec<Bar> {
+        self.client
+            .request_bars(correlation_id, bar_type, start, end, limit)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use nautilus_common::{cache::Cache, msgbus::MessageBus};
+    use nautilus_model::data::stubs::quote_ethusdt_binance;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::mocks::MockDataClient;
+
+    fn faulty_client(config: FaultConfig) -> FaultyDataClient {
+        let client = MockDataClient::new(
+            Rc::new(RefCell::new(Cache::default())),
+            Rc::new(RefCell::new(MessageBus::default())),
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+        );
+        FaultyDataClient::new(Box::new(client), config).unwrap()
+    }
+
+    #[rstest]
+    fn test_passes_data_without_faults() {
+        let client = faulty_client(FaultConfig::default());
+        let quote = Data::Quote(quote_ethusdt_binance());
+
+        assert_eq!(client.process(quote.clone(), UnixNanos::default()), [quote]);
+        assert_eq!(client.stats().messages, 1);
+        assert_eq!(client.client_id(), ClientId::from("BINANCE"));
+    }
+
+    #[rstest]
+    fn test_disconnect_fault_until_restarted() {
+        let mut client = faulty_client(FaultConfig {
+            disconnect_probability: 1.0,
+            ..Default::default()
+        });
+        let quote = Data::Quote(quote_ethusdt_binance());
+        let instrument_id = quote.instrument_id();
+
+        assert!(client.process(quote, UnixNanos::default()).is_empty());
+        assert!(client.is_disconnected());
+        assert!(!client.is_connected());
+        assert!(client.subscribe_quote_ticks(&instrument_id).is_err());
+
+        client.start();
+        assert!(client.is_connected());
+        assert!(client.subscribe_quote_ticks(&instrument_id).is_ok());
+        assert_eq!(client.stats().disconnects, 1);
+    }
+}
@@ -27,6 +27,7 @@ use std::{
 
 use indexmap::IndexMap;
 use nautilus_common::{
+    capabilities::ClientCapabilities,
     clock::Clock,
     messages::data::{Action, DataRequest, DataResponse, Payload, SubscriptionCommand},
 };
@@ -52,6 +53,8 @@ pub trait DataClient {
     fn dispose(&self);
     fn is_connected(&self) -> bool;
     fn is_disconnected(&self) -> bool;
+    /// Returns the data types and order book types and depths supported by the client.
+    fn capabilities(&self) -> ClientCapabilities;
 
     // TODO: Move to separate trait
     // A [`LiveDataClient`] must have two channels to send back data and data responses
@@ -0,0 +1,178 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conflation of data for subscribers which cannot keep up with fast feeds.
+//!
+//! A [`Conflator`] delivers at most one update per interval, keeping the latest update received
+//! within the interval. The pending update is delivered when the interval elapses, either on the
+//! next update or when flushed by a timer. Dropped updates are counted for telemetry.
+
+use std::num::NonZeroU64;
+
+use nautilus_common::telemetry::IntCounter;
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos};
+
+/// Conflates a stream of data to at most one update per interval, keeping the latest.
+#[derive(Debug)]
+pub struct Conflator<T> {
+    interval_ns: u64,
+    last_published: Option<UnixNanos>,
+    pending: Option<T>,
+    dropped: u64,
+    drop_counter: IntCounter,
+}
+
+impl<T> Conflator<T> {
+    /// Creates a new [`Conflator`] instance, recording dropped updates to the `drop_counter`.
+    #[must_use]
+    pub fn new(interval_ms: NonZeroU64, drop_counter: IntCounter) -> Self {
+        Self {
+            interval_ns: interval_ms.get() * NANOSECONDS_IN_MILLISECOND,
+            last_published: None,
+            pending: None,
+            dropped: 0,
+            drop_counter,
+        }
+    }
+
+    /// Returns the conflation interval in nanoseconds.
+    #[must_use]
+    pub const fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    /// Returns the update pending delivery (if any).
+    #[must_use]
+    pub const fn pending(&self) -> Option<&T> {
+        self.pending.as_ref()
+    }
+
+    /// Returns the count of updates dropped by the conflator.
+    #[must_use]
+    pub const fn dropped_count(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Updates the conflator with the given `data` received at `ts_now`, returning the data if
+    /// it should be delivered immediately.
+    ///
+    /// Data received within the interval of the last delivery is held as pending, replacing any
+    /// previously pending data (which is dropped).
+    pub fn update(&mut self, data: T, ts_now: UnixNanos) -> Option<T> {
+        if self.is_due(ts_now) {
+            if self.pending.take().is_some() {
+                self.record_drop();
+            }
+            self.last_published = Some(ts_now);
+            return Some(data);
+        }
+
+        if self.pending.replace(data).is_some() {
+            self.record_drop();
+        }
+        None
+    }
+
+    /// Returns the pending data if the interval since the last delivery has elapsed at `ts_now`.
+    pub fn flush(&mut self, ts_now: UnixNanos) -> Option<T> {
+        if self.pending.is_none() || !self.is_due(ts_now) {
+            return None;
+        }
+
+        self.last_published = Some(ts_now);
+        self.pending.take()
+    }
+
+    fn is_due(&self, ts_now: UnixNanos) -> bool {
+        self.last_published
+            .is_none_or(|last| ts_now.as_u64() >= last.as_u64() + self.interval_ns)
+    }
+
+    fn record_drop(&mut self) {
+        self.dropped += 1;
+        self.drop_counter.inc();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::telemetry::Telemetry;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn conflator(telemetry: &Telemetry) -> Conflator<u32> {
+        Conflator::new(
+            NonZeroU64::new(50).unwrap(),
+            telemetry.conflated_counter("Test"),
+        )
+    }
+
+    fn ms(millis: u64) -> UnixNanos {
+        UnixNanos::from(millis * NANOSECONDS_IN_MILLISECOND)
+    }
+
+    #[rstest]
+    fn test_first_update_is_delivered_immediately() {
+        let telemetry = Telemetry::new().unwrap();
+        let mut conflator = conflator(&telemetry);
+
+        assert_eq!(conflator.update(1, ms(0)), Some(1));
+        assert_eq!(conflator.pending(), None);
+    }
+
+    #[rstest]
+    fn test_updates_within_interval_keep_latest() {
+        let telemetry = Telemetry::new().unwrap();
+        let mut conflator = conflator(&telemetry);
+
+        conflator.update(1, ms(0));
+        assert_eq!(conflator.update(2, ms(10)), None);
+        assert_eq!(conflator.update(3, ms(20)), None);
+
+        assert_eq!(conflator.pending(), Some(&3));
+        assert_eq!(conflator.dropped_count(), 1);
+        assert_eq!(telemetry.conflated_counter("Test").get(), 1);
+    }
+
+    #[rstest]
+    fn test_flush_delivers_pending_when_interval_elapsed() {
+        let telemetry = Telemetry::new().unwrap();
+        let mut conflator = conflator(&telemetry);
+        conflator.update(1, ms(0));
+        conflator.update(2, ms(10));
+
+        assert_eq!(conflator.flush(ms(40)), None);
+        assert_eq!(conflator.flush(ms(50)), Some(2));
+        assert_eq!(conflator.flush(ms(100)), None);
+        assert_eq!(conflator.update(3, ms(60)), None);
+        assert_eq!(conflator.dropped_count(), 0);
+    }
+
+    #[rstest]
+    fn test_update_after_interval_supersedes_pending() {
+        let telemetry = Telemetry::new().unwrap();
+        let mut conflator = conflator(&telemetry);
+        conflator.update(1, ms(0));
+        conflator.update(2, ms(10));
+
+        assert_eq!(conflator.update(3, ms(60)), Some(3));
+        assert_eq!(conflator.pending(), None);
+        assert_eq!(conflator.dropped_count(), 1);
+    }
+}
@@ -0,0 +1,320 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Consolidation of quotes for the same instrument from multiple venues.
+//!
+//! A [`ConsolidatedFeed`] merges the latest quotes from each of its component instruments into a
+//! best bid and offer composite (in the style of a national best bid and offer) on a composite
+//! instrument ID, with attribution of the venues quoting at the best prices.
+
+use std::fmt::{Display, Formatter};
+
+use anyhow::bail;
+use indexmap::IndexMap;
+use nautilus_model::{
+    data::quote::QuoteTick,
+    identifiers::{InstrumentId, Venue},
+    types::quantity::Quantity,
+};
+
+/// Represents a consolidated best bid and offer quote, with the venues quoting at each price.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsolidatedQuote {
+    /// The composite quote, with the sizes summed across the venues at the best prices.
+    pub quote: QuoteTick,
+    /// The venues quoting at the best bid price.
+    pub bid_venues: Vec<Venue>,
+    /// The venues quoting at the best ask price.
+    pub ask_venues: Vec<Venue>,
+}
+
+impl Display for ConsolidatedQuote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |venues: &[Venue]| {
+            venues
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+        write!(
+            f,
+            "{},bid_venues={},ask_venues={}",
+            self.quote,
+            join(&self.bid_venues),
+            join(&self.ask_venues),
+        )
+    }
+}
+
+/// Merges quotes for the same instrument from multiple venues into a composite quote.
+#[derive(Debug)]
+pub struct ConsolidatedFeed {
+    instrument_id: InstrumentId,
+    max_quote_age_ns: Option<u64>,
+    quotes: IndexMap<InstrumentId, Option<QuoteTick>>,
+    last: Option<ConsolidatedQuote>,
+}
+
+impl ConsolidatedFeed {
+    /// Creates a new [`ConsolidatedFeed`] instance producing quotes for the composite
+    /// `instrument_id` from the `components`.
+    ///
+    /// Component quotes older than `max_quote_age_ns` relative to the latest quote are excluded
+    /// from the composite (all quotes are included if None).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `components` is empty or contains duplicates.
+    /// - If `components` contains the composite `instrument_id`.
+    pub fn new(
+        instrument_id: InstrumentId,
+        components: Vec<InstrumentId>,
+        max_quote_age_ns: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        if components.is_empty() {
+            bail!("No components for consolidated feed {instrument_id}");
+        }
+        if components.contains(&instrument_id) {
+            bail!("Consolidated feed {instrument_id} cannot be one of its own components");
+        }
+
+        let mut quotes = IndexMap::new();
+        for component in components {
+            if quotes.insert(component, None).is_some() {
+                bail!("Duplicate component {component} for consolidated feed {instrument_id}");
+            }
+        }
+
+        Ok(Self {
+            instrument_id,
+            max_quote_age_ns,
+            quotes,
+            last: None,
+        })
+    }
+
+    /// Returns the composite instrument ID for the feed.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the component instrument IDs for the feed.
+    #[must_use]
+    pub fn components(&self) -> Vec<InstrumentId> {
+        self.quotes.keys().copied().collect()
+    }
+
+    /// Returns whether `instrument_id` is a component of the feed.
+    #[must_use]
+    pub fn contains(&self, instrument_id: &InstrumentId) -> bool {
+        self.quotes.contains_key(instrument_id)
+    }
+
+    /// Returns the last consolidated quote produced by the feed.
+    #[must_use]
+    pub const fn last(&self) -> Option<&ConsolidatedQuote> {
+        self.last.as_ref()
+    }
+
+    /// Updates the feed with the given component `quote`, returning a new consolidated quote if
+    /// the best bid or offer changed.
+    pub fn update(&mut self, quote: &QuoteTick) -> Option<ConsolidatedQuote> {
+        let slot = self.quotes.get_mut(&quote.instrument_id)?;
+        if slot.is_some_and(|last| last.ts_event > quote.ts_event) {
+            return None; // Ignore out of order quotes
+        }
+        *slot = Some(*quote);
+
+        let is_current = |q: &&QuoteTick| {
+            self.max_quote_age_ns.is_none_or(|max_age_ns| {
+                quote.ts_event.as_u64().saturating_sub(q.ts_event.as_u64()) <= max_age_ns
+            })
+        };
+        let current: Vec<&QuoteTick> = self.quotes.values().flatten().filter(is_current).collect();
+
+        let best_bid = current.iter().map(|q| q.bid_price).max()?;
+        let best_ask = current.iter().map(|q| q.ask_price).min()?;
+        let bids: Vec<&QuoteTick> = current
+            .iter()
+            .copied()
+            .filter(|q| q.bid_price == best_bid)
+            .collect();
+        let asks: Vec<&QuoteTick> = current
+            .iter()
+            .copied()
+            .filter(|q| q.ask_price == best_ask)
+            .collect();
+
+        let composite = QuoteTick {
+            instrument_id: self.instrument_id,
+            bid_price: best_bid,
+            ask_price: best_ask,
+            bid_size: sum_sizes(bids.iter().map(|q| q.bid_size)),
+            ask_size: sum_sizes(asks.iter().map(|q| q.ask_size)),
+            ts_event: quote.ts_event,
+            ts_init: quote.ts_init,
+        };
+        if self.last.as_ref().is_some_and(|last| {
+            let last = &last.quote;
+            (last.bid_price, last.ask_price, last.bid_size, last.ask_size)
+                == (
+                    composite.bid_price,
+                    composite.ask_price,
+                    composite.bid_size,
+                    composite.ask_size,
+                )
+        }) {
+            return None;
+        }
+
+        let consolidated = ConsolidatedQuote {
+            quote: composite,
+            bid_venues: bids.iter().map(|q| q.instrument_id.venue).collect(),
+            ask_venues: asks.iter().map(|q| q.instrument_id.venue).collect(),
+        };
+        self.last = Some(consolidated.clone());
+        Some(consolidated)
+    }
+}
+
+fn sum_sizes(sizes: impl Iterator<Item = Quantity>) -> Quantity {
+    let (raw, precision) = sizes.fold((0, 0), |(raw, precision), size| {
+        (raw + size.raw, precision.max(size.precision))
+    });
+    Quantity::from_raw(raw, precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::types::price::Price;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str, size: &str, ts: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            bid_price: Price::from(bid),
+            ask_price: Price::from(ask),
+            bid_size: Quantity::from(size),
+            ask_size: Quantity::from(size),
+            ts_event: UnixNanos::from(ts),
+            ts_init: UnixNanos::from(ts),
+        }
+    }
+
+    fn feed(max_quote_age_ns: Option<u64>) -> ConsolidatedFeed {
+        ConsolidatedFeed::new(
+            InstrumentId::from("AAPL.COMPOSITE"),
+            vec![
+                InstrumentId::from("AAPL.XNAS"),
+                InstrumentId::from("AAPL.ARCX"),
+                InstrumentId::from("AAPL.BATS"),
+            ],
+            max_quote_age_ns,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_new_validation() {
+        let id = InstrumentId::from("AAPL.COMPOSITE");
+        assert!(ConsolidatedFeed::new(id, vec![], None).is_err());
+        assert!(ConsolidatedFeed::new(id, vec![id], None).is_err());
+        let xnas = InstrumentId::from("AAPL.XNAS");
+        assert_eq!(
+            ConsolidatedFeed::new(id, vec![xnas, xnas], None)
+                .unwrap_err()
+                .to_string(),
+            "Duplicate component AAPL.XNAS for consolidated feed AAPL.COMPOSITE"
+        );
+    }
+
+    #[rstest]
+    fn test_consolidates_best_bid_and_offer_with_attribution() {
+        let mut feed = feed(None);
+        feed.update(&quote("AAPL.XNAS", "100.00", "100.05", "100", 1))
+            .unwrap();
+        feed.update(&quote("AAPL.ARCX", "100.01", "100.05", "200", 2))
+            .unwrap();
+        let consolidated = feed
+            .update(&quote("AAPL.BATS", "100.01", "100.06", "300", 3))
+            .unwrap();
+
+        let composite = consolidated.quote;
+        assert_eq!(
+            composite.instrument_id,
+            InstrumentId::from("AAPL.COMPOSITE")
+        );
+        assert_eq!(composite.bid_price, Price::from("100.01"));
+        assert_eq!(composite.ask_price, Price::from("100.05"));
+        assert_eq!(composite.bid_size, Quantity::from("500"));
+        assert_eq!(composite.ask_size, Quantity::from("300"));
+        assert_eq!(composite.ts_event, UnixNanos::from(3));
+        assert_eq!(
+            consolidated.bid_venues,
+            vec![Venue::from("ARCX"), Venue::from("BATS")]
+        );
+        assert_eq!(
+            consolidated.ask_venues,
+            vec![Venue::from("XNAS"), Venue::from("ARCX")]
+        );
+        assert_eq!(feed.last(), Some(&consolidated));
+    }
+
+    #[rstest]
+    fn test_unchanged_composite_is_not_emitted() {
+        let mut feed = feed(None);
+        assert!(feed
+            .update(&quote("AAPL.XNAS", "100.00", "100.05", "100", 1))
+            .is_some());
+        // Worse prices on another venue do not change the composite
+        assert!(feed
+            .update(&quote("AAPL.ARCX", "99.99", "100.06", "100", 2))
+            .is_none());
+        assert!(feed
+            .update(&quote("AAPL.MSFT", "101.00", "101.05", "100", 3))
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_stale_component_quotes_are_excluded() {
+        let mut feed = feed(Some(10));
+        feed.update(&quote("AAPL.XNAS", "100.02", "100.03", "100", 1))
+            .unwrap();
+        let consolidated = feed
+            .update(&quote("AAPL.ARCX", "100.00", "100.05", "100", 20))
+            .unwrap();
+        assert_eq!(consolidated.quote.bid_price, Price::from("100.00"));
+        assert_eq!(consolidated.bid_venues, vec![Venue::from("ARCX")]);
+    }
+
+    #[rstest]
+    fn test_out_of_order_quote_is_ignored() {
+        let mut feed = feed(None);
+        feed.update(&quote("AAPL.XNAS", "100.00", "100.05", "100", 5))
+            .unwrap();
+        assert!(feed
+            .update(&quote("AAPL.XNAS", "100.01", "100.05", "100", 4))
+            .is_none());
+    }
+}
@@ -0,0 +1,246 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Delayed republishing of market data, to simulate subscribers with slower data paths.
+//!
+//! A [`DelayedDataPublisher`] subscribes to source topics on the message bus and republishes
+//! each message on a delayed topic once the clock reaches the message `ts_init` plus the delay.
+//! This allows a backtest where, for example, signal generation consumes delayed consolidated
+//! data while execution consumes real-time direct-feed data.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    num::NonZeroU64,
+    rc::Rc,
+};
+
+use indexmap::IndexMap;
+use nautilus_common::{
+    clock::Clock,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::datetime::NANOSECONDS_IN_MILLISECOND;
+use nautilus_model::data::{Data, GetTsInit};
+use ustr::Ustr;
+
+/// Returns the topic on which the data published on `source_topic` is republished after
+/// `delay_ms`.
+#[must_use]
+pub fn delayed_topic(source_topic: &Ustr, delay_ms: NonZeroU64) -> Ustr {
+    Ustr::from(&format!("delayed.{delay_ms}.{source_topic}"))
+}
+
+/// Republishes data streams from the message bus with a configurable delay.
+pub struct DelayedDataPublisher {
+    clock: Rc<RefCell<dyn Clock>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    feeds: IndexMap<Ustr, (Ustr, ShareableMessageHandler)>,
+}
+
+impl DelayedDataPublisher {
+    /// Creates a new [`DelayedDataPublisher`] instance.
+    pub fn new(clock: Rc<RefCell<dyn Clock>>, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            clock,
+            msgbus,
+            feeds: IndexMap::new(),
+        }
+    }
+
+    /// Returns the delayed topics of the feeds.
+    #[must_use]
+    pub fn delayed_topics(&self) -> Vec<Ustr> {
+        self.feeds.keys().copied().collect()
+    }
+
+    /// Adds a feed which republishes the `T` data published on `source_topic` after `delay_ms`,
+    /// returning the delayed topic to subscribe to.
+    ///
+    /// Adding a feed which already exists has no effect.
+    pub fn add_feed<T: GetTsInit + Clone + 'static>(
+        &mut self,
+        source_topic: Ustr,
+        delay_ms: NonZeroU64,
+    ) -> Ustr {
+        let topic = delayed_topic(&source_topic, delay_ms);
+        if self.feeds.contains_key(&topic) {
+            return topic;
+        }
+
+        let handler = ShareableMessageHandler(Rc::new(DelayedDataHandler::<T> {
+            id: topic,
+            delay_ns: delay_ms.get() * NANOSECONDS_IN_MILLISECOND,
+            clock: self.clock.clone(),
+            msgbus: self.msgbus.clone(),
+            count: Cell::new(0),
+            _phantom: PhantomData,
+        }));
+        self.msgbus
+            .borrow_mut()
+            .subscribe(source_topic, handler.clone(), None);
+
+        log::info!("Added delayed feed {topic}");
+        self.feeds.insert(topic, (source_topic, handler));
+        topic
+    }
+
+    /// Removes the feed for the given `delayed_topic`, data already received is still
+    /// republished.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no feed exists for the delayed topic.
+    pub fn remove_feed(&mut self, delayed_topic: &Ustr) -> anyhow::Result<()> {
+        let Some((source_topic, handler)) = self.feeds.shift_remove(delayed_topic) else {
+            anyhow::bail!("No delayed feed for {delayed_topic}");
+        };
+
+        self.msgbus.borrow_mut().unsubscribe(source_topic, handler);
+        log::info!("Removed delayed feed {delayed_topic}");
+        Ok(())
+    }
+}
+
+// Schedules each message received to be republished on the delayed topic
+struct DelayedDataHandler<T> {
+    id: Ustr,
+    delay_ns: u64,
+    clock: Rc<RefCell<dyn Clock>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    count: Cell<u64>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: GetTsInit + Clone + 'static> MessageHandler for DelayedDataHandler<T> {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let Some(data) = message.downcast_ref::<T>() else {
+            log::error!("Invalid message type for delayed feed {}", self.id);
+            return;
+        };
+
+        let count = self.count.get() + 1;
+        self.count.set(count);
+
+        let mut clock = self.clock.borrow_mut();
+        // Alerts must be in the future, so data already past its delay is released next
+        let release_ns =
+            (data.ts_init().as_u64() + self.delay_ns).max(clock.timestamp_ns().as_u64() + 1);
+
+        let data = data.clone();
+        let topic = self.id;
+        let msgbus = self.msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+            msgbus.borrow().publish(&topic, &data as &dyn Any);
+        }));
+        clock.set_time_alert_ns(
+            &format!("{}|{count}", self.id),
+            release_ns.into(),
+            Some(callback),
+        );
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::data::{quote::QuoteTick, stubs::quote_ethusdt_binance};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delayed_topic() {
+        let topic = delayed_topic(
+            &Ustr::from("data.quotes.BINANCE.ETHUSDT-PERP"),
+            NonZeroU64::new(200).unwrap(),
+        );
+
+        assert_eq!(topic, "delayed.200.data.quotes.BINANCE.ETHUSDT-PERP");
+    }
+
+    #[rstest]
+    fn test_data_republished_after_delay() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let mut publisher = DelayedDataPublisher::new(clock.clone(), msgbus.clone());
+        let quote = quote_ethusdt_binance();
+        let source_topic = msgbus
+            .borrow_mut()
+            .switchboard
+            .get_quote_topic(quote.instrument_id);
+        let topic = publisher.add_feed::<QuoteTick>(source_topic, NonZeroU64::new(200).unwrap());
+        let real_time_handler = get_message_saving_handler::<QuoteTick>(None);
+        let delayed_handler = get_message_saving_handler::<QuoteTick>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe(source_topic, real_time_handler.clone(), None);
+        msgbus
+            .borrow_mut()
+            .subscribe(topic, delayed_handler.clone(), None);
+
+        msgbus.borrow().publish(&source_topic, &quote as &dyn Any);
+        TestClock::advance_time_and_run(&clock, UnixNanos::from(199 * NANOSECONDS_IN_MILLISECOND));
+        assert_eq!(get_saved_messages::<QuoteTick>(real_time_handler).len(), 1);
+        assert!(get_saved_messages::<QuoteTick>(delayed_handler.clone()).is_empty());
+
+        TestClock::advance_time_and_run(&clock, UnixNanos::from(201 * NANOSECONDS_IN_MILLISECOND));
+        assert_eq!(
+            get_saved_messages::<QuoteTick>(delayed_handler),
+            vec![quote]
+        );
+    }
+
+    #[rstest]
+    fn test_remove_feed() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let mut publisher = DelayedDataPublisher::new(clock, msgbus);
+        let topic = publisher.add_feed::<QuoteTick>(
+            Ustr::from("data.quotes.BINANCE.ETHUSDT-PERP"),
+            NonZeroU64::new(50).unwrap(),
+        );
+
+        assert_eq!(publisher.delayed_topics(), vec![topic]);
+        publisher.remove_feed(&topic).unwrap();
+        assert!(publisher.delayed_topics().is_empty());
+        assert!(publisher.remove_feed(&topic).is_err());
+    }
+}
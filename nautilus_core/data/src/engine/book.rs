@@ -17,15 +17,20 @@ use std::{
     any::Any,
     cell::{Ref, RefCell},
     rc::Rc,
+    time::Instant,
 };
 
 use nautilus_common::{
     cache::Cache,
     messages::data::DataResponse,
     msgbus::{handler::MessageHandler, MessageBus},
+    telemetry::telemetry,
     timer::TimeEvent,
 };
-use nautilus_model::{data::Data, identifiers::InstrumentId};
+use nautilus_model::{
+    data::{depth::OrderBookDepth, Data},
+    identifiers::InstrumentId,
+};
 use ustr::Ustr;
 
 use super::BookSnapshotInfo;
@@ -60,12 +65,17 @@ impl MessageHandler for BookUpdater {
             .borrow_mut()
             .order_book_mut(&data.instrument_id())
         {
+            let start = Instant::now();
             match data {
                 Data::Delta(delta) => book.apply_delta(&delta),
                 Data::Deltas(deltas) => book.apply_deltas(&deltas),
-                Data::Depth10(depth) => book.apply_depth(&depth),
-                _ => log::error!("Invalid data type for book update, was {data:?}"),
+                Data::Depth10(depth) => book.apply_depth(&OrderBookDepth::from(depth)),
+                _ => {
+                    log::error!("Invalid data type for book update, was {data:?}");
+                    return;
+                }
             }
+            telemetry().observe_book_apply(start.elapsed());
         }
     }
     fn as_any(&self) -> &dyn Any {
@@ -129,3 +139,48 @@ impl BookSnapshotter {
         self.msgbus.borrow_mut().publish(topic, book as &dyn Any);
     }
 }
+
+/// Publishes periodic [`OrderBookDepth10`](nautilus_model::data::depth::OrderBookDepth10) snapshots of an order book maintained in the cache.
+///
+/// Snapshots are only published once the book has been updated, so that book history can be
+/// recorded at a fixed interval without storing every delta.
+pub struct BookDepthSnapshotter {
+    pub instrument_id: InstrumentId,
+    pub topic: Ustr,
+    pub cache: Rc<RefCell<Cache>>,
+    pub msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl BookDepthSnapshotter {
+    /// Creates a new [`BookDepthSnapshotter`] instance.
+    pub const fn new(
+        instrument_id: InstrumentId,
+        topic: Ustr,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            instrument_id,
+            topic,
+            cache,
+            msgbus,
+        }
+    }
+
+    pub fn snapshot(&self, event: TimeEvent) {
+        let depth = match self.cache.borrow().order_book(&self.instrument_id) {
+            Some(book) if book.count > 0 => book.to_depth10(event.ts_event),
+            _ => {
+                log::debug!(
+                    "OrderBook for {} not yet updated for depth snapshot",
+                    self.instrument_id
+                );
+                return;
+            }
+        };
+
+        self.msgbus
+            .borrow()
+            .publish(&self.topic, &depth as &dyn Any);
+    }
+}
@@ -13,9 +13,17 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_model::identifiers::ClientId;
+use std::num::NonZeroU64;
+
+use nautilus_common::config::{check_setting, ValidateConfig};
+use nautilus_model::identifiers::{ClientId, InstrumentId};
+use serde::{Deserialize, Serialize};
+
+use crate::{quality::DataQualityConfig, rates::MessageRateConfig, sequencing::LateDataConfig};
 
 /// Configuration for `DataEngine` instances.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DataEngineConfig {
     pub time_bars_build_with_no_updates: bool,
     pub time_bars_timestamp_on_close: bool,
@@ -24,6 +32,14 @@ pub struct DataEngineConfig {
     pub buffer_deltas: bool,
     pub external_clients: Option<Vec<ClientId>>,
     pub debug: bool,
+    /// The configuration for live data quality monitoring (disabled if None).
+    pub quality: Option<DataQualityConfig>,
+    /// The handling of late and duplicate data.
+    pub late_data: LateDataConfig,
+    /// The configuration for market data message rate statistics (disabled if None).
+    pub message_rates: Option<MessageRateConfig>,
+    /// The configuration for periodic order book depth snapshots (disabled if None).
+    pub book_depth_snapshots: Option<BookDepthSnapshotConfig>,
 }
 
 impl Default for DataEngineConfig {
@@ -37,6 +53,65 @@ impl Default for DataEngineConfig {
             buffer_deltas: false,
             external_clients: None,
             debug: false,
+            quality: None,
+            late_data: LateDataConfig::default(),
+            message_rates: None,
+            book_depth_snapshots: None,
         }
     }
 }
+
+/// Configuration for periodic `OrderBookDepth10` snapshots of the order books maintained by the
+/// `DataEngine`, published on `data.book.depth_snapshots.{venue}.{symbol}.{interval_ms}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookDepthSnapshotConfig {
+    /// The instruments to snapshot the order books for.
+    pub instrument_ids: Vec<InstrumentId>,
+    /// The interval between snapshots, in milliseconds.
+    pub interval_ms: NonZeroU64,
+}
+
+impl ValidateConfig for BookDepthSnapshotConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            !self.instrument_ids.is_empty(),
+            "data_engine.book_depth_snapshots.instrument_ids",
+            "must not be empty",
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_book_depth_snapshot_config_validate() {
+        let mut config = BookDepthSnapshotConfig {
+            instrument_ids: vec![InstrumentId::from("ETHUSDT-PERP.BINANCE")],
+            interval_ms: NonZeroU64::new(1_000).unwrap(),
+        };
+        assert!(config.validate().is_ok());
+
+        config.instrument_ids.clear();
+        assert!(config.validate().is_err());
+    }
+
+    #[rstest]
+    fn test_book_depth_snapshot_config_deserialize() {
+        let config: BookDepthSnapshotConfig = serde_json::from_str(
+            r#"{"instrument_ids": ["ETHUSDT-PERP.BINANCE"], "interval_ms": 1000}"#,
+        )
+        .unwrap();
+        assert_eq!(config.interval_ms.get(), 1_000);
+        assert!(serde_json::from_str::<BookDepthSnapshotConfig>(
+            r#"{"instrument_ids": ["ETHUSDT-PERP.BINANCE"], "interval_ms": 0}"#,
+        )
+        .is_err());
+    }
+}
@@ -50,19 +50,23 @@ use std::{
     sync::Arc,
 };
 
-use book::{BookSnapshotter, BookUpdater};
+use book::{BookDepthSnapshotter, BookSnapshotter, BookUpdater};
 use config::DataEngineConfig;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
     logging::{RECV, RES},
-    messages::data::{DataRequest, DataResponse, SubscriptionCommand},
+    messages::{
+        data::{Action, DataRequest, DataResponse, SubscriptionCommand},
+        quality::DataQualityAlert,
+    },
     msgbus::{
         handler::{MessageHandler, ShareableMessageHandler},
         MessageBus,
     },
-    timer::TimeEventCallback,
+    telemetry::{telemetry, IntCounter},
+    timer::{TimeEvent, TimeEventCallback},
 };
 use nautilus_core::{
     correctness::{check_key_in_index_map, check_key_not_in_index_map, FAILED},
@@ -79,13 +83,24 @@ use nautilus_model::{
         Data, DataType,
     },
     enums::{BookType, RecordFlag},
+    events::instrument::InstrumentUpdate,
     identifiers::{ClientId, InstrumentId, Venue},
     instruments::{any::InstrumentAny, synthetic::SyntheticInstrument},
     orderbook::book::OrderBook,
 };
 use ustr::Ustr;
 
-use crate::{aggregation::BarAggregator, client::DataClientAdapter};
+use crate::{
+    aggregation::BarAggregator,
+    client::DataClientAdapter,
+    conflation::Conflator,
+    consolidation::{ConsolidatedFeed, ConsolidatedQuote},
+    quality::{publish_data_quality_alerts, DataQualityMonitor},
+    rates::{MessageRateMonitor, MessageRateStats},
+    sequencing::{DataSequencer, LateDataPolicy},
+};
+
+const LATE_DATA_TIMER_NAME: &str = "DataEngine|late_data";
 
 /// Provides a high-performance `DataEngine` for all environments.
 pub struct DataEngine {
@@ -100,22 +115,45 @@ pub struct DataEngine {
     bar_aggregators: Vec<Box<dyn BarAggregator>>, // TODO: dyn for now
     synthetic_quote_feeds: HashMap<InstrumentId, Vec<SyntheticInstrument>>,
     synthetic_trade_feeds: HashMap<InstrumentId, Vec<SyntheticInstrument>>,
+    consolidated_feeds: IndexMap<InstrumentId, ConsolidatedFeed>,
+    conflated_quotes: IndexMap<(InstrumentId, NonZeroU64), Rc<RefCell<Conflator<QuoteTick>>>>,
+    conflated_trades: IndexMap<(InstrumentId, NonZeroU64), Rc<RefCell<Conflator<TradeTick>>>>,
+    book_depth_snapshots: IndexSet<(InstrumentId, NonZeroU64)>,
     buffered_deltas_map: HashMap<InstrumentId, Vec<OrderBookDelta>>,
     msgbus_priority: u8,
     command_queue: VecDeque<SubscriptionCommand>,
+    message_counter: IntCounter,
     config: DataEngineConfig,
+    quality_monitor: Option<Rc<RefCell<DataQualityMonitor>>>,
+    rate_monitor: Option<MessageRateMonitor>,
+    sequencer: Rc<RefCell<DataSequencer>>,
+    released_data: Rc<RefCell<VecDeque<Data>>>,
 }
 
 impl DataEngine {
     /// Creates a new [`DataEngine`] instance.
     #[must_use]
     pub fn new(
-        clock: Box<dyn Clock>,
+        mut clock: Box<dyn Clock>,
         cache: Rc<RefCell<Cache>>,
         msgbus: Rc<RefCell<MessageBus>>,
         config: Option<DataEngineConfig>,
     ) -> Self {
-        Self {
+        let config = config.unwrap_or_default();
+        let quality_monitor = config.quality.clone().map(|quality_config| {
+            let monitor = Rc::new(RefCell::new(DataQualityMonitor::new(quality_config)));
+            Self::start_stale_data_timer(clock.as_mut(), &monitor, &msgbus);
+            monitor
+        });
+        let rate_monitor = config.message_rates.clone().map(MessageRateMonitor::new);
+        let sequencer = Rc::new(RefCell::new(DataSequencer::new(config.late_data.clone())));
+        let released_data = Rc::new(RefCell::new(VecDeque::new()));
+        if config.late_data.policy == LateDataPolicy::Reorder {
+            Self::start_late_data_timer(clock.as_mut(), &sequencer, &released_data);
+        }
+        let book_depth_snapshots = config.book_depth_snapshots.clone();
+
+        let mut engine = Self {
             clock,
             cache,
             msgbus,
@@ -127,11 +165,77 @@ impl DataEngine {
             bar_aggregators: Vec::new(),
             synthetic_quote_feeds: HashMap::new(),
             synthetic_trade_feeds: HashMap::new(),
+            consolidated_feeds: IndexMap::new(),
+            conflated_quotes: IndexMap::new(),
+            conflated_trades: IndexMap::new(),
+            book_depth_snapshots: IndexSet::new(),
             buffered_deltas_map: HashMap::new(),
             msgbus_priority: 10, // High-priority for built-in component
             command_queue: VecDeque::new(),
-            config: config.unwrap_or_default(),
+            message_counter: telemetry().message_counter(stringify!(DataEngine)),
+            config,
+            quality_monitor,
+            rate_monitor,
+            sequencer,
+            released_data,
+        };
+
+        if let Some(snapshot_config) = book_depth_snapshots {
+            for instrument_id in snapshot_config.instrument_ids {
+                engine.start_book_depth_snapshots(instrument_id, snapshot_config.interval_ms);
+            }
         }
+
+        engine
+    }
+
+    fn start_stale_data_timer(
+        clock: &mut dyn Clock,
+        monitor: &Rc<RefCell<DataQualityMonitor>>,
+        msgbus: &Rc<RefCell<MessageBus>>,
+    ) {
+        let Some(interval_ns) = monitor.borrow().stale_threshold_ns() else {
+            return;
+        };
+
+        let monitor = monitor.clone();
+        let msgbus = msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let alerts = monitor.borrow_mut().check_stale(event.ts_event);
+            publish_data_quality_alerts(&mut msgbus.borrow_mut(), &alerts);
+        }));
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            "DataEngine|data_quality",
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        );
+    }
+
+    // Releases the data buffered for longer than the reorder window, so it is handled without
+    // waiting for further data to arrive
+    fn start_late_data_timer(
+        clock: &mut dyn Clock,
+        sequencer: &Rc<RefCell<DataSequencer>>,
+        released_data: &Rc<RefCell<VecDeque<Data>>>,
+    ) {
+        let interval_ns = sequencer.borrow().reorder_window_ns();
+        let sequencer = sequencer.clone();
+        let released_data = released_data.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let released = sequencer.borrow_mut().release_expired(event.ts_event);
+            released_data.borrow_mut().extend(released);
+        }));
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            LATE_DATA_TIMER_NAME,
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        );
     }
 
     /// Provides read-only access to the cache.
@@ -140,6 +244,74 @@ impl DataEngine {
         self.cache.borrow()
     }
 
+    /// Returns the order books (and intervals) for which depth snapshots are being published.
+    #[must_use]
+    pub fn book_depth_snapshots(&self) -> Vec<(InstrumentId, NonZeroU64)> {
+        self.book_depth_snapshots.iter().copied().collect()
+    }
+
+    /// Starts publishing `OrderBookDepth10` snapshots of the order book for the given
+    /// `instrument_id` every `interval_ms`, aligned to the interval.
+    ///
+    /// The order book must be maintained in the cache (e.g. from an order book deltas
+    /// subscription), snapshots are skipped until the book has been updated.
+    pub fn start_book_depth_snapshots(
+        &mut self,
+        instrument_id: InstrumentId,
+        interval_ms: NonZeroU64,
+    ) {
+        if !self
+            .book_depth_snapshots
+            .insert((instrument_id, interval_ms))
+        {
+            return;
+        }
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_depth_snapshot_topic(instrument_id, interval_ms);
+        let snapshotter = BookDepthSnapshotter::new(
+            instrument_id,
+            topic,
+            self.cache.clone(),
+            self.msgbus.clone(),
+        );
+        let callback = TimeEventCallback::Rust(Rc::new(move |event| snapshotter.snapshot(event)));
+
+        let interval_ns = millis_to_nanos(interval_ms.get() as f64);
+        let now_ns = self.clock.timestamp_ns().as_u64();
+        let start_time_ns = now_ns - (now_ns % interval_ns);
+        self.clock.set_timer_ns(
+            &book_depth_snapshot_timer_name(instrument_id, interval_ms),
+            interval_ns,
+            start_time_ns.into(),
+            None,
+            Some(callback),
+        );
+
+        log::info!("Snapshotting OrderBookDepth10 for {instrument_id} every {interval_ms}ms");
+    }
+
+    /// Stops publishing order book depth snapshots for the given `instrument_id` and `interval_ms`.
+    pub fn stop_book_depth_snapshots(
+        &mut self,
+        instrument_id: InstrumentId,
+        interval_ms: NonZeroU64,
+    ) {
+        if self
+            .book_depth_snapshots
+            .shift_remove(&(instrument_id, interval_ms))
+        {
+            self.clock
+                .cancel_timer(&book_depth_snapshot_timer_name(instrument_id, interval_ms));
+            log::info!(
+                "Stopped snapshotting OrderBookDepth10 for {instrument_id} at {interval_ms}ms"
+            );
+        }
+    }
+
     // pub fn register_catalog(&mut self, catalog: ParquetDataCatalog) {}  TODO: Implement catalog
 
     /// Registers the given data `client` with the engine as the default routing client.
@@ -159,7 +331,8 @@ impl DataEngine {
         self.clients.values().for_each(|client| client.start());
     }
 
-    pub fn stop(self) {
+    pub fn stop(mut self) {
+        self.flush_late_data();
         self.clients.values().for_each(|client| client.stop());
     }
 
@@ -219,6 +392,22 @@ impl DataEngine {
         }
     }
 
+    // Checks the `data_type` is supported by the client it is routed to (if found)
+    fn check_capabilities(
+        &self,
+        client_id: &ClientId,
+        venue: &Venue,
+        data_type: &DataType,
+    ) -> anyhow::Result<()> {
+        let Some(client) = self.get_client(client_id, venue) else {
+            return Ok(());
+        };
+        client
+            .capabilities()
+            .check_data_type(data_type)
+            .map_err(|e| anyhow::anyhow!("{e} by {}", client.client_id))
+    }
+
     fn get_client_mut(
         &mut self,
         client_id: &ClientId,
@@ -322,6 +511,45 @@ impl DataEngine {
         log::info!("Deregistered client {client_id}");
     }
 
+    /// Adds the consolidated quote `feed`, which is updated with the quotes for its components.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a feed for the same composite instrument ID has already been added.
+    pub fn add_consolidated_feed(&mut self, feed: ConsolidatedFeed) {
+        let instrument_id = feed.instrument_id();
+        check_key_not_in_index_map(
+            &instrument_id,
+            &self.consolidated_feeds,
+            "instrument_id",
+            "consolidated_feeds",
+        )
+        .expect(FAILED);
+
+        log::info!("Added consolidated feed {instrument_id}");
+        self.consolidated_feeds.insert(instrument_id, feed);
+    }
+
+    /// Removes the consolidated quote feed for the composite `instrument_id`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If no feed for the composite instrument ID has been added.
+    pub fn remove_consolidated_feed(&mut self, instrument_id: &InstrumentId) {
+        check_key_in_index_map(
+            instrument_id,
+            &self.consolidated_feeds,
+            "instrument_id",
+            "consolidated_feeds",
+        )
+        .expect(FAILED);
+
+        self.consolidated_feeds.shift_remove(instrument_id);
+        log::info!("Removed consolidated feed {instrument_id}");
+    }
+
     pub fn run(&mut self) {
         let commands: Vec<_> = self.command_queue.drain(..).collect();
         for cmd in commands {
@@ -338,11 +566,18 @@ impl DataEngine {
     }
 
     pub fn execute(&mut self, cmd: SubscriptionCommand) {
+        if matches!(cmd.action, Action::Subscribe) {
+            if let Err(e) = self.check_capabilities(&cmd.client_id, &cmd.venue, &cmd.data_type) {
+                log::error!("Cannot handle subscription: {e}");
+                return;
+            }
+        }
+
         match cmd.data_type.type_name() {
             stringify!(OrderBookDelta) => self.handle_subscribe_book_deltas(&cmd),
             stringify!(OrderBook) => self.handle_subscribe_book_snapshots(&cmd),
-            // stringify!(QuoteTick) => self.handle_subscribe_quote_ticks(cmd),
-            // stringify!(TradeTick) => self.handle_subscribe_trade_ticks(cmd),
+            stringify!(QuoteTick) => self.handle_subscribe_quote_ticks(&cmd),
+            stringify!(TradeTick) => self.handle_subscribe_trade_ticks(&cmd),
             // stringify!(Bar) => self.handle_subscribe_bars(cmd),
             type_name => Err(anyhow::anyhow!(
                 "Cannot handle subscription, type `{type_name}` is unrecognized"
@@ -372,6 +607,11 @@ impl DataEngine {
 
     /// Sends a [`DataRequest`] to an endpoint that must be a data client implementation.
     pub fn request(&self, req: DataRequest) {
+        if let Err(e) = self.check_capabilities(&req.client_id, &req.venue, &req.data_type) {
+            log::error!("Cannot handle request: {e}");
+            return;
+        }
+
         if let Some(client) = self.get_client(&req.client_id, &req.venue) {
             client.through_request(req);
         } else {
@@ -385,12 +625,64 @@ impl DataEngine {
     pub fn process(&mut self, data: &dyn Any) {
         if let Some(instrument) = data.downcast_ref::<InstrumentAny>() {
             self.handle_instrument(instrument.clone());
+        } else if let Some(update) = data.downcast_ref::<InstrumentUpdate>() {
+            self.handle_instrument_update(update);
         } else {
             log::error!("Cannot process data {data:?}, type is unrecognized");
         }
     }
 
     pub fn process_data(&mut self, data: Data) {
+        self.message_counter.inc();
+
+        // Data released by the late data timer precedes the new data
+        self.handle_released_data();
+
+        let ts_now = self.clock.timestamp_ns();
+        let sequenced = self.sequencer.borrow_mut().process(data, ts_now);
+        for data in sequenced {
+            self.handle_data(data);
+        }
+    }
+
+    /// Handles the data released from the reorder buffer by the late data timer.
+    pub fn handle_released_data(&mut self) {
+        let released: Vec<Data> = self.released_data.borrow_mut().drain(..).collect();
+        for data in released {
+            self.handle_data(data);
+        }
+    }
+
+    /// Handles all data buffered for reordering by the late data policy.
+    pub fn flush_late_data(&mut self) {
+        self.handle_released_data();
+
+        let flushed = self.sequencer.borrow_mut().flush();
+        for data in flushed {
+            self.handle_data(data);
+        }
+    }
+
+    /// Returns the sequencer applying the late data policy.
+    #[must_use]
+    pub fn sequencer(&self) -> Ref<'_, DataSequencer> {
+        self.sequencer.borrow()
+    }
+
+    /// Returns the message rate statistics for the given `instrument_id`, if message rates are
+    /// configured and data has been processed for the instrument.
+    #[must_use]
+    pub fn message_rate_stats(&self, instrument_id: &InstrumentId) -> Option<MessageRateStats> {
+        self.rate_monitor
+            .as_ref()
+            .and_then(|monitor| monitor.stats(instrument_id))
+    }
+
+    fn handle_data(&mut self, data: Data) {
+        if let Some(monitor) = &mut self.rate_monitor {
+            monitor.on_data(&data);
+        }
+
         match data {
             Data::Delta(delta) => self.handle_delta(delta),
             Data::Deltas(deltas) => self.handle_deltas(deltas.deref().clone()), // TODO: Optimize
@@ -433,6 +725,16 @@ impl DataEngine {
 
     // -- DATA HANDLERS ---------------------------------------------------------------------------
 
+    fn check_data_quality(
+        &self,
+        check: impl FnOnce(&mut DataQualityMonitor) -> Vec<DataQualityAlert>,
+    ) {
+        if let Some(monitor) = &self.quality_monitor {
+            let alerts = check(&mut monitor.borrow_mut());
+            publish_data_quality_alerts(&mut self.msgbus.borrow_mut(), &alerts);
+        }
+    }
+
     fn handle_instrument(&mut self, instrument: InstrumentAny) {
         if let Err(e) = self
             .cache
@@ -448,6 +750,29 @@ impl DataEngine {
         msgbus.publish(&topic, &instrument as &dyn Any); // TODO: Optimize
     }
 
+    fn handle_instrument_update(&mut self, update: &InstrumentUpdate) {
+        let instrument = {
+            let mut cache = self.cache.as_ref().borrow_mut();
+            match cache.update_instrument(update) {
+                Ok(version) => {
+                    log::info!("Applied {update} (version {version})");
+                    cache.instrument(&update.instrument_id).cloned()
+                }
+                Err(e) => {
+                    log::error!("Error applying {update}: {e}");
+                    None
+                }
+            }
+        };
+
+        // Subscribers to the instrument receive its updated definition
+        if let Some(instrument) = instrument {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_instrument_topic(instrument.id());
+            msgbus.publish(&topic, &instrument as &dyn Any);
+        }
+    }
+
     fn handle_delta(&mut self, delta: OrderBookDelta) {
         let deltas = if self.config.buffer_deltas {
             let buffer_deltas = self
@@ -467,6 +792,8 @@ impl DataEngine {
             OrderBookDeltas::new(delta.instrument_id, vec![delta])
         };
 
+        self.check_data_quality(|monitor| monitor.on_deltas(&deltas));
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
         msgbus.publish(&topic, &deltas as &dyn Any);
@@ -497,12 +824,16 @@ impl DataEngine {
             deltas
         };
 
+        self.check_data_quality(|monitor| monitor.on_deltas(&deltas));
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
         msgbus.publish(&topic, &deltas as &dyn Any); // TODO: Optimize
     }
 
     fn handle_depth10(&mut self, depth: OrderBookDepth10) {
+        self.check_data_quality(|monitor| monitor.on_depth10(&depth));
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_depth_topic(depth.instrument_id);
         msgbus.publish(&topic, &depth as &dyn Any); // TODO: Optimize
@@ -515,9 +846,50 @@ impl DataEngine {
 
         // TODO: Handle synthetics
 
+        self.check_data_quality(|monitor| monitor.on_quote(&quote));
+
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
+            msgbus.publish(&topic, &quote as &dyn Any); // TODO: Optimize
+        }
+
+        for ((instrument_id, interval_ms), conflator) in &self.conflated_quotes {
+            if *instrument_id != quote.instrument_id {
+                continue;
+            }
+            if let Some(quote) = conflator.borrow_mut().update(quote, quote.ts_init) {
+                let mut msgbus = self.msgbus.borrow_mut();
+                let topic = msgbus
+                    .switchboard
+                    .get_conflated_quote_topic(*instrument_id, *interval_ms);
+                msgbus.publish(&topic, &quote as &dyn Any);
+            }
+        }
+
+        let consolidated: Vec<ConsolidatedQuote> = self
+            .consolidated_feeds
+            .values_mut()
+            .filter_map(|feed| feed.update(&quote))
+            .collect();
+        for consolidated in consolidated {
+            self.handle_consolidated_quote(consolidated);
+        }
+    }
+
+    fn handle_consolidated_quote(&mut self, consolidated: ConsolidatedQuote) {
+        let quote = consolidated.quote;
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_quote(quote) {
+            log::error!("Error on cache insert: {e}");
+        }
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
-        msgbus.publish(&topic, &quote as &dyn Any); // TODO: Optimize
+        msgbus.publish(&topic, &quote as &dyn Any);
+        let topic = msgbus
+            .switchboard
+            .get_consolidated_quote_topic(quote.instrument_id);
+        msgbus.publish(&topic, &consolidated as &dyn Any);
     }
 
     fn handle_trade(&mut self, trade: TradeTick) {
@@ -527,9 +899,23 @@ impl DataEngine {
 
         // TODO: Handle synthetics
 
+        self.check_data_quality(|monitor| monitor.on_trade(&trade));
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_trade_topic(trade.instrument_id);
         msgbus.publish(&topic, &trade as &dyn Any); // TODO: Optimize
+
+        for ((instrument_id, interval_ms), conflator) in &self.conflated_trades {
+            if *instrument_id != trade.instrument_id {
+                continue;
+            }
+            if let Some(trade) = conflator.borrow_mut().update(trade, trade.ts_init) {
+                let topic = msgbus
+                    .switchboard
+                    .get_conflated_trade_topic(*instrument_id, *interval_ms);
+                msgbus.publish(&topic, &trade as &dyn Any);
+            }
+        }
     }
 
     fn handle_bar(&mut self, bar: Bar) {
@@ -594,6 +980,68 @@ impl DataEngine {
         Ok(())
     }
 
+    fn handle_subscribe_quote_ticks(
+        &mut self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<()> {
+        let Some(interval_ms) = command.data_type.conflation_ms() else {
+            return Ok(()); // No conflation for the subscription
+        };
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid quote ticks subscription: did not contain an `instrument_id`, {}",
+                command.data_type
+            )
+        })?;
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_conflated_quote_topic(instrument_id, interval_ms);
+        update_conflation(
+            self.clock.as_mut(),
+            &self.msgbus,
+            &mut self.conflated_quotes,
+            &command.action,
+            (instrument_id, interval_ms),
+            topic,
+            stringify!(QuoteTick),
+        );
+        Ok(())
+    }
+
+    fn handle_subscribe_trade_ticks(
+        &mut self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<()> {
+        let Some(interval_ms) = command.data_type.conflation_ms() else {
+            return Ok(()); // No conflation for the subscription
+        };
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid trade ticks subscription: did not contain an `instrument_id`, {}",
+                command.data_type
+            )
+        })?;
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_conflated_trade_topic(instrument_id, interval_ms);
+        update_conflation(
+            self.clock.as_mut(),
+            &self.msgbus,
+            &mut self.conflated_trades,
+            &command.action,
+            (instrument_id, interval_ms),
+            topic,
+            stringify!(TradeTick),
+        );
+        Ok(())
+    }
+
     fn handle_subscribe_book_snapshots(
         &mut self,
         command: &SubscriptionCommand,
@@ -798,6 +1246,62 @@ impl DataEngine {
     }
 }
 
+fn book_depth_snapshot_timer_name(instrument_id: InstrumentId, interval_ms: NonZeroU64) -> String {
+    format!("BookDepthSnapshot|{instrument_id}|{interval_ms}")
+}
+
+// Adds or removes the conflator for the given key, with a timer to flush pending data
+fn update_conflation<T: 'static>(
+    clock: &mut dyn Clock,
+    msgbus: &Rc<RefCell<MessageBus>>,
+    conflators: &mut IndexMap<(InstrumentId, NonZeroU64), Rc<RefCell<Conflator<T>>>>,
+    action: &Action,
+    key: (InstrumentId, NonZeroU64),
+    topic: Ustr,
+    type_name: &str,
+) {
+    let (instrument_id, interval_ms) = key;
+    let timer_name = format!("Conflation|{type_name}|{instrument_id}|{interval_ms}");
+
+    match action {
+        Action::Subscribe => {
+            if conflators.contains_key(&key) {
+                return;
+            }
+
+            let conflator = Rc::new(RefCell::new(Conflator::new(
+                interval_ms,
+                telemetry().conflated_counter(type_name),
+            )));
+            let interval_ns = conflator.borrow().interval_ns();
+            let callback_conflator = conflator.clone();
+            let msgbus = msgbus.clone();
+            let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+                if let Some(data) = callback_conflator.borrow_mut().flush(event.ts_event) {
+                    msgbus.borrow().publish(&topic, &data as &dyn Any);
+                }
+            }));
+            let start_time_ns = clock.timestamp_ns();
+            clock.set_timer_ns(
+                &timer_name,
+                interval_ns,
+                start_time_ns,
+                None,
+                Some(callback),
+            );
+
+            log::info!("Conflating {type_name} for {instrument_id} to {interval_ms}ms");
+            conflators.insert(key, conflator);
+        }
+        Action::Unsubscribe => {
+            if conflators.shift_remove(&key).is_some() {
+                clock.cancel_timer(&timer_name);
+                log::info!("Stopped conflating {type_name} for {instrument_id} at {interval_ms}ms");
+            }
+        }
+    }
+}
+
 pub struct SubscriptionCommandHandler {
     pub id: Ustr,
     pub engine_ref: Rc<RefCell<DataEngine>>,
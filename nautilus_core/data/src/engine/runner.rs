@@ -23,11 +23,13 @@ use futures::StreamExt;
 use nautilus_common::{
     clock::{Clock, LiveClock, TestClock},
     messages::data::{DataEvent, DataResponse, SubscriptionCommand},
-    runtime::get_runtime,
+    runtime::{
+        channel::{channel, Consumer, Producer},
+        get_runtime,
+    },
     timer::{TimeEvent, TimeEventHandlerV2},
 };
 use nautilus_model::data::GetTsInit;
-use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use super::DataEngine;
 
@@ -38,7 +40,13 @@ pub trait DataQueue {
 pub type GlobalDataQueue = Rc<RefCell<dyn DataQueue>>;
 
 pub struct SyncDataQueue(VecDeque<DataEvent>);
-pub struct AsyncDataQueue(UnboundedSender<DataEvent>);
+
+/// Provides a data queue over the live data channel, buffering events in an unbounded
+/// overflow queue while the channel is full so that no data or responses are dropped.
+pub struct AsyncDataQueue {
+    tx: Producer<DataEvent>,
+    overflow: Rc<RefCell<VecDeque<DataEvent>>>,
+}
 
 impl DataQueue for SyncDataQueue {
     fn push(&mut self, event: DataEvent) {
@@ -48,8 +56,17 @@ impl DataQueue for SyncDataQueue {
 
 impl DataQueue for AsyncDataQueue {
     fn push(&mut self, event: DataEvent) {
-        if let Err(e) = self.0.send(event) {
-            log::error!("Unable to send data event to async data channel: {e}");
+        let mut overflow = self.overflow.borrow_mut();
+
+        // Queue behind any overflowed events to preserve ordering
+        if !overflow.is_empty() {
+            overflow.push_back(event);
+            return;
+        }
+
+        if let Err(event) = self.tx.push(event) {
+            log::warn!("Async data channel full, buffering events until drained");
+            overflow.push_back(event);
         }
     }
 }
@@ -166,20 +183,43 @@ impl Runner for BacktestRunner {
     }
 }
 
+/// The capacity of the live data channel between data clients and the data engine.
+pub const LIVE_DATA_CHANNEL_CAPACITY: usize = 65_536;
+
 pub struct LiveRunner {
-    resp_rx: UnboundedReceiver<DataEvent>,
+    resp_rx: Consumer<DataEvent>,
+    overflow: Rc<RefCell<VecDeque<DataEvent>>>,
     pub clock: Rc<RefCell<LiveClock>>,
 }
 
+impl LiveRunner {
+    /// Returns the next overflowed event, once all events in the channel were received.
+    fn next_overflow_event(&self) -> Option<DataEvent> {
+        if self.resp_rx.is_empty() {
+            self.overflow.borrow_mut().pop_front()
+        } else {
+            None
+        }
+    }
+}
+
 impl Runner for LiveRunner {
     fn new() -> Self {
-        let (resp_tx, resp_rx) = tokio::sync::mpsc::unbounded_channel::<DataEvent>();
-        set_data_queue(Rc::new(RefCell::new(AsyncDataQueue(resp_tx))));
+        let (tx, resp_rx) = channel::<DataEvent>(LIVE_DATA_CHANNEL_CAPACITY);
+        let overflow = Rc::new(RefCell::new(VecDeque::new()));
+        set_data_queue(Rc::new(RefCell::new(AsyncDataQueue {
+            tx,
+            overflow: overflow.clone(),
+        })));
 
         let clock = Rc::new(RefCell::new(LiveClock::new()));
         set_clock(clock.clone());
 
-        Self { resp_rx, clock }
+        Self {
+            resp_rx,
+            overflow,
+            clock,
+        }
     }
 
     fn run(&mut self, engine: &mut DataEngine) {
@@ -192,13 +232,16 @@ impl Runner for LiveRunner {
             }
 
             // Collect the next event to process
-            let next_event = get_runtime().block_on(async {
-                tokio::select! {
-                    Some(resp) = self.resp_rx.recv() => Some(RunnerEvent::Data(resp)),
-                    Some(event) = time_event_stream.next() => Some(RunnerEvent::Timer(event)),
-                    else => None,
-                }
-            });
+            let next_event = match self.next_overflow_event() {
+                Some(resp) => Some(RunnerEvent::Data(resp)),
+                None => get_runtime().block_on(async {
+                    tokio::select! {
+                        Some(resp) = self.resp_rx.recv() => Some(RunnerEvent::Data(resp)),
+                        Some(event) = time_event_stream.next() => Some(RunnerEvent::Timer(event)),
+                        else => None,
+                    }
+                }),
+            };
 
             // Process the event outside of the async context
             match next_event {
@@ -206,7 +249,10 @@ impl Runner for LiveRunner {
                     DataEvent::Response(resp) => engine.response(resp),
                     DataEvent::Data(data) => engine.process_data(data),
                 },
-                Some(RunnerEvent::Timer(event)) => self.clock.borrow().get_handler(event).run(),
+                Some(RunnerEvent::Timer(event)) => {
+                    self.clock.borrow().get_handler(event).run();
+                    engine.handle_released_data();
+                }
                 None => break,
             }
         }
@@ -221,19 +267,67 @@ enum RunnerEvent {
     Timer(TimeEvent),
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
-#[cfg(feature = "clock_v2")]
 mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    use std::{cell::RefCell, collections::VecDeque, rc::Rc};
 
+    #[cfg(feature = "clock_v2")]
     use futures::StreamExt;
+    #[cfg(feature = "clock_v2")]
     use nautilus_common::{
         clock::{LiveClock, TestClock},
         timer::{TimeEvent, TimeEventCallback},
     };
+    use nautilus_common::{messages::data::DataEvent, runtime::channel::channel};
+    use nautilus_model::data::{quote::QuoteTick, stubs::quote_ethusdt_binance, Data};
+    use rstest::rstest;
 
+    #[cfg(feature = "clock_v2")]
     use super::{get_clock, set_clock};
+    use super::{AsyncDataQueue, DataQueue};
+
+    fn quote_at(ts_init: u64) -> DataEvent {
+        DataEvent::Data(Data::Quote(QuoteTick {
+            ts_init: ts_init.into(),
+            ..quote_ethusdt_binance()
+        }))
+    }
+
+    fn ts_init(event: DataEvent) -> u64 {
+        match event {
+            DataEvent::Data(Data::Quote(quote)) => quote.ts_init.as_u64(),
+            _ => panic!("Expected quote"),
+        }
+    }
+
+    #[rstest]
+    fn test_async_data_queue_buffers_when_channel_full() {
+        let (tx, mut rx) = channel::<DataEvent>(2);
+        let overflow = Rc::new(RefCell::new(VecDeque::new()));
+        let mut queue = AsyncDataQueue {
+            tx,
+            overflow: overflow.clone(),
+        };
+
+        for ts in 0..4 {
+            queue.push(quote_at(ts));
+        }
+        assert_eq!(overflow.borrow().len(), 2);
+
+        // Events pushed while overflowed queue behind the overflow, even with space in the channel
+        assert_eq!(ts_init(rx.pop().unwrap()), 0);
+        queue.push(quote_at(4));
+        assert_eq!(overflow.borrow().len(), 3);
+
+        let mut received = vec![ts_init(rx.pop().unwrap())];
+        received.extend(overflow.borrow_mut().drain(..).map(ts_init));
+        assert_eq!(received, vec![1, 2, 3, 4]);
+    }
 
+    #[cfg(feature = "clock_v2")]
     #[test]
     fn test_global_test_clock() {
         let test_clock = Rc::new(RefCell::new(TestClock::new()));
@@ -251,6 +345,7 @@ mod tests {
         assert!(test_clock.borrow_mut().next().is_some());
     }
 
+    #[cfg(feature = "clock_v2")]
     #[tokio::test]
     async fn test_global_live_clock() {
         let live_clock = Rc::new(RefCell::new(LiveClock::new()));
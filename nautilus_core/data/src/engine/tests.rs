@@ -18,42 +18,62 @@
 use std::{
     any::Any,
     cell::{OnceCell, RefCell},
+    num::NonZeroU64,
     rc::Rc,
 };
 
 use indexmap::indexmap;
 use nautilus_common::{
     cache::Cache,
+    capabilities::ClientCapabilities,
     clock::TestClock,
-    messages::data::{Action, SubscriptionCommand},
+    messages::{
+        data::{Action, SubscriptionCommand},
+        quality::{DataQualityAlert, DataQualityIssue},
+    },
     msgbus::{
         handler::ShareableMessageHandler,
         stubs::{get_message_saving_handler, get_saved_messages},
         switchboard::MessagingSwitchboard,
         MessageBus,
     },
+    timer::TimeEvent,
 };
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
         deltas::{OrderBookDeltas, OrderBookDeltas_API},
         depth::OrderBookDepth10,
         quote::QuoteTick,
-        stubs::{stub_delta, stub_deltas, stub_depth10},
+        stubs::{
+            quote_ethusdt_binance, stub_delta, stub_deltas, stub_depth10, stub_trade_ethusdt_buyer,
+        },
         trade::TradeTick,
         Data, DataType,
     },
     enums::BookType,
-    identifiers::{ClientId, TraderId, Venue},
+    events::instrument::InstrumentUpdate,
+    identifiers::{ClientId, InstrumentId, TraderId, Venue},
     instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+    orderbook::book::OrderBook,
+    types::price::Price,
 };
 use rstest::*;
+use ustr::Ustr;
 
 use crate::{
     client::DataClientAdapter,
-    engine::{DataEngine, SubscriptionCommandHandler},
+    consolidation::{ConsolidatedFeed, ConsolidatedQuote},
+    engine::{
+        book::BookDepthSnapshotter,
+        config::{BookDepthSnapshotConfig, DataEngineConfig},
+        DataEngine, SubscriptionCommandHandler, LATE_DATA_TIMER_NAME,
+    },
     mocks::MockDataClient,
+    quality::DataQualityConfig,
+    rates::MessageRateConfig,
+    sequencing::{LateDataConfig, LateDataPolicy},
 };
 
 // TODO: Used for development
@@ -222,6 +242,61 @@ fn test_execute_subscribe_order_book_deltas(
         .contains(&audusd_sim.id));
 }
 
+#[rstest]
+fn test_execute_subscribe_unsupported_by_client(
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    clock: Box<TestClock>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let mut client = MockDataClient::new(cache, msgbus, client_id, venue);
+    client.capabilities = ClientCapabilities::unrestricted()
+        .with_data_types([stringify!(OrderBookDelta), stringify!(QuoteTick)])
+        .with_book_types([BookType::L2_MBP]);
+    let client = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.borrow_mut().register_client(client, None);
+
+    let subscribe = |data_type: DataType| {
+        data_engine.borrow_mut().execute(SubscriptionCommand::new(
+            client_id,
+            venue,
+            data_type,
+            Action::Subscribe,
+            UUID4::new(),
+            UnixNanos::default(),
+        ));
+    };
+    let book_data_type = |book_type: BookType| {
+        let metadata = indexmap! {
+            "instrument_id".to_string() => audusd_sim.id.to_string(),
+            "book_type".to_string() => book_type.to_string(),
+            "managed".to_string() => "true".to_string(),
+        };
+        DataType::new(stringify!(OrderBookDelta), Some(metadata))
+    };
+    let trades = DataType::new(
+        stringify!(TradeTick),
+        Some(indexmap! { "instrument_id".to_string() => audusd_sim.id.to_string() }),
+    );
+
+    subscribe(trades);
+    subscribe(book_data_type(BookType::L3_MBO));
+    assert!(data_engine.borrow().subscribed_trade_ticks().is_empty());
+    assert!(data_engine
+        .borrow()
+        .subscribed_order_book_deltas()
+        .is_empty());
+
+    subscribe(book_data_type(BookType::L2_MBP));
+    assert!(data_engine
+        .borrow()
+        .subscribed_order_book_deltas()
+        .contains(&audusd_sim.id));
+}
+
 #[rstest]
 fn test_execute_subscribe_order_book_snapshots(
     audusd_sim: CurrencyPair,
@@ -475,6 +550,41 @@ fn test_process_instrument(
     assert!(messages.contains(&audusd_sim));
 }
 
+#[rstest]
+fn test_process_instrument_update(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+    let handler = get_message_saving_handler::<InstrumentAny>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_instrument_topic(instrument.id());
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+    let update = InstrumentUpdate::new(
+        instrument.id(),
+        Some(Price::from("0.00005")),
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process(&instrument as &dyn Any);
+    data_engine.process(&update as &dyn Any);
+    let cache = data_engine.get_cache();
+    let messages = get_saved_messages::<InstrumentAny>(handler);
+
+    assert_eq!(cache.instrument_version(&instrument.id()), Some(1));
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[1].price_increment(), Price::from("0.00005"));
+}
+
 #[rstest]
 fn test_process_order_book_delta(
     audusd_sim: CurrencyPair,
@@ -782,3 +892,344 @@ fn test_process_bar(
     assert_eq!(messages.len(), 1);
     assert!(messages.contains(&bar));
 }
+
+#[rstest]
+fn test_process_crossed_quote_publishes_data_quality_alert(
+    clock: Box<TestClock>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        quality: Some(DataQualityConfig::default()),
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus.clone(), Some(config));
+
+    let quote = QuoteTick {
+        bid_price: Price::from("10002.0000"),
+        ..quote_ethusdt_binance()
+    };
+    let alert_handler = get_message_saving_handler::<DataQualityAlert>(None);
+    let quote_handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_data_quality_topic(quote.instrument_id);
+        msgbus.subscribe(topic, alert_handler.clone(), None);
+        let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
+        msgbus.subscribe(topic, quote_handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Quote(quote));
+    data_engine.process_data(Data::Quote(quote_ethusdt_binance()));
+
+    let alerts = get_saved_messages::<DataQualityAlert>(alert_handler);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(
+        alerts[0].issue,
+        DataQualityIssue::CrossedBook {
+            bid: quote.bid_price,
+            ask: quote.ask_price,
+        }
+    );
+    assert_eq!(get_saved_messages::<QuoteTick>(quote_handler).len(), 2);
+}
+
+#[rstest]
+fn test_process_late_quote_dropped_by_policy(
+    clock: Box<TestClock>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        late_data: LateDataConfig {
+            policy: LateDataPolicy::Drop,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus.clone(), Some(config));
+
+    let quote = QuoteTick {
+        ts_event: 2.into(),
+        ts_init: 2.into(),
+        ..quote_ethusdt_binance()
+    };
+    let late_quote = QuoteTick {
+        ts_event: 1.into(),
+        ts_init: 3.into(),
+        ..quote_ethusdt_binance()
+    };
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Quote(quote));
+    data_engine.process_data(Data::Quote(late_quote));
+
+    assert_eq!(get_saved_messages::<QuoteTick>(handler), vec![quote]);
+    assert_eq!(data_engine.sequencer().late_count(), 1);
+    assert_eq!(
+        data_engine.get_cache().quote(&quote.instrument_id),
+        Some(&quote)
+    );
+}
+
+#[rstest]
+fn test_late_data_timer_releases_reordered_data(
+    clock: Box<TestClock>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        late_data: LateDataConfig {
+            policy: LateDataPolicy::Reorder,
+            reorder_window_ms: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus.clone(), Some(config));
+    assert!(data_engine
+        .clock
+        .timer_names()
+        .contains(&LATE_DATA_TIMER_NAME));
+
+    let quote = QuoteTick {
+        ts_event: 2.into(),
+        ts_init: 2.into(),
+        ..quote_ethusdt_binance()
+    };
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Quote(quote));
+    assert!(get_saved_messages::<QuoteTick>(handler.clone()).is_empty());
+
+    // Released once the window has elapsed, without further data
+    let ts_event = UnixNanos::from(2 + NANOSECONDS_IN_MILLISECOND);
+    let event = TimeEvent::new(
+        Ustr::from(LATE_DATA_TIMER_NAME),
+        UUID4::new(),
+        ts_event,
+        ts_event,
+    );
+    data_engine.clock.get_handler(event).run();
+    data_engine.handle_released_data();
+
+    assert_eq!(get_saved_messages::<QuoteTick>(handler), vec![quote]);
+    assert_eq!(data_engine.sequencer().buffered_count(), 0);
+}
+
+#[rstest]
+fn test_process_data_records_message_rates(
+    clock: Box<TestClock>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        message_rates: Some(MessageRateConfig::default()),
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus, Some(config));
+    let quote = quote_ethusdt_binance();
+    let trade = TradeTick {
+        instrument_id: quote.instrument_id,
+        ts_init: quote.ts_init,
+        ..stub_trade_ethusdt_buyer()
+    };
+
+    assert!(data_engine
+        .message_rate_stats(&quote.instrument_id)
+        .is_none());
+
+    data_engine.process_data(Data::Quote(quote));
+    data_engine.process_data(Data::Quote(quote));
+    data_engine.process_data(Data::Trade(trade));
+
+    let stats = data_engine
+        .message_rate_stats(&quote.instrument_id)
+        .unwrap();
+    assert_eq!(stats.updates, 3);
+    assert_eq!(stats.trades, 1);
+    assert_eq!(stats.peak_burst, 3);
+    assert_eq!(stats.updates_per_sec, 3.0);
+}
+
+#[rstest]
+fn test_process_quote_updates_consolidated_feed(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let composite_id = InstrumentId::from("ETHUSDT-PERP.COMPOSITE");
+    let binance = quote_ethusdt_binance();
+    let bybit = QuoteTick {
+        instrument_id: InstrumentId::from("ETHUSDT-PERP.BYBIT"),
+        bid_price: Price::from("10000.5000"),
+        ..binance
+    };
+    let feed = ConsolidatedFeed::new(
+        composite_id,
+        vec![binance.instrument_id, bybit.instrument_id],
+        None,
+    )
+    .unwrap();
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.add_consolidated_feed(feed);
+
+    let quote_handler = get_message_saving_handler::<QuoteTick>(None);
+    let consolidated_handler = get_message_saving_handler::<ConsolidatedQuote>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quote_topic(composite_id);
+        msgbus.subscribe(topic, quote_handler.clone(), None);
+        let topic = msgbus
+            .switchboard
+            .get_consolidated_quote_topic(composite_id);
+        msgbus.subscribe(topic, consolidated_handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Quote(binance));
+    data_engine.process_data(Data::Quote(bybit));
+
+    let quotes = get_saved_messages::<QuoteTick>(quote_handler);
+    let consolidated = get_saved_messages::<ConsolidatedQuote>(consolidated_handler);
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes[1].bid_price, bybit.bid_price);
+    assert_eq!(consolidated[1].bid_venues, vec![Venue::from("BYBIT")]);
+    assert_eq!(
+        consolidated[1].ask_venues,
+        vec![Venue::from("BINANCE"), Venue::from("BYBIT")]
+    );
+    assert_eq!(
+        data_engine.get_cache().quote(&composite_id),
+        Some(&quotes[1])
+    );
+}
+
+#[rstest]
+fn test_process_quotes_with_conflated_subscription(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.register_client(data_client, None);
+
+    let quote = quote_ethusdt_binance();
+    let metadata = indexmap! {
+        "instrument_id".to_string() => quote.instrument_id.to_string(),
+        "conflation_ms".to_string() => "50".to_string(),
+    };
+    let data_type = DataType::new(stringify!(QuoteTick), Some(metadata));
+    data_engine.execute(SubscriptionCommand::new(
+        client_id,
+        venue,
+        data_type,
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+    ));
+
+    let quote_handler = get_message_saving_handler::<QuoteTick>(None);
+    let conflated_handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quote_topic(quote.instrument_id);
+        msgbus.subscribe(topic, quote_handler.clone(), None);
+        let topic = msgbus
+            .switchboard
+            .get_conflated_quote_topic(quote.instrument_id, NonZeroU64::new(50).unwrap());
+        msgbus.subscribe(topic, conflated_handler.clone(), None);
+    }
+
+    let quotes: Vec<QuoteTick> = [0, 10, 20, 60]
+        .into_iter()
+        .map(|ts_ms| QuoteTick {
+            ts_event: UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND),
+            ts_init: UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND),
+            ..quote
+        })
+        .collect();
+    for quote in &quotes {
+        data_engine.process_data(Data::Quote(*quote));
+    }
+
+    assert_eq!(get_saved_messages::<QuoteTick>(quote_handler).len(), 4);
+    assert_eq!(
+        get_saved_messages::<QuoteTick>(conflated_handler),
+        vec![quotes[0], quotes[3]]
+    );
+}
+
+#[rstest]
+fn test_book_depth_snapshots_from_config(
+    clock: Box<TestClock>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let interval_ms = NonZeroU64::new(1_000).unwrap();
+    let config = DataEngineConfig {
+        book_depth_snapshots: Some(BookDepthSnapshotConfig {
+            instrument_ids: vec![instrument_id],
+            interval_ms,
+        }),
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus, Some(config));
+
+    assert_eq!(
+        data_engine.book_depth_snapshots(),
+        vec![(instrument_id, interval_ms)]
+    );
+
+    data_engine.stop_book_depth_snapshots(instrument_id, interval_ms);
+
+    assert!(data_engine.book_depth_snapshots().is_empty());
+}
+
+#[rstest]
+fn test_book_depth_snapshotter_publishes_depth(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let delta = stub_delta();
+    let instrument_id = delta.instrument_id;
+    let interval_ms = NonZeroU64::new(1_000).unwrap();
+    let handler = get_message_saving_handler::<OrderBookDepth10>(None);
+    let topic = {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_depth_snapshot_topic(instrument_id, interval_ms);
+        msgbus.subscribe(topic, handler.clone(), None);
+        topic
+    };
+    let snapshotter =
+        BookDepthSnapshotter::new(instrument_id, topic, cache.clone(), msgbus.clone());
+    let event =
+        |ts: u64| TimeEvent::new(Ustr::from("snapshot"), UUID4::new(), ts.into(), ts.into());
+
+    // No book maintained yet
+    snapshotter.snapshot(event(1_000));
+
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    book.apply_delta(&delta);
+    cache.borrow_mut().add_order_book(book.clone()).unwrap();
+    snapshotter.snapshot(event(2_000));
+
+    let snapshots = get_saved_messages::<OrderBookDepth10>(handler);
+    assert_eq!(snapshots, vec![book.to_depth10(UnixNanos::from(2_000))]);
+    assert_eq!(snapshots[0].ts_init, UnixNanos::from(2_000));
+}
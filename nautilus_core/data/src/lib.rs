@@ -28,6 +28,13 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod aggregation;
+pub mod chaos;
 pub mod client;
+pub mod conflation;
+pub mod consolidation;
+pub mod delay;
 pub mod engine;
 pub mod mocks;
+pub mod quality;
+pub mod rates;
+pub mod sequencing;
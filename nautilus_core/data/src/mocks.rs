@@ -21,6 +21,7 @@ use std::{cell::RefCell, rc::Rc};
 
 use nautilus_common::{
     cache::Cache,
+    capabilities::ClientCapabilities,
     messages::data::{DataRequest, Payload},
     msgbus::MessageBus,
 };
@@ -44,6 +45,7 @@ pub struct MockDataClient {
     msgbus: Rc<RefCell<MessageBus>>,
     pub client_id: ClientId,
     pub venue: Venue,
+    pub capabilities: ClientCapabilities,
 }
 
 impl MockDataClient {
@@ -59,6 +61,7 @@ impl MockDataClient {
             msgbus,
             client_id,
             venue,
+            capabilities: ClientCapabilities::unrestricted(),
         }
     }
 }
@@ -81,6 +84,9 @@ impl DataClient for MockDataClient {
     fn is_disconnected(&self) -> bool {
         false
     }
+    fn capabilities(&self) -> ClientCapabilities {
+        self.capabilities.clone()
+    }
 
     // -- COMMAND HANDLERS ---------------------------------------------------------------------------
 
@@ -0,0 +1,477 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Monitoring of live market data quality.
+//!
+//! The [`DataQualityMonitor`] checks the data processed by the `DataEngine` for crossed or locked
+//! books, out of order timestamps and extreme price jumps, and periodically checks each instrument
+//! for staleness. Issues are published as [`DataQualityAlert`]s on the message bus.
+
+use std::any::Any;
+
+use indexmap::IndexMap;
+use nautilus_common::{
+    config::{check_setting, ValidateConfig},
+    messages::quality::{DataQualityAlert, DataQualityIssue},
+    msgbus::MessageBus,
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos};
+use nautilus_model::{
+    data::{deltas::OrderBookDeltas, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick},
+    identifiers::InstrumentId,
+    types::price::Price,
+};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for `DataQualityMonitor` instances.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DataQualityConfig {
+    /// The time without updates after which an instrument is stale, in milliseconds
+    /// (not checked if None).
+    pub stale_threshold_ms: Option<u64>,
+    /// If quotes and order book depth are checked for crossed and locked books.
+    pub check_crossed: bool,
+    /// If event timestamps are checked to be non-decreasing for each data type.
+    pub check_sequence: bool,
+    /// The relative price change between updates which is an extreme jump, e.g. 0.05 for 5%
+    /// (not checked if None).
+    pub max_price_jump: Option<f64>,
+}
+
+impl Default for DataQualityConfig {
+    /// Creates a new default [`DataQualityConfig`] instance.
+    fn default() -> Self {
+        Self {
+            stale_threshold_ms: None,
+            check_crossed: true,
+            check_sequence: true,
+            max_price_jump: None,
+        }
+    }
+}
+
+impl ValidateConfig for DataQualityConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.stale_threshold_ms != Some(0),
+            "data_engine.quality.stale_threshold_ms",
+            "must be positive",
+        )?;
+        check_setting(
+            self.max_price_jump.is_none_or(|jump| jump > 0.0),
+            "data_engine.quality.max_price_jump",
+            "must be positive",
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct InstrumentState {
+    last_update_ns: UnixNanos,
+    is_stale: bool,
+    last_quote_ts: Option<UnixNanos>,
+    last_trade_ts: Option<UnixNanos>,
+    last_depth_ts: Option<UnixNanos>,
+    last_deltas_ts: Option<UnixNanos>,
+    last_mid: Option<f64>,
+    last_trade_price: Option<f64>,
+}
+
+/// Checks live market data for quality issues.
+#[derive(Debug)]
+pub struct DataQualityMonitor {
+    config: DataQualityConfig,
+    states: IndexMap<InstrumentId, InstrumentState>,
+}
+
+impl DataQualityMonitor {
+    /// Creates a new [`DataQualityMonitor`] instance.
+    #[must_use]
+    pub fn new(config: DataQualityConfig) -> Self {
+        Self {
+            config,
+            states: IndexMap::new(),
+        }
+    }
+
+    /// Returns the configuration for the monitor.
+    #[must_use]
+    pub const fn config(&self) -> &DataQualityConfig {
+        &self.config
+    }
+
+    /// Returns the staleness threshold in nanoseconds, if configured.
+    #[must_use]
+    pub fn stale_threshold_ns(&self) -> Option<u64> {
+        self.config
+            .stale_threshold_ms
+            .map(|ms| ms * NANOSECONDS_IN_MILLISECOND)
+    }
+
+    /// Checks the given `quote`, returning any alerts.
+    pub fn on_quote(&mut self, quote: &QuoteTick) -> Vec<DataQualityAlert> {
+        let config = &self.config;
+        let state = update_state(&mut self.states, quote.instrument_id, quote.ts_init);
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        let issues = [
+            check_sequence(config, &mut state.last_quote_ts, quote.ts_event),
+            check_book(config, quote.bid_price, quote.ask_price),
+            check_price_jump(config, &mut state.last_mid, mid),
+        ];
+        to_alerts(
+            issues,
+            quote.instrument_id,
+            stringify!(QuoteTick),
+            quote.ts_event,
+            quote.ts_init,
+        )
+    }
+
+    /// Checks the given `trade`, returning any alerts.
+    pub fn on_trade(&mut self, trade: &TradeTick) -> Vec<DataQualityAlert> {
+        let config = &self.config;
+        let state = update_state(&mut self.states, trade.instrument_id, trade.ts_init);
+        let issues = [
+            check_sequence(config, &mut state.last_trade_ts, trade.ts_event),
+            None,
+            check_price_jump(config, &mut state.last_trade_price, trade.price.as_f64()),
+        ];
+        to_alerts(
+            issues,
+            trade.instrument_id,
+            stringify!(TradeTick),
+            trade.ts_event,
+            trade.ts_init,
+        )
+    }
+
+    /// Checks the given order book `depth`, returning any alerts.
+    pub fn on_depth10(&mut self, depth: &OrderBookDepth10) -> Vec<DataQualityAlert> {
+        let config = &self.config;
+        let state = update_state(&mut self.states, depth.instrument_id, depth.ts_init);
+        let (best_bid, best_ask) = (depth.bids[0], depth.asks[0]);
+        let book_issue = if best_bid.size.is_positive() && best_ask.size.is_positive() {
+            check_book(config, best_bid.price, best_ask.price)
+        } else {
+            None
+        };
+        let issues = [
+            check_sequence(config, &mut state.last_depth_ts, depth.ts_event),
+            book_issue,
+            None,
+        ];
+        to_alerts(
+            issues,
+            depth.instrument_id,
+            stringify!(OrderBookDepth10),
+            depth.ts_event,
+            depth.ts_init,
+        )
+    }
+
+    /// Checks the given order book `deltas`, returning any alerts.
+    pub fn on_deltas(&mut self, deltas: &OrderBookDeltas) -> Vec<DataQualityAlert> {
+        let config = &self.config;
+        let state = update_state(&mut self.states, deltas.instrument_id, deltas.ts_init);
+        let issues = [
+            check_sequence(config, &mut state.last_deltas_ts, deltas.ts_event),
+            None,
+            None,
+        ];
+        to_alerts(
+            issues,
+            deltas.instrument_id,
+            stringify!(OrderBookDeltas),
+            deltas.ts_event,
+            deltas.ts_init,
+        )
+    }
+
+    /// Checks every instrument with data for staleness at the time `now`, returning an alert for
+    /// each instrument which has become stale since the last check.
+    ///
+    /// An instrument is alerted once, until it receives another update.
+    pub fn check_stale(&mut self, now: UnixNanos) -> Vec<DataQualityAlert> {
+        let Some(threshold_ns) = self.stale_threshold_ns() else {
+            return Vec::new();
+        };
+
+        let mut alerts = Vec::new();
+        for (instrument_id, state) in &mut self.states {
+            let elapsed_ns = now.as_u64().saturating_sub(state.last_update_ns.as_u64());
+            if state.is_stale || elapsed_ns <= threshold_ns {
+                continue;
+            }
+            state.is_stale = true;
+            let issue = DataQualityIssue::Stale {
+                last_update_ns: state.last_update_ns,
+                threshold_ns,
+            };
+            alerts.push(DataQualityAlert::new(
+                *instrument_id,
+                "Data",
+                issue,
+                now,
+                now,
+            ));
+        }
+        alerts
+    }
+}
+
+/// Logs and publishes the given `alerts` on their data quality topics.
+pub fn publish_data_quality_alerts(msgbus: &mut MessageBus, alerts: &[DataQualityAlert]) {
+    for alert in alerts {
+        log::warn!("{alert}");
+        let topic = msgbus
+            .switchboard
+            .get_data_quality_topic(alert.instrument_id);
+        msgbus.publish(&topic, alert as &dyn Any);
+    }
+}
+
+fn update_state(
+    states: &mut IndexMap<InstrumentId, InstrumentState>,
+    instrument_id: InstrumentId,
+    ts_init: UnixNanos,
+) -> &mut InstrumentState {
+    let state = states.entry(instrument_id).or_default();
+    state.last_update_ns = state.last_update_ns.max(ts_init);
+    state.is_stale = false;
+    state
+}
+
+fn check_sequence(
+    config: &DataQualityConfig,
+    last_ts_event: &mut Option<UnixNanos>,
+    ts_event: UnixNanos,
+) -> Option<DataQualityIssue> {
+    if !config.check_sequence {
+        return None;
+    }
+
+    match last_ts_event.replace(ts_event) {
+        Some(last_ts_event) if ts_event < last_ts_event => Some(DataQualityIssue::OutOfOrder {
+            ts_event,
+            last_ts_event,
+        }),
+        _ => None,
+    }
+}
+
+fn check_book(config: &DataQualityConfig, bid: Price, ask: Price) -> Option<DataQualityIssue> {
+    if !config.check_crossed {
+        return None;
+    }
+
+    if bid > ask {
+        Some(DataQualityIssue::CrossedBook { bid, ask })
+    } else if bid == ask {
+        Some(DataQualityIssue::LockedBook { price: bid })
+    } else {
+        None
+    }
+}
+
+fn check_price_jump(
+    config: &DataQualityConfig,
+    last_price: &mut Option<f64>,
+    price: f64,
+) -> Option<DataQualityIssue> {
+    let max_price_jump = config.max_price_jump?;
+    let last = last_price.replace(price)?;
+    if last == 0.0 {
+        return None;
+    }
+
+    let change = (price - last) / last;
+    (change.abs() > max_price_jump).then_some(DataQualityIssue::PriceJump {
+        last,
+        price,
+        change,
+    })
+}
+
+fn to_alerts(
+    issues: [Option<DataQualityIssue>; 3],
+    instrument_id: InstrumentId,
+    data_type: &str,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Vec<DataQualityAlert> {
+    issues
+        .into_iter()
+        .flatten()
+        .map(|issue| DataQualityAlert::new(instrument_id, data_type, issue, ts_event, ts_init))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::{quote_ethusdt_binance, stub_trade_ethusdt_buyer};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn monitor() -> DataQualityMonitor {
+        DataQualityMonitor::new(DataQualityConfig {
+            stale_threshold_ms: Some(1_000),
+            max_price_jump: Some(0.05),
+            ..Default::default()
+        })
+    }
+
+    #[rstest]
+    fn test_valid_quotes_raise_no_alerts() {
+        let mut monitor = monitor();
+        let quote = quote_ethusdt_binance();
+        assert!(monitor.on_quote(&quote).is_empty());
+        assert!(monitor
+            .on_quote(&QuoteTick {
+                ts_event: UnixNanos::from(10),
+                ..quote
+            })
+            .is_empty());
+    }
+
+    #[rstest]
+    #[case("10001.0000", DataQualityIssue::CrossedBook { bid: Price::from("10001.0000"), ask: Price::from("10000.0000") })]
+    #[case("10000.0000", DataQualityIssue::LockedBook { price: Price::from("10000.0000") })]
+    fn test_crossed_and_locked_quotes(#[case] bid: &str, #[case] expected: DataQualityIssue) {
+        let mut monitor = monitor();
+        let quote = QuoteTick {
+            bid_price: Price::from(bid),
+            ask_price: Price::from("10000.0000"),
+            ..quote_ethusdt_binance()
+        };
+
+        let alerts = monitor.on_quote(&quote);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].issue, expected);
+        assert_eq!(alerts[0].data_type, "QuoteTick");
+        assert_eq!(alerts[0].instrument_id, quote.instrument_id);
+    }
+
+    #[rstest]
+    fn test_out_of_order_trades() {
+        let mut monitor = monitor();
+        let trade = TradeTick {
+            ts_event: UnixNanos::from(100),
+            ..stub_trade_ethusdt_buyer()
+        };
+        assert!(monitor.on_trade(&trade).is_empty());
+
+        let late = TradeTick {
+            ts_event: UnixNanos::from(50),
+            ..trade
+        };
+        let alerts = monitor.on_trade(&late);
+        assert_eq!(
+            alerts[0].issue,
+            DataQualityIssue::OutOfOrder {
+                ts_event: UnixNanos::from(50),
+                last_ts_event: UnixNanos::from(100),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_price_jump() {
+        let mut monitor = monitor();
+        let trade = stub_trade_ethusdt_buyer();
+        assert!(monitor.on_trade(&trade).is_empty());
+
+        let jump = TradeTick {
+            price: Price::from("11000.0000"),
+            ..trade
+        };
+        let alerts = monitor.on_trade(&jump);
+        assert_eq!(
+            alerts[0].issue,
+            DataQualityIssue::PriceJump {
+                last: 10_000.0,
+                price: 11_000.0,
+                change: 0.1,
+            }
+        );
+
+        // The next update is compared with the jumped price
+        assert!(monitor.on_trade(&jump).is_empty());
+    }
+
+    #[rstest]
+    fn test_checks_disabled() {
+        let mut monitor = DataQualityMonitor::new(DataQualityConfig {
+            check_crossed: false,
+            check_sequence: false,
+            ..Default::default()
+        });
+        let quote = QuoteTick {
+            bid_price: Price::from("10002.0000"),
+            ts_event: UnixNanos::from(10),
+            ..quote_ethusdt_binance()
+        };
+        assert!(monitor.on_quote(&quote).is_empty());
+        assert!(monitor.on_quote(&quote_ethusdt_binance()).is_empty());
+        assert!(monitor.check_stale(UnixNanos::from(u64::MAX)).is_empty());
+    }
+
+    #[rstest]
+    fn test_check_stale_alerts_once_until_updated() {
+        let mut monitor = monitor();
+        let quote = quote_ethusdt_binance();
+        monitor.on_quote(&quote);
+
+        let threshold_ns = 1_000 * NANOSECONDS_IN_MILLISECOND;
+        assert!(monitor
+            .check_stale(UnixNanos::from(threshold_ns + 1))
+            .is_empty());
+
+        let now = UnixNanos::from(threshold_ns + 2);
+        let alerts = monitor.check_stale(now);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(
+            alerts[0].issue,
+            DataQualityIssue::Stale {
+                last_update_ns: quote.ts_init,
+                threshold_ns,
+            }
+        );
+        assert_eq!(alerts[0].ts_event, now);
+        assert!(monitor.check_stale(now + 1).is_empty());
+
+        monitor.on_quote(&QuoteTick {
+            ts_init: now,
+            ..quote
+        });
+        assert_eq!(monitor.check_stale(now + threshold_ns + 1).len(), 1);
+    }
+
+    #[rstest]
+    fn test_config_validation() {
+        let config = DataQualityConfig {
+            max_price_jump: Some(-0.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.validate().unwrap_err().to_string(),
+            "Invalid setting 'data_engine.quality.max_price_jump': must be positive"
+        );
+    }
+}
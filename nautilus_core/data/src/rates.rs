@@ -0,0 +1,302 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Online message rate statistics for market data.
+//!
+//! The [`MessageRateMonitor`] tracks the market data updates processed by the `DataEngine` for
+//! each instrument, computing the update rate over a sliding window, the peak burst of updates
+//! within a short window (e.g. from quote stuffing) and the ratio of order book deltas to trades.
+//! Statistics are also exported as telemetry gauges labeled by instrument.
+
+use std::collections::VecDeque;
+
+use indexmap::IndexMap;
+use nautilus_common::{
+    config::{check_setting, ValidateConfig},
+    telemetry::{telemetry, Gauge, IntGauge},
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos};
+use nautilus_model::{
+    data::{Data, GetTsInit},
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for `MessageRateMonitor` instances.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessageRateConfig {
+    /// The sliding window the update rate is computed over, in milliseconds.
+    pub window_ms: u64,
+    /// The sliding window the peak burst of updates is computed over, in milliseconds.
+    pub burst_window_ms: u64,
+}
+
+impl Default for MessageRateConfig {
+    /// Creates a new default [`MessageRateConfig`] instance.
+    fn default() -> Self {
+        Self {
+            window_ms: 1_000,
+            burst_window_ms: 10,
+        }
+    }
+}
+
+impl ValidateConfig for MessageRateConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.window_ms > 0,
+            "data_engine.message_rates.window_ms",
+            "must be positive",
+        )?;
+        check_setting(
+            self.burst_window_ms > 0,
+            "data_engine.message_rates.burst_window_ms",
+            "must be positive",
+        )
+    }
+}
+
+/// Represents the message rate statistics for an instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MessageRateStats {
+    /// The market data updates per second over the rate window, as of the last update.
+    pub updates_per_sec: f64,
+    /// The most updates seen within any burst window.
+    pub peak_burst: usize,
+    /// The total market data updates.
+    pub updates: u64,
+    /// The total order book deltas.
+    pub deltas: u64,
+    /// The total trades.
+    pub trades: u64,
+}
+
+impl MessageRateStats {
+    /// Returns the ratio of order book deltas to trades, if any trades have been seen.
+    #[must_use]
+    pub fn delta_to_trade_ratio(&self) -> Option<f64> {
+        (self.trades > 0).then(|| self.deltas as f64 / self.trades as f64)
+    }
+}
+
+#[derive(Debug)]
+struct RateState {
+    stats: MessageRateStats,
+    last_ts: UnixNanos,
+    window: VecDeque<UnixNanos>,
+    burst: VecDeque<UnixNanos>,
+    update_rate_gauge: Gauge,
+    peak_burst_gauge: IntGauge,
+    delta_trade_ratio_gauge: Gauge,
+}
+
+impl RateState {
+    fn new(instrument_id: InstrumentId) -> Self {
+        let label = instrument_id.to_string();
+        Self {
+            stats: MessageRateStats::default(),
+            last_ts: UnixNanos::default(),
+            window: VecDeque::new(),
+            burst: VecDeque::new(),
+            update_rate_gauge: telemetry().update_rate_gauge(&label),
+            peak_burst_gauge: telemetry().peak_burst_gauge(&label),
+            delta_trade_ratio_gauge: telemetry().delta_trade_ratio_gauge(&label),
+        }
+    }
+}
+
+/// Computes market data message rate statistics online for each instrument.
+#[derive(Debug)]
+pub struct MessageRateMonitor {
+    config: MessageRateConfig,
+    states: IndexMap<InstrumentId, RateState>,
+}
+
+impl MessageRateMonitor {
+    /// Creates a new [`MessageRateMonitor`] instance.
+    #[must_use]
+    pub fn new(config: MessageRateConfig) -> Self {
+        Self {
+            config,
+            states: IndexMap::new(),
+        }
+    }
+
+    /// Returns the configuration for the monitor.
+    #[must_use]
+    pub const fn config(&self) -> &MessageRateConfig {
+        &self.config
+    }
+
+    /// Returns the statistics for the given `instrument_id`, if any data has been seen.
+    #[must_use]
+    pub fn stats(&self, instrument_id: &InstrumentId) -> Option<MessageRateStats> {
+        self.states.get(instrument_id).map(|state| state.stats)
+    }
+
+    /// Records the given market `data` update, timestamped by its `ts_init`.
+    ///
+    /// Bars are derived data and are not counted.
+    pub fn on_data(&mut self, data: &Data) {
+        let (deltas, trades) = match data {
+            Data::Delta(_) => (1, 0),
+            Data::Deltas(deltas) => (deltas.deltas.len() as u64, 0),
+            Data::Trade(_) => (0, 1),
+            Data::Depth10(_) | Data::Quote(_) => (0, 0),
+            Data::Bar(_) => return,
+        };
+
+        let window_ns = self.config.window_ms * NANOSECONDS_IN_MILLISECOND;
+        let burst_window_ns = self.config.burst_window_ms * NANOSECONDS_IN_MILLISECOND;
+        let instrument_id = data.instrument_id();
+        let state = self
+            .states
+            .entry(instrument_id)
+            .or_insert_with(|| RateState::new(instrument_id));
+
+        // Late data is counted at the latest time seen, keeping the windows ordered
+        state.last_ts = state.last_ts.max(data.ts_init());
+        let ts = state.last_ts;
+        push_within(&mut state.window, ts, window_ns);
+        push_within(&mut state.burst, ts, burst_window_ns);
+
+        let stats = &mut state.stats;
+        stats.updates += 1;
+        stats.deltas += deltas;
+        stats.trades += trades;
+        stats.updates_per_sec = state.window.len() as f64 * 1_000.0 / self.config.window_ms as f64;
+        stats.peak_burst = stats.peak_burst.max(state.burst.len());
+
+        state.update_rate_gauge.set(stats.updates_per_sec);
+        state.peak_burst_gauge.set(stats.peak_burst as i64);
+        if let Some(ratio) = stats.delta_to_trade_ratio() {
+            state.delta_trade_ratio_gauge.set(ratio);
+        }
+    }
+}
+
+// Pushes `ts` to the window, removing the timestamps which are no longer within `window_ns`
+fn push_within(window: &mut VecDeque<UnixNanos>, ts: UnixNanos, window_ns: u64) {
+    while window
+        .front()
+        .is_some_and(|front| ts.as_u64() - front.as_u64() >= window_ns)
+    {
+        window.pop_front();
+    }
+    window.push_back(ts);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{
+        deltas::{OrderBookDeltas, OrderBookDeltas_API},
+        quote::QuoteTick,
+        stubs::{quote_ethusdt_binance, stub_delta, stub_deltas, stub_trade_ethusdt_buyer},
+        trade::TradeTick,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(ts_ms: u64) -> Data {
+        let ts = UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND);
+        Data::Quote(QuoteTick {
+            ts_event: ts,
+            ts_init: ts,
+            ..quote_ethusdt_binance()
+        })
+    }
+
+    #[rstest]
+    fn test_config_validate() {
+        assert!(MessageRateConfig::default().validate().is_ok());
+        let config = MessageRateConfig {
+            burst_window_ms: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[rstest]
+    fn test_stats_when_no_data() {
+        let monitor = MessageRateMonitor::new(MessageRateConfig::default());
+        let instrument_id = quote_ethusdt_binance().instrument_id;
+
+        assert!(monitor.stats(&instrument_id).is_none());
+    }
+
+    #[rstest]
+    fn test_update_rate_and_peak_burst() {
+        let mut monitor = MessageRateMonitor::new(MessageRateConfig::default());
+        let instrument_id = quote_ethusdt_binance().instrument_id;
+
+        // A burst of 5 updates within 10ms, then sparse updates
+        for ts_ms in [0, 1, 2, 3, 4, 500, 1_200] {
+            monitor.on_data(&quote_at(ts_ms));
+        }
+
+        let stats = monitor.stats(&instrument_id).unwrap();
+        assert_eq!(stats.updates, 7);
+        assert_eq!(stats.peak_burst, 5);
+        assert_eq!(stats.updates_per_sec, 2.0); // Updates at 500ms and 1200ms within the window
+        assert_eq!(stats.delta_to_trade_ratio(), None);
+    }
+
+    #[rstest]
+    fn test_late_data_counted_at_latest_time() {
+        let mut monitor = MessageRateMonitor::new(MessageRateConfig::default());
+        let instrument_id = quote_ethusdt_binance().instrument_id;
+
+        for ts_ms in [1_000, 0, 1_500] {
+            monitor.on_data(&quote_at(ts_ms));
+        }
+
+        let stats = monitor.stats(&instrument_id).unwrap();
+        assert_eq!(stats.updates_per_sec, 3.0);
+        assert_eq!(stats.peak_burst, 2);
+    }
+
+    #[rstest]
+    fn test_delta_to_trade_ratio(stub_deltas: OrderBookDeltas) {
+        let mut monitor = MessageRateMonitor::new(MessageRateConfig::default());
+        let num_deltas = stub_deltas.deltas.len() as u64;
+        let instrument_id = stub_deltas.instrument_id;
+
+        monitor.on_data(&Data::Deltas(OrderBookDeltas_API::new(stub_deltas)));
+        monitor.on_data(&Data::Delta(stub_delta()));
+
+        let stats = monitor.stats(&instrument_id).unwrap();
+        assert_eq!(stats.deltas, num_deltas + 1);
+        assert_eq!(stats.delta_to_trade_ratio(), None);
+
+        let trade = TradeTick {
+            instrument_id,
+            ..stub_trade_ethusdt_buyer()
+        };
+        monitor.on_data(&Data::Trade(trade));
+        monitor.on_data(&Data::Trade(trade));
+
+        let stats = monitor.stats(&instrument_id).unwrap();
+        assert_eq!(stats.trades, 2);
+        assert_eq!(
+            stats.delta_to_trade_ratio(),
+            Some((num_deltas + 1) as f64 / 2.0)
+        );
+    }
+}
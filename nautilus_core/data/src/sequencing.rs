@@ -0,0 +1,430 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Handling of late and duplicate market data.
+//!
+//! The [`DataSequencer`] checks the quotes, trades, bars and order book depth processed by the
+//! `DataEngine` against the last data of the same stream (data type and instrument or bar type).
+//! Data with a `ts_event` prior to the last data is late, and data identical to the last data is a
+//! duplicate. Late data is handled according to the configured [`LateDataPolicy`], and each issue
+//! is counted in telemetry. Order book deltas are sequenced by the order book and pass through.
+
+use std::collections::HashMap;
+
+use nautilus_common::{
+    config::{check_setting, ValidateConfig},
+    telemetry::telemetry,
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos};
+use nautilus_model::data::{Data, GetTsInit};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// The policy for handling data with a `ts_event` prior to the last data of its stream.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LateDataPolicy {
+    /// Accept late data as is.
+    #[default]
+    Accept,
+    /// Drop late data.
+    Drop,
+    /// Accept late data, overriding `ts_init` so it is non-decreasing for each stream.
+    OverrideTsInit,
+    /// Buffer data for the reorder window and release it in `ts_event` order, dropping data
+    /// which is still late.
+    Reorder,
+}
+
+/// Configuration for `DataSequencer` instances.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LateDataConfig {
+    /// The policy for handling late data.
+    pub policy: LateDataPolicy,
+    /// If data identical to the last data of its stream is dropped.
+    pub drop_duplicates: bool,
+    /// The time data is buffered for before release, in milliseconds (reorder policy only).
+    pub reorder_window_ms: u64,
+}
+
+impl Default for LateDataConfig {
+    /// Creates a new default [`LateDataConfig`] instance.
+    fn default() -> Self {
+        Self {
+            policy: LateDataPolicy::Accept,
+            drop_duplicates: false,
+            reorder_window_ms: 100,
+        }
+    }
+}
+
+impl ValidateConfig for LateDataConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.policy != LateDataPolicy::Reorder || self.reorder_window_ms > 0,
+            "data_engine.late_data.reorder_window_ms",
+            "must be positive for the reorder policy",
+        )
+    }
+}
+
+type StreamKey = (&'static str, Ustr);
+
+#[derive(Debug)]
+struct StreamState {
+    last: Data,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+/// Checks market data for late and duplicate data, applying a [`LateDataPolicy`].
+#[derive(Debug)]
+pub struct DataSequencer {
+    config: LateDataConfig,
+    streams: HashMap<StreamKey, StreamState>,
+    buffer: Vec<Data>,
+    latest_ts_init: UnixNanos,
+    late_count: u64,
+    duplicate_count: u64,
+    reordered_count: u64,
+}
+
+impl DataSequencer {
+    /// Creates a new [`DataSequencer`] instance.
+    #[must_use]
+    pub fn new(config: LateDataConfig) -> Self {
+        Self {
+            config,
+            streams: HashMap::new(),
+            buffer: Vec::new(),
+            latest_ts_init: UnixNanos::default(),
+            late_count: 0,
+            duplicate_count: 0,
+            reordered_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &LateDataConfig {
+        &self.config
+    }
+
+    /// Returns the count of late data received.
+    #[must_use]
+    pub const fn late_count(&self) -> u64 {
+        self.late_count
+    }
+
+    /// Returns the count of duplicate data dropped.
+    #[must_use]
+    pub const fn duplicate_count(&self) -> u64 {
+        self.duplicate_count
+    }
+
+    /// Returns the count of data released in a different order than received.
+    #[must_use]
+    pub const fn reordered_count(&self) -> u64 {
+        self.reordered_count
+    }
+
+    /// Returns the count of data buffered for reordering.
+    #[must_use]
+    pub fn buffered_count(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Processes the given `data` at `ts_now`, returning the data to be handled in order.
+    pub fn process(&mut self, data: Data, ts_now: UnixNanos) -> Vec<Data> {
+        let Some(key) = stream_key(&data) else {
+            return vec![data];
+        };
+
+        if self.config.policy == LateDataPolicy::Reorder {
+            return self.buffer_data(key, data, ts_now);
+        }
+
+        let mut data = data;
+        if let Some((is_duplicate, last_ts_event, last_ts_init)) = self.stream_state(key, &data) {
+            if self.config.drop_duplicates && is_duplicate {
+                self.record_issue(key, "duplicate");
+                return Vec::new();
+            }
+            if ts_event(&data) < last_ts_event {
+                self.record_issue(key, "late");
+                if self.config.policy == LateDataPolicy::Drop {
+                    return Vec::new();
+                }
+            }
+            if self.config.policy == LateDataPolicy::OverrideTsInit && data.ts_init() < last_ts_init
+            {
+                set_ts_init(&mut data, last_ts_init);
+            }
+        }
+        self.update_stream(key, &data);
+        vec![data]
+    }
+
+    /// Releases all data buffered for reordering, in `ts_event` order.
+    pub fn flush(&mut self) -> Vec<Data> {
+        self.release(None)
+    }
+
+    /// Releases the data buffered for longer than the reorder window at `ts_now`, in
+    /// `ts_event` order.
+    pub fn release_expired(&mut self, ts_now: UnixNanos) -> Vec<Data> {
+        self.latest_ts_init = self.latest_ts_init.max(ts_now);
+        self.release(Some(self.window_start()))
+    }
+
+    /// Returns the reorder window in nanoseconds.
+    #[must_use]
+    pub const fn reorder_window_ns(&self) -> u64 {
+        self.config.reorder_window_ms * NANOSECONDS_IN_MILLISECOND
+    }
+
+    fn buffer_data(&mut self, key: StreamKey, data: Data, ts_now: UnixNanos) -> Vec<Data> {
+        let ts = ts_event(&data);
+        if let Some((is_duplicate, last_ts_event, _)) = self.stream_state(key, &data) {
+            if self.config.drop_duplicates && is_duplicate {
+                self.record_issue(key, "duplicate");
+                return Vec::new();
+            }
+            if ts < last_ts_event {
+                self.record_issue(key, "late");
+                return Vec::new(); // Later than the reorder window
+            }
+        }
+        let mut buffered = self
+            .buffer
+            .iter()
+            .filter(|buffered| stream_key(buffered) == Some(key));
+        let is_duplicate = buffered.clone().any(|buffered| *buffered == data);
+        let is_reordered = buffered.any(|buffered| ts_event(buffered) > ts);
+        if self.config.drop_duplicates && is_duplicate {
+            self.record_issue(key, "duplicate");
+            return Vec::new();
+        }
+        if is_reordered {
+            self.record_issue(key, "reordered");
+        }
+
+        self.latest_ts_init = self.latest_ts_init.max(data.ts_init()).max(ts_now);
+        self.buffer.push(data);
+        self.release(Some(self.window_start()))
+    }
+
+    // Returns the start of the reorder window, before which buffered data is released
+    fn window_start(&self) -> UnixNanos {
+        self.latest_ts_init
+            .as_u64()
+            .saturating_sub(self.reorder_window_ns())
+            .into()
+    }
+
+    // Releases the buffered data with a `ts_init` at or before `ts` (all if None)
+    fn release(&mut self, ts: Option<UnixNanos>) -> Vec<Data> {
+        self.buffer
+            .sort_by_key(|data| (ts_event(data), data.ts_init()));
+        let (released, retained): (Vec<Data>, Vec<Data>) = self
+            .buffer
+            .drain(..)
+            .partition(|data| ts.is_none_or(|ts| data.ts_init() <= ts));
+        self.buffer = retained;
+        for data in &released {
+            if let Some(key) = stream_key(data) {
+                self.update_stream(key, data);
+            }
+        }
+        released
+    }
+
+    // Returns whether the data duplicates the last data of its stream, and the stream's last
+    // `ts_event` and `ts_init` (None for a new stream)
+    fn stream_state(&self, key: StreamKey, data: &Data) -> Option<(bool, UnixNanos, UnixNanos)> {
+        self.streams
+            .get(&key)
+            .map(|state| (state.last == *data, state.ts_event, state.ts_init))
+    }
+
+    fn update_stream(&mut self, key: StreamKey, data: &Data) {
+        let ts_event = ts_event(data);
+        let ts_init = data.ts_init();
+        self.streams
+            .entry(key)
+            .and_modify(|state| {
+                state.last = data.clone();
+                state.ts_event = state.ts_event.max(ts_event);
+                state.ts_init = state.ts_init.max(ts_init);
+            })
+            .or_insert_with(|| StreamState {
+                last: data.clone(),
+                ts_event,
+                ts_init,
+            });
+    }
+
+    fn record_issue(&mut self, key: StreamKey, issue: &str) {
+        match issue {
+            "late" => self.late_count += 1,
+            "duplicate" => self.duplicate_count += 1,
+            _ => self.reordered_count += 1,
+        }
+        log::debug!("{} data for {}: {issue}", key.0, key.1);
+        telemetry().record_data_sequence_issue(key.0, issue);
+    }
+}
+
+fn stream_key(data: &Data) -> Option<StreamKey> {
+    match data {
+        Data::Quote(quote) => Some(("QuoteTick", quote.instrument_id.to_string().into())),
+        Data::Trade(trade) => Some(("TradeTick", trade.instrument_id.to_string().into())),
+        Data::Bar(bar) => Some(("Bar", bar.bar_type.to_string().into())),
+        Data::Depth10(depth) => Some(("OrderBookDepth10", depth.instrument_id.to_string().into())),
+        Data::Delta(_) | Data::Deltas(_) => None,
+    }
+}
+
+fn ts_event(data: &Data) -> UnixNanos {
+    match data {
+        Data::Delta(delta) => delta.ts_event,
+        Data::Deltas(deltas) => deltas.ts_event,
+        Data::Depth10(depth) => depth.ts_event,
+        Data::Quote(quote) => quote.ts_event,
+        Data::Trade(trade) => trade.ts_event,
+        Data::Bar(bar) => bar.ts_event,
+    }
+}
+
+fn set_ts_init(data: &mut Data, ts_init: UnixNanos) {
+    match data {
+        Data::Depth10(depth) => depth.ts_init = ts_init,
+        Data::Quote(quote) => quote.ts_init = ts_init,
+        Data::Trade(trade) => trade.ts_init = ts_init,
+        Data::Bar(bar) => bar.ts_init = ts_init,
+        Data::Delta(_) | Data::Deltas(_) => {}
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{quote::QuoteTick, stubs::quote_ethusdt_binance};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(ts_event: u64, ts_init: u64) -> Data {
+        Data::Quote(QuoteTick {
+            ts_event: ts_event.into(),
+            ts_init: ts_init.into(),
+            ..quote_ethusdt_binance()
+        })
+    }
+
+    fn sequencer(policy: LateDataPolicy) -> DataSequencer {
+        DataSequencer::new(LateDataConfig {
+            policy,
+            drop_duplicates: true,
+            reorder_window_ms: 1,
+        })
+    }
+
+    #[rstest]
+    fn test_accept_counts_late_data() {
+        let mut sequencer = sequencer(LateDataPolicy::Accept);
+
+        assert_eq!(sequencer.process(quote(2, 2), 0.into()).len(), 1);
+        let released = sequencer.process(quote(1, 3), 0.into());
+
+        assert_eq!(released, vec![quote(1, 3)]);
+        assert_eq!(sequencer.late_count(), 1);
+    }
+
+    #[rstest]
+    fn test_drop_late_and_duplicate_data() {
+        let mut sequencer = sequencer(LateDataPolicy::Drop);
+
+        sequencer.process(quote(2, 2), 0.into());
+
+        assert!(sequencer.process(quote(1, 3), 0.into()).is_empty());
+        assert!(sequencer.process(quote(2, 2), 0.into()).is_empty());
+        assert_eq!(sequencer.process(quote(2, 4), 0.into()).len(), 1);
+        assert_eq!(sequencer.late_count(), 1);
+        assert_eq!(sequencer.duplicate_count(), 1);
+    }
+
+    #[rstest]
+    fn test_override_ts_init_is_monotonic() {
+        let mut sequencer = sequencer(LateDataPolicy::OverrideTsInit);
+
+        sequencer.process(quote(2, 5), 0.into());
+        let released = sequencer.process(quote(1, 3), 0.into());
+
+        assert_eq!(released, vec![quote(1, 5)]);
+        assert_eq!(sequencer.late_count(), 1);
+    }
+
+    #[rstest]
+    fn test_reorder_within_window() {
+        let mut sequencer = sequencer(LateDataPolicy::Reorder);
+        let window_ns = NANOSECONDS_IN_MILLISECOND;
+
+        assert!(sequencer.process(quote(20, 20), 0.into()).is_empty());
+        assert!(sequencer.process(quote(10, 30), 0.into()).is_empty());
+        assert_eq!(sequencer.buffered_count(), 2);
+        assert_eq!(sequencer.reordered_count(), 1);
+
+        // Both released once the window has elapsed, in `ts_event` order
+        let released = sequencer.process(quote(40, 40 + window_ns), 0.into());
+        assert_eq!(released, vec![quote(10, 30), quote(20, 20)]);
+
+        // Prior to released data, so later than the window
+        assert!(sequencer
+            .process(quote(15, 50 + window_ns), 0.into())
+            .is_empty());
+        assert_eq!(sequencer.late_count(), 1);
+        assert_eq!(sequencer.flush(), vec![quote(40, 40 + window_ns)]);
+    }
+
+    #[rstest]
+    fn test_release_expired_without_new_data() {
+        let mut sequencer = sequencer(LateDataPolicy::Reorder);
+        let window_ns = NANOSECONDS_IN_MILLISECOND;
+
+        sequencer.process(quote(20, 20), 0.into());
+        sequencer.process(quote(10, 30), 0.into());
+
+        assert!(sequencer
+            .release_expired((20 + window_ns - 1).into())
+            .is_empty());
+        let released = sequencer.release_expired((30 + window_ns).into());
+        assert_eq!(released, vec![quote(10, 30), quote(20, 20)]);
+        assert_eq!(sequencer.buffered_count(), 0);
+    }
+
+    #[rstest]
+    fn test_validate_config() {
+        let config = LateDataConfig {
+            policy: LateDataPolicy::Reorder,
+            reorder_window_ms: 0,
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_err());
+        assert!(LateDataConfig::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,96 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Generates the engine control gRPC service from the message types defined in
+    // `src/grpc/proto.rs`, without requiring `protoc` at build time
+    #[cfg(feature = "grpc")]
+    {
+        use tonic_build::manual::{Builder, Method, MethodBuilder, Service};
+
+        fn method(name: &str, route_name: &str, input: &str, output: &str) -> MethodBuilder {
+            Method::builder()
+                .name(name)
+                .route_name(route_name)
+                .input_type(format!("crate::grpc::proto::{input}"))
+                .output_type(format!("crate::grpc::proto::{output}"))
+                .codec_path("tonic::codec::ProstCodec")
+        }
+
+        let service = Service::builder()
+            .name("EngineControl")
+            .package("nautilus.control")
+            .method(
+                method(
+                    "subscribe_data",
+                    "SubscribeData",
+                    "SubscribeDataRequest",
+                    "DataMessage",
+                )
+                .server_streaming()
+                .build(),
+            )
+            .method(
+                method(
+                    "submit_order",
+                    "SubmitOrder",
+                    "SubmitOrderRequest",
+                    "CommandResponse",
+                )
+                .build(),
+            )
+            .method(
+                method(
+                    "cancel_order",
+                    "CancelOrder",
+                    "CancelOrderRequest",
+                    "CommandResponse",
+                )
+                .build(),
+            )
+            .method(
+                method(
+                    "get_portfolio",
+                    "GetPortfolio",
+                    "PortfolioRequest",
+                    "PortfolioState",
+                )
+                .build(),
+            )
+            .method(
+                method(
+                    "set_parameter",
+                    "SetParameter",
+                    "SetParameterRequest",
+                    "ParameterChangedMessage",
+                )
+                .build(),
+            )
+            .method(
+                method(
+                    "get_parameters",
+                    "GetParameters",
+                    "ParametersRequest",
+                    "ParametersState",
+                )
+                .build(),
+            )
+            .build();
+
+        Builder::new().compile(&[service]);
+    }
+}
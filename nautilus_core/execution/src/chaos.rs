@@ -0,0 +1,375 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fault injection for execution clients.
+//!
+//! A [`FaultyExecutionHandler`] is installed between an `ExecutionClient` and the handler which
+//! executes its commands. It injects faults from a seeded schedule into the order events sent to
+//! the `ExecutionEngine` for the client's orders, by intercepting the `exec_engine_process`
+//! endpoint. A disconnect fault drops events and fails commands until the client is reconnected.
+
+use std::{any::Any, cell::RefCell, collections::HashSet, rc::Rc};
+
+use nautilus_common::{
+    chaos::{Fault, FaultConfig, FaultInjector, FaultStats},
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use nautilus_model::{
+    data::Data,
+    events::order::OrderEventAny,
+    identifiers::{ClientId, ClientOrderId},
+    orders::any::OrderAny,
+};
+use ustr::Ustr;
+
+use crate::{
+    client::{ExecutionClient, ExecutionHandler},
+    messages::TradingCommand,
+};
+
+/// Provides an execution handler which injects faults at the boundary between an execution
+/// client and the `ExecutionEngine`.
+pub struct FaultyExecutionHandler {
+    client_id: ClientId,
+    handler: Rc<RefCell<dyn ExecutionHandler>>,
+    events: Rc<FaultyEventHandler>,
+}
+
+impl FaultyExecutionHandler {
+    /// Installs a [`FaultyExecutionHandler`] as the handler of the `client`, executing commands
+    /// with the given `handler`, and registers it on the `exec_engine_process` endpoint of the
+    /// `msgbus`.
+    ///
+    /// The endpoint is intercepted by forwarding to the handler previously registered there, so
+    /// this must be called after the execution engine is registered.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `config` is invalid.
+    pub fn install(
+        client: &mut ExecutionClient,
+        handler: Rc<RefCell<dyn ExecutionHandler>>,
+        config: FaultConfig,
+        clock: &'static AtomicTime,
+        msgbus: &Rc<RefCell<MessageBus>>,
+    ) -> anyhow::Result<Rc<RefCell<Self>>> {
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        let events = Rc::new(FaultyEventHandler {
+            id: Ustr::from(&format!("FaultyExecutionHandler-{}", client.client_id)),
+            client_id: client.client_id,
+            injector: RefCell::new(FaultInjector::new(config)?),
+            client_order_ids: RefCell::new(HashSet::new()),
+            clock,
+            engine_handler: msgbus.borrow().get_endpoint(endpoint).cloned(),
+        });
+        msgbus
+            .borrow_mut()
+            .register(endpoint, ShareableMessageHandler(events.clone()));
+
+        let faulty = Rc::new(RefCell::new(Self {
+            client_id: client.client_id,
+            handler,
+            events,
+        }));
+        client.set_handler(faulty.clone());
+        Ok(faulty)
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> FaultStats {
+        self.events.injector.borrow().stats()
+    }
+
+    #[must_use]
+    pub fn is_disconnected(&self) -> bool {
+        self.events.injector.borrow().is_disconnected()
+    }
+
+    /// Sends the delayed order events due for delivery at `ts_now` to the `ExecutionEngine`.
+    pub fn release(&self, ts_now: UnixNanos) {
+        let events = self.events.injector.borrow_mut().release(ts_now);
+        self.events.forward(events);
+    }
+
+    /// Restores the connection after a disconnect fault.
+    pub fn reconnect(&self) {
+        self.events.injector.borrow_mut().reconnect();
+        log::info!("Reconnected {} after disconnect fault", self.client_id);
+    }
+}
+
+impl ExecutionHandler for FaultyExecutionHandler {
+    fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        if self.is_disconnected() {
+            anyhow::bail!(
+                "Cannot execute {command}: {} disconnected by fault injection",
+                self.client_id
+            );
+        }
+
+        let mut client_order_ids = self.events.client_order_ids.borrow_mut();
+        match &command {
+            TradingCommand::SubmitOrder(command) => {
+                client_order_ids.insert(command.client_order_id);
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                client_order_ids.extend(
+                    command
+                        .order_list
+                        .orders
+                        .iter()
+                        .map(OrderAny::client_order_id),
+                );
+            }
+            _ => {}
+        }
+        drop(client_order_ids);
+
+        self.handler.borrow_mut().execute(command)
+    }
+}
+
+/// Injects faults into the order events for the orders submitted through a client, forwarding
+/// all other messages to the `ExecutionEngine` unchanged.
+struct FaultyEventHandler {
+    id: Ustr,
+    client_id: ClientId,
+    injector: RefCell<FaultInjector<OrderEventAny>>,
+    client_order_ids: RefCell<HashSet<ClientOrderId>>,
+    clock: &'static AtomicTime,
+    engine_handler: Option<ShareableMessageHandler>,
+}
+
+impl FaultyEventHandler {
+    fn forward(&self, events: Vec<OrderEventAny>) {
+        let Some(engine_handler) = &self.engine_handler else {
+            return;
+        };
+        for event in events {
+            engine_handler.0.handle(&event as &dyn Any);
+        }
+    }
+}
+
+impl MessageHandler for FaultyEventHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let event = message.downcast_ref::<OrderEventAny>().filter(|event| {
+            self.client_order_ids
+                .borrow()
+                .contains(&event.client_order_id())
+        });
+        let Some(event) = event else {
+            if let Some(engine_handler) = &self.engine_handler {
+                engine_handler.0.handle(message);
+            }
+            return;
+        };
+
+        let ts_now = self.clock.get_time_ns();
+        let (fault, events) = self.injector.borrow_mut().process(event.clone(), ts_now);
+        if fault == Fault::Disconnect {
+            log::warn!("Injected disconnect fault for {}", self.client_id);
+        }
+        self.forward(events);
+    }
+
+    fn handle_response(&self, resp: DataResponse) {
+        if let Some(engine_handler) = &self.engine_handler {
+            engine_handler.0.handle_response(resp);
+        }
+    }
+
+    fn handle_data(&self, data: Data) {
+        if let Some(engine_handler) = &self.engine_handler {
+            engine_handler.0.handle_data(data);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use nautilus_common::{
+        cache::Cache,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+    use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, uuid::UUID4};
+    use nautilus_model::{
+        enums::{AccountType, OmsType, OrderType},
+        events::order::OrderAccepted,
+        identifiers::{AccountId, TraderId, Venue, VenueOrderId},
+        orders::builder::OrderTestBuilder,
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::SubmitOrder;
+
+    static ATOMIC_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+
+    /// Accepts each submitted order, sending the event to the `ExecutionEngine` as a venue would.
+    struct AcceptingHandler {
+        msgbus: Rc<RefCell<MessageBus>>,
+    }
+
+    impl ExecutionHandler for AcceptingHandler {
+        fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+            if let TradingCommand::SubmitOrder(command) = command {
+                let event = OrderEventAny::Accepted(OrderAccepted {
+                    client_order_id: command.client_order_id,
+                    ..Default::default()
+                });
+                let msgbus = self.msgbus.borrow();
+                msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+            }
+            Ok(())
+        }
+    }
+
+    struct TestContext {
+        client: ExecutionClient,
+        faulty: Rc<RefCell<FaultyExecutionHandler>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        events: ShareableMessageHandler,
+    }
+
+    fn context(config: FaultConfig) -> TestContext {
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let events = get_message_saving_handler::<OrderEventAny>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+        msgbus.borrow_mut().register(endpoint, events.clone());
+
+        let mut client = ExecutionClient::new(
+            TraderId::from("TRADER-001"),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Cash,
+            None,
+            &ATOMIC_TIME,
+            Rc::new(RefCell::new(Cache::default())),
+            msgbus.clone(),
+        );
+        let handler = Rc::new(RefCell::new(AcceptingHandler {
+            msgbus: msgbus.clone(),
+        }));
+        let faulty =
+            FaultyExecutionHandler::install(&mut client, handler, config, &ATOMIC_TIME, &msgbus)
+                .unwrap();
+        TestContext {
+            client,
+            faulty,
+            msgbus,
+            events,
+        }
+    }
+
+    fn submit_order(client_order_id: &str) -> SubmitOrder {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id("AUD/USD.SIM".into())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .quantity(Quantity::from(100_000))
+            .build();
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("NONE"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_delayed_events_sent_on_release() {
+        let ctx = context(FaultConfig {
+            delay_probability: 1.0,
+            max_delay_ms: 1,
+            ..Default::default()
+        });
+
+        ctx.client.submit_order(submit_order("O-1")).unwrap();
+        assert!(get_saved_messages::<OrderEventAny>(ctx.events.clone()).is_empty());
+
+        ctx.faulty
+            .borrow()
+            .release(UnixNanos::from(NANOSECONDS_IN_MILLISECOND));
+        let events = get_saved_messages::<OrderEventAny>(ctx.events);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].client_order_id(), ClientOrderId::from("O-1"));
+    }
+
+    #[rstest]
+    fn test_disconnect_fault_fails_commands_until_reconnected() {
+        let ctx = context(FaultConfig {
+            disconnect_probability: 1.0,
+            ..Default::default()
+        });
+
+        ctx.client.submit_order(submit_order("O-1")).unwrap();
+        assert!(ctx.faulty.borrow().is_disconnected());
+        assert!(ctx.client.submit_order(submit_order("O-2")).is_err());
+        assert!(get_saved_messages::<OrderEventAny>(ctx.events.clone()).is_empty());
+
+        ctx.faulty.borrow().reconnect();
+        assert!(ctx.client.submit_order(submit_order("O-3")).is_ok());
+        let stats = ctx.faulty.borrow().stats();
+        assert_eq!((stats.messages, stats.disconnects), (2, 2));
+    }
+
+    #[rstest]
+    fn test_events_for_other_orders_forwarded_unchanged() {
+        let ctx = context(FaultConfig {
+            drop_probability: 1.0,
+            ..Default::default()
+        });
+        let event = OrderEventAny::Accepted(OrderAccepted {
+            client_order_id: ClientOrderId::from("O-OTHER"),
+            ..Default::default()
+        });
+
+        let msgbus = ctx.msgbus.borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+
+        assert_eq!(get_saved_messages::<OrderEventAny>(ctx.events), vec![event]);
+        assert_eq!(ctx.faulty.borrow().stats().messages, 0);
+    }
+}
@@ -21,11 +21,11 @@
 
 use std::{cell::RefCell, rc::Rc};
 
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_common::{cache::Cache, capabilities::ClientCapabilities, msgbus::MessageBus};
 use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
 use nautilus_model::{
     accounts::any::AccountAny,
-    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
+    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType, VenueRejectReason},
     events::{
         account::state::AccountState,
         order::{
@@ -48,10 +48,34 @@ use nautilus_model::{
 };
 
 use crate::messages::{
-    cancel::CancelOrder, cancel_batch::BatchCancelOrders, modify::ModifyOrder, query::QueryOrder,
-    submit::SubmitOrder, submit_list::SubmitOrderList,
+    cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
+    modify::ModifyOrder, modify_batch::BatchModifyOrders, query::QueryOrder, submit::SubmitOrder,
+    submit_list::SubmitOrderList, TradingCommand,
 };
 
+/// Executes the trading commands routed to an [`ExecutionClient`] in-process, for clients
+/// without a venue API (such as a sandbox).
+pub trait ExecutionHandler {
+    /// Executes the given trading `command`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the command cannot be executed.
+    fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()>;
+}
+
+/// The native batch endpoints supported by the venue of an execution client.
+///
+/// Batch commands without a native endpoint are executed as individual commands by the
+/// `ExecutionEngine` where their semantics allow.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NativeBatchSupport {
+    pub submit_order_list: bool,
+    pub cancel_all_orders: bool,
+    pub batch_cancel_orders: bool,
+    pub batch_modify_orders: bool,
+}
+
 pub struct ExecutionClient {
     pub trader_id: TraderId,
     pub client_id: ClientId,
@@ -61,12 +85,62 @@ pub struct ExecutionClient {
     pub account_type: AccountType,
     pub base_currency: Option<Currency>,
     pub is_connected: bool,
+    pub native_batch: NativeBatchSupport,
+    /// If the venue supports orders with a quantity in the quote currency natively.
+    pub native_quote_quantity: bool,
+    /// The order types and time in force options supported by the venue.
+    pub capabilities: ClientCapabilities,
+    handler: Option<Rc<RefCell<dyn ExecutionHandler>>>,
     clock: &'static AtomicTime,
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
 }
 
 impl ExecutionClient {
+    /// Creates a new [`ExecutionClient`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        client_id: ClientId,
+        venue: Venue,
+        oms_type: OmsType,
+        account_id: AccountId,
+        account_type: AccountType,
+        base_currency: Option<Currency>,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            venue,
+            oms_type,
+            account_id,
+            account_type,
+            base_currency,
+            is_connected: false,
+            native_batch: NativeBatchSupport {
+                submit_order_list: false,
+                cancel_all_orders: false,
+                batch_cancel_orders: false,
+                batch_modify_orders: false,
+            },
+            native_quote_quantity: false,
+            capabilities: ClientCapabilities::unrestricted(),
+            handler: None,
+            clock,
+            cache,
+            msgbus,
+        }
+    }
+
+    /// Sets the `handler` to execute the commands routed to the client.
+    pub fn set_handler(&mut self, handler: Rc<RefCell<dyn ExecutionHandler>>) {
+        self.handler = Some(handler);
+    }
+
     #[must_use]
     pub fn get_account(&self) -> AccountAny {
         let cache = self.cache.as_ref().borrow();
@@ -76,27 +150,57 @@ impl ExecutionClient {
     // -- COMMAND HANDLERS ----------------------------------------------------
 
     pub fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()> {
-        todo!();
+        self.execute(TradingCommand::SubmitOrder(command))
     }
 
     pub fn submit_order_list(&self, command: SubmitOrderList) -> anyhow::Result<()> {
-        todo!();
+        self.execute(TradingCommand::SubmitOrderList(command))
     }
 
     pub fn modify_order(&self, command: ModifyOrder) -> anyhow::Result<()> {
-        todo!();
+        self.execute(TradingCommand::ModifyOrder(command))
     }
 
     pub fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()> {
-        todo!();
+        self.execute(TradingCommand::CancelOrder(command))
+    }
+
+    pub fn cancel_all_orders(&self, command: CancelAllOrders) -> anyhow::Result<()> {
+        if self.handler.is_none() {
+            anyhow::bail!(
+                "Cannot cancel all orders for {}: no native venue endpoint",
+                command.instrument_id
+            )
+        }
+        self.execute(TradingCommand::CancelAllOrders(command))
     }
 
     pub fn batch_cancel_orders(&self, command: BatchCancelOrders) -> anyhow::Result<()> {
-        todo!();
+        self.execute(TradingCommand::BatchCancelOrders(command))
+    }
+
+    pub fn batch_modify_orders(&self, command: BatchModifyOrders) -> anyhow::Result<()> {
+        if self.handler.is_none() {
+            anyhow::bail!(
+                "Cannot batch modify orders for {}: no native venue endpoint",
+                command.instrument_id
+            )
+        }
+        self.execute(TradingCommand::BatchModifyOrders(command))
     }
 
     pub fn query_order(&self, command: QueryOrder) -> anyhow::Result<()> {
-        todo!();
+        self.execute(TradingCommand::QueryOrder(command))
+    }
+
+    fn execute(&self, command: TradingCommand) -> anyhow::Result<()> {
+        match &self.handler {
+            Some(handler) => handler.borrow_mut().execute(command),
+            None => anyhow::bail!(
+                "Cannot execute {command}: no handler for {}",
+                self.client_id
+            ),
+        }
     }
 
     pub fn generate_account_state(
@@ -147,6 +251,7 @@ impl ExecutionClient {
         instrument_id: InstrumentId,
         client_order_id: ClientOrderId,
         reason: &str,
+        reject_reason: VenueRejectReason,
         ts_event: UnixNanos,
     ) {
         let event = OrderRejected::new(
@@ -160,6 +265,7 @@ impl ExecutionClient {
             ts_event,
             self.clock.get_time_ns(),
             false,
+            reject_reason,
         );
         self.send_order_event(OrderEventAny::Rejected(event));
     }
@@ -187,6 +293,7 @@ impl ExecutionClient {
         self.send_order_event(OrderEventAny::Accepted(event));
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn generate_order_modify_rejected(
         &self,
         strategy_id: StrategyId,
@@ -194,6 +301,7 @@ impl ExecutionClient {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
         reason: &str,
+        reject_reason: VenueRejectReason,
         ts_event: UnixNanos,
     ) {
         let event = OrderModifyRejected::new(
@@ -208,10 +316,12 @@ impl ExecutionClient {
             false,
             Some(venue_order_id),
             Some(self.account_id),
+            reject_reason,
         );
         self.send_order_event(OrderEventAny::ModifyRejected(event));
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn generate_order_cancel_rejected(
         &self,
         strategy_id: StrategyId,
@@ -219,6 +329,7 @@ impl ExecutionClient {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
         reason: &str,
+        reject_reason: VenueRejectReason,
         ts_event: UnixNanos,
     ) {
         let event = OrderCancelRejected::new(
@@ -233,6 +344,7 @@ impl ExecutionClient {
             false,
             Some(venue_order_id),
             Some(self.account_id),
+            reject_reason,
         );
         self.send_order_event(OrderEventAny::CancelRejected(event));
     }
@@ -0,0 +1,471 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conditional orders which are held by the execution engine until a data trigger is hit.
+//!
+//! A [`DataTrigger`] references an instrument, the price to evaluate (last, bid, ask, mark or
+//! index price) and a threshold. The trigger instrument need not be the instrument of the
+//! contained order, so an order can be activated on the data for a related market. When the
+//! trigger is hit the contained `SubmitOrder` command is released for execution.
+
+use std::fmt::Display;
+
+use anyhow::bail;
+use indexmap::IndexMap;
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::OrderStatus,
+    identifiers::{ClientOrderId, InstrumentId},
+    types::price::Price,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::submit::SubmitOrder;
+
+/// The price evaluated by a [`DataTrigger`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum DataTriggerType {
+    /// The last traded price.
+    LastPrice,
+    /// The best bid price of the last quote.
+    BidPrice,
+    /// The best ask price of the last quote.
+    AskPrice,
+    /// The venue published mark price.
+    MarkPrice,
+    /// The venue published index price.
+    IndexPrice,
+}
+
+/// The condition under which a [`DataTrigger`] is hit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum TriggerCondition {
+    /// The price touches the threshold from below.
+    AtOrAbove,
+    /// The price touches the threshold from above.
+    AtOrBelow,
+}
+
+/// Represents a price condition on the data for an instrument.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DataTrigger {
+    /// The instrument ID for the data evaluated.
+    pub instrument_id: InstrumentId,
+    /// The price evaluated.
+    pub trigger_type: DataTriggerType,
+    /// The condition under which the trigger is hit.
+    pub condition: TriggerCondition,
+    /// The threshold price.
+    pub threshold: Price,
+}
+
+impl DataTrigger {
+    /// Creates a new [`DataTrigger`] instance.
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        trigger_type: DataTriggerType,
+        condition: TriggerCondition,
+        threshold: Price,
+    ) -> Self {
+        Self {
+            instrument_id,
+            trigger_type,
+            condition,
+            threshold,
+        }
+    }
+
+    /// Returns whether the trigger is hit by the given `price`.
+    #[must_use]
+    pub fn is_hit(&self, price: Price) -> bool {
+        match self.condition {
+            TriggerCondition::AtOrAbove => price >= self.threshold,
+            TriggerCondition::AtOrBelow => price <= self.threshold,
+        }
+    }
+}
+
+impl Display for DataTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, condition={}, threshold={})",
+            self.trigger_type, self.instrument_id, self.condition, self.threshold,
+        )
+    }
+}
+
+/// Represents an order held until its trigger is hit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConditionalOrder {
+    /// The trigger for the order.
+    pub trigger: DataTrigger,
+    /// The command to submit the contained order once triggered.
+    pub command: SubmitOrder,
+}
+
+/// Holds conditional orders and evaluates their triggers against incoming data.
+///
+/// Triggered orders are removed and returned in the order they were added.
+#[derive(Clone, Debug, Default)]
+pub struct ConditionalOrderManager {
+    pending: IndexMap<ClientOrderId, ConditionalOrder>,
+}
+
+impl ConditionalOrderManager {
+    /// Creates a new empty [`ConditionalOrderManager`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the order for the given submit `command`, to be released when `trigger` is hit.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The order is already pending.
+    /// - The order is not in the `INITIALIZED` state.
+    pub fn add(&mut self, trigger: DataTrigger, command: SubmitOrder) -> anyhow::Result<()> {
+        let client_order_id = command.order.client_order_id();
+        if self.pending.contains_key(&client_order_id) {
+            bail!("Conditional order {client_order_id} already pending");
+        }
+        if command.order.status() != OrderStatus::Initialized {
+            bail!(
+                "Conditional order {client_order_id} was not INITIALIZED, was {}",
+                command.order.status()
+            );
+        }
+
+        self.pending
+            .insert(client_order_id, ConditionalOrder { trigger, command });
+        Ok(())
+    }
+
+    /// Removes the pending order with the given `client_order_id` (if found).
+    pub fn cancel(&mut self, client_order_id: &ClientOrderId) -> Option<ConditionalOrder> {
+        self.pending.shift_remove(client_order_id)
+    }
+
+    /// Returns the pending order with the given `client_order_id` (if found).
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&ConditionalOrder> {
+        self.pending.get(client_order_id)
+    }
+
+    /// Returns the count of pending orders.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Evaluates the bid and ask price triggers for the quote's instrument.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> Vec<ConditionalOrder> {
+        let mut triggered = self.evaluate(
+            quote.instrument_id,
+            DataTriggerType::BidPrice,
+            quote.bid_price,
+        );
+        triggered.extend(self.evaluate(
+            quote.instrument_id,
+            DataTriggerType::AskPrice,
+            quote.ask_price,
+        ));
+        triggered
+    }
+
+    /// Evaluates the last price triggers for the trade's instrument.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Vec<ConditionalOrder> {
+        self.evaluate(trade.instrument_id, DataTriggerType::LastPrice, trade.price)
+    }
+
+    /// Evaluates the mark price triggers for the given `instrument_id`.
+    pub fn handle_mark_price(
+        &mut self,
+        instrument_id: InstrumentId,
+        price: Price,
+    ) -> Vec<ConditionalOrder> {
+        self.evaluate(instrument_id, DataTriggerType::MarkPrice, price)
+    }
+
+    /// Evaluates the index price triggers for the given `instrument_id`.
+    pub fn handle_index_price(
+        &mut self,
+        instrument_id: InstrumentId,
+        price: Price,
+    ) -> Vec<ConditionalOrder> {
+        self.evaluate(instrument_id, DataTriggerType::IndexPrice, price)
+    }
+
+    fn evaluate(
+        &mut self,
+        instrument_id: InstrumentId,
+        trigger_type: DataTriggerType,
+        price: Price,
+    ) -> Vec<ConditionalOrder> {
+        let triggered_ids: Vec<ClientOrderId> = self
+            .pending
+            .iter()
+            .filter(|(_, conditional)| {
+                let trigger = &conditional.trigger;
+                trigger.instrument_id == instrument_id
+                    && trigger.trigger_type == trigger_type
+                    && trigger.is_hit(price)
+            })
+            .map(|(client_order_id, _)| *client_order_id)
+            .collect();
+
+        triggered_ids
+            .iter()
+            .filter_map(|client_order_id| self.pending.shift_remove(client_order_id))
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{AggressorSide, OrderSide, OrderType},
+        identifiers::{ClientId, TradeId, VenueOrderId},
+        orders::{any::OrderAny, builder::OrderTestBuilder},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const ETHUSDT: &str = "ETHUSDT-PERP.BINANCE";
+    const ETHUSD_INDEX: &str = "ETHUSD-INDEX.BINANCE";
+
+    fn submit_order(client_order_id: &str) -> SubmitOrder {
+        let order: OrderAny = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from(ETHUSDT))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("BINANCE"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    fn trigger(
+        instrument_id: &str,
+        trigger_type: DataTriggerType,
+        condition: TriggerCondition,
+        threshold: &str,
+    ) -> DataTrigger {
+        DataTrigger::new(
+            InstrumentId::from(instrument_id),
+            trigger_type,
+            condition,
+            Price::from(threshold),
+        )
+    }
+
+    fn quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(ETHUSDT),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn trade(price: &str) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from(ETHUSDT),
+            Price::from(price),
+            Quantity::from("1.000"),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    #[case(TriggerCondition::AtOrAbove, "2999.99", false)]
+    #[case(TriggerCondition::AtOrAbove, "3000.00", true)]
+    #[case(TriggerCondition::AtOrBelow, "3000.00", true)]
+    #[case(TriggerCondition::AtOrBelow, "3000.01", false)]
+    fn test_trigger_is_hit(
+        #[case] condition: TriggerCondition,
+        #[case] price: &str,
+        #[case] expected: bool,
+    ) {
+        let trigger = trigger(ETHUSDT, DataTriggerType::LastPrice, condition, "3000.00");
+        assert_eq!(trigger.is_hit(Price::from(price)), expected);
+    }
+
+    #[rstest]
+    fn test_add_duplicate_order() {
+        let mut manager = ConditionalOrderManager::new();
+        let trigger = trigger(
+            ETHUSDT,
+            DataTriggerType::LastPrice,
+            TriggerCondition::AtOrAbove,
+            "3000.00",
+        );
+        manager.add(trigger, submit_order("O-1")).unwrap();
+
+        let result = manager.add(trigger, submit_order("O-1"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Conditional order O-1 already pending"
+        );
+    }
+
+    #[rstest]
+    fn test_trade_activates_last_price_trigger() {
+        let mut manager = ConditionalOrderManager::new();
+        manager
+            .add(
+                trigger(
+                    ETHUSDT,
+                    DataTriggerType::LastPrice,
+                    TriggerCondition::AtOrAbove,
+                    "3000.00",
+                ),
+                submit_order("O-1"),
+            )
+            .unwrap();
+
+        assert!(manager.handle_trade(&trade("2999.99")).is_empty());
+        // A quote through the threshold does not hit a last price trigger
+        assert!(manager
+            .handle_quote(&quote("3001.00", "3001.01"))
+            .is_empty());
+
+        let triggered = manager.handle_trade(&trade("3000.00"));
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(
+            triggered[0].command.order.client_order_id(),
+            ClientOrderId::from("O-1")
+        );
+        assert_eq!(manager.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_quote_activates_bid_and_ask_triggers_in_order() {
+        let mut manager = ConditionalOrderManager::new();
+        manager
+            .add(
+                trigger(
+                    ETHUSDT,
+                    DataTriggerType::AskPrice,
+                    TriggerCondition::AtOrBelow,
+                    "2990.00",
+                ),
+                submit_order("O-1"),
+            )
+            .unwrap();
+        manager
+            .add(
+                trigger(
+                    ETHUSDT,
+                    DataTriggerType::BidPrice,
+                    TriggerCondition::AtOrBelow,
+                    "2990.00",
+                ),
+                submit_order("O-2"),
+            )
+            .unwrap();
+
+        let triggered = manager.handle_quote(&quote("2989.99", "2990.00"));
+        let ids: Vec<ClientOrderId> = triggered
+            .iter()
+            .map(|conditional| conditional.command.order.client_order_id())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![ClientOrderId::from("O-2"), ClientOrderId::from("O-1")]
+        );
+    }
+
+    #[rstest]
+    fn test_index_price_activates_order_on_other_instrument() {
+        let mut manager = ConditionalOrderManager::new();
+        manager
+            .add(
+                trigger(
+                    ETHUSD_INDEX,
+                    DataTriggerType::IndexPrice,
+                    TriggerCondition::AtOrBelow,
+                    "2900.00",
+                ),
+                submit_order("O-1"),
+            )
+            .unwrap();
+
+        // Mark price on the same instrument is a different trigger type
+        assert!(manager
+            .handle_mark_price(InstrumentId::from(ETHUSD_INDEX), Price::from("2800.00"))
+            .is_empty());
+
+        let triggered =
+            manager.handle_index_price(InstrumentId::from(ETHUSD_INDEX), Price::from("2899.50"));
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(
+            triggered[0].command.instrument_id,
+            InstrumentId::from(ETHUSDT)
+        );
+    }
+
+    #[rstest]
+    fn test_cancel_removes_pending_order() {
+        let mut manager = ConditionalOrderManager::new();
+        manager
+            .add(
+                trigger(
+                    ETHUSDT,
+                    DataTriggerType::MarkPrice,
+                    TriggerCondition::AtOrAbove,
+                    "3000.00",
+                ),
+                submit_order("O-1"),
+            )
+            .unwrap();
+
+        assert!(manager.cancel(&ClientOrderId::from("O-1")).is_some());
+        assert!(manager
+            .handle_mark_price(InstrumentId::from(ETHUSDT), Price::from("3100.00"))
+            .is_empty());
+    }
+}
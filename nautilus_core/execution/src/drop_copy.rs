@@ -0,0 +1,466 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `DropCopyReconciler` which cross-checks fills from a drop copy session against the primary
+//! execution client.
+//!
+//! A drop copy session (such as a FIX drop copy or a venue's secondary WebSocket feed) receives
+//! a copy of every execution on the account, independently of the session orders were submitted
+//! through. Each fill is matched by instrument and trade ID against the fill from the other
+//! source, and a `DropCopyAlert` is published on the message bus when the matched fills disagree,
+//! or when a fill is not matched by the other source within the configured timeout.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use nautilus_common::{
+    clock::Clock,
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::OrderSide,
+    events::order::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, TradeId, VenueOrderId},
+    types::{price::Price, quantity::Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+/// Represents a fill received from a drop copy session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DropCopyFill {
+    pub instrument_id: InstrumentId,
+    pub trade_id: TradeId,
+    pub venue_order_id: VenueOrderId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub order_side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub ts_event: UnixNanos,
+}
+
+impl From<&OrderFilled> for DropCopyFill {
+    fn from(fill: &OrderFilled) -> Self {
+        Self {
+            instrument_id: fill.instrument_id,
+            trade_id: fill.trade_id,
+            venue_order_id: fill.venue_order_id,
+            client_order_id: Some(fill.client_order_id),
+            order_side: fill.order_side,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            ts_event: fill.ts_event,
+        }
+    }
+}
+
+/// The source of a fill being reconciled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FillSource {
+    /// The primary execution client.
+    Primary,
+    /// The drop copy session.
+    DropCopy,
+}
+
+impl Display for FillSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Primary => write!(f, "PRIMARY"),
+            Self::DropCopy => write!(f, "DROP_COPY"),
+        }
+    }
+}
+
+/// A discrepancy found between the primary and drop copy fills.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DropCopyDiscrepancy {
+    /// The fill was only received from the given source within the timeout.
+    Unmatched(FillSource),
+    /// The matched fills have a different order side.
+    SideMismatch {
+        primary: OrderSide,
+        drop_copy: OrderSide,
+    },
+    /// The matched fills have a different fill quantity.
+    QuantityMismatch {
+        primary: Quantity,
+        drop_copy: Quantity,
+    },
+    /// The matched fills have a different fill price.
+    PriceMismatch { primary: Price, drop_copy: Price },
+}
+
+impl Display for DropCopyDiscrepancy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unmatched(source) => write!(f, "unmatched {source} fill"),
+            Self::SideMismatch { primary, drop_copy } => {
+                write!(f, "side mismatch: primary={primary}, drop_copy={drop_copy}")
+            }
+            Self::QuantityMismatch { primary, drop_copy } => {
+                write!(
+                    f,
+                    "quantity mismatch: primary={primary}, drop_copy={drop_copy}"
+                )
+            }
+            Self::PriceMismatch { primary, drop_copy } => {
+                write!(
+                    f,
+                    "price mismatch: primary={primary}, drop_copy={drop_copy}"
+                )
+            }
+        }
+    }
+}
+
+/// Represents an alert raised on a discrepancy between the primary and drop copy fills.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DropCopyAlert {
+    pub instrument_id: InstrumentId,
+    pub trade_id: TradeId,
+    pub discrepancy: DropCopyDiscrepancy,
+    pub ts_event: UnixNanos,
+}
+
+impl Display for DropCopyAlert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, trade_id={}, discrepancy={})",
+            stringify!(DropCopyAlert),
+            self.instrument_id,
+            self.trade_id,
+            self.discrepancy,
+        )
+    }
+}
+
+type FillKey = (InstrumentId, TradeId);
+
+// The fills awaiting a match, shared with the timeout alert callbacks
+#[derive(Default)]
+struct PendingFills {
+    primary: HashMap<FillKey, DropCopyFill>,
+    drop_copy: HashMap<FillKey, DropCopyFill>,
+}
+
+impl PendingFills {
+    fn source_mut(&mut self, source: FillSource) -> &mut HashMap<FillKey, DropCopyFill> {
+        match source {
+            FillSource::Primary => &mut self.primary,
+            FillSource::DropCopy => &mut self.drop_copy,
+        }
+    }
+}
+
+/// Reconciles the fills of the primary execution client against a drop copy session.
+pub struct DropCopyReconciler {
+    clock: Rc<RefCell<dyn Clock>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    timeout_ns: u64,
+    pending: Rc<RefCell<PendingFills>>,
+    matched_count: usize,
+    alert_count: Rc<RefCell<usize>>,
+}
+
+impl DropCopyReconciler {
+    /// Creates a new [`DropCopyReconciler`] instance.
+    ///
+    /// A fill which is not matched by the other source within `timeout_ns` raises an alert.
+    pub fn new(
+        clock: Rc<RefCell<dyn Clock>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        timeout_ns: u64,
+    ) -> Self {
+        Self {
+            clock,
+            msgbus,
+            timeout_ns,
+            pending: Rc::new(RefCell::new(PendingFills::default())),
+            matched_count: 0,
+            alert_count: Rc::new(RefCell::new(0)),
+        }
+    }
+
+    /// Returns the count of fills awaiting a match from the given `source`.
+    #[must_use]
+    pub fn pending_count(&self, source: FillSource) -> usize {
+        let pending = self.pending.borrow();
+        match source {
+            FillSource::Primary => pending.primary.len(),
+            FillSource::DropCopy => pending.drop_copy.len(),
+        }
+    }
+
+    /// Returns the count of fills matched between both sources.
+    #[must_use]
+    pub const fn matched_count(&self) -> usize {
+        self.matched_count
+    }
+
+    /// Returns the count of alerts raised.
+    #[must_use]
+    pub fn alert_count(&self) -> usize {
+        *self.alert_count.borrow()
+    }
+
+    /// Handles the given `fill` from the primary execution client.
+    pub fn on_primary_fill(&mut self, fill: &OrderFilled) {
+        self.on_fill(DropCopyFill::from(fill), FillSource::Primary);
+    }
+
+    /// Handles the given `fill` from the drop copy session.
+    pub fn on_drop_copy_fill(&mut self, fill: DropCopyFill) {
+        self.on_fill(fill, FillSource::DropCopy);
+    }
+
+    fn on_fill(&mut self, fill: DropCopyFill, source: FillSource) {
+        let key = (fill.instrument_id, fill.trade_id);
+        let other_source = match source {
+            FillSource::Primary => FillSource::DropCopy,
+            FillSource::DropCopy => FillSource::Primary,
+        };
+
+        let other = self
+            .pending
+            .borrow_mut()
+            .source_mut(other_source)
+            .remove(&key);
+        if let Some(other) = other {
+            self.clock
+                .borrow_mut()
+                .cancel_timer(&timer_name(other_source, &key));
+            self.matched_count += 1;
+
+            let (primary, drop_copy) = match source {
+                FillSource::Primary => (fill, other),
+                FillSource::DropCopy => (other, fill),
+            };
+            let ts_now = self.clock.borrow().timestamp_ns();
+            for discrepancy in compare_fills(&primary, &drop_copy) {
+                self.alert(key, discrepancy, ts_now);
+            }
+            return;
+        }
+
+        let previous = self
+            .pending
+            .borrow_mut()
+            .source_mut(source)
+            .insert(key, fill);
+        if previous.is_some() {
+            log::warn!(
+                "Duplicate {source} fill for {} trade {}",
+                fill.instrument_id,
+                fill.trade_id,
+            );
+            return; // Timeout already pending
+        }
+
+        let pending = self.pending.clone();
+        let msgbus = self.msgbus.clone();
+        let alert_count = self.alert_count.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            if pending
+                .borrow_mut()
+                .source_mut(source)
+                .remove(&key)
+                .is_some()
+            {
+                let discrepancy = DropCopyDiscrepancy::Unmatched(source);
+                publish_alert(&msgbus, &alert_count, key, discrepancy, event.ts_event);
+            }
+        }));
+
+        let mut clock = self.clock.borrow_mut();
+        let alert_time_ns = clock.timestamp_ns() + self.timeout_ns;
+        clock.set_time_alert_ns(&timer_name(source, &key), alert_time_ns, Some(callback));
+    }
+
+    fn alert(&self, key: FillKey, discrepancy: DropCopyDiscrepancy, ts_event: UnixNanos) {
+        publish_alert(&self.msgbus, &self.alert_count, key, discrepancy, ts_event);
+    }
+}
+
+fn timer_name(source: FillSource, key: &FillKey) -> String {
+    format!("DropCopy-{source}-{}-{}", key.0, key.1)
+}
+
+fn compare_fills(primary: &DropCopyFill, drop_copy: &DropCopyFill) -> Vec<DropCopyDiscrepancy> {
+    let mut discrepancies = Vec::new();
+    if primary.order_side != drop_copy.order_side {
+        discrepancies.push(DropCopyDiscrepancy::SideMismatch {
+            primary: primary.order_side,
+            drop_copy: drop_copy.order_side,
+        });
+    }
+    if primary.last_qty != drop_copy.last_qty {
+        discrepancies.push(DropCopyDiscrepancy::QuantityMismatch {
+            primary: primary.last_qty,
+            drop_copy: drop_copy.last_qty,
+        });
+    }
+    if primary.last_px != drop_copy.last_px {
+        discrepancies.push(DropCopyDiscrepancy::PriceMismatch {
+            primary: primary.last_px,
+            drop_copy: drop_copy.last_px,
+        });
+    }
+    discrepancies
+}
+
+fn publish_alert(
+    msgbus: &Rc<RefCell<MessageBus>>,
+    alert_count: &Rc<RefCell<usize>>,
+    key: FillKey,
+    discrepancy: DropCopyDiscrepancy,
+    ts_event: UnixNanos,
+) {
+    let alert = DropCopyAlert {
+        instrument_id: key.0,
+        trade_id: key.1,
+        discrepancy,
+        ts_event,
+    };
+    log::error!("{alert}");
+    *alert_count.borrow_mut() += 1;
+
+    let msgbus = msgbus.borrow();
+    msgbus.publish(&msgbus.switchboard.drop_copy_topic, &alert as &dyn Any);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::{
+            handler::ShareableMessageHandler,
+            stubs::{get_message_saving_handler, get_saved_messages},
+        },
+    };
+    use nautilus_model::events::order::stubs::order_filled;
+    use rstest::rstest;
+
+    use super::*;
+
+    const TIMEOUT_NS: u64 = 1_000;
+
+    struct TestContext {
+        clock: Rc<RefCell<TestClock>>,
+        handler: ShareableMessageHandler,
+        reconciler: DropCopyReconciler,
+    }
+
+    fn context() -> TestContext {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let handler = get_message_saving_handler::<DropCopyAlert>(None);
+        let mut msgbus = MessageBus::default();
+        let topic = msgbus.switchboard.drop_copy_topic;
+        msgbus.subscribe(topic, handler.clone(), None);
+        let reconciler =
+            DropCopyReconciler::new(clock.clone(), Rc::new(RefCell::new(msgbus)), TIMEOUT_NS);
+        TestContext {
+            clock,
+            handler,
+            reconciler,
+        }
+    }
+
+    #[rstest]
+    fn test_matching_fills_raise_no_alert(order_filled: OrderFilled) {
+        let mut ctx = context();
+
+        ctx.reconciler
+            .on_drop_copy_fill(DropCopyFill::from(&order_filled));
+        ctx.reconciler.on_primary_fill(&order_filled);
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(2 * TIMEOUT_NS));
+
+        assert_eq!(ctx.reconciler.matched_count(), 1);
+        assert_eq!(ctx.reconciler.pending_count(FillSource::Primary), 0);
+        assert_eq!(ctx.reconciler.pending_count(FillSource::DropCopy), 0);
+        assert_eq!(ctx.reconciler.alert_count(), 0);
+        assert!(get_saved_messages::<DropCopyAlert>(ctx.handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_mismatched_fills_raise_alerts(order_filled: OrderFilled) {
+        let mut ctx = context();
+        let mut drop_copy = DropCopyFill::from(&order_filled);
+        drop_copy.last_qty = Quantity::from("0.500");
+        drop_copy.last_px = Price::from("22001");
+
+        ctx.reconciler.on_primary_fill(&order_filled);
+        ctx.reconciler.on_drop_copy_fill(drop_copy);
+
+        let alerts = get_saved_messages::<DropCopyAlert>(ctx.handler);
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].trade_id, order_filled.trade_id);
+        assert_eq!(
+            alerts[0].discrepancy,
+            DropCopyDiscrepancy::QuantityMismatch {
+                primary: order_filled.last_qty,
+                drop_copy: Quantity::from("0.500"),
+            }
+        );
+        assert_eq!(
+            alerts[1].discrepancy,
+            DropCopyDiscrepancy::PriceMismatch {
+                primary: order_filled.last_px,
+                drop_copy: Price::from("22001"),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(FillSource::Primary)]
+    #[case(FillSource::DropCopy)]
+    fn test_unmatched_fill_raises_alert_after_timeout(
+        order_filled: OrderFilled,
+        #[case] source: FillSource,
+    ) {
+        let mut ctx = context();
+        match source {
+            FillSource::Primary => ctx.reconciler.on_primary_fill(&order_filled),
+            FillSource::DropCopy => ctx
+                .reconciler
+                .on_drop_copy_fill(DropCopyFill::from(&order_filled)),
+        }
+        assert_eq!(ctx.reconciler.pending_count(source), 1);
+
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(TIMEOUT_NS - 1));
+        assert!(get_saved_messages::<DropCopyAlert>(ctx.handler.clone()).is_empty());
+
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(TIMEOUT_NS));
+        let alerts = get_saved_messages::<DropCopyAlert>(ctx.handler);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(
+            alerts[0].discrepancy,
+            DropCopyDiscrepancy::Unmatched(source)
+        );
+        assert_eq!(alerts[0].ts_event, UnixNanos::from(TIMEOUT_NS));
+        assert_eq!(ctx.reconciler.pending_count(source), 0);
+        assert_eq!(ctx.reconciler.alert_count(), 1);
+    }
+}
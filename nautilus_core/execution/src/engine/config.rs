@@ -13,8 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_model::enums::NettingPolicy;
 use serde::{Deserialize, Serialize};
 
+use crate::self_trade::SelfTradePreventionMode;
+
 /// Configuration for `ExecutionEngine` instances.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionEngineConfig {
@@ -37,6 +40,34 @@ pub struct ExecutionEngineConfig {
     #[serde(default)]
     pub snapshot_positions_interval_secs: Option<f64>,
 
+    /// The policy for matching closing fills against the open lots of positions.
+    #[serde(default)]
+    pub netting_policy: NettingPolicy,
+
+    /// The path of the append-only journal of commands and events (disabled if None)
+    #[serde(default)]
+    pub journal_path: Option<String>,
+
+    /// If open orders with a GTD, AT_THE_OPEN or AT_THE_CLOSE time in force are expired by the
+    /// engine, for venues which do not natively support them.
+    #[serde(default)]
+    pub manage_order_expiry: bool,
+
+    /// The self-trade prevention mode applied to submitted orders against the open orders of
+    /// the same strategy, for venues which do not natively support it (disabled if None).
+    #[serde(default)]
+    pub self_trade_prevention: Option<SelfTradePreventionMode>,
+
+    /// The timeout (milliseconds) within which fills must be matched against a drop copy
+    /// session before raising an alert (drop copy reconciliation is disabled if None).
+    #[serde(default)]
+    pub drop_copy_timeout_ms: Option<u64>,
+
+    /// If quote quantity orders are converted to a base quantity at the order price (or else the
+    /// last price) before submission, for venues which don't support quote quantities natively.
+    #[serde(default = "default_true")]
+    pub convert_quote_qty_to_base: bool,
+
     /// If debug mode is active (will provide extra debug logging)
     #[serde(default)]
     pub debug: bool,
@@ -53,6 +84,12 @@ impl Default for ExecutionEngineConfig {
             snapshot_orders: false,
             snapshot_positions: false,
             snapshot_positions_interval_secs: None,
+            netting_policy: NettingPolicy::default(),
+            journal_path: None,
+            manage_order_expiry: false,
+            self_trade_prevention: None,
+            drop_copy_timeout_ms: None,
+            convert_quote_qty_to_base: true,
             debug: false,
         }
     }
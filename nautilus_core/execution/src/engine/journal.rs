@@ -0,0 +1,392 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An append-only journal of the commands and events flowing through the `ExecutionEngine`.
+//!
+//! Each entry is written as a line of JSON holding a sequence number, the time it was recorded
+//! and the command or event itself. Entries are flushed to the file as they are recorded, so a
+//! crash loses at most the entry being written. A journal recorded by a live node can be replayed
+//! into a fresh engine with `ExecutionEngine::replay_journal` for post-mortem debugging.
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::events::order::OrderEventAny;
+use serde::{Deserialize, Serialize};
+
+use crate::messages::TradingCommand;
+
+/// Represents a command or event recorded in an [`EventJournal`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum JournalPayload {
+    Command(TradingCommand),
+    Event(OrderEventAny),
+}
+
+/// Represents a single entry of an [`EventJournal`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// The sequence number of the entry, starting from zero and without gaps.
+    pub sequence: u64,
+    /// UNIX timestamp (nanoseconds) when the entry was recorded.
+    pub ts_recorded: UnixNanos,
+    /// The recorded command or event.
+    pub payload: JournalPayload,
+}
+
+/// Provides an append-only journal of execution commands and events backed by a file.
+#[derive(Debug)]
+pub struct EventJournal {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    next_sequence: u64,
+}
+
+impl EventJournal {
+    /// Opens the journal at the given `path`, creating it if it does not exist.
+    ///
+    /// When the journal already exists, new entries continue from its last sequence number. An
+    /// incomplete final entry left by a crash during a write is truncated from the file.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the file cannot be opened.
+    /// - If an existing journal cannot be read.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let contents = if path.exists() {
+            Some(read_contents(&path)?)
+        } else {
+            None
+        };
+
+        let mut file = File::options().create(true).append(true).open(&path)?;
+        let mut next_sequence = 0;
+        if let Some(contents) = contents {
+            if file.metadata()?.len() > contents.valid_len {
+                log::warn!(
+                    "Truncating journal {} to {} bytes",
+                    path.display(),
+                    contents.valid_len,
+                );
+                file.set_len(contents.valid_len)?;
+            }
+            if contents.missing_newline {
+                file.write_all(b"\n")?;
+            }
+            next_sequence = contents.entries.len() as u64;
+        }
+
+        Ok(Self {
+            path,
+            writer: BufWriter::new(file),
+            next_sequence,
+        })
+    }
+
+    /// Returns the path of the journal file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the sequence number the next entry will be recorded with.
+    #[must_use]
+    pub const fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Records the `command`, returning its sequence number.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the entry cannot be written.
+    pub fn record_command(
+        &mut self,
+        command: &TradingCommand,
+        ts_recorded: UnixNanos,
+    ) -> anyhow::Result<u64> {
+        self.record(JournalPayload::Command(command.clone()), ts_recorded)
+    }
+
+    /// Records the `event`, returning its sequence number.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the entry cannot be written.
+    pub fn record_event(
+        &mut self,
+        event: &OrderEventAny,
+        ts_recorded: UnixNanos,
+    ) -> anyhow::Result<u64> {
+        self.record(JournalPayload::Event(event.clone()), ts_recorded)
+    }
+
+    fn record(&mut self, payload: JournalPayload, ts_recorded: UnixNanos) -> anyhow::Result<u64> {
+        let entry = JournalEntry {
+            sequence: self.next_sequence,
+            ts_recorded,
+            payload,
+        };
+        serde_json::to_writer(&mut self.writer, &entry)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        self.next_sequence += 1;
+        Ok(entry.sequence)
+    }
+}
+
+/// Reads all entries of the journal at the given `path`, in sequence order.
+///
+/// An incomplete final entry left by a crash during a write is ignored with a warning.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the file cannot be read.
+/// - If an entry before the final line cannot be deserialized.
+/// - If the sequence numbers are not contiguous from zero.
+pub fn read_journal(path: impl AsRef<Path>) -> anyhow::Result<Vec<JournalEntry>> {
+    Ok(read_contents(path.as_ref())?.entries)
+}
+
+struct JournalContents {
+    entries: Vec<JournalEntry>,
+    // The length in bytes of the valid entries, excluding any incomplete final entry
+    valid_len: u64,
+    // Whether the last valid entry is missing its line terminator
+    missing_newline: bool,
+}
+
+fn read_contents(path: &Path) -> anyhow::Result<JournalContents> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut contents = JournalContents {
+        entries: Vec::new(),
+        valid_len: 0,
+        missing_newline: false,
+    };
+    let mut line = Vec::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        let read = reader.read_until(b'\n', &mut line)?;
+        if read == 0 {
+            break;
+        }
+        line_number += 1;
+        if line.iter().all(u8::is_ascii_whitespace) {
+            contents.valid_len += read as u64;
+            continue;
+        }
+
+        let entry: JournalEntry = match serde_json::from_slice(&line) {
+            Ok(entry) => entry,
+            Err(e) if reader.fill_buf()?.is_empty() => {
+                log::warn!(
+                    "Ignoring incomplete final entry at line {line_number} of journal {}: {e}",
+                    path.display(),
+                );
+                break;
+            }
+            Err(e) => anyhow::bail!("Invalid journal entry at line {line_number}: {e}"),
+        };
+        let expected = contents.entries.len() as u64;
+        if entry.sequence != expected {
+            anyhow::bail!(
+                "Invalid journal sequence {} at line {line_number}, expected {expected}",
+                entry.sequence,
+            );
+        }
+        contents.entries.push(entry);
+        contents.valid_len += read as u64;
+        contents.missing_newline = line.last() != Some(&b'\n');
+    }
+
+    Ok(contents)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        identifiers::{AccountId, VenueOrderId},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn journal_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn stub_events() -> Vec<OrderEventAny> {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id("AUD/USD.SIM".into())
+            .quantity(Quantity::from(100_000))
+            .build();
+        let account_id = AccountId::from("SIM-001");
+        vec![
+            TestOrderEventStubs::order_submitted(&order, account_id),
+            TestOrderEventStubs::order_accepted(&order, account_id, VenueOrderId::from("V-1")),
+        ]
+    }
+
+    #[rstest]
+    fn test_record_and_read_journal() {
+        let path = journal_path("test_record_and_read_journal");
+        let events = stub_events();
+        let mut journal = EventJournal::open(&path).unwrap();
+
+        assert_eq!(journal.record_event(&events[0], 1.into()).unwrap(), 0);
+        assert_eq!(journal.record_event(&events[1], 2.into()).unwrap(), 1);
+
+        // Entries are readable while the journal is still open
+        let entries = read_journal(&path).unwrap();
+        drop(journal);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].sequence, 1);
+        assert_eq!(entries[1].ts_recorded, UnixNanos::from(2));
+        assert!(matches!(&entries[1].payload, JournalPayload::Event(e) if *e == events[1]));
+    }
+
+    #[rstest]
+    fn test_reopen_continues_sequence() {
+        let path = journal_path("test_reopen_continues_sequence");
+        let events = stub_events();
+        {
+            let mut journal = EventJournal::open(&path).unwrap();
+            journal.record_event(&events[0], 1.into()).unwrap();
+        }
+
+        let mut journal = EventJournal::open(&path).unwrap();
+        assert_eq!(journal.next_sequence(), 1);
+        assert_eq!(journal.record_event(&events[1], 2.into()).unwrap(), 1);
+        drop(journal);
+
+        let entries = read_journal(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[rstest]
+    fn test_read_journal_with_sequence_gap_errors() {
+        let path = journal_path("test_read_journal_with_sequence_gap_errors");
+        let events = stub_events();
+        {
+            let mut journal = EventJournal::open(&path).unwrap();
+            journal.record_event(&events[0], 1.into()).unwrap();
+            journal.next_sequence += 1;
+            journal.record_event(&events[1], 2.into()).unwrap();
+        }
+
+        let result = read_journal(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_open_truncates_incomplete_final_entry() {
+        let path = journal_path("test_open_truncates_incomplete_final_entry");
+        let events = stub_events();
+        {
+            let mut journal = EventJournal::open(&path).unwrap();
+            journal.record_event(&events[0], 1.into()).unwrap();
+        }
+        let complete_len = std::fs::metadata(&path).unwrap().len();
+        let mut file = File::options().append(true).open(&path).unwrap();
+        file.write_all(br#"{"sequence":1,"ts_recorded":2,"pay"#)
+            .unwrap();
+        drop(file);
+
+        assert_eq!(read_journal(&path).unwrap().len(), 1);
+
+        let mut journal = EventJournal::open(&path).unwrap();
+        assert_eq!(journal.next_sequence(), 1);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), complete_len);
+        journal.record_event(&events[1], 2.into()).unwrap();
+        drop(journal);
+
+        let entries = read_journal(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].sequence, 1);
+    }
+
+    #[rstest]
+    fn test_open_terminates_final_entry_missing_newline() {
+        let path = journal_path("test_open_terminates_final_entry_missing_newline");
+        let events = stub_events();
+        {
+            let mut journal = EventJournal::open(&path).unwrap();
+            journal.record_event(&events[0], 1.into()).unwrap();
+        }
+        let len = std::fs::metadata(&path).unwrap().len();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(len - 1)
+            .unwrap();
+
+        let mut journal = EventJournal::open(&path).unwrap();
+        journal.record_event(&events[1], 2.into()).unwrap();
+        drop(journal);
+
+        let entries = read_journal(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[rstest]
+    fn test_read_journal_with_corrupt_entry_before_final_line_errors() {
+        let path = journal_path("test_read_journal_with_corrupt_entry_before_final_line_errors");
+        let events = stub_events();
+        {
+            let mut journal = EventJournal::open(&path).unwrap();
+            journal.record_event(&events[0], 1.into()).unwrap();
+            journal.record_event(&events[1], 2.into()).unwrap();
+        }
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let (_, last_line) = contents.split_once('\n').unwrap();
+        std::fs::write(&path, format!("{{corrupt}}\n{last_line}")).unwrap();
+
+        let read_result = read_journal(&path);
+        let open_result = EventJournal::open(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(read_result.is_err());
+        assert!(open_result.is_err());
+    }
+}
@@ -20,6 +20,7 @@
 #![allow(unused_variables)]
 
 pub mod config;
+pub mod journal;
 
 #[cfg(test)]
 mod tests;
@@ -27,17 +28,25 @@ mod tests;
 use std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
+    path::Path,
     rc::Rc,
+    time::Duration,
 };
 
 use config::ExecutionEngineConfig;
+use journal::{read_journal, EventJournal, JournalPayload};
 use nautilus_common::{
     cache::Cache, clock::Clock, generators::position_id::PositionIdGenerator, msgbus::MessageBus,
+    session::SessionCalendar, telemetry::telemetry,
 };
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
     enums::{OmsType, OrderSide},
-    events::order::{filled::OrderFilled, OrderEvent, OrderEventAny},
-    identifiers::{ClientId, InstrumentId, PositionId, StrategyId, Venue},
+    events::order::{filled::OrderFilled, OrderDenied, OrderEvent, OrderEventAny},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, Venue,
+    },
     instruments::any::InstrumentAny,
     orders::any::OrderAny,
     position::Position,
@@ -46,11 +55,16 @@ use nautilus_model::{
 
 use crate::{
     client::ExecutionClient,
+    conditional::{ConditionalOrder, ConditionalOrderManager, DataTrigger},
+    drop_copy::{DropCopyFill, DropCopyReconciler},
+    expiry::OrderExpiryManager,
     messages::{
         cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
-        modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
+        modify::ModifyOrder, modify_batch::BatchModifyOrders, query::QueryOrder,
+        submit::SubmitOrder, submit_list::SubmitOrderList, BatchDispatch, BatchSemantics,
         TradingCommand,
     },
+    self_trade::{resolve_self_trade, SelfTradeResolution},
 };
 
 pub struct ExecutionEngine {
@@ -60,37 +74,80 @@ pub struct ExecutionEngine {
     clients: HashMap<ClientId, ExecutionClient>,
     default_client: Option<ExecutionClient>,
     routing_map: HashMap<Venue, ClientId>,
+    account_routing: HashMap<AccountId, ClientId>,
+    strategy_accounts: HashMap<StrategyId, AccountId>,
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
-    pos_id_generator: PositionIdGenerator,
+    pos_id_generator: RefCell<PositionIdGenerator>,
+    journal: Option<RefCell<EventJournal>>,
+    expiry_manager: RefCell<OrderExpiryManager>,
+    conditional_orders: RefCell<ConditionalOrderManager>,
+    drop_copy: Option<RefCell<DropCopyReconciler>>,
+    submit_times: RefCell<HashMap<ClientOrderId, UnixNanos>>,
     config: ExecutionEngineConfig,
 }
 
 impl ExecutionEngine {
+    /// Creates a new [`ExecutionEngine`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the configured journal cannot be opened.
     pub fn new(
         clock: Rc<RefCell<dyn Clock>>,
         cache: Rc<RefCell<Cache>>,
         msgbus: Rc<RefCell<MessageBus>>,
         config: ExecutionEngineConfig,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
         let trader_id = msgbus.borrow().trader_id;
-        Self {
+        let journal = match &config.journal_path {
+            Some(path) => {
+                Some(RefCell::new(EventJournal::open(path).map_err(|e| {
+                    anyhow::anyhow!("Failed to open journal at {path}: {e}")
+                })?))
+            }
+            None => None,
+        };
+        let expiry_manager = OrderExpiryManager::new(clock.clone(), msgbus.clone());
+        let drop_copy = config.drop_copy_timeout_ms.map(|timeout_ms| {
+            RefCell::new(DropCopyReconciler::new(
+                clock.clone(),
+                msgbus.clone(),
+                timeout_ms * 1_000_000,
+            ))
+        });
+        Ok(Self {
             clock: clock.clone(),
             cache,
             msgbus,
             clients: HashMap::new(),
             default_client: None,
             routing_map: HashMap::new(),
+            account_routing: HashMap::new(),
+            strategy_accounts: HashMap::new(),
             oms_overrides: HashMap::new(),
             external_order_claims: HashMap::new(),
-            pos_id_generator: PositionIdGenerator::new(trader_id, clock),
+            pos_id_generator: RefCell::new(PositionIdGenerator::new(trader_id, clock)),
+            journal,
+            expiry_manager: RefCell::new(expiry_manager),
+            conditional_orders: RefCell::new(ConditionalOrderManager::new()),
+            drop_copy,
+            submit_times: RefCell::new(HashMap::new()),
             config,
-        }
+        })
     }
 
     #[must_use]
     pub fn position_id_count(&self, strategy_id: StrategyId) -> usize {
-        self.pos_id_generator.count(strategy_id)
+        self.pos_id_generator.borrow().count(strategy_id)
+    }
+
+    /// Sets the session calendar used to expire `AT_THE_OPEN` and `AT_THE_CLOSE` orders on the
+    /// given `venue`.
+    pub fn set_session_calendar(&self, venue: Venue, calendar: SessionCalendar) {
+        self.expiry_manager
+            .borrow_mut()
+            .set_session_calendar(venue, calendar);
     }
 
     #[must_use]
@@ -127,6 +184,8 @@ impl ExecutionEngine {
 
         // If client has venue, register routing
         self.routing_map.insert(client.venue, client.client_id);
+        self.account_routing
+            .insert(client.account_id, client.client_id);
 
         log::info!("Registered client {}", client.client_id);
         self.clients.insert(client.client_id, client);
@@ -152,6 +211,32 @@ impl ExecutionEngine {
         Ok(())
     }
 
+    /// Routes the orders of the given `strategy_id` to the client for the given `account_id`,
+    /// for venues with multiple accounts (sub-accounts).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no client is registered for the account.
+    pub fn register_account_routing(
+        &mut self,
+        strategy_id: StrategyId,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        if !self.account_routing.contains_key(&account_id) {
+            anyhow::bail!("No client registered for account {account_id}");
+        }
+
+        self.strategy_accounts.insert(strategy_id, account_id);
+        log::info!("Set account {account_id} routing for {strategy_id}");
+        Ok(())
+    }
+
+    /// Returns the account the orders of the given `strategy_id` are routed to (if set).
+    #[must_use]
+    pub fn strategy_account(&self, strategy_id: &StrategyId) -> Option<AccountId> {
+        self.strategy_accounts.get(strategy_id).copied()
+    }
+
     // TODO: Implement `Strategy`
     // pub fn register_external_order_claims(&mut self, strategy: Strategy) -> anyhow::Result<()> {
     //     todo!();
@@ -162,6 +247,10 @@ impl ExecutionEngine {
             // Remove from routing map if present
             self.routing_map
                 .retain(|_, mapped_id| mapped_id != &client_id);
+            self.account_routing
+                .retain(|_, mapped_id| mapped_id != &client_id);
+            self.strategy_accounts
+                .retain(|_, account_id| self.account_routing.contains_key(account_id));
             log::info!("Deregistered client {client_id}");
             Ok(())
         } else {
@@ -179,25 +268,212 @@ impl ExecutionEngine {
         todo!();
     }
 
+    pub fn execute(&self, command: TradingCommand) {
+        if let Some(journal) = &self.journal {
+            let ts_recorded = self.clock.borrow().timestamp_ns();
+            if let Err(e) = journal.borrow_mut().record_command(&command, ts_recorded) {
+                log::error!("Error recording command to journal: {e}");
+            }
+        }
+
+        self.execute_command(command);
+    }
+
+    /// Holds the order for the given submit `command` until `trigger` is hit by incoming data,
+    /// at which point the command is executed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order cannot be held as a conditional order.
+    pub fn submit_conditional_order(
+        &self,
+        trigger: DataTrigger,
+        command: SubmitOrder,
+    ) -> anyhow::Result<()> {
+        log::info!(
+            "Holding conditional order {} until {trigger}",
+            command.order.client_order_id()
+        );
+        self.conditional_orders.borrow_mut().add(trigger, command)
+    }
+
+    /// Cancels the pending conditional order with the given `client_order_id` (if found).
+    pub fn cancel_conditional_order(&self, client_order_id: &ClientOrderId) -> Option<SubmitOrder> {
+        self.conditional_orders
+            .borrow_mut()
+            .cancel(client_order_id)
+            .map(|conditional| conditional.command)
+    }
+
+    #[must_use]
+    pub fn conditional_order_count(&self) -> usize {
+        self.conditional_orders.borrow().pending_count()
+    }
+
+    /// Evaluates conditional order bid and ask price triggers against the `quote`.
+    pub fn process_quote(&self, quote: &QuoteTick) {
+        let triggered = self.conditional_orders.borrow_mut().handle_quote(quote);
+        self.activate_conditional_orders(triggered);
+    }
+
+    /// Evaluates conditional order last price triggers against the `trade`.
+    pub fn process_trade(&self, trade: &TradeTick) {
+        let triggered = self.conditional_orders.borrow_mut().handle_trade(trade);
+        self.activate_conditional_orders(triggered);
+    }
+
+    /// Evaluates conditional order mark price triggers against the mark `price`.
+    pub fn process_mark_price(&self, instrument_id: InstrumentId, price: Price) {
+        let triggered = self
+            .conditional_orders
+            .borrow_mut()
+            .handle_mark_price(instrument_id, price);
+        self.activate_conditional_orders(triggered);
+    }
+
+    /// Evaluates conditional order index price triggers against the index `price`.
+    pub fn process_index_price(&self, instrument_id: InstrumentId, price: Price) {
+        let triggered = self
+            .conditional_orders
+            .borrow_mut()
+            .handle_index_price(instrument_id, price);
+        self.activate_conditional_orders(triggered);
+    }
+
+    /// Returns the self-trade resolution for the given `order` against the open orders of its
+    /// strategy, if self-trade prevention is enabled and the order would trade against them.
+    #[must_use]
+    pub fn check_self_trade(&self, order: &OrderAny) -> Option<SelfTradeResolution> {
+        let mode = self.config.self_trade_prevention?;
+        let cache = self.cache.borrow();
+        let open_orders = cache.orders_open(
+            None,
+            Some(&order.instrument_id()),
+            Some(&order.strategy_id()),
+            None,
+        );
+        resolve_self_trade(mode, order, &open_orders)
+    }
+
+    fn activate_conditional_orders(&self, triggered: Vec<ConditionalOrder>) {
+        for conditional in triggered {
+            log::info!(
+                "Activating conditional order {} on {}",
+                conditional.command.order.client_order_id(),
+                conditional.trigger,
+            );
+            self.execute(TradingCommand::SubmitOrder(conditional.command));
+        }
+    }
+
     pub fn process(&self, event: &OrderEventAny) {
-        todo!()
+        if let Some(journal) = &self.journal {
+            let ts_recorded = self.clock.borrow().timestamp_ns();
+            if let Err(e) = journal.borrow_mut().record_event(event, ts_recorded) {
+                log::error!("Error recording event to journal: {e}");
+            }
+        }
+
+        self.handle_event(event.clone());
+    }
+
+    /// Processes the given `fill` received from a drop copy session, cross-checking it against
+    /// the fills from the primary execution clients.
+    pub fn process_drop_copy(&self, fill: DropCopyFill) {
+        match &self.drop_copy {
+            Some(drop_copy) => drop_copy.borrow_mut().on_drop_copy_fill(fill),
+            None => {
+                log::warn!("Cannot process drop copy fill: drop copy reconciliation not enabled")
+            }
+        }
+    }
+
+    /// Replays the journal at the given `path` into the engine, returning the number of
+    /// entries replayed.
+    ///
+    /// Only the engine state is rebuilt: orders are cached from the replayed submit commands
+    /// and events are applied to them, without any command being sent to a client. Replayed
+    /// commands and events are not recorded to the engine's own journal.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the journal cannot be read.
+    pub fn replay_journal(&self, path: impl AsRef<Path>) -> anyhow::Result<usize> {
+        let entries = read_journal(path)?;
+        let count = entries.len();
+
+        for entry in entries {
+            if self.config.debug {
+                log::debug!("Replaying journal entry {}", entry.sequence);
+            }
+            match entry.payload {
+                JournalPayload::Command(TradingCommand::SubmitOrder(command)) => {
+                    self.cache_submitted_order(
+                        &command.order,
+                        command.position_id,
+                        command.client_id,
+                    );
+                }
+                JournalPayload::Command(TradingCommand::SubmitOrderList(command)) => {
+                    for order in &command.order_list.orders {
+                        self.cache_submitted_order(order, command.position_id, command.client_id);
+                    }
+                }
+                // Other commands only change state through their resulting events
+                JournalPayload::Command(_) => {}
+                JournalPayload::Event(event) => self.handle_event(event),
+            }
+        }
+
+        Ok(count)
     }
 
     // -- COMMAND HANDLERS ----------------------------------------------------
 
-    fn execute_command(&self, command: TradingCommand) {
-        log::debug!("<--[CMD] {command:?}"); // TODO: Log constants
+    /// Returns whether a client is registered which the `command` can be routed to.
+    #[must_use]
+    pub fn has_client_for(&self, command: &TradingCommand) -> bool {
+        self.get_client(command).is_some()
+    }
 
-        let client = self
-            .clients
+    fn get_client(&self, command: &TradingCommand) -> Option<&ExecutionClient> {
+        self.clients
             .get(&command.client_id())
+            .or_else(|| {
+                self.command_account_id(command)
+                    .and_then(|account_id| self.account_routing.get(&account_id))
+                    .and_then(|client_id| self.clients.get(client_id))
+            })
             .or_else(|| {
                 self.routing_map
                     .get(&command.instrument_id().venue)
                     .and_then(|client_id| self.clients.get(client_id))
             })
             .or(self.default_client.as_ref())
-            .expect("No client found");
+    }
+
+    // Returns the account of the order for the command, or else the account of the strategy
+    fn command_account_id(&self, command: &TradingCommand) -> Option<AccountId> {
+        let client_order_id = match command {
+            TradingCommand::ModifyOrder(cmd) => Some(cmd.client_order_id),
+            TradingCommand::CancelOrder(cmd) => Some(cmd.client_order_id),
+            TradingCommand::QueryOrder(cmd) => Some(cmd.client_order_id),
+            _ => None,
+        };
+        client_order_id
+            .and_then(|client_order_id| {
+                self.cache
+                    .borrow()
+                    .order(&client_order_id)
+                    .and_then(OrderAny::account_id)
+            })
+            .or_else(|| self.strategy_account(&command.strategy_id()))
+    }
+
+    fn execute_command(&self, command: TradingCommand) {
+        log::debug!("<--[CMD] {command:?}"); // TODO: Log constants
+
+        let client = self.get_client(&command).expect("No client found");
 
         match command {
             TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(client, cmd),
@@ -206,105 +482,407 @@ impl ExecutionEngine {
             TradingCommand::CancelOrder(cmd) => self.handle_cancel_order(client, cmd),
             TradingCommand::CancelAllOrders(cmd) => self.handle_cancel_all_orders(client, cmd),
             TradingCommand::BatchCancelOrders(cmd) => self.handle_batch_cancel_orders(client, cmd),
+            TradingCommand::BatchModifyOrders(cmd) => self.handle_batch_modify_orders(client, cmd),
             TradingCommand::QueryOrder(cmd) => self.handle_query_order(client, cmd),
         }
     }
 
+    fn cache_submitted_order(
+        &self,
+        order: &OrderAny,
+        position_id: Option<PositionId>,
+        client_id: ClientId,
+    ) {
+        if self.cache.borrow().order_exists(&order.client_order_id()) {
+            return;
+        }
+
+        self.cache
+            .borrow_mut()
+            .add_order(order.clone(), position_id, Some(client_id), true)
+            .unwrap();
+
+        if self.config.snapshot_orders {
+            self.create_order_state_snapshot(order);
+        }
+    }
+
     fn handle_submit_order(&self, client: &ExecutionClient, command: SubmitOrder) {
         let order = &command.order;
 
-        if !self.cache.borrow().order_exists(&order.client_order_id()) {
-            // Cache order
-            self.cache
-                .borrow_mut()
-                .add_order(
-                    order.clone(),
-                    command.position_id,
-                    Some(command.client_id),
-                    true,
-                )
-                .unwrap();
+        self.cache_submitted_order(order, command.position_id, command.client_id);
 
-            if self.config.snapshot_orders {
-                self.create_order_state_snapshot(order);
-            }
+        if let Some(reason) = unsupported_order_reason(client, order) {
+            self.deny_order(order, &reason);
+            return;
         }
 
-        let instrument =
-            if let Some(instrument) = self.cache.borrow().instrument(&order.instrument_id()) {
-                instrument
-            } else {
-                log::error!(
-                    "Cannot handle submit order: no instrument found for {}, {}",
-                    order.instrument_id(),
-                    &command
+        // Check for self-trades against open orders of the same strategy
+        if let Some(resolution) = self.check_self_trade(order) {
+            self.apply_self_trade_resolution(client, &command, &resolution);
+            if resolution.cancel_incoming {
+                self.deny_order(
+                    order,
+                    &format!(
+                        "SELF_TRADE_PREVENTED: would trade against {:?}",
+                        resolution.resting_order_ids()
+                    ),
                 );
                 return;
-            };
+            }
+        }
+
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id())
+            .cloned();
+        let Some(instrument) = instrument else {
+            log::error!(
+                "Cannot handle submit order: no instrument found for {}, {}",
+                order.instrument_id(),
+                &command
+            );
+            return;
+        };
+
+        let mut command = command;
+        if let Err(reason) = self.convert_quote_qty(client, &instrument, &mut command.order) {
+            self.deny_order(&command.order, &reason);
+            return;
+        }
+
+        // Send to execution client
+        let client_order_id = command.order.client_order_id();
+        match client.submit_order(command) {
+            Ok(()) => self.record_submit_time(client_order_id),
+            Err(e) => log::error!("Error submitting order {client_order_id}: {e}"),
+        }
+    }
+
+    // Handles quote quantity conversion, unless the venue supports quote quantities natively,
+    // returning the reason to deny the order if there is no price to convert at
+    fn convert_quote_qty(
+        &self,
+        client: &ExecutionClient,
+        instrument: &InstrumentAny,
+        order: &mut OrderAny,
+    ) -> Result<(), String> {
+        if !order.is_quote_quantity()
+            || client.native_quote_quantity
+            || !self.config.convert_quote_qty_to_base
+            || instrument.is_inverse()
+        {
+            return Ok(());
+        }
+
+        let last_px = order
+            .price()
+            .or_else(|| order.trigger_price())
+            .or_else(|| self.last_px_for_conversion(&order.instrument_id(), order.order_side()));
+        let Some(last_px) = last_px else {
+            return Err(format!(
+                "no-price-to-convert-quote-qty {}",
+                order.instrument_id()
+            ));
+        };
+
+        let base_qty = instrument.get_base_quantity(order.quantity(), last_px);
+        self.set_order_base_qty(order, base_qty);
+        Ok(())
+    }
 
-        // Handle quote quantity conversion
-        // TODO: implemnent is_quote_quantity
-        // if !instrument.is_inverse() && order.is_quote_quantity() {
-        //     let last_px = self.last_px_for_conversion(&order.instrument_id(), order.order_side());
+    fn apply_self_trade_resolution(
+        &self,
+        client: &ExecutionClient,
+        command: &SubmitOrder,
+        resolution: &SelfTradeResolution,
+    ) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let resting_orders: Vec<OrderAny> = {
+            let cache = self.cache.borrow();
+            resolution
+                .resting_order_ids()
+                .iter()
+                .filter_map(|client_order_id| cache.order(client_order_id).cloned())
+                .collect()
+        };
 
-        //     if last_px.is_none() {
-        //         self.deny_order(
-        //             &order,
-        //             &format!("no-price-to-convert-quote-qty {}", order.instrument_id()),
-        //         );
-        //         return;
-        //     }
+        for order in resting_orders {
+            let client_order_id = order.client_order_id();
+            let Some(venue_order_id) = order.venue_order_id() else {
+                log::warn!("Cannot prevent self-trade with {client_order_id}: no venue order ID");
+                continue;
+            };
 
-        //     // TODO: convert f64 to Price
-        //     let base_qty = instrument.get_base_quantity(order.quantity(), last_px.unwrap().into());
-        //     self.set_order_base_qty(&order, base_qty);
-        // }
+            let result = match resolution
+                .decremented
+                .iter()
+                .find(|(id, _)| *id == client_order_id)
+            {
+                Some((_, quantity)) => ModifyOrder::new(
+                    order.trader_id(),
+                    command.client_id,
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    venue_order_id,
+                    Some(*quantity),
+                    None,
+                    None,
+                    UUID4::new(),
+                    ts_now,
+                )
+                .and_then(|modify| client.modify_order(modify)),
+                None => CancelOrder::new(
+                    order.trader_id(),
+                    command.client_id,
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    venue_order_id,
+                    UUID4::new(),
+                    ts_now,
+                )
+                .and_then(|cancel| client.cancel_order(cancel)),
+            };
 
-        // // Send to execution client
-        // client.submit_order(command);
+            if let Err(e) = result {
+                log::error!("Error preventing self-trade with {client_order_id}: {e}");
+            }
+        }
     }
 
     pub fn handle_submit_order_list(&self, client: &ExecutionClient, command: SubmitOrderList) {
         for order in &command.order_list.orders {
-            if !self.cache.borrow().order_exists(&order.client_order_id()) {
-                self.cache
-                    .borrow_mut()
-                    .add_order(
-                        order.clone(),
-                        command.position_id,
-                        Some(command.client_id),
-                        true,
-                    )
-                    .unwrap();
+            self.cache_submitted_order(order, command.position_id, command.client_id);
+        }
 
-                if self.config.snapshot_orders {
-                    self.create_order_state_snapshot(order);
+        // The list is denied as a whole if any order is not supported by the client
+        if let Some(reason) = command
+            .order_list
+            .orders
+            .iter()
+            .find_map(|order| unsupported_order_reason(client, order))
+        {
+            for order in &command.order_list.orders {
+                self.deny_order(order, &reason);
+            }
+            return;
+        }
+
+        match command
+            .semantics
+            .dispatch(client.native_batch.submit_order_list)
+        {
+            Ok(BatchDispatch::Native) => {
+                let mut command = command;
+                for i in 0..command.order_list.orders.len() {
+                    let instrument_id = command.order_list.orders[i].instrument_id();
+                    let instrument = self.cache.borrow().instrument(&instrument_id).cloned();
+                    let Some(instrument) = instrument else {
+                        log::error!(
+                            "Cannot handle submit order list: no instrument found for {instrument_id}, {}",
+                            &command
+                        );
+                        return;
+                    };
+
+                    // The list is denied as a whole if any order cannot be converted
+                    let order = &mut command.order_list.orders[i];
+                    if let Err(reason) = self.convert_quote_qty(client, &instrument, order) {
+                        for order in &command.order_list.orders {
+                            self.deny_order(order, &reason);
+                        }
+                        return;
+                    }
+                }
+
+                let client_order_ids: Vec<ClientOrderId> = command
+                    .order_list
+                    .orders
+                    .iter()
+                    .map(OrderAny::client_order_id)
+                    .collect();
+
+                // Send to execution client
+                let order_list_id = command.order_list.id;
+                match client.submit_order_list(command) {
+                    Ok(()) => {
+                        for client_order_id in client_order_ids {
+                            self.record_submit_time(client_order_id);
+                        }
+                    }
+                    Err(e) => log::error!("Error submitting order list {order_list_id}: {e}"),
+                }
+            }
+            Ok(BatchDispatch::Individual) => {
+                for order in command.order_list.orders {
+                    match SubmitOrder::new(
+                        command.trader_id,
+                        command.client_id,
+                        command.strategy_id,
+                        order.instrument_id(),
+                        order.client_order_id(),
+                        command.venue_order_id,
+                        order,
+                        command.exec_algorith_id,
+                        command.position_id,
+                        UUID4::new(),
+                        command.ts_init,
+                    ) {
+                        Ok(submit) => self.handle_submit_order(client, submit),
+                        Err(e) => log::error!("Error submitting order from list: {e}"),
+                    }
+                }
+            }
+            Err(e) => {
+                for order in &command.order_list.orders {
+                    self.deny_order(order, &e.to_string());
                 }
             }
         }
+    }
 
-        // Send to execution client
-        client.submit_order_list(command).unwrap();
+    // Records when the order was sent to the venue, to observe its round trip once acknowledged
+    fn record_submit_time(&self, client_order_id: ClientOrderId) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.submit_times
+            .borrow_mut()
+            .insert(client_order_id, ts_now);
+    }
+
+    fn observe_round_trip(&self, event: &OrderEventAny) {
+        if !matches!(
+            event,
+            OrderEventAny::Accepted(_) | OrderEventAny::Rejected(_)
+        ) {
+            return;
+        }
+        let ts_submitted = self
+            .submit_times
+            .borrow_mut()
+            .remove(&event.client_order_id());
+        if let Some(ts_submitted) = ts_submitted {
+            let ts_now = self.clock.borrow().timestamp_ns();
+            let latency_ns = ts_now.as_u64().saturating_sub(ts_submitted.as_u64());
+            telemetry().observe_order_round_trip(Duration::from_nanos(latency_ns));
+        }
     }
 
     fn handle_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
-        todo!();
+        let client_order_id = command.client_order_id;
+        if let Err(e) = client.modify_order(command) {
+            log::error!("Error modifying order {client_order_id}: {e}");
+        }
     }
 
     fn handle_cancel_order(&self, client: &ExecutionClient, command: CancelOrder) {
-        todo!();
+        let client_order_id = command.client_order_id;
+        if let Err(e) = client.cancel_order(command) {
+            log::error!("Error canceling order {client_order_id}: {e}");
+        }
     }
 
-    pub const fn handle_cancel_all_orders(
-        &self,
-        client: &ExecutionClient,
-        command: CancelAllOrders,
-    ) {
-        // TODO
-        // client.cancel_all_orders(command);
+    pub fn handle_cancel_all_orders(&self, client: &ExecutionClient, command: CancelAllOrders) {
+        if client.native_batch.cancel_all_orders {
+            if let Err(e) = client.cancel_all_orders(command) {
+                log::error!("Error canceling all orders: {e}");
+            }
+            return;
+        }
+
+        let batch = self.cancel_all_as_batch(&command);
+        if batch.cancels.is_empty() {
+            log::debug!("No open orders to cancel for {command}");
+            return;
+        }
+        self.handle_batch_cancel_orders(client, batch);
     }
+
     fn handle_batch_cancel_orders(&self, client: &ExecutionClient, command: BatchCancelOrders) {
-        todo!();
+        match command
+            .semantics
+            .dispatch(client.native_batch.batch_cancel_orders)
+        {
+            Ok(BatchDispatch::Native) => {
+                if let Err(e) = client.batch_cancel_orders(command) {
+                    log::error!("Error batch canceling orders: {e}");
+                }
+            }
+            Ok(BatchDispatch::Individual) => {
+                for cancel in command.cancels {
+                    self.handle_cancel_order(client, cancel);
+                }
+            }
+            Err(e) => log::error!("Cannot execute {command}: {e}"),
+        }
+    }
+
+    fn handle_batch_modify_orders(&self, client: &ExecutionClient, command: BatchModifyOrders) {
+        match command
+            .semantics
+            .dispatch(client.native_batch.batch_modify_orders)
+        {
+            Ok(BatchDispatch::Native) => {
+                if let Err(e) = client.batch_modify_orders(command) {
+                    log::error!("Error batch modifying orders: {e}");
+                }
+            }
+            Ok(BatchDispatch::Individual) => {
+                for modify in command.modifies {
+                    self.handle_modify_order(client, modify);
+                }
+            }
+            Err(e) => log::error!("Cannot execute {command}: {e}"),
+        }
+    }
+
+    // Returns a best-effort batch cancel of the open orders matching the cancel all `command`
+    fn cancel_all_as_batch(&self, command: &CancelAllOrders) -> BatchCancelOrders {
+        let side = match command.order_side {
+            OrderSide::NoOrderSide => None,
+            side => Some(side),
+        };
+        let cache = self.cache.borrow();
+        let cancels = cache
+            .orders_open(
+                None,
+                Some(&command.instrument_id),
+                Some(&command.strategy_id),
+                side,
+            )
+            .into_iter()
+            .filter_map(|order| {
+                let client_order_id = order.client_order_id();
+                let Some(venue_order_id) = order.venue_order_id() else {
+                    log::warn!("Cannot cancel {client_order_id}: no venue order ID");
+                    return None;
+                };
+                CancelOrder::new(
+                    command.trader_id,
+                    command.client_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    UUID4::new(),
+                    command.ts_init,
+                )
+                .ok()
+            })
+            .collect();
+
+        BatchCancelOrders {
+            trader_id: command.trader_id,
+            client_id: command.client_id,
+            strategy_id: command.strategy_id,
+            instrument_id: command.instrument_id,
+            cancels,
+            semantics: BatchSemantics::BestEffort,
+            command_id: UUID4::new(),
+            ts_init: command.ts_init,
+        }
     }
 
     fn handle_query_order(&self, client: &ExecutionClient, command: QueryOrder) {
@@ -324,7 +902,58 @@ impl ExecutionEngine {
     // -- EVENT HANDLERS ----------------------------------------------------
 
     fn handle_event(&self, event: OrderEventAny) {
-        todo!()
+        if self.config.debug {
+            log::debug!("<--[EVT] {event:?}"); // TODO: Log constants
+        }
+
+        let order = self.cache.borrow().order(&event.client_order_id()).cloned();
+        let Some(mut order) = order else {
+            log::error!(
+                "Cannot apply event to any order: {} not found in the cache",
+                event.client_order_id()
+            );
+            return;
+        };
+
+        self.observe_round_trip(&event);
+
+        // Carry the order metadata onto fills, for attribution of executions downstream
+        let mut event = event;
+        let mut fill_oms_type = None;
+        if let OrderEventAny::Filled(fill) = &mut event {
+            if fill.metadata.is_empty() {
+                fill.metadata = order.metadata();
+            }
+            let oms_type = self.determine_oms_type(fill);
+            fill.position_id = Some(self.determine_position_id(*fill, oms_type));
+            fill_oms_type = Some(oms_type);
+            if let Some(drop_copy) = &self.drop_copy {
+                drop_copy.borrow_mut().on_primary_fill(fill);
+            }
+        }
+
+        let is_working_update = matches!(
+            event,
+            OrderEventAny::Accepted(_) | OrderEventAny::Updated(_)
+        );
+
+        let fill = match &event {
+            OrderEventAny::Filled(fill) => Some(*fill),
+            _ => None,
+        };
+        self.apply_event_to_order(&mut order, event);
+        if let (Some(fill), Some(oms_type)) = (fill, fill_oms_type) {
+            self.handle_order_fill(&order, fill, oms_type);
+        }
+
+        if self.config.manage_order_expiry {
+            let mut expiry_manager = self.expiry_manager.borrow_mut();
+            if order.is_closed() {
+                expiry_manager.untrack(&order.client_order_id());
+            } else if is_working_update {
+                expiry_manager.track(&order);
+            }
+        }
     }
 
     fn determine_oms_type(&self, fill: &OrderFilled) -> OmsType {
@@ -333,8 +962,12 @@ impl ExecutionEngine {
             return *oms_type;
         }
 
-        // Use native venue OMS
-        if let Some(client_id) = self.routing_map.get(&fill.instrument_id.venue) {
+        // Use native venue OMS, of the client for the account if multiple on the venue
+        if let Some(client_id) = self
+            .account_routing
+            .get(&fill.account_id)
+            .or_else(|| self.routing_map.get(&fill.instrument_id.venue))
+        {
             if let Some(client) = self.clients.get(client_id) {
                 return client.oms_type;
             }
@@ -347,7 +980,7 @@ impl ExecutionEngine {
         OmsType::Netting // Default fallback
     }
 
-    fn determine_position_id(&mut self, fill: OrderFilled, oms_type: OmsType) -> PositionId {
+    fn determine_position_id(&self, fill: OrderFilled, oms_type: OmsType) -> PositionId {
         match oms_type {
             OmsType::Hedging => self.determine_hedging_position_id(fill),
             OmsType::Netting => self.determine_netting_position_id(fill),
@@ -355,7 +988,7 @@ impl ExecutionEngine {
         }
     }
 
-    fn determine_hedging_position_id(&mut self, fill: OrderFilled) -> PositionId {
+    fn determine_hedging_position_id(&self, fill: OrderFilled) -> PositionId {
         // Check if position ID already exists
         if let Some(position_id) = fill.position_id {
             if self.config.debug {
@@ -391,7 +1024,10 @@ impl ExecutionEngine {
         }
 
         // Generate new position ID
-        let position_id = self.pos_id_generator.generate(fill.strategy_id, false);
+        let position_id = self
+            .pos_id_generator
+            .borrow_mut()
+            .generate(fill.strategy_id, false);
         if self.config.debug {
             log::debug!("Generated {} for {}", position_id, fill.client_order_id());
         }
@@ -414,7 +1050,29 @@ impl ExecutionEngine {
     }
 
     fn handle_order_fill(&self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
-        todo!();
+        let instrument = self.cache.borrow().instrument(&fill.instrument_id).cloned();
+        let Some(instrument) = instrument else {
+            log::error!(
+                "Cannot handle order fill: no instrument found for {}, {fill}",
+                fill.instrument_id,
+            );
+            return;
+        };
+        let Some(position_id) = fill.position_id else {
+            log::error!("Cannot handle order fill: no position ID for {fill}");
+            return;
+        };
+
+        // A closed netting position is reopened by the fill, keeping its netting policy
+        let position = self.cache.borrow().position(&position_id).cloned();
+        match position {
+            Some(mut position) => self.update_position(instrument, &mut position, fill, oms_type),
+            None => {
+                if let Err(e) = self.open_position(instrument, position_id, fill, oms_type) {
+                    log::error!("Error opening position {position_id}: {e}");
+                }
+            }
+        }
     }
 
     fn open_position(
@@ -424,7 +1082,8 @@ impl ExecutionEngine {
         fill: OrderFilled,
         oms_type: OmsType,
     ) -> anyhow::Result<()> {
-        let position = Position::new(&instrument, fill);
+        let mut position = Position::new(&instrument, fill);
+        position.set_netting_policy(self.config.netting_policy);
         self.cache.borrow_mut().add_position(position, oms_type)
     }
 
@@ -436,6 +1095,9 @@ impl ExecutionEngine {
         oms_type: OmsType,
     ) {
         position.apply(&fill);
+        if let Err(e) = self.cache.borrow_mut().update_position(position) {
+            log::error!("Error updating position {}: {e}", position.id);
+        }
     }
 
     fn will_flip_position(&self, position: &Position, fill: OrderFilled) {
@@ -490,14 +1152,16 @@ impl ExecutionEngine {
         }
     }
 
-    fn set_order_base_qty(&self, order: &OrderAny, quantity: Quantity) {
-        // Implementation depends on your order type system
-        // This is a placeholder for the actual implementation
-        log::debug!(
-            "Setting base quantity {} for order {}",
-            quantity,
-            order.client_order_id()
+    fn set_order_base_qty(&self, order: &mut OrderAny, quantity: Quantity) {
+        log::info!(
+            "Setting {} order quote quantity {} to base quantity {quantity}",
+            order.client_order_id(),
+            order.quantity(),
         );
+        order.set_base_quantity(quantity);
+        if let Err(e) = self.cache.borrow_mut().update_order(order) {
+            log::error!("Error updating order {}: {e}", order.client_order_id());
+        }
     }
 
     fn deny_order(&self, order: &OrderAny, reason: &str) {
@@ -505,6 +1169,26 @@ impl ExecutionEngine {
             "Order denied: {reason}, order ID: {}",
             order.client_order_id()
         );
-        todo!()
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let denied = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            reason.into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        );
+        self.handle_event(OrderEventAny::Denied(denied));
     }
 }
+
+// Returns the reason to deny the `order` if it is not supported by the `client`
+fn unsupported_order_reason(client: &ExecutionClient, order: &OrderAny) -> Option<String> {
+    client
+        .capabilities
+        .check_order(order)
+        .err()
+        .map(|e| format!("UNSUPPORTED_BY_CLIENT: {e} by {}", client.client_id))
+}
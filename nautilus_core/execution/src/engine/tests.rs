@@ -14,3 +14,945 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Tests module for `ExecutionEngine`.
+
+use std::{cell::RefCell, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    capabilities::ClientCapabilities,
+    clock::{Clock, TestClock},
+    msgbus::{
+        stubs::{get_message_saving_handler, get_saved_messages},
+        MessageBus,
+    },
+    telemetry::telemetry,
+};
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_static, uuid::UUID4};
+use nautilus_model::{
+    enums::{AccountType, NettingPolicy, OmsType, OrderSide, OrderStatus, OrderType, TimeInForce},
+    events::order::OrderEventAny,
+    identifiers::{AccountId, ClientId, OrderListId, TradeId, Venue, VenueOrderId},
+    instruments::{
+        any::InstrumentAny,
+        stubs::{audusd_sim, currency_pair_btcusdt},
+    },
+    orders::{
+        any::OrderAny, builder::OrderTestBuilder, list::OrderList, stubs::TestOrderEventStubs,
+    },
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
+};
+use rstest::rstest;
+
+use super::{config::ExecutionEngineConfig, journal::EventJournal, ExecutionEngine};
+use crate::{
+    client::{ExecutionClient, ExecutionHandler},
+    conditional::{DataTrigger, DataTriggerType, TriggerCondition},
+    drop_copy::{DropCopyFill, FillSource},
+    messages::{
+        cancel_all::CancelAllOrders, submit::SubmitOrder, submit_list::SubmitOrderList,
+        BatchSemantics, TradingCommand,
+    },
+    self_trade::SelfTradePreventionMode,
+};
+
+fn engine_with_order(order: &OrderAny, config: ExecutionEngineConfig) -> ExecutionEngine {
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    ExecutionEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+        Rc::new(RefCell::new(MessageBus::default())),
+        config,
+    )
+    .unwrap()
+}
+
+#[rstest]
+fn test_replay_journal_into_fresh_engine() {
+    let path = std::env::temp_dir().join(format!("exec-journal-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id("AUD/USD.SIM".into())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let account_id = AccountId::from("SIM-001");
+    let config = ExecutionEngineConfig {
+        journal_path: Some(path.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+
+    let engine = engine_with_order(&order, config);
+    engine.process(&TestOrderEventStubs::order_submitted(&order, account_id));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &order,
+        account_id,
+        VenueOrderId::from("V-1"),
+    ));
+
+    let replayed = engine_with_order(&order, ExecutionEngineConfig::default());
+    let count = replayed.replay_journal(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let cache = replayed.cache.borrow();
+    let replayed_order = cache.order(&order.client_order_id()).unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(replayed_order.status(), OrderStatus::Accepted);
+    assert_eq!(
+        replayed_order.status(),
+        engine
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .unwrap()
+            .status()
+    );
+}
+
+#[rstest]
+fn test_replay_journal_rebuilds_state_without_dispatching_commands() {
+    let path = std::env::temp_dir().join(format!(
+        "exec-journal-commands-{}.jsonl",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id("AUD/USD.SIM".into())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let account_id = AccountId::from("SIM-001");
+    let command = SubmitOrder::new(
+        order.trader_id(),
+        ClientId::from("SIM"),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        VenueOrderId::from("NONE"),
+        order.clone(),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+
+    let mut journal = EventJournal::open(&path).unwrap();
+    journal
+        .record_command(&TradingCommand::SubmitOrder(command), UnixNanos::default())
+        .unwrap();
+    journal
+        .record_event(
+            &TestOrderEventStubs::order_submitted(&order, account_id),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+    // No client is registered, so dispatching the command would panic
+    let engine = ExecutionEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+        Rc::new(RefCell::new(MessageBus::default())),
+        ExecutionEngineConfig::default(),
+    )
+    .unwrap();
+    let count = engine.replay_journal(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let cache = engine.cache.borrow();
+    assert_eq!(count, 2);
+    assert_eq!(
+        cache.order(&order.client_order_id()).unwrap().status(),
+        OrderStatus::Submitted
+    );
+    assert_eq!(
+        cache.client_id(&order.client_order_id()),
+        Some(&ClientId::from("SIM"))
+    );
+}
+
+#[rstest]
+fn test_new_with_unopenable_journal_returns_error() {
+    let config = ExecutionEngineConfig {
+        journal_path: Some(
+            std::env::temp_dir()
+                .join("missing-dir")
+                .join("journal.jsonl")
+                .to_string_lossy()
+                .to_string(),
+        ),
+        ..Default::default()
+    };
+
+    let result = ExecutionEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+        Rc::new(RefCell::new(MessageBus::default())),
+        config,
+    );
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_new_recovers_journal_with_incomplete_final_entry() {
+    let path = std::env::temp_dir().join(format!("exec-journal-torn-{}.jsonl", std::process::id()));
+    std::fs::write(&path, br#"{"sequence":0,"ts_recorded":1,"pay"#).unwrap();
+    let config = ExecutionEngineConfig {
+        journal_path: Some(path.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+
+    let result = ExecutionEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+        Rc::new(RefCell::new(MessageBus::default())),
+        config,
+    );
+    let len = std::fs::metadata(&path).unwrap().len();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(result.is_ok());
+    assert_eq!(len, 0);
+}
+
+#[rstest]
+fn test_fill_carries_order_metadata() {
+    let metadata: OrderMetadata = "signal=momentum".parse().unwrap();
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .quantity(Quantity::from(100_000))
+        .metadata(metadata)
+        .build();
+    let account_id = AccountId::from("SIM-001");
+
+    let engine = engine_with_order(&order, ExecutionEngineConfig::default());
+    engine.process(&TestOrderEventStubs::order_submitted(&order, account_id));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &order,
+        account_id,
+        VenueOrderId::from("001"),
+    ));
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &instrument,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(account_id),
+    );
+    engine.process(&fill);
+
+    let cache = engine.cache.borrow();
+    let cached_order = cache.order(&order.client_order_id()).unwrap();
+    let OrderEventAny::Filled(cached_fill) = cached_order.last_event() else {
+        panic!("expected fill");
+    };
+    assert_eq!(cached_order.status(), OrderStatus::Filled);
+    assert_eq!(cached_fill.metadata, metadata);
+}
+
+#[rstest]
+fn test_managed_gtd_order_expires() {
+    let order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id("AUD/USD.SIM".into())
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .time_in_force(TimeInForce::Gtd)
+        .expire_time(UnixNanos::from(1_000))
+        .build();
+    let account_id = AccountId::from("SIM-001");
+    let config = ExecutionEngineConfig {
+        manage_order_expiry: true,
+        ..Default::default()
+    };
+
+    let clock = Rc::new(RefCell::new(TestClock::new()));
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    let handler = get_message_saving_handler::<OrderEventAny>(None);
+    let mut msgbus = MessageBus::default();
+    msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+    let engine =
+        ExecutionEngine::new(clock.clone(), cache, Rc::new(RefCell::new(msgbus)), config).unwrap();
+    engine.process(&TestOrderEventStubs::order_submitted(&order, account_id));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &order,
+        account_id,
+        VenueOrderId::from("001"),
+    ));
+    assert_eq!(clock.borrow().timer_count(), 1);
+
+    // Feed the expiry back into the engine as the exec engine endpoint would
+    let handlers = {
+        let mut clock = clock.borrow_mut();
+        let events = clock.advance_time(UnixNanos::from(1_000), true);
+        clock.match_handlers(events)
+    };
+    assert_eq!(handlers.len(), 1);
+    for time_handler in handlers {
+        time_handler.run();
+    }
+    for event in get_saved_messages::<OrderEventAny>(handler) {
+        engine.process(&event);
+    }
+
+    let cache = engine.cache.borrow();
+    assert_eq!(
+        cache.order(&order.client_order_id()).unwrap().status(),
+        OrderStatus::Expired
+    );
+    assert_eq!(engine.expiry_manager.borrow().tracked_count(), 0);
+}
+
+#[rstest]
+fn test_conditional_order_held_until_canceled() {
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id("AUD/USD.SIM".into())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let engine = engine_with_order(&order, ExecutionEngineConfig::default());
+    let command = SubmitOrder::new(
+        order.trader_id(),
+        ClientId::from("SIM"),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        VenueOrderId::from("001"),
+        order.clone(),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    let trigger = DataTrigger::new(
+        order.instrument_id(),
+        DataTriggerType::MarkPrice,
+        TriggerCondition::AtOrAbove,
+        Price::from("0.80000"),
+    );
+
+    engine
+        .submit_conditional_order(trigger, command.clone())
+        .unwrap();
+    engine.process_mark_price(order.instrument_id(), Price::from("0.79999"));
+    assert_eq!(engine.conditional_order_count(), 1);
+
+    assert_eq!(
+        engine.cancel_conditional_order(&order.client_order_id()),
+        Some(command)
+    );
+    assert_eq!(engine.conditional_order_count(), 0);
+}
+
+#[rstest]
+#[case(None, false)]
+#[case(Some(SelfTradePreventionMode::CancelNewest), true)]
+fn test_check_self_trade_against_open_order(
+    #[case] mode: Option<SelfTradePreventionMode>,
+    #[case] expected_self_trade: bool,
+) {
+    let resting = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id("AUD/USD.SIM".into())
+        .side(OrderSide::Sell)
+        .price(Price::from("0.80000"))
+        .quantity(Quantity::from(100_000))
+        .build();
+    let account_id = AccountId::from("SIM-001");
+    let config = ExecutionEngineConfig {
+        self_trade_prevention: mode,
+        ..Default::default()
+    };
+    let engine = engine_with_order(&resting, config);
+    engine.process(&TestOrderEventStubs::order_submitted(&resting, account_id));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &resting,
+        account_id,
+        VenueOrderId::from("V-1"),
+    ));
+    let incoming = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id("AUD/USD.SIM".into())
+        .client_order_id("O-2".into())
+        .side(OrderSide::Buy)
+        .price(Price::from("0.80010"))
+        .quantity(Quantity::from(50_000))
+        .build();
+
+    let resolution = engine.check_self_trade(&incoming);
+
+    assert_eq!(resolution.is_some(), expected_self_trade);
+    if let Some(resolution) = resolution {
+        assert!(resolution.cancel_incoming);
+        assert!(resolution.canceled.is_empty());
+    }
+}
+
+#[rstest]
+fn test_drop_copy_fill_matched_against_primary_fill() {
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let account_id = AccountId::from("SIM-001");
+    let config = ExecutionEngineConfig {
+        drop_copy_timeout_ms: Some(1_000),
+        ..Default::default()
+    };
+
+    let engine = engine_with_order(&order, config);
+    engine.process(&TestOrderEventStubs::order_submitted(&order, account_id));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &order,
+        account_id,
+        VenueOrderId::from("001"),
+    ));
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &instrument,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(account_id),
+    );
+    engine.process(&fill);
+    let OrderEventAny::Filled(fill) = fill else {
+        panic!("expected fill");
+    };
+    engine.process_drop_copy(DropCopyFill::from(&fill));
+
+    let drop_copy = engine.drop_copy.as_ref().unwrap().borrow();
+    assert_eq!(drop_copy.matched_count(), 1);
+    assert_eq!(drop_copy.pending_count(FillSource::Primary), 0);
+    assert_eq!(drop_copy.alert_count(), 0);
+}
+
+#[rstest]
+fn test_orders_routed_by_strategy_account() {
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id("AUD/USD.SIM".into())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let mut engine = engine_with_order(&order, ExecutionEngineConfig::default());
+    for (client_id, account_id) in [("SIM-A", "SIM-001"), ("SIM-B", "SIM-002")] {
+        let client = ExecutionClient::new(
+            order.trader_id(),
+            ClientId::from(client_id),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from(account_id),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            engine.cache.clone(),
+            engine.msgbus.clone(),
+        );
+        engine.register_client(client).unwrap();
+    }
+    let command = TradingCommand::SubmitOrder(
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("001"),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap(),
+    );
+
+    assert!(engine
+        .register_account_routing(order.strategy_id(), AccountId::from("SIM-003"))
+        .is_err());
+    engine
+        .register_account_routing(order.strategy_id(), AccountId::from("SIM-001"))
+        .unwrap();
+
+    let client = engine.get_client(&command).unwrap();
+    assert_eq!(client.client_id, ClientId::from("SIM-A"));
+    assert_eq!(
+        engine.strategy_account(&order.strategy_id()),
+        Some(AccountId::from("SIM-001"))
+    );
+}
+
+#[rstest]
+fn test_cancel_all_orders_as_batch_filters_side() {
+    let orders: Vec<OrderAny> = [OrderSide::Buy, OrderSide::Sell, OrderSide::Buy]
+        .into_iter()
+        .enumerate()
+        .map(|(i, side)| {
+            OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id("AUD/USD.SIM".into())
+                .client_order_id(format!("O-{i}").as_str().into())
+                .side(side)
+                .price(Price::from("1.00000"))
+                .quantity(Quantity::from(100_000))
+                .build()
+        })
+        .collect();
+    let engine = engine_with_order(&orders[0], ExecutionEngineConfig::default());
+    let account_id = AccountId::from("SIM-001");
+    for (i, order) in orders.iter().enumerate() {
+        if i > 0 {
+            engine
+                .cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+                .unwrap();
+        }
+        engine.process(&TestOrderEventStubs::order_submitted(order, account_id));
+        engine.process(&TestOrderEventStubs::order_accepted(
+            order,
+            account_id,
+            VenueOrderId::from(format!("V-{i}").as_str()),
+        ));
+    }
+
+    let command = CancelAllOrders {
+        instrument_id: orders[0].instrument_id(),
+        strategy_id: orders[0].strategy_id(),
+        order_side: OrderSide::Buy,
+        ..Default::default()
+    };
+    let batch = engine.cancel_all_as_batch(&command);
+
+    let mut canceled: Vec<_> = batch
+        .cancels
+        .iter()
+        .map(|cancel| (cancel.client_order_id, cancel.venue_order_id))
+        .collect();
+    canceled.sort();
+    assert_eq!(batch.semantics, BatchSemantics::BestEffort);
+    assert_eq!(
+        canceled,
+        vec![
+            (orders[0].client_order_id(), VenueOrderId::from("V-0")),
+            (orders[2].client_order_id(), VenueOrderId::from("V-2")),
+        ]
+    );
+
+    let all_sides = CancelAllOrders {
+        order_side: OrderSide::NoOrderSide,
+        ..command
+    };
+    assert_eq!(engine.cancel_all_as_batch(&all_sides).cancels.len(), 3);
+}
+
+#[rstest]
+fn test_atomic_order_list_denied_without_native_batch() {
+    let orders: Vec<OrderAny> = (0..2)
+        .map(|i| {
+            OrderTestBuilder::new(OrderType::Market)
+                .instrument_id("AUD/USD.SIM".into())
+                .client_order_id(format!("O-{i}").as_str().into())
+                .quantity(Quantity::from(100_000))
+                .build()
+        })
+        .collect();
+    let engine = engine_with_order(&orders[0], ExecutionEngineConfig::default());
+    let client = ExecutionClient::new(
+        orders[0].trader_id(),
+        ClientId::from("SIM"),
+        Venue::from("SIM"),
+        OmsType::Netting,
+        AccountId::from("SIM-001"),
+        AccountType::Margin,
+        None,
+        get_atomic_clock_static(),
+        engine.cache.clone(),
+        engine.msgbus.clone(),
+    );
+    let command = SubmitOrderList::new(
+        orders[0].trader_id(),
+        ClientId::from("SIM"),
+        orders[0].strategy_id(),
+        orders[0].instrument_id(),
+        orders[0].client_order_id(),
+        VenueOrderId::from("001"),
+        OrderList::new(
+            OrderListId::from("OL-001"),
+            orders[0].instrument_id(),
+            orders[0].strategy_id(),
+            orders.clone(),
+            UnixNanos::default(),
+        ),
+        None,
+        None,
+        BatchSemantics::Atomic,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+
+    engine.handle_submit_order_list(&client, command);
+
+    let cache = engine.cache.borrow();
+    for order in &orders {
+        let status = cache.order(&order.client_order_id()).unwrap().status();
+        assert_eq!(status, OrderStatus::Denied);
+    }
+}
+
+#[rstest]
+fn test_orders_unsupported_by_client_denied() {
+    let supported = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id("AUD/USD.SIM".into())
+        .client_order_id("O-1".into())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let unsupported = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id("AUD/USD.SIM".into())
+        .client_order_id("O-2".into())
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .time_in_force(TimeInForce::Fok)
+        .build();
+    let engine = engine_with_order(&supported, ExecutionEngineConfig::default());
+    let mut client = ExecutionClient::new(
+        supported.trader_id(),
+        ClientId::from("SIM"),
+        Venue::from("SIM"),
+        OmsType::Netting,
+        AccountId::from("SIM-001"),
+        AccountType::Margin,
+        None,
+        get_atomic_clock_static(),
+        engine.cache.clone(),
+        engine.msgbus.clone(),
+    );
+    client.capabilities = ClientCapabilities::unrestricted()
+        .with_order_types([OrderType::Market, OrderType::Limit])
+        .with_time_in_force([TimeInForce::Gtc, TimeInForce::Ioc]);
+
+    for order in [&supported, &unsupported] {
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            client.client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("001"),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine.handle_submit_order(&client, command);
+    }
+
+    let cache = engine.cache.borrow();
+    let supported = cache.order(&supported.client_order_id()).unwrap();
+    assert_eq!(supported.status(), OrderStatus::Initialized);
+    let unsupported = cache.order(&unsupported.client_order_id()).unwrap();
+    assert_eq!(unsupported.status(), OrderStatus::Denied);
+    let OrderEventAny::Denied(denied) = unsupported.last_event() else {
+        panic!("expected denied event");
+    };
+    assert_eq!(
+        denied.reason.as_str(),
+        "UNSUPPORTED_BY_CLIENT: time in force FOK not supported, supported: [GTC, IOC] by SIM"
+    );
+}
+
+#[rstest]
+fn test_quote_quantity_orders_converted_to_base_quantity() {
+    let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+    let limit = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument.id())
+        .client_order_id("O-1".into())
+        .price(Price::from("50000.00"))
+        .quantity(Quantity::from(500))
+        .quote_quantity(true)
+        .build();
+    let market = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .client_order_id("O-2".into())
+        .quantity(Quantity::from(500))
+        .quote_quantity(true)
+        .build();
+    let engine = engine_with_order(&limit, ExecutionEngineConfig::default());
+    engine
+        .cache
+        .borrow_mut()
+        .add_instrument(instrument.clone())
+        .unwrap();
+    let client = ExecutionClient::new(
+        limit.trader_id(),
+        ClientId::from("BINANCE"),
+        Venue::from("BINANCE"),
+        OmsType::Netting,
+        AccountId::from("BINANCE-001"),
+        AccountType::Cash,
+        None,
+        get_atomic_clock_static(),
+        engine.cache.clone(),
+        engine.msgbus.clone(),
+    );
+
+    for order in [&limit, &market] {
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            client.client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("001"),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine.handle_submit_order(&client, command);
+    }
+
+    let cache = engine.cache.borrow();
+    let converted = cache.order(&limit.client_order_id()).unwrap();
+    assert_eq!(converted.quantity(), Quantity::from("0.010000"));
+    assert!(!converted.is_quote_quantity());
+
+    // No price to convert the market order at
+    let denied = cache.order(&market.client_order_id()).unwrap();
+    assert_eq!(denied.status(), OrderStatus::Denied);
+    assert!(denied.is_quote_quantity());
+}
+
+#[rstest]
+fn test_native_order_list_converts_quote_quantity_and_handles_client_error() {
+    let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+    let orders: Vec<OrderAny> = ["50000.00", "40000.00"]
+        .iter()
+        .enumerate()
+        .map(|(i, price)| {
+            OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument.id())
+                .client_order_id(format!("O-{i}").as_str().into())
+                .price(Price::from(*price))
+                .quantity(Quantity::from(500))
+                .quote_quantity(true)
+                .build()
+        })
+        .collect();
+    let engine = engine_with_order(&orders[0], ExecutionEngineConfig::default());
+    engine
+        .cache
+        .borrow_mut()
+        .add_instrument(instrument.clone())
+        .unwrap();
+    let mut client = ExecutionClient::new(
+        orders[0].trader_id(),
+        ClientId::from("BINANCE"),
+        Venue::from("BINANCE"),
+        OmsType::Netting,
+        AccountId::from("BINANCE-001"),
+        AccountType::Cash,
+        None,
+        get_atomic_clock_static(),
+        engine.cache.clone(),
+        engine.msgbus.clone(),
+    );
+    client.native_batch.submit_order_list = true;
+    let command = SubmitOrderList::new(
+        orders[0].trader_id(),
+        client.client_id,
+        orders[0].strategy_id(),
+        orders[0].instrument_id(),
+        orders[0].client_order_id(),
+        VenueOrderId::from("001"),
+        OrderList::new(
+            OrderListId::from("OL-001"),
+            orders[0].instrument_id(),
+            orders[0].strategy_id(),
+            orders.clone(),
+            UnixNanos::default(),
+        ),
+        None,
+        None,
+        BatchSemantics::Atomic,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+
+    // The client has no handler, so the submit errors without panicking
+    engine.handle_submit_order_list(&client, command);
+
+    let cache = engine.cache.borrow();
+    let quantities: Vec<Quantity> = orders
+        .iter()
+        .map(|order| cache.order(&order.client_order_id()).unwrap().quantity())
+        .collect();
+    assert_eq!(
+        quantities,
+        vec![Quantity::from("0.010000"), Quantity::from("0.012500")]
+    );
+    assert!(orders.iter().all(|order| !cache
+        .order(&order.client_order_id())
+        .unwrap()
+        .is_quote_quantity()));
+}
+
+#[rstest]
+fn test_fills_open_and_update_position_with_netting_policy() {
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    cache
+        .borrow_mut()
+        .add_instrument(instrument.clone())
+        .unwrap();
+    let config = ExecutionEngineConfig {
+        netting_policy: NettingPolicy::Lifo,
+        ..Default::default()
+    };
+    let engine = ExecutionEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        cache.clone(),
+        Rc::new(RefCell::new(MessageBus::default())),
+        config,
+    )
+    .unwrap();
+
+    let account_id = AccountId::from("SIM-001");
+    let fills = [
+        (OrderSide::Buy, 100_000, "1.00000"),
+        (OrderSide::Buy, 100_000, "1.00020"),
+        (OrderSide::Buy, 100_000, "1.00010"),
+        (OrderSide::Sell, 150_000, "1.00030"),
+    ];
+    for (i, (side, quantity, price)) in fills.into_iter().enumerate() {
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .client_order_id(format!("O-{}", i + 1).as_str().into())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        engine.process(&TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            Some(TradeId::new((i + 1).to_string())),
+            None,
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+    }
+
+    let cache = cache.borrow();
+    let positions = cache.positions_open(None, None, None, None);
+    assert_eq!(positions.len(), 1);
+    let position = positions[0];
+    assert_eq!(position.netting_policy, NettingPolicy::Lifo);
+    assert_eq!(position.quantity, Quantity::from(150_000));
+    let trade_ids: Vec<TradeId> = position
+        .closed_lots
+        .iter()
+        .map(|lot| lot.opening_trade_id)
+        .collect();
+    assert_eq!(trade_ids, vec![TradeId::new("3"), TradeId::new("2")]);
+}
+
+struct AcceptingHandler;
+
+impl ExecutionHandler for AcceptingHandler {
+    fn execute(&mut self, _command: TradingCommand) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+fn order_round_trip_count() -> u64 {
+    telemetry()
+        .encode()
+        .unwrap()
+        .lines()
+        .find_map(|line| line.strip_prefix("nautilus_order_round_trip_seconds_count "))
+        .map_or(0, |count| count.parse().unwrap())
+}
+
+#[rstest]
+fn test_order_round_trip_observed_on_acknowledgement() {
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id("AUD/USD.SIM".into())
+        .quantity(Quantity::from(100_000))
+        .build();
+    let engine = engine_with_order(&order, ExecutionEngineConfig::default());
+    engine
+        .cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+        .unwrap();
+    let mut client = ExecutionClient::new(
+        order.trader_id(),
+        ClientId::from("SIM"),
+        Venue::from("SIM"),
+        OmsType::Netting,
+        AccountId::from("SIM-001"),
+        AccountType::Margin,
+        None,
+        get_atomic_clock_static(),
+        engine.cache.clone(),
+        engine.msgbus.clone(),
+    );
+    client.set_handler(Rc::new(RefCell::new(AcceptingHandler)));
+    let command = SubmitOrder::new(
+        order.trader_id(),
+        client.client_id,
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        VenueOrderId::default(),
+        order.clone(),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    let count = order_round_trip_count();
+
+    engine.handle_submit_order(&client, command);
+    engine.process(&TestOrderEventStubs::order_submitted(
+        &order,
+        AccountId::from("SIM-001"),
+    ));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &order,
+        AccountId::from("SIM-001"),
+        VenueOrderId::from("V-1"),
+    ));
+
+    assert!(order_round_trip_count() > count);
+    assert!(engine.submit_times.borrow().is_empty());
+}
@@ -0,0 +1,325 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `OrderExpiryManager` which expires open orders on behalf of venues.
+//!
+//! `GTD` orders expire at their expire time, while `AT_THE_OPEN` and `AT_THE_CLOSE` orders
+//! expire at the next session open or close following their initialization, as given by the
+//! session calendar for the order's venue. A time alert is set on the clock for each tracked
+//! order, which sends an `OrderExpired` event to the execution engine when triggered.
+
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{
+    clock::Clock,
+    msgbus::MessageBus,
+    session::{order_expire_time, SessionCalendar},
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::TimeInForce,
+    events::order::{OrderEventAny, OrderExpired},
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, Venue, VenueOrderId,
+    },
+    orders::any::OrderAny,
+};
+
+/// Manages the expiry of open orders with a `GTD`, `AT_THE_OPEN` or `AT_THE_CLOSE` time in force.
+pub struct OrderExpiryManager {
+    clock: Rc<RefCell<dyn Clock>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    calendars: HashMap<Venue, SessionCalendar>,
+    expire_times: HashMap<ClientOrderId, UnixNanos>,
+}
+
+impl OrderExpiryManager {
+    /// Creates a new [`OrderExpiryManager`] instance.
+    pub fn new(clock: Rc<RefCell<dyn Clock>>, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            clock,
+            msgbus,
+            calendars: HashMap::new(),
+            expire_times: HashMap::new(),
+        }
+    }
+
+    /// Sets the session calendar for the given `venue`.
+    pub fn set_session_calendar(&mut self, venue: Venue, calendar: SessionCalendar) {
+        self.calendars.insert(venue, calendar);
+    }
+
+    /// Returns the session calendar for the given `venue` (if found).
+    #[must_use]
+    pub fn session_calendar(&self, venue: &Venue) -> Option<&SessionCalendar> {
+        self.calendars.get(venue)
+    }
+
+    /// Returns the expire time for the given tracked order (if found).
+    #[must_use]
+    pub fn expire_time(&self, client_order_id: &ClientOrderId) -> Option<UnixNanos> {
+        self.expire_times.get(client_order_id).copied()
+    }
+
+    /// Returns the count of tracked orders.
+    #[must_use]
+    pub fn tracked_count(&self) -> usize {
+        self.expire_times.len()
+    }
+
+    /// Starts tracking the given `order` for expiry, replacing any previous expiry for the order.
+    ///
+    /// Returns the expire time, or `None` if the order does not expire (in which case it is not
+    /// tracked). An order which has already expired is expired immediately.
+    pub fn track(&mut self, order: &OrderAny) -> Option<UnixNanos> {
+        self.untrack(&order.client_order_id());
+
+        let calendar = self.calendars.get(&order.instrument_id().venue);
+        let Some(expire_time) = order_expire_time(
+            order.time_in_force(),
+            order.expire_time(),
+            order.ts_init(),
+            calendar,
+        ) else {
+            if calendar.is_none()
+                && matches!(
+                    order.time_in_force(),
+                    TimeInForce::AtTheOpen | TimeInForce::AtTheClose
+                )
+            {
+                log::warn!(
+                    "Cannot manage expiry of {}: no session calendar for {}",
+                    order.client_order_id(),
+                    order.instrument_id().venue,
+                );
+            }
+            return None;
+        };
+
+        let target = ExpiryTarget::from(order);
+        let ts_now = self.clock.borrow().timestamp_ns();
+        if expire_time <= ts_now {
+            target.expire(&self.msgbus, ts_now);
+            return None;
+        }
+
+        let msgbus = self.msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            target.expire(&msgbus, event.ts_event);
+        }));
+        self.clock.borrow_mut().set_time_alert_ns(
+            &timer_name(&order.client_order_id()),
+            expire_time,
+            Some(callback),
+        );
+        self.expire_times
+            .insert(order.client_order_id(), expire_time);
+
+        Some(expire_time)
+    }
+
+    /// Stops tracking the order with the given `client_order_id`, canceling any pending expiry.
+    pub fn untrack(&mut self, client_order_id: &ClientOrderId) {
+        if self.expire_times.remove(client_order_id).is_some() {
+            self.clock
+                .borrow_mut()
+                .cancel_timer(&timer_name(client_order_id));
+        }
+    }
+}
+
+fn timer_name(client_order_id: &ClientOrderId) -> String {
+    format!("OrderExpiry-{client_order_id}")
+}
+
+// The identifiers required to expire an order, captured when tracking begins
+#[derive(Clone, Copy, Debug)]
+struct ExpiryTarget {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: Option<VenueOrderId>,
+    account_id: Option<AccountId>,
+}
+
+impl From<&OrderAny> for ExpiryTarget {
+    fn from(order: &OrderAny) -> Self {
+        Self {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: order.venue_order_id(),
+            account_id: order.account_id(),
+        }
+    }
+}
+
+impl ExpiryTarget {
+    fn expire(&self, msgbus: &Rc<RefCell<MessageBus>>, ts_event: UnixNanos) {
+        log::info!("Expiring order {}", self.client_order_id);
+        let event = OrderEventAny::Expired(OrderExpired::new(
+            self.trader_id,
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            false,
+            self.venue_order_id,
+            self.account_id,
+        ));
+        let msgbus = msgbus.borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::{
+            handler::ShareableMessageHandler,
+            stubs::{get_message_saving_handler, get_saved_messages},
+        },
+        session::TradingSession,
+    };
+    use nautilus_model::{
+        enums::{OrderType, TimeInForce},
+        events::order::OrderEventType,
+        orders::builder::OrderTestBuilder,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+
+    struct TestContext {
+        clock: Rc<RefCell<TestClock>>,
+        handler: ShareableMessageHandler,
+        manager: OrderExpiryManager,
+    }
+
+    fn context() -> TestContext {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        let mut msgbus = MessageBus::default();
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let manager = OrderExpiryManager::new(clock.clone(), Rc::new(RefCell::new(msgbus)));
+        TestContext {
+            clock,
+            handler,
+            manager,
+        }
+    }
+
+    fn limit_order(time_in_force: TimeInForce, expire_time: Option<u64>) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(OrderType::Limit);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .time_in_force(time_in_force);
+        if let Some(expire_time) = expire_time {
+            builder.expire_time(UnixNanos::from(expire_time));
+        }
+        builder.build()
+    }
+
+    #[rstest]
+    fn test_gtd_order_expires_at_expire_time() {
+        let mut ctx = context();
+        let order = limit_order(TimeInForce::Gtd, Some(1_000));
+
+        let expire_time = ctx.manager.track(&order);
+        assert_eq!(expire_time, Some(UnixNanos::from(1_000)));
+        assert_eq!(ctx.manager.tracked_count(), 1);
+
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(999));
+        assert!(get_saved_messages::<OrderEventAny>(ctx.handler.clone()).is_empty());
+
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(1_000));
+        let messages = get_saved_messages::<OrderEventAny>(ctx.handler);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].event_type(), OrderEventType::Expired);
+        assert_eq!(messages[0].client_order_id(), order.client_order_id());
+        assert_eq!(messages[0].ts_event(), UnixNanos::from(1_000));
+    }
+
+    #[rstest]
+    fn test_untrack_cancels_expiry() {
+        let mut ctx = context();
+        let order = limit_order(TimeInForce::Gtd, Some(1_000));
+
+        ctx.manager.track(&order);
+        ctx.manager.untrack(&order.client_order_id());
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(2_000));
+
+        assert_eq!(ctx.manager.tracked_count(), 0);
+        assert!(get_saved_messages::<OrderEventAny>(ctx.handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_already_expired_order_expires_immediately() {
+        let mut ctx = context();
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(2_000));
+        let order = limit_order(TimeInForce::Gtd, Some(1_000));
+
+        assert_eq!(ctx.manager.track(&order), None);
+
+        let messages = get_saved_messages::<OrderEventAny>(ctx.handler);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].event_type(), OrderEventType::Expired);
+    }
+
+    #[rstest]
+    fn test_at_the_open_order_requires_session_calendar() {
+        let mut ctx = context();
+        let order = limit_order(TimeInForce::AtTheOpen, None);
+
+        assert_eq!(ctx.manager.track(&order), None);
+
+        // Session opens at 08:00 UTC on weekdays, 1970-01-01 was a Thursday
+        let calendar = SessionCalendar::weekdays(TradingSession::new(8 * 3600, 17 * 3600), 0);
+        ctx.manager
+            .set_session_calendar(Venue::from("SIM"), calendar);
+
+        assert_eq!(
+            ctx.manager.track(&order),
+            Some(UnixNanos::from(8 * 3600 * 1_000_000_000))
+        );
+        assert_eq!(
+            ctx.manager.expire_time(&order.client_order_id()),
+            Some(UnixNanos::from(8 * 3600 * 1_000_000_000))
+        );
+    }
+
+    #[rstest]
+    fn test_day_order_is_not_tracked() {
+        let mut ctx = context();
+        let order = limit_order(TimeInForce::Day, None);
+
+        assert_eq!(ctx.manager.track(&order), None);
+        assert_eq!(ctx.manager.tracked_count(), 0);
+    }
+}
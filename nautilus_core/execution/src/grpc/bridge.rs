@@ -0,0 +1,468 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The node side of the engine control service, applying requests on the node thread.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+    parameters::{ParameterService, UpdateParameter},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    accounts::any::AccountAny,
+    data::{
+        bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+        order::BookOrder, quote::QuoteTick, trade::TradeTick, Data, GetTsInit,
+    },
+    identifiers::{InstrumentId, StrategyId},
+    position::Position,
+};
+use tokio::sync::{
+    mpsc::{self, error::TrySendError},
+    oneshot,
+};
+use tonic::Status;
+use ustr::Ustr;
+
+use super::proto::{
+    data_message::Data as DataMessageData, AccountSnapshot, BalanceSnapshot, BarMessage,
+    BookDeltaMessage, BookDeltasMessage, BookDepthMessage, BookLevelMessage, DataMessage,
+    ParameterChangedMessage, ParameterSnapshot, ParametersState, PortfolioState, PositionSnapshot,
+    QuoteMessage, TradeMessage,
+};
+use crate::{engine::ExecutionEngine, messages::TradingCommand};
+
+/// The sender half of a data stream returned to a gRPC client.
+pub type DataStreamSender = mpsc::Sender<Result<DataMessage, Status>>;
+
+/// Represents a request from the engine control service to be applied on the node thread.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum ControlRequest {
+    /// Forwards the data published on `topic` to the stream `sender`.
+    Subscribe {
+        topic: String,
+        sender: DataStreamSender,
+    },
+    /// Passes the `command` to the execution engine.
+    Execute {
+        command: TradingCommand,
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+    /// Replies with a snapshot of the portfolio state.
+    Portfolio {
+        reply: oneshot::Sender<PortfolioState>,
+    },
+    /// Applies the parameter update `command` with the parameter service.
+    SetParameter {
+        command: UpdateParameter,
+        reply: oneshot::Sender<Result<ParameterChangedMessage, String>>,
+    },
+    /// Replies with the registered parameters of the strategy.
+    Parameters {
+        strategy_id: StrategyId,
+        reply: oneshot::Sender<Result<ParametersState, String>>,
+    },
+}
+
+/// Provides a thread-safe handle for sending requests to a [`ControlBridge`].
+#[derive(Clone, Debug)]
+pub struct ControlHandle {
+    tx: mpsc::UnboundedSender<ControlRequest>,
+}
+
+impl ControlHandle {
+    /// Sends the `request` to the bridge.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the bridge has been dropped.
+    pub fn send(&self, request: ControlRequest) -> anyhow::Result<()> {
+        self.tx
+            .send(request)
+            .map_err(|_| anyhow::anyhow!("Control bridge has been dropped"))
+    }
+}
+
+struct DataStream {
+    topic: Ustr,
+    handler: ShareableMessageHandler,
+    sender: DataStreamSender,
+}
+
+/// Applies engine control requests against the node's message bus, cache and execution engine.
+///
+/// The node components are single-threaded, so requests are queued by the service and applied
+/// when the node calls [`ControlBridge::process`] from its event loop.
+pub struct ControlBridge {
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    exec_engine: Rc<RefCell<ExecutionEngine>>,
+    parameter_service: Option<Rc<ParameterService>>,
+    rx: mpsc::UnboundedReceiver<ControlRequest>,
+    streams: Vec<DataStream>,
+}
+
+impl ControlBridge {
+    /// Creates a new [`ControlBridge`] instance, returning it with a handle for the service.
+    #[must_use]
+    pub fn new(
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        exec_engine: Rc<RefCell<ExecutionEngine>>,
+    ) -> (Self, ControlHandle) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let bridge = Self {
+            cache,
+            msgbus,
+            exec_engine,
+            parameter_service: None,
+            rx,
+            streams: Vec::new(),
+        };
+        (bridge, ControlHandle { tx })
+    }
+
+    /// Sets the parameter service used to update and query strategy parameters.
+    pub fn set_parameter_service(&mut self, parameter_service: Rc<ParameterService>) {
+        self.parameter_service = Some(parameter_service);
+    }
+
+    /// Returns the number of open data streams.
+    #[must_use]
+    pub fn stream_count(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Applies all pending requests, returning the number applied.
+    ///
+    /// Data streams closed by their clients are unsubscribed first.
+    pub fn process(&mut self) -> usize {
+        self.remove_closed_streams();
+
+        let mut count = 0;
+        while let Ok(request) = self.rx.try_recv() {
+            self.apply(request);
+            count += 1;
+        }
+        count
+    }
+
+    fn apply(&mut self, request: ControlRequest) {
+        match request {
+            ControlRequest::Subscribe { topic, sender } => self.subscribe(topic, sender),
+            ControlRequest::Execute { command, reply } => {
+                let _ = reply.send(self.execute(command));
+            }
+            ControlRequest::Portfolio { reply } => {
+                let _ = reply.send(self.portfolio_state());
+            }
+            ControlRequest::SetParameter { command, reply } => {
+                let _ = reply.send(self.set_parameter(command));
+            }
+            ControlRequest::Parameters { strategy_id, reply } => {
+                let _ = reply.send(self.parameters_state(&strategy_id));
+            }
+        }
+    }
+
+    fn subscribe(&mut self, topic: String, sender: DataStreamSender) {
+        let topic = Ustr::from(&topic);
+        let handler = ShareableMessageHandler(Rc::new(DataStreamHandler {
+            id: Ustr::from(&format!("EngineControl-{}", UUID4::new())),
+            topic,
+            sender: sender.clone(),
+        }));
+        self.msgbus
+            .borrow_mut()
+            .subscribe(topic, handler.clone(), None);
+        log::info!("Opened data stream for topic '{topic}'");
+
+        self.streams.push(DataStream {
+            topic,
+            handler,
+            sender,
+        });
+    }
+
+    fn remove_closed_streams(&mut self) {
+        let msgbus = &self.msgbus;
+        self.streams.retain(|stream| {
+            if stream.sender.is_closed() {
+                msgbus
+                    .borrow_mut()
+                    .unsubscribe(stream.topic, stream.handler.clone());
+                log::info!("Closed data stream for topic '{}'", stream.topic);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    fn execute(&self, command: TradingCommand) -> Result<(), String> {
+        let exec_engine = self.exec_engine.borrow();
+        if !exec_engine.has_client_for(&command) {
+            return Err(format!(
+                "No execution client for {} {}",
+                command.client_id(),
+                command.instrument_id()
+            ));
+        }
+        exec_engine.execute(command);
+        Ok(())
+    }
+
+    fn portfolio_state(&self) -> PortfolioState {
+        let cache = self.cache.borrow();
+        let accounts = cache
+            .account_ids()
+            .into_iter()
+            .filter_map(|account_id| cache.account(account_id))
+            .map(account_snapshot)
+            .collect();
+        let positions = cache
+            .positions_open(None, None, None, None)
+            .into_iter()
+            .map(position_snapshot)
+            .collect();
+
+        PortfolioState {
+            accounts,
+            positions,
+        }
+    }
+
+    fn parameter_service(&self) -> Result<&ParameterService, String> {
+        self.parameter_service
+            .as_deref()
+            .ok_or_else(|| "No parameter service".to_string())
+    }
+
+    fn set_parameter(&self, command: UpdateParameter) -> Result<ParameterChangedMessage, String> {
+        let event = self
+            .parameter_service()?
+            .update(command)
+            .map_err(|e| e.to_string())?;
+        Ok(ParameterChangedMessage {
+            strategy_id: event.strategy_id.to_string(),
+            name: event.name.to_string(),
+            old_value: Some(event.old_value.into()),
+            new_value: Some(event.new_value.into()),
+            ts_event: event.ts_event.as_u64(),
+        })
+    }
+
+    fn parameters_state(&self, strategy_id: &StrategyId) -> Result<ParametersState, String> {
+        let parameters = self
+            .parameter_service()?
+            .parameters(strategy_id)
+            .into_iter()
+            .map(|(definition, value)| ParameterSnapshot {
+                name: definition.name.to_string(),
+                kind: definition.kind.to_string(),
+                value: Some(value.into()),
+                min: definition.min,
+                max: definition.max,
+                choices: definition.choices,
+            })
+            .collect();
+        Ok(ParametersState { parameters })
+    }
+}
+
+fn account_snapshot(account: &AccountAny) -> AccountSnapshot {
+    let balances = account
+        .last_event()
+        .map(|state| state.balances)
+        .unwrap_or_default();
+    AccountSnapshot {
+        account_id: account.id().to_string(),
+        balances: balances
+            .into_iter()
+            .map(|balance| BalanceSnapshot {
+                currency: balance.currency.code.to_string(),
+                total: balance.total.as_decimal().to_string(),
+                locked: balance.locked.as_decimal().to_string(),
+                free: balance.free.as_decimal().to_string(),
+            })
+            .collect(),
+    }
+}
+
+fn position_snapshot(position: &Position) -> PositionSnapshot {
+    PositionSnapshot {
+        position_id: position.id.to_string(),
+        instrument_id: position.instrument_id.to_string(),
+        strategy_id: position.strategy_id.to_string(),
+        side: position.side.to_string(),
+        quantity: position.quantity.to_string(),
+        avg_px_open: position.avg_px_open,
+        realized_pnl: position.realized_pnl.map(|pnl| pnl.to_string()),
+    }
+}
+
+/// Forwards the data published on a topic to a gRPC data stream.
+struct DataStreamHandler {
+    id: Ustr,
+    topic: Ustr,
+    sender: DataStreamSender,
+}
+
+impl DataStreamHandler {
+    fn forward(&self, ts_init: UnixNanos, data: DataMessageData) {
+        let message = DataMessage {
+            topic: self.topic.to_string(),
+            ts_init: ts_init.as_u64(),
+            data: Some(data),
+        };
+
+        match self.sender.try_send(Ok(message)) {
+            Ok(()) | Err(TrySendError::Closed(_)) => {}
+            Err(TrySendError::Full(_)) => {
+                log::warn!(
+                    "Data stream for topic '{}' is full, dropping data",
+                    self.topic
+                );
+            }
+        }
+    }
+}
+
+fn quote_message(quote: &QuoteTick) -> DataMessageData {
+    DataMessageData::Quote(QuoteMessage {
+        instrument_id: quote.instrument_id.to_string(),
+        bid_price: quote.bid_price.to_string(),
+        ask_price: quote.ask_price.to_string(),
+        bid_size: quote.bid_size.to_string(),
+        ask_size: quote.ask_size.to_string(),
+        ts_event: quote.ts_event.as_u64(),
+    })
+}
+
+fn trade_message(trade: &TradeTick) -> DataMessageData {
+    DataMessageData::Trade(TradeMessage {
+        instrument_id: trade.instrument_id.to_string(),
+        price: trade.price.to_string(),
+        size: trade.size.to_string(),
+        aggressor_side: trade.aggressor_side.to_string(),
+        trade_id: trade.trade_id.to_string(),
+        ts_event: trade.ts_event.as_u64(),
+    })
+}
+
+fn bar_message(bar: &Bar) -> DataMessageData {
+    DataMessageData::Bar(BarMessage {
+        bar_type: bar.bar_type.to_string(),
+        open: bar.open.to_string(),
+        high: bar.high.to_string(),
+        low: bar.low.to_string(),
+        close: bar.close.to_string(),
+        volume: bar.volume.to_string(),
+        ts_event: bar.ts_event.as_u64(),
+    })
+}
+
+fn deltas_message(instrument_id: InstrumentId, deltas: &[OrderBookDelta]) -> DataMessageData {
+    DataMessageData::Deltas(BookDeltasMessage {
+        instrument_id: instrument_id.to_string(),
+        deltas: deltas
+            .iter()
+            .map(|delta| BookDeltaMessage {
+                action: delta.action.to_string(),
+                side: delta.order.side.to_string(),
+                price: delta.order.price.to_string(),
+                size: delta.order.size.to_string(),
+                order_id: delta.order.order_id,
+                flags: u32::from(delta.flags),
+                sequence: delta.sequence,
+                ts_event: delta.ts_event.as_u64(),
+            })
+            .collect(),
+    })
+}
+
+fn depth_message(depth: &OrderBookDepth10) -> DataMessageData {
+    let levels = |orders: &[BookOrder], counts: &[u32]| {
+        orders
+            .iter()
+            .zip(counts)
+            .filter(|(order, _)| order.size.is_positive())
+            .map(|(order, count)| BookLevelMessage {
+                price: order.price.to_string(),
+                size: order.size.to_string(),
+                count: *count,
+            })
+            .collect()
+    };
+    DataMessageData::Depth(BookDepthMessage {
+        instrument_id: depth.instrument_id.to_string(),
+        bids: levels(&depth.bids, &depth.bid_counts),
+        asks: levels(&depth.asks, &depth.ask_counts),
+        flags: u32::from(depth.flags),
+        sequence: depth.sequence,
+        ts_event: depth.ts_event.as_u64(),
+    })
+}
+
+impl MessageHandler for DataStreamHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            self.forward(quote.ts_init, quote_message(quote));
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            self.forward(trade.ts_init, trade_message(trade));
+        } else if let Some(bar) = message.downcast_ref::<Bar>() {
+            self.forward(bar.ts_init, bar_message(bar));
+        } else if let Some(delta) = message.downcast_ref::<OrderBookDelta>() {
+            let data = deltas_message(delta.instrument_id, std::slice::from_ref(delta));
+            self.forward(delta.ts_init, data);
+        } else if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            let data = deltas_message(deltas.instrument_id, &deltas.deltas);
+            self.forward(deltas.ts_init, data);
+        } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+            self.forward(depth.ts_init, depth_message(depth));
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        let ts_init = data.ts_init();
+        let data = match &data {
+            Data::Delta(delta) => deltas_message(delta.instrument_id, std::slice::from_ref(delta)),
+            Data::Deltas(deltas) => deltas_message(deltas.instrument_id, &deltas.deltas),
+            Data::Depth10(depth) => depth_message(depth),
+            Data::Quote(quote) => quote_message(quote),
+            Data::Trade(trade) => trade_message(trade),
+            Data::Bar(bar) => bar_message(bar),
+        };
+        self.forward(ts_init, data);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
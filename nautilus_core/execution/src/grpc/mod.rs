@@ -0,0 +1,28 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A gRPC service for controlling a running node over the network.
+//!
+//! External UIs and services can stream the data published on the message bus, submit and
+//! cancel orders, query the portfolio state, and tune strategy parameters. The server runs on
+//! the Nautilus runtime and queues requests to a [`bridge::ControlBridge`] owned by the node,
+//! which applies them when the node calls [`bridge::ControlBridge::process`].
+
+pub mod bridge;
+pub mod proto;
+pub mod server;
+
+#[cfg(test)]
+mod tests;
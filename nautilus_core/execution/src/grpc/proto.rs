@@ -0,0 +1,439 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Protobuf messages and generated service code for the `nautilus.control.EngineControl` service.
+//!
+//! Identifiers and enum values are encoded as their string representations, and prices,
+//! quantities and money as decimal strings. The equivalent `.proto` definition is:
+//!
+//! ```protobuf
+//! service EngineControl {
+//!   rpc SubscribeData(SubscribeDataRequest) returns (stream DataMessage);
+//!   rpc SubmitOrder(SubmitOrderRequest) returns (CommandResponse);
+//!   rpc CancelOrder(CancelOrderRequest) returns (CommandResponse);
+//!   rpc GetPortfolio(PortfolioRequest) returns (PortfolioState);
+//!   rpc SetParameter(SetParameterRequest) returns (ParameterChangedMessage);
+//!   rpc GetParameters(ParametersRequest) returns (ParametersState);
+//! }
+//!
+//! message DataMessage {
+//!   string topic = 1;
+//!   uint64 ts_init = 2;
+//!   oneof data {
+//!     QuoteMessage quote = 3;
+//!     TradeMessage trade = 4;
+//!     BarMessage bar = 5;
+//!     BookDeltasMessage deltas = 6;
+//!     BookDepthMessage depth = 7;
+//!   }
+//! }
+//!
+//! message ParameterValueMessage {
+//!   oneof value {
+//!     bool bool_value = 1;
+//!     int64 int_value = 2;
+//!     double float_value = 3;
+//!     string string_value = 4;
+//!   }
+//! }
+//! ```
+
+#![allow(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
+
+use nautilus_common::parameters::ParameterValue;
+
+/// A request to stream the data published on the message bus for a topic.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct SubscribeDataRequest {
+    /// The topic (or topic pattern with `*` and `?` wildcards), e.g. `data.quotes.BINANCE.*`.
+    #[prost(string, tag = "1")]
+    pub topic: String,
+}
+
+/// A data object published on the message bus.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct DataMessage {
+    /// The topic the data was published on.
+    #[prost(string, tag = "1")]
+    pub topic: String,
+    /// UNIX timestamp (nanoseconds) when the data object was initialized.
+    #[prost(uint64, tag = "2")]
+    pub ts_init: u64,
+    #[prost(oneof = "data_message::Data", tags = "3, 4, 5, 6, 7")]
+    pub data: Option<data_message::Data>,
+}
+
+pub mod data_message {
+    /// The data object carried by a [`super::DataMessage`].
+    #[derive(Clone, PartialEq, Eq, prost::Oneof)]
+    pub enum Data {
+        #[prost(message, tag = "3")]
+        Quote(super::QuoteMessage),
+        #[prost(message, tag = "4")]
+        Trade(super::TradeMessage),
+        #[prost(message, tag = "5")]
+        Bar(super::BarMessage),
+        #[prost(message, tag = "6")]
+        Deltas(super::BookDeltasMessage),
+        #[prost(message, tag = "7")]
+        Depth(super::BookDepthMessage),
+    }
+}
+
+/// A quote tick.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QuoteMessage {
+    #[prost(string, tag = "1")]
+    pub instrument_id: String,
+    #[prost(string, tag = "2")]
+    pub bid_price: String,
+    #[prost(string, tag = "3")]
+    pub ask_price: String,
+    #[prost(string, tag = "4")]
+    pub bid_size: String,
+    #[prost(string, tag = "5")]
+    pub ask_size: String,
+    #[prost(uint64, tag = "6")]
+    pub ts_event: u64,
+}
+
+/// A trade tick.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct TradeMessage {
+    #[prost(string, tag = "1")]
+    pub instrument_id: String,
+    #[prost(string, tag = "2")]
+    pub price: String,
+    #[prost(string, tag = "3")]
+    pub size: String,
+    /// The aggressor side, e.g. `BUYER`.
+    #[prost(string, tag = "4")]
+    pub aggressor_side: String,
+    #[prost(string, tag = "5")]
+    pub trade_id: String,
+    #[prost(uint64, tag = "6")]
+    pub ts_event: u64,
+}
+
+/// A bar.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct BarMessage {
+    #[prost(string, tag = "1")]
+    pub bar_type: String,
+    #[prost(string, tag = "2")]
+    pub open: String,
+    #[prost(string, tag = "3")]
+    pub high: String,
+    #[prost(string, tag = "4")]
+    pub low: String,
+    #[prost(string, tag = "5")]
+    pub close: String,
+    #[prost(string, tag = "6")]
+    pub volume: String,
+    #[prost(uint64, tag = "7")]
+    pub ts_event: u64,
+}
+
+/// A batch of order book deltas for an instrument.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct BookDeltasMessage {
+    #[prost(string, tag = "1")]
+    pub instrument_id: String,
+    #[prost(message, repeated, tag = "2")]
+    pub deltas: Vec<BookDeltaMessage>,
+}
+
+/// An order book delta.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct BookDeltaMessage {
+    /// The book action, e.g. `ADD`.
+    #[prost(string, tag = "1")]
+    pub action: String,
+    /// The order side, e.g. `BUY`.
+    #[prost(string, tag = "2")]
+    pub side: String,
+    #[prost(string, tag = "3")]
+    pub price: String,
+    #[prost(string, tag = "4")]
+    pub size: String,
+    #[prost(uint64, tag = "5")]
+    pub order_id: u64,
+    #[prost(uint32, tag = "6")]
+    pub flags: u32,
+    #[prost(uint64, tag = "7")]
+    pub sequence: u64,
+    #[prost(uint64, tag = "8")]
+    pub ts_event: u64,
+}
+
+/// An order book depth snapshot of up to ten levels per side.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct BookDepthMessage {
+    #[prost(string, tag = "1")]
+    pub instrument_id: String,
+    #[prost(message, repeated, tag = "2")]
+    pub bids: Vec<BookLevelMessage>,
+    #[prost(message, repeated, tag = "3")]
+    pub asks: Vec<BookLevelMessage>,
+    #[prost(uint32, tag = "4")]
+    pub flags: u32,
+    #[prost(uint64, tag = "5")]
+    pub sequence: u64,
+    #[prost(uint64, tag = "6")]
+    pub ts_event: u64,
+}
+
+/// An order book price level.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct BookLevelMessage {
+    #[prost(string, tag = "1")]
+    pub price: String,
+    #[prost(string, tag = "2")]
+    pub size: String,
+    #[prost(uint32, tag = "3")]
+    pub count: u32,
+}
+
+/// A request to submit a new market or limit order.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct SubmitOrderRequest {
+    #[prost(string, tag = "1")]
+    pub trader_id: String,
+    #[prost(string, tag = "2")]
+    pub client_id: String,
+    #[prost(string, tag = "3")]
+    pub strategy_id: String,
+    #[prost(string, tag = "4")]
+    pub instrument_id: String,
+    #[prost(string, tag = "5")]
+    pub client_order_id: String,
+    /// The order side, e.g. `BUY`.
+    #[prost(string, tag = "6")]
+    pub order_side: String,
+    /// The order type, either `MARKET` or `LIMIT`.
+    #[prost(string, tag = "7")]
+    pub order_type: String,
+    #[prost(string, tag = "8")]
+    pub quantity: String,
+    /// The limit price, required for `LIMIT` orders.
+    #[prost(string, optional, tag = "9")]
+    pub price: Option<String>,
+    /// The time in force, e.g. `GTC`.
+    #[prost(string, tag = "10")]
+    pub time_in_force: String,
+    /// UNIX timestamp (nanoseconds) when a `GTD` order expires.
+    #[prost(uint64, optional, tag = "11")]
+    pub expire_time: Option<u64>,
+    #[prost(bool, tag = "12")]
+    pub post_only: bool,
+    #[prost(bool, tag = "13")]
+    pub reduce_only: bool,
+    #[prost(string, optional, tag = "14")]
+    pub position_id: Option<String>,
+}
+
+/// A request to cancel an open order.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CancelOrderRequest {
+    #[prost(string, tag = "1")]
+    pub trader_id: String,
+    #[prost(string, tag = "2")]
+    pub client_id: String,
+    #[prost(string, tag = "3")]
+    pub strategy_id: String,
+    #[prost(string, tag = "4")]
+    pub instrument_id: String,
+    #[prost(string, tag = "5")]
+    pub client_order_id: String,
+    #[prost(string, optional, tag = "6")]
+    pub venue_order_id: Option<String>,
+}
+
+/// The acknowledgement of a trading command passed to the execution engine.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CommandResponse {
+    /// The ID of the accepted command.
+    #[prost(string, tag = "1")]
+    pub command_id: String,
+}
+
+/// A request for a snapshot of the portfolio state.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct PortfolioRequest {}
+
+/// A snapshot of the accounts and open positions held in the cache.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PortfolioState {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: Vec<AccountSnapshot>,
+    #[prost(message, repeated, tag = "2")]
+    pub positions: Vec<PositionSnapshot>,
+}
+
+/// The balances of an account.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct AccountSnapshot {
+    #[prost(string, tag = "1")]
+    pub account_id: String,
+    #[prost(message, repeated, tag = "2")]
+    pub balances: Vec<BalanceSnapshot>,
+}
+
+/// An account balance in a single currency, with amounts as decimal strings.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct BalanceSnapshot {
+    #[prost(string, tag = "1")]
+    pub currency: String,
+    #[prost(string, tag = "2")]
+    pub total: String,
+    #[prost(string, tag = "3")]
+    pub locked: String,
+    #[prost(string, tag = "4")]
+    pub free: String,
+}
+
+/// An open position, with quantities and money as decimal strings.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PositionSnapshot {
+    #[prost(string, tag = "1")]
+    pub position_id: String,
+    #[prost(string, tag = "2")]
+    pub instrument_id: String,
+    #[prost(string, tag = "3")]
+    pub strategy_id: String,
+    #[prost(string, tag = "4")]
+    pub side: String,
+    #[prost(string, tag = "5")]
+    pub quantity: String,
+    #[prost(double, tag = "6")]
+    pub avg_px_open: f64,
+    #[prost(string, optional, tag = "7")]
+    pub realized_pnl: Option<String>,
+}
+
+/// The value of a strategy parameter.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ParameterValueMessage {
+    #[prost(oneof = "parameter_value_message::Value", tags = "1, 2, 3, 4")]
+    pub value: Option<parameter_value_message::Value>,
+}
+
+pub mod parameter_value_message {
+    /// The typed value carried by a [`super::ParameterValueMessage`].
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Value {
+        #[prost(bool, tag = "1")]
+        BoolValue(bool),
+        #[prost(int64, tag = "2")]
+        IntValue(i64),
+        #[prost(double, tag = "3")]
+        FloatValue(f64),
+        #[prost(string, tag = "4")]
+        StringValue(String),
+    }
+}
+
+impl From<ParameterValue> for ParameterValueMessage {
+    fn from(value: ParameterValue) -> Self {
+        use parameter_value_message::Value;
+
+        let value = match value {
+            ParameterValue::Bool(value) => Value::BoolValue(value),
+            ParameterValue::Int(value) => Value::IntValue(value),
+            ParameterValue::Float(value) => Value::FloatValue(value),
+            ParameterValue::String(value) => Value::StringValue(value),
+        };
+        Self { value: Some(value) }
+    }
+}
+
+impl TryFrom<ParameterValueMessage> for ParameterValue {
+    type Error = anyhow::Error;
+
+    fn try_from(message: ParameterValueMessage) -> anyhow::Result<Self> {
+        use parameter_value_message::Value;
+
+        match message.value {
+            Some(Value::BoolValue(value)) => Ok(Self::Bool(value)),
+            Some(Value::IntValue(value)) => Ok(Self::Int(value)),
+            Some(Value::FloatValue(value)) => Ok(Self::Float(value)),
+            Some(Value::StringValue(value)) => Ok(Self::String(value)),
+            None => anyhow::bail!("Parameter value was not set"),
+        }
+    }
+}
+
+/// A request to update the value of a registered strategy parameter.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SetParameterRequest {
+    #[prost(string, tag = "1")]
+    pub strategy_id: String,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(message, optional, tag = "3")]
+    pub value: Option<ParameterValueMessage>,
+}
+
+/// The change in the value of a strategy parameter.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ParameterChangedMessage {
+    #[prost(string, tag = "1")]
+    pub strategy_id: String,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(message, optional, tag = "3")]
+    pub old_value: Option<ParameterValueMessage>,
+    #[prost(message, optional, tag = "4")]
+    pub new_value: Option<ParameterValueMessage>,
+    #[prost(uint64, tag = "5")]
+    pub ts_event: u64,
+}
+
+/// A request for the registered parameters of a strategy.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct ParametersRequest {
+    #[prost(string, tag = "1")]
+    pub strategy_id: String,
+}
+
+/// The registered parameters of a strategy, in registration order.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ParametersState {
+    #[prost(message, repeated, tag = "1")]
+    pub parameters: Vec<ParameterSnapshot>,
+}
+
+/// The definition and current value of a strategy parameter.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ParameterSnapshot {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    /// The parameter type, e.g. `FLOAT`.
+    #[prost(string, tag = "2")]
+    pub kind: String,
+    #[prost(message, optional, tag = "3")]
+    pub value: Option<ParameterValueMessage>,
+    #[prost(double, optional, tag = "4")]
+    pub min: Option<f64>,
+    #[prost(double, optional, tag = "5")]
+    pub max: Option<f64>,
+    #[prost(string, repeated, tag = "6")]
+    pub choices: Vec<String>,
+}
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/nautilus.control.EngineControl.rs"
+));
@@ -0,0 +1,388 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The tonic server for the engine control service.
+
+use std::{fmt::Display, net::SocketAddr, str::FromStr};
+
+use nautilus_common::{
+    parameters::{ParameterValue, UpdateParameter},
+    runtime::get_runtime,
+};
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime, uuid::UUID4};
+use nautilus_model::{
+    enums::{OrderSide, OrderType, TimeInForce},
+    identifiers::{
+        ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TraderId, VenueOrderId,
+    },
+    orders::{any::OrderAny, limit::LimitOrder, market::MarketOrder},
+    types::{price::Price, quantity::Quantity},
+};
+use tokio::{
+    net::TcpListener,
+    sync::{mpsc, oneshot},
+};
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
+use tonic::{transport::Server, Request, Response, Status};
+
+use super::{
+    bridge::{ControlHandle, ControlRequest},
+    proto::{
+        engine_control_server::{EngineControl, EngineControlServer},
+        CancelOrderRequest, CommandResponse, DataMessage, ParameterChangedMessage,
+        ParametersRequest, ParametersState, PortfolioRequest, PortfolioState, SetParameterRequest,
+        SubmitOrderRequest, SubscribeDataRequest,
+    },
+};
+use crate::messages::{CancelOrder, SubmitOrder, TradingCommand};
+
+/// The number of data messages buffered per stream before further messages are dropped.
+pub const DATA_STREAM_BUFFER_SIZE: usize = 10_000;
+
+/// Provides the engine control gRPC service, forwarding requests to a `ControlBridge`.
+#[derive(Clone, Debug)]
+pub struct EngineControlService {
+    handle: ControlHandle,
+}
+
+impl EngineControlService {
+    /// Creates a new [`EngineControlService`] instance.
+    #[must_use]
+    pub const fn new(handle: ControlHandle) -> Self {
+        Self { handle }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn send(&self, request: ControlRequest) -> Result<(), Status> {
+        self.handle
+            .send(request)
+            .map_err(|e| Status::unavailable(e.to_string()))
+    }
+
+    async fn execute(&self, command: TradingCommand) -> Result<(), Status> {
+        let (reply, rx) = oneshot::channel();
+        self.send(ControlRequest::Execute { command, reply })?;
+        rx.await
+            .map_err(|_| Status::unavailable("Control bridge dropped the request"))?
+            .map_err(Status::failed_precondition)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_field<T, E: Display>(name: &str, result: Result<T, E>) -> Result<T, Status> {
+    result.map_err(|e| Status::invalid_argument(format!("Invalid `{name}`: {e}")))
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_submit_order(request: SubmitOrderRequest) -> Result<SubmitOrder, Status> {
+    let trader_id = parse_field("trader_id", TraderId::new_checked(&request.trader_id))?;
+    let client_id = parse_field("client_id", ClientId::new_checked(&request.client_id))?;
+    let strategy_id = parse_strategy_id(&request.strategy_id)?;
+    let instrument_id = parse_field(
+        "instrument_id",
+        InstrumentId::from_str(&request.instrument_id),
+    )?;
+    let client_order_id = parse_field(
+        "client_order_id",
+        ClientOrderId::new_checked(&request.client_order_id),
+    )?;
+    let order_side = parse_field("order_side", OrderSide::from_str(&request.order_side))?;
+    let order_type = parse_field("order_type", OrderType::from_str(&request.order_type))?;
+    let quantity = parse_field("quantity", Quantity::from_str(&request.quantity))?;
+    let time_in_force = parse_field(
+        "time_in_force",
+        TimeInForce::from_str(&request.time_in_force),
+    )?;
+    let position_id = request
+        .position_id
+        .map(|value| parse_field("position_id", PositionId::new_checked(value)))
+        .transpose()?;
+    let ts_init = get_atomic_clock_realtime().get_time_ns();
+
+    let order = match order_type {
+        OrderType::Market => MarketOrder::new_checked(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            time_in_force,
+            UUID4::new(),
+            ts_init,
+            request.reduce_only,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .map(OrderAny::Market),
+        OrderType::Limit => {
+            let price = request
+                .price
+                .ok_or_else(|| Status::invalid_argument("`price` is required for LIMIT orders"))?;
+            LimitOrder::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+                parse_field("price", Price::from_str(&price))?,
+                time_in_force,
+                request.expire_time.map(UnixNanos::from),
+                request.post_only,
+                request.reduce_only,
+                false,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                UUID4::new(),
+                ts_init,
+            )
+            .map(OrderAny::Limit)
+        }
+        _ => {
+            return Err(Status::invalid_argument(format!(
+                "Unsupported order type {order_type}, use MARKET or LIMIT"
+            )))
+        }
+    };
+    let order = order.map_err(|e| Status::invalid_argument(format!("Invalid order: {e}")))?;
+
+    SubmitOrder::new(
+        trader_id,
+        client_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        VenueOrderId::from("NONE"),
+        order,
+        None,
+        position_id,
+        UUID4::new(),
+        ts_init,
+    )
+    .map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_cancel_order(request: CancelOrderRequest) -> Result<CancelOrder, Status> {
+    let venue_order_id = request
+        .venue_order_id
+        .map(|value| parse_field("venue_order_id", VenueOrderId::new_checked(value)))
+        .transpose()?
+        .unwrap_or_else(|| VenueOrderId::from("NONE"));
+
+    CancelOrder::new(
+        parse_field("trader_id", TraderId::new_checked(&request.trader_id))?,
+        parse_field("client_id", ClientId::new_checked(&request.client_id))?,
+        parse_strategy_id(&request.strategy_id)?,
+        parse_field(
+            "instrument_id",
+            InstrumentId::from_str(&request.instrument_id),
+        )?,
+        parse_field(
+            "client_order_id",
+            ClientOrderId::new_checked(&request.client_order_id),
+        )?,
+        venue_order_id,
+        UUID4::new(),
+        get_atomic_clock_realtime().get_time_ns(),
+    )
+    .map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_strategy_id(value: &str) -> Result<StrategyId, Status> {
+    parse_field("strategy_id", StrategyId::new_checked(value))
+}
+
+#[tonic::async_trait]
+impl EngineControl for EngineControlService {
+    type SubscribeDataStream = ReceiverStream<Result<DataMessage, Status>>;
+
+    async fn subscribe_data(
+        &self,
+        request: Request<SubscribeDataRequest>,
+    ) -> Result<Response<Self::SubscribeDataStream>, Status> {
+        let topic = request.into_inner().topic;
+        if topic.is_empty() {
+            return Err(Status::invalid_argument("Topic was empty"));
+        }
+
+        let (sender, rx) = mpsc::channel(DATA_STREAM_BUFFER_SIZE);
+        self.send(ControlRequest::Subscribe { topic, sender })?;
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn submit_order(
+        &self,
+        request: Request<SubmitOrderRequest>,
+    ) -> Result<Response<CommandResponse>, Status> {
+        let command = parse_submit_order(request.into_inner())?;
+        let command_id = command.command_id.to_string();
+        self.execute(TradingCommand::SubmitOrder(command)).await?;
+        Ok(Response::new(CommandResponse { command_id }))
+    }
+
+    async fn cancel_order(
+        &self,
+        request: Request<CancelOrderRequest>,
+    ) -> Result<Response<CommandResponse>, Status> {
+        let command = parse_cancel_order(request.into_inner())?;
+        let command_id = command.command_id.to_string();
+        self.execute(TradingCommand::CancelOrder(command)).await?;
+        Ok(Response::new(CommandResponse { command_id }))
+    }
+
+    async fn get_portfolio(
+        &self,
+        _request: Request<PortfolioRequest>,
+    ) -> Result<Response<PortfolioState>, Status> {
+        let (reply, rx) = oneshot::channel();
+        self.send(ControlRequest::Portfolio { reply })?;
+        let state = rx
+            .await
+            .map_err(|_| Status::unavailable("Control bridge dropped the request"))?;
+        Ok(Response::new(state))
+    }
+
+    async fn set_parameter(
+        &self,
+        request: Request<SetParameterRequest>,
+    ) -> Result<Response<ParameterChangedMessage>, Status> {
+        let request = request.into_inner();
+        let strategy_id = parse_strategy_id(&request.strategy_id)?;
+        let value = request
+            .value
+            .ok_or_else(|| anyhow::anyhow!("Parameter value was not set"))
+            .and_then(ParameterValue::try_from);
+        let value = parse_field("value", value)?;
+        let command = UpdateParameter::new(strategy_id, &request.name, value, UnixNanos::default());
+
+        let (reply, rx) = oneshot::channel();
+        self.send(ControlRequest::SetParameter { command, reply })?;
+        let event = rx
+            .await
+            .map_err(|_| Status::unavailable("Control bridge dropped the request"))?
+            .map_err(Status::failed_precondition)?;
+        Ok(Response::new(event))
+    }
+
+    async fn get_parameters(
+        &self,
+        request: Request<ParametersRequest>,
+    ) -> Result<Response<ParametersState>, Status> {
+        let strategy_id = parse_strategy_id(&request.into_inner().strategy_id)?;
+
+        let (reply, rx) = oneshot::channel();
+        self.send(ControlRequest::Parameters { strategy_id, reply })?;
+        let state = rx
+            .await
+            .map_err(|_| Status::unavailable("Control bridge dropped the request"))?
+            .map_err(Status::failed_precondition)?;
+        Ok(Response::new(state))
+    }
+}
+
+/// The gRPC metadata key carrying the bearer token.
+pub const AUTHORIZATION_KEY: &str = "authorization";
+
+/// Returns an interceptor rejecting requests without the bearer `auth_token`, when one is set.
+#[allow(clippy::result_large_err)]
+fn auth_interceptor(
+    auth_token: Option<String>,
+) -> impl FnMut(Request<()>) -> Result<Request<()>, Status> + Clone {
+    let expected = auth_token.map(|token| format!("Bearer {token}"));
+    move |request: Request<()>| {
+        let Some(expected) = &expected else {
+            return Ok(request);
+        };
+        let provided = request
+            .metadata()
+            .get(AUTHORIZATION_KEY)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+            Ok(request)
+        } else {
+            Err(Status::unauthenticated("Invalid or missing bearer token"))
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Starts serving the engine control service on the given `addr` using the Nautilus runtime,
+/// returning the bound address.
+///
+/// The service is unencrypted, so it may only be bound to a loopback address. When an
+/// `auth_token` is given, clients must send it as `authorization: Bearer <token>` metadata.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The `addr` is not a loopback address.
+/// - The `auth_token` is empty.
+/// - The address cannot be bound.
+pub fn start_grpc_server(
+    addr: SocketAddr,
+    auth_token: Option<String>,
+    handle: ControlHandle,
+) -> anyhow::Result<SocketAddr> {
+    if !addr.ip().is_loopback() {
+        anyhow::bail!("Engine control gRPC must bind to a loopback address, was {addr}");
+    }
+    if auth_token.as_ref().is_some_and(String::is_empty) {
+        anyhow::bail!("Engine control gRPC `auth_token` was empty");
+    }
+
+    let runtime = get_runtime();
+    let listener = runtime.block_on(TcpListener::bind(addr))?;
+    let local_addr = listener.local_addr()?;
+    let service = EngineControlServer::with_interceptor(
+        EngineControlService::new(handle),
+        auth_interceptor(auth_token),
+    );
+
+    runtime.spawn(async move {
+        if let Err(e) = Server::builder()
+            .add_service(service)
+            .serve_with_incoming(TcpListenerStream::new(listener))
+            .await
+        {
+            log::error!("Engine control gRPC server stopped: {e}");
+        }
+    });
+
+    log::info!("Serving engine control gRPC on {local_addr}");
+    Ok(local_addr)
+}
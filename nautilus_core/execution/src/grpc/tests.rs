@@ -0,0 +1,347 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tests module for the engine control gRPC service.
+
+use std::{any::Any, cell::RefCell, future::Future, net::SocketAddr, rc::Rc, time::Duration};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::TestClock,
+    msgbus::MessageBus,
+    parameters::{ParameterDefinition, ParameterService, ParameterValue},
+    runtime::get_runtime,
+};
+use nautilus_model::{data::stubs::quote_ethusdt_binance, identifiers::StrategyId};
+use rstest::rstest;
+use tonic::{transport::Channel, Code};
+use ustr::Ustr;
+
+use super::{
+    bridge::{ControlBridge, ControlHandle},
+    proto::{
+        data_message::Data, engine_control_client::EngineControlClient, CancelOrderRequest,
+        ParametersRequest, PortfolioRequest, SetParameterRequest, SubmitOrderRequest,
+        SubscribeDataRequest,
+    },
+    server::{start_grpc_server, AUTHORIZATION_KEY},
+};
+use crate::engine::{config::ExecutionEngineConfig, ExecutionEngine};
+
+struct TestNode {
+    msgbus: Rc<RefCell<MessageBus>>,
+    bridge: ControlBridge,
+    addr: SocketAddr,
+}
+
+fn new_bridge(msgbus: Rc<RefCell<MessageBus>>) -> (ControlBridge, ControlHandle) {
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let exec_engine = Rc::new(RefCell::new(
+        ExecutionEngine::new(
+            Rc::new(RefCell::new(TestClock::new())),
+            cache.clone(),
+            msgbus.clone(),
+            ExecutionEngineConfig::default(),
+        )
+        .unwrap(),
+    ));
+    ControlBridge::new(cache, msgbus, exec_engine)
+}
+
+fn test_bridge() -> (ControlBridge, ControlHandle) {
+    new_bridge(Rc::new(RefCell::new(MessageBus::default())))
+}
+
+impl TestNode {
+    fn start() -> Self {
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let (bridge, handle) = new_bridge(msgbus.clone());
+        let addr = start_grpc_server("127.0.0.1:0".parse().unwrap(), None, handle).unwrap();
+        Self {
+            msgbus,
+            bridge,
+            addr,
+        }
+    }
+
+    fn client(&self) -> EngineControlClient<Channel> {
+        let url = format!("http://{}", self.addr);
+        get_runtime()
+            .block_on(EngineControlClient::connect(url))
+            .unwrap()
+    }
+
+    /// Runs the `future` on the runtime while processing the bridge, as a node would.
+    fn run<F>(&mut self, future: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let task = get_runtime().spawn(future);
+        while !task.is_finished() {
+            self.bridge.process();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        get_runtime().block_on(task).unwrap()
+    }
+}
+
+#[rstest]
+fn test_get_portfolio_when_empty() {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+
+    let state = node
+        .run(async move { client.get_portfolio(PortfolioRequest {}).await })
+        .unwrap()
+        .into_inner();
+
+    assert!(state.accounts.is_empty());
+    assert!(state.positions.is_empty());
+}
+
+fn submit_order_request() -> SubmitOrderRequest {
+    SubmitOrderRequest {
+        trader_id: "TRADER-001".to_string(),
+        client_id: "BINANCE".to_string(),
+        strategy_id: "EMACross-001".to_string(),
+        instrument_id: "ETHUSDT.BINANCE".to_string(),
+        client_order_id: "O-001".to_string(),
+        order_side: "BUY".to_string(),
+        order_type: "LIMIT".to_string(),
+        quantity: "1.000".to_string(),
+        price: Some("1500.00".to_string()),
+        time_in_force: "GTC".to_string(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case::invalid_side(SubmitOrderRequest { order_side: "UP".to_string(), ..submit_order_request() })]
+#[case::invalid_quantity(SubmitOrderRequest { quantity: "abc".to_string(), ..submit_order_request() })]
+#[case::limit_without_price(SubmitOrderRequest { price: None, ..submit_order_request() })]
+#[case::gtd_without_expire_time(SubmitOrderRequest { time_in_force: "GTD".to_string(), ..submit_order_request() })]
+#[case::unsupported_type(SubmitOrderRequest { order_type: "STOP_MARKET".to_string(), ..submit_order_request() })]
+fn test_submit_order_with_invalid_request(#[case] request: SubmitOrderRequest) {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+
+    let status = node
+        .run(async move { client.submit_order(request).await })
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+fn test_submit_order_without_client() {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+
+    let status = node
+        .run(async move { client.submit_order(submit_order_request()).await })
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::FailedPrecondition);
+}
+
+#[rstest]
+fn test_cancel_order_without_client() {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+
+    let request = CancelOrderRequest {
+        trader_id: "TRADER-001".to_string(),
+        client_id: "BINANCE".to_string(),
+        strategy_id: "EMACross-001".to_string(),
+        instrument_id: "ETHUSDT.BINANCE".to_string(),
+        client_order_id: "O-001".to_string(),
+        venue_order_id: None,
+    };
+    let status = node
+        .run(async move { client.cancel_order(request).await })
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::FailedPrecondition);
+}
+
+#[rstest]
+fn test_subscribe_data_streams_published_quotes() {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+    let topic = Ustr::from("data.quotes.BINANCE.ETHUSDT");
+
+    let mut stream = node
+        .run(async move {
+            client
+                .subscribe_data(SubscribeDataRequest {
+                    topic: topic.to_string(),
+                })
+                .await
+        })
+        .unwrap()
+        .into_inner();
+    node.bridge.process();
+    assert_eq!(node.bridge.stream_count(), 1);
+    assert_eq!(node.msgbus.borrow().subscriptions_count(topic), 1);
+
+    let quote = quote_ethusdt_binance();
+    node.msgbus.borrow().publish(&topic, &quote as &dyn Any);
+    let message = node
+        .run(async move { stream.message().await })
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(message.topic, topic.as_str());
+    assert_eq!(message.ts_init, quote.ts_init.as_u64());
+    let Some(Data::Quote(received)) = message.data else {
+        panic!("Expected a quote, was {:?}", message.data);
+    };
+    assert_eq!(received.instrument_id, quote.instrument_id.to_string());
+    assert_eq!(received.bid_price, quote.bid_price.to_string());
+    assert_eq!(received.ask_size, quote.ask_size.to_string());
+}
+
+#[rstest]
+fn test_set_parameter_updates_registered_parameter() {
+    let mut node = TestNode::start();
+    let strategy_id = StrategyId::from("EMACross-001");
+    let service = Rc::new(ParameterService::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        node.msgbus.clone(),
+    ));
+    service
+        .register(
+            strategy_id,
+            ParameterDefinition::new("threshold", ParameterValue::Float(0.5))
+                .with_bounds(Some(0.0), Some(1.0)),
+        )
+        .unwrap();
+    node.bridge.set_parameter_service(service.clone());
+    let mut client = node.client();
+
+    let (changed, rejected, state) = node.run(async move {
+        let request = |value: f64| SetParameterRequest {
+            strategy_id: "EMACross-001".to_string(),
+            name: "threshold".to_string(),
+            value: Some(ParameterValue::Float(value).into()),
+        };
+        let changed = client.set_parameter(request(0.8)).await;
+        let rejected = client.set_parameter(request(2.0)).await;
+        let state = client
+            .get_parameters(ParametersRequest {
+                strategy_id: "EMACross-001".to_string(),
+            })
+            .await;
+        (changed, rejected, state)
+    });
+
+    let changed = changed.unwrap().into_inner();
+    assert_eq!(
+        (changed.old_value, changed.new_value),
+        (
+            Some(ParameterValue::Float(0.5).into()),
+            Some(ParameterValue::Float(0.8).into())
+        )
+    );
+    assert_eq!(rejected.unwrap_err().code(), Code::FailedPrecondition);
+    let state = state.unwrap().into_inner();
+    assert_eq!(state.parameters.len(), 1);
+    assert_eq!(state.parameters[0].kind, "FLOAT");
+    assert_eq!(
+        state.parameters[0].value,
+        Some(ParameterValue::Float(0.8).into())
+    );
+    assert_eq!(
+        service.value(&strategy_id, "threshold"),
+        Some(ParameterValue::Float(0.8))
+    );
+}
+
+#[rstest]
+fn test_set_parameter_without_parameter_service() {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+
+    let request = SetParameterRequest {
+        strategy_id: "EMACross-001".to_string(),
+        name: "threshold".to_string(),
+        value: Some(ParameterValue::Float(0.8).into()),
+    };
+    let status = node
+        .run(async move { client.set_parameter(request).await })
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::FailedPrecondition);
+}
+
+#[rstest]
+fn test_set_parameter_without_value() {
+    let mut node = TestNode::start();
+    let mut client = node.client();
+
+    let request = SetParameterRequest {
+        strategy_id: "EMACross-001".to_string(),
+        name: "threshold".to_string(),
+        value: None,
+    };
+    let status = node
+        .run(async move { client.set_parameter(request).await })
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+fn test_start_grpc_server_rejects_non_loopback_address() {
+    let (_bridge, handle) = test_bridge();
+
+    let result = start_grpc_server("0.0.0.0:0".parse().unwrap(), None, handle);
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_auth_token_required_when_set() {
+    let (mut bridge, handle) = test_bridge();
+    let addr = start_grpc_server(
+        "127.0.0.1:0".parse().unwrap(),
+        Some("secret".to_string()),
+        handle,
+    )
+    .unwrap();
+    let url = format!("http://{addr}");
+    let mut client = get_runtime()
+        .block_on(EngineControlClient::connect(url))
+        .unwrap();
+
+    let task = get_runtime().spawn(async move {
+        let rejected = client.get_portfolio(PortfolioRequest {}).await;
+        let mut request = tonic::Request::new(PortfolioRequest {});
+        request
+            .metadata_mut()
+            .insert(AUTHORIZATION_KEY, "Bearer secret".parse().unwrap());
+        let accepted = client.get_portfolio(request).await;
+        (rejected, accepted)
+    });
+    while !task.is_finished() {
+        bridge.process();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    let (rejected, accepted) = get_runtime().block_on(task).unwrap();
+
+    assert_eq!(rejected.unwrap_err().code(), Code::Unauthenticated);
+    assert!(accepted.is_ok());
+}
@@ -0,0 +1,507 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `DeltaHedger` execution algorithm which hedges the net delta of option portfolios.
+//!
+//! The hedger consumes the `GreeksUpdate`s published on the greeks topic, and when the net delta
+//! for an underlying is outside the configured band, submits an order in the hedge instrument to
+//! bring the delta back to zero. Only one hedge order per hedge instrument is worked at a time.
+
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    config::{check_setting, ValidateConfig},
+    generators::client_order_id::ClientOrderIdGenerator,
+    greeks::GreeksUpdate,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    data::Data,
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce},
+    identifiers::{
+        ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, StrategyId, VenueOrderId,
+    },
+    orders::{any::OrderAny, limit::LimitOrder, market::MarketOrder},
+    types::price::Price,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::messages::{submit::SubmitOrder, TradingCommand};
+
+/// The execution algorithm ID for orders submitted by the `DeltaHedger`.
+pub const DELTA_HEDGER_ID: &str = "DeltaHedger";
+
+/// Configuration for `DeltaHedger` instances.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeltaHedgerConfig {
+    /// The strategy ID the hedge orders are submitted for.
+    pub strategy_id: StrategyId,
+    /// The client ID for the hedge orders (the venue of the hedge instrument if None).
+    pub client_id: Option<ClientId>,
+    /// The instrument used to hedge each underlying.
+    pub hedge_instruments: HashMap<Ustr, InstrumentId>,
+    /// The absolute net delta (in underlying units) above which the delta is hedged.
+    pub band: f64,
+    /// The type of hedge orders, either `MARKET` or `LIMIT` at the opposite side of the quote.
+    pub order_type: OrderType,
+}
+
+impl Default for DeltaHedgerConfig {
+    /// Creates a new default [`DeltaHedgerConfig`] instance.
+    fn default() -> Self {
+        Self {
+            strategy_id: StrategyId::from("DeltaHedger-001"),
+            client_id: None,
+            hedge_instruments: HashMap::new(),
+            band: 0.0,
+            order_type: OrderType::Market,
+        }
+    }
+}
+
+impl ValidateConfig for DeltaHedgerConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.band >= 0.0,
+            "delta_hedger.band",
+            "must be non-negative",
+        )?;
+        check_setting(
+            matches!(self.order_type, OrderType::Market | OrderType::Limit),
+            "delta_hedger.order_type",
+            "must be MARKET or LIMIT",
+        )
+    }
+}
+
+/// Hedges the net delta per underlying with orders in the hedge instruments.
+pub struct DeltaHedger {
+    config: DeltaHedgerConfig,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    hedge_orders: RefCell<HashMap<InstrumentId, ClientOrderId>>,
+    order_id_generator: RefCell<ClientOrderIdGenerator>,
+}
+
+impl DeltaHedger {
+    /// Creates a new [`DeltaHedger`] instance.
+    ///
+    /// Client order IDs are generated for the configured strategy, with the sequence persisted
+    /// to the `cache` so IDs remain unique across restarts.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `config` is invalid.
+    pub fn new(
+        config: DeltaHedgerConfig,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> anyhow::Result<Self> {
+        config.validate()?;
+        let trader_id = msgbus.borrow().trader_id;
+        let order_id_generator = ClientOrderIdGenerator::new_persistent(
+            trader_id,
+            config.strategy_id,
+            clock,
+            cache.clone(),
+        );
+        Ok(Self {
+            config,
+            cache,
+            msgbus,
+            hedge_orders: RefCell::new(HashMap::new()),
+            order_id_generator: RefCell::new(order_id_generator),
+        })
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &DeltaHedgerConfig {
+        &self.config
+    }
+
+    /// Subscribes the hedger to the greeks topic, returning the handler to unsubscribe with.
+    pub fn start(self: &Rc<Self>) -> ShareableMessageHandler {
+        let handler = ShareableMessageHandler(Rc::new(DeltaHedgerHandler {
+            id: Ustr::from(DELTA_HEDGER_ID),
+            hedger: self.clone(),
+        }));
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus.switchboard.greeks_topic;
+        msgbus.subscribe(topic, handler.clone(), None);
+        log::info!(
+            "{DELTA_HEDGER_ID} hedging outside band {}",
+            self.config.band
+        );
+        handler
+    }
+
+    /// Handles the greeks `update`, submitting a hedge order if the net delta is outside the
+    /// band and no hedge order is already working in the hedge instrument.
+    pub fn handle_greeks(&self, update: &GreeksUpdate) -> Option<SubmitOrder> {
+        let instrument_id = *self.config.hedge_instruments.get(&update.underlying)?;
+        if update.delta.abs() <= self.config.band || self.is_hedging(&instrument_id) {
+            return None;
+        }
+
+        let order = match self.hedge_order(&instrument_id, update.delta, update.ts_event) {
+            Ok(order) => order?,
+            Err(e) => {
+                log::error!("Error creating hedge order for {}: {e}", update.underlying);
+                return None;
+            }
+        };
+        let client_id = self
+            .config
+            .client_id
+            .unwrap_or_else(|| ClientId::new(instrument_id.venue.as_str()));
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            VenueOrderId::from("NONE"),
+            order,
+            Some(ExecAlgorithmId::from(DELTA_HEDGER_ID)),
+            None,
+            UUID4::new(),
+            update.ts_event,
+        )
+        .ok()?;
+
+        log::info!(
+            "Hedging {} delta {:.4} with {}",
+            update.underlying,
+            update.delta,
+            command.client_order_id,
+        );
+        self.hedge_orders
+            .borrow_mut()
+            .insert(instrument_id, command.client_order_id);
+        let message = TradingCommand::SubmitOrder(command.clone());
+        let msgbus = self.msgbus.borrow();
+        msgbus.send(
+            &msgbus.switchboard.exec_engine_execute,
+            &message as &dyn Any,
+        );
+        Some(command)
+    }
+
+    // Returns whether the last hedge order for the instrument is still working
+    fn is_hedging(&self, instrument_id: &InstrumentId) -> bool {
+        let hedge_orders = self.hedge_orders.borrow();
+        let Some(client_order_id) = hedge_orders.get(instrument_id) else {
+            return false;
+        };
+        self.cache
+            .borrow()
+            .order(client_order_id)
+            .is_some_and(|order| !order.is_closed())
+    }
+
+    // Returns the order to hedge the delta, or None if the hedge quantity rounds to zero
+    fn hedge_order(
+        &self,
+        instrument_id: &InstrumentId,
+        delta: f64,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<OrderAny>> {
+        let quantity = {
+            let cache = self.cache.borrow();
+            let Some(instrument) = cache.instrument(instrument_id) else {
+                anyhow::bail!("No instrument found for {instrument_id}");
+            };
+            instrument.make_qty(delta.abs() / instrument.multiplier().as_f64())
+        };
+        if quantity.is_zero() {
+            return Ok(None);
+        }
+        let side = if delta > 0.0 {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+
+        // Generated before borrowing the cache, as the sequence is persisted to it
+        let client_order_id = self.order_id_generator.borrow_mut().generate();
+        let cache = self.cache.borrow();
+        let trader_id = self.msgbus.borrow().trader_id;
+        let exec_algorithm_id = Some(ExecAlgorithmId::from(DELTA_HEDGER_ID));
+
+        let order = match self.config.order_type {
+            OrderType::Limit => {
+                let price = hedge_price(&cache, instrument_id, side)?;
+                OrderAny::Limit(LimitOrder::new(
+                    trader_id,
+                    self.config.strategy_id,
+                    *instrument_id,
+                    client_order_id,
+                    side,
+                    quantity,
+                    price,
+                    TimeInForce::Gtc,
+                    None,
+                    false,
+                    false,
+                    false,
+                    None,
+                    None,
+                    None,
+                    Some(ContingencyType::NoContingency),
+                    None,
+                    None,
+                    None,
+                    exec_algorithm_id,
+                    None,
+                    Some(client_order_id),
+                    None,
+                    UUID4::new(),
+                    ts_init,
+                )?)
+            }
+            _ => OrderAny::Market(MarketOrder::new(
+                trader_id,
+                self.config.strategy_id,
+                *instrument_id,
+                client_order_id,
+                side,
+                quantity,
+                TimeInForce::Gtc,
+                UUID4::new(),
+                ts_init,
+                false,
+                false,
+                Some(ContingencyType::NoContingency),
+                None,
+                None,
+                None,
+                exec_algorithm_id,
+                None,
+                Some(client_order_id),
+                None,
+            )),
+        };
+        Ok(Some(order))
+    }
+}
+
+// Returns the price at the opposite side of the quote, so a limit hedge order is marketable
+fn hedge_price(
+    cache: &Cache,
+    instrument_id: &InstrumentId,
+    side: OrderSide,
+) -> anyhow::Result<Price> {
+    let Some(quote) = cache.quote(instrument_id) else {
+        anyhow::bail!("No quote to price hedge order for {instrument_id}");
+    };
+    Ok(match side {
+        OrderSide::Buy => quote.ask_price,
+        _ => quote.bid_price,
+    })
+}
+
+struct DeltaHedgerHandler {
+    id: Ustr,
+    hedger: Rc<DeltaHedger>,
+}
+
+impl MessageHandler for DeltaHedgerHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(update) = message.downcast_ref::<GreeksUpdate>() {
+            self.hedger.handle_greeks(update);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::msgbus::stubs::{get_message_saving_handler, get_saved_messages};
+    use nautilus_core::time::get_atomic_clock_static;
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        instruments::{any::InstrumentAny, equity::Equity, stubs::equity_aapl},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    struct TestContext {
+        hedger: Rc<DeltaHedger>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        commands: ShareableMessageHandler,
+    }
+
+    fn context(equity: Equity, order_type: OrderType) -> TestContext {
+        let mut cache = Cache::default();
+        cache.add_instrument(InstrumentAny::Equity(equity)).unwrap();
+        cache
+            .add_quote(QuoteTick {
+                instrument_id: equity.id,
+                bid_price: Price::from("149.99"),
+                ask_price: Price::from("150.01"),
+                ..Default::default()
+            })
+            .unwrap();
+        let cache = Rc::new(RefCell::new(cache));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let commands = get_message_saving_handler::<TradingCommand>(None);
+        let endpoint = msgbus.borrow().switchboard.exec_engine_execute;
+        msgbus.borrow_mut().register(endpoint, commands.clone());
+
+        let config = DeltaHedgerConfig {
+            hedge_instruments: HashMap::from([(Ustr::from("AAPL"), equity.id)]),
+            band: 50.0,
+            order_type,
+            ..Default::default()
+        };
+        let hedger = Rc::new(
+            DeltaHedger::new(
+                config,
+                get_atomic_clock_static(),
+                cache.clone(),
+                msgbus.clone(),
+            )
+            .unwrap(),
+        );
+        TestContext {
+            hedger,
+            cache,
+            msgbus,
+            commands,
+        }
+    }
+
+    fn update(underlying: &str, delta: f64) -> GreeksUpdate {
+        GreeksUpdate {
+            underlying: Ustr::from(underlying),
+            delta,
+            gamma: 0.0,
+            vega: 0.0,
+            theta: 0.0,
+            beta_weighted_delta: None,
+            ts_event: UnixNanos::from(1),
+            ts_init: UnixNanos::from(1),
+        }
+    }
+
+    #[rstest]
+    fn test_invalid_config() {
+        let config = DeltaHedgerConfig {
+            order_type: OrderType::StopMarket,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[rstest]
+    fn test_delta_within_band_not_hedged(equity_aapl: Equity) {
+        let ctx = context(equity_aapl, OrderType::Market);
+
+        assert!(ctx.hedger.handle_greeks(&update("AAPL", 49.0)).is_none());
+        assert!(ctx.hedger.handle_greeks(&update("MSFT", 500.0)).is_none());
+        assert!(get_saved_messages::<TradingCommand>(ctx.commands).is_empty());
+    }
+
+    #[rstest]
+    fn test_hedges_delta_outside_band_on_greeks_topic(equity_aapl: Equity) {
+        let ctx = context(equity_aapl, OrderType::Market);
+        ctx.hedger.start();
+
+        let msgbus = ctx.msgbus.borrow();
+        let update = update("AAPL", 120.4);
+        msgbus.publish(&msgbus.switchboard.greeks_topic, &update as &dyn Any);
+
+        let commands = get_saved_messages::<TradingCommand>(ctx.commands.clone());
+        assert_eq!(commands.len(), 1);
+        let TradingCommand::SubmitOrder(command) = &commands[0] else {
+            panic!("Expected SubmitOrder");
+        };
+        assert_eq!(command.order.order_type(), OrderType::Market);
+        assert_eq!(command.order.order_side(), OrderSide::Sell);
+        assert_eq!(command.order.quantity(), Quantity::from(120));
+        assert_eq!(command.client_id, ClientId::from("XNAS"));
+        assert_eq!(
+            command.exec_algorith_id,
+            Some(ExecAlgorithmId::from(DELTA_HEDGER_ID))
+        );
+    }
+
+    #[rstest]
+    fn test_limit_hedge_at_opposite_side_and_one_order_working(equity_aapl: Equity) {
+        let ctx = context(equity_aapl, OrderType::Limit);
+
+        let command = ctx.hedger.handle_greeks(&update("AAPL", -80.0)).unwrap();
+        assert_eq!(command.order.order_side(), OrderSide::Buy);
+        assert_eq!(command.order.price(), Some(Price::from("150.01")));
+
+        // The hedge order is working
+        ctx.cache
+            .borrow_mut()
+            .add_order(command.order, None, None, false)
+            .unwrap();
+        assert!(ctx.hedger.handle_greeks(&update("AAPL", -80.0)).is_none());
+        assert_eq!(get_saved_messages::<TradingCommand>(ctx.commands).len(), 1);
+    }
+
+    #[rstest]
+    fn test_client_order_id_sequence_persisted_across_restarts(equity_aapl: Equity) {
+        let ctx = context(equity_aapl, OrderType::Market);
+        let first = ctx.hedger.handle_greeks(&update("AAPL", 120.0)).unwrap();
+
+        let restarted = DeltaHedger::new(
+            ctx.hedger.config().clone(),
+            get_atomic_clock_static(),
+            ctx.cache.clone(),
+            ctx.msgbus.clone(),
+        )
+        .unwrap();
+        let second = restarted.handle_greeks(&update("AAPL", 120.0)).unwrap();
+
+        assert_eq!(
+            first.client_order_id,
+            ClientOrderId::from("O-19700101-000000-001-001-1")
+        );
+        assert_eq!(
+            second.client_order_id,
+            ClientOrderId::from("O-19700101-000000-001-001-2")
+        );
+    }
+}
@@ -0,0 +1,869 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `IdempotentCommandLayer` which prevents duplicate orders after network errors.
+//!
+//! Submit and cancel commands are assigned a [`DedupKey`] from their client order ID, and a
+//! command with the key of one already sent is dropped. A command with no response from the
+//! venue within the command timeout has an unknown outcome: rather than being resent blindly,
+//! the order is queried, and the command is only resent once the venue order status shows it
+//! did not take effect.
+//!
+//! Once registered in front of the `ExecutionEngine`, commands sent to the engine pass through
+//! the layer, which resolves them from the order events sent to the engine and the order
+//! statuses published on the order status topic.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt::Display,
+    rc::Rc,
+};
+
+use nautilus_common::{
+    clock::Clock,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::Data, enums::OrderStatus, events::order::OrderEventAny, identifiers::ClientOrderId,
+};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use ustr::Ustr;
+
+use crate::messages::{QueryOrder, TradingCommand};
+
+/// Configuration for `IdempotentCommandLayer` instances.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// The timeout (milliseconds) for a response from the venue before a command's outcome is
+    /// unknown.
+    pub command_timeout_ms: u64,
+    /// The maximum number of queries and resends for a command before it fails.
+    pub max_retries: u32,
+    /// The time (milliseconds) resolved commands are retained for deduplication before
+    /// eviction.
+    pub resolved_retention_ms: u64,
+}
+
+impl Default for IdempotencyConfig {
+    /// Creates a new default [`IdempotencyConfig`] instance.
+    fn default() -> Self {
+        Self {
+            command_timeout_ms: 5_000,
+            max_retries: 3,
+            resolved_retention_ms: 60_000,
+        }
+    }
+}
+
+/// The kind of an idempotent order command.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum CommandKind {
+    Submit,
+    Cancel,
+}
+
+/// The deduplication key of an order command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DedupKey {
+    pub kind: CommandKind,
+    pub client_order_id: ClientOrderId,
+}
+
+impl DedupKey {
+    /// Returns the deduplication key for the given `command`, if it is a submit or cancel.
+    #[must_use]
+    pub const fn from_command(command: &TradingCommand) -> Option<Self> {
+        let (kind, client_order_id) = match command {
+            TradingCommand::SubmitOrder(command) => (CommandKind::Submit, command.client_order_id),
+            TradingCommand::CancelOrder(command) => (CommandKind::Cancel, command.client_order_id),
+            _ => return None,
+        };
+        Some(Self {
+            kind,
+            client_order_id,
+        })
+    }
+}
+
+impl Display for DedupKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.kind, self.client_order_id)
+    }
+}
+
+/// The outcome of an idempotent order command.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum CommandOutcome {
+    /// The command was sent and is awaiting a response from the venue.
+    Pending,
+    /// The command timed out and the order was queried to reconcile its outcome.
+    Unknown,
+    /// The venue confirmed the command took effect.
+    Confirmed,
+    /// The command was rejected.
+    Rejected,
+    /// The outcome could not be reconciled within the maximum retries.
+    Failed,
+}
+
+impl CommandOutcome {
+    /// Returns whether the command is awaiting a response.
+    #[must_use]
+    pub const fn is_inflight(self) -> bool {
+        matches!(self, Self::Pending | Self::Unknown)
+    }
+}
+
+#[derive(Debug)]
+struct TrackedCommand {
+    command: TradingCommand,
+    outcome: CommandOutcome,
+    retries: u32,
+    ts_updated: UnixNanos,
+}
+
+/// Represents the venue status of an order, as returned for an order query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderStatusUpdate {
+    pub client_order_id: ClientOrderId,
+    /// The venue order status, `None` if the venue has no such order.
+    pub status: Option<OrderStatus>,
+    pub ts_event: UnixNanos,
+}
+
+/// Sends order commands to the `ExecutionEngine` at most once per deduplication key, and
+/// reconciles commands with unknown outcomes against the venue order status.
+///
+/// Resolved commands are retained for the configured retention period, so duplicates of
+/// confirmed commands are still dropped, and then evicted.
+pub struct IdempotentCommandLayer {
+    msgbus: Rc<RefCell<MessageBus>>,
+    config: IdempotencyConfig,
+    commands: RefCell<HashMap<DedupKey, TrackedCommand>>,
+    duplicates: Cell<usize>,
+    engine_handler: Option<ShareableMessageHandler>,
+}
+
+impl IdempotentCommandLayer {
+    /// Creates a new [`IdempotentCommandLayer`] instance, which sends commands to the
+    /// `exec_engine_execute` endpoint.
+    pub fn new(msgbus: Rc<RefCell<MessageBus>>, config: IdempotencyConfig) -> Self {
+        Self {
+            msgbus,
+            config,
+            commands: RefCell::new(HashMap::new()),
+            duplicates: Cell::new(0),
+            engine_handler: None,
+        }
+    }
+
+    /// Creates a new [`IdempotentCommandLayer`] and registers it in front of the
+    /// `ExecutionEngine` on the `msgbus`.
+    ///
+    /// The layer intercepts the `exec_engine_execute` and `exec_engine_process` endpoints,
+    /// forwarding to the handlers previously registered there, so it must be registered after
+    /// the execution engine. Order statuses are received on the `order_status_topic`, and
+    /// timeouts are checked on a timer at the command timeout interval.
+    pub fn register(
+        clock: Rc<RefCell<dyn Clock>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        config: IdempotencyConfig,
+    ) -> Rc<Self> {
+        let (execute_endpoint, process_endpoint, status_topic) = {
+            let msgbus = msgbus.borrow();
+            let switchboard = &msgbus.switchboard;
+            (
+                switchboard.exec_engine_execute,
+                switchboard.exec_engine_process,
+                switchboard.order_status_topic,
+            )
+        };
+        let (engine_handler, process_handler) = {
+            let msgbus = msgbus.borrow();
+            (
+                msgbus.get_endpoint(execute_endpoint).cloned(),
+                msgbus.get_endpoint(process_endpoint).cloned(),
+            )
+        };
+
+        let interval_ns = config.command_timeout_ms * NANOSECONDS_IN_MILLISECOND;
+        let layer = Rc::new(Self {
+            engine_handler,
+            ..Self::new(msgbus.clone(), config)
+        });
+
+        let execute_handler = IdempotencyHandler {
+            id: Ustr::from("IdempotentCommandLayer.execute"),
+            layer: layer.clone(),
+            clock: clock.clone(),
+            inner: None,
+        };
+        let process_handler = IdempotencyHandler {
+            id: Ustr::from("IdempotentCommandLayer.process"),
+            layer: layer.clone(),
+            clock: clock.clone(),
+            inner: process_handler,
+        };
+        {
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.register(
+                execute_endpoint,
+                ShareableMessageHandler(Rc::new(execute_handler.clone())),
+            );
+            msgbus.register(
+                process_endpoint,
+                ShareableMessageHandler(Rc::new(process_handler)),
+            );
+            msgbus.subscribe(
+                status_topic,
+                ShareableMessageHandler(Rc::new(execute_handler)),
+                None,
+            );
+        }
+
+        let timer_layer = layer.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            timer_layer.check_timeouts(event.ts_event);
+        }));
+        let mut clock = clock.borrow_mut();
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            "IdempotentCommandLayer|timeouts",
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        );
+
+        layer
+    }
+
+    /// Returns the outcome of the command with the given `key`, if tracked.
+    #[must_use]
+    pub fn outcome(&self, key: &DedupKey) -> Option<CommandOutcome> {
+        self.commands
+            .borrow()
+            .get(key)
+            .map(|tracked| tracked.outcome)
+    }
+
+    /// Returns the count of tracked commands.
+    #[must_use]
+    pub fn tracked_count(&self) -> usize {
+        self.commands.borrow().len()
+    }
+
+    /// Returns the count of duplicate commands dropped.
+    #[must_use]
+    pub fn duplicates(&self) -> usize {
+        self.duplicates.get()
+    }
+
+    /// Sends the submit or cancel `command` at `ts_now`, returning whether it was sent or
+    /// dropped as a duplicate.
+    ///
+    /// A command with the key of a rejected or failed command is sent again.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the command is not a submit or cancel.
+    pub fn execute(&self, command: TradingCommand, ts_now: UnixNanos) -> anyhow::Result<bool> {
+        let Some(key) = DedupKey::from_command(&command) else {
+            anyhow::bail!("Command {command} has no deduplication key");
+        };
+        {
+            let mut commands = self.commands.borrow_mut();
+            if let Some(tracked) = commands.get(&key) {
+                if matches!(
+                    tracked.outcome,
+                    CommandOutcome::Pending | CommandOutcome::Unknown | CommandOutcome::Confirmed
+                ) {
+                    log::warn!("Dropping duplicate command {key}, was {}", tracked.outcome);
+                    self.duplicates.set(self.duplicates.get() + 1);
+                    return Ok(false);
+                }
+            }
+            commands.insert(
+                key,
+                TrackedCommand {
+                    command: command.clone(),
+                    outcome: CommandOutcome::Pending,
+                    retries: 0,
+                    ts_updated: ts_now,
+                },
+            );
+        }
+
+        self.send(&command);
+        Ok(true)
+    }
+
+    /// Queries the orders of commands with no response within the command timeout at `ts_now`,
+    /// returning the count of commands which timed out.
+    ///
+    /// A command which times out after the maximum retries fails. Resolved commands which
+    /// were last updated before the retention period are evicted.
+    pub fn check_timeouts(&self, ts_now: UnixNanos) -> usize {
+        let timeout_ns = self.config.command_timeout_ms * NANOSECONDS_IN_MILLISECOND;
+        let retention_ns = self.config.resolved_retention_ms * NANOSECONDS_IN_MILLISECOND;
+        let mut queries = Vec::new();
+        let mut timed_out = 0;
+        {
+            let mut commands = self.commands.borrow_mut();
+            commands.retain(|key, tracked| {
+                let elapsed_ns = ts_now.as_u64().saturating_sub(tracked.ts_updated.as_u64());
+                if !tracked.outcome.is_inflight() {
+                    return elapsed_ns < retention_ns;
+                }
+                if elapsed_ns < timeout_ns {
+                    return true;
+                }
+                timed_out += 1;
+
+                if tracked.retries >= self.config.max_retries {
+                    log::error!(
+                        "Command {key} outcome unknown after {} retries",
+                        tracked.retries
+                    );
+                    tracked.outcome = CommandOutcome::Failed;
+                    tracked.ts_updated = ts_now;
+                    return true;
+                }
+                log::warn!("Command {key} timed out, querying order status");
+                tracked.outcome = CommandOutcome::Unknown;
+                tracked.retries += 1;
+                tracked.ts_updated = ts_now;
+                queries.push(query_order(&tracked.command, ts_now));
+                true
+            });
+        }
+
+        for query in &queries {
+            self.send(query);
+        }
+        timed_out
+    }
+
+    /// Reconciles commands with unknown outcomes for the order with the given `client_order_id`
+    /// against its venue order `status` at `ts_now`, where `None` means the venue has no such
+    /// order.
+    ///
+    /// A submit is resent if the order is not found, and a cancel is resent if the order is
+    /// still open.
+    pub fn on_order_status(
+        &self,
+        client_order_id: ClientOrderId,
+        status: Option<OrderStatus>,
+        ts_now: UnixNanos,
+    ) {
+        let mut resends = Vec::new();
+        {
+            let mut commands = self.commands.borrow_mut();
+            for kind in [CommandKind::Submit, CommandKind::Cancel] {
+                let key = DedupKey {
+                    kind,
+                    client_order_id,
+                };
+                let Some(tracked) = commands.get_mut(&key) else {
+                    continue;
+                };
+                if tracked.outcome != CommandOutcome::Unknown {
+                    continue;
+                }
+
+                tracked.ts_updated = ts_now;
+                let resend = match (kind, status) {
+                    (CommandKind::Submit, None) => true,
+                    (CommandKind::Submit, Some(_)) => false,
+                    (CommandKind::Cancel, None) => {
+                        log::warn!("Command {key} rejected, order not found");
+                        tracked.outcome = CommandOutcome::Rejected;
+                        continue;
+                    }
+                    (CommandKind::Cancel, Some(status)) => !is_closed(status),
+                };
+                if resend {
+                    log::info!("Resending command {key}, did not take effect");
+                    tracked.outcome = CommandOutcome::Pending;
+                    resends.push(tracked.command.clone());
+                } else {
+                    log::info!("Command {key} confirmed by order status");
+                    tracked.outcome = CommandOutcome::Confirmed;
+                }
+            }
+        }
+
+        for command in &resends {
+            self.send(command);
+        }
+    }
+
+    /// Resolves the commands for the order of the given `event`.
+    pub fn on_order_event(&self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        let submit_outcome = match event {
+            OrderEventAny::Denied(_) | OrderEventAny::Rejected(_) => Some(CommandOutcome::Rejected),
+            OrderEventAny::Accepted(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_)
+            | OrderEventAny::Triggered(_)
+            | OrderEventAny::Updated(_)
+            | OrderEventAny::PartiallyFilled(_)
+            | OrderEventAny::Filled(_) => Some(CommandOutcome::Confirmed),
+            _ => None,
+        };
+        let cancel_outcome = match event {
+            OrderEventAny::CancelRejected(_) => Some(CommandOutcome::Rejected),
+            OrderEventAny::Canceled(_) | OrderEventAny::Expired(_) | OrderEventAny::Filled(_) => {
+                Some(CommandOutcome::Confirmed)
+            }
+            _ => None,
+        };
+
+        let mut commands = self.commands.borrow_mut();
+        for (kind, outcome) in [
+            (CommandKind::Submit, submit_outcome),
+            (CommandKind::Cancel, cancel_outcome),
+        ] {
+            let key = DedupKey {
+                kind,
+                client_order_id,
+            };
+            if let (Some(outcome), Some(tracked)) = (outcome, commands.get_mut(&key)) {
+                if tracked.outcome.is_inflight() {
+                    tracked.outcome = outcome;
+                    tracked.ts_updated = event.ts_event();
+                }
+            }
+        }
+    }
+
+    fn send(&self, command: &TradingCommand) {
+        match &self.engine_handler {
+            Some(handler) => handler.0.handle(command as &dyn Any),
+            None => {
+                let msgbus = self.msgbus.borrow();
+                msgbus.send(&msgbus.switchboard.exec_engine_execute, command as &dyn Any);
+            }
+        }
+    }
+}
+
+// Passes commands, events and order statuses through the layer, forwarding events to the
+// `inner` handler
+#[derive(Clone)]
+struct IdempotencyHandler {
+    id: Ustr,
+    layer: Rc<IdempotentCommandLayer>,
+    clock: Rc<RefCell<dyn Clock>>,
+    inner: Option<ShareableMessageHandler>,
+}
+
+impl MessageHandler for IdempotencyHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(command) = message.downcast_ref::<TradingCommand>() {
+            if DedupKey::from_command(command).is_some() {
+                let ts_now = self.clock.borrow().timestamp_ns();
+                if let Err(e) = self.layer.execute(command.clone(), ts_now) {
+                    log::error!("Error executing command: {e}");
+                }
+            } else {
+                self.layer.send(command);
+            }
+        } else if let Some(event) = message.downcast_ref::<OrderEventAny>() {
+            self.layer.on_order_event(event);
+        } else if let Some(update) = message.downcast_ref::<OrderStatusUpdate>() {
+            self.layer
+                .on_order_status(update.client_order_id, update.status, update.ts_event);
+            return;
+        }
+
+        if let Some(inner) = &self.inner {
+            inner.0.handle(message);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn query_order(command: &TradingCommand, ts_now: UnixNanos) -> TradingCommand {
+    let (trader_id, client_id, strategy_id, instrument_id, client_order_id, venue_order_id) =
+        match command {
+            TradingCommand::SubmitOrder(command) => (
+                command.trader_id,
+                command.client_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+            ),
+            TradingCommand::CancelOrder(command) => (
+                command.trader_id,
+                command.client_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+            ),
+            _ => unreachable!("Only submit and cancel commands are tracked"),
+        };
+    TradingCommand::QueryOrder(QueryOrder {
+        trader_id,
+        client_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        command_id: UUID4::new(),
+        ts_init: ts_now,
+    })
+}
+
+const fn is_closed(status: OrderStatus) -> bool {
+    matches!(
+        status,
+        OrderStatus::Denied
+            | OrderStatus::Rejected
+            | OrderStatus::Canceled
+            | OrderStatus::Expired
+            | OrderStatus::Filled
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+    use nautilus_model::{
+        enums::OrderType,
+        events::order::{OrderAccepted, OrderCancelRejected},
+        identifiers::{ClientId, InstrumentId, VenueOrderId},
+        orders::builder::OrderTestBuilder,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::{CancelOrder, SubmitOrder};
+
+    const MS: u64 = 1_000_000;
+
+    fn layer(config: IdempotencyConfig) -> (IdempotentCommandLayer, ShareableMessageHandler) {
+        let commands = get_message_saving_handler::<TradingCommand>(None);
+        let mut msgbus = MessageBus::default();
+        msgbus.register(msgbus.switchboard.exec_engine_execute, commands.clone());
+        (
+            IdempotentCommandLayer::new(Rc::new(RefCell::new(msgbus)), config),
+            commands,
+        )
+    }
+
+    fn submit_order() -> TradingCommand {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("BTCUSDT-PERP.BINANCE"))
+            .client_order_id(ClientOrderId::from("O-1"))
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(1))
+            .build();
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("BINANCE"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn cancel_order() -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder {
+            client_id: ClientId::from("BINANCE"),
+            instrument_id: InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            client_order_id: ClientOrderId::from("O-1"),
+            ..Default::default()
+        })
+    }
+
+    fn key(kind: CommandKind) -> DedupKey {
+        DedupKey {
+            kind,
+            client_order_id: ClientOrderId::from("O-1"),
+        }
+    }
+
+    fn sent(commands: ShareableMessageHandler) -> Vec<String> {
+        get_saved_messages::<TradingCommand>(commands)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[rstest]
+    fn test_duplicate_submit_dropped() {
+        let (layer, commands) = layer(IdempotencyConfig::default());
+
+        assert!(layer.execute(submit_order(), UnixNanos::default()).unwrap());
+        assert!(!layer.execute(submit_order(), UnixNanos::default()).unwrap());
+
+        assert_eq!(sent(commands), ["SubmitOrder"]);
+        assert_eq!(layer.duplicates(), 1);
+        assert_eq!(key(CommandKind::Submit).to_string(), "SUBMIT-O-1");
+        assert_eq!(
+            layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Pending)
+        );
+    }
+
+    #[rstest]
+    #[case(None, vec!["SubmitOrder", "QueryOrder", "SubmitOrder"], CommandOutcome::Pending)]
+    #[case(Some(OrderStatus::Accepted), vec!["SubmitOrder", "QueryOrder"], CommandOutcome::Confirmed)]
+    fn test_submit_timeout_reconciled_against_order_status(
+        #[case] status: Option<OrderStatus>,
+        #[case] expected_sent: Vec<&str>,
+        #[case] expected_outcome: CommandOutcome,
+    ) {
+        let (layer, commands) = layer(IdempotencyConfig::default());
+        let client_order_id = ClientOrderId::from("O-1");
+
+        layer.execute(submit_order(), UnixNanos::default()).unwrap();
+        assert_eq!(layer.check_timeouts(UnixNanos::from(4_999 * MS)), 0);
+        assert_eq!(layer.check_timeouts(UnixNanos::from(5_000 * MS)), 1);
+        assert_eq!(
+            layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Unknown)
+        );
+
+        layer.on_order_status(client_order_id, status, UnixNanos::from(5_100 * MS));
+        assert_eq!(sent(commands), expected_sent);
+        assert_eq!(
+            layer.outcome(&key(CommandKind::Submit)),
+            Some(expected_outcome)
+        );
+
+        layer.on_order_event(&OrderEventAny::Accepted(OrderAccepted {
+            client_order_id,
+            ..Default::default()
+        }));
+        assert_eq!(
+            layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Confirmed)
+        );
+    }
+
+    #[rstest]
+    fn test_cancel_resent_while_order_open() {
+        let (layer, commands) = layer(IdempotencyConfig::default());
+        let client_order_id = ClientOrderId::from("O-1");
+
+        layer.execute(cancel_order(), UnixNanos::default()).unwrap();
+        layer.check_timeouts(UnixNanos::from(5_000 * MS));
+        layer.on_order_status(
+            client_order_id,
+            Some(OrderStatus::Accepted),
+            UnixNanos::from(5_000 * MS),
+        );
+        assert_eq!(sent(commands), ["CancelOrder", "QueryOrder", "CancelOrder"]);
+
+        layer.on_order_event(&OrderEventAny::CancelRejected(OrderCancelRejected {
+            client_order_id,
+            ..Default::default()
+        }));
+        assert_eq!(
+            layer.outcome(&key(CommandKind::Cancel)),
+            Some(CommandOutcome::Rejected)
+        );
+        assert!(layer.execute(cancel_order(), UnixNanos::default()).unwrap());
+    }
+
+    #[rstest]
+    fn test_fails_after_max_retries() {
+        let (layer, commands) = layer(IdempotencyConfig {
+            command_timeout_ms: 100,
+            max_retries: 2,
+            ..Default::default()
+        });
+
+        layer.execute(submit_order(), UnixNanos::default()).unwrap();
+        for time_ms in [100, 200, 300] {
+            layer.check_timeouts(UnixNanos::from(time_ms * MS));
+        }
+
+        assert_eq!(sent(commands), ["SubmitOrder", "QueryOrder", "QueryOrder"]);
+        assert_eq!(
+            layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Failed)
+        );
+        assert!(layer.execute(cancel_order(), UnixNanos::default()).is_ok());
+        assert!(layer
+            .execute(
+                TradingCommand::QueryOrder(QueryOrder::default()),
+                UnixNanos::default()
+            )
+            .is_err());
+    }
+
+    struct TestContext {
+        clock: Rc<RefCell<TestClock>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        commands: ShareableMessageHandler,
+        events: ShareableMessageHandler,
+        layer: Rc<IdempotentCommandLayer>,
+    }
+
+    fn registered_layer() -> TestContext {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let commands = get_message_saving_handler::<TradingCommand>(None);
+        let events = get_message_saving_handler::<OrderEventAny>(None);
+        {
+            let mut msgbus = msgbus.borrow_mut();
+            let execute = msgbus.switchboard.exec_engine_execute;
+            let process = msgbus.switchboard.exec_engine_process;
+            msgbus.register(execute, commands.clone());
+            msgbus.register(process, events.clone());
+        }
+        let layer = IdempotentCommandLayer::register(
+            clock.clone(),
+            msgbus.clone(),
+            IdempotencyConfig::default(),
+        );
+        TestContext {
+            clock,
+            msgbus,
+            commands,
+            events,
+            layer,
+        }
+    }
+
+    fn send_execute(msgbus: &Rc<RefCell<MessageBus>>, command: &TradingCommand) {
+        let msgbus = msgbus.borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_execute, command as &dyn Any);
+    }
+
+    #[rstest]
+    fn test_registered_layer_passes_commands_and_events_to_engine() {
+        let ctx = registered_layer();
+        let accepted = OrderEventAny::Accepted(OrderAccepted {
+            client_order_id: ClientOrderId::from("O-1"),
+            ..Default::default()
+        });
+
+        send_execute(&ctx.msgbus, &submit_order());
+        send_execute(&ctx.msgbus, &submit_order());
+        send_execute(
+            &ctx.msgbus,
+            &TradingCommand::QueryOrder(QueryOrder::default()),
+        );
+        {
+            let msgbus = ctx.msgbus.borrow();
+            msgbus.send(
+                &msgbus.switchboard.exec_engine_process,
+                &accepted as &dyn Any,
+            );
+        }
+
+        assert_eq!(sent(ctx.commands), ["SubmitOrder", "QueryOrder"]);
+        assert_eq!(get_saved_messages::<OrderEventAny>(ctx.events), [accepted]);
+        assert_eq!(ctx.layer.duplicates(), 1);
+        assert_eq!(
+            ctx.layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Confirmed)
+        );
+    }
+
+    #[rstest]
+    fn test_registered_layer_reconciles_on_timer_and_order_status() {
+        let ctx = registered_layer();
+
+        send_execute(&ctx.msgbus, &submit_order());
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(5_000 * MS));
+        assert_eq!(
+            ctx.layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Unknown)
+        );
+
+        let update = OrderStatusUpdate {
+            client_order_id: ClientOrderId::from("O-1"),
+            status: None,
+            ts_event: UnixNanos::from(5_100 * MS),
+        };
+        {
+            let msgbus = ctx.msgbus.borrow();
+            msgbus.publish(&msgbus.switchboard.order_status_topic, &update as &dyn Any);
+        }
+
+        assert_eq!(
+            sent(ctx.commands),
+            ["SubmitOrder", "QueryOrder", "SubmitOrder"]
+        );
+        assert_eq!(
+            ctx.layer.outcome(&key(CommandKind::Submit)),
+            Some(CommandOutcome::Pending)
+        );
+    }
+
+    #[rstest]
+    fn test_resolved_commands_evicted_after_retention() {
+        let (layer, _) = layer(IdempotencyConfig::default());
+
+        layer.execute(submit_order(), UnixNanos::default()).unwrap();
+        layer.on_order_event(&OrderEventAny::Accepted(OrderAccepted {
+            client_order_id: ClientOrderId::from("O-1"),
+            ts_event: UnixNanos::from(MS),
+            ..Default::default()
+        }));
+
+        layer.check_timeouts(UnixNanos::from(60_000 * MS));
+        assert_eq!(layer.tracked_count(), 1);
+        layer.check_timeouts(UnixNanos::from(60_001 * MS));
+        assert_eq!(layer.tracked_count(), 0);
+    }
+}
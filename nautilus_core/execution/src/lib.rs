@@ -25,9 +25,22 @@
 //! for the main `nautilus_trader` Python package, or as part of a Rust only build.
 //!
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
+//! - `grpc`: Enables the engine control gRPC service from `tonic`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod chaos;
 pub mod client;
+pub mod conditional;
+pub mod drop_copy;
 pub mod engine;
+pub mod expiry;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod hedger;
+pub mod idempotency;
 pub mod matching_core;
 pub mod messages;
+pub mod reports;
+pub mod router;
+pub mod self_trade;
+pub mod statements;
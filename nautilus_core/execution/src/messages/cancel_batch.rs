@@ -20,7 +20,7 @@ use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::identifiers::{ClientId, InstrumentId, StrategyId, TraderId};
 use serde::{Deserialize, Serialize};
 
-use super::cancel::CancelOrder;
+use super::{cancel::CancelOrder, BatchSemantics};
 
 #[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
@@ -31,6 +31,7 @@ pub struct BatchCancelOrders {
     pub strategy_id: StrategyId,
     pub instrument_id: InstrumentId,
     pub cancels: Vec<CancelOrder>,
+    pub semantics: BatchSemantics,
     pub command_id: UUID4,
     pub ts_init: UnixNanos,
 }
@@ -44,6 +45,7 @@ impl BatchCancelOrders {
         strategy_id: StrategyId,
         instrument_id: InstrumentId,
         cancels: Vec<CancelOrder>,
+        semantics: BatchSemantics,
         command_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
@@ -53,6 +55,7 @@ impl BatchCancelOrders {
             strategy_id,
             instrument_id,
             cancels,
+            semantics,
             command_id,
             ts_init,
         })
@@ -63,8 +66,10 @@ impl Display for BatchCancelOrders {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "BatchCancelOrders(instrument_id={}, cancels=TBD)",
+            "BatchCancelOrders(instrument_id={}, cancels={}, semantics={})",
             self.instrument_id,
+            self.cancels.len(),
+            self.semantics,
         )
     }
 }
@@ -19,22 +19,65 @@ pub mod cancel;
 pub mod cancel_all;
 pub mod cancel_batch;
 pub mod modify;
+pub mod modify_batch;
 pub mod query;
 pub mod submit;
 pub mod submit_list;
 
-use nautilus_model::identifiers::{ClientId, InstrumentId};
+use nautilus_model::identifiers::{ClientId, InstrumentId, StrategyId};
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
 // Re-exports
 pub use self::{
     cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
-    modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
+    modify::ModifyOrder, modify_batch::BatchModifyOrders, query::QueryOrder, submit::SubmitOrder,
+    submit_list::SubmitOrderList,
 };
 
+/// The semantics for executing a batch of order commands.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum BatchSemantics {
+    /// The batch must be executed as a whole by a native batch endpoint of the venue, otherwise
+    /// it is rejected.
+    Atomic,
+    /// The batch is executed by a native batch endpoint where supported, otherwise as individual
+    /// commands which each succeed or fail on their own.
+    #[default]
+    BestEffort,
+}
+
+/// How a batch of order commands is dispatched to an execution client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchDispatch {
+    /// As a single request to the native batch endpoint of the venue.
+    Native,
+    /// As individual commands.
+    Individual,
+}
+
+impl BatchSemantics {
+    /// Returns how a batch with these semantics is dispatched, given whether the venue supports
+    /// a native batch endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the semantics are atomic and no native batch endpoint is supported.
+    pub fn dispatch(self, native_supported: bool) -> anyhow::Result<BatchDispatch> {
+        match (self, native_supported) {
+            (_, true) => Ok(BatchDispatch::Native),
+            (Self::BestEffort, false) => Ok(BatchDispatch::Individual),
+            (Self::Atomic, false) => {
+                anyhow::bail!("Atomic batch not supported without a native batch endpoint")
+            }
+        }
+    }
+}
+
 // TODO
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, Display)]
+#[derive(Clone, Debug, Display, Serialize, Deserialize)]
 pub enum TradingCommand {
     SubmitOrder(SubmitOrder),
     SubmitOrderList(SubmitOrderList),
@@ -42,6 +85,7 @@ pub enum TradingCommand {
     CancelOrder(CancelOrder),
     CancelAllOrders(CancelAllOrders),
     BatchCancelOrders(BatchCancelOrders),
+    BatchModifyOrders(BatchModifyOrders),
     QueryOrder(QueryOrder),
 }
 
@@ -55,10 +99,25 @@ impl TradingCommand {
             Self::CancelOrder(command) => command.client_id,
             Self::CancelAllOrders(command) => command.client_id,
             Self::BatchCancelOrders(command) => command.client_id,
+            Self::BatchModifyOrders(command) => command.client_id,
             Self::QueryOrder(command) => command.client_id,
         }
     }
 
+    #[must_use]
+    pub const fn strategy_id(&self) -> StrategyId {
+        match self {
+            Self::SubmitOrder(command) => command.strategy_id,
+            Self::SubmitOrderList(command) => command.strategy_id,
+            Self::ModifyOrder(command) => command.strategy_id,
+            Self::CancelOrder(command) => command.strategy_id,
+            Self::CancelAllOrders(command) => command.strategy_id,
+            Self::BatchCancelOrders(command) => command.strategy_id,
+            Self::BatchModifyOrders(command) => command.strategy_id,
+            Self::QueryOrder(command) => command.strategy_id,
+        }
+    }
+
     #[must_use]
     pub const fn instrument_id(&self) -> InstrumentId {
         match self {
@@ -68,7 +127,40 @@ impl TradingCommand {
             Self::CancelOrder(command) => command.instrument_id,
             Self::CancelAllOrders(command) => command.instrument_id,
             Self::BatchCancelOrders(command) => command.instrument_id,
+            Self::BatchModifyOrders(command) => command.instrument_id,
             Self::QueryOrder(command) => command.instrument_id,
         }
     }
+
+    /// Returns whether the command cancels orders.
+    #[must_use]
+    pub const fn is_cancel(&self) -> bool {
+        matches!(
+            self,
+            Self::CancelOrder(_) | Self::CancelAllOrders(_) | Self::BatchCancelOrders(_)
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(BatchSemantics::Atomic, true, Some(BatchDispatch::Native))]
+    #[case(BatchSemantics::Atomic, false, None)]
+    #[case(BatchSemantics::BestEffort, true, Some(BatchDispatch::Native))]
+    #[case(BatchSemantics::BestEffort, false, Some(BatchDispatch::Individual))]
+    fn test_batch_dispatch(
+        #[case] semantics: BatchSemantics,
+        #[case] native_supported: bool,
+        #[case] expected: Option<BatchDispatch>,
+    ) {
+        assert_eq!(semantics.dispatch(native_supported).ok(), expected);
+    }
 }
@@ -25,6 +25,8 @@ use nautilus_model::{
 };
 use serde::{Deserialize, Serialize};
 
+use super::BatchSemantics;
+
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub struct SubmitOrderList {
@@ -37,6 +39,7 @@ pub struct SubmitOrderList {
     pub order_list: OrderList,
     pub exec_algorith_id: Option<ExecAlgorithmId>,
     pub position_id: Option<PositionId>,
+    pub semantics: BatchSemantics,
     pub command_id: UUID4,
     pub ts_init: UnixNanos,
 }
@@ -54,6 +57,7 @@ impl SubmitOrderList {
         order_list: OrderList,
         exec_algorith_id: Option<ExecAlgorithmId>,
         position_id: Option<PositionId>,
+        semantics: BatchSemantics,
         command_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
@@ -67,6 +71,7 @@ impl SubmitOrderList {
             order_list,
             exec_algorith_id,
             position_id,
+            semantics,
             command_id,
             ts_init,
         })
@@ -77,10 +82,11 @@ impl Display for SubmitOrderList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "SubmitOrderList(instrument_id={}, order_list=TBD, position_id={})",
+            "SubmitOrderList(instrument_id={}, order_list=TBD, position_id={}, semantics={})",
             self.instrument_id,
             self.position_id
                 .map_or("None".to_string(), |position_id| format!("{position_id}")),
+            self.semantics,
         )
     }
 }
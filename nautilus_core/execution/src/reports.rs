@@ -0,0 +1,390 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution reports aggregating order fills, commissions and positions, for export of
+//! execution quality data as JSON or CSV.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+    events::order::{filled::OrderFilled, OrderEventAny},
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
+    },
+    orders::any::OrderAny,
+    position::Position,
+    types::{
+        currency::Currency, metadata::OrderMetadata, money::Money, price::Price, quantity::Quantity,
+    },
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// Represents a report of a single order fill.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderFillReport {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: VenueOrderId,
+    pub account_id: AccountId,
+    pub trade_id: TradeId,
+    pub position_id: Option<PositionId>,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub liquidity_side: LiquiditySide,
+    /// The order price (if the order has one), for comparison with the fill price.
+    pub order_price: Option<Price>,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub commission: Option<Money>,
+    /// The metadata of the order, e.g. the signal or algorithm which created it.
+    pub metadata: OrderMetadata,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderFillReport {
+    /// Creates a new [`OrderFillReport`] instance from the given `fill` and its order price.
+    #[must_use]
+    pub fn new(fill: &OrderFilled, order_price: Option<Price>) -> Self {
+        Self {
+            trader_id: fill.trader_id,
+            strategy_id: fill.strategy_id,
+            instrument_id: fill.instrument_id,
+            client_order_id: fill.client_order_id,
+            venue_order_id: fill.venue_order_id,
+            account_id: fill.account_id,
+            trade_id: fill.trade_id,
+            position_id: fill.position_id,
+            order_side: fill.order_side,
+            order_type: fill.order_type,
+            liquidity_side: fill.liquidity_side,
+            order_price,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            commission: fill.commission,
+            metadata: fill.metadata,
+            ts_event: fill.ts_event,
+            ts_init: fill.ts_init,
+        }
+    }
+}
+
+/// Represents a report of the commissions paid for an instrument in a single currency.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommissionReport {
+    pub instrument_id: InstrumentId,
+    pub currency: Currency,
+    pub fill_count: usize,
+    pub maker_commission: Money,
+    pub taker_commission: Money,
+    pub total_commission: Money,
+}
+
+impl CommissionReport {
+    fn new(instrument_id: InstrumentId, currency: Currency) -> Self {
+        let zero = Money::new(0.0, currency);
+        Self {
+            instrument_id,
+            currency,
+            fill_count: 0,
+            maker_commission: zero,
+            taker_commission: zero,
+            total_commission: zero,
+        }
+    }
+}
+
+/// Represents a report of the status of a position.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PositionStatusReport {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub position_id: PositionId,
+    pub account_id: AccountId,
+    pub side: PositionSide,
+    pub quantity: Quantity,
+    pub peak_qty: Quantity,
+    pub avg_px_open: f64,
+    pub avg_px_close: Option<f64>,
+    pub realized_return: f64,
+    pub realized_pnl: Option<Money>,
+    pub event_count: usize,
+    pub ts_opened: UnixNanos,
+    pub ts_closed: Option<UnixNanos>,
+    pub duration_ns: u64,
+    /// The metadata of the opening order, extended with new keys from subsequent fills.
+    pub metadata: OrderMetadata,
+}
+
+impl From<&Position> for PositionStatusReport {
+    fn from(position: &Position) -> Self {
+        Self {
+            trader_id: position.trader_id,
+            strategy_id: position.strategy_id,
+            instrument_id: position.instrument_id,
+            position_id: position.id,
+            account_id: position.account_id,
+            side: position.side,
+            quantity: position.quantity,
+            peak_qty: position.peak_qty,
+            avg_px_open: position.avg_px_open,
+            avg_px_close: position.avg_px_close,
+            realized_return: position.realized_return,
+            realized_pnl: position.realized_pnl,
+            event_count: position.event_count(),
+            ts_opened: position.ts_opened,
+            ts_closed: position.ts_closed,
+            duration_ns: position.duration_ns,
+            metadata: position.metadata,
+        }
+    }
+}
+
+/// Generates fill reports for all fills of the given `orders`, ordered by event time.
+#[must_use]
+pub fn generate_fill_reports(orders: &[&OrderAny]) -> Vec<OrderFillReport> {
+    let mut reports: Vec<OrderFillReport> = orders
+        .iter()
+        .flat_map(|order| {
+            order.events().into_iter().filter_map(|event| match event {
+                OrderEventAny::Filled(fill) => {
+                    let mut report = OrderFillReport::new(fill, order.price());
+                    report.metadata = report.metadata.merge(&order.metadata());
+                    Some(report)
+                }
+                _ => None,
+            })
+        })
+        .collect();
+    reports.sort_by_key(|report| report.ts_event);
+    reports
+}
+
+/// Generates commission reports from the given `fills`, one per instrument and commission
+/// currency, ordered by instrument ID and currency code.
+#[must_use]
+pub fn generate_commission_reports(fills: &[OrderFillReport]) -> Vec<CommissionReport> {
+    let mut reports: BTreeMap<(InstrumentId, Ustr), CommissionReport> = BTreeMap::new();
+    for fill in fills {
+        let Some(commission) = fill.commission else {
+            continue;
+        };
+        let report = reports
+            .entry((fill.instrument_id, commission.currency.code))
+            .or_insert_with(|| CommissionReport::new(fill.instrument_id, commission.currency));
+        report.fill_count += 1;
+        report.total_commission += commission;
+        match fill.liquidity_side {
+            LiquiditySide::Maker => report.maker_commission += commission,
+            LiquiditySide::Taker => report.taker_commission += commission,
+            LiquiditySide::NoLiquiditySide => {}
+        }
+    }
+    reports.into_values().collect()
+}
+
+/// Generates position status reports for the given `positions`, ordered by opening time.
+#[must_use]
+pub fn generate_position_reports(positions: &[&Position]) -> Vec<PositionStatusReport> {
+    let mut reports: Vec<PositionStatusReport> = positions
+        .iter()
+        .map(|position| PositionStatusReport::from(*position))
+        .collect();
+    reports.sort_by_key(|report| report.ts_opened);
+    reports
+}
+
+/// Serializes the given `reports` to a JSON array.
+pub fn reports_to_json<T: Serialize>(reports: &[T]) -> anyhow::Result<String> {
+    Ok(serde_json::to_string(reports)?)
+}
+
+/// Serializes the given `reports` to CSV, with a header row of the field names.
+pub fn reports_to_csv<T: Serialize>(reports: &[T]) -> anyhow::Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for report in reports {
+        writer.serialize(report)?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{
+            builder::OrderTestBuilder,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn filled_order(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        trade_id: &str,
+        price: &str,
+        liquidity_side: LiquiditySide,
+        ts_filled: u64,
+    ) -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from(100_000))
+            .client_order_id(ClientOrderId::new(format!("O-{trade_id}")))
+            .build();
+        let mut order = TestOrderStubs::make_accepted_order(&order);
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            Some(TradeId::new(trade_id)),
+            None,
+            Some(Price::from(price)),
+            None,
+            Some(liquidity_side),
+            None,
+            Some(ts_filled.into()),
+            None,
+        );
+        order.apply(fill).unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_generate_fill_and_commission_reports(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order1 = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            "2",
+            "0.80000",
+            LiquiditySide::Taker,
+            2,
+        );
+        let order2 = filled_order(
+            &instrument,
+            OrderSide::Sell,
+            "1",
+            "0.80010",
+            LiquiditySide::Maker,
+            1,
+        );
+
+        let fills = generate_fill_reports(&[&order1, &order2]);
+        let commissions = generate_commission_reports(&fills);
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].trade_id, TradeId::new("1"));
+        assert_eq!(fills[0].order_price, Some(Price::from("0.80010")));
+        assert_eq!(fills[1].order_side, OrderSide::Buy);
+        assert_eq!(commissions.len(), 1);
+        assert_eq!(commissions[0].fill_count, 2);
+        assert_eq!(commissions[0].maker_commission, Money::from("2 USD"));
+        assert_eq!(commissions[0].taker_commission, Money::from("2 USD"));
+        assert_eq!(commissions[0].total_commission, Money::from("4 USD"));
+    }
+
+    #[rstest]
+    fn test_generate_position_reports(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            "1",
+            "0.80000",
+            LiquiditySide::Taker,
+            1,
+        );
+        let OrderEventAny::Filled(fill) = order.last_event() else {
+            panic!("expected fill");
+        };
+        let position = Position::new(&instrument, *fill);
+
+        let reports = generate_position_reports(&[&position]);
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].side, PositionSide::Long);
+        assert_eq!(reports[0].quantity, Quantity::from(100_000));
+        assert_eq!(reports[0].avg_px_open, 0.8);
+        assert_eq!(reports[0].realized_pnl, Some(Money::from("-2 USD")));
+    }
+
+    #[rstest]
+    fn test_reports_carry_order_metadata(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut order = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            "1",
+            "0.80000",
+            LiquiditySide::Taker,
+            1,
+        );
+        let metadata: OrderMetadata = "algo=twap,signal=momentum".parse().unwrap();
+        order.set_metadata(metadata);
+        let OrderEventAny::Filled(mut fill) = *order.last_event() else {
+            panic!("expected fill");
+        };
+        fill.metadata = order.metadata();
+        let position = Position::new(&instrument, fill);
+
+        let fills = generate_fill_reports(&[&order]);
+        let positions = generate_position_reports(&[&position]);
+        let csv = reports_to_csv(&fills).unwrap();
+
+        assert_eq!(fills[0].metadata, metadata);
+        assert_eq!(positions[0].metadata, metadata);
+        assert!(csv.contains("\"algo=twap,signal=momentum\""), "{csv}");
+    }
+
+    #[rstest]
+    fn test_reports_to_json_and_csv(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            "1",
+            "0.80000",
+            LiquiditySide::Taker,
+            1,
+        );
+        let fills = generate_fill_reports(&[&order]);
+        let commissions = generate_commission_reports(&fills);
+
+        let json = reports_to_json(&fills).unwrap();
+        let deserialized: Vec<OrderFillReport> = serde_json::from_str(&json).unwrap();
+        let csv = reports_to_csv(&commissions).unwrap();
+
+        assert_eq!(deserialized, fills);
+        assert_eq!(
+            csv,
+            "instrument_id,currency,fill_count,maker_commission,taker_commission,total_commission\n\
+             AUD/USD.SIM,USD,1,0.00 USD,2.00 USD,2.00 USD\n"
+        );
+    }
+}
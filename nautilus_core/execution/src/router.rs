@@ -0,0 +1,590 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `SmartOrderRouter` for splitting parent orders across venues.
+//!
+//! The router walks the price levels of the order books for an instrument on each venue in
+//! order of their effective price, which includes the venue taker fee and a penalty for the
+//! venue latency, and allocates the parent quantity to the cheapest liquidity first. Each venue
+//! allocation is emitted as an IOC limit child order, spawned from the parent and tagged with
+//! its routing metadata.
+
+use std::collections::HashMap;
+
+use anyhow::bail;
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{OrderSide, TimeInForce},
+    identifiers::{ClientOrderId, ExecAlgorithmId, InstrumentId, Venue},
+    orderbook::book::OrderBook,
+    orders::{any::OrderAny, limit::LimitOrder},
+    types::{price::Price, quantity::Quantity},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// The execution algorithm ID set on child orders emitted by the router.
+pub const SMART_ORDER_ROUTER_ID: &str = "SOR";
+
+/// Configuration for routing orders to a venue.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VenueRoutingConfig {
+    /// The venue.
+    pub venue: Venue,
+    /// The taker fee rate for the venue, e.g. 0.0005 for 5 bps.
+    #[serde(default)]
+    pub taker_fee: Decimal,
+    /// The expected order round-trip latency for the venue (nanoseconds).
+    #[serde(default)]
+    pub latency_ns: u64,
+}
+
+/// Configuration for `SmartOrderRouter` instances.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SmartOrderRouterConfig {
+    /// The venues available for routing.
+    pub venues: Vec<VenueRoutingConfig>,
+    /// The expected adverse price move per millisecond of venue latency, in basis points, which
+    /// is added to the effective price of liquidity on slower venues.
+    #[serde(default)]
+    pub latency_penalty_bps_per_ms: f64,
+}
+
+/// Represents the quantity allocated to a venue by the router.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteAllocation {
+    /// The instrument ID on the venue.
+    pub instrument_id: InstrumentId,
+    /// The quantity allocated.
+    pub quantity: Quantity,
+    /// The worst price level the allocation is expected to fill at, used as the limit price.
+    pub limit_price: Price,
+    /// The expected average fill price.
+    pub avg_price: f64,
+    /// The expected fees, in the quote currency.
+    pub fees: f64,
+    /// The expected latency for the venue (nanoseconds).
+    pub latency_ns: u64,
+}
+
+/// Represents the split of a parent quantity across venues.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoutingPlan {
+    /// The side of the parent order.
+    pub side: OrderSide,
+    /// The allocations, in order of the best effective price on each venue.
+    pub allocations: Vec<RouteAllocation>,
+    /// The quantity which could not be routed for lack of liquidity within the limit price.
+    pub unrouted_qty: Quantity,
+    /// The expected average price including fees and the latency penalty.
+    pub avg_effective_price: f64,
+}
+
+impl RoutingPlan {
+    /// Returns the total quantity routed.
+    #[must_use]
+    pub fn routed_qty(&self) -> Quantity {
+        let precision = self
+            .allocations
+            .iter()
+            .map(|allocation| allocation.quantity.precision)
+            .max()
+            .unwrap_or(self.unrouted_qty.precision);
+        let raw = self
+            .allocations
+            .iter()
+            .map(|allocation| allocation.quantity.raw)
+            .sum();
+        Quantity::from_raw(raw, precision)
+    }
+}
+
+/// Splits parent orders across venues to minimize their expected cost.
+#[derive(Debug)]
+pub struct SmartOrderRouter {
+    config: SmartOrderRouterConfig,
+    venues: HashMap<Venue, VenueRoutingConfig>,
+    algorithm_id: ExecAlgorithmId,
+}
+
+struct Candidate<'a> {
+    venue: &'a VenueRoutingConfig,
+    instrument_id: InstrumentId,
+    price: Price,
+    size_raw: u64,
+    precision: u8,
+    effective_price: f64,
+}
+
+#[derive(Default)]
+struct Fill {
+    raw: u64,
+    precision: u8,
+    notional: f64,
+    effective_notional: f64,
+    worst_price: Option<Price>,
+}
+
+impl SmartOrderRouter {
+    /// Creates a new [`SmartOrderRouter`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no venues are configured, or a venue is configured more than once.
+    /// - If a taker fee is negative or the latency penalty is negative.
+    pub fn new(config: SmartOrderRouterConfig) -> anyhow::Result<Self> {
+        if config.venues.is_empty() {
+            bail!("No venues configured for routing");
+        }
+        if config.latency_penalty_bps_per_ms < 0.0 {
+            bail!("Latency penalty must not be negative");
+        }
+
+        let mut venues = HashMap::new();
+        for venue in &config.venues {
+            if venue.taker_fee.is_sign_negative() {
+                bail!("Taker fee for {} must not be negative", venue.venue);
+            }
+            if venues.insert(venue.venue, venue.clone()).is_some() {
+                bail!("Venue {} is configured more than once", venue.venue);
+            }
+        }
+
+        Ok(Self {
+            config,
+            venues,
+            algorithm_id: ExecAlgorithmId::new(SMART_ORDER_ROUTER_ID),
+        })
+    }
+
+    /// Returns the configuration for the router.
+    #[must_use]
+    pub const fn config(&self) -> &SmartOrderRouterConfig {
+        &self.config
+    }
+
+    /// Plans the split of `quantity` on the given `side` across the venue order `books`, taking
+    /// no liquidity beyond the `limit_price` (if any).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a book is for a venue with no routing configuration.
+    pub fn plan(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        limit_price: Option<Price>,
+        books: &[&OrderBook],
+    ) -> anyhow::Result<RoutingPlan> {
+        let is_buy = match side {
+            OrderSide::Buy => true,
+            OrderSide::Sell => false,
+            OrderSide::NoOrderSide => bail!("Cannot route an order with no side"),
+        };
+
+        let mut candidates = Vec::new();
+        for book in books {
+            let venue_id = book.instrument_id.venue;
+            let Some(venue) = self.venues.get(&venue_id) else {
+                bail!("No routing configuration for venue {venue_id}");
+            };
+            let cost_rate = venue.taker_fee.to_f64().unwrap_or_default()
+                + self.config.latency_penalty_bps_per_ms * venue.latency_ns as f64
+                    / 1_000_000.0
+                    / 10_000.0;
+
+            let levels: Vec<_> = if is_buy {
+                book.asks().collect()
+            } else {
+                book.bids().collect()
+            };
+            for level in levels {
+                let price = level.price.value;
+                let within_limit = limit_price.is_none_or(|limit| {
+                    if is_buy {
+                        price <= limit
+                    } else {
+                        price >= limit
+                    }
+                });
+                let Some(first) = level.first() else {
+                    continue;
+                };
+                if !within_limit {
+                    break; // Levels are ordered from the best price
+                }
+
+                let effective_price = if is_buy {
+                    price.as_f64() * (1.0 + cost_rate)
+                } else {
+                    price.as_f64() * (1.0 - cost_rate)
+                };
+                candidates.push(Candidate {
+                    venue,
+                    instrument_id: book.instrument_id,
+                    price,
+                    size_raw: level.size_raw(),
+                    precision: first.size.precision,
+                    effective_price,
+                });
+            }
+        }
+
+        // Best effective price first, stable for ties so faster listed venues win
+        candidates.sort_by(|a, b| {
+            let ordering = a.effective_price.total_cmp(&b.effective_price);
+            if is_buy {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        let mut remaining_raw = quantity.raw;
+        let mut fills: IndexMap<InstrumentId, (&VenueRoutingConfig, Fill)> = IndexMap::new();
+        for candidate in candidates {
+            if remaining_raw == 0 {
+                break;
+            }
+            let take_raw = remaining_raw.min(candidate.size_raw);
+            remaining_raw -= take_raw;
+
+            let take = Quantity::from_raw(take_raw, candidate.precision).as_f64();
+            let (_, fill) = fills
+                .entry(candidate.instrument_id)
+                .or_insert_with(|| (candidate.venue, Fill::default()));
+            fill.raw += take_raw;
+            fill.precision = fill.precision.max(candidate.precision);
+            fill.notional += take * candidate.price.as_f64();
+            fill.effective_notional += take * candidate.effective_price;
+            fill.worst_price = Some(candidate.price);
+        }
+
+        let precision = quantity.precision;
+        let mut total_qty = 0.0;
+        let mut total_effective = 0.0;
+        let allocations = fills
+            .into_iter()
+            .map(|(instrument_id, (venue, fill))| {
+                let quantity = Quantity::from_raw(fill.raw, fill.precision.max(precision));
+                let qty = quantity.as_f64();
+                total_qty += qty;
+                total_effective += fill.effective_notional;
+                RouteAllocation {
+                    instrument_id,
+                    quantity,
+                    limit_price: fill.worst_price.expect("Fill has a price"),
+                    avg_price: fill.notional / qty,
+                    fees: fill.notional * venue.taker_fee.to_f64().unwrap_or_default(),
+                    latency_ns: venue.latency_ns,
+                }
+            })
+            .collect();
+
+        Ok(RoutingPlan {
+            side,
+            allocations,
+            unrouted_qty: Quantity::from_raw(remaining_raw, precision),
+            avg_effective_price: if total_qty > 0.0 {
+                total_effective / total_qty
+            } else {
+                0.0
+            },
+        })
+    }
+
+    /// Routes the `parent` order across the venue order `books`, returning the routing plan and
+    /// an IOC limit child order for each venue allocation.
+    ///
+    /// Child orders are spawned from the parent, with the execution algorithm ID
+    /// [`SMART_ORDER_ROUTER_ID`], a `route:{venue}` tag, and the routing metadata in their
+    /// execution algorithm parameters.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the plan fails, or a child order is invalid.
+    pub fn route(
+        &self,
+        parent: &OrderAny,
+        books: &[&OrderBook],
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<(RoutingPlan, Vec<OrderAny>)> {
+        let plan = self.plan(
+            parent.order_side(),
+            parent.leaves_qty(),
+            parent.price(),
+            books,
+        )?;
+
+        let parent_id = parent.client_order_id();
+        let mut children = Vec::with_capacity(plan.allocations.len());
+        for (i, allocation) in plan.allocations.iter().enumerate() {
+            let venue = allocation.instrument_id.venue;
+            let params = HashMap::from([
+                (Ustr::from("route_venue"), venue.inner()),
+                (
+                    Ustr::from("expected_avg_px"),
+                    Ustr::from(&allocation.avg_price.to_string()),
+                ),
+                (
+                    Ustr::from("expected_fees"),
+                    Ustr::from(&allocation.fees.to_string()),
+                ),
+                (
+                    Ustr::from("latency_ns"),
+                    Ustr::from(&allocation.latency_ns.to_string()),
+                ),
+            ]);
+            let child = LimitOrder::new(
+                parent.trader_id(),
+                parent.strategy_id(),
+                allocation.instrument_id,
+                ClientOrderId::new(format!("{parent_id}-R{}", i + 1)),
+                parent.order_side(),
+                allocation.quantity,
+                allocation.limit_price,
+                TimeInForce::Ioc,
+                None,
+                false,
+                parent.is_reduce_only(),
+                false,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(self.algorithm_id),
+                Some(params),
+                Some(parent_id),
+                Some(vec![Ustr::from(&format!("route:{venue}"))]),
+                UUID4::new(),
+                ts_init,
+            )?;
+            children.push(OrderAny::Limit(child));
+        }
+
+        Ok((plan, children))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::order::BookOrder,
+        enums::{BookType, OrderType},
+        orders::builder::OrderTestBuilder,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn book(instrument_id: &str, asks: &[(&str, &str)], bids: &[(&str, &str)]) -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from(instrument_id), BookType::L2_MBP);
+        for (side, levels) in [(OrderSide::Sell, asks), (OrderSide::Buy, bids)] {
+            for (i, (price, size)) in levels.iter().enumerate() {
+                let order =
+                    BookOrder::new(side, Price::from(*price), Quantity::from(*size), i as u64);
+                book.add(order, 0, 0, UnixNanos::default());
+            }
+        }
+        book
+    }
+
+    fn router(latency_penalty_bps_per_ms: f64) -> SmartOrderRouter {
+        SmartOrderRouter::new(SmartOrderRouterConfig {
+            venues: vec![
+                VenueRoutingConfig {
+                    venue: Venue::from("BINANCE"),
+                    taker_fee: dec!(0.001),
+                    latency_ns: 1_000_000,
+                },
+                VenueRoutingConfig {
+                    venue: Venue::from("BYBIT"),
+                    taker_fee: dec!(0.0),
+                    latency_ns: 50_000_000,
+                },
+            ],
+            latency_penalty_bps_per_ms,
+        })
+        .unwrap()
+    }
+
+    fn books() -> (OrderBook, OrderBook) {
+        (
+            book(
+                "ETHUSDT.BINANCE",
+                &[("100.00", "1.0"), ("100.20", "5.0")],
+                &[("99.90", "2.0")],
+            ),
+            book(
+                "ETHUSDT.BYBIT",
+                &[("100.05", "2.0"), ("100.30", "5.0")],
+                &[("99.95", "1.0")],
+            ),
+        )
+    }
+
+    #[rstest]
+    fn test_new_validation() {
+        let venue = VenueRoutingConfig {
+            venue: Venue::from("BINANCE"),
+            taker_fee: dec!(0.001),
+            latency_ns: 0,
+        };
+        assert!(SmartOrderRouter::new(SmartOrderRouterConfig::default()).is_err());
+        let err = SmartOrderRouter::new(SmartOrderRouterConfig {
+            venues: vec![venue.clone(), venue],
+            latency_penalty_bps_per_ms: 0.0,
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Venue BINANCE is configured more than once"
+        );
+    }
+
+    #[rstest]
+    fn test_plan_buy_splits_by_effective_price() {
+        let (binance, bybit) = books();
+        let plan = router(0.0)
+            .plan(
+                OrderSide::Buy,
+                Quantity::from("3.0"),
+                None,
+                &[&binance, &bybit],
+            )
+            .unwrap();
+
+        // BYBIT 100.05 (no fee) beats BINANCE 100.00 * 1.001 = 100.10
+        let routed: Vec<(Venue, Quantity, Price)> = plan
+            .allocations
+            .iter()
+            .map(|a| (a.instrument_id.venue, a.quantity, a.limit_price))
+            .collect();
+        assert_eq!(
+            routed,
+            vec![
+                (
+                    Venue::from("BYBIT"),
+                    Quantity::from("2.0"),
+                    Price::from("100.05")
+                ),
+                (
+                    Venue::from("BINANCE"),
+                    Quantity::from("1.0"),
+                    Price::from("100.00")
+                ),
+            ]
+        );
+        assert_eq!(plan.unrouted_qty, Quantity::from("0.0"));
+        assert_eq!(plan.routed_qty(), Quantity::from("3.0"));
+        assert!((plan.allocations[1].avg_price - 100.0).abs() < 1e-9);
+        assert!((plan.allocations[1].fees - 0.1).abs() < 1e-9);
+        assert!((plan.avg_effective_price - (2.0 * 100.05 + 100.1) / 3.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_plan_latency_penalty_changes_routing() {
+        let (binance, bybit) = books();
+        // 50ms at 1bp per ms adds 0.5% to BYBIT prices
+        let plan = router(1.0)
+            .plan(
+                OrderSide::Buy,
+                Quantity::from("1.0"),
+                None,
+                &[&binance, &bybit],
+            )
+            .unwrap();
+        assert_eq!(plan.allocations.len(), 1);
+        assert_eq!(
+            plan.allocations[0].instrument_id.venue,
+            Venue::from("BINANCE")
+        );
+    }
+
+    #[rstest]
+    fn test_plan_sell_respects_limit_price() {
+        let (binance, bybit) = books();
+        let plan = router(0.0)
+            .plan(
+                OrderSide::Sell,
+                Quantity::from("5.0"),
+                Some(Price::from("99.92")),
+                &[&binance, &bybit],
+            )
+            .unwrap();
+        assert_eq!(plan.allocations.len(), 1);
+        assert_eq!(
+            plan.allocations[0].instrument_id.venue,
+            Venue::from("BYBIT")
+        );
+        assert_eq!(plan.unrouted_qty, Quantity::from("4.0"));
+    }
+
+    #[rstest]
+    fn test_plan_unknown_venue() {
+        let book = book("ETHUSDT.OKX", &[("100.00", "1.0")], &[]);
+        let err = router(0.0)
+            .plan(OrderSide::Buy, Quantity::from("1.0"), None, &[&book])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "No routing configuration for venue OKX");
+    }
+
+    #[rstest]
+    fn test_route_emits_tagged_child_orders() {
+        let (binance, bybit) = books();
+        let parent = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id("ETHUSDT.COMPOSITE".into())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("3.0"))
+            .build();
+
+        let (plan, children) = router(0.0)
+            .route(&parent, &[&binance, &bybit], UnixNanos::from(1))
+            .unwrap();
+
+        assert_eq!(children.len(), plan.allocations.len());
+        let OrderAny::Limit(child) = &children[0] else {
+            panic!("Expected a limit order");
+        };
+        assert_eq!(child.instrument_id, InstrumentId::from("ETHUSDT.BYBIT"));
+        assert_eq!(child.quantity, Quantity::from("2.0"));
+        assert_eq!(child.price, Price::from("100.05"));
+        assert_eq!(child.time_in_force, TimeInForce::Ioc);
+        assert_eq!(child.exec_spawn_id, Some(parent.client_order_id()));
+        assert_eq!(
+            child.exec_algorithm_id,
+            Some(ExecAlgorithmId::new(SMART_ORDER_ROUTER_ID))
+        );
+        assert_eq!(child.tags, Some(vec![Ustr::from("route:BYBIT")]));
+        assert_eq!(
+            child.exec_algorithm_params.as_ref().unwrap()[&Ustr::from("route_venue")],
+            "BYBIT"
+        );
+        assert_eq!(
+            children[1].client_order_id(),
+            ClientOrderId::new(format!("{}-R2", parent.client_order_id()))
+        );
+    }
+}
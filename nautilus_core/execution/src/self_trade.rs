@@ -0,0 +1,399 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Self-trade prevention (STP) which stops a strategy's aggressing orders from trading against
+//! its own resting orders.
+//!
+//! When an incoming order would cross one or more resting orders of the same trader and strategy
+//! on the opposite side, the configured [`SelfTradePreventionMode`] decides which side is
+//! canceled, following the conventions of the major exchanges.
+
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus, OrderType},
+    identifiers::ClientOrderId,
+    orders::any::OrderAny,
+    types::quantity::Quantity,
+};
+use serde::{Deserialize, Serialize};
+
+/// The action taken when an incoming order would trade against a resting order of the same
+/// strategy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradePreventionMode {
+    /// Cancel the incoming (newest) order, leaving the resting orders untouched.
+    CancelNewest,
+    /// Cancel the crossing resting (oldest) orders, then process the incoming order.
+    CancelOldest,
+    /// Cancel both the incoming order and the crossing resting orders.
+    CancelBoth,
+    /// Reduce the incoming and resting orders by their overlapping quantity, canceling any order
+    /// which is reduced to zero.
+    Decrement,
+}
+
+/// The resolution of a self-trade between an incoming order and resting orders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfTradeResolution {
+    /// If the incoming order is canceled.
+    pub cancel_incoming: bool,
+    /// The remaining quantity of the incoming order (after any decrement).
+    pub incoming_leaves_qty: Quantity,
+    /// The resting orders to cancel.
+    pub canceled: Vec<ClientOrderId>,
+    /// The resting orders to reduce, with their new (total) quantity.
+    pub decremented: Vec<(ClientOrderId, Quantity)>,
+}
+
+impl SelfTradeResolution {
+    /// Returns the identifiers of all resting orders affected by the resolution.
+    #[must_use]
+    pub fn resting_order_ids(&self) -> Vec<ClientOrderId> {
+        self.canceled
+            .iter()
+            .copied()
+            .chain(self.decremented.iter().map(|(id, _)| *id))
+            .collect()
+    }
+}
+
+/// Returns the resting orders from `resting` which the `incoming` order would trade against,
+/// in price priority for the incoming order (time priority is kept from the given ordering).
+///
+/// Only resting orders of the same trader, strategy and instrument on the opposite side are
+/// considered. The incoming order must be immediately marketable by type (`MARKET`,
+/// `MARKET_TO_LIMIT` or `LIMIT`), and resting orders must be working at a limit price.
+#[must_use]
+pub fn crossing_orders<'a>(incoming: &OrderAny, resting: &[&'a OrderAny]) -> Vec<&'a OrderAny> {
+    if !matches!(
+        incoming.order_type(),
+        OrderType::Market | OrderType::MarketToLimit | OrderType::Limit
+    ) {
+        return Vec::new();
+    }
+
+    let incoming_price = incoming.price();
+    let mut crossing: Vec<&OrderAny> = resting
+        .iter()
+        .copied()
+        .filter(|order| {
+            order.client_order_id() != incoming.client_order_id()
+                && order.trader_id() == incoming.trader_id()
+                && order.strategy_id() == incoming.strategy_id()
+                && order.instrument_id() == incoming.instrument_id()
+                && order.order_side() != incoming.order_side()
+                && !order.is_closed()
+                && is_resting_at_price(order)
+        })
+        .filter(|order| {
+            let resting_price = order.price().expect("resting order has a price");
+            match (incoming.order_side(), incoming_price) {
+                (_, None) => true,
+                (OrderSide::Buy, Some(price)) => resting_price <= price,
+                (OrderSide::Sell, Some(price)) => resting_price >= price,
+                _ => false,
+            }
+        })
+        .collect();
+
+    // Stable sort to keep time priority within each price level
+    match incoming.order_side() {
+        OrderSide::Buy => crossing.sort_by_key(|order| order.price()),
+        _ => crossing.sort_by_key(|order| std::cmp::Reverse(order.price())),
+    }
+
+    crossing
+}
+
+/// Resolves a potential self-trade between the `incoming` order and the `resting` orders
+/// according to the given `mode`.
+///
+/// Returns `None` if the incoming order would not trade against any of the resting orders.
+#[must_use]
+pub fn resolve_self_trade(
+    mode: SelfTradePreventionMode,
+    incoming: &OrderAny,
+    resting: &[&OrderAny],
+) -> Option<SelfTradeResolution> {
+    let crossing = crossing_orders(incoming, resting);
+    if crossing.is_empty() {
+        return None;
+    }
+
+    let leaves_qty = incoming.leaves_qty();
+    let crossing_ids = || {
+        crossing
+            .iter()
+            .map(|order| order.client_order_id())
+            .collect()
+    };
+
+    let resolution = match mode {
+        SelfTradePreventionMode::CancelNewest => SelfTradeResolution {
+            cancel_incoming: true,
+            incoming_leaves_qty: leaves_qty,
+            canceled: Vec::new(),
+            decremented: Vec::new(),
+        },
+        SelfTradePreventionMode::CancelOldest => SelfTradeResolution {
+            cancel_incoming: false,
+            incoming_leaves_qty: leaves_qty,
+            canceled: crossing_ids(),
+            decremented: Vec::new(),
+        },
+        SelfTradePreventionMode::CancelBoth => SelfTradeResolution {
+            cancel_incoming: true,
+            incoming_leaves_qty: leaves_qty,
+            canceled: crossing_ids(),
+            decremented: Vec::new(),
+        },
+        SelfTradePreventionMode::Decrement => {
+            let mut remaining = leaves_qty;
+            let mut canceled = Vec::new();
+            let mut decremented = Vec::new();
+            for order in crossing {
+                if remaining.is_zero() {
+                    break;
+                }
+                let overlap = remaining.min(order.leaves_qty());
+                remaining -= overlap;
+                if overlap == order.leaves_qty() {
+                    canceled.push(order.client_order_id());
+                } else {
+                    decremented.push((order.client_order_id(), order.quantity() - overlap));
+                }
+            }
+            SelfTradeResolution {
+                cancel_incoming: remaining.is_zero(),
+                incoming_leaves_qty: remaining,
+                canceled,
+                decremented,
+            }
+        }
+    };
+
+    Some(resolution)
+}
+
+fn is_resting_at_price(order: &OrderAny) -> bool {
+    match order.order_type() {
+        OrderType::Limit | OrderType::MarketToLimit => order.price().is_some(),
+        OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+            order.status() == OrderStatus::Triggered
+        }
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::{InstrumentId, StrategyId},
+        orders::builder::OrderTestBuilder,
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order(
+        client_order_id: &str,
+        order_type: OrderType,
+        side: OrderSide,
+        price: Option<&str>,
+        quantity: i64,
+    ) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .quantity(Quantity::from(quantity));
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        builder.build()
+    }
+
+    fn resting_asks() -> Vec<OrderAny> {
+        vec![
+            order(
+                "O-2",
+                OrderType::Limit,
+                OrderSide::Sell,
+                Some("1.00010"),
+                100,
+            ),
+            order(
+                "O-1",
+                OrderType::Limit,
+                OrderSide::Sell,
+                Some("1.00000"),
+                100,
+            ),
+            order(
+                "O-3",
+                OrderType::Limit,
+                OrderSide::Sell,
+                Some("1.00020"),
+                100,
+            ),
+        ]
+    }
+
+    #[rstest]
+    fn test_crossing_orders_in_price_priority() {
+        let resting = resting_asks();
+        let resting: Vec<&OrderAny> = resting.iter().collect();
+        let incoming = order(
+            "O-4",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("1.00010"),
+            100,
+        );
+
+        let crossing: Vec<ClientOrderId> = crossing_orders(&incoming, &resting)
+            .iter()
+            .map(|order| order.client_order_id())
+            .collect();
+
+        assert_eq!(
+            crossing,
+            vec![ClientOrderId::from("O-1"), ClientOrderId::from("O-2")]
+        );
+    }
+
+    #[rstest]
+    fn test_no_self_trade_for_other_strategy_or_side() {
+        let mut builder = OrderTestBuilder::new(OrderType::Limit);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .strategy_id(StrategyId::from("S-002"))
+            .side(OrderSide::Sell)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100));
+        let other_strategy = builder.build();
+        let same_side = order(
+            "O-2",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("1.00000"),
+            100,
+        );
+        let incoming = order("O-3", OrderType::Market, OrderSide::Buy, None, 100);
+
+        let resolution = resolve_self_trade(
+            SelfTradePreventionMode::CancelBoth,
+            &incoming,
+            &[&other_strategy, &same_side],
+        );
+
+        assert!(resolution.is_none());
+    }
+
+    #[rstest]
+    #[case(SelfTradePreventionMode::CancelNewest, true, vec![])]
+    #[case(SelfTradePreventionMode::CancelOldest, false, vec!["O-1", "O-2", "O-3"])]
+    #[case(SelfTradePreventionMode::CancelBoth, true, vec!["O-1", "O-2", "O-3"])]
+    fn test_resolve_cancel_modes(
+        #[case] mode: SelfTradePreventionMode,
+        #[case] cancel_incoming: bool,
+        #[case] canceled: Vec<&str>,
+    ) {
+        let resting = resting_asks();
+        let resting: Vec<&OrderAny> = resting.iter().collect();
+        let incoming = order("O-4", OrderType::Market, OrderSide::Buy, None, 150);
+
+        let resolution = resolve_self_trade(mode, &incoming, &resting).unwrap();
+
+        assert_eq!(resolution.cancel_incoming, cancel_incoming);
+        assert_eq!(resolution.incoming_leaves_qty, Quantity::from(150));
+        assert_eq!(
+            resolution.canceled,
+            canceled
+                .into_iter()
+                .map(ClientOrderId::from)
+                .collect::<Vec<_>>()
+        );
+        assert!(resolution.decremented.is_empty());
+    }
+
+    #[rstest]
+    fn test_resolve_decrement_reduces_both_sides() {
+        let resting = resting_asks();
+        let resting: Vec<&OrderAny> = resting.iter().collect();
+        let incoming = order(
+            "O-4",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("1.00010"),
+            150,
+        );
+
+        let resolution =
+            resolve_self_trade(SelfTradePreventionMode::Decrement, &incoming, &resting).unwrap();
+
+        assert!(resolution.cancel_incoming);
+        assert_eq!(resolution.incoming_leaves_qty, Quantity::from(0));
+        assert_eq!(resolution.canceled, vec![ClientOrderId::from("O-1")]);
+        assert_eq!(
+            resolution.decremented,
+            vec![(ClientOrderId::from("O-2"), Quantity::from(50))]
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_decrement_leaves_incoming_remainder() {
+        let resting = resting_asks();
+        let resting: Vec<&OrderAny> = resting.iter().collect();
+        let incoming = order(
+            "O-4",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("1.00000"),
+            250,
+        );
+
+        let resolution =
+            resolve_self_trade(SelfTradePreventionMode::Decrement, &incoming, &resting).unwrap();
+
+        assert!(!resolution.cancel_incoming);
+        assert_eq!(resolution.incoming_leaves_qty, Quantity::from(150));
+        assert_eq!(resolution.canceled, vec![ClientOrderId::from("O-1")]);
+        assert_eq!(
+            resolution.resting_order_ids(),
+            vec![ClientOrderId::from("O-1")]
+        );
+    }
+
+    #[rstest]
+    fn test_stop_orders_do_not_self_trade() {
+        let resting = resting_asks();
+        let resting: Vec<&OrderAny> = resting.iter().collect();
+        let mut builder = OrderTestBuilder::new(OrderType::StopMarket);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Buy)
+            .trigger_price(Price::from("1.00050"))
+            .quantity(Quantity::from(100));
+        let incoming = builder.build();
+
+        assert!(crossing_orders(&incoming, &resting).is_empty());
+    }
+}
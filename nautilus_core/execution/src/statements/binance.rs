@@ -0,0 +1,237 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Importers for Binance account statement CSV exports.
+//!
+//! The trade history export is parsed into fills with their commissions, and the transaction
+//! history export into funding payments and the fees not charged for fills. Instrument IDs are
+//! the Binance symbol at the given venue, e.g. `BTCUSDT.BINANCE`.
+
+use std::io::Read;
+
+use anyhow::Context;
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{InstrumentId, Symbol, TradeId, Venue, VenueOrderId},
+};
+use serde::Deserialize;
+
+use super::{
+    parse_datetime, parse_money, parse_price, parse_quantity, AccountStatement, StatementFee,
+    StatementFill, StatementFunding,
+};
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The transaction types which are fees not charged for a fill.
+const FEE_TYPES: [&str; 6] = [
+    "INSURANCE_CLEAR",
+    "OPTIONS_PREMIUM_FEE",
+    "POSITION_LIMIT_INCREASE_FEE",
+    "COMMISSION_REBATE",
+    "API_REBATE",
+    "REFERRAL_KICKBACK",
+];
+
+const FUNDING_TYPE: &str = "FUNDING_FEE";
+
+#[derive(Debug, Deserialize)]
+struct TradeRow {
+    #[serde(rename = "Date(UTC)")]
+    date: String,
+    #[serde(rename = "Symbol")]
+    symbol: String,
+    #[serde(rename = "Order ID")]
+    order_id: String,
+    #[serde(rename = "Trade ID")]
+    trade_id: String,
+    #[serde(rename = "Side")]
+    side: String,
+    #[serde(rename = "Price")]
+    price: String,
+    #[serde(rename = "Quantity")]
+    quantity: String,
+    #[serde(rename = "Fee")]
+    fee: String,
+    #[serde(rename = "Fee Coin")]
+    fee_coin: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransactionRow {
+    #[serde(rename = "Time")]
+    time: String,
+    #[serde(rename = "Symbol")]
+    symbol: String,
+    #[serde(rename = "Type")]
+    kind: String,
+    #[serde(rename = "Amount")]
+    amount: String,
+    #[serde(rename = "Asset")]
+    asset: String,
+}
+
+/// Parses a Binance trade history CSV export with the columns
+/// `Date(UTC),Symbol,Order ID,Trade ID,Side,Price,Quantity,Fee,Fee Coin` into fills.
+///
+/// # Errors
+///
+/// Returns an error if a row is malformed.
+pub fn parse_trades_csv<R: Read>(reader: R, venue: Venue) -> anyhow::Result<AccountStatement> {
+    let mut statement = AccountStatement::default();
+    for (i, row) in csv::Reader::from_reader(reader)
+        .deserialize::<TradeRow>()
+        .enumerate()
+    {
+        let row = row?;
+        let fill =
+            parse_trade_row(&row, venue).with_context(|| format!("Invalid row {}", i + 1))?;
+        statement.fills.push(fill);
+    }
+    Ok(statement)
+}
+
+/// Parses a Binance transaction history CSV export with the columns
+/// `Time,Symbol,Type,Amount,Asset` into funding payments and fees.
+///
+/// Commissions and other transaction types, such as transfers and realized PnL, are skipped.
+///
+/// # Errors
+///
+/// Returns an error if a row is malformed.
+pub fn parse_transactions_csv<R: Read>(
+    reader: R,
+    venue: Venue,
+) -> anyhow::Result<AccountStatement> {
+    let mut statement = AccountStatement::default();
+    for (i, row) in csv::Reader::from_reader(reader)
+        .deserialize::<TransactionRow>()
+        .enumerate()
+    {
+        let row = row?;
+        let is_fee = FEE_TYPES.contains(&row.kind.as_str());
+        if !is_fee && row.kind != FUNDING_TYPE {
+            continue;
+        }
+
+        let context = || format!("Invalid row {}", i + 1);
+        let instrument_id = (!row.symbol.is_empty())
+            .then(|| InstrumentId::new(Symbol::from(row.symbol.as_str()), venue));
+        let amount = parse_money(&row.amount, &row.asset).with_context(context)?;
+        let ts_event = parse_datetime(&row.time, DATETIME_FORMAT).with_context(context)?;
+        if is_fee {
+            statement.fees.push(StatementFee {
+                instrument_id,
+                amount,
+                description: row.kind,
+                ts_event,
+            });
+        } else {
+            statement.funding.push(StatementFunding {
+                instrument_id,
+                amount,
+                ts_event,
+            });
+        }
+    }
+    Ok(statement)
+}
+
+fn parse_trade_row(row: &TradeRow, venue: Venue) -> anyhow::Result<StatementFill> {
+    let order_side = match row.side.as_str() {
+        "BUY" => OrderSide::Buy,
+        "SELL" => OrderSide::Sell,
+        side => anyhow::bail!("Invalid side '{side}'"),
+    };
+    Ok(StatementFill {
+        instrument_id: InstrumentId::new(Symbol::from(row.symbol.as_str()), venue),
+        trade_id: TradeId::new_checked(&row.trade_id)?,
+        venue_order_id: VenueOrderId::new_checked(&row.order_id)?,
+        order_side,
+        last_qty: parse_quantity(&row.quantity)?,
+        last_px: parse_price(&row.price)?,
+        commission: Some(parse_money(&row.fee, &row.fee_coin)?),
+        ts_event: parse_datetime(&row.date, DATETIME_FORMAT)?,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::types::{money::Money, price::Price, quantity::Quantity};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_trades_csv() {
+        let data = "\
+Date(UTC),Symbol,Order ID,Trade ID,Side,Price,Quantity,Fee,Fee Coin
+2024-01-15 09:30:00,BTCUSDT,1001,5001,BUY,42000.10,0.010,0.168,USDT
+2024-01-15 09:31:00,BTCUSDT,1002,5002,SELL,42010.00,0.010,0.168,USDT
+";
+        let statement = parse_trades_csv(data.as_bytes(), Venue::from("BINANCE")).unwrap();
+
+        assert_eq!(statement.fills.len(), 2);
+        let fill = statement.fills[0];
+        assert_eq!(fill.instrument_id, InstrumentId::from("BTCUSDT.BINANCE"));
+        assert_eq!(fill.trade_id, TradeId::from("5001"));
+        assert_eq!(fill.venue_order_id, VenueOrderId::from("1001"));
+        assert_eq!(fill.order_side, OrderSide::Buy);
+        assert_eq!(fill.last_qty, Quantity::from("0.010"));
+        assert_eq!(fill.last_px, Price::from("42000.10"));
+        assert_eq!(fill.commission, Some(Money::from("0.168 USDT")));
+        assert_eq!(fill.ts_event, UnixNanos::from(1_705_311_000_000_000_000));
+        assert_eq!(statement.fills[1].order_side, OrderSide::Sell);
+    }
+
+    #[rstest]
+    fn test_parse_trades_csv_invalid_side() {
+        let data = "\
+Date(UTC),Symbol,Order ID,Trade ID,Side,Price,Quantity,Fee,Fee Coin
+2024-01-15 09:30:00,BTCUSDT,1001,5001,HOLD,42000.10,0.010,0.168,USDT
+";
+        let result = parse_trades_csv(data.as_bytes(), Venue::from("BINANCE"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_parse_transactions_csv() {
+        let data = "\
+Time,Symbol,Type,Amount,Asset
+2024-01-15 08:00:00,BTCUSDT,FUNDING_FEE,-1.25,USDT
+2024-01-15 09:30:00,BTCUSDT,COMMISSION,-0.168,USDT
+2024-01-15 10:00:00,,REFERRAL_KICKBACK,0.05,USDT
+2024-01-15 11:00:00,,TRANSFER,1000,USDT
+";
+        let statement = parse_transactions_csv(data.as_bytes(), Venue::from("BINANCE")).unwrap();
+
+        assert!(statement.fills.is_empty());
+        assert_eq!(statement.funding.len(), 1);
+        assert_eq!(
+            statement.funding[0].instrument_id,
+            Some(InstrumentId::from("BTCUSDT.BINANCE"))
+        );
+        assert_eq!(statement.funding[0].amount, Money::from("-1.25 USDT"));
+        assert_eq!(statement.fees.len(), 1);
+        assert_eq!(statement.fees[0].instrument_id, None);
+        assert_eq!(statement.fees[0].amount, Money::from("0.05 USDT"));
+        assert_eq!(statement.fees[0].description, "REFERRAL_KICKBACK");
+    }
+}
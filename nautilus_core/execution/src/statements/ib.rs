@@ -0,0 +1,251 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Importer for Interactive Brokers Flex query XML statements.
+//!
+//! The `Trade` elements of each flex statement are parsed into fills, and the `CashTransaction`
+//! elements into fees and financing (broker interest) payments. Instrument IDs are the symbol at
+//! its listing exchange, e.g. `AAPL.NASDAQ`. Datetimes are expected in the `yyyyMMdd;HHmmss`
+//! format of the flex query, in UTC.
+
+use anyhow::Context;
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{InstrumentId, Symbol, TradeId, Venue, VenueOrderId},
+    types::money::Money,
+};
+use serde::Deserialize;
+
+use super::{
+    parse_datetime, parse_money, parse_price, parse_quantity, AccountStatement, StatementFee,
+    StatementFill, StatementFunding,
+};
+
+const DATETIME_FORMAT: &str = "%Y%m%d;%H%M%S";
+
+/// The cash transaction types which are fees not charged for a fill.
+const FEE_TYPES: [&str; 3] = ["Other Fees", "Broker Fees", "Commission Adjustments"];
+
+/// The cash transaction types which are financing payments.
+const FUNDING_TYPES: [&str; 2] = ["Broker Interest Paid", "Broker Interest Received"];
+
+#[derive(Debug, Deserialize)]
+struct FlexQueryResponse {
+    #[serde(rename = "FlexStatements")]
+    statements: FlexStatements,
+}
+
+#[derive(Debug, Deserialize)]
+struct FlexStatements {
+    #[serde(rename = "FlexStatement", default)]
+    statements: Vec<FlexStatement>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FlexStatement {
+    #[serde(rename = "Trades", default)]
+    trades: FlexTrades,
+    #[serde(rename = "CashTransactions", default)]
+    cash_transactions: FlexCashTransactions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FlexTrades {
+    #[serde(rename = "Trade", default)]
+    trades: Vec<FlexTrade>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FlexTrade {
+    #[serde(rename = "@symbol")]
+    symbol: String,
+    #[serde(rename = "@listingExchange")]
+    listing_exchange: String,
+    #[serde(rename = "@tradeID")]
+    trade_id: String,
+    #[serde(rename = "@ibOrderID")]
+    order_id: String,
+    #[serde(rename = "@buySell")]
+    buy_sell: String,
+    #[serde(rename = "@quantity")]
+    quantity: String,
+    #[serde(rename = "@tradePrice")]
+    price: String,
+    #[serde(rename = "@ibCommission")]
+    commission: String,
+    #[serde(rename = "@ibCommissionCurrency")]
+    commission_currency: String,
+    #[serde(rename = "@dateTime")]
+    datetime: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FlexCashTransactions {
+    #[serde(rename = "CashTransaction", default)]
+    transactions: Vec<FlexCashTransaction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FlexCashTransaction {
+    #[serde(rename = "@type")]
+    kind: String,
+    #[serde(rename = "@symbol", default)]
+    symbol: String,
+    #[serde(rename = "@listingExchange", default)]
+    listing_exchange: String,
+    #[serde(rename = "@amount")]
+    amount: String,
+    #[serde(rename = "@currency")]
+    currency: String,
+    #[serde(rename = "@dateTime")]
+    datetime: String,
+}
+
+/// Parses an Interactive Brokers Flex query XML statement into fills, fees and financing
+/// payments, across all flex statements (accounts) in the response.
+///
+/// Cash transactions of other types, such as deposits and dividends, are skipped.
+///
+/// # Errors
+///
+/// Returns an error if the XML or a record is malformed.
+pub fn parse_flex_xml(xml: &str) -> anyhow::Result<AccountStatement> {
+    let response: FlexQueryResponse =
+        quick_xml::de::from_str(xml).context("Invalid flex query XML")?;
+
+    let mut statement = AccountStatement::default();
+    for flex in response.statements.statements {
+        for trade in &flex.trades.trades {
+            let fill = parse_trade(trade)
+                .with_context(|| format!("Invalid trade '{}'", trade.trade_id))?;
+            statement.fills.push(fill);
+        }
+        for transaction in flex.cash_transactions.transactions {
+            let is_fee = FEE_TYPES.contains(&transaction.kind.as_str());
+            if !is_fee && !FUNDING_TYPES.contains(&transaction.kind.as_str()) {
+                continue;
+            }
+
+            let context = || format!("Invalid cash transaction '{}'", transaction.kind);
+            let instrument_id = (!transaction.symbol.is_empty())
+                .then(|| instrument_id(&transaction.symbol, &transaction.listing_exchange));
+            let amount =
+                parse_money(&transaction.amount, &transaction.currency).with_context(context)?;
+            let ts_event =
+                parse_datetime(&transaction.datetime, DATETIME_FORMAT).with_context(context)?;
+            if is_fee {
+                statement.fees.push(StatementFee {
+                    instrument_id,
+                    amount,
+                    description: transaction.kind,
+                    ts_event,
+                });
+            } else {
+                statement.funding.push(StatementFunding {
+                    instrument_id,
+                    amount,
+                    ts_event,
+                });
+            }
+        }
+    }
+    Ok(statement)
+}
+
+fn instrument_id(symbol: &str, listing_exchange: &str) -> InstrumentId {
+    InstrumentId::new(Symbol::from(symbol), Venue::from(listing_exchange))
+}
+
+fn parse_trade(trade: &FlexTrade) -> anyhow::Result<StatementFill> {
+    // Cancelled and corrected trades are suffixed, e.g. `BUY (Ca.)`
+    let order_side = match trade.buy_sell.split_whitespace().next() {
+        Some("BUY") => OrderSide::Buy,
+        Some("SELL") => OrderSide::Sell,
+        _ => anyhow::bail!("Invalid buySell '{}'", trade.buy_sell),
+    };
+
+    // IB reports commissions as a negative cash amount
+    let commission = parse_money(&trade.commission, &trade.commission_currency)?;
+
+    Ok(StatementFill {
+        instrument_id: instrument_id(&trade.symbol, &trade.listing_exchange),
+        trade_id: TradeId::new_checked(&trade.trade_id)?,
+        venue_order_id: VenueOrderId::new_checked(&trade.order_id)?,
+        order_side,
+        last_qty: parse_quantity(&trade.quantity)?,
+        last_px: parse_price(&trade.price)?,
+        commission: Some(Money::new(-commission.as_f64(), commission.currency)),
+        ts_event: parse_datetime(&trade.datetime, DATETIME_FORMAT)?,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::types::{price::Price, quantity::Quantity};
+    use rstest::rstest;
+
+    use super::*;
+
+    const FLEX_XML: &str = r#"<FlexQueryResponse queryName="Statement" type="AF">
+  <FlexStatements count="1">
+    <FlexStatement accountId="U1234567" fromDate="20240115" toDate="20240115">
+      <Trades>
+        <Trade accountId="U1234567" currency="USD" symbol="AAPL" listingExchange="NASDAQ" tradeID="700001" ibOrderID="900001" buySell="BUY" quantity="100" tradePrice="185.25" ibCommission="-1.00" ibCommissionCurrency="USD" dateTime="20240115;143000" />
+        <Trade accountId="U1234567" currency="USD" symbol="AAPL" listingExchange="NASDAQ" tradeID="700002" ibOrderID="900002" buySell="SELL" quantity="-100" tradePrice="185.50" ibCommission="-1.00" ibCommissionCurrency="USD" dateTime="20240115;150000" />
+      </Trades>
+      <CashTransactions>
+        <CashTransaction type="Broker Interest Paid" currency="USD" amount="-3.21" dateTime="20240115;000000" />
+        <CashTransaction type="Other Fees" currency="USD" symbol="AAPL" listingExchange="NASDAQ" amount="-0.02" dateTime="20240115;000000" />
+        <CashTransaction type="Deposits/Withdrawals" currency="USD" amount="10000" dateTime="20240115;000000" />
+      </CashTransactions>
+    </FlexStatement>
+  </FlexStatements>
+</FlexQueryResponse>"#;
+
+    #[rstest]
+    fn test_parse_flex_xml() {
+        let statement = parse_flex_xml(FLEX_XML).unwrap();
+
+        assert_eq!(statement.fills.len(), 2);
+        let fill = statement.fills[1];
+        assert_eq!(fill.instrument_id, InstrumentId::from("AAPL.NASDAQ"));
+        assert_eq!(fill.trade_id, TradeId::from("700002"));
+        assert_eq!(fill.venue_order_id, VenueOrderId::from("900002"));
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from(100));
+        assert_eq!(fill.last_px, Price::from("185.50"));
+        assert_eq!(fill.commission, Some(Money::from("1.00 USD")));
+        assert_eq!(fill.ts_event, UnixNanos::from(1_705_330_800_000_000_000));
+
+        assert_eq!(statement.funding.len(), 1);
+        assert_eq!(statement.funding[0].instrument_id, None);
+        assert_eq!(statement.funding[0].amount, Money::from("-3.21 USD"));
+        assert_eq!(statement.fees.len(), 1);
+        assert_eq!(
+            statement.fees[0].instrument_id,
+            Some(InstrumentId::from("AAPL.NASDAQ"))
+        );
+        assert_eq!(statement.fees[0].description, "Other Fees");
+    }
+
+    #[rstest]
+    fn test_parse_flex_xml_invalid() {
+        assert!(parse_flex_xml("<FlexQueryResponse>").is_err());
+    }
+}
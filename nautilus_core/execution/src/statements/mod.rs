@@ -0,0 +1,372 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Importers for official venue account statements, for reconciling the fills, fees and funding
+//! recorded by the live engine against the records of the venue or broker.
+//!
+//! Each importer parses a statement file into an [`AccountStatement`], and the statement fills
+//! are matched by instrument and trade ID against the engine fills with [`reconcile_fills`].
+
+pub mod binance;
+pub mod ib;
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use chrono::NaiveDateTime;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::OrderSide,
+    events::order::OrderFilled,
+    identifiers::{InstrumentId, TradeId, VenueOrderId},
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Represents a fill from an account statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementFill {
+    pub instrument_id: InstrumentId,
+    pub trade_id: TradeId,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    /// The commission charged for the fill, positive for a cost.
+    pub commission: Option<Money>,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents a fee from an account statement which is not charged for a fill.
+///
+/// A positive `amount` was credited to the account, a negative `amount` was charged.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementFee {
+    pub instrument_id: Option<InstrumentId>,
+    pub amount: Money,
+    /// The fee type as described by the venue.
+    pub description: String,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents a funding (or financing) payment from an account statement.
+///
+/// A positive `amount` was received by the account, a negative `amount` was paid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementFunding {
+    pub instrument_id: Option<InstrumentId>,
+    pub amount: Money,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents the records parsed from one or more account statement files.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountStatement {
+    pub fills: Vec<StatementFill>,
+    pub fees: Vec<StatementFee>,
+    pub funding: Vec<StatementFunding>,
+}
+
+impl AccountStatement {
+    /// Appends the records of the `other` statement to this statement.
+    pub fn extend(&mut self, other: Self) {
+        self.fills.extend(other.fills);
+        self.fees.extend(other.fees);
+        self.funding.extend(other.funding);
+    }
+}
+
+/// A discrepancy found between a statement fill and the engine fill with the same trade ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatementDiscrepancy {
+    /// The statement fill has no engine fill.
+    MissingFromEngine,
+    /// The engine fill has no statement fill.
+    MissingFromStatement,
+    /// The fills have a different order side.
+    SideMismatch {
+        engine: OrderSide,
+        statement: OrderSide,
+    },
+    /// The fills have a different fill quantity.
+    QuantityMismatch {
+        engine: Quantity,
+        statement: Quantity,
+    },
+    /// The fills have a different fill price.
+    PriceMismatch { engine: Price, statement: Price },
+    /// The fills were charged a different commission.
+    CommissionMismatch { engine: Money, statement: Money },
+}
+
+impl Display for StatementDiscrepancy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingFromEngine => write!(f, "missing from engine"),
+            Self::MissingFromStatement => write!(f, "missing from statement"),
+            Self::SideMismatch { engine, statement } => {
+                write!(f, "side mismatch: engine={engine}, statement={statement}")
+            }
+            Self::QuantityMismatch { engine, statement } => {
+                write!(
+                    f,
+                    "quantity mismatch: engine={engine}, statement={statement}"
+                )
+            }
+            Self::PriceMismatch { engine, statement } => {
+                write!(f, "price mismatch: engine={engine}, statement={statement}")
+            }
+            Self::CommissionMismatch { engine, statement } => {
+                write!(
+                    f,
+                    "commission mismatch: engine={engine}, statement={statement}"
+                )
+            }
+        }
+    }
+}
+
+/// Represents a break between the statement and the engine records for a single fill.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementBreak {
+    pub instrument_id: InstrumentId,
+    pub trade_id: TradeId,
+    pub discrepancy: StatementDiscrepancy,
+}
+
+impl Display for StatementBreak {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, trade_id={}, discrepancy={})",
+            stringify!(StatementBreak),
+            self.instrument_id,
+            self.trade_id,
+            self.discrepancy,
+        )
+    }
+}
+
+/// Reconciles the `statement_fills` against the `engine_fills`, returning the breaks found.
+///
+/// Fills are matched by instrument and trade ID. Commissions are only compared when the engine
+/// fill has a commission in the same currency as the statement.
+#[must_use]
+pub fn reconcile_fills(
+    statement_fills: &[StatementFill],
+    engine_fills: &[OrderFilled],
+) -> Vec<StatementBreak> {
+    let mut engine: HashMap<(InstrumentId, TradeId), &OrderFilled> = engine_fills
+        .iter()
+        .map(|fill| ((fill.instrument_id, fill.trade_id), fill))
+        .collect();
+
+    let mut breaks = Vec::new();
+    for statement in statement_fills {
+        let key = (statement.instrument_id, statement.trade_id);
+        let mut push = |discrepancy| {
+            breaks.push(StatementBreak {
+                instrument_id: key.0,
+                trade_id: key.1,
+                discrepancy,
+            });
+        };
+
+        let Some(fill) = engine.remove(&key) else {
+            push(StatementDiscrepancy::MissingFromEngine);
+            continue;
+        };
+        if fill.order_side != statement.order_side {
+            push(StatementDiscrepancy::SideMismatch {
+                engine: fill.order_side,
+                statement: statement.order_side,
+            });
+        }
+        if fill.last_qty != statement.last_qty {
+            push(StatementDiscrepancy::QuantityMismatch {
+                engine: fill.last_qty,
+                statement: statement.last_qty,
+            });
+        }
+        if fill.last_px != statement.last_px {
+            push(StatementDiscrepancy::PriceMismatch {
+                engine: fill.last_px,
+                statement: statement.last_px,
+            });
+        }
+        if let (Some(engine), Some(statement)) = (fill.commission, statement.commission) {
+            if engine.currency == statement.currency && engine != statement {
+                push(StatementDiscrepancy::CommissionMismatch { engine, statement });
+            }
+        }
+    }
+
+    // Remaining engine fills in order of the input
+    breaks.extend(
+        engine_fills
+            .iter()
+            .filter(|fill| engine.contains_key(&(fill.instrument_id, fill.trade_id)))
+            .map(|fill| StatementBreak {
+                instrument_id: fill.instrument_id,
+                trade_id: fill.trade_id,
+                discrepancy: StatementDiscrepancy::MissingFromStatement,
+            }),
+    );
+    breaks
+}
+
+fn parse_datetime(value: &str, format: &str) -> anyhow::Result<UnixNanos> {
+    let datetime = NaiveDateTime::parse_from_str(value, format)
+        .map_err(|e| anyhow::anyhow!("Invalid datetime '{value}': {e}"))?;
+    let nanos = datetime
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("Datetime '{value}' out of range"))?;
+    Ok(UnixNanos::from(u64::try_from(nanos)?))
+}
+
+fn parse_price(value: &str) -> anyhow::Result<Price> {
+    value.parse().map_err(anyhow::Error::msg)
+}
+
+fn parse_quantity(value: &str) -> anyhow::Result<Quantity> {
+    value
+        .trim_start_matches('-')
+        .parse()
+        .map_err(anyhow::Error::msg)
+}
+
+fn parse_money(amount: &str, currency: &str) -> anyhow::Result<Money> {
+    let amount = Decimal::from_str(amount)
+        .or_else(|_| Decimal::from_scientific(amount))
+        .map_err(|e| anyhow::anyhow!("Invalid amount '{amount}': {e}"))?;
+    Money::from_decimal(amount, currency.parse()?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderType},
+        identifiers::{AccountId, ClientOrderId, StrategyId, TraderId},
+        types::currency::Currency,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn engine_fill(trade_id: &str, last_px: &str, commission: &str) -> OrderFilled {
+        OrderFilled::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            ClientOrderId::default(),
+            VenueOrderId::from("1001"),
+            AccountId::default(),
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            OrderType::Limit,
+            Quantity::from("0.010"),
+            Price::from(last_px),
+            Currency::USDT(),
+            LiquiditySide::Maker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            None,
+            Some(Money::from(commission)),
+        )
+    }
+
+    fn statement_fill(trade_id: &str, last_px: &str, commission: &str) -> StatementFill {
+        StatementFill {
+            instrument_id: InstrumentId::from("BTCUSDT.BINANCE"),
+            trade_id: TradeId::from(trade_id),
+            venue_order_id: VenueOrderId::from("1001"),
+            order_side: OrderSide::Buy,
+            last_qty: Quantity::from("0.01"),
+            last_px: Price::from(last_px),
+            commission: Some(Money::from(commission)),
+            ts_event: UnixNanos::default(),
+        }
+    }
+
+    #[rstest]
+    fn test_reconcile_fills() {
+        let engine_fills = vec![
+            engine_fill("1", "42000.10", "0.21 USDT"),
+            engine_fill("2", "42000.10", "0.21 USDT"),
+            engine_fill("3", "42000.10", "0.21 USDT"),
+        ];
+        let statement_fills = vec![
+            // Matches with a different price precision
+            statement_fill("1", "42000.1", "0.21 USDT"),
+            statement_fill("2", "42000.20", "0.42 USDT"),
+            statement_fill("4", "42000.10", "0.21 USDT"),
+        ];
+
+        let breaks = reconcile_fills(&statement_fills, &engine_fills);
+
+        let discrepancies: Vec<_> = breaks
+            .iter()
+            .map(|b| (b.trade_id.to_string(), b.discrepancy))
+            .collect();
+        assert_eq!(
+            discrepancies,
+            vec![
+                (
+                    "2".to_string(),
+                    StatementDiscrepancy::PriceMismatch {
+                        engine: Price::from("42000.10"),
+                        statement: Price::from("42000.20"),
+                    }
+                ),
+                (
+                    "2".to_string(),
+                    StatementDiscrepancy::CommissionMismatch {
+                        engine: Money::from("0.21 USDT"),
+                        statement: Money::from("0.42 USDT"),
+                    }
+                ),
+                ("4".to_string(), StatementDiscrepancy::MissingFromEngine),
+                ("3".to_string(), StatementDiscrepancy::MissingFromStatement),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("-0.21", "-0.21 USDT")]
+    #[case("1.5E-3", "0.0015 USDT")]
+    fn test_parse_money(#[case] amount: &str, #[case] expected: &str) {
+        assert_eq!(parse_money(amount, "USDT").unwrap(), Money::from(expected));
+    }
+
+    #[rstest]
+    #[case("NaN")]
+    #[case("inf")]
+    #[case("1e400")]
+    #[case("1.0.0")]
+    fn test_parse_money_with_invalid_amount(#[case] amount: &str) {
+        assert!(parse_money(amount, "USDT").is_err());
+    }
+}
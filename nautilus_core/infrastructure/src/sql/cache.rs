@@ -494,6 +494,16 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_instrument(&self, instrument: &InstrumentAny) -> anyhow::Result<()> {
+        // The instrument table has no columns for the forward and delivery terms
+        if matches!(
+            instrument,
+            InstrumentAny::Commodity(_) | InstrumentAny::FxForward(_) | InstrumentAny::Ndf(_)
+        ) {
+            anyhow::bail!(
+                "Persisting instrument {} is not supported by the Postgres cache database",
+                instrument.id()
+            );
+        }
         let query = DatabaseQuery::AddInstrument(instrument.clone());
         self.tx.send(query).map_err(|e| {
             anyhow::anyhow!("Failed to send query add_instrument to database message handler: {e}")
@@ -787,6 +797,12 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                     DatabaseQueries::add_instrument(pool, "FUTURES_SPREAD", Box::new(instrument))
                         .await
                 }
+                InstrumentAny::Commodity(_)
+                | InstrumentAny::FxForward(_)
+                | InstrumentAny::Ndf(_) => Err(anyhow::anyhow!(
+                    "Persisting instrument {} is not supported",
+                    instrument_any.id()
+                )),
                 InstrumentAny::OptionsContract(instrument) => {
                     DatabaseQueries::add_instrument(pool, "OPTIONS_CONTRACT", Box::new(instrument))
                         .await
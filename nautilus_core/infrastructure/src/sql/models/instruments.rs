@@ -624,6 +624,9 @@ impl<'r> FromRow<'r, PgRow> for EquityModel {
         let isin = row
             .try_get::<Option<String>, _>("isin")
             .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let figi = row
+            .try_get::<Option<String>, _>("figi")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
         let currency = row
             .try_get::<String, _>("quote_currency")
             .map(Currency::from)?;
@@ -669,6 +672,7 @@ impl<'r> FromRow<'r, PgRow> for EquityModel {
             id,
             raw_symbol,
             isin,
+            figi,
             currency,
             price_precision as u8,
             price_increment,
@@ -695,6 +699,12 @@ impl<'r> FromRow<'r, PgRow> for FuturesContractModel {
         let asset_class = row
             .try_get::<AssetClassModel, _>("asset_class")
             .map(|res| res.0)?;
+        let isin = row
+            .try_get::<Option<String>, _>("isin")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let figi = row
+            .try_get::<Option<String>, _>("figi")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
         let exchange = row
             .try_get::<Option<String>, _>("exchange")
             .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
@@ -750,6 +760,8 @@ impl<'r> FromRow<'r, PgRow> for FuturesContractModel {
             raw_symbol,
             asset_class,
             exchange,
+            isin,
+            figi,
             underlying,
             activation_ns,
             expiration_ns,
@@ -784,6 +796,12 @@ impl<'r> FromRow<'r, PgRow> for OptionsContractModel {
         let asset_class = row
             .try_get::<AssetClassModel, _>("asset_class")
             .map(|res| res.0)?;
+        let isin = row
+            .try_get::<Option<String>, _>("isin")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let figi = row
+            .try_get::<Option<String>, _>("figi")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
         let exchange = row
             .try_get::<Option<String>, _>("exchange")
             .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
@@ -846,6 +864,8 @@ impl<'r> FromRow<'r, PgRow> for OptionsContractModel {
             raw_symbol,
             asset_class,
             exchange,
+            isin,
+            figi,
             underlying,
             option_kind,
             strike_price,
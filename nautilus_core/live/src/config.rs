@@ -0,0 +1,275 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the configuration for a live trading node, loadable from a file with
+//! [`nautilus_common::config::load_config`].
+//!
+//! Each integration adapter enabled by its feature flag has an optional section, with the
+//! adapter's clients created only when the section is present.
+
+use std::net::SocketAddr;
+
+use nautilus_common::{
+    cache::CacheConfig,
+    config::{check_setting, ValidateConfig},
+    logging::subscriber::{init_tracing_with_config, TracingConfig, TracingGuard},
+    monitor::MonitorConfig,
+    msgbus::database::MessageBusConfig,
+    telemetry::{exporter::init_telemetry, TelemetryConfig},
+};
+use nautilus_data::engine::config::DataEngineConfig;
+use nautilus_execution::engine::config::ExecutionEngineConfig;
+use nautilus_model::identifiers::TraderId;
+use nautilus_persistence::recorder::DataRecorderConfig;
+use nautilus_risk::engine::config::RiskEngineConfig;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "databento")]
+use nautilus_adapters::databento::config::DatabentoDataClientConfig;
+#[cfg(feature = "tardis")]
+use nautilus_adapters::tardis::config::TardisDataClientConfig;
+
+/// Configuration for a live trading node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LiveNodeConfig {
+    /// The trader ID for the node.
+    pub trader_id: TraderId,
+    /// The configuration for logging.
+    #[serde(default)]
+    pub logging: TracingConfig,
+    /// The configuration for the cache.
+    #[serde(default)]
+    pub cache: CacheConfig,
+    /// The configuration for the message bus.
+    #[serde(default)]
+    pub msgbus: MessageBusConfig,
+    /// The configuration for telemetry collection and export.
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+    /// The configuration for the monitoring WebSocket server.
+    #[serde(default)]
+    pub monitor: MonitorConfig,
+    /// The configuration for recording market data to a catalog (disabled if None).
+    #[serde(default)]
+    pub recorder: Option<DataRecorderConfig>,
+    /// The configuration for the data engine.
+    #[serde(default)]
+    pub data_engine: DataEngineConfig,
+    /// The configuration for the risk engine.
+    #[serde(default)]
+    pub risk_engine: RiskEngineConfig,
+    /// The configuration for the execution engine.
+    #[serde(default)]
+    pub exec_engine: ExecutionEngineConfig,
+    /// The configuration for the Databento data client (disabled if None).
+    #[cfg(feature = "databento")]
+    #[serde(default)]
+    pub databento: Option<DatabentoDataClientConfig>,
+    /// The configuration for the Tardis data client (disabled if None).
+    #[cfg(feature = "tardis")]
+    #[serde(default)]
+    pub tardis: Option<TardisDataClientConfig>,
+}
+
+impl LiveNodeConfig {
+    /// Initializes the global `tracing` subscriber from the `logging` config.
+    ///
+    /// The returned guard must be held for the life of the node, so that buffered file output
+    /// is flushed at exit.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the subscriber cannot be initialized.
+    pub fn init_logging(&self) -> anyhow::Result<TracingGuard> {
+        init_tracing_with_config(&self.logging)
+    }
+
+    /// Starts the Prometheus exporter if enabled by the `telemetry` config, returning the bound
+    /// address.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the configured address cannot be bound.
+    pub fn start_telemetry(&self) -> anyhow::Result<Option<SocketAddr>> {
+        init_telemetry(&self.telemetry)
+    }
+}
+
+impl ValidateConfig for LiveNodeConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.monitor.addr.is_none() || !self.monitor.topics.is_empty(),
+            "monitor.topics",
+            "at least one topic is required when the monitor is enabled",
+        )?;
+        for (setting, rate_limit) in [
+            (
+                "risk_engine.max_order_submit",
+                &self.risk_engine.max_order_submit,
+            ),
+            (
+                "risk_engine.max_order_modify",
+                &self.risk_engine.max_order_modify,
+            ),
+        ] {
+            check_setting(
+                rate_limit.limit > 0 && rate_limit.interval_ns > 0,
+                setting,
+                "limit and interval_ns must be positive",
+            )?;
+        }
+
+        if let Some(quality) = &self.data_engine.quality {
+            quality.validate()?;
+        }
+        self.data_engine.late_data.validate()?;
+        self.risk_engine.validate()?;
+        if let Some(recorder) = &self.recorder {
+            recorder.validate()?;
+        }
+
+        #[cfg(feature = "databento")]
+        if let Some(databento) = &self.databento {
+            databento.validate()?;
+        }
+        #[cfg(feature = "tardis")]
+        if let Some(tardis) = &self.tardis {
+            tardis.validate()?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(all(test, feature = "databento", feature = "tardis"))]
+mod tests {
+    use nautilus_common::{
+        config::{parse_config, ConfigFormat},
+        credentials::CredentialSource,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const CONFIG: &str = r#"
+trader_id = "TRADER-042"
+
+[logging]
+level = "debug"
+
+[risk_engine.max_order_submit]
+limit = 10
+interval_ns = 1_000_000_000
+
+[exec_engine]
+snapshot_positions = true
+
+[databento]
+api_key = { env = "DATABENTO_API_KEY" }
+dataset = "GLBX.MDP3"
+publishers_filepath = "publishers.json"
+
+[tardis]
+options = [{ exchange = "bitmex", symbols = ["XBTUSD"], dataTypes = ["trade"] }]
+"#;
+
+    #[rstest]
+    fn test_parse_live_node_config() {
+        let config: LiveNodeConfig = parse_config(CONFIG, ConfigFormat::Toml).unwrap();
+
+        assert_eq!(config.trader_id, TraderId::from("TRADER-042"));
+        assert_eq!(config.logging.level, "debug");
+        assert_eq!(config.risk_engine.max_order_submit.limit, 10);
+        assert_eq!(config.risk_engine.max_order_modify.limit, 100);
+        assert!(config.exec_engine.snapshot_positions);
+        assert!(config.monitor.addr.is_none());
+        assert!(config.recorder.is_none());
+        assert_eq!(
+            config.databento.unwrap().api_key,
+            CredentialSource::Env("DATABENTO_API_KEY".to_string())
+        );
+        assert_eq!(config.tardis.unwrap().options[0].data_types, vec!["trade"]);
+    }
+
+    #[rstest]
+    #[case("[exec_engine]", "[exec_engines]", "unknown field `exec_engines`")]
+    #[case(
+        "limit = 10",
+        "limit = 0",
+        "Invalid setting 'risk_engine.max_order_submit': limit and interval_ns must be positive"
+    )]
+    #[case(
+        "dataset = \"GLBX.MDP3\"",
+        "dataset = \"\"",
+        "Invalid setting 'databento.dataset': was empty"
+    )]
+    #[case(
+        "dataTypes = [\"trade\"]",
+        "dataTypes = []",
+        "Invalid setting 'tardis.options.data_types': no data types for bitmex"
+    )]
+    fn test_validation_errors(#[case] from: &str, #[case] to: &str, #[case] expected: &str) {
+        let text = CONFIG.replace(from, to);
+        let err = parse_config::<LiveNodeConfig>(&text, ConfigFormat::Toml).unwrap_err();
+        assert!(err.to_string().contains(expected), "{err}");
+    }
+
+    #[rstest]
+    fn test_parse_recorder_config() {
+        let text =
+            format!("{CONFIG}\n[recorder]\ncatalog_path = \"/data/catalog\"\nflush_rows = 500\n");
+        let config: LiveNodeConfig = parse_config(&text, ConfigFormat::Toml).unwrap();
+        let recorder = config.recorder.unwrap();
+        assert_eq!(
+            recorder,
+            DataRecorderConfig {
+                flush_rows: 500,
+                ..DataRecorderConfig::new("/data/catalog")
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_monitor_requires_topics() {
+        let text = format!("{CONFIG}\n[monitor]\naddr = \"127.0.0.1:9100\"\n");
+        let err = parse_config::<LiveNodeConfig>(&text, ConfigFormat::Toml).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid setting 'monitor.topics': at least one topic is required when the monitor is enabled"
+        );
+    }
+
+    #[rstest]
+    fn test_start_telemetry_from_config() {
+        let config: LiveNodeConfig = parse_config(CONFIG, ConfigFormat::Toml).unwrap();
+        assert!(config.start_telemetry().unwrap().is_none());
+
+        let text = format!("{CONFIG}\n[telemetry]\nprometheus_addr = \"127.0.0.1:0\"\n");
+        let config: LiveNodeConfig = parse_config(&text, ConfigFormat::Toml).unwrap();
+        let addr = config.start_telemetry().unwrap().unwrap();
+        assert!(addr.ip().is_loopback());
+        assert_ne!(addr.port(), 0);
+    }
+
+    #[rstest]
+    fn test_init_logging_rejects_invalid_levels() {
+        let text = CONFIG.replace("level = \"debug\"", "level = \"nautilus=verbose\"");
+        let config: LiveNodeConfig = parse_config(&text, ConfigFormat::Toml).unwrap();
+        assert!(config.init_logging().is_err());
+    }
+}
@@ -0,0 +1,87 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nautilus_model::types::{money::Money, price::Price, quantity::Quantity};
+
+fn bench_price(c: &mut Criterion) {
+    let mut group = c.benchmark_group("price");
+    let price1 = Price::from("1.00001");
+    let price2 = Price::from("0.00010");
+
+    group.bench_function("add", |b| {
+        b.iter(|| black_box(price1) + black_box(price2));
+    });
+    group.bench_function("sub", |b| {
+        b.iter(|| black_box(price1) - black_box(price2));
+    });
+    group.bench_function("mul_f64", |b| {
+        b.iter(|| black_box(price1) * black_box(2.0));
+    });
+    group.bench_function("cmp", |b| {
+        b.iter(|| black_box(price1) > black_box(price2));
+    });
+    group.bench_function("from_str", |b| {
+        b.iter(|| Price::from(black_box("1.00001")));
+    });
+
+    group.finish();
+}
+
+fn bench_quantity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("quantity");
+    let qty1 = Quantity::from("100.5");
+    let qty2 = Quantity::from("0.5");
+
+    group.bench_function("add", |b| {
+        b.iter(|| black_box(qty1) + black_box(qty2));
+    });
+    group.bench_function("sub", |b| {
+        b.iter(|| black_box(qty1) - black_box(qty2));
+    });
+
+    group.finish();
+}
+
+fn bench_money(c: &mut Criterion) {
+    let mut group = c.benchmark_group("money");
+    let money1 = Money::from("1000.00 USD");
+    let money2 = Money::from("0.01 USD");
+
+    group.bench_function("add", |b| {
+        b.iter(|| black_box(money1) + black_box(money2));
+    });
+    group.bench_function("sub", |b| {
+        b.iter(|| black_box(money1) - black_box(money2));
+    });
+    group.bench_function("add_assign", |b| {
+        b.iter(|| {
+            let mut money = black_box(money1);
+            money += black_box(money2);
+            money
+        });
+    });
+    group.bench_function("mul_f64", |b| {
+        b.iter(|| black_box(money1) * black_box(1.5));
+    });
+    group.bench_function("from_str", |b| {
+        b.iter(|| Money::from(black_box("1000.00 USD")));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_price, bench_quantity, bench_money);
+criterion_main!(benches);
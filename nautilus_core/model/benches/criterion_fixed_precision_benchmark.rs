@@ -1,5 +1,9 @@
 use criterion::{black_box, criterion_group, Criterion};
-use nautilus_model::types::fixed::f64_to_fixed_i64;
+use nautilus_model::types::fixed::{
+    f64_slice_to_fixed_i64, f64_to_fixed_i64, fixed_i64_slice_to_f64, fixed_i64_to_f64,
+};
+
+const BATCH_SIZE: usize = 1_000_000;
 
 pub fn criterion_fixed_precision_benchmark(c: &mut Criterion) {
     c.bench_function("f64_to_fixed_i64", |b| {
@@ -7,5 +11,39 @@ pub fn criterion_fixed_precision_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, criterion_fixed_precision_benchmark);
+pub fn criterion_fixed_precision_batch_benchmark(c: &mut Criterion) {
+    let values: Vec<f64> = (0..BATCH_SIZE).map(|i| i as f64 * 0.000_01).collect();
+    let raws: Vec<i64> = f64_slice_to_fixed_i64(&values, 5);
+
+    let mut group = c.benchmark_group("fixed_precision_batch");
+    group.bench_function("f64_to_fixed_i64_loop", |b| {
+        b.iter(|| {
+            black_box(&values)
+                .iter()
+                .map(|value| f64_to_fixed_i64(*value, 5))
+                .collect::<Vec<i64>>()
+        });
+    });
+    group.bench_function("f64_slice_to_fixed_i64", |b| {
+        b.iter(|| f64_slice_to_fixed_i64(black_box(&values), black_box(5)));
+    });
+    group.bench_function("fixed_i64_to_f64_loop", |b| {
+        b.iter(|| {
+            black_box(&raws)
+                .iter()
+                .map(|raw| fixed_i64_to_f64(*raw))
+                .collect::<Vec<f64>>()
+        });
+    });
+    group.bench_function("fixed_i64_slice_to_f64", |b| {
+        b.iter(|| fixed_i64_slice_to_f64(black_box(&raws)));
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    criterion_fixed_precision_benchmark,
+    criterion_fixed_precision_batch_benchmark
+);
 criterion::criterion_main!(benches);
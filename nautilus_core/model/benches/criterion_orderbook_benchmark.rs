@@ -0,0 +1,151 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use nautilus_model::{
+    data::{delta::OrderBookDelta, order::BookOrder},
+    enums::{BookAction, BookStorage, BookType, OrderSide},
+    identifiers::InstrumentId,
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+
+const LEVELS: u64 = 1_000;
+
+fn delta(
+    action: BookAction,
+    side: OrderSide,
+    level: u64,
+    size: u64,
+    sequence: u64,
+) -> OrderBookDelta {
+    let raw_price = match side {
+        OrderSide::Buy => 100_000 - level as i64,
+        _ => 100_001 + level as i64,
+    };
+    let order = BookOrder::new(
+        side,
+        Price::from_raw(raw_price * 10_000_000, 2),
+        Quantity::from_raw(size * 1_000_000_000, 0),
+        sequence,
+    );
+    OrderBookDelta::new(
+        InstrumentId::from("AAPL.XNAS"),
+        action,
+        order,
+        0,
+        sequence,
+        sequence.into(),
+        sequence.into(),
+    )
+}
+
+fn deltas(action: BookAction, size: u64) -> Vec<OrderBookDelta> {
+    (0..LEVELS)
+        .flat_map(|level| {
+            [
+                delta(action, OrderSide::Buy, level, size, level * 2),
+                delta(action, OrderSide::Sell, level, size, level * 2 + 1),
+            ]
+        })
+        .collect()
+}
+
+fn filled_book(book_type: BookType, storage: BookStorage) -> OrderBook {
+    let mut book = OrderBook::with_storage(InstrumentId::from("AAPL.XNAS"), book_type, storage);
+    for delta in &deltas(BookAction::Add, 100) {
+        book.apply_delta(delta);
+    }
+    book
+}
+
+fn bench_apply_delta(c: &mut Criterion) {
+    let adds = deltas(BookAction::Add, 100);
+    let updates = deltas(BookAction::Update, 200);
+    let deletes = deltas(BookAction::Delete, 0);
+
+    let mut group = c.benchmark_group("orderbook_apply_delta");
+    group.throughput(Throughput::Elements(adds.len() as u64));
+
+    for (book_type, storage) in
+        [BookType::L2_MBP, BookType::L3_MBO]
+            .into_iter()
+            .flat_map(|book_type| {
+                [BookStorage::BTreeMap, BookStorage::SortedVec].map(|storage| (book_type, storage))
+            })
+    {
+        group.bench_function(format!("add_{book_type}_{storage}"), |b| {
+            b.iter_batched_ref(
+                || OrderBook::with_storage(InstrumentId::from("AAPL.XNAS"), book_type, storage),
+                |book| {
+                    for delta in &adds {
+                        book.apply_delta(black_box(delta));
+                    }
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_function(format!("update_{book_type}_{storage}"), |b| {
+            b.iter_batched_ref(
+                || filled_book(book_type, storage),
+                |book| {
+                    for delta in &updates {
+                        book.apply_delta(black_box(delta));
+                    }
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_function(format!("delete_{book_type}_{storage}"), |b| {
+            b.iter_batched_ref(
+                || filled_book(book_type, storage),
+                |book| {
+                    for delta in &deletes {
+                        book.apply_delta(black_box(delta));
+                    }
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("orderbook_queries");
+
+    for storage in [BookStorage::BTreeMap, BookStorage::SortedVec] {
+        let book = filled_book(BookType::L2_MBP, storage);
+
+        group.bench_function(format!("best_bid_price_{storage}"), |b| {
+            b.iter(|| black_box(&book).best_bid_price());
+        });
+        group.bench_function(format!("spread_{storage}"), |b| {
+            b.iter(|| black_box(&book).spread());
+        });
+        group.bench_function(format!("get_avg_px_for_quantity_{storage}"), |b| {
+            let qty = Quantity::from(10_000);
+            b.iter(|| black_box(&book).get_avg_px_for_quantity(black_box(qty), OrderSide::Buy));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_apply_delta, bench_queries);
+criterion_main!(benches);
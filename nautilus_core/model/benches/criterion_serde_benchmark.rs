@@ -0,0 +1,55 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nautilus_core::serialization::Serializable;
+use nautilus_model::data::{
+    bar::Bar,
+    delta::OrderBookDelta,
+    depth::OrderBookDepth10,
+    quote::QuoteTick,
+    stubs::{quote_ethusdt_binance, stub_bar, stub_delta, stub_depth10, stub_trade_ethusdt_buyer},
+    trade::TradeTick,
+};
+
+fn bench_round_trip<T: Serializable>(c: &mut Criterion, name: &str, value: &T) {
+    let mut group = c.benchmark_group(format!("serde_{name}"));
+
+    group.bench_function("json_round_trip", |b| {
+        b.iter(|| {
+            let bytes = black_box(value).as_json_bytes().unwrap();
+            T::from_json_bytes(&bytes).unwrap()
+        });
+    });
+    group.bench_function("msgpack_round_trip", |b| {
+        b.iter(|| {
+            let bytes = black_box(value).as_msgpack_bytes().unwrap();
+            T::from_msgpack_bytes(&bytes).unwrap()
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_serde(c: &mut Criterion) {
+    bench_round_trip::<QuoteTick>(c, "quote", &quote_ethusdt_binance());
+    bench_round_trip::<TradeTick>(c, "trade", &stub_trade_ethusdt_buyer());
+    bench_round_trip::<Bar>(c, "bar", &stub_bar());
+    bench_round_trip::<OrderBookDelta>(c, "delta", &stub_delta());
+    bench_round_trip::<OrderBookDepth10>(c, "depth10", &stub_depth10());
+}
+
+criterion_group!(benches, bench_serde);
+criterion_main!(benches);
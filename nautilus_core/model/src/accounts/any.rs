@@ -13,6 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -20,6 +22,7 @@ use crate::{
     enums::AccountType,
     events::account::state::AccountState,
     identifiers::AccountId,
+    types::{currency::Currency, money::Money},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +40,38 @@ impl AccountAny {
         }
     }
 
+    #[must_use]
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            AccountAny::Margin(margin) => margin.account_type(),
+            AccountAny::Cash(cash) => cash.account_type(),
+        }
+    }
+
+    #[must_use]
+    pub fn base_currency(&self) -> Option<Currency> {
+        match self {
+            AccountAny::Margin(margin) => margin.base_currency(),
+            AccountAny::Cash(cash) => cash.base_currency(),
+        }
+    }
+
+    #[must_use]
+    pub fn balances_total(&self) -> HashMap<Currency, Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balances_total(),
+            AccountAny::Cash(cash) => cash.balances_total(),
+        }
+    }
+
+    #[must_use]
+    pub fn balances_free(&self) -> HashMap<Currency, Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balances_free(),
+            AccountAny::Cash(cash) => cash.balances_free(),
+        }
+    }
+
     pub fn last_event(&self) -> Option<AccountState> {
         match self {
             AccountAny::Margin(margin) => margin.last_event(),
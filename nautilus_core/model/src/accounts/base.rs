@@ -19,10 +19,10 @@ use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{AccountType, LiquiditySide, OrderSide},
+    enums::{AccountType, LiquiditySide, OrderSide, PositionSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
     identifiers::AccountId,
-    instruments::any::InstrumentAny,
+    instruments::{any::InstrumentAny, ndf::Ndf},
     position::Position,
     types::{
         balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
@@ -135,6 +135,36 @@ impl BaseAccount {
         }
     }
 
+    /// Applies the given `adjustment` (such as a funding or financing payment) to the total and
+    /// free balances for its currency.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the adjustment would result in a negative total
+    /// balance, in which case the balances are unchanged.
+    pub fn apply_balance_adjustment(&mut self, adjustment: Money) -> anyhow::Result<()> {
+        let currency = adjustment.currency;
+        let zero = Money::new(0.0, currency);
+        let balance = self
+            .balances
+            .get(&currency)
+            .copied()
+            .unwrap_or_else(|| AccountBalance::new(zero, zero, zero));
+        let total = balance.total + adjustment;
+        if total.raw < 0 {
+            anyhow::bail!(
+                "Cannot apply adjustment {adjustment}: total balance {} would become negative",
+                balance.total
+            );
+        }
+        self.update_balances(vec![AccountBalance::new(
+            total,
+            balance.locked,
+            balance.free + adjustment,
+        )]);
+        Ok(())
+    }
+
     pub fn base_apply(&mut self, event: AccountState) {
         self.update_balances(event.balances.clone());
         self.events.push(event);
@@ -181,6 +211,10 @@ impl BaseAccount {
         fill: OrderFilled,
         position: Option<Position>,
     ) -> anyhow::Result<Vec<Money>> {
+        if let InstrumentAny::Ndf(ndf) = &instrument {
+            return Ok(Self::calculate_ndf_pnls(ndf, &fill, position));
+        }
+
         let mut pnls: HashMap<Currency, Money> = HashMap::new();
         let quote_currency = instrument.quote_currency();
         let base_currency = instrument.base_currency();
@@ -217,6 +251,22 @@ impl BaseAccount {
         Ok(pnls.into_values().collect())
     }
 
+    /// Returns the cash settlement for the `fill` of a non-deliverable forward, which exchanges
+    /// no currencies when traded and is settled in the settlement currency when the `position`
+    /// (prior to the fill) is reduced, at the fixing rate for fills on the fixing date.
+    fn calculate_ndf_pnls(ndf: &Ndf, fill: &OrderFilled, position: Option<Position>) -> Vec<Money> {
+        let Some(position) = position else {
+            return Vec::new();
+        };
+        let quantity = position.quantity.as_f64().min(fill.last_qty.as_f64());
+        let signed_qty = match (position.side, fill.order_side) {
+            (PositionSide::Long, OrderSide::Sell) => quantity,
+            (PositionSide::Short, OrderSide::Buy) => -quantity,
+            _ => return Vec::new(),
+        };
+        vec![ndf.settlement_amount(signed_qty, position.avg_px_open, fill.last_px.as_f64())]
+    }
+
     pub fn base_calculate_commission(
         &self,
         instrument: InstrumentAny,
@@ -19,16 +19,14 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+use nautilus_core::uuid::UUID4;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     accounts::base::{Account, BaseAccount},
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
-    identifiers::{
-        stubs::{account_id, uuid4},
-        AccountId,
-    },
+    identifiers::AccountId,
     instruments::any::InstrumentAny,
     position::Position,
     types::{
@@ -225,7 +223,7 @@ impl Default for CashAccount {
     fn default() -> Self {
         // million dollar account
         let init_event = AccountState::new(
-            account_id(),
+            AccountId::default(),
             AccountType::Cash,
             vec![AccountBalance::new(
                 Money::from("1000000 USD"),
@@ -234,7 +232,7 @@ impl Default for CashAccount {
             )],
             vec![],
             true,
-            uuid4(),
+            UUID4::from("16578139-a945-4b65-b46c-bc131a15d8e7"),
             0.into(),
             0.into(),
             Some(Currency::USD()),
@@ -259,7 +257,7 @@ mod tests {
         identifiers::{position_id::PositionId, AccountId},
         instruments::{
             any::InstrumentAny, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-            equity::Equity, stubs::*, Instrument,
+            equity::Equity, ndf::Ndf, stubs::*, Instrument,
         },
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         position::Position,
@@ -560,6 +558,59 @@ mod tests {
         assert_eq!(result2_set, result2_expected);
     }
 
+    #[rstest]
+    fn test_calculate_pnls_for_ndf_cash_settles_on_reduce(
+        cash_account_million_usd: CashAccount,
+        ndf_usdinr_1m: Ndf,
+    ) {
+        let ndf = InstrumentAny::Ndf(ndf_usdinr_1m);
+        let order1 = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(ndf.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1000000"))
+            .build();
+        let fill1 = TestOrderEventStubs::order_filled(
+            &order1,
+            &ndf,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("83.3700")),
+            None,
+            None,
+            None,
+            None,
+            Some(AccountId::from("SIM-001")),
+        );
+        let position = Position::new(&ndf, fill1.clone().into());
+        // No currencies are exchanged when opening
+        let result1 = cash_account_million_usd
+            .calculate_pnls(ndf.clone(), fill1.into(), Some(position.clone()))
+            .unwrap();
+        assert!(result1.is_empty());
+
+        let order2 = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(ndf.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("1000000"))
+            .build();
+        let fill2 = TestOrderEventStubs::order_filled(
+            &order2,
+            &ndf,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("84.0000")),
+            None,
+            None,
+            None,
+            None,
+            Some(AccountId::from("SIM-001")),
+        );
+        let result2 = cash_account_million_usd
+            .calculate_pnls(ndf, fill2.into(), Some(position))
+            .unwrap();
+        assert_eq!(result2, vec![Money::from("7500.00 USD")]);
+    }
+
     #[rstest]
     #[case(false, Money::from("-0.00218331 BTC"))]
     #[case(true, Money::from("-25.0 USD"))]
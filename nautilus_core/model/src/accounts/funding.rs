@@ -0,0 +1,356 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Funding payment accrual for perpetual positions.
+
+use std::collections::HashMap;
+
+use nautilus_core::nanos::UnixNanos;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    accounts::base::BaseAccount,
+    data::funding::FundingRateUpdate,
+    enums::PositionSide,
+    identifiers::{InstrumentId, PositionId},
+    position::Position,
+    types::{money::Money, price::Price},
+};
+
+/// Represents a funding payment booked for a position.
+///
+/// A positive `amount` was received by the position, a negative `amount` was paid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundingPayment {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub rate: Decimal,
+    pub mark_price: Price,
+    pub amount: Money,
+    pub ts_event: UnixNanos,
+}
+
+/// Returns the funding payment for the given `position` at the funding `rate` and `mark_price`.
+///
+/// With a positive rate long positions pay and short positions receive, and vice versa.
+#[must_use]
+pub fn calculate_funding_payment(position: &Position, rate: Decimal, mark_price: Price) -> Money {
+    let notional = position.notional_value(mark_price);
+    let amount = (notional.as_decimal() * rate).to_f64().unwrap();
+    match position.side {
+        PositionSide::Long => Money::new(-amount, notional.currency),
+        PositionSide::Short => Money::new(amount, notional.currency),
+        PositionSide::Flat | PositionSide::NoPositionSide => Money::new(0.0, notional.currency),
+    }
+}
+
+/// Books periodic funding payments for open perpetual positions to an account, and keeps the
+/// funding payment history per position.
+#[derive(Clone, Debug, Default)]
+pub struct FundingLedger {
+    payments: HashMap<PositionId, Vec<FundingPayment>>,
+    last_funding_ns: HashMap<InstrumentId, UnixNanos>,
+}
+
+impl FundingLedger {
+    /// Creates a new empty [`FundingLedger`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the funding rate `update` to all open `positions` for its instrument, booking
+    /// the net payment to the `account` balances and returning the payments.
+    ///
+    /// Updates at or before the last applied funding time for the instrument are ignored, so
+    /// each funding period is only booked once.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the net payment cannot be booked to the `account`,
+    /// in which case the funding period is not recorded.
+    pub fn apply_funding(
+        &mut self,
+        account: &mut BaseAccount,
+        update: &FundingRateUpdate,
+        mark_price: Price,
+        positions: &[&Position],
+    ) -> anyhow::Result<Vec<FundingPayment>> {
+        let Some(payments) = self.calculate_payments(update, mark_price, positions) else {
+            return Ok(Vec::new());
+        };
+        if let Some(first) = payments.first() {
+            let net = payments
+                .iter()
+                .skip(1)
+                .fold(first.amount, |total, payment| total + payment.amount);
+            account.apply_balance_adjustment(net)?;
+        }
+        self.record(update, &payments);
+        Ok(payments)
+    }
+
+    /// Records the funding payments for the funding rate `update` to all open `positions` for
+    /// its instrument and returns them, leaving the booking to the caller (such as a simulated
+    /// exchange which books payments through account state events).
+    ///
+    /// Updates at or before the last applied funding time for the instrument are ignored.
+    pub fn process(
+        &mut self,
+        update: &FundingRateUpdate,
+        mark_price: Price,
+        positions: &[&Position],
+    ) -> Vec<FundingPayment> {
+        let Some(payments) = self.calculate_payments(update, mark_price, positions) else {
+            return Vec::new();
+        };
+        self.record(update, &payments);
+        payments
+    }
+
+    fn calculate_payments(
+        &self,
+        update: &FundingRateUpdate,
+        mark_price: Price,
+        positions: &[&Position],
+    ) -> Option<Vec<FundingPayment>> {
+        if self
+            .last_funding_ns
+            .get(&update.instrument_id)
+            .is_some_and(|last| update.ts_event <= *last)
+        {
+            return None;
+        }
+
+        let payments = positions
+            .iter()
+            .filter(|p| p.instrument_id == update.instrument_id && p.is_open())
+            .map(|position| FundingPayment {
+                position_id: position.id,
+                instrument_id: update.instrument_id,
+                rate: update.rate,
+                mark_price,
+                amount: calculate_funding_payment(position, update.rate, mark_price),
+                ts_event: update.ts_event,
+            })
+            .collect();
+        Some(payments)
+    }
+
+    fn record(&mut self, update: &FundingRateUpdate, payments: &[FundingPayment]) {
+        self.last_funding_ns
+            .insert(update.instrument_id, update.ts_event);
+        for payment in payments {
+            self.payments
+                .entry(payment.position_id)
+                .or_default()
+                .push(*payment);
+        }
+    }
+
+    /// Returns the funding payment history for the given `position_id`.
+    #[must_use]
+    pub fn payments(&self, position_id: &PositionId) -> &[FundingPayment] {
+        self.payments
+            .get(position_id)
+            .map_or(&[], |payments| payments.as_slice())
+    }
+
+    /// Returns the cumulative funding PnL for the given `position_id` (if any payments).
+    #[must_use]
+    pub fn funding_pnl(&self, position_id: &PositionId) -> Option<Money> {
+        let payments = self.payments(position_id);
+        let first = payments.first()?;
+        Some(
+            payments
+                .iter()
+                .skip(1)
+                .fold(first.amount, |total, payment| total + payment.amount),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        accounts::{base::Account, cash::CashAccount},
+        enums::{AccountType, OrderSide, OrderType},
+        events::account::state::AccountState,
+        identifiers::{stubs::uuid4, AccountId},
+        instruments::{any::InstrumentAny, crypto_perpetual::CryptoPerpetual, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{balance::AccountBalance, currency::Currency, quantity::Quantity},
+    };
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, id: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new(id)),
+            Some(Price::from("2000.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    fn funding_rate(rate: Decimal, ts_event: u64) -> FundingRateUpdate {
+        FundingRateUpdate::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            rate,
+            None,
+            ts_event.into(),
+            ts_event.into(),
+        )
+    }
+
+    #[fixture]
+    fn account() -> CashAccount {
+        let state = AccountState::new(
+            AccountId::new("BINANCE-001"),
+            AccountType::Cash,
+            vec![AccountBalance::new(
+                Money::from("1000000 USDT"),
+                Money::from("0 USDT"),
+                Money::from("1000000 USDT"),
+            )],
+            vec![],
+            true,
+            uuid4(),
+            0.into(),
+            0.into(),
+            None,
+        );
+        CashAccount::new(state, false)
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, dec!(0.0001), "-0.2 USDT")]
+    #[case(OrderSide::Sell, dec!(0.0001), "0.2 USDT")]
+    #[case(OrderSide::Buy, dec!(-0.0001), "0.2 USDT")]
+    fn test_calculate_funding_payment(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] rate: Decimal,
+        #[case] expected: &str,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let position = position(&instrument, side, "P-1");
+        assert_eq!(
+            calculate_funding_payment(&position, rate, Price::from("2000.00")),
+            Money::from(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_apply_funding_books_payments_and_history(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        mut account: CashAccount,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let long = position(&instrument, OrderSide::Buy, "P-1");
+        let short = position(&instrument, OrderSide::Sell, "P-2");
+        let mut ledger = FundingLedger::new();
+
+        let payments = ledger
+            .apply_funding(
+                &mut account,
+                &funding_rate(dec!(0.0001), 1),
+                Price::from("2000.00"),
+                &[&long],
+            )
+            .unwrap();
+        assert_eq!(payments.len(), 1);
+        ledger
+            .apply_funding(
+                &mut account,
+                &funding_rate(dec!(0.0002), 2),
+                Price::from("2500.00"),
+                &[&long, &short],
+            )
+            .unwrap();
+
+        assert_eq!(ledger.payments(&long.id).len(), 2);
+        assert_eq!(ledger.funding_pnl(&long.id), Some(Money::from("-0.7 USDT")));
+        assert_eq!(ledger.funding_pnl(&short.id), Some(Money::from("0.5 USDT")));
+        assert_eq!(
+            account.balance_total(Some(Currency::USDT())),
+            Some(Money::from("999999.8 USDT"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_funding_ignores_already_applied_period(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        mut account: CashAccount,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let long = position(&instrument, OrderSide::Buy, "P-1");
+        let mut ledger = FundingLedger::new();
+        let update = funding_rate(dec!(0.0001), 1);
+
+        ledger
+            .apply_funding(&mut account, &update, Price::from("2000.00"), &[&long])
+            .unwrap();
+        let payments = ledger
+            .apply_funding(&mut account, &update, Price::from("2000.00"), &[&long])
+            .unwrap();
+
+        assert!(payments.is_empty());
+        assert_eq!(ledger.payments(&long.id).len(), 1);
+        assert!(ledger.funding_pnl(&PositionId::new("P-2")).is_none());
+    }
+
+    #[rstest]
+    fn test_apply_funding_with_debit_exceeding_balance(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        mut account: CashAccount,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let long = position(&instrument, OrderSide::Buy, "P-1");
+        let mut ledger = FundingLedger::new();
+
+        let result = ledger.apply_funding(
+            &mut account,
+            &funding_rate(dec!(600), 1),
+            Price::from("2000.00"),
+            &[&long],
+        );
+
+        assert!(result.is_err());
+        assert!(ledger.payments(&long.id).is_empty());
+        assert_eq!(
+            account.balance_total(Some(Currency::USDT())),
+            Some(Money::from("1000000 USDT"))
+        );
+    }
+}
@@ -26,7 +26,10 @@ use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    accounts::base::{Account, BaseAccount},
+    accounts::{
+        base::{Account, BaseAccount},
+        tiers::MarginTierSchedule,
+    },
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
     identifiers::{AccountId, InstrumentId},
@@ -51,6 +54,7 @@ pub struct MarginAccount {
     pub leverages: HashMap<InstrumentId, f64>,
     pub margins: HashMap<InstrumentId, MarginBalance>,
     pub default_leverage: f64,
+    pub margin_tiers: HashMap<InstrumentId, MarginTierSchedule>,
 }
 
 impl MarginAccount {
@@ -61,6 +65,7 @@ impl MarginAccount {
             leverages: HashMap::new(),
             margins: HashMap::new(),
             default_leverage: 1.0,
+            margin_tiers: HashMap::new(),
         }
     }
 
@@ -80,6 +85,28 @@ impl MarginAccount {
             .unwrap_or(&self.default_leverage)
     }
 
+    /// Sets the margin tier schedule for the given `instrument_id`, which caps the leverage and
+    /// sets the maintenance margin rate by position notional value.
+    pub fn set_margin_tiers(&mut self, instrument_id: InstrumentId, schedule: MarginTierSchedule) {
+        self.margin_tiers.insert(instrument_id, schedule);
+    }
+
+    #[must_use]
+    pub fn margin_tiers(&self, instrument_id: &InstrumentId) -> Option<&MarginTierSchedule> {
+        self.margin_tiers.get(instrument_id)
+    }
+
+    /// Returns the leverage for a position with the given `notional` value, being the account
+    /// leverage for the instrument capped by the max leverage of its margin tier (if any).
+    #[must_use]
+    pub fn effective_leverage(&self, instrument_id: &InstrumentId, notional: f64) -> f64 {
+        let leverage = self.get_leverage(instrument_id);
+        match self.margin_tiers.get(instrument_id) {
+            Some(schedule) => leverage.min(schedule.max_leverage(notional)),
+            None => leverage,
+        }
+    }
+
     #[must_use]
     pub fn is_unleveraged(&self, instrument_id: InstrumentId) -> bool {
         self.get_leverage(&instrument_id) == 1.0
@@ -189,6 +216,7 @@ impl MarginAccount {
             self.leverages
                 .insert(instrument.id(), self.default_leverage);
         }
+        let leverage = self.effective_leverage(&instrument.id(), notional.as_f64());
         let adjusted_notional = notional / leverage;
         let initial_margin_f64 = instrument.margin_init().to_f64().unwrap();
         let mut margin = adjusted_notional * initial_margin_f64;
@@ -215,9 +243,12 @@ impl MarginAccount {
             self.leverages
                 .insert(instrument.id(), self.default_leverage);
         }
+        let leverage = self.effective_leverage(&instrument.id(), notional.as_f64());
         let adjusted_notional = notional / leverage;
-        let margin_maint_f64 = instrument.margin_maint().to_f64().unwrap();
-        let mut margin = adjusted_notional * margin_maint_f64;
+        let mut margin = match self.margin_tiers.get(&instrument.id()) {
+            Some(schedule) => schedule.maintenance_margin(notional.as_f64()),
+            None => adjusted_notional * instrument.margin_maint().to_f64().unwrap(),
+        };
         // Add taker fee
         margin += adjusted_notional * instrument.taker_fee().to_f64().unwrap();
         let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
@@ -412,7 +443,7 @@ mod tests {
     use rstest::rstest;
 
     use crate::{
-        accounts::{base::Account, margin::MarginAccount, stubs::*},
+        accounts::{base::Account, margin::MarginAccount, stubs::*, tiers::MarginTierSchedule},
         events::account::{state::AccountState, stubs::*},
         identifiers::{stubs::*, InstrumentId},
         instruments::{crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, stubs::*},
@@ -650,4 +681,34 @@ mod tests {
         );
         assert_eq!(result, Money::from("0.00042500 BTC"));
     }
+
+    #[rstest]
+    fn test_margin_tiers_cap_leverage_and_set_maintenance_rate(
+        mut margin_account: MarginAccount,
+        margin_tier_schedule: MarginTierSchedule,
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let instrument_id = currency_pair_btcusdt.id;
+        margin_account.set_leverage(instrument_id, 125.0);
+        margin_account.set_margin_tiers(instrument_id, margin_tier_schedule);
+
+        // 1,000,000 USDT notional is in the 50x tier
+        assert_eq!(
+            margin_account.effective_leverage(&instrument_id, 1_000_000.0),
+            50.0
+        );
+        assert_eq!(
+            margin_account.effective_leverage(&instrument_id, 10_000.0),
+            125.0
+        );
+
+        let result = margin_account.calculate_maintenance_margin(
+            currency_pair_btcusdt,
+            Quantity::from("20.000000"),
+            Price::from("50000.00"),
+            None,
+        );
+        // Tier maintenance of 8,700 USDT plus the taker fee on the 20,000 USDT margin
+        assert_eq!(result, Money::from("8720.00000000 USDT"));
+    }
 }
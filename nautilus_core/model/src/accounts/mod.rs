@@ -18,7 +18,9 @@
 pub mod any;
 pub mod base;
 pub mod cash;
+pub mod funding;
 pub mod margin;
+pub mod tiers;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -14,9 +14,15 @@
 // -------------------------------------------------------------------------------------------------
 
 use rstest::fixture;
+use rust_decimal_macros::dec;
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{
+        base::Account,
+        cash::CashAccount,
+        margin::MarginAccount,
+        tiers::{MarginTier, MarginTierSchedule},
+    },
     enums::LiquiditySide,
     events::account::{state::AccountState, stubs::*},
     instruments::any::InstrumentAny,
@@ -43,6 +49,18 @@ pub fn cash_account_multi(cash_account_state_multi: AccountState) -> CashAccount
     CashAccount::new(cash_account_state_multi, true)
 }
 
+// Binance USD-M BTCUSDT perpetual brackets
+#[fixture]
+pub fn margin_tier_schedule() -> MarginTierSchedule {
+    MarginTierSchedule::new(vec![
+        MarginTier::new(dec!(50_000), dec!(125), dec!(0.004), dec!(0)),
+        MarginTier::new(dec!(250_000), dec!(100), dec!(0.005), dec!(50)),
+        MarginTier::new(dec!(1_000_000), dec!(50), dec!(0.01), dec!(1_300)),
+        MarginTier::new(dec!(10_000_000), dec!(20), dec!(0.025), dec!(16_300)),
+    ])
+    .unwrap()
+}
+
 #[must_use]
 pub fn calculate_commission(
     instrument: InstrumentAny,
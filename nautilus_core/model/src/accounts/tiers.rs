@@ -0,0 +1,169 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tiered margin schedules, where the maximum leverage and maintenance margin rate of a position
+//! depend on its notional value, as used by Binance and Bybit for derivatives.
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+/// Represents a notional band of a [`MarginTierSchedule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MarginTier {
+    /// The maximum position notional value for the tier (inclusive).
+    pub notional_cap: Decimal,
+    /// The maximum leverage for positions in the tier.
+    pub max_leverage: Decimal,
+    /// The maintenance margin rate for positions in the tier.
+    pub maintenance_rate: Decimal,
+    /// The maintenance amount deducted from the maintenance margin, which keeps the margin
+    /// continuous across tier boundaries.
+    pub maintenance_amount: Decimal,
+}
+
+impl MarginTier {
+    /// Creates a new [`MarginTier`] instance.
+    #[must_use]
+    pub const fn new(
+        notional_cap: Decimal,
+        max_leverage: Decimal,
+        maintenance_rate: Decimal,
+        maintenance_amount: Decimal,
+    ) -> Self {
+        Self {
+            notional_cap,
+            max_leverage,
+            maintenance_rate,
+            maintenance_amount,
+        }
+    }
+}
+
+/// Represents the margin tiers for an instrument, ordered by increasing notional cap.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MarginTierSchedule {
+    tiers: Vec<MarginTier>,
+}
+
+impl MarginTierSchedule {
+    /// Creates a new [`MarginTierSchedule`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `tiers` is empty.
+    /// - If the notional caps are not strictly increasing.
+    /// - If any max leverage is not positive, or any maintenance rate is not in [0, 1).
+    pub fn new(tiers: Vec<MarginTier>) -> anyhow::Result<Self> {
+        anyhow::ensure!(!tiers.is_empty(), "Margin tier schedule was empty");
+        for (i, tier) in tiers.iter().enumerate() {
+            anyhow::ensure!(
+                tier.max_leverage > Decimal::ZERO,
+                "Invalid max leverage for margin tier {i}, was {}",
+                tier.max_leverage
+            );
+            anyhow::ensure!(
+                tier.maintenance_rate >= Decimal::ZERO && tier.maintenance_rate < Decimal::ONE,
+                "Invalid maintenance rate for margin tier {i}, was {}",
+                tier.maintenance_rate
+            );
+            if i > 0 {
+                anyhow::ensure!(
+                    tier.notional_cap > tiers[i - 1].notional_cap,
+                    "Margin tier notional caps must be strictly increasing, was {} after {}",
+                    tier.notional_cap,
+                    tiers[i - 1].notional_cap
+                );
+            }
+        }
+        Ok(Self { tiers })
+    }
+
+    #[must_use]
+    pub fn tiers(&self) -> &[MarginTier] {
+        &self.tiers
+    }
+
+    /// Returns the tier for a position with the given `notional` value.
+    ///
+    /// Notional values above the last cap use the last tier.
+    #[must_use]
+    pub fn tier(&self, notional: f64) -> &MarginTier {
+        let notional = Decimal::try_from(notional.abs()).unwrap_or(Decimal::MAX);
+        self.tiers
+            .iter()
+            .find(|tier| notional <= tier.notional_cap)
+            .unwrap_or_else(|| self.tiers.last().expect("schedule was not empty"))
+    }
+
+    /// Returns the maximum leverage for a position with the given `notional` value.
+    #[must_use]
+    pub fn max_leverage(&self, notional: f64) -> f64 {
+        self.tier(notional).max_leverage.to_f64().unwrap()
+    }
+
+    /// Returns the maintenance margin for a position with the given `notional` value.
+    #[must_use]
+    pub fn maintenance_margin(&self, notional: f64) -> f64 {
+        let tier = self.tier(notional);
+        let margin = notional.abs() * tier.maintenance_rate.to_f64().unwrap()
+            - tier.maintenance_amount.to_f64().unwrap();
+        margin.max(0.0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::accounts::stubs::margin_tier_schedule;
+
+    #[rstest]
+    fn test_new_rejects_unordered_caps() {
+        let result = MarginTierSchedule::new(vec![
+            MarginTier::new(dec!(250_000), dec!(50), dec!(0.02), dec!(1_250)),
+            MarginTier::new(dec!(50_000), dec!(125), dec!(0.004), dec!(0)),
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(10_000.0, 125.0, 40.0)]
+    #[case(50_000.0, 125.0, 200.0)]
+    #[case(100_000.0, 100.0, 450.0)]
+    #[case(1_000_000.0, 50.0, 8_700.0)]
+    #[case(2_000_000.0, 20.0, 33_700.0)]
+    fn test_tier_lookup(
+        margin_tier_schedule: MarginTierSchedule,
+        #[case] notional: f64,
+        #[case] expected_leverage: f64,
+        #[case] expected_maintenance: f64,
+    ) {
+        assert_eq!(
+            margin_tier_schedule.max_leverage(notional),
+            expected_leverage
+        );
+        assert_eq!(
+            margin_tier_schedule.maintenance_margin(notional),
+            expected_maintenance
+        );
+    }
+}
@@ -50,6 +50,7 @@ pub fn get_bar_interval(bar_type: &BarType) -> TimeDelta {
         BarAggregation::Minute => TimeDelta::minutes(spec.step as i64),
         BarAggregation::Hour => TimeDelta::hours(spec.step as i64),
         BarAggregation::Day => TimeDelta::days(spec.step as i64),
+        BarAggregation::Week => TimeDelta::weeks(spec.step as i64),
         _ => panic!("Aggregation not time based"),
     }
 }
@@ -143,6 +144,7 @@ impl BarSpecification {
             BarAggregation::Minute => Duration::minutes(self.step as i64),
             BarAggregation::Hour => Duration::hours(self.step as i64),
             BarAggregation::Day => Duration::days(self.step as i64),
+            BarAggregation::Week => Duration::weeks(self.step as i64),
             _ => panic!(
                 "Timedelta not supported for aggregation type: {:?}",
                 self.aggregation
@@ -607,6 +609,7 @@ mod tests {
     #[case(BarAggregation::Hour, 4, TimeDelta::hours(4))]
     #[case(BarAggregation::Day, 1, TimeDelta::days(1))]
     #[case(BarAggregation::Day, 2, TimeDelta::days(2))]
+    #[case(BarAggregation::Week, 1, TimeDelta::weeks(1))]
     #[should_panic(expected = "Aggregation not time based")]
     #[case(BarAggregation::Tick, 1, TimeDelta::zero())]
     fn test_get_bar_interval(
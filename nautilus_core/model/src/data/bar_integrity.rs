@@ -0,0 +1,445 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integrity validation and repair for series of `Bar` data.
+//!
+//! [`validate_bars`] reports OHLC violations, zero-volume anomalies, out of order bars and
+//! missing intervals. A [`BarRepairer`] repairs a stream of bars with a [`BarRepairStrategy`],
+//! and can be applied to catalog queries or to bars received by adapters.
+//!
+//! Missing intervals are only detected for time aggregated bars, where calendar gaps such as
+//! weekends are also reported as missing.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::nanos::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use super::bar::{Bar, BarType};
+use crate::{
+    enums::BarAggregation,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents an integrity issue with a bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+pub enum BarIssue {
+    /// The high price was below the low price.
+    HighBelowLow,
+    /// The open price was outside the low to high range.
+    OpenOutsideRange,
+    /// The close price was outside the low to high range.
+    CloseOutsideRange,
+    /// The volume was zero although the price moved within the bar.
+    ZeroVolume,
+    /// The `ts_event` was not after that of the previous bar of the same type.
+    OutOfOrder,
+    /// The bars for `count` intervals before the bar were missing.
+    MissingIntervals { count: u64 },
+}
+
+impl Display for BarIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HighBelowLow => write!(f, "high below low"),
+            Self::OpenOutsideRange => write!(f, "open outside high-low range"),
+            Self::CloseOutsideRange => write!(f, "close outside high-low range"),
+            Self::ZeroVolume => write!(f, "zero volume with price movement"),
+            Self::OutOfOrder => write!(f, "out of order"),
+            Self::MissingIntervals { count } => write!(f, "{count} missing intervals before"),
+        }
+    }
+}
+
+/// Represents an integrity issue found at a position in a series of bars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BarViolation {
+    /// The index of the bar in the series.
+    pub index: usize,
+    /// The bar type of the bar.
+    pub bar_type: BarType,
+    /// UNIX timestamp (nanoseconds) of the bar.
+    pub ts_event: UnixNanos,
+    /// The issue found.
+    pub issue: BarIssue,
+}
+
+impl Display for BarViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bar {} at {}: {}",
+            self.bar_type, self.index, self.ts_event, self.issue
+        )
+    }
+}
+
+/// Represents how invalid bars and missing intervals are repaired.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarRepairStrategy {
+    /// Invalid bars are dropped, and missing intervals are left empty.
+    Drop,
+    /// Invalid bars and missing intervals are replaced with flat zero-volume bars at the close
+    /// price of the previous valid bar.
+    ForwardFill,
+    /// Invalid bars and missing intervals are replaced with flat zero-volume bars, with prices
+    /// linearly interpolated in time from the close of the previous valid bar to the open of the
+    /// next valid bar.
+    Interpolate,
+}
+
+/// Returns the OHLC and volume issues with the given `bar`, regardless of its neighbours.
+#[must_use]
+pub fn check_bar(bar: &Bar) -> Vec<BarIssue> {
+    let mut issues = Vec::new();
+    if bar.high < bar.low {
+        issues.push(BarIssue::HighBelowLow);
+    }
+    if bar.open < bar.low || bar.open > bar.high {
+        issues.push(BarIssue::OpenOutsideRange);
+    }
+    if bar.close < bar.low || bar.close > bar.high {
+        issues.push(BarIssue::CloseOutsideRange);
+    }
+    if bar.volume.is_zero() && bar.high != bar.low {
+        issues.push(BarIssue::ZeroVolume);
+    }
+    issues
+}
+
+/// Returns the integrity violations in the given series of `bars`, which may contain several
+/// bar types, and is expected in `ts_event` order for each bar type.
+#[must_use]
+pub fn validate_bars(bars: &[Bar]) -> Vec<BarViolation> {
+    let mut last_ts_events: HashMap<BarType, UnixNanos> = HashMap::new();
+    let mut violations = Vec::new();
+
+    for (index, bar) in bars.iter().enumerate() {
+        let mut issues = check_bar(bar);
+        if let Some(&last_ts_event) = last_ts_events.get(&bar.bar_type) {
+            if bar.ts_event <= last_ts_event {
+                issues.push(BarIssue::OutOfOrder);
+            } else if let Some(interval_ns) = time_interval_ns(&bar.bar_type) {
+                let count = missing_intervals(last_ts_event, bar.ts_event, interval_ns);
+                if count > 0 {
+                    issues.push(BarIssue::MissingIntervals { count });
+                }
+            }
+        }
+
+        let last_ts_event = last_ts_events.entry(bar.bar_type).or_default();
+        *last_ts_event = (*last_ts_event).max(bar.ts_event);
+
+        violations.extend(issues.into_iter().map(|issue| BarViolation {
+            index,
+            bar_type: bar.bar_type,
+            ts_event: bar.ts_event,
+            issue,
+        }));
+    }
+    violations
+}
+
+/// Returns the given series of `bars` repaired with the `strategy`.
+///
+/// Out of order bars are always dropped.
+#[must_use]
+pub fn repair_bars(bars: &[Bar], strategy: BarRepairStrategy) -> Vec<Bar> {
+    let mut repairer = BarRepairer::new(strategy);
+    let mut repaired: Vec<Bar> = bars.iter().flat_map(|bar| repairer.process(*bar)).collect();
+    repaired.extend(repairer.flush());
+    repaired
+}
+
+#[derive(Debug, Default)]
+struct BarSeriesState {
+    last_valid: Option<Bar>,
+    last_ts_event: Option<UnixNanos>,
+    pending: Vec<(UnixNanos, UnixNanos)>,
+}
+
+/// Repairs streams of bars with a [`BarRepairStrategy`], for each bar type independently.
+///
+/// Repaired bars are emitted immediately before the next valid bar of their type, so with
+/// [`BarRepairStrategy::Interpolate`] invalid bars are held back until the next valid bar is
+/// processed. Out of order bars are always dropped.
+#[derive(Debug)]
+pub struct BarRepairer {
+    strategy: BarRepairStrategy,
+    series: IndexMap<BarType, BarSeriesState>,
+}
+
+impl BarRepairer {
+    /// Creates a new [`BarRepairer`] instance.
+    #[must_use]
+    pub fn new(strategy: BarRepairStrategy) -> Self {
+        Self {
+            strategy,
+            series: IndexMap::new(),
+        }
+    }
+
+    /// Returns the strategy for the repairer.
+    #[must_use]
+    pub const fn strategy(&self) -> BarRepairStrategy {
+        self.strategy
+    }
+
+    /// Processes the next `bar` of its type, returning the bars to emit in order.
+    pub fn process(&mut self, bar: Bar) -> Vec<Bar> {
+        let interval_ns = time_interval_ns(&bar.bar_type);
+        let state = self.series.entry(bar.bar_type).or_default();
+        if state.last_ts_event.is_some_and(|ts| bar.ts_event <= ts) {
+            return Vec::new();
+        }
+
+        // Slots (ts_event, ts_init) to fill for missing intervals and an invalid bar
+        let mut slots = Vec::new();
+        if let (Some(last_ts_event), Some(interval_ns)) = (state.last_ts_event, interval_ns) {
+            let mut ts_event = last_ts_event + interval_ns;
+            while ts_event < bar.ts_event {
+                slots.push((ts_event, ts_event));
+                ts_event += interval_ns;
+            }
+        }
+        state.last_ts_event = Some(bar.ts_event);
+
+        let is_valid = check_bar(&bar).is_empty();
+        if !is_valid {
+            slots.push((bar.ts_event, bar.ts_init));
+        }
+
+        let mut bars = match (self.strategy, state.last_valid) {
+            (BarRepairStrategy::Drop, _) | (_, None) => Vec::new(),
+            (BarRepairStrategy::ForwardFill, Some(last)) => slots
+                .into_iter()
+                .map(|(ts_event, ts_init)| flat_bar(&last, last.close, ts_event, ts_init))
+                .collect(),
+            (BarRepairStrategy::Interpolate, Some(last)) => {
+                state.pending.extend(slots);
+                if !is_valid {
+                    return Vec::new();
+                }
+                state
+                    .pending
+                    .drain(..)
+                    .map(|(ts_event, ts_init)| {
+                        let price = interpolate(&last, &bar, ts_event);
+                        flat_bar(&last, price, ts_event, ts_init)
+                    })
+                    .collect()
+            }
+        };
+
+        if is_valid {
+            state.last_valid = Some(bar);
+            bars.push(bar);
+        }
+        bars
+    }
+
+    /// Returns the bars still held back for interpolation, forward filled from the last valid
+    /// bar of their type as there is no next valid bar to interpolate to.
+    pub fn flush(&mut self) -> Vec<Bar> {
+        let mut bars = Vec::new();
+        for state in self.series.values_mut() {
+            let Some(last) = state.last_valid else {
+                state.pending.clear();
+                continue;
+            };
+            bars.extend(
+                state
+                    .pending
+                    .drain(..)
+                    .map(|(ts_event, ts_init)| flat_bar(&last, last.close, ts_event, ts_init)),
+            );
+        }
+        bars
+    }
+}
+
+fn time_interval_ns(bar_type: &BarType) -> Option<u64> {
+    let spec = bar_type.spec();
+    match spec.aggregation {
+        BarAggregation::Millisecond
+        | BarAggregation::Second
+        | BarAggregation::Minute
+        | BarAggregation::Hour
+        | BarAggregation::Day => spec
+            .timedelta()
+            .num_nanoseconds()
+            .map(|interval_ns| interval_ns as u64),
+        _ => None,
+    }
+}
+
+fn missing_intervals(last_ts_event: UnixNanos, ts_event: UnixNanos, interval_ns: u64) -> u64 {
+    (ts_event.as_u64() - last_ts_event.as_u64() - 1) / interval_ns
+}
+
+fn interpolate(last: &Bar, next: &Bar, ts_event: UnixNanos) -> Price {
+    let elapsed = (ts_event.as_u64() - last.ts_event.as_u64()) as f64;
+    let total = (next.ts_event.as_u64() - last.ts_event.as_u64()) as f64;
+    let start = last.close.as_f64();
+    let end = next.open.as_f64();
+    Price::new(
+        start + (end - start) * elapsed / total,
+        last.close.precision,
+    )
+}
+
+fn flat_bar(last: &Bar, price: Price, ts_event: UnixNanos, ts_init: UnixNanos) -> Bar {
+    Bar::new(
+        last.bar_type,
+        price,
+        price,
+        price,
+        price,
+        Quantity::zero(last.volume.precision),
+        ts_event,
+        ts_init,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const MINUTE_NS: u64 = 60_000_000_000;
+
+    fn bar(minute: u64, open: &str, high: &str, low: &str, close: &str, volume: &str) -> Bar {
+        let ts = UnixNanos::from(minute * MINUTE_NS);
+        Bar::new(
+            BarType::from("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL"),
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from(volume),
+            ts,
+            ts,
+        )
+    }
+
+    fn series() -> Vec<Bar> {
+        vec![
+            bar(1, "1.00", "1.10", "0.90", "1.00", "10"),
+            bar(2, "1.00", "0.90", "1.10", "1.00", "10"), // High below low
+            bar(4, "1.06", "1.10", "1.00", "1.08", "10"), // Missing minute 3
+        ]
+    }
+
+    #[rstest]
+    #[case(bar(1, "1.00", "1.10", "0.90", "1.05", "10"), vec![])]
+    #[case(bar(1, "1.00", "1.00", "1.00", "1.00", "0"), vec![])]
+    #[case(bar(1, "1.00", "0.90", "1.10", "1.00", "10"), vec![BarIssue::HighBelowLow, BarIssue::OpenOutsideRange, BarIssue::CloseOutsideRange])]
+    #[case(bar(1, "1.20", "1.10", "0.90", "0.80", "10"), vec![BarIssue::OpenOutsideRange, BarIssue::CloseOutsideRange])]
+    #[case(bar(1, "1.00", "1.10", "0.90", "1.00", "0"), vec![BarIssue::ZeroVolume])]
+    fn test_check_bar(#[case] bar: Bar, #[case] expected: Vec<BarIssue>) {
+        assert_eq!(check_bar(&bar), expected);
+    }
+
+    #[rstest]
+    fn test_validate_bars() {
+        let mut bars = series();
+        bars.push(bar(4, "1.08", "1.10", "1.00", "1.08", "10"));
+
+        let issues: Vec<(usize, BarIssue)> = validate_bars(&bars)
+            .into_iter()
+            .map(|violation| (violation.index, violation.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (1, BarIssue::HighBelowLow),
+                (1, BarIssue::OpenOutsideRange),
+                (1, BarIssue::CloseOutsideRange),
+                (2, BarIssue::MissingIntervals { count: 1 }),
+                (3, BarIssue::OutOfOrder),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_repair_drop() {
+        let repaired = repair_bars(&series(), BarRepairStrategy::Drop);
+        let minutes: Vec<u64> = repaired
+            .iter()
+            .map(|bar| bar.ts_event.as_u64() / MINUTE_NS)
+            .collect();
+        assert_eq!(minutes, vec![1, 4]);
+    }
+
+    #[rstest]
+    fn test_repair_forward_fill() {
+        let repaired = repair_bars(&series(), BarRepairStrategy::ForwardFill);
+        assert_eq!(repaired.len(), 4);
+        assert!(validate_bars(&repaired).is_empty());
+        for filled in &repaired[1..3] {
+            assert_eq!(filled.open, Price::from("1.00"));
+            assert_eq!(filled.high, filled.low);
+            assert!(filled.volume.is_zero());
+        }
+        assert_eq!(repaired[3], series()[2]);
+    }
+
+    #[rstest]
+    fn test_repair_interpolate() {
+        let repaired = repair_bars(&series(), BarRepairStrategy::Interpolate);
+        let closes: Vec<Price> = repaired.iter().map(|bar| bar.close).collect();
+        assert_eq!(
+            closes,
+            vec![
+                Price::from("1.00"),
+                Price::from("1.02"),
+                Price::from("1.04"),
+                Price::from("1.08"),
+            ]
+        );
+        assert!(validate_bars(&repaired).is_empty());
+    }
+
+    #[rstest]
+    fn test_repair_interpolate_flushes_trailing_invalid_bars() {
+        let mut repairer = BarRepairer::new(BarRepairStrategy::Interpolate);
+        let bars = series();
+        assert_eq!(repairer.process(bars[0]), vec![bars[0]]);
+        assert!(repairer.process(bars[1]).is_empty());
+
+        let flushed = repairer.flush();
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].close, Price::from("1.00"));
+        assert_eq!(flushed[0].ts_event, bars[1].ts_event);
+        assert!(repairer.flush().is_empty());
+    }
+
+    #[rstest]
+    fn test_repair_without_previous_valid_bar_drops() {
+        let bars = series();
+        let repaired = repair_bars(&bars[1..], BarRepairStrategy::ForwardFill);
+        assert_eq!(repaired, vec![bars[2]]);
+    }
+}
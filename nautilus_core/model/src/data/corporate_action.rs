@@ -0,0 +1,517 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `CorporateAction` data type representing a split, dividend or symbol change for an
+//! instrument, along with a `CorporateActionAdjuster` for adjusting historical data.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    nanos::UnixNanos,
+    serialization::Serializable,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+use super::{bar::Bar, quote::QuoteTick, trade::TradeTick, Data, GetTsInit};
+use crate::{
+    enums::CorporateActionType,
+    identifiers::{InstrumentId, Symbol},
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a corporate action event for an instrument (split, dividend or symbol change).
+///
+/// The `ts_event` is the effective (ex) date of the action, data with a `ts_event` strictly
+/// before this timestamp is considered to be prior to the action.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct CorporateAction {
+    /// The instrument ID for the corporate action.
+    pub instrument_id: InstrumentId,
+    /// The type of corporate action.
+    pub action_type: CorporateActionType,
+    /// The split ratio (new shares per old share), e.g. 4 for a 4-for-1 split.
+    pub split_ratio: Option<Decimal>,
+    /// The cash dividend amount per share.
+    pub dividend: Option<Money>,
+    /// The new symbol for the instrument following a symbol change.
+    pub new_symbol: Option<Symbol>,
+    /// UNIX timestamp (nanoseconds) when the corporate action became effective.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl CorporateAction {
+    /// Creates a new split [`CorporateAction`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `ratio` is not positive.
+    pub fn new_split_checked(
+        instrument_id: InstrumentId,
+        ratio: Decimal,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(ratio > Decimal::ZERO, "`ratio` must be positive")?;
+
+        Ok(Self {
+            instrument_id,
+            action_type: CorporateActionType::Split,
+            split_ratio: Some(ratio),
+            dividend: None,
+            new_symbol: None,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new split [`CorporateAction`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a correctness check fails. See [`CorporateAction::new_split_checked`] for more details.
+    pub fn new_split(
+        instrument_id: InstrumentId,
+        ratio: Decimal,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_split_checked(instrument_id, ratio, ts_event, ts_init).expect(FAILED)
+    }
+
+    /// Creates a new cash dividend [`CorporateAction`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `amount` is negative.
+    pub fn new_dividend_checked(
+        instrument_id: InstrumentId,
+        amount: Money,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(amount.raw >= 0, "`amount` must not be negative")?;
+
+        Ok(Self {
+            instrument_id,
+            action_type: CorporateActionType::Dividend,
+            split_ratio: None,
+            dividend: Some(amount),
+            new_symbol: None,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new cash dividend [`CorporateAction`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a correctness check fails. See [`CorporateAction::new_dividend_checked`] for more details.
+    pub fn new_dividend(
+        instrument_id: InstrumentId,
+        amount: Money,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_dividend_checked(instrument_id, amount, ts_event, ts_init).expect(FAILED)
+    }
+
+    /// Creates a new symbol change [`CorporateAction`] instance.
+    #[must_use]
+    pub fn new_symbol_change(
+        instrument_id: InstrumentId,
+        new_symbol: Symbol,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            action_type: CorporateActionType::SymbolChange,
+            split_ratio: None,
+            dividend: None,
+            new_symbol: Some(new_symbol),
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the instrument ID following the action (only differs for symbol changes).
+    #[must_use]
+    pub fn new_instrument_id(&self) -> InstrumentId {
+        match self.new_symbol {
+            Some(symbol) => InstrumentId::new(symbol, self.instrument_id.venue),
+            None => self.instrument_id,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+}
+
+impl Display for CorporateAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let detail = match self.action_type {
+            CorporateActionType::Split => self
+                .split_ratio
+                .map_or_else(String::new, |ratio| ratio.to_string()),
+            CorporateActionType::Dividend => self
+                .dividend
+                .map_or_else(String::new, |amount| amount.to_string()),
+            CorporateActionType::SymbolChange => self
+                .new_symbol
+                .map_or_else(String::new, |symbol| symbol.to_string()),
+        };
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.action_type, detail, self.ts_event
+        )
+    }
+}
+
+impl Serializable for CorporateAction {}
+
+impl GetTsInit for CorporateAction {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Provides back-adjustment of historical market data for corporate actions.
+///
+/// Splits are applied by dividing prices (and multiplying sizes) for all data prior to the
+/// effective date by the cumulative ratio of all subsequent splits, so that a series spanning
+/// a split is continuous. Dividends do not alter prices, but are used to compute
+/// dividend-adjusted (total) return series.
+#[derive(Clone, Debug, Default)]
+pub struct CorporateActionAdjuster {
+    actions: HashMap<InstrumentId, Vec<CorporateAction>>,
+}
+
+impl CorporateActionAdjuster {
+    /// Creates a new [`CorporateActionAdjuster`] instance from the given `actions`.
+    #[must_use]
+    pub fn new(actions: Vec<CorporateAction>) -> Self {
+        let mut adjuster = Self::default();
+        for action in actions {
+            adjuster.add_action(action);
+        }
+        adjuster
+    }
+
+    /// Adds the given corporate `action`, keeping actions ordered by `ts_event`.
+    pub fn add_action(&mut self, action: CorporateAction) {
+        let actions = self.actions.entry(action.instrument_id).or_default();
+        let idx = actions.partition_point(|a| a.ts_event <= action.ts_event);
+        actions.insert(idx, action);
+    }
+
+    /// Returns the corporate actions for the given `instrument_id`, ordered by `ts_event`.
+    #[must_use]
+    pub fn actions(&self, instrument_id: &InstrumentId) -> &[CorporateAction] {
+        self.actions
+            .get(instrument_id)
+            .map_or(&[], |actions| actions.as_slice())
+    }
+
+    /// Returns the cumulative split ratio for data of `instrument_id` at `ts_event`.
+    ///
+    /// This is the product of all split ratios which become effective after `ts_event`.
+    #[must_use]
+    pub fn split_factor(&self, instrument_id: &InstrumentId, ts_event: UnixNanos) -> Decimal {
+        self.actions(instrument_id)
+            .iter()
+            .filter(|a| a.action_type == CorporateActionType::Split && a.ts_event > ts_event)
+            .filter_map(|a| a.split_ratio)
+            .product()
+    }
+
+    /// Returns the given `bar` adjusted for any subsequent splits.
+    #[must_use]
+    pub fn adjust_bar(&self, bar: &Bar) -> Bar {
+        let factor = self.split_factor(&bar.instrument_id(), bar.ts_event);
+        if factor == Decimal::ONE {
+            return *bar;
+        }
+        Bar {
+            open: adjust_price(bar.open, factor),
+            high: adjust_price(bar.high, factor),
+            low: adjust_price(bar.low, factor),
+            close: adjust_price(bar.close, factor),
+            volume: adjust_quantity(bar.volume, factor),
+            ..*bar
+        }
+    }
+
+    /// Returns the given `quote` adjusted for any subsequent splits.
+    #[must_use]
+    pub fn adjust_quote(&self, quote: &QuoteTick) -> QuoteTick {
+        let factor = self.split_factor(&quote.instrument_id, quote.ts_event);
+        if factor == Decimal::ONE {
+            return *quote;
+        }
+        QuoteTick {
+            bid_price: adjust_price(quote.bid_price, factor),
+            ask_price: adjust_price(quote.ask_price, factor),
+            bid_size: adjust_quantity(quote.bid_size, factor),
+            ask_size: adjust_quantity(quote.ask_size, factor),
+            ..*quote
+        }
+    }
+
+    /// Returns the given `trade` adjusted for any subsequent splits.
+    #[must_use]
+    pub fn adjust_trade(&self, trade: &TradeTick) -> TradeTick {
+        let factor = self.split_factor(&trade.instrument_id, trade.ts_event);
+        if factor == Decimal::ONE {
+            return *trade;
+        }
+        TradeTick {
+            price: adjust_price(trade.price, factor),
+            size: adjust_quantity(trade.size, factor),
+            ..*trade
+        }
+    }
+
+    /// Returns the given `data` adjusted for any subsequent splits.
+    ///
+    /// Order book data is passed through unchanged.
+    #[must_use]
+    pub fn adjust_data(&self, data: Data) -> Data {
+        match data {
+            Data::Bar(bar) => Data::Bar(self.adjust_bar(&bar)),
+            Data::Quote(quote) => Data::Quote(self.adjust_quote(&quote)),
+            Data::Trade(trade) => Data::Trade(self.adjust_trade(&trade)),
+            other => other,
+        }
+    }
+
+    /// Computes the dividend-adjusted (total) simple returns for the given close-to-close
+    /// `bars` series, which must be ordered by `ts_event`.
+    ///
+    /// A dividend is credited to the first bar whose `ts_event` is on or after the ex-date,
+    /// and both bars and dividends are split adjusted so the series is consistent.
+    #[must_use]
+    pub fn dividend_adjusted_returns(&self, bars: &[Bar]) -> Vec<f64> {
+        let mut returns = Vec::with_capacity(bars.len().saturating_sub(1));
+
+        for window in bars.windows(2) {
+            let prev = self.adjust_bar(&window[0]);
+            let curr = self.adjust_bar(&window[1]);
+            let instrument_id = curr.instrument_id();
+
+            let dividends: f64 = self
+                .actions(&instrument_id)
+                .iter()
+                .filter(|a| a.action_type == CorporateActionType::Dividend)
+                .filter(|a| a.ts_event > prev.ts_event && a.ts_event <= curr.ts_event)
+                .filter_map(|a| {
+                    let factor = self.split_factor(&instrument_id, a.ts_event);
+                    a.dividend
+                        .map(|amount| amount.as_f64() / factor.to_f64().unwrap_or(1.0))
+                })
+                .sum();
+
+            let prev_close = prev.close.as_f64();
+            if prev_close == 0.0 {
+                returns.push(0.0);
+            } else {
+                returns.push((curr.close.as_f64() + dividends) / prev_close - 1.0);
+            }
+        }
+
+        returns
+    }
+}
+
+fn adjust_price(price: Price, factor: Decimal) -> Price {
+    let value = (price.as_decimal() / factor).to_f64().expect(FAILED);
+    Price::new(value, price.precision)
+}
+
+fn adjust_quantity(quantity: Quantity, factor: Decimal) -> Quantity {
+    let value = (quantity.as_decimal() * factor).to_f64().expect(FAILED);
+    Quantity::new(value, quantity.precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        data::{bar::BarType, stubs::stub_bar},
+        types::currency::Currency,
+    };
+
+    fn bar(close: &str, ts: u64) -> Bar {
+        Bar::new(
+            BarType::from("AAPL.XNAS-1-DAY-LAST-EXTERNAL"),
+            Price::from(close),
+            Price::from(close),
+            Price::from(close),
+            Price::from(close),
+            Quantity::from(100),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AAPL.XNAS")
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`ratio` must be positive")]
+    fn test_new_split_with_zero_ratio_panics() {
+        let _ = CorporateAction::new_split(instrument_id(), dec!(0), 1.into(), 1.into());
+    }
+
+    #[rstest]
+    fn test_to_string() {
+        let action = CorporateAction::new_split(instrument_id(), dec!(4), 1.into(), 2.into());
+        assert_eq!(action.to_string(), "AAPL.XNAS,SPLIT,4,1");
+    }
+
+    #[rstest]
+    fn test_json_serialization() {
+        let action = CorporateAction::new_dividend(
+            instrument_id(),
+            Money::new(0.24, Currency::USD()),
+            1.into(),
+            2.into(),
+        );
+        let serialized = action.as_json_bytes().unwrap();
+        let deserialized = CorporateAction::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, action);
+    }
+
+    #[rstest]
+    fn test_new_instrument_id_for_symbol_change() {
+        let action = CorporateAction::new_symbol_change(
+            InstrumentId::from("FB.XNAS"),
+            Symbol::new("META"),
+            1.into(),
+            1.into(),
+        );
+        assert_eq!(action.new_instrument_id(), InstrumentId::from("META.XNAS"));
+    }
+
+    #[rstest]
+    fn test_adjust_bar_before_and_after_split() {
+        let adjuster = CorporateActionAdjuster::new(vec![CorporateAction::new_split(
+            instrument_id(),
+            dec!(4),
+            10.into(),
+            10.into(),
+        )]);
+
+        let before = adjuster.adjust_bar(&bar("400.00", 5));
+        let after = adjuster.adjust_bar(&bar("101.00", 10));
+
+        assert_eq!(before.close, Price::from("100.00"));
+        assert_eq!(before.volume, Quantity::from(400));
+        assert_eq!(after.close, Price::from("101.00"));
+        assert_eq!(after.volume, Quantity::from(100));
+    }
+
+    #[rstest]
+    fn test_split_factor_is_cumulative() {
+        let adjuster = CorporateActionAdjuster::new(vec![
+            CorporateAction::new_split(instrument_id(), dec!(2), 20.into(), 20.into()),
+            CorporateAction::new_split(instrument_id(), dec!(3), 10.into(), 10.into()),
+        ]);
+
+        assert_eq!(adjuster.split_factor(&instrument_id(), 5.into()), dec!(6));
+        assert_eq!(adjuster.split_factor(&instrument_id(), 15.into()), dec!(2));
+        assert_eq!(adjuster.split_factor(&instrument_id(), 20.into()), dec!(1));
+    }
+
+    #[rstest]
+    fn test_adjust_data_for_other_instrument_is_unchanged(stub_bar: Bar) {
+        let adjuster = CorporateActionAdjuster::new(vec![CorporateAction::new_split(
+            instrument_id(),
+            dec!(2),
+            10.into(),
+            10.into(),
+        )]);
+
+        assert_eq!(
+            adjuster.adjust_data(Data::Bar(stub_bar)),
+            Data::Bar(stub_bar)
+        );
+    }
+
+    #[rstest]
+    fn test_dividend_adjusted_returns() {
+        let adjuster = CorporateActionAdjuster::new(vec![CorporateAction::new_dividend(
+            instrument_id(),
+            Money::new(1.0, Currency::USD()),
+            2.into(),
+            2.into(),
+        )]);
+        let bars = vec![bar("100.00", 1), bar("99.00", 2), bar("99.00", 3)];
+
+        let returns = adjuster.dividend_adjusted_returns(&bars);
+
+        assert_eq!(returns.len(), 2);
+        assert!((returns[0] - 0.0).abs() < 1e-12);
+        assert!((returns[1] - 0.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_dividend_adjusted_returns_across_split() {
+        let adjuster = CorporateActionAdjuster::new(vec![CorporateAction::new_split(
+            instrument_id(),
+            dec!(2),
+            2.into(),
+            2.into(),
+        )]);
+        let bars = vec![bar("100.00", 1), bar("55.00", 2)];
+
+        let returns = adjuster.dividend_adjusted_returns(&bars);
+
+        assert!((returns[0] - 0.1).abs() < 1e-12);
+    }
+}
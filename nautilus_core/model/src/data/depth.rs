@@ -13,7 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! An `OrderBookDepth10` aggregated top-of-book data type with a fixed depth of 10 levels per side.
+//! An `OrderBookDepth10` aggregated top-of-book data type with a fixed depth of 10 levels per side,
+//! and an `OrderBookDepth<N>` generalization for other fixed depths.
 
 use std::{
     collections::HashMap,
@@ -199,6 +200,177 @@ impl GetTsInit for OrderBookDepth10 {
     }
 }
 
+/// Represents a aggregated order book update with a fixed depth of `N` levels per side.
+///
+/// This generalizes [`OrderBookDepth10`] for venues publishing snapshots of other depths
+/// (e.g. 5, 20, 25 or 50 levels), so these do not need remapping to 10 levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OrderBookDepth<const N: usize> {
+    /// The instrument ID for the book.
+    pub instrument_id: InstrumentId,
+    /// The bid orders for the depth update.
+    #[serde(with = "serde_levels")]
+    pub bids: [BookOrder; N],
+    /// The ask orders for the depth update.
+    #[serde(with = "serde_levels")]
+    pub asks: [BookOrder; N],
+    /// The count of bid orders per level for the depth update.
+    #[serde(with = "serde_levels")]
+    pub bid_counts: [u32; N],
+    /// The count of ask orders per level for the depth update.
+    #[serde(with = "serde_levels")]
+    pub ask_counts: [u32; N],
+    /// The record flags bit field, indicating event end and data information.
+    pub flags: u8,
+    /// The message sequence number assigned at the venue.
+    pub sequence: u64,
+    /// UNIX timestamp (nanoseconds) when the book event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl<const N: usize> OrderBookDepth<N> {
+    /// Creates a new [`OrderBookDepth`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        bids: [BookOrder; N],
+        asks: [BookOrder; N],
+        bid_counts: [u32; N],
+        ask_counts: [u32; N],
+        flags: u8,
+        sequence: u64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        OrderBookDepth10::get_metadata(instrument_id, price_precision, size_precision)
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        for (prefix, data_type) in [
+            ("bid_price", "Int64"),
+            ("ask_price", "Int64"),
+            ("bid_size", "UInt64"),
+            ("ask_size", "UInt64"),
+            ("bid_count", "UInt32"),
+            ("ask_count", "UInt32"),
+        ] {
+            for i in 0..N {
+                metadata.insert(format!("{prefix}_{i}"), data_type.to_string());
+            }
+        }
+        metadata.insert("flags".to_string(), "UInt8".to_string());
+        metadata.insert("sequence".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl From<OrderBookDepth10> for OrderBookDepth<DEPTH10_LEN> {
+    fn from(depth: OrderBookDepth10) -> Self {
+        Self::new(
+            depth.instrument_id,
+            depth.bids,
+            depth.asks,
+            depth.bid_counts,
+            depth.ask_counts,
+            depth.flags,
+            depth.sequence,
+            depth.ts_event,
+            depth.ts_init,
+        )
+    }
+}
+
+impl From<OrderBookDepth<DEPTH10_LEN>> for OrderBookDepth10 {
+    fn from(depth: OrderBookDepth<DEPTH10_LEN>) -> Self {
+        Self::new(
+            depth.instrument_id,
+            depth.bids,
+            depth.asks,
+            depth.bid_counts,
+            depth.ask_counts,
+            depth.flags,
+            depth.sequence,
+            depth.ts_event,
+            depth.ts_init,
+        )
+    }
+}
+
+impl<const N: usize> Display for OrderBookDepth<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},depth={},flags={},sequence={},ts_event={},ts_init={}",
+            self.instrument_id, N, self.flags, self.sequence, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl<const N: usize> Serializable for OrderBookDepth<N> {}
+
+impl<const N: usize> GetTsInit for OrderBookDepth<N> {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Serializes fixed size level arrays as sequences, as `serde` only implements arrays up to
+/// 32 elements.
+mod serde_levels {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S, T, const N: usize>(
+        levels: &[T; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        levels.as_slice().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let levels = Vec::<T>::deserialize(deserializer)?;
+        let len = levels.len();
+        levels
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{N} levels").as_str()))
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -236,6 +408,41 @@ mod tests {
     }
 
     // TODO: Exact format for Debug and Display TBD
+    #[rstest]
+    fn test_depth_n_display(stub_depth10: OrderBookDepth10) {
+        let depth = OrderBookDepth::from(stub_depth10);
+        assert_eq!(
+            format!("{depth}"),
+            "AAPL.XNAS,depth=10,flags=0,sequence=0,ts_event=1,ts_init=2".to_string()
+        );
+    }
+
+    #[rstest]
+    fn test_depth_n_round_trip_depth10(stub_depth10: OrderBookDepth10) {
+        let depth = OrderBookDepth::<DEPTH10_LEN>::from(stub_depth10);
+        assert_eq!(OrderBookDepth10::from(depth), stub_depth10);
+    }
+
+    #[rstest]
+    fn test_depth_n_get_fields() {
+        let fields = OrderBookDepth::<25>::get_fields();
+        assert_eq!(fields.len(), 6 * 25 + 4);
+        assert_eq!(fields.get("ask_price_24").unwrap(), "Int64");
+        assert_eq!(fields.get("bid_count_0").unwrap(), "UInt32");
+        assert_eq!(
+            OrderBookDepth::<DEPTH10_LEN>::get_fields(),
+            OrderBookDepth10::get_fields()
+        );
+    }
+
+    #[rstest]
+    fn test_depth_n_json_serialization(stub_depth50: OrderBookDepth<50>) {
+        let serialized = stub_depth50.as_json_bytes().unwrap();
+        let deserialized = OrderBookDepth::<50>::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_depth50);
+        assert!(OrderBookDepth::<25>::from_json_bytes(serialized.as_ref()).is_err());
+    }
+
     #[rstest]
     fn test_display(stub_depth10: OrderBookDepth10) {
         let depth = stub_depth10;
@@ -16,12 +16,16 @@
 //! Data types for the trading domain model.
 
 pub mod bar;
+pub mod bar_integrity;
+pub mod corporate_action;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
 pub mod greeks;
 pub mod order;
 pub mod quote;
+pub mod rate;
 pub mod status;
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -78,6 +82,18 @@ impl Data {
         }
     }
 
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Delta(delta) => delta.ts_event,
+            Self::Deltas(deltas) => deltas.ts_event,
+            Self::Depth10(depth) => depth.ts_event,
+            Self::Quote(quote) => quote.ts_event,
+            Self::Trade(trade) => trade.ts_event,
+            Self::Bar(bar) => bar.ts_event,
+        }
+    }
+
     /// Returns whether the data is a type of order book data.
     pub fn is_order_book_data(&self) -> bool {
         matches!(self, Self::Delta(_) | Self::Deltas(_) | Self::Depth10(_))
@@ -330,6 +346,22 @@ impl DataType {
         )
     }
 
+    /// Returns an [`Option<NonZeroU64>`] conflation interval (milliseconds) from the metadata.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the `conflation_ms` value contained in the metadata is invalid.
+    pub fn conflation_ms(&self) -> Option<NonZeroU64> {
+        let metadata = self.metadata.as_ref()?;
+        let conflation_ms_str = metadata.get("conflation_ms")?;
+        Some(
+            conflation_ms_str
+                .parse::<NonZeroU64>()
+                .expect("Invalid `NonZeroU64` for 'conflation_ms'"),
+        )
+    }
+
     /// Returns a [`NonZeroU64`] interval (milliseconds) from the metadata.
     ///
     /// # Panics
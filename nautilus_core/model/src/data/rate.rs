@@ -0,0 +1,141 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Historical rates, such as borrow and interest rates, which change over time.
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::nanos::UnixNanos;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Represents an annual rate which is effective from `ts_event`, until the next rate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RateRecord {
+    /// The annual rate.
+    pub rate: Decimal,
+    /// UNIX timestamp (nanoseconds) when the rate became effective.
+    pub ts_event: UnixNanos,
+}
+
+impl RateRecord {
+    /// Creates a new [`RateRecord`] instance.
+    #[must_use]
+    pub const fn new(rate: Decimal, ts_event: UnixNanos) -> Self {
+        Self { rate, ts_event }
+    }
+}
+
+impl Display for RateRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.rate, self.ts_event)
+    }
+}
+
+/// Provides the history of a rate, for looking up the rate effective at a point in time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateSchedule {
+    records: Vec<RateRecord>,
+}
+
+impl RateSchedule {
+    /// Creates a new [`RateSchedule`] instance from the given `records`.
+    ///
+    /// Records are sorted by `ts_event`, where a later record replaces an earlier record with the
+    /// same `ts_event`.
+    #[must_use]
+    pub fn new(records: Vec<RateRecord>) -> Self {
+        let mut schedule = Self::default();
+        for record in records {
+            schedule.insert(record);
+        }
+        schedule
+    }
+
+    /// Returns the records of the schedule, sorted by `ts_event`.
+    #[must_use]
+    pub fn records(&self) -> &[RateRecord] {
+        &self.records
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Inserts the given `record`, replacing any record with the same `ts_event`.
+    pub fn insert(&mut self, record: RateRecord) {
+        match self
+            .records
+            .binary_search_by_key(&record.ts_event, |r| r.ts_event)
+        {
+            Ok(i) => self.records[i] = record,
+            Err(i) => self.records.insert(i, record),
+        }
+    }
+
+    /// Returns the rate effective at `ts` (None if before the first record).
+    #[must_use]
+    pub fn rate_at(&self, ts: UnixNanos) -> Option<Decimal> {
+        let i = self.records.partition_point(|record| record.ts_event <= ts);
+        i.checked_sub(1).map(|i| self.records[i].rate)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[rstest]
+    fn test_rate_at() {
+        let schedule = RateSchedule::new(vec![
+            RateRecord::new(dec!(0.05), 20.into()),
+            RateRecord::new(dec!(0.04), 10.into()),
+        ]);
+
+        assert_eq!(schedule.rate_at(9.into()), None);
+        assert_eq!(schedule.rate_at(10.into()), Some(dec!(0.04)));
+        assert_eq!(schedule.rate_at(19.into()), Some(dec!(0.04)));
+        assert_eq!(schedule.rate_at(20.into()), Some(dec!(0.05)));
+        assert_eq!(schedule.rate_at(u64::MAX.into()), Some(dec!(0.05)));
+    }
+
+    #[rstest]
+    fn test_insert_replaces_same_time() {
+        let mut schedule = RateSchedule::new(vec![RateRecord::new(dec!(0.04), 10.into())]);
+
+        schedule.insert(RateRecord::new(dec!(0.045), 10.into()));
+
+        assert_eq!(
+            schedule.records(),
+            [RateRecord::new(dec!(0.045), 10.into())]
+        );
+        assert!(RateSchedule::default().is_empty());
+    }
+
+    #[rstest]
+    fn test_record_to_string() {
+        assert_eq!(
+            RateRecord::new(dec!(0.04), 10.into()).to_string(),
+            "0.04,10"
+        );
+    }
+}
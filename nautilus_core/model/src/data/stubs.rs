@@ -21,7 +21,7 @@ use rstest::fixture;
 use super::{
     bar::{Bar, BarSpecification, BarType},
     deltas::OrderBookDeltas,
-    depth::DEPTH10_LEN,
+    depth::{OrderBookDepth, DEPTH10_LEN},
     quote::QuoteTick,
     status::InstrumentStatus,
     trade::TradeTick,
@@ -276,6 +276,38 @@ pub fn stub_depth10() -> OrderBookDepth10 {
     )
 }
 
+#[fixture]
+pub fn stub_depth50() -> OrderBookDepth<50> {
+    let bids = std::array::from_fn(|i| {
+        BookOrder::new(
+            OrderSide::Buy,
+            Price::new(99.0 - i as f64, 2),
+            Quantity::new(100.0 * (i + 1) as f64, 0),
+            i as u64 + 1,
+        )
+    });
+    let asks = std::array::from_fn(|i| {
+        BookOrder::new(
+            OrderSide::Sell,
+            Price::new(100.0 + i as f64, 2),
+            Quantity::new(100.0 * (i + 1) as f64, 0),
+            i as u64 + 51,
+        )
+    });
+
+    OrderBookDepth::new(
+        InstrumentId::from("AAPL.XNAS"),
+        bids,
+        asks,
+        [1; 50],
+        [1; 50],
+        0,
+        0,
+        1.into(),
+        2.into(),
+    )
+}
+
 #[fixture]
 pub fn stub_book_order() -> BookOrder {
     let price = Price::from("100.00");
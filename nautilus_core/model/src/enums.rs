@@ -395,6 +395,39 @@ impl FromU8 for BookType {
     }
 }
 
+/// The storage strategy for the price levels of an order book.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum BookStorage {
+    /// Levels are held in a balanced tree keyed by price.
+    #[default]
+    BTreeMap = 1,
+    /// Levels are held contiguously in a vector sorted by price, which is more cache friendly
+    /// for deep books where most updates occur near the top of the book.
+    SortedVec = 2,
+}
+
 /// The order contigency type which specifies the behavior of linked orders.
 ///
 /// [FIX 5.0 SP2 : ContingencyType <1385> field](https://www.onixs.biz/fix-dictionary/5.0.sp2/tagnum_1385.html).
@@ -433,6 +466,38 @@ pub enum ContingencyType {
     Ouo = 3,
 }
 
+/// The type of corporate action affecting an instrument.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum CorporateActionType {
+    /// A stock split (or reverse split) changing the number of outstanding shares.
+    Split = 1,
+    /// A cash dividend paid per share.
+    Dividend = 2,
+    /// A change of the instruments ticker symbol.
+    SymbolChange = 3,
+}
+
 /// The broad currency type.
 #[repr(C)]
 #[derive(
@@ -649,6 +714,43 @@ impl FromU16 for MarketStatusAction {
     }
 }
 
+/// The policy for matching a closing fill against the open lots of a position.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum NettingPolicy {
+    /// Closing fills are matched against the average cost of all open lots.
+    #[default]
+    AvgCost = 0,
+    /// Closing fills are matched against the oldest open lots first (first-in, first-out).
+    Fifo = 1,
+    /// Closing fills are matched against the newest open lots first (last-in, first-out).
+    Lifo = 2,
+    /// Closing fills are matched against the least favorable open lots first, i.e. the highest
+    /// priced lots of a long position and the lowest priced lots of a short position.
+    HighestCost = 3,
+}
+
 /// The order management system (OMS) type for a trading venue or trading strategy.
 #[repr(C)]
 #[derive(
@@ -1182,6 +1284,57 @@ pub enum TriggerType {
     IndexPrice = 9,
 }
 
+/// The normalized reason for an order, modify or cancel request being rejected by a venue.
+///
+/// Venue-specific error codes are mapped to these categories so strategies can branch on the
+/// cause of a reject portably across venues.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum VenueRejectReason {
+    /// The reject reason is unknown or has no normalized category.
+    #[default]
+    Unknown = 0,
+    /// The account has insufficient balance or margin.
+    InsufficientBalance = 1,
+    /// The price is invalid, e.g. outside price limits or not a multiple of the tick size.
+    InvalidPrice = 2,
+    /// The quantity is invalid, e.g. outside size limits or not a multiple of the size increment.
+    InvalidQuantity = 3,
+    /// The request exceeded a venue rate limit.
+    RateLimited = 4,
+    /// The post-only order would have crossed the spread and taken liquidity.
+    PostOnlyWouldCross = 5,
+    /// The reduce-only order would have increased the position.
+    ReduceOnlyWouldIncrease = 6,
+    /// The client order ID duplicates an existing order.
+    DuplicateOrder = 7,
+    /// The order to modify or cancel was not found.
+    OrderNotFound = 8,
+    /// The market is closed or halted.
+    MarketClosed = 9,
+}
+
 enum_strum_serde!(AccountType);
 enum_strum_serde!(AggregationSource);
 enum_strum_serde!(AggressorSide);
@@ -1189,13 +1342,16 @@ enum_strum_serde!(AssetClass);
 enum_strum_serde!(InstrumentClass);
 enum_strum_serde!(BarAggregation);
 enum_strum_serde!(BookAction);
+enum_strum_serde!(BookStorage);
 enum_strum_serde!(BookType);
 enum_strum_serde!(ContingencyType);
+enum_strum_serde!(CorporateActionType);
 enum_strum_serde!(CurrencyType);
 enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
 enum_strum_serde!(MarketStatus);
 enum_strum_serde!(MarketStatusAction);
+enum_strum_serde!(NettingPolicy);
 enum_strum_serde!(OmsType);
 enum_strum_serde!(OptionKind);
 enum_strum_serde!(OrderSide);
@@ -1208,3 +1364,4 @@ enum_strum_serde!(TimeInForce);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
 enum_strum_serde!(TriggerType);
+enum_strum_serde!(VenueRejectReason);
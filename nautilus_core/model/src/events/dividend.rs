@@ -0,0 +1,206 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::nanos::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::corporate_action::CorporateAction, enums::CorporateActionType, identifiers::InstrumentId,
+    position::Position, types::money::Money,
+};
+
+/// Represents a cash dividend declared for an instrument.
+///
+/// Holders of the instrument at the ex-date are entitled to the dividend, which is paid on
+/// the pay date (if known).
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Dividend {
+    pub instrument_id: InstrumentId,
+    /// The cash amount paid per unit (share) of the instrument.
+    pub amount: Money,
+    /// UNIX timestamp (nanoseconds) for the ex-dividend date.
+    pub ex_date: UnixNanos,
+    /// UNIX timestamp (nanoseconds) for the pay date (if known).
+    pub pay_date: Option<UnixNanos>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Dividend {
+    /// Creates a new [`Dividend`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        amount: Money,
+        ex_date: UnixNanos,
+        pay_date: Option<UnixNanos>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            amount,
+            ex_date,
+            pay_date,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the dividend entitlement for the given `position`, credited for long positions
+    /// and debited for short positions.
+    #[must_use]
+    pub fn entitlement(&self, position: &Position) -> Money {
+        let units = position.signed_qty * position.multiplier.as_f64();
+        Money::new(units * self.amount.as_f64(), self.amount.currency)
+    }
+}
+
+impl TryFrom<CorporateAction> for Dividend {
+    type Error = anyhow::Error;
+
+    /// Converts a cash dividend corporate action, effective on its ex-date, to a [`Dividend`].
+    fn try_from(action: CorporateAction) -> anyhow::Result<Self> {
+        match (action.action_type, action.dividend) {
+            (CorporateActionType::Dividend, Some(amount)) => Ok(Self::new(
+                action.instrument_id,
+                amount,
+                action.ts_event,
+                None,
+                action.ts_event,
+                action.ts_init,
+            )),
+            _ => anyhow::bail!("Corporate action is not a cash dividend: {action}"),
+        }
+    }
+}
+
+impl Display for Dividend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, amount={}, ex_date={}, pay_date={})",
+            stringify!(Dividend),
+            self.instrument_id,
+            self.amount,
+            self.ex_date,
+            self.pay_date
+                .map_or("None".to_string(), |pay_date| pay_date.to_string()),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType},
+        identifiers::PositionId,
+        instruments::{any::InstrumentAny, equity::Equity, stubs::*},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn position(instrument: &InstrumentAny, side: OrderSide) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from("100"))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from("180.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    #[rstest]
+    fn test_entitlement(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let dividend = Dividend::new(
+            instrument.id(),
+            Money::from("0.25 USD"),
+            UnixNanos::from(1),
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        assert_eq!(
+            dividend.entitlement(&position(&instrument, OrderSide::Buy)),
+            Money::from("25.00 USD")
+        );
+        assert_eq!(
+            dividend.entitlement(&position(&instrument, OrderSide::Sell)),
+            Money::from("-25.00 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_try_from_corporate_action(equity_aapl: Equity) {
+        let action = CorporateAction::new_dividend(
+            equity_aapl.id,
+            Money::from("0.25 USD"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        let dividend = Dividend::try_from(action).unwrap();
+
+        assert_eq!(dividend.amount, Money::from("0.25 USD"));
+        assert_eq!(dividend.ex_date, UnixNanos::from(1));
+        assert_eq!(dividend.ts_init, UnixNanos::from(2));
+        assert!(Dividend::try_from(CorporateAction::new_split(
+            equity_aapl.id,
+            dec!(4),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        ))
+        .is_err());
+    }
+
+    #[rstest]
+    fn test_display(equity_aapl: Equity) {
+        let dividend = Dividend::new(
+            equity_aapl.id,
+            Money::from("0.25 USD"),
+            UnixNanos::from(1),
+            Some(UnixNanos::from(2)),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        assert_eq!(
+            dividend.to_string(),
+            "Dividend(instrument_id=AAPL.XNAS, amount=0.25 USD, ex_date=1, pay_date=2)"
+        );
+    }
+}
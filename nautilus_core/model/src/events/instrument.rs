@@ -0,0 +1,233 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::nanos::UnixNanos;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    identifiers::InstrumentId,
+    instruments::any::InstrumentAny,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents an intraday change to the definition of an instrument, such as a tick size change.
+///
+/// Only the changed fields are set, and are applied to the instrument in place with
+/// [`InstrumentAny::apply_update`].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct InstrumentUpdate {
+    pub instrument_id: InstrumentId,
+    /// The new minimum price increment (tick size).
+    pub price_increment: Option<Price>,
+    /// The new minimum size increment.
+    pub size_increment: Option<Quantity>,
+    /// The new rounded lot unit size.
+    pub lot_size: Option<Quantity>,
+    /// The new initial (order) margin requirement in percentage of order value.
+    pub margin_init: Option<Decimal>,
+    /// The new maintenance (position) margin in percentage of position value.
+    pub margin_maint: Option<Decimal>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl InstrumentUpdate {
+    /// Creates a new [`InstrumentUpdate`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        price_increment: Option<Price>,
+        size_increment: Option<Quantity>,
+        lot_size: Option<Quantity>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            price_increment,
+            size_increment,
+            lot_size,
+            margin_init,
+            margin_maint,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the update from the `previous` to the `current` definition of an instrument,
+    /// or `None` if none of the updatable fields changed.
+    #[must_use]
+    pub fn between(previous: &InstrumentAny, current: &InstrumentAny) -> Option<Self> {
+        fn changed<T: PartialEq>(previous: T, current: T) -> Option<T> {
+            (previous != current).then_some(current)
+        }
+
+        let update = Self::new(
+            current.id(),
+            changed(previous.price_increment(), current.price_increment()),
+            changed(previous.size_increment(), current.size_increment()),
+            changed(previous.lot_size(), current.lot_size()).flatten(),
+            changed(previous.margin_init(), current.margin_init()),
+            changed(previous.margin_maint(), current.margin_maint()),
+            current.ts_event(),
+            current.ts_init(),
+        );
+        (previous.id() == current.id() && !update.is_empty()).then_some(update)
+    }
+
+    /// Returns whether the update changes no fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.price_increment.is_none()
+            && self.size_increment.is_none()
+            && self.lot_size.is_none()
+            && self.margin_init.is_none()
+            && self.margin_maint.is_none()
+    }
+}
+
+impl Display for InstrumentUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let fmt_opt = |value: Option<String>| value.unwrap_or_else(|| "None".to_string());
+        write!(
+            f,
+            "{}(instrument_id={}, price_increment={}, size_increment={}, lot_size={}, margin_init={}, margin_maint={}, ts_event={})",
+            stringify!(InstrumentUpdate),
+            self.instrument_id,
+            fmt_opt(self.price_increment.map(|x| x.to_string())),
+            fmt_opt(self.size_increment.map(|x| x.to_string())),
+            fmt_opt(self.lot_size.map(|x| x.to_string())),
+            fmt_opt(self.margin_init.map(|x| x.to_string())),
+            fmt_opt(self.margin_maint.map(|x| x.to_string())),
+            self.ts_event,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::{currency_pair::CurrencyPair, equity::Equity, stubs::*};
+
+    fn tick_size_update(instrument_id: InstrumentId, price_increment: &str) -> InstrumentUpdate {
+        InstrumentUpdate::new(
+            instrument_id,
+            Some(Price::from(price_increment)),
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+    }
+
+    #[rstest]
+    fn test_apply_update(audusd_sim: CurrencyPair) {
+        let mut instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let update = InstrumentUpdate::new(
+            audusd_sim.id,
+            Some(Price::from("0.00005")),
+            None,
+            Some(Quantity::from(1_000)),
+            Some(dec!(0.05)),
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        instrument.apply_update(&update).unwrap();
+
+        assert_eq!(instrument.price_increment(), Price::from("0.00005"));
+        assert_eq!(instrument.lot_size(), Some(Quantity::from(1_000)));
+        assert_eq!(instrument.margin_init(), dec!(0.05));
+        assert_eq!(instrument.margin_maint(), audusd_sim.margin_maint);
+        assert_eq!(instrument.ts_init(), UnixNanos::from(2));
+    }
+
+    #[rstest]
+    fn test_apply_update_with_changed_precision_fails(audusd_sim: CurrencyPair) {
+        let mut instrument = InstrumentAny::CurrencyPair(audusd_sim);
+
+        let result = instrument.apply_update(&tick_size_update(audusd_sim.id, "0.0001"));
+
+        assert!(result.is_err());
+        assert_eq!(instrument.price_increment(), audusd_sim.price_increment);
+    }
+
+    #[rstest]
+    fn test_apply_update_for_other_instrument_fails(audusd_sim: CurrencyPair, equity_aapl: Equity) {
+        let mut instrument = InstrumentAny::Equity(equity_aapl);
+
+        assert!(instrument
+            .apply_update(&tick_size_update(audusd_sim.id, "0.01"))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_apply_update_of_unsupported_field_fails(equity_aapl: Equity) {
+        let mut instrument = InstrumentAny::Equity(equity_aapl);
+        let update = InstrumentUpdate::new(
+            equity_aapl.id,
+            Some(Price::from("0.05")),
+            Some(Quantity::from(1)),
+            None,
+            None,
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        assert!(instrument.apply_update(&update).is_err());
+        assert_eq!(instrument.price_increment(), equity_aapl.price_increment);
+    }
+
+    #[rstest]
+    fn test_between(audusd_sim: CurrencyPair) {
+        let previous = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut current = audusd_sim;
+        current.price_increment = Price::from("0.00005");
+        current.ts_event = UnixNanos::from(10);
+        let current = InstrumentAny::CurrencyPair(current);
+
+        let update = InstrumentUpdate::between(&previous, &current).unwrap();
+
+        assert_eq!(update.price_increment, Some(Price::from("0.00005")));
+        assert!(update.size_increment.is_none());
+        assert_eq!(update.ts_event, UnixNanos::from(10));
+        assert!(InstrumentUpdate::between(&previous, &previous).is_none());
+    }
+
+    #[rstest]
+    fn test_display(audusd_sim: CurrencyPair) {
+        assert_eq!(
+            tick_size_update(audusd_sim.id, "0.00005").to_string(),
+            "InstrumentUpdate(instrument_id=AUD/USD.SIM, price_increment=0.00005, size_increment=None, lot_size=None, margin_init=None, margin_maint=None, ts_event=1)"
+        );
+    }
+}
@@ -16,5 +16,7 @@
 //! Events for the trading domain model.
 
 pub mod account;
+pub mod dividend;
+pub mod instrument;
 pub mod order;
 pub mod position;
@@ -20,6 +20,7 @@ use ustr::Ustr;
 
 use super::{OrderEvent, OrderEventType};
 use crate::{
+    enums::VenueRejectReason,
     events::order::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
         OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel,
@@ -211,6 +212,17 @@ impl OrderEventAny {
             Self::Filled(_) => None,
         }
     }
+
+    /// Returns the normalized reject reason for reject events.
+    #[must_use]
+    pub const fn reject_reason(&self) -> Option<VenueRejectReason> {
+        match self {
+            Self::Rejected(event) => Some(event.reject_reason),
+            Self::ModifyRejected(event) => Some(event.reject_reason),
+            Self::CancelRejected(event) => Some(event.reject_reason),
+            _ => None,
+        }
+    }
 }
 
 impl From<OrderEventAny> for OrderFilled {
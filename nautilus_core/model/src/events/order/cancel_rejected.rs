@@ -23,7 +23,7 @@ use ustr::Ustr;
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
-        TriggerType,
+        TriggerType, VenueRejectReason,
     },
     events::order::OrderEvent,
     identifiers::{
@@ -54,6 +54,8 @@ pub struct OrderCancelRejected {
     pub reconciliation: u8, // TODO: Change to bool once Cython removed
     pub venue_order_id: Option<VenueOrderId>,
     pub account_id: Option<AccountId>,
+    #[serde(default)]
+    pub reject_reason: VenueRejectReason,
 }
 
 impl OrderCancelRejected {
@@ -71,6 +73,7 @@ impl OrderCancelRejected {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reject_reason: VenueRejectReason,
     ) -> Self {
         Self {
             trader_id,
@@ -84,6 +87,7 @@ impl OrderCancelRejected {
             reconciliation: u8::from(reconciliation),
             venue_order_id,
             account_id,
+            reject_reason,
         }
     }
 }
@@ -92,7 +96,7 @@ impl Debug for OrderCancelRejected {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}(trader_id={}, strategy_id={}, instrument_id={}, client_order_id={}, venue_order_id={}, account_id={}, reason='{}', event_id={}, ts_event={}, ts_init={})",
+            "{}(trader_id={}, strategy_id={}, instrument_id={}, client_order_id={}, venue_order_id={}, account_id={}, reason='{}', reject_reason={}, event_id={}, ts_event={}, ts_init={})",
             stringify!(OrderCancelRejected),
             self.trader_id,
             self.strategy_id,
@@ -101,6 +105,7 @@ impl Debug for OrderCancelRejected {
             self.venue_order_id.map_or_else(|| "None".to_string(), |venue_order_id| format!("{venue_order_id}")),
             self.account_id.map_or_else(|| "None".to_string(), |account_id| format!("{account_id}")),
             self.reason,
+            self.reject_reason,
             self.event_id,
             self.ts_event,
             self.ts_init
@@ -30,7 +30,9 @@ use crate::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, TradeId, TraderId, VenueOrderId,
     },
-    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency, metadata::OrderMetadata, money::Money, price::Price, quantity::Quantity,
+    },
 };
 
 #[repr(C)]
@@ -61,6 +63,8 @@ pub struct OrderFilled {
     pub reconciliation: bool,
     pub position_id: Option<PositionId>,
     pub commission: Option<Money>,
+    #[serde(default)]
+    pub metadata: OrderMetadata,
 }
 
 impl OrderFilled {
@@ -107,6 +111,7 @@ impl OrderFilled {
             reconciliation,
             position_id,
             commission,
+            metadata: OrderMetadata::default(),
         }
     }
 
@@ -144,6 +149,7 @@ impl Default for OrderFilled {
             last_px: Price::from("1.00000"),
             currency: Currency::USD(),
             commission: None,
+            metadata: OrderMetadata::default(),
             liquidity_side: LiquiditySide::Taker,
             event_id: Default::default(),
             ts_event: Default::default(),
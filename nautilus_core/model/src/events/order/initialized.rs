@@ -34,7 +34,9 @@ use crate::{
         StrategyId, TradeId, TraderId, VenueOrderId,
     },
     orders::any::OrderAny,
-    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency, metadata::OrderMetadata, money::Money, price::Price, quantity::Quantity,
+    },
 };
 
 #[repr(C)]
@@ -79,6 +81,8 @@ pub struct OrderInitialized {
     pub exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
     pub exec_spawn_id: Option<ClientOrderId>,
     pub tags: Option<Vec<Ustr>>,
+    #[serde(default)]
+    pub metadata: OrderMetadata,
 }
 
 impl Default for OrderInitialized {
@@ -114,6 +118,7 @@ impl Default for OrderInitialized {
             exec_algorithm_params: Default::default(),
             exec_spawn_id: Default::default(),
             tags: Default::default(),
+            metadata: Default::default(),
             event_id: Default::default(),
             ts_event: Default::default(),
             ts_init: Default::default(),
@@ -194,6 +199,7 @@ impl OrderInitialized {
             exec_algorithm_params,
             exec_spawn_id,
             tags,
+            metadata: OrderMetadata::default(),
         }
     }
 }
@@ -40,6 +40,7 @@ pub mod initialized;
 pub mod modify_rejected;
 pub mod pending_cancel;
 pub mod pending_update;
+pub mod reject;
 pub mod rejected;
 pub mod released;
 pub mod snapshot;
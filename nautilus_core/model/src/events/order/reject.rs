@@ -0,0 +1,100 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mappings from venue-specific reject error codes to a normalized [`VenueRejectReason`].
+
+use crate::{enums::VenueRejectReason, identifiers::Venue};
+
+/// The Binance Spot and USD-M Futures reject error codes.
+///
+/// The generic `-2010` NEW_ORDER_REJECTED code is deliberately unmapped, as its cause
+/// is only given by the accompanying message text.
+const BINANCE_REJECT_CODES: &[(i64, VenueRejectReason)] = &[
+    (-1003, VenueRejectReason::RateLimited), // TOO_MANY_REQUESTS
+    (-1015, VenueRejectReason::RateLimited), // TOO_MANY_ORDERS
+    (-2011, VenueRejectReason::OrderNotFound), // CANCEL_REJECTED
+    (-2013, VenueRejectReason::OrderNotFound), // NO_SUCH_ORDER
+    (-2018, VenueRejectReason::InsufficientBalance), // BALANCE_NOT_SUFFICIENT
+    (-2019, VenueRejectReason::InsufficientBalance), // MARGIN_NOT_SUFFICIENT
+    (-2022, VenueRejectReason::ReduceOnlyWouldIncrease), // REDUCE_ONLY_REJECT
+    (-4003, VenueRejectReason::InvalidQuantity), // QTY_LESS_THAN_ZERO
+    (-4005, VenueRejectReason::InvalidQuantity), // QTY_GREATER_THAN_MAX_QTY
+    (-4014, VenueRejectReason::InvalidPrice), // PRICE_NOT_INCREASED_BY_TICK_SIZE
+    (-4016, VenueRejectReason::InvalidPrice), // PRICE_HIGHER_THAN_MULTIPLIER_UP
+    (-4024, VenueRejectReason::InvalidPrice), // PRICE_LOWER_THAN_MULTIPLIER_DOWN
+    (-4116, VenueRejectReason::DuplicateOrder), // DUPLICATED_CLIENT_ORDER_ID
+    (-5022, VenueRejectReason::PostOnlyWouldCross), // GTX_ORDER_REJECT
+];
+
+/// The Bybit V5 API reject error codes.
+const BYBIT_REJECT_CODES: &[(i64, VenueRejectReason)] = &[
+    (10006, VenueRejectReason::RateLimited),    // Too many visits
+    (10018, VenueRejectReason::RateLimited),    // Exceeded the IP rate limit
+    (110001, VenueRejectReason::OrderNotFound), // Order does not exist
+    (110003, VenueRejectReason::InvalidPrice),  // Order price exceeds the allowable range
+    (110004, VenueRejectReason::InsufficientBalance), // Wallet balance is insufficient
+    (110007, VenueRejectReason::InsufficientBalance), // Available balance is insufficient
+    (110012, VenueRejectReason::InsufficientBalance), // Insufficient available balance
+    (110017, VenueRejectReason::ReduceOnlyWouldIncrease), // Reduce-only rule not satisfied
+    (110072, VenueRejectReason::DuplicateOrder), // OrderLinkedID is duplicate
+    (170131, VenueRejectReason::InsufficientBalance), // Spot balance insufficient
+    (170213, VenueRejectReason::OrderNotFound), // Spot order does not exist
+];
+
+/// Returns the normalized [`VenueRejectReason`] for the given venue-specific error `code`.
+///
+/// Returns [`VenueRejectReason::Unknown`] if the venue or code has no mapping.
+#[must_use]
+pub fn venue_reject_reason(venue: &Venue, code: i64) -> VenueRejectReason {
+    let codes = match venue.as_str() {
+        "BINANCE" => BINANCE_REJECT_CODES,
+        "BYBIT" => BYBIT_REJECT_CODES,
+        _ => return VenueRejectReason::Unknown,
+    };
+    codes
+        .iter()
+        .find(|(venue_code, _)| *venue_code == code)
+        .map_or(VenueRejectReason::Unknown, |(_, reason)| *reason)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("BINANCE", -2018, VenueRejectReason::InsufficientBalance)]
+    #[case("BINANCE", -2010, VenueRejectReason::Unknown)]
+    #[case("BINANCE", -1015, VenueRejectReason::RateLimited)]
+    #[case("BINANCE", -4014, VenueRejectReason::InvalidPrice)]
+    #[case("BINANCE", -5022, VenueRejectReason::PostOnlyWouldCross)]
+    #[case("BINANCE", -9999, VenueRejectReason::Unknown)]
+    #[case("BYBIT", 110007, VenueRejectReason::InsufficientBalance)]
+    #[case("BYBIT", 10006, VenueRejectReason::RateLimited)]
+    #[case("BYBIT", 110017, VenueRejectReason::ReduceOnlyWouldIncrease)]
+    #[case("BYBIT", -2010, VenueRejectReason::Unknown)]
+    #[case("SIM", -2010, VenueRejectReason::Unknown)]
+    fn test_venue_reject_reason(
+        #[case] venue: &str,
+        #[case] code: i64,
+        #[case] expected: VenueRejectReason,
+    ) {
+        assert_eq!(venue_reject_reason(&Venue::from(venue), code), expected);
+    }
+}
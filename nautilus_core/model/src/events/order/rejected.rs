@@ -23,7 +23,7 @@ use ustr::Ustr;
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
-        TriggerType,
+        TriggerType, VenueRejectReason,
     },
     events::order::OrderEvent,
     identifiers::{
@@ -53,6 +53,8 @@ pub struct OrderRejected {
     pub ts_init: UnixNanos,
     #[serde(deserialize_with = "from_bool_as_u8")]
     pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    #[serde(default)]
+    pub reject_reason: VenueRejectReason,
 }
 
 impl OrderRejected {
@@ -69,6 +71,7 @@ impl OrderRejected {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
         reconciliation: bool,
+        reject_reason: VenueRejectReason,
     ) -> Self {
         Self {
             trader_id,
@@ -81,6 +84,7 @@ impl OrderRejected {
             ts_event,
             ts_init,
             reconciliation: u8::from(reconciliation),
+            reject_reason,
         }
     }
 }
@@ -88,7 +92,7 @@ impl OrderRejected {
 impl Debug for OrderRejected {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f,
-            "{}(trader_id={}, strategy_id={}, instrument_id={}, client_order_id={}, account_id={}, reason='{}', event_id={}, ts_event={}, ts_init={})",
+            "{}(trader_id={}, strategy_id={}, instrument_id={}, client_order_id={}, account_id={}, reason='{}', reject_reason={}, event_id={}, ts_event={}, ts_init={})",
             stringify!(OrderRejected),
             self.trader_id,
             self.strategy_id,
@@ -96,6 +100,7 @@ impl Debug for OrderRejected {
             self.client_order_id,
             self.account_id,
             self.reason,
+            self.reject_reason,
             self.event_id,
             self.ts_event,
             self.ts_init
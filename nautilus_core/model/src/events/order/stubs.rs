@@ -20,7 +20,10 @@ use rstest::fixture;
 use ustr::Ustr;
 
 use crate::{
-    enums::{ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType},
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType,
+        VenueRejectReason,
+    },
     events::order::{
         OrderAccepted, OrderCancelRejected, OrderDenied, OrderEmulated, OrderExpired, OrderFilled,
         OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
@@ -108,6 +111,7 @@ pub fn order_rejected_insufficient_margin(
         UnixNanos::default(),
         UnixNanos::default(),
         false,
+        VenueRejectReason::InsufficientBalance,
     )
 }
 
@@ -339,6 +343,7 @@ pub fn order_modify_rejected(
         false,
         Some(venue_order_id),
         Some(account_id),
+        VenueRejectReason::OrderNotFound,
     )
 }
 
@@ -388,6 +393,7 @@ pub fn order_cancel_rejected(
         false,
         Some(venue_order_id),
         Some(account_id),
+        VenueRejectReason::OrderNotFound,
     )
 }
 
@@ -18,6 +18,7 @@ use std::ffi::c_char;
 use nautilus_core::{ffi::string::cstr_to_ustr, nanos::UnixNanos, uuid::UUID4};
 
 use crate::{
+    enums::VenueRejectReason,
     events::order::{
         OrderAccepted, OrderDenied, OrderEmulated, OrderRejected, OrderReleased, OrderSubmitted,
     },
@@ -172,5 +173,6 @@ pub unsafe extern "C" fn order_rejected_new(
         ts_event,
         ts_init,
         reconciliation,
+        reject_reason: VenueRejectReason::Unknown,
     }
 }
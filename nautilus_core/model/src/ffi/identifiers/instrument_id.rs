@@ -58,6 +58,21 @@ pub extern "C" fn instrument_id_to_cstr(instrument_id: &InstrumentId) -> *const
     str_to_cstr(&instrument_id.to_string())
 }
 
+#[no_mangle]
+pub extern "C" fn instrument_id_symbol(instrument_id: &InstrumentId) -> Symbol {
+    instrument_id.symbol
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_id_venue(instrument_id: &InstrumentId) -> Venue {
+    instrument_id.venue
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_id_eq(lhs: &InstrumentId, rhs: &InstrumentId) -> u8 {
+    u8::from(lhs == rhs)
+}
+
 #[no_mangle]
 pub extern "C" fn instrument_id_hash(instrument_id: &InstrumentId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -123,6 +138,22 @@ mod tests {
         }
     }
 
+    #[rstest]
+    fn test_symbol_and_venue() {
+        let id = InstrumentId::from("ETH/USDT.BINANCE");
+
+        assert_eq!(instrument_id_symbol(&id), Symbol::from("ETH/USDT"));
+        assert_eq!(instrument_id_venue(&id), Venue::from("BINANCE"));
+        assert_eq!(
+            instrument_id_eq(&id, &InstrumentId::from("ETH/USDT.BINANCE")),
+            1
+        );
+        assert_eq!(
+            instrument_id_eq(&id, &InstrumentId::from("BTC/USDT.BINANCE")),
+            0
+        );
+    }
+
     #[rstest]
     fn test_from_symbol_and_back() {
         unsafe {
@@ -23,8 +23,12 @@ use nautilus_core::ffi::{cvec::CVec, string::str_to_cstr};
 use super::level::Level_API;
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas_API, depth::OrderBookDepth10,
-        order::BookOrder, quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas_API,
+        depth::{OrderBookDepth, OrderBookDepth10},
+        order::BookOrder,
+        quote::QuoteTick,
+        trade::TradeTick,
     },
     enums::{BookType, OrderSide},
     identifiers::InstrumentId,
@@ -159,7 +163,7 @@ pub extern "C" fn orderbook_apply_deltas(book: &mut OrderBook_API, deltas: &Orde
 
 #[no_mangle]
 pub extern "C" fn orderbook_apply_depth(book: &mut OrderBook_API, depth: &OrderBookDepth10) {
-    book.apply_depth(depth);
+    book.apply_depth(&OrderBookDepth::from(*depth));
 }
 
 #[no_mangle]
@@ -228,6 +232,15 @@ pub extern "C" fn orderbook_midpoint(book: &mut OrderBook_API) -> f64 {
         .expect("Error: Unable to calculate `midpoint` (no bid or ask)")
 }
 
+/// Returns the order book imbalance over the top `depth` levels of each side.
+///
+/// The imbalance is `(bid_size - ask_size) / (bid_size + ask_size)`, or `NaN` when there
+/// are no levels on either side.
+#[no_mangle]
+pub extern "C" fn orderbook_imbalance(book: &OrderBook_API, depth: usize) -> f64 {
+    book.imbalance(depth).unwrap_or(f64::NAN)
+}
+
 #[no_mangle]
 pub extern "C" fn orderbook_get_avg_px_for_quantity(
     book: &mut OrderBook_API,
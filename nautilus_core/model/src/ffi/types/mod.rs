@@ -17,3 +17,19 @@ pub mod currency;
 pub mod money;
 pub mod price;
 pub mod quantity;
+
+use std::panic::{catch_unwind, UnwindSafe};
+
+/// Writes the result of `f` to `out`, returning 1 on success or 0 if `f` panicked.
+///
+/// Prevents a panic from unwinding across the `extern "C"` boundary; `out` is left
+/// unchanged on failure.
+pub(crate) fn write_checked<T>(out: &mut T, f: impl FnOnce() -> T + UnwindSafe) -> u8 {
+    match catch_unwind(f) {
+        Ok(value) => {
+            *out = value;
+            1
+        }
+        Err(_) => 0,
+    }
+}
@@ -13,9 +13,17 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ops::{AddAssign, SubAssign};
+use std::{
+    ffi::c_char,
+    ops::{AddAssign, SubAssign},
+};
 
-use crate::types::{currency::Currency, money::Money};
+use nautilus_core::ffi::string::str_to_cstr;
+
+use crate::{
+    ffi::types::write_checked,
+    types::{currency::Currency, money::Money},
+};
 
 // TODO: Document panic
 #[no_mangle]
@@ -43,3 +51,65 @@ pub extern "C" fn money_add_assign(mut a: Money, b: Money) {
 pub extern "C" fn money_sub_assign(mut a: Money, b: Money) {
     a.sub_assign(b);
 }
+
+/// Writes the sum of `a` and `b` to `result`, returning 1 on success.
+///
+/// Returns 0 if the currencies of `a` and `b` differ or the result overflows.
+#[no_mangle]
+pub extern "C" fn money_add(a: Money, b: Money, result: &mut Money) -> u8 {
+    write_checked(result, || a + b)
+}
+
+/// Writes the difference of `a` and `b` to `result`, returning 1 on success.
+///
+/// Returns 0 if the currencies of `a` and `b` differ or the result underflows.
+#[no_mangle]
+pub extern "C" fn money_sub(a: Money, b: Money, result: &mut Money) -> u8 {
+    write_checked(result, || a - b)
+}
+
+/// Returns a [`Money`] as a C string pointer.
+#[no_mangle]
+pub extern "C" fn money_to_cstr(money: &Money) -> *const c_char {
+    str_to_cstr(&money.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_add_sub_and_to_cstr() {
+        let a = Money::from("100.50 USD");
+        let b = Money::from("0.25 USD");
+
+        let mut result = Money::from("0 USD");
+
+        assert_eq!(money_add(a, b, &mut result), 1);
+        assert_eq!(result, Money::from("100.75 USD"));
+        assert_eq!(money_sub(a, b, &mut result), 1);
+        assert_eq!(result, Money::from("100.25 USD"));
+        unsafe {
+            let cstr = money_to_cstr(&a);
+            assert_eq!(CStr::from_ptr(cstr).to_str().unwrap(), "100.50 USD");
+        }
+    }
+
+    #[rstest]
+    fn test_add_sub_currency_mismatch_returns_zero() {
+        let a = Money::from("100.50 USD");
+        let b = Money::from("0.25 AUD");
+        let mut result = Money::from("1.00 USD");
+
+        assert_eq!(money_add(a, b, &mut result), 0);
+        assert_eq!(money_sub(a, b, &mut result), 0);
+        assert_eq!(result, Money::from("1.00 USD"));
+    }
+}
@@ -13,9 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ops::{AddAssign, SubAssign};
+use std::{
+    ffi::c_char,
+    ops::{AddAssign, SubAssign},
+};
 
-use crate::types::price::Price;
+use nautilus_core::ffi::string::str_to_cstr;
+
+use crate::{ffi::types::write_checked, types::price::Price};
 
 // TODO: Document panic
 #[no_mangle]
@@ -43,3 +48,25 @@ pub extern "C" fn price_add_assign(mut a: Price, b: Price) {
 pub extern "C" fn price_sub_assign(mut a: Price, b: Price) {
     a.sub_assign(b);
 }
+
+/// Writes the sum of `a` and `b` to `result`, returning 1 on success.
+///
+/// Returns 0 if `b` has a greater precision than `a` or the result overflows.
+#[no_mangle]
+pub extern "C" fn price_add(a: Price, b: Price, result: &mut Price) -> u8 {
+    write_checked(result, || a + b)
+}
+
+/// Writes the difference of `a` and `b` to `result`, returning 1 on success.
+///
+/// Returns 0 if `b` has a greater precision than `a` or the result underflows.
+#[no_mangle]
+pub extern "C" fn price_sub(a: Price, b: Price, result: &mut Price) -> u8 {
+    write_checked(result, || a - b)
+}
+
+/// Returns a [`Price`] as a C string pointer.
+#[no_mangle]
+pub extern "C" fn price_to_cstr(price: &Price) -> *const c_char {
+    str_to_cstr(&price.to_string())
+}
@@ -13,9 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ops::{AddAssign, SubAssign};
+use std::{
+    ffi::c_char,
+    ops::{AddAssign, SubAssign},
+};
 
-use crate::types::quantity::Quantity;
+use nautilus_core::ffi::string::str_to_cstr;
+
+use crate::{ffi::types::write_checked, types::quantity::Quantity};
 
 // TODO: Document panic
 #[no_mangle]
@@ -53,3 +58,25 @@ pub extern "C" fn quantity_sub_assign(mut a: Quantity, b: Quantity) {
 pub extern "C" fn quantity_sub_assign_u64(mut a: Quantity, b: u64) {
     a.sub_assign(b);
 }
+
+/// Writes the sum of `a` and `b` to `result`, returning 1 on success.
+///
+/// Returns 0 if `b` has a greater precision than `a` or the result overflows.
+#[no_mangle]
+pub extern "C" fn quantity_add(a: Quantity, b: Quantity, result: &mut Quantity) -> u8 {
+    write_checked(result, || a + b)
+}
+
+/// Writes the difference of `a` and `b` to `result`, returning 1 on success.
+///
+/// Returns 0 if `b` has a greater precision than `a` or the result underflows.
+#[no_mangle]
+pub extern "C" fn quantity_sub(a: Quantity, b: Quantity, result: &mut Quantity) -> u8 {
+    write_checked(result, || a - b)
+}
+
+/// Returns a [`Quantity`] as a C string pointer.
+#[no_mangle]
+pub extern "C" fn quantity_to_cstr(qty: &Quantity) -> *const c_char {
+    str_to_cstr(&qty.to_string())
+}
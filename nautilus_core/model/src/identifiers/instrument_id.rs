@@ -135,7 +135,7 @@ impl<'de> Deserialize<'de> for InstrumentId {
         D: Deserializer<'de>,
     {
         let instrument_id_str = String::deserialize(deserializer)?;
-        Ok(Self::from(instrument_id_str.as_str()))
+        Self::from_str(&instrument_id_str).map_err(serde::de::Error::custom)
     }
 }
 
@@ -31,9 +31,8 @@ macro_rules! impl_serialization_for_identifier {
             where
                 D: Deserializer<'de>,
             {
-                let value_str: &str = Deserialize::deserialize(deserializer)?;
-                let value: $ty = value_str.into();
-                Ok(value)
+                let value_str = String::deserialize(deserializer)?;
+                Self::new_checked(value_str).map_err(serde::de::Error::custom)
             }
         }
     };
@@ -13,32 +13,41 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{
+    correctness::{check_equal, check_equal_u8},
+    nanos::UnixNanos,
+};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
-    betting::BettingInstrument, binary_option::BinaryOption, crypto_future::CryptoFuture,
-    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
-    futures_contract::FuturesContract, futures_spread::FuturesSpread,
-    options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
+    betting::BettingInstrument, binary_option::BinaryOption, commodity::Commodity,
+    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    fx_forward::FxForward, info::InstrumentInfo, ndf::Ndf, options_contract::OptionsContract,
+    options_spread::OptionsSpread, Instrument,
 };
 use crate::{
-    enums::InstrumentClass,
+    enums::{AssetClass, InstrumentClass},
+    events::instrument::InstrumentUpdate,
     identifiers::InstrumentId,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum InstrumentAny {
     Betting(BettingInstrument),
     BinaryOption(BinaryOption),
+    Commodity(Commodity),
     CryptoFuture(CryptoFuture),
     CryptoPerpetual(CryptoPerpetual),
     CurrencyPair(CurrencyPair),
     Equity(Equity),
     FuturesContract(FuturesContract),
     FuturesSpread(FuturesSpread),
+    FxForward(FxForward),
+    Ndf(Ndf),
     OptionsContract(OptionsContract),
     OptionsSpread(OptionsSpread),
 }
@@ -50,12 +59,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => Box::new(inst),
             Self::BinaryOption(inst) => Box::new(inst),
+            Self::Commodity(inst) => Box::new(inst),
             Self::CryptoFuture(inst) => Box::new(inst),
             Self::CryptoPerpetual(inst) => Box::new(inst),
             Self::CurrencyPair(inst) => Box::new(inst),
             Self::Equity(inst) => Box::new(inst),
             Self::FuturesContract(inst) => Box::new(inst),
             Self::FuturesSpread(inst) => Box::new(inst),
+            Self::FxForward(inst) => Box::new(inst),
+            Self::Ndf(inst) => Box::new(inst),
             Self::OptionsContract(inst) => Box::new(inst),
             Self::OptionsSpread(inst) => Box::new(inst),
         }
@@ -66,12 +78,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id,
             Self::BinaryOption(inst) => inst.id,
+            Self::Commodity(inst) => inst.id,
             Self::CryptoFuture(inst) => inst.id,
             Self::CryptoPerpetual(inst) => inst.id,
             Self::CurrencyPair(inst) => inst.id,
             Self::Equity(inst) => inst.id,
             Self::FuturesContract(inst) => inst.id,
             Self::FuturesSpread(inst) => inst.id,
+            Self::FxForward(inst) => inst.id,
+            Self::Ndf(inst) => inst.id,
             Self::OptionsContract(inst) => inst.id,
             Self::OptionsSpread(inst) => inst.id,
         }
@@ -82,12 +97,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(_) => None,
             Self::BinaryOption(_) => None,
+            Self::Commodity(_) => None,
             Self::CryptoFuture(inst) => Some(&inst.underlying.code),
             Self::CryptoPerpetual(_) => None,
             Self::CurrencyPair(_) => None,
             Self::Equity(_) => None,
             Self::FuturesContract(inst) => Some(&inst.underlying),
             Self::FuturesSpread(inst) => Some(&inst.underlying),
+            Self::FxForward(_) => None,
+            Self::Ndf(_) => None,
             Self::OptionsContract(inst) => Some(&inst.underlying),
             Self::OptionsSpread(inst) => Some(&inst.underlying),
         }
@@ -98,12 +116,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.base_currency(),
             Self::BinaryOption(inst) => inst.base_currency(),
+            Self::Commodity(inst) => inst.base_currency(),
             Self::CryptoFuture(inst) => inst.base_currency(),
             Self::CryptoPerpetual(inst) => inst.base_currency(),
             Self::CurrencyPair(inst) => inst.base_currency(),
             Self::Equity(inst) => inst.base_currency(),
             Self::FuturesContract(inst) => inst.base_currency(),
             Self::FuturesSpread(inst) => inst.base_currency(),
+            Self::FxForward(inst) => inst.base_currency(),
+            Self::Ndf(inst) => inst.base_currency(),
             Self::OptionsContract(inst) => inst.base_currency(),
             Self::OptionsSpread(inst) => inst.base_currency(),
         }
@@ -114,12 +135,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.quote_currency(),
             Self::BinaryOption(inst) => inst.quote_currency(),
+            Self::Commodity(inst) => inst.quote_currency(),
             Self::CryptoFuture(inst) => inst.quote_currency(),
             Self::CryptoPerpetual(inst) => inst.quote_currency(),
             Self::CurrencyPair(inst) => inst.quote_currency(),
             Self::Equity(inst) => inst.quote_currency(),
             Self::FuturesContract(inst) => inst.quote_currency(),
             Self::FuturesSpread(inst) => inst.quote_currency(),
+            Self::FxForward(inst) => inst.quote_currency(),
+            Self::Ndf(inst) => inst.quote_currency(),
             Self::OptionsContract(inst) => inst.quote_currency(),
             Self::OptionsSpread(inst) => inst.quote_currency(),
         }
@@ -130,12 +154,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.settlement_currency(),
             Self::BinaryOption(inst) => inst.settlement_currency(),
+            Self::Commodity(inst) => inst.settlement_currency(),
             Self::CryptoFuture(inst) => inst.settlement_currency(),
             Self::CryptoPerpetual(inst) => inst.settlement_currency(),
             Self::CurrencyPair(inst) => inst.settlement_currency(),
             Self::Equity(inst) => inst.settlement_currency(),
             Self::FuturesContract(inst) => inst.settlement_currency(),
             Self::FuturesSpread(inst) => inst.settlement_currency(),
+            Self::FxForward(inst) => inst.settlement_currency(),
+            Self::Ndf(inst) => inst.settlement_currency(),
             Self::OptionsContract(inst) => inst.settlement_currency(),
             Self::OptionsSpread(inst) => inst.settlement_currency(),
         }
@@ -146,12 +173,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.is_inverse(),
             Self::BinaryOption(inst) => inst.is_inverse(),
+            Self::Commodity(inst) => inst.is_inverse(),
             Self::CryptoFuture(inst) => inst.is_inverse(),
             Self::CryptoPerpetual(inst) => inst.is_inverse(),
             Self::CurrencyPair(inst) => inst.is_inverse(),
             Self::Equity(inst) => inst.is_inverse(),
             Self::FuturesContract(inst) => inst.is_inverse(),
             Self::FuturesSpread(inst) => inst.is_inverse(),
+            Self::FxForward(inst) => inst.is_inverse(),
+            Self::Ndf(inst) => inst.is_inverse(),
             Self::OptionsContract(inst) => inst.is_inverse(),
             Self::OptionsSpread(inst) => inst.is_inverse(),
         }
@@ -162,12 +192,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_precision(),
             Self::BinaryOption(inst) => inst.price_precision(),
+            Self::Commodity(inst) => inst.price_precision(),
             Self::CryptoFuture(inst) => inst.price_precision(),
             Self::CryptoPerpetual(inst) => inst.price_precision(),
             Self::CurrencyPair(inst) => inst.price_precision(),
             Self::Equity(inst) => inst.price_precision(),
             Self::FuturesContract(inst) => inst.price_precision(),
             Self::FuturesSpread(inst) => inst.price_precision(),
+            Self::FxForward(inst) => inst.price_precision(),
+            Self::Ndf(inst) => inst.price_precision(),
             Self::OptionsContract(inst) => inst.price_precision(),
             Self::OptionsSpread(inst) => inst.price_precision(),
         }
@@ -178,12 +211,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_precision(),
             Self::BinaryOption(inst) => inst.size_precision(),
+            Self::Commodity(inst) => inst.size_precision(),
             Self::CryptoFuture(inst) => inst.size_precision(),
             Self::CryptoPerpetual(inst) => inst.size_precision(),
             Self::CurrencyPair(inst) => inst.size_precision(),
             Self::Equity(inst) => inst.size_precision(),
             Self::FuturesContract(inst) => inst.size_precision(),
             Self::FuturesSpread(inst) => inst.size_precision(),
+            Self::FxForward(inst) => inst.size_precision(),
+            Self::Ndf(inst) => inst.size_precision(),
             Self::OptionsContract(inst) => inst.size_precision(),
             Self::OptionsSpread(inst) => inst.size_precision(),
         }
@@ -194,12 +230,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_increment(),
             Self::BinaryOption(inst) => inst.price_increment(),
+            Self::Commodity(inst) => inst.price_increment(),
             Self::CryptoFuture(inst) => inst.price_increment(),
             Self::CryptoPerpetual(inst) => inst.price_increment(),
             Self::CurrencyPair(inst) => inst.price_increment(),
             Self::Equity(inst) => inst.price_increment(),
             Self::FuturesContract(inst) => inst.price_increment(),
             Self::FuturesSpread(inst) => inst.price_increment(),
+            Self::FxForward(inst) => inst.price_increment(),
+            Self::Ndf(inst) => inst.price_increment(),
             Self::OptionsContract(inst) => inst.price_increment(),
             Self::OptionsSpread(inst) => inst.price_increment(),
         }
@@ -210,44 +249,205 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_increment(),
             Self::BinaryOption(inst) => inst.size_increment(),
+            Self::Commodity(inst) => inst.size_increment(),
             Self::CryptoFuture(inst) => inst.size_increment(),
             Self::CryptoPerpetual(inst) => inst.size_increment(),
             Self::CurrencyPair(inst) => inst.size_increment(),
             Self::Equity(inst) => inst.size_increment(),
             Self::FuturesContract(inst) => inst.size_increment(),
             Self::FuturesSpread(inst) => inst.size_increment(),
+            Self::FxForward(inst) => inst.size_increment(),
+            Self::Ndf(inst) => inst.size_increment(),
             Self::OptionsContract(inst) => inst.size_increment(),
             Self::OptionsSpread(inst) => inst.size_increment(),
         }
     }
 
+    #[must_use]
+    pub fn max_quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::Betting(inst) => inst.max_quantity(),
+            Self::BinaryOption(inst) => inst.max_quantity(),
+            Self::Commodity(inst) => inst.max_quantity(),
+            Self::CryptoFuture(inst) => inst.max_quantity(),
+            Self::CryptoPerpetual(inst) => inst.max_quantity(),
+            Self::CurrencyPair(inst) => inst.max_quantity(),
+            Self::Equity(inst) => inst.max_quantity(),
+            Self::FuturesContract(inst) => inst.max_quantity(),
+            Self::FuturesSpread(inst) => inst.max_quantity(),
+            Self::FxForward(inst) => inst.max_quantity(),
+            Self::Ndf(inst) => inst.max_quantity(),
+            Self::OptionsContract(inst) => inst.max_quantity(),
+            Self::OptionsSpread(inst) => inst.max_quantity(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::Betting(inst) => inst.min_quantity(),
+            Self::BinaryOption(inst) => inst.min_quantity(),
+            Self::Commodity(inst) => inst.min_quantity(),
+            Self::CryptoFuture(inst) => inst.min_quantity(),
+            Self::CryptoPerpetual(inst) => inst.min_quantity(),
+            Self::CurrencyPair(inst) => inst.min_quantity(),
+            Self::Equity(inst) => inst.min_quantity(),
+            Self::FuturesContract(inst) => inst.min_quantity(),
+            Self::FuturesSpread(inst) => inst.min_quantity(),
+            Self::FxForward(inst) => inst.min_quantity(),
+            Self::Ndf(inst) => inst.min_quantity(),
+            Self::OptionsContract(inst) => inst.min_quantity(),
+            Self::OptionsSpread(inst) => inst.min_quantity(),
+        }
+    }
+
+    #[must_use]
+    pub fn lot_size(&self) -> Option<Quantity> {
+        match self {
+            Self::Betting(inst) => inst.lot_size(),
+            Self::BinaryOption(inst) => inst.lot_size(),
+            Self::Commodity(inst) => inst.lot_size(),
+            Self::CryptoFuture(inst) => inst.lot_size(),
+            Self::CryptoPerpetual(inst) => inst.lot_size(),
+            Self::CurrencyPair(inst) => inst.lot_size(),
+            Self::Equity(inst) => inst.lot_size(),
+            Self::FuturesContract(inst) => inst.lot_size(),
+            Self::FuturesSpread(inst) => inst.lot_size(),
+            Self::FxForward(inst) => inst.lot_size(),
+            Self::Ndf(inst) => inst.lot_size(),
+            Self::OptionsContract(inst) => inst.lot_size(),
+            Self::OptionsSpread(inst) => inst.lot_size(),
+        }
+    }
+
+    #[must_use]
+    pub fn max_notional(&self) -> Option<Money> {
+        match self {
+            Self::Betting(inst) => inst.max_notional(),
+            Self::BinaryOption(inst) => inst.max_notional(),
+            Self::Commodity(inst) => inst.max_notional(),
+            Self::CryptoFuture(inst) => inst.max_notional(),
+            Self::CryptoPerpetual(inst) => inst.max_notional(),
+            Self::CurrencyPair(inst) => inst.max_notional(),
+            Self::Equity(inst) => inst.max_notional(),
+            Self::FuturesContract(inst) => inst.max_notional(),
+            Self::FuturesSpread(inst) => inst.max_notional(),
+            Self::FxForward(inst) => inst.max_notional(),
+            Self::Ndf(inst) => inst.max_notional(),
+            Self::OptionsContract(inst) => inst.max_notional(),
+            Self::OptionsSpread(inst) => inst.max_notional(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_notional(&self) -> Option<Money> {
+        match self {
+            Self::Betting(inst) => inst.min_notional(),
+            Self::BinaryOption(inst) => inst.min_notional(),
+            Self::Commodity(inst) => inst.min_notional(),
+            Self::CryptoFuture(inst) => inst.min_notional(),
+            Self::CryptoPerpetual(inst) => inst.min_notional(),
+            Self::CurrencyPair(inst) => inst.min_notional(),
+            Self::Equity(inst) => inst.min_notional(),
+            Self::FuturesContract(inst) => inst.min_notional(),
+            Self::FuturesSpread(inst) => inst.min_notional(),
+            Self::FxForward(inst) => inst.min_notional(),
+            Self::Ndf(inst) => inst.min_notional(),
+            Self::OptionsContract(inst) => inst.min_notional(),
+            Self::OptionsSpread(inst) => inst.min_notional(),
+        }
+    }
+
+    #[must_use]
+    pub fn max_price(&self) -> Option<Price> {
+        match self {
+            Self::Betting(inst) => inst.max_price(),
+            Self::BinaryOption(inst) => inst.max_price(),
+            Self::Commodity(inst) => inst.max_price(),
+            Self::CryptoFuture(inst) => inst.max_price(),
+            Self::CryptoPerpetual(inst) => inst.max_price(),
+            Self::CurrencyPair(inst) => inst.max_price(),
+            Self::Equity(inst) => inst.max_price(),
+            Self::FuturesContract(inst) => inst.max_price(),
+            Self::FuturesSpread(inst) => inst.max_price(),
+            Self::FxForward(inst) => inst.max_price(),
+            Self::Ndf(inst) => inst.max_price(),
+            Self::OptionsContract(inst) => inst.max_price(),
+            Self::OptionsSpread(inst) => inst.max_price(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_price(&self) -> Option<Price> {
+        match self {
+            Self::Betting(inst) => inst.min_price(),
+            Self::BinaryOption(inst) => inst.min_price(),
+            Self::Commodity(inst) => inst.min_price(),
+            Self::CryptoFuture(inst) => inst.min_price(),
+            Self::CryptoPerpetual(inst) => inst.min_price(),
+            Self::CurrencyPair(inst) => inst.min_price(),
+            Self::Equity(inst) => inst.min_price(),
+            Self::FuturesContract(inst) => inst.min_price(),
+            Self::FuturesSpread(inst) => inst.min_price(),
+            Self::FxForward(inst) => inst.min_price(),
+            Self::Ndf(inst) => inst.min_price(),
+            Self::OptionsContract(inst) => inst.min_price(),
+            Self::OptionsSpread(inst) => inst.min_price(),
+        }
+    }
+
     #[must_use]
     pub fn multiplier(&self) -> Quantity {
         match self {
             Self::Betting(inst) => inst.multiplier(),
             Self::BinaryOption(inst) => inst.multiplier(),
+            Self::Commodity(inst) => inst.multiplier(),
             Self::CryptoFuture(inst) => inst.multiplier(),
             Self::CryptoPerpetual(inst) => inst.multiplier(),
             Self::CurrencyPair(inst) => inst.multiplier(),
             Self::Equity(inst) => inst.multiplier(),
             Self::FuturesContract(inst) => inst.multiplier(),
             Self::FuturesSpread(inst) => inst.multiplier(),
+            Self::FxForward(inst) => inst.multiplier(),
+            Self::Ndf(inst) => inst.multiplier(),
             Self::OptionsContract(inst) => inst.multiplier(),
             Self::OptionsSpread(inst) => inst.multiplier(),
         }
     }
 
+    #[must_use]
+    pub fn asset_class(&self) -> AssetClass {
+        match self {
+            Self::Betting(inst) => inst.asset_class(),
+            Self::BinaryOption(inst) => inst.asset_class(),
+            Self::Commodity(inst) => inst.asset_class(),
+            Self::CryptoFuture(inst) => inst.asset_class(),
+            Self::CryptoPerpetual(inst) => inst.asset_class(),
+            Self::CurrencyPair(inst) => inst.asset_class(),
+            Self::Equity(inst) => inst.asset_class(),
+            Self::FuturesContract(inst) => inst.asset_class(),
+            Self::FuturesSpread(inst) => inst.asset_class(),
+            Self::FxForward(inst) => inst.asset_class(),
+            Self::Ndf(inst) => inst.asset_class(),
+            Self::OptionsContract(inst) => inst.asset_class(),
+            Self::OptionsSpread(inst) => inst.asset_class(),
+        }
+    }
+
     #[must_use]
     pub fn instrument_class(&self) -> InstrumentClass {
         match self {
             Self::Betting(inst) => inst.instrument_class(),
             Self::BinaryOption(inst) => inst.instrument_class(),
+            Self::Commodity(inst) => inst.instrument_class(),
             Self::CryptoFuture(inst) => inst.instrument_class(),
             Self::CryptoPerpetual(inst) => inst.instrument_class(),
             Self::CurrencyPair(inst) => inst.instrument_class(),
             Self::Equity(inst) => inst.instrument_class(),
             Self::FuturesContract(inst) => inst.instrument_class(),
             Self::FuturesSpread(inst) => inst.instrument_class(),
+            Self::FxForward(inst) => inst.instrument_class(),
+            Self::Ndf(inst) => inst.instrument_class(),
             Self::OptionsContract(inst) => inst.instrument_class(),
             Self::OptionsSpread(inst) => inst.instrument_class(),
         }
@@ -258,12 +458,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.activation_ns(),
             Self::BinaryOption(inst) => inst.activation_ns(),
+            Self::Commodity(inst) => inst.activation_ns(),
             Self::CryptoFuture(inst) => inst.activation_ns(),
             Self::CryptoPerpetual(inst) => inst.activation_ns(),
             Self::CurrencyPair(inst) => inst.activation_ns(),
             Self::Equity(inst) => inst.activation_ns(),
             Self::FuturesContract(inst) => inst.activation_ns(),
             Self::FuturesSpread(inst) => inst.activation_ns(),
+            Self::FxForward(inst) => inst.activation_ns(),
+            Self::Ndf(inst) => inst.activation_ns(),
             Self::OptionsContract(inst) => inst.activation_ns(),
             Self::OptionsSpread(inst) => inst.activation_ns(),
         }
@@ -274,12 +477,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.expiration_ns(),
             Self::BinaryOption(inst) => inst.expiration_ns(),
+            Self::Commodity(inst) => inst.expiration_ns(),
             Self::CryptoFuture(inst) => inst.expiration_ns(),
             Self::CryptoPerpetual(inst) => inst.expiration_ns(),
             Self::CurrencyPair(inst) => inst.expiration_ns(),
             Self::Equity(inst) => inst.expiration_ns(),
             Self::FuturesContract(inst) => inst.expiration_ns(),
             Self::FuturesSpread(inst) => inst.expiration_ns(),
+            Self::FxForward(inst) => inst.expiration_ns(),
+            Self::Ndf(inst) => inst.expiration_ns(),
             Self::OptionsContract(inst) => inst.expiration_ns(),
             Self::OptionsSpread(inst) => inst.expiration_ns(),
         }
@@ -289,12 +495,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_price(value),
             Self::BinaryOption(inst) => inst.make_price(value),
+            Self::Commodity(inst) => inst.make_price(value),
             Self::CryptoFuture(inst) => inst.make_price(value),
             Self::CryptoPerpetual(inst) => inst.make_price(value),
             Self::CurrencyPair(inst) => inst.make_price(value),
             Self::Equity(inst) => inst.make_price(value),
             Self::FuturesContract(inst) => inst.make_price(value),
             Self::FuturesSpread(inst) => inst.make_price(value),
+            Self::FxForward(inst) => inst.make_price(value),
+            Self::Ndf(inst) => inst.make_price(value),
             Self::OptionsContract(inst) => inst.make_price(value),
             Self::OptionsSpread(inst) => inst.make_price(value),
         }
@@ -304,12 +513,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_qty(value),
             Self::BinaryOption(inst) => inst.make_qty(value),
+            Self::Commodity(inst) => inst.make_qty(value),
             Self::CryptoFuture(inst) => inst.make_qty(value),
             Self::CryptoPerpetual(inst) => inst.make_qty(value),
             Self::CurrencyPair(inst) => inst.make_qty(value),
             Self::Equity(inst) => inst.make_qty(value),
             Self::FuturesContract(inst) => inst.make_qty(value),
             Self::FuturesSpread(inst) => inst.make_qty(value),
+            Self::FxForward(inst) => inst.make_qty(value),
+            Self::Ndf(inst) => inst.make_qty(value),
             Self::OptionsContract(inst) => inst.make_qty(value),
             Self::OptionsSpread(inst) => inst.make_qty(value),
         }
@@ -329,6 +541,9 @@ impl InstrumentAny {
             Self::BinaryOption(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::Commodity(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::CryptoFuture(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -347,6 +562,12 @@ impl InstrumentAny {
             Self::FuturesSpread(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::FxForward(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
+            Self::Ndf(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::OptionsContract(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -362,12 +583,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.maker_fee(),
             Self::BinaryOption(inst) => inst.maker_fee(),
+            Self::Commodity(inst) => inst.maker_fee(),
             Self::CryptoFuture(inst) => inst.maker_fee(),
             Self::CryptoPerpetual(inst) => inst.maker_fee(),
             Self::CurrencyPair(inst) => inst.maker_fee(),
             Self::Equity(inst) => inst.maker_fee(),
             Self::FuturesContract(inst) => inst.maker_fee(),
             Self::FuturesSpread(inst) => inst.maker_fee(),
+            Self::FxForward(inst) => inst.maker_fee(),
+            Self::Ndf(inst) => inst.maker_fee(),
             Self::OptionsContract(inst) => inst.maker_fee(),
             Self::OptionsSpread(inst) => inst.maker_fee(),
         }
@@ -379,31 +603,391 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.taker_fee(),
             Self::BinaryOption(inst) => inst.taker_fee(),
+            Self::Commodity(inst) => inst.taker_fee(),
             Self::CryptoFuture(inst) => inst.taker_fee(),
             Self::CryptoPerpetual(inst) => inst.taker_fee(),
             Self::CurrencyPair(inst) => inst.taker_fee(),
             Self::Equity(inst) => inst.taker_fee(),
             Self::FuturesContract(inst) => inst.taker_fee(),
             Self::FuturesSpread(inst) => inst.taker_fee(),
+            Self::FxForward(inst) => inst.taker_fee(),
+            Self::Ndf(inst) => inst.taker_fee(),
             Self::OptionsContract(inst) => inst.taker_fee(),
             Self::OptionsSpread(inst) => inst.taker_fee(),
         }
     }
 
+    #[must_use]
+    pub fn margin_init(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_init(),
+            Self::BinaryOption(inst) => inst.margin_init(),
+            Self::Commodity(inst) => inst.margin_init(),
+            Self::CryptoFuture(inst) => inst.margin_init(),
+            Self::CryptoPerpetual(inst) => inst.margin_init(),
+            Self::CurrencyPair(inst) => inst.margin_init(),
+            Self::Equity(inst) => inst.margin_init(),
+            Self::FuturesContract(inst) => inst.margin_init(),
+            Self::FuturesSpread(inst) => inst.margin_init(),
+            Self::FxForward(inst) => inst.margin_init(),
+            Self::Ndf(inst) => inst.margin_init(),
+            Self::OptionsContract(inst) => inst.margin_init(),
+            Self::OptionsSpread(inst) => inst.margin_init(),
+        }
+    }
+
+    #[must_use]
+    pub fn margin_maint(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_maint(),
+            Self::BinaryOption(inst) => inst.margin_maint(),
+            Self::Commodity(inst) => inst.margin_maint(),
+            Self::CryptoFuture(inst) => inst.margin_maint(),
+            Self::CryptoPerpetual(inst) => inst.margin_maint(),
+            Self::CurrencyPair(inst) => inst.margin_maint(),
+            Self::Equity(inst) => inst.margin_maint(),
+            Self::FuturesContract(inst) => inst.margin_maint(),
+            Self::FuturesSpread(inst) => inst.margin_maint(),
+            Self::FxForward(inst) => inst.margin_maint(),
+            Self::Ndf(inst) => inst.margin_maint(),
+            Self::OptionsContract(inst) => inst.margin_maint(),
+            Self::OptionsSpread(inst) => inst.margin_maint(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Betting(inst) => inst.ts_event(),
+            Self::BinaryOption(inst) => inst.ts_event(),
+            Self::Commodity(inst) => inst.ts_event(),
+            Self::CryptoFuture(inst) => inst.ts_event(),
+            Self::CryptoPerpetual(inst) => inst.ts_event(),
+            Self::CurrencyPair(inst) => inst.ts_event(),
+            Self::Equity(inst) => inst.ts_event(),
+            Self::FuturesContract(inst) => inst.ts_event(),
+            Self::FuturesSpread(inst) => inst.ts_event(),
+            Self::FxForward(inst) => inst.ts_event(),
+            Self::Ndf(inst) => inst.ts_event(),
+            Self::OptionsContract(inst) => inst.ts_event(),
+            Self::OptionsSpread(inst) => inst.ts_event(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Betting(inst) => inst.ts_init(),
+            Self::BinaryOption(inst) => inst.ts_init(),
+            Self::Commodity(inst) => inst.ts_init(),
+            Self::CryptoFuture(inst) => inst.ts_init(),
+            Self::CryptoPerpetual(inst) => inst.ts_init(),
+            Self::CurrencyPair(inst) => inst.ts_init(),
+            Self::Equity(inst) => inst.ts_init(),
+            Self::FuturesContract(inst) => inst.ts_init(),
+            Self::FuturesSpread(inst) => inst.ts_init(),
+            Self::FxForward(inst) => inst.ts_init(),
+            Self::Ndf(inst) => inst.ts_init(),
+            Self::OptionsContract(inst) => inst.ts_init(),
+            Self::OptionsSpread(inst) => inst.ts_init(),
+        }
+    }
+
+    /// Returns the ISO 10383 Market Identifier Code (MIC) of the exchange where the instrument
+    /// trades (if any).
+    #[must_use]
+    pub fn exchange(&self) -> Option<Ustr> {
+        match self {
+            Self::Betting(inst) => inst.exchange(),
+            Self::BinaryOption(inst) => inst.exchange(),
+            Self::Commodity(inst) => inst.exchange(),
+            Self::CryptoFuture(inst) => inst.exchange(),
+            Self::CryptoPerpetual(inst) => inst.exchange(),
+            Self::CurrencyPair(inst) => inst.exchange(),
+            Self::Equity(inst) => inst.exchange(),
+            Self::FuturesContract(inst) => inst.exchange(),
+            Self::FuturesSpread(inst) => inst.exchange(),
+            Self::FxForward(inst) => inst.exchange(),
+            Self::Ndf(inst) => inst.exchange(),
+            Self::OptionsContract(inst) => inst.exchange(),
+            Self::OptionsSpread(inst) => inst.exchange(),
+        }
+    }
+
+    /// Returns the ISIN (International Securities Identification Number) of the instrument (if any).
+    #[must_use]
+    pub fn isin(&self) -> Option<Ustr> {
+        match self {
+            Self::Betting(inst) => inst.isin(),
+            Self::BinaryOption(inst) => inst.isin(),
+            Self::Commodity(inst) => inst.isin(),
+            Self::CryptoFuture(inst) => inst.isin(),
+            Self::CryptoPerpetual(inst) => inst.isin(),
+            Self::CurrencyPair(inst) => inst.isin(),
+            Self::Equity(inst) => inst.isin(),
+            Self::FuturesContract(inst) => inst.isin(),
+            Self::FuturesSpread(inst) => inst.isin(),
+            Self::FxForward(inst) => inst.isin(),
+            Self::Ndf(inst) => inst.isin(),
+            Self::OptionsContract(inst) => inst.isin(),
+            Self::OptionsSpread(inst) => inst.isin(),
+        }
+    }
+
+    /// Returns the FIGI (Financial Instrument Global Identifier) of the instrument (if any).
+    #[must_use]
+    pub fn figi(&self) -> Option<Ustr> {
+        match self {
+            Self::Betting(inst) => inst.figi(),
+            Self::BinaryOption(inst) => inst.figi(),
+            Self::Commodity(inst) => inst.figi(),
+            Self::CryptoFuture(inst) => inst.figi(),
+            Self::CryptoPerpetual(inst) => inst.figi(),
+            Self::CurrencyPair(inst) => inst.figi(),
+            Self::Equity(inst) => inst.figi(),
+            Self::FuturesContract(inst) => inst.figi(),
+            Self::FuturesSpread(inst) => inst.figi(),
+            Self::FxForward(inst) => inst.figi(),
+            Self::Ndf(inst) => inst.figi(),
+            Self::OptionsContract(inst) => inst.figi(),
+            Self::OptionsSpread(inst) => inst.figi(),
+        }
+    }
+
+    /// Returns the additional key-value facts about the instrument (if any).
+    #[must_use]
+    pub fn info(&self) -> Option<InstrumentInfo> {
+        match self {
+            Self::Betting(inst) => inst.info,
+            Self::BinaryOption(inst) => inst.info,
+            Self::Commodity(inst) => inst.info,
+            Self::CryptoFuture(inst) => inst.info,
+            Self::CryptoPerpetual(inst) => inst.info,
+            Self::CurrencyPair(inst) => inst.info,
+            Self::Equity(inst) => inst.info,
+            Self::FuturesContract(inst) => inst.info,
+            Self::FuturesSpread(inst) => inst.info,
+            Self::FxForward(inst) => inst.info,
+            Self::Ndf(inst) => inst.info,
+            Self::OptionsContract(inst) => inst.info,
+            Self::OptionsSpread(inst) => inst.info,
+        }
+    }
+
+    /// Sets the additional key-value facts about the instrument (`None` for no info).
+    pub fn set_info(&mut self, info: Option<InstrumentInfo>) {
+        match self {
+            Self::Betting(inst) => inst.info = info,
+            Self::BinaryOption(inst) => inst.info = info,
+            Self::Commodity(inst) => inst.info = info,
+            Self::CryptoFuture(inst) => inst.info = info,
+            Self::CryptoPerpetual(inst) => inst.info = info,
+            Self::CurrencyPair(inst) => inst.info = info,
+            Self::Equity(inst) => inst.info = info,
+            Self::FuturesContract(inst) => inst.info = info,
+            Self::FuturesSpread(inst) => inst.info = info,
+            Self::FxForward(inst) => inst.info = info,
+            Self::Ndf(inst) => inst.info = info,
+            Self::OptionsContract(inst) => inst.info = info,
+            Self::OptionsSpread(inst) => inst.info = info,
+        }
+    }
+
     pub fn get_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
         match self {
             Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::BinaryOption(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Commodity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoFuture(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CurrencyPair(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::Equity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FxForward(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Ndf(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionsContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionsSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
         }
     }
+
+    /// Applies the changed fields of the `update` to the instrument in place.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error, leaving the instrument unchanged:
+    /// - If the `update` is for a different instrument.
+    /// - If a new price or size increment does not have the precision of the instrument.
+    /// - If the `update` changes a field the instrument type does not have.
+    pub fn apply_update(&mut self, update: &InstrumentUpdate) -> anyhow::Result<()> {
+        check_equal(
+            update.instrument_id,
+            self.id(),
+            "update.instrument_id",
+            "instrument.id",
+        )?;
+        if let Some(price_increment) = update.price_increment {
+            check_equal_u8(
+                price_increment.precision,
+                self.price_precision(),
+                "update.price_increment.precision",
+                "instrument.price_precision",
+            )?;
+        }
+        if let Some(size_increment) = update.size_increment {
+            check_equal_u8(
+                size_increment.precision,
+                self.size_precision(),
+                "update.size_increment.precision",
+                "instrument.size_precision",
+            )?;
+        }
+
+        let (has_size_increment, has_lot_size, has_margins) = match self {
+            Self::Betting(_) => (true, false, false),
+            Self::BinaryOption(_) => (true, false, true),
+            Self::Equity(_) => (false, true, true),
+            _ => (true, true, true),
+        };
+        let unsupported = [
+            (
+                "size_increment",
+                update.size_increment.is_some() && !has_size_increment,
+            ),
+            ("lot_size", update.lot_size.is_some() && !has_lot_size),
+            ("margin_init", update.margin_init.is_some() && !has_margins),
+            (
+                "margin_maint",
+                update.margin_maint.is_some() && !has_margins,
+            ),
+        ];
+        if let Some((field, _)) = unsupported.iter().find(|(_, unsupported)| *unsupported) {
+            anyhow::bail!("Cannot update `{field}` of instrument {}", self.id());
+        }
+
+        match self {
+            Self::Betting(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::BinaryOption(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.margin_init, update.margin_init.map(Some));
+                set_field(&mut inst.margin_maint, update.margin_maint.map(Some));
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::Commodity(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size.map(Some));
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::CryptoFuture(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size);
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::CryptoPerpetual(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size);
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::CurrencyPair(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size.map(Some));
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::Equity(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.lot_size, update.lot_size.map(Some));
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::FuturesContract(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size);
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::FuturesSpread(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size);
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::FxForward(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size.map(Some));
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::Ndf(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size.map(Some));
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::OptionsContract(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size);
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+            Self::OptionsSpread(inst) => {
+                set_field(&mut inst.price_increment, update.price_increment);
+                set_field(&mut inst.size_increment, update.size_increment);
+                set_field(&mut inst.lot_size, update.lot_size);
+                set_field(&mut inst.margin_init, update.margin_init);
+                set_field(&mut inst.margin_maint, update.margin_maint);
+                inst.ts_event = update.ts_event;
+                inst.ts_init = update.ts_init;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Sets the `field` to the `value` (if any)
+fn set_field<T>(field: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *field = value;
+    }
 }
 
 impl PartialEq for InstrumentAny {
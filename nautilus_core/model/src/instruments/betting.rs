@@ -16,20 +16,93 @@
 use std::hash::{Hash, Hasher};
 
 use nautilus_core::{
-    correctness::{check_equal_u8, check_positive_i64, check_positive_u64, FAILED},
+    correctness::{
+        check_equal_u8, check_positive_i64, check_positive_u64, check_predicate_true, FAILED,
+    },
     nanos::UnixNanos,
 };
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    Instrument,
+};
 use crate::{
-    enums::{AssetClass, InstrumentClass, OptionKind},
+    enums::{AssetClass, InstrumentClass, OptionKind, OrderSide},
     identifiers::{InstrumentId, Symbol},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// Returns the implied probability for the given decimal `odds`.
+///
+/// # Panics
+///
+/// This function panics if `odds` is not greater than one.
+#[must_use]
+pub fn decimal_odds_to_probability(odds: Decimal) -> Decimal {
+    check_predicate_true(odds > Decimal::ONE, "odds must be greater than 1").expect(FAILED);
+    Decimal::ONE / odds
+}
+
+/// Returns the decimal odds for the given implied `probability`.
+///
+/// # Panics
+///
+/// This function panics if `probability` is not in the range (0, 1].
+#[must_use]
+pub fn probability_to_decimal_odds(probability: Decimal) -> Decimal {
+    check_predicate_true(
+        probability > Decimal::ZERO && probability <= Decimal::ONE,
+        "probability must be in range (0, 1]",
+    )
+    .expect(FAILED);
+    Decimal::ONE / probability
+}
+
+/// Returns the decimal odds for the given fractional odds `numerator`/`denominator` (e.g. 5/2 -> 3.5).
+///
+/// # Panics
+///
+/// This function panics if `denominator` is zero.
+#[must_use]
+pub fn fractional_to_decimal_odds(numerator: u32, denominator: u32) -> Decimal {
+    check_predicate_true(denominator != 0, "denominator was zero").expect(FAILED);
+    Decimal::ONE + Decimal::from(numerator) / Decimal::from(denominator)
+}
+
+/// Returns the fractional odds `(numerator, denominator)` in lowest terms for the given decimal `odds`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `odds` is not greater than one.
+/// - If the numerator or denominator in lowest terms is not representable as a `u64`.
+pub fn decimal_to_fractional_odds(odds: Decimal) -> anyhow::Result<(u64, u64)> {
+    check_predicate_true(odds > Decimal::ONE, "odds must be greater than 1")?;
+    let profit = (odds - Decimal::ONE).normalize();
+    // The mantissa of a `Decimal` is at most 96 bits and its scale at most 28
+    let numerator = profit.mantissa().unsigned_abs();
+    let denominator = 10_u128.pow(profit.scale());
+    let divisor = gcd(numerator, denominator);
+    match (
+        u64::try_from(numerator / divisor),
+        u64::try_from(denominator / divisor),
+    ) {
+        (Ok(numerator), Ok(denominator)) => Ok((numerator, denominator)),
+        _ => anyhow::bail!("fractional odds for {odds} not representable as `u64`"),
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 /// Represents a generic sports betting instrument.
 #[repr(C)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -72,6 +145,14 @@ pub struct BettingInstrument {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl BettingInstrument {
@@ -165,6 +246,8 @@ impl BettingInstrument {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -242,6 +325,70 @@ impl BettingInstrument {
         )
         .expect(FAILED)
     }
+
+    /// Returns the implied probability of the selection for the given odds `price`.
+    #[must_use]
+    pub fn implied_probability(&self, price: Price) -> Decimal {
+        decimal_odds_to_probability(price.as_decimal())
+    }
+
+    /// Returns the profit of a back bet if the selection wins, which is also the liability of
+    /// a lay bet, for the given `quantity` matched at the odds `price`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the profit is outside the representable range of [`Money`].
+    #[must_use]
+    pub fn potential_profit(&self, quantity: Quantity, price: Price) -> Money {
+        self.make_money(quantity.as_decimal() * (price.as_decimal() - Decimal::ONE))
+    }
+
+    /// Returns the liability (maximum loss) of a bet for the given `quantity` matched at the
+    /// odds `price`, where a `BUY` is a back and a `SELL` is a lay.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `side` is `NoOrderSide`.
+    #[must_use]
+    pub fn liability(&self, quantity: Quantity, price: Price, side: OrderSide) -> Money {
+        match side {
+            OrderSide::Buy => self.make_money(quantity.as_decimal()),
+            OrderSide::Sell => self.potential_profit(quantity, price),
+            OrderSide::NoOrderSide => panic!("Invalid `OrderSide`, was {side}"),
+        }
+    }
+
+    /// Returns the profit and loss of a bet if the selection wins.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `side` is `NoOrderSide`.
+    #[must_use]
+    pub fn win_payoff(&self, quantity: Quantity, price: Price, side: OrderSide) -> Money {
+        match side {
+            OrderSide::Buy => self.potential_profit(quantity, price),
+            OrderSide::Sell => -self.potential_profit(quantity, price),
+            OrderSide::NoOrderSide => panic!("Invalid `OrderSide`, was {side}"),
+        }
+    }
+
+    /// Returns the profit and loss of a bet if the selection loses.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `side` is `NoOrderSide`.
+    #[must_use]
+    pub fn lose_payoff(&self, quantity: Quantity, side: OrderSide) -> Money {
+        match side {
+            OrderSide::Buy => -self.make_money(quantity.as_decimal()),
+            OrderSide::Sell => self.make_money(quantity.as_decimal()),
+            OrderSide::NoOrderSide => panic!("Invalid `OrderSide`, was {side}"),
+        }
+    }
+
+    fn make_money(&self, amount: Decimal) -> Money {
+        Money::from_decimal(amount, self.currency).expect(FAILED)
+    }
 }
 
 impl PartialEq<Self> for BettingInstrument {
@@ -299,6 +446,10 @@ impl Instrument for BettingInstrument {
         None
     }
 
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
+
     fn exchange(&self) -> Option<Ustr> {
         None
     }
@@ -359,6 +510,14 @@ impl Instrument for BettingInstrument {
         self.ts_init
     }
 
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
+
     fn strike_price(&self) -> Option<Price> {
         None
     }
@@ -386,12 +545,128 @@ impl Instrument for BettingInstrument {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
-    use crate::instruments::{betting::BettingInstrument, stubs::*};
+    use super::*;
+    use crate::{instruments::stubs::*, tick_scheme::BETFAIR_TICK_SCHEME};
 
     #[rstest]
     fn test_equality(betting: BettingInstrument) {
         let cloned = betting;
         assert_eq!(betting, cloned);
     }
+
+    #[rstest]
+    #[case(dec!(2.0), dec!(0.5))]
+    #[case(dec!(4.0), dec!(0.25))]
+    #[case(dec!(1.25), dec!(0.8))]
+    fn test_decimal_odds_probability_round_trip(
+        #[case] odds: Decimal,
+        #[case] probability: Decimal,
+    ) {
+        assert_eq!(decimal_odds_to_probability(odds), probability);
+        assert_eq!(probability_to_decimal_odds(probability), odds);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "odds must be greater than 1")]
+    fn test_decimal_odds_to_probability_with_invalid_odds() {
+        let _ = decimal_odds_to_probability(dec!(1.0));
+    }
+
+    #[rstest]
+    #[case(5, 2, dec!(3.5))]
+    #[case(1, 1, dec!(2.0))]
+    #[case(1, 4, dec!(1.25))]
+    fn test_fractional_decimal_odds_round_trip(
+        #[case] numerator: u32,
+        #[case] denominator: u32,
+        #[case] odds: Decimal,
+    ) {
+        assert_eq!(fractional_to_decimal_odds(numerator, denominator), odds);
+        assert_eq!(
+            decimal_to_fractional_odds(odds).unwrap(),
+            (u64::from(numerator), u64::from(denominator))
+        );
+    }
+
+    #[rstest]
+    fn test_decimal_to_fractional_odds_with_high_precision() {
+        assert_eq!(
+            decimal_to_fractional_odds(dec!(1.0000000000000000005)).unwrap(),
+            (1, 2_000_000_000_000_000_000)
+        );
+        assert!(decimal_to_fractional_odds(dec!(1.0000000000000000000000000001)).is_err());
+    }
+
+    #[rstest]
+    fn test_decimal_to_fractional_odds_with_invalid_odds() {
+        assert!(decimal_to_fractional_odds(dec!(1.0)).is_err());
+    }
+
+    #[rstest]
+    fn test_next_prices_with_betfair_tick_scheme(mut betting: BettingInstrument) {
+        betting.tick_scheme = Some(Ustr::from(BETFAIR_TICK_SCHEME));
+
+        assert_eq!(
+            betting.next_ask_price(3.01, 0).unwrap(),
+            Some(Price::from("3.05"))
+        );
+        assert_eq!(
+            betting.next_bid_price(3.01, 1).unwrap(),
+            Some(Price::from("2.98"))
+        );
+        assert_eq!(betting.next_ask_price(1000.0, 1).unwrap(), None);
+    }
+
+    #[rstest]
+    fn test_next_prices_with_unregistered_tick_scheme(mut betting: BettingInstrument) {
+        betting.tick_scheme = Some(Ustr::from("UNKNOWN"));
+
+        assert!(betting.next_bid_price(3.01, 0).is_err());
+        assert!(betting.next_ask_price(3.01, 0).is_err());
+    }
+
+    #[rstest]
+    fn test_implied_probability(betting: BettingInstrument) {
+        assert_eq!(betting.implied_probability(Price::from("5.00")), dec!(0.2));
+    }
+
+    #[rstest]
+    fn test_back_bet_liability_and_payoffs(betting: BettingInstrument) {
+        let quantity = Quantity::from("10.00");
+        let price = Price::from("3.50");
+
+        assert_eq!(
+            betting.liability(quantity, price, OrderSide::Buy),
+            Money::from("10 GBP")
+        );
+        assert_eq!(
+            betting.win_payoff(quantity, price, OrderSide::Buy),
+            Money::from("25 GBP")
+        );
+        assert_eq!(
+            betting.lose_payoff(quantity, OrderSide::Buy),
+            Money::from("-10 GBP")
+        );
+    }
+
+    #[rstest]
+    fn test_lay_bet_liability_and_payoffs(betting: BettingInstrument) {
+        let quantity = Quantity::from("10.00");
+        let price = Price::from("3.50");
+
+        assert_eq!(
+            betting.liability(quantity, price, OrderSide::Sell),
+            Money::from("25 GBP")
+        );
+        assert_eq!(
+            betting.win_payoff(quantity, price, OrderSide::Sell),
+            Money::from("-25 GBP")
+        );
+        assert_eq!(
+            betting.lose_payoff(quantity, OrderSide::Sell),
+            Money::from("10 GBP")
+        );
+    }
 }
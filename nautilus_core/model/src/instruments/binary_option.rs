@@ -23,7 +23,11 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    Instrument,
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -63,6 +67,14 @@ pub struct BinaryOption {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl BinaryOption {
@@ -138,6 +150,8 @@ impl BinaryOption {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -254,6 +268,10 @@ impl Instrument for BinaryOption {
         None
     }
 
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
+
     fn exchange(&self) -> Option<Ustr> {
         None
     }
@@ -314,6 +332,14 @@ impl Instrument for BinaryOption {
         self.ts_init
     }
 
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
+
     fn strike_price(&self) -> Option<Price> {
         None
     }
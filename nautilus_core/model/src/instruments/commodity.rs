@@ -0,0 +1,442 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::hash::{Hash, Hasher};
+
+use nautilus_core::{
+    correctness::{
+        check_equal_u8, check_positive_i64, check_positive_u64, check_valid_string_optional, FAILED,
+    },
+    nanos::UnixNanos,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    Instrument,
+};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind},
+    identifiers::{InstrumentId, Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a generic commodity instrument, traded spot or for delivery on a delivery date.
+///
+/// Physically settled commodities are delivered to the holder at the delivery location, to the
+/// quality specification of the contract.
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct Commodity {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub quote_currency: Currency,
+    /// The delivery location (e.g. a hub or warehouse) for physical settlement.
+    pub delivery_location: Option<Ustr>,
+    /// The quality specification (grade) of the delivered commodity.
+    pub quality_spec: Option<Ustr>,
+    /// If the instrument is settled by physical delivery rather than cash.
+    pub is_physically_settled: bool,
+    /// UNIX timestamp (nanoseconds) for the delivery date (none for spot).
+    pub delivery_ns: Option<UnixNanos>,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    pub multiplier: Quantity,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub margin_init: Decimal,
+    pub margin_maint: Decimal,
+    pub lot_size: Option<Quantity>,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_notional: Option<Money>,
+    pub min_notional: Option<Money>,
+    pub max_price: Option<Price>,
+    pub min_price: Option<Price>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
+}
+
+impl Commodity {
+    /// Creates a new [`Commodity`] instance with correctness checking.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        quote_currency: Currency,
+        delivery_location: Option<Ustr>,
+        quality_spec: Option<Ustr>,
+        is_physically_settled: bool,
+        delivery_ns: Option<UnixNanos>,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        multiplier: Quantity,
+        taker_fee: Decimal,
+        maker_fee: Decimal,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_u64(size_increment.raw, stringify!(size_increment.raw))?;
+        check_positive_u64(multiplier.raw, stringify!(multiplier.raw))?;
+        check_valid_string_optional(delivery_location, stringify!(delivery_location))?;
+        check_valid_string_optional(quality_spec, stringify!(quality_spec))?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            quote_currency,
+            delivery_location,
+            quality_spec,
+            is_physically_settled,
+            delivery_ns,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            maker_fee,
+            taker_fee,
+            margin_init,
+            margin_maint,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+            tick_scheme: None,
+            info: None,
+        })
+    }
+
+    /// Creates a new [`Commodity`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        quote_currency: Currency,
+        delivery_location: Option<Ustr>,
+        quality_spec: Option<Ustr>,
+        is_physically_settled: bool,
+        delivery_ns: Option<UnixNanos>,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        multiplier: Quantity,
+        taker_fee: Decimal,
+        maker_fee: Decimal,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            quote_currency,
+            delivery_location,
+            quality_spec,
+            is_physically_settled,
+            delivery_ns,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            taker_fee,
+            maker_fee,
+            margin_init,
+            margin_maint,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns whether a position in the instrument would go to physical delivery.
+    #[must_use]
+    pub fn is_deliverable(&self) -> bool {
+        self.is_physically_settled && self.delivery_ns.is_some()
+    }
+}
+
+impl PartialEq<Self> for Commodity {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Commodity {}
+
+impl Hash for Commodity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for Commodity {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::Commodity(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Commodity
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        if self.delivery_ns.is_some() {
+            InstrumentClass::Forward
+        } else {
+            InstrumentClass::Spot
+        }
+    }
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.quote_currency
+    }
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        self.delivery_ns
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        self.max_notional
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        self.min_notional
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        enums::InstrumentClass,
+        instruments::{commodity::Commodity, stubs::*, Instrument},
+    };
+
+    #[rstest]
+    fn test_equality(commodity_wti_cushing: Commodity) {
+        let cloned = commodity_wti_cushing;
+        assert_eq!(commodity_wti_cushing, cloned);
+    }
+
+    #[rstest]
+    fn test_delivery(commodity_wti_cushing: Commodity) {
+        assert!(commodity_wti_cushing.is_deliverable());
+        assert_eq!(
+            commodity_wti_cushing.instrument_class(),
+            InstrumentClass::Forward
+        );
+        assert_eq!(
+            commodity_wti_cushing.expiration_ns(),
+            commodity_wti_cushing.delivery_ns
+        );
+
+        let spot = Commodity {
+            delivery_ns: None,
+            ..commodity_wti_cushing
+        };
+        assert!(!spot.is_deliverable());
+        assert_eq!(spot.instrument_class(), InstrumentClass::Spot);
+    }
+}
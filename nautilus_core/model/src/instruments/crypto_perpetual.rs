@@ -23,7 +23,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::any::InstrumentAny;
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -64,6 +67,14 @@ pub struct CryptoPerpetual {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl CryptoPerpetual {
@@ -139,6 +150,8 @@ impl CryptoPerpetual {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -253,6 +266,10 @@ impl Instrument for CryptoPerpetual {
     fn isin(&self) -> Option<Ustr> {
         None
     }
+
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
     fn option_kind(&self) -> Option<OptionKind> {
         None
     }
@@ -346,6 +363,14 @@ impl Instrument for CryptoPerpetual {
     fn ts_init(&self) -> UnixNanos {
         self.ts_init
     }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -23,7 +23,11 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    Instrument,
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -60,6 +64,14 @@ pub struct CurrencyPair {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl CurrencyPair {
@@ -129,6 +141,8 @@ impl CurrencyPair {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -237,6 +251,10 @@ impl Instrument for CurrencyPair {
         None
     }
 
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
+
     fn is_inverse(&self) -> bool {
         false
     }
@@ -289,6 +307,14 @@ impl Instrument for CurrencyPair {
         self.ts_init
     }
 
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
+
     fn margin_init(&self) -> Decimal {
         self.margin_init
     }
@@ -16,14 +16,19 @@
 use std::hash::{Hash, Hasher};
 
 use nautilus_core::{
-    correctness::{check_equal_u8, check_positive_i64, check_valid_string_optional, FAILED},
+    correctness::{check_equal_u8, check_positive_i64, FAILED},
     nanos::UnixNanos,
 };
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    security_id::{check_figi_optional, check_isin_optional},
+    Instrument,
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -43,6 +48,9 @@ pub struct Equity {
     pub raw_symbol: Symbol,
     /// The ISIN (International Securities Identification Number).
     pub isin: Option<Ustr>,
+    /// The FIGI (Financial Instrument Global Identifier).
+    #[serde(default)]
+    pub figi: Option<Ustr>,
     pub currency: Currency,
     pub price_precision: u8,
     pub price_increment: Price,
@@ -57,6 +65,14 @@ pub struct Equity {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl Equity {
@@ -70,6 +86,7 @@ impl Equity {
         id: InstrumentId,
         raw_symbol: Symbol,
         isin: Option<Ustr>,
+        figi: Option<Ustr>,
         currency: Currency,
         price_precision: u8,
         price_increment: Price,
@@ -85,7 +102,8 @@ impl Equity {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
-        check_valid_string_optional(isin.map(|u| u.as_str()), stringify!(isin))?;
+        check_isin_optional(isin, stringify!(isin))?;
+        check_figi_optional(figi, stringify!(figi))?;
         check_equal_u8(
             price_precision,
             price_increment.precision,
@@ -98,6 +116,7 @@ impl Equity {
             id,
             raw_symbol,
             isin,
+            figi,
             currency,
             price_precision,
             price_increment,
@@ -112,6 +131,8 @@ impl Equity {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -121,6 +142,7 @@ impl Equity {
         id: InstrumentId,
         raw_symbol: Symbol,
         isin: Option<Ustr>,
+        figi: Option<Ustr>,
         currency: Currency,
         price_precision: u8,
         price_increment: Price,
@@ -140,6 +162,7 @@ impl Equity {
             id,
             raw_symbol,
             isin,
+            figi,
             currency,
             price_precision,
             price_increment,
@@ -213,6 +236,10 @@ impl Instrument for Equity {
         self.isin
     }
 
+    fn figi(&self) -> Option<Ustr> {
+        self.figi
+    }
+
     fn option_kind(&self) -> Option<OptionKind> {
         None
     }
@@ -292,6 +319,14 @@ impl Instrument for Equity {
     fn ts_init(&self) -> UnixNanos {
         self.ts_init
     }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -301,11 +336,26 @@ impl Instrument for Equity {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{equity::Equity, stubs::*};
+    use crate::{
+        instruments::{equity::Equity, stubs::*, Instrument},
+        types::price::Price,
+    };
 
     #[rstest]
     fn test_equality(equity_aapl: Equity) {
         let cloned = equity_aapl;
         assert_eq!(equity_aapl, cloned);
     }
+
+    #[rstest]
+    fn test_next_prices_without_tick_scheme(equity_aapl: Equity) {
+        assert_eq!(
+            equity_aapl.next_bid_price(191.234, 0).unwrap(),
+            Some(Price::from("191.23"))
+        );
+        assert_eq!(
+            equity_aapl.next_ask_price(191.234, 2).unwrap(),
+            Some(Price::from("191.26"))
+        );
+    }
 }
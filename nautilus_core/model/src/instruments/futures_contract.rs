@@ -25,7 +25,12 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    security_id::{check_figi_optional, check_isin_optional},
+    Instrument,
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -46,6 +51,12 @@ pub struct FuturesContract {
     pub asset_class: AssetClass,
     /// The exchange ISO 10383 Market Identifier Code (MIC) where the instrument trades.
     pub exchange: Option<Ustr>,
+    /// The ISIN (International Securities Identification Number).
+    #[serde(default)]
+    pub isin: Option<Ustr>,
+    /// The FIGI (Financial Instrument Global Identifier).
+    #[serde(default)]
+    pub figi: Option<Ustr>,
     pub underlying: Ustr,
     pub activation_ns: UnixNanos,
     pub expiration_ns: UnixNanos,
@@ -64,6 +75,14 @@ pub struct FuturesContract {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl FuturesContract {
@@ -78,6 +97,8 @@ impl FuturesContract {
         raw_symbol: Symbol,
         asset_class: AssetClass,
         exchange: Option<Ustr>,
+        isin: Option<Ustr>,
+        figi: Option<Ustr>,
         underlying: Ustr,
         activation_ns: UnixNanos,
         expiration_ns: UnixNanos,
@@ -95,7 +116,9 @@ impl FuturesContract {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
-        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(isin))?;
+        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(exchange))?;
+        check_isin_optional(isin, stringify!(isin))?;
+        check_figi_optional(figi, stringify!(figi))?;
         check_valid_string(underlying.as_str(), stringify!(underlying))?;
         check_equal_u8(
             price_precision,
@@ -109,6 +132,8 @@ impl FuturesContract {
             raw_symbol,
             asset_class,
             exchange,
+            isin,
+            figi,
             underlying,
             activation_ns,
             expiration_ns,
@@ -127,6 +152,8 @@ impl FuturesContract {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -137,6 +164,8 @@ impl FuturesContract {
         raw_symbol: Symbol,
         asset_class: AssetClass,
         exchange: Option<Ustr>,
+        isin: Option<Ustr>,
+        figi: Option<Ustr>,
         underlying: Ustr,
         activation_ns: UnixNanos,
         expiration_ns: UnixNanos,
@@ -159,6 +188,8 @@ impl FuturesContract {
             raw_symbol,
             asset_class,
             exchange,
+            isin,
+            figi,
             underlying,
             activation_ns,
             expiration_ns,
@@ -231,7 +262,11 @@ impl Instrument for FuturesContract {
     }
 
     fn isin(&self) -> Option<Ustr> {
-        None
+        self.isin
+    }
+
+    fn figi(&self) -> Option<Ustr> {
+        self.figi
     }
 
     fn option_kind(&self) -> Option<OptionKind> {
@@ -313,6 +348,14 @@ impl Instrument for FuturesContract {
     fn ts_init(&self) -> UnixNanos {
         self.ts_init
     }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -321,7 +364,9 @@ impl Instrument for FuturesContract {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
+    use ustr::Ustr;
 
+    use super::*;
     use crate::instruments::stubs::*;
 
     #[rstest]
@@ -329,4 +374,71 @@ mod tests {
         let futures_contract = futures_contract_es(None, None);
         assert_eq!(futures_contract, futures_contract.clone());
     }
+
+    fn new_with_identifiers(
+        isin: Option<&str>,
+        figi: Option<&str>,
+    ) -> anyhow::Result<FuturesContract> {
+        let esz1 = futures_contract_es(None, None);
+        FuturesContract::new_checked(
+            esz1.id,
+            esz1.raw_symbol,
+            esz1.asset_class,
+            esz1.exchange,
+            isin.map(Ustr::from),
+            figi.map(Ustr::from),
+            esz1.underlying,
+            esz1.activation_ns,
+            esz1.expiration_ns,
+            esz1.currency,
+            esz1.price_precision,
+            esz1.price_increment,
+            esz1.multiplier,
+            esz1.lot_size,
+            esz1.max_quantity,
+            esz1.min_quantity,
+            esz1.max_price,
+            esz1.min_price,
+            Some(esz1.margin_init),
+            Some(esz1.margin_maint),
+            esz1.ts_event,
+            esz1.ts_init,
+        )
+    }
+
+    #[rstest]
+    fn test_new_with_identifiers() {
+        let futures_contract =
+            new_with_identifiers(Some("DE000C6EV110"), Some("BBG00JX0P539")).unwrap();
+
+        assert_eq!(futures_contract.isin(), Some(Ustr::from("DE000C6EV110")));
+        assert_eq!(futures_contract.figi(), Some(Ustr::from("BBG00JX0P539")));
+    }
+
+    #[rstest]
+    #[case(Some("DE000C6EV111"), None)]
+    #[case(None, Some("BBG00JX0P530"))]
+    fn test_new_with_invalid_identifiers(#[case] isin: Option<&str>, #[case] figi: Option<&str>) {
+        assert!(new_with_identifiers(isin, figi).is_err());
+    }
+
+    #[rstest]
+    fn test_serde_identifiers() {
+        let futures_contract =
+            new_with_identifiers(Some("DE000C6EV110"), Some("BBG00JX0P539")).unwrap();
+
+        let json = serde_json::to_string(&futures_contract).unwrap();
+        assert!(json.contains(r#""isin":"DE000C6EV110","figi":"BBG00JX0P539""#));
+        let deserialized: FuturesContract = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.isin, futures_contract.isin);
+        assert_eq!(deserialized.figi, futures_contract.figi);
+
+        // Definitions serialized without the identifiers remain readable
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value.as_object_mut().unwrap().remove("isin");
+        value.as_object_mut().unwrap().remove("figi");
+        let deserialized: FuturesContract = serde_json::from_str(&value.to_string()).unwrap();
+        assert_eq!(deserialized.isin, None);
+        assert_eq!(deserialized.figi, None);
+    }
 }
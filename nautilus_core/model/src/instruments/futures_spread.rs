@@ -25,7 +25,11 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    Instrument,
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -65,6 +69,14 @@ pub struct FuturesSpread {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl FuturesSpread {
@@ -97,7 +109,7 @@ impl FuturesSpread {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
-        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(isin))?;
+        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(exchange))?;
         check_valid_string(strategy_type.as_str(), stringify!(strategy_type))?;
         check_equal_u8(
             price_precision,
@@ -130,6 +142,8 @@ impl FuturesSpread {
             min_price,
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -239,6 +253,10 @@ impl Instrument for FuturesSpread {
         None
     }
 
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
+
     fn option_kind(&self) -> Option<OptionKind> {
         None
     }
@@ -318,6 +336,14 @@ impl Instrument for FuturesSpread {
     fn ts_init(&self) -> UnixNanos {
         self.ts_init
     }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
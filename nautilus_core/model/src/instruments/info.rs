@@ -0,0 +1,157 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Display, Formatter};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use ustr::Ustr;
+
+/// Represents additional key-value facts about an instrument, such as adapter specific fields
+/// (e.g. contract unit descriptions or sector codes).
+///
+/// The facts are held as an interned JSON object so that instruments remain `Copy`, and are
+/// intended for small definitions which rarely change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InstrumentInfo(Ustr);
+
+impl InstrumentInfo {
+    /// Creates a new [`InstrumentInfo`] instance from the given `facts`.
+    #[must_use]
+    pub fn new(facts: &IndexMap<Ustr, Value>) -> Self {
+        let json = serde_json::to_string(facts).expect("Facts serialize to JSON");
+        Self(Ustr::from(&json))
+    }
+
+    /// Creates a new [`InstrumentInfo`] instance from a JSON object string.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `json` is not a JSON object.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let facts: IndexMap<Ustr, Value> = serde_json::from_str(json)?;
+        Ok(Self::new(&facts))
+    }
+
+    /// Returns the facts, in insertion order.
+    #[must_use]
+    pub fn facts(&self) -> IndexMap<Ustr, Value> {
+        serde_json::from_str(self.0.as_str()).expect("Info is a JSON object")
+    }
+
+    /// Returns the value for the given `key` (if found).
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.facts().shift_remove(&Ustr::from(key))
+    }
+
+    /// Returns the facts as a JSON object string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Display for InstrumentInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for InstrumentInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.facts().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for InstrumentInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let facts = IndexMap::<Ustr, Value>::deserialize(deserializer)?;
+        Ok(Self::new(&facts))
+    }
+}
+
+/// Deserializes optional instrument info, where an empty object is no info.
+pub(crate) fn deserialize_info<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<InstrumentInfo>, D::Error> {
+    let facts = Option::<IndexMap<Ustr, Value>>::deserialize(deserializer)?;
+    Ok(facts
+        .filter(|facts| !facts.is_empty())
+        .map(|facts| InstrumentInfo::new(&facts)))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+    use crate::instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::*};
+
+    fn info() -> InstrumentInfo {
+        InstrumentInfo::new(&indexmap! {
+            Ustr::from("sector") => json!("Technology"),
+            Ustr::from("unit_of_measure") => json!({"unit": "IPNT", "qty": 50}),
+        })
+    }
+
+    #[rstest]
+    fn test_facts_preserve_order_and_values() {
+        let info = info();
+
+        let keys: Vec<Ustr> = info.facts().keys().copied().collect();
+        assert_eq!(
+            keys,
+            vec![Ustr::from("sector"), Ustr::from("unit_of_measure")]
+        );
+        assert_eq!(info.get("sector"), Some(json!("Technology")));
+        assert_eq!(info.get("missing"), None);
+        assert_eq!(
+            info.as_str(),
+            r#"{"sector":"Technology","unit_of_measure":{"qty":50,"unit":"IPNT"}}"#
+        );
+    }
+
+    #[rstest]
+    fn test_from_json_requires_object() {
+        assert_eq!(InstrumentInfo::from_json(info().as_str()).unwrap(), info());
+        assert!(InstrumentInfo::from_json("[1, 2]").is_err());
+    }
+
+    #[rstest]
+    fn test_instrument_serde_round_trip_preserves_info(audusd_sim: CurrencyPair) {
+        let mut instrument = futures_contract_es(None, None);
+        instrument.info = Some(info());
+        let instrument = InstrumentAny::FuturesContract(instrument);
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: InstrumentAny = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.info(), Some(info()));
+        assert!(json.contains(r#""info":{"sector":"Technology""#));
+
+        // Instruments without info serialize as before
+        let json = serde_json::to_string(&audusd_sim).unwrap();
+        assert!(!json.contains("info"));
+        let deserialized: CurrencyPair = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.info, None);
+    }
+}
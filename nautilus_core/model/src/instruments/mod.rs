@@ -18,28 +18,36 @@
 pub mod any;
 pub mod betting;
 pub mod binary_option;
+pub mod commodity;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod fx_forward;
+pub mod info;
+pub mod ndf;
 pub mod options_contract;
 pub mod options_spread;
+pub mod security_id;
 pub mod synthetic;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
 
+use std::sync::Arc;
+
 use nautilus_core::nanos::UnixNanos;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use ustr::Ustr;
 
-use self::any::InstrumentAny;
+use self::{any::InstrumentAny, info::InstrumentInfo};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol, Venue},
+    tick_scheme::{get_tick_scheme, FixedTickScheme, TickScheme},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -60,6 +68,7 @@ pub trait Instrument: 'static + Send {
     fn quote_currency(&self) -> Currency;
     fn settlement_currency(&self) -> Currency;
     fn isin(&self) -> Option<Ustr>;
+    fn figi(&self) -> Option<Ustr>;
     fn option_kind(&self) -> Option<OptionKind>;
     fn exchange(&self) -> Option<Ustr>;
     fn strike_price(&self) -> Option<Price>;
@@ -95,6 +104,13 @@ pub trait Instrument: 'static + Send {
     }
     fn ts_event(&self) -> UnixNanos;
     fn ts_init(&self) -> UnixNanos;
+    /// Returns the name of the registered tick scheme for the instrument's prices, if any.
+    ///
+    /// Instruments without a tick scheme use a fixed `price_increment`.
+    fn tick_scheme(&self) -> Option<Ustr> {
+        None
+    }
+    fn info(&self) -> Option<InstrumentInfo>;
 
     /// Creates a new `Price` from the given `value` with the correct price precision for the instrument.
     fn make_price(&self, value: f64) -> Price {
@@ -106,6 +122,36 @@ pub trait Instrument: 'static + Send {
         Quantity::new(value, self.size_precision())
     }
 
+    /// Returns the price `n` ticks below the nearest valid bid price at or below `value`,
+    /// or `None` if outside the range of valid prices.
+    ///
+    /// Uses the instruments tick scheme if set, otherwise the fixed `price_increment`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instruments tick scheme has not been registered.
+    fn next_bid_price(&self, value: f64, n: usize) -> anyhow::Result<Option<Price>> {
+        match self.tick_scheme() {
+            Some(name) => Ok(registered_tick_scheme(&name)?.next_bid_price(value, n)),
+            None => Ok(fixed_tick_scheme(self).next_bid_price(value, n)),
+        }
+    }
+
+    /// Returns the price `n` ticks above the nearest valid ask price at or above `value`,
+    /// or `None` if outside the range of valid prices.
+    ///
+    /// Uses the instruments tick scheme if set, otherwise the fixed `price_increment`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instruments tick scheme has not been registered.
+    fn next_ask_price(&self, value: f64, n: usize) -> anyhow::Result<Option<Price>> {
+        match self.tick_scheme() {
+            Some(name) => Ok(registered_tick_scheme(&name)?.next_ask_price(value, n)),
+            None => Ok(fixed_tick_scheme(self).next_ask_price(value, n)),
+        }
+    }
+
     /// Calculates the notional value from the given parameters.
     /// The `use_quote_for_inverse` flag is only applicable for inverse instruments.
     ///
@@ -147,8 +193,22 @@ pub trait Instrument: 'static + Send {
     }
 }
 
-pub const EXPIRING_INSTRUMENT_TYPES: [InstrumentClass; 4] = [
+fn registered_tick_scheme(name: &Ustr) -> anyhow::Result<Arc<dyn TickScheme>> {
+    get_tick_scheme(name)
+        .ok_or_else(|| anyhow::anyhow!("Tick scheme '{name}' has not been registered"))
+}
+
+fn fixed_tick_scheme<T: Instrument + ?Sized>(instrument: &T) -> FixedTickScheme {
+    FixedTickScheme::new(
+        instrument.price_increment(),
+        instrument.min_price(),
+        instrument.max_price(),
+    )
+}
+
+pub const EXPIRING_INSTRUMENT_TYPES: [InstrumentClass; 5] = [
     InstrumentClass::Future,
+    InstrumentClass::Forward,
     InstrumentClass::FutureSpread,
     InstrumentClass::Option,
     InstrumentClass::OptionSpread,
@@ -0,0 +1,472 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::hash::{Hash, Hasher};
+
+use nautilus_core::{
+    correctness::{
+        check_equal_u8, check_positive_i64, check_positive_u64, check_predicate_true,
+        check_valid_string, FAILED,
+    },
+    nanos::UnixNanos,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    Instrument,
+};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind},
+    identifiers::{InstrumentId, Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a non-deliverable FX forward (NDF) instrument, cash settled in the settlement
+/// currency against the fixing rate published by the fixing source on the fixing date.
+///
+/// Prices are outright forward rates, being the spot rate plus the forward points. The
+/// settlement currency must be either the base or the quote currency.
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct Ndf {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub base_currency: Currency,
+    pub quote_currency: Currency,
+    pub settlement_currency: Currency,
+    /// UNIX timestamp (nanoseconds) for the fixing date.
+    pub fixing_date: UnixNanos,
+    /// UNIX timestamp (nanoseconds) for the value (settlement) date.
+    pub value_date: UnixNanos,
+    /// The forward points added to the spot rate for the outright forward rate.
+    pub forward_points: Decimal,
+    /// The fixing source for the settlement rate.
+    pub fixing_source: Ustr,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub margin_init: Decimal,
+    pub margin_maint: Decimal,
+    pub lot_size: Option<Quantity>,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_notional: Option<Money>,
+    pub min_notional: Option<Money>,
+    pub max_price: Option<Price>,
+    pub min_price: Option<Price>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
+}
+
+impl Ndf {
+    /// Creates a new [`Ndf`] instance with correctness checking.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        fixing_date: UnixNanos,
+        value_date: UnixNanos,
+        forward_points: Decimal,
+        fixing_source: Ustr,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        taker_fee: Decimal,
+        maker_fee: Decimal,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_u64(size_increment.raw, stringify!(size_increment.raw))?;
+        check_predicate_true(
+            settlement_currency == base_currency || settlement_currency == quote_currency,
+            "`settlement_currency` was not the base or quote currency",
+        )?;
+        check_predicate_true(
+            fixing_date <= value_date,
+            "`fixing_date` was after the `value_date`",
+        )?;
+        check_valid_string(fixing_source, stringify!(fixing_source))?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            fixing_date,
+            value_date,
+            forward_points,
+            fixing_source,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            maker_fee,
+            taker_fee,
+            margin_init,
+            margin_maint,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+            tick_scheme: None,
+            info: None,
+        })
+    }
+
+    /// Creates a new [`Ndf`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        fixing_date: UnixNanos,
+        value_date: UnixNanos,
+        forward_points: Decimal,
+        fixing_source: Ustr,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        taker_fee: Decimal,
+        maker_fee: Decimal,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            fixing_date,
+            value_date,
+            forward_points,
+            fixing_source,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            taker_fee,
+            maker_fee,
+            margin_init,
+            margin_maint,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns the outright forward rate for the given `spot` rate.
+    #[must_use]
+    pub fn outright_price(&self, spot: Price) -> Price {
+        Price::from_decimal(
+            spot.as_decimal() + self.forward_points,
+            self.price_precision,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns the cash settlement amount in the settlement currency, for the signed `quantity`
+    /// (positive for long) traded at the `contract_rate` and fixed at the `fixing_rate`.
+    #[must_use]
+    pub fn settlement_amount(&self, quantity: f64, contract_rate: f64, fixing_rate: f64) -> Money {
+        let amount = quantity * (fixing_rate - contract_rate);
+        if self.settlement_currency == self.quote_currency {
+            Money::new(amount, self.quote_currency)
+        } else {
+            Money::new(amount / fixing_rate, self.settlement_currency)
+        }
+    }
+}
+
+impl PartialEq<Self> for Ndf {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Ndf {}
+
+impl Hash for Ndf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for Ndf {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::Ndf(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::FX
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Forward
+    }
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        Some(self.base_currency)
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.settlement_currency
+    }
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn figi(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.fixing_date)
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        self.max_notional
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        self.min_notional
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        enums::InstrumentClass,
+        instruments::{ndf::Ndf, stubs::*, Instrument},
+        types::{currency::Currency, money::Money, price::Price},
+    };
+
+    #[rstest]
+    fn test_equality(ndf_usdinr_1m: Ndf) {
+        let cloned = ndf_usdinr_1m;
+        assert_eq!(ndf_usdinr_1m, cloned);
+    }
+
+    #[rstest]
+    fn test_fields(ndf_usdinr_1m: Ndf) {
+        assert_eq!(ndf_usdinr_1m.instrument_class(), InstrumentClass::Forward);
+        assert_eq!(ndf_usdinr_1m.settlement_currency(), Currency::USD());
+        assert_eq!(
+            ndf_usdinr_1m.expiration_ns(),
+            Some(ndf_usdinr_1m.fixing_date)
+        );
+        assert_eq!(
+            ndf_usdinr_1m.outright_price(Price::from("83.2500")),
+            Price::from("83.3700")
+        );
+    }
+
+    #[rstest]
+    fn test_settlement_amount_in_base_currency(ndf_usdinr_1m: Ndf) {
+        // Long 1,000,000 USD at 83.3700 fixed at 84.0000 gains 630,000 INR, paid in USD
+        let amount = ndf_usdinr_1m.settlement_amount(1_000_000.0, 83.37, 84.0);
+        assert_eq!(amount, Money::from("7500.00 USD"));
+
+        let amount = ndf_usdinr_1m.settlement_amount(-1_000_000.0, 83.37, 84.0);
+        assert_eq!(amount, Money::from("-7500.00 USD"));
+    }
+}
@@ -25,7 +25,12 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::{any::InstrumentAny, Instrument};
+use super::{
+    any::InstrumentAny,
+    info::{deserialize_info, InstrumentInfo},
+    security_id::{check_figi_optional, check_isin_optional},
+    Instrument,
+};
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
@@ -46,6 +51,12 @@ pub struct OptionsContract {
     pub asset_class: AssetClass,
     /// The exchange ISO 10383 Market Identifier Code (MIC) where the instrument trades.
     pub exchange: Option<Ustr>,
+    /// The ISIN (International Securities Identification Number).
+    #[serde(default)]
+    pub isin: Option<Ustr>,
+    /// The FIGI (Financial Instrument Global Identifier).
+    #[serde(default)]
+    pub figi: Option<Ustr>,
     pub underlying: Ustr,
     pub option_kind: OptionKind,
     pub strike_price: Price,
@@ -66,6 +77,14 @@ pub struct OptionsContract {
     pub min_price: Option<Price>,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    pub tick_scheme: Option<Ustr>,
+    /// Additional key-value facts about the instrument (if any).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_info",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub info: Option<InstrumentInfo>,
 }
 
 impl OptionsContract {
@@ -80,6 +99,8 @@ impl OptionsContract {
         raw_symbol: Symbol,
         asset_class: AssetClass,
         exchange: Option<Ustr>,
+        isin: Option<Ustr>,
+        figi: Option<Ustr>,
         underlying: Ustr,
         option_kind: OptionKind,
         strike_price: Price,
@@ -99,7 +120,9 @@ impl OptionsContract {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
-        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(isin))?;
+        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(exchange))?;
+        check_isin_optional(isin, stringify!(isin))?;
+        check_figi_optional(figi, stringify!(figi))?;
         check_valid_string(underlying.as_str(), stringify!(underlying))?;
         check_equal_u8(
             price_precision,
@@ -114,6 +137,8 @@ impl OptionsContract {
             raw_symbol,
             asset_class,
             exchange,
+            isin,
+            figi,
             underlying,
             option_kind,
             activation_ns,
@@ -134,6 +159,8 @@ impl OptionsContract {
             margin_maint: margin_maint.unwrap_or(0.into()),
             ts_event,
             ts_init,
+            tick_scheme: None,
+            info: None,
         })
     }
 
@@ -144,6 +171,8 @@ impl OptionsContract {
         raw_symbol: Symbol,
         asset_class: AssetClass,
         exchange: Option<Ustr>,
+        isin: Option<Ustr>,
+        figi: Option<Ustr>,
         underlying: Ustr,
         option_kind: OptionKind,
         strike_price: Price,
@@ -168,6 +197,8 @@ impl OptionsContract {
             raw_symbol,
             asset_class,
             exchange,
+            isin,
+            figi,
             underlying,
             option_kind,
             strike_price,
@@ -242,7 +273,11 @@ impl Instrument for OptionsContract {
     }
 
     fn isin(&self) -> Option<Ustr> {
-        None
+        self.isin
+    }
+
+    fn figi(&self) -> Option<Ustr> {
+        self.figi
     }
 
     fn option_kind(&self) -> Option<OptionKind> {
@@ -324,6 +359,14 @@ impl Instrument for OptionsContract {
     fn ts_init(&self) -> UnixNanos {
         self.ts_init
     }
+
+    fn tick_scheme(&self) -> Option<Ustr> {
+        self.tick_scheme
+    }
+
+    fn info(&self) -> Option<InstrumentInfo> {
+        self.info
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Validation of standard security identifiers (ISIN and FIGI) held on instruments.
+
+use ustr::Ustr;
+
+const ID_LEN: usize = 12;
+
+/// The FIGI prefixes which are not assigned, as they collide with ISIN country codes.
+const FIGI_INVALID_PREFIXES: [&str; 7] = ["BS", "BM", "GG", "GB", "GH", "KY", "VG"];
+
+/// Checks the `value` is a valid ISO 6166 ISIN (International Securities Identification Number),
+/// including its check digit.
+///
+/// # Errors
+///
+/// This function returns an error if the validation check fails.
+pub fn check_isin(value: &str, param: &str) -> anyhow::Result<()> {
+    let bytes = value.as_bytes();
+    if bytes.len() != ID_LEN {
+        anyhow::bail!("invalid ISIN for '{param}', was '{value}' (length not {ID_LEN})");
+    }
+    if !bytes[..2].iter().all(u8::is_ascii_uppercase)
+        || !bytes[2..11]
+            .iter()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        || !bytes[11].is_ascii_digit()
+    {
+        anyhow::bail!("invalid ISIN for '{param}', was '{value}' (invalid format)");
+    }
+
+    // Letters expand to two digits (A=10..Z=35) before the Luhn check over all digits
+    let digits: Vec<u32> = bytes
+        .iter()
+        .flat_map(|b| {
+            let value = char_value(*b);
+            if value < 10 {
+                vec![value]
+            } else {
+                vec![value / 10, value % 10]
+            }
+        })
+        .collect();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 1 { sum_digits(d * 2) } else { *d })
+        .sum();
+
+    if sum % 10 != 0 {
+        anyhow::bail!("invalid ISIN for '{param}', was '{value}' (check digit mismatch)");
+    }
+    Ok(())
+}
+
+/// Checks the `value` is a valid FIGI (Financial Instrument Global Identifier), including its
+/// check digit.
+///
+/// # Errors
+///
+/// This function returns an error if the validation check fails.
+pub fn check_figi(value: &str, param: &str) -> anyhow::Result<()> {
+    let bytes = value.as_bytes();
+    if bytes.len() != ID_LEN {
+        anyhow::bail!("invalid FIGI for '{param}', was '{value}' (length not {ID_LEN})");
+    }
+    let is_consonant = |b: &u8| b.is_ascii_uppercase() && !b"AEIOU".contains(b);
+    if !bytes[..2].iter().all(is_consonant)
+        || FIGI_INVALID_PREFIXES.contains(&&value[..2])
+        || bytes[2] != b'G'
+        || !bytes[3..11]
+            .iter()
+            .all(|b| is_consonant(b) || b.is_ascii_digit())
+        || !bytes[11].is_ascii_digit()
+    {
+        anyhow::bail!("invalid FIGI for '{param}', was '{value}' (invalid format)");
+    }
+
+    // Character values (A=10..Z=35) are doubled at every second position, then digits summed
+    let sum: u32 = bytes[..11]
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let value = char_value(*b);
+            sum_digits(if i % 2 == 1 { value * 2 } else { value })
+        })
+        .sum();
+    let check_digit = (10 - sum % 10) % 10;
+
+    if char_value(bytes[11]) != check_digit {
+        anyhow::bail!("invalid FIGI for '{param}', was '{value}' (check digit mismatch)");
+    }
+    Ok(())
+}
+
+/// Checks the `value` (if any) is a valid ISIN.
+///
+/// # Errors
+///
+/// This function returns an error if the validation check fails.
+pub fn check_isin_optional(value: Option<Ustr>, param: &str) -> anyhow::Result<()> {
+    value.map_or(Ok(()), |value| check_isin(value.as_str(), param))
+}
+
+/// Checks the `value` (if any) is a valid FIGI.
+///
+/// # Errors
+///
+/// This function returns an error if the validation check fails.
+pub fn check_figi_optional(value: Option<Ustr>, param: &str) -> anyhow::Result<()> {
+    value.map_or(Ok(()), |value| check_figi(value.as_str(), param))
+}
+
+fn char_value(b: u8) -> u32 {
+    if b.is_ascii_digit() {
+        u32::from(b - b'0')
+    } else {
+        u32::from(b - b'A') + 10
+    }
+}
+
+fn sum_digits(mut value: u32) -> u32 {
+    let mut sum = 0;
+    while value > 0 {
+        sum += value % 10;
+        value /= 10;
+    }
+    sum
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("US0378331005")] // Apple
+    #[case("US5949181045")] // Microsoft
+    #[case("GB0002634946")] // BAE Systems
+    #[case("AU0000XVGZA3")] // Treasury Corporation of Victoria
+    #[case("DE0007164600")] // SAP
+    fn test_check_isin_valid(#[case] value: &str) {
+        assert!(check_isin(value, "isin").is_ok());
+    }
+
+    #[rstest]
+    #[case("US0378331006")] // Check digit mismatch
+    #[case("US037833100")] // Too short
+    #[case("us0378331005")] // Lowercase country code
+    #[case("1S0378331005")] // Numeric country code
+    #[case("US037833100X")] // Non-numeric check digit
+    #[case("")]
+    fn test_check_isin_invalid(#[case] value: &str) {
+        assert!(check_isin(value, "isin").is_err());
+    }
+
+    #[rstest]
+    #[case("BBG000BLNNH6")] // IBM
+    #[case("BBG000B9XRY4")] // Apple
+    #[case("BBG000BPH459")] // Microsoft
+    #[case("BBG00JX0P539")]
+    fn test_check_figi_valid(#[case] value: &str) {
+        assert!(check_figi(value, "figi").is_ok());
+    }
+
+    #[rstest]
+    #[case("BBG000BLNNH7")] // Check digit mismatch
+    #[case("BBG000BLNNH")] // Too short
+    #[case("BAG000BLNNH6")] // Vowel in prefix
+    #[case("GBG000BLNNH6")] // Reserved prefix
+    #[case("BBX000BLNNH6")] // Third character not 'G'
+    #[case("BBG000BANNH6")] // Vowel in identifier
+    fn test_check_figi_invalid(#[case] value: &str) {
+        assert!(check_figi(value, "figi").is_err());
+    }
+
+    #[rstest]
+    fn test_check_optional() {
+        assert!(check_isin_optional(None, "isin").is_ok());
+        assert!(check_figi_optional(None, "figi").is_ok());
+        assert!(check_isin_optional(Some(Ustr::from("US0378331006")), "isin").is_err());
+        assert!(check_figi_optional(Some(Ustr::from("BBG000BLNNH6")), "figi").is_ok());
+    }
+}
@@ -21,8 +21,9 @@ use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 use super::{
-    betting::BettingInstrument, binary_option::BinaryOption, futures_spread::FuturesSpread,
-    options_spread::OptionsSpread, synthetic::SyntheticInstrument,
+    betting::BettingInstrument, binary_option::BinaryOption, commodity::Commodity,
+    futures_spread::FuturesSpread, fx_forward::FxForward, ndf::Ndf, options_spread::OptionsSpread,
+    synthetic::SyntheticInstrument,
 };
 use crate::{
     enums::{AssetClass, OptionKind},
@@ -290,6 +291,117 @@ pub fn usdjpy_idealpro() -> CurrencyPair {
     default_fx_ccy(Symbol::from("USD/JPY"), Some(Venue::from("IDEALPRO")))
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Commodity
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn commodity_wti_cushing() -> Commodity {
+    let delivery = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    Commodity::new(
+        InstrumentId::from("WTI-CUSHING.SIM"),
+        Symbol::from("WTI-CUSHING"),
+        Currency::USD(),
+        Some(Ustr::from("Cushing, OK")),
+        Some(Ustr::from("Light Sweet")),
+        true,
+        Some(UnixNanos::from(
+            delivery.timestamp_nanos_opt().unwrap() as u64
+        )),
+        2,
+        0,
+        Price::from("0.01"),
+        Quantity::from("1"),
+        Quantity::from("1000"),
+        dec!(0.0001),
+        dec!(0.0001),
+        dec!(0.1),
+        dec!(0.1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        0.into(),
+        0.into(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// FxForward
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn fx_forward_eurusd_3m() -> FxForward {
+    let value_date = Utc.with_ymd_and_hms(2024, 4, 3, 0, 0, 0).unwrap();
+    FxForward::new(
+        InstrumentId::from("EUR/USD-3M.SIM"),
+        Symbol::from("EUR/USD-3M"),
+        Currency::EUR(),
+        Currency::USD(),
+        UnixNanos::from(value_date.timestamp_nanos_opt().unwrap() as u64),
+        dec!(0.00465),
+        Some(Ustr::from("WMR")),
+        5,
+        0,
+        Price::from("0.00001"),
+        Quantity::from("1"),
+        dec!(0.00002),
+        dec!(0.00002),
+        dec!(0.03),
+        dec!(0.03),
+        Some(Quantity::from("1000")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        0.into(),
+        0.into(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Ndf
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn ndf_usdinr_1m() -> Ndf {
+    let fixing_date = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    let value_date = Utc.with_ymd_and_hms(2024, 2, 5, 0, 0, 0).unwrap();
+    Ndf::new(
+        InstrumentId::from("USD/INR-1M.SIM"),
+        Symbol::from("USD/INR-1M"),
+        Currency::USD(),
+        Currency::INR(),
+        Currency::USD(),
+        UnixNanos::from(fixing_date.timestamp_nanos_opt().unwrap() as u64),
+        UnixNanos::from(value_date.timestamp_nanos_opt().unwrap() as u64),
+        dec!(0.12),
+        Ustr::from("FBIL"),
+        4,
+        0,
+        Price::from("0.0001"),
+        Quantity::from("1"),
+        dec!(0.00002),
+        dec!(0.00002),
+        dec!(0.03),
+        dec!(0.03),
+        Some(Quantity::from("1000")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        0.into(),
+        0.into(),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Equity
 ////////////////////////////////////////////////////////////////////////////////
@@ -300,6 +412,7 @@ pub fn equity_aapl() -> Equity {
         InstrumentId::from("AAPL.XNAS"),
         Symbol::from("AAPL"),
         Some(Ustr::from("US0378331005")),
+        Some(Ustr::from("BBG000B9XRY4")),
         Currency::from("USD"),
         2,
         Price::from("0.01"),
@@ -342,6 +455,8 @@ pub fn futures_contract_es(
         Symbol::from("ESZ21"),
         AssetClass::Index,
         Some(Ustr::from("XCME")),
+        None,
+        None,
         Ustr::from("ES"),
         activation,
         expiration,
@@ -407,6 +522,8 @@ pub fn options_contract_appl() -> OptionsContract {
         Symbol::from("AAPL211217C00150000"),
         AssetClass::Equity,
         Some(Ustr::from("GMNI")), // Nasdaq GEMX
+        None,
+        None,
         Ustr::from("AAPL"),
         OptionKind::Call,
         Price::from("149.0"),
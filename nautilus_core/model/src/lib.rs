@@ -27,6 +27,10 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 //! - `stubs`: Enables type stubs for use in testing scenarios.
+//! - `wasm`: Enables builds for the `wasm32-unknown-unknown` target (not to be combined with `ffi` or `python`).
+
+#[cfg(all(feature = "wasm", any(feature = "ffi", feature = "python")))]
+compile_error!("The `wasm` feature cannot be combined with the `ffi` or `python` features");
 
 pub mod accounts;
 pub mod currencies;
@@ -36,9 +40,11 @@ pub mod events;
 pub mod identifiers;
 pub mod instruments;
 pub mod macros;
+pub mod mic;
 pub mod orderbook;
 pub mod orders;
 pub mod position;
+pub mod tick_scheme;
 pub mod types;
 pub mod venues;
 
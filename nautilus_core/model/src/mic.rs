@@ -0,0 +1,345 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! ISO 10383 Market Identifier Codes (MICs) and a registry of their operating/segment relationships.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    sync::Mutex,
+};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{identifiers::Venue, instruments::any::InstrumentAny, venues::VENUE_MAP};
+
+/// The known MICs as `(mic, operating_mic, name)`, where an operating MIC is its own operating MIC.
+const KNOWN_MICS: [(&str, &str, &str); 14] = [
+    ("XCME", "XCME", "CHICAGO MERCANTILE EXCHANGE"),
+    ("GLBX", "XCME", "CME GLOBEX"),
+    ("XCBT", "XCME", "CHICAGO BOARD OF TRADE"),
+    ("XCEC", "XCME", "COMMODITIES EXCHANGE CENTER"),
+    ("XFXS", "XCME", "CME FX LINK"),
+    ("XNYM", "XCME", "NEW YORK MERCANTILE EXCHANGE"),
+    ("XNAS", "XNAS", "NASDAQ - ALL MARKETS"),
+    ("XNCM", "XNAS", "NASDAQ CAPITAL MARKET"),
+    ("XNGS", "XNAS", "NASDAQ/NGS (GLOBAL SELECT MARKET)"),
+    ("XNMS", "XNAS", "NASDAQ/NMS (GLOBAL MARKET)"),
+    ("XNYS", "XNYS", "NEW YORK STOCK EXCHANGE, INC."),
+    ("XEUR", "XEUR", "EUREX DEUTSCHLAND"),
+    ("XLON", "XLON", "LONDON STOCK EXCHANGE"),
+    ("XASX", "XASX", "ASX - ALL MARKETS"),
+];
+
+/// Checks the `value` is a valid ISO 10383 MIC (four uppercase alphanumeric characters).
+///
+/// # Errors
+///
+/// This function returns an error if the validation check fails.
+pub fn check_mic(value: &str, param: &str) -> anyhow::Result<()> {
+    if value.len() != 4
+        || !value
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        anyhow::bail!(
+            "invalid MIC for '{param}', was '{value}' (not four uppercase alphanumeric characters)"
+        );
+    }
+    Ok(())
+}
+
+/// Represents an ISO 10383 Market Identifier Code (MIC) and its operating MIC.
+///
+/// An operating MIC identifies the entity operating an exchange, and market segment MICs identify
+/// the sections of that exchange (e.g. GLBX is a segment of XCME). An operating MIC is its own
+/// operating MIC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MarketIdentifierCode {
+    pub mic: Ustr,
+    pub operating_mic: Ustr,
+    pub name: Ustr,
+}
+
+impl MarketIdentifierCode {
+    /// Creates a new [`MarketIdentifierCode`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `mic` or `operating_mic` are not valid MICs.
+    pub fn new_checked(mic: &str, operating_mic: &str, name: &str) -> anyhow::Result<Self> {
+        check_mic(mic, stringify!(mic))?;
+        check_mic(operating_mic, stringify!(operating_mic))?;
+        Ok(Self {
+            mic: Ustr::from(mic),
+            operating_mic: Ustr::from(operating_mic),
+            name: Ustr::from(name),
+        })
+    }
+
+    /// Returns whether the MIC is an operating MIC.
+    #[must_use]
+    pub fn is_operating(&self) -> bool {
+        self.mic == self.operating_mic
+    }
+
+    /// Returns whether the MIC is a market segment MIC.
+    #[must_use]
+    pub fn is_segment(&self) -> bool {
+        !self.is_operating()
+    }
+}
+
+impl Display for MarketIdentifierCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mic)
+    }
+}
+
+/// A registry of known MICs and their operating/segment relationships.
+#[derive(Clone, Debug)]
+pub struct MicRegistry {
+    mics: HashMap<Ustr, MarketIdentifierCode>,
+}
+
+impl Default for MicRegistry {
+    /// Creates a new default [`MicRegistry`] instance containing the known MICs.
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for (mic, operating_mic, name) in KNOWN_MICS {
+            registry
+                .register(MarketIdentifierCode::new_checked(mic, operating_mic, name).unwrap())
+                .unwrap();
+        }
+        registry
+    }
+}
+
+impl MicRegistry {
+    /// Creates a new empty [`MicRegistry`] instance.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            mics: HashMap::new(),
+        }
+    }
+
+    /// Registers the given `mic`, replacing any existing registration of the same MIC.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `mic` is a segment of an operating MIC which is not
+    /// registered.
+    pub fn register(&mut self, mic: MarketIdentifierCode) -> anyhow::Result<()> {
+        if mic.is_segment()
+            && !self
+                .mics
+                .get(&mic.operating_mic)
+                .is_some_and(MarketIdentifierCode::is_operating)
+        {
+            anyhow::bail!(
+                "Operating MIC {} for segment {} is not registered",
+                mic.operating_mic,
+                mic.mic
+            );
+        }
+        self.mics.insert(mic.mic, mic);
+        Ok(())
+    }
+
+    /// Returns the registered MIC for the given `mic` (if found).
+    #[must_use]
+    pub fn get(&self, mic: &str) -> Option<&MarketIdentifierCode> {
+        self.mics.get(&Ustr::from(mic))
+    }
+
+    /// Returns whether the given `mic` is registered.
+    #[must_use]
+    pub fn contains(&self, mic: &str) -> bool {
+        self.get(mic).is_some()
+    }
+
+    /// Returns the operating MIC for the given `mic` (if registered).
+    #[must_use]
+    pub fn operating_mic(&self, mic: &str) -> Option<Ustr> {
+        self.get(mic).map(|mic| mic.operating_mic)
+    }
+
+    /// Returns the market segment MICs of the given `operating_mic`, in sorted order.
+    #[must_use]
+    pub fn segments(&self, operating_mic: &str) -> Vec<Ustr> {
+        let operating_mic = Ustr::from(operating_mic);
+        let mut segments: Vec<Ustr> = self
+            .mics
+            .values()
+            .filter(|mic| mic.is_segment() && mic.operating_mic == operating_mic)
+            .map(|mic| mic.mic)
+            .collect();
+        segments.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        segments
+    }
+
+    /// Checks the `value` is a valid and registered MIC.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the validation check fails.
+    pub fn check_known(&self, value: &str, param: &str) -> anyhow::Result<()> {
+        check_mic(value, param)?;
+        if !self.contains(value) {
+            anyhow::bail!("unknown MIC for '{param}', was '{value}'");
+        }
+        Ok(())
+    }
+
+    /// Checks the `exchange` of the given `instrument` (if any) is a valid and registered MIC.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the validation check fails.
+    pub fn check_exchange(&self, instrument: &InstrumentAny) -> anyhow::Result<()> {
+        match instrument.exchange() {
+            Some(exchange) => self.check_known(exchange.as_str(), stringify!(exchange)),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the operating MIC of the given `instrument` (if known), from its `exchange` or
+    /// otherwise its venue.
+    #[must_use]
+    pub fn instrument_operating_mic(&self, instrument: &InstrumentAny) -> Option<Ustr> {
+        instrument
+            .exchange()
+            .and_then(|exchange| self.operating_mic(exchange.as_str()))
+            .or_else(|| self.operating_mic(instrument.id().venue.as_str()))
+    }
+}
+
+/// The global registry of MICs, which adapters and users may extend with [`register_mic`].
+pub static MIC_REGISTRY: Lazy<Mutex<MicRegistry>> =
+    Lazy::new(|| Mutex::new(MicRegistry::default()));
+
+/// Registers the given `mic` with the global [`MIC_REGISTRY`], and its code as a [`Venue`] code
+/// (so it can be resolved with [`Venue::from_code`]).
+///
+/// # Errors
+///
+/// This function returns an error if the `mic` cannot be registered.
+pub fn register_mic(mic: MarketIdentifierCode) -> anyhow::Result<()> {
+    MIC_REGISTRY
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Error acquiring lock on `MIC_REGISTRY`: {e}"))?
+        .register(mic)?;
+    VENUE_MAP
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Error acquiring lock on `VENUE_MAP`: {e}"))?
+        .insert(mic.mic.as_str(), Venue::from_ustr_unchecked(mic.mic));
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::{crypto_perpetual::CryptoPerpetual, equity::Equity, stubs::*};
+
+    #[rstest]
+    #[case("XCME", true)]
+    #[case("GLBX", true)]
+    #[case("XN1S", true)]
+    #[case("xcme", false)]
+    #[case("XCM", false)]
+    #[case("BINANCE", false)]
+    #[case("XC-E", false)]
+    fn test_check_mic(#[case] value: &str, #[case] expected: bool) {
+        assert_eq!(check_mic(value, "mic").is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_default_registry_relationships() {
+        let registry = MicRegistry::default();
+
+        assert!(registry.get("XCME").unwrap().is_operating());
+        assert!(registry.get("GLBX").unwrap().is_segment());
+        assert_eq!(registry.operating_mic("XNYM"), Some(Ustr::from("XCME")));
+        assert_eq!(registry.operating_mic("ZZZZ"), None);
+        assert_eq!(
+            registry.segments("XCME"),
+            vec![
+                Ustr::from("GLBX"),
+                Ustr::from("XCBT"),
+                Ustr::from("XCEC"),
+                Ustr::from("XFXS"),
+                Ustr::from("XNYM"),
+            ]
+        );
+        assert!(registry.segments("XNYS").is_empty());
+    }
+
+    #[rstest]
+    fn test_register_segment_requires_operating_mic() {
+        let mut registry = MicRegistry::empty();
+        let segment = MarketIdentifierCode::new_checked("SEGM", "OPRT", "SEGMENT").unwrap();
+
+        assert!(registry.register(segment).is_err());
+
+        registry
+            .register(MarketIdentifierCode::new_checked("OPRT", "OPRT", "OPERATOR").unwrap())
+            .unwrap();
+        registry.register(segment).unwrap();
+        assert_eq!(registry.segments("OPRT"), vec![Ustr::from("SEGM")]);
+    }
+
+    #[rstest]
+    fn test_check_exchange(equity_aapl: Equity, xbtusd_bitmex: CryptoPerpetual) {
+        let registry = MicRegistry::default();
+        let es = futures_contract_es(None, None);
+
+        assert!(registry
+            .check_exchange(&InstrumentAny::FuturesContract(es))
+            .is_ok());
+        assert!(registry
+            .check_exchange(&InstrumentAny::CryptoPerpetual(xbtusd_bitmex))
+            .is_ok());
+
+        let mut unknown = es;
+        unknown.exchange = Some(Ustr::from("ZZZZ"));
+        assert!(registry
+            .check_exchange(&InstrumentAny::FuturesContract(unknown))
+            .is_err());
+
+        // Equities without an exchange resolve their operating MIC from the venue
+        assert_eq!(
+            registry.instrument_operating_mic(&InstrumentAny::Equity(equity_aapl)),
+            Some(Ustr::from("XNAS"))
+        );
+    }
+
+    #[rstest]
+    fn test_register_mic_extends_venue_codes() {
+        let mic = MarketIdentifierCode::new_checked("TSTX", "TSTX", "TEST EXCHANGE").unwrap();
+
+        register_mic(mic).unwrap();
+
+        assert!(MIC_REGISTRY.lock().unwrap().contains("TSTX"));
+        assert_eq!(Venue::from_code("TSTX").unwrap(), Venue::from("TSTX"));
+    }
+}
@@ -22,10 +22,14 @@ use nautilus_core::nanos::UnixNanos;
 use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::Level};
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
-        quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth, OrderBookDepth10, DEPTH10_LEN},
+        order::{BookOrder, NULL_ORDER},
+        quote::QuoteTick,
+        trade::TradeTick,
     },
-    enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
+    enums::{BookAction, BookStorage, BookType, OrderSide, OrderSideSpecified, RecordFlag},
     identifiers::InstrumentId,
     orderbook::{error::InvalidBookOperation, ladder::Ladder},
     types::{price::Price, quantity::Quantity},
@@ -81,17 +85,33 @@ impl OrderBook {
     /// Creates a new [`OrderBook`] instance.
     #[must_use]
     pub fn new(instrument_id: InstrumentId, book_type: BookType) -> Self {
+        Self::with_storage(instrument_id, book_type, BookStorage::default())
+    }
+
+    /// Creates a new [`OrderBook`] instance with the given price level `storage` strategy.
+    #[must_use]
+    pub fn with_storage(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        storage: BookStorage,
+    ) -> Self {
         Self {
             instrument_id,
             book_type,
             sequence: 0,
             ts_last: UnixNanos::default(),
             count: 0,
-            bids: Ladder::new(OrderSide::Buy),
-            asks: Ladder::new(OrderSide::Sell),
+            bids: Ladder::with_storage(OrderSide::Buy, storage),
+            asks: Ladder::with_storage(OrderSide::Sell, storage),
         }
     }
 
+    /// Returns the price level storage strategy for the order book.
+    #[must_use]
+    pub fn storage(&self) -> BookStorage {
+        self.bids.storage()
+    }
+
     pub fn reset(&mut self) {
         self.bids.clear();
         self.asks.clear();
@@ -165,7 +185,10 @@ impl OrderBook {
         }
     }
 
-    pub fn apply_depth(&mut self, depth: &OrderBookDepth10) {
+    /// Replaces the state of the book with the levels of the given `depth` snapshot.
+    ///
+    /// An [`OrderBookDepth10`] is applied by converting it into an `OrderBookDepth<10>`.
+    pub fn apply_depth<const N: usize>(&mut self, depth: &OrderBookDepth<N>) {
         self.bids.clear();
         self.asks.clear();
 
@@ -236,6 +259,56 @@ impl OrderBook {
         }
     }
 
+    /// Returns the price and size of up to `depth` bid levels (all levels if `None`), best first.
+    #[must_use]
+    pub fn bids_depth(&self, depth: Option<usize>) -> Vec<(f64, f64)> {
+        levels_depth(self.bids(), depth)
+    }
+
+    /// Returns the price and size of up to `depth` ask levels (all levels if `None`), best first.
+    #[must_use]
+    pub fn asks_depth(&self, depth: Option<usize>) -> Vec<(f64, f64)> {
+        levels_depth(self.asks(), depth)
+    }
+
+    /// Returns a snapshot of the top 10 levels per side of the book as an [`OrderBookDepth10`],
+    /// timestamped with the last event applied to the book and the given `ts_init`.
+    ///
+    /// Each level is aggregated to a single order, with the count of orders at the level, and
+    /// missing levels are filled with null orders.
+    #[must_use]
+    pub fn to_depth10(&self, ts_init: UnixNanos) -> OrderBookDepth10 {
+        let (bids, bid_counts) = depth10_levels(self.bids(), OrderSide::Buy);
+        let (asks, ask_counts) = depth10_levels(self.asks(), OrderSide::Sell);
+        OrderBookDepth10::new(
+            self.instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8,
+            self.sequence,
+            self.ts_last,
+            ts_init,
+        )
+    }
+
+    /// Returns the imbalance between the bid and ask size over the top `depth` levels.
+    ///
+    /// The imbalance is `(bid_size - ask_size) / (bid_size + ask_size)`, ranging from -1.0 when
+    /// all size is on the ask side to 1.0 when all size is on the bid side.
+    #[must_use]
+    pub fn imbalance(&self, depth: usize) -> Option<f64> {
+        let bid_size: f64 = self.bids().take(depth).map(Level::size).sum();
+        let ask_size: f64 = self.asks().take(depth).map(Level::size).sum();
+        let total_size = bid_size + ask_size;
+        if total_size > 0.0 {
+            Some((bid_size - ask_size) / total_size)
+        } else {
+            None
+        }
+    }
+
     #[must_use]
     pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         let levels = match order_side.as_specified() {
@@ -359,23 +432,91 @@ impl OrderBook {
     }
 }
 
+fn depth10_levels<'a>(
+    levels: impl Iterator<Item = &'a Level>,
+    side: OrderSide,
+) -> ([BookOrder; DEPTH10_LEN], [u32; DEPTH10_LEN]) {
+    let mut orders = [NULL_ORDER; DEPTH10_LEN];
+    let mut counts = [0; DEPTH10_LEN];
+    for (i, level) in levels
+        .filter(|level| !level.is_empty())
+        .take(DEPTH10_LEN)
+        .enumerate()
+    {
+        let price = level.price.value;
+        let size_precision = level.first().map_or(0, |order| order.size.precision);
+        orders[i] = BookOrder::new(
+            side,
+            price,
+            Quantity::from_raw(level.size_raw(), size_precision),
+            price.raw as u64,
+        );
+        counts[i] = level.len() as u32;
+    }
+    (orders, counts)
+}
+
+fn levels_depth<'a>(
+    levels: impl Iterator<Item = &'a Level>,
+    depth: Option<usize>,
+) -> Vec<(f64, f64)> {
+    levels
+        .take(depth.unwrap_or(usize::MAX))
+        .map(|level| (level.price.value.as_f64(), level.size()))
+        .collect()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::nanos::UnixNanos;
     use rstest::rstest;
 
     use crate::{
         data::{
-            depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick,
+            depth::{OrderBookDepth, OrderBookDepth10},
+            order::BookOrder,
+            quote::QuoteTick,
+            stubs::*,
+            trade::TradeTick,
         },
-        enums::{AggressorSide, BookType, OrderSide},
+        enums::{AggressorSide, BookStorage, BookType, OrderSide},
         identifiers::{InstrumentId, TradeId},
         orderbook::{analysis::book_check_integrity, book::OrderBook},
         types::{price::Price, quantity::Quantity},
     };
 
+    #[rstest]
+    fn test_to_depth10() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        let orders = [
+            BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 1),
+            BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(5), 2),
+            BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from(7), 3),
+            BookOrder::new(OrderSide::Sell, Price::from("101.00"), Quantity::from(3), 4),
+        ];
+        for (i, order) in orders.into_iter().enumerate() {
+            book.add(order, 0, i as u64 + 1, (i as u64 + 1).into());
+        }
+
+        let depth = book.to_depth10(10.into());
+
+        assert_eq!(depth.instrument_id, instrument_id);
+        assert_eq!(depth.bids[0].price, Price::from("100.00"));
+        assert_eq!(depth.bids[0].size, Quantity::from(15));
+        assert_eq!(depth.bids[1].price, Price::from("99.00"));
+        assert_eq!(depth.bid_counts[..3], [2, 1, 0]);
+        assert_eq!(depth.bids[2], BookOrder::default());
+        assert_eq!(depth.asks[0].size, Quantity::from(3));
+        assert_eq!(depth.ask_counts[..2], [1, 0]);
+        assert_eq!(depth.sequence, 4);
+        assert_eq!(depth.ts_event, UnixNanos::from(4));
+        assert_eq!(depth.ts_init, UnixNanos::from(10));
+    }
+
     #[rstest]
     fn test_display() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -492,6 +633,38 @@ mod tests {
         assert_eq!(book.midpoint(), Some(1.5));
     }
 
+    #[rstest]
+    fn test_depth_and_imbalance_with_no_bids_or_asks() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        assert!(book.bids_depth(None).is_empty());
+        assert!(book.asks_depth(None).is_empty());
+        assert_eq!(book.imbalance(5), None);
+    }
+
+    #[rstest]
+    fn test_depth_and_imbalance_with_bids_asks() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        let orders = [
+            (OrderSide::Buy, "1.000", "3.0"),
+            (OrderSide::Buy, "0.900", "1.0"),
+            (OrderSide::Sell, "2.000", "1.0"),
+            (OrderSide::Sell, "2.100", "5.0"),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), i as u64);
+            book.add(order, 0, i as u64, 100.into());
+        }
+
+        assert_eq!(book.bids_depth(None), vec![(1.0, 3.0), (0.9, 1.0)]);
+        assert_eq!(book.asks_depth(Some(1)), vec![(2.0, 1.0)]);
+        assert_eq!(book.imbalance(1), Some(0.5));
+        assert_eq!(book.imbalance(2), Some(-0.2));
+    }
+
     #[rstest]
     fn test_get_price_for_quantity_no_market() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -639,7 +812,7 @@ mod tests {
         let depth = stub_depth10;
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
         let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
-        book.apply_depth(&depth);
+        book.apply_depth(&OrderBookDepth::from(depth));
 
         let qty = Quantity::from(1);
 
@@ -659,7 +832,7 @@ mod tests {
         let instrument_id = InstrumentId::from("AAPL.XNAS");
         let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
 
-        book.apply_depth(&depth);
+        book.apply_depth(&OrderBookDepth::from(depth));
 
         assert_eq!(book.best_bid_price().unwrap().as_f64(), 99.00);
         assert_eq!(book.best_ask_price().unwrap().as_f64(), 100.00);
@@ -667,6 +840,57 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap().as_f64(), 100.0);
     }
 
+    #[rstest]
+    fn test_apply_depth_50(stub_depth50: OrderBookDepth<50>) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        book.apply_depth(&stub_depth50);
+
+        assert_eq!(book.bids().count(), 50);
+        assert_eq!(book.asks().count(), 50);
+        assert_eq!(book.best_bid_price().unwrap().as_f64(), 99.00);
+        assert_eq!(book.best_ask_price().unwrap().as_f64(), 100.00);
+        assert_eq!(book.asks().last().unwrap().price.value.as_f64(), 149.00);
+    }
+
+    #[rstest]
+    fn test_storage_strategies_match(
+        stub_depth50: OrderBookDepth<50>,
+        #[values(BookStorage::BTreeMap, BookStorage::SortedVec)] storage: BookStorage,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut expected = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let mut book = OrderBook::with_storage(instrument_id, BookType::L2_MBP, storage);
+        let update = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("99.50"),
+            Quantity::from("50"),
+            0, // order_id not applicable
+        );
+
+        for book in [&mut expected, &mut book] {
+            book.apply_depth(&stub_depth50);
+            book.update(update, 0, 1, 2.into());
+            book.delete(stub_depth50.asks[0], 0, 2, 3.into());
+        }
+
+        let qty = Quantity::from(1_000);
+        assert_eq!(book.storage(), storage);
+        assert_eq!(book.best_bid_price(), expected.best_bid_price());
+        assert_eq!(book.best_ask_price(), expected.best_ask_price());
+        assert_eq!(
+            book.get_avg_px_for_quantity(qty, OrderSide::Buy),
+            expected.get_avg_px_for_quantity(qty, OrderSide::Buy)
+        );
+        assert_eq!(
+            book.get_quantity_for_price(Price::from("98.00"), OrderSide::Sell),
+            expected.get_quantity_for_price(Price::from("98.00"), OrderSide::Sell)
+        );
+        assert_eq!(book.pprint(5), expected.pprint(5));
+        book_check_integrity(&book).unwrap();
+    }
+
     #[rstest]
     fn test_orderbook_creation() {
         let instrument_id = InstrumentId::from("AAPL.XNAS");
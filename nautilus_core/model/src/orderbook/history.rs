@@ -0,0 +1,293 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An order book wrapper which retains a rolling window of deltas, to reconstruct the state of
+//! the book at any timestamp within the window.
+
+use std::collections::VecDeque;
+
+use nautilus_core::{
+    correctness::check_predicate_true, datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos,
+};
+
+use crate::{
+    data::{delta::OrderBookDelta, deltas::OrderBookDeltas},
+    enums::BookType,
+    identifiers::InstrumentId,
+    orderbook::book::OrderBook,
+};
+
+/// Provides an order book with a replayable history of the most recent deltas.
+///
+/// The history is bounded by a maximum count of deltas and/or a maximum age in milliseconds
+/// (relative to the last applied delta). Deltas evicted from the window are folded into a base
+/// book, from which the state at any timestamp within the window is rebuilt on request.
+#[derive(Clone, Debug)]
+pub struct OrderBookHistory {
+    book: OrderBook,
+    base: OrderBook,
+    deltas: VecDeque<OrderBookDelta>,
+    max_deltas: Option<usize>,
+    max_age_ns: Option<u64>,
+}
+
+impl OrderBookHistory {
+    /// Creates a new [`OrderBookHistory`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - Neither `max_deltas` nor `max_age_ms` is specified.
+    /// - `max_deltas` or `max_age_ms` is zero.
+    pub fn new(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        max_deltas: Option<usize>,
+        max_age_ms: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            max_deltas.is_some() || max_age_ms.is_some(),
+            "at least one of `max_deltas` or `max_age_ms` must be specified",
+        )?;
+        check_predicate_true(max_deltas != Some(0), "`max_deltas` must be positive")?;
+        check_predicate_true(max_age_ms != Some(0), "`max_age_ms` must be positive")?;
+
+        let book = OrderBook::new(instrument_id, book_type);
+        Ok(Self {
+            base: book.clone(),
+            book,
+            deltas: VecDeque::new(),
+            max_deltas,
+            max_age_ns: max_age_ms.map(|ms| ms * NANOSECONDS_IN_MILLISECOND),
+        })
+    }
+
+    /// Returns the current state of the order book.
+    #[must_use]
+    pub const fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    /// Returns the count of deltas retained in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deltas.len()
+    }
+
+    /// If no deltas are retained in the window.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.deltas.is_empty()
+    }
+
+    /// Returns the earliest timestamp for which the book state can be reconstructed, or `None`
+    /// if no deltas have been evicted (in which case the full history is retained).
+    #[must_use]
+    pub fn ts_earliest(&self) -> Option<UnixNanos> {
+        (self.base.count > 0).then_some(self.base.ts_last)
+    }
+
+    /// Applies the given `delta` to the book and retains it in the window, evicting any deltas
+    /// which no longer fit the window.
+    pub fn apply_delta(&mut self, delta: &OrderBookDelta) {
+        self.book.apply_delta(delta);
+        self.deltas.push_back(*delta);
+        self.evict();
+    }
+
+    /// Applies the given `deltas` to the book and retains them in the window.
+    pub fn apply_deltas(&mut self, deltas: &OrderBookDeltas) {
+        for delta in &deltas.deltas {
+            self.apply_delta(delta);
+        }
+    }
+
+    /// Returns the state of the book as at the given `ts` (after all deltas with an event
+    /// timestamp at or before `ts` were applied).
+    ///
+    /// Returns `None` if `ts` is earlier than the window.
+    #[must_use]
+    pub fn book_at(&self, ts: UnixNanos) -> Option<OrderBook> {
+        if self.ts_earliest().is_some_and(|earliest| ts < earliest) {
+            return None;
+        }
+
+        let mut book = self.base.clone();
+        for delta in self.deltas.iter().take_while(|delta| delta.ts_event <= ts) {
+            book.apply_delta(delta);
+        }
+        Some(book)
+    }
+
+    /// Returns the state of the book `ago_ms` milliseconds before the last applied delta.
+    ///
+    /// Returns `None` if the requested time is earlier than the window.
+    #[must_use]
+    pub fn book_ago(&self, ago_ms: u64) -> Option<OrderBook> {
+        let ts_last = self.book.ts_last.as_u64();
+        let ago_ns = ago_ms * NANOSECONDS_IN_MILLISECOND;
+        self.book_at(UnixNanos::from(ts_last.saturating_sub(ago_ns)))
+    }
+
+    /// Clears the book and its history.
+    pub fn reset(&mut self) {
+        self.book.reset();
+        self.base.reset();
+        self.deltas.clear();
+    }
+
+    fn evict(&mut self) {
+        let ts_last = self.book.ts_last;
+        while let Some(oldest) = self.deltas.front() {
+            let exceeds_count = self
+                .max_deltas
+                .is_some_and(|max_deltas| self.deltas.len() > max_deltas);
+            let exceeds_age = self.max_age_ns.is_some_and(|max_age_ns| {
+                ts_last.as_u64().saturating_sub(oldest.ts_event.as_u64()) > max_age_ns
+            });
+            if !exceeds_count && !exceeds_age {
+                break;
+            }
+            let oldest = self.deltas.pop_front().expect("oldest delta exists");
+            self.base.apply_delta(&oldest);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        enums::{BookAction, OrderSide},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn delta(action: BookAction, side: OrderSide, price: &str, ts_ms: u64) -> OrderBookDelta {
+        let ts = UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND);
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(100), 0),
+            0,
+            ts_ms,
+            ts,
+            ts,
+        )
+    }
+
+    fn history(max_deltas: Option<usize>, max_age_ms: Option<u64>) -> OrderBookHistory {
+        let mut history = OrderBookHistory::new(
+            InstrumentId::from("AAPL.XNAS"),
+            BookType::L2_MBP,
+            max_deltas,
+            max_age_ms,
+        )
+        .unwrap();
+        for delta in [
+            delta(BookAction::Add, OrderSide::Buy, "100.00", 100),
+            delta(BookAction::Add, OrderSide::Sell, "101.00", 200),
+            delta(BookAction::Delete, OrderSide::Buy, "100.00", 300),
+            delta(BookAction::Add, OrderSide::Buy, "99.00", 400),
+        ] {
+            history.apply_delta(&delta);
+        }
+        history
+    }
+
+    #[rstest]
+    #[case(None, None)]
+    #[case(Some(0), None)]
+    #[case(None, Some(0))]
+    fn test_new_with_invalid_window(
+        #[case] max_deltas: Option<usize>,
+        #[case] max_age_ms: Option<u64>,
+    ) {
+        let result = OrderBookHistory::new(
+            InstrumentId::from("AAPL.XNAS"),
+            BookType::L2_MBP,
+            max_deltas,
+            max_age_ms,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(50, None, None)]
+    #[case(100, Some("100.00"), None)]
+    #[case(250, Some("100.00"), Some("101.00"))]
+    #[case(300, None, Some("101.00"))]
+    #[case(400, Some("99.00"), Some("101.00"))]
+    fn test_book_at_reconstructs_state(
+        #[case] ts_ms: u64,
+        #[case] expected_bid: Option<&str>,
+        #[case] expected_ask: Option<&str>,
+    ) {
+        let history = history(Some(10), None);
+
+        let book = history
+            .book_at(UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND))
+            .unwrap();
+
+        assert_eq!(book.best_bid_price(), expected_bid.map(Price::from));
+        assert_eq!(book.best_ask_price(), expected_ask.map(Price::from));
+    }
+
+    #[rstest]
+    fn test_book_ago_relative_to_last_delta() {
+        let history = history(Some(10), None);
+
+        let book = history.book_ago(200).unwrap();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("101.00")));
+        assert_eq!(history.book().best_bid_price(), Some(Price::from("99.00")));
+    }
+
+    #[rstest]
+    fn test_evicts_by_max_deltas() {
+        let history = history(Some(2), None);
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history.ts_earliest(),
+            Some(UnixNanos::from(200 * NANOSECONDS_IN_MILLISECOND))
+        );
+        assert!(history
+            .book_at(UnixNanos::from(100 * NANOSECONDS_IN_MILLISECOND))
+            .is_none());
+        let book = history
+            .book_at(UnixNanos::from(200 * NANOSECONDS_IN_MILLISECOND))
+            .unwrap();
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.00")));
+    }
+
+    #[rstest]
+    fn test_evicts_by_max_age() {
+        let history = history(None, Some(150));
+
+        assert_eq!(history.len(), 2);
+        assert!(history.book_ago(250).is_none());
+        let book = history.book_ago(50).unwrap();
+        assert_eq!(book.best_bid_price(), None);
+        assert_eq!(book.best_ask_price(), Some(Price::from("101.00")));
+    }
+}
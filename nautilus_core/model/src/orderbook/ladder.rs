@@ -17,7 +17,7 @@
 
 use std::{
     cmp::Ordering,
-    collections::{BTreeMap, HashMap},
+    collections::HashMap,
     fmt::{Display, Formatter},
 };
 
@@ -25,8 +25,8 @@ use nautilus_core::nanos::UnixNanos;
 
 use crate::{
     data::order::{BookOrder, OrderId},
-    enums::{OrderSide, OrderSideSpecified},
-    orderbook::level::Level,
+    enums::{BookStorage, OrderSide, OrderSideSpecified},
+    orderbook::{level::Level, storage::LevelStorage},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -80,7 +80,7 @@ impl Display for BookPrice {
 #[derive(Clone, Debug)]
 pub struct Ladder {
     pub side: OrderSide,
-    pub levels: BTreeMap<BookPrice, Level>,
+    pub levels: LevelStorage,
     pub cache: HashMap<u64, BookPrice>,
 }
 
@@ -88,13 +88,24 @@ impl Ladder {
     /// Creates a new [`Ladder`] instance.
     #[must_use]
     pub fn new(side: OrderSide) -> Self {
+        Self::with_storage(side, BookStorage::default())
+    }
+
+    /// Creates a new [`Ladder`] instance with the given level `storage` strategy.
+    #[must_use]
+    pub fn with_storage(side: OrderSide, storage: BookStorage) -> Self {
         Self {
             side,
-            levels: BTreeMap::new(),
+            levels: LevelStorage::new(storage),
             cache: HashMap::new(),
         }
     }
 
+    #[must_use]
+    pub fn storage(&self) -> BookStorage {
+        self.levels.storage()
+    }
+
     #[must_use]
     pub fn len(&self) -> usize {
         self.levels.len()
@@ -183,10 +194,7 @@ impl Ladder {
 
     #[must_use]
     pub fn top(&self) -> Option<&Level> {
-        match self.levels.iter().next() {
-            Some((_, l)) => Option::Some(l),
-            None => Option::None,
-        }
+        self.levels.first()
     }
 
     #[must_use]
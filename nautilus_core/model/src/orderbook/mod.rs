@@ -20,5 +20,7 @@ pub mod analysis;
 pub mod book;
 pub mod display;
 pub mod error;
+pub mod history;
 pub mod ladder;
 pub mod level;
+pub mod storage;
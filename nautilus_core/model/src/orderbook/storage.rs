@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Storage for the price levels of one side of an order book.
+
+use std::{
+    collections::{btree_map, BTreeMap},
+    iter::Rev,
+    slice,
+};
+
+use super::{ladder::BookPrice, level::Level};
+use crate::enums::BookStorage;
+
+/// Represents the price levels of one side of an order book, held in the [`BookStorage`]
+/// strategy chosen for the book.
+///
+/// With [`BookStorage::SortedVec`] the levels are held contiguously from the worst price to the
+/// best price, so that the frequent updates near the top of the book shift few elements.
+#[derive(Clone, Debug)]
+pub enum LevelStorage {
+    BTreeMap(BTreeMap<BookPrice, Level>),
+    SortedVec(Vec<Level>),
+}
+
+impl LevelStorage {
+    /// Creates a new empty [`LevelStorage`] instance for the given `storage` strategy.
+    #[must_use]
+    pub fn new(storage: BookStorage) -> Self {
+        match storage {
+            BookStorage::BTreeMap => Self::BTreeMap(BTreeMap::new()),
+            BookStorage::SortedVec => Self::SortedVec(Vec::new()),
+        }
+    }
+
+    /// Returns the storage strategy for the levels.
+    #[must_use]
+    pub fn storage(&self) -> BookStorage {
+        match self {
+            Self::BTreeMap(_) => BookStorage::BTreeMap,
+            Self::SortedVec(_) => BookStorage::SortedVec,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::BTreeMap(levels) => levels.len(),
+            Self::SortedVec(levels) => levels.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        match self {
+            Self::BTreeMap(levels) => levels.clear(),
+            Self::SortedVec(levels) => levels.clear(),
+        }
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, price: &BookPrice) -> bool {
+        match self {
+            Self::BTreeMap(levels) => levels.contains_key(price),
+            Self::SortedVec(levels) => search(levels, price).is_ok(),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, price: &BookPrice) -> Option<&Level> {
+        match self {
+            Self::BTreeMap(levels) => levels.get(price),
+            Self::SortedVec(levels) => search(levels, price).ok().map(|index| &levels[index]),
+        }
+    }
+
+    pub fn get_mut(&mut self, price: &BookPrice) -> Option<&mut Level> {
+        match self {
+            Self::BTreeMap(levels) => levels.get_mut(price),
+            Self::SortedVec(levels) => match search(levels, price) {
+                Ok(index) => Some(&mut levels[index]),
+                Err(_) => None,
+            },
+        }
+    }
+
+    /// Inserts the `level` at the given `price`, returning any level it replaced.
+    pub fn insert(&mut self, price: BookPrice, level: Level) -> Option<Level> {
+        match self {
+            Self::BTreeMap(levels) => levels.insert(price, level),
+            Self::SortedVec(levels) => match search(levels, &price) {
+                Ok(index) => Some(std::mem::replace(&mut levels[index], level)),
+                Err(index) => {
+                    levels.insert(index, level);
+                    None
+                }
+            },
+        }
+    }
+
+    /// Removes and returns the level at the given `price` (if found).
+    pub fn remove(&mut self, price: &BookPrice) -> Option<Level> {
+        match self {
+            Self::BTreeMap(levels) => levels.remove(price),
+            Self::SortedVec(levels) => search(levels, price).ok().map(|index| levels.remove(index)),
+        }
+    }
+
+    /// Returns the best priced level (if any).
+    #[must_use]
+    pub fn first(&self) -> Option<&Level> {
+        match self {
+            Self::BTreeMap(levels) => levels.values().next(),
+            Self::SortedVec(levels) => levels.last(),
+        }
+    }
+
+    /// Returns the best price and its level (if any).
+    #[must_use]
+    pub fn first_key_value(&self) -> Option<(&BookPrice, &Level)> {
+        self.first().map(|level| (&level.price, level))
+    }
+
+    /// Returns an iterator over the prices and levels, from the best price to the worst.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_> {
+        match self {
+            Self::BTreeMap(levels) => Iter::BTreeMap(levels.iter()),
+            Self::SortedVec(levels) => Iter::SortedVec(levels.iter().rev()),
+        }
+    }
+
+    /// Returns an iterator over the levels, from the best price to the worst.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &Level> + ExactSizeIterator {
+        self.iter().map(|(_, level)| level)
+    }
+}
+
+/// Searches the `levels`, held from the worst price to the best, for the given `price`.
+fn search(levels: &[Level], price: &BookPrice) -> Result<usize, usize> {
+    levels.binary_search_by(|level| price.cmp(&level.price))
+}
+
+/// Provides an iterator over the prices and levels of a [`LevelStorage`].
+#[derive(Clone, Debug)]
+pub enum Iter<'a> {
+    BTreeMap(btree_map::Iter<'a, BookPrice, Level>),
+    SortedVec(Rev<slice::Iter<'a, Level>>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a BookPrice, &'a Level);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::BTreeMap(iter) => iter.next(),
+            Self::SortedVec(iter) => iter.next().map(|level| (&level.price, level)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::BTreeMap(iter) => iter.size_hint(),
+            Self::SortedVec(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::BTreeMap(iter) => iter.next_back(),
+            Self::SortedVec(iter) => iter.next_back().map(|level| (&level.price, level)),
+        }
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl<'a> IntoIterator for &'a LevelStorage {
+    type Item = (&'a BookPrice, &'a Level);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        enums::OrderSide,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn level(side: OrderSide, price: &str) -> (BookPrice, Level) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(10), 1);
+        (order.to_book_price(), Level::from_order(order))
+    }
+
+    #[rstest]
+    fn test_sorted_vec_matches_btree_map_ordering(
+        #[values(OrderSide::Buy, OrderSide::Sell)] side: OrderSide,
+    ) {
+        let mut tree = LevelStorage::new(BookStorage::BTreeMap);
+        let mut vec = LevelStorage::new(BookStorage::SortedVec);
+
+        for price in ["10.0", "12.0", "8.0", "11.0", "9.0"] {
+            let (book_price, level) = level(side, price);
+            tree.insert(book_price, level.clone());
+            vec.insert(book_price, level);
+        }
+        let (removed, _) = level(side, "11.0");
+        tree.remove(&removed);
+        vec.remove(&removed);
+
+        let tree_prices: Vec<BookPrice> = tree.iter().map(|(price, _)| *price).collect();
+        let vec_prices: Vec<BookPrice> = vec.iter().map(|(price, _)| *price).collect();
+        assert_eq!(vec.storage(), BookStorage::SortedVec);
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec_prices, tree_prices);
+        assert_eq!(
+            vec.first().unwrap().price.value,
+            tree.first().unwrap().price.value
+        );
+        assert!(!vec.contains_key(&removed));
+    }
+
+    #[rstest]
+    fn test_sorted_vec_get_mut_and_replace() {
+        let mut storage = LevelStorage::new(BookStorage::SortedVec);
+        let (book_price, level1) = level(OrderSide::Buy, "10.0");
+        let (_, level2) = level(OrderSide::Buy, "10.0");
+
+        assert!(storage.insert(book_price, level1).is_none());
+        assert!(storage.insert(book_price, level2).is_some());
+        assert!(storage.get_mut(&book_price).is_some());
+        assert_eq!(storage.len(), 1);
+
+        storage.clear();
+
+        assert!(storage.is_empty());
+        assert!(storage.first_key_value().is_none());
+    }
+}
@@ -33,14 +33,14 @@ use super::{
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
-        PositionSide, TriggerType,
+        PositionSide, TimeInForce, TriggerType,
     },
-    events::order::OrderEventAny,
+    events::order::{OrderEventAny, OrderUpdated},
     identifiers::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId,
         VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -156,6 +156,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn events(&self) -> Vec<&OrderEventAny> {
+        match self {
+            Self::Limit(order) => order.events(),
+            Self::LimitIfTouched(order) => order.events(),
+            Self::Market(order) => order.events(),
+            Self::MarketIfTouched(order) => order.events(),
+            Self::MarketToLimit(order) => order.events(),
+            Self::StopLimit(order) => order.events(),
+            Self::StopMarket(order) => order.events(),
+            Self::TrailingStopLimit(order) => order.events(),
+            Self::TrailingStopMarket(order) => order.events(),
+        }
+    }
+
     #[must_use]
     pub fn trader_id(&self) -> TraderId {
         match self {
@@ -246,6 +261,51 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::LimitIfTouched(order) => order.time_in_force(),
+            Self::Market(order) => order.time_in_force(),
+            Self::MarketIfTouched(order) => order.time_in_force(),
+            Self::MarketToLimit(order) => order.time_in_force(),
+            Self::StopLimit(order) => order.time_in_force(),
+            Self::StopMarket(order) => order.time_in_force(),
+            Self::TrailingStopLimit(order) => order.time_in_force(),
+            Self::TrailingStopMarket(order) => order.time_in_force(),
+        }
+    }
+
+    #[must_use]
+    pub fn expire_time(&self) -> Option<UnixNanos> {
+        match self {
+            Self::Limit(order) => order.expire_time(),
+            Self::LimitIfTouched(order) => order.expire_time(),
+            Self::Market(order) => order.expire_time(),
+            Self::MarketIfTouched(order) => order.expire_time(),
+            Self::MarketToLimit(order) => order.expire_time(),
+            Self::StopLimit(order) => order.expire_time(),
+            Self::StopMarket(order) => order.expire_time(),
+            Self::TrailingStopLimit(order) => order.expire_time(),
+            Self::TrailingStopMarket(order) => order.expire_time(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Limit(order) => order.ts_init(),
+            Self::LimitIfTouched(order) => order.ts_init(),
+            Self::Market(order) => order.ts_init(),
+            Self::MarketIfTouched(order) => order.ts_init(),
+            Self::MarketToLimit(order) => order.ts_init(),
+            Self::StopLimit(order) => order.ts_init(),
+            Self::StopMarket(order) => order.ts_init(),
+            Self::TrailingStopLimit(order) => order.ts_init(),
+            Self::TrailingStopMarket(order) => order.ts_init(),
+        }
+    }
+
     #[must_use]
     pub fn position_id(&self) -> Option<PositionId> {
         match self {
@@ -291,6 +351,37 @@ impl OrderAny {
         }
     }
 
+    /// Returns the metadata for the order.
+    #[must_use]
+    pub fn metadata(&self) -> OrderMetadata {
+        match self {
+            Self::Limit(order) => order.metadata,
+            Self::LimitIfTouched(order) => order.metadata,
+            Self::Market(order) => order.metadata,
+            Self::MarketIfTouched(order) => order.metadata,
+            Self::MarketToLimit(order) => order.metadata,
+            Self::StopLimit(order) => order.metadata,
+            Self::StopMarket(order) => order.metadata,
+            Self::TrailingStopLimit(order) => order.metadata,
+            Self::TrailingStopMarket(order) => order.metadata,
+        }
+    }
+
+    /// Sets the metadata for the order, which is carried onto its fills.
+    pub fn set_metadata(&mut self, metadata: OrderMetadata) {
+        match self {
+            Self::Limit(order) => order.set_metadata(metadata),
+            Self::LimitIfTouched(order) => order.set_metadata(metadata),
+            Self::Market(order) => order.set_metadata(metadata),
+            Self::MarketIfTouched(order) => order.set_metadata(metadata),
+            Self::MarketToLimit(order) => order.set_metadata(metadata),
+            Self::StopLimit(order) => order.set_metadata(metadata),
+            Self::StopMarket(order) => order.set_metadata(metadata),
+            Self::TrailingStopLimit(order) => order.set_metadata(metadata),
+            Self::TrailingStopMarket(order) => order.set_metadata(metadata),
+        }
+    }
+
     #[must_use]
     pub fn order_side(&self) -> OrderSide {
         match self {
@@ -516,6 +607,67 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_post_only(),
+            Self::Market(order) => order.is_post_only(),
+            Self::MarketToLimit(order) => order.is_post_only(),
+            Self::LimitIfTouched(order) => order.is_post_only(),
+            Self::MarketIfTouched(order) => order.is_post_only(),
+            Self::StopLimit(order) => order.is_post_only(),
+            Self::StopMarket(order) => order.is_post_only(),
+            Self::TrailingStopLimit(order) => order.is_post_only(),
+            Self::TrailingStopMarket(order) => order.is_post_only(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_quote_quantity(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_quote_quantity(),
+            Self::Market(order) => order.is_quote_quantity(),
+            Self::MarketToLimit(order) => order.is_quote_quantity(),
+            Self::LimitIfTouched(order) => order.is_quote_quantity(),
+            Self::MarketIfTouched(order) => order.is_quote_quantity(),
+            Self::StopLimit(order) => order.is_quote_quantity(),
+            Self::StopMarket(order) => order.is_quote_quantity(),
+            Self::TrailingStopLimit(order) => order.is_quote_quantity(),
+            Self::TrailingStopMarket(order) => order.is_quote_quantity(),
+        }
+    }
+
+    /// Updates the quantity and prices of the order from the given `event`, without a state
+    /// transition, as when a venue amends an order on receipt.
+    pub fn update(&mut self, event: &OrderUpdated) {
+        match self {
+            Self::Limit(order) => order.update(event),
+            Self::Market(order) => order.update(event),
+            Self::MarketToLimit(order) => order.update(event),
+            Self::LimitIfTouched(order) => order.update(event),
+            Self::MarketIfTouched(order) => order.update(event),
+            Self::StopLimit(order) => order.update(event),
+            Self::StopMarket(order) => order.update(event),
+            Self::TrailingStopLimit(order) => order.update(event),
+            Self::TrailingStopMarket(order) => order.update(event),
+        }
+    }
+
+    /// Sets the `quantity` of the base asset for an order expressed in quote quantity.
+    pub fn set_base_quantity(&mut self, quantity: Quantity) {
+        match self {
+            Self::Limit(order) => order.set_base_quantity(quantity),
+            Self::Market(order) => order.set_base_quantity(quantity),
+            Self::MarketToLimit(order) => order.set_base_quantity(quantity),
+            Self::LimitIfTouched(order) => order.set_base_quantity(quantity),
+            Self::MarketIfTouched(order) => order.set_base_quantity(quantity),
+            Self::StopLimit(order) => order.set_base_quantity(quantity),
+            Self::StopMarket(order) => order.set_base_quantity(quantity),
+            Self::TrailingStopLimit(order) => order.set_base_quantity(quantity),
+            Self::TrailingStopMarket(order) => order.set_base_quantity(quantity),
+        }
+    }
+
     #[must_use]
     pub fn is_reduce_only(&self) -> bool {
         match self {
@@ -749,6 +901,22 @@ impl PassiveOrderAny {
             Self::Stop(order) => order.expire_time(),
         }
     }
+
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::Stop(order) => order.time_in_force(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Limit(order) => order.ts_init(),
+            Self::Stop(order) => order.ts_init(),
+        }
+    }
 }
 
 impl PartialEq for PassiveOrderAny {
@@ -818,6 +986,26 @@ impl LimitOrderAny {
             Self::TrailingStopLimit(order) => order.expire_time,
         }
     }
+
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force,
+            Self::MarketToLimit(order) => order.time_in_force,
+            Self::StopLimit(order) => order.time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Limit(order) => order.ts_init,
+            Self::MarketToLimit(order) => order.ts_init,
+            Self::StopLimit(order) => order.ts_init,
+            Self::TrailingStopLimit(order) => order.ts_init,
+        }
+    }
 }
 
 impl PartialEq for LimitOrderAny {
@@ -901,6 +1089,30 @@ impl StopOrderAny {
             Self::TrailingStopMarket(order) => order.expire_time,
         }
     }
+
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::LimitIfTouched(order) => order.time_in_force,
+            Self::MarketIfTouched(order) => order.time_in_force,
+            Self::StopLimit(order) => order.time_in_force,
+            Self::StopMarket(order) => order.time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force,
+            Self::TrailingStopMarket(order) => order.time_in_force,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::LimitIfTouched(order) => order.ts_init,
+            Self::MarketIfTouched(order) => order.ts_init,
+            Self::StopLimit(order) => order.ts_init,
+            Self::StopMarket(order) => order.ts_init,
+            Self::TrailingStopLimit(order) => order.ts_init,
+            Self::TrailingStopMarket(order) => order.ts_init,
+        }
+    }
 }
 
 impl PartialEq for StopOrderAny {
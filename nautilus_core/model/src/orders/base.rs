@@ -36,7 +36,9 @@ use crate::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
-    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency, metadata::OrderMetadata, money::Money, price::Price, quantity::Quantity,
+    },
 };
 
 const STOP_ORDER_TYPES: &[OrderType] = &[
@@ -199,6 +201,7 @@ pub trait Order: 'static + Send {
     fn exec_algorithm_params(&self) -> Option<&HashMap<Ustr, Ustr>>;
     fn exec_spawn_id(&self) -> Option<ClientOrderId>;
     fn tags(&self) -> Option<&[Ustr]>;
+    fn metadata(&self) -> OrderMetadata;
     fn filled_qty(&self) -> Quantity;
     fn leaves_qty(&self) -> Quantity;
     fn avg_px(&self) -> Option<f64>;
@@ -389,6 +392,7 @@ where
             exec_algorithm_params: order.exec_algorithm_params().map(|x| x.to_owned()),
             exec_spawn_id: order.exec_spawn_id(),
             tags: order.tags().map(|x| x.to_vec()),
+            metadata: order.metadata(),
             event_id: order.init_id(),
             ts_event: order.ts_init(),
             ts_init: order.ts_init(),
@@ -429,6 +433,7 @@ pub struct OrderCore {
     pub exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
     pub exec_spawn_id: Option<ClientOrderId>,
     pub tags: Option<Vec<Ustr>>,
+    pub metadata: OrderMetadata,
     pub filled_qty: Quantity,
     pub leaves_qty: Quantity,
     pub avg_px: Option<f64>,
@@ -475,6 +480,7 @@ impl OrderCore {
             exec_algorithm_params: init.exec_algorithm_params,
             exec_spawn_id: init.exec_spawn_id,
             tags: init.tags,
+            metadata: init.metadata,
             filled_qty: Quantity::zero(init.quantity.precision),
             leaves_qty: init.quantity,
             avg_px: None,
@@ -485,6 +491,33 @@ impl OrderCore {
         }
     }
 
+    /// Sets the metadata for the order, which is carried onto its fills.
+    ///
+    /// The metadata is also recorded on the initialization event, so it is restored when the
+    /// order is rebuilt from its events.
+    pub fn set_metadata(&mut self, metadata: OrderMetadata) {
+        self.metadata = metadata;
+        if let Some(OrderEventAny::Initialized(init)) = self.events.first_mut() {
+            init.metadata = metadata;
+        }
+    }
+
+    /// Sets the `quantity` of the base asset for an order expressed in quote quantity, once
+    /// converted at a price before the order is submitted.
+    ///
+    /// The order is then no longer a quote quantity order, which is also recorded on the
+    /// initialization event.
+    pub fn set_base_quantity(&mut self, quantity: Quantity) {
+        self.quantity = quantity;
+        self.filled_qty = Quantity::zero(quantity.precision);
+        self.leaves_qty = quantity;
+        self.is_quote_quantity = false;
+        if let Some(OrderEventAny::Initialized(init)) = self.events.first_mut() {
+            init.quantity = quantity;
+            init.quote_quantity = false;
+        }
+    }
+
     pub fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
@@ -767,6 +800,27 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_set_base_quantity() {
+        let init = OrderInitializedBuilder::default()
+            .quantity(Quantity::from(500))
+            .quote_quantity(true)
+            .build()
+            .unwrap();
+        let mut order: MarketOrder = init.into();
+
+        order.set_base_quantity(Quantity::from("0.012"));
+
+        assert_eq!(order.quantity, Quantity::from("0.012"));
+        assert_eq!(order.leaves_qty, Quantity::from("0.012"));
+        assert!(!order.is_quote_quantity);
+        let Some(OrderEventAny::Initialized(init)) = order.events.first() else {
+            panic!("Expected initialization event");
+        };
+        assert_eq!(init.quantity, Quantity::from("0.012"));
+        assert!(!init.quote_quantity);
+    }
+
     #[rstest]
     fn test_order_state_transition_denied() {
         let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
@@ -33,7 +33,7 @@ use crate::{
         stop_market::StopMarketOrder, trailing_stop_limit::TrailingStopLimitOrder,
         trailing_stop_market::TrailingStopMarketOrder,
     },
-    types::{currency::Currency, price::Price, quantity::Quantity},
+    types::{currency::Currency, metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 pub struct OrderTestBuilder {
@@ -69,6 +69,7 @@ pub struct OrderTestBuilder {
     exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
     exec_spawn_id: Option<ClientOrderId>,
     tags: Option<Vec<Ustr>>,
+    metadata: Option<OrderMetadata>,
     init_id: Option<UUID4>,
     ts_init: Option<UnixNanos>,
     contingency_type: Option<ContingencyType>,
@@ -113,6 +114,7 @@ impl OrderTestBuilder {
             init_id: None,
             ts_init: None,
             tags: None,
+            metadata: None,
         }
     }
 
@@ -395,6 +397,12 @@ impl OrderTestBuilder {
         self.tags.clone()
     }
 
+    // ----------- Metadata ----------
+    pub fn metadata(&mut self, metadata: OrderMetadata) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     // ----------- InitId ----------
     pub fn init_id(&mut self, init_id: UUID4) -> &mut Self {
         self.init_id = Some(init_id);
@@ -469,7 +477,7 @@ impl OrderTestBuilder {
     }
 
     pub fn build(&self) -> OrderAny {
-        match self.kind {
+        let mut order = match self.kind {
             OrderType::Market => OrderAny::Market(MarketOrder::new(
                 self.get_trader_id(),
                 self.get_strategy_id(),
@@ -722,6 +730,10 @@ impl OrderTestBuilder {
                     self.get_ts_init(),
                 ))
             }
+        };
+        if let Some(metadata) = self.metadata {
+            order.set_metadata(metadata);
         }
+        order
     }
 }
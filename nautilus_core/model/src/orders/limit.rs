@@ -39,6 +39,7 @@ use crate::{
     },
     orders::base::OrderError,
     types::{
+        metadata::OrderMetadata,
         price::Price,
         quantity::{check_quantity_positive, Quantity},
     },
@@ -319,6 +320,10 @@ impl Order for LimitOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -443,7 +448,8 @@ impl From<OrderAny> for LimitOrder {
 
 impl From<OrderInitialized> for LimitOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -472,7 +478,9 @@ impl From<OrderInitialized> for LimitOrder {
             event.event_id,
             event.ts_event,
         )
-        .unwrap()
+        .unwrap();
+        order.set_metadata(metadata);
+        order
     }
 }
 
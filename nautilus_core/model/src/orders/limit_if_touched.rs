@@ -36,7 +36,7 @@ use crate::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -306,6 +306,10 @@ impl Order for LimitIfTouchedOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -391,7 +395,8 @@ impl From<OrderAny> for LimitIfTouchedOrder {
 
 impl From<OrderInitialized> for LimitIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -427,6 +432,8 @@ impl From<OrderInitialized> for LimitIfTouchedOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.set_metadata(metadata);
+        order
     }
 }
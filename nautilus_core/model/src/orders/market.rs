@@ -43,6 +43,7 @@ use crate::{
     },
     orders::base::OrderError,
     types::{
+        metadata::OrderMetadata,
         price::Price,
         quantity::{check_quantity_positive, Quantity},
     },
@@ -348,6 +349,10 @@ impl Order for MarketOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -465,7 +470,8 @@ impl From<OrderAny> for MarketOrder {
 
 impl From<OrderInitialized> for MarketOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -485,7 +491,9 @@ impl From<OrderInitialized> for MarketOrder {
             event.exec_algorithm_params,
             event.exec_spawn_id,
             event.tags,
-        )
+        );
+        order.set_metadata(metadata);
+        order
     }
 }
 
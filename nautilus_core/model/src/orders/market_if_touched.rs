@@ -36,7 +36,7 @@ use crate::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -300,6 +300,10 @@ impl Order for MarketIfTouchedOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -383,7 +387,8 @@ impl From<OrderAny> for MarketIfTouchedOrder {
 
 impl From<OrderInitialized> for MarketIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -415,6 +420,8 @@ impl From<OrderInitialized> for MarketIfTouchedOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.set_metadata(metadata);
+        order
     }
 }
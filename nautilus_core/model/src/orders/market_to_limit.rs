@@ -37,7 +37,7 @@ use crate::{
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
     orders::base::OrderError,
-    types::{price::Price, quantity::Quantity},
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -292,6 +292,10 @@ impl Order for MarketToLimitOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -365,7 +369,8 @@ impl Order for MarketToLimitOrder {
 
 impl From<OrderInitialized> for MarketToLimitOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -388,6 +393,8 @@ impl From<OrderInitialized> for MarketToLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.set_metadata(metadata);
+        order
     }
 }
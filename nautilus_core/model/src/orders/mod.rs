@@ -30,6 +30,7 @@ pub mod stop_limit;
 pub mod stop_market;
 pub mod trailing_stop_limit;
 pub mod trailing_stop_market;
+pub mod validation;
 
 pub mod builder;
 
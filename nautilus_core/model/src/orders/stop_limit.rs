@@ -37,7 +37,7 @@ use crate::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -313,6 +313,10 @@ impl Order for StopLimitOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -400,7 +404,8 @@ impl From<OrderAny> for StopLimitOrder {
 
 impl From<OrderInitialized> for StopLimitOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -434,7 +439,9 @@ impl From<OrderInitialized> for StopLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.set_metadata(metadata);
+        order
     }
 }
 
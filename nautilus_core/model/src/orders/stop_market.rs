@@ -37,7 +37,7 @@ use crate::{
         StrategyId, Symbol, TradeId, TraderId, Venue, VenueOrderId,
     },
     orders::base::OrderError,
-    types::{price::Price, quantity::Quantity},
+    types::{metadata::OrderMetadata, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -301,6 +301,10 @@ impl Order for StopMarketOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> OrderMetadata {
+        self.metadata
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -370,7 +374,8 @@ impl Order for StopMarketOrder {
 
 impl From<OrderInitialized> for StopMarketOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let metadata = event.metadata;
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -402,6 +407,8 @@ impl From<OrderInitialized> for StopMarketOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.set_metadata(metadata);
+        order
     }
 }
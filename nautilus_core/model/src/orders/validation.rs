@@ -0,0 +1,346 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Validation of order prices and quantities against the target instrument.
+//!
+//! The [`OrderValidator`] checks orders at creation, so invalid orders can be caught with a typed
+//! [`OrderValidationError`] before they are submitted to the `RiskEngine`.
+
+use super::any::OrderAny;
+use crate::{
+    identifiers::InstrumentId,
+    instruments::any::InstrumentAny,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum OrderValidationError {
+    #[error("Order instrument {actual} does not match validator instrument {expected}")]
+    InstrumentMismatch {
+        expected: InstrumentId,
+        actual: InstrumentId,
+    },
+    #[error(
+        "Price {price} precision {precision} did not match instrument price precision {expected}"
+    )]
+    PricePrecision {
+        price: Price,
+        precision: u8,
+        expected: u8,
+    },
+    #[error("Price {price} is not a multiple of price increment {increment}")]
+    PriceIncrement { price: Price, increment: Price },
+    #[error("Price {price} was less than minimum price {min}")]
+    PriceBelowMin { price: Price, min: Price },
+    #[error("Price {price} was greater than maximum price {max}")]
+    PriceAboveMax { price: Price, max: Price },
+    #[error("Quantity {quantity} precision {precision} did not match instrument size precision {expected}")]
+    QuantityPrecision {
+        quantity: Quantity,
+        precision: u8,
+        expected: u8,
+    },
+    #[error("Quantity {quantity} is not a multiple of size increment {increment}")]
+    QuantityIncrement {
+        quantity: Quantity,
+        increment: Quantity,
+    },
+    #[error("Quantity {quantity} is not a multiple of lot size {lot_size}")]
+    LotSize {
+        quantity: Quantity,
+        lot_size: Quantity,
+    },
+    #[error("Quantity {quantity} was less than minimum quantity {min}")]
+    QuantityBelowMin { quantity: Quantity, min: Quantity },
+    #[error("Quantity {quantity} was greater than maximum quantity {max}")]
+    QuantityAboveMax { quantity: Quantity, max: Quantity },
+    #[error("Notional {notional} was less than minimum notional {min}")]
+    NotionalBelowMin { notional: Money, min: Money },
+    #[error("Notional {notional} was greater than maximum notional {max}")]
+    NotionalAboveMax { notional: Money, max: Money },
+}
+
+/// Validates orders against the price and quantity constraints of an instrument.
+#[derive(Clone, Debug)]
+pub struct OrderValidator {
+    instrument: InstrumentAny,
+    enforce_lot_size: bool,
+}
+
+impl OrderValidator {
+    /// Creates a new [`OrderValidator`] instance for the given `instrument`.
+    ///
+    /// Quantities are only required to be a multiple of the instrument lot size if
+    /// `enforce_lot_size` is true, as many venues accept odd lots.
+    #[must_use]
+    pub const fn new(instrument: InstrumentAny, enforce_lot_size: bool) -> Self {
+        Self {
+            instrument,
+            enforce_lot_size,
+        }
+    }
+
+    #[must_use]
+    pub const fn instrument(&self) -> &InstrumentAny {
+        &self.instrument
+    }
+
+    /// Validates the given `order`, returning the first constraint it fails.
+    ///
+    /// The price, trigger price, quantity and notional value are checked. Notional bounds are only
+    /// checked for orders with a price, and size constraints are not checked for orders with a
+    /// quantity denominated in the quote currency.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order is for a different instrument.
+    /// - If a price or quantity fails an instrument constraint.
+    pub fn validate(&self, order: &OrderAny) -> Result<(), OrderValidationError> {
+        if order.instrument_id() != self.instrument.id() {
+            return Err(OrderValidationError::InstrumentMismatch {
+                expected: self.instrument.id(),
+                actual: order.instrument_id(),
+            });
+        }
+
+        if let Some(price) = order.price() {
+            self.validate_price(price)?;
+        }
+        if let Some(trigger_price) = order.trigger_price() {
+            self.validate_price(trigger_price)?;
+        }
+
+        let quantity = order.quantity();
+        if order.is_quote_quantity() {
+            let notional = Money::new(quantity.as_f64(), self.instrument.quote_currency());
+            return self.validate_notional(notional);
+        }
+
+        self.validate_quantity(quantity)?;
+        if let Some(price) = order.price().or(order.trigger_price()) {
+            let notional = self
+                .instrument
+                .calculate_notional_value(quantity, price, None);
+            self.validate_notional(notional)?;
+        }
+        Ok(())
+    }
+
+    /// Validates the given `price` against the instrument price precision, increment and bounds.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the price fails an instrument constraint.
+    pub fn validate_price(&self, price: Price) -> Result<(), OrderValidationError> {
+        let expected = self.instrument.price_precision();
+        if price.precision != expected {
+            return Err(OrderValidationError::PricePrecision {
+                price,
+                precision: price.precision,
+                expected,
+            });
+        }
+
+        let increment = self.instrument.price_increment();
+        if increment.raw > 0 && price.raw % increment.raw != 0 {
+            return Err(OrderValidationError::PriceIncrement { price, increment });
+        }
+        if let Some(min) = self.instrument.min_price() {
+            if price < min {
+                return Err(OrderValidationError::PriceBelowMin { price, min });
+            }
+        }
+        if let Some(max) = self.instrument.max_price() {
+            if price > max {
+                return Err(OrderValidationError::PriceAboveMax { price, max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the given `quantity` against the instrument size precision, increment, lot size
+    /// (if enforced) and bounds.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the quantity fails an instrument constraint.
+    pub fn validate_quantity(&self, quantity: Quantity) -> Result<(), OrderValidationError> {
+        let expected = self.instrument.size_precision();
+        if quantity.precision != expected {
+            return Err(OrderValidationError::QuantityPrecision {
+                quantity,
+                precision: quantity.precision,
+                expected,
+            });
+        }
+
+        let increment = self.instrument.size_increment();
+        if quantity.raw % increment.raw != 0 {
+            return Err(OrderValidationError::QuantityIncrement {
+                quantity,
+                increment,
+            });
+        }
+        if let Some(lot_size) = self.instrument.lot_size().filter(|_| self.enforce_lot_size) {
+            if quantity.raw % lot_size.raw != 0 {
+                return Err(OrderValidationError::LotSize { quantity, lot_size });
+            }
+        }
+        if let Some(min) = self.instrument.min_quantity() {
+            if quantity < min {
+                return Err(OrderValidationError::QuantityBelowMin { quantity, min });
+            }
+        }
+        if let Some(max) = self.instrument.max_quantity() {
+            if quantity > max {
+                return Err(OrderValidationError::QuantityAboveMax { quantity, max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the given `notional` value against the instrument notional bounds.
+    ///
+    /// Bounds denominated in a different currency to the notional are not checked.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the notional is outside the instrument bounds.
+    pub fn validate_notional(&self, notional: Money) -> Result<(), OrderValidationError> {
+        if let Some(min) = self.instrument.min_notional() {
+            if min.currency == notional.currency && notional < min {
+                return Err(OrderValidationError::NotionalBelowMin { notional, min });
+            }
+        }
+        if let Some(max) = self.instrument.max_notional() {
+            if max.currency == notional.currency && notional > max {
+                return Err(OrderValidationError::NotionalAboveMax { notional, max });
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType},
+        instruments::{crypto_perpetual::CryptoPerpetual, stubs::crypto_perpetual_ethusdt},
+        orders::builder::OrderTestBuilder,
+    };
+
+    fn limit_order(instrument: &InstrumentAny, price: &str, quantity: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from(price))
+            .quantity(Quantity::from(quantity))
+            .build()
+    }
+
+    #[rstest]
+    fn test_validate_valid_order(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let validator = OrderValidator::new(instrument.clone(), false);
+
+        let order = limit_order(&instrument, "1500.00", "1.000");
+
+        assert!(validator.validate(&order).is_ok());
+    }
+
+    #[rstest]
+    #[case("1500.0", "1.000", "PricePrecision")]
+    #[case("0.50", "100.000", "PriceBelowMin")]
+    #[case("15000.01", "1.000", "PriceAboveMax")]
+    #[case("1500.00", "1.0", "QuantityPrecision")]
+    #[case("1500.00", "10001.000", "QuantityAboveMax")]
+    #[case("1500.00", "0.005", "NotionalBelowMin")]
+    fn test_validate_invalid_order(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] quantity: &str,
+        #[case] expected: &str,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let validator = OrderValidator::new(instrument.clone(), false);
+
+        let order = limit_order(&instrument, price, quantity);
+        let error = validator.validate(&order).unwrap_err();
+
+        assert!(format!("{error:?}").starts_with(expected), "{error:?}");
+    }
+
+    #[rstest]
+    fn test_validate_quantity_lot_size(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let validator = OrderValidator::new(instrument.clone(), true);
+
+        let result = validator.validate_quantity(Quantity::from("1.500"));
+
+        assert_eq!(
+            result,
+            Err(OrderValidationError::LotSize {
+                quantity: Quantity::from("1.500"),
+                lot_size: Quantity::from(1),
+            })
+        );
+        assert!(validator.validate_quantity(Quantity::from("2.000")).is_ok());
+    }
+
+    #[rstest]
+    fn test_validate_price_not_multiple_of_increment(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut perpetual = crypto_perpetual_ethusdt;
+        perpetual.price_increment = Price::from("0.05");
+        let validator = OrderValidator::new(InstrumentAny::CryptoPerpetual(perpetual), false);
+
+        let result = validator.validate_price(Price::from("1500.02"));
+
+        assert_eq!(
+            result,
+            Err(OrderValidationError::PriceIncrement {
+                price: Price::from("1500.02"),
+                increment: Price::from("0.05"),
+            })
+        );
+        assert!(validator.validate_price(Price::from("1500.05")).is_ok());
+    }
+
+    #[rstest]
+    fn test_validate_instrument_mismatch(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let validator = OrderValidator::new(
+            InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt),
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("BTCUSDT-PERP.BINANCE"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+
+        let result = validator.validate(&order);
+
+        assert!(matches!(
+            result,
+            Err(OrderValidationError::InstrumentMismatch { .. })
+        ));
+    }
+}
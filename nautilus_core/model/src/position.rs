@@ -16,6 +16,7 @@
 //! A `Position` for the trading domain model.
 
 use std::{
+    cmp::Reverse,
     collections::{HashMap, HashSet},
     fmt::Display,
     hash::{Hash, Hasher},
@@ -25,16 +26,56 @@ use nautilus_core::nanos::UnixNanos;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{OrderSide, OrderSideSpecified, PositionSide},
+    enums::{NettingPolicy, OrderSide, OrderSideSpecified, PositionSide},
     events::order::filled::OrderFilled,
     identifiers::{
         AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, Symbol, TradeId, TraderId,
         Venue, VenueOrderId,
     },
     instruments::any::InstrumentAny,
-    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency, metadata::OrderMetadata, money::Money, price::Price, quantity::Quantity,
+    },
 };
 
+/// Represents an open lot of a position, opened by a single fill.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PositionLot {
+    /// The trade match ID of the fill which opened the lot.
+    pub trade_id: TradeId,
+    /// The remaining open quantity of the lot.
+    pub quantity: Quantity,
+    /// The price at which the lot was opened.
+    pub price: Price,
+    /// UNIX timestamp (nanoseconds) when the lot was opened.
+    pub ts_opened: UnixNanos,
+}
+
+/// Represents the (partial) closing of a position lot, reporting the realized PnL per lot.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClosedLot {
+    /// The position ID the lot belonged to.
+    pub position_id: PositionId,
+    /// The instrument ID for the lot.
+    pub instrument_id: InstrumentId,
+    /// The trade match ID of the fill which opened the lot.
+    pub opening_trade_id: TradeId,
+    /// The trade match ID of the fill which closed the lot.
+    pub closing_trade_id: TradeId,
+    /// The quantity of the lot which was closed.
+    pub quantity: Quantity,
+    /// The cost basis price for the closed quantity, per the netting policy.
+    pub open_px: f64,
+    /// The price at which the quantity was closed.
+    pub close_px: Price,
+    /// The realized PnL for the closed quantity (excluding commissions).
+    pub realized_pnl: Money,
+    /// UNIX timestamp (nanoseconds) when the lot was opened.
+    pub ts_opened: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the quantity was closed.
+    pub ts_closed: UnixNanos,
+}
+
 /// Represents a position in a market.
 ///
 /// The position ID may be assigned at the trading venue, or can be system
@@ -79,6 +120,15 @@ pub struct Position {
     pub buy_qty: Quantity,
     pub sell_qty: Quantity,
     pub commissions: HashMap<Currency, Money>,
+    #[serde(default)]
+    pub netting_policy: NettingPolicy,
+    #[serde(default)]
+    pub open_lots: Vec<PositionLot>,
+    #[serde(default)]
+    pub closed_lots: Vec<ClosedLot>,
+    /// The metadata of the opening order, extended with new keys from subsequent fills.
+    #[serde(default)]
+    pub metadata: OrderMetadata,
 }
 
 impl Position {
@@ -123,6 +173,10 @@ impl Position {
             avg_px_close: None,
             realized_return: 0.0,
             realized_pnl: None,
+            netting_policy: NettingPolicy::default(),
+            open_lots: Vec::new(),
+            closed_lots: Vec::new(),
+            metadata: fill.metadata,
         };
         item.apply(&fill);
         item
@@ -152,8 +206,13 @@ impl Position {
             self.avg_px_close = None;
             self.realized_return = 0.0;
             self.realized_pnl = None;
+            self.open_lots.clear();
+            self.closed_lots.clear();
+            self.metadata = fill.metadata;
         }
 
+        self.metadata = self.metadata.merge(&fill.metadata);
+
         self.events.push(*fill);
         self.trade_ids.push(fill.trade_id);
 
@@ -228,8 +287,8 @@ impl Position {
             let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
             self.avg_px_close = Some(avg_px_close);
             self.realized_return = self.calculate_return(self.avg_px_open, avg_px_close);
-            realized_pnl += self.calculate_pnl_raw(self.avg_px_open, last_px, last_qty);
         }
+        realized_pnl += self.update_lots(fill);
 
         if self.realized_pnl.is_none() {
             self.realized_pnl = Some(Money::new(realized_pnl, self.settlement_currency));
@@ -266,8 +325,8 @@ impl Position {
             let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
             self.avg_px_close = Some(avg_px_close);
             self.realized_return = self.calculate_return(self.avg_px_open, avg_px_close);
-            realized_pnl += self.calculate_pnl_raw(self.avg_px_open, last_px, last_qty);
         }
+        realized_pnl += self.update_lots(fill);
 
         if self.realized_pnl.is_none() {
             self.realized_pnl = Some(Money::new(realized_pnl, self.settlement_currency));
@@ -282,6 +341,95 @@ impl Position {
         self.sell_qty += last_qty_object;
     }
 
+    /// Sets the policy for matching closing fills against the open lots of the position.
+    pub fn set_netting_policy(&mut self, netting_policy: NettingPolicy) {
+        self.netting_policy = netting_policy;
+    }
+
+    /// Updates the open lots for the given `fill`, returning the realized PnL for any lots it
+    /// closed (excluding commissions).
+    ///
+    /// Closing fills are matched against the open lots per the netting policy, and any quantity
+    /// in excess of the position quantity opens a new lot on the other side.
+    fn update_lots(&mut self, fill: &OrderFilled) -> f64 {
+        let mut realized_pnl = 0.0;
+        let mut remaining = fill.last_qty;
+
+        if self.side != PositionSide::Flat && self.is_opposite_side(fill.order_side) {
+            let last_px = fill.last_px.as_f64();
+            while remaining.raw > 0 {
+                let Some(index) = self.next_lot_index() else {
+                    break;
+                };
+                let lot = self.open_lots[index];
+                let quantity = lot.quantity.min(remaining);
+                let open_px = match self.netting_policy {
+                    NettingPolicy::AvgCost => self.avg_px_open,
+                    _ => lot.price.as_f64(),
+                };
+                let lot_pnl = self.calculate_pnl_raw(open_px, last_px, quantity.as_f64());
+                realized_pnl += lot_pnl;
+
+                self.closed_lots.push(ClosedLot {
+                    position_id: self.id,
+                    instrument_id: self.instrument_id,
+                    opening_trade_id: lot.trade_id,
+                    closing_trade_id: fill.trade_id,
+                    quantity,
+                    open_px,
+                    close_px: fill.last_px,
+                    realized_pnl: Money::new(lot_pnl, self.settlement_currency),
+                    ts_opened: lot.ts_opened,
+                    ts_closed: fill.ts_event,
+                });
+
+                remaining -= quantity;
+                if quantity == lot.quantity {
+                    self.open_lots.remove(index);
+                } else {
+                    self.open_lots[index].quantity = lot.quantity - quantity;
+                }
+            }
+        }
+
+        if remaining.raw > 0 {
+            self.open_lots.push(PositionLot {
+                trade_id: fill.trade_id,
+                quantity: remaining,
+                price: fill.last_px,
+                ts_opened: fill.ts_event,
+            });
+        }
+
+        if self.netting_policy != NettingPolicy::AvgCost && !self.open_lots.is_empty() {
+            let (cost, quantity) = self.open_lots.iter().fold((0.0, 0.0), |(cost, qty), lot| {
+                let lot_qty = lot.quantity.as_f64();
+                (cost + lot.price.as_f64() * lot_qty, qty + lot_qty)
+            });
+            self.avg_px_open = cost / quantity;
+        }
+
+        realized_pnl
+    }
+
+    fn next_lot_index(&self) -> Option<usize> {
+        if self.open_lots.is_empty() {
+            return None;
+        }
+        match self.netting_policy {
+            NettingPolicy::AvgCost | NettingPolicy::Fifo => Some(0),
+            NettingPolicy::Lifo => Some(self.open_lots.len() - 1),
+            NettingPolicy::HighestCost => {
+                let lots = self.open_lots.iter().enumerate();
+                let lot = match self.side {
+                    PositionSide::Short => lots.min_by_key(|(_, lot)| lot.price),
+                    _ => lots.max_by_key(|(index, lot)| (lot.price, Reverse(*index))),
+                };
+                lot.map(|(index, _)| index)
+            }
+        }
+    }
+
     #[must_use]
     pub fn calculate_avg_px(&self, qty: f64, avg_pg: f64, last_px: f64, last_qty: f64) -> f64 {
         let start_cost = avg_pg * qty;
@@ -529,7 +677,7 @@ mod tests {
     use rstest::rstest;
 
     use crate::{
-        enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+        enums::{LiquiditySide, NettingPolicy, OrderSide, OrderType, PositionSide},
         events::order::OrderFilled,
         identifiers::{stubs::uuid4, AccountId, PositionId, StrategyId, TradeId, VenueOrderId},
         instruments::{
@@ -539,7 +687,7 @@ mod tests {
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         position::Position,
         stubs::*,
-        types::{money::Money, price::Price, quantity::Quantity},
+        types::{metadata::OrderMetadata, money::Money, price::Price, quantity::Quantity},
     };
 
     #[rstest]
@@ -1948,4 +2096,209 @@ mod tests {
         let position = Position::new(&audusd_sim, fill);
         assert_eq!(position.realized_pnl, Some(Money::from("0 USD")));
     }
+
+    fn lot_fill(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        trade_id: &str,
+        quantity: i64,
+        price: &str,
+    ) -> OrderFilled {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            Some(TradeId::new(trade_id)),
+            None,
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .into()
+    }
+
+    #[rstest]
+    #[case(NettingPolicy::AvgCost, "30.00 USD", vec!["1", "2"])]
+    #[case(NettingPolicy::Fifo, "35.00 USD", vec!["1", "2"])]
+    #[case(NettingPolicy::Lifo, "25.00 USD", vec!["3", "2"])]
+    #[case(NettingPolicy::HighestCost, "20.00 USD", vec!["2", "3"])]
+    fn test_netting_policy_lot_matching(
+        audusd_sim: CurrencyPair,
+        #[case] netting_policy: NettingPolicy,
+        #[case] expected_pnl: &str,
+        #[case] expected_trade_ids: Vec<&str>,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = Position::new(
+            &audusd_sim,
+            lot_fill(&audusd_sim, OrderSide::Buy, "1", 100_000, "1.00000"),
+        );
+        position.set_netting_policy(netting_policy);
+        position.apply(&lot_fill(
+            &audusd_sim,
+            OrderSide::Buy,
+            "2",
+            100_000,
+            "1.00020",
+        ));
+        position.apply(&lot_fill(
+            &audusd_sim,
+            OrderSide::Buy,
+            "3",
+            100_000,
+            "1.00010",
+        ));
+        position.apply(&lot_fill(
+            &audusd_sim,
+            OrderSide::Sell,
+            "4",
+            150_000,
+            "1.00030",
+        ));
+
+        let lots_pnl = position
+            .closed_lots
+            .iter()
+            .fold(Money::from("0 USD"), |pnl, lot| pnl + lot.realized_pnl);
+        let trade_ids: Vec<TradeId> = position
+            .closed_lots
+            .iter()
+            .map(|lot| lot.opening_trade_id)
+            .collect();
+        let commissions = position.commissions()[0];
+        let open_qty = position
+            .open_lots
+            .iter()
+            .fold(Quantity::from(0), |qty, lot| qty + lot.quantity);
+
+        assert_eq!(lots_pnl, Money::from(expected_pnl));
+        assert_eq!(position.realized_pnl, Some(lots_pnl - commissions));
+        assert_eq!(
+            trade_ids,
+            expected_trade_ids
+                .into_iter()
+                .map(TradeId::new)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(position.quantity, Quantity::from(150_000));
+        assert_eq!(open_qty, position.quantity);
+        assert!(position
+            .closed_lots
+            .iter()
+            .all(|lot| lot.closing_trade_id == TradeId::new("4")));
+    }
+
+    #[rstest]
+    fn test_netting_policy_fifo_updates_avg_px_open(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = Position::new(
+            &audusd_sim,
+            lot_fill(&audusd_sim, OrderSide::Sell, "1", 100_000, "1.00000"),
+        );
+        position.set_netting_policy(NettingPolicy::Fifo);
+        position.apply(&lot_fill(
+            &audusd_sim,
+            OrderSide::Sell,
+            "2",
+            100_000,
+            "1.00020",
+        ));
+        position.apply(&lot_fill(
+            &audusd_sim,
+            OrderSide::Buy,
+            "3",
+            100_000,
+            "1.00010",
+        ));
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.open_lots.len(), 1);
+        assert_eq!(position.open_lots[0].trade_id, TradeId::new("2"));
+        assert_eq!(position.avg_px_open, 1.0002);
+        assert_eq!(
+            position.closed_lots[0].realized_pnl,
+            Money::from("-10.00 USD")
+        );
+        assert_eq!(
+            position.unrealized_pnl(Price::from("1.00010")),
+            Money::from("10.00 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_netting_policy_flip_opens_lot_for_excess_quantity(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = Position::new(
+            &audusd_sim,
+            lot_fill(&audusd_sim, OrderSide::Buy, "1", 100_000, "1.00000"),
+        );
+        position.set_netting_policy(NettingPolicy::Lifo);
+        position.apply(&lot_fill(
+            &audusd_sim,
+            OrderSide::Sell,
+            "2",
+            150_000,
+            "1.00010",
+        ));
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.closed_lots.len(), 1);
+        assert_eq!(position.closed_lots[0].quantity, Quantity::from(100_000));
+        assert_eq!(position.open_lots.len(), 1);
+        assert_eq!(position.open_lots[0].trade_id, TradeId::new("2"));
+        assert_eq!(position.open_lots[0].quantity, Quantity::from(50_000));
+        assert_eq!(position.avg_px_open, 1.0001);
+    }
+
+    #[rstest]
+    fn test_position_deserialize_without_lot_fields(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let position = Position::new(
+            &audusd_sim,
+            lot_fill(&audusd_sim, OrderSide::Buy, "1", 100_000, "1.00000"),
+        );
+        let mut value = serde_json::to_value(&position).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("netting_policy");
+        fields.remove("open_lots");
+        fields.remove("closed_lots");
+
+        let json = serde_json::to_string(&value).unwrap();
+
+        let result: Position = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result.netting_policy, NettingPolicy::AvgCost);
+        assert!(result.open_lots.is_empty());
+        assert!(result.closed_lots.is_empty());
+        assert_eq!(result.quantity, position.quantity);
+    }
+
+    #[rstest]
+    fn test_position_metadata_from_fills(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut fill1 = lot_fill(&audusd_sim, OrderSide::Buy, "1", 100_000, "1.00000");
+        fill1.metadata = OrderMetadata::from_str("signal=breakout").unwrap();
+        let mut fill2 = lot_fill(&audusd_sim, OrderSide::Buy, "2", 50_000, "1.00010");
+        fill2.metadata = OrderMetadata::from_str("algo=twap,signal=momentum").unwrap();
+        let mut fill3 = lot_fill(&audusd_sim, OrderSide::Sell, "3", 150_000, "1.00020");
+        fill3.metadata = OrderMetadata::from_str("signal=exit").unwrap();
+        let mut fill4 = lot_fill(&audusd_sim, OrderSide::Sell, "4", 100_000, "1.00020");
+        fill4.metadata = OrderMetadata::from_str("signal=reversal").unwrap();
+
+        let mut position = Position::new(&audusd_sim, fill1);
+        position.apply(&fill2);
+        assert_eq!(position.metadata.as_str(), "algo=twap,signal=breakout");
+
+        position.apply(&fill3);
+        position.apply(&fill4);
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.metadata.as_str(), "signal=reversal");
+    }
 }
@@ -0,0 +1,117 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Helpers for extracting columns of values from NumPy arrays for batch data constructors.
+//!
+//! Arrays are read through the Python buffer protocol, so a `numpy.ndarray` (or any other
+//! buffer exporter) with a matching dtype is copied in a single pass without touching each
+//! element from Python. Other sequences such as lists are extracted element by element.
+
+use pyo3::{
+    buffer::{Element, PyBuffer},
+    exceptions::PyValueError,
+    prelude::*,
+};
+
+/// Extracts the one-dimensional array `values` into a vector.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `values` exports a buffer which is not one-dimensional.
+/// - If `values` is neither a compatible buffer nor a sequence of `T`.
+pub fn extract_array<T>(values: &Bound<'_, PyAny>, name: &str) -> PyResult<Vec<T>>
+where
+    T: Element + for<'py> FromPyObject<'py>,
+{
+    match PyBuffer::<T>::get_bound(values) {
+        Ok(buffer) => {
+            if buffer.dimensions() != 1 {
+                return Err(PyValueError::new_err(format!(
+                    "`{name}` must be one-dimensional, was {} dimensions",
+                    buffer.dimensions()
+                )));
+            }
+            buffer.to_vec(values.py())
+        }
+        // Not a buffer with a compatible item type, fall back to a sequence
+        Err(_) => values.extract(),
+    }
+}
+
+/// Checks that every named array has the length `expected`.
+///
+/// # Errors
+///
+/// This function returns an error if any array length differs from `expected`.
+pub fn check_array_lengths(expected: usize, arrays: &[(&str, usize)]) -> PyResult<()> {
+    for (name, len) in arrays {
+        if *len != expected {
+            return Err(PyValueError::new_err(format!(
+                "`{name}` length {len} did not match expected length {expected}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::types::PyList;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_extract_array_from_buffer() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let array = py
+                .import_bound("array")
+                .unwrap()
+                .getattr("array")
+                .unwrap()
+                .call1(("d", vec![1.5, 2.5]))
+                .unwrap();
+
+            assert_eq!(
+                extract_array::<f64>(&array, "values").unwrap(),
+                vec![1.5, 2.5]
+            );
+        });
+    }
+
+    #[rstest]
+    fn test_extract_array_from_list() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let list = PyList::new_bound(py, [1_u64, 2, 3]);
+
+            assert_eq!(
+                extract_array::<u64>(list.as_any(), "values").unwrap(),
+                vec![1, 2, 3]
+            );
+        });
+    }
+
+    #[rstest]
+    fn test_check_array_lengths() {
+        assert!(check_array_lengths(2, &[("a", 2), ("b", 2)]).is_ok());
+        assert!(check_array_lengths(2, &[("a", 2), ("b", 3)]).is_err());
+    }
+}
@@ -15,6 +15,7 @@
 
 //! Data types for the trading domain model.
 
+pub mod arrays;
 pub mod bar;
 pub mod delta;
 pub mod deltas;
@@ -23,6 +24,7 @@ pub mod greeks;
 pub mod order;
 pub mod quote;
 pub mod status;
+pub mod stream;
 pub mod trade;
 
 use indexmap::IndexMap;
@@ -30,7 +30,10 @@ use pyo3::{
     types::{PyDict, PyLong, PyString, PyTuple},
 };
 
-use super::data_to_pycapsule;
+use super::{
+    arrays::{check_array_lengths, extract_array},
+    data_to_pycapsule,
+};
 use crate::{
     data::{quote::QuoteTick, Data},
     enums::PriceType,
@@ -294,6 +297,63 @@ impl QuoteTick {
         .map_err(to_pyvalue_err)
     }
 
+    /// Returns a list of quotes built from the given arrays of values, one quote per index.
+    ///
+    /// NumPy arrays are read through the buffer protocol (`float64` for prices and sizes and
+    /// `uint64` for timestamps). When `ts_init` is `None` it defaults to `ts_event`.
+    #[staticmethod]
+    #[pyo3(
+        name = "from_arrays",
+        signature = (instrument_id, ts_event, bid_price, ask_price, bid_size, ask_size, price_precision, size_precision, ts_init=None)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn py_from_arrays(
+        instrument_id: InstrumentId,
+        ts_event: &Bound<'_, PyAny>,
+        bid_price: &Bound<'_, PyAny>,
+        ask_price: &Bound<'_, PyAny>,
+        bid_size: &Bound<'_, PyAny>,
+        ask_size: &Bound<'_, PyAny>,
+        price_precision: u8,
+        size_precision: u8,
+        ts_init: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Vec<Self>> {
+        let ts_event: Vec<u64> = extract_array(ts_event, "ts_event")?;
+        let bid_price: Vec<f64> = extract_array(bid_price, "bid_price")?;
+        let ask_price: Vec<f64> = extract_array(ask_price, "ask_price")?;
+        let bid_size: Vec<f64> = extract_array(bid_size, "bid_size")?;
+        let ask_size: Vec<f64> = extract_array(ask_size, "ask_size")?;
+        let ts_init: Vec<u64> = match ts_init {
+            Some(ts_init) => extract_array(ts_init, "ts_init")?,
+            None => ts_event.clone(),
+        };
+        check_array_lengths(
+            ts_event.len(),
+            &[
+                ("bid_price", bid_price.len()),
+                ("ask_price", ask_price.len()),
+                ("bid_size", bid_size.len()),
+                ("ask_size", ask_size.len()),
+                ("ts_init", ts_init.len()),
+            ],
+        )?;
+
+        (0..ts_event.len())
+            .map(|i| {
+                Self::new_checked(
+                    instrument_id,
+                    Price::new_checked(bid_price[i], price_precision)?,
+                    Price::new_checked(ask_price[i], price_precision)?,
+                    Quantity::new_checked(bid_size[i], size_precision)?,
+                    Quantity::new_checked(ask_size[i], size_precision)?,
+                    ts_event[i].into(),
+                    ts_init[i].into(),
+                )
+            })
+            .collect::<anyhow::Result<Vec<Self>>>()
+            .map_err(to_pyvalue_err)
+    }
+
     /// Returns a new object from the given dictionary representation.
     #[staticmethod]
     #[pyo3(name = "from_dict")]
@@ -375,7 +435,7 @@ impl QuoteTick {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use pyo3::{IntoPy, Python};
+    use pyo3::prelude::*;
     use rstest::rstest;
 
     use crate::data::{quote::QuoteTick, stubs::quote_ethusdt_binance};
@@ -415,4 +475,57 @@ mod tests {
             assert_eq!(parsed_tick, quote);
         });
     }
+
+    #[rstest]
+    fn test_from_arrays(quote_ethusdt_binance: QuoteTick) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let array = py.import_bound("array").unwrap().getattr("array").unwrap();
+            let prices = |value: f64| array.call1(("d", vec![value; 2])).unwrap();
+            let sizes = array.call1(("d", vec![1.0; 2])).unwrap();
+            let ts_event = vec![0_u64, 0].into_py(py);
+            let ts_init = array.call1(("Q", vec![1_u64, 1])).unwrap();
+
+            let quotes = QuoteTick::py_from_arrays(
+                quote_ethusdt_binance.instrument_id,
+                ts_event.bind(py),
+                &prices(10_000.0),
+                &prices(10_001.0),
+                &sizes,
+                &sizes,
+                4,
+                8,
+                Some(&ts_init),
+            )
+            .unwrap();
+
+            assert_eq!(quotes, vec![quote_ethusdt_binance; 2]);
+        });
+    }
+
+    #[rstest]
+    fn test_from_arrays_with_mismatched_lengths_errors(quote_ethusdt_binance: QuoteTick) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let ts_event = vec![0_u64, 1].into_py(py);
+            let values = vec![1.0_f64].into_py(py);
+            let values = values.bind(py);
+
+            let result = QuoteTick::py_from_arrays(
+                quote_ethusdt_binance.instrument_id,
+                ts_event.bind(py),
+                values,
+                values,
+                values,
+                values,
+                4,
+                8,
+                None,
+            );
+
+            assert!(result.is_err());
+        });
+    }
 }
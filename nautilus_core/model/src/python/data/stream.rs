@@ -0,0 +1,143 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A lazy Python iterator over chunked streams of data.
+
+use nautilus_core::python::to_pyruntime_err;
+use pyo3::prelude::*;
+
+use crate::data::Data;
+
+/// A source of data decoded in chunks, such as a catalog query or a CSV file.
+pub type DataChunks = Box<dyn Iterator<Item = anyhow::Result<Vec<Data>>> + Send>;
+
+/// Provides a Python iterator yielding data objects one at a time from a chunked source.
+///
+/// Only one chunk is held in memory at a time, and the GIL is released while the next chunk
+/// is decoded, so arbitrarily large sources can be streamed without materializing them in a list.
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")]
+pub struct DataStream {
+    chunks: DataChunks,
+    buffer: std::vec::IntoIter<Data>,
+}
+
+impl DataStream {
+    /// Creates a new [`DataStream`] instance from the given `chunks`.
+    #[must_use]
+    pub fn new(chunks: DataChunks) -> Self {
+        Self {
+            chunks,
+            buffer: Vec::new().into_iter(),
+        }
+    }
+
+    /// Returns the next data item, decoding the next chunk if the buffer is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if decoding a chunk fails.
+    pub fn next_data(&mut self) -> anyhow::Result<Option<Data>> {
+        loop {
+            if let Some(data) = self.buffer.next() {
+                return Ok(Some(data));
+            }
+            match self.chunks.next().transpose()? {
+                Some(chunk) => self.buffer = chunk.into_iter(),
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl DataStream {
+    const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let stream = &mut *slf;
+        let data = match stream.buffer.next() {
+            Some(data) => Some(data),
+            None => py
+                .allow_threads(|| stream.next_data())
+                .map_err(to_pyruntime_err)?,
+        };
+        Ok(data.map(|data| data_to_pyobject(py, data)))
+    }
+}
+
+/// Converts the given `data` into its Python object.
+#[must_use]
+pub fn data_to_pyobject(py: Python, data: Data) -> PyObject {
+    match data {
+        Data::Delta(delta) => delta.into_py(py),
+        Data::Deltas(deltas) => (*deltas).clone().into_py(py),
+        Data::Depth10(depth) => depth.into_py(py),
+        Data::Quote(quote) => quote.into_py(py),
+        Data::Trade(trade) => trade.into_py(py),
+        Data::Bar(bar) => bar.into_py(py),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::{quote::QuoteTick, stubs::quote_ethusdt_binance};
+
+    #[rstest]
+    fn test_data_stream_yields_items_across_chunks(quote_ethusdt_binance: QuoteTick) {
+        pyo3::prepare_freethreaded_python();
+        let chunks: Vec<anyhow::Result<Vec<Data>>> = vec![
+            Ok(vec![Data::Quote(quote_ethusdt_binance); 2]),
+            Ok(Vec::new()),
+            Ok(vec![Data::Quote(quote_ethusdt_binance)]),
+        ];
+        let stream = DataStream::new(Box::new(chunks.into_iter()));
+
+        Python::with_gil(|py| {
+            let stream = Bound::new(py, stream).unwrap();
+            let items: Vec<QuoteTick> = stream
+                .iter()
+                .unwrap()
+                .map(|item| item.unwrap().extract().unwrap())
+                .collect();
+
+            assert_eq!(items, vec![quote_ethusdt_binance; 3]);
+        });
+    }
+
+    #[rstest]
+    fn test_data_stream_raises_on_chunk_error(quote_ethusdt_binance: QuoteTick) {
+        pyo3::prepare_freethreaded_python();
+        let chunks: Vec<anyhow::Result<Vec<Data>>> = vec![
+            Ok(vec![Data::Quote(quote_ethusdt_binance)]),
+            Err(anyhow::anyhow!("Invalid record")),
+        ];
+        let stream = DataStream::new(Box::new(chunks.into_iter()));
+
+        Python::with_gil(|py| {
+            let mut items = Bound::new(py, stream).unwrap().iter().unwrap();
+
+            assert!(items.next().unwrap().is_ok());
+            assert!(items.next().unwrap().is_err());
+        });
+    }
+}
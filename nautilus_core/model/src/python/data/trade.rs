@@ -30,7 +30,10 @@ use pyo3::{
     types::{PyDict, PyLong, PyString, PyTuple},
 };
 
-use super::data_to_pycapsule;
+use super::{
+    arrays::{check_array_lengths, extract_array},
+    data_to_pycapsule,
+};
 use crate::{
     data::{trade::TradeTick, Data},
     enums::{AggressorSide, FromU8},
@@ -279,6 +282,67 @@ impl TradeTick {
         Ok(py_dict)
     }
 
+    /// Returns a list of trades built from the given arrays of values, one trade per index.
+    ///
+    /// NumPy arrays are read through the buffer protocol (`float64` for prices and sizes,
+    /// `uint8` for aggressor sides and `uint64` for timestamps). When `ts_init` is `None` it
+    /// defaults to `ts_event`.
+    #[staticmethod]
+    #[pyo3(
+        name = "from_arrays",
+        signature = (instrument_id, ts_event, price, size, aggressor_side, trade_id, price_precision, size_precision, ts_init=None)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn py_from_arrays(
+        instrument_id: InstrumentId,
+        ts_event: &Bound<'_, PyAny>,
+        price: &Bound<'_, PyAny>,
+        size: &Bound<'_, PyAny>,
+        aggressor_side: &Bound<'_, PyAny>,
+        trade_id: Vec<String>,
+        price_precision: u8,
+        size_precision: u8,
+        ts_init: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Vec<Self>> {
+        let ts_event: Vec<u64> = extract_array(ts_event, "ts_event")?;
+        let price: Vec<f64> = extract_array(price, "price")?;
+        let size: Vec<f64> = extract_array(size, "size")?;
+        let aggressor_side: Vec<u8> = extract_array(aggressor_side, "aggressor_side")?;
+        let ts_init: Vec<u64> = match ts_init {
+            Some(ts_init) => extract_array(ts_init, "ts_init")?,
+            None => ts_event.clone(),
+        };
+        check_array_lengths(
+            ts_event.len(),
+            &[
+                ("price", price.len()),
+                ("size", size.len()),
+                ("aggressor_side", aggressor_side.len()),
+                ("trade_id", trade_id.len()),
+                ("ts_init", ts_init.len()),
+            ],
+        )?;
+
+        (0..ts_event.len())
+            .map(|i| {
+                let aggressor_side =
+                    AggressorSide::from_u8(aggressor_side[i]).ok_or_else(|| {
+                        anyhow::anyhow!("Invalid `AggressorSide` value, was {}", aggressor_side[i])
+                    })?;
+                Ok(Self::new(
+                    instrument_id,
+                    Price::new_checked(price[i], price_precision)?,
+                    Quantity::new_checked(size[i], size_precision)?,
+                    aggressor_side,
+                    TradeId::new_checked(&trade_id[i])?,
+                    ts_event[i].into(),
+                    ts_init[i].into(),
+                ))
+            })
+            .collect::<anyhow::Result<Vec<Self>>>()
+            .map_err(to_pyvalue_err)
+    }
+
     /// Returns a new object from the given dictionary representation.
     #[staticmethod]
     #[pyo3(name = "from_dict")]
@@ -350,7 +414,7 @@ impl TradeTick {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use pyo3::{IntoPy, Python};
+    use pyo3::prelude::*;
     use rstest::rstest;
 
     use crate::data::{stubs::stub_trade_ethusdt_buyer, trade::TradeTick};
@@ -390,4 +454,33 @@ mod tests {
             assert_eq!(parsed_tick, trade);
         });
     }
+
+    #[rstest]
+    fn test_from_arrays(stub_trade_ethusdt_buyer: TradeTick) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let array = py.import_bound("array").unwrap().getattr("array").unwrap();
+            let ts_event = array.call1(("Q", vec![0_u64])).unwrap();
+            let ts_init = array.call1(("Q", vec![1_u64])).unwrap();
+            let price = array.call1(("d", vec![10_000.0])).unwrap();
+            let size = array.call1(("d", vec![1.0])).unwrap();
+            let aggressor_side = array.call1(("B", vec![1_u8])).unwrap();
+
+            let trades = TradeTick::py_from_arrays(
+                stub_trade_ethusdt_buyer.instrument_id,
+                &ts_event,
+                &price,
+                &size,
+                &aggressor_side,
+                vec!["123456789".to_string()],
+                4,
+                8,
+                Some(&ts_init),
+            )
+            .unwrap();
+
+            assert_eq!(trades, vec![stub_trade_ethusdt_buyer]);
+        });
+    }
 }
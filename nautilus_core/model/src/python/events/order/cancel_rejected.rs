@@ -23,6 +23,7 @@ use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use ustr::Ustr;
 
 use crate::{
+    enums::VenueRejectReason,
     events::order::OrderCancelRejected,
     identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
 };
@@ -31,7 +32,7 @@ use crate::{
 impl OrderCancelRejected {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, reason, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, reason, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, reject_reason=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -44,6 +45,7 @@ impl OrderCancelRejected {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reject_reason: Option<VenueRejectReason>,
     ) -> PyResult<Self> {
         let reason = Ustr::from_str(reason).map_err(to_pyvalue_err)?;
         Ok(Self::new(
@@ -58,6 +60,7 @@ impl OrderCancelRejected {
             reconciliation,
             venue_order_id,
             account_id,
+            reject_reason.unwrap_or_default(),
         ))
     }
 
@@ -96,6 +99,7 @@ impl OrderCancelRejected {
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
         dict.set_item("reconciliation", self.reconciliation)?;
+        dict.set_item("reject_reason", self.reject_reason.to_string())?;
         match self.venue_order_id {
             Some(venue_order_id) => dict.set_item("venue_order_id", venue_order_id.to_string())?,
             None => dict.set_item("venue_order_id", py.None())?,
@@ -23,6 +23,7 @@ use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use ustr::Ustr;
 
 use crate::{
+    enums::VenueRejectReason,
     events::order::OrderRejected,
     identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId},
 };
@@ -30,6 +31,7 @@ use crate::{
 impl OrderRejected {
     #[allow(clippy::too_many_arguments)]
     #[new]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, account_id, reason, event_id, ts_event, ts_init, reconciliation, reject_reason=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -41,6 +43,7 @@ impl OrderRejected {
         ts_event: u64,
         ts_init: u64,
         reconciliation: bool,
+        reject_reason: Option<VenueRejectReason>,
     ) -> PyResult<Self> {
         let reason = Ustr::from_str(reason).map_err(to_pyvalue_err)?;
         Ok(Self::new(
@@ -54,6 +57,7 @@ impl OrderRejected {
             ts_event.into(),
             ts_init.into(),
             reconciliation,
+            reject_reason.unwrap_or_default(),
         ))
     }
 
@@ -93,6 +97,7 @@ impl OrderRejected {
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
         dict.set_item("reconciliation", self.reconciliation)?;
+        dict.set_item("reject_reason", self.reject_reason.to_string())?;
         Ok(dict.into())
     }
 }
@@ -27,6 +27,7 @@ use crate::{
     enums::{AssetClass, InstrumentClass},
     identifiers::{InstrumentId, Symbol},
     instruments::betting::BettingInstrument,
+    python::instruments::{info_from_pydict, info_to_pydict},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -34,8 +35,9 @@ use crate::{
 impl BettingInstrument {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, event_type_id, event_type_name, competition_id, competition_name, event_id, event_name, event_country_code, event_open_date, betting_type, market_id, market_name, market_type, market_start_time, selection_id, selection_name, selection_handicap, currency, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, ts_event, ts_init, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None))]
+    #[pyo3(signature = (id, raw_symbol, event_type_id, event_type_name, competition_id, competition_name, event_id, event_name, event_country_code, event_open_date, betting_type, market_id, market_name, market_type, market_start_time, selection_id, selection_name, selection_handicap, currency, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, ts_event, ts_init, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None, info=None))]
     fn py_new(
+        py: Python<'_>,
         id: InstrumentId,
         raw_symbol: Symbol,
         event_type_id: u64,
@@ -69,8 +71,9 @@ impl BettingInstrument {
         min_notional: Option<Money>,
         max_price: Option<Price>,
         min_price: Option<Price>,
+        info: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        Self::new_checked(
+        let mut instrument = Self::new_checked(
             id,
             raw_symbol,
             event_type_id,
@@ -105,7 +108,9 @@ impl BettingInstrument {
             ts_event.into(),
             ts_init.into(),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(to_pyvalue_err)?;
+        instrument.info = info_from_pydict(py, info)?;
+        Ok(instrument)
     }
 
     fn __hash__(&self) -> isize {
@@ -327,7 +332,7 @@ impl BettingInstrument {
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(PyDict::new_bound(py).into())
+        info_to_pydict(py, self.info)
     }
 
     #[getter]
@@ -379,7 +384,7 @@ impl BettingInstrument {
         dict.set_item("taker_fee", self.taker_fee.to_string())?;
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
-        dict.set_item("info", PyDict::new_bound(py))?;
+        dict.set_item("info", info_to_pydict(py, self.info)?)?;
         match self.max_quantity {
             Some(value) => dict.set_item("max_quantity", value.to_string())?,
             None => dict.set_item("max_quantity", py.None())?,
@@ -27,6 +27,7 @@ use crate::{
     enums::AssetClass,
     identifiers::{InstrumentId, Symbol},
     instruments::binary_option::BinaryOption,
+    python::instruments::{info_from_pydict, info_to_pydict},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -34,8 +35,9 @@ use crate::{
 impl BinaryOption {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, asset_class, currency, activation_ns, expiration_ns, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, ts_event, ts_init, outcome=None, description=None, margin_init=None, margin_maint=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None))]
+    #[pyo3(signature = (id, raw_symbol, asset_class, currency, activation_ns, expiration_ns, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, ts_event, ts_init, outcome=None, description=None, margin_init=None, margin_maint=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None, info=None))]
     fn py_new(
+        py: Python<'_>,
         id: InstrumentId,
         raw_symbol: Symbol,
         asset_class: AssetClass,
@@ -60,8 +62,9 @@ impl BinaryOption {
         min_notional: Option<Money>,
         max_price: Option<Price>,
         min_price: Option<Price>,
+        info: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        Self::new_checked(
+        let mut instrument = Self::new_checked(
             id,
             raw_symbol,
             asset_class,
@@ -87,7 +90,9 @@ impl BinaryOption {
             ts_event.into(),
             ts_init.into(),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(to_pyvalue_err)?;
+        instrument.info = info_from_pydict(py, info)?;
+        Ok(instrument)
     }
 
     fn __hash__(&self) -> isize {
@@ -249,7 +254,7 @@ impl BinaryOption {
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(PyDict::new_bound(py).into())
+        info_to_pydict(py, self.info)
     }
 
     #[getter]
@@ -284,7 +289,7 @@ impl BinaryOption {
         dict.set_item("size_precision", self.size_precision)?;
         dict.set_item("price_increment", self.price_increment.to_string())?;
         dict.set_item("size_increment", self.size_increment.to_string())?;
-        dict.set_item("info", PyDict::new_bound(py))?;
+        dict.set_item("info", info_to_pydict(py, self.info)?)?;
         dict.set_item("maker_fee", self.maker_fee.to_string())?;
         dict.set_item("taker_fee", self.taker_fee.to_string())?;
         dict.set_item("ts_event", self.ts_event.as_u64())?;
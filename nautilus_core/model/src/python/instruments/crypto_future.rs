@@ -25,6 +25,7 @@ use rust_decimal::Decimal;
 use crate::{
     identifiers::{InstrumentId, Symbol},
     instruments::crypto_future::CryptoFuture,
+    python::instruments::{info_from_pydict, info_to_pydict},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -32,8 +33,9 @@ use crate::{
 impl CryptoFuture {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, underlying, quote_currency, settlement_currency, is_inverse, activation_ns, expiration_ns, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, ts_event, ts_init, multiplier=None, lot_size=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None))]
+    #[pyo3(signature = (id, raw_symbol, underlying, quote_currency, settlement_currency, is_inverse, activation_ns, expiration_ns, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, ts_event, ts_init, multiplier=None, lot_size=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None, info=None))]
     fn py_new(
+        py: Python<'_>,
         id: InstrumentId,
         raw_symbol: Symbol,
         underlying: Currency,
@@ -60,8 +62,9 @@ impl CryptoFuture {
         min_notional: Option<Money>,
         max_price: Option<Price>,
         min_price: Option<Price>,
+        info: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        Self::new_checked(
+        let mut instrument = Self::new_checked(
             id,
             raw_symbol,
             underlying,
@@ -89,7 +92,9 @@ impl CryptoFuture {
             ts_event.into(),
             ts_init.into(),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(to_pyvalue_err)?;
+        instrument.info = info_from_pydict(py, info)?;
+        Ok(instrument)
     }
 
     fn __hash__(&self) -> isize {
@@ -257,7 +262,7 @@ impl CryptoFuture {
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(PyDict::new_bound(py).into())
+        info_to_pydict(py, self.info)
     }
 
     #[getter]
@@ -301,7 +306,7 @@ impl CryptoFuture {
         dict.set_item("margin_maint", self.margin_maint.to_string())?;
         dict.set_item("multiplier", self.multiplier.to_string())?;
         dict.set_item("lot_size", self.lot_size.to_string())?;
-        dict.set_item("info", PyDict::new_bound(py))?;
+        dict.set_item("info", info_to_pydict(py, self.info)?)?;
         dict.set_item("maker_fee", self.maker_fee.to_string())?;
         dict.set_item("taker_fee", self.taker_fee.to_string())?;
         dict.set_item("ts_event", self.ts_event.as_u64())?;
@@ -25,6 +25,7 @@ use rust_decimal::Decimal;
 use crate::{
     identifiers::{InstrumentId, Symbol},
     instruments::currency_pair::CurrencyPair,
+    python::instruments::{info_from_pydict, info_to_pydict},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -32,8 +33,9 @@ use crate::{
 impl CurrencyPair {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, base_currency, quote_currency, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, ts_event, ts_init, lot_size=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None))]
+    #[pyo3(signature = (id, raw_symbol, base_currency, quote_currency, price_precision, size_precision, price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, ts_event, ts_init, lot_size=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None, info=None))]
     fn py_new(
+        py: Python<'_>,
         id: InstrumentId,
         raw_symbol: Symbol,
         base_currency: Currency,
@@ -55,8 +57,9 @@ impl CurrencyPair {
         min_notional: Option<Money>,
         max_price: Option<Price>,
         min_price: Option<Price>,
+        info: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        Self::new_checked(
+        let mut instrument = Self::new_checked(
             id,
             raw_symbol,
             base_currency,
@@ -79,7 +82,9 @@ impl CurrencyPair {
             ts_event.into(),
             ts_init.into(),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(to_pyvalue_err)?;
+        instrument.info = info_from_pydict(py, info)?;
+        Ok(instrument)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -229,7 +234,7 @@ impl CurrencyPair {
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(PyDict::new_bound(py).into())
+        info_to_pydict(py, self.info)
     }
 
     #[staticmethod]
@@ -254,7 +259,7 @@ impl CurrencyPair {
         dict.set_item("taker_fee", self.taker_fee.to_string())?;
         dict.set_item("margin_init", self.margin_init.to_string())?;
         dict.set_item("margin_maint", self.margin_maint.to_string())?;
-        dict.set_item("info", PyDict::new_bound(py))?;
+        dict.set_item("info", info_to_pydict(py, self.info)?)?;
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
         match self.lot_size {
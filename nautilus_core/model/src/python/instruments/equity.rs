@@ -26,6 +26,7 @@ use ustr::Ustr;
 use crate::{
     identifiers::{InstrumentId, Symbol},
     instruments::equity::Equity,
+    python::instruments::{info_from_pydict, info_to_pydict},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
@@ -33,8 +34,9 @@ use crate::{
 impl Equity {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, currency, price_precision, price_increment, ts_event, ts_init, maker_fee=None, taker_fee=None, margin_init=None, margin_maint=None, isin=None, lot_size=None, max_quantity=None, min_quantity=None, max_price=None, min_price=None))]
+    #[pyo3(signature = (id, raw_symbol, currency, price_precision, price_increment, ts_event, ts_init, maker_fee=None, taker_fee=None, margin_init=None, margin_maint=None, isin=None, lot_size=None, max_quantity=None, min_quantity=None, max_price=None, min_price=None, figi=None, info=None))]
     fn py_new(
+        py: Python<'_>,
         id: InstrumentId,
         raw_symbol: Symbol,
         currency: Currency,
@@ -52,11 +54,14 @@ impl Equity {
         min_quantity: Option<Quantity>,
         max_price: Option<Price>,
         min_price: Option<Price>,
+        figi: Option<String>,
+        info: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        Self::new_checked(
+        let mut instrument = Self::new_checked(
             id,
             raw_symbol,
             isin.map(|x| Ustr::from(&x)),
+            figi.map(|x| Ustr::from(&x)),
             currency,
             price_precision,
             price_increment,
@@ -72,7 +77,9 @@ impl Equity {
             ts_event.into(),
             ts_init.into(),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(to_pyvalue_err)?;
+        instrument.info = info_from_pydict(py, info)?;
+        Ok(instrument)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -114,6 +121,12 @@ impl Equity {
         }
     }
 
+    #[getter]
+    #[pyo3(name = "figi")]
+    fn py_figi(&self) -> Option<&str> {
+        self.figi.map(|x| x.as_str())
+    }
+
     #[getter]
     #[pyo3(name = "quote_currency")] // TODO: Currency property standardization
     fn py_quote_currency(&self) -> Currency {
@@ -177,7 +190,7 @@ impl Equity {
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(PyDict::new_bound(py).into())
+        info_to_pydict(py, self.info)
     }
 
     #[staticmethod]
@@ -197,7 +210,7 @@ impl Equity {
         dict.set_item("price_increment", self.price_increment.to_string())?;
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
-        dict.set_item("info", PyDict::new_bound(py))?;
+        dict.set_item("info", info_to_pydict(py, self.info)?)?;
         dict.set_item("maker_fee", self.maker_fee.to_string())?;
         dict.set_item("taker_fee", self.taker_fee.to_string())?;
         dict.set_item("margin_init", self.margin_init.to_string())?;
@@ -206,6 +219,10 @@ impl Equity {
             Some(value) => dict.set_item("isin", value.to_string())?,
             None => dict.set_item("isin", py.None())?,
         }
+        match self.figi {
+            Some(value) => dict.set_item("figi", value.to_string())?,
+            None => dict.set_item("figi", py.None())?,
+        }
         match self.lot_size {
             Some(value) => dict.set_item("lot_size", value.to_string())?,
             None => dict.set_item("lot_size", py.None())?,
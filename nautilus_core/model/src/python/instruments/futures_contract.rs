@@ -27,6 +27,7 @@ use crate::{
     enums::AssetClass,
     identifiers::{InstrumentId, Symbol},
     instruments::futures_contract::FuturesContract,
+    python::instruments::{info_from_pydict, info_to_pydict},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
@@ -34,8 +35,9 @@ use crate::{
 impl FuturesContract {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, asset_class, underlying, activation_ns, expiration_ns, currency, price_precision, price_increment, multiplier, lot_size, ts_event, ts_init, margin_init=None, margin_maint=None, max_quantity=None, min_quantity=None, max_price=None, min_price=None, exchange=None))]
+    #[pyo3(signature = (id, raw_symbol, asset_class, underlying, activation_ns, expiration_ns, currency, price_precision, price_increment, multiplier, lot_size, ts_event, ts_init, margin_init=None, margin_maint=None, max_quantity=None, min_quantity=None, max_price=None, min_price=None, exchange=None, isin=None, figi=None, info=None))]
     fn py_new(
+        py: Python<'_>,
         id: InstrumentId,
         raw_symbol: Symbol,
         asset_class: AssetClass,
@@ -56,12 +58,17 @@ impl FuturesContract {
         max_price: Option<Price>,
         min_price: Option<Price>,
         exchange: Option<String>,
+        isin: Option<String>,
+        figi: Option<String>,
+        info: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        Self::new_checked(
+        let mut instrument = Self::new_checked(
             id,
             raw_symbol,
             asset_class,
             exchange.map(|e| Ustr::from(&e)),
+            isin.map(|x| Ustr::from(&x)),
+            figi.map(|x| Ustr::from(&x)),
             underlying.into(),
             activation_ns.into(),
             expiration_ns.into(),
@@ -79,7 +86,9 @@ impl FuturesContract {
             ts_event.into(),
             ts_init.into(),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(to_pyvalue_err)?;
+        instrument.info = info_from_pydict(py, info)?;
+        Ok(instrument)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -124,6 +133,18 @@ impl FuturesContract {
         self.exchange.map(|e| e.to_string())
     }
 
+    #[getter]
+    #[pyo3(name = "isin")]
+    fn py_isin(&self) -> Option<&str> {
+        self.isin.map(|x| x.as_str())
+    }
+
+    #[getter]
+    #[pyo3(name = "figi")]
+    fn py_figi(&self) -> Option<&str> {
+        self.figi.map(|x| x.as_str())
+    }
+
     #[getter]
     #[pyo3(name = "underlying")]
     fn py_underlying(&self) -> &str {
@@ -235,7 +256,7 @@ impl FuturesContract {
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
-        Ok(PyDict::new_bound(py).into())
+        info_to_pydict(py, self.info)
     }
 
     #[staticmethod]
@@ -263,7 +284,7 @@ impl FuturesContract {
         dict.set_item("lot_size", self.lot_size.to_string())?;
         dict.set_item("margin_init", self.margin_init.to_string())?;
         dict.set_item("margin_maint", self.margin_maint.to_string())?;
-        dict.set_item("info", PyDict::new_bound(py))?;
+        dict.set_item("info", info_to_pydict(py, self.info)?)?;
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
         match self.max_quantity {
@@ -286,6 +307,14 @@ impl FuturesContract {
             Some(value) => dict.set_item("exchange", value.to_string())?,
             None => dict.set_item("exchange", py.None())?,
         }
+        match self.isin {
+            Some(value) => dict.set_item("isin", value.to_string())?,
+            None => dict.set_item("isin", py.None())?,
+        }
+        match self.figi {
+            Some(value) => dict.set_item("figi", value.to_string())?,
+            None => dict.set_item("figi", py.None())?,
+        }
         Ok(dict.into())
     }
 }
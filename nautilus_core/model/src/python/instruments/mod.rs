@@ -16,36 +16,69 @@
 //! Instrument definitions the trading domain model.
 
 use nautilus_core::python::to_pyvalue_err;
-use pyo3::{IntoPy, PyObject, PyResult, Python};
+use pyo3::{prelude::*, types::PyDict, IntoPy, PyObject, PyResult, Python};
 
 use crate::instruments::{
     any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption,
-    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    commodity::Commodity, crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
+    currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
+    futures_spread::FuturesSpread, fx_forward::FxForward, info::InstrumentInfo, ndf::Ndf,
     options_contract::OptionsContract, options_spread::OptionsSpread,
 };
 
 pub mod betting;
 pub mod binary_option;
+pub mod commodity;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod fx_forward;
+pub mod ndf;
 pub mod options_contract;
 pub mod options_spread;
 
+/// Converts the instrument `info` to a Python dict (empty for no info).
+pub fn info_to_pydict(py: Python, info: Option<InstrumentInfo>) -> PyResult<PyObject> {
+    match info {
+        Some(info) => Ok(py
+            .import_bound("json")?
+            .call_method1("loads", (info.as_str(),))?
+            .into()),
+        None => Ok(PyDict::new_bound(py).into()),
+    }
+}
+
+/// Converts a Python dict of instrument `info` (if any) to [`InstrumentInfo`], where an empty
+/// dict is no info.
+pub fn info_from_pydict(py: Python, info: Option<Py<PyDict>>) -> PyResult<Option<InstrumentInfo>> {
+    let Some(info) = info.filter(|info| !info.bind(py).is_empty()) else {
+        return Ok(None);
+    };
+    let json: String = py
+        .import_bound("json")?
+        .call_method1("dumps", (info,))?
+        .extract()?;
+    InstrumentInfo::from_json(&json)
+        .map(Some)
+        .map_err(to_pyvalue_err)
+}
+
 pub fn instrument_any_to_pyobject(py: Python, instrument: InstrumentAny) -> PyResult<PyObject> {
     match instrument {
         InstrumentAny::Betting(inst) => Ok(inst.into_py(py)),
         InstrumentAny::BinaryOption(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::Commodity(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CryptoFuture(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CryptoPerpetual(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CurrencyPair(inst) => Ok(inst.into_py(py)),
         InstrumentAny::Equity(inst) => Ok(inst.into_py(py)),
         InstrumentAny::FuturesContract(inst) => Ok(inst.into_py(py)),
         InstrumentAny::FuturesSpread(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::FxForward(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::Ndf(inst) => Ok(inst.into_py(py)),
         InstrumentAny::OptionsContract(inst) => Ok(inst.into_py(py)),
         InstrumentAny::OptionsSpread(inst) => Ok(inst.into_py(py)),
     }
@@ -59,6 +92,9 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(BinaryOption) => Ok(InstrumentAny::BinaryOption(
             instrument.extract::<BinaryOption>(py)?,
         )),
+        stringify!(Commodity) => Ok(InstrumentAny::Commodity(
+            instrument.extract::<Commodity>(py)?,
+        )),
         stringify!(CryptoFuture) => Ok(InstrumentAny::CryptoFuture(
             instrument.extract::<CryptoFuture>(py)?,
         )),
@@ -75,6 +111,10 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(FuturesSpread) => Ok(InstrumentAny::FuturesSpread(
             instrument.extract::<FuturesSpread>(py)?,
         )),
+        stringify!(FxForward) => Ok(InstrumentAny::FxForward(
+            instrument.extract::<FxForward>(py)?,
+        )),
+        stringify!(Ndf) => Ok(InstrumentAny::Ndf(instrument.extract::<Ndf>(py)?)),
         stringify!(OptionsContract) => Ok(InstrumentAny::OptionsContract(
             instrument.extract::<OptionsContract>(py)?,
         )),
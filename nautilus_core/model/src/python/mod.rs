@@ -55,6 +55,7 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::data::quote::QuoteTick>()?;
     m.add_class::<crate::data::status::InstrumentStatus>()?;
     m.add_class::<crate::data::trade::TradeTick>()?;
+    m.add_class::<crate::python::data::stream::DataStream>()?;
     m.add_function(wrap_pyfunction!(
         crate::python::data::greeks::py_black_scholes_greeks,
         m
@@ -93,6 +94,7 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::enums::TradingState>()?;
     m.add_class::<crate::enums::TrailingOffsetType>()?;
     m.add_class::<crate::enums::TriggerType>()?;
+    m.add_class::<crate::enums::VenueRejectReason>()?;
     // Identifiers
     m.add_class::<crate::identifiers::AccountId>()?;
     m.add_class::<crate::identifiers::ClientId>()?;
@@ -122,18 +124,22 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Instruments
     m.add_class::<crate::instruments::betting::BettingInstrument>()?;
     m.add_class::<crate::instruments::binary_option::BinaryOption>()?;
+    m.add_class::<crate::instruments::commodity::Commodity>()?;
     m.add_class::<crate::instruments::crypto_future::CryptoFuture>()?;
     m.add_class::<crate::instruments::crypto_perpetual::CryptoPerpetual>()?;
     m.add_class::<crate::instruments::currency_pair::CurrencyPair>()?;
     m.add_class::<crate::instruments::equity::Equity>()?;
     m.add_class::<crate::instruments::futures_contract::FuturesContract>()?;
     m.add_class::<crate::instruments::futures_spread::FuturesSpread>()?;
+    m.add_class::<crate::instruments::fx_forward::FxForward>()?;
+    m.add_class::<crate::instruments::ndf::Ndf>()?;
     m.add_class::<crate::instruments::options_contract::OptionsContract>()?;
     m.add_class::<crate::instruments::options_spread::OptionsSpread>()?;
     m.add_class::<crate::instruments::synthetic::SyntheticInstrument>()?;
     // Order book
     m.add_class::<crate::orderbook::book::OrderBook>()?;
     m.add_class::<crate::orderbook::level::Level>()?;
+    m.add_class::<crate::python::orderbook::view::LevelsView>()?;
     m.add_function(wrap_pyfunction!(
         crate::python::orderbook::book::py_update_book_with_quote_tick,
         m
@@ -18,12 +18,17 @@ use pyo3::prelude::*;
 
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
-        quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth, OrderBookDepth10},
+        order::BookOrder,
+        quote::QuoteTick,
+        trade::TradeTick,
     },
     enums::{BookType, OrderSide},
     identifiers::InstrumentId,
     orderbook::{analysis::book_check_integrity, book::OrderBook, level::Level},
+    python::orderbook::view::LevelsView,
     types::{price::Price, quantity::Quantity},
 };
 
@@ -138,7 +143,7 @@ impl OrderBook {
 
     #[pyo3(name = "apply_depth")]
     fn py_apply_depth(&mut self, depth: &OrderBookDepth10) {
-        self.apply_depth(depth);
+        self.apply_depth(&OrderBookDepth::from(*depth));
     }
 
     #[pyo3(name = "check_integrity")]
@@ -188,6 +193,24 @@ impl OrderBook {
         self.midpoint()
     }
 
+    #[pyo3(name = "bids_view")]
+    #[pyo3(signature = (depth=None))]
+    fn py_bids_view(&self, depth: Option<usize>) -> LevelsView {
+        LevelsView::new(&self.bids_depth(depth))
+    }
+
+    #[pyo3(name = "asks_view")]
+    #[pyo3(signature = (depth=None))]
+    fn py_asks_view(&self, depth: Option<usize>) -> LevelsView {
+        LevelsView::new(&self.asks_depth(depth))
+    }
+
+    #[pyo3(name = "imbalance")]
+    #[pyo3(signature = (depth=1))]
+    fn py_imbalance(&self, depth: usize) -> Option<f64> {
+        self.imbalance(depth)
+    }
+
     #[pyo3(name = "get_avg_px_for_quantity")]
     fn py_get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         self.get_avg_px_for_quantity(qty, order_side)
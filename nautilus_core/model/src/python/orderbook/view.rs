@@ -0,0 +1,214 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A read-only view of order book levels exposed through the Python buffer protocol.
+//!
+//! A [`LevelsView`] holds the price and size of each level in a contiguous row-major array,
+//! which `numpy.asarray(view)` wraps as a `(num_levels, 2)` float64 array without copying.
+
+use std::{
+    ffi::{c_int, c_void, CStr},
+    ptr,
+};
+
+use pyo3::{exceptions::PyBufferError, ffi, prelude::*};
+
+/// The buffer protocol format string for a C `double`.
+const FORMAT_F64: &CStr = c"d";
+
+/// The number of columns per level (price and size).
+const NUM_COLUMNS: usize = 2;
+
+/// Represents a snapshot of order book levels as rows of `[price, size]`, best level first.
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.model", frozen)]
+#[derive(Clone, Debug)]
+pub struct LevelsView {
+    data: Vec<f64>,
+    shape: [ffi::Py_ssize_t; 2],
+    strides: [ffi::Py_ssize_t; 2],
+}
+
+impl LevelsView {
+    /// Creates a new [`LevelsView`] instance from the given `(price, size)` levels.
+    #[must_use]
+    pub fn new(levels: &[(f64, f64)]) -> Self {
+        let item_size = std::mem::size_of::<f64>() as ffi::Py_ssize_t;
+        Self {
+            data: levels
+                .iter()
+                .flat_map(|(price, size)| [*price, *size])
+                .collect(),
+            shape: [
+                levels.len() as ffi::Py_ssize_t,
+                NUM_COLUMNS as ffi::Py_ssize_t,
+            ],
+            strides: [item_size * NUM_COLUMNS as ffi::Py_ssize_t, item_size],
+        }
+    }
+
+    /// Returns the number of levels in the view.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len() / NUM_COLUMNS
+    }
+
+    /// Returns whether the view has no levels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the price of each level.
+    #[must_use]
+    pub fn prices(&self) -> Vec<f64> {
+        self.data.iter().step_by(NUM_COLUMNS).copied().collect()
+    }
+
+    /// Returns the size of each level.
+    #[must_use]
+    pub fn sizes(&self) -> Vec<f64> {
+        self.data
+            .iter()
+            .skip(1)
+            .step_by(NUM_COLUMNS)
+            .copied()
+            .collect()
+    }
+}
+
+#[pymethods]
+impl LevelsView {
+    fn __repr__(&self) -> String {
+        format!("LevelsView(len={})", self.len())
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    #[getter]
+    #[pyo3(name = "prices")]
+    fn py_prices(&self) -> Vec<f64> {
+        self.prices()
+    }
+
+    #[getter]
+    #[pyo3(name = "sizes")]
+    fn py_sizes(&self) -> Vec<f64> {
+        self.sizes()
+    }
+
+    #[pyo3(name = "to_list")]
+    fn py_to_list(&self) -> Vec<(f64, f64)> {
+        self.data
+            .chunks_exact(NUM_COLUMNS)
+            .map(|row| (row[0], row[1]))
+            .collect()
+    }
+
+    /// Fills the buffer `view` with the levels, which are exported read-only.
+    ///
+    /// # Safety
+    ///
+    /// Called by the Python interpreter with a valid `view` pointer.
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("View is null"));
+        }
+        if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("LevelsView is read-only"));
+        }
+
+        let this = slf.get();
+        let view = &mut *view;
+        view.buf = this.data.as_ptr() as *mut c_void;
+        view.len = (this.data.len() * std::mem::size_of::<f64>()) as ffi::Py_ssize_t;
+        view.readonly = 1;
+        view.itemsize = std::mem::size_of::<f64>() as ffi::Py_ssize_t;
+        view.format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            FORMAT_F64.as_ptr().cast_mut()
+        } else {
+            ptr::null_mut()
+        };
+        view.ndim = NUM_COLUMNS as c_int;
+        view.shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            this.shape.as_ptr().cast_mut()
+        } else {
+            ptr::null_mut()
+        };
+        view.strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            this.strides.as_ptr().cast_mut()
+        } else {
+            ptr::null_mut()
+        };
+        view.suboffsets = ptr::null_mut();
+        view.internal = ptr::null_mut();
+        // The buffer holds a reference to the view, keeping the data alive until released
+        view.obj = slf.into_any().into_ptr();
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{buffer::PyBuffer, Python};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_levels_view() {
+        let view = LevelsView::new(&[(1.0, 3.0), (0.9, 1.0)]);
+
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.prices(), vec![1.0, 0.9]);
+        assert_eq!(view.sizes(), vec![3.0, 1.0]);
+        assert_eq!(view.py_to_list(), vec![(1.0, 3.0), (0.9, 1.0)]);
+    }
+
+    #[rstest]
+    fn test_levels_view_buffer() {
+        pyo3::prepare_freethreaded_python();
+        let view = LevelsView::new(&[(1.0, 3.0), (0.9, 1.0), (0.8, 2.0)]);
+
+        Python::with_gil(|py| {
+            let view = Bound::new(py, view).unwrap();
+            let buffer = PyBuffer::<f64>::get_bound(view.as_any()).unwrap();
+
+            assert!(buffer.readonly());
+            assert_eq!(buffer.dimensions(), 2);
+            assert_eq!(buffer.shape(), &[3, 2]);
+            assert!(buffer.is_c_contiguous());
+            assert_eq!(
+                buffer.to_vec(py).unwrap(),
+                vec![1.0, 3.0, 0.9, 1.0, 0.8, 2.0]
+            );
+            assert_eq!(
+                buffer.buf_ptr().cast_const(),
+                view.get().data.as_ptr().cast::<c_void>()
+            );
+        });
+    }
+}
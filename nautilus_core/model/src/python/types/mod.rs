@@ -20,3 +20,29 @@ pub mod currency;
 pub mod money;
 pub mod price;
 pub mod quantity;
+
+use pyo3::{prelude::*, types::PyFloat};
+use rust_decimal::Decimal;
+
+/// Extracts the given Python `value` as a `Decimal` unless it is a `float`.
+///
+/// Returns `None` for a `float`, which has no exact decimal value and is converted via `f64`,
+/// while `int` and `decimal.Decimal` values are converted exactly.
+pub(crate) fn extract_exact_decimal(value: &Bound<'_, PyAny>) -> PyResult<Option<Decimal>> {
+    if value.is_instance_of::<PyFloat>() {
+        return Ok(None);
+    }
+    value.extract::<Decimal>().map(Some)
+}
+
+/// Formats the given `value` with a Python `format_spec`, as for a `decimal.Decimal`.
+pub(crate) fn format_decimal(
+    py: Python<'_>,
+    value: Decimal,
+    format_spec: &str,
+) -> PyResult<String> {
+    value
+        .into_py(py)
+        .call_method1(py, "__format__", (format_spec,))?
+        .extract(py)
+}
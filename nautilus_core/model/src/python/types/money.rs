@@ -29,13 +29,18 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
+use super::{extract_exact_decimal, format_decimal};
 use crate::types::{currency::Currency, money::Money};
 
 #[pymethods]
 impl Money {
     #[new]
-    fn py_new(amount: f64, currency: Currency) -> PyResult<Self> {
-        Self::new_checked(amount, currency).map_err(to_pyvalue_err)
+    fn py_new(amount: &Bound<'_, PyAny>, currency: Currency) -> PyResult<Self> {
+        match extract_exact_decimal(amount)? {
+            Some(amount) => Self::from_decimal(amount, currency),
+            None => Self::new_checked(amount.extract()?, currency),
+        }
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, state: &Bound<'_, PyAny>) -> PyResult<()> {
@@ -327,6 +332,15 @@ impl Money {
         self.to_string()
     }
 
+    /// Formats the amount with the given `format_spec`, followed by the currency code.
+    fn __format__(&self, py: Python, format_spec: &str) -> PyResult<String> {
+        if format_spec.is_empty() {
+            return Ok(self.to_string());
+        }
+        let amount = format_decimal(py, self.as_decimal(), format_spec)?;
+        Ok(format!("{amount} {}", self.currency))
+    }
+
     #[getter]
     fn raw(&self) -> i64 {
         self.raw
@@ -381,3 +395,35 @@ impl ToPyObject for Money {
         self.into_py(py)
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_py_new_from_decimal() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let amount = Decimal::new(1_234_567, 3).into_py(py);
+            let money = Money::py_new(amount.bind(py), Currency::USD()).unwrap();
+
+            assert_eq!(money, Money::from("1234.57 USD"));
+        });
+    }
+
+    #[rstest]
+    fn test_format() {
+        pyo3::prepare_freethreaded_python();
+        let money = Money::from("1234.5 USD");
+
+        Python::with_gil(|py| {
+            assert_eq!(money.__format__(py, "").unwrap(), "1234.50 USD");
+            assert_eq!(money.__format__(py, ",.1f").unwrap(), "1,234.5 USD");
+        });
+    }
+}
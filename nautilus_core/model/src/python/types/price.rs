@@ -28,13 +28,18 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
+use super::{extract_exact_decimal, format_decimal};
 use crate::types::{fixed::fixed_i64_to_f64, price::Price};
 
 #[pymethods]
 impl Price {
     #[new]
-    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Self::new_checked(value, precision).map_err(to_pyvalue_err)
+    fn py_new(value: &Bound<'_, PyAny>, precision: u8) -> PyResult<Self> {
+        match extract_exact_decimal(value)? {
+            Some(value) => Self::from_decimal(value, precision),
+            None => Self::new_checked(value.extract()?, precision),
+        }
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, state: &Bound<'_, PyAny>) -> PyResult<()> {
@@ -321,6 +326,13 @@ impl Price {
         self.to_string()
     }
 
+    fn __format__(&self, py: Python, format_spec: &str) -> PyResult<String> {
+        if format_spec.is_empty() {
+            return Ok(self.to_string());
+        }
+        format_decimal(py, self.as_decimal(), format_spec)
+    }
+
     #[getter]
     fn raw(&self) -> i64 {
         self.raw
@@ -381,3 +393,44 @@ impl Price {
         self.to_formatted_string()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_py_new_from_decimal_and_float() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let decimal = Decimal::from_str("1.005").unwrap().into_py(py);
+            let from_decimal = Price::py_new(decimal.bind(py), 2).unwrap();
+            let from_float = Price::py_new(1.25_f64.into_py(py).bind(py), 2).unwrap();
+
+            assert_eq!(from_decimal, Price::from("1.01"));
+            assert_eq!(
+                from_decimal.py_as_decimal(),
+                Decimal::from_str("1.01").unwrap()
+            );
+            assert_eq!(from_float, Price::from("1.25"));
+        });
+    }
+
+    #[rstest]
+    #[case("", "1.0050")]
+    #[case(".2f", "1.00")]
+    #[case(",.1f", "1.0")]
+    #[case(">8", "  1.0050")]
+    fn test_format(#[case] format_spec: &str, #[case] expected: &str) {
+        pyo3::prepare_freethreaded_python();
+        let price = Price::from("1.0050");
+
+        Python::with_gil(|py| {
+            assert_eq!(price.__format__(py, format_spec).unwrap(), expected);
+        });
+    }
+}
@@ -27,13 +27,18 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
+use super::{extract_exact_decimal, format_decimal};
 use crate::types::quantity::Quantity;
 
 #[pymethods]
 impl Quantity {
     #[new]
-    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Self::new_checked(value, precision).map_err(to_pyvalue_err)
+    fn py_new(value: &Bound<'_, PyAny>, precision: u8) -> PyResult<Self> {
+        match extract_exact_decimal(value)? {
+            Some(value) => Self::from_decimal(value, precision),
+            None => Self::new_checked(value.extract()?, precision),
+        }
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, state: &Bound<'_, PyAny>) -> PyResult<()> {
@@ -320,6 +325,13 @@ impl Quantity {
         self.to_string()
     }
 
+    fn __format__(&self, py: Python, format_spec: &str) -> PyResult<String> {
+        if format_spec.is_empty() {
+            return Ok(self.to_string());
+        }
+        format_decimal(py, self.as_decimal(), format_spec)
+    }
+
     #[getter]
     fn raw(&self) -> u64 {
         self.raw
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tick schemes for instruments with price-dependent tick sizes.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use nautilus_core::correctness::{check_positive_i64, check_predicate_true, FAILED};
+use once_cell::sync::Lazy;
+use ustr::Ustr;
+
+use crate::types::{
+    fixed::{f64_to_fixed_i64, FIXED_PRECISION},
+    price::Price,
+};
+
+/// The name of the built-in Betfair odds ladder tick scheme.
+pub const BETFAIR_TICK_SCHEME: &str = "BETFAIR";
+
+/// The Betfair odds ladder tiers as `(start, stop, increment)`, where `stop` is exclusive.
+pub const BETFAIR_PRICE_TIERS: [(f64, f64, f64); 10] = [
+    (1.01, 2.0, 0.01),
+    (2.0, 3.0, 0.02),
+    (3.0, 4.0, 0.05),
+    (4.0, 6.0, 0.1),
+    (6.0, 10.0, 0.2),
+    (10.0, 20.0, 0.5),
+    (20.0, 30.0, 1.0),
+    (30.0, 50.0, 2.0),
+    (50.0, 100.0, 5.0),
+    (100.0, 1010.0, 10.0),
+];
+
+/// Represents a scheme of valid prices for an instrument.
+pub trait TickScheme: Send + Sync {
+    /// Returns the price `n` ticks below the nearest valid bid price at or below `value`,
+    /// or `None` if outside the range of the scheme.
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price>;
+
+    /// Returns the price `n` ticks above the nearest valid ask price at or above `value`,
+    /// or `None` if outside the range of the scheme.
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price>;
+}
+
+/// A rule-based tick scheme where all prices are multiples of a fixed increment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedTickScheme {
+    pub increment: Price,
+    pub min_price: Option<Price>,
+    pub max_price: Option<Price>,
+}
+
+impl FixedTickScheme {
+    /// Creates a new [`FixedTickScheme`] instance with correctness checking.
+    pub fn new_checked(
+        increment: Price,
+        min_price: Option<Price>,
+        max_price: Option<Price>,
+    ) -> anyhow::Result<Self> {
+        check_positive_i64(increment.raw, stringify!(increment.raw))?;
+
+        Ok(Self {
+            increment,
+            min_price,
+            max_price,
+        })
+    }
+
+    /// Creates a new [`FixedTickScheme`] instance.
+    #[must_use]
+    pub fn new(increment: Price, min_price: Option<Price>, max_price: Option<Price>) -> Self {
+        Self::new_checked(increment, min_price, max_price).expect(FAILED)
+    }
+
+    fn make_price(&self, raw: i64) -> Option<Price> {
+        let price = Price::from_raw(raw, self.increment.precision);
+        if self.min_price.is_some_and(|min| price < min)
+            || self.max_price.is_some_and(|max| price > max)
+        {
+            return None;
+        }
+        Some(price)
+    }
+}
+
+impl TickScheme for FixedTickScheme {
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price> {
+        let step = self.increment.raw;
+        let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+        let base = raw.div_euclid(step) * step;
+        self.make_price(base - step * n as i64)
+    }
+
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price> {
+        let step = self.increment.raw;
+        let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+        let base = -(-raw).div_euclid(step) * step;
+        self.make_price(base + step * n as i64)
+    }
+}
+
+/// A table-driven tick scheme where the increment varies between price tiers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TieredTickScheme {
+    pub ticks: Vec<Price>,
+}
+
+impl TieredTickScheme {
+    /// Creates a new [`TieredTickScheme`] instance with correctness checking.
+    ///
+    /// Each tier is given as `(start, stop, increment)` where `stop` is exclusive, and tiers
+    /// must be in ascending price order.
+    pub fn new_checked(tiers: &[(f64, f64, f64)], price_precision: u8) -> anyhow::Result<Self> {
+        let mut ticks: Vec<Price> = Vec::new();
+        for &(start, stop, increment) in tiers {
+            let start = Price::new_checked(start, price_precision)?;
+            let stop = Price::new_checked(stop, price_precision)?;
+            let increment = Price::new_checked(increment, price_precision)?;
+            check_positive_i64(increment.raw, stringify!(increment.raw))?;
+            check_predicate_true(start < stop, "tier start was not less than stop")?;
+            check_predicate_true(
+                ticks.last().is_none_or(|last| *last < start),
+                "tiers were not in ascending order",
+            )?;
+
+            let mut raw = start.raw;
+            while raw < stop.raw {
+                ticks.push(Price::from_raw(raw, price_precision));
+                raw += increment.raw;
+            }
+        }
+
+        Ok(Self { ticks })
+    }
+
+    /// Creates a new [`TieredTickScheme`] instance.
+    #[must_use]
+    pub fn new(tiers: &[(f64, f64, f64)], price_precision: u8) -> Self {
+        Self::new_checked(tiers, price_precision).expect(FAILED)
+    }
+
+    /// Returns the lowest price of the scheme.
+    #[must_use]
+    pub fn min_price(&self) -> Option<Price> {
+        self.ticks.first().copied()
+    }
+
+    /// Returns the highest price of the scheme.
+    #[must_use]
+    pub fn max_price(&self) -> Option<Price> {
+        self.ticks.last().copied()
+    }
+}
+
+impl TickScheme for TieredTickScheme {
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price> {
+        let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+        let count = self.ticks.partition_point(|tick| tick.raw <= raw);
+        count
+            .checked_sub(1 + n)
+            .and_then(|idx| self.ticks.get(idx))
+            .copied()
+    }
+
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price> {
+        let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+        let idx = self.ticks.partition_point(|tick| tick.raw < raw);
+        self.ticks.get(idx + n).copied()
+    }
+}
+
+pub static TICK_SCHEMES: Lazy<Mutex<HashMap<Ustr, Arc<dyn TickScheme>>>> = Lazy::new(|| {
+    let mut map: HashMap<Ustr, Arc<dyn TickScheme>> = HashMap::new();
+    map.insert(
+        Ustr::from(BETFAIR_TICK_SCHEME),
+        Arc::new(TieredTickScheme::new(&BETFAIR_PRICE_TIERS, 2)),
+    );
+    Mutex::new(map)
+});
+
+/// Registers the given tick `scheme` under `name`, replacing any existing scheme of that name.
+pub fn register_tick_scheme(name: &str, scheme: Arc<dyn TickScheme>) {
+    TICK_SCHEMES
+        .lock()
+        .unwrap()
+        .insert(Ustr::from(name), scheme);
+}
+
+/// Returns the tick scheme registered under `name` (if found).
+#[must_use]
+pub fn get_tick_scheme(name: &Ustr) -> Option<Arc<dyn TickScheme>> {
+    TICK_SCHEMES.lock().unwrap().get(name).cloned()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1.015, 0, Some("1.01"))]
+    #[case(1.01, 0, Some("1.01"))]
+    #[case(1.01, 1, Some("1.00"))]
+    #[case(1.01, 2, None)]
+    fn test_fixed_next_bid_price(
+        #[case] value: f64,
+        #[case] n: usize,
+        #[case] expected: Option<&str>,
+    ) {
+        let scheme = FixedTickScheme::new(Price::from("0.01"), Some(Price::from("1.00")), None);
+        assert_eq!(scheme.next_bid_price(value, n), expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[case(1.015, 0, Some("1.02"))]
+    #[case(1.02, 0, Some("1.02"))]
+    #[case(1.02, 3, Some("1.05"))]
+    #[case(1.99, 2, None)]
+    fn test_fixed_next_ask_price(
+        #[case] value: f64,
+        #[case] n: usize,
+        #[case] expected: Option<&str>,
+    ) {
+        let scheme = FixedTickScheme::new(Price::from("0.01"), None, Some(Price::from("2.00")));
+        assert_eq!(scheme.next_ask_price(value, n), expected.map(Price::from));
+    }
+
+    #[rstest]
+    fn test_tiered_scheme_bounds() {
+        let scheme = TieredTickScheme::new(&BETFAIR_PRICE_TIERS, 2);
+        assert_eq!(scheme.min_price(), Some(Price::from("1.01")));
+        assert_eq!(scheme.max_price(), Some(Price::from("1000.00")));
+        assert_eq!(scheme.ticks.len(), 350);
+    }
+
+    #[rstest]
+    #[case(1.999, 0, Some("1.99"))]
+    #[case(2.01, 0, Some("2.00"))]
+    #[case(2.01, 1, Some("1.99"))]
+    #[case(3.0, 2, Some("2.96"))]
+    #[case(1.01, 1, None)]
+    #[case(1.0, 0, None)]
+    fn test_tiered_next_bid_price(
+        #[case] value: f64,
+        #[case] n: usize,
+        #[case] expected: Option<&str>,
+    ) {
+        let scheme = TieredTickScheme::new(&BETFAIR_PRICE_TIERS, 2);
+        assert_eq!(scheme.next_bid_price(value, n), expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[case(1.999, 0, Some("2.00"))]
+    #[case(2.01, 0, Some("2.02"))]
+    #[case(1.99, 2, Some("2.02"))]
+    #[case(9.9, 0, Some("10.00"))]
+    #[case(9.9, 1, Some("10.50"))]
+    #[case(1000.0, 0, Some("1000.00"))]
+    #[case(1000.0, 1, None)]
+    fn test_tiered_next_ask_price(
+        #[case] value: f64,
+        #[case] n: usize,
+        #[case] expected: Option<&str>,
+    ) {
+        let scheme = TieredTickScheme::new(&BETFAIR_PRICE_TIERS, 2);
+        assert_eq!(scheme.next_ask_price(value, n), expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "tiers were not in ascending order")]
+    fn test_tiered_scheme_with_overlapping_tiers() {
+        let _ = TieredTickScheme::new(&[(1.0, 2.0, 0.1), (1.5, 3.0, 0.5)], 1);
+    }
+
+    #[rstest]
+    fn test_registry() {
+        let scheme = FixedTickScheme::new(Price::from("0.25"), None, None);
+        register_tick_scheme("TEST_QUARTERS", Arc::new(scheme));
+
+        let registered = get_tick_scheme(&Ustr::from("TEST_QUARTERS")).unwrap();
+        assert_eq!(
+            registered.next_ask_price(100.1, 0),
+            Some(Price::from("100.25"))
+        );
+        assert!(get_tick_scheme(&Ustr::from(BETFAIR_TICK_SCHEME)).is_some());
+        assert!(get_tick_scheme(&Ustr::from("UNKNOWN")).is_none());
+    }
+}
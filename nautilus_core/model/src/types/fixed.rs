@@ -18,12 +18,17 @@
 //! This module provides constants and functions that enforce a fixed-point precision strategy,
 //! ensuring consistent precision and scaling across various types and calculations.
 
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
 /// The maximum fixed-point precision.
 pub const FIXED_PRECISION: u8 = 9;
 
 /// The scalar value corresponding to the maximum precision (10^9).
 pub const FIXED_SCALAR: f64 = 1_000_000_000.0; // 10.0**FIXED_PRECISION
 
+/// The number of values converted per unrolled step of the batch conversion functions.
+const BATCH_LANES: usize = 8;
+
 /// Checks if a given `precision` value is within the allowed fixed-point precision range.
 ///
 /// # Errors
@@ -67,6 +72,47 @@ pub fn f64_to_fixed_u64(value: f64, precision: u8) -> u64 {
     rounded * pow2
 }
 
+/// Converts a `Decimal` value to a raw fixed-point `i64` representation with a specified precision.
+///
+/// The value is rounded to `precision` decimal places (half away from zero, as for `f64` values)
+/// without passing through a floating-point representation.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `precision` exceeds `FIXED_PRECISION`.
+/// - If the scaled value cannot be represented as an `i64`.
+pub fn decimal_to_fixed_i64(value: Decimal, precision: u8) -> anyhow::Result<i64> {
+    check_fixed_precision(precision)?;
+    scale_decimal(value, precision)
+        .and_then(|scaled| scaled.to_i64())
+        .ok_or_else(|| anyhow::anyhow!("Decimal value {value} is not representable as `i64`"))
+}
+
+/// Converts a `Decimal` value to a raw fixed-point `u64` representation with a specified precision.
+///
+/// The value is rounded to `precision` decimal places (half away from zero, as for `f64` values)
+/// without passing through a floating-point representation.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `precision` exceeds `FIXED_PRECISION`.
+/// - If the scaled value cannot be represented as a `u64`.
+pub fn decimal_to_fixed_u64(value: Decimal, precision: u8) -> anyhow::Result<u64> {
+    check_fixed_precision(precision)?;
+    scale_decimal(value, precision)
+        .and_then(|scaled| scaled.to_u64())
+        .ok_or_else(|| anyhow::anyhow!("Decimal value {value} is not representable as `u64`"))
+}
+
+/// Rounds `value` to `precision` decimal places then scales it to an integral fixed-point value.
+fn scale_decimal(value: Decimal, precision: u8) -> Option<Decimal> {
+    value
+        .round_dp_with_strategy(u32::from(precision), RoundingStrategy::MidpointAwayFromZero)
+        .checked_mul(Decimal::from(10_u64.pow(u32::from(FIXED_PRECISION))))
+}
+
 /// Converts a raw fixed-point `i64` value back to an `f64` value.
 #[must_use]
 pub fn fixed_i64_to_f64(value: i64) -> f64 {
@@ -79,6 +125,76 @@ pub fn fixed_u64_to_f64(value: u64) -> f64 {
     (value as f64) / FIXED_SCALAR
 }
 
+/// Converts a slice of `f64` values to raw fixed-point `i64` representations with a specified
+/// precision.
+///
+/// Produces the same results as [`f64_to_fixed_i64`] for each value, with the loop unrolled into
+/// fixed size lanes so the compiler can vectorize the conversion.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `precision` exceeds `FIXED_PRECISION`.
+#[must_use]
+pub fn f64_slice_to_fixed_i64(values: &[f64], precision: u8) -> Vec<i64> {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    let pow1 = 10_i64.pow(u32::from(precision)) as f64;
+    let pow2 = 10_i64.pow(u32::from(FIXED_PRECISION - precision));
+    batch_convert(values, |value| (value * pow1).round() as i64 * pow2)
+}
+
+/// Converts a slice of `f64` values to raw fixed-point `u64` representations with a specified
+/// precision.
+///
+/// Produces the same results as [`f64_to_fixed_u64`] for each value, with the loop unrolled into
+/// fixed size lanes so the compiler can vectorize the conversion.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `precision` exceeds `FIXED_PRECISION`.
+#[must_use]
+pub fn f64_slice_to_fixed_u64(values: &[f64], precision: u8) -> Vec<u64> {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    let pow1 = 10_u64.pow(u32::from(precision)) as f64;
+    let pow2 = 10_u64.pow(u32::from(FIXED_PRECISION - precision));
+    batch_convert(values, |value| (value * pow1).round() as u64 * pow2)
+}
+
+/// Converts a slice of raw fixed-point `i64` values back to `f64` values.
+#[must_use]
+pub fn fixed_i64_slice_to_f64(values: &[i64]) -> Vec<f64> {
+    batch_convert(values, |value| (value as f64) / FIXED_SCALAR)
+}
+
+/// Converts a slice of raw fixed-point `u64` values back to `f64` values.
+#[must_use]
+pub fn fixed_u64_slice_to_f64(values: &[u64]) -> Vec<f64> {
+    batch_convert(values, |value| (value as f64) / FIXED_SCALAR)
+}
+
+#[inline(always)]
+fn batch_convert<T: Copy, U: Copy + Default>(values: &[T], convert: impl Fn(T) -> U) -> Vec<U> {
+    let mut result = vec![U::default(); values.len()];
+    let mut src_chunks = values.chunks_exact(BATCH_LANES);
+    let mut dst_chunks = result.chunks_exact_mut(BATCH_LANES);
+
+    for (src, dst) in src_chunks.by_ref().zip(dst_chunks.by_ref()) {
+        for lane in 0..BATCH_LANES {
+            dst[lane] = convert(src[lane]);
+        }
+    }
+    for (src, dst) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *dst = convert(*src);
+    }
+
+    result
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -222,4 +338,89 @@ mod tests {
         let result = fixed_u64_to_f64(value);
         assert_eq!(result, (value as f64) / FIXED_SCALAR);
     }
+
+    #[rstest]
+    fn test_f64_slice_to_fixed_i64_matches_scalar(
+        #[values(0, 2, 9)] precision: u8,
+        #[values(0, 7, 8, 17)] len: usize,
+    ) {
+        let values: Vec<f64> = (0..len).map(|i| (i as f64 - 8.0) * 1.234_567_891).collect();
+        let expected: Vec<i64> = values
+            .iter()
+            .map(|value| f64_to_fixed_i64(*value, precision))
+            .collect();
+        assert_eq!(f64_slice_to_fixed_i64(&values, precision), expected);
+    }
+
+    #[rstest]
+    fn test_f64_slice_to_fixed_u64_matches_scalar(
+        #[values(0, 2, 9)] precision: u8,
+        #[values(0, 7, 8, 17)] len: usize,
+    ) {
+        let values: Vec<f64> = (0..len).map(|i| i as f64 * 1.234_567_891).collect();
+        let expected: Vec<u64> = values
+            .iter()
+            .map(|value| f64_to_fixed_u64(*value, precision))
+            .collect();
+        assert_eq!(f64_slice_to_fixed_u64(&values, precision), expected);
+    }
+
+    #[rstest]
+    fn test_fixed_slice_to_f64_matches_scalar(#[values(0, 7, 8, 17)] len: usize) {
+        let signed: Vec<i64> = (0..len as i64).map(|i| (i - 8) * 123_456_789).collect();
+        let unsigned: Vec<u64> = (0..len as u64).map(|i| i * 123_456_789).collect();
+        assert_eq!(
+            fixed_i64_slice_to_f64(&signed),
+            signed
+                .iter()
+                .map(|v| fixed_i64_to_f64(*v))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            fixed_u64_slice_to_f64(&unsigned),
+            unsigned
+                .iter()
+                .map(|v| fixed_u64_to_f64(*v))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "precision exceeded maximum 9")]
+    fn test_f64_slice_to_fixed_i64_with_invalid_precision() {
+        let _ = f64_slice_to_fixed_i64(&[1.0], FIXED_PRECISION + 1);
+    }
+
+    #[rstest]
+    #[case("0.1", 1, 100_000_000)]
+    #[case("-1.25", 1, -1_300_000_000)]
+    #[case("1.005", 2, 1_010_000_000)]
+    #[case("9223372036.854775807", 9, 9_223_372_036_854_775_807)]
+    fn test_decimal_to_fixed_i64(
+        #[case] value: &str,
+        #[case] precision: u8,
+        #[case] expected: i64,
+    ) {
+        let value = Decimal::from_str_exact(value).unwrap();
+        assert_eq!(decimal_to_fixed_i64(value, precision).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("0.1", 1, 100_000_000)]
+    #[case("123456789.123456789", 9, 123_456_789_123_456_789)]
+    fn test_decimal_to_fixed_u64(
+        #[case] value: &str,
+        #[case] precision: u8,
+        #[case] expected: u64,
+    ) {
+        let value = Decimal::from_str_exact(value).unwrap();
+        assert_eq!(decimal_to_fixed_u64(value, precision).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_decimal_to_fixed_errors() {
+        assert!(decimal_to_fixed_i64(Decimal::ONE, FIXED_PRECISION + 1).is_err());
+        assert!(decimal_to_fixed_i64(Decimal::from(10_000_000_000_i64), 0).is_err());
+        assert!(decimal_to_fixed_u64(Decimal::NEGATIVE_ONE, 0).is_err());
+    }
 }
@@ -0,0 +1,252 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Represents user metadata attached to orders, such as the signal or algorithm which created
+//! them, which is carried onto their fills, positions and reports.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
+
+use anyhow::{bail, ensure};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ustr::Ustr;
+
+const ENTRY_SEPARATOR: char = ',';
+const KEY_VALUE_SEPARATOR: char = '=';
+
+/// Represents an immutable map of string keys to string values, e.g. `algo=twap,signal=momentum`.
+///
+/// Entries are held sorted by key in an interned string, so metadata is cheap to copy onto every
+/// fill of an order. Keys must be non-empty, and keys and values must not contain `,` or `=`.
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OrderMetadata(Ustr);
+
+impl OrderMetadata {
+    /// Creates a new [`OrderMetadata`] instance from the given `entries`, with later entries
+    /// replacing earlier entries for the same key.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a key is empty, or a key or value contains `,` or `=`.
+    pub fn new<K, V>(entries: impl IntoIterator<Item = (K, V)>) -> anyhow::Result<Self>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut map = BTreeMap::new();
+        for (key, value) in entries {
+            let (key, value) = (key.as_ref(), value.as_ref());
+            check_entry(key, value)?;
+            map.insert(key.to_string(), value.to_string());
+        }
+        Ok(Self::from_sorted(map.iter()))
+    }
+
+    fn from_sorted<'a>(entries: impl Iterator<Item = (&'a String, &'a String)>) -> Self {
+        let text = entries
+            .map(|(key, value)| format!("{key}{KEY_VALUE_SEPARATOR}{value}"))
+            .collect::<Vec<_>>()
+            .join(&ENTRY_SEPARATOR.to_string());
+        Self(Ustr::from(&text))
+    }
+
+    /// Returns the metadata as a string of `key=value` entries separated by `,`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns if the metadata has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns the entries in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.as_str()
+            .split(ENTRY_SEPARATOR)
+            .filter_map(|entry| entry.split_once(KEY_VALUE_SEPARATOR))
+    }
+
+    /// Returns the value for the given `key`, if any.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+
+    /// Returns a copy of the metadata with the `key` set to `value`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the key is empty, or the key or value contains `,`
+    /// or `=`.
+    pub fn with(&self, key: &str, value: &str) -> anyhow::Result<Self> {
+        Self::new(self.iter().chain([(key, value)]))
+    }
+
+    /// Returns the metadata extended with the entries of `other` for keys not already present.
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        if other.is_empty() || self == other {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+
+        let mut map: BTreeMap<String, String> = other
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        map.extend(
+            self.iter()
+                .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        Self::from_sorted(map.iter())
+    }
+}
+
+fn check_entry(key: &str, value: &str) -> anyhow::Result<()> {
+    ensure!(!key.is_empty(), "Metadata key was empty");
+    for text in [key, value] {
+        if text.contains([ENTRY_SEPARATOR, KEY_VALUE_SEPARATOR]) {
+            bail!("Metadata entry '{key}' contains '{ENTRY_SEPARATOR}' or '{KEY_VALUE_SEPARATOR}'");
+        }
+    }
+    Ok(())
+}
+
+impl FromStr for OrderMetadata {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut entries = Vec::new();
+        for entry in s.split(ENTRY_SEPARATOR) {
+            let Some((key, value)) = entry.split_once(KEY_VALUE_SEPARATOR) else {
+                bail!("Invalid metadata entry '{entry}', expected 'key=value'");
+            };
+            entries.push((key, value));
+        }
+        Self::new(entries)
+    }
+}
+
+impl Debug for OrderMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", stringify!(OrderMetadata), self.0)
+    }
+}
+
+impl Display for OrderMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for OrderMetadata {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderMetadata {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text: String = Deserialize::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_new_sorts_entries() {
+        let metadata =
+            OrderMetadata::new([("signal", "momentum"), ("algo", "twap"), ("signal", "mr")])
+                .unwrap();
+
+        assert_eq!(metadata.as_str(), "algo=twap,signal=mr");
+        assert_eq!(metadata.len(), 2);
+        assert_eq!(metadata.get("algo"), Some("twap"));
+        assert_eq!(metadata.get("missing"), None);
+        assert_eq!(
+            metadata,
+            OrderMetadata::from_str("signal=mr,algo=twap").unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_default_is_empty() {
+        let metadata = OrderMetadata::default();
+        assert!(metadata.is_empty());
+        assert_eq!(metadata.len(), 0);
+        assert_eq!(OrderMetadata::from_str("").unwrap(), metadata);
+    }
+
+    #[rstest]
+    #[case("", "x", "Metadata key was empty")]
+    #[case("a,b", "x", "Metadata entry 'a,b' contains ',' or '='")]
+    #[case("a", "x=y", "Metadata entry 'a' contains ',' or '='")]
+    fn test_invalid_entries(#[case] key: &str, #[case] value: &str, #[case] expected: &str) {
+        let err = OrderMetadata::new([(key, value)]).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_with_and_merge() {
+        let metadata = OrderMetadata::from_str("signal=momentum").unwrap();
+        let other = OrderMetadata::from_str("algo=twap,signal=mr").unwrap();
+
+        assert_eq!(
+            metadata.with("venue", "SIM").unwrap().as_str(),
+            "signal=momentum,venue=SIM"
+        );
+        assert_eq!(metadata.merge(&other).as_str(), "algo=twap,signal=momentum");
+        assert_eq!(OrderMetadata::default().merge(&other), other);
+    }
+
+    #[rstest]
+    fn test_serialization() {
+        let metadata = OrderMetadata::from_str("algo=twap").unwrap();
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(json, r#""algo=twap""#);
+        assert_eq!(
+            serde_json::from_str::<OrderMetadata>(&json).unwrap(),
+            metadata
+        );
+        assert!(serde_json::from_str::<OrderMetadata>(r#""algo""#).is_err());
+    }
+}
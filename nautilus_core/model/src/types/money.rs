@@ -24,14 +24,14 @@ use std::{
 };
 
 use nautilus_core::correctness::{check_in_range_inclusive_f64, FAILED};
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::FIXED_PRECISION;
 use crate::types::{
     currency::Currency,
-    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+    fixed::{decimal_to_fixed_i64, f64_to_fixed_i64, fixed_i64_to_f64},
 };
 
 /// The maximum valid money amount which can be represented.
@@ -79,6 +79,24 @@ impl Money {
         })
     }
 
+    /// Creates a new [`Money`] instance from a `Decimal` amount with correctness checking.
+    ///
+    /// Unlike [`Money::new_checked`] the amount is converted without a floating-point round trip.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `amount` is invalid outside the representable range [-9_223_372_036, 9_223_372_036].
+    pub fn from_decimal(amount: Decimal, currency: Currency) -> anyhow::Result<Self> {
+        let amount_f64 = amount.to_f64().unwrap_or(f64::NAN);
+        check_in_range_inclusive_f64(amount_f64, MONEY_MIN, MONEY_MAX, "amount")?;
+
+        Ok(Self {
+            raw: decimal_to_fixed_i64(amount, currency.precision)?,
+            currency,
+        })
+    }
+
     /// Creates a new [`Money`] instance.
     ///
     /// # Panics
@@ -363,7 +381,7 @@ impl<'de> Deserialize<'de> for Money {
         D: Deserializer<'de>,
     {
         let money_str: String = Deserialize::deserialize(deserializer)?;
-        Ok(Money::from(money_str.as_str()))
+        Self::from_str(&money_str).map_err(serde::de::Error::custom)
     }
 }
 
@@ -386,6 +404,14 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    fn test_from_decimal() {
+        let money = Money::from_decimal(dec!(1010.125), Currency::USD()).unwrap();
+        assert_eq!(money, Money::new(1010.13, Currency::USD()));
+        assert_eq!(money.as_decimal(), dec!(1010.13));
+        assert!(Money::from_decimal(dec!(-9223372037), Currency::USD()).is_err());
+    }
+
     #[rstest]
     fn test_display() {
         let money = Money::new(1010.12, Currency::USD());
@@ -27,12 +27,12 @@ use nautilus_core::{
     correctness::{check_in_range_inclusive_f64, FAILED},
     parsing::precision_from_str,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use crate::types::fixed::{decimal_to_fixed_i64, f64_to_fixed_i64, fixed_i64_to_f64};
 
 /// The sentinel value for an unset or null price.
 pub const PRICE_UNDEF: i64 = i64::MAX;
@@ -98,6 +98,25 @@ impl Price {
         })
     }
 
+    /// Creates a new [`Price`] instance from a `Decimal` value with correctness checking.
+    ///
+    /// Unlike [`Price::new_checked`] the value is converted without a floating-point round trip.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is invalid outside the representable range [-9_223_372_036, 9_223_372_036].
+    /// - If `precision` is invalid outside the representable range [0, 9].
+    pub fn from_decimal(value: Decimal, precision: u8) -> anyhow::Result<Self> {
+        let value_f64 = value.to_f64().unwrap_or(f64::NAN);
+        check_in_range_inclusive_f64(value_f64, PRICE_MIN, PRICE_MAX, "value")?;
+
+        Ok(Self {
+            raw: decimal_to_fixed_i64(value, precision)?,
+            precision,
+        })
+    }
+
     /// Creates a new [`Price`] instance.
     ///
     /// # Panics
@@ -482,6 +501,24 @@ mod tests {
         ));
     }
 
+    #[rstest]
+    fn test_from_decimal() {
+        let price = Price::from_decimal(dec!(1.005), 2).unwrap();
+        assert_eq!(price.raw, 1_010_000_000);
+        assert_eq!(price.precision, 2);
+        assert_eq!(price.as_decimal(), dec!(1.01));
+        assert_eq!(
+            Price::from_decimal(dec!(-0.1), 1).unwrap(),
+            Price::from("-0.1")
+        );
+    }
+
+    #[rstest]
+    fn test_from_decimal_out_of_range() {
+        assert!(Price::from_decimal(dec!(9223372037), 0).is_err());
+        assert!(Price::from_decimal(dec!(1), 10).is_err());
+    }
+
     #[rstest]
     fn test_with_maximum_value() {
         let price = Price::new(PRICE_MAX, 9);
@@ -27,12 +27,12 @@ use nautilus_core::{
     correctness::{check_in_range_inclusive_f64, FAILED},
     parsing::precision_from_str,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+use crate::types::fixed::{decimal_to_fixed_u64, f64_to_fixed_u64, fixed_u64_to_f64};
 
 /// The sentinel value for an unset or null quantity.
 pub const QUANTITY_UNDEF: u64 = u64::MAX;
@@ -89,6 +89,25 @@ impl Quantity {
         })
     }
 
+    /// Creates a new [`Quantity`] instance from a `Decimal` value with correctness checking.
+    ///
+    /// Unlike [`Quantity::new_checked`] the value is converted without a floating-point round trip.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is invalid outside the representable range [0, 18_446_744_073].
+    /// - If `precision` is invalid outside the representable range [0, 9].
+    pub fn from_decimal(value: Decimal, precision: u8) -> anyhow::Result<Self> {
+        let value_f64 = value.to_f64().unwrap_or(f64::NAN);
+        check_in_range_inclusive_f64(value_f64, QUANTITY_MIN, QUANTITY_MAX, "value")?;
+
+        Ok(Self {
+            raw: decimal_to_fixed_u64(value, precision)?,
+            precision,
+        })
+    }
+
     /// Creates a new [`Quantity`] instance.
     ///
     /// # Panics
@@ -460,6 +479,14 @@ mod tests {
         assert!(approx_eq!(f64, qty.as_f64(), 0.00812, epsilon = 0.000_001));
     }
 
+    #[rstest]
+    fn test_from_decimal() {
+        let qty = Quantity::from_decimal(dec!(0.30000000000000004), 8).unwrap();
+        assert_eq!(qty.raw, 300_000_000);
+        assert_eq!(qty, Quantity::from("0.30000000"));
+        assert!(Quantity::from_decimal(dec!(-1), 0).is_err());
+    }
+
     #[rstest]
     fn test_undefined() {
         let qty = Quantity::from_raw(QUANTITY_UNDEF, 0);
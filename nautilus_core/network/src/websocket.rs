@@ -26,6 +26,7 @@ use futures_util::{
     stream::{SplitSink, SplitStream},
     SinkExt, StreamExt,
 };
+use nautilus_common::telemetry::telemetry;
 use nautilus_cryptography::providers::install_cryptographic_provider;
 use pyo3::{prelude::*, types::PyBytes};
 use tokio::{net::TcpStream, sync::Mutex, task, time::sleep};
@@ -282,6 +283,14 @@ impl WebSocketClientInner {
         Ok(())
     }
 
+    // Returns the label of the client for telemetry, its URL without any query
+    fn client_label(&self) -> &str {
+        self.config
+            .url
+            .split_once('?')
+            .map_or(self.config.url.as_str(), |(url, _)| url)
+    }
+
     /// Check if the client is still connected.
     ///
     /// The client is connected if the read task has not finished. It is expected
@@ -426,6 +435,7 @@ impl WebSocketClient {
                         Ok(()) => {
                             tracing::debug!("Reconnected successfully");
                             retry_counter = 0;
+                            telemetry().record_reconnect(inner.client_label());
 
                             if let Some(ref handler) = post_reconnection {
                                 Python::with_gil(|py| match handler.call0(py) {
@@ -16,4 +16,6 @@
 //! Provides an Apache Parquet backend powered by [DataFusion](https://arrow.apache.org/datafusion).
 
 pub mod kmerge_batch;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod session;
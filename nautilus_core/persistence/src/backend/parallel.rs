@@ -0,0 +1,218 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Multi-threaded reading of catalog Parquet files.
+//!
+//! Each file is read and decoded on a [`rayon`] thread pool, then the decoded files are
+//! k-way merged in ascending order of `ts_init`.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    iter::Once,
+    path::{Path, PathBuf},
+    vec::IntoIter,
+};
+
+use datafusion::{
+    arrow::record_batch::RecordBatch, parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+};
+use nautilus_model::data::Data;
+use nautilus_serialization::arrow::{DecodeDataFromRecordBatch, EncodingError};
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+
+use super::{kmerge_batch::KMerge, session::TsInitComparator};
+
+type DecodeFn = fn(&HashMap<String, String>, RecordBatch) -> Result<Vec<Data>, EncodingError>;
+
+pub type ParallelQueryResult = KMerge<Once<IntoIter<Data>>, Data, TsInitComparator>;
+
+/// Configuration for a [`ParallelCatalogReader`].
+#[derive(Clone, Debug)]
+pub struct ParallelReadConfig {
+    /// The maximum number of threads used to decode files (defaults to the number of CPUs).
+    pub num_threads: Option<usize>,
+    /// The number of rows per record batch read from each file.
+    pub batch_size: usize,
+}
+
+impl Default for ParallelReadConfig {
+    /// Creates a new default [`ParallelReadConfig`] instance.
+    fn default() -> Self {
+        Self {
+            num_threads: None,
+            batch_size: 8192,
+        }
+    }
+}
+
+struct FileSource {
+    path: PathBuf,
+    decode: DecodeFn,
+}
+
+/// Provides multi-threaded reading of catalog Parquet files.
+///
+/// Registered files are decoded in parallel on a dedicated thread pool, sized by the
+/// configured thread budget, and merged into a single stream ordered by `ts_init`.
+pub struct ParallelCatalogReader {
+    pool: ThreadPool,
+    batch_size: usize,
+    files: Vec<FileSource>,
+}
+
+impl ParallelCatalogReader {
+    /// Creates a new [`ParallelCatalogReader`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the thread pool cannot be built.
+    pub fn new(config: ParallelReadConfig) -> anyhow::Result<Self> {
+        let mut builder = ThreadPoolBuilder::new().thread_name(|i| format!("catalog-read-{i}"));
+        if let Some(num_threads) = config.num_threads {
+            builder = builder.num_threads(num_threads);
+        }
+
+        Ok(Self {
+            pool: builder.build()?,
+            batch_size: config.batch_size,
+            files: Vec::new(),
+        })
+    }
+
+    /// Returns the number of threads in the reader's thread pool.
+    #[must_use]
+    pub fn num_threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    /// Registers the Parquet file at the given `file_path` to be decoded as `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file data must be ordered by the `ts_init` in ascending order for this
+    /// to work correctly.
+    pub fn add_file<T>(&mut self, file_path: impl AsRef<Path>)
+    where
+        T: DecodeDataFromRecordBatch,
+    {
+        self.files.push(FileSource {
+            path: file_path.as_ref().to_path_buf(),
+            decode: T::decode_data_batch,
+        });
+    }
+
+    /// Decodes all registered files in parallel, returning the merged data in ascending
+    /// order of `ts_init`.
+    ///
+    /// The registered files are consumed by the query.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any file cannot be read or decoded.
+    pub fn get_query_result(&mut self) -> anyhow::Result<ParallelQueryResult> {
+        let files = std::mem::take(&mut self.files);
+        let batch_size = self.batch_size;
+        let decoded: Vec<Vec<Data>> = self.pool.install(|| {
+            files
+                .par_iter()
+                .map(|file| read_file(file, batch_size))
+                .collect::<anyhow::Result<_>>()
+        })?;
+
+        let mut kmerge = KMerge::new(TsInitComparator);
+        for data in decoded {
+            kmerge.push_iter(std::iter::once(data.into_iter()));
+        }
+        Ok(kmerge)
+    }
+}
+
+fn read_file(file: &FileSource, batch_size: usize) -> anyhow::Result<Vec<Data>> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&file.path)?)?;
+
+    // Key-value metadata is decoded into the schema metadata, as when read through DataFusion
+    let mut metadata = builder.schema().metadata().clone();
+    if let Some(key_values) = builder.metadata().file_metadata().key_value_metadata() {
+        for kv in key_values {
+            if let Some(value) = &kv.value {
+                metadata
+                    .entry(kv.key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+    }
+
+    let reader = builder.with_batch_size(batch_size).build()?;
+    let mut data = Vec::new();
+    for batch in reader {
+        data.extend((file.decode)(&metadata, batch?)?);
+    }
+    Ok(data)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{
+        is_monotonically_increasing_by_init, quote::QuoteTick, trade::TradeTick, GetTsInit,
+    };
+    use nautilus_test_kit::common::get_test_data_file_path;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::backend::session::DataBackendSession;
+
+    #[rstest]
+    fn test_parallel_query_matches_session(#[values(1, 2)] num_threads: usize) {
+        let file_path_quotes = get_test_data_file_path("nautilus/quotes.parquet");
+        let file_path_trades = get_test_data_file_path("nautilus/trades.parquet");
+        let mut reader = ParallelCatalogReader::new(ParallelReadConfig {
+            num_threads: Some(num_threads),
+            ..Default::default()
+        })
+        .unwrap();
+        reader.add_file::<QuoteTick>(&file_path_quotes);
+        reader.add_file::<TradeTick>(&file_path_trades);
+        let mut session = DataBackendSession::new(5_000);
+        session
+            .add_file::<QuoteTick>("quote_tick", file_path_quotes.as_str(), None)
+            .unwrap();
+        session
+            .add_file::<TradeTick>("trade_tick", file_path_trades.as_str(), None)
+            .unwrap();
+
+        let data: Vec<Data> = reader.get_query_result().unwrap().collect();
+        let expected: Vec<Data> = session.get_query_result().collect();
+
+        assert_eq!(reader.num_threads(), num_threads);
+        assert_eq!(data.len(), 9_600);
+        assert!(is_monotonically_increasing_by_init(&data));
+        assert!(data
+            .iter()
+            .map(GetTsInit::ts_init)
+            .eq(expected.iter().map(GetTsInit::ts_init)));
+    }
+
+    #[rstest]
+    fn test_parallel_query_missing_file_errors() {
+        let mut reader = ParallelCatalogReader::new(ParallelReadConfig::default()).unwrap();
+        reader.add_file::<QuoteTick>("missing.parquet");
+
+        assert!(reader.get_query_result().is_err());
+    }
+}
@@ -21,7 +21,11 @@ use datafusion::{
 };
 use futures::StreamExt;
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{Data, GetTsInit};
+use nautilus_model::data::{
+    bar_integrity::{BarRepairStrategy, BarRepairer},
+    corporate_action::CorporateActionAdjuster,
+    Data, GetTsInit,
+};
 use nautilus_serialization::arrow::{
     DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
 };
@@ -185,6 +189,8 @@ pub struct DataQueryResult {
     pub result: QueryResult,
     pub acc: Vec<Data>,
     pub size: usize,
+    pub adjuster: Option<CorporateActionAdjuster>,
+    pub bar_repairer: Option<BarRepairer>,
 }
 
 impl DataQueryResult {
@@ -196,9 +202,26 @@ impl DataQueryResult {
             result,
             acc: Vec::new(),
             size,
+            adjuster: None,
+            bar_repairer: None,
         }
     }
 
+    /// Sets the corporate action `adjuster` applied to each data item as it is iterated.
+    #[must_use]
+    pub fn with_adjuster(mut self, adjuster: CorporateActionAdjuster) -> Self {
+        self.adjuster = Some(adjuster);
+        self
+    }
+
+    /// Sets the `strategy` for repairing invalid bars and missing intervals as bars are iterated,
+    /// after any corporate action adjustment.
+    #[must_use]
+    pub fn with_bar_repair(mut self, strategy: BarRepairStrategy) -> Self {
+        self.bar_repairer = Some(BarRepairer::new(strategy));
+        self
+    }
+
     /// Set new `CVec` backed chunk from data
     ///
     /// It also drops previously allocated chunk
@@ -228,8 +251,25 @@ impl Iterator for DataQueryResult {
     fn next(&mut self) -> Option<Self::Item> {
         for _ in 0..self.size {
             match self.result.next() {
-                Some(item) => self.acc.push(item),
-                None => break,
+                Some(item) => {
+                    let item = match &self.adjuster {
+                        Some(adjuster) => adjuster.adjust_data(item),
+                        None => item,
+                    };
+                    match (&mut self.bar_repairer, item) {
+                        (Some(repairer), Data::Bar(bar)) => {
+                            self.acc
+                                .extend(repairer.process(bar).into_iter().map(Data::Bar));
+                        }
+                        (_, item) => self.acc.push(item),
+                    }
+                }
+                None => {
+                    if let Some(repairer) = &mut self.bar_repairer {
+                        self.acc.extend(repairer.flush().into_iter().map(Data::Bar));
+                    }
+                    break;
+                }
             }
         }
 
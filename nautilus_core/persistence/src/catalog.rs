@@ -0,0 +1,545 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Discovery of the instruments and data available in a catalog.
+//!
+//! Market data is laid out as written by the [`DataRecorder`](crate::recorder::DataRecorder), at
+//! `<catalog>/<data_type>/<instrument_id or bar_type>/<YYYYMMDD>.parquet`. Instrument definitions
+//! are stored as one JSON object per instrument at `<catalog>/instrument/<instrument_id>.json`.
+//!
+//! Bars can be resampled to daily or weekly bars cut at the venue's local session close with
+//! [`DataCatalog::resample_bars`].
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+use chrono::NaiveDate;
+use datafusion::{
+    arrow::array::{Array, UInt64Array},
+    parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+};
+use nautilus_common::session::SessionCalendar;
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos};
+use nautilus_model::{
+    data::bar::{Bar, BarType},
+    enums::{AssetClass, BarAggregation},
+    identifiers::{InstrumentId, Venue},
+    instruments::any::InstrumentAny,
+};
+use nautilus_serialization::arrow::DecodeFromRecordBatch;
+
+/// The catalog directory instrument definitions are stored in.
+pub const INSTRUMENT_DIR: &str = "instrument";
+
+/// The catalog directories market data is stored in, per data type.
+pub const DATA_TYPE_DIRS: [&str; 5] = [
+    "order_book_deltas",
+    "order_book_depth10",
+    "quote_tick",
+    "trade_tick",
+    "bar",
+];
+
+/// Provides discovery of the instruments and data available in a catalog.
+#[derive(Clone, Debug)]
+pub struct DataCatalog {
+    path: PathBuf,
+}
+
+impl DataCatalog {
+    /// Creates a new [`DataCatalog`] instance for the catalog at `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the definitions of the given `instruments` to the catalog, replacing any existing
+    /// definitions with the same IDs.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a definition cannot be written.
+    pub fn write_instruments(&self, instruments: &[InstrumentAny]) -> anyhow::Result<()> {
+        let dir = self.path.join(INSTRUMENT_DIR);
+        fs::create_dir_all(&dir)?;
+        for instrument in instruments {
+            let identifier = instrument.id().to_string().replace('/', "");
+            let path = dir.join(format!("{identifier}.json"));
+            fs::write(path, serde_json::to_string(instrument)?)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the instrument definitions in the catalog for the given `venue` and `asset_class`
+    /// (all if None), sorted by ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a definition cannot be read.
+    pub fn list_instruments(
+        &self,
+        venue: Option<&Venue>,
+        asset_class: Option<AssetClass>,
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
+        let mut instruments = Vec::new();
+        for path in list_dir(&self.path.join(INSTRUMENT_DIR))? {
+            let instrument: InstrumentAny = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            if venue.is_some_and(|venue| instrument.id().venue != *venue)
+                || asset_class.is_some_and(|asset_class| instrument.asset_class() != asset_class)
+            {
+                continue;
+            }
+            instruments.push(instrument);
+        }
+        instruments.sort_by_key(InstrumentAny::id);
+        Ok(instruments)
+    }
+
+    /// Returns the dates with data for the given `instrument_id` along with the data types
+    /// available on each date, sorted by date.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the catalog cannot be read.
+    pub fn coverage(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<Vec<(NaiveDate, Vec<String>)>> {
+        let mut coverage: BTreeMap<NaiveDate, BTreeSet<String>> = BTreeMap::new();
+        for data_type in DATA_TYPE_DIRS {
+            for path in self.data_files(instrument_id, data_type)? {
+                if let Some(date) = file_date(&path) {
+                    coverage
+                        .entry(date)
+                        .or_default()
+                        .insert(data_type.to_string());
+                }
+            }
+        }
+        Ok(coverage
+            .into_iter()
+            .map(|(date, data_types)| (date, data_types.into_iter().collect()))
+            .collect())
+    }
+
+    /// Returns the first and last `ts_init` of the `data_type` data for the given
+    /// `instrument_id` (None if there is no data).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a data file cannot be read.
+    pub fn time_range(
+        &self,
+        instrument_id: &InstrumentId,
+        data_type: &str,
+    ) -> anyhow::Result<Option<(UnixNanos, UnixNanos)>> {
+        let files = self.data_files(instrument_id, data_type)?;
+        let dates: BTreeSet<NaiveDate> = files.iter().filter_map(|path| file_date(path)).collect();
+        let (Some(first_date), Some(last_date)) = (dates.first(), dates.last()) else {
+            return Ok(None);
+        };
+
+        let mut first = None;
+        let mut last = None;
+        for path in &files {
+            let date = file_date(path);
+            if date != Some(*first_date) && date != Some(*last_date) {
+                continue;
+            }
+            if let Some((min, max)) = ts_init_range(path)? {
+                first = Some(first.map_or(min, |first: u64| first.min(min)));
+                last = Some(last.map_or(max, |last: u64| last.max(max)));
+            }
+        }
+        Ok(first
+            .zip(last)
+            .map(|(first, last)| (first.into(), last.into())))
+    }
+
+    /// Returns the first and last `ts_init` of each data type available for the given
+    /// `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a data file cannot be read.
+    pub fn time_ranges(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<BTreeMap<String, (UnixNanos, UnixNanos)>> {
+        let mut ranges = BTreeMap::new();
+        for data_type in DATA_TYPE_DIRS {
+            if let Some(range) = self.time_range(instrument_id, data_type)? {
+                ranges.insert(data_type.to_string(), range);
+            }
+        }
+        Ok(ranges)
+    }
+
+    /// Returns the bars of `bar_type` in the catalog resampled to the session aligned `DAY` or
+    /// `WEEK` bars of `target`, cut at the session closes of `calendar`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a data file cannot be read.
+    /// - If the bars cannot be resampled to `target` (see [`resample_session_bars`]).
+    pub fn resample_bars(
+        &self,
+        bar_type: &BarType,
+        target: BarType,
+        calendar: &SessionCalendar,
+    ) -> anyhow::Result<Vec<Bar>> {
+        let dir = self
+            .path
+            .join("bar")
+            .join(bar_type.to_string().replace('/', ""));
+        let mut bars = Vec::new();
+        for path in list_dir(&dir)? {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path)?)?;
+            // Record batches are read without the schema metadata the bars are decoded with
+            let metadata = builder.schema().metadata().clone();
+            for batch in builder.build()? {
+                bars.extend(Bar::decode_batch(&metadata, batch?)?);
+            }
+        }
+        bars.sort_by_key(|bar| bar.ts_event);
+        resample_session_bars(&bars, target, calendar)
+    }
+
+    // Returns the data files for the instrument, including those of all its bar types
+    fn data_files(
+        &self,
+        instrument_id: &InstrumentId,
+        data_type: &str,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let identifier = instrument_id.to_string().replace('/', "");
+        let bar_prefix = format!("{identifier}-");
+        let mut files = Vec::new();
+        for dir in list_dir(&self.path.join(data_type))? {
+            let Some(name) = dir.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if name == identifier || (data_type == "bar" && name.starts_with(&bar_prefix)) {
+                files.extend(list_dir(&dir)?);
+            }
+        }
+        Ok(files)
+    }
+}
+
+/// Resamples the `bars` (sorted by `ts_event`) to the session aligned `DAY` or `WEEK` bars of
+/// `bar_type`, cut at the session closes of `calendar` in the venue's local time.
+///
+/// Each bar is assigned to the session bar containing its `ts_event`, as the close of the bar.
+/// The resampled bars are timestamped at their session close.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the `bar_type` aggregation is not `DAY` or `WEEK`, or its step is not 1.
+/// - If the `calendar` has no session close for a bar.
+pub fn resample_session_bars(
+    bars: &[Bar],
+    bar_type: BarType,
+    calendar: &SessionCalendar,
+) -> anyhow::Result<Vec<Bar>> {
+    let spec = bar_type.spec();
+    check_predicate_true(
+        matches!(spec.aggregation, BarAggregation::Day | BarAggregation::Week),
+        "session aligned bars require a `DAY` or `WEEK` aggregation",
+    )?;
+    check_predicate_true(spec.step == 1, "session aligned bars require a step of 1")?;
+
+    let mut resampled: Vec<Bar> = Vec::new();
+    for bar in bars {
+        let Some(close_ns) = calendar.session_bar_close(bar.ts_event, spec.aggregation) else {
+            anyhow::bail!("No session close in calendar for bar at {}", bar.ts_event);
+        };
+        match resampled.last_mut() {
+            Some(last) if last.ts_event == close_ns => {
+                last.high = last.high.max(bar.high);
+                last.low = last.low.min(bar.low);
+                last.close = bar.close;
+                last.volume += bar.volume;
+            }
+            _ => resampled.push(Bar::new(
+                bar_type, bar.open, bar.high, bar.low, bar.close, bar.volume, close_ns, close_ns,
+            )),
+        }
+    }
+    Ok(resampled)
+}
+
+fn list_dir(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<anyhow::Result<Vec<PathBuf>>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+// Parses the date from a `<YYYYMMDD>[_<n>].parquet` file name
+fn file_date(path: &Path) -> Option<NaiveDate> {
+    let stem = path.file_stem()?.to_str()?;
+    let date = stem.split('_').next()?;
+    NaiveDate::parse_from_str(date, "%Y%m%d").ok()
+}
+
+fn ts_init_range(path: &Path) -> anyhow::Result<Option<(u64, u64)>> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+    let mut range: Option<(u64, u64)> = None;
+    for batch in reader {
+        let batch = batch?;
+        let Some(column) = batch.column_by_name("ts_init") else {
+            anyhow::bail!("No `ts_init` column in {}", path.display());
+        };
+        let Some(ts_init) = column.as_any().downcast_ref::<UInt64Array>() else {
+            anyhow::bail!("Invalid `ts_init` column in {}", path.display());
+        };
+        for ts in ts_init.iter().flatten() {
+            range = Some(range.map_or((ts, ts), |(min, max)| (min.min(ts), max.max(ts))));
+        }
+    }
+    Ok(range)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc, Weekday};
+    use nautilus_common::session::TradingSession;
+    use nautilus_model::{
+        data::{
+            bar::{Bar, BarType},
+            quote::QuoteTick,
+            stubs::quote_ethusdt_binance,
+            Data,
+        },
+        instruments::stubs::{audusd_sim, crypto_perpetual_ethusdt, equity_aapl},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::recorder::{DataRecorder, DataRecorderConfig};
+
+    const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+    fn quote(ts_init: u64) -> Data {
+        Data::Quote(QuoteTick {
+            ts_event: ts_init.into(),
+            ts_init: ts_init.into(),
+            ..quote_ethusdt_binance()
+        })
+    }
+
+    fn bar(ts_init: u64) -> Data {
+        Data::Bar(Bar::new(
+            BarType::from("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL"),
+            Price::from("10000.00"),
+            Price::from("10001.00"),
+            Price::from("9999.00"),
+            Price::from("10000.00"),
+            Quantity::from("1.000"),
+            ts_init.into(),
+            ts_init.into(),
+        ))
+    }
+
+    fn catalog() -> (TempDir, DataCatalog) {
+        let temp = TempDir::new().unwrap();
+        let recorder = DataRecorder::new(DataRecorderConfig::new(temp.path())).unwrap();
+        for data in [
+            quote(5),
+            quote(NANOS_PER_DAY + 7),
+            quote(2 * NANOS_PER_DAY + 9),
+            bar(NANOS_PER_DAY + 60),
+        ] {
+            recorder.record(&data).unwrap();
+        }
+        recorder.close().unwrap();
+        let catalog = DataCatalog::new(temp.path());
+        (temp, catalog)
+    }
+
+    #[rstest]
+    fn test_list_instruments() {
+        let (_temp, catalog) = catalog();
+        catalog
+            .write_instruments(&[
+                InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt()),
+                InstrumentAny::CurrencyPair(audusd_sim()),
+                InstrumentAny::Equity(equity_aapl()),
+            ])
+            .unwrap();
+
+        let all = catalog.list_instruments(None, None).unwrap();
+        let binance = catalog
+            .list_instruments(Some(&Venue::from("BINANCE")), None)
+            .unwrap();
+        let fx = catalog
+            .list_instruments(None, Some(AssetClass::FX))
+            .unwrap();
+
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].id(), InstrumentId::from("AAPL.XNAS"));
+        assert_eq!(binance.len(), 1);
+        assert_eq!(binance[0].id(), InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        assert_eq!(fx.len(), 1);
+        assert_eq!(fx[0], InstrumentAny::CurrencyPair(audusd_sim()));
+    }
+
+    #[rstest]
+    fn test_coverage() {
+        let (_temp, catalog) = catalog();
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+
+        let coverage = catalog.coverage(&instrument_id).unwrap();
+
+        let date = |day| NaiveDate::from_ymd_opt(1970, 1, day).unwrap();
+        assert_eq!(
+            coverage,
+            vec![
+                (date(1), vec!["quote_tick".to_string()]),
+                (date(2), vec!["bar".to_string(), "quote_tick".to_string()]),
+                (date(3), vec!["quote_tick".to_string()]),
+            ]
+        );
+        assert!(catalog
+            .coverage(&InstrumentId::from("AAPL.XNAS"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_time_ranges() {
+        let (_temp, catalog) = catalog();
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+
+        let ranges = catalog.time_ranges(&instrument_id).unwrap();
+
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(
+            ranges["quote_tick"],
+            (UnixNanos::from(5), UnixNanos::from(2 * NANOS_PER_DAY + 9))
+        );
+        assert_eq!(
+            ranges["bar"],
+            (
+                UnixNanos::from(NANOS_PER_DAY + 60),
+                UnixNanos::from(NANOS_PER_DAY + 60)
+            )
+        );
+        assert_eq!(
+            catalog.time_range(&instrument_id, "trade_tick").unwrap(),
+            None
+        );
+    }
+
+    fn utc(month: u32, day: u32, hour: u32) -> UnixNanos {
+        let dt = Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn hour_bar(ts: UnixNanos, high: &str, low: &str, close: &str) -> Bar {
+        Bar::new(
+            BarType::from("ESH4.XCME-1-HOUR-LAST-EXTERNAL"),
+            Price::from("5000.00"),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from("10"),
+            ts,
+            ts,
+        )
+    }
+
+    fn cme() -> SessionCalendar {
+        // Globex sessions from 17:00 to 16:00 Chicago time, opening Sunday to Thursday
+        let session = TradingSession::new(17 * 3600, 16 * 3600);
+        let mut calendar = SessionCalendar::new(0).with_timezone(chrono_tz::America::Chicago);
+        for weekday in [
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+        ] {
+            calendar.set_session(weekday, Some(session));
+        }
+        calendar
+    }
+
+    #[rstest]
+    fn test_resample_bars_to_session_close() {
+        let temp = TempDir::new().unwrap();
+        let recorder = DataRecorder::new(DataRecorderConfig::new(temp.path())).unwrap();
+        for bar in [
+            // Session closing Friday 8 March 16:00 CST (22:00 UTC)
+            hour_bar(utc(3, 7, 23), "5010.00", "4990.00", "5005.00"),
+            hour_bar(utc(3, 8, 20), "5020.00", "4995.00", "5015.00"),
+            // Session closing Monday 11 March 16:00 CDT (21:00 UTC) after the DST change
+            hour_bar(utc(3, 10, 23), "5030.00", "5000.00", "5025.00"),
+            hour_bar(utc(3, 11, 21), "5040.00", "4980.00", "5035.00"),
+        ] {
+            recorder.record(&Data::Bar(bar)).unwrap();
+        }
+        recorder.close().unwrap();
+        let catalog = DataCatalog::new(temp.path());
+        let source = BarType::from("ESH4.XCME-1-HOUR-LAST-EXTERNAL");
+        let daily = BarType::from("ESH4.XCME-1-DAY-LAST-EXTERNAL");
+        let weekly = BarType::from("ESH4.XCME-1-WEEK-LAST-EXTERNAL");
+
+        let bars = catalog.resample_bars(&source, daily, &cme()).unwrap();
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].bar_type, daily);
+        assert_eq!(bars[0].high, Price::from("5020.00"));
+        assert_eq!(bars[0].low, Price::from("4990.00"));
+        assert_eq!(bars[0].close, Price::from("5015.00"));
+        assert_eq!(bars[0].volume, Quantity::from("20"));
+        assert_eq!(bars[0].ts_event, utc(3, 8, 22));
+        assert_eq!(bars[1].ts_event, utc(3, 11, 21));
+
+        let bars = catalog.resample_bars(&source, weekly, &cme()).unwrap();
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].ts_event, utc(3, 8, 22));
+        assert_eq!(bars[1].ts_event, utc(3, 15, 21));
+        assert_eq!(bars[1].low, Price::from("4980.00"));
+    }
+
+    #[rstest]
+    fn test_resample_session_bars_requires_daily_or_weekly() {
+        let bars = [hour_bar(utc(3, 8, 20), "5020.00", "4995.00", "5015.00")];
+        let target = BarType::from("ESH4.XCME-4-HOUR-LAST-EXTERNAL");
+
+        assert!(resample_session_bars(&bars, target, &cme()).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-instrument dividend schedules in a data catalog.
+//!
+//! Schedules are stored as JSON lines at `<catalog>/dividend/<instrument_id>.jsonl`, sorted by
+//! ex-date, and can be loaded from CSV files with the columns `instrument_id`, `ex_date`,
+//! `pay_date` (optional), `amount` and `currency`, with dates formatted as `YYYY-MM-DD`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{BufRead, BufReader, Read, Write},
+    path::Path,
+};
+
+use anyhow::Context;
+use chrono::NaiveDate;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{events::dividend::Dividend, identifiers::InstrumentId, types::money::Money};
+use serde::Deserialize;
+
+/// The catalog directory dividend schedules are stored in.
+pub const DIVIDEND_DIR: &str = "dividend";
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Deserialize)]
+struct DividendRow {
+    instrument_id: String,
+    ex_date: String,
+    pay_date: Option<String>,
+    amount: String,
+    currency: String,
+}
+
+/// Parses the dividends from the CSV data in `reader`.
+///
+/// # Errors
+///
+/// This function returns an error if a row cannot be parsed.
+pub fn parse_dividends_csv<R: Read>(reader: R) -> anyhow::Result<Vec<Dividend>> {
+    let mut dividends = Vec::new();
+    for (i, row) in csv::Reader::from_reader(reader)
+        .deserialize::<DividendRow>()
+        .enumerate()
+    {
+        let row = row?;
+        let dividend = parse_dividend(&row).with_context(|| format!("Invalid row {}", i + 1))?;
+        dividends.push(dividend);
+    }
+    Ok(dividends)
+}
+
+/// Writes the `dividends` to the catalog at `catalog_path`, merged with the schedules already
+/// stored and returning the number of dividends written.
+///
+/// A dividend replaces any stored dividend for the same instrument and ex-date.
+///
+/// # Errors
+///
+/// This function returns an error if a schedule cannot be read or written.
+pub fn write_dividends(catalog_path: &Path, dividends: &[Dividend]) -> anyhow::Result<usize> {
+    let mut by_instrument: HashMap<InstrumentId, Vec<Dividend>> = HashMap::new();
+    for dividend in dividends {
+        by_instrument
+            .entry(dividend.instrument_id)
+            .or_default()
+            .push(*dividend);
+    }
+
+    let dir = catalog_path.join(DIVIDEND_DIR);
+    fs::create_dir_all(&dir)?;
+    for (instrument_id, dividends) in by_instrument {
+        let mut schedule: BTreeMap<UnixNanos, Dividend> =
+            read_dividends(catalog_path, &instrument_id)?
+                .into_iter()
+                .map(|dividend| (dividend.ex_date, dividend))
+                .collect();
+        schedule.extend(
+            dividends
+                .into_iter()
+                .map(|dividend| (dividend.ex_date, dividend)),
+        );
+
+        let mut file = File::create(dir.join(format!("{instrument_id}.jsonl")))?;
+        for dividend in schedule.values() {
+            writeln!(file, "{}", serde_json::to_string(dividend)?)?;
+        }
+    }
+    Ok(dividends.len())
+}
+
+/// Returns the dividend schedule for the given `instrument_id` in the catalog at
+/// `catalog_path`, sorted by ex-date (empty if none stored).
+///
+/// # Errors
+///
+/// This function returns an error if the schedule cannot be read.
+pub fn read_dividends(
+    catalog_path: &Path,
+    instrument_id: &InstrumentId,
+) -> anyhow::Result<Vec<Dividend>> {
+    let path = catalog_path
+        .join(DIVIDEND_DIR)
+        .join(format!("{instrument_id}.jsonl"));
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut dividends = Vec::new();
+    for line in BufReader::new(File::open(&path)?).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        dividends.push(serde_json::from_str(&line)?);
+    }
+    Ok(dividends)
+}
+
+/// Loads the dividends from the CSV file at `csv_path` into the catalog at `catalog_path`,
+/// returning the number of dividends loaded.
+///
+/// # Errors
+///
+/// This function returns an error if the CSV file cannot be parsed or the catalog written.
+pub fn load_dividends_csv(csv_path: &Path, catalog_path: &Path) -> anyhow::Result<usize> {
+    let file = File::open(csv_path)
+        .with_context(|| format!("Cannot open dividends CSV {}", csv_path.display()))?;
+    let dividends = parse_dividends_csv(file)?;
+    write_dividends(catalog_path, &dividends)
+}
+
+fn parse_dividend(row: &DividendRow) -> anyhow::Result<Dividend> {
+    let instrument_id = InstrumentId::from(row.instrument_id.as_str());
+    let amount: f64 = row
+        .amount
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid amount '{}': {e}", row.amount))?;
+    let amount = Money::new(amount, row.currency.parse()?);
+    let ex_date = parse_date(&row.ex_date)?;
+    let pay_date = match row.pay_date.as_deref() {
+        Some(value) if !value.is_empty() => Some(parse_date(value)?),
+        _ => None,
+    };
+    Ok(Dividend::new(
+        instrument_id,
+        amount,
+        ex_date,
+        pay_date,
+        ex_date,
+        ex_date,
+    ))
+}
+
+fn parse_date(value: &str) -> anyhow::Result<UnixNanos> {
+    let date = NaiveDate::parse_from_str(value, DATE_FORMAT)
+        .map_err(|e| anyhow::anyhow!("Invalid date '{value}': {e}"))?;
+    let nanos = date
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight was valid")
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("Date '{value}' out of range"))?;
+    Ok(UnixNanos::from(u64::try_from(nanos)?))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const CSV: &str = "\
+instrument_id,ex_date,pay_date,amount,currency
+AAPL.XNAS,2024-02-09,2024-02-15,0.24,USD
+AAPL.XNAS,2024-05-10,,0.25,USD
+MSFT.XNAS,2024-02-14,2024-03-14,0.75,USD
+";
+
+    #[rstest]
+    fn test_parse_dividends_csv() {
+        let dividends = parse_dividends_csv(CSV.as_bytes()).unwrap();
+
+        assert_eq!(dividends.len(), 3);
+        assert_eq!(dividends[0].instrument_id, InstrumentId::from("AAPL.XNAS"));
+        assert_eq!(dividends[0].amount, Money::from("0.24 USD"));
+        assert_eq!(
+            dividends[0].ex_date,
+            UnixNanos::from(1_707_436_800_000_000_000)
+        );
+        assert_eq!(
+            dividends[0].pay_date,
+            Some(UnixNanos::from(1_707_955_200_000_000_000))
+        );
+        assert_eq!(dividends[1].pay_date, None);
+    }
+
+    #[rstest]
+    fn test_parse_dividends_csv_invalid_date() {
+        let csv =
+            "instrument_id,ex_date,pay_date,amount,currency\nAAPL.XNAS,09/02/2024,,0.24,USD\n";
+
+        let result = parse_dividends_csv(csv.as_bytes());
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_load_dividends_csv_merges_into_catalog() {
+        let temp = TempDir::new().unwrap();
+        let csv_path = temp.path().join("dividends.csv");
+        fs::write(&csv_path, CSV).unwrap();
+        let catalog = temp.path().join("catalog");
+
+        assert_eq!(load_dividends_csv(&csv_path, &catalog).unwrap(), 3);
+
+        // Restating a dividend replaces it
+        let restated = "instrument_id,ex_date,pay_date,amount,currency\nAAPL.XNAS,2024-05-10,2024-05-16,0.26,USD\n";
+        fs::write(&csv_path, restated).unwrap();
+        assert_eq!(load_dividends_csv(&csv_path, &catalog).unwrap(), 1);
+
+        let aapl = read_dividends(&catalog, &InstrumentId::from("AAPL.XNAS")).unwrap();
+        assert_eq!(aapl.len(), 2);
+        assert_eq!(aapl[0].amount, Money::from("0.24 USD"));
+        assert_eq!(aapl[1].amount, Money::from("0.26 USD"));
+        assert!(aapl[1].pay_date.is_some());
+
+        let msft = read_dividends(&catalog, &InstrumentId::from("MSFT.XNAS")).unwrap();
+        assert_eq!(msft.len(), 1);
+        assert!(read_dividends(&catalog, &InstrumentId::from("NVDA.XNAS"))
+            .unwrap()
+            .is_empty());
+    }
+}
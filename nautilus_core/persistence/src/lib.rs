@@ -26,8 +26,14 @@
 //!
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
+//! - `parallel`: Enables multi-threaded catalog queries on a `rayon` thread pool.
 
 pub mod backend;
+pub mod catalog;
+pub mod dividends;
+pub mod mmap;
+pub mod rates;
+pub mod recorder;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -0,0 +1,330 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Memory-mapped replay of Arrow IPC data files.
+//!
+//! Arrow IPC files hold record batches with the same layout on disk as in memory, so a mapped
+//! file is decoded in place without first reading it onto the heap. Batches are paged in by the
+//! OS as they are iterated, and with the default [`MmapReplayConfig`] the pages of consumed
+//! batches are released again, keeping the resident set bounded by the prefetch window rather
+//! than the size of the file.
+
+use std::{collections::HashMap, fs::File, path::Path, ptr::NonNull, sync::Arc};
+
+use datafusion::arrow::{
+    buffer::Buffer,
+    datatypes::SchemaRef,
+    ipc::{
+        convert::fb_to_schema,
+        reader::{read_footer_length, FileDecoder},
+        root_as_footer, Block,
+    },
+    record_batch::RecordBatch,
+};
+use memmap2::Mmap;
+#[cfg(unix)]
+use memmap2::{Advice, UncheckedAdvice};
+use nautilus_model::data::Data;
+use nautilus_serialization::arrow::{DecodeDataFromRecordBatch, EncodingError};
+
+type DecodeFn = fn(&HashMap<String, String>, RecordBatch) -> Result<Vec<Data>, EncodingError>;
+
+/// The length of the trailer at the end of an Arrow IPC file (footer length and magic).
+const TRAILER_LEN: usize = 10;
+
+/// The stride used when touching the pages of a batch.
+const PAGE_SIZE: usize = 4096;
+
+/// Configuration for a [`MmapReplayReader`].
+#[derive(Clone, Debug)]
+pub struct MmapReplayConfig {
+    /// The number of batches ahead of the current batch to advise the OS to prefetch.
+    pub prefetch_batches: usize,
+    /// If the pages of each batch are touched in one pass before the batch is decoded.
+    pub touch_pages: bool,
+    /// If the pages of each batch are released back to the OS once the batch is decoded.
+    pub release_consumed: bool,
+}
+
+impl Default for MmapReplayConfig {
+    /// Creates a new default [`MmapReplayConfig`] instance.
+    fn default() -> Self {
+        Self {
+            prefetch_batches: 2,
+            touch_pages: true,
+            release_consumed: true,
+        }
+    }
+}
+
+/// Provides iteration over the record batches of a memory-mapped Arrow IPC file, decoded
+/// into [`Data`].
+///
+/// Each call to `next` decodes one record batch. Prefetch and release hints are only issued
+/// on Unix platforms, elsewhere the file is paged in and out by the OS alone.
+pub struct MmapReplayReader {
+    mmap: Arc<Mmap>,
+    buffer: Buffer,
+    decoder: FileDecoder,
+    schema: SchemaRef,
+    blocks: Vec<Block>,
+    decode: DecodeFn,
+    config: MmapReplayConfig,
+    cursor: usize,
+}
+
+impl MmapReplayReader {
+    /// Opens the Arrow IPC file at the given `file_path` to be decoded as `T`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be mapped, or is not a valid Arrow
+    /// IPC file.
+    pub fn open<T>(file_path: impl AsRef<Path>, config: MmapReplayConfig) -> anyhow::Result<Self>
+    where
+        T: DecodeDataFromRecordBatch,
+    {
+        let file = File::open(file_path)?;
+        // SAFETY: The map is read-only, the file must not be truncated while it is mapped
+        let mmap = Arc::new(unsafe { Mmap::map(&file)? });
+        #[cfg(unix)]
+        mmap.advise(Advice::Sequential)?;
+
+        let len = mmap.len();
+        if len < TRAILER_LEN {
+            anyhow::bail!("File too short for Arrow IPC format: {len} bytes");
+        }
+
+        let ptr = NonNull::from(&mmap[..]).cast::<u8>();
+        // SAFETY: The pointer is valid for `len` bytes for as long as the map is alive, and
+        // the buffer holds a reference to the map
+        let buffer = unsafe { Buffer::from_custom_allocation(ptr, len, mmap.clone()) };
+
+        let trailer_start = len - TRAILER_LEN;
+        let footer_len = read_footer_length(buffer[trailer_start..].try_into()?)?;
+        let footer = root_as_footer(&buffer[trailer_start - footer_len..trailer_start])
+            .map_err(|e| anyhow::anyhow!("Invalid Arrow IPC footer: {e}"))?;
+        let schema =
+            Arc::new(fb_to_schema(footer.schema().ok_or_else(|| {
+                anyhow::anyhow!("Missing schema in Arrow IPC footer")
+            })?));
+
+        let mut decoder = FileDecoder::new(schema.clone(), footer.version());
+        for block in footer.dictionaries().iter().flatten() {
+            decoder.read_dictionary(block, &block_buffer(&buffer, block))?;
+        }
+        let blocks = footer
+            .recordBatches()
+            .map(|blocks| blocks.iter().copied().collect())
+            .unwrap_or_default();
+
+        Ok(Self {
+            mmap,
+            buffer,
+            decoder,
+            schema,
+            blocks,
+            decode: T::decode_data_batch,
+            config,
+            cursor: 0,
+        })
+    }
+
+    /// Returns the schema of the file.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the total number of record batches in the file.
+    #[must_use]
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns the number of record batches not yet decoded.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.blocks.len() - self.cursor
+    }
+
+    fn read_block(&self, block: &Block) -> anyhow::Result<Vec<Data>> {
+        let data = block_buffer(&self.buffer, block);
+        if self.config.touch_pages {
+            touch_pages(data.as_slice());
+        }
+
+        match self.decoder.read_record_batch(block, &data)? {
+            Some(batch) => Ok((self.decode)(self.schema.metadata(), batch)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    #[cfg(unix)]
+    fn prefetch(&self) {
+        let start = self.cursor + 1;
+        let end = (start + self.config.prefetch_batches).min(self.blocks.len());
+        if let Some((offset, len)) = span(&self.blocks[start.min(end)..end]) {
+            // Advice is only a hint, failure leaves the pages to be faulted in on access
+            let _ = self.mmap.advise_range(Advice::WillNeed, offset, len);
+        }
+    }
+
+    #[cfg(unix)]
+    fn release(&self, block: &Block) {
+        if let Some((offset, len)) = span(std::slice::from_ref(block)) {
+            // SAFETY: The map is a read-only file mapping, so released pages are reloaded
+            // from the file if accessed again
+            let _ = unsafe {
+                self.mmap
+                    .unchecked_advise_range(UncheckedAdvice::DontNeed, offset, len)
+            };
+        }
+    }
+}
+
+impl Iterator for MmapReplayReader {
+    type Item = anyhow::Result<Vec<Data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block = *self.blocks.get(self.cursor)?;
+
+        #[cfg(unix)]
+        if self.config.prefetch_batches > 0 {
+            self.prefetch();
+        }
+
+        let result = self.read_block(&block);
+
+        #[cfg(unix)]
+        if self.config.release_consumed {
+            self.release(&block);
+        }
+
+        self.cursor += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
+
+impl ExactSizeIterator for MmapReplayReader {}
+
+fn block_len(block: &Block) -> usize {
+    block.metaDataLength() as usize + block.bodyLength() as usize
+}
+
+fn block_buffer(buffer: &Buffer, block: &Block) -> Buffer {
+    buffer.slice_with_length(block.offset() as usize, block_len(block))
+}
+
+/// Returns the byte offset and length spanning the given contiguous `blocks` (if any).
+fn span(blocks: &[Block]) -> Option<(usize, usize)> {
+    let first = blocks.first()?;
+    let last = blocks.last()?;
+    let offset = first.offset() as usize;
+    Some((offset, last.offset() as usize + block_len(last) - offset))
+}
+
+/// Touches each page of the given `data`, faulting the pages in as one sequential pass.
+pub fn touch_pages(data: &[u8]) {
+    for byte in data.iter().step_by(PAGE_SIZE) {
+        std::hint::black_box(*byte);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use datafusion::{
+        arrow::ipc::writer::FileWriter,
+        parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+    };
+    use nautilus_model::data::{is_monotonically_increasing_by_init, quote::QuoteTick};
+    use nautilus_test_kit::common::get_test_data_file_path;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Converts the Parquet test file with the given `name` into an Arrow IPC file.
+    fn write_ipc_file(name: &str, batch_size: usize) -> (PathBuf, Vec<Data>) {
+        let file =
+            File::open(get_test_data_file_path(&format!("nautilus/{name}.parquet"))).unwrap();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        let schema = builder.schema().clone();
+        let reader = builder.with_batch_size(batch_size).build().unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("{name}-{batch_size}-{}.arrow", std::process::id()));
+        let mut writer = FileWriter::try_new(File::create(&path).unwrap(), &schema).unwrap();
+        let mut expected = Vec::new();
+        for batch in reader {
+            let batch = batch.unwrap().with_schema(schema.clone()).unwrap();
+            writer.write(&batch).unwrap();
+            expected.extend(QuoteTick::decode_data_batch(schema.metadata(), batch).unwrap());
+        }
+        writer.finish().unwrap();
+        (path, expected)
+    }
+
+    #[rstest]
+    fn test_replay_matches_parquet(
+        #[values(true, false)] touch_pages: bool,
+        #[values(true, false)] release_consumed: bool,
+    ) {
+        let (path, expected) = write_ipc_file("quotes", 1_000);
+        let config = MmapReplayConfig {
+            touch_pages,
+            release_consumed,
+            ..Default::default()
+        };
+        let reader = MmapReplayReader::open::<QuoteTick>(&path, config).unwrap();
+
+        assert_eq!(reader.num_batches(), 10);
+        assert_eq!(reader.len(), 10);
+
+        let data: Vec<Data> = reader.flat_map(Result::unwrap).collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(data.len(), 9_500);
+        assert!(is_monotonically_increasing_by_init(&data));
+        assert_eq!(data, expected);
+    }
+
+    #[rstest]
+    fn test_open_invalid_file_errors() {
+        let path = std::env::temp_dir().join(format!("invalid-{}.arrow", std::process::id()));
+        std::fs::write(&path, b"not an arrow file").unwrap();
+
+        let result = MmapReplayReader::open::<QuoteTick>(&path, MmapReplayConfig::default());
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_open_missing_file_errors() {
+        let result =
+            MmapReplayReader::open::<QuoteTick>("missing.arrow", MmapReplayConfig::default());
+
+        assert!(result.is_err());
+    }
+}
@@ -14,8 +14,12 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
+        trade::TradeTick,
+    },
+    python::data::stream::DataStream,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
@@ -88,6 +92,19 @@ impl DataBackendSession {
         let query_result = slf.get_query_result();
         DataQueryResult::new(query_result, slf.chunk_size)
     }
+
+    /// Consumes the registered queries into an iterator yielding data objects one at a time.
+    ///
+    /// Records are decoded a chunk at a time with the GIL released, so query results of any
+    /// size can be streamed without materializing them.
+    #[pyo3(name = "to_data_stream")]
+    fn py_to_data_stream(mut slf: PyRefMut<'_, Self>) -> DataStream {
+        let query_result = slf.get_query_result();
+        let chunks = DataQueryResult::new(query_result, slf.chunk_size)
+            .take_while(|chunk| !chunk.is_empty())
+            .map(Ok);
+        DataStream::new(Box::new(chunks))
+    }
 }
 
 #[pymethods]
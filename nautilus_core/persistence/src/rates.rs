@@ -0,0 +1,331 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Historical funding, borrow and interest rates in a data catalog.
+//!
+//! Rates are stored as JSON lines sorted by `ts_event`, with funding rates at
+//! `<catalog>/funding_rate/<instrument_id>.jsonl`, borrow rates at
+//! `<catalog>/borrow_rate/<instrument_id>.jsonl` and interest rates at
+//! `<catalog>/interest_rate/<currency>.jsonl`.
+//!
+//! Funding rates are read as `FundingRateUpdate`s for funding accounting, while borrow and
+//! interest rates are read as [`RateSchedule`]s for the backtest financing and interest modules.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{
+        funding::FundingRateUpdate,
+        rate::{RateRecord, RateSchedule},
+    },
+    identifiers::InstrumentId,
+    types::currency::Currency,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The catalog directory funding rates are stored in.
+pub const FUNDING_RATE_DIR: &str = "funding_rate";
+
+/// The catalog directory borrow rates are stored in.
+pub const BORROW_RATE_DIR: &str = "borrow_rate";
+
+/// The catalog directory interest rates are stored in.
+pub const INTEREST_RATE_DIR: &str = "interest_rate";
+
+/// Writes the funding `rates` to the catalog at `catalog_path`, merged with the rates already
+/// stored and returning the number of rates written.
+///
+/// A rate replaces any stored rate for the same instrument and `ts_event`.
+///
+/// # Errors
+///
+/// This function returns an error if a table cannot be read or written.
+pub fn write_funding_rates(
+    catalog_path: &Path,
+    rates: &[FundingRateUpdate],
+) -> anyhow::Result<usize> {
+    let mut by_instrument: BTreeMap<InstrumentId, Vec<FundingRateUpdate>> = BTreeMap::new();
+    for rate in rates.iter().cloned() {
+        by_instrument
+            .entry(rate.instrument_id)
+            .or_default()
+            .push(rate);
+    }
+
+    for (instrument_id, rates) in by_instrument {
+        let path = funding_rate_path(catalog_path, &instrument_id);
+        merge_records(&path, rates, |rate| rate.ts_event)?;
+    }
+    Ok(rates.len())
+}
+
+/// Returns the funding rates for the given `instrument_id` in the catalog at `catalog_path`,
+/// with a `ts_event` within the `start` and `end` (inclusive) if given, sorted by `ts_event`.
+///
+/// # Errors
+///
+/// This function returns an error if the table cannot be read.
+pub fn read_funding_rates(
+    catalog_path: &Path,
+    instrument_id: &InstrumentId,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<Vec<FundingRateUpdate>> {
+    let rates: Vec<FundingRateUpdate> =
+        read_records(&funding_rate_path(catalog_path, instrument_id))?;
+    Ok(rates
+        .into_iter()
+        .filter(|rate| {
+            start.is_none_or(|start| rate.ts_event >= start)
+                && end.is_none_or(|end| rate.ts_event <= end)
+        })
+        .collect())
+}
+
+/// Writes the borrow `rates` for the given `instrument_id` to the catalog at `catalog_path`,
+/// merged with the rates already stored and returning the number of rates written.
+///
+/// # Errors
+///
+/// This function returns an error if the table cannot be read or written.
+pub fn write_borrow_rates(
+    catalog_path: &Path,
+    instrument_id: &InstrumentId,
+    rates: &[RateRecord],
+) -> anyhow::Result<usize> {
+    let path = borrow_rate_path(catalog_path, instrument_id);
+    merge_records(&path, rates.to_vec(), |rate| rate.ts_event)?;
+    Ok(rates.len())
+}
+
+/// Returns the borrow rate schedule for the given `instrument_id` in the catalog at
+/// `catalog_path` (empty if none stored).
+///
+/// # Errors
+///
+/// This function returns an error if the table cannot be read.
+pub fn read_borrow_rates(
+    catalog_path: &Path,
+    instrument_id: &InstrumentId,
+) -> anyhow::Result<RateSchedule> {
+    let path = borrow_rate_path(catalog_path, instrument_id);
+    Ok(RateSchedule::new(read_records(&path)?))
+}
+
+/// Writes the interest `rates` for the given `currency` to the catalog at `catalog_path`,
+/// merged with the rates already stored and returning the number of rates written.
+///
+/// # Errors
+///
+/// This function returns an error if the table cannot be read or written.
+pub fn write_interest_rates(
+    catalog_path: &Path,
+    currency: &Currency,
+    rates: &[RateRecord],
+) -> anyhow::Result<usize> {
+    let path = interest_rate_path(catalog_path, currency);
+    merge_records(&path, rates.to_vec(), |rate| rate.ts_event)?;
+    Ok(rates.len())
+}
+
+/// Returns the interest rate schedule for the given `currency` in the catalog at
+/// `catalog_path` (empty if none stored).
+///
+/// # Errors
+///
+/// This function returns an error if the table cannot be read.
+pub fn read_interest_rates(
+    catalog_path: &Path,
+    currency: &Currency,
+) -> anyhow::Result<RateSchedule> {
+    Ok(RateSchedule::new(read_records(&interest_rate_path(
+        catalog_path,
+        currency,
+    ))?))
+}
+
+fn file_stem(instrument_id: &InstrumentId) -> String {
+    instrument_id.to_string().replace('/', "")
+}
+
+fn funding_rate_path(catalog_path: &Path, instrument_id: &InstrumentId) -> PathBuf {
+    catalog_path
+        .join(FUNDING_RATE_DIR)
+        .join(format!("{}.jsonl", file_stem(instrument_id)))
+}
+
+fn borrow_rate_path(catalog_path: &Path, instrument_id: &InstrumentId) -> PathBuf {
+    catalog_path
+        .join(BORROW_RATE_DIR)
+        .join(format!("{}.jsonl", file_stem(instrument_id)))
+}
+
+fn interest_rate_path(catalog_path: &Path, currency: &Currency) -> PathBuf {
+    catalog_path
+        .join(INTEREST_RATE_DIR)
+        .join(format!("{}.jsonl", currency.code))
+}
+
+// Merges the `records` into the table at `path` keyed by timestamp, replacing existing records
+fn merge_records<T: Serialize + DeserializeOwned>(
+    path: &Path,
+    records: Vec<T>,
+    key: impl Fn(&T) -> UnixNanos,
+) -> anyhow::Result<()> {
+    let mut table: BTreeMap<UnixNanos, T> = read_records(path)?
+        .into_iter()
+        .map(|record| (key(&record), record))
+        .collect();
+    table.extend(records.into_iter().map(|record| (key(&record), record)));
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = File::create(path)?;
+    for record in table.values() {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+    Ok(())
+}
+
+fn read_records<T: DeserializeOwned>(path: &Path) -> anyhow::Result<Vec<T>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut records = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(&line)?);
+    }
+    Ok(records)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn funding_rate(
+        instrument_id: &str,
+        rate: rust_decimal::Decimal,
+        ts: u64,
+    ) -> FundingRateUpdate {
+        FundingRateUpdate::new(
+            InstrumentId::from(instrument_id),
+            rate,
+            None,
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_write_and_read_funding_rates() {
+        let temp = TempDir::new().unwrap();
+        let ethusdt = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let rates = [
+            funding_rate("ETHUSDT-PERP.BINANCE", dec!(0.0002), 20),
+            funding_rate("ETHUSDT-PERP.BINANCE", dec!(0.0001), 10),
+            funding_rate("BTCUSDT-PERP.BINANCE", dec!(0.0003), 10),
+        ];
+
+        assert_eq!(write_funding_rates(temp.path(), &rates).unwrap(), 3);
+        // Restating a rate replaces it
+        let restated = [funding_rate("ETHUSDT-PERP.BINANCE", dec!(0.00025), 20)];
+        assert_eq!(write_funding_rates(temp.path(), &restated).unwrap(), 1);
+
+        let all = read_funding_rates(temp.path(), &ethusdt, None, None).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].rate, dec!(0.0001));
+        assert_eq!(all[1].rate, dec!(0.00025));
+
+        let ranged =
+            read_funding_rates(temp.path(), &ethusdt, Some(15.into()), Some(20.into())).unwrap();
+        assert_eq!(ranged.len(), 1);
+        assert_eq!(ranged[0].ts_event, UnixNanos::from(20));
+
+        let unknown = InstrumentId::from("SOLUSDT-PERP.BINANCE");
+        assert!(read_funding_rates(temp.path(), &unknown, None, None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_write_and_read_borrow_rates() {
+        let temp = TempDir::new().unwrap();
+        let aapl = InstrumentId::from("AAPL.XNAS");
+
+        write_borrow_rates(
+            temp.path(),
+            &aapl,
+            &[
+                RateRecord::new(dec!(0.03), 10.into()),
+                RateRecord::new(dec!(0.05), 20.into()),
+            ],
+        )
+        .unwrap();
+        write_borrow_rates(
+            temp.path(),
+            &aapl,
+            &[RateRecord::new(dec!(0.04), 30.into())],
+        )
+        .unwrap();
+
+        let schedule = read_borrow_rates(temp.path(), &aapl).unwrap();
+        assert_eq!(schedule.records().len(), 3);
+        assert_eq!(schedule.rate_at(25.into()), Some(dec!(0.05)));
+        assert_eq!(schedule.rate_at(30.into()), Some(dec!(0.04)));
+        assert!(
+            read_borrow_rates(temp.path(), &InstrumentId::from("MSFT.XNAS"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[rstest]
+    fn test_write_and_read_interest_rates() {
+        let temp = TempDir::new().unwrap();
+
+        write_interest_rates(
+            temp.path(),
+            &Currency::USD(),
+            &[RateRecord::new(dec!(0.0525), 10.into())],
+        )
+        .unwrap();
+
+        let schedule = read_interest_rates(temp.path(), &Currency::USD()).unwrap();
+        assert_eq!(schedule.rate_at(10.into()), Some(dec!(0.0525)));
+        assert!(temp.path().join("interest_rate/USD.jsonl").exists());
+        assert!(read_interest_rates(temp.path(), &Currency::EUR())
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration for the live data recorder.
+
+use std::path::PathBuf;
+
+use nautilus_common::config::{check_setting, ValidateConfig};
+use serde::{Deserialize, Serialize};
+
+/// The message bus topics recorded by default, covering all market data.
+pub const DEFAULT_RECORDER_TOPICS: [&str; 5] = [
+    "data.book.deltas.*",
+    "data.book.depth.*",
+    "data.quotes.*",
+    "data.trades.*",
+    "data.bars.*",
+];
+
+/// Configuration for a [`DataRecorder`](super::DataRecorder).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataRecorderConfig {
+    /// The path to the catalog to record data to.
+    pub catalog_path: PathBuf,
+    /// The message bus topics (or topic patterns) to record, e.g. `data.quotes.*`, or
+    /// `data.book.depth_snapshots.*` to record periodic order book snapshots.
+    #[serde(default = "default_topics")]
+    pub topics: Vec<String>,
+    /// The number of rows buffered for a stream before they are written to its file.
+    #[serde(default = "default_flush_rows")]
+    pub flush_rows: usize,
+    /// The span of `ts_init` (nanoseconds) buffered for a stream before it is written to its file.
+    #[serde(default = "default_flush_interval_ns")]
+    pub flush_interval_ns: u64,
+}
+
+impl DataRecorderConfig {
+    /// Creates a new [`DataRecorderConfig`] instance recording the default topics.
+    #[must_use]
+    pub fn new(catalog_path: impl Into<PathBuf>) -> Self {
+        Self {
+            catalog_path: catalog_path.into(),
+            topics: default_topics(),
+            flush_rows: default_flush_rows(),
+            flush_interval_ns: default_flush_interval_ns(),
+        }
+    }
+}
+
+fn default_topics() -> Vec<String> {
+    DEFAULT_RECORDER_TOPICS.map(String::from).to_vec()
+}
+
+const fn default_flush_rows() -> usize {
+    10_000
+}
+
+const fn default_flush_interval_ns() -> u64 {
+    60_000_000_000 // 1 minute
+}
+
+impl ValidateConfig for DataRecorderConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            !self.catalog_path.as_os_str().is_empty(),
+            "recorder.catalog_path",
+            "was empty",
+        )?;
+        check_setting(
+            !self.topics.is_empty(),
+            "recorder.topics",
+            "at least one topic is required",
+        )?;
+        check_setting(
+            self.flush_rows > 0,
+            "recorder.flush_rows",
+            "must be positive",
+        )
+    }
+}
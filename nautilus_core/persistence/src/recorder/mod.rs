@@ -0,0 +1,750 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A live data recorder, persisting market data from the message bus to a Parquet catalog.
+//!
+//! Each stream of data, for a data type and instrument (or bar type), is buffered and then
+//! written as a row group to the file for the UTC day of its `ts_init`, at
+//! `<catalog>/<data_type>/<instrument_id or bar_type>/<YYYYMMDD>.parquet`. A file is completed
+//! when its stream rotates to the next day, or the recorder is closed. If the file for a day
+//! already exists, e.g. after a restart, a new file is started with a `_<n>` suffix.
+//!
+//! Periods when a data client was disconnected are recorded as [`DataGap`] markers, one JSON
+//! object per line, at `<catalog>/data_gap/<client_id>.jsonl`.
+//!
+//! Equity updates, when the equity topic is recorded, are appended one JSON object per line at
+//! `<catalog>/equity/<currency>.jsonl` and can be loaded with [`read_equity_updates`].
+
+pub mod config;
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, File, OpenOptions},
+    hash::Hash,
+    io::Write,
+    mem,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use chrono::{DateTime, NaiveDate};
+use datafusion::{
+    arrow::{datatypes::SchemaRef, record_batch::RecordBatch},
+    parquet::{
+        arrow::ArrowWriter,
+        basic::{Compression, ZstdLevel},
+        file::properties::WriterProperties,
+    },
+};
+use nautilus_common::{
+    equity::EquityUpdate,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_core::{datetime::unix_nanos_to_iso8601, nanos::UnixNanos};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::OrderBookDepth10,
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data, GetTsInit,
+    },
+    identifiers::{ClientId, InstrumentId},
+    types::currency::Currency,
+};
+use nautilus_serialization::arrow::{
+    bars_to_arrow_record_batch_bytes, order_book_deltas_to_arrow_record_batch_bytes,
+    order_book_depth10_to_arrow_record_batch_bytes, quote_ticks_to_arrow_record_batch_bytes,
+    trade_ticks_to_arrow_record_batch_bytes, EncodingError,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+pub use crate::recorder::config::DataRecorderConfig;
+
+type EncodeFn<T> = fn(Vec<T>) -> Result<RecordBatch, EncodingError>;
+
+/// The catalog directory data gap markers are recorded in.
+pub const DATA_GAP_DIR: &str = "data_gap";
+
+/// The catalog directory equity updates are recorded in.
+pub const EQUITY_DIR: &str = "equity";
+
+/// Represents a period when a data client was disconnected, so data may be missing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataGap {
+    /// The data client which was disconnected.
+    pub client_id: ClientId,
+    /// UNIX timestamp (nanoseconds) when the client disconnected.
+    pub ts_start: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the client reconnected (still disconnected if None).
+    pub ts_end: Option<UnixNanos>,
+}
+
+struct OpenFile {
+    writer: ArrowWriter<File>,
+    schema: SchemaRef,
+    path: PathBuf,
+}
+
+struct RecordStream<T> {
+    dir: PathBuf,
+    encode: EncodeFn<T>,
+    buffer: Vec<T>,
+    buffer_start_ns: UnixNanos,
+    date: Option<NaiveDate>,
+    file: Option<OpenFile>,
+}
+
+impl<T: GetTsInit> RecordStream<T> {
+    fn new(dir: PathBuf, encode: EncodeFn<T>) -> Self {
+        Self {
+            dir,
+            encode,
+            buffer: Vec::new(),
+            buffer_start_ns: UnixNanos::default(),
+            date: None,
+            file: None,
+        }
+    }
+
+    fn push(&mut self, item: T, config: &DataRecorderConfig) -> anyhow::Result<()> {
+        let ts_init = item.ts_init();
+        let date = utc_date(ts_init);
+        if self.date.is_some_and(|current| current != date) {
+            self.close()?; // Rotate to the file for the new day
+        }
+        self.date = Some(date);
+
+        if self.buffer.is_empty() {
+            self.buffer_start_ns = ts_init;
+        }
+        self.buffer.push(item);
+
+        if self.buffer.len() >= config.flush_rows
+            || ts_init
+                .as_u64()
+                .saturating_sub(self.buffer_start_ns.as_u64())
+                >= config.flush_interval_ns
+        {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> anyhow::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let batch = (self.encode)(mem::take(&mut self.buffer))?;
+
+        // A change in the schema metadata, e.g. of precision, starts a new file
+        if self
+            .file
+            .as_ref()
+            .is_some_and(|file| file.schema != batch.schema())
+        {
+            self.close_file()?;
+        }
+
+        let file = match self.file.as_mut() {
+            Some(file) => file,
+            None => {
+                let date = self.date.expect("Date set with the first buffered row");
+                self.file
+                    .insert(open_file(&self.dir, date, batch.schema())?)
+            }
+        };
+        file.writer.write(&batch)?;
+        file.writer.flush()?;
+        Ok(())
+    }
+
+    fn close(&mut self) -> anyhow::Result<()> {
+        self.flush()?;
+        self.close_file()
+    }
+
+    fn close_file(&mut self) -> anyhow::Result<()> {
+        if let Some(file) = self.file.take() {
+            file.writer.close()?;
+            log::info!("File written: {}", file.path.display());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct RecorderState {
+    deltas: HashMap<InstrumentId, RecordStream<OrderBookDelta>>,
+    depths: HashMap<InstrumentId, RecordStream<OrderBookDepth10>>,
+    quotes: HashMap<InstrumentId, RecordStream<QuoteTick>>,
+    trades: HashMap<InstrumentId, RecordStream<TradeTick>>,
+    bars: HashMap<BarType, RecordStream<Bar>>,
+    disconnected: HashMap<ClientId, UnixNanos>,
+    closed: bool,
+}
+
+impl RecorderState {
+    fn record_delta(
+        &mut self,
+        delta: OrderBookDelta,
+        config: &DataRecorderConfig,
+    ) -> anyhow::Result<()> {
+        record(
+            &mut self.deltas,
+            delta.instrument_id,
+            delta,
+            "order_book_deltas",
+            order_book_deltas_to_arrow_record_batch_bytes,
+            config,
+        )
+    }
+
+    fn record_deltas(
+        &mut self,
+        deltas: &OrderBookDeltas,
+        config: &DataRecorderConfig,
+    ) -> anyhow::Result<()> {
+        deltas
+            .deltas
+            .iter()
+            .try_for_each(|delta| self.record_delta(*delta, config))
+    }
+
+    fn record_depth(
+        &mut self,
+        depth: OrderBookDepth10,
+        config: &DataRecorderConfig,
+    ) -> anyhow::Result<()> {
+        record(
+            &mut self.depths,
+            depth.instrument_id,
+            depth,
+            "order_book_depth10",
+            order_book_depth10_to_arrow_record_batch_bytes,
+            config,
+        )
+    }
+
+    fn record_quote(
+        &mut self,
+        quote: QuoteTick,
+        config: &DataRecorderConfig,
+    ) -> anyhow::Result<()> {
+        record(
+            &mut self.quotes,
+            quote.instrument_id,
+            quote,
+            "quote_tick",
+            quote_ticks_to_arrow_record_batch_bytes,
+            config,
+        )
+    }
+
+    fn record_trade(
+        &mut self,
+        trade: TradeTick,
+        config: &DataRecorderConfig,
+    ) -> anyhow::Result<()> {
+        record(
+            &mut self.trades,
+            trade.instrument_id,
+            trade,
+            "trade_tick",
+            trade_ticks_to_arrow_record_batch_bytes,
+            config,
+        )
+    }
+
+    fn record_bar(&mut self, bar: Bar, config: &DataRecorderConfig) -> anyhow::Result<()> {
+        record(
+            &mut self.bars,
+            bar.bar_type,
+            bar,
+            "bar",
+            bars_to_arrow_record_batch_bytes,
+            config,
+        )
+    }
+
+    fn flush(&mut self) -> anyhow::Result<()> {
+        self.deltas.values_mut().try_for_each(RecordStream::flush)?;
+        self.depths.values_mut().try_for_each(RecordStream::flush)?;
+        self.quotes.values_mut().try_for_each(RecordStream::flush)?;
+        self.trades.values_mut().try_for_each(RecordStream::flush)?;
+        self.bars.values_mut().try_for_each(RecordStream::flush)
+    }
+
+    fn close(&mut self) -> anyhow::Result<()> {
+        self.deltas.values_mut().try_for_each(RecordStream::close)?;
+        self.depths.values_mut().try_for_each(RecordStream::close)?;
+        self.quotes.values_mut().try_for_each(RecordStream::close)?;
+        self.trades.values_mut().try_for_each(RecordStream::close)?;
+        self.bars.values_mut().try_for_each(RecordStream::close)
+    }
+
+    fn buffered_rows(&self) -> usize {
+        self.deltas.values().map(|s| s.buffer.len()).sum::<usize>()
+            + self.depths.values().map(|s| s.buffer.len()).sum::<usize>()
+            + self.quotes.values().map(|s| s.buffer.len()).sum::<usize>()
+            + self.trades.values().map(|s| s.buffer.len()).sum::<usize>()
+            + self.bars.values().map(|s| s.buffer.len()).sum::<usize>()
+    }
+}
+
+/// Provides recording of the market data published on the message bus to a Parquet catalog.
+///
+/// The recorder is subscribed to the configured topics with [`DataRecorder::subscribe`], and
+/// should be closed with [`DataRecorder::close`] on shutdown to complete the open files (which
+/// are otherwise closed on drop).
+pub struct DataRecorder {
+    id: Ustr,
+    config: DataRecorderConfig,
+    state: RefCell<RecorderState>,
+}
+
+impl DataRecorder {
+    /// Creates a new [`DataRecorder`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the catalog directory cannot be created.
+    pub fn new(config: DataRecorderConfig) -> anyhow::Result<Self> {
+        fs::create_dir_all(&config.catalog_path)?;
+        Ok(Self {
+            id: Ustr::from(&format!("DataRecorder-{}", config.catalog_path.display())),
+            config,
+            state: RefCell::new(RecorderState::default()),
+        })
+    }
+
+    /// Subscribes the recorder to each of its configured topics on the `msgbus`.
+    pub fn subscribe(self: &Rc<Self>, msgbus: &mut MessageBus) {
+        for topic in &self.config.topics {
+            msgbus.subscribe(topic, ShareableMessageHandler(self.clone()), None);
+        }
+    }
+
+    /// Records the given `data`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if buffered data cannot be written.
+    pub fn record(&self, data: &Data) -> anyhow::Result<()> {
+        let mut state = self.state.borrow_mut();
+        let config = &self.config;
+        match data {
+            Data::Delta(delta) => state.record_delta(*delta, config),
+            Data::Deltas(deltas) => state.record_deltas(deltas, config),
+            Data::Depth10(depth) => state.record_depth(*depth, config),
+            Data::Quote(quote) => state.record_quote(*quote, config),
+            Data::Trade(trade) => state.record_trade(*trade, config),
+            Data::Bar(bar) => state.record_bar(*bar, config),
+        }
+    }
+
+    /// Records that the data client `client_id` disconnected at `ts_event`, first writing all
+    /// buffered data so that it is persisted before the gap.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if buffered data cannot be written.
+    pub fn on_disconnected(&self, client_id: ClientId, ts_event: UnixNanos) -> anyhow::Result<()> {
+        let mut state = self.state.borrow_mut();
+        state.disconnected.entry(client_id).or_insert(ts_event);
+        state.flush()
+    }
+
+    /// Records that the data client `client_id` reconnected at `ts_event`, writing a
+    /// [`DataGap`] marker if it was disconnected.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the marker cannot be written.
+    pub fn on_reconnected(&self, client_id: ClientId, ts_event: UnixNanos) -> anyhow::Result<()> {
+        let ts_start = self.state.borrow_mut().disconnected.remove(&client_id);
+        match ts_start {
+            Some(ts_start) => self.write_gap(&DataGap {
+                client_id,
+                ts_start,
+                ts_end: Some(ts_event),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the number of rows buffered and not yet written.
+    #[must_use]
+    pub fn buffered_rows(&self) -> usize {
+        self.state.borrow().buffered_rows()
+    }
+
+    /// Writes all buffered data to the open files.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if buffered data cannot be written.
+    pub fn flush(&self) -> anyhow::Result<()> {
+        self.state.borrow_mut().flush()
+    }
+
+    /// Writes all buffered data and completes the open files, and writes an open-ended
+    /// [`DataGap`] marker for each client still disconnected.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if data or markers cannot be written.
+    pub fn close(&self) -> anyhow::Result<()> {
+        let disconnected = {
+            let mut state = self.state.borrow_mut();
+            state.closed = true;
+            state.close()?;
+            mem::take(&mut state.disconnected)
+        };
+        for (client_id, ts_start) in disconnected {
+            self.write_gap(&DataGap {
+                client_id,
+                ts_start,
+                ts_end: None,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn write_gap(&self, gap: &DataGap) -> anyhow::Result<()> {
+        let dir = self.config.catalog_path.join(DATA_GAP_DIR);
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.jsonl", gap.client_id));
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(gap)?)?;
+        log::warn!(
+            "Recorded data gap for {} from {}",
+            gap.client_id,
+            unix_nanos_to_iso8601(gap.ts_start)
+        );
+        Ok(())
+    }
+
+    fn write_equity(&self, update: &EquityUpdate) -> anyhow::Result<()> {
+        let dir = self.config.catalog_path.join(EQUITY_DIR);
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.jsonl", update.equity.currency));
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(update)?)?;
+        Ok(())
+    }
+
+    fn handle_result(result: anyhow::Result<()>) {
+        if let Err(e) = result {
+            log::error!("Error recording data: {e}");
+        }
+    }
+}
+
+impl MessageHandler for DataRecorder {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(update) = message.downcast_ref::<EquityUpdate>() {
+            Self::handle_result(self.write_equity(update));
+            return;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let config = &self.config;
+        let result = if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            state.record_deltas(deltas, config)
+        } else if let Some(delta) = message.downcast_ref::<OrderBookDelta>() {
+            state.record_delta(*delta, config)
+        } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+            state.record_depth(*depth, config)
+        } else if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            state.record_quote(*quote, config)
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            state.record_trade(*trade, config)
+        } else if let Some(bar) = message.downcast_ref::<Bar>() {
+            state.record_bar(*bar, config)
+        } else {
+            Ok(())
+        };
+        Self::handle_result(result);
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        Self::handle_result(self.record(&data));
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Reads the equity updates recorded in `base_currency` from the catalog at `catalog_path`.
+///
+/// # Errors
+///
+/// This function returns an error if the file cannot be read or a line cannot be parsed.
+pub fn read_equity_updates(
+    catalog_path: &Path,
+    base_currency: Currency,
+) -> anyhow::Result<Vec<EquityUpdate>> {
+    let path = catalog_path
+        .join(EQUITY_DIR)
+        .join(format!("{base_currency}.jsonl"));
+    let text = fs::read_to_string(path)?;
+    text.lines()
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+impl Drop for DataRecorder {
+    fn drop(&mut self) {
+        if !self.state.borrow().closed {
+            Self::handle_result(self.close());
+        }
+    }
+}
+
+fn record<K, T>(
+    streams: &mut HashMap<K, RecordStream<T>>,
+    key: K,
+    item: T,
+    typename: &str,
+    encode: EncodeFn<T>,
+    config: &DataRecorderConfig,
+) -> anyhow::Result<()>
+where
+    K: Eq + Hash + Display,
+    T: GetTsInit,
+{
+    streams
+        .entry(key)
+        .or_insert_with_key(|key| {
+            let identifier = key.to_string().replace('/', "");
+            RecordStream::new(config.catalog_path.join(typename).join(identifier), encode)
+        })
+        .push(item, config)
+}
+
+fn utc_date(ts: UnixNanos) -> NaiveDate {
+    DateTime::from_timestamp_nanos(ts.as_i64()).date_naive()
+}
+
+fn open_file(dir: &Path, date: NaiveDate, schema: SchemaRef) -> anyhow::Result<OpenFile> {
+    fs::create_dir_all(dir)?;
+    let date_str = date.format("%Y%m%d");
+    let path = (0..)
+        .map(|n| match n {
+            0 => dir.join(format!("{date_str}.parquet")),
+            n => dir.join(format!("{date_str}_{n}.parquet")),
+        })
+        .find(|path| !path.exists())
+        .expect("Unbounded file name search");
+
+    let props = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(ZstdLevel::default()))
+        .build();
+    let writer = ArrowWriter::try_new(File::create(&path)?, schema.clone(), Some(props))?;
+    log::debug!("File opened: {}", path.display());
+    Ok(OpenFile {
+        writer,
+        schema,
+        path,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use nautilus_model::{
+        data::stubs::{quote_ethusdt_binance, stub_trade_ethusdt_buyer},
+        types::money::Money,
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+    fn quote(ts_init: u64) -> QuoteTick {
+        QuoteTick {
+            ts_event: ts_init.into(),
+            ts_init: ts_init.into(),
+            ..quote_ethusdt_binance()
+        }
+    }
+
+    fn read_rows(path: &Path) -> usize {
+        ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap().num_rows())
+            .sum()
+    }
+
+    #[rstest]
+    fn test_records_subscribed_topics() {
+        let catalog = TempDir::new().unwrap();
+        let recorder = Rc::new(DataRecorder::new(DataRecorderConfig::new(catalog.path())).unwrap());
+        let mut msgbus = MessageBus::default();
+        recorder.subscribe(&mut msgbus);
+
+        let quote_topic = Ustr::from("data.quotes.BINANCE.ETHUSDT-PERP");
+        let trade_topic = Ustr::from("data.trades.BINANCE.ETHUSDT-PERP");
+        msgbus.publish(&quote_topic, &quote(1) as &dyn Any);
+        msgbus.publish(&quote_topic, &quote(2) as &dyn Any);
+        msgbus.publish(&trade_topic, &stub_trade_ethusdt_buyer() as &dyn Any);
+        assert_eq!(recorder.buffered_rows(), 3);
+
+        recorder.close().unwrap();
+
+        assert_eq!(recorder.buffered_rows(), 0);
+        let quotes_path = catalog
+            .path()
+            .join("quote_tick/ETHUSDT-PERP.BINANCE/19700101.parquet");
+        let trades_path = catalog
+            .path()
+            .join("trade_tick/ETHUSDT-PERP.BINANCE/19700101.parquet");
+        assert_eq!(read_rows(&quotes_path), 2);
+        assert_eq!(read_rows(&trades_path), 1);
+    }
+
+    #[rstest]
+    fn test_flushes_and_rotates_by_day() {
+        let catalog = TempDir::new().unwrap();
+        let config = DataRecorderConfig {
+            flush_rows: 2,
+            ..DataRecorderConfig::new(catalog.path())
+        };
+        let recorder = DataRecorder::new(config).unwrap();
+
+        for ts_init in [1, 2, 3, NANOS_PER_DAY, NANOS_PER_DAY + 1] {
+            recorder.record(&Data::Quote(quote(ts_init))).unwrap();
+        }
+        assert_eq!(recorder.buffered_rows(), 0);
+        recorder
+            .record(&Data::Quote(quote(NANOS_PER_DAY + 2)))
+            .unwrap();
+        assert_eq!(recorder.buffered_rows(), 1);
+        recorder.close().unwrap();
+
+        let dir = catalog.path().join("quote_tick/ETHUSDT-PERP.BINANCE");
+        assert_eq!(read_rows(&dir.join("19700101.parquet")), 3);
+        assert_eq!(read_rows(&dir.join("19700102.parquet")), 3);
+    }
+
+    #[rstest]
+    fn test_existing_file_is_not_overwritten() {
+        let catalog = TempDir::new().unwrap();
+        for _ in 0..2 {
+            let recorder = DataRecorder::new(DataRecorderConfig::new(catalog.path())).unwrap();
+            recorder.record(&Data::Quote(quote(1))).unwrap();
+            drop(recorder); // Closes the file
+        }
+
+        let dir = catalog.path().join("quote_tick/ETHUSDT-PERP.BINANCE");
+        assert_eq!(read_rows(&dir.join("19700101.parquet")), 1);
+        assert_eq!(read_rows(&dir.join("19700101_1.parquet")), 1);
+    }
+
+    #[rstest]
+    fn test_gap_markers() {
+        let catalog = TempDir::new().unwrap();
+        let recorder = DataRecorder::new(DataRecorderConfig::new(catalog.path())).unwrap();
+        let client_id = ClientId::from("BINANCE");
+
+        recorder.record(&Data::Quote(quote(1))).unwrap();
+        recorder.on_disconnected(client_id, 10.into()).unwrap();
+        assert_eq!(recorder.buffered_rows(), 0);
+        recorder.on_reconnected(client_id, 20.into()).unwrap();
+        recorder.on_disconnected(client_id, 30.into()).unwrap();
+        recorder.close().unwrap();
+
+        let text = fs::read_to_string(catalog.path().join("data_gap/BINANCE.jsonl")).unwrap();
+        let gaps: Vec<DataGap> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            gaps,
+            vec![
+                DataGap {
+                    client_id,
+                    ts_start: 10.into(),
+                    ts_end: Some(20.into()),
+                },
+                DataGap {
+                    client_id,
+                    ts_start: 30.into(),
+                    ts_end: None,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_equity_updates() {
+        let catalog = TempDir::new().unwrap();
+        let mut config = DataRecorderConfig::new(catalog.path());
+        config.topics.push("events.equity".to_string());
+        let recorder = Rc::new(DataRecorder::new(config).unwrap());
+        let mut msgbus = MessageBus::default();
+        recorder.subscribe(&mut msgbus);
+        let updates: Vec<EquityUpdate> = [("1000 USD", 0.0), ("1010 USD", 0.01)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (equity, period_return))| EquityUpdate {
+                equity: Money::from(equity),
+                balance: Money::from(equity),
+                unrealized_pnl: Money::from("0 USD"),
+                period_return,
+                ts_event: (i as u64).into(),
+                ts_init: (i as u64).into(),
+            })
+            .collect();
+
+        let topic = msgbus.switchboard.equity_topic;
+        for update in &updates {
+            msgbus.publish(&topic, update as &dyn Any);
+        }
+
+        assert_eq!(recorder.buffered_rows(), 0);
+        assert_eq!(
+            read_equity_updates(catalog.path(), Currency::USD()).unwrap(),
+            updates
+        );
+        assert!(read_equity_updates(catalog.path(), Currency::EUR()).is_err());
+    }
+}
@@ -13,10 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
-    trade::TradeTick, Data,
+use nautilus_core::{ffi::cvec::CVec, nanos::UnixNanos};
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        bar_integrity::{validate_bars, BarRepairStrategy},
+        corporate_action::{CorporateAction, CorporateActionAdjuster},
+        delta::OrderBookDelta,
+        is_monotonically_increasing_by_init,
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data,
+    },
+    identifiers::InstrumentId,
+    types::{price::Price, quantity::Quantity},
 };
 use nautilus_persistence::{
     backend::session::{DataBackendSession, DataQueryResult, QueryResult},
@@ -27,6 +37,7 @@ use nautilus_test_kit::common::get_test_data_file_path;
 use procfs::{self, process::Process};
 use pyo3::{prelude::*, types::PyCapsule};
 use rstest::rstest;
+use rust_decimal_macros::dec;
 
 /// Memory leak test
 ///
@@ -176,6 +187,35 @@ fn test_quote_tick_python_control_flow() {
     });
 }
 
+#[rstest]
+fn test_quote_tick_python_data_stream() {
+    pyo3::prepare_freethreaded_python();
+
+    let file_path = get_test_data_file_path("nautilus/quotes.parquet");
+    let expected_length = 9500;
+    let catalog = DataBackendSession::new(1_000);
+    Python::with_gil(|py| {
+        let pycatalog: Py<PyAny> = catalog.into_py(py);
+        pycatalog
+            .call_method1(
+                py,
+                "add_file",
+                (NautilusDataType::QuoteTick, "quotes", file_path.as_str()),
+            )
+            .unwrap();
+        let stream = pycatalog.call_method0(py, "to_data_stream").unwrap();
+        let quotes: Vec<QuoteTick> = stream
+            .bind(py)
+            .iter()
+            .unwrap()
+            .map(|item| item.unwrap().extract().unwrap())
+            .collect();
+
+        assert_eq!(quotes.len(), expected_length);
+        assert!(is_monotonically_increasing_by_init(&quotes));
+    });
+}
+
 #[ignore] // TODO: Investigate why this is suddenly failing the monotonically increasing assert?
 #[rstest]
 fn test_order_book_delta_query() {
@@ -320,3 +360,64 @@ fn test_bar_query() {
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
 }
+
+#[rstest]
+fn test_trade_tick_query_with_split_adjustment() {
+    let file_path = get_test_data_file_path("nautilus/trades.parquet");
+    let mut catalog = DataBackendSession::new(10_000);
+    catalog
+        .add_file::<TradeTick>("trade_001", file_path.as_str(), None)
+        .unwrap();
+    let raw_ticks: Vec<Data> = catalog.get_query_result().collect();
+
+    catalog
+        .add_file::<TradeTick>("trade_002", file_path.as_str(), None)
+        .unwrap();
+    let split = CorporateAction::new_split(
+        InstrumentId::from("EUR/USD.SIM"),
+        dec!(2),
+        UnixNanos::from(u64::MAX),
+        UnixNanos::from(u64::MAX),
+    );
+    let query_result = DataQueryResult::new(catalog.get_query_result(), catalog.chunk_size)
+        .with_adjuster(CorporateActionAdjuster::new(vec![split]));
+    let adjusted_ticks: Vec<Data> = query_result.take(1).flatten().collect();
+
+    assert_eq!(adjusted_ticks.len(), raw_ticks.len());
+    match (&raw_ticks[0], &adjusted_ticks[0]) {
+        (Data::Trade(raw), Data::Trade(adjusted)) => {
+            assert_eq!(
+                adjusted.price,
+                Price::new(raw.price.as_f64() / 2.0, raw.price.precision)
+            );
+            assert_eq!(
+                adjusted.size,
+                Quantity::new(raw.size.as_f64() * 2.0, raw.size.precision)
+            );
+        }
+        _ => panic!("Invalid test"),
+    }
+}
+
+#[rstest]
+fn test_bar_query_with_repair() {
+    let file_path = get_test_data_file_path("nautilus/bars.parquet");
+    let mut catalog = DataBackendSession::new(10_000);
+    catalog
+        .add_file::<Bar>("bar_001", file_path.as_str(), None)
+        .unwrap();
+    let query_result = DataQueryResult::new(catalog.get_query_result(), catalog.chunk_size)
+        .with_bar_repair(BarRepairStrategy::ForwardFill);
+    let bars: Vec<Bar> = query_result
+        .take_while(|chunk| !chunk.is_empty())
+        .flatten()
+        .map(|data| match data {
+            Data::Bar(bar) => bar,
+            _ => panic!("Invalid test"),
+        })
+        .collect();
+
+    assert!(!bars.is_empty());
+    assert!(is_monotonically_increasing_by_init(&bars));
+    assert_eq!(validate_bars(&bars), vec![]);
+}
@@ -0,0 +1,450 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Portfolio risk analytics with historical-simulation value at risk and stress testing.
+//!
+//! Positions are valued in the base currency with a [`ValuationService`]. The historical
+//! simulation applies each stored set of instrument returns to the current position values,
+//! while stress scenarios apply price and volatility shocks per asset class.
+
+use std::collections::{BTreeMap, HashMap};
+
+use indexmap::IndexMap;
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos};
+use nautilus_model::{
+    enums::AssetClass,
+    identifiers::{InstrumentId, PositionId},
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::{currency::Currency, money::Money},
+};
+use ustr::Ustr;
+
+use crate::valuation::ValuationService;
+
+/// Represents a shock applied to the positions of an asset class.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StressShock {
+    /// The relative price change, e.g. -0.1 for a 10% fall.
+    pub price_shock: f64,
+    /// The implied volatility change in vol points, e.g. 5.0 for +5 vol, applied to positions
+    /// with a vega.
+    pub vol_shock: f64,
+}
+
+/// Represents a user-defined stress scenario of shocks per asset class.
+///
+/// Positions in asset classes without a shock are unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StressScenario {
+    pub name: Ustr,
+    pub shocks: HashMap<AssetClass, StressShock>,
+}
+
+impl StressScenario {
+    /// Creates a new [`StressScenario`] instance.
+    #[must_use]
+    pub fn new(name: &str, shocks: HashMap<AssetClass, StressShock>) -> Self {
+        Self {
+            name: Ustr::from(name),
+            shocks,
+        }
+    }
+}
+
+/// Represents the value at risk and expected shortfall of a portfolio, as positive losses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ValueAtRisk {
+    pub confidence: f64,
+    pub var: Money,
+    pub expected_shortfall: Money,
+    /// The number of historical return observations simulated.
+    pub observations: usize,
+}
+
+/// Represents the result of applying a stress scenario to the portfolio.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StressResult {
+    pub scenario: Ustr,
+    /// The total PnL of the portfolio under the scenario.
+    pub pnl: Money,
+    pub position_pnls: HashMap<PositionId, Money>,
+}
+
+/// Provides value at risk and stress testing of portfolio positions in a base currency.
+#[derive(Clone, Debug)]
+pub struct RiskAnalytics {
+    base_currency: Currency,
+    returns: HashMap<InstrumentId, BTreeMap<UnixNanos, f64>>,
+    asset_classes: HashMap<InstrumentId, AssetClass>,
+    vegas: HashMap<InstrumentId, f64>,
+    scenarios: IndexMap<Ustr, StressScenario>,
+}
+
+impl RiskAnalytics {
+    /// Creates a new [`RiskAnalytics`] instance.
+    #[must_use]
+    pub fn new(base_currency: Currency) -> Self {
+        Self {
+            base_currency,
+            returns: HashMap::new(),
+            asset_classes: HashMap::new(),
+            vegas: HashMap::new(),
+            scenarios: IndexMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn base_currency(&self) -> Currency {
+        self.base_currency
+    }
+
+    /// Adds the given `instrument`, whose asset class determines the stress shocks applied.
+    pub fn add_instrument(&mut self, instrument: &InstrumentAny) {
+        self.asset_classes
+            .insert(instrument.id(), instrument.asset_class());
+    }
+
+    /// Adds the return for the given `instrument_id` over the period ending at `ts`.
+    pub fn add_return(&mut self, instrument_id: InstrumentId, ts: UnixNanos, value: f64) {
+        self.returns
+            .entry(instrument_id)
+            .or_default()
+            .insert(ts, value);
+    }
+
+    /// Adds the series of `returns` for the given `instrument_id`.
+    pub fn add_returns(&mut self, instrument_id: InstrumentId, returns: &BTreeMap<UnixNanos, f64>) {
+        self.returns
+            .entry(instrument_id)
+            .or_default()
+            .extend(returns);
+    }
+
+    /// Returns the stored return series for the given `instrument_id` (if any).
+    #[must_use]
+    pub fn returns(&self, instrument_id: &InstrumentId) -> Option<&BTreeMap<UnixNanos, f64>> {
+        self.returns.get(instrument_id)
+    }
+
+    /// Updates the `vega` for the given option `instrument_id`, as the change in price per unit
+    /// quantity for a one vol point change in implied volatility.
+    pub fn update_vega(&mut self, instrument_id: InstrumentId, vega: f64) {
+        self.vegas.insert(instrument_id, vega);
+    }
+
+    /// Adds the given stress `scenario`, replacing any scenario with the same name.
+    pub fn add_scenario(&mut self, scenario: StressScenario) {
+        self.scenarios.insert(scenario.name, scenario);
+    }
+
+    /// Returns the stress scenarios in the order added.
+    pub fn scenarios(&self) -> impl Iterator<Item = &StressScenario> {
+        self.scenarios.values()
+    }
+
+    /// Returns the historical-simulation value at risk and expected shortfall of the given
+    /// `positions` at the `confidence` level, e.g. 0.99.
+    ///
+    /// Only the timestamps with a return for every instrument held are simulated.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The `confidence` is not in the range (0, 1).
+    /// - A position could not be valued in the base currency.
+    /// - There are no return observations common to the instruments held.
+    pub fn value_at_risk(
+        &self,
+        positions: &[&Position],
+        valuation: &ValuationService,
+        confidence: f64,
+    ) -> anyhow::Result<ValueAtRisk> {
+        check_predicate_true(
+            confidence > 0.0 && confidence < 1.0,
+            "`confidence` was not in range (0, 1)",
+        )?;
+
+        let mut exposures: HashMap<InstrumentId, f64> = HashMap::new();
+        for position in positions {
+            let value = valuation.value_position(position, self.base_currency)?;
+            *exposures.entry(position.instrument_id).or_default() += value.as_f64();
+        }
+
+        let mut pnls = self.simulate_pnls(&exposures)?;
+        pnls.sort_by(f64::total_cmp);
+
+        let tail = ((1.0 - confidence) * pnls.len() as f64).ceil().max(1.0) as usize;
+        let var = -pnls[tail - 1];
+        let expected_shortfall = -pnls[..tail].iter().sum::<f64>() / tail as f64;
+
+        Ok(ValueAtRisk {
+            confidence,
+            var: Money::new(var, self.base_currency),
+            expected_shortfall: Money::new(expected_shortfall, self.base_currency),
+            observations: pnls.len(),
+        })
+    }
+
+    /// Applies the stress scenario with the given `name` to the `positions`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The scenario has not been added.
+    /// - The asset class of a position's instrument is unknown.
+    /// - A position could not be valued in the base currency.
+    pub fn stress_test(
+        &self,
+        positions: &[&Position],
+        valuation: &ValuationService,
+        name: &str,
+    ) -> anyhow::Result<StressResult> {
+        let scenario = self
+            .scenarios
+            .get(&Ustr::from(name))
+            .ok_or_else(|| anyhow::anyhow!("No stress scenario '{name}'"))?;
+
+        let mut pnl = Money::new(0.0, self.base_currency);
+        let mut position_pnls = HashMap::new();
+        for position in positions {
+            let position_pnl = self.shock_position(position, valuation, scenario)?;
+            pnl += position_pnl;
+            position_pnls.insert(position.id, position_pnl);
+        }
+
+        Ok(StressResult {
+            scenario: scenario.name,
+            pnl,
+            position_pnls,
+        })
+    }
+
+    /// Applies every added stress scenario to the `positions`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any scenario could not be applied.
+    pub fn stress_test_all(
+        &self,
+        positions: &[&Position],
+        valuation: &ValuationService,
+    ) -> anyhow::Result<Vec<StressResult>> {
+        self.scenarios
+            .keys()
+            .map(|name| self.stress_test(positions, valuation, name))
+            .collect()
+    }
+
+    fn simulate_pnls(&self, exposures: &HashMap<InstrumentId, f64>) -> anyhow::Result<Vec<f64>> {
+        let mut series = Vec::with_capacity(exposures.len());
+        for (instrument_id, exposure) in exposures {
+            let returns = self
+                .returns
+                .get(instrument_id)
+                .ok_or_else(|| anyhow::anyhow!("No returns for {instrument_id}"))?;
+            series.push((returns, *exposure));
+        }
+        let Some((first, _)) = series.first() else {
+            anyhow::bail!("No positions to simulate");
+        };
+
+        let pnls: Vec<f64> = first
+            .keys()
+            .filter_map(|ts| {
+                series.iter().try_fold(0.0, |total, (returns, exposure)| {
+                    returns.get(ts).map(|value| total + exposure * value)
+                })
+            })
+            .collect();
+        if pnls.is_empty() {
+            anyhow::bail!("No return observations common to all instruments held");
+        }
+        Ok(pnls)
+    }
+
+    fn shock_position(
+        &self,
+        position: &Position,
+        valuation: &ValuationService,
+        scenario: &StressScenario,
+    ) -> anyhow::Result<Money> {
+        let asset_class = self
+            .asset_classes
+            .get(&position.instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No asset class for {}", position.instrument_id))?;
+        let Some(shock) = scenario.shocks.get(asset_class) else {
+            return Ok(Money::new(0.0, self.base_currency));
+        };
+
+        let value = valuation.value_position(position, self.base_currency)?;
+        let mut pnl = Money::new(value.as_f64() * shock.price_shock, self.base_currency);
+        if let Some(vega) = self.vegas.get(&position.instrument_id) {
+            let vol_pnl = Money::new(
+                vega * position.signed_qty * shock.vol_shock,
+                position.quote_currency,
+            );
+            pnl += valuation.convert(vol_pnl, self.base_currency)?;
+        }
+        Ok(pnl)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            None,
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    fn analytics(instrument: &InstrumentAny) -> RiskAnalytics {
+        let mut analytics = RiskAnalytics::new(Currency::USD());
+        analytics.add_instrument(instrument);
+        let returns = [-0.03, -0.02, -0.01, 0.0, 0.01, 0.01, 0.02, 0.02, 0.03, 0.04];
+        for (i, value) in returns.into_iter().enumerate() {
+            analytics.add_return(instrument.id(), UnixNanos::from(i as u64), value);
+        }
+        analytics
+    }
+
+    fn valuation(instrument: &InstrumentAny) -> ValuationService {
+        let mut valuation = ValuationService::default();
+        valuation.update_mark_price(instrument.id(), Price::from("0.80000"));
+        valuation
+    }
+
+    #[rstest]
+    #[case(0.9, "2400 USD", "2400 USD")]
+    #[case(0.8, "1600 USD", "2000 USD")]
+    fn test_value_at_risk(
+        audusd_sim: CurrencyPair,
+        #[case] confidence: f64,
+        #[case] expected_var: &str,
+        #[case] expected_es: &str,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy);
+        let analytics = analytics(&instrument);
+
+        let result = analytics
+            .value_at_risk(&[&long], &valuation(&instrument), confidence)
+            .unwrap();
+
+        // 80,000 USD long exposure
+        assert_eq!(result.var, Money::from(expected_var));
+        assert_eq!(result.expected_shortfall, Money::from(expected_es));
+        assert_eq!(result.observations, 10);
+    }
+
+    #[rstest]
+    fn test_value_at_risk_short_loses_on_rising_returns(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let short = position(&instrument, OrderSide::Sell);
+        let analytics = analytics(&instrument);
+
+        let result = analytics
+            .value_at_risk(&[&short], &valuation(&instrument), 0.9)
+            .unwrap();
+
+        assert_eq!(result.var, Money::from("3200 USD"));
+    }
+
+    #[rstest]
+    fn test_value_at_risk_errors(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy);
+        let valuation = valuation(&instrument);
+
+        assert!(analytics(&instrument)
+            .value_at_risk(&[&long], &valuation, 1.0)
+            .is_err());
+        assert!(RiskAnalytics::new(Currency::USD())
+            .value_at_risk(&[&long], &valuation, 0.95)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_stress_test_price_and_vol_shocks(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy);
+        let mut analytics = analytics(&instrument);
+        analytics.add_scenario(StressScenario::new(
+            "fx_crash",
+            HashMap::from([(
+                AssetClass::FX,
+                StressShock {
+                    price_shock: -0.1,
+                    vol_shock: 5.0,
+                },
+            )]),
+        ));
+        analytics.add_scenario(StressScenario::new(
+            "equity_crash",
+            HashMap::from([(
+                AssetClass::Equity,
+                StressShock {
+                    price_shock: -0.2,
+                    vol_shock: 0.0,
+                },
+            )]),
+        ));
+
+        let valuation = valuation(&instrument);
+        let result = analytics
+            .stress_test(&[&long], &valuation, "fx_crash")
+            .unwrap();
+        assert_eq!(result.pnl, Money::from("-8000 USD"));
+        assert_eq!(result.position_pnls[&long.id], Money::from("-8000 USD"));
+
+        analytics.update_vega(instrument.id(), 0.001);
+        let results = analytics.stress_test_all(&[&long], &valuation).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].pnl, Money::from("-7500 USD"));
+        assert_eq!(results[1].pnl, Money::from("0 USD"));
+
+        assert!(analytics
+            .stress_test(&[&long], &valuation, "unknown")
+            .is_err());
+    }
+}
@@ -0,0 +1,290 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trade compliance checks.
+//!
+//! The [`ComplianceChecker`] checks new orders against a restricted instrument list, a maximum
+//! order to trade ratio per instrument, and for potential wash trades, being orders opposite to
+//! another order for the same instrument within a time window. Orders which breach a rule are
+//! denied by the `RiskEngine` with an auditable `ComplianceViolated` event.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use nautilus_common::{
+    config::{check_setting, ValidateConfig},
+    messages::risk::ComplianceBreach,
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{ClientOrderId, InstrumentId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for trade compliance checks.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComplianceConfig {
+    /// The instruments for which all orders are denied.
+    pub restricted_instruments: HashSet<InstrumentId>,
+    /// The maximum ratio of orders to trades per instrument, e.g. 20 for 20:1
+    /// (not checked if None).
+    pub max_order_to_trade_ratio: Option<usize>,
+    /// The number of orders for an instrument before the order to trade ratio is checked.
+    pub order_to_trade_min_orders: usize,
+    /// The window (seconds) in which opposite orders for the same instrument are denied as
+    /// potential wash trades (not checked if None).
+    pub wash_trade_window_secs: Option<u64>,
+}
+
+impl ValidateConfig for ComplianceConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.max_order_to_trade_ratio != Some(0),
+            "risk_engine.compliance.max_order_to_trade_ratio",
+            "must be positive",
+        )?;
+        check_setting(
+            self.wash_trade_window_secs != Some(0),
+            "risk_engine.compliance.wash_trade_window_secs",
+            "must be positive",
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RecentOrder {
+    client_order_id: ClientOrderId,
+    side: OrderSide,
+    ts: UnixNanos,
+}
+
+#[derive(Debug, Default)]
+struct InstrumentActivity {
+    orders: usize,
+    trades: usize,
+    recent_orders: VecDeque<RecentOrder>,
+}
+
+/// Checks orders against the trade compliance rules.
+#[derive(Debug, Default)]
+pub struct ComplianceChecker {
+    config: ComplianceConfig,
+    activity: HashMap<InstrumentId, InstrumentActivity>,
+}
+
+impl ComplianceChecker {
+    /// Creates a new [`ComplianceChecker`] instance.
+    #[must_use]
+    pub fn new(config: ComplianceConfig) -> Self {
+        Self {
+            config,
+            activity: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the given `instrument_id` is restricted.
+    #[must_use]
+    pub fn is_restricted(&self, instrument_id: &InstrumentId) -> bool {
+        self.config.restricted_instruments.contains(instrument_id)
+    }
+
+    /// Adds or removes the given `instrument_id` from the restricted list.
+    pub fn set_restricted(&mut self, instrument_id: InstrumentId, restricted: bool) {
+        if restricted {
+            self.config.restricted_instruments.insert(instrument_id);
+        } else {
+            self.config.restricted_instruments.remove(&instrument_id);
+        }
+    }
+
+    /// Returns the counts of orders and trades for the given `instrument_id`.
+    #[must_use]
+    pub fn order_trade_counts(&self, instrument_id: &InstrumentId) -> (usize, usize) {
+        self.activity
+            .get(instrument_id)
+            .map_or((0, 0), |activity| (activity.orders, activity.trades))
+    }
+
+    /// Records a trade for the given `instrument_id`, for the order to trade ratio.
+    pub fn record_trade(&mut self, instrument_id: InstrumentId) {
+        self.activity.entry(instrument_id).or_default().trades += 1;
+    }
+
+    /// Checks an order for the given instrument with the given `side` at `ts`, returning the
+    /// first rule it would breach.
+    ///
+    /// Orders which pass are counted towards the order to trade ratio, and for detecting wash
+    /// trades against subsequent orders.
+    pub fn check_order(
+        &mut self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        side: OrderSide,
+        ts: UnixNanos,
+    ) -> Option<ComplianceBreach> {
+        if self.is_restricted(&instrument_id) {
+            return Some(ComplianceBreach::RestrictedInstrument);
+        }
+
+        let activity = self.activity.entry(instrument_id).or_default();
+        if let Some(limit) = self.config.max_order_to_trade_ratio {
+            let orders = activity.orders + 1;
+            let trades = activity.trades;
+            if orders > self.config.order_to_trade_min_orders && orders > limit * trades.max(1) {
+                return Some(ComplianceBreach::OrderToTradeRatio {
+                    limit,
+                    orders,
+                    trades,
+                });
+            }
+        }
+
+        if let Some(window_secs) = self.config.wash_trade_window_secs {
+            let window_ns = window_secs * NANOSECONDS_IN_SECOND;
+            while activity
+                .recent_orders
+                .front()
+                .is_some_and(|front| front.ts.as_u64() + window_ns <= ts.as_u64())
+            {
+                activity.recent_orders.pop_front();
+            }
+            if let Some(opposite) = activity
+                .recent_orders
+                .iter()
+                .rev()
+                .find(|recent| recent.side != side)
+            {
+                return Some(ComplianceBreach::WashTrade {
+                    opposite_order_id: opposite.client_order_id,
+                    window_secs,
+                });
+            }
+            activity.recent_orders.push_back(RecentOrder {
+                client_order_id,
+                side,
+                ts,
+            });
+        }
+
+        activity.orders += 1;
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn check(
+        checker: &mut ComplianceChecker,
+        client_order_id: &str,
+        side: OrderSide,
+        ts_secs: u64,
+    ) -> Option<ComplianceBreach> {
+        checker.check_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            ClientOrderId::from(client_order_id),
+            side,
+            UnixNanos::from(ts_secs * NANOSECONDS_IN_SECOND),
+        )
+    }
+
+    #[rstest]
+    fn test_restricted_instrument_denied() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let mut checker = ComplianceChecker::new(ComplianceConfig {
+            restricted_instruments: HashSet::from([instrument_id]),
+            ..Default::default()
+        });
+
+        let breach = check(&mut checker, "O-1", OrderSide::Buy, 0);
+        assert_eq!(breach, Some(ComplianceBreach::RestrictedInstrument));
+
+        checker.set_restricted(instrument_id, false);
+        assert!(check(&mut checker, "O-2", OrderSide::Buy, 0).is_none());
+        assert_eq!(checker.order_trade_counts(&instrument_id), (1, 0));
+    }
+
+    #[rstest]
+    fn test_order_to_trade_ratio_breach() {
+        let mut checker = ComplianceChecker::new(ComplianceConfig {
+            max_order_to_trade_ratio: Some(2),
+            order_to_trade_min_orders: 3,
+            ..Default::default()
+        });
+
+        for i in 0..3 {
+            let breach = check(&mut checker, &format!("O-{i}"), OrderSide::Buy, 0);
+            assert!(breach.is_none());
+        }
+        let breach = check(&mut checker, "O-3", OrderSide::Buy, 0);
+        assert_eq!(
+            breach,
+            Some(ComplianceBreach::OrderToTradeRatio {
+                limit: 2,
+                orders: 4,
+                trades: 0,
+            })
+        );
+
+        checker.record_trade(InstrumentId::from("AUD/USD.SIM"));
+        checker.record_trade(InstrumentId::from("AUD/USD.SIM"));
+        assert!(check(&mut checker, "O-3", OrderSide::Buy, 0).is_none());
+    }
+
+    #[rstest]
+    fn test_opposite_order_within_window_is_wash_trade() {
+        let mut checker = ComplianceChecker::new(ComplianceConfig {
+            wash_trade_window_secs: Some(60),
+            ..Default::default()
+        });
+
+        assert!(check(&mut checker, "O-1", OrderSide::Buy, 0).is_none());
+        assert!(check(&mut checker, "O-2", OrderSide::Buy, 30).is_none());
+
+        let breach = check(&mut checker, "O-3", OrderSide::Sell, 59);
+        assert_eq!(
+            breach,
+            Some(ComplianceBreach::WashTrade {
+                opposite_order_id: ClientOrderId::from("O-2"),
+                window_secs: 60,
+            })
+        );
+
+        // Both buy orders have left the window
+        assert!(check(&mut checker, "O-4", OrderSide::Sell, 90).is_none());
+    }
+
+    #[rstest]
+    #[case(Some(0), None)]
+    #[case(None, Some(0))]
+    fn test_config_validation_rejects_zero(
+        #[case] max_order_to_trade_ratio: Option<usize>,
+        #[case] wash_trade_window_secs: Option<u64>,
+    ) {
+        let config = ComplianceConfig {
+            max_order_to_trade_ratio,
+            wash_trade_window_secs,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,527 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `DeadMansSwitch` which cancels all open orders when a live node stops responding.
+//!
+//! The switch triggers when heartbeats from the strategies or host stop for longer than the
+//! heartbeat timeout, or when an execution client stays disconnected for longer than the grace
+//! period. On triggering, a `CancelAllOrders` command is sent to the execution engine for every
+//! strategy and instrument with open orders, except on a disconnected venue which natively
+//! cancels orders when its session drops. Trading is then halted on the `RiskEngine`, which
+//! closes all open positions when the switch is configured to flatten.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::{BTreeSet, HashSet},
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_execution::messages::{cancel_all::CancelAllOrders, TradingCommand};
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{ClientId, Venue},
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::engine::RiskEngine;
+
+/// The name of the timer which triggers the switch when heartbeats stop.
+pub const DEAD_MANS_SWITCH_TIMER: &str = "DeadMansSwitch";
+
+/// Configuration for `DeadMansSwitch` instances.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeadMansSwitchConfig {
+    /// The timeout (milliseconds) after the last heartbeat before the switch triggers.
+    pub heartbeat_timeout_ms: u64,
+    /// The grace period (milliseconds) for an execution client to reconnect before the switch
+    /// triggers.
+    pub disconnect_grace_ms: u64,
+    /// If open positions should be flattened when the switch triggers.
+    pub flatten: bool,
+    /// The venues which natively cancel open orders when the session disconnects.
+    pub venue_cancel_on_disconnect: HashSet<Venue>,
+}
+
+impl Default for DeadMansSwitchConfig {
+    /// Creates a new default [`DeadMansSwitchConfig`] instance.
+    fn default() -> Self {
+        Self {
+            heartbeat_timeout_ms: 30_000,
+            disconnect_grace_ms: 10_000,
+            flatten: false,
+            venue_cancel_on_disconnect: HashSet::new(),
+        }
+    }
+}
+
+/// The cause of the dead man's switch triggering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadMansSwitchTrigger {
+    /// No heartbeat was received within the heartbeat timeout.
+    HeartbeatTimeout,
+    /// The execution client for the venue did not reconnect within the grace period.
+    Disconnected(Venue),
+}
+
+impl Display for DeadMansSwitchTrigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HeartbeatTimeout => write!(f, "heartbeat timeout"),
+            Self::Disconnected(venue) => write!(f, "{venue} disconnected"),
+        }
+    }
+}
+
+/// Cancels all open orders and halts trading when heartbeats stop or a venue disconnects.
+pub struct DeadMansSwitch<C>
+where
+    C: Clock,
+{
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    risk_engine: Rc<RefCell<RiskEngine<C>>>,
+    config: DeadMansSwitchConfig,
+    triggered: Cell<Option<DeadMansSwitchTrigger>>,
+}
+
+impl<C> DeadMansSwitch<C>
+where
+    C: Clock + 'static,
+{
+    /// Creates a new [`DeadMansSwitch`] instance.
+    pub fn new(
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        risk_engine: Rc<RefCell<RiskEngine<C>>>,
+        config: DeadMansSwitchConfig,
+    ) -> Self {
+        Self {
+            clock,
+            cache,
+            msgbus,
+            risk_engine,
+            config,
+            triggered: Cell::new(None),
+        }
+    }
+
+    /// Returns the cause of the switch triggering, if triggered.
+    #[must_use]
+    pub fn triggered(&self) -> Option<DeadMansSwitchTrigger> {
+        self.triggered.get()
+    }
+
+    /// Records a heartbeat, restarting the heartbeat timeout.
+    ///
+    /// The first heartbeat arms the switch. Heartbeats after the switch has triggered are
+    /// ignored until it is reset.
+    pub fn heartbeat(self: &Rc<Self>) {
+        if self.triggered.get().is_some() {
+            return;
+        }
+
+        let switch = self.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            switch.trigger(DeadMansSwitchTrigger::HeartbeatTimeout, event.ts_event);
+        }));
+        let mut clock = self.clock.borrow_mut();
+        let alert_time_ns = clock.timestamp_ns() + self.config.heartbeat_timeout_ms * 1_000_000;
+        if clock.timer_names().contains(&DEAD_MANS_SWITCH_TIMER) {
+            clock.cancel_timer(DEAD_MANS_SWITCH_TIMER);
+        }
+        clock.set_time_alert_ns(DEAD_MANS_SWITCH_TIMER, alert_time_ns, Some(callback));
+    }
+
+    /// Handles the execution client for the given `venue` disconnecting, triggering the switch
+    /// unless it reconnects within the grace period.
+    pub fn on_disconnected(self: &Rc<Self>, venue: Venue) {
+        if self.triggered.get().is_some() {
+            return;
+        }
+        log::warn!(
+            "{venue} disconnected, cancelling all orders in {}ms unless reconnected",
+            self.config.disconnect_grace_ms,
+        );
+
+        let switch = self.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            switch.trigger(DeadMansSwitchTrigger::Disconnected(venue), event.ts_event);
+        }));
+        let mut clock = self.clock.borrow_mut();
+        let alert_time_ns = clock.timestamp_ns() + self.config.disconnect_grace_ms * 1_000_000;
+        clock.set_time_alert_ns(
+            &disconnect_timer_name(&venue),
+            alert_time_ns,
+            Some(callback),
+        );
+    }
+
+    /// Handles the execution client for the given `venue` reconnecting within the grace period.
+    pub fn on_connected(&self, venue: Venue) {
+        let timer_name = disconnect_timer_name(&venue);
+        let mut clock = self.clock.borrow_mut();
+        if clock.timer_names().contains(&timer_name.as_str()) {
+            log::info!("{venue} reconnected within grace period");
+            clock.cancel_timer(&timer_name);
+        }
+    }
+
+    /// Disarms the switch, canceling all pending timeouts and resetting it if triggered.
+    pub fn reset(&self) {
+        let mut clock = self.clock.borrow_mut();
+        let timer_names: Vec<String> = clock
+            .timer_names()
+            .into_iter()
+            .filter(|name| name.starts_with(DEAD_MANS_SWITCH_TIMER))
+            .map(str::to_string)
+            .collect();
+        for timer_name in timer_names {
+            clock.cancel_timer(&timer_name);
+        }
+        self.triggered.set(None);
+    }
+
+    /// Triggers the switch for the given `reason` at `ts_event`, returning the count of
+    /// `CancelAllOrders` commands sent.
+    ///
+    /// Trading is halted on the risk engine, which closes all open positions if the switch is
+    /// configured to flatten.
+    pub fn trigger(&self, reason: DeadMansSwitchTrigger, ts_event: UnixNanos) -> usize {
+        if self.triggered.get().is_some() {
+            return 0;
+        }
+        self.triggered.set(Some(reason));
+        log::error!("Dead man's switch triggered: {reason}");
+
+        let skip_venue = match reason {
+            DeadMansSwitchTrigger::Disconnected(venue)
+                if self.config.venue_cancel_on_disconnect.contains(&venue) =>
+            {
+                log::info!("Open orders on {venue} canceled venue-side on disconnect");
+                Some(venue)
+            }
+            _ => None,
+        };
+
+        // One command per client, strategy and instrument with open orders
+        let targets: BTreeSet<_> = {
+            let cache = self.cache.borrow();
+            cache
+                .orders_open(None, None, None, None)
+                .into_iter()
+                .filter(|order| Some(order.instrument_id().venue) != skip_venue)
+                .map(|order| {
+                    let client_id = cache
+                        .client_id(&order.client_order_id())
+                        .copied()
+                        .unwrap_or_else(|| ClientId::new(order.instrument_id().venue.as_str()));
+                    (client_id, order.strategy_id(), order.instrument_id())
+                })
+                .collect()
+        };
+
+        let msgbus = self.msgbus.borrow();
+        for &(client_id, strategy_id, instrument_id) in &targets {
+            let command = TradingCommand::CancelAllOrders(CancelAllOrders {
+                trader_id: msgbus.trader_id,
+                client_id,
+                strategy_id,
+                instrument_id,
+                order_side: OrderSide::NoOrderSide,
+                command_id: UUID4::new(),
+                ts_init: ts_event,
+            });
+            log::warn!("Dead man's switch sending {command}");
+            msgbus.send(
+                &msgbus.switchboard.exec_engine_execute,
+                &command as &dyn Any,
+            );
+        }
+        drop(msgbus);
+
+        self.risk_engine.borrow_mut().halt(
+            Ustr::from(&format!("Dead man's switch: {reason}")),
+            self.config.flatten,
+        );
+
+        targets.len()
+    }
+}
+
+fn disconnect_timer_name(venue: &Venue) -> String {
+    format!("{DEAD_MANS_SWITCH_TIMER}-{venue}")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        messages::risk::TradingStateChanged,
+        msgbus::{
+            handler::ShareableMessageHandler,
+            stubs::{get_message_saving_handler, get_saved_messages},
+        },
+    };
+    use nautilus_execution::messages::submit::SubmitOrder;
+    use nautilus_model::{
+        enums::{OmsType, OrderType, TradingState},
+        events::order::OrderEventAny,
+        identifiers::{AccountId, InstrumentId, VenueOrderId},
+        instruments::{any::InstrumentAny, stubs::audusd_sim},
+        orders::{any::OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        stubs::stub_position_long,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::engine::config::RiskEngineConfig;
+
+    const MS: u64 = 1_000_000;
+
+    struct TestContext {
+        clock: Rc<RefCell<TestClock>>,
+        commands: ShareableMessageHandler,
+        events: ShareableMessageHandler,
+        risk_events: ShareableMessageHandler,
+        risk_engine: Rc<RefCell<RiskEngine<TestClock>>>,
+        switch: Rc<DeadMansSwitch<TestClock>>,
+    }
+
+    fn open_order(instrument_id: &str, client_order_id: &str) -> OrderAny {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from(instrument_id))
+            .client_order_id(client_order_id.into())
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let account_id = AccountId::from("SIM-001");
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                account_id,
+                VenueOrderId::from(client_order_id),
+            ))
+            .unwrap();
+        order
+    }
+
+    fn context(config: DeadMansSwitchConfig) -> TestContext {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        cache
+            .add_position(stub_position_long(audusd_sim()), OmsType::Netting)
+            .unwrap();
+        for order in [
+            open_order("AUD/USD.SIM", "O-1"),
+            open_order("EUR/USD.SIM", "O-2"),
+            open_order("ETHUSDT.BINANCE", "O-3"),
+        ] {
+            cache.add_order(order.clone(), None, None, false).unwrap();
+            cache.update_order(&order).unwrap();
+        }
+
+        let cache = Rc::new(RefCell::new(cache));
+
+        let commands = get_message_saving_handler::<TradingCommand>(None);
+        let events = get_message_saving_handler::<OrderEventAny>(None);
+        let risk_events = get_message_saving_handler::<TradingStateChanged>(None);
+        let mut msgbus = MessageBus::default();
+        msgbus.register(msgbus.switchboard.exec_engine_execute, commands.clone());
+        msgbus.register(msgbus.switchboard.exec_engine_process, events.clone());
+        let topic = msgbus.switchboard.risk_events_topic;
+        msgbus.subscribe(topic, risk_events.clone(), None);
+        let msgbus = Rc::new(RefCell::new(msgbus));
+
+        let risk_engine = Rc::new(RefCell::new(
+            RiskEngine::new(
+                clock.clone(),
+                cache.clone(),
+                msgbus.clone(),
+                RiskEngineConfig::default(),
+            )
+            .unwrap(),
+        ));
+        let switch = Rc::new(DeadMansSwitch::new(
+            clock.clone(),
+            cache,
+            msgbus,
+            risk_engine.clone(),
+            config,
+        ));
+        TestContext {
+            clock,
+            commands,
+            events,
+            risk_events,
+            risk_engine,
+            switch,
+        }
+    }
+
+    #[rstest]
+    fn test_heartbeat_timeout_cancels_all_and_halts() {
+        let config = DeadMansSwitchConfig {
+            heartbeat_timeout_ms: 100,
+            flatten: true,
+            ..Default::default()
+        };
+        let ctx = context(config);
+
+        ctx.switch.heartbeat();
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(90 * MS));
+        ctx.switch.heartbeat();
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(180 * MS));
+        assert_eq!(ctx.switch.triggered(), None);
+
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(190 * MS));
+        assert_eq!(
+            ctx.switch.triggered(),
+            Some(DeadMansSwitchTrigger::HeartbeatTimeout)
+        );
+        let commands = get_saved_messages::<TradingCommand>(ctx.commands);
+        assert_eq!(commands.len(), 4);
+        assert!(commands[..3]
+            .iter()
+            .all(|command| matches!(command, TradingCommand::CancelAllOrders(_))));
+        let TradingCommand::SubmitOrder(close) = &commands[3] else {
+            panic!("Expected SubmitOrder, was {:?}", commands[3]);
+        };
+        let position = stub_position_long(audusd_sim());
+        assert_eq!(close.position_id, Some(position.id));
+        assert_eq!(close.order.order_side(), OrderSide::Sell);
+        assert_eq!(close.order.quantity(), position.quantity);
+        assert!(close.order.is_reduce_only());
+
+        assert_eq!(
+            ctx.risk_engine.borrow().trading_state(),
+            TradingState::Halted
+        );
+        let events = get_saved_messages::<TradingStateChanged>(ctx.risk_events);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].state, TradingState::Halted);
+        assert!(events[0].flatten);
+    }
+
+    #[rstest]
+    fn test_trigger_halts_risk_engine_and_denies_later_orders() {
+        let ctx = context(DeadMansSwitchConfig::default());
+
+        ctx.switch.trigger(
+            DeadMansSwitchTrigger::HeartbeatTimeout,
+            UnixNanos::default(),
+        );
+
+        // Positions are left open without flatten
+        let commands = get_saved_messages::<TradingCommand>(ctx.commands.clone());
+        assert_eq!(commands.len(), 3);
+        assert!(commands
+            .iter()
+            .all(|command| matches!(command, TradingCommand::CancelAllOrders(_))));
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim().id)
+            .client_order_id("O-4".into())
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("NONE"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        ctx.risk_engine
+            .borrow_mut()
+            .execute(TradingCommand::SubmitOrder(command));
+
+        assert_eq!(get_saved_messages::<TradingCommand>(ctx.commands).len(), 3);
+        let events = get_saved_messages::<OrderEventAny>(ctx.events);
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Denied(denied) = &events[0] else {
+            panic!("Expected OrderDenied, was {:?}", events[0]);
+        };
+        assert_eq!(denied.reason.as_str(), "TradingState::HALTED");
+    }
+
+    #[rstest]
+    fn test_reconnect_within_grace_period_does_not_trigger() {
+        let ctx = context(DeadMansSwitchConfig::default());
+
+        ctx.switch.on_disconnected(Venue::from("SIM"));
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(5_000 * MS));
+        ctx.switch.on_connected(Venue::from("SIM"));
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(20_000 * MS));
+
+        assert_eq!(ctx.switch.triggered(), None);
+        assert!(get_saved_messages::<TradingCommand>(ctx.commands).is_empty());
+    }
+
+    #[rstest]
+    fn test_disconnect_skips_venue_with_native_cancel_on_disconnect() {
+        let config = DeadMansSwitchConfig {
+            venue_cancel_on_disconnect: HashSet::from([Venue::from("SIM")]),
+            ..Default::default()
+        };
+        let ctx = context(config);
+
+        ctx.switch.on_disconnected(Venue::from("SIM"));
+        TestClock::advance_time_and_run(&ctx.clock, UnixNanos::from(10_000 * MS));
+
+        assert_eq!(
+            ctx.switch.triggered(),
+            Some(DeadMansSwitchTrigger::Disconnected(Venue::from("SIM")))
+        );
+        let commands = get_saved_messages::<TradingCommand>(ctx.commands);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(
+            commands[0].instrument_id(),
+            InstrumentId::from("ETHUSDT.BINANCE")
+        );
+
+        ctx.switch.reset();
+        assert_eq!(ctx.switch.triggered(), None);
+    }
+}
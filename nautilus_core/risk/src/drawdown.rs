@@ -0,0 +1,328 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A trailing drawdown guard for portfolio equity.
+//!
+//! The [`DrawdownGuard`] tracks the peak equity and reports a [`DrawdownBreach`] when the
+//! drawdown from the peak exceeds an absolute or percentage threshold. The `RiskEngine` then
+//! changes its trading state according to the configured [`DrawdownAction`].
+
+use anyhow::ensure;
+use nautilus_common::config::{check_setting, ValidateConfig};
+use nautilus_model::{enums::TradingState, types::money::Money};
+use serde::{Deserialize, Serialize};
+
+/// Represents the action taken when the drawdown threshold is breached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DrawdownAction {
+    /// Halt trading, denying all new order commands.
+    #[default]
+    Halt,
+    /// Only permit orders which reduce positions.
+    Reduce,
+    /// Only permit orders which reduce positions, and request all open positions are closed.
+    Flatten,
+}
+
+impl DrawdownAction {
+    /// Returns the trading state for the action.
+    #[must_use]
+    pub const fn trading_state(&self) -> TradingState {
+        match self {
+            Self::Halt => TradingState::Halted,
+            Self::Reduce | Self::Flatten => TradingState::Reducing,
+        }
+    }
+}
+
+/// Configuration for `DrawdownGuard` instances.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrawdownGuardConfig {
+    /// The maximum drawdown from the peak equity (not checked if None).
+    pub max_drawdown: Option<Money>,
+    /// The maximum drawdown as a fraction of the peak equity, e.g. 0.1 for 10%
+    /// (not checked if None).
+    pub max_drawdown_pct: Option<f64>,
+    /// The action taken when a threshold is breached.
+    pub action: DrawdownAction,
+}
+
+impl ValidateConfig for DrawdownGuardConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_setting(
+            self.max_drawdown.is_some() || self.max_drawdown_pct.is_some(),
+            "risk_engine.drawdown",
+            "at least one of max_drawdown or max_drawdown_pct is required",
+        )?;
+        check_setting(
+            self.max_drawdown.is_none_or(|max| max.raw > 0),
+            "risk_engine.drawdown.max_drawdown",
+            "must be positive",
+        )?;
+        check_setting(
+            self.max_drawdown_pct
+                .is_none_or(|pct| pct > 0.0 && pct <= 1.0),
+            "risk_engine.drawdown.max_drawdown_pct",
+            "must be in the range (0, 1]",
+        )
+    }
+}
+
+/// Represents a breach of the drawdown thresholds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DrawdownBreach {
+    /// The peak equity.
+    pub peak: Money,
+    /// The equity which breached the thresholds.
+    pub equity: Money,
+    /// The drawdown from the peak equity.
+    pub drawdown: Money,
+    /// The drawdown as a fraction of the peak equity.
+    pub drawdown_pct: f64,
+    /// The action to take.
+    pub action: DrawdownAction,
+}
+
+impl DrawdownBreach {
+    /// Returns a description of the breach.
+    #[must_use]
+    pub fn reason(&self) -> String {
+        format!(
+            "Drawdown {} ({:.2}%) from peak equity {} exceeded limit",
+            self.drawdown,
+            self.drawdown_pct * 100.0,
+            self.peak,
+        )
+    }
+}
+
+/// Tracks the peak equity and checks the trailing drawdown against the configured thresholds.
+#[derive(Clone, Debug)]
+pub struct DrawdownGuard {
+    config: DrawdownGuardConfig,
+    peak: Option<Money>,
+    last: Option<Money>,
+    is_breached: bool,
+}
+
+impl DrawdownGuard {
+    /// Creates a new [`DrawdownGuard`] instance.
+    #[must_use]
+    pub const fn new(config: DrawdownGuardConfig) -> Self {
+        Self {
+            config,
+            peak: None,
+            last: None,
+            is_breached: false,
+        }
+    }
+
+    /// Returns the configuration for the guard.
+    #[must_use]
+    pub const fn config(&self) -> &DrawdownGuardConfig {
+        &self.config
+    }
+
+    /// Returns the peak equity, if any equity has been updated.
+    #[must_use]
+    pub const fn peak(&self) -> Option<Money> {
+        self.peak
+    }
+
+    /// Returns the current drawdown from the peak equity, if any equity has been updated.
+    #[must_use]
+    pub fn drawdown(&self) -> Option<Money> {
+        Some(self.peak? - self.last?)
+    }
+
+    /// Returns if the thresholds have been breached since the last reset.
+    #[must_use]
+    pub const fn is_breached(&self) -> bool {
+        self.is_breached
+    }
+
+    /// Updates the guard with the current `equity`, returning a breach when the drawdown first
+    /// exceeds a threshold. Further breaches are not reported until the guard is reset.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `equity` is not in the currency of the peak equity or
+    /// the `max_drawdown` threshold.
+    pub fn update(&mut self, equity: Money) -> anyhow::Result<Option<DrawdownBreach>> {
+        if let Some(max_drawdown) = self.config.max_drawdown {
+            ensure!(
+                equity.currency == max_drawdown.currency,
+                "Equity currency {} does not match max drawdown currency {}",
+                equity.currency,
+                max_drawdown.currency,
+            );
+        }
+        let peak = match self.peak {
+            Some(peak) => {
+                ensure!(
+                    equity.currency == peak.currency,
+                    "Equity currency {} does not match peak equity currency {}",
+                    equity.currency,
+                    peak.currency,
+                );
+                peak.max(equity)
+            }
+            None => equity,
+        };
+        self.peak = Some(peak);
+        self.last = Some(equity);
+
+        if self.is_breached {
+            return Ok(None);
+        }
+
+        let drawdown = peak - equity;
+        let drawdown_pct = if peak.raw > 0 {
+            drawdown.as_f64() / peak.as_f64()
+        } else {
+            0.0
+        };
+        let is_breached = self.config.max_drawdown.is_some_and(|max| drawdown > max)
+            || self
+                .config
+                .max_drawdown_pct
+                .is_some_and(|max| drawdown_pct > max);
+        if !is_breached {
+            return Ok(None);
+        }
+
+        self.is_breached = true;
+        Ok(Some(DrawdownBreach {
+            peak,
+            equity,
+            drawdown,
+            drawdown_pct,
+            action: self.config.action,
+        }))
+    }
+
+    /// Resets the guard, with the peak equity restarting from the next update.
+    pub fn reset(&mut self) {
+        self.peak = None;
+        self.last = None;
+        self.is_breached = false;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn guard(max_drawdown: Option<&str>, max_drawdown_pct: Option<f64>) -> DrawdownGuard {
+        DrawdownGuard::new(DrawdownGuardConfig {
+            max_drawdown: max_drawdown.map(Money::from),
+            max_drawdown_pct,
+            action: DrawdownAction::Flatten,
+        })
+    }
+
+    #[rstest]
+    fn test_tracks_trailing_peak() {
+        let mut guard = guard(Some("5000 USD"), None);
+
+        for equity in ["100000 USD", "110000 USD", "106000 USD"] {
+            assert!(guard.update(Money::from(equity)).unwrap().is_none());
+        }
+
+        assert_eq!(guard.peak(), Some(Money::from("110000 USD")));
+        assert_eq!(guard.drawdown(), Some(Money::from("4000 USD")));
+        assert!(!guard.is_breached());
+    }
+
+    #[rstest]
+    fn test_absolute_breach_reported_once() {
+        let mut guard = guard(Some("5000 USD"), None);
+        guard.update(Money::from("110000 USD")).unwrap();
+
+        let breach = guard.update(Money::from("104000 USD")).unwrap().unwrap();
+
+        assert_eq!(breach.peak, Money::from("110000 USD"));
+        assert_eq!(breach.drawdown, Money::from("6000 USD"));
+        assert_eq!(breach.action, DrawdownAction::Flatten);
+        assert_eq!(breach.action.trading_state(), TradingState::Reducing);
+        assert!(guard.is_breached());
+        assert!(guard.update(Money::from("100000 USD")).unwrap().is_none());
+    }
+
+    #[rstest]
+    fn test_percentage_breach() {
+        let mut guard = guard(None, Some(0.1));
+        guard.update(Money::from("200000 USD")).unwrap();
+        assert!(guard.update(Money::from("180000 USD")).unwrap().is_none());
+
+        let breach = guard.update(Money::from("179000 USD")).unwrap().unwrap();
+
+        assert!((breach.drawdown_pct - 0.105).abs() < 1e-9);
+        assert_eq!(
+            breach.reason(),
+            "Drawdown 21000.00 USD (10.50%) from peak equity 200000.00 USD exceeded limit"
+        );
+    }
+
+    #[rstest]
+    fn test_reset_restarts_peak() {
+        let mut guard = guard(Some("5000 USD"), None);
+        guard.update(Money::from("110000 USD")).unwrap();
+        guard.update(Money::from("100000 USD")).unwrap();
+
+        guard.reset();
+
+        assert!(guard.peak().is_none());
+        assert!(guard.update(Money::from("100000 USD")).unwrap().is_none());
+        assert_eq!(guard.peak(), Some(Money::from("100000 USD")));
+    }
+
+    #[rstest]
+    fn test_currency_mismatch() {
+        let mut guard = guard(Some("5000 USD"), None);
+        let err = guard.update(Money::from("100000 EUR")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Equity currency EUR does not match max drawdown currency USD"
+        );
+    }
+
+    #[rstest]
+    #[case(
+        None,
+        None,
+        "at least one of max_drawdown or max_drawdown_pct is required"
+    )]
+    #[case(Some("0 USD"), None, "must be positive")]
+    #[case(None, Some(1.5), "must be in the range (0, 1]")]
+    fn test_config_validation(
+        #[case] max_drawdown: Option<&str>,
+        #[case] max_drawdown_pct: Option<f64>,
+        #[case] expected: &str,
+    ) {
+        let err = guard(max_drawdown, max_drawdown_pct)
+            .config()
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().ends_with(expected), "{err}");
+    }
+}
@@ -17,17 +17,37 @@
 
 use std::collections::HashMap;
 
-use nautilus_common::throttler::RateLimit;
+use nautilus_common::{
+    config::{check_setting, ValidateConfig},
+    throttler::{budget::MessageBudget, RateLimit},
+};
 use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
-use nautilus_model::identifiers::InstrumentId;
+use nautilus_model::identifiers::{AccountId, InstrumentId, StrategyId, Venue};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{compliance::ComplianceConfig, drawdown::DrawdownGuardConfig, limits::StrategyLimits};
 
 /// Configuration for `RiskEngineConfig` instances.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RiskEngineConfig {
     pub bypass: bool,
     pub max_order_submit: RateLimit,
     pub max_order_modify: RateLimit,
     pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    /// The message budgets for venue sessions, shared by all strategies trading on the venue.
+    pub venue_budgets: HashMap<Venue, MessageBudget>,
+    /// If orders priced outside venue price limits are denied, otherwise they are only flagged.
+    pub reject_price_limit_breaches: bool,
+    /// The configuration for the trailing drawdown guard (disabled if None).
+    pub drawdown: Option<DrawdownGuardConfig>,
+    /// The risk limits for specific strategies.
+    pub strategy_limits: HashMap<StrategyId, StrategyLimits>,
+    /// The risk limits for specific accounts, for venues with multiple accounts (sub-accounts).
+    pub account_limits: HashMap<AccountId, StrategyLimits>,
+    /// The configuration for trade compliance checks.
+    pub compliance: ComplianceConfig,
     pub debug: bool,
 }
 
@@ -39,7 +59,36 @@ impl Default for RiskEngineConfig {
             max_order_submit: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_order_modify: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_notional_per_order: HashMap::new(),
+            venue_budgets: HashMap::new(),
+            reject_price_limit_breaches: true,
+            drawdown: None,
+            strategy_limits: HashMap::new(),
+            account_limits: HashMap::new(),
+            compliance: ComplianceConfig::default(),
             debug: false,
         }
     }
 }
+
+impl ValidateConfig for RiskEngineConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        if let Some(drawdown) = &self.drawdown {
+            drawdown.validate()?;
+        }
+        for (strategy_id, limits) in &self.strategy_limits {
+            limits.validate_for(strategy_id)?;
+        }
+        for (account_id, limits) in &self.account_limits {
+            limits.validate_for_account(account_id)?;
+        }
+        self.compliance.validate()?;
+        for (venue, budget) in &self.venue_budgets {
+            check_setting(
+                MessageBudget::new_checked(budget.rate, budget.burst).is_ok(),
+                &format!("risk_engine.venue_budgets.{venue}"),
+                "must have a positive rate and burst",
+            )?;
+        }
+        Ok(())
+    }
+}
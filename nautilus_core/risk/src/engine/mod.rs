@@ -19,81 +19,665 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    rc::Rc,
+};
 
 use config::RiskEngineConfig;
-use nautilus_common::{cache::Cache, clock::Clock, msgbus::MessageBus, throttler::Throttler};
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    config::ValidateConfig,
+    messages::{
+        data::DataResponse,
+        risk::{ComplianceViolated, StrategyLimitViolated, TradingStateChanged},
+    },
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+    price_limits::{PriceBand, PriceLimits},
+    short_sale::{ShortSaleConstraints, ShortSaleRules},
+    throttler::{
+        budget::{MessagePriority, VenueThrottler},
+        Throttler,
+    },
+};
+use nautilus_core::uuid::UUID4;
 use nautilus_execution::messages::{
-    modify::ModifyOrder, submit::SubmitOrder, submit_list::SubmitOrderList, TradingCommand,
+    cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+    submit_list::SubmitOrderList, TradingCommand,
 };
 use nautilus_model::{
-    enums::TradingState,
-    events::order::OrderEventAny,
-    identifiers::InstrumentId,
+    accounts::any::AccountAny,
+    data::Data,
+    enums::{
+        ContingencyType, OrderSide, OrderType, PositionSide, PriceType, TimeInForce, TradingState,
+    },
+    events::{
+        account::state::AccountState,
+        order::{OrderDenied, OrderEventAny, OrderFilled},
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, Venue, VenueOrderId,
+    },
     instruments::any::InstrumentAny,
-    orders::{any::OrderAny, list::OrderList},
-    types::quantity::Quantity,
+    orders::{any::OrderAny, list::OrderList, market::MarketOrder},
+    types::{money::Money, price::Price, quantity::Quantity},
 };
 use rust_decimal::Decimal;
+use ustr::Ustr;
+
+use crate::{
+    compliance::ComplianceChecker,
+    drawdown::{DrawdownAction, DrawdownGuard},
+    limits::{AccountLimitChecker, StrategyLimitChecker, StrategyLimits},
+    sizing::{PositionSizer, SizingContext},
+};
 
 pub mod config;
 
+type CommandThrottler = VenueThrottler<TradingCommand, Box<dyn Fn(TradingCommand)>>;
+
 pub struct RiskEngine<C>
 where
     C: Clock,
 {
-    clock: C,
+    clock: Rc<RefCell<C>>,
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
     order_submit_throttler: Throttler<SubmitOrder, Box<dyn Fn(SubmitOrder)>>,
     order_modify_throttler: Throttler<ModifyOrder, Box<dyn Fn(ModifyOrder)>>,
+    venue_throttlers: HashMap<Venue, CommandThrottler>,
     max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    price_limits: PriceLimits,
+    short_sale: ShortSaleRules,
+    trading_state: TradingState,
+    drawdown_guard: Option<DrawdownGuard>,
+    strategy_limits: StrategyLimitChecker,
+    account_limits: AccountLimitChecker,
+    compliance: ComplianceChecker,
+    position_sizers: HashMap<StrategyId, Box<dyn PositionSizer>>,
+    volatilities: HashMap<InstrumentId, f64>,
+    flatten_count: usize,
     config: RiskEngineConfig,
 }
 
 impl<C> RiskEngine<C>
 where
-    C: Clock,
+    C: Clock + 'static,
 {
+    /// Creates a new [`RiskEngine`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `config` is invalid.
+    pub fn new(
+        clock: Rc<RefCell<C>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        config: RiskEngineConfig,
+    ) -> anyhow::Result<Self> {
+        config.validate()?;
+        let throttler_clock: Rc<RefCell<dyn Clock>> = clock.clone();
+
+        // Commands for venues with a message budget are sent to execution by its throttler
+        let venue_throttlers: HashMap<Venue, CommandThrottler> = config
+            .venue_budgets
+            .iter()
+            .map(|(venue, budget)| {
+                let venue_msgbus = msgbus.clone();
+                let throttler = VenueThrottler::new(
+                    *budget,
+                    throttler_clock.clone(),
+                    format!("{venue}-MESSAGE_BUDGET"),
+                    Box::new(move |command: TradingCommand| {
+                        send_to_execution(&venue_msgbus, command);
+                    }) as Box<dyn Fn(TradingCommand)>,
+                );
+                (*venue, throttler)
+            })
+            .collect();
+
+        let submit_msgbus = msgbus.clone();
+        let submit_venue_throttlers = venue_throttlers.clone();
+        let order_submit_throttler = Throttler::new(
+            config.max_order_submit,
+            throttler_clock.clone(),
+            "ORDER_SUBMIT_THROTTLER".to_string(),
+            Box::new(move |command: SubmitOrder| {
+                send_to_venue(
+                    &submit_venue_throttlers,
+                    &submit_msgbus,
+                    TradingCommand::SubmitOrder(command),
+                );
+            }) as Box<dyn Fn(SubmitOrder)>,
+            None,
+        );
+        let modify_msgbus = msgbus.clone();
+        let modify_venue_throttlers = venue_throttlers.clone();
+        let order_modify_throttler = Throttler::new(
+            config.max_order_modify,
+            throttler_clock,
+            "ORDER_MODIFY_THROTTLER".to_string(),
+            Box::new(move |command: ModifyOrder| {
+                send_to_venue(
+                    &modify_venue_throttlers,
+                    &modify_msgbus,
+                    TradingCommand::ModifyOrder(command),
+                );
+            }) as Box<dyn Fn(ModifyOrder)>,
+            None,
+        );
+
+        Ok(Self {
+            clock,
+            cache,
+            msgbus,
+            order_submit_throttler,
+            order_modify_throttler,
+            venue_throttlers,
+            max_notional_per_order: config.max_notional_per_order.clone(),
+            price_limits: PriceLimits::new(),
+            short_sale: ShortSaleRules::new(),
+            trading_state: TradingState::Active,
+            drawdown_guard: config.drawdown.clone().map(DrawdownGuard::new),
+            strategy_limits: StrategyLimitChecker::new(config.strategy_limits.clone()),
+            account_limits: AccountLimitChecker::new(config.account_limits.clone()),
+            compliance: ComplianceChecker::new(config.compliance.clone()),
+            position_sizers: HashMap::new(),
+            volatilities: HashMap::new(),
+            flatten_count: 0,
+            config,
+        })
+    }
+
+    #[must_use]
+    pub const fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    /// Subscribes the `engine` to the account state events, updating the drawdown guard with
+    /// the account equity, and returns the handler to unsubscribe with.
+    pub fn subscribe_account_state(engine: &Rc<RefCell<Self>>) -> ShareableMessageHandler {
+        let handler = ShareableMessageHandler(Rc::new(AccountStateHandler {
+            id: Ustr::from("RiskEngine.account_state"),
+            engine: engine.clone(),
+        }));
+        let msgbus = engine.borrow().msgbus.clone();
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.account_state_topic;
+        msgbus.subscribe(topic, handler.clone(), None);
+        handler
+    }
+
     // -- COMMANDS --------------------------------------------------------------------------------
 
-    pub fn execute(&self, command: TradingCommand) {
+    pub fn execute(&mut self, command: TradingCommand) {
         // This will extend to other commands such as `RiskCommand`
-        todo!()
+        self.handle_command(command);
     }
 
-    pub fn process(&self, event: OrderEventAny) {
+    pub fn process(&mut self, event: OrderEventAny) {
         // This will extend to other events such as `RiskEvent`
-        todo!()
+        self.handle_event(event);
     }
 
-    pub fn set_trading_state(&self, state: TradingState) {
-        todo!()
+    /// Sets the trading state, publishing a [`TradingStateChanged`] event if changed.
+    ///
+    /// Setting the state to active resets the drawdown guard, with the peak equity restarting
+    /// from the next equity update.
+    pub fn set_trading_state(&mut self, state: TradingState) {
+        if state == TradingState::Active {
+            if let Some(guard) = self.drawdown_guard.as_mut() {
+                guard.reset();
+            }
+        }
+        self.change_trading_state(state, None, false);
+    }
+
+    /// Updates the drawdown guard with the equity of the account `state` (if any).
+    ///
+    /// The equity is the total balance in the drawdown limit currency, else in the account
+    /// base currency, else the single balance of the account.
+    pub fn handle_account_state(&mut self, state: &AccountState) {
+        let Some(guard) = self.drawdown_guard.as_ref() else {
+            return;
+        };
+        let currency = guard
+            .config()
+            .max_drawdown
+            .map(|limit| limit.currency)
+            .or(state.base_currency);
+        let balance = match currency {
+            Some(currency) => state
+                .balances
+                .iter()
+                .find(|balance| balance.currency == currency),
+            None if state.balances.len() == 1 => state.balances.first(),
+            None => None,
+        };
+        match balance {
+            Some(balance) => self.update_equity(balance.total),
+            None => log::debug!("No equity for drawdown guard in {}", state.account_id),
+        }
+    }
+
+    /// Updates the drawdown guard with the current portfolio `equity`, changing the trading
+    /// state according to the configured action if the drawdown limit is breached.
+    ///
+    /// On a [`DrawdownAction::Flatten`] breach all open orders are canceled and all open
+    /// positions are closed.
+    pub fn update_equity(&mut self, equity: Money) {
+        let Some(guard) = self.drawdown_guard.as_mut() else {
+            return;
+        };
+
+        match guard.update(equity) {
+            Ok(Some(breach)) => {
+                let reason = breach.reason();
+                log::error!("{reason}, action {:?}", breach.action);
+                let flatten = breach.action == DrawdownAction::Flatten;
+                self.change_trading_state(
+                    breach.action.trading_state(),
+                    Some(Ustr::from(&reason)),
+                    flatten,
+                );
+                if flatten {
+                    self.flatten();
+                }
+            }
+            Ok(None) => {}
+            Err(e) => log::error!("Error updating drawdown guard: {e}"),
+        }
     }
 
     pub fn set_max_notional_per_order(&self, instrument_id: InstrumentId, new_value: Decimal) {
         todo!()
     }
 
+    pub fn set_strategy_limits(&mut self, strategy_id: StrategyId, limits: StrategyLimits) {
+        log::info!("Set strategy limits for {strategy_id}: {limits:?}");
+        self.strategy_limits.set_limits(strategy_id, limits);
+    }
+
+    /// Sets the position `sizer` for the given `strategy_id`, denying orders of the strategy
+    /// which exceed the position size it calculates.
+    pub fn set_position_sizer(&mut self, strategy_id: StrategyId, sizer: Box<dyn PositionSizer>) {
+        log::info!("Set position sizer for {strategy_id}");
+        self.position_sizers.insert(strategy_id, sizer);
+    }
+
+    /// Updates the annualized `volatility` of the returns of the given `instrument_id`, for
+    /// volatility target position sizing.
+    pub fn update_volatility(&mut self, instrument_id: InstrumentId, volatility: f64) {
+        self.volatilities.insert(instrument_id, volatility);
+    }
+
+    /// Updates the signed notional `exposure` of the strategy to the given instrument, for
+    /// checking the strategy limits of subsequent orders.
+    pub fn update_strategy_exposure(
+        &mut self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        exposure: Money,
+    ) {
+        self.strategy_limits
+            .update_exposure(strategy_id, instrument_id, exposure);
+    }
+
+    /// Adds or removes the given `instrument_id` from the compliance restricted list.
+    pub fn set_restricted(&mut self, instrument_id: InstrumentId, restricted: bool) {
+        log::info!("Set {instrument_id} restricted={restricted}");
+        self.compliance.set_restricted(instrument_id, restricted);
+    }
+
+    /// Records the given `fill` as a trade for the compliance order to trade ratio.
+    pub fn update_compliance_fill(&mut self, fill: &OrderFilled) {
+        self.compliance.record_trade(fill.instrument_id);
+    }
+
+    pub fn set_account_limits(&mut self, account_id: AccountId, limits: StrategyLimits) {
+        log::info!("Set account limits for {account_id}: {limits:?}");
+        self.account_limits.set_limits(account_id, limits);
+    }
+
+    /// Updates the signed notional `exposure` of the account to the given instrument, for
+    /// checking the account limits of subsequent orders.
+    pub fn update_account_exposure(
+        &mut self,
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        exposure: Money,
+    ) {
+        self.account_limits
+            .update_exposure(account_id, instrument_id, exposure);
+    }
+
+    pub fn update_price_band(&mut self, band: PriceBand) {
+        if self.config.debug {
+            log::debug!("Updating {band:?}");
+        }
+        self.price_limits.update(band);
+    }
+
+    pub fn set_short_sale_constraints(&mut self, constraints: ShortSaleConstraints) {
+        log::info!("Set {constraints:?}");
+        self.short_sale.set_constraints(constraints);
+    }
+
+    /// Sets the quantity located for selling the given `instrument_id` short.
+    pub fn set_located(&mut self, instrument_id: InstrumentId, quantity: Quantity) {
+        log::info!("Set located {quantity} for {instrument_id}");
+        self.short_sale.set_located(instrument_id, quantity);
+    }
+
+    fn change_trading_state(&mut self, state: TradingState, reason: Option<Ustr>, flatten: bool) {
+        if state == self.trading_state && !flatten {
+            log::warn!("No change to trading state: already set to {state}");
+            return;
+        }
+
+        self.trading_state = state;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let msgbus = self.msgbus.borrow();
+        let event = TradingStateChanged::new(
+            msgbus.trader_id,
+            state,
+            reason,
+            flatten,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        );
+        log::info!("Trading state set to {state}");
+        msgbus.publish(&msgbus.switchboard.risk_events_topic, &event as &dyn Any);
+    }
+
+    /// Halts trading for the given `reason`.
+    ///
+    /// If `flatten` is set all open positions are closed with reduce-only market orders, which
+    /// are sent to the venues without pre-trade checks. Open orders are left to the caller.
+    pub fn halt(&mut self, reason: Ustr, flatten: bool) {
+        self.change_trading_state(TradingState::Halted, Some(reason), flatten);
+        if flatten {
+            self.close_positions();
+        }
+    }
+
+    /// Cancels all open orders and closes all open positions with reduce-only market orders,
+    /// which are sent to the venues without pre-trade checks.
+    fn flatten(&mut self) {
+        self.cancel_open_orders();
+        self.close_positions();
+    }
+
+    fn cancel_open_orders(&mut self) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let trader_id = self.msgbus.borrow().trader_id;
+
+        // One command per client, strategy and instrument with open orders
+        let targets: BTreeSet<_> = {
+            let cache = self.cache.borrow();
+            cache
+                .orders_open(None, None, None, None)
+                .into_iter()
+                .map(|order| {
+                    let client_id = cache
+                        .client_id(&order.client_order_id())
+                        .copied()
+                        .unwrap_or_else(|| ClientId::new(order.instrument_id().venue.as_str()));
+                    (client_id, order.strategy_id(), order.instrument_id())
+                })
+                .collect()
+        };
+        for (client_id, strategy_id, instrument_id) in targets {
+            self.send_to_venue(TradingCommand::CancelAllOrders(CancelAllOrders {
+                trader_id,
+                client_id,
+                strategy_id,
+                instrument_id,
+                order_side: OrderSide::NoOrderSide,
+                command_id: UUID4::new(),
+                ts_init: ts_now,
+            }));
+        }
+    }
+
+    fn close_positions(&mut self) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let positions: Vec<_> = self
+            .cache
+            .borrow()
+            .positions_open(None, None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        for position in positions {
+            let order_side = match position.side {
+                PositionSide::Long => OrderSide::Sell,
+                PositionSide::Short => OrderSide::Buy,
+                _ => continue,
+            };
+            self.flatten_count += 1;
+            let client_order_id =
+                ClientOrderId::new(format!("O-FLATTEN-{}-{}", position.id, self.flatten_count));
+            let order = OrderAny::Market(MarketOrder::new(
+                position.trader_id,
+                position.strategy_id,
+                position.instrument_id,
+                client_order_id,
+                order_side,
+                position.quantity,
+                TimeInForce::Gtc,
+                UUID4::new(),
+                ts_now,
+                true,
+                false,
+                Some(ContingencyType::NoContingency),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ));
+            let command = SubmitOrder::new(
+                position.trader_id,
+                ClientId::new(position.instrument_id.venue.as_str()),
+                position.strategy_id,
+                position.instrument_id,
+                client_order_id,
+                VenueOrderId::from("NONE"),
+                order,
+                None,
+                Some(position.id),
+                UUID4::new(),
+                ts_now,
+            );
+            match command {
+                Ok(command) => {
+                    log::warn!("Closing {} to flatten", position.id);
+                    self.send_to_venue(TradingCommand::SubmitOrder(command));
+                }
+                Err(e) => log::error!("Cannot close {}: {e}", position.id),
+            }
+        }
+    }
+
     // -- COMMAND HANDLERS ------------------------------------------------------------------------
 
-    fn handle_command(&self, command: TradingCommand) {
+    fn handle_command(&mut self, command: TradingCommand) {
         // Renamed from `execute_command`
-        todo!();
+        if self.config.debug {
+            log::debug!("-->[CMD] {command:?}");
+        }
+
+        if self.config.bypass {
+            self.send_to_execution(command);
+            return;
+        }
+
+        match command {
+            TradingCommand::SubmitOrder(command) => self.handle_submit_order(command),
+            TradingCommand::SubmitOrderList(command) => self.handle_submit_order_list(command),
+            TradingCommand::ModifyOrder(command) => self.order_modify_throttler.send(command),
+            _ => self.send_to_venue(command),
+        }
     }
 
-    fn handle_submit_order(&self, command: SubmitOrder) {
-        todo!();
+    fn handle_submit_order(&mut self, command: SubmitOrder) {
+        let order = &command.order;
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id())
+            .cloned();
+        let Some(instrument) = instrument else {
+            self.deny_order(
+                order,
+                &format!("Instrument for {} not found", order.instrument_id()),
+            );
+            return;
+        };
+
+        if let Some(reason) = self.check_order(&instrument, order, None) {
+            self.deny_order(order, &reason);
+            return;
+        }
+
+        self.execution_gateway(command);
     }
 
-    fn handle_submit_order_list(&self, command: SubmitOrderList) {
-        todo!();
+    fn handle_submit_order_list(&mut self, command: SubmitOrderList) {
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&command.instrument_id)
+            .cloned();
+        let Some(instrument) = instrument else {
+            let reason = format!("Instrument for {} not found", command.instrument_id);
+            self.deny_order_list(&command.order_list, &reason);
+            return;
+        };
+
+        for order in &command.order_list.orders {
+            let stop_loss = stop_loss_for(&command.order_list, order);
+            if let Some(reason) = self.check_order(&instrument, order, stop_loss) {
+                let reason = format!("OrderList {} DENIED: {reason}", command.order_list.id);
+                self.deny_order_list(&command.order_list, &reason);
+                return;
+            }
+        }
+
+        let orders = &command.order_list.orders;
+        if let Some(reason) = orders
+            .iter()
+            .find_map(|order| self.check_trading_state(order))
+        {
+            self.deny_order_list(&command.order_list, &reason);
+            return;
+        }
+        self.send_to_venue(TradingCommand::SubmitOrderList(command));
     }
 
     // -- PRE-TRADE CHECKS ------------------------------------------------------------------------
 
-    fn check_order(&self, instrument: InstrumentAny, order: OrderAny) -> bool {
-        todo!()
+    /// Checks the `order` against the pre-trade risk rules, returning the reason to deny it
+    /// (if any).
+    ///
+    /// The `stop_loss` price is used to size orders entering a position with a stop-loss.
+    fn check_order(
+        &mut self,
+        instrument: &InstrumentAny,
+        order: &OrderAny,
+        stop_loss: Option<Price>,
+    ) -> Option<String> {
+        if let Some(reason) = self.check_order_price_limits(order) {
+            return Some(reason);
+        }
+        if let Some(reason) = self.check_order_short_sale(order) {
+            return Some(reason);
+        }
+        if let Some(reason) = self.check_order_size(instrument, order, stop_loss) {
+            return Some(reason);
+        }
+        if let Some(notional) = self.order_notional(instrument, order) {
+            if let Some(reason) = self.check_order_strategy_limits(order, notional) {
+                return Some(reason);
+            }
+            if let Some(account_id) = self.order_account_id(order) {
+                if let Some(reason) = self.check_order_account_limits(account_id, order, notional) {
+                    return Some(reason);
+                }
+            }
+        }
+        // Checked last, as the order is recorded for the compliance rules
+        self.check_order_compliance(order)
+    }
+
+    /// Returns the account the `order` is for, being the account of its venue if the order
+    /// has not yet been assigned one.
+    fn order_account_id(&self, order: &OrderAny) -> Option<AccountId> {
+        order.account_id().or_else(|| {
+            self.cache
+                .borrow()
+                .account_for_venue(&order.instrument_id().venue)
+                .map(AccountAny::id)
+        })
+    }
+
+    /// Returns the notional value of the `order` at its price, or at the last market price for
+    /// orders without a price (None if no price is available).
+    fn order_notional(&self, instrument: &InstrumentAny, order: &OrderAny) -> Option<Money> {
+        let price = self.order_price(order)?;
+        Some(instrument.calculate_notional_value(order.quantity(), price, None))
+    }
+
+    /// Returns the price of the `order`, or the last market price for orders without a price.
+    fn order_price(&self, order: &OrderAny) -> Option<Price> {
+        order.price().or_else(|| order.trigger_price()).or_else(|| {
+            let cache = self.cache.borrow();
+            let instrument_id = order.instrument_id();
+            cache
+                .price(&instrument_id, PriceType::Last)
+                .or_else(|| cache.price(&instrument_id, PriceType::Mid))
+        })
+    }
+
+    /// Checks the `order` against the trading state, returning the reason to deny it (if any).
+    fn check_trading_state(&self, order: &OrderAny) -> Option<String> {
+        match self.trading_state {
+            TradingState::Halted => Some("TradingState::HALTED".to_string()),
+            TradingState::Reducing => {
+                let net_position = self.net_position(&order.instrument_id());
+                match order.order_side() {
+                    OrderSide::Buy if net_position > 0.0 => Some(format!(
+                        "TradingState::REDUCING and order would increase long position {}",
+                        order.instrument_id()
+                    )),
+                    OrderSide::Sell if net_position < 0.0 => Some(format!(
+                        "TradingState::REDUCING and order would increase short position {}",
+                        order.instrument_id()
+                    )),
+                    _ => None,
+                }
+            }
+            TradingState::Active => None,
+        }
+    }
+
+    fn net_position(&self, instrument_id: &InstrumentId) -> f64 {
+        self.cache
+            .borrow()
+            .positions_open(None, Some(instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum()
     }
 
     fn check_order_price(&self, instrument: InstrumentAny, order: OrderAny) -> bool {
@@ -104,6 +688,182 @@ where
         todo!()
     }
 
+    fn check_order_price_limits(&self, order: &OrderAny) -> Option<String> {
+        let price = order.price()?;
+        let breach = self
+            .price_limits
+            .check_price(&order.instrument_id(), price)?;
+        let reason = format!("price {price} {breach}");
+
+        if self.config.reject_price_limit_breaches {
+            Some(reason)
+        } else {
+            // Flag only, the venue will make the final decision
+            log::warn!("Order {} {reason}", order.client_order_id());
+            None
+        }
+    }
+
+    fn check_order_size(
+        &self,
+        instrument: &InstrumentAny,
+        order: &OrderAny,
+        stop_loss: Option<Price>,
+    ) -> Option<String> {
+        let sizer = self.position_sizers.get(&order.strategy_id())?;
+
+        // Orders closing positions, including contingent exits, are not sized
+        if order.is_reduce_only() || order.parent_order_id().is_some() {
+            return None;
+        }
+
+        let size = match self.position_size(sizer.as_ref(), instrument, order, stop_loss) {
+            Ok(size) => size,
+            Err(e) => return Some(format!("cannot size order: {e}")),
+        };
+        if order.quantity() > size {
+            return Some(format!(
+                "quantity {} exceeds position size {size} for strategy {}",
+                order.quantity(),
+                order.strategy_id()
+            ));
+        }
+        None
+    }
+
+    /// Returns the position size for the `order` from the `sizer`, for the equity of the
+    /// account in its base currency, or the settlement currency of the `instrument`.
+    fn position_size(
+        &self,
+        sizer: &dyn PositionSizer,
+        instrument: &InstrumentAny,
+        order: &OrderAny,
+        stop_loss: Option<Price>,
+    ) -> anyhow::Result<Quantity> {
+        let entry = self
+            .order_price(order)
+            .ok_or_else(|| anyhow::anyhow!("no price for {}", order.instrument_id()))?;
+        let account_id = self
+            .order_account_id(order)
+            .ok_or_else(|| anyhow::anyhow!("no account for {}", order.instrument_id().venue))?;
+
+        let cache = self.cache.borrow();
+        let account = cache
+            .account(&account_id)
+            .ok_or_else(|| anyhow::anyhow!("account {account_id} not found"))?;
+        let currency = account
+            .base_currency()
+            .unwrap_or_else(|| instrument.settlement_currency());
+        let equity = account
+            .balances_total()
+            .get(&currency)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("no {currency} balance for account {account_id}"))?;
+        let quote_currency = instrument.quote_currency();
+        let exchange_rate = cache
+            .get_xrate(
+                &order.instrument_id().venue,
+                quote_currency,
+                currency,
+                PriceType::Mid,
+            )
+            .and_then(|rate| Decimal::try_from(rate).ok())
+            .ok_or_else(|| anyhow::anyhow!("no exchange rate for {quote_currency}/{currency}"))?;
+
+        let mut context = SizingContext::new(equity, entry).with_exchange_rate(exchange_rate);
+        if let Some(stop_loss) = stop_loss {
+            context = context.with_stop_loss(stop_loss);
+        }
+        if let Some(volatility) = self.volatilities.get(&order.instrument_id()) {
+            context = context.with_volatility(*volatility);
+        }
+        sizer.calculate(instrument, &context)
+    }
+
+    fn check_order_short_sale(&self, order: &OrderAny) -> Option<String> {
+        let instrument_id = order.instrument_id();
+        let net_position = self.net_position(&instrument_id);
+        let violation = self.short_sale.check_order(
+            &instrument_id,
+            order.order_side(),
+            order.quantity(),
+            net_position,
+        )?;
+        Some(format!("short sale of {instrument_id} {violation}"))
+    }
+
+    fn check_order_strategy_limits(&mut self, order: &OrderAny, notional: Money) -> Option<String> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let breach = match self.strategy_limits.check_order(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.order_side_specified(),
+            notional,
+            ts_now,
+        ) {
+            Ok(breach) => breach?,
+            Err(e) => return Some(e.to_string()),
+        };
+
+        let msgbus = self.msgbus.borrow();
+        let event = StrategyLimitViolated::new(
+            msgbus.trader_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            breach,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        );
+        msgbus.publish(&msgbus.switchboard.risk_events_topic, &event as &dyn Any);
+        Some(format!("strategy {} {breach}", order.strategy_id()))
+    }
+
+    fn check_order_account_limits(
+        &mut self,
+        account_id: AccountId,
+        order: &OrderAny,
+        notional: Money,
+    ) -> Option<String> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        match self.account_limits.check_order(
+            account_id,
+            order.instrument_id(),
+            order.order_side_specified(),
+            notional,
+            ts_now,
+        ) {
+            Ok(breach) => breach.map(|breach| format!("account {account_id} {breach}")),
+            Err(e) => Some(e.to_string()),
+        }
+    }
+
+    fn check_order_compliance(&mut self, order: &OrderAny) -> Option<String> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let breach = self.compliance.check_order(
+            order.instrument_id(),
+            order.client_order_id(),
+            order.order_side(),
+            ts_now,
+        )?;
+
+        let msgbus = self.msgbus.borrow();
+        let event = ComplianceViolated::new(
+            msgbus.trader_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            breach,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        );
+        log::warn!("{event}");
+        msgbus.publish(&msgbus.switchboard.risk_events_topic, &event as &dyn Any);
+        Some(format!("compliance {breach}"))
+    }
+
     fn check_orders_risk(&self, instrument: InstrumentAny, orders: Vec<OrderAny>) -> bool {
         todo!()
     }
@@ -130,32 +890,638 @@ where
         todo!()
     }
 
-    fn deny_order(&self, order: OrderAny, reason: &str) {
-        todo!()
+    fn deny_order(&self, order: &OrderAny, reason: &str) {
+        log::warn!(
+            "SubmitOrder for {} DENIED: {reason}",
+            order.client_order_id()
+        );
+
+        {
+            let mut cache = self.cache.borrow_mut();
+            if cache.order(&order.client_order_id()).is_none() {
+                if let Err(e) = cache.add_order(order.clone(), None, None, false) {
+                    log::error!("Cannot add denied order to cache: {e}");
+                }
+            }
+        }
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = OrderEventAny::Denied(OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        ));
+        let msgbus = self.msgbus.borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 
-    fn deny_order_list(&self, order_list: OrderList, reason: &str) {
-        todo!()
+    fn deny_order_list(&self, order_list: &OrderList, reason: &str) {
+        for order in &order_list.orders {
+            if !order.is_closed() {
+                self.deny_order(order, reason);
+            }
+        }
     }
 
     fn reject_modify_order(&self, order: OrderAny, reason: &str) {}
 
     // -- EGRESS ----------------------------------------------------------------------------------
 
-    fn execution_gateway(&self, instrument: InstrumentAny, command: TradingCommand) {
-        todo!()
+    fn execution_gateway(&self, command: SubmitOrder) {
+        if let Some(reason) = self.check_trading_state(&command.order) {
+            self.deny_order(&command.order, &reason);
+            return;
+        }
+        self.order_submit_throttler.send(command);
     }
 
     fn send_to_execution(&self, command: TradingCommand) {
-        todo!()
+        send_to_execution(&self.msgbus, command);
     }
 
-    fn handle_event(&self, event: OrderEventAny) {
+    fn send_to_venue(&self, command: TradingCommand) {
+        send_to_venue(&self.venue_throttlers, &self.msgbus, command);
+    }
+
+    fn handle_event(&mut self, event: OrderEventAny) {
         if self.config.debug {
             log::debug!("<--[EVT] {event:?}");
         }
 
         // We intend to extend the risk engine to be able to handle additional events.
-        // For now we just log.
+        // For now we record fills for the compliance rules.
+        if let OrderEventAny::Filled(fill) = event {
+            self.update_compliance_fill(&fill);
+        }
+    }
+}
+
+struct AccountStateHandler<C>
+where
+    C: Clock,
+{
+    id: Ustr,
+    engine: Rc<RefCell<RiskEngine<C>>>,
+}
+
+impl<C> MessageHandler for AccountStateHandler<C>
+where
+    C: Clock + 'static,
+{
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(state) = message.downcast_ref::<AccountState>() {
+            self.engine.borrow_mut().handle_account_state(state);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Returns the trigger price of the stop-loss order contingent on the `order` in the
+/// `order_list` (if any).
+fn stop_loss_for(order_list: &OrderList, order: &OrderAny) -> Option<Price> {
+    order_list
+        .orders
+        .iter()
+        .find(|child| {
+            child.parent_order_id() == Some(order.client_order_id())
+                && matches!(
+                    child.order_type(),
+                    OrderType::StopMarket | OrderType::StopLimit
+                )
+        })
+        .and_then(OrderAny::trigger_price)
+}
+
+fn send_to_venue(
+    venue_throttlers: &HashMap<Venue, CommandThrottler>,
+    msgbus: &Rc<RefCell<MessageBus>>,
+    command: TradingCommand,
+) {
+    let Some(throttler) = venue_throttlers.get(&command.instrument_id().venue) else {
+        send_to_execution(msgbus, command);
+        return;
+    };
+
+    // Cancels take priority over other commands queued for the venue budget
+    let priority = if command.is_cancel() {
+        MessagePriority::High
+    } else {
+        MessagePriority::Normal
+    };
+    throttler.send(command, priority);
+}
+
+fn send_to_execution(msgbus: &Rc<RefCell<MessageBus>>, command: TradingCommand) {
+    let msgbus = msgbus.borrow();
+    msgbus.send(
+        &msgbus.switchboard.exec_engine_execute,
+        &command as &dyn Any,
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::{
+            handler::ShareableMessageHandler,
+            stubs::{get_message_saving_handler, get_saved_messages},
+        },
+        throttler::{budget::MessageBudget, RateLimit},
+    };
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        accounts::stubs::cash_account,
+        enums::{OmsType, OrderType},
+        events::account::stubs::{cash_account_state, cash_account_state_million_usd},
+        identifiers::{ClientId, ClientOrderId, VenueOrderId},
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderStubs},
+        position::Position,
+        stubs::stub_position_long,
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        compliance::ComplianceConfig,
+        drawdown::DrawdownGuardConfig,
+        sizing::{FixedRiskSizer, VolatilityTargetSizer},
+    };
+
+    struct TestContext {
+        clock: Rc<RefCell<TestClock>>,
+        engine: RiskEngine<TestClock>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        commands: ShareableMessageHandler,
+        events: ShareableMessageHandler,
+    }
+
+    fn context(config: RiskEngineConfig) -> TestContext {
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        let cache = Rc::new(RefCell::new(cache));
+
+        let commands = get_message_saving_handler::<TradingCommand>(None);
+        let events = get_message_saving_handler::<OrderEventAny>(None);
+        let mut msgbus = MessageBus::default();
+        msgbus.register(msgbus.switchboard.exec_engine_execute, commands.clone());
+        msgbus.register(msgbus.switchboard.exec_engine_process, events.clone());
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let engine = RiskEngine::new(clock.clone(), cache.clone(), msgbus.clone(), config).unwrap();
+        TestContext {
+            clock,
+            engine,
+            cache,
+            msgbus,
+            commands,
+            events,
+        }
+    }
+
+    fn limit_order(side: OrderSide, price: &str, client_order_id: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim().id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from(100_000))
+            .build()
+    }
+
+    fn submit(order: OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::from("NONE"),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submitted(context: &TestContext) -> Vec<ClientOrderId> {
+        get_saved_messages::<TradingCommand>(context.commands.clone())
+            .into_iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some(command.client_order_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn denied(context: &TestContext) -> Vec<String> {
+        get_saved_messages::<OrderEventAny>(context.events.clone())
+            .into_iter()
+            .filter_map(|event| match event {
+                OrderEventAny::Denied(denied) => Some(denied.reason.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_submit_order_sends_to_execution() {
+        let mut context = context(RiskEngineConfig::default());
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.00000", "O-1")));
+
+        assert_eq!(submitted(&context), vec![ClientOrderId::from("O-1")]);
+        assert!(denied(&context).is_empty());
+    }
+
+    #[rstest]
+    fn test_submit_order_for_unknown_instrument_denied() {
+        let mut context = context(RiskEngineConfig::default());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("EUR/USD.SIM"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        context.engine.execute(submit(order.clone()));
+
+        assert!(submitted(&context).is_empty());
+        assert_eq!(
+            denied(&context),
+            vec!["Instrument for EUR/USD.SIM not found"]
+        );
+        assert!(context
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .is_some());
+    }
+
+    #[rstest]
+    fn test_submit_order_when_halted_denied() {
+        let mut context = context(RiskEngineConfig::default());
+        context.engine.set_trading_state(TradingState::Halted);
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.00000", "O-1")));
+
+        assert!(submitted(&context).is_empty());
+        assert_eq!(denied(&context), vec!["TradingState::HALTED"]);
+    }
+
+    #[rstest]
+    #[case(true, vec![], vec!["price 1.10000 above upper price limit 1.05000"])]
+    #[case(false, vec![ClientOrderId::from("O-1")], vec![])]
+    fn test_submit_order_outside_price_limits(
+        #[case] reject_price_limit_breaches: bool,
+        #[case] expected_submitted: Vec<ClientOrderId>,
+        #[case] expected_denied: Vec<&str>,
+    ) {
+        let mut context = context(RiskEngineConfig {
+            reject_price_limit_breaches,
+            ..Default::default()
+        });
+        let instrument: CurrencyPair = audusd_sim();
+        context.engine.update_price_band(PriceBand::new(
+            instrument.id,
+            Some(Price::from("0.95000")),
+            Some(Price::from("1.05000")),
+            UnixNanos::default(),
+        ));
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.10000", "O-1")));
+
+        assert_eq!(submitted(&context), expected_submitted);
+        assert_eq!(denied(&context), expected_denied);
+    }
+
+    #[rstest]
+    #[case("50000", vec![], vec!["short sale of AUD/USD.SIM short quantity 100000 exceeds located 50000"])]
+    #[case("100000", vec![ClientOrderId::from("O-1")], vec![])]
+    fn test_submit_order_short_sale_checks_located(
+        #[case] located: &str,
+        #[case] expected_submitted: Vec<ClientOrderId>,
+        #[case] expected_denied: Vec<&str>,
+    ) {
+        let mut context = context(RiskEngineConfig::default());
+        let instrument_id = audusd_sim().id;
+        context
+            .engine
+            .set_short_sale_constraints(ShortSaleConstraints::new(
+                instrument_id,
+                false,
+                None,
+                true,
+            ));
+        context
+            .engine
+            .set_located(instrument_id, Quantity::from(located));
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Sell, "1.00000", "O-1")));
+
+        assert_eq!(submitted(&context), expected_submitted);
+        assert_eq!(denied(&context), expected_denied);
+    }
+
+    #[rstest]
+    fn test_submit_order_exceeding_strategy_limits_denied() {
+        let order = limit_order(OrderSide::Buy, "1.00000", "O-1");
+        let strategy_id = order.strategy_id();
+        let mut context = context(RiskEngineConfig {
+            strategy_limits: HashMap::from([(
+                strategy_id,
+                StrategyLimits {
+                    max_gross_exposure: Some(Money::from("150_000 USD")),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        });
+        context.engine.update_strategy_exposure(
+            strategy_id,
+            order.instrument_id(),
+            Money::from("100_000 USD"),
+        );
+
+        context.engine.execute(submit(order));
+
+        assert!(submitted(&context).is_empty());
+        assert_eq!(
+            denied(&context),
+            vec![format!(
+                "strategy {strategy_id} gross exposure 200000.00 USD exceeds limit \
+                 150000.00 USD by 50000.00 USD"
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_exceeding_account_limits_denied() {
+        let account_id = AccountId::from("SIM-001");
+        let mut context = context(RiskEngineConfig {
+            account_limits: HashMap::from([(
+                account_id,
+                StrategyLimits {
+                    max_net_exposure: Some(Money::from("50_000 USD")),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        });
+        // The order is checked against the account of its venue before it is assigned one
+        let account = cash_account(cash_account_state());
+        assert_eq!(account.id, account_id);
+        context
+            .cache
+            .borrow_mut()
+            .add_account(AccountAny::Cash(account))
+            .unwrap();
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Sell, "1.00000", "O-1")));
+
+        assert!(submitted(&context).is_empty());
+        assert_eq!(
+            denied(&context),
+            vec![
+                "account SIM-001 net exposure 100000.00 USD exceeds limit 50000.00 USD by \
+                 50000.00 USD"
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(100_000, true)]
+    #[case(200_000, false)]
+    fn test_submit_order_checked_against_position_sizer(
+        #[case] quantity: i64,
+        #[case] expected_submitted: bool,
+    ) {
+        let mut context = context(RiskEngineConfig::default());
+        context
+            .cache
+            .borrow_mut()
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd("1000000 USD", "0 USD", "1000000 USD"),
+            )))
+            .unwrap();
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim().id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(quantity))
+            .build();
+        context.engine.set_position_sizer(
+            order.strategy_id(),
+            Box::new(VolatilityTargetSizer::new(0.1, 1.0).unwrap()),
+        );
+        context.engine.update_volatility(audusd_sim().id, 1.0);
+
+        context.engine.execute(submit(order.clone()));
+
+        if expected_submitted {
+            assert_eq!(submitted(&context), vec![order.client_order_id()]);
+            assert!(denied(&context).is_empty());
+        } else {
+            assert!(submitted(&context).is_empty());
+            assert_eq!(
+                denied(&context),
+                vec!["quantity 200000 exceeds position size 100000 for strategy S-001"]
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_submit_order_which_cannot_be_sized_denied() {
+        let mut context = context(RiskEngineConfig::default());
+        context
+            .cache
+            .borrow_mut()
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd("1000000 USD", "0 USD", "1000000 USD"),
+            )))
+            .unwrap();
+        let order = limit_order(OrderSide::Buy, "1.00000", "O-1");
+        context.engine.set_position_sizer(
+            order.strategy_id(),
+            Box::new(FixedRiskSizer::new(Decimal::new(1, 2)).unwrap()),
+        );
+
+        context.engine.execute(submit(order));
+
+        assert!(submitted(&context).is_empty());
+        assert_eq!(
+            denied(&context),
+            vec!["cannot size order: A stop-loss price is required for fixed risk sizing"]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_for_restricted_instrument_denied() {
+        let mut context = context(RiskEngineConfig::default());
+        context.engine.set_restricted(audusd_sim().id, true);
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.00000", "O-1")));
+
+        assert!(submitted(&context).is_empty());
+        assert_eq!(
+            denied(&context),
+            vec!["compliance instrument is restricted"]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_opposite_order_within_wash_trade_window_denied() {
+        let mut context = context(RiskEngineConfig {
+            compliance: ComplianceConfig {
+                wash_trade_window_secs: Some(60),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.00000", "O-1")));
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Sell, "1.00000", "O-2")));
+
+        assert_eq!(submitted(&context), vec![ClientOrderId::from("O-1")]);
+        assert_eq!(
+            denied(&context),
+            vec!["compliance potential wash trade against O-1 within 60s"]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_orders_sent_within_venue_budget() {
+        let mut context = context(RiskEngineConfig {
+            venue_budgets: HashMap::from([(
+                Venue::from("SIM"),
+                MessageBudget::new(RateLimit::new(1, 10), 1),
+            )]),
+            ..Default::default()
+        });
+
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.00000", "O-1")));
+        context
+            .engine
+            .execute(submit(limit_order(OrderSide::Buy, "1.00000", "O-2")));
+        assert_eq!(submitted(&context), vec![ClientOrderId::from("O-1")]);
+
+        // The queued order is sent once the budget refills
+        let handlers = {
+            let mut clock = context.clock.borrow_mut();
+            let events = clock.advance_time(UnixNanos::from(10), true);
+            clock.match_handlers(events)
+        };
+        for handler in handlers {
+            handler.run();
+        }
+
+        assert_eq!(
+            submitted(&context),
+            vec![ClientOrderId::from("O-1"), ClientOrderId::from("O-2")]
+        );
+        assert!(denied(&context).is_empty());
+    }
+
+    #[rstest]
+    fn test_drawdown_flatten_on_account_state_cancels_and_closes(
+        audusd_sim: CurrencyPair,
+        stub_position_long: Position,
+    ) {
+        let config = RiskEngineConfig {
+            drawdown: Some(DrawdownGuardConfig {
+                max_drawdown: Some(Money::from("1000 USD")),
+                action: DrawdownAction::Flatten,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let context = context(config);
+        let order =
+            TestOrderStubs::make_accepted_order(&limit_order(OrderSide::Buy, "1.00000", "O-1"));
+        let mut cache = context.cache.borrow_mut();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        cache.update_order(&order).unwrap();
+        drop(cache);
+        context
+            .cache
+            .borrow_mut()
+            .add_position(stub_position_long.clone(), OmsType::Netting)
+            .unwrap();
+        let TestContext {
+            engine,
+            msgbus,
+            commands,
+            ..
+        } = context;
+        let engine = Rc::new(RefCell::new(engine));
+        RiskEngine::subscribe_account_state(&engine);
+
+        let topic = msgbus.borrow().switchboard.account_state_topic;
+        for total in ["100000 USD", "98000 USD"] {
+            let state = cash_account_state_million_usd(total, "0 USD", total);
+            msgbus.borrow().publish(&topic, &state);
+        }
+
+        assert_eq!(engine.borrow().trading_state(), TradingState::Reducing);
+        let commands = get_saved_messages::<TradingCommand>(commands);
+        assert_eq!(commands.len(), 2);
+        let TradingCommand::CancelAllOrders(cancel) = &commands[0] else {
+            panic!("Expected CancelAllOrders, was {:?}", commands[0]);
+        };
+        assert_eq!(cancel.instrument_id, audusd_sim.id);
+        let TradingCommand::SubmitOrder(close) = &commands[1] else {
+            panic!("Expected SubmitOrder, was {:?}", commands[1]);
+        };
+        assert_eq!(close.position_id, Some(stub_position_long.id));
+        assert_eq!(close.order.order_side(), OrderSide::Sell);
+        assert_eq!(close.order.order_type(), OrderType::Market);
+        assert_eq!(close.order.quantity(), stub_position_long.quantity);
+        assert!(close.order.is_reduce_only());
     }
 }
@@ -27,5 +27,11 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod analytics;
+pub mod compliance;
+pub mod dead_man;
+pub mod drawdown;
 pub mod engine;
+pub mod limits;
 pub mod sizing;
+pub mod valuation;
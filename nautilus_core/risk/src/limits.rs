@@ -0,0 +1,601 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-strategy and per-account risk limits.
+//!
+//! The [`StrategyLimitChecker`] tracks the exposure and recent orders of each strategy, and checks
+//! new orders against the configured [`StrategyLimits`]. Exposures are signed notional values per
+//! instrument, netted across instruments for the net exposure limit. The [`AccountLimitChecker`]
+//! applies the same limits per account, for venues with multiple accounts (sub-accounts).
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    hash::Hash,
+};
+
+use anyhow::ensure;
+use indexmap::IndexMap;
+use nautilus_common::{config::check_setting, messages::risk::StrategyLimitBreach};
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+use nautilus_model::{
+    enums::OrderSideSpecified,
+    identifiers::{AccountId, InstrumentId, StrategyId},
+    types::{currency::Currency, money::Money},
+};
+use serde::{Deserialize, Serialize};
+
+const NANOSECONDS_IN_MINUTE: u64 = 60 * NANOSECONDS_IN_SECOND;
+
+/// Configuration for the risk limits of a strategy.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StrategyLimits {
+    /// The maximum sum of the absolute exposures over all instruments (not checked if None).
+    pub max_gross_exposure: Option<Money>,
+    /// The maximum absolute exposure netted over all instruments (not checked if None).
+    pub max_net_exposure: Option<Money>,
+    /// The maximum number of instruments with exposure (not checked if None).
+    pub max_instruments: Option<usize>,
+    /// The maximum number of orders submitted in any minute (not checked if None).
+    pub max_orders_per_minute: Option<usize>,
+}
+
+impl StrategyLimits {
+    /// Validates the limits for the given `strategy_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a limit is not positive, or the exposure limits are in
+    /// different currencies.
+    pub fn validate_for(&self, strategy_id: &StrategyId) -> anyhow::Result<()> {
+        self.validate_at(&format!("risk_engine.strategy_limits.{strategy_id}"))
+    }
+
+    /// Validates the limits for the given `account_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a limit is not positive, or the exposure limits are in
+    /// different currencies.
+    pub fn validate_for_account(&self, account_id: &AccountId) -> anyhow::Result<()> {
+        self.validate_at(&format!("risk_engine.account_limits.{account_id}"))
+    }
+
+    fn validate_at(&self, prefix: &str) -> anyhow::Result<()> {
+        let setting = |field: &str| format!("{prefix}.{field}");
+        for (field, limit) in [
+            ("max_gross_exposure", self.max_gross_exposure),
+            ("max_net_exposure", self.max_net_exposure),
+        ] {
+            check_setting(
+                limit.is_none_or(|limit| limit.raw > 0),
+                &setting(field),
+                "must be positive",
+            )?;
+        }
+        if let (Some(gross), Some(net)) = (self.max_gross_exposure, self.max_net_exposure) {
+            check_setting(
+                gross.currency == net.currency,
+                &setting("max_net_exposure"),
+                format!(
+                    "must be in the max gross exposure currency {}",
+                    gross.currency
+                ),
+            )?;
+        }
+        for (field, limit) in [
+            ("max_instruments", self.max_instruments),
+            ("max_orders_per_minute", self.max_orders_per_minute),
+        ] {
+            check_setting(limit != Some(0), &setting(field), "must be positive")?;
+        }
+        Ok(())
+    }
+
+    fn currency(&self) -> Option<Currency> {
+        self.max_gross_exposure
+            .or(self.max_net_exposure)
+            .map(|limit| limit.currency)
+    }
+}
+
+#[derive(Debug, Default)]
+struct StrategyState {
+    exposures: IndexMap<InstrumentId, Money>,
+    order_timestamps: VecDeque<UnixNanos>,
+}
+
+impl StrategyState {
+    fn gross_exposure(&self, currency: Currency) -> Money {
+        let raw = self
+            .exposures
+            .values()
+            .map(|exposure| exposure.raw.abs())
+            .sum();
+        Money::from_raw(raw, currency)
+    }
+
+    fn net_exposure(&self, currency: Currency) -> Money {
+        let raw: i64 = self.exposures.values().map(|exposure| exposure.raw).sum();
+        Money::from_raw(raw.abs(), currency)
+    }
+}
+
+/// Checks orders against per-strategy risk limits.
+pub type StrategyLimitChecker = LimitChecker<StrategyId>;
+
+/// Checks orders against per-account risk limits.
+pub type AccountLimitChecker = LimitChecker<AccountId>;
+
+/// Checks orders against the risk limits of their strategy or account.
+#[derive(Debug)]
+pub struct LimitChecker<K> {
+    limits: HashMap<K, StrategyLimits>,
+    states: HashMap<K, StrategyState>,
+}
+
+impl<K> Default for LimitChecker<K> {
+    fn default() -> Self {
+        Self {
+            limits: HashMap::new(),
+            states: HashMap::new(),
+        }
+    }
+}
+
+impl<K> LimitChecker<K>
+where
+    K: Copy + Eq + Hash + Display,
+{
+    /// Creates a new [`LimitChecker`] instance.
+    #[must_use]
+    pub fn new(limits: HashMap<K, StrategyLimits>) -> Self {
+        Self {
+            limits,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Returns the limits for the given `id`, if any.
+    #[must_use]
+    pub fn limits(&self, id: &K) -> Option<&StrategyLimits> {
+        self.limits.get(id)
+    }
+
+    /// Sets the `limits` for the given `id`.
+    pub fn set_limits(&mut self, id: K, limits: StrategyLimits) {
+        self.limits.insert(id, limits);
+    }
+
+    /// Returns the signed exposure of the strategy or account to the given instrument, if any.
+    #[must_use]
+    pub fn exposure(&self, id: &K, instrument_id: &InstrumentId) -> Option<Money> {
+        self.states.get(id)?.exposures.get(instrument_id).copied()
+    }
+
+    /// Returns the gross exposure of the strategy or account in its limit currency, if any limits
+    /// are set.
+    #[must_use]
+    pub fn gross_exposure(&self, id: &K) -> Option<Money> {
+        let currency = self.limits.get(id)?.currency()?;
+        let state = self.states.get(id);
+        Some(state.map_or(Money::from_raw(0, currency), |state| {
+            state.gross_exposure(currency)
+        }))
+    }
+
+    /// Returns the net exposure of the strategy or account in its limit currency, if any limits
+    /// are set.
+    #[must_use]
+    pub fn net_exposure(&self, id: &K) -> Option<Money> {
+        let currency = self.limits.get(id)?.currency()?;
+        let state = self.states.get(id);
+        Some(state.map_or(Money::from_raw(0, currency), |state| {
+            state.net_exposure(currency)
+        }))
+    }
+
+    /// Sets the signed `exposure` of the strategy or account to the given instrument, positive for
+    /// long and negative for short, with zero removing the instrument.
+    pub fn update_exposure(&mut self, id: K, instrument_id: InstrumentId, exposure: Money) {
+        let state = self.states.entry(id).or_default();
+        if exposure.raw == 0 {
+            state.exposures.shift_remove(&instrument_id);
+        } else {
+            state.exposures.insert(instrument_id, exposure);
+        }
+    }
+
+    /// Checks an order for the strategy or account with the given `side` and `notional` value at
+    /// `ts`, returning the first limit it would breach.
+    ///
+    /// Exposure limits are only breached by orders which increase the exposure. Orders which
+    /// pass are counted towards the orders per minute limit.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `notional` is not in the exposure limit currency.
+    pub fn check_order(
+        &mut self,
+        id: K,
+        instrument_id: InstrumentId,
+        side: OrderSideSpecified,
+        notional: Money,
+        ts: UnixNanos,
+    ) -> anyhow::Result<Option<StrategyLimitBreach>> {
+        let Some(limits) = self.limits.get(&id) else {
+            return Ok(None);
+        };
+        if let Some(currency) = limits.currency() {
+            ensure!(
+                notional.currency == currency,
+                "Order notional {notional} for {id} is not in the limit currency {currency}",
+            );
+        }
+
+        let state = self.states.entry(id).or_default();
+        while state
+            .order_timestamps
+            .front()
+            .is_some_and(|front| front.as_u64() + NANOSECONDS_IN_MINUTE <= ts.as_u64())
+        {
+            state.order_timestamps.pop_front();
+        }
+        if let Some(limit) = limits.max_orders_per_minute {
+            let value = state.order_timestamps.len() + 1;
+            if value > limit {
+                return Ok(Some(StrategyLimitBreach::OrdersPerMinute { limit, value }));
+            }
+        }
+
+        let current = state.exposures.get(&instrument_id).copied();
+        if let Some(limit) = limits.max_instruments {
+            let value = state.exposures.len() + usize::from(current.is_none());
+            if value > limit {
+                return Ok(Some(StrategyLimitBreach::Instruments { limit, value }));
+            }
+        }
+
+        let signed = match side {
+            OrderSideSpecified::Buy => notional.raw,
+            OrderSideSpecified::Sell => -notional.raw,
+        };
+        let mut projected = StrategyState::default();
+        projected.exposures.clone_from(&state.exposures);
+        let current_raw = current.map_or(0, |exposure| exposure.raw);
+        projected.exposures.insert(
+            instrument_id,
+            Money::from_raw(current_raw + signed, notional.currency),
+        );
+
+        if let Some(limit) = limits.max_gross_exposure {
+            let (before, value) = (
+                state.gross_exposure(limit.currency),
+                projected.gross_exposure(limit.currency),
+            );
+            if value > limit && value > before {
+                return Ok(Some(StrategyLimitBreach::GrossExposure { limit, value }));
+            }
+        }
+        if let Some(limit) = limits.max_net_exposure {
+            let (before, value) = (
+                state.net_exposure(limit.currency),
+                projected.net_exposure(limit.currency),
+            );
+            if value > limit && value > before {
+                return Ok(Some(StrategyLimitBreach::NetExposure { limit, value }));
+            }
+        }
+
+        state.order_timestamps.push_back(ts);
+        Ok(None)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn strategy_id() -> StrategyId {
+        StrategyId::from("S-001")
+    }
+
+    #[fixture]
+    fn checker() -> StrategyLimitChecker {
+        StrategyLimitChecker::new(HashMap::from([(
+            strategy_id(),
+            StrategyLimits {
+                max_gross_exposure: Some(Money::from("100000 USD")),
+                max_net_exposure: Some(Money::from("50000 USD")),
+                max_instruments: Some(2),
+                max_orders_per_minute: Some(3),
+            },
+        )]))
+    }
+
+    fn check(
+        checker: &mut StrategyLimitChecker,
+        instrument_id: &str,
+        side: OrderSideSpecified,
+        notional: &str,
+        ts: u64,
+    ) -> Option<StrategyLimitBreach> {
+        checker
+            .check_order(
+                strategy_id(),
+                InstrumentId::from(instrument_id),
+                side,
+                Money::from(notional),
+                UnixNanos::from(ts),
+            )
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_unlimited_strategy_passes() {
+        let mut checker = StrategyLimitChecker::default();
+        let breach = check(
+            &mut checker,
+            "AUD/USD.SIM",
+            OrderSideSpecified::Buy,
+            "1000000000 USD",
+            0,
+        );
+        assert!(breach.is_none());
+    }
+
+    #[rstest]
+    fn test_net_exposure_nets_long_and_short(mut checker: StrategyLimitChecker) {
+        checker.update_exposure(
+            strategy_id(),
+            InstrumentId::from("AUD/USD.SIM"),
+            Money::from("40000 USD"),
+        );
+
+        let breach = check(
+            &mut checker,
+            "AUD/USD.SIM",
+            OrderSideSpecified::Buy,
+            "20000 USD",
+            0,
+        );
+        assert_eq!(
+            breach,
+            Some(StrategyLimitBreach::NetExposure {
+                limit: Money::from("50000 USD"),
+                value: Money::from("60000 USD"),
+            })
+        );
+
+        let breach = check(
+            &mut checker,
+            "EUR/USD.SIM",
+            OrderSideSpecified::Sell,
+            "30000 USD",
+            0,
+        );
+        assert!(breach.is_none());
+        checker.update_exposure(
+            strategy_id(),
+            InstrumentId::from("EUR/USD.SIM"),
+            Money::from("-30000 USD"),
+        );
+        assert_eq!(
+            checker.gross_exposure(&strategy_id()),
+            Some(Money::from("70000 USD"))
+        );
+        assert_eq!(
+            checker.net_exposure(&strategy_id()),
+            Some(Money::from("10000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_gross_exposure_breach_reports_excess(mut checker: StrategyLimitChecker) {
+        checker.update_exposure(
+            strategy_id(),
+            InstrumentId::from("AUD/USD.SIM"),
+            Money::from("45000 USD"),
+        );
+        checker.update_exposure(
+            strategy_id(),
+            InstrumentId::from("EUR/USD.SIM"),
+            Money::from("-45000 USD"),
+        );
+
+        let breach = check(
+            &mut checker,
+            "EUR/USD.SIM",
+            OrderSideSpecified::Sell,
+            "20000 USD",
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            breach.to_string(),
+            "gross exposure 110000.00 USD exceeds limit 100000.00 USD by 10000.00 USD"
+        );
+    }
+
+    #[rstest]
+    fn test_reducing_order_passes_when_over_limit(mut checker: StrategyLimitChecker) {
+        checker.update_exposure(
+            strategy_id(),
+            InstrumentId::from("AUD/USD.SIM"),
+            Money::from("80000 USD"),
+        );
+
+        let breach = check(
+            &mut checker,
+            "AUD/USD.SIM",
+            OrderSideSpecified::Sell,
+            "10000 USD",
+            0,
+        );
+        assert!(breach.is_none());
+    }
+
+    #[rstest]
+    fn test_max_instruments(mut checker: StrategyLimitChecker) {
+        for instrument_id in ["AUD/USD.SIM", "EUR/USD.SIM"] {
+            checker.update_exposure(
+                strategy_id(),
+                InstrumentId::from(instrument_id),
+                Money::from("1000 USD"),
+            );
+        }
+
+        let breach = check(
+            &mut checker,
+            "GBP/USD.SIM",
+            OrderSideSpecified::Buy,
+            "1000 USD",
+            0,
+        );
+        assert_eq!(
+            breach,
+            Some(StrategyLimitBreach::Instruments { limit: 2, value: 3 })
+        );
+        assert!(check(
+            &mut checker,
+            "EUR/USD.SIM",
+            OrderSideSpecified::Buy,
+            "1000 USD",
+            0
+        )
+        .is_none());
+    }
+
+    #[rstest]
+    fn test_max_orders_per_minute_window(mut checker: StrategyLimitChecker) {
+        for ts in [0, 10, 20] {
+            assert!(check(
+                &mut checker,
+                "AUD/USD.SIM",
+                OrderSideSpecified::Buy,
+                "1 USD",
+                ts
+            )
+            .is_none());
+        }
+
+        let breach = check(
+            &mut checker,
+            "AUD/USD.SIM",
+            OrderSideSpecified::Buy,
+            "1 USD",
+            30,
+        );
+        assert_eq!(
+            breach,
+            Some(StrategyLimitBreach::OrdersPerMinute { limit: 3, value: 4 })
+        );
+
+        let ts = NANOSECONDS_IN_MINUTE;
+        assert!(check(
+            &mut checker,
+            "AUD/USD.SIM",
+            OrderSideSpecified::Buy,
+            "1 USD",
+            ts
+        )
+        .is_none());
+    }
+
+    #[rstest]
+    fn test_currency_mismatch(mut checker: StrategyLimitChecker) {
+        let err = checker
+            .check_order(
+                strategy_id(),
+                InstrumentId::from("EUR/USD.SIM"),
+                OrderSideSpecified::Buy,
+                Money::from("1000 EUR"),
+                UnixNanos::default(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Order notional 1000.00 EUR for S-001 is not in the limit currency USD"
+        );
+    }
+
+    #[rstest]
+    fn test_limits_validation() {
+        let limits = StrategyLimits {
+            max_gross_exposure: Some(Money::from("100000 USD")),
+            max_net_exposure: Some(Money::from("50000 EUR")),
+            ..Default::default()
+        };
+        assert_eq!(
+            limits.validate_for(&strategy_id()).unwrap_err().to_string(),
+            "Invalid setting 'risk_engine.strategy_limits.S-001.max_net_exposure': \
+             must be in the max gross exposure currency USD"
+        );
+    }
+
+    #[rstest]
+    fn test_account_limits_checked_per_account() {
+        let sub_account = AccountId::from("SIM-002");
+        let limits = StrategyLimits {
+            max_gross_exposure: Some(Money::from("100000 USD")),
+            ..Default::default()
+        };
+        let mut checker = AccountLimitChecker::new(HashMap::from([(sub_account, limits.clone())]));
+        checker.update_exposure(
+            sub_account,
+            InstrumentId::from("AUD/USD.SIM"),
+            Money::from("90000 USD"),
+        );
+
+        let check = |checker: &mut AccountLimitChecker, account_id: AccountId| {
+            checker
+                .check_order(
+                    account_id,
+                    InstrumentId::from("AUD/USD.SIM"),
+                    OrderSideSpecified::Buy,
+                    Money::from("20000 USD"),
+                    UnixNanos::default(),
+                )
+                .unwrap()
+        };
+        assert_eq!(
+            check(&mut checker, sub_account),
+            Some(StrategyLimitBreach::GrossExposure {
+                limit: Money::from("100000 USD"),
+                value: Money::from("110000 USD"),
+            })
+        );
+        assert!(check(&mut checker, AccountId::from("SIM-001")).is_none());
+
+        let limits = StrategyLimits {
+            max_instruments: Some(0),
+            ..limits
+        };
+        assert_eq!(
+            limits
+                .validate_for_account(&sub_account)
+                .unwrap_err()
+                .to_string(),
+            "Invalid setting 'risk_engine.account_limits.SIM-002.max_instruments': must be positive"
+        );
+    }
+}
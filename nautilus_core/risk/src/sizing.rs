@@ -13,31 +13,449 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Position sizing calculation functions.
-
-// Under development
-#![allow(dead_code)]
-#![allow(unused_variables)]
+//! Position sizing for strategies and the risk engine.
+//!
+//! A [`PositionSizer`] calculates the quantity for a position from a [`SizingContext`], rounded
+//! down to the size increment of the instrument and limited to its maximum quantity. Sizes below
+//! the minimum quantity of the instrument are returned as zero.
+//!
+//! The risk engine denies orders which exceed the size calculated by the sizer set for their
+//! strategy with `RiskEngine::set_position_sizer`.
 
+use anyhow::{bail, ensure};
 use nautilus_model::{
     instruments::any::InstrumentAny,
     types::{money::Money, price::Price, quantity::Quantity},
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+/// Provides the inputs for a position sizing calculation.
+#[derive(Clone, Copy, Debug)]
+pub struct SizingContext {
+    /// The account equity available for sizing.
+    pub equity: Money,
+    /// The expected entry price.
+    pub entry: Price,
+    /// The stop-loss price, required for fixed risk sizing.
+    pub stop_loss: Option<Price>,
+    /// The annualized volatility of the instrument returns, required for volatility target
+    /// sizing, e.g. 0.2 for 20%.
+    pub volatility: Option<f64>,
+    /// The exchange rate from the instrument quote currency to the account currency.
+    pub exchange_rate: Decimal,
+}
+
+impl SizingContext {
+    /// Creates a new [`SizingContext`] instance, with an exchange rate of one.
+    #[must_use]
+    pub const fn new(equity: Money, entry: Price) -> Self {
+        Self {
+            equity,
+            entry,
+            stop_loss: None,
+            volatility: None,
+            exchange_rate: Decimal::ONE,
+        }
+    }
+
+    /// Sets the `stop_loss` price.
+    #[must_use]
+    pub const fn with_stop_loss(mut self, stop_loss: Price) -> Self {
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    /// Sets the annualized `volatility` of the instrument returns.
+    #[must_use]
+    pub const fn with_volatility(mut self, volatility: f64) -> Self {
+        self.volatility = Some(volatility);
+        self
+    }
+
+    /// Sets the `exchange_rate` from the instrument quote currency to the account currency.
+    #[must_use]
+    pub const fn with_exchange_rate(mut self, exchange_rate: Decimal) -> Self {
+        self.exchange_rate = exchange_rate;
+        self
+    }
+
+    fn quote_value(&self, account_value: f64) -> anyhow::Result<f64> {
+        let exchange_rate = self.exchange_rate.to_f64().unwrap_or_default();
+        ensure!(exchange_rate > 0.0, "Exchange rate must be positive");
+        Ok(account_value / exchange_rate)
+    }
+}
+
+/// Provides the calculation of position sizes.
+pub trait PositionSizer {
+    /// Returns the position size for the `instrument` in the given `context`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the context is missing an input the sizer requires, or
+    /// an input is invalid.
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        context: &SizingContext,
+    ) -> anyhow::Result<Quantity>;
+}
+
+/// Sizes positions to risk a fixed fraction of equity between the entry and stop-loss prices.
+#[derive(Clone, Debug)]
+pub struct FixedRiskSizer {
+    /// The fraction of equity to risk per trade, e.g. 0.01 for 1%.
+    pub risk: Decimal,
+    /// The commission rate, charged on entry and exit.
+    pub commission_rate: Decimal,
+    /// The hard limit for the total position size (unlimited if None).
+    pub hard_limit: Option<Decimal>,
+    /// The unit batch size each unit is rounded down to a multiple of (no batching if zero).
+    pub unit_batch_size: Decimal,
+    /// The number of units the position is split into.
+    pub units: usize,
+}
+
+impl FixedRiskSizer {
+    /// Creates a new [`FixedRiskSizer`] instance risking the fraction `risk` of equity.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `risk` is not in the range (0, 1].
+    pub fn new(risk: Decimal) -> anyhow::Result<Self> {
+        ensure!(
+            risk > Decimal::ZERO && risk <= Decimal::ONE,
+            "Risk must be in the range (0, 1], was {risk}"
+        );
+        Ok(Self {
+            risk,
+            commission_rate: Decimal::ZERO,
+            hard_limit: None,
+            unit_batch_size: Decimal::ZERO,
+            units: 1,
+        })
+    }
+}
+
+impl PositionSizer for FixedRiskSizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        context: &SizingContext,
+    ) -> anyhow::Result<Quantity> {
+        let Some(stop_loss) = context.stop_loss else {
+            bail!("A stop-loss price is required for fixed risk sizing");
+        };
+        ensure!(
+            context.exchange_rate > Decimal::ZERO,
+            "Exchange rate must be positive"
+        );
+        ensure!(self.units > 0, "Units must be positive");
+
+        Ok(calculate_fixed_risk_position_size(
+            instrument,
+            context.entry,
+            stop_loss,
+            context.equity,
+            self.risk,
+            self.commission_rate,
+            context.exchange_rate,
+            self.hard_limit,
+            self.unit_batch_size,
+            self.units,
+        ))
+    }
+}
+
+/// Sizes positions with a fraction of the Kelly criterion for a strategy's win probability and
+/// average win to loss ratio.
+#[derive(Clone, Debug)]
+pub struct KellySizer {
+    /// The probability of a winning trade.
+    pub win_probability: f64,
+    /// The ratio of the average win to the average loss.
+    pub win_loss_ratio: f64,
+    /// The fraction of the full Kelly allocation to use, e.g. 0.5 for half Kelly.
+    pub fraction: f64,
+}
+
+impl KellySizer {
+    /// Creates a new [`KellySizer`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `win_probability` is not in the range [0, 1].
+    /// - If `win_loss_ratio` is not positive.
+    /// - If `fraction` is not in the range (0, 1].
+    pub fn new(win_probability: f64, win_loss_ratio: f64, fraction: f64) -> anyhow::Result<Self> {
+        ensure!(
+            (0.0..=1.0).contains(&win_probability),
+            "Win probability must be in the range [0, 1], was {win_probability}"
+        );
+        ensure!(
+            win_loss_ratio > 0.0,
+            "Win to loss ratio must be positive, was {win_loss_ratio}"
+        );
+        ensure!(
+            fraction > 0.0 && fraction <= 1.0,
+            "Kelly fraction must be in the range (0, 1], was {fraction}"
+        );
+        Ok(Self {
+            win_probability,
+            win_loss_ratio,
+            fraction,
+        })
+    }
 
+    /// Returns the full Kelly fraction of equity to allocate, which is zero or negative when the
+    /// strategy has no edge.
+    #[must_use]
+    pub fn kelly_fraction(&self) -> f64 {
+        self.win_probability - (1.0 - self.win_probability) / self.win_loss_ratio
+    }
+}
+
+impl PositionSizer for KellySizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        context: &SizingContext,
+    ) -> anyhow::Result<Quantity> {
+        let allocation = self.kelly_fraction().max(0.0) * self.fraction;
+        let notional = context.quote_value(context.equity.as_f64() * allocation)?;
+        Ok(size_for_notional(instrument, context.entry, notional))
+    }
+}
+
+/// Sizes positions so their expected annualized volatility is a target fraction of equity.
+#[derive(Clone, Debug)]
+pub struct VolatilityTargetSizer {
+    /// The target annualized volatility, e.g. 0.1 for 10%.
+    pub target_volatility: f64,
+    /// The maximum position notional as a multiple of equity.
+    pub max_leverage: f64,
+}
+
+impl VolatilityTargetSizer {
+    /// Creates a new [`VolatilityTargetSizer`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `target_volatility` or `max_leverage` is not positive.
+    pub fn new(target_volatility: f64, max_leverage: f64) -> anyhow::Result<Self> {
+        ensure!(
+            target_volatility > 0.0,
+            "Target volatility must be positive, was {target_volatility}"
+        );
+        ensure!(
+            max_leverage > 0.0,
+            "Max leverage must be positive, was {max_leverage}"
+        );
+        Ok(Self {
+            target_volatility,
+            max_leverage,
+        })
+    }
+}
+
+impl PositionSizer for VolatilityTargetSizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        context: &SizingContext,
+    ) -> anyhow::Result<Quantity> {
+        let Some(volatility) = context.volatility else {
+            bail!("A volatility estimate is required for volatility target sizing");
+        };
+        ensure!(
+            volatility > 0.0,
+            "Volatility must be positive, was {volatility}"
+        );
+
+        let leverage = (self.target_volatility / volatility).min(self.max_leverage);
+        let notional = context.quote_value(context.equity.as_f64() * leverage)?;
+        Ok(size_for_notional(instrument, context.entry, notional))
+    }
+}
+
+/// Returns the position size which risks the fraction `risk` of `equity` (net of commissions)
+/// between the `entry` and `stop_loss` prices, limited to the `hard_limit` and split into
+/// `units` rounded down to a multiple of the `unit_batch_size`.
+///
+/// The returned size is for each unit.
 #[must_use]
 #[allow(clippy::too_many_arguments)]
 pub fn calculate_fixed_risk_position_size(
-    instrument: InstrumentAny,
+    instrument: &InstrumentAny,
     entry: Price,
     stop_loss: Price,
     equity: Money,
     risk: Decimal,
     commission_rate: Decimal,
-    exchange_range: Decimal,
+    exchange_rate: Decimal,
     hard_limit: Option<Decimal>,
     unit_batch_size: Decimal,
     units: usize,
 ) -> Quantity {
-    todo!()
+    let zero = Quantity::zero(instrument.size_precision());
+    let risk_per_unit =
+        (entry.as_decimal() - stop_loss.as_decimal()).abs() * instrument.multiplier().as_decimal();
+    let equity = equity.as_decimal();
+    if risk_per_unit <= Decimal::ZERO
+        || equity <= Decimal::ZERO
+        || exchange_rate <= Decimal::ZERO
+        || units == 0
+    {
+        return zero;
+    }
+
+    // Commission is charged on entry and exit
+    let risk_money = equity * risk;
+    let risk_money = risk_money - risk_money * commission_rate * Decimal::TWO;
+
+    let mut position_size = risk_money / exchange_rate / risk_per_unit;
+    if let Some(hard_limit) = hard_limit {
+        position_size = position_size.min(hard_limit);
+    }
+
+    let mut unit_size = (position_size / Decimal::from(units)).max(Decimal::ZERO);
+    if unit_batch_size > Decimal::ZERO {
+        unit_size = (unit_size / unit_batch_size).floor() * unit_batch_size;
+    }
+    round_to_instrument(instrument, unit_size.to_f64().unwrap_or_default())
+}
+
+fn size_for_notional(instrument: &InstrumentAny, entry: Price, notional: f64) -> Quantity {
+    let unit_value = entry.as_f64() * instrument.multiplier().as_f64();
+    if unit_value <= 0.0 {
+        return Quantity::zero(instrument.size_precision());
+    }
+    round_to_instrument(instrument, notional / unit_value)
+}
+
+fn round_to_instrument(instrument: &InstrumentAny, size: f64) -> Quantity {
+    let increment = instrument.size_increment().as_f64();
+    // Tolerance so sizes which are already a multiple of the increment are not rounded down
+    let mut size = ((size / increment) + 1e-9).floor() * increment;
+    if let Some(max_quantity) = instrument.max_quantity() {
+        size = size.min(max_quantity.as_f64());
+    }
+    if size <= 0.0
+        || instrument
+            .min_quantity()
+            .is_some_and(|min_quantity| size < min_quantity.as_f64())
+    {
+        return Quantity::zero(instrument.size_precision());
+    }
+    instrument.make_qty(size)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::{currency_pair::CurrencyPair, stubs::audusd_sim};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn context() -> SizingContext {
+        SizingContext::new(Money::from("1_000_000 USD"), Price::from("1.00000"))
+    }
+
+    #[rstest]
+    fn test_fixed_risk_size(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sizer = FixedRiskSizer::new(dec!(0.001)).unwrap();
+        let context = context().with_stop_loss(Price::from("0.99900"));
+
+        // Risks 1_000 USD over 0.001 per unit
+        let size = sizer.calculate(&instrument, &context).unwrap();
+        assert_eq!(size, Quantity::from("1000000"));
+    }
+
+    #[rstest]
+    fn test_fixed_risk_size_with_units_and_batch(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sizer = FixedRiskSizer {
+            hard_limit: Some(dec!(500_000)),
+            unit_batch_size: dec!(1_000),
+            units: 3,
+            ..FixedRiskSizer::new(dec!(0.001)).unwrap()
+        };
+        let context = context().with_stop_loss(Price::from("0.99900"));
+
+        let size = sizer.calculate(&instrument, &context).unwrap();
+        assert_eq!(size, Quantity::from("166000"));
+    }
+
+    #[rstest]
+    fn test_fixed_risk_requires_stop_loss(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sizer = FixedRiskSizer::new(dec!(0.01)).unwrap();
+        let err = sizer.calculate(&instrument, &context()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "A stop-loss price is required for fixed risk sizing"
+        );
+    }
+
+    #[rstest]
+    fn test_kelly_size(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        // Full Kelly is 0.6 - 0.4 / 2 = 0.4, half Kelly allocates 20% of equity
+        let sizer = KellySizer::new(0.6, 2.0, 0.5).unwrap();
+        assert!((sizer.kelly_fraction() - 0.4).abs() < 1e-12);
+
+        let size = sizer.calculate(&instrument, &context()).unwrap();
+        assert_eq!(size, Quantity::from("200000"));
+    }
+
+    #[rstest]
+    fn test_kelly_without_edge_is_zero(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sizer = KellySizer::new(0.3, 1.0, 1.0).unwrap();
+        let size = sizer.calculate(&instrument, &context()).unwrap();
+        assert_eq!(size, Quantity::from("0"));
+    }
+
+    #[rstest]
+    #[case(0.2, "500000")]
+    #[case(0.01, "1000000")] // Limited by max leverage then max quantity
+    fn test_volatility_target_size(
+        audusd_sim: CurrencyPair,
+        #[case] volatility: f64,
+        #[case] expected: &str,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sizer = VolatilityTargetSizer::new(0.1, 3.0).unwrap();
+        let context = context()
+            .with_volatility(volatility)
+            .with_exchange_rate(dec!(1));
+
+        let size = sizer.calculate(&instrument, &context).unwrap();
+        assert_eq!(size, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_size_below_min_quantity_is_zero(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sizer = VolatilityTargetSizer::new(0.1, 3.0).unwrap();
+        let context =
+            SizingContext::new(Money::from("50 USD"), Price::from("1.00000")).with_volatility(0.1);
+        assert_eq!(
+            sizer.calculate(&instrument, &context).unwrap(),
+            Quantity::from("0")
+        );
+    }
+
+    #[rstest]
+    fn test_invalid_sizer_parameters() {
+        assert!(FixedRiskSizer::new(dec!(0)).is_err());
+        assert!(KellySizer::new(1.5, 1.0, 0.5).is_err());
+        assert!(VolatilityTargetSizer::new(0.1, 0.0).is_err());
+    }
 }
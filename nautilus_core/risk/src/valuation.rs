@@ -0,0 +1,407 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mark-to-market valuation of positions and portfolios.
+
+use std::collections::HashMap;
+
+use nautilus_core::correctness::{check_positive_decimal, check_slice_not_empty, FAILED};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::{PositionSide, PriceType},
+    identifiers::InstrumentId,
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price},
+};
+use rust_decimal::Decimal;
+use strum::Display;
+
+/// The source of a price used for valuation.
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValuationPriceSource {
+    /// The venue published mark price.
+    Mark,
+    /// The venue published index price.
+    Index,
+    /// The midpoint of the last quote.
+    Mid,
+    /// The last traded price.
+    Last,
+}
+
+/// Provides mark-to-market valuation of positions and portfolios in a target currency.
+///
+/// Prices are resolved per instrument using the first available source in order of preference.
+#[derive(Clone, Debug)]
+pub struct ValuationService {
+    price_sources: Vec<ValuationPriceSource>,
+    prices: HashMap<(InstrumentId, ValuationPriceSource), Price>,
+    exchange_rates: HashMap<(Currency, Currency), Decimal>,
+}
+
+impl ValuationService {
+    /// Creates a new [`ValuationService`] instance with correctness checking.
+    pub fn new_checked(price_sources: Vec<ValuationPriceSource>) -> anyhow::Result<Self> {
+        check_slice_not_empty(&price_sources, stringify!(price_sources))?;
+
+        Ok(Self {
+            price_sources,
+            prices: HashMap::new(),
+            exchange_rates: HashMap::new(),
+        })
+    }
+
+    /// Creates a new [`ValuationService`] instance.
+    #[must_use]
+    pub fn new(price_sources: Vec<ValuationPriceSource>) -> Self {
+        Self::new_checked(price_sources).expect(FAILED)
+    }
+
+    /// Returns the price sources in order of preference.
+    #[must_use]
+    pub fn price_sources(&self) -> &[ValuationPriceSource] {
+        &self.price_sources
+    }
+
+    pub fn update_mark_price(&mut self, instrument_id: InstrumentId, price: Price) {
+        self.prices
+            .insert((instrument_id, ValuationPriceSource::Mark), price);
+    }
+
+    pub fn update_index_price(&mut self, instrument_id: InstrumentId, price: Price) {
+        self.prices
+            .insert((instrument_id, ValuationPriceSource::Index), price);
+    }
+
+    pub fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.prices.insert(
+            (quote.instrument_id, ValuationPriceSource::Mid),
+            quote.extract_price(PriceType::Mid),
+        );
+    }
+
+    pub fn handle_trade(&mut self, trade: &TradeTick) {
+        self.prices.insert(
+            (trade.instrument_id, ValuationPriceSource::Last),
+            trade.price,
+        );
+    }
+
+    /// Updates the exchange `rate` to convert an amount in `from` into `to`.
+    ///
+    /// The inverse rate is used when converting in the opposite direction.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `rate` is not positive.
+    pub fn update_exchange_rate(
+        &mut self,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> anyhow::Result<()> {
+        check_positive_decimal(rate, stringify!(rate))?;
+        self.exchange_rates.insert((from, to), rate);
+        Ok(())
+    }
+
+    /// Returns the preferred available price for the given `instrument_id`, along with its source.
+    #[must_use]
+    pub fn price(&self, instrument_id: &InstrumentId) -> Option<(Price, ValuationPriceSource)> {
+        self.price_sources.iter().find_map(|source| {
+            self.prices
+                .get(&(*instrument_id, *source))
+                .map(|price| (*price, *source))
+        })
+    }
+
+    /// Returns the exchange rate to convert an amount in `from` into `to` (if available).
+    #[must_use]
+    pub fn exchange_rate(&self, from: Currency, to: Currency) -> Option<Decimal> {
+        if from == to {
+            return Some(Decimal::ONE);
+        }
+        self.exchange_rates.get(&(from, to)).copied().or_else(|| {
+            self.exchange_rates
+                .get(&(to, from))
+                .and_then(|rate| Decimal::ONE.checked_div(*rate))
+        })
+    }
+
+    /// Converts the given `amount` into the `target` currency.
+    pub fn convert(&self, amount: Money, target: Currency) -> anyhow::Result<Money> {
+        let rate = self.exchange_rate(amount.currency, target).ok_or_else(|| {
+            anyhow::anyhow!("No exchange rate for {}/{}", amount.currency, target)
+        })?;
+        let value = amount
+            .as_decimal()
+            .checked_mul(rate)
+            .ok_or_else(|| anyhow::anyhow!("Overflow converting {amount} into {target}"))?;
+        Money::from_decimal(value, target)
+    }
+
+    /// Returns the signed market value of the given `position` in the `target` currency,
+    /// where short positions have a negative value.
+    pub fn value_position(&self, position: &Position, target: Currency) -> anyhow::Result<Money> {
+        let price = self.position_price(position)?;
+        let notional = position.notional_value(price);
+        let value = match position.side {
+            PositionSide::Short => -notional,
+            PositionSide::Flat | PositionSide::NoPositionSide => Money::new(0.0, notional.currency),
+            PositionSide::Long => notional,
+        };
+        self.convert(value, target)
+    }
+
+    /// Returns the unrealized PnL of the given `position` in the `target` currency.
+    pub fn unrealized_pnl(&self, position: &Position, target: Currency) -> anyhow::Result<Money> {
+        let price = self.position_price(position)?;
+        self.convert(position.unrealized_pnl(price), target)
+    }
+
+    /// Returns the net market value of all the given `positions` in the `target` currency.
+    pub fn value_portfolio(
+        &self,
+        positions: &[&Position],
+        target: Currency,
+    ) -> anyhow::Result<Money> {
+        positions
+            .iter()
+            .try_fold(Money::new(0.0, target), |total, position| {
+                Ok(total + self.value_position(position, target)?)
+            })
+    }
+
+    /// Returns the total unrealized PnL of all the given `positions` in the `target` currency.
+    pub fn portfolio_unrealized_pnl(
+        &self,
+        positions: &[&Position],
+        target: Currency,
+    ) -> anyhow::Result<Money> {
+        positions
+            .iter()
+            .try_fold(Money::new(0.0, target), |total, position| {
+                Ok(total + self.unrealized_pnl(position, target)?)
+            })
+    }
+
+    fn position_price(&self, position: &Position) -> anyhow::Result<Price> {
+        self.price(&position.instrument_id)
+            .map(|(price, _)| price)
+            .ok_or_else(|| anyhow::anyhow!("No valuation price for {}", position.instrument_id))
+    }
+}
+
+impl Default for ValuationService {
+    /// Creates a new default [`ValuationService`] instance preferring mark > mid > last prices.
+    fn default() -> Self {
+        Self::new(vec![
+            ValuationPriceSource::Mark,
+            ValuationPriceSource::Mid,
+            ValuationPriceSource::Last,
+        ])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{AggressorSide, OrderSide, OrderType},
+        identifiers::TradeId,
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, px: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            None,
+            Some(Price::from(px)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, filled.into())
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    fn trade(instrument_id: InstrumentId, px: &str) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(px),
+            Quantity::from(1_000),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    #[rstest]
+    #[should_panic(expected = "the 'price_sources' slice")]
+    fn test_new_with_no_price_sources() {
+        let _ = ValuationService::new(vec![]);
+    }
+
+    #[rstest]
+    fn test_price_source_preference(audusd_sim: CurrencyPair) {
+        let mut service = ValuationService::default();
+        assert!(service.price(&audusd_sim.id).is_none());
+
+        service.handle_trade(&trade(audusd_sim.id, "0.80010"));
+        assert_eq!(
+            service.price(&audusd_sim.id),
+            Some((Price::from("0.80010"), ValuationPriceSource::Last))
+        );
+
+        service.handle_quote(&quote(audusd_sim.id, "0.80000", "0.80002"));
+        assert_eq!(
+            service.price(&audusd_sim.id),
+            Some((Price::from("0.800010"), ValuationPriceSource::Mid))
+        );
+
+        service.update_mark_price(audusd_sim.id, Price::from("0.80005"));
+        assert_eq!(
+            service.price(&audusd_sim.id),
+            Some((Price::from("0.80005"), ValuationPriceSource::Mark))
+        );
+
+        // Index prices are not used unless configured
+        service.update_index_price(audusd_sim.id, Price::from("0.90000"));
+        assert_eq!(
+            service.price(&audusd_sim.id).unwrap().1,
+            ValuationPriceSource::Mark
+        );
+    }
+
+    #[rstest]
+    fn test_exchange_rate_and_inverse() {
+        let mut service = ValuationService::default();
+        service
+            .update_exchange_rate(Currency::AUD(), Currency::USD(), dec!(0.8))
+            .unwrap();
+
+        assert_eq!(
+            service.exchange_rate(Currency::USD(), Currency::USD()),
+            Some(Decimal::ONE)
+        );
+        assert_eq!(
+            service.exchange_rate(Currency::AUD(), Currency::USD()),
+            Some(dec!(0.8))
+        );
+        assert_eq!(
+            service.exchange_rate(Currency::USD(), Currency::AUD()),
+            Some(dec!(1.25))
+        );
+        assert_eq!(
+            service.exchange_rate(Currency::USD(), Currency::JPY()),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case(Decimal::ZERO)]
+    #[case(dec!(-0.8))]
+    fn test_update_exchange_rate_when_not_positive(#[case] rate: Decimal) {
+        let mut service = ValuationService::default();
+
+        assert!(service
+            .update_exchange_rate(Currency::AUD(), Currency::USD(), rate)
+            .is_err());
+        assert_eq!(
+            service.exchange_rate(Currency::USD(), Currency::AUD()),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_value_positions_in_target_currency(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy, "0.80000");
+        let short = position(&instrument, OrderSide::Sell, "0.80000");
+
+        let mut service = ValuationService::default();
+        service.update_mark_price(instrument.id(), Price::from("0.81000"));
+        service
+            .update_exchange_rate(Currency::AUD(), Currency::USD(), dec!(0.8))
+            .unwrap();
+
+        assert_eq!(
+            service.value_position(&long, Currency::USD()).unwrap(),
+            Money::from("81000 USD")
+        );
+        assert_eq!(
+            service.value_position(&short, Currency::AUD()).unwrap(),
+            Money::from("-101250 AUD")
+        );
+        assert_eq!(
+            service.unrealized_pnl(&long, Currency::USD()).unwrap(),
+            Money::from("1000 USD")
+        );
+        assert_eq!(
+            service
+                .value_portfolio(&[&long, &short], Currency::USD())
+                .unwrap(),
+            Money::from("0 USD")
+        );
+        assert_eq!(
+            service
+                .portfolio_unrealized_pnl(&[&long, &short], Currency::AUD())
+                .unwrap(),
+            Money::from("0 AUD")
+        );
+    }
+
+    #[rstest]
+    fn test_value_position_without_price_or_rate(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy, "0.80000");
+        let mut service = ValuationService::default();
+
+        assert!(service.value_position(&long, Currency::USD()).is_err());
+
+        service.update_mark_price(instrument.id(), Price::from("0.81000"));
+        assert!(service.value_position(&long, Currency::JPY()).is_err());
+    }
+}
@@ -27,8 +27,8 @@ use nautilus_model::{
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_BAR_TYPE, KEY_PRICE_PRECISION,
-    KEY_SIZE_PRECISION,
+    extract_column, extract_price_column, extract_quantity_column, DecodeDataFromRecordBatch,
+    EncodingError, KEY_BAR_TYPE, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
@@ -127,21 +127,21 @@ impl DecodeFromRecordBatch for Bar {
         let (bar_type, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
 
-        let open_values = extract_column::<Int64Array>(cols, "open", 0, DataType::Int64)?;
-        let high_values = extract_column::<Int64Array>(cols, "high", 1, DataType::Int64)?;
-        let low_values = extract_column::<Int64Array>(cols, "low", 2, DataType::Int64)?;
-        let close_values = extract_column::<Int64Array>(cols, "close", 3, DataType::Int64)?;
-        let volume_values = extract_column::<UInt64Array>(cols, "volume", 4, DataType::UInt64)?;
+        let open_values = extract_price_column(cols, "open", 0, price_precision)?;
+        let high_values = extract_price_column(cols, "high", 1, price_precision)?;
+        let low_values = extract_price_column(cols, "low", 2, price_precision)?;
+        let close_values = extract_price_column(cols, "close", 3, price_precision)?;
+        let volume_values = extract_quantity_column(cols, "volume", 4, size_precision)?;
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 5, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 6, DataType::UInt64)?;
 
         let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
             .map(|i| {
-                let open = Price::from_raw(open_values.value(i), price_precision);
-                let high = Price::from_raw(high_values.value(i), price_precision);
-                let low = Price::from_raw(low_values.value(i), price_precision);
-                let close = Price::from_raw(close_values.value(i), price_precision);
-                let volume = Quantity::from_raw(volume_values.value(i), size_precision);
+                let open = Price::from_raw(open_values[i], price_precision);
+                let high = Price::from_raw(high_values[i], price_precision);
+                let low = Price::from_raw(low_values[i], price_precision);
+                let close = Price::from_raw(close_values[i], price_precision);
+                let volume = Quantity::from_raw(volume_values[i], size_precision);
                 let ts_event = ts_event_values.value(i).into();
                 let ts_init = ts_init_values.value(i).into();
 
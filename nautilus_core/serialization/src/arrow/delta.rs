@@ -29,8 +29,8 @@ use nautilus_model::{
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_price_column, extract_quantity_column, DecodeDataFromRecordBatch,
+    EncodingError, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
@@ -143,8 +143,8 @@ impl DecodeFromRecordBatch for OrderBookDelta {
 
         let action_values = extract_column::<UInt8Array>(cols, "action", 0, DataType::UInt8)?;
         let side_values = extract_column::<UInt8Array>(cols, "side", 1, DataType::UInt8)?;
-        let price_values = extract_column::<Int64Array>(cols, "price", 2, DataType::Int64)?;
-        let size_values = extract_column::<UInt64Array>(cols, "size", 3, DataType::UInt64)?;
+        let price_values = extract_price_column(cols, "price", 2, price_precision)?;
+        let size_values = extract_quantity_column(cols, "size", 3, size_precision)?;
         let order_id_values = extract_column::<UInt64Array>(cols, "order_id", 4, DataType::UInt64)?;
         let flags_values = extract_column::<UInt8Array>(cols, "flags", 5, DataType::UInt8)?;
         let sequence_values = extract_column::<UInt64Array>(cols, "sequence", 6, DataType::UInt64)?;
@@ -167,8 +167,8 @@ impl DecodeFromRecordBatch for OrderBookDelta {
                         format!("Invalid enum value, was {side_value}"),
                     )
                 })?;
-                let price = Price::from_raw(price_values.value(i), price_precision);
-                let size = Quantity::from_raw(size_values.value(i), size_precision);
+                let price = Price::from_raw(price_values[i], price_precision);
+                let size = Quantity::from_raw(size_values[i], size_precision);
                 let order_id = order_id_values.value(i);
                 let flags = flags_values.value(i);
                 let sequence = sequence_values.value(i);
@@ -23,7 +23,7 @@ use arrow::{
 };
 use nautilus_model::{
     data::{
-        depth::{OrderBookDepth10, DEPTH10_LEN},
+        depth::{OrderBookDepth, OrderBookDepth10, DEPTH10_LEN},
         order::BookOrder,
     },
     enums::OrderSide,
@@ -419,6 +419,185 @@ impl DecodeDataFromRecordBatch for OrderBookDepth10 {
     }
 }
 
+impl<const N: usize> ArrowSchemaProvider for OrderBookDepth<N> {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let mut fields = Vec::with_capacity(6 * N + 4);
+        for (prefix, data_type) in DEPTH_LEVEL_COLUMNS {
+            for i in 0..N {
+                fields.push(Field::new(
+                    format!("{prefix}_{i}"),
+                    data_type.clone(),
+                    false,
+                ));
+            }
+        }
+        fields.push(Field::new("flags", DataType::UInt8, false));
+        fields.push(Field::new("sequence", DataType::UInt64, false));
+        fields.push(Field::new("ts_event", DataType::UInt64, false));
+        fields.push(Field::new("ts_init", DataType::UInt64, false));
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+/// The per level column prefixes and types, in schema order.
+const DEPTH_LEVEL_COLUMNS: [(&str, DataType); 6] = [
+    ("bid_price", DataType::Int64),
+    ("ask_price", DataType::Int64),
+    ("bid_size", DataType::UInt64),
+    ("ask_size", DataType::UInt64),
+    ("bid_count", DataType::UInt32),
+    ("ask_count", DataType::UInt32),
+];
+
+impl<const N: usize> EncodeToRecordBatch for OrderBookDepth<N> {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut columns: Vec<Arc<dyn Array>> = Vec::with_capacity(6 * N + 4);
+        for i in 0..N {
+            let values = data.iter().map(|depth| depth.bids[i].price.raw);
+            columns.push(Arc::new(Int64Array::from_iter_values(values)));
+        }
+        for i in 0..N {
+            let values = data.iter().map(|depth| depth.asks[i].price.raw);
+            columns.push(Arc::new(Int64Array::from_iter_values(values)));
+        }
+        for i in 0..N {
+            let values = data.iter().map(|depth| depth.bids[i].size.raw);
+            columns.push(Arc::new(UInt64Array::from_iter_values(values)));
+        }
+        for i in 0..N {
+            let values = data.iter().map(|depth| depth.asks[i].size.raw);
+            columns.push(Arc::new(UInt64Array::from_iter_values(values)));
+        }
+        for i in 0..N {
+            let values = data.iter().map(|depth| depth.bid_counts[i]);
+            columns.push(Arc::new(UInt32Array::from_iter_values(values)));
+        }
+        for i in 0..N {
+            let values = data.iter().map(|depth| depth.ask_counts[i]);
+            columns.push(Arc::new(UInt32Array::from_iter_values(values)));
+        }
+        columns.push(Arc::new(UInt8Array::from_iter_values(
+            data.iter().map(|depth| depth.flags),
+        )));
+        columns.push(Arc::new(UInt64Array::from_iter_values(
+            data.iter().map(|depth| depth.sequence),
+        )));
+        columns.push(Arc::new(UInt64Array::from_iter_values(
+            data.iter().map(|depth| depth.ts_event.as_u64()),
+        )));
+        columns.push(Arc::new(UInt64Array::from_iter_values(
+            data.iter().map(|depth| depth.ts_init.as_u64()),
+        )));
+
+        RecordBatch::try_new(Self::get_schema(Some(metadata.clone())).into(), columns)
+    }
+}
+
+impl<const N: usize> DecodeFromRecordBatch for OrderBookDepth<N> {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let mut bid_prices = Vec::with_capacity(N);
+        let mut ask_prices = Vec::with_capacity(N);
+        let mut bid_sizes = Vec::with_capacity(N);
+        let mut ask_sizes = Vec::with_capacity(N);
+        let mut bid_counts = Vec::with_capacity(N);
+        let mut ask_counts = Vec::with_capacity(N);
+
+        for i in 0..N {
+            bid_prices.push(extract_column::<Int64Array>(
+                cols,
+                "bid_price",
+                i,
+                DataType::Int64,
+            )?);
+            ask_prices.push(extract_column::<Int64Array>(
+                cols,
+                "ask_price",
+                N + i,
+                DataType::Int64,
+            )?);
+            bid_sizes.push(extract_column::<UInt64Array>(
+                cols,
+                "bid_size",
+                2 * N + i,
+                DataType::UInt64,
+            )?);
+            ask_sizes.push(extract_column::<UInt64Array>(
+                cols,
+                "ask_size",
+                3 * N + i,
+                DataType::UInt64,
+            )?);
+            bid_counts.push(extract_column::<UInt32Array>(
+                cols,
+                "bid_count",
+                4 * N + i,
+                DataType::UInt32,
+            )?);
+            ask_counts.push(extract_column::<UInt32Array>(
+                cols,
+                "ask_count",
+                5 * N + i,
+                DataType::UInt32,
+            )?);
+        }
+
+        let flags = extract_column::<UInt8Array>(cols, "flags", 6 * N, DataType::UInt8)?;
+        let sequence =
+            extract_column::<UInt64Array>(cols, "sequence", 6 * N + 1, DataType::UInt64)?;
+        let ts_event =
+            extract_column::<UInt64Array>(cols, "ts_event", 6 * N + 2, DataType::UInt64)?;
+        let ts_init = extract_column::<UInt64Array>(cols, "ts_init", 6 * N + 3, DataType::UInt64)?;
+
+        let result = (0..record_batch.num_rows())
+            .map(|row| {
+                let bids = std::array::from_fn(|j| {
+                    BookOrder::new(
+                        OrderSide::Buy,
+                        Price::from_raw(bid_prices[j].value(row), price_precision),
+                        Quantity::from_raw(bid_sizes[j].value(row), size_precision),
+                        0, // Order ID always zero
+                    )
+                });
+                let asks = std::array::from_fn(|j| {
+                    BookOrder::new(
+                        OrderSide::Sell,
+                        Price::from_raw(ask_prices[j].value(row), price_precision),
+                        Quantity::from_raw(ask_sizes[j].value(row), size_precision),
+                        0, // Order ID always zero
+                    )
+                });
+
+                Self::new(
+                    instrument_id,
+                    bids,
+                    asks,
+                    std::array::from_fn(|j| bid_counts[j].value(row)),
+                    std::array::from_fn(|j| ask_counts[j].value(row)),
+                    flags.value(row),
+                    sequence.value(row),
+                    ts_event.value(row).into(),
+                    ts_init.value(row).into(),
+                )
+            })
+            .collect();
+
+        Ok(result)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -426,7 +605,7 @@ impl DecodeDataFromRecordBatch for OrderBookDepth10 {
 mod tests {
 
     use arrow::datatypes::{DataType, Field, Schema};
-    use nautilus_model::data::stubs::stub_depth10;
+    use nautilus_model::data::stubs::{stub_depth10, stub_depth50};
     use rstest::rstest;
 
     use super::*;
@@ -813,4 +992,40 @@ mod tests {
 
         assert_eq!(decoded_data.len(), 1);
     }
+
+    #[rstest]
+    fn test_depth_n_schema_matches_depth10() {
+        assert_eq!(
+            OrderBookDepth::<DEPTH10_LEN>::get_schema(None),
+            OrderBookDepth10::get_schema(None)
+        );
+        assert_eq!(OrderBookDepth::<50>::get_schema(None).fields().len(), 304);
+    }
+
+    #[rstest]
+    fn test_depth_n_encode_decode_round_trip(stub_depth50: OrderBookDepth<50>) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = OrderBookDepth::<50>::get_metadata(&instrument_id, 2, 0);
+        let data = vec![stub_depth50];
+
+        let record_batch = OrderBookDepth::<50>::encode_batch(&metadata, &data).unwrap();
+        let decoded = OrderBookDepth::<50>::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].bids[49].price, stub_depth50.bids[49].price);
+        assert_eq!(decoded[0].asks[49].size, stub_depth50.asks[49].size);
+        assert_eq!(decoded[0].ask_counts, stub_depth50.ask_counts);
+        assert_eq!(decoded[0].ts_init, stub_depth50.ts_init);
+    }
+
+    #[rstest]
+    fn test_depth_n_decode_with_missing_levels(stub_depth10: OrderBookDepth10) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = OrderBookDepth10::get_metadata(&instrument_id, 2, 0);
+        let record_batch = OrderBookDepth10::encode_batch(&metadata, &[stub_depth10]).unwrap();
+
+        let result = OrderBookDepth::<50>::decode_batch(&metadata, record_batch);
+
+        assert!(result.is_err());
+    }
 }
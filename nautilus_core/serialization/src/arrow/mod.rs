@@ -22,20 +22,24 @@ pub mod quote;
 pub mod trade;
 
 use std::{
+    borrow::Cow,
     collections::HashMap,
     io::{self, Write},
 };
 
 use arrow::{
-    array::{Array, ArrayRef},
+    array::{Array, ArrayRef, Float64Array, Int64Array, UInt64Array},
     datatypes::{DataType, Schema},
     error::ArrowError,
     ipc::writer::StreamWriter,
     record_batch::RecordBatch,
 };
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
-    Data,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
+        trade::TradeTick, Data,
+    },
+    types::fixed::{f64_slice_to_fixed_i64, f64_slice_to_fixed_u64, FIXED_PRECISION},
 };
 use pyo3::prelude::*;
 
@@ -99,7 +103,7 @@ where
 
 pub trait DecodeFromRecordBatch
 where
-    Self: Sized + Into<Data> + ArrowSchemaProvider,
+    Self: Sized + ArrowSchemaProvider,
 {
     fn decode_batch(
         metadata: &HashMap<String, String>,
@@ -152,6 +156,67 @@ pub fn extract_column<'a, T: Array + 'static>(
     Ok(downcasted_values)
 }
 
+/// Extracts the raw fixed-point values of a price column.
+///
+/// Raw `Int64` columns are borrowed as is, while `Float64` columns (as written by external
+/// tools) are converted to raw values with the given `precision`.
+pub fn extract_price_column<'a>(
+    cols: &'a [ArrayRef],
+    column_key: &'static str,
+    column_index: usize,
+    precision: u8,
+) -> Result<Cow<'a, [i64]>, EncodingError> {
+    if is_float_column(cols, column_index) {
+        let values =
+            extract_column::<Float64Array>(cols, column_key, column_index, DataType::Float64)?;
+        check_fixed_precision(column_key, precision)?;
+        return Ok(Cow::Owned(f64_slice_to_fixed_i64(
+            values.values(),
+            precision,
+        )));
+    }
+    let values = extract_column::<Int64Array>(cols, column_key, column_index, DataType::Int64)?;
+    Ok(Cow::Borrowed(values.values().as_ref()))
+}
+
+/// Extracts the raw fixed-point values of a quantity column.
+///
+/// Raw `UInt64` columns are borrowed as is, while `Float64` columns (as written by external
+/// tools) are converted to raw values with the given `precision`.
+pub fn extract_quantity_column<'a>(
+    cols: &'a [ArrayRef],
+    column_key: &'static str,
+    column_index: usize,
+    precision: u8,
+) -> Result<Cow<'a, [u64]>, EncodingError> {
+    if is_float_column(cols, column_index) {
+        let values =
+            extract_column::<Float64Array>(cols, column_key, column_index, DataType::Float64)?;
+        check_fixed_precision(column_key, precision)?;
+        return Ok(Cow::Owned(f64_slice_to_fixed_u64(
+            values.values(),
+            precision,
+        )));
+    }
+    let values = extract_column::<UInt64Array>(cols, column_key, column_index, DataType::UInt64)?;
+    Ok(Cow::Borrowed(values.values().as_ref()))
+}
+
+fn is_float_column(cols: &[ArrayRef], column_index: usize) -> bool {
+    cols.get(column_index)
+        .is_some_and(|col| col.data_type() == &DataType::Float64)
+}
+
+fn check_fixed_precision(column_key: &'static str, precision: u8) -> Result<(), EncodingError> {
+    if precision > FIXED_PRECISION {
+        return Err(EncodingError::ParseError(
+            column_key,
+            format!("precision {precision} exceeded maximum {FIXED_PRECISION}"),
+        ));
+    }
+    Ok(())
+}
+
 pub fn order_book_deltas_to_arrow_record_batch_bytes(
     data: Vec<OrderBookDelta>,
 ) -> Result<RecordBatch, EncodingError> {
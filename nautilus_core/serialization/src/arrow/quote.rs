@@ -28,8 +28,8 @@ use nautilus_model::{
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_price_column, extract_quantity_column, DecodeDataFromRecordBatch,
+    EncodingError, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
@@ -125,19 +125,19 @@ impl DecodeFromRecordBatch for QuoteTick {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
 
-        let bid_price_values = extract_column::<Int64Array>(cols, "bid_price", 0, DataType::Int64)?;
-        let ask_price_values = extract_column::<Int64Array>(cols, "ask_price", 1, DataType::Int64)?;
-        let bid_size_values = extract_column::<UInt64Array>(cols, "bid_size", 2, DataType::UInt64)?;
-        let ask_size_values = extract_column::<UInt64Array>(cols, "ask_size", 3, DataType::UInt64)?;
+        let bid_price_values = extract_price_column(cols, "bid_price", 0, price_precision)?;
+        let ask_price_values = extract_price_column(cols, "ask_price", 1, price_precision)?;
+        let bid_size_values = extract_quantity_column(cols, "bid_size", 2, size_precision)?;
+        let ask_size_values = extract_quantity_column(cols, "ask_size", 3, size_precision)?;
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
 
         let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
             .map(|i| {
-                let bid_price = Price::from_raw(bid_price_values.value(i), price_precision);
-                let ask_price = Price::from_raw(ask_price_values.value(i), price_precision);
-                let bid_size = Quantity::from_raw(bid_size_values.value(i), size_precision);
-                let ask_size = Quantity::from_raw(ask_size_values.value(i), size_precision);
+                let bid_price = Price::from_raw(bid_price_values[i], price_precision);
+                let ask_price = Price::from_raw(ask_price_values[i], price_precision);
+                let bid_size = Quantity::from_raw(bid_size_values[i], size_precision);
+                let ask_size = Quantity::from_raw(ask_size_values[i], size_precision);
                 let ts_event = ts_event_values.value(i).into();
                 let ts_init = ts_init_values.value(i).into();
 
@@ -29,8 +29,8 @@ use nautilus_model::{
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_price_column, extract_quantity_column, DecodeDataFromRecordBatch,
+    EncodingError, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
@@ -126,8 +126,8 @@ impl DecodeFromRecordBatch for TradeTick {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
 
-        let price_values = extract_column::<Int64Array>(cols, "price", 0, DataType::Int64)?;
-        let size_values = extract_column::<UInt64Array>(cols, "size", 1, DataType::UInt64)?;
+        let price_values = extract_price_column(cols, "price", 0, price_precision)?;
+        let size_values = extract_quantity_column(cols, "size", 1, size_precision)?;
         let aggressor_side_values =
             extract_column::<UInt8Array>(cols, "aggressor_side", 2, DataType::UInt8)?;
         let trade_id_values = extract_column::<StringArray>(cols, "trade_id", 3, DataType::Utf8)?;
@@ -136,8 +136,8 @@ impl DecodeFromRecordBatch for TradeTick {
 
         let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
             .map(|i| {
-                let price = Price::from_raw(price_values.value(i), price_precision);
-                let size = Quantity::from_raw(size_values.value(i), size_precision);
+                let price = Price::from_raw(price_values[i], price_precision);
+                let size = Quantity::from_raw(size_values[i], size_precision);
                 let aggressor_side_value = aggressor_side_values.value(i);
                 let aggressor_side = AggressorSide::from_repr(aggressor_side_value as usize)
                     .ok_or_else(|| {
@@ -184,7 +184,7 @@ mod tests {
     use std::sync::Arc;
 
     use arrow::{
-        array::{Array, Int64Array, StringArray, UInt64Array, UInt8Array},
+        array::{Array, Float64Array, Int64Array, StringArray, UInt64Array, UInt8Array},
         record_batch::RecordBatch,
     };
     use rstest::rstest;
@@ -308,4 +308,67 @@ mod tests {
         let decoded_data = TradeTick::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[rstest]
+    fn test_decode_batch_with_float_price_and_size() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = TradeTick::get_metadata(&instrument_id, 2, 0);
+        let schema = Schema::new(vec![
+            Field::new("price", DataType::Float64, false),
+            Field::new("size", DataType::Float64, false),
+            Field::new("aggressor_side", DataType::UInt8, false),
+            Field::new("trade_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ]);
+
+        let record_batch = RecordBatch::try_new(
+            schema.into(),
+            vec![
+                Arc::new(Float64Array::from(vec![100.10, 101.00])),
+                Arc::new(Float64Array::from(vec![1000.0, 900.0])),
+                Arc::new(UInt8Array::from(vec![0, 1])),
+                Arc::new(StringArray::from(vec!["1", "2"])),
+                Arc::new(UInt64Array::from(vec![1, 2])),
+                Arc::new(UInt64Array::from(vec![3, 4])),
+            ],
+        )
+        .unwrap();
+
+        let decoded_data = TradeTick::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded_data[0].price, Price::from("100.10"));
+        assert_eq!(decoded_data[0].size, Quantity::from(1000));
+        assert_eq!(decoded_data[1].price, Price::from("101.00"));
+        assert_eq!(decoded_data[1].size, Quantity::from(900));
+    }
+
+    #[rstest]
+    fn test_decode_batch_with_float_price_and_invalid_precision() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = TradeTick::get_metadata(&instrument_id, 10, 0);
+        let schema = Schema::new(vec![
+            Field::new("price", DataType::Float64, false),
+            Field::new("size", DataType::UInt64, false),
+            Field::new("aggressor_side", DataType::UInt8, false),
+            Field::new("trade_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ]);
+
+        let record_batch = RecordBatch::try_new(
+            schema.into(),
+            vec![
+                Arc::new(Float64Array::from(vec![100.10])),
+                Arc::new(UInt64Array::from(vec![1000])),
+                Arc::new(UInt8Array::from(vec![0])),
+                Arc::new(StringArray::from(vec!["1"])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![3])),
+            ],
+        )
+        .unwrap();
+
+        let result = TradeTick::decode_batch(&metadata, record_batch);
+        assert!(matches!(result, Err(EncodingError::ParseError("price", _))));
+    }
 }
@@ -0,0 +1,434 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feature extraction from rolling windows of market data into fixed-shape matrices.
+//!
+//! A [`FeatureExtractor`] converts the recent bars, quotes or trades held in a rolling window
+//! (latest first) into a [`FeatureMatrix`] of `rows` x `features` values, with rows in
+//! chronological order so that the last row is the latest. The matrix can be passed directly
+//! to model inference as a row-major `f64` slice, or converted to an Arrow [`RecordBatch`].
+
+use std::{fmt::Display, sync::Arc};
+
+use arrow::{
+    array::{ArrayRef, Float64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+
+#[derive(thiserror::Error, Debug)]
+pub enum FeatureError {
+    #[error("No features specified")]
+    NoFeatures,
+    #[error("Invalid feature `{0}`: period must be positive")]
+    InvalidPeriod(Feature),
+    #[error("Insufficient data: {required} required, {available} available")]
+    InsufficientData { required: usize, available: usize },
+    #[error("Arrow error: {0}")]
+    ArrowError(#[from] ArrowError),
+}
+
+/// A feature computed for each row of a [`FeatureMatrix`].
+///
+/// The price is the bar close, quote mid or trade price, and the volume is the bar volume,
+/// total quote size or trade size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// The price.
+    Price,
+    /// The volume.
+    Volume,
+    /// The simple return of the price from the previous row.
+    Return,
+    /// The logarithmic return of the price from the previous row.
+    LogReturn,
+    /// The mean of the price over the given period.
+    RollingMean(usize),
+    /// The population standard deviation of the price over the given period.
+    RollingStd(usize),
+    /// The z-score of the price relative to the given period (zero if the deviation is zero).
+    ZScore(usize),
+}
+
+impl Feature {
+    /// Returns the number of values prior to a row required to compute the feature.
+    #[must_use]
+    pub const fn lookback(&self) -> usize {
+        match self {
+            Self::Price | Self::Volume => 0,
+            Self::Return | Self::LogReturn => 1,
+            Self::RollingMean(period) | Self::RollingStd(period) | Self::ZScore(period) => {
+                period.saturating_sub(1)
+            }
+        }
+    }
+
+    const fn period(&self) -> Option<usize> {
+        match self {
+            Self::RollingMean(period) | Self::RollingStd(period) | Self::ZScore(period) => {
+                Some(*period)
+            }
+            _ => None,
+        }
+    }
+
+    // Computes the feature for the value at `index`, which must have sufficient lookback
+    fn compute(&self, prices: &[f64], volumes: &[f64], index: usize) -> f64 {
+        match self {
+            Self::Price => prices[index],
+            Self::Volume => volumes[index],
+            Self::Return => prices[index] / prices[index - 1] - 1.0,
+            Self::LogReturn => (prices[index] / prices[index - 1]).ln(),
+            Self::RollingMean(period) => mean(&prices[index + 1 - period..=index]),
+            Self::RollingStd(period) => std(&prices[index + 1 - period..=index]),
+            Self::ZScore(period) => {
+                let window = &prices[index + 1 - period..=index];
+                let std = std(window);
+                if std == 0.0 {
+                    0.0
+                } else {
+                    (prices[index] - mean(window)) / std
+                }
+            }
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Price => write!(f, "price"),
+            Self::Volume => write!(f, "volume"),
+            Self::Return => write!(f, "return"),
+            Self::LogReturn => write!(f, "log_return"),
+            Self::RollingMean(period) => write!(f, "mean_{period}"),
+            Self::RollingStd(period) => write!(f, "std_{period}"),
+            Self::ZScore(period) => write!(f, "zscore_{period}"),
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn std(values: &[f64]) -> f64 {
+    let mean = mean(values);
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    variance.sqrt()
+}
+
+/// A fixed-shape matrix of feature values, stored row-major with rows in chronological order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureMatrix {
+    features: Vec<Feature>,
+    rows: usize,
+    values: Vec<f64>,
+}
+
+impl FeatureMatrix {
+    /// Returns the features of the matrix columns.
+    #[must_use]
+    pub fn features(&self) -> &[Feature] {
+        &self.features
+    }
+
+    /// Returns the shape of the matrix as (rows, columns).
+    #[must_use]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.features.len())
+    }
+
+    /// Returns the row-major matrix values.
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the values of the given `row`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `row` is out of bounds.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[f64] {
+        let cols = self.features.len();
+        &self.values[row * cols..(row + 1) * cols]
+    }
+
+    /// Returns the values of the given `col`.
+    #[must_use]
+    pub fn column(&self, col: usize) -> Vec<f64> {
+        self.values
+            .iter()
+            .skip(col)
+            .step_by(self.features.len())
+            .copied()
+            .collect()
+    }
+
+    /// Returns the value at the given `row` and `col`.
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if col >= self.features.len() {
+            return None;
+        }
+        self.values.get(row * self.features.len() + col).copied()
+    }
+
+    /// Returns the Arrow schema with a `Float64` field for each feature.
+    #[must_use]
+    pub fn schema(&self) -> Schema {
+        let fields: Vec<Field> = self
+            .features
+            .iter()
+            .map(|feature| Field::new(feature.to_string(), DataType::Float64, false))
+            .collect();
+        Schema::new(fields)
+    }
+
+    /// Converts the matrix to an Arrow record batch with a column for each feature.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the record batch cannot be created.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        let columns: Vec<ArrayRef> = (0..self.features.len())
+            .map(|col| Arc::new(Float64Array::from(self.column(col))) as ArrayRef)
+            .collect();
+        RecordBatch::try_new(Arc::new(self.schema()), columns)
+    }
+}
+
+/// Extracts a fixed-shape [`FeatureMatrix`] from rolling windows of market data.
+#[derive(Clone, Debug)]
+pub struct FeatureExtractor {
+    features: Vec<Feature>,
+    rows: usize,
+}
+
+impl FeatureExtractor {
+    /// Creates a new [`FeatureExtractor`] instance producing `rows` rows of the given `features`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `features` is empty or a rolling feature period is zero.
+    pub fn new(features: Vec<Feature>, rows: usize) -> Result<Self, FeatureError> {
+        if features.is_empty() {
+            return Err(FeatureError::NoFeatures);
+        }
+        if let Some(feature) = features.iter().find(|f| f.period() == Some(0)) {
+            return Err(FeatureError::InvalidPeriod(*feature));
+        }
+
+        Ok(Self { features, rows })
+    }
+
+    /// Returns the number of values required to extract a matrix.
+    #[must_use]
+    pub fn required(&self) -> usize {
+        let lookback = self.features.iter().map(Feature::lookback).max();
+        self.rows + lookback.unwrap_or_default()
+    }
+
+    /// Extracts the features from the given `bars` (latest first).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are fewer bars than required.
+    pub fn extract_bars(&self, bars: &[Bar]) -> Result<FeatureMatrix, FeatureError> {
+        self.extract(
+            bars.iter()
+                .map(|bar| (bar.close.as_f64(), bar.volume.as_f64())),
+        )
+    }
+
+    /// Extracts the features from the given `quotes` (latest first).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are fewer quotes than required.
+    pub fn extract_quotes(&self, quotes: &[QuoteTick]) -> Result<FeatureMatrix, FeatureError> {
+        self.extract(quotes.iter().map(|quote| {
+            let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+            let size = quote.bid_size.as_f64() + quote.ask_size.as_f64();
+            (mid, size)
+        }))
+    }
+
+    /// Extracts the features from the given `trades` (latest first).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are fewer trades than required.
+    pub fn extract_trades(&self, trades: &[TradeTick]) -> Result<FeatureMatrix, FeatureError> {
+        self.extract(
+            trades
+                .iter()
+                .map(|trade| (trade.price.as_f64(), trade.size.as_f64())),
+        )
+    }
+
+    // Takes the (price, volume) values latest first
+    fn extract(
+        &self,
+        values: impl DoubleEndedIterator<Item = (f64, f64)> + ExactSizeIterator,
+    ) -> Result<FeatureMatrix, FeatureError> {
+        let required = self.required();
+        if values.len() < required {
+            return Err(FeatureError::InsufficientData {
+                required,
+                available: values.len(),
+            });
+        }
+
+        let (prices, volumes): (Vec<f64>, Vec<f64>) = values.take(required).rev().unzip();
+        let start = required - self.rows;
+        let mut matrix = Vec::with_capacity(self.rows * self.features.len());
+        for index in start..required {
+            for feature in &self.features {
+                matrix.push(feature.compute(&prices, &volumes, index));
+            }
+        }
+
+        Ok(FeatureMatrix {
+            features: self.features.clone(),
+            rows: self.rows,
+            values: matrix,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use nautilus_model::{
+        data::stubs::stub_bar,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    // Returns bars with the given closes, latest first
+    fn bars(closes: &[&str]) -> Vec<Bar> {
+        closes
+            .iter()
+            .rev()
+            .map(|close| {
+                let mut bar = stub_bar();
+                bar.close = Price::from(*close);
+                bar.volume = Quantity::from(10);
+                bar
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_new_validation() {
+        assert!(matches!(
+            FeatureExtractor::new(vec![], 1),
+            Err(FeatureError::NoFeatures)
+        ));
+        assert!(matches!(
+            FeatureExtractor::new(vec![Feature::ZScore(0)], 1),
+            Err(FeatureError::InvalidPeriod(Feature::ZScore(0)))
+        ));
+    }
+
+    #[rstest]
+    #[case(vec![Feature::Price], 3, 3)]
+    #[case(vec![Feature::Price, Feature::Return], 3, 4)]
+    #[case(vec![Feature::Return, Feature::RollingMean(5)], 2, 6)]
+    fn test_required(#[case] features: Vec<Feature>, #[case] rows: usize, #[case] expected: usize) {
+        let extractor = FeatureExtractor::new(features, rows).unwrap();
+        assert_eq!(extractor.required(), expected);
+    }
+
+    #[rstest]
+    fn test_extract_insufficient_data() {
+        let extractor = FeatureExtractor::new(vec![Feature::Return], 3).unwrap();
+        let result = extractor.extract_bars(&bars(&["1.0", "2.0", "3.0"]));
+
+        assert!(matches!(
+            result,
+            Err(FeatureError::InsufficientData {
+                required: 4,
+                available: 3
+            })
+        ));
+    }
+
+    #[rstest]
+    fn test_extract_bars() {
+        let features = vec![
+            Feature::Price,
+            Feature::Volume,
+            Feature::Return,
+            Feature::RollingMean(2),
+            Feature::ZScore(2),
+        ];
+        let extractor = FeatureExtractor::new(features, 2).unwrap();
+        // Only the most recent bars required are used
+        let matrix = extractor
+            .extract_bars(&bars(&["9.0", "1.0", "2.0", "4.0"]))
+            .unwrap();
+
+        assert_eq!(matrix.shape(), (2, 5));
+        assert_eq!(matrix.row(0), &[2.0, 10.0, 1.0, 1.5, 1.0]);
+        assert_eq!(matrix.row(1), &[4.0, 10.0, 1.0, 3.0, 1.0]);
+        assert_eq!(matrix.get(1, 0), Some(4.0));
+        assert_eq!(matrix.get(0, 5), None);
+    }
+
+    #[rstest]
+    fn test_extract_zscore_with_zero_deviation() {
+        let extractor =
+            FeatureExtractor::new(vec![Feature::RollingStd(3), Feature::ZScore(3)], 1).unwrap();
+        let matrix = extractor
+            .extract_bars(&bars(&["2.0", "2.0", "2.0"]))
+            .unwrap();
+
+        assert_eq!(matrix.as_slice(), &[0.0, 0.0]);
+    }
+
+    #[rstest]
+    fn test_to_record_batch() {
+        let extractor = FeatureExtractor::new(vec![Feature::Price, Feature::LogReturn], 2).unwrap();
+        let matrix = extractor
+            .extract_bars(&bars(&["1.0", "2.0", "4.0"]))
+            .unwrap();
+        let batch = matrix.to_record_batch().unwrap();
+
+        let schema = batch.schema();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(schema.field(0).name(), "price");
+        assert_eq!(schema.field(1).name(), "log_return");
+        let log_returns = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(log_returns.len(), 2);
+        assert_eq!(log_returns.value(1), 2.0_f64.ln());
+    }
+}
@@ -28,6 +28,7 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod arrow;
+pub mod features;
 pub mod parquet;
 
 #[cfg(feature = "python")]
@@ -789,6 +789,55 @@ typedef enum TriggerType {
     INDEX_PRICE = 9,
 } TriggerType;
 
+/**
+ * The normalized reason for an order, modify or cancel request being rejected by a venue.
+ *
+ * Venue-specific error codes are mapped to these categories so strategies can branch on the
+ * cause of a reject portably across venues.
+ */
+typedef enum VenueRejectReason {
+    /**
+     * The reject reason is unknown or has no normalized category.
+     */
+    UNKNOWN = 0,
+    /**
+     * The account has insufficient balance or margin.
+     */
+    INSUFFICIENT_BALANCE = 1,
+    /**
+     * The price is invalid, e.g. outside price limits or not a multiple of the tick size.
+     */
+    INVALID_PRICE = 2,
+    /**
+     * The quantity is invalid, e.g. outside size limits or not a multiple of the size increment.
+     */
+    INVALID_QUANTITY = 3,
+    /**
+     * The request exceeded a venue rate limit.
+     */
+    RATE_LIMITED = 4,
+    /**
+     * The post-only order would have crossed the spread and taken liquidity.
+     */
+    POST_ONLY_WOULD_CROSS = 5,
+    /**
+     * The reduce-only order would have increased the position.
+     */
+    REDUCE_ONLY_WOULD_INCREASE = 6,
+    /**
+     * The client order ID duplicates an existing order.
+     */
+    DUPLICATE_ORDER = 7,
+    /**
+     * The order to modify or cancel was not found.
+     */
+    ORDER_NOT_FOUND = 8,
+    /**
+     * The market is closed or halted.
+     */
+    MARKET_CLOSED = 9,
+} VenueRejectReason;
+
 /**
  * Represents a discrete price level in an order book.
  *
@@ -1360,6 +1409,7 @@ typedef struct OrderRejected_t {
     uint64_t ts_event;
     uint64_t ts_init;
     uint8_t reconciliation;
+    enum VenueRejectReason reject_reason;
 } OrderRejected_t;
 
 /**
@@ -2185,6 +2235,12 @@ struct InstrumentId_t instrument_id_from_cstr(const char *ptr);
  */
 const char *instrument_id_to_cstr(const struct InstrumentId_t *instrument_id);
 
+struct Symbol_t instrument_id_symbol(const struct InstrumentId_t *instrument_id);
+
+struct Venue_t instrument_id_venue(const struct InstrumentId_t *instrument_id);
+
+uint8_t instrument_id_eq(const struct InstrumentId_t *lhs, const struct InstrumentId_t *rhs);
+
 uint64_t instrument_id_hash(const struct InstrumentId_t *instrument_id);
 
 uint8_t instrument_id_is_synthetic(const struct InstrumentId_t *instrument_id);
@@ -2417,6 +2473,14 @@ double orderbook_spread(struct OrderBook_API *book);
 
 double orderbook_midpoint(struct OrderBook_API *book);
 
+/**
+ * Returns the order book imbalance over the top `depth` levels of each side.
+ *
+ * The imbalance is `(bid_size - ask_size) / (bid_size + ask_size)`, or `NaN` when there
+ * are no levels on either side.
+ */
+double orderbook_imbalance(const struct OrderBook_API *book, uintptr_t depth);
+
 double orderbook_get_avg_px_for_quantity(struct OrderBook_API *book,
                                          struct Quantity_t qty,
                                          enum OrderSide order_side);
@@ -2522,6 +2586,25 @@ void money_add_assign(struct Money_t a, struct Money_t b);
 
 void money_sub_assign(struct Money_t a, struct Money_t b);
 
+/**
+ * Writes the sum of `a` and `b` to `result`, returning 1 on success.
+ *
+ * Returns 0 if the currencies of `a` and `b` differ or the result overflows.
+ */
+uint8_t money_add(struct Money_t a, struct Money_t b, struct Money_t *result);
+
+/**
+ * Writes the difference of `a` and `b` to `result`, returning 1 on success.
+ *
+ * Returns 0 if the currencies of `a` and `b` differ or the result underflows.
+ */
+uint8_t money_sub(struct Money_t a, struct Money_t b, struct Money_t *result);
+
+/**
+ * Returns a [`Money`] as a C string pointer.
+ */
+const char *money_to_cstr(const struct Money_t *money);
+
 struct Price_t price_new(double value, uint8_t precision);
 
 struct Price_t price_from_raw(int64_t raw, uint8_t precision);
@@ -2532,6 +2615,25 @@ void price_add_assign(struct Price_t a, struct Price_t b);
 
 void price_sub_assign(struct Price_t a, struct Price_t b);
 
+/**
+ * Writes the sum of `a` and `b` to `result`, returning 1 on success.
+ *
+ * Returns 0 if `b` has a greater precision than `a` or the result overflows.
+ */
+uint8_t price_add(struct Price_t a, struct Price_t b, struct Price_t *result);
+
+/**
+ * Writes the difference of `a` and `b` to `result`, returning 1 on success.
+ *
+ * Returns 0 if `b` has a greater precision than `a` or the result underflows.
+ */
+uint8_t price_sub(struct Price_t a, struct Price_t b, struct Price_t *result);
+
+/**
+ * Returns a [`Price`] as a C string pointer.
+ */
+const char *price_to_cstr(const struct Price_t *price);
+
 struct Quantity_t quantity_new(double value, uint8_t precision);
 
 struct Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
@@ -2545,3 +2647,22 @@ void quantity_add_assign_u64(struct Quantity_t a, uint64_t b);
 void quantity_sub_assign(struct Quantity_t a, struct Quantity_t b);
 
 void quantity_sub_assign_u64(struct Quantity_t a, uint64_t b);
+
+/**
+ * Writes the sum of `a` and `b` to `result`, returning 1 on success.
+ *
+ * Returns 0 if `b` has a greater precision than `a` or the result overflows.
+ */
+uint8_t quantity_add(struct Quantity_t a, struct Quantity_t b, struct Quantity_t *result);
+
+/**
+ * Writes the difference of `a` and `b` to `result`, returning 1 on success.
+ *
+ * Returns 0 if `b` has a greater precision than `a` or the result underflows.
+ */
+uint8_t quantity_sub(struct Quantity_t a, struct Quantity_t b, struct Quantity_t *result);
+
+/**
+ * Returns a [`Quantity`] as a C string pointer.
+ */
+const char *quantity_to_cstr(const struct Quantity_t *qty);
@@ -416,6 +416,32 @@ cdef extern from "../includes/model.h":
         # Based on the index price for the instrument.
         INDEX_PRICE # = 9,
 
+    # The normalized reason for an order, modify or cancel request being rejected by a venue.
+    #
+    # Venue-specific error codes are mapped to these categories so strategies can branch on the
+    # cause of a reject portably across venues.
+    cpdef enum VenueRejectReason:
+        # The reject reason is unknown or has no normalized category.
+        UNKNOWN # = 0,
+        # The account has insufficient balance or margin.
+        INSUFFICIENT_BALANCE # = 1,
+        # The price is invalid, e.g. outside price limits or not a multiple of the tick size.
+        INVALID_PRICE # = 2,
+        # The quantity is invalid, e.g. outside size limits or not a multiple of the size increment.
+        INVALID_QUANTITY # = 3,
+        # The request exceeded a venue rate limit.
+        RATE_LIMITED # = 4,
+        # The post-only order would have crossed the spread and taken liquidity.
+        POST_ONLY_WOULD_CROSS # = 5,
+        # The reduce-only order would have increased the position.
+        REDUCE_ONLY_WOULD_INCREASE # = 6,
+        # The client order ID duplicates an existing order.
+        DUPLICATE_ORDER # = 7,
+        # The order to modify or cancel was not found.
+        ORDER_NOT_FOUND # = 8,
+        # The market is closed or halted.
+        MARKET_CLOSED # = 9,
+
     # Represents a discrete price level in an order book.
     #
     # The level maintains a collection of orders as well as tracking insertion order
@@ -773,6 +799,7 @@ cdef extern from "../includes/model.h":
         uint64_t ts_event;
         uint64_t ts_init;
         uint8_t reconciliation;
+        VenueRejectReason reject_reason;
 
     # Represents a system client ID.
     cdef struct ClientId_t:
@@ -1449,6 +1476,12 @@ cdef extern from "../includes/model.h":
     # Returns an [`InstrumentId`] as a C string pointer.
     const char *instrument_id_to_cstr(const InstrumentId_t *instrument_id);
 
+    Symbol_t instrument_id_symbol(const InstrumentId_t *instrument_id);
+
+    Venue_t instrument_id_venue(const InstrumentId_t *instrument_id);
+
+    uint8_t instrument_id_eq(const InstrumentId_t *lhs, const InstrumentId_t *rhs);
+
     uint64_t instrument_id_hash(const InstrumentId_t *instrument_id);
 
     uint8_t instrument_id_is_synthetic(const InstrumentId_t *instrument_id);
@@ -1654,6 +1687,12 @@ cdef extern from "../includes/model.h":
 
     double orderbook_midpoint(OrderBook_API *book);
 
+    # Returns the order book imbalance over the top `depth` levels of each side.
+    #
+    # The imbalance is `(bid_size - ask_size) / (bid_size + ask_size)`, or `NaN` when there
+    # are no levels on either side.
+    double orderbook_imbalance(const OrderBook_API *book, uintptr_t depth);
+
     double orderbook_get_avg_px_for_quantity(OrderBook_API *book,
                                              Quantity_t qty,
                                              OrderSide order_side);
@@ -1747,6 +1786,19 @@ cdef extern from "../includes/model.h":
 
     void money_sub_assign(Money_t a, Money_t b);
 
+    # Writes the sum of `a` and `b` to `result`, returning 1 on success.
+    #
+    # Returns 0 if the currencies of `a` and `b` differ or the result overflows.
+    uint8_t money_add(Money_t a, Money_t b, Money_t *result);
+
+    # Writes the difference of `a` and `b` to `result`, returning 1 on success.
+    #
+    # Returns 0 if the currencies of `a` and `b` differ or the result underflows.
+    uint8_t money_sub(Money_t a, Money_t b, Money_t *result);
+
+    # Returns a [`Money`] as a C string pointer.
+    const char *money_to_cstr(const Money_t *money);
+
     Price_t price_new(double value, uint8_t precision);
 
     Price_t price_from_raw(int64_t raw, uint8_t precision);
@@ -1757,6 +1809,19 @@ cdef extern from "../includes/model.h":
 
     void price_sub_assign(Price_t a, Price_t b);
 
+    # Writes the sum of `a` and `b` to `result`, returning 1 on success.
+    #
+    # Returns 0 if `b` has a greater precision than `a` or the result overflows.
+    uint8_t price_add(Price_t a, Price_t b, Price_t *result);
+
+    # Writes the difference of `a` and `b` to `result`, returning 1 on success.
+    #
+    # Returns 0 if `b` has a greater precision than `a` or the result underflows.
+    uint8_t price_sub(Price_t a, Price_t b, Price_t *result);
+
+    # Returns a [`Price`] as a C string pointer.
+    const char *price_to_cstr(const Price_t *price);
+
     Quantity_t quantity_new(double value, uint8_t precision);
 
     Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
@@ -1770,3 +1835,16 @@ cdef extern from "../includes/model.h":
     void quantity_sub_assign(Quantity_t a, Quantity_t b);
 
     void quantity_sub_assign_u64(Quantity_t a, uint64_t b);
+
+    # Writes the sum of `a` and `b` to `result`, returning 1 on success.
+    #
+    # Returns 0 if `b` has a greater precision than `a` or the result overflows.
+    uint8_t quantity_add(Quantity_t a, Quantity_t b, Quantity_t *result);
+
+    # Writes the difference of `a` and `b` to `result`, returning 1 on success.
+    #
+    # Returns 0 if `b` has a greater precision than `a` or the result underflows.
+    uint8_t quantity_sub(Quantity_t a, Quantity_t b, Quantity_t *result);
+
+    # Returns a [`Quantity`] as a C string pointer.
+    const char *quantity_to_cstr(const Quantity_t *qty);
@@ -56,6 +56,7 @@ CREATE TABLE IF NOT EXISTS "instrument" (
     quote_currency TEXT REFERENCES currency(id),
     settlement_currency TEXT REFERENCES currency(id),
     isin TEXT,
+    figi TEXT,
     asset_class ASSET_CLASS,
     exchange TEXT,
     multiplier TEXT,